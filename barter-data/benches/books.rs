@@ -0,0 +1,161 @@
+//! Benchmarks local L2 order book maintenance at various depths, comparing the sorted `Vec`
+//! backed [`OrderBook`] with the `BTreeMap` backed [`BTreeOrderBook`] & fixed-point
+//! [`TickOrderBook`].
+//!
+//! Run with: `cargo bench -p barter-data --bench books`
+
+use barter_data::{
+    books::{
+        backend::LocalOrderBook,
+        btree::{BTreeOrderBook, BTreeOrderBookSide},
+        ticks::TickOrderBook,
+        Level, OrderBook, OrderBookSide,
+    },
+    subscription::book::OrderBookEvent,
+};
+use barter_instrument::instrument::fixed::TickScale;
+use criterion::{
+    criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, BenchmarkId, Criterion,
+};
+use rust_decimal::Decimal;
+use std::hint::black_box;
+
+const DEPTHS: [u32; 4] = [10, 100, 1_000, 5_000];
+
+/// Number of levels in each [`OrderBookEvent::Update`] side.
+const UPDATE_LEVELS: u32 = 10;
+
+/// Amounts cycled through by each upsert, exercising the replace, remove & re-insert scenarios
+/// so the depth of the book remains stable.
+const AMOUNTS: [Decimal; 3] = [Decimal::TWO, Decimal::ZERO, Decimal::ONE];
+
+/// Generate `depth` levels with a one tick price increment from the `start` price.
+fn levels(start: i64, depth: u32, step: i64) -> Vec<Level> {
+    (0..i64::from(depth))
+        .map(|index| Level::new(Decimal::new(start + index * step, 2), Decimal::ONE))
+        .collect()
+}
+
+fn book(depth: u32) -> OrderBook {
+    OrderBook::new(
+        0,
+        None,
+        levels(999_999, depth, -1),
+        levels(1_000_000, depth, 1),
+    )
+}
+
+fn tick_book(depth: u32) -> TickOrderBook {
+    let mut book = TickOrderBook::new(TickScale::new(Decimal::new(1, 2), Decimal::ONE).unwrap());
+    book.update(OrderBookEvent::Snapshot(self::book(depth)));
+    book
+}
+
+/// Upsert a `Level` into the middle of a bid side of each depth.
+fn side_upsert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("books/side_upsert");
+    for depth in DEPTHS {
+        let price = Decimal::new(999_999 - i64::from(depth / 2), 2);
+
+        let mut bids = OrderBookSide::bids(levels(999_999, depth, -1));
+        let mut amounts = AMOUNTS.iter().cycle();
+        group.bench_function(BenchmarkId::new("sorted", depth), |b| {
+            b.iter(|| bids.upsert(black_box([Level::new(price, *amounts.next().unwrap())])))
+        });
+
+        let mut bids = BTreeOrderBookSide::bids(levels(999_999, depth, -1));
+        let mut amounts = AMOUNTS.iter().cycle();
+        group.bench_function(BenchmarkId::new("btree", depth), |b| {
+            b.iter(|| bids.upsert(black_box([Level::new(price, *amounts.next().unwrap())])))
+        });
+    }
+    group.finish();
+}
+
+/// Apply [`OrderBookEvent::Update`]s to a book, each updating [`UPDATE_LEVELS`] levels per side
+/// spread evenly across the full depth of the book (ie/ mid-book churn).
+fn bench_book_update<Book>(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    backend: &str,
+    mut book: Book,
+    depth: u32,
+) where
+    Book: LocalOrderBook,
+{
+    let stride = (depth / UPDATE_LEVELS).max(1);
+
+    let updates = AMOUNTS
+        .into_iter()
+        .map(|amount| {
+            let update = |start: i64, step: i64| {
+                (0..UPDATE_LEVELS.min(depth))
+                    .map(|index| {
+                        let offset = i64::from(index * stride) * step;
+                        Level::new(Decimal::new(start + offset, 2), amount)
+                    })
+                    .collect::<Vec<_>>()
+            };
+            OrderBookEvent::Update(OrderBook::new(
+                0,
+                None,
+                update(999_999, -1),
+                update(1_000_000, 1),
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    let mut updates = updates.iter().cycle();
+    group.bench_function(BenchmarkId::new(backend, depth), |b| {
+        b.iter(|| book.update(black_box(updates.next().unwrap().clone())))
+    });
+}
+
+fn book_update(c: &mut Criterion) {
+    let mut group = c.benchmark_group("books/book_update");
+    for depth in DEPTHS {
+        bench_book_update(&mut group, "sorted", book(depth), depth);
+        bench_book_update(
+            &mut group,
+            "btree",
+            BTreeOrderBook::from(book(depth)),
+            depth,
+        );
+        bench_book_update(&mut group, "ticks", tick_book(depth), depth);
+    }
+    group.finish();
+}
+
+fn book_snapshot(c: &mut Criterion) {
+    let mut group = c.benchmark_group("books/book_snapshot");
+    for depth in DEPTHS {
+        let book = book(depth);
+        group.bench_function(BenchmarkId::new("sorted", depth), |b| {
+            b.iter(|| book.snapshot(black_box(depth as usize)))
+        });
+
+        let book = BTreeOrderBook::from(book);
+        group.bench_function(BenchmarkId::new("btree", depth), |b| {
+            b.iter(|| book.snapshot(black_box(depth as usize)))
+        });
+    }
+    group.finish();
+}
+
+fn mid_price(c: &mut Criterion) {
+    let mut group = c.benchmark_group("books/mid_price");
+    for depth in DEPTHS {
+        let book = book(depth);
+        group.bench_function(BenchmarkId::new("sorted", depth), |b| {
+            b.iter(|| black_box(&book).mid_price())
+        });
+
+        let book = BTreeOrderBook::from(book);
+        group.bench_function(BenchmarkId::new("btree", depth), |b| {
+            b.iter(|| black_box(&book).mid_price())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, side_upsert, book_update, book_snapshot, mid_price);
+criterion_main!(benches);
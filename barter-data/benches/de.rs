@@ -0,0 +1,51 @@
+//! Benchmarks the deserialisation of high-rate exchange WebSocket messages.
+//!
+//! Run with: `cargo bench -p barter-data --bench de`
+
+use barter_data::exchange::{
+    binance::{spot::l2::BinanceSpotOrderBookL2Update, trade::BinanceTrade},
+    bybit::{message::BybitMessage, subscription::BybitResponse, trade::BybitTrade},
+};
+use criterion::{
+    criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion, Throughput,
+};
+use serde::{de::DeserializeOwned, Deserialize};
+use std::hint::black_box;
+
+const BINANCE_TRADE: &str = r#"{"e":"trade","E":1649324825173,"s":"ETHUSDT","t":1000000000,"p":"10000.19","q":"0.239000","b":10108767791,"a":10108764858,"T":1749354825200,"m":false,"M":true}"#;
+
+const BINANCE_L2: &str = r#"{"e":"depthUpdate","E":1671656397761,"s":"ETHUSDT","U":22611425143,"u":22611425151,"b":[["1209.67000000","85.48210000"],["1209.66000000","20.68790000"],["1209.65000000","1.00000000"]],"a":[["1209.68000000","2.50000000"]]}"#;
+
+const BYBIT_TRADE: &str = r#"{"topic":"publicTrade.BTCUSDT","type":"snapshot","ts":1672304486868,"data":[{"T":1672304486865,"s":"BTCUSDT","S":"Buy","v":"0.001","p":"16578.50","L":"PlusTick","i":"20f43950-d8dd-5b31-9112-a178eb6023af","BT":false}]}"#;
+
+/// Untagged equivalent of [`BybitMessage`], which buffers the entire message before attempting
+/// to deserialise each variant.
+#[allow(dead_code)]
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum UntaggedBybitMessage {
+    Response(BybitResponse),
+    Trade(BybitTrade),
+}
+
+fn bench_de<T>(group: &mut BenchmarkGroup<'_, WallTime>, name: &str, input: &str)
+where
+    T: DeserializeOwned,
+{
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_function(name, |b| {
+        b.iter(|| serde_json::from_str::<T>(black_box(input)).unwrap())
+    });
+}
+
+fn de(c: &mut Criterion) {
+    let mut group = c.benchmark_group("de");
+    bench_de::<BinanceTrade>(&mut group, "binance_trade", BINANCE_TRADE);
+    bench_de::<BinanceSpotOrderBookL2Update>(&mut group, "binance_l2_update", BINANCE_L2);
+    bench_de::<UntaggedBybitMessage>(&mut group, "bybit_trade_untagged", BYBIT_TRADE);
+    bench_de::<BybitMessage>(&mut group, "bybit_trade", BYBIT_TRADE);
+    group.finish();
+}
+
+criterion_group!(benches, de);
+criterion_main!(benches);
@@ -0,0 +1,136 @@
+//! Benchmarks order book read latency under contention, comparing an `Arc<RwLock<OrderBook>>`
+//! with a [`SharedOrderBook`], while a writer thread continuously applies updates.
+//!
+//! Run with: `cargo bench -p barter-data --bench shared_books`
+
+use barter_data::{
+    books::{shared::SharedOrderBook, Level, OrderBook},
+    subscription::book::OrderBookEvent,
+};
+use parking_lot::RwLock;
+use rust_decimal::Decimal;
+use std::{
+    hint::black_box,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Barrier,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+const DEPTH: i64 = 1_000;
+const READERS: usize = 4;
+const DURATION: Duration = Duration::from_secs(2);
+
+fn book() -> OrderBook {
+    let levels = |start: i64, step: i64| {
+        (0..DEPTH)
+            .map(|index| Level::new(Decimal::new(start + index * step, 2), Decimal::ONE))
+            .collect::<Vec<_>>()
+    };
+    OrderBook::new(0, None, levels(999_999, -1), levels(1_000_000, 1))
+}
+
+/// Cycle through updates that replace, remove & re-insert the best bid & ask.
+fn updates() -> Vec<OrderBookEvent> {
+    [Decimal::TWO, Decimal::ZERO, Decimal::ONE]
+        .into_iter()
+        .enumerate()
+        .map(|(sequence, amount)| {
+            OrderBookEvent::Update(OrderBook::new(
+                sequence as u64,
+                None,
+                [Level::new(Decimal::new(999_999, 2), amount)],
+                [Level::new(Decimal::new(1_000_000, 2), amount)],
+            ))
+        })
+        .collect()
+}
+
+/// Run [`READERS`] reader threads and a writer thread for [`DURATION`], printing the mean read
+/// latency & the writer throughput.
+fn bench<Read, Write>(name: &str, read: Read, write: Write)
+where
+    Read: Fn() -> Box<dyn FnMut() + Send> + Send + Sync,
+    Write: FnMut(OrderBookEvent) + Send,
+{
+    let running = AtomicBool::new(true);
+    let barrier = Barrier::new(READERS + 1);
+
+    let (reads, writes) = thread::scope(|scope| {
+        let readers = (0..READERS)
+            .map(|_| {
+                let mut read = read();
+                let (running, barrier) = (&running, &barrier);
+                scope.spawn(move || {
+                    barrier.wait();
+                    let start = Instant::now();
+                    let mut reads = 0u64;
+                    while running.load(Ordering::Relaxed) {
+                        read();
+                        reads += 1;
+                    }
+                    (reads, start.elapsed())
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut write = write;
+        let updates = updates();
+        barrier.wait();
+        let start = Instant::now();
+        let mut writes = 0u64;
+        for update in updates.iter().cycle() {
+            write(update.clone());
+            writes += 1;
+            if start.elapsed() > DURATION {
+                break;
+            }
+        }
+        running.store(false, Ordering::Relaxed);
+
+        let reads = readers
+            .into_iter()
+            .map(|reader| reader.join().unwrap())
+            .collect::<Vec<_>>();
+        (reads, writes)
+    });
+
+    let mean_read_ns = reads
+        .iter()
+        .map(|(reads, elapsed)| elapsed.as_nanos() as f64 / *reads as f64)
+        .sum::<f64>()
+        / reads.len() as f64;
+
+    println!(
+        "{name:<40} {mean_read_ns:>10.1} ns/read {:>12.0} writes/s",
+        writes as f64 / DURATION.as_secs_f64()
+    );
+}
+
+fn main() {
+    let locked = Arc::new(RwLock::new(book()));
+    bench(
+        "shared_books/rwlock",
+        || {
+            let book = Arc::clone(&locked);
+            Box::new(move || {
+                black_box(book.read().mid_price());
+            })
+        },
+        |event| locked.write().update(event),
+    );
+
+    let shared = Arc::new(SharedOrderBook::new(book()));
+    bench(
+        "shared_books/shared_order_book",
+        || {
+            let mut reader = shared.reader();
+            Box::new(move || {
+                black_box(reader.load().mid_price());
+            })
+        },
+        |event| shared.update(event),
+    );
+}
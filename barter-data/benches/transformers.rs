@@ -0,0 +1,158 @@
+//! Benchmarks exchange [`Transformer`] throughput, parsing & transforming recorded raw
+//! WebSocket message fixtures into normalised Barter [`MarketEvent`](barter_data::event::MarketEvent)s.
+//!
+//! Run with: `cargo bench -p barter-data --bench transformers`
+
+use barter_data::{
+    books::{Level, OrderBook},
+    event::MarketEvent,
+    exchange::{
+        binance::{
+            channel::BinanceChannel,
+            spot::{l2::BinanceSpotOrderBooksL2Transformer, BinanceSpot},
+            trade::BinanceTrade,
+        },
+        bybit::{channel::BybitChannel, message::BybitMessage, spot::BybitSpot},
+        subscription::ExchangeSub,
+    },
+    subscription::{
+        book::{OrderBookEvent, OrderBooksL2},
+        trade::PublicTrades,
+        Map, SubscriptionKind,
+    },
+    transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+    Identifier,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::{
+    protocol::{
+        websocket::{WebSocketParser, WsMessage},
+        StreamParser,
+    },
+    subscription::SubscriptionId,
+    Transformer,
+};
+use chrono::Utc;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use std::hint::black_box;
+use tokio::sync::mpsc;
+
+const BINANCE_SPOT_TRADES: &str = include_str!("fixtures/binance_spot_trades.jsonl");
+const BINANCE_SPOT_DEPTH_UPDATES: &str = include_str!("fixtures/binance_spot_depth_updates.jsonl");
+const BYBIT_SPOT_TRADES: &str = include_str!("fixtures/bybit_spot_trades.jsonl");
+
+/// Last update id of the [`OrderBook`] snapshot preceding the recorded Binance spot depth updates.
+const BINANCE_SPOT_SNAPSHOT_SEQUENCE: u64 = 22611425142;
+
+const INSTRUMENT: &str = "btc_usdt";
+
+fn fixture(raw: &str) -> Vec<String> {
+    raw.lines().map(String::from).collect()
+}
+
+fn instrument_map(subscription_id: SubscriptionId) -> Map<&'static str> {
+    Map::from_iter([(subscription_id, INSTRUMENT)])
+}
+
+fn init<Exchange, Kind, Transform>(
+    subscription_id: SubscriptionId,
+    initial_snapshots: &[MarketEvent<&'static str, Kind::Event>],
+) -> Transform
+where
+    Kind: SubscriptionKind,
+    Transform: ExchangeTransformer<Exchange, &'static str, Kind>,
+{
+    let (ws_sink_tx, _) = mpsc::unbounded_channel();
+    futures::executor::block_on(Transform::init(
+        instrument_map(subscription_id),
+        initial_snapshots,
+        ws_sink_tx,
+    ))
+    .unwrap()
+}
+
+/// Parse & transform every message of the fixture, asserting each yields a `MarketEvent`.
+fn transform_all<Transform>(transformer: &mut Transform, messages: &[String])
+where
+    Transform: Transformer,
+    Transform::Input: serde::de::DeserializeOwned,
+    Transform::Error: std::fmt::Debug,
+{
+    for message in messages {
+        let input =
+            WebSocketParser::parse::<Transform::Input>(Ok(WsMessage::Text(message.clone())))
+                .unwrap()
+                .unwrap();
+
+        for output in transformer.transform(input) {
+            black_box(output.unwrap());
+        }
+    }
+}
+
+fn transformers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("transformers");
+
+    let messages = fixture(BINANCE_SPOT_TRADES);
+    let mut transformer = init::<
+        BinanceSpot,
+        PublicTrades,
+        StatelessTransformer<BinanceSpot, &'static str, PublicTrades, BinanceTrade>,
+    >(
+        ExchangeSub::from((BinanceChannel::TRADES, "BTCUSDT")).id(),
+        &[],
+    );
+    group.throughput(Throughput::Elements(messages.len() as u64));
+    group.bench_function("binance_spot_trades", |b| {
+        b.iter(|| transform_all(&mut transformer, &messages))
+    });
+
+    let messages = fixture(BYBIT_SPOT_TRADES);
+    let mut transformer = init::<
+        BybitSpot,
+        PublicTrades,
+        StatelessTransformer<BybitSpot, &'static str, PublicTrades, BybitMessage>,
+    >(
+        ExchangeSub::from((BybitChannel::TRADES, "BTCUSDT")).id(),
+        &[],
+    );
+    group.throughput(Throughput::Elements(messages.len() as u64));
+    group.bench_function("bybit_spot_trades", |b| {
+        b.iter(|| transform_all(&mut transformer, &messages))
+    });
+
+    // Sequenced updates can only be applied once, so each iteration is set up with a transformer
+    // re-initialised from the same snapshot
+    let messages = fixture(BINANCE_SPOT_DEPTH_UPDATES);
+    let snapshot = MarketEvent {
+        time_exchange: Utc::now(),
+        time_received: Utc::now(),
+        exchange: ExchangeId::BinanceSpot,
+        instrument: INSTRUMENT,
+        kind: OrderBookEvent::Snapshot(OrderBook::new(
+            BINANCE_SPOT_SNAPSHOT_SEQUENCE,
+            None,
+            Vec::<Level>::new(),
+            Vec::<Level>::new(),
+        )),
+    };
+    let subscription_id = ExchangeSub::from((BinanceChannel::ORDER_BOOK_L2, "BTCUSDT")).id();
+    group.throughput(Throughput::Elements(messages.len() as u64));
+    group.bench_function("binance_spot_depth_updates", |b| {
+        b.iter_batched(
+            || {
+                init::<BinanceSpot, OrderBooksL2, BinanceSpotOrderBooksL2Transformer<&'static str>>(
+                    subscription_id.clone(),
+                    std::slice::from_ref(&snapshot),
+                )
+            },
+            |mut transformer| transform_all(&mut transformer, &messages),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, transformers);
+criterion_main!(benches);
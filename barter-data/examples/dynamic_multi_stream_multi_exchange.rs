@@ -11,6 +11,7 @@ use barter_instrument::{
     instrument::{kind::InstrumentKind, Instrument},
 };
 use futures::StreamExt;
+use std::collections::HashMap;
 use tracing::{info, warn};
 
 #[rustfmt::skip]
@@ -29,9 +30,10 @@ async fn main() {
     // - Every "subscription batch" will initialise at-least-one WebSocket stream under the hood.
     // - If the "subscription batch" contains more-than-one ExchangeId and/or SubKind, the batch
     //   will be further split under the hood for compile-time reasons.
+    // - Exchanges without a ConnectorConfig in the provided map connect to mainnet.
 
     // Initialise market reconnect::Event streams for various ExchangeIds and SubscriptionKinds
-    let streams = DynamicStreams::init([
+    let streams = DynamicStreams::init(&HashMap::new(), [
         // Batch notes:
         // Since batch contains 1 ExchangeId and 1 SubscriptionKind, so only 1 (1x1) WebSockets
         // will be spawned for this batch.
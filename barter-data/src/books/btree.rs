@@ -0,0 +1,320 @@
+use crate::{
+    books::{mid_price, volume_weighted_mid_price, Asks, Bids, Level, OrderBook, OrderBookSide},
+    subscription::book::OrderBookEvent,
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tracing::debug;
+
+/// Normalised Barter L2 order book backed by a [`BTreeMap`] price ladder for each side.
+///
+/// Exposes the same API as the sorted `Vec` backed [`OrderBook`], but upserts are
+/// `O(log n)` rather than `O(n)`, making it the better choice for very deep books with heavy
+/// mid-book churn. Reading the top of the book & taking snapshots is slower, since levels are
+/// not contiguous in memory.
+///
+/// See `benches/books.rs` for a comparison of the two backends.
+#[derive(Clone, PartialEq, Eq, Debug, Default, Deserialize, Serialize)]
+pub struct BTreeOrderBook {
+    pub sequence: u64,
+    pub time_engine: Option<DateTime<Utc>>,
+    bids: BTreeOrderBookSide<Bids>,
+    asks: BTreeOrderBookSide<Asks>,
+}
+
+impl BTreeOrderBook {
+    /// Construct a new [`BTreeOrderBook`].
+    ///
+    /// Note that the passed bid and asks levels do not need to be pre-sorted.
+    pub fn new<IterBids, IterAsks, L>(
+        sequence: u64,
+        time_engine: Option<DateTime<Utc>>,
+        bids: IterBids,
+        asks: IterAsks,
+    ) -> Self
+    where
+        IterBids: IntoIterator<Item = L>,
+        IterAsks: IntoIterator<Item = L>,
+        L: Into<Level>,
+    {
+        Self {
+            sequence,
+            time_engine,
+            bids: BTreeOrderBookSide::bids(bids),
+            asks: BTreeOrderBookSide::asks(asks),
+        }
+    }
+
+    /// Generate a sorted [`OrderBook`] snapshot with a maximum depth.
+    pub fn snapshot(&self, depth: usize) -> OrderBook {
+        OrderBook::new(
+            self.sequence,
+            self.time_engine,
+            self.bids.levels().take(depth),
+            self.asks.levels().take(depth),
+        )
+    }
+
+    /// Update the local [`BTreeOrderBook`] from a new [`OrderBookEvent`].
+    pub fn update(&mut self, event: OrderBookEvent) {
+        match event {
+            OrderBookEvent::Snapshot(snapshot) => {
+                *self = Self::from(snapshot);
+            }
+            OrderBookEvent::Update(update) => {
+                self.sequence = update.sequence;
+                self.time_engine = update.time_engine;
+                self.upsert_bids(update.bids());
+                self.upsert_asks(update.asks());
+            }
+        }
+    }
+
+    /// Update the local [`BTreeOrderBook`] by upserting the levels in an [`OrderBookSide`].
+    pub fn upsert_bids(&mut self, update: &OrderBookSide<Bids>) {
+        self.bids.upsert(update.levels().iter().copied())
+    }
+
+    /// Update the local [`BTreeOrderBook`] by upserting the levels in an [`OrderBookSide`].
+    pub fn upsert_asks(&mut self, update: &OrderBookSide<Asks>) {
+        self.asks.upsert(update.levels().iter().copied())
+    }
+
+    /// Return a reference to this [`BTreeOrderBook`]s bids.
+    pub fn bids(&self) -> &BTreeOrderBookSide<Bids> {
+        &self.bids
+    }
+
+    /// Return a reference to this [`BTreeOrderBook`]s asks.
+    pub fn asks(&self) -> &BTreeOrderBookSide<Asks> {
+        &self.asks
+    }
+
+    /// Calculate the mid-price by taking the average of the best bid and ask prices.
+    ///
+    /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
+    pub fn mid_price(&self) -> Option<Decimal> {
+        match (self.bids.best(), self.asks.best()) {
+            (Some(best_bid), Some(best_ask)) => Some(mid_price(best_bid.price, best_ask.price)),
+            (Some(best_bid), None) => Some(best_bid.price),
+            (None, Some(best_ask)) => Some(best_ask.price),
+            (None, None) => None,
+        }
+    }
+
+    /// Calculate the volume weighted mid-price (micro-price), weighing the best bid and ask prices
+    /// with their associated amount.
+    ///
+    /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
+    pub fn volume_weighed_mid_price(&self) -> Option<Decimal> {
+        match (self.bids.best(), self.asks.best()) {
+            (Some(best_bid), Some(best_ask)) => Some(volume_weighted_mid_price(best_bid, best_ask)),
+            (Some(best_bid), None) => Some(best_bid.price),
+            (None, Some(best_ask)) => Some(best_ask.price),
+            (None, None) => None,
+        }
+    }
+}
+
+impl From<OrderBook> for BTreeOrderBook {
+    fn from(book: OrderBook) -> Self {
+        Self {
+            sequence: book.sequence,
+            time_engine: book.time_engine,
+            bids: BTreeOrderBookSide::bids(book.bids().levels().iter().copied()),
+            asks: BTreeOrderBookSide::asks(book.asks().levels().iter().copied()),
+        }
+    }
+}
+
+/// [`BTreeMap`] price ladder of `price -> amount` for one [`Side`](barter_instrument::Side) of a
+/// [`BTreeOrderBook`].
+#[derive(Clone, PartialEq, Eq, Debug, Default, Deserialize, Serialize)]
+pub struct BTreeOrderBookSide<Side> {
+    #[serde(skip_serializing, default)]
+    pub side: Side,
+    levels: BTreeMap<Decimal, Decimal>,
+}
+
+impl<Side> BTreeOrderBookSide<Side>
+where
+    Side: std::fmt::Display,
+{
+    fn new<Iter, L>(side: Side, levels: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = L>,
+        L: Into<Level>,
+    {
+        Self {
+            side,
+            levels: levels
+                .into_iter()
+                .map(L::into)
+                .map(|level| (level.price, level.amount))
+                .collect(),
+        }
+    }
+
+    /// Number of price levels in this [`BTreeOrderBookSide`].
+    pub fn len(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Returns true if this [`BTreeOrderBookSide`] contains no price levels.
+    pub fn is_empty(&self) -> bool {
+        self.levels.is_empty()
+    }
+
+    /// Upsert [`Level`]s into this [`BTreeOrderBookSide`], removing any with a zero amount.
+    pub fn upsert<Iter, L>(&mut self, levels: Iter)
+    where
+        Iter: IntoIterator<Item = L>,
+        L: Into<Level>,
+    {
+        levels.into_iter().for_each(|upsert| {
+            let upsert = upsert.into();
+            if !upsert.amount.is_zero() {
+                self.levels.insert(upsert.price, upsert.amount);
+            } else if self.levels.remove(&upsert.price).is_none() {
+                debug!(
+                    ?upsert,
+                    side = %self.side,
+                    "received upsert Level with zero amount (to remove) that was not found"
+                );
+            }
+        })
+    }
+}
+
+impl BTreeOrderBookSide<Bids> {
+    /// Construct a new [`BTreeOrderBookSide<Bids>`] from the provided [`Level`]s.
+    pub fn bids<Iter, L>(levels: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = L>,
+        L: Into<Level>,
+    {
+        Self::new(Bids, levels)
+    }
+
+    /// Return an [`Iterator`] over the bid [`Level`]s, best (highest price) first.
+    pub fn levels(&self) -> impl Iterator<Item = Level> + '_ {
+        self.levels.iter().rev().map(to_level)
+    }
+
+    /// Return the best (highest price) bid [`Level`], if any.
+    pub fn best(&self) -> Option<Level> {
+        self.levels.last_key_value().map(to_level)
+    }
+}
+
+impl BTreeOrderBookSide<Asks> {
+    /// Construct a new [`BTreeOrderBookSide<Asks>`] from the provided [`Level`]s.
+    pub fn asks<Iter, L>(levels: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = L>,
+        L: Into<Level>,
+    {
+        Self::new(Asks, levels)
+    }
+
+    /// Return an [`Iterator`] over the ask [`Level`]s, best (lowest price) first.
+    pub fn levels(&self) -> impl Iterator<Item = Level> + '_ {
+        self.levels.iter().map(to_level)
+    }
+
+    /// Return the best (lowest price) ask [`Level`], if any.
+    pub fn best(&self) -> Option<Level> {
+        self.levels.first_key_value().map(to_level)
+    }
+}
+
+fn to_level((price, amount): (&Decimal, &Decimal)) -> Level {
+    Level {
+        price: *price,
+        amount: *amount,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_btree_order_book_matches_order_book() {
+        struct TestCase {
+            event: OrderBookEvent,
+            expected_mid_price: Option<Decimal>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: snapshot replaces the empty book
+                event: OrderBookEvent::Snapshot(OrderBook::new(
+                    1,
+                    None,
+                    vec![Level::new(dec!(99), dec!(1)), Level::new(dec!(98), dec!(2))],
+                    vec![
+                        Level::new(dec!(101), dec!(1)),
+                        Level::new(dec!(102), dec!(2)),
+                    ],
+                )),
+                expected_mid_price: Some(dec!(100)),
+            },
+            TestCase {
+                // TC1: update inserts a new best bid, replaces an ask & removes an ask
+                event: OrderBookEvent::Update(OrderBook::new(
+                    2,
+                    None,
+                    vec![Level::new(dec!(100), dec!(3))],
+                    vec![
+                        Level::new(dec!(101), dec!(0)),
+                        Level::new(dec!(102), dec!(5)),
+                    ],
+                )),
+                expected_mid_price: Some(dec!(101)),
+            },
+            TestCase {
+                // TC2: removing non-existent levels & every bid leaves only the asks
+                event: OrderBookEvent::Update(OrderBook::new(
+                    3,
+                    None,
+                    vec![
+                        Level::new(dec!(100), dec!(0)),
+                        Level::new(dec!(99), dec!(0)),
+                        Level::new(dec!(98), dec!(0)),
+                        Level::new(dec!(97), dec!(0)),
+                    ],
+                    vec![],
+                )),
+                expected_mid_price: Some(dec!(102)),
+            },
+        ];
+
+        let mut vec_book = OrderBook::default();
+        let mut btree_book = BTreeOrderBook::default();
+
+        for (index, test) in tests.into_iter().enumerate() {
+            vec_book.update(test.event.clone());
+            btree_book.update(test.event);
+
+            assert_eq!(
+                btree_book.snapshot(usize::MAX),
+                vec_book.snapshot(usize::MAX),
+                "TC{index} failed"
+            );
+            assert_eq!(
+                btree_book.mid_price(),
+                test.expected_mid_price,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                btree_book.volume_weighed_mid_price(),
+                vec_book.volume_weighed_mid_price(),
+                "TC{index} failed"
+            );
+        }
+    }
+}
@@ -0,0 +1,433 @@
+use crate::{
+    books::{mid_price, Level, OrderBook},
+    event::MarketEvent,
+    subscription::book::OrderBookEvent,
+};
+use barter_integration::error::SocketError;
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+/// Default number of [`OrderBookEvent`]s applied between each [`OrderBookHistory`] checkpoint.
+pub const DEFAULT_CHECKPOINT_INTERVAL: usize = 1_000;
+
+/// Reconstructs historical L2 [`OrderBook`]s from recorded snapshot & delta [`OrderBookEvent`]s,
+/// answering point-in-time queries for market-microstructure research.
+///
+/// [`OrderBook`] checkpoints are stored at a fixed interval of applied [`OrderBookEvent`]s, so a
+/// point-in-time query only replays the [`OrderBookEvent`]s since the closest prior checkpoint.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct OrderBookHistory {
+    events: Vec<(DateTime<Utc>, OrderBookEvent)>,
+    checkpoints: Vec<Checkpoint>,
+}
+
+/// [`OrderBook`] state after applying the first `applied` [`OrderBookEvent`]s.
+#[derive(Clone, PartialEq, Eq, Debug)]
+struct Checkpoint {
+    applied: usize,
+    book: OrderBook,
+}
+
+/// Point-in-time sample of an [`OrderBook`], suitable for tabular (eg/ DataFrame) analysis.
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct BookSample {
+    pub time: DateTime<Utc>,
+    pub sequence: u64,
+    pub best_bid_price: Option<Decimal>,
+    pub best_ask_price: Option<Decimal>,
+    pub mid_price: Option<Decimal>,
+    pub spread: Option<Decimal>,
+    /// Cumulative amount of the top `depth` bid [`Level`]s.
+    pub bid_depth: Decimal,
+    /// Cumulative amount of the top `depth` ask [`Level`]s.
+    pub ask_depth: Decimal,
+}
+
+impl OrderBookHistory {
+    /// Construct a new [`OrderBookHistory`] from recorded [`OrderBookEvent`]s, using the
+    /// [`DEFAULT_CHECKPOINT_INTERVAL`].
+    ///
+    /// [`OrderBookEvent`]s are ordered by `time_exchange`. Any [`OrderBookEvent::Update`]s preceding
+    /// the first [`OrderBookEvent::Snapshot`] cannot be applied, and are discarded.
+    pub fn new<Iter, InstrumentKey>(events: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = MarketEvent<InstrumentKey, OrderBookEvent>>,
+    {
+        Self::with_checkpoint_interval(events, DEFAULT_CHECKPOINT_INTERVAL)
+    }
+
+    /// Construct a new [`OrderBookHistory`] from recorded [`OrderBookEvent`]s, storing an
+    /// [`OrderBook`] checkpoint every `interval` applied [`OrderBookEvent`]s.
+    ///
+    /// See [`Self::new`] for details.
+    pub fn with_checkpoint_interval<Iter, InstrumentKey>(events: Iter, interval: usize) -> Self
+    where
+        Iter: IntoIterator<Item = MarketEvent<InstrumentKey, OrderBookEvent>>,
+    {
+        let mut events = events
+            .into_iter()
+            .map(|event| (event.time_exchange, event.kind))
+            .collect::<Vec<_>>();
+
+        // Stable sort retains the recorded order of OrderBookEvents with identical timestamps
+        events.sort_by_key(|(time, _)| *time);
+
+        let first_snapshot = events
+            .iter()
+            .position(|(_, event)| matches!(event, OrderBookEvent::Snapshot(_)))
+            .unwrap_or(events.len());
+        events.drain(..first_snapshot);
+
+        let interval = interval.max(1);
+        let mut book = OrderBook::default();
+        let checkpoints = events
+            .iter()
+            .enumerate()
+            .filter_map(|(index, (_, event))| {
+                book.update(event.clone());
+                let applied = index + 1;
+                (applied % interval == 0).then(|| Checkpoint {
+                    applied,
+                    book: book.clone(),
+                })
+            })
+            .collect();
+
+        Self {
+            events,
+            checkpoints,
+        }
+    }
+
+    /// Load an [`OrderBookHistory`] from recorded newline delimited JSON
+    /// [`MarketEvent<InstrumentKey, OrderBookEvent>`](MarketEvent)s.
+    pub fn from_json_lines<InstrumentKey>(input: &str) -> Result<Self, SocketError>
+    where
+        InstrumentKey: for<'de> Deserialize<'de>,
+    {
+        let events = input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str::<MarketEvent<InstrumentKey, OrderBookEvent>>(line).map_err(
+                    |error| SocketError::Deserialise {
+                        error,
+                        payload: line.to_owned(),
+                    },
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::new(events))
+    }
+
+    /// Number of recorded [`OrderBookEvent`]s that can be replayed.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns true if there are no recorded [`OrderBookEvent`]s that can be replayed.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Time range covered by the recorded [`OrderBookEvent`]s.
+    pub fn time_range(&self) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        Some((self.events.first()?.0, self.events.last()?.0))
+    }
+
+    /// Reconstruct the [`OrderBook`] state at the provided time, after every [`OrderBookEvent`]
+    /// with a `time_exchange` at or before it has been applied.
+    ///
+    /// Returns `None` if the time precedes the first recorded [`OrderBookEvent::Snapshot`].
+    pub fn book_at(&self, time: DateTime<Utc>) -> Option<OrderBook> {
+        let applied = self
+            .events
+            .partition_point(|(event_time, _)| *event_time <= time);
+        (applied > 0).then(|| self.replay(applied))
+    }
+
+    /// Sample the [`OrderBook`] at a fixed interval from `start` until `end` (inclusive),
+    /// generating a spread & depth time series. Bid & ask depth is the cumulative amount of the top
+    /// `depth` [`Level`]s.
+    ///
+    /// Sample times that precede the first recorded [`OrderBookEvent::Snapshot`] are skipped.
+    pub fn sample(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        interval: Duration,
+        depth: usize,
+    ) -> Vec<BookSample> {
+        if interval <= Duration::zero() {
+            return vec![];
+        }
+
+        let mut applied = self
+            .events
+            .partition_point(|(event_time, _)| *event_time <= start);
+        let mut book = self.replay(applied);
+        let mut samples = Vec::new();
+        let mut time = start;
+
+        while time <= end {
+            // Apply OrderBookEvents up until the sample time
+            while let Some((_, event)) = self
+                .events
+                .get(applied)
+                .filter(|(event_time, _)| *event_time <= time)
+            {
+                book.update(event.clone());
+                applied += 1;
+            }
+
+            if applied > 0 {
+                samples.push(BookSample::new(time, &book, depth));
+            }
+
+            time += interval;
+        }
+
+        samples
+    }
+
+    /// Replay the first `applied` [`OrderBookEvent`]s, starting from the closest checkpoint.
+    fn replay(&self, applied: usize) -> OrderBook {
+        let checkpoint = self
+            .checkpoints
+            .partition_point(|checkpoint| checkpoint.applied <= applied)
+            .checked_sub(1)
+            .map(|index| &self.checkpoints[index]);
+
+        let (mut book, replayed) = match checkpoint {
+            Some(checkpoint) => (checkpoint.book.clone(), checkpoint.applied),
+            None => (OrderBook::default(), 0),
+        };
+
+        self.events[replayed..applied]
+            .iter()
+            .for_each(|(_, event)| book.update(event.clone()));
+
+        book
+    }
+}
+
+impl BookSample {
+    /// CSV header matching the columns generated by [`Self::write_csv_row`].
+    pub const CSV_HEADER: &'static str =
+        "time,sequence,best_bid_price,best_ask_price,mid_price,spread,bid_depth,ask_depth";
+
+    /// Construct a new [`BookSample`] from the provided [`OrderBook`].
+    pub fn new(time: DateTime<Utc>, book: &OrderBook, depth: usize) -> Self {
+        let best_bid = book.bids().levels().first();
+        let best_ask = book.asks().levels().first();
+        let cumulative = |levels: &[Level]| levels.iter().take(depth).map(|l| l.amount).sum();
+
+        Self {
+            time,
+            sequence: book.sequence,
+            best_bid_price: best_bid.map(|level| level.price),
+            best_ask_price: best_ask.map(|level| level.price),
+            mid_price: best_bid
+                .zip(best_ask)
+                .map(|(bid, ask)| mid_price(bid.price, ask.price)),
+            spread: best_bid
+                .zip(best_ask)
+                .map(|(bid, ask)| ask.price - bid.price),
+            bid_depth: cumulative(book.bids().levels()),
+            ask_depth: cumulative(book.asks().levels()),
+        }
+    }
+
+    /// Write the [`BookSample`] as a CSV row, leaving unavailable values empty.
+    pub fn write_csv_row<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let optional = |value: Option<Decimal>| value.map(|v| v.to_string()).unwrap_or_default();
+
+        writeln!(
+            writer,
+            "{},{},{},{},{},{},{},{}",
+            self.time.to_rfc3339(),
+            self.sequence,
+            optional(self.best_bid_price),
+            optional(self.best_ask_price),
+            optional(self.mid_price),
+            optional(self.spread),
+            self.bid_depth,
+            self.ask_depth,
+        )
+    }
+}
+
+/// Write the [`BookSample`]s as CSV, including a header row.
+pub fn write_csv<W: Write>(samples: &[BookSample], mut writer: W) -> std::io::Result<()> {
+    writeln!(writer, "{}", BookSample::CSV_HEADER)?;
+    samples
+        .iter()
+        .try_for_each(|sample| sample.write_csv_row(&mut writer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_instrument::exchange::ExchangeId;
+    use rust_decimal_macros::dec;
+
+    fn event(time: DateTime<Utc>, event: OrderBookEvent) -> MarketEvent<u64, OrderBookEvent> {
+        MarketEvent {
+            time_exchange: time,
+            time_received: time,
+            exchange: ExchangeId::BinanceSpot,
+            instrument: 0,
+            kind: event,
+        }
+    }
+
+    fn events(base: DateTime<Utc>) -> Vec<MarketEvent<u64, OrderBookEvent>> {
+        let second = |seconds| base + Duration::seconds(seconds);
+        vec![
+            // Update preceding the first Snapshot is discarded
+            event(
+                second(0),
+                OrderBookEvent::Update(OrderBook::new(0, None, vec![(dec!(1), dec!(1))], vec![])),
+            ),
+            event(
+                second(1),
+                OrderBookEvent::Snapshot(OrderBook::new(
+                    1,
+                    None,
+                    vec![(dec!(99), dec!(1)), (dec!(98), dec!(2))],
+                    vec![(dec!(101), dec!(1)), (dec!(102), dec!(2))],
+                )),
+            ),
+            event(
+                second(2),
+                OrderBookEvent::Update(OrderBook::new(2, None, vec![(dec!(100), dec!(3))], vec![])),
+            ),
+            event(
+                second(3),
+                OrderBookEvent::Update(OrderBook::new(3, None, vec![], vec![(dec!(101), dec!(0))])),
+            ),
+            event(
+                second(4),
+                OrderBookEvent::Update(OrderBook::new(4, None, vec![(dec!(99), dec!(0))], vec![])),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_order_book_history_book_at() {
+        let base = Utc::now();
+
+        struct TestCase {
+            time: DateTime<Utc>,
+            expected: Option<OrderBook>,
+        }
+
+        let tests = [
+            TestCase {
+                // TC0: time before first Snapshot
+                time: base,
+                expected: None,
+            },
+            TestCase {
+                // TC1: time of first Snapshot
+                time: base + Duration::seconds(1),
+                expected: Some(OrderBook::new(
+                    1,
+                    None,
+                    vec![(dec!(99), dec!(1)), (dec!(98), dec!(2))],
+                    vec![(dec!(101), dec!(1)), (dec!(102), dec!(2))],
+                )),
+            },
+            TestCase {
+                // TC2: time between Updates
+                time: base + Duration::milliseconds(3500),
+                expected: Some(OrderBook::new(
+                    3,
+                    None,
+                    vec![
+                        (dec!(100), dec!(3)),
+                        (dec!(99), dec!(1)),
+                        (dec!(98), dec!(2)),
+                    ],
+                    vec![(dec!(102), dec!(2))],
+                )),
+            },
+            TestCase {
+                // TC3: time after final Update
+                time: base + Duration::seconds(60),
+                expected: Some(OrderBook::new(
+                    4,
+                    None,
+                    vec![(dec!(100), dec!(3)), (dec!(98), dec!(2))],
+                    vec![(dec!(102), dec!(2))],
+                )),
+            },
+        ];
+
+        // Recorded JSON lines are equivalent to the MarketEvents
+        let json_lines = events(base)
+            .iter()
+            .map(|event| serde_json::to_string(event).unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(
+            OrderBookHistory::from_json_lines::<u64>(&json_lines).unwrap(),
+            OrderBookHistory::new(events(base))
+        );
+
+        // Checkpoint intervals must not affect the reconstructed OrderBooks
+        for interval in [1, 2, DEFAULT_CHECKPOINT_INTERVAL] {
+            let history = OrderBookHistory::with_checkpoint_interval(events(base), interval);
+            assert_eq!(history.len(), 4);
+
+            for (index, test) in tests.iter().enumerate() {
+                let actual = history.book_at(test.time);
+                assert_eq!(actual, test.expected, "TC{index} failed");
+            }
+        }
+    }
+
+    #[test]
+    fn test_order_book_history_sample_to_csv() {
+        let base = Utc::now();
+        let history = OrderBookHistory::with_checkpoint_interval(events(base), 2);
+
+        let samples = history.sample(base, base + Duration::seconds(4), Duration::seconds(2), 1);
+
+        // Sample at base precedes the first Snapshot
+        assert_eq!(samples.len(), 2);
+        assert_eq!(
+            samples[0],
+            BookSample {
+                time: base + Duration::seconds(2),
+                sequence: 2,
+                best_bid_price: Some(dec!(100)),
+                best_ask_price: Some(dec!(101)),
+                mid_price: Some(dec!(100.5)),
+                spread: Some(dec!(1)),
+                bid_depth: dec!(3),
+                ask_depth: dec!(1),
+            }
+        );
+        assert_eq!(samples[1].sequence, 4);
+        assert_eq!(samples[1].spread, Some(dec!(2)));
+
+        let mut csv = Vec::new();
+        write_csv(&samples, &mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let mut lines = csv.lines();
+
+        assert_eq!(lines.next(), Some(BookSample::CSV_HEADER));
+        assert_eq!(
+            lines.next().unwrap(),
+            format!(
+                "{},2,100,101,100.50,1,3,1",
+                (base + Duration::seconds(2)).to_rfc3339()
+            )
+        );
+        assert_eq!(lines.count(), 1);
+    }
+}
@@ -1,6 +1,8 @@
 use crate::{
     books::{
+        backend::{LocalOrderBook, ManagedOrderBook, OrderBookBackend},
         map::{OrderBookMap, OrderBookMapMulti},
+        shared::{SharedOrderBook, SharedOrderBookMap},
         OrderBook,
     },
     error::DataError,
@@ -33,6 +35,7 @@ where
     St: Stream<Item = MarketStreamEvent<BookMap::Key, OrderBookEvent>> + Unpin,
     BookMap: OrderBookMap,
     BookMap::Key: Debug,
+    BookMap::Book: LocalOrderBook,
 {
     /// Manage local L2 [`OrderBook`]s.
     pub async fn run(mut self) {
@@ -61,6 +64,37 @@ where
     }
 }
 
+impl<St, Key> OrderBookL2Manager<St, SharedOrderBookMap<Key>>
+where
+    St: Stream<Item = MarketStreamEvent<Key, OrderBookEvent>> + Unpin,
+    Key: Debug + Eq + Hash,
+{
+    /// Manage local L2 [`SharedOrderBook`]s, publishing each updated [`OrderBook`] to readers.
+    pub async fn run_shared(mut self) {
+        while let Some(stream_event) = self.stream.next().await {
+            // Extract MarketEvent<InstrumentKey, OrderBookEvent>
+            let event = match stream_event {
+                MarketStreamEvent::Reconnecting(exchange) => {
+                    warn!(%exchange, "OrderBook manager input stream disconnected");
+                    continue;
+                }
+                MarketStreamEvent::Item(event) => event,
+            };
+
+            // Find SharedOrderBook associated with the MarketEvent InstrumentKey
+            let Some(book) = self.books.find(&event.instrument) else {
+                warn!(
+                    instrument = ?event.instrument,
+                    "consumed MarketStreamEvent<_, OrderBookEvent> for non-configured instrument"
+                );
+                continue;
+            };
+
+            book.update(event.kind);
+        }
+    }
+}
+
 /// Initialise a [`OrderBookL2Manager`] using the provided batches of [`OrderBooksL2`]
 /// [`Subscription`]s.
 ///
@@ -70,7 +104,7 @@ pub async fn init_multi_order_book_l2_manager<SubBatchIter, SubIter, Sub, Exchan
 ) -> Result<
     OrderBookL2Manager<
         impl Stream<Item = MarketStreamEvent<Instrument::Key, OrderBookEvent>>,
-        impl OrderBookMap<Key = Instrument::Key>,
+        impl OrderBookMap<Key = Instrument::Key, Book = OrderBook>,
     >,
     DataError,
 >
@@ -83,18 +117,147 @@ where
     Instrument::Key: Eq + Hash + Send + 'static,
     Subscription<Exchange, Instrument, OrderBooksL2>:
         Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    let (stream, books) =
+        init_order_book_l2_stream(subscription_batches.into_iter().map(|batch| {
+            batch
+                .into_iter()
+                .map(|sub| (sub.into(), OrderBook::default()))
+        }))
+        .await?;
+
+    Ok(OrderBookL2Manager {
+        stream,
+        books: OrderBookMapMulti::new(
+            books
+                .into_iter()
+                .map(|(key, book)| (key, Arc::new(RwLock::new(book))))
+                .collect(),
+        ),
+    })
+}
+
+/// Initialise a [`OrderBookL2Manager`] using the provided batches of [`OrderBooksL2`]
+/// [`Subscription`]s, each paired with the [`OrderBookBackend`] used to maintain it's local
+/// [`ManagedOrderBook`].
+///
+/// For example, a very deep book with heavy mid-book churn can use the
+/// [`OrderBookBackend::BTree`], while the other books remain [`OrderBookBackend::Sorted`].
+pub async fn init_multi_order_book_l2_manager_with_backends<
+    SubBatchIter,
+    SubIter,
+    Sub,
+    Exchange,
+    Instrument,
+>(
+    subscription_batches: SubBatchIter,
+) -> Result<
+    OrderBookL2Manager<
+        impl Stream<Item = MarketStreamEvent<Instrument::Key, OrderBookEvent>>,
+        impl OrderBookMap<Key = Instrument::Key, Book = ManagedOrderBook>,
+    >,
+    DataError,
+>
+where
+    SubBatchIter: IntoIterator<Item = SubIter>,
+    SubIter: IntoIterator<Item = (Sub, OrderBookBackend)>,
+    Sub: Into<Subscription<Exchange, Instrument, OrderBooksL2>>,
+    Exchange: StreamSelector<Instrument, OrderBooksL2> + Ord + Send + Sync + 'static,
+    Instrument: InstrumentData + Ord + 'static,
+    Instrument::Key: Eq + Hash + Send + 'static,
+    Subscription<Exchange, Instrument, OrderBooksL2>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    let (stream, books) =
+        init_order_book_l2_stream(subscription_batches.into_iter().map(|batch| {
+            batch
+                .into_iter()
+                .map(|(sub, backend)| (sub.into(), ManagedOrderBook::new(backend)))
+        }))
+        .await?;
+
+    Ok(OrderBookL2Manager {
+        stream,
+        books: OrderBookMapMulti::new(
+            books
+                .into_iter()
+                .map(|(key, book)| (key, Arc::new(RwLock::new(book))))
+                .collect(),
+        ),
+    })
+}
+
+/// Initialise a [`OrderBookL2Manager`] maintaining [`SharedOrderBook`]s using the provided
+/// batches of [`OrderBooksL2`] [`Subscription`]s.
+///
+/// Readers of the [`SharedOrderBookMap`] get lock-free access to the latest [`OrderBook`]
+/// snapshots while the manager applies updates.
+pub async fn init_multi_order_book_l2_manager_shared<
+    SubBatchIter,
+    SubIter,
+    Sub,
+    Exchange,
+    Instrument,
+>(
+    subscription_batches: SubBatchIter,
+) -> Result<
+    OrderBookL2Manager<
+        impl Stream<Item = MarketStreamEvent<Instrument::Key, OrderBookEvent>>,
+        SharedOrderBookMap<Instrument::Key>,
+    >,
+    DataError,
+>
+where
+    SubBatchIter: IntoIterator<Item = SubIter>,
+    SubIter: IntoIterator<Item = Sub>,
+    Sub: Into<Subscription<Exchange, Instrument, OrderBooksL2>>,
+    Exchange: StreamSelector<Instrument, OrderBooksL2> + Ord + Send + Sync + 'static,
+    Instrument: InstrumentData + Ord + 'static,
+    Instrument::Key: Eq + Hash + Send + 'static,
+    Subscription<Exchange, Instrument, OrderBooksL2>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    let (stream, books) =
+        init_order_book_l2_stream(subscription_batches.into_iter().map(|batch| {
+            batch
+                .into_iter()
+                .map(|sub| (sub.into(), SharedOrderBook::default()))
+        }))
+        .await?;
+
+    Ok(OrderBookL2Manager {
+        stream,
+        books: SharedOrderBookMap::from_iter(books),
+    })
+}
+
+/// Initialise the merged [`OrderBooksL2`] stream of the provided batches of [`Subscription`]s,
+/// returning it alongside each unique Subscription's initial book.
+async fn init_order_book_l2_stream<SubBatchIter, SubIter, Exchange, Instrument, Book>(
+    subscription_batches: SubBatchIter,
+) -> Result<
+    (
+        impl Stream<Item = MarketStreamEvent<Instrument::Key, OrderBookEvent>>,
+        FnvHashMap<Instrument::Key, Book>,
+    ),
+    DataError,
+>
+where
+    SubBatchIter: IntoIterator<Item = SubIter>,
+    SubIter: IntoIterator<Item = (Subscription<Exchange, Instrument, OrderBooksL2>, Book)>,
+    Exchange: StreamSelector<Instrument, OrderBooksL2> + Ord + Send + Sync + 'static,
+    Instrument: InstrumentData + Ord + 'static,
+    Instrument::Key: Eq + Hash + Send + 'static,
+    Subscription<Exchange, Instrument, OrderBooksL2>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
 {
     // Generate Streams from provided OrderBooksL2 Subscription batches
     let (stream_builder, books) = subscription_batches.into_iter().fold(
         (Streams::<OrderBooksL2>::builder(), FnvHashMap::default()),
         |(builder, mut books), batch| {
             // Insert OrderBook Entry for each unique Subscription (duplicates upserted)
-            let batch = batch.into_iter().map(|sub| {
-                let subscription = sub.into();
-                books.insert(
-                    subscription.instrument.key().clone(),
-                    Arc::new(RwLock::new(OrderBook::default())),
-                );
+            let batch = batch.into_iter().map(|(subscription, book)| {
+                books.insert(subscription.instrument.key().clone(), book);
                 subscription
             });
 
@@ -115,8 +278,5 @@ where
             )
         });
 
-    Ok(OrderBookL2Manager {
-        stream,
-        books: OrderBookMapMulti::new(books),
-    })
+    Ok((stream, books))
 }
@@ -11,32 +11,49 @@ use std::{hash::Hash, sync::Arc};
 /// See [`OrderBookMapSingle`] and [`OrderBookMapMulti`] for implementations.
 pub trait OrderBookMap: Clone {
     type Key;
+    type Book;
 
     /// Return an [`Iterator`] over the [`OrderBookMap`] Keys (eg/ InstrumentKey).
     fn keys(&self) -> impl Iterator<Item = &Self::Key>;
 
     /// Attempt to find the [`OrderBook`] associated with the provided Key.
-    fn find(&self, key: &Self::Key) -> Option<Arc<RwLock<OrderBook>>>;
+    fn find(&self, key: &Self::Key) -> Option<Arc<RwLock<Self::Book>>>;
 }
 
 /// Single Instrument [`OrderBook`] wrapped in a shared-state lock.
-#[derive(Debug, Clone, Constructor)]
-pub struct OrderBookMapSingle<Key> {
+///
+/// The `Book` defaults to the sorted `Vec` backed [`OrderBook`], see
+/// [`OrderBookBackend`](super::backend::OrderBookBackend) for alternatives.
+#[derive(Debug, Constructor)]
+pub struct OrderBookMapSingle<Key, Book = OrderBook> {
     pub instrument: Key,
-    pub book: Arc<RwLock<OrderBook>>,
+    pub book: Arc<RwLock<Book>>,
+}
+
+impl<Key, Book> Clone for OrderBookMapSingle<Key, Book>
+where
+    Key: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            instrument: self.instrument.clone(),
+            book: Arc::clone(&self.book),
+        }
+    }
 }
 
-impl<Key> OrderBookMap for OrderBookMapSingle<Key>
+impl<Key, Book> OrderBookMap for OrderBookMapSingle<Key, Book>
 where
     Key: PartialEq + Clone,
 {
     type Key = Key;
+    type Book = Book;
 
     fn keys(&self) -> impl Iterator<Item = &Self::Key> {
         std::iter::once(&self.instrument)
     }
 
-    fn find(&self, key: &Self::Key) -> Option<Arc<RwLock<OrderBook>>> {
+    fn find(&self, key: &Self::Key) -> Option<Arc<RwLock<Self::Book>>> {
         if &self.instrument == key {
             Some(self.book.clone())
         } else {
@@ -46,35 +63,50 @@ where
 }
 
 /// Multiple Instrument [`OrderBook`] wrapped in a shared-state lock.
-#[derive(Debug, Clone, Constructor)]
-pub struct OrderBookMapMulti<Key>
+///
+/// The `Book` defaults to the sorted `Vec` backed [`OrderBook`], see
+/// [`OrderBookBackend`](super::backend::OrderBookBackend) for alternatives.
+#[derive(Debug, Constructor)]
+pub struct OrderBookMapMulti<Key, Book = OrderBook>
 where
     Key: Eq + Hash,
 {
-    pub books: FnvHashMap<Key, Arc<RwLock<OrderBook>>>,
+    pub books: FnvHashMap<Key, Arc<RwLock<Book>>>,
+}
+
+impl<Key, Book> Clone for OrderBookMapMulti<Key, Book>
+where
+    Key: Clone + Eq + Hash,
+{
+    fn clone(&self) -> Self {
+        Self {
+            books: self.books.clone(),
+        }
+    }
 }
 
-impl<Key> OrderBookMap for OrderBookMapMulti<Key>
+impl<Key, Book> OrderBookMap for OrderBookMapMulti<Key, Book>
 where
     Key: Clone + Eq + Hash,
 {
     type Key = Key;
+    type Book = Book;
 
     fn keys(&self) -> impl Iterator<Item = &Self::Key> {
         self.books.keys()
     }
 
-    fn find(&self, key: &Self::Key) -> Option<Arc<RwLock<OrderBook>>> {
+    fn find(&self, key: &Self::Key) -> Option<Arc<RwLock<Self::Book>>> {
         self.books.get(key).cloned()
     }
 }
 
-impl<Key> OrderBookMapMulti<Key>
+impl<Key, Book> OrderBookMapMulti<Key, Book>
 where
     Key: Eq + Hash,
 {
     /// Insert a new [`OrderBook`] into the [`OrderBookMapMulti`].
-    pub fn insert(&mut self, instrument: Key, book: Arc<RwLock<OrderBook>>) {
+    pub fn insert(&mut self, instrument: Key, book: Arc<RwLock<Book>>) {
         self.books.insert(instrument, book);
     }
 }
@@ -2,6 +2,7 @@ use crate::subscription::book::OrderBookEvent;
 use chrono::{DateTime, Utc};
 use derive_more::Display;
 use rust_decimal::Decimal;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize, Serializer};
 use std::cmp::Ordering;
 use tracing::debug;
@@ -10,11 +11,36 @@ use tracing::debug;
 /// L2 [`OrderBook`]s.
 pub mod manager;
 
+/// Provides an [`OrderBookHistory`](history::OrderBookHistory) research utility for
+/// reconstructing historical L2 [`OrderBook`]s from recorded snapshots & deltas, and answering
+/// point-in-time queries.
+pub mod history;
+
 /// Provides an abstract collection of cheaply cloneable shared-state [`OrderBooks`].
 pub mod map;
 
+/// Provides a [`BTreeOrderBook`](btree::BTreeOrderBook) backed by a `BTreeMap` price ladder,
+/// suited to very deep books with heavy mid-book churn.
+pub mod btree;
+
+/// Provides a [`TickOrderBook`](ticks::TickOrderBook) with prices & amounts represented as
+/// fixed-point integer ticks & lots of the instrument tick size & lot size.
+pub mod ticks;
+
+/// Provides a [`SharedOrderBook`](shared::SharedOrderBook) giving readers lock-free access to
+/// the latest [`OrderBook`] snapshot while the data thread applies updates.
+pub mod shared;
+
+/// Provides the [`OrderBookBackend`](backend::OrderBookBackend) selectable for each locally
+/// managed L2 book, and the associated [`ManagedOrderBook`](backend::ManagedOrderBook).
+pub mod backend;
+
+/// Provides a strategy-facing [`DepthWatcher`](watch::DepthWatcher) that incrementally evaluates
+/// registered depth thresholds as [`OrderBookEvent`]s are applied.
+pub mod watch;
+
 /// Normalised Barter [`OrderBook`] snapshot.
-#[derive(Clone, PartialEq, Eq, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Default, Deserialize, Serialize, JsonSchema)]
 pub struct OrderBook {
     pub sequence: u64,
     pub time_engine: Option<DateTime<Utc>>,
@@ -119,19 +145,45 @@ impl OrderBook {
 }
 
 /// Normalised Barter [`Level`]s for one [`Side`] of the [`OrderBook`].
-#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize, JsonSchema)]
 pub struct OrderBookSide<Side> {
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing, default)]
     pub side: Side,
     levels: Vec<Level>,
 }
 
 /// Unit type to tag an [`OrderBookSide`] as the bid Side (ie/ buyers) of an [`OrderBook`].
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Display)]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Default,
+    Deserialize,
+    Display,
+    JsonSchema,
+)]
 pub struct Bids;
 
 /// Unit type to tag an [`OrderBookSide`] as the ask Side (ie/ sellers) of an [`OrderBook`].
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Display)]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Default,
+    Deserialize,
+    Display,
+    JsonSchema,
+)]
 pub struct Asks;
 
 impl Serialize for Asks {
@@ -179,7 +231,7 @@ impl OrderBookSide<Asks> {
         L: Into<Level>,
     {
         let mut levels = levels.into_iter().map(L::into).collect::<Vec<_>>();
-        levels.sort_unstable_by(|a, b| a.price.cmp(&b.price));
+        levels.sort_unstable_by_key(|level| level.price);
 
         Self { side: Asks, levels }
     }
@@ -266,7 +318,7 @@ impl Default for OrderBookSide<Asks> {
 }
 
 /// Normalised Barter OrderBook [`Level`].
-#[derive(Clone, Copy, PartialEq, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Copy, PartialEq, Debug, Default, Deserialize, Serialize, JsonSchema)]
 pub struct Level {
     pub price: Decimal,
     pub amount: Decimal,
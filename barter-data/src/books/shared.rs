@@ -0,0 +1,286 @@
+use crate::{books::OrderBook, subscription::book::OrderBookEvent};
+use fnv::FnvHashMap;
+use parking_lot::{Mutex, RwLock};
+use std::{
+    collections::VecDeque,
+    hash::Hash,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+/// Shared-state L2 [`OrderBook`] giving readers lock-free access to the latest published
+/// snapshot while a single data thread applies [`OrderBookEvent`]s.
+///
+/// Uses a left-right style pool of buffers:
+/// - The writer applies each [`OrderBookEvent`] to a private back [`OrderBook`] that no reader
+///   references, publishes it with an `O(1)` [`Arc`] swap, and retains the previous front as a
+///   back buffer. Back buffers are brought up to date by replaying the events they missed. If
+///   every back buffer is still referenced by a reader, the front [`OrderBook`] is cloned.
+/// - Each [`SharedOrderBookReader`] caches the latest [`Arc<OrderBook>`] and only refreshes it
+///   when the published version changes, so reading an unchanged book is a single atomic load.
+///   Refreshing briefly read locks the publish slot to clone the [`Arc`], but never waits on a
+///   book update being applied.
+///
+/// Compared to an `Arc<RwLock<OrderBook>>`, readers never block the writer, and the writer never
+/// blocks readers while it applies an update. The trade-off is that every event is applied to
+/// at least two buffers, so writer throughput is lower. Run
+/// `cargo bench -p barter-data --bench shared_books` to compare them under contention.
+#[derive(Debug)]
+pub struct SharedOrderBook {
+    version: AtomicU64,
+    front: RwLock<Arc<OrderBook>>,
+    writer: Mutex<Writer>,
+}
+
+/// Maximum number of retained back buffers.
+const MAX_BACK_BUFFERS: usize = 4;
+
+#[derive(Debug)]
+struct Writer {
+    /// Version of the published front [`OrderBook`].
+    version: u64,
+    /// Retained back buffers & the version each reflects.
+    backs: VecDeque<(u64, Arc<OrderBook>)>,
+    /// Events applied since version `log_start`, replayed to bring back buffers up to date.
+    log: VecDeque<OrderBookEvent>,
+    log_start: u64,
+}
+
+impl SharedOrderBook {
+    /// Construct a new [`SharedOrderBook`] from an initial [`OrderBook`].
+    pub fn new(book: OrderBook) -> Self {
+        Self {
+            version: AtomicU64::new(0),
+            front: RwLock::new(Arc::new(book)),
+            writer: Mutex::new(Writer {
+                version: 0,
+                backs: VecDeque::with_capacity(MAX_BACK_BUFFERS + 1),
+                log: VecDeque::new(),
+                log_start: 0,
+            }),
+        }
+    }
+
+    /// Version of the published [`OrderBook`], incremented with every applied update.
+    pub fn version(&self) -> u64 {
+        self.version.load(Ordering::Acquire)
+    }
+
+    /// Load the latest published [`OrderBook`] snapshot.
+    ///
+    /// Prefer a cached [`SharedOrderBookReader`] for repeated reads.
+    pub fn load(&self) -> Arc<OrderBook> {
+        Arc::clone(&self.front.read())
+    }
+
+    /// Construct a new [`SharedOrderBookReader`] caching the latest published [`OrderBook`].
+    pub fn reader(self: &Arc<Self>) -> SharedOrderBookReader {
+        let (version, cached) = self.load_versioned();
+        SharedOrderBookReader {
+            shared: Arc::clone(self),
+            version,
+            cached,
+        }
+    }
+
+    /// Apply the [`OrderBookEvent`] and publish the updated [`OrderBook`].
+    ///
+    /// Intended to be called by a single data thread. Concurrent writers are serialised.
+    pub fn update(&self, event: OrderBookEvent) {
+        let mut writer = self.writer.lock();
+        let writer = &mut *writer;
+
+        // Take the most recent back buffer no reader references, else clone the published front
+        let (back_version, mut back) = match writer
+            .backs
+            .iter()
+            .rposition(|(_, back)| Arc::strong_count(back) == 1)
+        {
+            Some(index) => writer.backs.remove(index).expect("index is in bounds"),
+            None => (writer.version, Arc::new(self.front.read().as_ref().clone())),
+        };
+
+        // Bring the back buffer up to date by replaying the events it missed
+        let book = Arc::get_mut(&mut back).expect("back buffer is not referenced by any reader");
+        let missed = (back_version - writer.log_start) as usize;
+        for missed in writer.log.range(missed..) {
+            book.update(missed.clone());
+        }
+        book.update(event.clone());
+
+        // Publish the back buffer, and retain the previous front as a back buffer
+        let previous = {
+            let mut front = self.front.write();
+            let previous = std::mem::replace(&mut *front, back);
+            self.version.fetch_add(1, Ordering::Release);
+            previous
+        };
+        writer.backs.push_back((writer.version, previous));
+        if writer.backs.len() > MAX_BACK_BUFFERS {
+            writer.backs.pop_front();
+        }
+        writer.log.push_back(event);
+        writer.version += 1;
+
+        // Discard events every retained back buffer has already applied
+        let oldest = writer
+            .backs
+            .iter()
+            .map(|(version, _)| *version)
+            .min()
+            .unwrap_or(writer.version);
+        while writer.log_start < oldest {
+            writer.log.pop_front();
+            writer.log_start += 1;
+        }
+    }
+
+    fn load_versioned(&self) -> (u64, Arc<OrderBook>) {
+        let front = self.front.read();
+        (self.version.load(Ordering::Acquire), Arc::clone(&front))
+    }
+}
+
+impl Default for SharedOrderBook {
+    fn default() -> Self {
+        Self::new(OrderBook::default())
+    }
+}
+
+/// Cached reader of a [`SharedOrderBook`], see [`SharedOrderBook::reader`].
+#[derive(Debug, Clone)]
+pub struct SharedOrderBookReader {
+    shared: Arc<SharedOrderBook>,
+    version: u64,
+    cached: Arc<OrderBook>,
+}
+
+impl SharedOrderBookReader {
+    /// Return the latest published [`OrderBook`], refreshing the cached snapshot only if a new
+    /// [`OrderBook`] has been published since the last read.
+    pub fn load(&mut self) -> &Arc<OrderBook> {
+        if self.shared.version() != self.version {
+            (self.version, self.cached) = self.shared.load_versioned();
+        }
+        &self.cached
+    }
+}
+
+/// Multiple Instrument [`SharedOrderBook`]s, cheaply cloneable for reading the latest
+/// [`OrderBook`]s elsewhere.
+#[derive(Debug, Clone)]
+pub struct SharedOrderBookMap<Key>
+where
+    Key: Eq + Hash,
+{
+    pub books: FnvHashMap<Key, Arc<SharedOrderBook>>,
+}
+
+impl<Key> SharedOrderBookMap<Key>
+where
+    Key: Eq + Hash,
+{
+    /// Return an [`Iterator`] over the [`SharedOrderBookMap`] Keys (eg/ InstrumentKey).
+    pub fn keys(&self) -> impl Iterator<Item = &Key> {
+        self.books.keys()
+    }
+
+    /// Attempt to find the [`SharedOrderBook`] associated with the provided Key.
+    pub fn find(&self, key: &Key) -> Option<&Arc<SharedOrderBook>> {
+        self.books.get(key)
+    }
+
+    /// Construct a new [`SharedOrderBookReader`] for the [`SharedOrderBook`] associated with the
+    /// provided Key.
+    pub fn reader(&self, key: &Key) -> Option<SharedOrderBookReader> {
+        self.books.get(key).map(SharedOrderBook::reader)
+    }
+}
+
+impl<Key> FromIterator<(Key, SharedOrderBook)> for SharedOrderBookMap<Key>
+where
+    Key: Eq + Hash,
+{
+    fn from_iter<Iter>(iter: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = (Key, SharedOrderBook)>,
+    {
+        Self {
+            books: iter
+                .into_iter()
+                .map(|(key, book)| (key, Arc::new(book)))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::books::Level;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_shared_order_book_update() {
+        struct TestCase {
+            event: OrderBookEvent,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: snapshot
+                event: OrderBookEvent::Snapshot(OrderBook::new(
+                    1,
+                    None,
+                    vec![Level::new(dec!(99), dec!(1))],
+                    vec![Level::new(dec!(101), dec!(1))],
+                )),
+            },
+            TestCase {
+                // TC1: update while a reader holds the previous snapshot
+                event: OrderBookEvent::Update(OrderBook::new(
+                    2,
+                    None,
+                    vec![Level::new(dec!(100), dec!(2))],
+                    vec![Level::new(dec!(101), dec!(0))],
+                )),
+            },
+            TestCase {
+                // TC2: update replayed to the previous front
+                event: OrderBookEvent::Update(OrderBook::new(
+                    3,
+                    None,
+                    vec![Level::new(dec!(99), dec!(0))],
+                    vec![Level::new(dec!(102), dec!(3))],
+                )),
+            },
+            TestCase {
+                // TC3: update applied to a back book no longer held by any reader
+                event: OrderBookEvent::Update(OrderBook::new(
+                    4,
+                    None,
+                    vec![Level::new(dec!(98), dec!(4))],
+                    vec![],
+                )),
+            },
+        ];
+
+        let shared = Arc::new(SharedOrderBook::default());
+        let mut reader = shared.reader();
+        let mut expected = OrderBook::default();
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let previous = Arc::clone(reader.load());
+
+            expected.update(test.event.clone());
+            shared.update(test.event);
+
+            assert_eq!(shared.version(), index as u64 + 1, "TC{index} failed");
+            assert_eq!(reader.load().as_ref(), &expected, "TC{index} failed");
+            assert_eq!(shared.load().as_ref(), &expected, "TC{index} failed");
+            assert_ne!(previous.as_ref(), &expected, "TC{index} failed");
+        }
+    }
+}
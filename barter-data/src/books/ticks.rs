@@ -0,0 +1,323 @@
+use crate::{
+    books::{mid_price, volume_weighted_mid_price, Asks, Bids, Level, OrderBook, OrderBookSide},
+    subscription::book::OrderBookEvent,
+};
+use barter_instrument::instrument::fixed::{Lots, TickScale, Ticks};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use tracing::{debug, warn};
+
+/// Normalised Barter L2 order book with prices & amounts represented as fixed-point integer
+/// [`Ticks`] & [`Lots`] of the instrument [`TickScale`].
+///
+/// [`OrderBookEvent`] levels are converted once on ingestion, so every subsequent search &
+/// upsert compares `i64`s rather than [`Decimal`]s. Levels are converted back into [`Decimal`]s
+/// when taking an [`OrderBook`] snapshot.
+///
+/// See `benches/books.rs` for a comparison with the [`Decimal`] backed [`OrderBook`].
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct TickOrderBook {
+    pub sequence: u64,
+    pub time_engine: Option<DateTime<Utc>>,
+    scale: TickScale,
+    bids: TickOrderBookSide<Bids>,
+    asks: TickOrderBookSide<Asks>,
+}
+
+impl TickOrderBook {
+    /// Construct a new empty [`TickOrderBook`] using the provided [`TickScale`].
+    pub fn new(scale: TickScale) -> Self {
+        Self {
+            sequence: 0,
+            time_engine: None,
+            scale,
+            bids: TickOrderBookSide {
+                side: Bids,
+                levels: vec![],
+            },
+            asks: TickOrderBookSide {
+                side: Asks,
+                levels: vec![],
+            },
+        }
+    }
+
+    /// [`TickScale`] used to convert the levels of this [`TickOrderBook`].
+    pub fn scale(&self) -> TickScale {
+        self.scale
+    }
+
+    /// Generate a sorted [`OrderBook`] snapshot with a maximum depth.
+    pub fn snapshot(&self, depth: usize) -> OrderBook {
+        OrderBook::new(
+            self.sequence,
+            self.time_engine,
+            self.bids
+                .levels
+                .iter()
+                .take(depth)
+                .map(|level| self.level(*level)),
+            self.asks
+                .levels
+                .iter()
+                .take(depth)
+                .map(|level| self.level(*level)),
+        )
+    }
+
+    /// Update the local [`TickOrderBook`] from a new [`OrderBookEvent`].
+    pub fn update(&mut self, event: OrderBookEvent) {
+        match event {
+            OrderBookEvent::Snapshot(snapshot) => {
+                self.sequence = snapshot.sequence;
+                self.time_engine = snapshot.time_engine;
+                self.bids.levels.clear();
+                self.asks.levels.clear();
+                self.upsert_bids(snapshot.bids());
+                self.upsert_asks(snapshot.asks());
+            }
+            OrderBookEvent::Update(update) => {
+                self.sequence = update.sequence;
+                self.time_engine = update.time_engine;
+                self.upsert_bids(update.bids());
+                self.upsert_asks(update.asks());
+            }
+        }
+    }
+
+    /// Update the local [`TickOrderBook`] by upserting the levels in an [`OrderBookSide`].
+    pub fn upsert_bids(&mut self, update: &OrderBookSide<Bids>) {
+        for level in update.levels() {
+            if let Some(upsert) = self.tick_level(level) {
+                self.bids.upsert_single(upsert, |existing| {
+                    existing.price.cmp(&upsert.price).reverse()
+                })
+            }
+        }
+    }
+
+    /// Update the local [`TickOrderBook`] by upserting the levels in an [`OrderBookSide`].
+    pub fn upsert_asks(&mut self, update: &OrderBookSide<Asks>) {
+        for level in update.levels() {
+            if let Some(upsert) = self.tick_level(level) {
+                self.asks
+                    .upsert_single(upsert, |existing| existing.price.cmp(&upsert.price))
+            }
+        }
+    }
+
+    /// Return a reference to this [`TickOrderBook`]s bids.
+    pub fn bids(&self) -> &TickOrderBookSide<Bids> {
+        &self.bids
+    }
+
+    /// Return a reference to this [`TickOrderBook`]s asks.
+    pub fn asks(&self) -> &TickOrderBookSide<Asks> {
+        &self.asks
+    }
+
+    /// Calculate the mid-price by taking the average of the best bid and ask prices.
+    ///
+    /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
+    pub fn mid_price(&self) -> Option<Decimal> {
+        match (self.bids.levels.first(), self.asks.levels.first()) {
+            (Some(best_bid), Some(best_ask)) => Some(mid_price(
+                self.scale.price(best_bid.price),
+                self.scale.price(best_ask.price),
+            )),
+            (Some(best_bid), None) => Some(self.scale.price(best_bid.price)),
+            (None, Some(best_ask)) => Some(self.scale.price(best_ask.price)),
+            (None, None) => None,
+        }
+    }
+
+    /// Calculate the volume weighted mid-price (micro-price), weighing the best bid and ask prices
+    /// with their associated amount.
+    ///
+    /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
+    pub fn volume_weighed_mid_price(&self) -> Option<Decimal> {
+        match (self.bids.levels.first(), self.asks.levels.first()) {
+            (Some(best_bid), Some(best_ask)) => Some(volume_weighted_mid_price(
+                self.level(*best_bid),
+                self.level(*best_ask),
+            )),
+            (Some(best_bid), None) => Some(self.scale.price(best_bid.price)),
+            (None, Some(best_ask)) => Some(self.scale.price(best_ask.price)),
+            (None, None) => None,
+        }
+    }
+
+    /// Convert a [`TickLevel`] into a [`Decimal`] [`Level`].
+    pub fn level(&self, level: TickLevel) -> Level {
+        Level {
+            price: self.scale.price(level.price),
+            amount: self.scale.amount(level.amount),
+        }
+    }
+
+    /// Convert a [`Decimal`] [`Level`] into a [`TickLevel`], logging & returning `None` if it
+    /// cannot be represented by the [`TickScale`].
+    fn tick_level(&self, level: &Level) -> Option<TickLevel> {
+        match (self.scale.ticks(level.price), self.scale.lots(level.amount)) {
+            (Some(price), Some(amount)) => Some(TickLevel { price, amount }),
+            _ => {
+                warn!(
+                    ?level,
+                    scale = ?self.scale,
+                    "TickOrderBook ignoring Level that overflows the TickScale"
+                );
+                None
+            }
+        }
+    }
+}
+
+/// Normalised Barter [`TickLevel`]s for one [`Side`](barter_instrument::Side) of a
+/// [`TickOrderBook`].
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct TickOrderBookSide<Side> {
+    #[serde(skip_serializing, default)]
+    pub side: Side,
+    levels: Vec<TickLevel>,
+}
+
+impl<Side> TickOrderBookSide<Side>
+where
+    Side: std::fmt::Display,
+{
+    /// Return a reference to the [`TickOrderBookSide`] levels, best first.
+    pub fn levels(&self) -> &[TickLevel] {
+        &self.levels
+    }
+
+    /// Upsert a single [`TickLevel`] into this [`TickOrderBookSide`].
+    ///
+    /// See [`OrderBookSide::upsert_single`] for the upsert scenarios.
+    fn upsert_single<FnOrd>(&mut self, new_level: TickLevel, fn_ord: FnOrd)
+    where
+        FnOrd: Fn(&TickLevel) -> Ordering,
+    {
+        match (self.levels.binary_search_by(fn_ord), new_level.amount) {
+            (Ok(index), new_amount) => {
+                if new_amount.is_zero() {
+                    let _removed = self.levels.remove(index);
+                } else {
+                    self.levels[index].amount = new_amount;
+                }
+            }
+            (Err(index), new_amount) => {
+                if new_amount.is_zero() {
+                    debug!(
+                        ?new_level,
+                        side = %self.side,
+                        "received upsert TickLevel with zero amount (to remove) that was not found"
+                    );
+                } else {
+                    self.levels.insert(index, new_level);
+                }
+            }
+        }
+    }
+}
+
+/// Fixed-point [`TickOrderBook`] level.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Deserialize, Serialize)]
+pub struct TickLevel {
+    pub price: Ticks,
+    pub amount: Lots,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_tick_order_book_matches_order_book() {
+        struct TestCase {
+            event: OrderBookEvent,
+            expected_best_bid: Option<TickLevel>,
+        }
+
+        let scale = TickScale::new(dec!(0.5), dec!(0.01)).unwrap();
+
+        let tests = vec![
+            TestCase {
+                // TC0: snapshot replaces the empty book
+                event: OrderBookEvent::Snapshot(OrderBook::new(
+                    1,
+                    None,
+                    vec![
+                        Level::new(dec!(99), dec!(1)),
+                        Level::new(dec!(98.5), dec!(2)),
+                    ],
+                    vec![
+                        Level::new(dec!(101), dec!(1)),
+                        Level::new(dec!(102), dec!(2)),
+                    ],
+                )),
+                expected_best_bid: Some(TickLevel {
+                    price: Ticks(198),
+                    amount: Lots(100),
+                }),
+            },
+            TestCase {
+                // TC1: update inserts a new best bid, replaces an ask & removes an ask
+                event: OrderBookEvent::Update(OrderBook::new(
+                    2,
+                    None,
+                    vec![Level::new(dec!(100), dec!(0.25))],
+                    vec![
+                        Level::new(dec!(101), dec!(0)),
+                        Level::new(dec!(102), dec!(5)),
+                    ],
+                )),
+                expected_best_bid: Some(TickLevel {
+                    price: Ticks(200),
+                    amount: Lots(25),
+                }),
+            },
+            TestCase {
+                // TC2: removing non-existent levels & every bid leaves only the asks
+                event: OrderBookEvent::Update(OrderBook::new(
+                    3,
+                    None,
+                    vec![
+                        Level::new(dec!(100), dec!(0)),
+                        Level::new(dec!(99), dec!(0)),
+                        Level::new(dec!(98.5), dec!(0)),
+                        Level::new(dec!(97), dec!(0)),
+                    ],
+                    vec![],
+                )),
+                expected_best_bid: None,
+            },
+        ];
+
+        let mut decimal_book = OrderBook::default();
+        let mut tick_book = TickOrderBook::new(scale);
+
+        for (index, test) in tests.into_iter().enumerate() {
+            decimal_book.update(test.event.clone());
+            tick_book.update(test.event);
+
+            assert_eq!(
+                tick_book.bids().levels().first().copied(),
+                test.expected_best_bid,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                tick_book.snapshot(usize::MAX),
+                decimal_book.snapshot(usize::MAX),
+                "TC{index} failed"
+            );
+            assert_eq!(
+                tick_book.mid_price(),
+                decimal_book.mid_price(),
+                "TC{index} failed"
+            );
+        }
+    }
+}
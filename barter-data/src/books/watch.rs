@@ -0,0 +1,393 @@
+use crate::{
+    books::{Level, OrderBook},
+    subscription::book::OrderBookEvent,
+};
+use barter_integration::Side;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Number of basis points in one unit (eg/ 1.0 == 10,000 bps).
+const BPS_PER_UNIT: Decimal = Decimal::from_parts(10_000, 0, 0, false, 0);
+
+/// Condition a [`DepthWatch`] evaluates against the cumulative depth within it's window.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DepthCondition {
+    /// Met when the cumulative depth is strictly below the threshold.
+    Below(Decimal),
+    /// Met when the cumulative depth is strictly above the threshold.
+    Above(Decimal),
+}
+
+impl DepthCondition {
+    /// Determine if the [`DepthCondition`] is met by the provided cumulative depth.
+    pub fn is_met(&self, depth: Decimal) -> bool {
+        match self {
+            DepthCondition::Below(threshold) => depth < *threshold,
+            DepthCondition::Above(threshold) => depth > *threshold,
+        }
+    }
+}
+
+/// Interest in the cumulative depth of one [`Side`] of an [`OrderBook`], within `window_bps` of
+/// the mid-price.
+///
+/// eg/ "cumulative bid depth within 10bps drops below 5.0":
+/// `DepthWatch { side: Side::Buy, window_bps: dec!(10), condition: DepthCondition::Below(dec!(5)) }`
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct DepthWatch {
+    /// [`Side::Buy`] watches the bids, [`Side::Sell`] watches the asks.
+    pub side: Side,
+    pub window_bps: Decimal,
+    pub condition: DepthCondition,
+}
+
+impl DepthWatch {
+    /// Calculate the worst price included in the [`DepthWatch`] window, given the mid-price.
+    pub fn window_bound(&self, mid_price: Decimal) -> Decimal {
+        let offset = mid_price * self.window_bps / BPS_PER_UNIT;
+        match self.side {
+            Side::Buy => mid_price - offset,
+            Side::Sell => mid_price + offset,
+        }
+    }
+
+    /// Determine if the [`Level`] price falls within the [`DepthWatch`] window bound.
+    fn is_within(&self, price: Decimal, bound: Decimal) -> bool {
+        match self.side {
+            Side::Buy => price >= bound,
+            Side::Sell => price <= bound,
+        }
+    }
+
+    /// Calculate the cumulative depth within the [`DepthWatch`] window of the provided
+    /// [`OrderBook`]. Only the [`Level`]s within the window are visited.
+    ///
+    /// Returns `None` if the [`OrderBook`] has no mid-price.
+    pub fn depth(&self, book: &OrderBook) -> Option<Decimal> {
+        let bound = self.window_bound(book.mid_price()?);
+
+        let levels = match self.side {
+            Side::Buy => book.bids().levels(),
+            Side::Sell => book.asks().levels(),
+        };
+
+        Some(
+            levels
+                .iter()
+                .take_while(|level| self.is_within(level.price, bound))
+                .map(|level| level.amount)
+                .sum(),
+        )
+    }
+}
+
+/// Notification generated when a registered [`DepthWatch`] [`DepthCondition`] becomes met.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DepthAlert<Id> {
+    pub id: Id,
+    pub watch: DepthWatch,
+    pub depth: Decimal,
+}
+
+/// Registered [`DepthWatch`] and it's most recently evaluated state.
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct Watched<Id> {
+    id: Id,
+    watch: DepthWatch,
+    depth: Option<Decimal>,
+    met: bool,
+}
+
+/// Strategy-facing local [`OrderBook`] that evaluates registered [`DepthWatch`]es incrementally
+/// as each [`OrderBookEvent`] is applied.
+///
+/// A [`DepthWatch`] is only re-evaluated if the applied [`OrderBookEvent`] changes the best bid or
+/// ask (and therefore the mid-price), or upserts a [`Level`] within it's window. A [`DepthAlert`]
+/// is generated each time a [`DepthCondition`] transitions from unmet to met.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct DepthWatcher<Id> {
+    book: OrderBook,
+    watches: Vec<Watched<Id>>,
+}
+
+impl<Id> DepthWatcher<Id>
+where
+    Id: Clone + PartialEq,
+{
+    /// Constructs a new [`DepthWatcher`] using the provided initial [`OrderBook`].
+    pub fn new(book: OrderBook) -> Self {
+        Self {
+            book,
+            watches: Vec::new(),
+        }
+    }
+
+    /// Return a reference to the local [`OrderBook`].
+    pub fn book(&self) -> &OrderBook {
+        &self.book
+    }
+
+    /// Register a [`DepthWatch`] with the provided identifier, replacing any existing
+    /// [`DepthWatch`] with the same identifier.
+    ///
+    /// The [`DepthWatch`] is evaluated immediately, but a [`DepthAlert`] is only generated by a
+    /// subsequent [`Self::update`].
+    pub fn register(&mut self, id: Id, watch: DepthWatch) {
+        self.remove(&id);
+
+        let depth = watch.depth(&self.book);
+        self.watches.push(Watched {
+            id,
+            watch,
+            depth,
+            met: depth.is_some_and(|depth| watch.condition.is_met(depth)),
+        });
+    }
+
+    /// Remove the [`DepthWatch`] associated with the provided identifier.
+    pub fn remove(&mut self, id: &Id) -> Option<DepthWatch> {
+        let index = self.watches.iter().position(|watched| &watched.id == id)?;
+        Some(self.watches.remove(index).watch)
+    }
+
+    /// Return the most recently evaluated cumulative depth for the [`DepthWatch`] associated with
+    /// the provided identifier.
+    pub fn depth(&self, id: &Id) -> Option<Decimal> {
+        self.watches
+            .iter()
+            .find(|watched| &watched.id == id)
+            .and_then(|watched| watched.depth)
+    }
+
+    /// Apply the [`OrderBookEvent`] to the local [`OrderBook`], and return a [`DepthAlert`] for
+    /// every [`DepthWatch`] whose [`DepthCondition`] became met.
+    pub fn update(&mut self, event: OrderBookEvent) -> Vec<DepthAlert<Id>> {
+        let best_before = self.best_prices();
+
+        // Prices of each upserted Level, or None if every DepthWatch must be re-evaluated
+        let upserted = match &event {
+            OrderBookEvent::Snapshot(_) => None,
+            OrderBookEvent::Update(update) => Some((
+                prices(update.bids().levels()),
+                prices(update.asks().levels()),
+            )),
+        };
+
+        self.book.update(event);
+
+        let best_changed = best_before != self.best_prices();
+        let mid_price = self.book.mid_price();
+
+        let mut alerts = Vec::new();
+        for watched in self.watches.iter_mut() {
+            let dirty = match (&upserted, mid_price) {
+                (None, _) | (_, None) => true,
+                _ if best_changed => true,
+                (Some((bids, asks)), Some(mid_price)) => {
+                    let bound = watched.watch.window_bound(mid_price);
+                    let upserted = match watched.watch.side {
+                        Side::Buy => bids,
+                        Side::Sell => asks,
+                    };
+                    upserted
+                        .iter()
+                        .any(|price| watched.watch.is_within(*price, bound))
+                }
+            };
+
+            if !dirty {
+                continue;
+            }
+
+            watched.depth = watched.watch.depth(&self.book);
+
+            let met = watched
+                .depth
+                .is_some_and(|depth| watched.watch.condition.is_met(depth));
+
+            if met && !watched.met {
+                alerts.push(DepthAlert {
+                    id: watched.id.clone(),
+                    watch: watched.watch,
+                    depth: watched.depth.unwrap_or_default(),
+                });
+            }
+
+            watched.met = met;
+        }
+
+        alerts
+    }
+
+    /// Best bid & ask prices of the local [`OrderBook`].
+    fn best_prices(&self) -> (Option<Decimal>, Option<Decimal>) {
+        (
+            self.book.bids().levels().first().map(|level| level.price),
+            self.book.asks().levels().first().map(|level| level.price),
+        )
+    }
+}
+
+fn prices(levels: &[Level]) -> Vec<Decimal> {
+    levels.iter().map(|level| level.price).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn book() -> OrderBook {
+        // Mid-price 100.0 => 10bps window is [99.9, 100.1]
+        OrderBook::new(
+            0,
+            None,
+            vec![
+                Level::new(dec!(99.95), dec!(2)),
+                Level::new(dec!(99.9), dec!(3)),
+                Level::new(dec!(99.0), dec!(100)),
+            ],
+            vec![
+                Level::new(dec!(100.05), dec!(1)),
+                Level::new(dec!(100.2), dec!(100)),
+            ],
+        )
+    }
+
+    fn update(bids: Vec<(Decimal, Decimal)>, asks: Vec<(Decimal, Decimal)>) -> OrderBookEvent {
+        OrderBookEvent::Update(OrderBook::new(1, None, bids, asks))
+    }
+
+    fn bid_watch(threshold: Decimal) -> DepthWatch {
+        DepthWatch {
+            side: Side::Buy,
+            window_bps: dec!(10),
+            condition: DepthCondition::Below(threshold),
+        }
+    }
+
+    #[test]
+    fn test_depth_watch_depth() {
+        struct TestCase {
+            watch: DepthWatch,
+            expected: Option<Decimal>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: bids within 10bps
+                watch: bid_watch(dec!(1)),
+                expected: Some(dec!(5)),
+            },
+            TestCase {
+                // TC1: asks within 10bps
+                watch: DepthWatch {
+                    side: Side::Sell,
+                    window_bps: dec!(10),
+                    condition: DepthCondition::Above(dec!(1)),
+                },
+                expected: Some(dec!(1)),
+            },
+            TestCase {
+                // TC2: bids within 100bps
+                watch: DepthWatch {
+                    side: Side::Buy,
+                    window_bps: dec!(100),
+                    condition: DepthCondition::Below(dec!(1)),
+                },
+                expected: Some(dec!(105)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(test.watch.depth(&book()), test.expected, "TC{index} failed");
+        }
+
+        // No mid-price
+        assert_eq!(bid_watch(dec!(1)).depth(&OrderBook::default()), None);
+    }
+
+    #[test]
+    fn test_depth_watcher_update() {
+        struct TestCase {
+            input: OrderBookEvent,
+            expected_alerts: Vec<DepthAlert<&'static str>>,
+            expected_depth: Option<Decimal>,
+        }
+
+        let mut watcher = DepthWatcher::new(book());
+        watcher.register("bids_below_4", bid_watch(dec!(4)));
+
+        let tests = vec![
+            TestCase {
+                // TC0: update outside window does not change depth
+                input: update(vec![(dec!(98), dec!(10))], vec![]),
+                expected_alerts: vec![],
+                expected_depth: Some(dec!(5)),
+            },
+            TestCase {
+                // TC1: depth within window drops below threshold => alert
+                input: update(vec![(dec!(99.95), dec!(0.5))], vec![]),
+                expected_alerts: vec![DepthAlert {
+                    id: "bids_below_4",
+                    watch: bid_watch(dec!(4)),
+                    depth: dec!(3.5),
+                }],
+                expected_depth: Some(dec!(3.5)),
+            },
+            TestCase {
+                // TC2: depth remains below threshold => no repeated alert
+                input: update(vec![(dec!(99.9), dec!(2))], vec![]),
+                expected_alerts: vec![],
+                expected_depth: Some(dec!(2.5)),
+            },
+            TestCase {
+                // TC3: depth recovers above threshold => no alert
+                input: update(vec![(dec!(99.92), dec!(5))], vec![]),
+                expected_alerts: vec![],
+                expected_depth: Some(dec!(7.5)),
+            },
+            TestCase {
+                // TC4: best ask change moves the window, depth drops again => alert
+                input: update(vec![], vec![(dec!(100.05), dec!(0))]),
+                expected_alerts: vec![DepthAlert {
+                    id: "bids_below_4",
+                    watch: bid_watch(dec!(4)),
+                    // Mid-price 100.075 => 10bps window bound 99.974925
+                    depth: dec!(0),
+                }],
+                expected_depth: Some(dec!(0)),
+            },
+            TestCase {
+                // TC5: snapshot resets the book => depth recovers, no alert
+                input: OrderBookEvent::Snapshot(book()),
+                expected_alerts: vec![],
+                expected_depth: Some(dec!(5)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = watcher.update(test.input);
+            assert_eq!(actual, test.expected_alerts, "TC{index} failed");
+            assert_eq!(
+                watcher.depth(&"bids_below_4"),
+                test.expected_depth,
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_depth_watcher_register_and_remove() {
+        let mut watcher = DepthWatcher::new(book());
+
+        watcher.register(1, bid_watch(dec!(4)));
+        watcher.register(1, bid_watch(dec!(10)));
+        assert_eq!(watcher.depth(&1), Some(dec!(5)));
+
+        assert_eq!(watcher.remove(&1), Some(bid_watch(dec!(10))));
+        assert_eq!(watcher.remove(&1), None);
+        assert_eq!(watcher.depth(&1), None);
+    }
+}
@@ -18,6 +18,12 @@ pub enum DataError {
     #[error("initial snapshot invalid: {0}")]
     InitialSnapshotInvalid(&'static str),
 
+    #[error("failed to publish MarketEvent: {0}")]
+    Publish(String),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
     #[error("SocketError: {0}")]
     Socket(#[from] SocketError),
 
@@ -27,6 +33,12 @@ pub enum DataError {
         sub_kind: SubKind,
     },
 
+    #[error("instrument metadata fetching is unsupported for exchange: {0}")]
+    UnsupportedInstrumentMetadata(ExchangeId),
+
+    #[error("server time response from exchange: {0} contained no server time")]
+    ServerTimeMissing(ExchangeId),
+
     #[error(
         "\
         InvalidSequence: first_update_id {first_update_id} does not follow on from the \
@@ -37,6 +49,14 @@ pub enum DataError {
         prev_last_update_id: u64,
         first_update_id: u64,
     },
+
+    #[error(
+        "\
+        InvalidChecksum: local OrderBook checksum {actual} does not match the exchange \
+        checksum {expected} \
+    "
+    )]
+    InvalidChecksum { expected: u32, actual: u32 },
 }
 
 impl DataError {
@@ -45,6 +65,7 @@ impl DataError {
     pub fn is_terminal(&self) -> bool {
         match self {
             DataError::InvalidSequence { .. } => true,
+            DataError::InvalidChecksum { .. } => true,
             _ => false,
         }
     }
@@ -71,7 +92,15 @@ mod tests {
                 expected: true,
             },
             TestCase {
-                // TC1: is not terminal w/ DataError::Socket
+                // TC1: is terminal w/ DataError::InvalidChecksum
+                input: DataError::InvalidChecksum {
+                    expected: 0,
+                    actual: 1,
+                },
+                expected: true,
+            },
+            TestCase {
+                // TC2: is not terminal w/ DataError::Socket
                 input: DataError::Socket(SocketError::Sink),
                 expected: false,
             },
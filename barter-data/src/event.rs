@@ -9,9 +9,12 @@ use crate::{
     },
 };
 use barter_instrument::{exchange::ExchangeId, instrument::Instrument};
+use barter_integration::{error::SocketError, stream::ExchangeTime};
 use chrono::{DateTime, Utc};
 use derive_more::From;
-use serde::{Deserialize, Serialize};
+use schemars::JsonSchema;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use smol_str::SmolStr;
 
 /// Convenient new type containing a collection of [`MarketEvent<T>`](MarketEvent)s.
 #[derive(Debug)]
@@ -38,7 +41,7 @@ impl<InstrumentKey, T> FromIterator<Result<MarketEvent<InstrumentKey, T>, DataEr
 /// - [`MarketEvent<PublicTrade>`](PublicTrade)
 /// - [`MarketEvent<OrderBookL1>`](OrderBookL1)
 /// - [`MarketEvent<DataKind>`](DataKind)
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize, JsonSchema)]
 pub struct MarketEvent<InstrumentKey = Instrument, T = DataKind> {
     pub time_exchange: DateTime<Utc>,
     pub time_received: DateTime<Utc>,
@@ -60,6 +63,22 @@ impl<InstrumentKey, T> MarketEvent<InstrumentKey, T> {
             kind: op(self.kind),
         }
     }
+
+    /// Convert this [`MarketEvent`] of a downstream defined [`CustomKind`] into a
+    /// [`MarketEvent<DataKind>`](DataKind) containing a [`DataKind::Custom`].
+    pub fn into_custom(self) -> Result<MarketEvent<InstrumentKey, DataKind>, DataError>
+    where
+        T: CustomKind,
+    {
+        let custom = CustomData::new(&self.kind)?;
+        Ok(self.map_kind(|_| DataKind::Custom(custom)))
+    }
+}
+
+impl<InstrumentKey, T> ExchangeTime for MarketEvent<InstrumentKey, T> {
+    fn time_exchange(&self) -> Option<DateTime<Utc>> {
+        Some(self.time_exchange)
+    }
 }
 
 /// Available kinds of normalised Barter [`MarketEvent<T>`](MarketEvent).
@@ -73,13 +92,90 @@ impl<InstrumentKey, T> MarketEvent<InstrumentKey, T> {
 ///   [`Subscription`](crate::subscription::Subscription)s directly, it is only used to
 ///   make ergonomic [`Streams`](crate::streams::Streams) containing many
 ///   [`MarketEvent<T>`](MarketEvent) kinds.
-#[derive(Clone, PartialEq, Debug, Deserialize, Serialize, From)]
+/// - Downstream defined kinds (eg/ venue specific events) are supported via
+///   [`DataKind::Custom`], see [`CustomKind`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize, From, JsonSchema)]
 pub enum DataKind {
     Trade(PublicTrade),
     OrderBookL1(OrderBookL1),
     OrderBook(OrderBookEvent),
     Candle(Candle),
     Liquidation(Liquidation),
+    Custom(CustomData),
+}
+
+/// Downstream defined [`MarketEvent<T>`](MarketEvent) kind (eg/ OKX option summaries), that can
+/// be combined with the normalised Barter kinds as a [`DataKind::Custom`] without patching
+/// barter-data.
+///
+/// A custom [`Connector`](crate::exchange::Connector) stream producing `MarketEvent<T>` where
+/// `T: CustomKind` can be merged into a `MarketStreamResult<InstrumentKey, DataKind>` stream via
+/// the provided `From` implementation, or converted with [`MarketEvent::into_custom`]. Consumers
+/// then dispatch on [`CustomData::decode`].
+pub trait CustomKind: Serialize + DeserializeOwned {
+    /// Unique name of this [`CustomKind`] (eg/ "okx_option_summary"), used to identify it within
+    /// a [`CustomData`].
+    const NAME: &'static str;
+}
+
+/// Type erased [`CustomKind`] carried by a [`DataKind::Custom`].
+///
+/// The [`CustomKind`] is stored JSON encoded so that [`DataKind`] remains (de)serialisable with
+/// every format (eg/ when recorded or journaled).
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize, JsonSchema)]
+pub struct CustomData {
+    /// [`CustomKind::NAME`] of the encoded [`CustomKind`].
+    #[schemars(with = "String")]
+    pub name: SmolStr,
+    /// JSON encoded [`CustomKind`].
+    pub payload: String,
+}
+
+impl CustomData {
+    /// Construct a new [`CustomData`] by encoding the provided [`CustomKind`].
+    pub fn new<T>(kind: &T) -> Result<Self, SocketError>
+    where
+        T: CustomKind,
+    {
+        serde_json::to_string(kind)
+            .map(|payload| Self {
+                name: SmolStr::new(T::NAME),
+                payload,
+            })
+            .map_err(SocketError::Serialise)
+    }
+
+    /// Determine if this [`CustomData`] contains the [`CustomKind`] `T`.
+    pub fn is<T>(&self) -> bool
+    where
+        T: CustomKind,
+    {
+        self.name == T::NAME
+    }
+
+    /// Decode the [`CustomKind`] `T`, returning `None` if this [`CustomData`] contains a
+    /// different [`CustomKind`].
+    pub fn decode<T>(&self) -> Option<Result<T, SocketError>>
+    where
+        T: CustomKind,
+    {
+        self.is::<T>().then(|| {
+            serde_json::from_str(&self.payload).map_err(|error| SocketError::Deserialise {
+                error,
+                payload: self.payload.clone(),
+            })
+        })
+    }
+}
+
+impl<InstrumentKey, T> From<MarketStreamResult<InstrumentKey, T>>
+    for MarketStreamResult<InstrumentKey, DataKind>
+where
+    T: CustomKind,
+{
+    fn from(value: MarketStreamResult<InstrumentKey, T>) -> Self {
+        value.map(|result| result.and_then(MarketEvent::into_custom))
+    }
 }
 
 impl<InstrumentKey> From<MarketStreamResult<InstrumentKey, PublicTrade>>
@@ -161,3 +257,53 @@ impl<InstrumentKey> From<MarketEvent<InstrumentKey, Liquidation>>
         value.map_kind(Liquidation::into)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streams::reconnect::Event;
+
+    #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+    struct OptionSummary {
+        mark_vol: f64,
+    }
+
+    impl CustomKind for OptionSummary {
+        const NAME: &'static str = "option_summary";
+    }
+
+    #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+    struct Other;
+
+    impl CustomKind for Other {
+        const NAME: &'static str = "other";
+    }
+
+    #[test]
+    fn test_market_stream_result_custom_kind_into_data_kind() {
+        let event = MarketEvent {
+            time_exchange: DateTime::<Utc>::MIN_UTC,
+            time_received: DateTime::<Utc>::MIN_UTC,
+            exchange: ExchangeId::Okx,
+            instrument: "instrument",
+            kind: OptionSummary { mark_vol: 0.5 },
+        };
+
+        let actual = MarketStreamResult::<&str, DataKind>::from(Event::Item(Ok(event)));
+
+        let custom = match actual {
+            Event::Item(Ok(MarketEvent {
+                kind: DataKind::Custom(custom),
+                ..
+            })) => custom,
+            other => panic!("expected DataKind::Custom, found: {other:?}"),
+        };
+
+        assert!(custom.is::<OptionSummary>());
+        assert!(custom.decode::<Other>().is_none());
+        assert_eq!(
+            custom.decode::<OptionSummary>().unwrap().unwrap(),
+            OptionSummary { mark_vol: 0.5 }
+        );
+    }
+}
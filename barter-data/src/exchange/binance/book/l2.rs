@@ -104,10 +104,53 @@ where
         .map(|market| ExchangeSub::from((BinanceChannel::ORDER_BOOK_L2, market)).id())
 }
 
+/// Normalise the [`SubscriptionId`] of an OrderBook Level2 subscription of any update speed
+/// (eg/ "@depth|BTCUSDT") into the form produced by [`de_ob_l2_subscription_id`]
+/// (eg/ "@depth@100ms|BTCUSDT").
+///
+/// This is required since the delta update messages do not contain the update speed.
+pub fn normalise_ob_l2_subscription_id(subscription_id: SubscriptionId) -> SubscriptionId {
+    match subscription_id.as_ref().split_once('|') {
+        Some((_, market)) => ExchangeSub::from((BinanceChannel::ORDER_BOOK_L2, market)).id(),
+        None => subscription_id,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_normalise_ob_l2_subscription_id() {
+        struct TestCase {
+            input: SubscriptionId,
+            expected: SubscriptionId,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: standard speed channel is normalised
+                input: SubscriptionId::from("@depth|BTCUSDT"),
+                expected: SubscriptionId::from("@depth@100ms|BTCUSDT"),
+            },
+            TestCase {
+                // TC1: 500ms channel is normalised
+                input: SubscriptionId::from("@depth@500ms|BTCUSDT"),
+                expected: SubscriptionId::from("@depth@100ms|BTCUSDT"),
+            },
+            TestCase {
+                // TC2: 100ms channel is unchanged
+                input: SubscriptionId::from("@depth@100ms|BTCUSDT"),
+                expected: SubscriptionId::from("@depth@100ms|BTCUSDT"),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = normalise_ob_l2_subscription_id(test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
     mod de {
         use super::*;
         use rust_decimal_macros::dec;
@@ -1,5 +1,6 @@
 use super::{futures::BinanceFuturesUsd, Binance};
 use crate::{
+    exchange::ExchangeServer,
     subscription::{
         book::{OrderBooksL1, OrderBooksL2},
         liquidation::Liquidations,
@@ -8,6 +9,7 @@ use crate::{
     },
     Identifier,
 };
+use barter_instrument::exchange::ExchangeId;
 use serde::Serialize;
 
 /// Type that defines how to translate a Barter [`Subscription`] into a [`Binance`]
@@ -30,6 +32,12 @@ impl BinanceChannel {
     /// See discord: <https://discord.com/channels/910237311332151317/923160222711812126/975712874582388757>
     pub const TRADES: Self = Self("@trade");
 
+    /// [`Binance`] real-time aggregate trades channel name.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#aggregate-trade-streams>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#aggregate-trade-streams>
+    pub const AGG_TRADES: Self = Self("@aggTrade");
+
     /// [`Binance`] real-time OrderBook Level1 (top of books) channel name.
     ///
     /// See docs:<https://binance-docs.github.io/apidocs/spot/en/#individual-symbol-book-ticker-streams>
@@ -42,17 +50,41 @@ impl BinanceChannel {
     /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#diff-book-depth-streams>
     pub const ORDER_BOOK_L2: Self = Self("@depth@100ms");
 
+    /// [`Binance`] OrderBook Level2 channel name (standard speed delta updates, being 1000ms for
+    /// [`BinanceSpot`](super::spot::BinanceSpot) and 250ms for [`BinanceFuturesUsd`]).
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#diff-depth-stream>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#diff-book-depth-streams>
+    pub const ORDER_BOOK_L2_STANDARD: Self = Self("@depth");
+
+    /// [`BinanceFuturesUsd`] OrderBook Level2 channel name (500ms delta updates).
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#diff-book-depth-streams>
+    pub const ORDER_BOOK_L2_500MS: Self = Self("@depth@500ms");
+
     /// [`BinanceFuturesUsd`] liquidation orders channel name.
     ///
     /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#liquidation-order-streams>
     pub const LIQUIDATIONS: Self = Self("@forceOrder");
+
+    /// Determine if this [`BinanceChannel`] is an OrderBook Level2 channel, of any update speed.
+    pub fn is_order_book_l2(&self) -> bool {
+        matches!(
+            *self,
+            Self::ORDER_BOOK_L2 | Self::ORDER_BOOK_L2_STANDARD | Self::ORDER_BOOK_L2_500MS
+        )
+    }
 }
 
 impl<Server, Instrument> Identifier<BinanceChannel>
     for Subscription<Binance<Server>, Instrument, PublicTrades>
 {
     fn id(&self) -> BinanceChannel {
-        BinanceChannel::TRADES
+        if self.params.aggregated_trades.unwrap_or_default() {
+            BinanceChannel::AGG_TRADES
+        } else {
+            BinanceChannel::TRADES
+        }
     }
 }
 
@@ -66,9 +98,18 @@ impl<Server, Instrument> Identifier<BinanceChannel>
 
 impl<Server, Instrument> Identifier<BinanceChannel>
     for Subscription<Binance<Server>, Instrument, OrderBooksL2>
+where
+    Server: ExchangeServer,
 {
     fn id(&self) -> BinanceChannel {
-        BinanceChannel::ORDER_BOOK_L2
+        // Unsupported update speeds are rejected by Binance::validate_params
+        match (Server::ID, self.params.update_speed_ms) {
+            (ExchangeId::BinanceSpot, Some(1000)) | (ExchangeId::BinanceFuturesUsd, Some(250)) => {
+                BinanceChannel::ORDER_BOOK_L2_STANDARD
+            }
+            (ExchangeId::BinanceFuturesUsd, Some(500)) => BinanceChannel::ORDER_BOOK_L2_500MS,
+            _ => BinanceChannel::ORDER_BOOK_L2,
+        }
     }
 }
 
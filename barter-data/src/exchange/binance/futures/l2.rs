@@ -5,10 +5,13 @@ use crate::{
     event::{MarketEvent, MarketIter},
     exchange::{
         binance::{
-            book::l2::{BinanceOrderBookL2Meta, BinanceOrderBookL2Snapshot},
+            book::l2::{
+                normalise_ob_l2_subscription_id, BinanceOrderBookL2Meta, BinanceOrderBookL2Snapshot,
+            },
             futures::BinanceFuturesUsd,
             market::BinanceMarket,
         },
+        config::ConnectorConfig,
         Connector,
     },
     instrument::InstrumentData,
@@ -22,7 +25,8 @@ use crate::{
 use async_trait::async_trait;
 use barter_instrument::exchange::ExchangeId;
 use barter_integration::{
-    error::SocketError, protocol::websocket::WsMessage, subscription::SubscriptionId, Transformer,
+    environment::Environment, error::SocketError, protocol::websocket::WsMessage,
+    subscription::SubscriptionId, Transformer,
 };
 use chrono::{DateTime, Utc};
 use futures_util::future::try_join_all;
@@ -36,6 +40,12 @@ use tokio::sync::mpsc::UnboundedSender;
 pub const HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_FUTURES_USD: &str =
     "https://fapi.binance.com/fapi/v1/depth";
 
+/// [`BinanceFuturesUsd`] testnet HTTP OrderBook L2 snapshot url.
+///
+/// See docs: <https://developers.binance.com/docs/derivatives/usds-margined-futures/general-info>
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_FUTURES_USD_TESTNET: &str =
+    "https://testnet.binancefuture.com/fapi/v1/depth";
+
 #[derive(Debug)]
 pub struct BinanceFuturesUsdOrderBooksL2SnapshotFetcher;
 
@@ -43,6 +53,7 @@ impl SnapshotFetcher<BinanceFuturesUsd, OrderBooksL2>
     for BinanceFuturesUsdOrderBooksL2SnapshotFetcher
 {
     fn fetch_snapshots<Instrument>(
+        config: &ConnectorConfig,
         subscriptions: &[Subscription<BinanceFuturesUsd, Instrument, OrderBooksL2>],
     ) -> impl Future<Output = Result<Vec<MarketEvent<Instrument::Key, OrderBookEvent>>, SocketError>>
            + Send
@@ -50,18 +61,28 @@ impl SnapshotFetcher<BinanceFuturesUsd, OrderBooksL2>
         Instrument: InstrumentData,
         Subscription<BinanceFuturesUsd, Instrument, OrderBooksL2>: Identifier<BinanceMarket>,
     {
+        let snapshot_base_url = match config.environment {
+            Environment::Mainnet => HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_FUTURES_USD,
+            Environment::Testnet => HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_FUTURES_USD_TESTNET,
+        };
+
         let l2_snapshot_futures = subscriptions.iter().map(|sub| {
             // Construct initial OrderBook snapshot GET url
             let market = sub.id();
             let snapshot_url = format!(
-                "{}?symbol={}&limit=100",
-                HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_FUTURES_USD,
+                "{}?symbol={}&limit={}",
+                snapshot_base_url,
                 market.as_ref(),
+                sub.params.depth.unwrap_or(100)
             );
 
+            let http_client = config.http_client();
+
             async move {
                 // Fetch initial OrderBook snapshot via HTTP
-                let snapshot = reqwest::get(snapshot_url)
+                let snapshot = http_client?
+                    .get(snapshot_url)
+                    .send()
                     .await
                     .map_err(SocketError::Http)?
                     .json::<BinanceOrderBookL2Snapshot>()
@@ -118,7 +139,7 @@ where
                 };
 
                 Ok((
-                    sub_id,
+                    normalise_ob_l2_subscription_id(sub_id),
                     BinanceOrderBookL2Meta::new(instrument_key, sequencer),
                 ))
             })
@@ -543,10 +564,7 @@ mod tests {
         for (index, test) in tests.into_iter().enumerate() {
             let actual = test.updater.validate_first_update(&test.input);
             match (actual, test.expected) {
-                (Ok(actual), Ok(expected)) => {
-                    assert_eq!(actual, expected, "TC{} failed", index)
-                }
-                (Err(_), Err(_)) => {
+                (Ok(()), Ok(())) | (Err(_), Err(_)) => {
                     // Test passed
                 }
                 (actual, expected) => {
@@ -610,10 +628,7 @@ mod tests {
         for (index, test) in tests.into_iter().enumerate() {
             let actual = test.updater.validate_next_update(&test.input);
             match (actual, test.expected) {
-                (Ok(actual), Ok(expected)) => {
-                    assert_eq!(actual, expected, "TC{} failed", index)
-                }
-                (Err(_), Err(_)) => {
+                (Ok(()), Ok(())) | (Err(_), Err(_)) => {
                     // Test passed
                 }
                 (actual, expected) => {
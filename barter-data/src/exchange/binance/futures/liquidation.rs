@@ -1,6 +1,7 @@
 use super::super::BinanceChannel;
 use crate::{
     event::{MarketEvent, MarketIter},
+    exchange::ExchangeSub,
     subscription::liquidation::Liquidation,
     Identifier,
 };
@@ -110,9 +111,8 @@ pub fn de_liquidation_subscription_id<'de, D>(deserializer: D) -> Result<Subscri
 where
     D: serde::de::Deserializer<'de>,
 {
-    Deserialize::deserialize(deserializer).map(|market: String| {
-        SubscriptionId::from(format!("{}|{}", BinanceChannel::LIQUIDATIONS.0, market))
-    })
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|market| ExchangeSub::from((BinanceChannel::LIQUIDATIONS, market)).id())
 }
 
 #[cfg(test)]
@@ -25,6 +25,12 @@ pub mod liquidation;
 /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#websocket-market-streams>
 pub const WEBSOCKET_BASE_URL_BINANCE_FUTURES_USD: &str = "wss://fstream.binance.com/ws";
 
+/// [`BinanceFuturesUsd`] testnet WebSocket server base url.
+///
+/// See docs: <https://developers.binance.com/docs/derivatives/usds-margined-futures/general-info>
+pub const WEBSOCKET_BASE_URL_BINANCE_FUTURES_USD_TESTNET: &str =
+    "wss://stream.binancefuture.com/ws";
+
 /// [`Binance`] perpetual usd exchange.
 pub type BinanceFuturesUsd = Binance<BinanceServerFuturesUsd>;
 
@@ -38,6 +44,10 @@ impl ExchangeServer for BinanceServerFuturesUsd {
     fn websocket_url() -> &'static str {
         WEBSOCKET_BASE_URL_BINANCE_FUTURES_USD
     }
+
+    fn websocket_url_testnet() -> Option<&'static str> {
+        Some(WEBSOCKET_BASE_URL_BINANCE_FUTURES_USD_TESTNET)
+    }
 }
 
 impl<Instrument> StreamSelector<Instrument, OrderBooksL2> for BinanceFuturesUsd
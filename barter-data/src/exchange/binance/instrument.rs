@@ -0,0 +1,291 @@
+use crate::instrument::metadata::InstrumentMetadata;
+use barter_instrument::{
+    asset::symbol::Symbol,
+    exchange::ExchangeId,
+    instrument::{
+        kind::{future::FutureContract, InstrumentKind},
+        spec::{
+            InstrumentSpec, InstrumentSpecNotional, InstrumentSpecPrice, InstrumentSpecQuantity,
+            OrderQuantityUnits,
+        },
+        Instrument,
+    },
+};
+use barter_integration::de::{datetime_utc_from_epoch_duration, de_str};
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
+
+/// [`BinanceSpot`](super::spot::BinanceSpot) HTTP exchange information url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#exchange-information>
+pub const HTTP_EXCHANGE_INFO_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3/exchangeInfo";
+
+/// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) HTTP exchange information url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#exchange-information>
+pub const HTTP_EXCHANGE_INFO_URL_BINANCE_FUTURES_USD: &str =
+    "https://fapi.binance.com/fapi/v1/exchangeInfo";
+
+/// [`Binance`](super::Binance) exchange information response, common to both
+/// [`BinanceSpot`](super::spot::BinanceSpot) and
+/// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#exchange-information>
+/// ```json
+/// {
+///     "symbols": [
+///         {
+///             "symbol": "BTCUSDT",
+///             "status": "TRADING",
+///             "baseAsset": "BTC",
+///             "quoteAsset": "USDT",
+///             "filters": [
+///                 {"filterType": "PRICE_FILTER", "minPrice": "0.01", "maxPrice": "1000000.00", "tickSize": "0.01"},
+///                 {"filterType": "LOT_SIZE", "minQty": "0.00001", "maxQty": "9000.00", "stepSize": "0.00001"},
+///                 {"filterType": "NOTIONAL", "minNotional": "5.00", "maxNotional": "9000000.00"}
+///             ]
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceExchangeInfo {
+    pub symbols: Vec<BinanceSymbolInfo>,
+}
+
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceSymbolInfo {
+    pub symbol: SmolStr,
+    pub status: SmolStr,
+    pub base_asset: SmolStr,
+    pub quote_asset: SmolStr,
+    /// Only present for [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd), eg/ "PERPETUAL".
+    #[serde(default)]
+    pub contract_type: Option<SmolStr>,
+    /// Only present for [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd).
+    #[serde(default)]
+    pub delivery_date: Option<u64>,
+    pub filters: Vec<BinanceSymbolFilter>,
+}
+
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "filterType", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BinanceSymbolFilter {
+    #[serde(rename_all = "camelCase")]
+    PriceFilter {
+        #[serde(deserialize_with = "de_str")]
+        min_price: f64,
+        #[serde(deserialize_with = "de_str")]
+        tick_size: f64,
+    },
+    #[serde(rename_all = "camelCase")]
+    LotSize {
+        #[serde(deserialize_with = "de_str")]
+        min_qty: f64,
+        #[serde(deserialize_with = "de_str")]
+        max_qty: f64,
+        #[serde(deserialize_with = "de_str")]
+        step_size: f64,
+    },
+    #[serde(rename_all = "camelCase")]
+    Notional {
+        #[serde(deserialize_with = "de_str")]
+        min_notional: f64,
+        #[serde(deserialize_with = "de_str")]
+        max_notional: f64,
+    },
+    /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) uses the "notional" field name.
+    MinNotional {
+        #[serde(alias = "minNotional", alias = "notional", deserialize_with = "de_str")]
+        min_notional: f64,
+    },
+    #[serde(other)]
+    Other,
+}
+
+impl BinanceExchangeInfo {
+    /// Map every actively trading [`BinanceSymbolInfo`] into [`InstrumentMetadata`]. Symbols
+    /// missing a price or lot size filter are skipped.
+    pub fn into_metadata(self, exchange: ExchangeId) -> Vec<InstrumentMetadata> {
+        self.symbols
+            .into_iter()
+            .filter(|symbol| symbol.status == "TRADING")
+            .filter_map(|symbol| symbol.into_metadata(exchange))
+            .collect()
+    }
+}
+
+impl BinanceSymbolInfo {
+    fn into_metadata(self, exchange: ExchangeId) -> Option<InstrumentMetadata> {
+        let kind = match (self.contract_type.as_deref(), self.delivery_date) {
+            (None, _) | (Some(""), _) => InstrumentKind::Spot,
+            (Some("PERPETUAL"), _) => InstrumentKind::Perpetual,
+            (Some(_), Some(delivery_ms)) => InstrumentKind::Future(FutureContract {
+                expiry: datetime_utc_from_epoch_duration(std::time::Duration::from_millis(
+                    delivery_ms,
+                )),
+            }),
+            (Some(_), None) => return None,
+        };
+
+        let mut price = None;
+        let mut quantity = None;
+        let mut notional = InstrumentSpecNotional {
+            min: 0.0,
+            max: None,
+        };
+
+        for filter in self.filters {
+            match filter {
+                BinanceSymbolFilter::PriceFilter {
+                    min_price,
+                    tick_size,
+                } => {
+                    price = Some(InstrumentSpecPrice {
+                        min: min_price,
+                        tick_size,
+                        tick_size_schedule: None,
+                    })
+                }
+                BinanceSymbolFilter::LotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => {
+                    quantity = Some(InstrumentSpecQuantity {
+                        unit: OrderQuantityUnits::Asset(Symbol::new(self.base_asset.as_str())),
+                        min: min_qty,
+                        increment: step_size,
+                        max: Some(max_qty),
+                        contract_multiplier: None,
+                    })
+                }
+                BinanceSymbolFilter::Notional {
+                    min_notional,
+                    max_notional,
+                } => {
+                    notional = InstrumentSpecNotional {
+                        min: min_notional,
+                        max: Some(max_notional),
+                    }
+                }
+                BinanceSymbolFilter::MinNotional { min_notional } => notional.min = min_notional,
+                BinanceSymbolFilter::Other => {}
+            }
+        }
+
+        Some(InstrumentMetadata {
+            exchange,
+            instrument: Instrument::new(self.base_asset.as_str(), self.quote_asset.as_str(), kind),
+            name_exchange: self.symbol,
+            spec: InstrumentSpec {
+                price: price?,
+                quantity: quantity?,
+                notional,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binance_exchange_info_into_metadata() {
+        struct TestCase {
+            exchange: ExchangeId,
+            input: &'static str,
+            expected: Vec<InstrumentMetadata>,
+        }
+
+        let spec = |notional| InstrumentSpec {
+            price: InstrumentSpecPrice {
+                min: 0.01,
+                tick_size: 0.01,
+                tick_size_schedule: None,
+            },
+            quantity: InstrumentSpecQuantity {
+                unit: OrderQuantityUnits::Asset(Symbol::new("btc")),
+                min: 0.00001,
+                increment: 0.00001,
+                max: Some(9000.0),
+                contract_multiplier: None,
+            },
+            notional,
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: BinanceSpot TRADING symbol is mapped, BREAK symbol is skipped
+                exchange: ExchangeId::BinanceSpot,
+                input: r#"
+                {
+                    "timezone": "UTC",
+                    "symbols": [
+                        {
+                            "symbol": "BTCUSDT", "status": "TRADING", "baseAsset": "BTC", "quoteAsset": "USDT",
+                            "filters": [
+                                {"filterType": "PRICE_FILTER", "minPrice": "0.01", "maxPrice": "1000000.00", "tickSize": "0.01"},
+                                {"filterType": "LOT_SIZE", "minQty": "0.00001", "maxQty": "9000.00", "stepSize": "0.00001"},
+                                {"filterType": "ICEBERG_PARTS", "limit": 10},
+                                {"filterType": "NOTIONAL", "minNotional": "5.00", "applyMinToMarket": true, "maxNotional": "9000000.00"}
+                            ]
+                        },
+                        {
+                            "symbol": "ETHBTC", "status": "BREAK", "baseAsset": "ETH", "quoteAsset": "BTC",
+                            "filters": []
+                        }
+                    ]
+                }
+                "#,
+                expected: vec![InstrumentMetadata {
+                    exchange: ExchangeId::BinanceSpot,
+                    name_exchange: SmolStr::new("BTCUSDT"),
+                    instrument: Instrument::new("btc", "usdt", InstrumentKind::Spot),
+                    spec: spec(InstrumentSpecNotional {
+                        min: 5.0,
+                        max: Some(9000000.0),
+                    }),
+                }],
+            },
+            TestCase {
+                // TC1: BinanceFuturesUsd PERPETUAL symbol w/ MIN_NOTIONAL "notional" field
+                exchange: ExchangeId::BinanceFuturesUsd,
+                input: r#"
+                {
+                    "symbols": [
+                        {
+                            "symbol": "BTCUSDT", "status": "TRADING", "baseAsset": "BTC", "quoteAsset": "USDT",
+                            "contractType": "PERPETUAL", "deliveryDate": 4133404800000,
+                            "filters": [
+                                {"filterType": "PRICE_FILTER", "minPrice": "0.01", "maxPrice": "4529764", "tickSize": "0.01"},
+                                {"filterType": "LOT_SIZE", "minQty": "0.00001", "maxQty": "9000", "stepSize": "0.00001"},
+                                {"filterType": "MIN_NOTIONAL", "notional": "100"}
+                            ]
+                        }
+                    ]
+                }
+                "#,
+                expected: vec![InstrumentMetadata {
+                    exchange: ExchangeId::BinanceFuturesUsd,
+                    name_exchange: SmolStr::new("BTCUSDT"),
+                    instrument: Instrument::new("btc", "usdt", InstrumentKind::Perpetual),
+                    spec: spec(InstrumentSpecNotional {
+                        min: 100.0,
+                        max: None,
+                    }),
+                }],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<BinanceExchangeInfo>(test.input)
+                .unwrap()
+                .into_metadata(test.exchange);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
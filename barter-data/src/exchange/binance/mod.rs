@@ -6,12 +6,14 @@ use crate::{
     exchange::{Connector, ExchangeServer, ExchangeSub, StreamSelector},
     instrument::InstrumentData,
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{book::OrderBooksL1, trade::PublicTrades, Map},
+    subscription::{book::OrderBooksL1, params::SubscriptionParams, trade::PublicTrades, Map},
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream, NoInitialSnapshots,
 };
 use barter_instrument::exchange::ExchangeId;
-use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_integration::{
+    environment::Environment, error::SocketError, protocol::websocket::WsMessage,
+};
 use std::{fmt::Debug, marker::PhantomData};
 use url::Url;
 
@@ -27,6 +29,11 @@ pub mod channel;
 /// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod futures;
 
+/// Exchange information types used to fetch the
+/// [`InstrumentMetadata`](crate::instrument::metadata::InstrumentMetadata) of
+/// [`BinanceSpot`](spot::BinanceSpot) & [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
+pub mod instrument;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
@@ -49,6 +56,10 @@ pub mod trade;
 /// ### Notes
 /// A `Server` [`ExchangeServer`] implementations exists for
 /// [`BinanceSpot`](spot::BinanceSpot) and [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
+///
+/// [`PublicTrades`] are served by the raw "trade" stream by default, or by the "aggTrade" stream
+/// if the [`Subscription`](crate::subscription::Subscription) uses
+/// [`SubscriptionParams::aggregated_trades`].
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub struct Binance<Server> {
     server: PhantomData<Server>,
@@ -69,6 +80,10 @@ where
         Url::parse(Server::websocket_url()).map_err(SocketError::UrlParse)
     }
 
+    fn url_for(environment: Environment) -> Result<Url, SocketError> {
+        Url::parse(Server::websocket_url_for(environment)?).map_err(SocketError::UrlParse)
+    }
+
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
         let stream_names = exchange_subs
             .into_iter()
@@ -97,6 +112,70 @@ where
     fn expected_responses<InstrumentKey>(_: &Map<InstrumentKey>) -> usize {
         1
     }
+
+    fn validate_params(
+        channel: &Self::Channel,
+        params: &SubscriptionParams,
+    ) -> Result<(), SocketError> {
+        // Binance only supports custom parameters for PublicTrades & OrderBook Level2
+        // subscriptions, where:
+        // - aggregated_trades selects the "aggTrade" rather than the raw "trade" stream channel
+        // - update_speed_ms selects the diff depth stream channel
+        // - depth is used as the limit of the initial OrderBook snapshot request
+        if !channel.is_order_book_l2() {
+            let is_trades = matches!(
+                *channel,
+                BinanceChannel::TRADES | BinanceChannel::AGG_TRADES
+            );
+            let unsupported = SubscriptionParams {
+                aggregated_trades: None,
+                ..*params
+            };
+
+            return if params.is_default() || (is_trades && unsupported.is_default()) {
+                Ok(())
+            } else {
+                Err(SocketError::Unsupported {
+                    entity: Self::ID.to_string(),
+                    item: format!("{} channel with {params:?}", channel.as_ref()),
+                })
+            };
+        }
+
+        if params.aggregated_trades.is_some() {
+            return Err(SocketError::Unsupported {
+                entity: Self::ID.to_string(),
+                item: format!("{} channel with {params:?}", channel.as_ref()),
+            });
+        }
+
+        let (speeds, depth_valid): (&[u32], fn(u32) -> bool) = match Self::ID {
+            ExchangeId::BinanceSpot => (&[100, 1000], |depth| (1..=5000).contains(&depth)),
+            ExchangeId::BinanceFuturesUsd => (&[100, 250, 500], |depth| {
+                matches!(depth, 5 | 10 | 20 | 50 | 100 | 500 | 1000)
+            }),
+            _ => (&[100], |depth| depth == 100),
+        };
+
+        if let Some(speed) = params
+            .update_speed_ms
+            .filter(|speed| !speeds.contains(speed))
+        {
+            return Err(SocketError::Unsupported {
+                entity: Self::ID.to_string(),
+                item: format!("OrderBook Level2 update_speed_ms: {speed}"),
+            });
+        }
+
+        if let Some(depth) = params.depth.filter(|depth| !depth_valid(*depth)) {
+            return Err(SocketError::Unsupported {
+                entity: Self::ID.to_string(),
+                item: format!("OrderBook Level2 depth: {depth}"),
+            });
+        }
+
+        Ok(())
+    }
 }
 
 impl<Instrument, Server> StreamSelector<Instrument, PublicTrades> for Binance<Server>
@@ -5,10 +5,13 @@ use crate::{
     event::{MarketEvent, MarketIter},
     exchange::{
         binance::{
-            book::l2::{BinanceOrderBookL2Meta, BinanceOrderBookL2Snapshot},
+            book::l2::{
+                normalise_ob_l2_subscription_id, BinanceOrderBookL2Meta, BinanceOrderBookL2Snapshot,
+            },
             market::BinanceMarket,
             spot::BinanceSpot,
         },
+        config::ConnectorConfig,
         Connector,
     },
     instrument::InstrumentData,
@@ -22,7 +25,8 @@ use crate::{
 use async_trait::async_trait;
 use barter_instrument::exchange::ExchangeId;
 use barter_integration::{
-    error::SocketError, protocol::websocket::WsMessage, subscription::SubscriptionId, Transformer,
+    environment::Environment, error::SocketError, protocol::websocket::WsMessage,
+    subscription::SubscriptionId, Transformer,
 };
 use chrono::{DateTime, Utc};
 use futures_util::future::try_join_all;
@@ -35,11 +39,18 @@ use tokio::sync::mpsc::UnboundedSender;
 /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#order-book>
 pub const HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3/depth";
 
+/// [`BinanceSpot`] testnet HTTP OrderBook L2 snapshot url.
+///
+/// See docs: <https://developers.binance.com/docs/binance-spot-api-docs/testnet/rest-api>
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT_TESTNET: &str =
+    "https://testnet.binance.vision/api/v3/depth";
+
 #[derive(Debug)]
 pub struct BinanceSpotOrderBooksL2SnapshotFetcher;
 
 impl SnapshotFetcher<BinanceSpot, OrderBooksL2> for BinanceSpotOrderBooksL2SnapshotFetcher {
     fn fetch_snapshots<Instrument>(
+        config: &ConnectorConfig,
         subscriptions: &[Subscription<BinanceSpot, Instrument, OrderBooksL2>],
     ) -> impl Future<Output = Result<Vec<MarketEvent<Instrument::Key, OrderBookEvent>>, SocketError>>
            + Send
@@ -47,17 +58,28 @@ impl SnapshotFetcher<BinanceSpot, OrderBooksL2> for BinanceSpotOrderBooksL2Snaps
         Instrument: InstrumentData,
         Subscription<BinanceSpot, Instrument, OrderBooksL2>: Identifier<BinanceMarket>,
     {
+        let snapshot_base_url = match config.environment {
+            Environment::Mainnet => HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT,
+            Environment::Testnet => HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT_TESTNET,
+        };
+
         let l2_snapshot_futures = subscriptions.iter().map(|subscription| {
             // Construct initial OrderBook snapshot GET url
             let market = subscription.id();
             let snapshot_url = format!(
-                "{}?symbol={}&limit=100",
-                HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT, market.0,
+                "{}?symbol={}&limit={}",
+                snapshot_base_url,
+                market.0,
+                subscription.params.depth.unwrap_or(100)
             );
 
+            let http_client = config.http_client();
+
             async move {
                 // Fetch initial OrderBook snapshot via HTTP
-                let snapshot = reqwest::get(snapshot_url)
+                let snapshot = http_client?
+                    .get(snapshot_url)
+                    .send()
                     .await
                     .map_err(SocketError::Http)?
                     .json::<BinanceOrderBookL2Snapshot>()
@@ -112,7 +134,7 @@ where
                     BinanceSpotOrderBookL2Sequencer::new(snapshot.sequence),
                 );
 
-                Ok((sub_id, book_meta))
+                Ok((normalise_ob_l2_subscription_id(sub_id), book_meta))
             })
             .collect::<Result<Map<_>, _>>()?;
 
@@ -149,11 +171,6 @@ where
             Err(error) => return vec![Err(error)],
         };
 
-        println!(
-            "Update: {}-{}",
-            valid_update.first_update_id, valid_update.last_update_id
-        );
-
         MarketIter::<InstrumentKey, OrderBookEvent>::from((
             BinanceSpot::ID,
             instrument.key.clone(),
@@ -521,10 +538,7 @@ mod tests {
         for (index, test) in tests.into_iter().enumerate() {
             let actual = test.sequencer.validate_first_update(&test.input);
             match (actual, test.expected) {
-                (Ok(actual), Ok(expected)) => {
-                    assert_eq!(actual, expected, "TC{} failed", index)
-                }
-                (Err(_), Err(_)) => {
+                (Ok(()), Ok(())) | (Err(_), Err(_)) => {
                     // Test passed
                 }
                 (actual, expected) => {
@@ -586,10 +600,7 @@ mod tests {
         for (index, test) in tests.into_iter().enumerate() {
             let actual = test.sequencer.validate_next_update(&test.input);
             match (actual, test.expected) {
-                (Ok(actual), Ok(expected)) => {
-                    assert_eq!(actual, expected, "TC{} failed", index)
-                }
-                (Err(_), Err(_)) => {
+                (Ok(()), Ok(())) | (Err(_), Err(_)) => {
                     // Test passed
                 }
                 (actual, expected) => {
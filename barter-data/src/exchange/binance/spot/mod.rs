@@ -20,6 +20,11 @@ pub mod l2;
 /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-market-streams>
 pub const WEBSOCKET_BASE_URL_BINANCE_SPOT: &str = "wss://stream.binance.com:9443/ws";
 
+/// [`BinanceSpot`] testnet WebSocket server base url.
+///
+/// See docs: <https://developers.binance.com/docs/binance-spot-api-docs/testnet/web-socket-streams>
+pub const WEBSOCKET_BASE_URL_BINANCE_SPOT_TESTNET: &str = "wss://testnet.binance.vision/ws";
+
 /// [`Binance`] spot exchange.
 pub type BinanceSpot = Binance<BinanceServerSpot>;
 
@@ -33,6 +38,10 @@ impl ExchangeServer for BinanceServerSpot {
     fn websocket_url() -> &'static str {
         WEBSOCKET_BASE_URL_BINANCE_SPOT
     }
+
+    fn websocket_url_testnet() -> Option<&'static str> {
+        Some(WEBSOCKET_BASE_URL_BINANCE_SPOT_TESTNET)
+    }
 }
 
 impl<Instrument> StreamSelector<Instrument, OrderBooksL2> for BinanceSpot
@@ -1,3 +1,4 @@
+use super::BinanceChannel;
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::ExchangeSub,
@@ -7,15 +8,15 @@ use crate::{
 use barter_instrument::exchange::ExchangeId;
 use barter_integration::{subscription::SubscriptionId, Side};
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
-
-use super::BinanceChannel;
+use serde::{de::Error, Deserialize, Serialize};
 
-/// Binance real-time trade message.
+/// Binance real-time trade message, published by either the raw "trade" or aggregate
+/// "aggTrade" stream (see
+/// [`SubscriptionParams::aggregated_trades`](crate::subscription::params::SubscriptionParams)).
 ///
 /// Note:
-/// For [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) this real-time stream is
-/// undocumented.
+/// For [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) the raw "trade" real-time
+/// stream is undocumented.
 ///
 /// See discord: <https://discord.com/channels/910237311332151317/923160222711812126/975712874582388757>
 ///
@@ -52,25 +53,142 @@ use super::BinanceChannel;
 ///     "m": true
 /// }
 /// ```
-#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+///
+/// #### Spot Side::Buy Aggregate Trade
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#aggregate-trade-streams>
+/// ```json
+/// {
+///     "e": "aggTrade",
+///     "E": 1672515782136,
+///     "s": "BNBBTC",
+///     "a": 12345,
+///     "p": "0.001",
+///     "q": "100",
+///     "f": 100,
+///     "l": 105,
+///     "T": 1672515782136,
+///     "m": false,
+///     "M": true
+/// }
+/// ```
+///
+/// Deserialised in a single pass over the message fields, since the meaning of the "a" field
+/// depends on the "e" event type (seller order id for "trade", aggregate trade id for
+/// "aggTrade").
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
 pub struct BinanceTrade {
-    #[serde(alias = "s", deserialize_with = "de_trade_subscription_id")]
     pub subscription_id: SubscriptionId,
-    #[serde(
-        alias = "T",
-        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
-    )]
     pub time: DateTime<Utc>,
-    #[serde(alias = "t")]
     pub id: u64,
-    #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
     pub price: f64,
-    #[serde(alias = "q", deserialize_with = "barter_integration::de::de_str")]
     pub amount: f64,
-    #[serde(alias = "m", deserialize_with = "de_side_from_buyer_is_maker")]
     pub side: Side,
+    /// Number of exchange trades aggregated, only present for "aggTrade" messages.
+    pub aggregate_count: Option<u64>,
 }
 
+impl<'de> Deserialize<'de> for BinanceTrade {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct BinanceTradeVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for BinanceTradeVisitor {
+            type Value = BinanceTrade;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BinanceTrade \"trade\" or \"aggTrade\" message")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut event = None;
+                let mut market = None;
+                let mut time = None;
+                let mut trade_id = None;
+                let mut aggregate_id = None;
+                let mut price = None;
+                let mut amount = None;
+                let mut side = None;
+                let mut first_trade_id = None;
+                let mut last_trade_id = None;
+
+                while let Some(key) = map.next_key::<&str>()? {
+                    match key {
+                        "e" => event = Some(map.next_value::<&str>()?),
+                        "s" => market = Some(map.next_value::<&str>()?),
+                        "T" => time = Some(map.next_value::<Timestamp>()?.0),
+                        "t" => trade_id = Some(map.next_value()?),
+                        "a" => aggregate_id = Some(map.next_value()?),
+                        "p" => price = Some(map.next_value::<StringF64>()?.0),
+                        "q" => amount = Some(map.next_value::<StringF64>()?.0),
+                        "m" => side = Some(map.next_value::<BuyerIsMaker>()?.0),
+                        "f" => first_trade_id = Some(map.next_value::<u64>()?),
+                        "l" => last_trade_id = Some(map.next_value::<u64>()?),
+                        _ => {
+                            map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+
+                let (channel, id, aggregate_count) = match event {
+                    Some("aggTrade") => (
+                        BinanceChannel::AGG_TRADES,
+                        aggregate_id.ok_or_else(|| Error::missing_field("a"))?,
+                        Some(
+                            last_trade_id
+                                .ok_or_else(|| Error::missing_field("l"))?
+                                .saturating_sub(
+                                    first_trade_id.ok_or_else(|| Error::missing_field("f"))?,
+                                )
+                                + 1,
+                        ),
+                    ),
+                    _ => (
+                        BinanceChannel::TRADES,
+                        trade_id.ok_or_else(|| Error::missing_field("t"))?,
+                        None,
+                    ),
+                };
+
+                Ok(BinanceTrade {
+                    subscription_id: ExchangeSub::from((
+                        channel,
+                        market.ok_or_else(|| Error::missing_field("s"))?,
+                    ))
+                    .id(),
+                    time: time.ok_or_else(|| Error::missing_field("T"))?,
+                    id,
+                    price: price.ok_or_else(|| Error::missing_field("p"))?,
+                    amount: amount.ok_or_else(|| Error::missing_field("q"))?,
+                    side: side.ok_or_else(|| Error::missing_field("m"))?,
+                    aggregate_count,
+                })
+            }
+        }
+
+        deserializer.deserialize_map(BinanceTradeVisitor)
+    }
+}
+
+/// [`BinanceTrade`] "T" field, deserialised as a [`DateTime<Utc>`].
+#[derive(Deserialize)]
+struct Timestamp(
+    #[serde(deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc")]
+    DateTime<Utc>,
+);
+
+/// [`BinanceTrade`] "p" & "q" fields, deserialised from a string as an `f64`.
+#[derive(Deserialize)]
+struct StringF64(#[serde(deserialize_with = "barter_integration::de::de_str")] f64);
+
+/// [`BinanceTrade`] "m" field, deserialised as a Barter [`Side`].
+#[derive(Deserialize)]
+struct BuyerIsMaker(#[serde(deserialize_with = "de_side_from_buyer_is_maker")] Side);
+
 impl Identifier<Option<SubscriptionId>> for BinanceTrade {
     fn id(&self) -> Option<SubscriptionId> {
         Some(self.subscription_id.clone())
@@ -91,21 +209,12 @@ impl<InstrumentKey> From<(ExchangeId, InstrumentKey, BinanceTrade)>
                 price: trade.price,
                 amount: trade.amount,
                 side: trade.side,
+                aggregate_count: trade.aggregate_count,
             },
         })])
     }
 }
 
-/// Deserialize a [`BinanceTrade`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`]
-/// (eg/ "@trade|BTCUSDT").
-pub fn de_trade_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
-where
-    D: serde::de::Deserializer<'de>,
-{
-    <&str as Deserialize>::deserialize(deserializer)
-        .map(|market| ExchangeSub::from((BinanceChannel::TRADES, market)).id())
-}
-
 /// Deserialize a [`BinanceTrade`] "buyer_is_maker" boolean field to a Barter [`Side`].
 ///
 /// Variants:
@@ -162,6 +271,7 @@ mod tests {
                         price: 10000.19,
                         amount: 0.239000,
                         side: Side::Buy,
+                        aggregate_count: None,
                     }),
                 },
                 TestCase {
@@ -193,6 +303,7 @@ mod tests {
                         price: 10000.19,
                         amount: 0.239000,
                         side: Side::Sell,
+                        aggregate_count: None,
                     }),
                 },
                 TestCase {
@@ -212,6 +323,7 @@ mod tests {
                         price: 10000.19,
                         amount: 0.239000,
                         side: Side::Buy,
+                        aggregate_count: None,
                     }),
                 },
                 TestCase {
@@ -229,6 +341,40 @@ mod tests {
                         price: 10000.19,
                         amount: 0.239000,
                         side: Side::Buy,
+                        aggregate_count: None,
+                    }),
+                },
+                TestCase {
+                    // TC5: Spot aggregate trade valid
+                    input: r#"
+                    {
+                        "e":"aggTrade","E":1672515782136,"s":"BNBBTC","a":12345,"p":"0.001",
+                        "q":"100","f":100,"l":105,"T":1672515782136,"m":true,"M":true
+                    }
+                    "#,
+                    expected: Ok(BinanceTrade {
+                        subscription_id: SubscriptionId::from("@aggTrade|BNBBTC"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672515782136,
+                        )),
+                        id: 12345,
+                        price: 0.001,
+                        amount: 100.0,
+                        side: Side::Sell,
+                        aggregate_count: Some(6),
+                    }),
+                },
+                TestCase {
+                    // TC6: Spot aggregate trade missing first trade id
+                    input: r#"
+                    {
+                        "e":"aggTrade","E":1672515782136,"s":"BNBBTC","a":12345,"p":"0.001",
+                        "q":"100","l":105,"T":1672515782136,"m":true,"M":true
+                    }
+                    "#,
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
                     }),
                 },
             ];
@@ -0,0 +1,271 @@
+use super::{
+    super::{
+        message::{BitfinexChannelMessage, BitfinexChannelPayload},
+        Bitfinex,
+    },
+    BitfinexBookPayload, BitfinexLevel,
+};
+use crate::{
+    books::OrderBook,
+    error::DataError,
+    event::MarketEvent,
+    exchange::Connector,
+    subscription::{
+        book::{OrderBookEvent, OrderBooksL2},
+        Map,
+    },
+    transformer::ExchangeTransformer,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    protocol::websocket::WsMessage, subscription::SubscriptionId, Side, Transformer,
+};
+use chrono::Utc;
+use derive_more::Constructor;
+use tokio::sync::mpsc::UnboundedSender;
+
+/// [`Bitfinex`] aggregated OrderBook Level2 depth requested when subscribing.
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-books>
+pub const ORDER_BOOK_L2_DEPTH_BITFINEX: &str = "25";
+
+/// Terse type alias for a [`Bitfinex`] real-time aggregated OrderBook Level2 WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-books>
+/// #### Snapshot
+/// ```json
+/// [17082,[[7254.7,3,3.3],[7254.6,2,1.2],[7254.8,1,-0.7]]]
+/// ```
+///
+/// #### Update
+/// ```json
+/// [17082,[7254.7,0,1]]
+/// ```
+pub type BitfinexOrderBookL2 = BitfinexChannelMessage<BitfinexBookPayload<BitfinexLevel>>;
+
+#[derive(Debug, Constructor)]
+pub struct BitfinexOrderBookL2Meta<InstrumentKey> {
+    pub key: InstrumentKey,
+    pub sequencer: BitfinexOrderBookL2Sequencer,
+}
+
+/// [`Bitfinex`] OrderBook Level2 sequencer.
+///
+/// [`Bitfinex`] does not publish update identifiers, relying on the ordering of the WebSocket,
+/// so the number of payloads processed is used as the [`OrderBook`] sequence.
+#[derive(Debug, Default)]
+pub struct BitfinexOrderBookL2Sequencer {
+    pub updates_processed: u64,
+}
+
+impl BitfinexOrderBookL2Sequencer {
+    /// Sequence the provided [`BitfinexBookPayload`] into an [`OrderBookEvent`].
+    pub fn sequence(&mut self, payload: BitfinexBookPayload<BitfinexLevel>) -> OrderBookEvent {
+        self.updates_processed += 1;
+
+        match payload {
+            BitfinexBookPayload::Snapshot(levels) => {
+                OrderBookEvent::Snapshot(order_book(self.updates_processed, levels))
+            }
+            BitfinexBookPayload::Update(level) => {
+                OrderBookEvent::Update(order_book(self.updates_processed, vec![level]))
+            }
+        }
+    }
+}
+
+/// Construct an [`OrderBook`] with the provided sequence from a collection of
+/// [`BitfinexLevel`]s, using the sign of each amount to determine the side.
+fn order_book(sequence: u64, levels: Vec<BitfinexLevel>) -> OrderBook {
+    let (bids, asks): (Vec<_>, Vec<_>) = levels
+        .into_iter()
+        .partition(|level| level.side() == Side::Buy);
+
+    OrderBook::new(sequence, None, bids, asks)
+}
+
+/// [`Bitfinex`] OrderBook Level2 transformer, multiplexing the [`BitfinexOrderBookL2`] messages
+/// of each subscription using the `CHANNEL_ID` allocated during subscription validation.
+#[derive(Debug)]
+pub struct BitfinexOrderBooksL2Transformer<InstrumentKey> {
+    instrument_map: Map<BitfinexOrderBookL2Meta<InstrumentKey>>,
+}
+
+#[async_trait]
+impl<InstrumentKey> ExchangeTransformer<Bitfinex, InstrumentKey, OrderBooksL2>
+    for BitfinexOrderBooksL2Transformer<InstrumentKey>
+where
+    InstrumentKey: Clone + Send,
+{
+    async fn init(
+        instrument_map: Map<InstrumentKey>,
+        _: &[MarketEvent<InstrumentKey, OrderBookEvent>],
+        _: UnboundedSender<WsMessage>,
+    ) -> Result<Self, DataError> {
+        let instrument_map = instrument_map
+            .0
+            .into_iter()
+            .map(|(sub_id, instrument_key)| {
+                let book_meta = BitfinexOrderBookL2Meta::new(
+                    instrument_key,
+                    BitfinexOrderBookL2Sequencer::default(),
+                );
+                (sub_id, book_meta)
+            })
+            .collect();
+
+        Ok(Self { instrument_map })
+    }
+}
+
+impl<InstrumentKey> Transformer for BitfinexOrderBooksL2Transformer<InstrumentKey>
+where
+    InstrumentKey: Clone,
+{
+    type Error = DataError;
+    type Input = BitfinexOrderBookL2;
+    type Output = MarketEvent<InstrumentKey, OrderBookEvent>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        // Heartbeats are not associated with any OrderBook, so are ignored
+        let BitfinexChannelPayload::Data(payload) = input.payload else {
+            return vec![];
+        };
+
+        // Find Instrument associated with the Input CHANNEL_ID and transform
+        let subscription_id = SubscriptionId::from(input.channel_id.to_string());
+        let instrument = match self.instrument_map.find_mut(&subscription_id) {
+            Ok(instrument) => instrument,
+            Err(unidentifiable) => return vec![Err(DataError::Socket(unidentifiable))],
+        };
+
+        // Bitfinex OrderBook updates are not timestamped by the exchange
+        let time_received = Utc::now();
+
+        vec![Ok(MarketEvent {
+            time_exchange: time_received,
+            time_received,
+            exchange: Bitfinex::ID,
+            instrument: instrument.key.clone(),
+            kind: instrument.sequencer.sequence(payload),
+        })]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::books::Level;
+    use rust_decimal_macros::dec;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_bitfinex_order_book_l2() {
+            struct TestCase {
+                input: &'static str,
+                expected: BitfinexOrderBookL2,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid OrderBook snapshot
+                    input: r#"[17082,[[7254.7,3,3.3],[7254.8,1,-0.7]]]"#,
+                    expected: BitfinexOrderBookL2 {
+                        channel_id: 17082,
+                        payload: BitfinexChannelPayload::Data(BitfinexBookPayload::Snapshot(vec![
+                            BitfinexLevel {
+                                price: dec!(7254.7),
+                                count: 3,
+                                amount: dec!(3.3),
+                            },
+                            BitfinexLevel {
+                                price: dec!(7254.8),
+                                count: 1,
+                                amount: dec!(-0.7),
+                            },
+                        ])),
+                    },
+                },
+                TestCase {
+                    // TC1: valid OrderBook update
+                    input: r#"[17082,[7254.7,0,1]]"#,
+                    expected: BitfinexOrderBookL2 {
+                        channel_id: 17082,
+                        payload: BitfinexChannelPayload::Data(BitfinexBookPayload::Update(
+                            BitfinexLevel {
+                                price: dec!(7254.7),
+                                count: 0,
+                                amount: dec!(1),
+                            },
+                        )),
+                    },
+                },
+                TestCase {
+                    // TC2: valid Heartbeat
+                    input: r#"[17082,"hb"]"#,
+                    expected: BitfinexOrderBookL2 {
+                        channel_id: 17082,
+                        payload: BitfinexChannelPayload::Heartbeat,
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BitfinexOrderBookL2>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+
+        #[test]
+        fn test_bitfinex_order_book_l2_unknown_tag() {
+            assert!(serde_json::from_str::<BitfinexOrderBookL2>(r#"[17082,"cs",-1]"#).is_err());
+        }
+    }
+
+    #[test]
+    fn test_sequencer_sequence() {
+        let mut sequencer = BitfinexOrderBookL2Sequencer::default();
+
+        let snapshot = sequencer.sequence(BitfinexBookPayload::Snapshot(vec![
+            BitfinexLevel {
+                price: dec!(100),
+                count: 1,
+                amount: dec!(1),
+            },
+            BitfinexLevel {
+                price: dec!(101),
+                count: 2,
+                amount: dec!(-2),
+            },
+        ]));
+        assert_eq!(
+            snapshot,
+            OrderBookEvent::Snapshot(OrderBook::new(
+                1,
+                None,
+                vec![Level::new(100, 1)],
+                vec![Level::new(101, 2)],
+            ))
+        );
+
+        // Level removal is signalled by a count of 0, with the amount sign identifying the side
+        let delta = sequencer.sequence(BitfinexBookPayload::Update(BitfinexLevel {
+            price: dec!(101),
+            count: 0,
+            amount: dec!(-1),
+        }));
+        assert_eq!(
+            delta,
+            OrderBookEvent::Update(OrderBook::new(
+                2,
+                None,
+                Vec::<Level>::new(),
+                vec![Level::new(101, 0)],
+            ))
+        );
+    }
+}
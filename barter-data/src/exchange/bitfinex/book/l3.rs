@@ -0,0 +1,322 @@
+use super::{
+    super::{
+        message::{BitfinexChannelMessage, BitfinexChannelPayload},
+        Bitfinex,
+    },
+    BitfinexBookPayload, BitfinexRawOrder,
+};
+use crate::{
+    books::{Level, OrderBook},
+    error::DataError,
+    event::MarketEvent,
+    exchange::Connector,
+    subscription::{
+        book::{OrderBookEvent, OrderBooksL3},
+        Map,
+    },
+    transformer::ExchangeTransformer,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    protocol::websocket::WsMessage, subscription::SubscriptionId, Side, Transformer,
+};
+use chrono::Utc;
+use derive_more::Constructor;
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+use tokio::sync::mpsc::UnboundedSender;
+
+/// [`Bitfinex`] raw OrderBook Level3 depth requested when subscribing.
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+pub const ORDER_BOOK_L3_DEPTH_BITFINEX: &str = "100";
+
+/// Terse type alias for a [`Bitfinex`] real-time raw OrderBook Level3 WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+/// #### Snapshot
+/// ```json
+/// [17470,[[34035219880,7254.7,0.5],[34035219881,7254.8,-0.7]]]
+/// ```
+///
+/// #### Update (order removed)
+/// ```json
+/// [17470,[34035219880,0,1]]
+/// ```
+pub type BitfinexOrderBookL3 = BitfinexChannelMessage<BitfinexBookPayload<BitfinexRawOrder>>;
+
+#[derive(Debug, Constructor)]
+pub struct BitfinexOrderBookL3Meta<InstrumentKey> {
+    pub key: InstrumentKey,
+    pub sequencer: BitfinexOrderBookL3Sequencer,
+}
+
+/// [`Bitfinex`] raw OrderBook Level3 sequencer.
+///
+/// Tracks every open [`BitfinexRawOrder`] so that each raw order update can be normalised into
+/// the aggregated amount of the price level it affects. As with
+/// [`BitfinexOrderBookL2Sequencer`](super::l2::BitfinexOrderBookL2Sequencer), the number of
+/// payloads processed is used as the [`OrderBook`] sequence.
+#[derive(Debug, Default)]
+pub struct BitfinexOrderBookL3Sequencer {
+    pub updates_processed: u64,
+    orders: FnvHashMap<u64, BitfinexRawOrder>,
+    bids: FnvHashMap<Decimal, Decimal>,
+    asks: FnvHashMap<Decimal, Decimal>,
+}
+
+impl BitfinexOrderBookL3Sequencer {
+    /// Sequence the provided [`BitfinexBookPayload`] into an [`OrderBookEvent`].
+    pub fn sequence(&mut self, payload: BitfinexBookPayload<BitfinexRawOrder>) -> OrderBookEvent {
+        self.updates_processed += 1;
+
+        match payload {
+            BitfinexBookPayload::Snapshot(orders) => {
+                self.orders.clear();
+                self.bids.clear();
+                self.asks.clear();
+                orders.into_iter().for_each(|order| self.insert(order));
+
+                OrderBookEvent::Snapshot(OrderBook::new(
+                    self.updates_processed,
+                    None,
+                    self.bids.iter().map(|(price, amount)| (*price, *amount)),
+                    self.asks.iter().map(|(price, amount)| (*price, *amount)),
+                ))
+            }
+            BitfinexBookPayload::Update(order) => {
+                let mut changed = Vec::with_capacity(2);
+
+                // Remove any previous state of the order, since updates replace the order
+                if let Some(prev) = self.orders.remove(&order.order_id) {
+                    self.remove(prev);
+                    changed.push((prev.side(), prev.price));
+                }
+
+                // A price of 0 indicates the order was removed
+                if !order.price.is_zero() {
+                    self.insert(order);
+                    changed.push((order.side(), order.price));
+                }
+                changed.dedup();
+
+                let (bids, asks): (Vec<_>, Vec<_>) = changed
+                    .into_iter()
+                    .map(|(side, price)| (side, self.level(side, price)))
+                    .partition(|(side, _)| *side == Side::Buy);
+
+                OrderBookEvent::Update(OrderBook::new(
+                    self.updates_processed,
+                    None,
+                    bids.into_iter().map(|(_, level)| level),
+                    asks.into_iter().map(|(_, level)| level),
+                ))
+            }
+        }
+    }
+
+    fn insert(&mut self, order: BitfinexRawOrder) {
+        *self
+            .levels_mut(order.side())
+            .entry(order.price)
+            .or_default() += order.amount.abs();
+        self.orders.insert(order.order_id, order);
+    }
+
+    fn remove(&mut self, order: BitfinexRawOrder) {
+        let levels = self.levels_mut(order.side());
+        if let Some(amount) = levels.get_mut(&order.price) {
+            *amount -= order.amount.abs();
+            if amount.is_sign_negative() || amount.is_zero() {
+                levels.remove(&order.price);
+            }
+        }
+    }
+
+    fn level(&self, side: Side, price: Decimal) -> Level {
+        let levels = match side {
+            Side::Buy => &self.bids,
+            Side::Sell => &self.asks,
+        };
+
+        Level::new(price, levels.get(&price).copied().unwrap_or_default())
+    }
+
+    fn levels_mut(&mut self, side: Side) -> &mut FnvHashMap<Decimal, Decimal> {
+        match side {
+            Side::Buy => &mut self.bids,
+            Side::Sell => &mut self.asks,
+        }
+    }
+}
+
+/// [`Bitfinex`] raw OrderBook Level3 transformer, multiplexing the [`BitfinexOrderBookL3`]
+/// messages of each subscription using the `CHANNEL_ID` allocated during subscription validation.
+#[derive(Debug)]
+pub struct BitfinexOrderBooksL3Transformer<InstrumentKey> {
+    instrument_map: Map<BitfinexOrderBookL3Meta<InstrumentKey>>,
+}
+
+#[async_trait]
+impl<InstrumentKey> ExchangeTransformer<Bitfinex, InstrumentKey, OrderBooksL3>
+    for BitfinexOrderBooksL3Transformer<InstrumentKey>
+where
+    InstrumentKey: Clone + Send,
+{
+    async fn init(
+        instrument_map: Map<InstrumentKey>,
+        _: &[MarketEvent<InstrumentKey, OrderBookEvent>],
+        _: UnboundedSender<WsMessage>,
+    ) -> Result<Self, DataError> {
+        let instrument_map = instrument_map
+            .0
+            .into_iter()
+            .map(|(sub_id, instrument_key)| {
+                let book_meta = BitfinexOrderBookL3Meta::new(
+                    instrument_key,
+                    BitfinexOrderBookL3Sequencer::default(),
+                );
+                (sub_id, book_meta)
+            })
+            .collect();
+
+        Ok(Self { instrument_map })
+    }
+}
+
+impl<InstrumentKey> Transformer for BitfinexOrderBooksL3Transformer<InstrumentKey>
+where
+    InstrumentKey: Clone,
+{
+    type Error = DataError;
+    type Input = BitfinexOrderBookL3;
+    type Output = MarketEvent<InstrumentKey, OrderBookEvent>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        // Heartbeats are not associated with any OrderBook, so are ignored
+        let BitfinexChannelPayload::Data(payload) = input.payload else {
+            return vec![];
+        };
+
+        // Find Instrument associated with the Input CHANNEL_ID and transform
+        let subscription_id = SubscriptionId::from(input.channel_id.to_string());
+        let instrument = match self.instrument_map.find_mut(&subscription_id) {
+            Ok(instrument) => instrument,
+            Err(unidentifiable) => return vec![Err(DataError::Socket(unidentifiable))],
+        };
+
+        // Bitfinex OrderBook updates are not timestamped by the exchange
+        let time_received = Utc::now();
+
+        vec![Ok(MarketEvent {
+            time_exchange: time_received,
+            time_received,
+            exchange: Bitfinex::ID,
+            instrument: instrument.key.clone(),
+            kind: instrument.sequencer.sequence(payload),
+        })]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_de_bitfinex_order_book_l3() {
+        let input = r#"[17470,[[34035219880,7254.7,0.5],[34035219881,7254.8,-0.7]]]"#;
+
+        assert_eq!(
+            serde_json::from_str::<BitfinexOrderBookL3>(input).unwrap(),
+            BitfinexOrderBookL3 {
+                channel_id: 17470,
+                payload: BitfinexChannelPayload::Data(BitfinexBookPayload::Snapshot(vec![
+                    BitfinexRawOrder {
+                        order_id: 34035219880,
+                        price: dec!(7254.7),
+                        amount: dec!(0.5),
+                    },
+                    BitfinexRawOrder {
+                        order_id: 34035219881,
+                        price: dec!(7254.8),
+                        amount: dec!(-0.7),
+                    },
+                ])),
+            }
+        );
+    }
+
+    #[test]
+    fn test_sequencer_sequence() {
+        struct TestCase {
+            input: BitfinexBookPayload<BitfinexRawOrder>,
+            expected: OrderBookEvent,
+        }
+
+        let order = |order_id, price, amount| BitfinexRawOrder {
+            order_id,
+            price,
+            amount,
+        };
+
+        let mut sequencer = BitfinexOrderBookL3Sequencer::default();
+
+        let tests = vec![
+            TestCase {
+                // TC0: snapshot aggregates orders at the same price level
+                input: BitfinexBookPayload::Snapshot(vec![
+                    order(1, dec!(100), dec!(1)),
+                    order(2, dec!(100), dec!(2)),
+                    order(3, dec!(101), dec!(-1)),
+                ]),
+                expected: OrderBookEvent::Snapshot(OrderBook::new(
+                    1,
+                    None,
+                    vec![Level::new(dec!(100), dec!(3))],
+                    vec![Level::new(dec!(101), dec!(1))],
+                )),
+            },
+            TestCase {
+                // TC1: new ask order at an existing level
+                input: BitfinexBookPayload::Update(order(4, dec!(101), dec!(-0.5))),
+                expected: OrderBookEvent::Update(OrderBook::new(
+                    2,
+                    None,
+                    vec![],
+                    vec![Level::new(dec!(101), dec!(1.5))],
+                )),
+            },
+            TestCase {
+                // TC2: existing bid order moved to a new price level
+                input: BitfinexBookPayload::Update(order(1, dec!(99), dec!(1))),
+                expected: OrderBookEvent::Update(OrderBook::new(
+                    3,
+                    None,
+                    vec![
+                        Level::new(dec!(100), dec!(2)),
+                        Level::new(dec!(99), dec!(1)),
+                    ],
+                    vec![],
+                )),
+            },
+            TestCase {
+                // TC3: last bid order at a level removed
+                input: BitfinexBookPayload::Update(order(2, dec!(0), dec!(1))),
+                expected: OrderBookEvent::Update(OrderBook::new(
+                    4,
+                    None,
+                    vec![Level::new(dec!(100), dec!(0))],
+                    vec![],
+                )),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = sequencer.sequence(test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use crate::books::Level;
+use barter_integration::Side;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Level 2 OrderBook types (aggregated "P0" books).
+pub mod l2;
+
+/// Level 3 OrderBook types (raw "R0" books).
+pub mod l3;
+
+/// [`Bitfinex`](super::Bitfinex) OrderBook payload received over the "book" channel.
+///
+/// The first message after subscribing contains the full OrderBook snapshot, whereas every
+/// subsequent message contains a single level (or order) update.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-books>
+/// #### Snapshot
+/// ```json
+/// [[7254.7,3,3.3],[7254.6,2,1.2],[7254.8,1,-0.7]]
+/// ```
+///
+/// #### Update
+/// ```json
+/// [7254.7,3,3.3]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BitfinexBookPayload<T> {
+    Snapshot(Vec<T>),
+    Update(T),
+}
+
+/// [`Bitfinex`](super::Bitfinex) aggregated OrderBook level, where the sign of the amount
+/// indicates the side of the OrderBook (positive for bids, negative for asks).
+///
+/// A count of 0 indicates the level must be removed from the OrderBook.
+///
+/// #### Raw Payload Examples
+/// Format: \[PRICE, COUNT, AMOUNT\]
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-books>
+/// ```json
+/// [7254.7,3,3.3]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitfinexLevel {
+    pub price: Decimal,
+    pub count: u64,
+    pub amount: Decimal,
+}
+
+impl BitfinexLevel {
+    /// Determine the [`Side`] of the OrderBook this [`BitfinexLevel`] applies to.
+    pub fn side(&self) -> Side {
+        if self.amount.is_sign_positive() {
+            Side::Buy
+        } else {
+            Side::Sell
+        }
+    }
+}
+
+impl From<BitfinexLevel> for Level {
+    fn from(level: BitfinexLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: if level.count == 0 {
+                Decimal::ZERO
+            } else {
+                level.amount.abs()
+            },
+        }
+    }
+}
+
+/// [`Bitfinex`](super::Bitfinex) raw OrderBook order, where the sign of the amount indicates the
+/// side of the OrderBook (positive for bids, negative for asks).
+///
+/// A price of 0 indicates the order must be removed from the OrderBook.
+///
+/// #### Raw Payload Examples
+/// Format: \[ORDER_ID, PRICE, AMOUNT\]
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+/// ```json
+/// [34035219880,7254.7,0.5]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitfinexRawOrder {
+    pub order_id: u64,
+    pub price: Decimal,
+    pub amount: Decimal,
+}
+
+impl BitfinexRawOrder {
+    /// Determine the [`Side`] of the OrderBook this [`BitfinexRawOrder`] applies to.
+    pub fn side(&self) -> Side {
+        if self.amount.is_sign_positive() {
+            Side::Buy
+        } else {
+            Side::Sell
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_bitfinex_book_payload() {
+            struct TestCase {
+                input: &'static str,
+                expected: BitfinexBookPayload<BitfinexLevel>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid OrderBook snapshot
+                    input: r#"[[7254.7,3,3.3],[7254.8,1,-0.7]]"#,
+                    expected: BitfinexBookPayload::Snapshot(vec![
+                        BitfinexLevel {
+                            price: dec!(7254.7),
+                            count: 3,
+                            amount: dec!(3.3),
+                        },
+                        BitfinexLevel {
+                            price: dec!(7254.8),
+                            count: 1,
+                            amount: dec!(-0.7),
+                        },
+                    ]),
+                },
+                TestCase {
+                    // TC1: valid OrderBook update
+                    input: r#"[7254.7,0,1]"#,
+                    expected: BitfinexBookPayload::Update(BitfinexLevel {
+                        price: dec!(7254.7),
+                        count: 0,
+                        amount: dec!(1),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual =
+                    serde_json::from_str::<BitfinexBookPayload<BitfinexLevel>>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bitfinex_level_into_level() {
+        struct TestCase {
+            input: BitfinexLevel,
+            expected: (Side, Level),
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: bid level
+                input: BitfinexLevel {
+                    price: dec!(100),
+                    count: 2,
+                    amount: dec!(1.5),
+                },
+                expected: (Side::Buy, Level::new(dec!(100), dec!(1.5))),
+            },
+            TestCase {
+                // TC1: ask level
+                input: BitfinexLevel {
+                    price: dec!(101),
+                    count: 1,
+                    amount: dec!(-0.5),
+                },
+                expected: (Side::Sell, Level::new(dec!(101), dec!(0.5))),
+            },
+            TestCase {
+                // TC2: removed ask level
+                input: BitfinexLevel {
+                    price: dec!(101),
+                    count: 0,
+                    amount: dec!(-1),
+                },
+                expected: (Side::Sell, Level::new(dec!(101), dec!(0))),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = (test.input.side(), Level::from(test.input));
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
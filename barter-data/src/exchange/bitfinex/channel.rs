@@ -1,6 +1,11 @@
 use super::Bitfinex;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{
+        book::{OrderBooksL2, OrderBooksL3},
+        status::DerivativesStatuses,
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +22,25 @@ impl BitfinexChannel {
     ///
     /// See docs: <https://docs.bitfinex.com/reference/ws-public-trades>
     pub const TRADES: Self = Self("trades");
+
+    /// [`Bitfinex`] real-time aggregated OrderBook Level2 channel (precision "P0").
+    ///
+    /// See docs: <https://docs.bitfinex.com/reference/ws-public-books>
+    pub const ORDER_BOOK_L2: Self = Self("book");
+
+    /// [`Bitfinex`] real-time raw OrderBook Level3 channel (precision "R0").
+    ///
+    /// Raw books are subscribed to via the "book" channel, so this identifier is only used
+    /// internally to distinguish them from aggregated books (see
+    /// [`BitfinexSubResponse::subscription_channel`](super::subscription::BitfinexSubResponse::subscription_channel)).
+    ///
+    /// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+    pub const ORDER_BOOK_L3: Self = Self("raw_book");
+
+    /// [`Bitfinex`] real-time derivatives status channel (funding, mark price, etc.).
+    ///
+    /// See docs: <https://docs.bitfinex.com/reference/ws-public-status>
+    pub const STATUS: Self = Self("status");
 }
 
 impl<Instrument> Identifier<BitfinexChannel> for Subscription<Bitfinex, Instrument, PublicTrades> {
@@ -25,6 +49,26 @@ impl<Instrument> Identifier<BitfinexChannel> for Subscription<Bitfinex, Instrume
     }
 }
 
+impl<Instrument> Identifier<BitfinexChannel> for Subscription<Bitfinex, Instrument, OrderBooksL2> {
+    fn id(&self) -> BitfinexChannel {
+        BitfinexChannel::ORDER_BOOK_L2
+    }
+}
+
+impl<Instrument> Identifier<BitfinexChannel> for Subscription<Bitfinex, Instrument, OrderBooksL3> {
+    fn id(&self) -> BitfinexChannel {
+        BitfinexChannel::ORDER_BOOK_L3
+    }
+}
+
+impl<Instrument> Identifier<BitfinexChannel>
+    for Subscription<Bitfinex, Instrument, DerivativesStatuses>
+{
+    fn id(&self) -> BitfinexChannel {
+        BitfinexChannel::STATUS
+    }
+}
+
 impl AsRef<str> for BitfinexChannel {
     fn as_ref(&self) -> &str {
         self.0
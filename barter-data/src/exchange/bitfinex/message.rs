@@ -56,6 +56,50 @@ impl Identifier<Option<SubscriptionId>> for BitfinexMessage {
     }
 }
 
+/// [`Bitfinex`](super::Bitfinex) message received over a channel that does not tag its payloads
+/// (eg/ "book" or "status"), where the payload type `T` is determined by the subscribed channel.
+///
+/// As with [`BitfinexMessage`], the message is associated with the original
+/// [`Subscription`](crate::Subscription) using the `channel_id` field as the [`SubscriptionId`].
+///
+/// ### Raw Payload Examples
+/// #### Heartbeat
+/// See docs: <https://docs.bitfinex.com/docs/ws-general#heartbeating>
+/// ```json
+/// [17082,"hb"]
+/// ```
+///
+/// #### OrderBook Level2 Update
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-books>
+/// ```json
+/// [17082,[7254.7,3,3.3]]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitfinexChannelMessage<T> {
+    pub channel_id: u32,
+    pub payload: BitfinexChannelPayload<T>,
+}
+
+/// [`Bitfinex`](super::Bitfinex) untagged channel payload variants.
+///
+/// See [`BitfinexChannelMessage`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub enum BitfinexChannelPayload<T> {
+    Heartbeat,
+    Data(T),
+}
+
+impl<T> Identifier<Option<SubscriptionId>> for BitfinexChannelMessage<T> {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self.payload {
+            BitfinexChannelPayload::Heartbeat => None,
+            BitfinexChannelPayload::Data(_) => {
+                Some(SubscriptionId::from(self.channel_id.to_string()))
+            }
+        }
+    }
+}
+
 impl<InstrumentKey> From<(ExchangeId, InstrumentKey, BitfinexMessage)>
     for MarketIter<InstrumentKey, PublicTrade>
 {
@@ -129,6 +173,72 @@ impl<'de> serde::Deserialize<'de> for BitfinexMessage {
     }
 }
 
+impl<'de, T> serde::Deserialize<'de> for BitfinexChannelMessage<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor<T>(std::marker::PhantomData<T>);
+
+        impl<'de, T> serde::de::Visitor<'de> for SeqVisitor<T>
+        where
+            T: serde::de::DeserializeOwned,
+        {
+            type Value = BitfinexChannelMessage<T>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BitfinexChannelMessage struct from the Bitfinex WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // Data: [CHANNEL_ID, PAYLOAD]
+                // Heartbeat: [ CHANNEL_ID, "hb" ]
+                #[derive(serde::Deserialize)]
+                #[serde(untagged)]
+                enum Element<T> {
+                    Tag(String),
+                    Data(T),
+                }
+
+                // Extract CHANNEL_ID used to identify SubscriptionId: 1st element of the sequence
+                let channel_id: u32 = extract_next(&mut seq, "channel_id")?;
+
+                // Extract either a message tag or the channel payload: 2nd element of sequence
+                let payload = match extract_next::<SeqAccessor, Element<T>>(&mut seq, "payload")? {
+                    Element::Tag(tag) if tag == "hb" => BitfinexChannelPayload::Heartbeat,
+                    Element::Tag(other) => {
+                        return Err(serde::de::Error::unknown_variant(
+                            other.as_str(),
+                            &["heartbeat (hb)"],
+                        ))
+                    }
+                    Element::Data(data) => BitfinexChannelPayload::Data(data),
+                };
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Bitfinex may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+                Ok(BitfinexChannelMessage {
+                    channel_id,
+                    payload,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the WebSocket BitfinexChannelMessage
+        deserializer.deserialize_seq(SeqVisitor(std::marker::PhantomData))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
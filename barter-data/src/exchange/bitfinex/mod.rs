@@ -14,20 +14,41 @@
 //! - The user is allowed up to 20 connections per minute on the public API.
 //! - Each connection can be used to connect up to 25 different channels.
 //!
+//! #### Channel Multiplexing
+//! - Bitfinex "book" & "status" channel messages are not tagged with their payload type, so the
+//!   `CHANNEL_ID` is the only way to associate them with a subscription.
+//! - Each transformer therefore maps the `CHANNEL_ID` of every message to the `SubscriptionId`
+//!   determined during validation (eg/ the OrderBook state of each `CHANNEL_ID` is maintained
+//!   independently by the OrderBook transformers).
+//! - Raw books are subscribed to via the "book" channel with precision "R0", and are
+//!   distinguished from aggregated books using the `BitfinexChannel::ORDER_BOOK_L3` identifier.
+//!
 //! #### Trade Variants
 //! - Bitfinex trades subscriptions results in receiving tag="te" & tag="tu" trades.
 //! - Both appear to be identical payloads, but "te" arriving marginally faster.
 //! - Therefore, tag="tu" trades are filtered out and considered only as additional Heartbeats.
 
 use self::{
-    channel::BitfinexChannel, market::BitfinexMarket, message::BitfinexMessage,
-    subscription::BitfinexPlatformEvent, validator::BitfinexWebSocketSubValidator,
+    book::{
+        l2::{BitfinexOrderBooksL2Transformer, ORDER_BOOK_L2_DEPTH_BITFINEX},
+        l3::{BitfinexOrderBooksL3Transformer, ORDER_BOOK_L3_DEPTH_BITFINEX},
+    },
+    channel::BitfinexChannel,
+    market::BitfinexMarket,
+    message::BitfinexMessage,
+    status::BitfinexDerivativesStatusMessage,
+    subscription::BitfinexPlatformEvent,
+    validator::BitfinexWebSocketSubValidator,
 };
 use crate::{
     exchange::{Connector, ExchangeSub, StreamSelector},
     instrument::InstrumentData,
     subscriber::WebSocketSubscriber,
-    subscription::trade::PublicTrades,
+    subscription::{
+        book::{OrderBooksL2, OrderBooksL3},
+        status::DerivativesStatuses,
+        trade::PublicTrades,
+    },
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream, NoInitialSnapshots,
 };
@@ -37,6 +58,9 @@ use barter_macro::{DeExchange, SerExchange};
 use serde_json::json;
 use url::Url;
 
+/// OrderBook types for [`Bitfinex`].
+pub mod book;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -52,6 +76,9 @@ pub mod message;
 /// [`Validator`](barter_integration::Validator) for [`Bitfinex`].
 pub mod subscription;
 
+/// Derivatives status (funding, mark price, etc.) types for [`Bitfinex`].
+pub mod status;
+
 /// Public trade types for [`Bitfinex`].
 pub mod trade;
 
@@ -64,6 +91,21 @@ pub mod validator;
 /// See docs: <https://docs.bitfinex.com/docs/ws-general>
 pub const BASE_URL_BITFINEX: &str = "wss://api-pub.bitfinex.com/ws/2";
 
+/// [`Bitfinex`] aggregated OrderBook precision.
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-books>
+pub const BOOK_PRECISION_AGGREGATED: &str = "P0";
+
+/// [`Bitfinex`] raw OrderBook precision.
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+pub const BOOK_PRECISION_RAW: &str = "R0";
+
+/// [`Bitfinex`] derivatives status channel key prefix.
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-status>
+pub const STATUS_KEY_PREFIX: &str = "deriv:";
+
 /// [`Bitfinex`] exchange.
 ///
 /// See docs: <https://docs.bitfinex.com/docs/ws-general>
@@ -88,14 +130,37 @@ impl Connector for Bitfinex {
         exchange_subs
             .into_iter()
             .map(|ExchangeSub { channel, market }| {
-                WsMessage::Text(
+                let request = if channel == BitfinexChannel::ORDER_BOOK_L2 {
+                    json!({
+                        "event": "subscribe",
+                        "channel": BitfinexChannel::ORDER_BOOK_L2.as_ref(),
+                        "symbol": market.as_ref(),
+                        "prec": BOOK_PRECISION_AGGREGATED,
+                        "len": ORDER_BOOK_L2_DEPTH_BITFINEX,
+                    })
+                } else if channel == BitfinexChannel::ORDER_BOOK_L3 {
+                    json!({
+                        "event": "subscribe",
+                        "channel": BitfinexChannel::ORDER_BOOK_L2.as_ref(),
+                        "symbol": market.as_ref(),
+                        "prec": BOOK_PRECISION_RAW,
+                        "len": ORDER_BOOK_L3_DEPTH_BITFINEX,
+                    })
+                } else if channel == BitfinexChannel::STATUS {
+                    json!({
+                        "event": "subscribe",
+                        "channel": channel.as_ref(),
+                        "key": format!("{STATUS_KEY_PREFIX}{}", market.as_ref()),
+                    })
+                } else {
                     json!({
                         "event": "subscribe",
                         "channel": channel.as_ref(),
                         "symbol": market.as_ref(),
                     })
-                    .to_string(),
-                )
+                };
+
+                WsMessage::Text(request.to_string())
             })
             .collect()
     }
@@ -110,3 +175,102 @@ where
         StatelessTransformer<Self, Instrument::Key, PublicTrades, BitfinexMessage>,
     >;
 }
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL2> for Bitfinex
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = ExchangeWsStream<BitfinexOrderBooksL2Transformer<Instrument::Key>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL3> for Bitfinex
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = ExchangeWsStream<BitfinexOrderBooksL3Transformer<Instrument::Key>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, DerivativesStatuses> for Bitfinex
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<
+            Self,
+            Instrument::Key,
+            DerivativesStatuses,
+            BitfinexDerivativesStatusMessage,
+        >,
+    >;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use smol_str::SmolStr;
+
+    #[test]
+    fn test_bitfinex_requests() {
+        struct TestCase {
+            input: ExchangeSub<BitfinexChannel, BitfinexMarket>,
+            expected: serde_json::Value,
+        }
+
+        let sub = |channel, market: &str| ExchangeSub {
+            channel,
+            market: BitfinexMarket(SmolStr::new(market)),
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: trades request
+                input: sub(BitfinexChannel::TRADES, "tBTCUSD"),
+                expected: json!({ "event": "subscribe", "channel": "trades", "symbol": "tBTCUSD" }),
+            },
+            TestCase {
+                // TC1: aggregated book request
+                input: sub(BitfinexChannel::ORDER_BOOK_L2, "tBTCUSD"),
+                expected: json!({
+                    "event": "subscribe",
+                    "channel": "book",
+                    "symbol": "tBTCUSD",
+                    "prec": "P0",
+                    "len": "25",
+                }),
+            },
+            TestCase {
+                // TC2: raw book request
+                input: sub(BitfinexChannel::ORDER_BOOK_L3, "tBTCUSD"),
+                expected: json!({
+                    "event": "subscribe",
+                    "channel": "book",
+                    "symbol": "tBTCUSD",
+                    "prec": "R0",
+                    "len": "100",
+                }),
+            },
+            TestCase {
+                // TC3: derivatives status request
+                input: sub(BitfinexChannel::STATUS, "tBTCF0:USTF0"),
+                expected: json!({
+                    "event": "subscribe",
+                    "channel": "status",
+                    "key": "deriv:tBTCF0:USTF0",
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = Bitfinex::requests(vec![test.input]);
+            assert_eq!(
+                actual,
+                vec![WsMessage::Text(test.expected.to_string())],
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use super::message::{BitfinexChannelMessage, BitfinexChannelPayload};
+use crate::{
+    event::{MarketEvent, MarketIter},
+    subscription::status::DerivativesStatus,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::de::{datetime_utc_from_epoch_duration, extract_next};
+use chrono::{DateTime, Utc};
+use serde::{de::IgnoredAny, Serialize};
+use std::time::Duration;
+
+/// Terse type alias for a [`Bitfinex`](super::Bitfinex) real-time derivatives status
+/// WebSocket message.
+pub type BitfinexDerivativesStatusMessage = BitfinexChannelMessage<BitfinexDerivativesStatus>;
+
+/// [`Bitfinex`](super::Bitfinex) real-time derivatives status message, containing the funding
+/// & pricing state of a derivative (eg/ "tBTCF0:USTF0").
+///
+/// ### Raw Payload Examples
+/// Format: \[MTS, _, DERIV_PRICE, SPOT_PRICE, _, INSURANCE_FUND_BALANCE, _,
+/// NEXT_FUNDING_EVT_MTS, NEXT_FUNDING_ACCRUED, NEXT_FUNDING_STEP, _, CURRENT_FUNDING, _, _,
+/// MARK_PRICE, _, _, OPEN_INTEREST, ...\]
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-status>
+/// ```json
+/// [335633,[1596124822000,null,0.896469,0.771,null,1010000,null,1596124800000,0.0001,1,null,0.00015,null,null,0.7712,null,null,1000,null,null,null,0.001,0.002]]
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitfinexDerivativesStatus {
+    pub time: DateTime<Utc>,
+    pub deriv_price: f64,
+    pub spot_price: f64,
+    pub next_funding_time: Option<DateTime<Utc>>,
+    pub current_funding: f64,
+    pub mark_price: f64,
+    pub open_interest: Option<f64>,
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, BitfinexDerivativesStatusMessage)>
+    for MarketIter<InstrumentKey, DerivativesStatus>
+{
+    fn from(
+        (exchange_id, instrument, message): (
+            ExchangeId,
+            InstrumentKey,
+            BitfinexDerivativesStatusMessage,
+        ),
+    ) -> Self {
+        let BitfinexChannelPayload::Data(status) = message.payload else {
+            return Self(vec![]);
+        };
+
+        Self(vec![Ok(MarketEvent {
+            time_exchange: status.time,
+            time_received: Utc::now(),
+            exchange: exchange_id,
+            instrument,
+            kind: DerivativesStatus {
+                mark_price: status.mark_price,
+                index_price: status.spot_price,
+                funding_rate: status.current_funding,
+                next_funding_time: status.next_funding_time,
+                open_interest: status.open_interest,
+            },
+        })])
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BitfinexDerivativesStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = BitfinexDerivativesStatus;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter
+                    .write_str("BitfinexDerivativesStatus struct from the Bitfinex WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // Status: [MTS, _, DERIV_PRICE, SPOT_PRICE, _, INSURANCE_FUND_BALANCE, _,
+                //          NEXT_FUNDING_EVT_MTS, NEXT_FUNDING_ACCRUED, NEXT_FUNDING_STEP, _,
+                //          CURRENT_FUNDING, _, _, MARK_PRICE, _, _, OPEN_INTEREST, ...]
+                let time = extract_next::<SeqAccessor, u64>(&mut seq, "MTS")?;
+                extract_next::<SeqAccessor, IgnoredAny>(&mut seq, "PLACEHOLDER")?;
+                let deriv_price = extract_next(&mut seq, "DERIV_PRICE")?;
+                let spot_price = extract_next(&mut seq, "SPOT_PRICE")?;
+                extract_next::<SeqAccessor, IgnoredAny>(&mut seq, "PLACEHOLDER")?;
+                extract_next::<SeqAccessor, IgnoredAny>(&mut seq, "INSURANCE_FUND_BALANCE")?;
+                extract_next::<SeqAccessor, IgnoredAny>(&mut seq, "PLACEHOLDER")?;
+                let next_funding_time =
+                    extract_next::<SeqAccessor, Option<u64>>(&mut seq, "NEXT_FUNDING_EVT_MTS")?;
+                extract_next::<SeqAccessor, IgnoredAny>(&mut seq, "NEXT_FUNDING_ACCRUED")?;
+                extract_next::<SeqAccessor, IgnoredAny>(&mut seq, "NEXT_FUNDING_STEP")?;
+                extract_next::<SeqAccessor, IgnoredAny>(&mut seq, "PLACEHOLDER")?;
+                let current_funding = extract_next(&mut seq, "CURRENT_FUNDING")?;
+                extract_next::<SeqAccessor, IgnoredAny>(&mut seq, "PLACEHOLDER")?;
+                extract_next::<SeqAccessor, IgnoredAny>(&mut seq, "PLACEHOLDER")?;
+                let mark_price = extract_next(&mut seq, "MARK_PRICE")?;
+                extract_next::<SeqAccessor, IgnoredAny>(&mut seq, "PLACEHOLDER")?;
+                extract_next::<SeqAccessor, IgnoredAny>(&mut seq, "PLACEHOLDER")?;
+                let open_interest = extract_next(&mut seq, "OPEN_INTEREST")?;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Bitfinex may add fields without warning
+                while seq.next_element::<IgnoredAny>()?.is_some() {}
+                Ok(BitfinexDerivativesStatus {
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(time)),
+                    deriv_price,
+                    spot_price,
+                    next_funding_time: next_funding_time.map(|next_funding_time| {
+                        datetime_utc_from_epoch_duration(Duration::from_millis(next_funding_time))
+                    }),
+                    current_funding,
+                    mark_price,
+                    open_interest,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the Bitfinex derivatives status payload
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_bitfinex_derivatives_status_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: BitfinexDerivativesStatusMessage,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: valid derivatives status
+                input: r#"[335633,[1596124822000,null,0.896469,0.771,null,1010000,null,1596124800000,0.0001,1,null,0.00015,null,null,0.7712,null,null,1000,null,null,null,0.001,0.002]]"#,
+                expected: BitfinexDerivativesStatusMessage {
+                    channel_id: 335633,
+                    payload: BitfinexChannelPayload::Data(BitfinexDerivativesStatus {
+                        time: DateTime::from_timestamp_millis(1596124822000).unwrap(),
+                        deriv_price: 0.896469,
+                        spot_price: 0.771,
+                        next_funding_time: Some(
+                            DateTime::from_timestamp_millis(1596124800000).unwrap(),
+                        ),
+                        current_funding: 0.00015,
+                        mark_price: 0.7712,
+                        open_interest: Some(1000.0),
+                    }),
+                },
+            },
+            TestCase {
+                // TC1: valid derivatives status with null open interest & funding time
+                input: r#"[335633,[1596124822000,null,0.896469,0.771,null,1010000,null,null,0,0,null,0,null,null,0.7712,null,null,null]]"#,
+                expected: BitfinexDerivativesStatusMessage {
+                    channel_id: 335633,
+                    payload: BitfinexChannelPayload::Data(BitfinexDerivativesStatus {
+                        time: DateTime::from_timestamp_millis(1596124822000).unwrap(),
+                        deriv_price: 0.896469,
+                        spot_price: 0.771,
+                        next_funding_time: None,
+                        current_funding: 0.0,
+                        mark_price: 0.7712,
+                        open_interest: None,
+                    }),
+                },
+            },
+            TestCase {
+                // TC2: valid Heartbeat
+                input: r#"[335633,"hb"]"#,
+                expected: BitfinexDerivativesStatusMessage {
+                    channel_id: 335633,
+                    payload: BitfinexChannelPayload::Heartbeat,
+                },
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual =
+                serde_json::from_str::<BitfinexDerivativesStatusMessage>(test.input).unwrap();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use super::{channel::BitfinexChannel, BOOK_PRECISION_RAW, STATUS_KEY_PREFIX};
 use barter_integration::{error::SocketError, Validator};
 use serde::{Deserialize, Serialize};
 
@@ -130,6 +131,30 @@ pub enum Status {
 /// }
 /// ```
 ///
+/// #### Subscription Raw Books Success
+/// ``` json
+/// {
+///   event: "subscribed",
+///   channel: "book",
+///   chanId: CHANNEL_ID,
+///   symbol: "tBTCUSD",
+///   prec: "R0",
+///   freq: "F0",
+///   len: "100",
+///   pair: "BTCUSD"
+/// }
+/// ```
+///
+/// #### Subscription Derivatives Status Success
+/// ``` json
+/// {
+///   event: "subscribed",
+///   channel: "status",
+///   chanId: CHANNEL_ID,
+///   key: "deriv:tBTCF0:USTF0"
+/// }
+/// ```
+///
 /// #### Subscription Failure
 /// ``` json
 /// {
@@ -140,10 +165,43 @@ pub enum Status {
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct BitfinexSubResponse {
     pub channel: String,
-    #[serde(rename = "symbol")]
+    #[serde(
+        rename = "symbol",
+        alias = "key",
+        deserialize_with = "de_sub_response_market"
+    )]
     pub market: String,
     #[serde(rename = "chanId")]
     pub channel_id: BitfinexChannelId,
+    #[serde(rename = "prec", default, skip_serializing_if = "Option::is_none")]
+    pub precision: Option<String>,
+}
+
+impl BitfinexSubResponse {
+    /// Determine the [`BitfinexChannel`] identifier this [`BitfinexSubResponse`] relates to.
+    ///
+    /// Raw books are subscribed to via the "book" channel with precision "R0", so they must be
+    /// distinguished from aggregated books using the [`BitfinexChannel::ORDER_BOOK_L3`]
+    /// identifier.
+    pub fn subscription_channel(&self) -> &str {
+        match (self.channel.as_str(), self.precision.as_deref()) {
+            ("book", Some(BOOK_PRECISION_RAW)) => BitfinexChannel::ORDER_BOOK_L3.as_ref(),
+            (channel, _) => channel,
+        }
+    }
+}
+
+/// Deserialize a [`BitfinexSubResponse`] market, removing the "deriv:" prefix of derivatives
+/// status keys (eg/ "deriv:tBTCF0:USTF0" -> "tBTCF0:USTF0").
+fn de_sub_response_market<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let market = String::deserialize(deserializer)?;
+    Ok(market
+        .strip_prefix(STATUS_KEY_PREFIX)
+        .map(String::from)
+        .unwrap_or(market))
 }
 
 /// [`Bitfinex`](super::Bitfinex) channel identifier that is used to identify the subscription
@@ -249,9 +307,30 @@ mod tests {
                     channel: "trades".to_string(),
                     channel_id: BitfinexChannelId(2203),
                     market: "tBTCUSD".to_owned(),
+                    precision: None,
+                })),
+            },
+            // TC3: successful raw books channel subscription
+            TestCase {
+                input: r#"{"event": "subscribed", "channel": "book", "chanId": 17470, "symbol": "tBTCUSD", "prec": "R0", "freq": "F0", "len": "100", "pair": "BTCUSD"}"#,
+                expected: Ok(BitfinexPlatformEvent::Subscribed(BitfinexSubResponse {
+                    channel: "book".to_string(),
+                    channel_id: BitfinexChannelId(17470),
+                    market: "tBTCUSD".to_owned(),
+                    precision: Some("R0".to_owned()),
+                })),
+            },
+            // TC4: successful derivatives status channel subscription
+            TestCase {
+                input: r#"{"event": "subscribed", "channel": "status", "chanId": 335633, "key": "deriv:tBTCF0:USTF0"}"#,
+                expected: Ok(BitfinexPlatformEvent::Subscribed(BitfinexSubResponse {
+                    channel: "status".to_string(),
+                    channel_id: BitfinexChannelId(335633),
+                    market: "tBTCF0:USTF0".to_owned(),
+                    precision: None,
                 })),
             },
-            // TC2: Input response is error
+            // TC5: Input response is error
             TestCase {
                 input: r#"{"event": "error", "msg": "Already subscribed", "code": 10202}"#,
                 expected: Ok(BitfinexPlatformEvent::Error(BitfinexError {
@@ -319,11 +398,13 @@ mod tests {
                     channel: "channel".to_string(),
                     market: "market".to_string(),
                     channel_id: BitfinexChannelId(1),
+                    precision: None,
                 }),
                 expected: Ok(BitfinexPlatformEvent::Subscribed(BitfinexSubResponse {
                     channel: "channel".to_string(),
                     market: "market".to_string(),
                     channel_id: BitfinexChannelId(1),
+                    precision: None,
                 })),
             },
             TestCase {
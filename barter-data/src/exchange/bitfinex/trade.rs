@@ -59,6 +59,7 @@ impl<InstrumentKey> From<(ExchangeId, InstrumentKey, BitfinexTrade)>
                 price: trade.price,
                 amount: trade.amount,
                 side: trade.side,
+                aggregate_count: None,
             },
         })])
     }
@@ -98,8 +98,8 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
                             // Subscription success
                             Ok(BitfinexPlatformEvent::Subscribed(response)) => {
                                 // Determine SubscriptionId associated with the success response
-                                let BitfinexSubResponse { channel, market, channel_id } = &response;
-                                let subscription_id = ExchangeSub::from((channel, market)).id();
+                                let BitfinexSubResponse { market, channel_id, .. } = &response;
+                                let subscription_id = ExchangeSub::from((response.subscription_channel(), market)).id();
 
                                 // Replace SubscriptionId with SubscriptionId(channel_id)
                                 if let Some(subscription) = instrument_map.0.remove(&subscription_id) {
@@ -0,0 +1,27 @@
+use crate::exchange::{upbit::Upbit, ExchangeServer};
+use barter_instrument::exchange::ExchangeId;
+
+/// [`BithumbSpot`] WebSocket server base url.
+///
+/// See docs: <https://apidocs.bithumb.com/reference/websocket-%EA%B8%B0%EB%B3%B8-%EC%A0%95%EB%B3%B4>
+pub const WEBSOCKET_BASE_URL_BITHUMB_SPOT: &str = "wss://ws-api.bithumb.com/websocket/v1";
+
+/// Bithumb spot exchange.
+///
+/// ### Notes
+/// The Bithumb v2 WebSocket API is compatible with the Upbit WebSocket API, so the generic
+/// [`Upbit`] [`Connector`](crate::exchange::Connector) is re-used with the
+/// [`BithumbServerSpot`].
+pub type BithumbSpot = Upbit<BithumbServerSpot>;
+
+/// Bithumb spot [`ExchangeServer`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct BithumbServerSpot;
+
+impl ExchangeServer for BithumbServerSpot {
+    const ID: ExchangeId = ExchangeId::Bithumb;
+
+    fn websocket_url() -> &'static str {
+        WEBSOCKET_BASE_URL_BITHUMB_SPOT
+    }
+}
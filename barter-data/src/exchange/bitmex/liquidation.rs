@@ -0,0 +1,193 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::bitmex::message::BitmexMessage,
+    subscription::liquidation::Liquidation,
+    Identifier,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::{subscription::SubscriptionId, Side};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use smol_str::format_smolstr;
+
+/// [`Bitmex`](super::Bitmex) real-time liquidations WebSocket message.
+///
+/// Only "insert" actions represent new liquidation orders. The initial "partial" snapshot, and
+/// any subsequent "update" or "delete" actions (eg/ the liquidation order being filled) are
+/// ignored to avoid yielding duplicate liquidations.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.bitmex.com/app/wsAPI#Subscriptions>
+/// #### Liquidation payload
+/// ```json
+/// {
+///     "table": "liquidation",
+///     "action": "insert",
+///     "data": [
+///         {
+///             "orderID": "a2a2ba53-5fb1-4a9f-b5bd-4e5e71a0a4cb",
+///             "symbol": "XBTUSD",
+///             "side": "Sell",
+///             "price": 24309.5,
+///             "leavesQty": 1200
+///         }
+///     ]
+/// }
+///```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(tag = "action", rename_all = "lowercase")]
+pub enum BitmexLiquidation {
+    Insert(BitmexMessage<BitmexLiquidationInner>),
+    #[serde(other)]
+    Other,
+}
+
+/// [`Bitmex`](super::Bitmex) liquidation order, where the side is that of the liquidation order
+/// (eg/ "Sell" when a long position is liquidated).
+///
+/// See [`BitmexLiquidation`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitmexLiquidationInner {
+    #[serde(rename = "orderID")]
+    pub order_id: String,
+    pub symbol: String,
+    pub side: Side,
+    pub price: f64,
+    #[serde(rename = "leavesQty")]
+    pub quantity: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for BitmexLiquidation {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            Self::Insert(message) => message.data.first().map(|liquidation| {
+                SubscriptionId(format_smolstr!("{}|{}", message.table, liquidation.symbol))
+            }),
+            Self::Other => None,
+        }
+    }
+}
+
+impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, BitmexLiquidation)>
+    for MarketIter<InstrumentKey, Liquidation>
+{
+    fn from(
+        (exchange, instrument, message): (ExchangeId, InstrumentKey, BitmexLiquidation),
+    ) -> Self {
+        let BitmexLiquidation::Insert(liquidations) = message else {
+            return Self(vec![]);
+        };
+
+        // Bitmex liquidations are not timestamped by the exchange
+        let time_received = Utc::now();
+
+        Self(
+            liquidations
+                .data
+                .into_iter()
+                .map(|liquidation| {
+                    Ok(MarketEvent {
+                        time_exchange: time_received,
+                        time_received,
+                        exchange,
+                        instrument: instrument.clone(),
+                        kind: Liquidation {
+                            side: liquidation.side,
+                            price: liquidation.price,
+                            quantity: liquidation.quantity,
+                            time: time_received,
+                        },
+                    })
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_bitmex_liquidation() {
+        struct TestCase {
+            input: &'static str,
+            expected: BitmexLiquidation,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: insert action is a new liquidation order
+                input: r#"
+                {
+                    "table": "liquidation",
+                    "action": "insert",
+                    "data": [
+                        {
+                            "orderID": "a2a2ba53-5fb1-4a9f-b5bd-4e5e71a0a4cb",
+                            "symbol": "XBTUSD",
+                            "side": "Sell",
+                            "price": 24309.5,
+                            "leavesQty": 1200
+                        }
+                    ]
+                }
+                "#,
+                expected: BitmexLiquidation::Insert(BitmexMessage {
+                    table: "liquidation".to_string(),
+                    data: vec![BitmexLiquidationInner {
+                        order_id: "a2a2ba53-5fb1-4a9f-b5bd-4e5e71a0a4cb".to_string(),
+                        symbol: "XBTUSD".to_string(),
+                        side: Side::Sell,
+                        price: 24309.5,
+                        quantity: 1200.0,
+                    }],
+                }),
+            },
+            TestCase {
+                // TC1: partial snapshot action is ignored
+                input: r#"{"table":"liquidation","action":"partial","keys":["orderID"],"data":[]}"#,
+                expected: BitmexLiquidation::Other,
+            },
+            TestCase {
+                // TC2: update action is ignored
+                input: r#"
+                {
+                    "table": "liquidation",
+                    "action": "update",
+                    "data": [
+                        {
+                            "orderID": "a2a2ba53-5fb1-4a9f-b5bd-4e5e71a0a4cb",
+                            "symbol": "XBTUSD",
+                            "leavesQty": 600
+                        }
+                    ]
+                }
+                "#,
+                expected: BitmexLiquidation::Other,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<BitmexLiquidation>(test.input).unwrap();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_bitmex_liquidation_id() {
+        let input = BitmexLiquidation::Insert(BitmexMessage {
+            table: "liquidation".to_string(),
+            data: vec![BitmexLiquidationInner {
+                order_id: "a2a2ba53-5fb1-4a9f-b5bd-4e5e71a0a4cb".to_string(),
+                symbol: "XBTUSD".to_string(),
+                side: Side::Buy,
+                price: 24309.5,
+                quantity: 1200.0,
+            }],
+        });
+
+        assert_eq!(input.id(), Some(SubscriptionId::from("liquidation|XBTUSD")));
+        assert_eq!(BitmexLiquidation::Other.id(), None);
+    }
+}
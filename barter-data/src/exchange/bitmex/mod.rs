@@ -1,15 +1,15 @@
 use crate::{
     exchange::{
         bitmex::{
-            channel::BitmexChannel, market::BitmexMarket, subscription::BitmexSubResponse,
-            trade::BitmexTrade,
+            channel::BitmexChannel, liquidation::BitmexLiquidation, market::BitmexMarket,
+            subscription::BitmexSubResponse, trade::BitmexTrade,
         },
         subscription::ExchangeSub,
         Connector, StreamSelector,
     },
     instrument::InstrumentData,
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{trade::PublicTrades, Map},
+    subscription::{liquidation::Liquidations, trade::PublicTrades, Map},
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream, NoInitialSnapshots,
 };
@@ -23,6 +23,9 @@ use url::Url;
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
+/// Liquidation types for [`Bitmex`].
+pub mod liquidation;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
@@ -86,6 +89,16 @@ where
         ExchangeWsStream<StatelessTransformer<Self, Instrument::Key, PublicTrades, BitmexTrade>>;
 }
 
+impl<Instrument> StreamSelector<Instrument, Liquidations> for Bitmex
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Key, Liquidations, BitmexLiquidation>,
+    >;
+}
+
 impl<'de> serde::Deserialize<'de> for Bitmex {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -69,6 +69,7 @@ impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, BitmexTrade)>
                             price: trade.price,
                             amount: trade.amount,
                             side: trade.side,
+                            aggregate_count: None,
                         },
                     })
                 })
@@ -0,0 +1,406 @@
+use super::{
+    super::{
+        market::BitstampMarket,
+        message::{de_str_epoch_us_as_datetime_utc, BitstampMessage},
+        Bitstamp,
+    },
+    BitstampLevel,
+};
+use crate::{
+    books::OrderBook,
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::{config::ConnectorConfig, Connector},
+    instrument::InstrumentData,
+    subscription::{
+        book::{OrderBookEvent, OrderBooksL2},
+        Map, Subscription,
+    },
+    transformer::ExchangeTransformer,
+    Identifier, SnapshotFetcher,
+};
+use async_trait::async_trait;
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage, Transformer};
+use chrono::{DateTime, Utc};
+use derive_more::Constructor;
+use futures_util::future::try_join_all;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use tokio::sync::mpsc::UnboundedSender;
+
+/// [`Bitstamp`] HTTP OrderBook L2 snapshot url.
+///
+/// See docs: <https://www.bitstamp.net/api/#tag/Order-book>
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_BITSTAMP: &str = "https://www.bitstamp.net/api/v2/order_book";
+
+#[derive(Debug)]
+pub struct BitstampOrderBooksL2SnapshotFetcher;
+
+impl SnapshotFetcher<Bitstamp, OrderBooksL2> for BitstampOrderBooksL2SnapshotFetcher {
+    fn fetch_snapshots<Instrument>(
+        config: &ConnectorConfig,
+        subscriptions: &[Subscription<Bitstamp, Instrument, OrderBooksL2>],
+    ) -> impl Future<Output = Result<Vec<MarketEvent<Instrument::Key, OrderBookEvent>>, SocketError>>
+           + Send
+    where
+        Instrument: InstrumentData,
+        Subscription<Bitstamp, Instrument, OrderBooksL2>: Identifier<BitstampMarket>,
+    {
+        let l2_snapshot_futures = subscriptions.iter().map(|subscription| {
+            // Construct initial OrderBook snapshot GET url
+            let market = subscription.id();
+            let snapshot_url = format!("{}/{}/", HTTP_BOOK_L2_SNAPSHOT_URL_BITSTAMP, market.0);
+
+            let http_client = config.http_client();
+
+            async move {
+                // Fetch initial OrderBook snapshot via HTTP
+                let snapshot = http_client?
+                    .get(snapshot_url)
+                    .send()
+                    .await
+                    .map_err(SocketError::Http)?
+                    .json::<BitstampOrderBookL2Data>()
+                    .await
+                    .map_err(SocketError::Http)?;
+
+                Ok(MarketEvent {
+                    time_exchange: snapshot.time,
+                    time_received: Utc::now(),
+                    exchange: ExchangeId::Bitstamp,
+                    instrument: subscription.instrument.key().clone(),
+                    kind: OrderBookEvent::Snapshot(OrderBook::from(snapshot)),
+                })
+            }
+        });
+
+        try_join_all(l2_snapshot_futures)
+    }
+}
+
+#[derive(Debug, Constructor)]
+pub struct BitstampOrderBookL2Meta<InstrumentKey> {
+    pub key: InstrumentKey,
+    pub sequencer: BitstampOrderBookL2Sequencer,
+}
+
+#[derive(Debug)]
+pub struct BitstampOrderBooksL2Transformer<InstrumentKey> {
+    instrument_map: Map<BitstampOrderBookL2Meta<InstrumentKey>>,
+}
+
+#[async_trait]
+impl<InstrumentKey> ExchangeTransformer<Bitstamp, InstrumentKey, OrderBooksL2>
+    for BitstampOrderBooksL2Transformer<InstrumentKey>
+where
+    InstrumentKey: Clone + PartialEq + Send + Sync,
+{
+    async fn init(
+        instrument_map: Map<InstrumentKey>,
+        initial_snapshots: &[MarketEvent<InstrumentKey, OrderBookEvent>],
+        _: UnboundedSender<WsMessage>,
+    ) -> Result<Self, DataError> {
+        let instrument_map = instrument_map
+            .0
+            .into_iter()
+            .map(|(sub_id, instrument_key)| {
+                let snapshot = initial_snapshots
+                    .iter()
+                    .find(|snapshot| snapshot.instrument == instrument_key)
+                    .ok_or_else(|| DataError::InitialSnapshotMissing(sub_id.clone()))?;
+
+                let OrderBookEvent::Snapshot(snapshot) = &snapshot.kind else {
+                    return Err(DataError::InitialSnapshotInvalid(
+                        "expected OrderBookEvent::Snapshot but found OrderBookEvent::Update",
+                    ));
+                };
+
+                let book_meta = BitstampOrderBookL2Meta::new(
+                    instrument_key,
+                    BitstampOrderBookL2Sequencer::new(snapshot.sequence),
+                );
+
+                Ok((sub_id, book_meta))
+            })
+            .collect::<Result<Map<_>, _>>()?;
+
+        Ok(Self { instrument_map })
+    }
+}
+
+impl<InstrumentKey> Transformer for BitstampOrderBooksL2Transformer<InstrumentKey>
+where
+    InstrumentKey: Clone,
+{
+    type Error = DataError;
+    type Input = BitstampOrderBookL2Update;
+    type Output = MarketEvent<InstrumentKey, OrderBookEvent>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        // Determine if the message has an identifiable SubscriptionId
+        let subscription_id = match input.id() {
+            Some(subscription_id) => subscription_id,
+            None => return vec![],
+        };
+
+        // Find Instrument associated with Input and transform
+        let instrument = match self.instrument_map.find_mut(&subscription_id) {
+            Ok(instrument) => instrument,
+            Err(unidentifiable) => return vec![Err(DataError::Socket(unidentifiable))],
+        };
+
+        // Drop any updates that are already reflected in the OrderBook
+        let Some(valid_update) = instrument.sequencer.validate_sequence(input) else {
+            return vec![];
+        };
+
+        MarketIter::<InstrumentKey, OrderBookEvent>::from((
+            Bitstamp::ID,
+            instrument.key.clone(),
+            valid_update,
+        ))
+        .0
+    }
+}
+
+/// [`Bitstamp`] [`BitstampOrderBookL2Sequencer`].
+///
+/// Bitstamp: How To Manage A Local OrderBook Correctly
+///
+/// 1. Subscribe to the "diff_order_book" channel & buffer the events received.
+/// 2. Get a snapshot from <https://www.bitstamp.net/api/v2/order_book/btcusd/>.
+/// 3. Drop any event where the microtimestamp is <= the microtimestamp of the snapshot.
+/// 4. The data in each event is the absolute quantity for a price level.
+/// 5. If the quantity is 0, remove the price level.
+///
+/// Notes:
+///  - Bitstamp does not publish update identifiers, so the event microtimestamp is used as the
+///    [`OrderBook`] sequence. Gaps in the diff feed can therefore not be detected.
+///
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+#[derive(Debug)]
+pub struct BitstampOrderBookL2Sequencer {
+    pub updates_processed: u64,
+    pub last_update_id: u64,
+}
+
+impl BitstampOrderBookL2Sequencer {
+    /// Construct a new [`Self`] with the provided initial snapshot microtimestamp.
+    pub fn new(last_update_id: u64) -> Self {
+        Self {
+            updates_processed: 0,
+            last_update_id,
+        }
+    }
+
+    /// Drop any update that is not more recent than the last processed update (or initial
+    /// snapshot), returning the update if it should be applied.
+    pub fn validate_sequence(
+        &mut self,
+        update: BitstampOrderBookL2Update,
+    ) -> Option<BitstampOrderBookL2Update> {
+        // 3. Drop any event where the microtimestamp is <= the microtimestamp of the snapshot
+        let update_id = update.data.sequence();
+        if update_id <= self.last_update_id {
+            return None;
+        }
+
+        // Update metadata
+        self.updates_processed += 1;
+        self.last_update_id = update_id;
+
+        Some(update)
+    }
+}
+
+/// Terse type alias for a [`Bitstamp`] OrderBook Level2 deltas WebSocket message.
+pub type BitstampOrderBookL2Update = BitstampMessage<BitstampOrderBookL2Data>;
+
+/// [`Bitstamp`] OrderBook Level2 data, used by both the HTTP snapshot & the WebSocket deltas.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+/// #### WebSocket Deltas
+/// ```json
+/// {
+///     "data": {
+///         "timestamp": "1643643584",
+///         "microtimestamp": "1643643584684047",
+///         "bids": [["36983.00", "0.11386208"]],
+///         "asks": [["36990.00", "0"]]
+///     },
+///     "channel": "diff_order_book_btcusd",
+///     "event": "data"
+/// }
+/// ```
+///
+/// #### HTTP Snapshot
+/// See docs: <https://www.bitstamp.net/api/#tag/Order-book>
+/// ```json
+/// {
+///     "timestamp": "1643643584",
+///     "microtimestamp": "1643643584684047",
+///     "bids": [["36983.00", "0.11386208"], ["36982.00", "0.5"]],
+///     "asks": [["36984.00", "1.2"]]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitstampOrderBookL2Data {
+    #[serde(
+        rename = "microtimestamp",
+        deserialize_with = "de_str_epoch_us_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub bids: Vec<BitstampLevel>,
+    pub asks: Vec<BitstampLevel>,
+}
+
+impl BitstampOrderBookL2Data {
+    /// [`OrderBook`] sequence of this [`BitstampOrderBookL2Data`], being its epoch
+    /// microseconds timestamp.
+    pub fn sequence(&self) -> u64 {
+        u64::try_from(self.time.timestamp_micros()).unwrap_or_default()
+    }
+}
+
+impl From<BitstampOrderBookL2Data> for OrderBook {
+    fn from(data: BitstampOrderBookL2Data) -> Self {
+        Self::new(data.sequence(), Some(data.time), data.bids, data.asks)
+    }
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, BitstampOrderBookL2Update)>
+    for MarketIter<InstrumentKey, OrderBookEvent>
+{
+    fn from(
+        (exchange_id, instrument, update): (ExchangeId, InstrumentKey, BitstampOrderBookL2Update),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            time_exchange: update.data.time,
+            time_received: Utc::now(),
+            exchange: exchange_id,
+            instrument,
+            kind: OrderBookEvent::Update(OrderBook::from(update.data)),
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::books::Level;
+    use barter_integration::subscription::SubscriptionId;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_de_bitstamp_order_book_l2_update() {
+        let input = r#"
+        {
+            "data": {
+                "timestamp": "1643643584",
+                "microtimestamp": "1643643584684047",
+                "bids": [["36983.00", "0.11386208"]],
+                "asks": [["36990.00", "0"]]
+            },
+            "channel": "diff_order_book_btcusd",
+            "event": "data"
+        }
+        "#;
+
+        assert_eq!(
+            serde_json::from_str::<BitstampOrderBookL2Update>(input).unwrap(),
+            BitstampOrderBookL2Update {
+                subscription_id: SubscriptionId::from("diff_order_book|btcusd"),
+                data: BitstampOrderBookL2Data {
+                    time: DateTime::from_timestamp_micros(1643643584684047).unwrap(),
+                    bids: vec![BitstampLevel {
+                        price: dec!(36983.00),
+                        amount: dec!(0.11386208),
+                    }],
+                    asks: vec![BitstampLevel {
+                        price: dec!(36990.00),
+                        amount: dec!(0),
+                    }],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_update_barter_order_book_with_sequenced_updates() {
+        fn update(micros: i64, bids: Vec<BitstampLevel>) -> BitstampOrderBookL2Update {
+            BitstampOrderBookL2Update {
+                subscription_id: SubscriptionId::from("subscription_id"),
+                data: BitstampOrderBookL2Data {
+                    time: DateTime::from_timestamp_micros(micros).unwrap(),
+                    bids,
+                    asks: vec![],
+                },
+            }
+        }
+
+        struct TestCase {
+            sequencer: BitstampOrderBookL2Sequencer,
+            book: OrderBook,
+            input_update: BitstampOrderBookL2Update,
+            expected: OrderBook,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: drop update that is not more recent than the snapshot
+                sequencer: BitstampOrderBookL2Sequencer::new(100),
+                book: OrderBook::new(100, None, vec![Level::new(50, 1)], vec![Level::new(60, 1)]),
+                input_update: update(
+                    100,
+                    vec![BitstampLevel {
+                        price: dec!(50),
+                        amount: dec!(0),
+                    }],
+                ),
+                expected: OrderBook::new(
+                    100,
+                    None,
+                    vec![Level::new(50, 1)],
+                    vec![Level::new(60, 1)],
+                ),
+            },
+            TestCase {
+                // TC1: apply more recent update
+                sequencer: BitstampOrderBookL2Sequencer::new(100),
+                book: OrderBook::new(100, None, vec![Level::new(50, 1)], vec![Level::new(60, 1)]),
+                input_update: update(
+                    101,
+                    vec![
+                        BitstampLevel {
+                            price: dec!(50),
+                            amount: dec!(0),
+                        },
+                        BitstampLevel {
+                            price: dec!(49),
+                            amount: dec!(2),
+                        },
+                    ],
+                ),
+                expected: OrderBook::new(
+                    101,
+                    None,
+                    vec![Level::new(49, 2)],
+                    vec![Level::new(60, 1)],
+                ),
+            },
+        ];
+
+        for (index, mut test) in tests.into_iter().enumerate() {
+            if let Some(valid_update) = test.sequencer.validate_sequence(test.input_update) {
+                let mut barter_update = OrderBook::from(valid_update.data);
+                barter_update.time_engine = None;
+                test.book.update(OrderBookEvent::Update(barter_update));
+            }
+
+            assert_eq!(test.book, test.expected, "TC{index} failed");
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::books::Level;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Level 2 OrderBook types.
+pub mod l2;
+
+/// [`Bitstamp`](super::Bitstamp) OrderBook level.
+///
+/// #### Raw Payload Examples
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+/// ```json
+/// ["11394.52", "0.00300000"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitstampLevel {
+    #[serde(with = "rust_decimal::serde::str")]
+    pub price: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub amount: Decimal,
+}
+
+impl From<BitstampLevel> for Level {
+    fn from(level: BitstampLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
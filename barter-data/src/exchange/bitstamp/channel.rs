@@ -0,0 +1,51 @@
+use super::{market::BitstampMarket, Bitstamp};
+use crate::{
+    subscription::{book::OrderBooksL2, trade::PublicTrades, Subscription},
+    Identifier,
+};
+use serde::Serialize;
+
+/// Type that defines how to translate a Barter [`Subscription`] into a [`Bitstamp`] channel to
+/// be subscribed to.
+///
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct BitstampChannel(pub &'static str);
+
+impl BitstampChannel {
+    /// [`Bitstamp`] real-time trades channel.
+    ///
+    /// See docs: <https://www.bitstamp.net/websocket/v2/>
+    pub const TRADES: Self = Self("live_trades");
+
+    /// [`Bitstamp`] OrderBook Level2 channel, publishing the changes of each updated level.
+    ///
+    /// See docs: <https://www.bitstamp.net/websocket/v2/>
+    pub const ORDER_BOOK_L2: Self = Self("diff_order_book");
+
+    /// Subscription channel name of this [`BitstampChannel`] for the provided
+    /// [`BitstampMarket`].
+    ///
+    /// eg/ "live_trades_btcusd"
+    pub fn topic(&self, market: &BitstampMarket) -> String {
+        format!("{}_{}", self.0, market.as_ref())
+    }
+}
+
+impl<Instrument> Identifier<BitstampChannel> for Subscription<Bitstamp, Instrument, PublicTrades> {
+    fn id(&self) -> BitstampChannel {
+        BitstampChannel::TRADES
+    }
+}
+
+impl<Instrument> Identifier<BitstampChannel> for Subscription<Bitstamp, Instrument, OrderBooksL2> {
+    fn id(&self) -> BitstampChannel {
+        BitstampChannel::ORDER_BOOK_L2
+    }
+}
+
+impl AsRef<str> for BitstampChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
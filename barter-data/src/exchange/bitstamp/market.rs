@@ -0,0 +1,42 @@
+use super::Bitstamp;
+use crate::{instrument::MarketInstrumentData, subscription::Subscription, Identifier};
+use barter_instrument::{asset::symbol::Symbol, instrument::Instrument, Keyed};
+use serde::{Deserialize, Serialize};
+use smol_str::{format_smolstr, SmolStr, StrExt};
+
+/// Type that defines how to translate a Barter [`Subscription`] into a [`Bitstamp`] market that
+/// can be subscribed to.
+///
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BitstampMarket(pub SmolStr);
+
+impl<Kind> Identifier<BitstampMarket> for Subscription<Bitstamp, Instrument, Kind> {
+    fn id(&self) -> BitstampMarket {
+        bitstamp_market(&self.instrument.base, &self.instrument.quote)
+    }
+}
+
+impl<InstrumentKey, Kind> Identifier<BitstampMarket>
+    for Subscription<Bitstamp, Keyed<InstrumentKey, Instrument>, Kind>
+{
+    fn id(&self) -> BitstampMarket {
+        bitstamp_market(&self.instrument.value.base, &self.instrument.value.quote)
+    }
+}
+
+impl<Kind> Identifier<BitstampMarket> for Subscription<Bitstamp, MarketInstrumentData, Kind> {
+    fn id(&self) -> BitstampMarket {
+        BitstampMarket(self.instrument.name_exchange.clone())
+    }
+}
+
+impl AsRef<str> for BitstampMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+fn bitstamp_market(base: &Symbol, quote: &Symbol) -> BitstampMarket {
+    BitstampMarket(format_smolstr!("{base}{quote}").to_lowercase_smolstr())
+}
@@ -0,0 +1,115 @@
+use crate::{exchange::subscription::ExchangeSub, Identifier};
+use barter_integration::subscription::SubscriptionId;
+use chrono::{DateTime, Utc};
+use serde::{
+    de::{Error, Unexpected},
+    Deserialize, Serialize,
+};
+
+/// Generic [`Bitstamp`](super::Bitstamp) market data message, wrapping the data of a subscribed
+/// channel.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+/// #### Trade
+/// ```json
+/// {
+///     "data": {
+///         "id": 122513385,
+///         "timestamp": "1597158445",
+///         "amount": 0.003,
+///         "amount_str": "0.00300000",
+///         "price": 11394.52,
+///         "price_str": "11394.52",
+///         "type": 0,
+///         "microtimestamp": "1597158445393000",
+///         "buy_order_id": 1273551540060160,
+///         "sell_order_id": 1273551539281920
+///     },
+///     "channel": "live_trades_btcusd",
+///     "event": "trade"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitstampMessage<T> {
+    #[serde(alias = "channel", deserialize_with = "de_message_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub data: T,
+}
+
+impl<T> Identifier<Option<SubscriptionId>> for BitstampMessage<T> {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+/// Deserialize a [`BitstampMessage`] "channel" (eg/ "live_trades_btcusd") as the associated
+/// [`SubscriptionId`].
+///
+/// eg/ "live_trades|btcusd"
+pub fn de_message_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let input = <&str as Deserialize>::deserialize(deserializer)?;
+
+    // Channel names themselves contain an '_' (eg/ "live_trades"), but markets do not
+    input
+        .rsplit_once('_')
+        .map(|(channel, market)| ExchangeSub::from((channel, market)).id())
+        .ok_or_else(|| {
+            Error::invalid_value(
+                Unexpected::Str(input),
+                &"channel with pattern: <channel>_<market>",
+            )
+        })
+}
+
+/// Deserialize a `String` epoch microseconds timestamp (eg/ "1597158445393000") as a
+/// `DateTime<Utc>`.
+pub fn de_str_epoch_us_as_datetime_utc<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let micros = barter_integration::de::de_str::<D, i64>(deserializer)?;
+    DateTime::from_timestamp_micros(micros)
+        .ok_or_else(|| Error::custom(format!("invalid epoch microseconds: {micros}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_message_subscription_id() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<SubscriptionId>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: trades channel
+                input: r#""live_trades_btcusd""#,
+                expected: Some(SubscriptionId::from("live_trades|btcusd")),
+            },
+            TestCase {
+                // TC1: OrderBookL2 channel
+                input: r#""diff_order_book_ethusd""#,
+                expected: Some(SubscriptionId::from("diff_order_book|ethusd")),
+            },
+            TestCase {
+                // TC2: channel without market
+                input: r#""heartbeat""#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual =
+                de_message_subscription_id(&mut serde_json::Deserializer::from_str(test.input))
+                    .ok();
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use self::{
+    book::l2::{BitstampOrderBooksL2SnapshotFetcher, BitstampOrderBooksL2Transformer},
+    channel::BitstampChannel,
+    market::BitstampMarket,
+    subscription::BitstampSubResponse,
+    trade::BitstampTrade,
+};
+use crate::{
+    exchange::{Connector, ExchangeSub, StreamSelector},
+    instrument::InstrumentData,
+    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscription::{book::OrderBooksL2, trade::PublicTrades},
+    transformer::stateless::StatelessTransformer,
+    ExchangeWsStream, NoInitialSnapshots,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_macro::{DeExchange, SerExchange};
+use serde_json::json;
+use url::Url;
+
+/// OrderBook types for [`Bitstamp`].
+pub mod book;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
+pub mod channel;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
+pub mod market;
+
+/// Generic [`BitstampMessage<T>`](message::BitstampMessage) type common to every [`Bitstamp`]
+/// market data stream.
+pub mod message;
+
+/// [`Subscription`](crate::subscription::Subscription) response type and response
+/// [`Validator`](barter_integration::Validator) for [`Bitstamp`].
+pub mod subscription;
+
+/// Public trade types for [`Bitstamp`].
+pub mod trade;
+
+/// [`Bitstamp`] server base url.
+///
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+pub const BASE_URL_BITSTAMP: &str = "wss://ws.bitstamp.net";
+
+/// [`Bitstamp`] spot exchange.
+///
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DeExchange, SerExchange,
+)]
+pub struct Bitstamp;
+
+impl Connector for Bitstamp {
+    const ID: ExchangeId = ExchangeId::Bitstamp;
+    type Channel = BitstampChannel;
+    type Market = BitstampMarket;
+    type Subscriber = WebSocketSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = BitstampSubResponse;
+
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(BASE_URL_BITSTAMP).map_err(SocketError::UrlParse)
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        exchange_subs
+            .into_iter()
+            .map(|ExchangeSub { channel, market }| {
+                WsMessage::text(
+                    json!({
+                        "event": "bts:subscribe",
+                        "data": {
+                            "channel": channel.topic(&market),
+                        },
+                    })
+                    .to_string(),
+                )
+            })
+            .collect()
+    }
+}
+
+impl<Instrument> StreamSelector<Instrument, PublicTrades> for Bitstamp
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Key, PublicTrades, BitstampTrade>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL2> for Bitstamp
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = BitstampOrderBooksL2SnapshotFetcher;
+    type Stream = ExchangeWsStream<BitstampOrderBooksL2Transformer<Instrument::Key>>;
+}
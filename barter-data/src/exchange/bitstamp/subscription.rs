@@ -0,0 +1,95 @@
+use barter_integration::{error::SocketError, Validator};
+use serde::{Deserialize, Serialize};
+
+/// [`Bitstamp`](super::Bitstamp) WebSocket subscription response.
+///
+/// ### Raw Payload Examples
+/// #### Subscription Ok Response
+/// ```json
+/// {
+///     "event": "bts:subscription_succeeded",
+///     "channel": "live_trades_btcusd",
+///     "data": {}
+/// }
+/// ```
+///
+/// #### Subscription Error Response
+/// ```json
+/// {
+///     "event": "bts:error",
+///     "channel": "",
+///     "data": {
+///         "code": null,
+///         "message": "Bad subscription string."
+///     }
+/// }
+/// ```
+///
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(tag = "event")]
+pub enum BitstampSubResponse {
+    #[serde(rename = "bts:subscription_succeeded")]
+    Subscribed { channel: String },
+    #[serde(rename = "bts:error")]
+    Error { data: BitstampError },
+}
+
+/// [`Bitstamp`](super::Bitstamp) error message.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BitstampError {
+    pub message: String,
+}
+
+impl Validator for BitstampSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match self {
+            Self::Subscribed { .. } => Ok(self),
+            Self::Error { data } => Err(SocketError::Subscribe(format!(
+                "received failure subscription response: {}",
+                data.message
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bitstamp_sub_response() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<bool>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: subscription success
+                input: r#"{"event": "bts:subscription_succeeded", "channel": "live_trades_btcusd", "data": {}}"#,
+                expected: Some(true),
+            },
+            TestCase {
+                // TC1: subscription failure
+                input: r#"{"event": "bts:error", "channel": "", "data": {"code": null, "message": "Bad subscription string."}}"#,
+                expected: Some(false),
+            },
+            TestCase {
+                // TC2: market data is not a subscription response
+                input: r#"{"event": "trade", "channel": "live_trades_btcusd", "data": {}}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<BitstampSubResponse>(test.input)
+                .ok()
+                .map(|response| response.validate().is_ok());
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
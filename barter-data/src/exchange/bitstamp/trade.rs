@@ -0,0 +1,119 @@
+use super::message::{de_str_epoch_us_as_datetime_utc, BitstampMessage};
+use crate::{
+    event::{MarketEvent, MarketIter},
+    subscription::trade::PublicTrade,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::Side;
+use chrono::{DateTime, Utc};
+use serde::{
+    de::{Error, Unexpected},
+    Deserialize, Serialize,
+};
+
+/// Terse type alias for a [`Bitstamp`](super::Bitstamp) real-time trade WebSocket message.
+pub type BitstampTrade = BitstampMessage<BitstampTradeData>;
+
+/// [`Bitstamp`](super::Bitstamp) real-time trade.
+///
+/// See [`BitstampMessage`] for full raw payload examples.
+///
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitstampTradeData {
+    pub id: u64,
+    #[serde(
+        rename = "price_str",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub price: f64,
+    #[serde(
+        rename = "amount_str",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub amount: f64,
+    #[serde(alias = "type", deserialize_with = "de_side")]
+    pub side: Side,
+    #[serde(
+        alias = "microtimestamp",
+        deserialize_with = "de_str_epoch_us_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, BitstampTrade)>
+    for MarketIter<InstrumentKey, PublicTrade>
+{
+    fn from((exchange_id, instrument, trade): (ExchangeId, InstrumentKey, BitstampTrade)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            time_exchange: trade.data.time,
+            time_received: Utc::now(),
+            exchange: exchange_id,
+            instrument,
+            kind: PublicTrade {
+                id: trade.data.id.to_string(),
+                price: trade.data.price,
+                amount: trade.data.amount,
+                side: trade.data.side,
+                aggregate_count: None,
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`BitstampTradeData`] "type" (0 => buy, 1 => sell) as the associated [`Side`].
+pub fn de_side<'de, D>(deserializer: D) -> Result<Side, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    match <u8 as Deserialize>::deserialize(deserializer)? {
+        0 => Ok(Side::Buy),
+        1 => Ok(Side::Sell),
+        other => Err(Error::invalid_value(
+            Unexpected::Unsigned(u64::from(other)),
+            &"0 (buy) or 1 (sell)",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::subscription::SubscriptionId;
+
+    #[test]
+    fn test_de_bitstamp_trade() {
+        let input = r#"
+        {
+            "data": {
+                "id": 122513385,
+                "timestamp": "1597158445",
+                "amount": 0.003,
+                "amount_str": "0.00300000",
+                "price": 11394.52,
+                "price_str": "11394.52",
+                "type": 1,
+                "microtimestamp": "1597158445393000",
+                "buy_order_id": 1273551540060160,
+                "sell_order_id": 1273551539281920
+            },
+            "channel": "live_trades_btcusd",
+            "event": "trade"
+        }
+        "#;
+
+        assert_eq!(
+            serde_json::from_str::<BitstampTrade>(input).unwrap(),
+            BitstampTrade {
+                subscription_id: SubscriptionId::from("live_trades|btcusd"),
+                data: BitstampTradeData {
+                    id: 122513385,
+                    price: 11394.52,
+                    amount: 0.003,
+                    side: Side::Sell,
+                    time: DateTime::from_timestamp_micros(1597158445393000).unwrap(),
+                },
+            }
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    exchange::bybit::Bybit,
-    subscription::{trade::PublicTrades, Subscription},
+    exchange::bybit::{futures::BybitPerpetualsUsd, Bybit},
+    subscription::{liquidation::Liquidations, trade::PublicTrades, Subscription},
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +17,11 @@ impl BybitChannel {
     ///
     /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/trade>
     pub const TRADES: Self = Self("publicTrade");
+
+    /// [`Bybit`] real-time liquidations channel name.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/all-liquidation>
+    pub const LIQUIDATIONS: Self = Self("allLiquidation");
 }
 
 impl<Server, Instrument> Identifier<BybitChannel>
@@ -27,6 +32,14 @@ impl<Server, Instrument> Identifier<BybitChannel>
     }
 }
 
+impl<Instrument> Identifier<BybitChannel>
+    for Subscription<BybitPerpetualsUsd, Instrument, Liquidations>
+{
+    fn id(&self) -> BybitChannel {
+        BybitChannel::LIQUIDATIONS
+    }
+}
+
 impl AsRef<str> for BybitChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -0,0 +1,150 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::bybit::message::BybitMessage,
+    subscription::liquidation::Liquidation,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::Side;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
+
+/// Terse type alias for a [`BybitPerpetualsUsd`](super::BybitPerpetualsUsd) real-time
+/// liquidations WebSocket message.
+pub type BybitLiquidation = BybitMessage<Vec<BybitLiquidationInner>>;
+
+/// [`BybitPerpetualsUsd`](super::BybitPerpetualsUsd) liquidation, where the side is that of the
+/// liquidated position.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/all-liquidation>
+/// ```json
+/// {
+///     "topic": "allLiquidation.ROSEUSDT",
+///     "type": "snapshot",
+///     "ts": 1739502303204,
+///     "data": [
+///         {
+///             "T": 1739502302929,
+///             "s": "ROSEUSDT",
+///             "S": "Sell",
+///             "v": "20000",
+///             "p": "0.04499"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitLiquidationInner {
+    #[serde(
+        alias = "T",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+
+    #[serde(rename = "s")]
+    pub market: SmolStr,
+
+    #[serde(rename = "S")]
+    pub position_side: Side,
+
+    #[serde(alias = "v", deserialize_with = "barter_integration::de::de_str")]
+    pub quantity: f64,
+
+    #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+}
+
+impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, BybitLiquidation)>
+    for MarketIter<InstrumentKey, Liquidation>
+{
+    fn from(
+        (exchange, instrument, message): (ExchangeId, InstrumentKey, BybitLiquidation),
+    ) -> Self {
+        let BybitMessage::Payload(liquidations) = message else {
+            return Self(vec![]);
+        };
+
+        Self(
+            liquidations
+                .data
+                .into_iter()
+                .map(|liquidation| {
+                    Ok(MarketEvent {
+                        time_exchange: liquidation.time,
+                        time_received: Utc::now(),
+                        exchange,
+                        instrument: instrument.clone(),
+                        kind: Liquidation {
+                            // Normalise to the side of the liquidation order, which closes
+                            // the liquidated position (eg/ a long is liquidated by a Sell)
+                            side: match liquidation.position_side {
+                                Side::Buy => Side::Sell,
+                                Side::Sell => Side::Buy,
+                            },
+                            price: liquidation.price,
+                            quantity: liquidation.quantity,
+                            time: liquidation.time,
+                        },
+                    })
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Identifier;
+    use barter_integration::{de::datetime_utc_from_epoch_duration, subscription::SubscriptionId};
+    use std::time::Duration;
+
+    #[test]
+    fn test_bybit_liquidation() {
+        let input = r#"
+        {
+            "topic": "allLiquidation.ROSEUSDT",
+            "type": "snapshot",
+            "ts": 1739502303204,
+            "data": [
+                {
+                    "T": 1739502302929,
+                    "s": "ROSEUSDT",
+                    "S": "Buy",
+                    "v": "20000",
+                    "p": "0.04499"
+                }
+            ]
+        }
+        "#;
+
+        let message = serde_json::from_str::<BybitLiquidation>(input).unwrap();
+        assert_eq!(
+            message.id(),
+            Some(SubscriptionId::from("allLiquidation|ROSEUSDT"))
+        );
+
+        let time = datetime_utc_from_epoch_duration(Duration::from_millis(1739502302929));
+        let actual = MarketIter::<&str, Liquidation>::from((
+            ExchangeId::BybitPerpetualsUsd,
+            "instrument",
+            message,
+        ))
+        .0
+        .into_iter()
+        .map(|event| event.unwrap().kind)
+        .collect::<Vec<_>>();
+
+        // Liquidated long position is normalised to a Sell liquidation order
+        assert_eq!(
+            actual,
+            vec![Liquidation {
+                side: Side::Sell,
+                price: 0.04499,
+                quantity: 20000.0,
+                time,
+            }]
+        );
+    }
+}
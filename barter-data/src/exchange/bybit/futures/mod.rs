@@ -1,11 +1,25 @@
+use self::liquidation::BybitLiquidation;
 use super::{Bybit, ExchangeServer};
+use crate::{
+    exchange::StreamSelector, instrument::InstrumentData, subscription::liquidation::Liquidations,
+    transformer::stateless::StatelessTransformer, ExchangeWsStream, NoInitialSnapshots,
+};
 use barter_instrument::exchange::ExchangeId;
 
+/// Liquidation types for [`BybitPerpetualsUsd`].
+pub mod liquidation;
+
 /// [`BybitPerpetualsUsd`] WebSocket server base url.
 ///
 /// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect>
 pub const WEBSOCKET_BASE_URL_BYBIT_PERPETUALS_USD: &str = "wss://stream.bybit.com/v5/public/linear";
 
+/// [`BybitPerpetualsUsd`] testnet WebSocket server base url.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect>
+pub const WEBSOCKET_BASE_URL_BYBIT_PERPETUALS_USD_TESTNET: &str =
+    "wss://stream-testnet.bybit.com/v5/public/linear";
+
 /// [`Bybit`] perpetual exchange.
 pub type BybitPerpetualsUsd = Bybit<BybitServerPerpetualsUsd>;
 
@@ -19,4 +33,18 @@ impl ExchangeServer for BybitServerPerpetualsUsd {
     fn websocket_url() -> &'static str {
         WEBSOCKET_BASE_URL_BYBIT_PERPETUALS_USD
     }
+
+    fn websocket_url_testnet() -> Option<&'static str> {
+        Some(WEBSOCKET_BASE_URL_BYBIT_PERPETUALS_USD_TESTNET)
+    }
+}
+
+impl<Instrument> StreamSelector<Instrument, Liquidations> for BybitPerpetualsUsd
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Key, Liquidations, BybitLiquidation>,
+    >;
 }
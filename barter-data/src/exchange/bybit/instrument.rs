@@ -0,0 +1,298 @@
+use crate::instrument::metadata::InstrumentMetadata;
+use barter_instrument::{
+    asset::symbol::Symbol,
+    exchange::ExchangeId,
+    instrument::{
+        kind::InstrumentKind,
+        spec::{
+            InstrumentSpec, InstrumentSpecNotional, InstrumentSpecPrice, InstrumentSpecQuantity,
+            OrderQuantityUnits,
+        },
+        Instrument,
+    },
+};
+use barter_integration::{
+    de::{de_str, de_str_optional},
+    error::SocketError,
+};
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
+
+/// [`Bybit`](super::Bybit) HTTP instruments info url. The `category` query parameter (eg/
+/// "spot", "linear") must be appended.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/market/instrument>
+pub const HTTP_INSTRUMENTS_INFO_URL_BYBIT: &str =
+    "https://api.bybit.com/v5/market/instruments-info";
+
+/// [`Bybit`](super::Bybit) instruments info response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/market/instrument>
+/// ```json
+/// {
+///     "retCode": 0,
+///     "retMsg": "OK",
+///     "result": {
+///         "category": "linear",
+///         "list": [
+///             {
+///                 "symbol": "BTCUSDT", "contractType": "LinearPerpetual", "status": "Trading",
+///                 "baseCoin": "BTC", "quoteCoin": "USDT",
+///                 "priceFilter": {"minPrice": "0.10", "maxPrice": "199999.80", "tickSize": "0.10"},
+///                 "lotSizeFilter": {"maxOrderQty": "100.000", "minOrderQty": "0.001", "qtyStep": "0.001", "minNotionalValue": "5"}
+///             }
+///         ]
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitInstrumentsInfo {
+    pub ret_code: i64,
+    #[serde(default)]
+    pub ret_msg: String,
+    pub result: BybitInstrumentsResult,
+}
+
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct BybitInstrumentsResult {
+    #[serde(default)]
+    pub list: Vec<BybitInstrument>,
+}
+
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitInstrument {
+    pub symbol: SmolStr,
+    pub status: SmolStr,
+    pub base_coin: SmolStr,
+    pub quote_coin: SmolStr,
+    /// Only present for derivative categories, eg/ "LinearPerpetual".
+    #[serde(default)]
+    pub contract_type: Option<SmolStr>,
+    pub price_filter: BybitPriceFilter,
+    pub lot_size_filter: BybitLotSizeFilter,
+}
+
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitPriceFilter {
+    #[serde(deserialize_with = "de_str")]
+    pub tick_size: f64,
+    #[serde(default, deserialize_with = "de_str_optional")]
+    pub min_price: Option<f64>,
+}
+
+/// Spot instruments define a `basePrecision` & `minOrderAmt`, whereas derivative instruments
+/// define a `qtyStep` & `minNotionalValue`.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitLotSizeFilter {
+    #[serde(deserialize_with = "de_str")]
+    pub min_order_qty: f64,
+    #[serde(default, deserialize_with = "de_str_optional")]
+    pub max_order_qty: Option<f64>,
+    #[serde(default, deserialize_with = "de_str_optional")]
+    pub base_precision: Option<f64>,
+    #[serde(default, deserialize_with = "de_str_optional")]
+    pub qty_step: Option<f64>,
+    #[serde(default, deserialize_with = "de_str_optional")]
+    pub min_order_amt: Option<f64>,
+    #[serde(default, deserialize_with = "de_str_optional")]
+    pub max_order_amt: Option<f64>,
+    #[serde(default, deserialize_with = "de_str_optional")]
+    pub min_notional_value: Option<f64>,
+}
+
+impl BybitInstrumentsInfo {
+    /// Map every actively trading spot & perpetual [`BybitInstrument`] into
+    /// [`InstrumentMetadata`].
+    pub fn into_metadata(
+        self,
+        exchange: ExchangeId,
+    ) -> Result<Vec<InstrumentMetadata>, SocketError> {
+        if self.ret_code != 0 {
+            return Err(SocketError::Exchange(format!(
+                "Bybit instruments info error code {}: {}",
+                self.ret_code, self.ret_msg
+            )));
+        }
+
+        Ok(self
+            .result
+            .list
+            .into_iter()
+            .filter(|instrument| instrument.status == "Trading")
+            .filter_map(|instrument| instrument.into_metadata(exchange))
+            .collect())
+    }
+}
+
+impl BybitInstrument {
+    fn into_metadata(self, exchange: ExchangeId) -> Option<InstrumentMetadata> {
+        let kind = match self.contract_type.as_deref() {
+            None | Some("") => InstrumentKind::Spot,
+            Some("LinearPerpetual") | Some("InversePerpetual") => InstrumentKind::Perpetual,
+            Some(_) => return None,
+        };
+
+        let lot = self.lot_size_filter;
+
+        Some(InstrumentMetadata {
+            exchange,
+            instrument: Instrument::new(self.base_coin.as_str(), self.quote_coin.as_str(), kind),
+            name_exchange: self.symbol,
+            spec: InstrumentSpec {
+                price: InstrumentSpecPrice {
+                    min: self
+                        .price_filter
+                        .min_price
+                        .unwrap_or(self.price_filter.tick_size),
+                    tick_size: self.price_filter.tick_size,
+                    tick_size_schedule: None,
+                },
+                quantity: InstrumentSpecQuantity {
+                    unit: OrderQuantityUnits::Asset(Symbol::new(self.base_coin.as_str())),
+                    min: lot.min_order_qty,
+                    increment: lot.qty_step.or(lot.base_precision)?,
+                    max: lot.max_order_qty,
+                    contract_multiplier: None,
+                },
+                notional: InstrumentSpecNotional {
+                    min: lot
+                        .min_notional_value
+                        .or(lot.min_order_amt)
+                        .unwrap_or_default(),
+                    max: lot.max_order_amt,
+                },
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bybit_instruments_info_into_metadata() {
+        struct TestCase {
+            exchange: ExchangeId,
+            input: &'static str,
+            expected: Vec<InstrumentMetadata>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: spot instrument w/ basePrecision & minOrderAmt
+                exchange: ExchangeId::BybitSpot,
+                input: r#"
+                {
+                    "retCode": 0,
+                    "retMsg": "OK",
+                    "result": {
+                        "category": "spot",
+                        "list": [
+                            {
+                                "symbol": "BTCUSDT", "baseCoin": "BTC", "quoteCoin": "USDT",
+                                "status": "Trading",
+                                "lotSizeFilter": {
+                                    "basePrecision": "0.000001", "quotePrecision": "0.00000001",
+                                    "minOrderQty": "0.000048", "maxOrderQty": "71.73956243",
+                                    "minOrderAmt": "1", "maxOrderAmt": "2000000"
+                                },
+                                "priceFilter": {"tickSize": "0.01"}
+                            }
+                        ]
+                    }
+                }
+                "#,
+                expected: vec![InstrumentMetadata {
+                    exchange: ExchangeId::BybitSpot,
+                    name_exchange: SmolStr::new("BTCUSDT"),
+                    instrument: Instrument::new("btc", "usdt", InstrumentKind::Spot),
+                    spec: InstrumentSpec {
+                        price: InstrumentSpecPrice {
+                            min: 0.01,
+                            tick_size: 0.01,
+                            tick_size_schedule: None,
+                        },
+                        quantity: InstrumentSpecQuantity {
+                            unit: OrderQuantityUnits::Asset(Symbol::new("btc")),
+                            min: 0.000048,
+                            increment: 0.000001,
+                            max: Some(71.73956243),
+                            contract_multiplier: None,
+                        },
+                        notional: InstrumentSpecNotional {
+                            min: 1.0,
+                            max: Some(2000000.0),
+                        },
+                    },
+                }],
+            },
+            TestCase {
+                // TC1: linear perpetual w/ qtyStep & minNotionalValue, PreLaunch is skipped
+                exchange: ExchangeId::BybitPerpetualsUsd,
+                input: r#"
+                {
+                    "retCode": 0,
+                    "retMsg": "OK",
+                    "result": {
+                        "category": "linear",
+                        "list": [
+                            {
+                                "symbol": "BTCUSDT", "contractType": "LinearPerpetual",
+                                "status": "Trading", "baseCoin": "BTC", "quoteCoin": "USDT",
+                                "priceFilter": {"minPrice": "0.10", "maxPrice": "199999.80", "tickSize": "0.10"},
+                                "lotSizeFilter": {
+                                    "maxOrderQty": "100.000", "minOrderQty": "0.001",
+                                    "qtyStep": "0.001", "minNotionalValue": "5"
+                                }
+                            },
+                            {
+                                "symbol": "NEWUSDT", "contractType": "LinearPerpetual",
+                                "status": "PreLaunch", "baseCoin": "NEW", "quoteCoin": "USDT",
+                                "priceFilter": {"minPrice": "0.0001", "tickSize": "0.0001"},
+                                "lotSizeFilter": {"minOrderQty": "1", "qtyStep": "1"}
+                            }
+                        ]
+                    }
+                }
+                "#,
+                expected: vec![InstrumentMetadata {
+                    exchange: ExchangeId::BybitPerpetualsUsd,
+                    name_exchange: SmolStr::new("BTCUSDT"),
+                    instrument: Instrument::new("btc", "usdt", InstrumentKind::Perpetual),
+                    spec: InstrumentSpec {
+                        price: InstrumentSpecPrice {
+                            min: 0.1,
+                            tick_size: 0.1,
+                            tick_size_schedule: None,
+                        },
+                        quantity: InstrumentSpecQuantity {
+                            unit: OrderQuantityUnits::Asset(Symbol::new("btc")),
+                            min: 0.001,
+                            increment: 0.001,
+                            max: Some(100.0),
+                            contract_multiplier: None,
+                        },
+                        notional: InstrumentSpecNotional {
+                            min: 5.0,
+                            max: None,
+                        },
+                    },
+                }],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<BybitInstrumentsInfo>(test.input)
+                .unwrap()
+                .into_metadata(test.exchange)
+                .unwrap();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use crate::{
     event::MarketIter,
-    exchange::bybit::{channel::BybitChannel, subscription::BybitResponse, trade::BybitTrade},
+    exchange::{
+        bybit::{channel::BybitChannel, subscription::BybitResponse, trade::BybitTradeInner},
+        ExchangeSub,
+    },
     subscription::trade::PublicTrade,
     Identifier,
 };
@@ -11,15 +14,97 @@ use serde::{
     de::{Error, Unexpected},
     Deserialize, Serialize,
 };
+use smol_str::SmolStr;
 
-/// [`Bybit`](super::Bybit) websocket message supports both [`BybitTrade`](BybitTrade) and [`BybitResponse`](BybitResponse) .
-#[derive(Debug, Serialize, Deserialize)]
+/// [`Bybit`](super::Bybit) websocket message supports both [`BybitPayload<T>`](BybitPayload)
+/// (eg/ [`BybitTrade`](super::trade::BybitTrade)) and [`BybitResponse`](BybitResponse).
+///
+/// Deserialised in a single pass over the message fields, rather than as an untagged enum
+/// (which buffers the entire message to retry each variant).
+#[derive(PartialEq, Debug, Serialize)]
 #[serde(untagged)]
-pub enum BybitMessage {
+pub enum BybitMessage<T = Vec<BybitTradeInner>> {
     Response(BybitResponse),
-    Trade(BybitTrade),
+    Payload(BybitPayload<T>),
+}
+
+impl<'de, T> Deserialize<'de> for BybitMessage<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct BybitMessageVisitor<T>(std::marker::PhantomData<T>);
+
+        impl<'de, T> serde::de::Visitor<'de> for BybitMessageVisitor<T>
+        where
+            T: Deserialize<'de>,
+        {
+            type Value = BybitMessage<T>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BybitResponse or BybitPayload")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut success = None;
+                let mut ret_msg = None;
+                let mut subscription_id = None;
+                let mut r#type = None;
+                let mut time = None;
+                let mut data = None;
+
+                while let Some(key) = map.next_key::<&str>()? {
+                    match key {
+                        "success" => success = Some(map.next_value()?),
+                        "ret_msg" => ret_msg = Some(map.next_value()?),
+                        "topic" => subscription_id = Some(map.next_value::<Topic>()?.0),
+                        "type" => r#type = Some(map.next_value()?),
+                        "ts" => time = Some(map.next_value::<Timestamp>()?.0),
+                        "data" => data = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+
+                if let Some(success) = success {
+                    return Ok(BybitMessage::Response(BybitResponse {
+                        success,
+                        ret_msg: ret_msg.unwrap_or_default(),
+                    }));
+                }
+
+                Ok(BybitMessage::Payload(BybitPayload {
+                    subscription_id: subscription_id
+                        .ok_or_else(|| Error::missing_field("topic"))?,
+                    r#type: r#type.ok_or_else(|| Error::missing_field("type"))?,
+                    time: time.ok_or_else(|| Error::missing_field("ts"))?,
+                    data: data.ok_or_else(|| Error::missing_field("data"))?,
+                }))
+            }
+        }
+
+        deserializer.deserialize_map(BybitMessageVisitor(std::marker::PhantomData))
+    }
 }
 
+/// [`BybitPayload`] "topic" field, deserialised as it's [`SubscriptionId`].
+#[derive(Deserialize)]
+struct Topic(#[serde(deserialize_with = "de_message_subscription_id")] SubscriptionId);
+
+/// [`BybitPayload`] "ts" field, deserialised as a `DateTime<Utc>`.
+#[derive(Deserialize)]
+struct Timestamp(
+    #[serde(deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc")]
+    DateTime<Utc>,
+);
+
 /// ### Raw Payload Examples
 /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/trade>
 /// #### Spot Side::Buy Trade
@@ -48,7 +133,7 @@ pub struct BybitPayload<T> {
     pub subscription_id: SubscriptionId,
 
     #[serde(rename = "type")]
-    pub r#type: String,
+    pub r#type: SmolStr,
 
     #[serde(
         alias = "ts",
@@ -70,10 +155,12 @@ where
     let mut tokens = input.split('.');
 
     match (tokens.next(), tokens.next(), tokens.next()) {
-        (Some("publicTrade"), Some(market), None) => Ok(SubscriptionId::from(format!(
-            "{}|{market}",
-            BybitChannel::TRADES.0
-        ))),
+        (Some("publicTrade"), Some(market), None) => {
+            Ok(ExchangeSub::from((BybitChannel::TRADES, market)).id())
+        }
+        (Some("allLiquidation"), Some(market), None) => {
+            Ok(ExchangeSub::from((BybitChannel::LIQUIDATIONS, market)).id())
+        }
         _ => Err(Error::invalid_value(
             Unexpected::Str(input),
             &"invalid message type expected pattern: <type>.<symbol>",
@@ -81,10 +168,10 @@ where
     }
 }
 
-impl Identifier<Option<SubscriptionId>> for BybitMessage {
+impl<T> Identifier<Option<SubscriptionId>> for BybitMessage<T> {
     fn id(&self) -> Option<SubscriptionId> {
         match self {
-            BybitMessage::Trade(trade) => Some(trade.subscription_id.clone()),
+            BybitMessage::Payload(payload) => Some(payload.subscription_id.clone()),
             _ => None,
         }
     }
@@ -96,7 +183,7 @@ impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, BybitMessage)>
     fn from((exchange_id, instrument, message): (ExchangeId, InstrumentKey, BybitMessage)) -> Self {
         match message {
             BybitMessage::Response(_) => Self(vec![]),
-            BybitMessage::Trade(trade) => Self::from((exchange_id, instrument, trade)),
+            BybitMessage::Payload(trade) => Self::from((exchange_id, instrument, trade)),
         }
     }
 }
@@ -151,5 +238,117 @@ mod tests {
                 }
             }
         }
+
+        #[test]
+        fn test_bybit_message() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BybitMessage<serde_json::Value>, SocketError>,
+            }
+
+            let tests = vec![
+                // TC0: input with "success" is deserialised as a BybitMessage::Response
+                TestCase {
+                    input: r#"
+                        {
+                            "success": true,
+                            "ret_msg": "subscribe",
+                            "conn_id": "2324d924-aa4d-45b0-a858-7b8be29ab52b",
+                            "req_id": "10001",
+                            "op": "subscribe"
+                        }
+                    "#,
+                    expected: Ok(BybitMessage::Response(BybitResponse {
+                        success: true,
+                        ret_msg: BybitReturnMessage::Subscribe,
+                    })),
+                },
+                // TC1: input without "ret_msg" is deserialised with the default BybitReturnMessage
+                TestCase {
+                    input: r#"{"success": false}"#,
+                    expected: Ok(BybitMessage::Response(BybitResponse {
+                        success: false,
+                        ret_msg: BybitReturnMessage::None,
+                    })),
+                },
+                // TC2: input with "topic" is deserialised as a BybitMessage::Payload
+                TestCase {
+                    input: r#"
+                        {
+                            "topic": "publicTrade.BTCUSDT",
+                            "type": "snapshot",
+                            "ts": 1672304486868,
+                            "data": [{"s": "BTCUSDT"}]
+                        }
+                    "#,
+                    expected: Ok(BybitMessage::Payload(BybitPayload {
+                        subscription_id: SubscriptionId::from("publicTrade|BTCUSDT"),
+                        r#type: SmolStr::new("snapshot"),
+                        time: DateTime::from_timestamp_millis(1672304486868).unwrap(),
+                        data: serde_json::json!([{"s": "BTCUSDT"}]),
+                    })),
+                },
+                // TC3: input with unknown extra fields is deserialised, ignoring them
+                TestCase {
+                    input: r#"
+                        {
+                            "id": "unknown",
+                            "topic": "allLiquidation.BTCUSDT",
+                            "nested": {"unknown": [1, 2, 3]},
+                            "type": "snapshot",
+                            "ts": 1672304486868,
+                            "data": {"s": "BTCUSDT"},
+                            "cs": 123456
+                        }
+                    "#,
+                    expected: Ok(BybitMessage::Payload(BybitPayload {
+                        subscription_id: SubscriptionId::from("allLiquidation|BTCUSDT"),
+                        r#type: SmolStr::new("snapshot"),
+                        time: DateTime::from_timestamp_millis(1672304486868).unwrap(),
+                        data: serde_json::json!({"s": "BTCUSDT"}),
+                    })),
+                },
+                // TC4: input payload missing "topic" is invalid
+                TestCase {
+                    input: r#"{"type": "snapshot", "ts": 1672304486868, "data": []}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "".to_string(),
+                        item: "".to_string(),
+                    }),
+                },
+                // TC5: input payload missing "data" is invalid
+                TestCase {
+                    input: r#"{"topic": "publicTrade.BTCUSDT", "type": "snapshot", "ts": 1672304486868}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "".to_string(),
+                        item: "".to_string(),
+                    }),
+                },
+                // TC6: input payload with an unsupported "topic" is invalid
+                TestCase {
+                    input: r#"{"topic": "orderbook.50.BTCUSDT", "type": "snapshot", "ts": 1672304486868, "data": []}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "".to_string(),
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BybitMessage<serde_json::Value>>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
     }
 }
@@ -14,7 +14,9 @@ use crate::{
     ExchangeWsStream, NoInitialSnapshots,
 };
 use barter_instrument::exchange::ExchangeId;
-use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_integration::{
+    environment::Environment, error::SocketError, protocol::websocket::WsMessage,
+};
 use serde::de::{Error, Unexpected};
 use std::{fmt::Debug, marker::PhantomData, time::Duration};
 use tokio::time;
@@ -28,6 +30,11 @@ pub mod channel;
 /// [`BybitFuturesUsd`](futures::BybitPerpetualsUsd).
 pub mod futures;
 
+/// Instruments info types used to fetch the
+/// [`InstrumentMetadata`](crate::instrument::metadata::InstrumentMetadata) of [`Bybit`] spot &
+/// perpetual markets.
+pub mod instrument;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
@@ -74,6 +81,10 @@ where
         Url::parse(Server::websocket_url()).map_err(SocketError::UrlParse)
     }
 
+    fn url_for(environment: Environment) -> Result<Url, SocketError> {
+        Url::parse(Server::websocket_url_for(environment)?).map_err(SocketError::UrlParse)
+    }
+
     fn ping_interval() -> Option<PingInterval> {
         Some(PingInterval {
             interval: time::interval(Duration::from_millis(5_000)),
@@ -6,6 +6,12 @@ use barter_instrument::exchange::ExchangeId;
 /// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect>
 pub const WEBSOCKET_BASE_URL_BYBIT_SPOT: &str = "wss://stream.bybit.com/v5/public/spot";
 
+/// [`BybitSpot`] testnet WebSocket server base url.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect>
+pub const WEBSOCKET_BASE_URL_BYBIT_SPOT_TESTNET: &str =
+    "wss://stream-testnet.bybit.com/v5/public/spot";
+
 /// [`Bybit`] spot exchange.
 pub type BybitSpot = Bybit<BybitServerSpot>;
 
@@ -19,4 +25,8 @@ impl ExchangeServer for BybitServerSpot {
     fn websocket_url() -> &'static str {
         WEBSOCKET_BASE_URL_BYBIT_SPOT
     }
+
+    fn websocket_url_testnet() -> Option<&'static str> {
+        Some(WEBSOCKET_BASE_URL_BYBIT_SPOT_TESTNET)
+    }
 }
@@ -41,6 +41,7 @@ pub enum BybitReturnMessage {
     Subscribe,
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for BybitReturnMessage {
     fn default() -> Self {
         Self::None
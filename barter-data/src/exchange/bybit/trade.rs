@@ -7,6 +7,7 @@ use barter_instrument::exchange::ExchangeId;
 use barter_integration::Side;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
 
 /// Terse type alias for an [`BybitTrade`](BybitTradeInner) real-time trades WebSocket message.
 pub type BybitTrade = BybitPayload<Vec<BybitTradeInner>>;
@@ -35,7 +36,7 @@ pub struct BybitTradeInner {
     pub time: DateTime<Utc>,
 
     #[serde(rename = "s")]
-    pub market: String,
+    pub market: SmolStr,
 
     #[serde(rename = "S")]
     pub side: Side,
@@ -69,6 +70,7 @@ impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, BybitTrade)>
                             price: trade.price,
                             amount: trade.amount,
                             side: trade.side,
+                            aggregate_count: None,
                         },
                     })
                 })
@@ -115,7 +117,7 @@ mod tests {
                         time: datetime_utc_from_epoch_duration(Duration::from_millis(
                             1672304486865,
                         )),
-                        market: "BTCUSDT".to_string(),
+                        market: SmolStr::new_static("BTCUSDT"),
                         side: Side::Buy,
                         amount: 0.001,
                         price: 16578.50,
@@ -140,7 +142,7 @@ mod tests {
                         time: datetime_utc_from_epoch_duration(Duration::from_millis(
                             1672304486865,
                         )),
-                        market: "BTCUSDT".to_string(),
+                        market: SmolStr::new_static("BTCUSDT"),
                         side: Side::Sell,
                         amount: 0.001,
                         price: 16578.50,
@@ -226,7 +228,7 @@ mod tests {
                     "#,
                     expected: Ok(BybitTrade {
                         subscription_id: SubscriptionId("publicTrade|BTCUSDT".to_smolstr()),
-                        r#type: "snapshot".to_string(),
+                        r#type: SmolStr::new_static("snapshot"),
                         time: datetime_utc_from_epoch_duration(Duration::from_millis(
                             1672304486868,
                         )),
@@ -235,7 +237,7 @@ mod tests {
                                 time: datetime_utc_from_epoch_duration(Duration::from_millis(
                                     1672304486865,
                                 )),
-                                market: "BTCUSDT".to_string(),
+                                market: SmolStr::new_static("BTCUSDT"),
                                 side: Side::Buy,
                                 amount: 0.001,
                                 price: 16578.50,
@@ -245,7 +247,7 @@ mod tests {
                                 time: datetime_utc_from_epoch_duration(Duration::from_millis(
                                     1672304486865,
                                 )),
-                                market: "BTCUSDT".to_string(),
+                                market: SmolStr::new_static("BTCUSDT"),
                                 side: Side::Sell,
                                 amount: 0.001,
                                 price: 16578.50,
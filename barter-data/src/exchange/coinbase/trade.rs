@@ -63,6 +63,7 @@ impl<InstrumentKey> From<(ExchangeId, InstrumentKey, CoinbaseTrade)>
                 price: trade.price,
                 amount: trade.amount,
                 side: trade.side,
+                aggregate_count: None,
             },
         })])
     }
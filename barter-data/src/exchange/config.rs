@@ -0,0 +1,46 @@
+use barter_integration::{
+    environment::Environment, error::SocketError, protocol::proxy::ProxyConfig,
+};
+use serde::{Deserialize, Serialize};
+
+/// Connection configuration of an exchange's market streams, carried from the
+/// [`StreamBuilder`](crate::streams::builder::StreamBuilder) (or
+/// [`DynamicStreams`](crate::streams::builder::dynamic::DynamicStreams)) to every connection of
+/// it's [`Connector`](super::Connector).
+#[derive(Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct ConnectorConfig {
+    /// [`Environment`] the market streams connect to, defaulting to [`Environment::Mainnet`].
+    #[serde(default)]
+    pub environment: Environment,
+    /// Optional [`ProxyConfig`] that the market streams (WebSocket & REST snapshot requests) are
+    /// routed through, defaulting to a direct connection.
+    #[serde(default)]
+    pub proxy: Option<ProxyConfig>,
+}
+
+impl ConnectorConfig {
+    /// Construct a new [`ConnectorConfig`] connecting directly to the provided [`Environment`].
+    pub fn new(environment: Environment) -> Self {
+        Self {
+            environment,
+            proxy: None,
+        }
+    }
+
+    /// Route the market streams through the provided [`ProxyConfig`].
+    pub fn with_proxy(self, proxy: ProxyConfig) -> Self {
+        Self {
+            proxy: Some(proxy),
+            ..self
+        }
+    }
+
+    /// HTTP client for REST requests of the market streams, routed through the
+    /// [`ProxyConfig`] if any.
+    pub fn http_client(&self) -> Result<reqwest::Client, SocketError> {
+        match &self.proxy {
+            Some(proxy) => proxy.http_client(),
+            None => Ok(reqwest::Client::new()),
+        }
+    }
+}
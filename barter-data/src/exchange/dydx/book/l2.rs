@@ -0,0 +1,144 @@
+use super::{super::message::DydxMessage, DydxLevel};
+use crate::{
+    books::OrderBook,
+    event::{MarketEvent, MarketIter},
+    subscription::book::OrderBookEvent,
+};
+use barter_instrument::exchange::ExchangeId;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// [`Dydx`](super::super::Dydx) OrderBook Level2 contents of a
+/// [`DydxChannel::ORDER_BOOK_L2`](super::super::channel::DydxChannel::ORDER_BOOK_L2) message.
+///
+/// The "subscribed" message contains the full OrderBook snapshot, and each "channel_data"
+/// message contains the changed levels, where the size is the absolute quantity of the level
+/// (0 meaning the level is removed). The connection `message_id` is used as the [`OrderBook`]
+/// sequence.
+///
+/// See [`DydxMessage`] for full raw payload examples.
+///
+/// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_websocket#orders>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DydxOrderBookL2 {
+    #[serde(default)]
+    pub bids: Vec<DydxLevel>,
+    #[serde(default)]
+    pub asks: Vec<DydxLevel>,
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, DydxMessage<DydxOrderBookL2>)>
+    for MarketIter<InstrumentKey, OrderBookEvent>
+{
+    fn from(
+        (exchange_id, instrument, message): (
+            ExchangeId,
+            InstrumentKey,
+            DydxMessage<DydxOrderBookL2>,
+        ),
+    ) -> Self {
+        let (message, is_snapshot) = match message {
+            DydxMessage::Subscribed(message) => (message, true),
+            DydxMessage::ChannelData(message) => (message, false),
+            DydxMessage::Other => return Self(vec![]),
+        };
+
+        let book = OrderBook::new(
+            message.message_id,
+            None,
+            message.contents.bids,
+            message.contents.asks,
+        );
+
+        // dYdX OrderBook messages are not timestamped by the exchange
+        let time_received = Utc::now();
+
+        Self(vec![Ok(MarketEvent {
+            time_exchange: time_received,
+            time_received,
+            exchange: exchange_id,
+            instrument,
+            kind: if is_snapshot {
+                OrderBookEvent::Snapshot(book)
+            } else {
+                OrderBookEvent::Update(book)
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::books::Level;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_dydx_order_book_l2_into_order_book_event() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<OrderBookEvent>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: subscribed message is the OrderBook snapshot
+                input: r#"
+                {
+                    "type": "subscribed",
+                    "connection_id": "1",
+                    "message_id": 1,
+                    "channel": "v4_orderbook",
+                    "id": "BTC-USD",
+                    "contents": {
+                        "bids": [{"price": "65000", "size": "1.5"}, {"price": "64999", "size": "2"}],
+                        "asks": [{"price": "65001", "size": "0.25"}]
+                    }
+                }
+                "#,
+                expected: Some(OrderBookEvent::Snapshot(OrderBook::new(
+                    1,
+                    None,
+                    vec![
+                        Level::new(dec!(65000), dec!(1.5)),
+                        Level::new(dec!(64999), dec!(2)),
+                    ],
+                    vec![Level::new(dec!(65001), dec!(0.25))],
+                ))),
+            },
+            TestCase {
+                // TC1: channel_data message is an OrderBook update w/ only bids changed
+                input: r#"
+                {
+                    "type": "channel_data",
+                    "connection_id": "1",
+                    "message_id": 5,
+                    "channel": "v4_orderbook",
+                    "id": "BTC-USD",
+                    "version": "1.0.0",
+                    "contents": {"bids": [["65000", "0"]]}
+                }
+                "#,
+                expected: Some(OrderBookEvent::Update(OrderBook::new(
+                    5,
+                    None,
+                    vec![Level::new(65000, 0)],
+                    Vec::<Level>::new(),
+                ))),
+            },
+            TestCase {
+                // TC2: connected message is ignored
+                input: r#"{"type": "connected", "connection_id": "1", "message_id": 0}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let message = serde_json::from_str::<DydxMessage<DydxOrderBookL2>>(test.input).unwrap();
+            let MarketIter(events) =
+                MarketIter::<(), OrderBookEvent>::from((ExchangeId::Dydx, (), message));
+            let actual = events.into_iter().next().map(|event| event.unwrap().kind);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
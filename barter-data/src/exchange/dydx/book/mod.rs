@@ -0,0 +1,90 @@
+use crate::books::Level;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Level 2 OrderBook types.
+pub mod l2;
+
+/// [`Dydx`](super::Dydx) OrderBook level.
+///
+/// ### Notes
+/// Snapshot levels are objects, whereas update levels are `[price, size]` arrays, so both
+/// representations are supported.
+///
+/// #### Raw Payload Examples
+/// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_websocket#orders>
+/// ```json
+/// {"price": "65000", "size": "1.5"}
+/// ```
+///
+/// ```json
+/// ["65000", "1.5"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(from = "DydxLevelRepr")]
+pub struct DydxLevel {
+    pub price: Decimal,
+    pub amount: Decimal,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DydxLevelRepr {
+    Object {
+        #[serde(with = "rust_decimal::serde::str")]
+        price: Decimal,
+        #[serde(with = "rust_decimal::serde::str")]
+        size: Decimal,
+    },
+    Array(
+        #[serde(with = "rust_decimal::serde::str")] Decimal,
+        #[serde(with = "rust_decimal::serde::str")] Decimal,
+    ),
+}
+
+impl From<DydxLevelRepr> for DydxLevel {
+    fn from(repr: DydxLevelRepr) -> Self {
+        match repr {
+            DydxLevelRepr::Object { price, size } | DydxLevelRepr::Array(price, size) => Self {
+                price,
+                amount: size,
+            },
+        }
+    }
+}
+
+impl From<DydxLevel> for Level {
+    fn from(level: DydxLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use rust_decimal_macros::dec;
+
+        #[test]
+        fn test_dydx_level() {
+            let expected = DydxLevel {
+                price: dec!(65000),
+                amount: dec!(1.5),
+            };
+
+            assert_eq!(
+                serde_json::from_str::<DydxLevel>(r#"{"price": "65000", "size": "1.5"}"#).unwrap(),
+                expected,
+            );
+            assert_eq!(
+                serde_json::from_str::<DydxLevel>(r#"["65000", "1.5"]"#).unwrap(),
+                expected,
+            );
+        }
+    }
+}
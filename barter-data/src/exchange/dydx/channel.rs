@@ -0,0 +1,44 @@
+use super::Dydx;
+use crate::{
+    subscription::{book::OrderBooksL2, trade::PublicTrades, Subscription},
+    Identifier,
+};
+use serde::Serialize;
+
+/// Type that defines how to translate a Barter [`Subscription`] into a [`Dydx`] channel to be
+/// subscribed to.
+///
+/// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_websocket>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct DydxChannel(pub &'static str);
+
+impl DydxChannel {
+    /// [`Dydx`] real-time trades channel.
+    ///
+    /// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_websocket#trades>
+    pub const TRADES: Self = Self("v4_trades");
+
+    /// [`Dydx`] OrderBook Level2 channel, publishing an initial OrderBook snapshot followed by
+    /// OrderBook deltas.
+    ///
+    /// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_websocket#orders>
+    pub const ORDER_BOOK_L2: Self = Self("v4_orderbook");
+}
+
+impl<Instrument> Identifier<DydxChannel> for Subscription<Dydx, Instrument, PublicTrades> {
+    fn id(&self) -> DydxChannel {
+        DydxChannel::TRADES
+    }
+}
+
+impl<Instrument> Identifier<DydxChannel> for Subscription<Dydx, Instrument, OrderBooksL2> {
+    fn id(&self) -> DydxChannel {
+        DydxChannel::ORDER_BOOK_L2
+    }
+}
+
+impl AsRef<str> for DydxChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
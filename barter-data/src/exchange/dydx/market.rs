@@ -0,0 +1,45 @@
+use super::Dydx;
+use crate::{instrument::MarketInstrumentData, subscription::Subscription, Identifier};
+use barter_instrument::{asset::symbol::Symbol, instrument::Instrument, Keyed};
+use serde::{Deserialize, Serialize};
+use smol_str::{format_smolstr, SmolStr, StrExt};
+
+/// Type that defines how to translate a Barter [`Subscription`] into a [`Dydx`] market that can
+/// be subscribed to.
+///
+/// ### Notes
+/// [`Dydx`] markets are perpetuals identified by their ticker (eg/ "BTC-USD").
+///
+/// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_websocket>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DydxMarket(pub SmolStr);
+
+impl<Kind> Identifier<DydxMarket> for Subscription<Dydx, Instrument, Kind> {
+    fn id(&self) -> DydxMarket {
+        dydx_market(&self.instrument.base, &self.instrument.quote)
+    }
+}
+
+impl<InstrumentKey, Kind> Identifier<DydxMarket>
+    for Subscription<Dydx, Keyed<InstrumentKey, Instrument>, Kind>
+{
+    fn id(&self) -> DydxMarket {
+        dydx_market(&self.instrument.value.base, &self.instrument.value.quote)
+    }
+}
+
+impl<Kind> Identifier<DydxMarket> for Subscription<Dydx, MarketInstrumentData, Kind> {
+    fn id(&self) -> DydxMarket {
+        DydxMarket(self.instrument.name_exchange.clone())
+    }
+}
+
+impl AsRef<str> for DydxMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+fn dydx_market(base: &Symbol, quote: &Symbol) -> DydxMarket {
+    DydxMarket(format_smolstr!("{base}-{quote}").to_uppercase_smolstr())
+}
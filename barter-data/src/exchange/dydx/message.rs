@@ -0,0 +1,119 @@
+use crate::{exchange::subscription::ExchangeSub, Identifier};
+use barter_integration::subscription::SubscriptionId;
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
+
+/// Generic [`Dydx`](super::Dydx) message envelope, where the "subscribed" variant contains the
+/// initial channel contents (eg/ OrderBook snapshot), and each subsequent "channel_data"
+/// variant contains the channel updates.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_websocket>
+/// #### Subscribed
+/// ```json
+/// {
+///     "type": "subscribed",
+///     "connection_id": "e2a3ef4a-6c4f-4e2c-8c1f-0a4f9b8d1e0a",
+///     "message_id": 1,
+///     "channel": "v4_orderbook",
+///     "id": "BTC-USD",
+///     "contents": {
+///         "bids": [{"price": "65000", "size": "1.5"}],
+///         "asks": [{"price": "65001", "size": "0.25"}]
+///     }
+/// }
+/// ```
+///
+/// #### Channel Data
+/// ```json
+/// {
+///     "type": "channel_data",
+///     "connection_id": "e2a3ef4a-6c4f-4e2c-8c1f-0a4f9b8d1e0a",
+///     "message_id": 2,
+///     "channel": "v4_orderbook",
+///     "id": "BTC-USD",
+///     "version": "1.0.0",
+///     "contents": {
+///         "bids": [["65000", "0"]]
+///     }
+/// }
+/// ```
+///
+/// #### Connected
+/// ```json
+/// {
+///     "type": "connected",
+///     "connection_id": "e2a3ef4a-6c4f-4e2c-8c1f-0a4f9b8d1e0a",
+///     "message_id": 0
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DydxMessage<T> {
+    Subscribed(DydxChannelMessage<T>),
+    ChannelData(DydxChannelMessage<T>),
+    #[serde(other)]
+    Other,
+}
+
+/// [`Dydx`](super::Dydx) channel message containing the channel specific contents `T`.
+///
+/// The `message_id` is incremented with every message sent over the connection, so it is
+/// monotonically increasing across each subscribed market.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DydxChannelMessage<T> {
+    pub channel: SmolStr,
+    pub id: SmolStr,
+    pub message_id: u64,
+    pub contents: T,
+}
+
+impl<T> Identifier<Option<SubscriptionId>> for DydxMessage<T> {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            Self::Subscribed(message) | Self::ChannelData(message) => {
+                Some(ExchangeSub::from((&message.channel, &message.id)).id())
+            }
+            Self::Other => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    #[test]
+    fn test_de_dydx_message_id() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<SubscriptionId>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: subscribed
+                input: r#"{"type": "subscribed", "connection_id": "1", "message_id": 1, "channel": "v4_orderbook", "id": "BTC-USD", "contents": {}}"#,
+                expected: Some(SubscriptionId::from("v4_orderbook|BTC-USD")),
+            },
+            TestCase {
+                // TC1: channel_data
+                input: r#"{"type": "channel_data", "connection_id": "1", "message_id": 2, "channel": "v4_trades", "id": "ETH-USD", "version": "2.1.0", "contents": {}}"#,
+                expected: Some(SubscriptionId::from("v4_trades|ETH-USD")),
+            },
+            TestCase {
+                // TC2: connected
+                input: r#"{"type": "connected", "connection_id": "1", "message_id": 0}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<DydxMessage<Value>>(test.input)
+                .unwrap()
+                .id();
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
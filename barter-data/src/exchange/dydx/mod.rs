@@ -0,0 +1,157 @@
+use self::{
+    book::l2::DydxOrderBookL2, channel::DydxChannel, market::DydxMarket, message::DydxMessage,
+    subscription::DydxSubResponse, trade::DydxTrades,
+};
+use crate::{
+    exchange::{Connector, ExchangeSub, StreamSelector},
+    instrument::InstrumentData,
+    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscription::{book::OrderBooksL2, trade::PublicTrades, Map},
+    transformer::stateless::StatelessTransformer,
+    ExchangeWsStream, NoInitialSnapshots,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::{
+    environment::Environment, error::SocketError, protocol::websocket::WsMessage,
+};
+use barter_macro::{DeExchange, SerExchange};
+use serde_json::json;
+use url::Url;
+
+/// OrderBook types for [`Dydx`].
+pub mod book;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
+pub mod channel;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
+pub mod market;
+
+/// Generic [`DydxMessage<T>`](message::DydxMessage) envelope of every message received over
+/// the [`Dydx`] indexer WebSocket.
+pub mod message;
+
+/// [`Subscription`](crate::subscription::Subscription) response type and response
+/// [`Validator`](barter_integration::Validator) for [`Dydx`].
+pub mod subscription;
+
+/// Public trade types for [`Dydx`].
+pub mod trade;
+
+/// [`Dydx`] v4 indexer WebSocket server base url.
+///
+/// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_websocket>
+pub const BASE_URL_DYDX: &str = "wss://indexer.dydx.trade/v4/ws";
+
+/// [`Dydx`] v4 testnet indexer WebSocket server base url.
+///
+/// See docs: <https://docs.dydx.exchange/infrastructure_providers-network/resources>
+pub const BASE_URL_DYDX_TESTNET: &str = "wss://indexer.v4testnet.dydx.exchange/v4/ws";
+
+/// [`Dydx`] v4 perpetuals exchange, whose market data is served by the off-chain indexer of the
+/// dYdX Cosmos app-chain.
+///
+/// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_websocket>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DeExchange, SerExchange,
+)]
+pub struct Dydx;
+
+impl Connector for Dydx {
+    const ID: ExchangeId = ExchangeId::Dydx;
+    type Channel = DydxChannel;
+    type Market = DydxMarket;
+    type Subscriber = WebSocketSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = DydxSubResponse;
+
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(BASE_URL_DYDX).map_err(SocketError::UrlParse)
+    }
+
+    fn url_for(environment: Environment) -> Result<Url, SocketError> {
+        match environment {
+            Environment::Mainnet => Self::url(),
+            Environment::Testnet => {
+                Url::parse(BASE_URL_DYDX_TESTNET).map_err(SocketError::UrlParse)
+            }
+        }
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        exchange_subs
+            .into_iter()
+            .map(|ExchangeSub { channel, market }| {
+                WsMessage::text(
+                    json!({
+                        "type": "subscribe",
+                        "channel": channel.as_ref(),
+                        "id": market.as_ref(),
+                    })
+                    .to_string(),
+                )
+            })
+            .collect()
+    }
+
+    /// [`Dydx`] acknowledges each subscription with a "subscribed" message containing the
+    /// initial channel contents (eg/ the OrderBook snapshot), so it must reach the transformer
+    /// rather than being consumed during subscription validation.
+    fn expected_responses<InstrumentKey>(_: &Map<InstrumentKey>) -> usize {
+        0
+    }
+}
+
+impl<Instrument> StreamSelector<Instrument, PublicTrades> for Dydx
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Key, PublicTrades, DydxMessage<DydxTrades>>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL2> for Dydx
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Key, OrderBooksL2, DydxMessage<DydxOrderBookL2>>,
+    >;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use smol_str::SmolStr;
+
+    #[test]
+    fn test_dydx_requests() {
+        let actual = Dydx::requests(vec![
+            ExchangeSub {
+                channel: DydxChannel::TRADES,
+                market: DydxMarket(SmolStr::new("BTC-USD")),
+            },
+            ExchangeSub {
+                channel: DydxChannel::ORDER_BOOK_L2,
+                market: DydxMarket(SmolStr::new("ETH-USD")),
+            },
+        ]);
+
+        let expected = vec![
+            WsMessage::text(
+                json!({"type": "subscribe", "channel": "v4_trades", "id": "BTC-USD"}).to_string(),
+            ),
+            WsMessage::text(
+                json!({"type": "subscribe", "channel": "v4_orderbook", "id": "ETH-USD"})
+                    .to_string(),
+            ),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
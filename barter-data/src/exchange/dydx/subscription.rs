@@ -0,0 +1,45 @@
+use barter_integration::{error::SocketError, Validator};
+use serde::{Deserialize, Serialize};
+
+/// [`Dydx`](super::Dydx) WebSocket error response.
+///
+/// ### Notes
+/// [`Dydx`](super::Dydx) subscription acknowledgements contain the initial channel contents,
+/// so no [`DydxSubResponse`] is awaited during subscription validation (see
+/// [`Connector::expected_responses`](crate::exchange::Connector::expected_responses)).
+///
+/// ### Raw Payload Examples
+/// #### Subscription Error Response
+/// ```json
+/// {
+///     "type": "error",
+///     "message": "Invalid subscribe message: channel is not a valid channel",
+///     "connection_id": "e2a3ef4a-6c4f-4e2c-8c1f-0a4f9b8d1e0a",
+///     "message_id": 1
+/// }
+/// ```
+///
+/// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_websocket>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DydxSubResponse {
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default)]
+    pub message: String,
+}
+
+impl Validator for DydxSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        if self.kind == "error" {
+            Err(SocketError::Subscribe(format!(
+                "received failure subscription response: {}",
+                self.message
+            )))
+        } else {
+            Ok(self)
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use super::message::DydxMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    subscription::trade::PublicTrade,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::Side;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
+
+/// [`Dydx`](super::Dydx) real-time trades contents of a
+/// [`DydxChannel::TRADES`](super::channel::DydxChannel::TRADES) message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_websocket#trades>
+/// ```json
+/// {
+///     "trades": [
+///         {
+///             "id": "8ee6d90d-272d-5edd-bf0f-2e4d6ae3d3b7",
+///             "size": "0.0032",
+///             "price": "65094",
+///             "side": "BUY",
+///             "createdAt": "2024-05-09T09:15:30.012Z",
+///             "type": "LIMIT"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DydxTrades {
+    pub trades: Vec<DydxTrade>,
+}
+
+/// [`Dydx`](super::Dydx) real-time trade, where the side is that of the taker.
+///
+/// See [`DydxTrades`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DydxTrade {
+    pub id: SmolStr,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(alias = "size", deserialize_with = "barter_integration::de::de_str")]
+    pub amount: f64,
+    pub side: Side,
+    #[serde(alias = "createdAt")]
+    pub time: DateTime<Utc>,
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, DydxMessage<DydxTrades>)>
+    for MarketIter<InstrumentKey, PublicTrade>
+where
+    InstrumentKey: Clone,
+{
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, InstrumentKey, DydxMessage<DydxTrades>),
+    ) -> Self {
+        // Subscribed message contents are historical trades, so only updates are yielded
+        let DydxMessage::ChannelData(message) = message else {
+            return Self(vec![]);
+        };
+
+        let time_received = Utc::now();
+
+        message
+            .contents
+            .trades
+            .into_iter()
+            .map(|trade| {
+                Ok(MarketEvent {
+                    time_exchange: trade.time,
+                    time_received,
+                    exchange: exchange_id,
+                    instrument: instrument.clone(),
+                    kind: PublicTrade {
+                        id: trade.id.to_string(),
+                        price: trade.price,
+                        amount: trade.amount,
+                        side: trade.side,
+                        aggregate_count: None,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dydx_trades_into_market_events() {
+        let input = r#"
+        {
+            "type": "channel_data",
+            "connection_id": "e2a3ef4a-6c4f-4e2c-8c1f-0a4f9b8d1e0a",
+            "message_id": 7,
+            "id": "BTC-USD",
+            "channel": "v4_trades",
+            "version": "2.1.0",
+            "contents": {
+                "trades": [
+                    {
+                        "id": "8ee6d90d-272d-5edd-bf0f-2e4d6ae3d3b7",
+                        "size": "0.0032",
+                        "price": "65094",
+                        "side": "BUY",
+                        "createdAt": "2024-05-09T09:15:30.012Z",
+                        "type": "LIMIT"
+                    },
+                    {
+                        "id": "2b7a0e8d-5fd6-5a1c-9d9b-2c0e7e6f3a11",
+                        "size": "1.5",
+                        "price": "65093",
+                        "side": "SELL",
+                        "createdAt": "2024-05-09T09:15:30.012Z",
+                        "type": "LIMIT"
+                    }
+                ]
+            }
+        }
+        "#;
+
+        let message = serde_json::from_str::<DydxMessage<DydxTrades>>(input).unwrap();
+        let MarketIter(events) =
+            MarketIter::<(), PublicTrade>::from((ExchangeId::Dydx, (), message));
+
+        let actual = events
+            .into_iter()
+            .map(|event| event.unwrap().kind)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![
+                PublicTrade {
+                    id: "8ee6d90d-272d-5edd-bf0f-2e4d6ae3d3b7".to_string(),
+                    price: 65094.0,
+                    amount: 0.0032,
+                    side: Side::Buy,
+                    aggregate_count: None,
+                },
+                PublicTrade {
+                    id: "2b7a0e8d-5fd6-5a1c-9d9b-2c0e7e6f3a11".to_string(),
+                    price: 65093.0,
+                    amount: 1.5,
+                    side: Side::Sell,
+                    aggregate_count: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dydx_subscribed_trades_are_ignored() {
+        let input = r#"
+        {
+            "type": "subscribed",
+            "connection_id": "e2a3ef4a-6c4f-4e2c-8c1f-0a4f9b8d1e0a",
+            "message_id": 1,
+            "channel": "v4_trades",
+            "id": "BTC-USD",
+            "contents": {
+                "trades": [
+                    {
+                        "id": "8ee6d90d-272d-5edd-bf0f-2e4d6ae3d3b7",
+                        "size": "0.0032",
+                        "price": "65094",
+                        "side": "BUY",
+                        "createdAt": "2024-05-09T09:15:30.012Z",
+                        "type": "LIMIT"
+                    }
+                ]
+            }
+        }
+        "#;
+
+        let message = serde_json::from_str::<DydxMessage<DydxTrades>>(input).unwrap();
+        let MarketIter(events) =
+            MarketIter::<(), PublicTrade>::from((ExchangeId::Dydx, (), message));
+        assert!(events.is_empty());
+    }
+}
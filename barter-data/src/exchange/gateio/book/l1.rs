@@ -0,0 +1,192 @@
+use super::super::message::GateioMessage;
+use crate::{
+    books::Level,
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeSub,
+    subscription::book::OrderBookL1,
+    Identifier,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::subscription::SubscriptionId;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Gateio`](super::super::Gateio) real-time OrderBook Level1
+/// (top of book) WebSocket message.
+pub type GateioOrderBookL1 = GateioMessage<GateioOrderBookL1Inner>;
+
+/// [`Gateio`](super::super::Gateio) real-time OrderBook Level1 (top of book) WebSocket message.
+///
+/// ### Raw Payload Examples
+/// #### GateioSpot OrderBookL1
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#best-bid-or-ask-price>
+/// ```json
+/// {
+///     "t": 1606293275123,
+///     "u": 48733182,
+///     "s": "BTC_USDT",
+///     "b": "19177.79",
+///     "B": "0.0003341504",
+///     "a": "19179.38",
+///     "A": "0.09"
+/// }
+/// ```
+///
+/// #### GateioPerpetualsUsd OrderBookL1
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#best-ask-bid-notification>
+/// ```json
+/// {
+///     "t": 1615366379123,
+///     "u": 2517661076,
+///     "s": "BTC_USD",
+///     "b": "54696.6",
+///     "B": 37000,
+///     "a": "54696.7",
+///     "A": 47061
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioOrderBookL1Inner {
+    #[serde(rename = "s")]
+    pub market: String,
+    #[serde(
+        rename = "t",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "b", with = "rust_decimal::serde::str")]
+    pub best_bid_price: Decimal,
+    /// Spot amounts are strings, whereas futures & perpetual amounts are numbers of contracts.
+    #[serde(rename = "B")]
+    pub best_bid_amount: Decimal,
+    #[serde(rename = "a", with = "rust_decimal::serde::str")]
+    pub best_ask_price: Decimal,
+    #[serde(rename = "A")]
+    pub best_ask_amount: Decimal,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioOrderBookL1 {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(ExchangeSub::from((&self.channel, &self.data.market)).id())
+    }
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, GateioOrderBookL1)>
+    for MarketIter<InstrumentKey, OrderBookL1>
+{
+    fn from(
+        (exchange_id, instrument, book): (ExchangeId, InstrumentKey, GateioOrderBookL1),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            time_exchange: book.data.time,
+            time_received: Utc::now(),
+            exchange: exchange_id,
+            instrument,
+            kind: OrderBookL1 {
+                last_update_time: book.data.time,
+                best_bid: Level::new(book.data.best_bid_price, book.data.best_bid_amount),
+                best_ask: Level::new(book.data.best_ask_price, book.data.best_ask_amount),
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use rust_decimal_macros::dec;
+
+        #[test]
+        fn test_gateio_order_book_l1() {
+            struct TestCase {
+                input: &'static str,
+                expected: GateioOrderBookL1,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid GateioSpot OrderBookL1
+                    input: r#"
+                    {
+                        "time": 1606293275,
+                        "time_ms": 1606293275723,
+                        "channel": "spot.book_ticker",
+                        "event": "update",
+                        "result": {
+                            "t": 1606293275123,
+                            "u": 48733182,
+                            "s": "BTC_USDT",
+                            "b": "19177.79",
+                            "B": "0.0003341504",
+                            "a": "19179.38",
+                            "A": "0.09"
+                        }
+                    }
+                    "#,
+                    expected: GateioOrderBookL1 {
+                        channel: "spot.book_ticker".to_string(),
+                        error: None,
+                        data: GateioOrderBookL1Inner {
+                            market: "BTC_USDT".to_string(),
+                            time: DateTime::from_timestamp_millis(1606293275123).unwrap(),
+                            best_bid_price: dec!(19177.79),
+                            best_bid_amount: dec!(0.0003341504),
+                            best_ask_price: dec!(19179.38),
+                            best_ask_amount: dec!(0.09),
+                        },
+                    },
+                },
+                TestCase {
+                    // TC1: valid GateioPerpetualsUsd OrderBookL1 with amounts in contracts
+                    input: r#"
+                    {
+                        "time": 1615366379,
+                        "time_ms": 1615366379123,
+                        "channel": "futures.book_ticker",
+                        "event": "update",
+                        "result": {
+                            "t": 1615366379123,
+                            "u": 2517661076,
+                            "s": "BTC_USD",
+                            "b": "54696.6",
+                            "B": 37000,
+                            "a": "54696.7",
+                            "A": 47061
+                        }
+                    }
+                    "#,
+                    expected: GateioOrderBookL1 {
+                        channel: "futures.book_ticker".to_string(),
+                        error: None,
+                        data: GateioOrderBookL1Inner {
+                            market: "BTC_USD".to_string(),
+                            time: DateTime::from_timestamp_millis(1615366379123).unwrap(),
+                            best_bid_price: dec!(54696.6),
+                            best_bid_amount: dec!(37000),
+                            best_ask_price: dec!(54696.7),
+                            best_ask_amount: dec!(47061),
+                        },
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<GateioOrderBookL1>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+                assert_eq!(
+                    actual.id(),
+                    Some(SubscriptionId::from(format!(
+                        "{}|{}",
+                        actual.channel, actual.data.market
+                    ))),
+                    "TC{} failed",
+                    index
+                );
+            }
+        }
+    }
+}
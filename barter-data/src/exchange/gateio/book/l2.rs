@@ -0,0 +1,759 @@
+use super::{
+    super::{
+        message::GateioMessage,
+        perpetual::{
+            HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_PERPETUALS_BTC,
+            HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_PERPETUALS_USD,
+        },
+        spot::HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_SPOT,
+        Gateio,
+    },
+    GateioLevel,
+};
+use crate::{
+    books::OrderBook,
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::{config::ConnectorConfig, Connector, ExchangeServer, ExchangeSub},
+    instrument::InstrumentData,
+    subscription::{
+        book::{OrderBookEvent, OrderBooksL2},
+        Map, Subscription,
+    },
+    transformer::ExchangeTransformer,
+    Identifier, SnapshotFetcher,
+};
+use async_trait::async_trait;
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::{
+    error::SocketError, protocol::websocket::WsMessage, subscription::SubscriptionId, Transformer,
+};
+use chrono::{DateTime, Utc};
+use derive_more::Constructor;
+use futures_util::future::try_join_all;
+use serde::{Deserialize, Serialize};
+use std::{future::Future, marker::PhantomData};
+use tokio::sync::mpsc::UnboundedSender;
+
+/// Number of levels requested for each [`Gateio`] OrderBook Level2 snapshot.
+pub const BOOK_L2_SNAPSHOT_DEPTH_GATEIO: u32 = 100;
+
+/// Construct the [`Gateio`] HTTP OrderBook Level2 snapshot url for the provided [`ExchangeId`]
+/// and market.
+///
+/// The `with_id` parameter is required to receive the snapshot id used to sequence the
+/// subsequent [`GateioOrderBookL2Update`]s.
+pub fn gateio_order_book_l2_snapshot_url(
+    exchange: ExchangeId,
+    market: &str,
+) -> Result<String, SocketError> {
+    let (base_url, market_param) = match exchange {
+        ExchangeId::GateioSpot => (HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_SPOT, "currency_pair"),
+        ExchangeId::GateioPerpetualsUsd => {
+            (HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_PERPETUALS_USD, "contract")
+        }
+        ExchangeId::GateioPerpetualsBtc => {
+            (HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_PERPETUALS_BTC, "contract")
+        }
+        unsupported => {
+            return Err(SocketError::Unsupported {
+                entity: unsupported.as_str().to_string(),
+                item: "OrderBooksL2 snapshot".to_string(),
+            })
+        }
+    };
+
+    Ok(format!(
+        "{base_url}?{market_param}={market}&limit={BOOK_L2_SNAPSHOT_DEPTH_GATEIO}&with_id=true"
+    ))
+}
+
+#[derive(Debug)]
+pub struct GateioOrderBooksL2SnapshotFetcher;
+
+impl<Server> SnapshotFetcher<Gateio<Server>, OrderBooksL2> for GateioOrderBooksL2SnapshotFetcher
+where
+    Server: ExchangeServer + Send + Sync,
+{
+    fn fetch_snapshots<Instrument>(
+        config: &ConnectorConfig,
+        subscriptions: &[Subscription<Gateio<Server>, Instrument, OrderBooksL2>],
+    ) -> impl Future<Output = Result<Vec<MarketEvent<Instrument::Key, OrderBookEvent>>, SocketError>>
+           + Send
+    where
+        Instrument: InstrumentData,
+        Subscription<Gateio<Server>, Instrument, OrderBooksL2>:
+            Identifier<<Gateio<Server> as Connector>::Market>,
+    {
+        let l2_snapshot_futures = subscriptions.iter().map(|subscription| {
+            // Construct initial OrderBook snapshot GET url
+            let market = subscription.id();
+            let snapshot_url = gateio_order_book_l2_snapshot_url(Server::ID, market.as_ref());
+
+            let http_client = config.http_client();
+
+            async move {
+                // Fetch initial OrderBook snapshot via HTTP
+                let snapshot = http_client?
+                    .get(snapshot_url?)
+                    .send()
+                    .await
+                    .map_err(SocketError::Http)?
+                    .json::<GateioOrderBookL2Snapshot>()
+                    .await
+                    .map_err(SocketError::Http)?;
+
+                Ok(MarketEvent::from((
+                    Server::ID,
+                    subscription.instrument.key().clone(),
+                    snapshot,
+                )))
+            }
+        });
+
+        try_join_all(l2_snapshot_futures)
+    }
+}
+
+/// [`Gateio`] OrderBook Level2 snapshot HTTP message.
+///
+/// Used as the starting [`OrderBook`] before OrderBook Level2 delta WebSocket updates are
+/// applied.
+///
+/// ### Payload Examples
+/// #### GateioSpot OrderBookL2Snapshot
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#retrieve-order-book>
+/// ```json
+/// {
+///     "id": 123456,
+///     "current": 1623898993123,
+///     "update": 1623898993121,
+///     "asks": [["1.52", "1.151"]],
+///     "bids": [["1.17", "201.863"]]
+/// }
+/// ```
+///
+/// #### GateioPerpetualsUsd OrderBookL2Snapshot
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#futures-order-book>
+/// ```json
+/// {
+///     "id": 123456,
+///     "current": 1623898993.123,
+///     "update": 1623898993.121,
+///     "asks": [{"p": "1.52", "s": 100}],
+///     "bids": [{"p": "1.17", "s": 150}]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioOrderBookL2Snapshot {
+    pub id: u64,
+    pub bids: Vec<GateioLevel>,
+    pub asks: Vec<GateioLevel>,
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, GateioOrderBookL2Snapshot)>
+    for MarketEvent<InstrumentKey, OrderBookEvent>
+{
+    fn from(
+        (exchange, instrument, snapshot): (ExchangeId, InstrumentKey, GateioOrderBookL2Snapshot),
+    ) -> Self {
+        let time_received = Utc::now();
+        Self {
+            time_exchange: time_received,
+            time_received,
+            exchange,
+            instrument,
+            kind: OrderBookEvent::from(snapshot),
+        }
+    }
+}
+
+impl From<GateioOrderBookL2Snapshot> for OrderBookEvent {
+    fn from(snapshot: GateioOrderBookL2Snapshot) -> Self {
+        Self::Snapshot(OrderBook::new(
+            snapshot.id,
+            None,
+            snapshot.bids,
+            snapshot.asks,
+        ))
+    }
+}
+
+/// Terse type alias for a [`Gateio`] OrderBook Level2 deltas WebSocket message.
+pub type GateioOrderBookL2Update = GateioMessage<GateioOrderBookL2UpdateInner>;
+
+/// [`Gateio`] OrderBook Level2 deltas WebSocket message.
+///
+/// ### Raw Payload Examples
+/// #### GateioSpot OrderBookL2Update
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#changed-order-book-levels>
+/// ```json
+/// {
+///     "t": 1606294781123,
+///     "e": "depthUpdate",
+///     "E": 1606294781,
+///     "s": "BTC_USDT",
+///     "U": 48776301,
+///     "u": 48776306,
+///     "b": [["19137.74", "0.0001"], ["19088.37", "0"]],
+///     "a": [["19137.75", "0.6135"]]
+/// }
+/// ```
+///
+/// #### GateioPerpetualsUsd OrderBookL2Update
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#order-book-update-notification>
+/// ```json
+/// {
+///     "t": 1615366381417,
+///     "s": "BTC_USD",
+///     "U": 2517661101,
+///     "u": 2517661113,
+///     "b": [{"p": "54672.1", "s": 0}, {"p": "54664.5", "s": 58794}],
+///     "a": [{"p": "54743.6", "s": 0}]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioOrderBookL2UpdateInner {
+    #[serde(rename = "s")]
+    pub market: String,
+    #[serde(
+        rename = "t",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time_exchange: DateTime<Utc>,
+    #[serde(rename = "U")]
+    pub first_update_id: u64,
+    #[serde(rename = "u")]
+    pub last_update_id: u64,
+    #[serde(rename = "b")]
+    pub bids: Vec<GateioLevel>,
+    #[serde(rename = "a")]
+    pub asks: Vec<GateioLevel>,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioOrderBookL2Update {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(ExchangeSub::from((&self.channel, &self.data.market)).id())
+    }
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, GateioOrderBookL2Update)>
+    for MarketIter<InstrumentKey, OrderBookEvent>
+{
+    fn from(
+        (exchange_id, instrument, update): (ExchangeId, InstrumentKey, GateioOrderBookL2Update),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            time_exchange: update.data.time_exchange,
+            time_received: Utc::now(),
+            exchange: exchange_id,
+            instrument,
+            kind: OrderBookEvent::Update(OrderBook::new(
+                update.data.last_update_id,
+                None,
+                update.data.bids,
+                update.data.asks,
+            )),
+        })])
+    }
+}
+
+#[derive(Debug, Constructor)]
+pub struct GateioOrderBookL2Meta<InstrumentKey> {
+    pub key: InstrumentKey,
+    pub sequencer: GateioOrderBookL2Sequencer,
+}
+
+#[derive(Debug)]
+pub struct GateioOrderBooksL2Transformer<Server, InstrumentKey> {
+    server: PhantomData<Server>,
+    instrument_map: Map<GateioOrderBookL2Meta<InstrumentKey>>,
+}
+
+#[async_trait]
+impl<Server, InstrumentKey> ExchangeTransformer<Gateio<Server>, InstrumentKey, OrderBooksL2>
+    for GateioOrderBooksL2Transformer<Server, InstrumentKey>
+where
+    Server: ExchangeServer + Send,
+    InstrumentKey: Clone + PartialEq + Send + Sync,
+{
+    async fn init(
+        instrument_map: Map<InstrumentKey>,
+        initial_snapshots: &[MarketEvent<InstrumentKey, OrderBookEvent>],
+        _: UnboundedSender<WsMessage>,
+    ) -> Result<Self, DataError> {
+        let instrument_map = instrument_map
+            .0
+            .into_iter()
+            .map(|(sub_id, instrument_key)| {
+                let snapshot = initial_snapshots
+                    .iter()
+                    .find(|snapshot| snapshot.instrument == instrument_key)
+                    .ok_or_else(|| DataError::InitialSnapshotMissing(sub_id.clone()))?;
+
+                let OrderBookEvent::Snapshot(snapshot) = &snapshot.kind else {
+                    return Err(DataError::InitialSnapshotInvalid(
+                        "expected OrderBookEvent::Snapshot but found OrderBookEvent::Update",
+                    ));
+                };
+
+                let book_meta = GateioOrderBookL2Meta::new(
+                    instrument_key,
+                    GateioOrderBookL2Sequencer::new(snapshot.sequence),
+                );
+
+                Ok((sub_id, book_meta))
+            })
+            .collect::<Result<Map<_>, _>>()?;
+
+        Ok(Self {
+            server: PhantomData,
+            instrument_map,
+        })
+    }
+}
+
+impl<Server, InstrumentKey> Transformer for GateioOrderBooksL2Transformer<Server, InstrumentKey>
+where
+    Server: ExchangeServer,
+    InstrumentKey: Clone,
+{
+    type Error = DataError;
+    type Input = GateioOrderBookL2Update;
+    type Output = MarketEvent<InstrumentKey, OrderBookEvent>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        // Determine if the message has an identifiable SubscriptionId
+        let subscription_id = match input.id() {
+            Some(subscription_id) => subscription_id,
+            None => return vec![],
+        };
+
+        // Find Instrument associated with Input and transform
+        let instrument = match self.instrument_map.find_mut(&subscription_id) {
+            Ok(instrument) => instrument,
+            Err(unidentifiable) => return vec![Err(DataError::Socket(unidentifiable))],
+        };
+
+        // Drop any outdated updates & validate sequence for relevant updates
+        let valid_update = match instrument.sequencer.validate_sequence(input) {
+            Ok(Some(valid_update)) => valid_update,
+            Ok(None) => return vec![],
+            Err(error) => return vec![Err(error)],
+        };
+
+        MarketIter::<InstrumentKey, OrderBookEvent>::from((
+            Gateio::<Server>::ID,
+            instrument.key.clone(),
+            valid_update,
+        ))
+        .0
+    }
+}
+
+/// [`Gateio`] OrderBook Level2 sequencer, common to [`GateioSpot`](super::super::spot::GateioSpot)
+/// and the [`Gateio`] perpetual exchanges.
+///
+/// Gateio: How To Maintain A Local OrderBook
+///
+/// 1. Subscribe to the OrderBook Level2 deltas channel, eg/ `["BTC_USDT", "100ms"]`.
+/// 2. Cache the WebSocket notifications, where U and u are the first and last update ids.
+/// 3. Retrieve the base OrderBook via HTTP with `with_id=true`, recording the id as baseId.
+/// 4. Dump all notifications which satisfy u < baseId+1.
+/// 5. The first processed notification should have U <= baseId+1 AND u >= baseId+1.
+/// 6. Each subsequent notification's U should be equal to the previous notification's u+1,
+///    otherwise some updates are lost and the local OrderBook must be rebuilt from step 3.
+/// 7. Amounts are absolute quantities for a price level, with 0 meaning the level is removed.
+///
+/// Notes:
+///  - Uppercase U => first_update_id
+///  - Lowercase u => last_update_id,
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#how-to-maintain-local-order-book>
+#[derive(Debug)]
+pub struct GateioOrderBookL2Sequencer {
+    pub updates_processed: u64,
+    pub last_update_id: u64,
+}
+
+impl GateioOrderBookL2Sequencer {
+    /// Construct a new [`Self`] with the provided initial snapshot id.
+    pub fn new(last_update_id: u64) -> Self {
+        Self {
+            updates_processed: 0,
+            last_update_id,
+        }
+    }
+
+    /// Gateio: How To Maintain A Local OrderBook
+    /// See Self's Rust Docs for more information on each numbered step
+    pub fn validate_sequence(
+        &mut self,
+        update: GateioOrderBookL2Update,
+    ) -> Result<Option<GateioOrderBookL2Update>, DataError> {
+        // 4. Dump all notifications which satisfy u < baseId+1:
+        if update.data.last_update_id <= self.last_update_id {
+            return Ok(None);
+        }
+
+        if self.is_first_update() {
+            // 5. The first processed notification should have U <= baseId+1 AND u >= baseId+1:
+            self.validate_first_update(&update)?;
+        } else {
+            // 6. Each subsequent notification's U should be equal to the previous u+1:
+            self.validate_next_update(&update)?;
+        }
+
+        // Update metadata
+        self.updates_processed += 1;
+        self.last_update_id = update.data.last_update_id;
+
+        Ok(Some(update))
+    }
+
+    /// Determine if no [`GateioOrderBookL2Update`] has been processed since the snapshot.
+    pub fn is_first_update(&self) -> bool {
+        self.updates_processed == 0
+    }
+
+    /// Gateio: How To Maintain A Local OrderBook: Step 5:
+    /// "The first processed notification should have U <= baseId+1 AND u >= baseId+1"
+    pub fn validate_first_update(&self, update: &GateioOrderBookL2Update) -> Result<(), DataError> {
+        let expected_next_id = self.last_update_id + 1;
+        if update.data.first_update_id <= expected_next_id
+            && update.data.last_update_id >= expected_next_id
+        {
+            Ok(())
+        } else {
+            Err(DataError::InvalidSequence {
+                prev_last_update_id: self.last_update_id,
+                first_update_id: update.data.first_update_id,
+            })
+        }
+    }
+
+    /// Gateio: How To Maintain A Local OrderBook: Step 6:
+    /// "Each subsequent notification's U should be equal to the previous notification's u+1"
+    pub fn validate_next_update(&self, update: &GateioOrderBookL2Update) -> Result<(), DataError> {
+        let expected_next_id = self.last_update_id + 1;
+        if update.data.first_update_id == expected_next_id {
+            Ok(())
+        } else {
+            Err(DataError::InvalidSequence {
+                prev_last_update_id: self.last_update_id,
+                first_update_id: update.data.first_update_id,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn update(first_update_id: u64, last_update_id: u64) -> GateioOrderBookL2Update {
+        GateioOrderBookL2Update {
+            channel: "spot.order_book_update".to_string(),
+            error: None,
+            data: GateioOrderBookL2UpdateInner {
+                market: "BTC_USDT".to_string(),
+                time_exchange: DateTime::from_timestamp_millis(1606294781123).unwrap(),
+                first_update_id,
+                last_update_id,
+                bids: vec![],
+                asks: vec![],
+            },
+        }
+    }
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_gateio_order_book_l2_snapshot() {
+            struct TestCase {
+                input: &'static str,
+                expected: GateioOrderBookL2Snapshot,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid GateioSpot OrderBookL2Snapshot
+                    input: r#"
+                    {
+                        "id": 123456,
+                        "current": 1623898993123,
+                        "update": 1623898993121,
+                        "asks": [["1.52", "1.151"]],
+                        "bids": [["1.17", "201.863"]]
+                    }
+                    "#,
+                    expected: GateioOrderBookL2Snapshot {
+                        id: 123456,
+                        bids: vec![GateioLevel {
+                            price: dec!(1.17),
+                            amount: dec!(201.863),
+                        }],
+                        asks: vec![GateioLevel {
+                            price: dec!(1.52),
+                            amount: dec!(1.151),
+                        }],
+                    },
+                },
+                TestCase {
+                    // TC1: valid GateioPerpetualsUsd OrderBookL2Snapshot
+                    input: r#"
+                    {
+                        "id": 123456,
+                        "current": 1623898993.123,
+                        "update": 1623898993.121,
+                        "asks": [{"p": "1.52", "s": 100}],
+                        "bids": [{"p": "1.17", "s": 150}]
+                    }
+                    "#,
+                    expected: GateioOrderBookL2Snapshot {
+                        id: 123456,
+                        bids: vec![GateioLevel {
+                            price: dec!(1.17),
+                            amount: dec!(150),
+                        }],
+                        asks: vec![GateioLevel {
+                            price: dec!(1.52),
+                            amount: dec!(100),
+                        }],
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<GateioOrderBookL2Snapshot>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+
+        #[test]
+        fn test_gateio_order_book_l2_update() {
+            struct TestCase {
+                input: &'static str,
+                expected: GateioOrderBookL2Update,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid GateioSpot OrderBookL2Update
+                    input: r#"
+                    {
+                        "time": 1606294781,
+                        "time_ms": 1606294781236,
+                        "channel": "spot.order_book_update",
+                        "event": "update",
+                        "result": {
+                            "t": 1606294781123,
+                            "e": "depthUpdate",
+                            "E": 1606294781,
+                            "s": "BTC_USDT",
+                            "U": 48776301,
+                            "u": 48776306,
+                            "b": [["19137.74", "0.0001"], ["19088.37", "0"]],
+                            "a": [["19137.75", "0.6135"]]
+                        }
+                    }
+                    "#,
+                    expected: GateioOrderBookL2Update {
+                        channel: "spot.order_book_update".to_string(),
+                        error: None,
+                        data: GateioOrderBookL2UpdateInner {
+                            market: "BTC_USDT".to_string(),
+                            time_exchange: DateTime::from_timestamp_millis(1606294781123).unwrap(),
+                            first_update_id: 48776301,
+                            last_update_id: 48776306,
+                            bids: vec![
+                                GateioLevel {
+                                    price: dec!(19137.74),
+                                    amount: dec!(0.0001),
+                                },
+                                GateioLevel {
+                                    price: dec!(19088.37),
+                                    amount: dec!(0),
+                                },
+                            ],
+                            asks: vec![GateioLevel {
+                                price: dec!(19137.75),
+                                amount: dec!(0.6135),
+                            }],
+                        },
+                    },
+                },
+                TestCase {
+                    // TC1: valid GateioPerpetualsUsd OrderBookL2Update
+                    input: r#"
+                    {
+                        "time": 1615366381,
+                        "time_ms": 1615366381123,
+                        "channel": "futures.order_book_update",
+                        "event": "update",
+                        "result": {
+                            "t": 1615366381417,
+                            "s": "BTC_USD",
+                            "U": 2517661101,
+                            "u": 2517661113,
+                            "b": [{"p": "54672.1", "s": 0}, {"p": "54664.5", "s": 58794}],
+                            "a": [{"p": "54743.6", "s": 0}]
+                        }
+                    }
+                    "#,
+                    expected: GateioOrderBookL2Update {
+                        channel: "futures.order_book_update".to_string(),
+                        error: None,
+                        data: GateioOrderBookL2UpdateInner {
+                            market: "BTC_USD".to_string(),
+                            time_exchange: DateTime::from_timestamp_millis(1615366381417).unwrap(),
+                            first_update_id: 2517661101,
+                            last_update_id: 2517661113,
+                            bids: vec![
+                                GateioLevel {
+                                    price: dec!(54672.1),
+                                    amount: dec!(0),
+                                },
+                                GateioLevel {
+                                    price: dec!(54664.5),
+                                    amount: dec!(58794),
+                                },
+                            ],
+                            asks: vec![GateioLevel {
+                                price: dec!(54743.6),
+                                amount: dec!(0),
+                            }],
+                        },
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<GateioOrderBookL2Update>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gateio_order_book_l2_snapshot_url() {
+        struct TestCase {
+            exchange: ExchangeId,
+            market: &'static str,
+            expected: Option<&'static str>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: GateioSpot
+                exchange: ExchangeId::GateioSpot,
+                market: "BTC_USDT",
+                expected: Some(
+                    "https://api.gateio.ws/api/v4/spot/order_book?currency_pair=BTC_USDT&limit=100&with_id=true",
+                ),
+            },
+            TestCase {
+                // TC1: GateioPerpetualsUsd
+                exchange: ExchangeId::GateioPerpetualsUsd,
+                market: "BTC_USDT",
+                expected: Some(
+                    "https://api.gateio.ws/api/v4/futures/usdt/order_book?contract=BTC_USDT&limit=100&with_id=true",
+                ),
+            },
+            TestCase {
+                // TC2: GateioPerpetualsBtc
+                exchange: ExchangeId::GateioPerpetualsBtc,
+                market: "BTC_USD",
+                expected: Some(
+                    "https://api.gateio.ws/api/v4/futures/btc/order_book?contract=BTC_USD&limit=100&with_id=true",
+                ),
+            },
+            TestCase {
+                // TC3: GateioOptions is unsupported
+                exchange: ExchangeId::GateioOptions,
+                market: "BTC_USDT-20211130-65000-C",
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = gateio_order_book_l2_snapshot_url(test.exchange, test.market).ok();
+            assert_eq!(actual.as_deref(), test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_sequencer_validate_sequence() {
+        struct TestCase {
+            sequencer: GateioOrderBookL2Sequencer,
+            input: GateioOrderBookL2Update,
+            expected: Result<Option<GateioOrderBookL2Update>, DataError>,
+        }
+
+        let processed = |updates_processed, last_update_id| GateioOrderBookL2Sequencer {
+            updates_processed,
+            last_update_id,
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: outdated update is dropped
+                sequencer: GateioOrderBookL2Sequencer::new(100),
+                input: update(90, 100),
+                expected: Ok(None),
+            },
+            TestCase {
+                // TC1: valid first update spanning baseId+1
+                sequencer: GateioOrderBookL2Sequencer::new(100),
+                input: update(95, 105),
+                expected: Ok(Some(update(95, 105))),
+            },
+            TestCase {
+                // TC2: invalid first update starting after baseId+1
+                sequencer: GateioOrderBookL2Sequencer::new(100),
+                input: update(102, 105),
+                expected: Err(DataError::InvalidSequence {
+                    prev_last_update_id: 100,
+                    first_update_id: 102,
+                }),
+            },
+            TestCase {
+                // TC3: valid next update following on from the previous u
+                sequencer: processed(1, 105),
+                input: update(106, 110),
+                expected: Ok(Some(update(106, 110))),
+            },
+            TestCase {
+                // TC4: invalid next update with lost updates
+                sequencer: processed(1, 105),
+                input: update(108, 110),
+                expected: Err(DataError::InvalidSequence {
+                    prev_last_update_id: 105,
+                    first_update_id: 108,
+                }),
+            },
+        ];
+
+        for (index, mut test) in tests.into_iter().enumerate() {
+            let actual = test.sequencer.validate_sequence(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (
+                    Err(DataError::InvalidSequence { .. }),
+                    Err(DataError::InvalidSequence { .. }),
+                ) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use crate::books::Level;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Level 1 OrderBook types (top of book).
+pub mod l1;
+
+/// Level 2 OrderBook types.
+pub mod l2;
+
+/// [`Gateio`](super::Gateio) OrderBook level.
+///
+/// ### Notes
+/// [`GateioSpot`](super::spot::GateioSpot) levels are `[price, amount]` arrays, whereas
+/// futures & perpetual levels are objects with the amount in contracts, so both representations
+/// are supported.
+///
+/// #### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#changed-order-book-levels>
+/// ```json
+/// ["19137.74", "0.0001"]
+/// ```
+///
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#order-book-update-notification>
+/// ```json
+/// {"p": "54672.1", "s": 95}
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(from = "GateioLevelRepr")]
+pub struct GateioLevel {
+    pub price: Decimal,
+    pub amount: Decimal,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum GateioLevelRepr {
+    Array(
+        #[serde(with = "rust_decimal::serde::str")] Decimal,
+        #[serde(with = "rust_decimal::serde::str")] Decimal,
+    ),
+    Object {
+        #[serde(with = "rust_decimal::serde::str")]
+        p: Decimal,
+        s: Decimal,
+    },
+}
+
+impl From<GateioLevelRepr> for GateioLevel {
+    fn from(repr: GateioLevelRepr) -> Self {
+        match repr {
+            GateioLevelRepr::Array(price, amount)
+            | GateioLevelRepr::Object {
+                p: price,
+                s: amount,
+            } => Self { price, amount },
+        }
+    }
+}
+
+impl From<GateioLevel> for Level {
+    fn from(level: GateioLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use rust_decimal_macros::dec;
+
+        #[test]
+        fn test_gateio_level() {
+            struct TestCase {
+                input: &'static str,
+                expected: GateioLevel,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: spot array level
+                    input: r#"["19137.74", "0.0001"]"#,
+                    expected: GateioLevel {
+                        price: dec!(19137.74),
+                        amount: dec!(0.0001),
+                    },
+                },
+                TestCase {
+                    // TC1: futures object level with amount in contracts
+                    input: r#"{"p": "54672.1", "s": 95}"#,
+                    expected: GateioLevel {
+                        price: dec!(54672.1),
+                        amount: dec!(95),
+                    },
+                },
+                TestCase {
+                    // TC2: futures object level removal
+                    input: r#"{"p": "54672.1", "s": 0}"#,
+                    expected: GateioLevel {
+                        price: dec!(54672.1),
+                        amount: dec!(0),
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<GateioLevel>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+}
@@ -1,6 +1,10 @@
 use crate::{
     instrument::InstrumentData,
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use barter_instrument::instrument::kind::InstrumentKind;
@@ -29,6 +33,53 @@ impl GateioChannel {
     ///
     /// See docs: <https://www.gate.io/docs/developers/options/ws/en/#public-contract-trades-channel>
     pub const OPTION_TRADES: Self = Self("options.trades");
+
+    /// Gateio [`InstrumentKind::Spot`] real-time OrderBook Level1 (top of book) channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#best-bid-or-ask-price>
+    pub const SPOT_ORDER_BOOK_L1: Self = Self("spot.book_ticker");
+
+    /// Gateio [`InstrumentKind::Future`] & [`InstrumentKind::Perpetual`] real-time OrderBook
+    /// Level1 (top of book) channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#best-ask-bid-subscription>
+    pub const FUTURE_ORDER_BOOK_L1: Self = Self("futures.book_ticker");
+
+    /// Gateio [`InstrumentKind::Option`] real-time OrderBook Level1 (top of book) channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/options/ws/en/#book-ticker-channel>
+    pub const OPTION_ORDER_BOOK_L1: Self = Self("options.book_ticker");
+
+    /// Gateio [`InstrumentKind::Spot`] OrderBook Level2 deltas channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#changed-order-book-levels>
+    pub const SPOT_ORDER_BOOK_L2: Self = Self("spot.order_book_update");
+
+    /// Gateio [`InstrumentKind::Future`] & [`InstrumentKind::Perpetual`] OrderBook Level2 deltas
+    /// channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#order-book-update-subscription>
+    pub const FUTURE_ORDER_BOOK_L2: Self = Self("futures.order_book_update");
+
+    /// Gateio [`InstrumentKind::Option`] OrderBook Level2 deltas channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/options/ws/en/#order-book-update-channel>
+    pub const OPTION_ORDER_BOOK_L2: Self = Self("options.order_book_update");
+
+    /// Update interval requested for OrderBook Level2 deltas channels.
+    pub const ORDER_BOOK_L2_INTERVAL: &'static str = "100ms";
+
+    /// Additional subscription payload parameters that follow the market, if any.
+    ///
+    /// eg/ OrderBook Level2 deltas channels require the update interval: `["BTC_USDT", "100ms"]`
+    pub fn payload_params(&self) -> &'static [&'static str] {
+        match *self {
+            Self::SPOT_ORDER_BOOK_L2 | Self::FUTURE_ORDER_BOOK_L2 | Self::OPTION_ORDER_BOOK_L2 => {
+                &[Self::ORDER_BOOK_L2_INTERVAL]
+            }
+            _ => &[],
+        }
+    }
 }
 
 impl<GateioExchange, Instrument> Identifier<GateioChannel>
@@ -45,6 +96,38 @@ where
     }
 }
 
+impl<GateioExchange, Instrument> Identifier<GateioChannel>
+    for Subscription<GateioExchange, Instrument, OrderBooksL1>
+where
+    Instrument: InstrumentData,
+{
+    fn id(&self) -> GateioChannel {
+        match self.instrument.kind() {
+            InstrumentKind::Spot => GateioChannel::SPOT_ORDER_BOOK_L1,
+            InstrumentKind::Future(_) | InstrumentKind::Perpetual => {
+                GateioChannel::FUTURE_ORDER_BOOK_L1
+            }
+            InstrumentKind::Option(_) => GateioChannel::OPTION_ORDER_BOOK_L1,
+        }
+    }
+}
+
+impl<GateioExchange, Instrument> Identifier<GateioChannel>
+    for Subscription<GateioExchange, Instrument, OrderBooksL2>
+where
+    Instrument: InstrumentData,
+{
+    fn id(&self) -> GateioChannel {
+        match self.instrument.kind() {
+            InstrumentKind::Spot => GateioChannel::SPOT_ORDER_BOOK_L2,
+            InstrumentKind::Future(_) | InstrumentKind::Perpetual => {
+                GateioChannel::FUTURE_ORDER_BOOK_L2
+            }
+            InstrumentKind::Option(_) => GateioChannel::OPTION_ORDER_BOOK_L2,
+        }
+    }
+}
+
 impl AsRef<str> for GateioChannel {
     fn as_ref(&self) -> &str {
         self.0
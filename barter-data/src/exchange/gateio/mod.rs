@@ -9,6 +9,11 @@ use serde_json::json;
 use std::{fmt::Debug, marker::PhantomData};
 use url::Url;
 
+/// OrderBook types common to [`GateioSpot`](spot::GateioSpot),
+/// [`GateioPerpetualUsdt`](perpetual::GateioPerpetualsUsd) and
+/// [`GateioPerpetualBtc`](perpetual::GateioPerpetualsBtc).
+pub mod book;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -81,7 +86,9 @@ where
                         "time": chrono::Utc::now().timestamp_millis(),
                         "channel": channel.as_ref(),
                         "event": "subscribe",
-                        "payload": [market.as_ref()]
+                        "payload": std::iter::once(market.as_ref())
+                            .chain(channel.payload_params().iter().copied())
+                            .collect::<Vec<_>>()
                     })
                     .to_string(),
                 )
@@ -1,9 +1,18 @@
 use self::trade::GateioFuturesTrades;
-use super::Gateio;
+use super::{
+    book::{
+        l1::GateioOrderBookL1,
+        l2::{GateioOrderBooksL2SnapshotFetcher, GateioOrderBooksL2Transformer},
+    },
+    Gateio,
+};
 use crate::{
     exchange::{ExchangeServer, StreamSelector},
     instrument::InstrumentData,
-    subscription::trade::PublicTrades,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        trade::PublicTrades,
+    },
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream, NoInitialSnapshots,
 };
@@ -17,6 +26,12 @@ pub mod trade;
 /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/>
 pub const WEBSOCKET_BASE_URL_GATEIO_PERPETUALS_USD: &str = "wss://fx-ws.gateio.ws/v4/ws/usdt";
 
+/// [`GateioPerpetualsUsd`] HTTP OrderBook L2 snapshot url.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#futures-order-book>
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_PERPETUALS_USD: &str =
+    "https://api.gateio.ws/api/v4/futures/usdt/order_book";
+
 /// [`Gateio`] perpetual usd exchange.
 pub type GateioPerpetualsUsd = Gateio<GateioServerPerpetualsUsd>;
 
@@ -42,11 +57,36 @@ where
     >;
 }
 
+impl<Instrument> StreamSelector<Instrument, OrderBooksL1> for GateioPerpetualsUsd
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Key, OrderBooksL1, GateioOrderBookL1>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL2> for GateioPerpetualsUsd
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = GateioOrderBooksL2SnapshotFetcher;
+    type Stream =
+        ExchangeWsStream<GateioOrderBooksL2Transformer<GateioServerPerpetualsUsd, Instrument::Key>>;
+}
+
 /// [`GateioPerpetualsBtc`] WebSocket server base url.
 ///
 /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/>
 pub const WEBSOCKET_BASE_URL_GATEIO_PERPETUALS_BTC: &str = "wss://fx-ws.gateio.ws/v4/ws/btc";
 
+/// [`GateioPerpetualsBtc`] HTTP OrderBook L2 snapshot url.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#futures-order-book>
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_PERPETUALS_BTC: &str =
+    "https://api.gateio.ws/api/v4/futures/btc/order_book";
+
 /// [`Gateio`] perpetual btc exchange.
 pub type GateioPerpetualsBtc = Gateio<GateioServerPerpetualsBtc>;
 
@@ -71,3 +111,22 @@ where
         StatelessTransformer<Self, Instrument::Key, PublicTrades, GateioFuturesTrades>,
     >;
 }
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL1> for GateioPerpetualsBtc
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Key, OrderBooksL1, GateioOrderBookL1>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL2> for GateioPerpetualsBtc
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = GateioOrderBooksL2SnapshotFetcher;
+    type Stream =
+        ExchangeWsStream<GateioOrderBooksL2Transformer<GateioServerPerpetualsBtc, Instrument::Key>>;
+}
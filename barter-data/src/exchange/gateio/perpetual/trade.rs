@@ -96,6 +96,7 @@ impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, GateioFuturesTrades)
                         } else {
                             Side::Sell
                         },
+                        aggregate_count: None,
                     },
                 })
             })
@@ -1,9 +1,18 @@
 use self::trade::GateioSpotTrade;
-use super::Gateio;
+use super::{
+    book::{
+        l1::GateioOrderBookL1,
+        l2::{GateioOrderBooksL2SnapshotFetcher, GateioOrderBooksL2Transformer},
+    },
+    Gateio,
+};
 use crate::{
     exchange::{ExchangeServer, StreamSelector},
     instrument::InstrumentData,
-    subscription::trade::PublicTrades,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        trade::PublicTrades,
+    },
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream, NoInitialSnapshots,
 };
@@ -18,6 +27,12 @@ pub mod trade;
 /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/>
 pub const WEBSOCKET_BASE_URL_GATEIO_SPOT: &str = "wss://api.gateio.ws/ws/v4/";
 
+/// [`GateioSpot`] HTTP OrderBook L2 snapshot url.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#retrieve-order-book>
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_SPOT: &str =
+    "https://api.gateio.ws/api/v4/spot/order_book";
+
 /// [`Gateio`] spot exchange.
 pub type GateioSpot = Gateio<GateioServerSpot>;
 
@@ -44,3 +59,22 @@ where
         StatelessTransformer<Self, Instrument::Key, PublicTrades, GateioSpotTrade>,
     >;
 }
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL1> for GateioSpot
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Key, OrderBooksL1, GateioOrderBookL1>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL2> for GateioSpot
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = GateioOrderBooksL2SnapshotFetcher;
+    type Stream =
+        ExchangeWsStream<GateioOrderBooksL2Transformer<GateioServerSpot, Instrument::Key>>;
+}
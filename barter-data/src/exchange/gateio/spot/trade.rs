@@ -70,6 +70,7 @@ impl<InstrumentKey> From<(ExchangeId, InstrumentKey, GateioSpotTrade)>
                 price: trade.data.price,
                 amount: trade.data.amount,
                 side: trade.data.side,
+                aggregate_count: None,
             },
         })])
     }
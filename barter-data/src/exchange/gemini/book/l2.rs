@@ -0,0 +1,208 @@
+use super::{
+    super::{
+        message::{de_message_subscription_id, GeminiMessage},
+        Gemini,
+    },
+    GeminiLevel,
+};
+use crate::{
+    books::OrderBook,
+    error::DataError,
+    event::MarketEvent,
+    exchange::Connector,
+    subscription::{
+        book::{OrderBookEvent, OrderBooksL2},
+        Map,
+    },
+    transformer::ExchangeTransformer,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    protocol::websocket::WsMessage, subscription::SubscriptionId, Side, Transformer,
+};
+use chrono::Utc;
+use derive_more::Constructor;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::UnboundedSender;
+
+/// [`Gemini`] OrderBook Level2 snapshot or deltas.
+///
+/// The first [`GeminiOrderBookL2Update`] received for a symbol is the full OrderBook
+/// snapshot, and every subsequent one contains the changes of each updated level, where the
+/// amount is the absolute quantity of the level (0 meaning the level is removed).
+///
+/// See [`GeminiMessage`] for full raw payload examples.
+///
+/// See docs: <https://docs.gemini.com/websocket-api/#level-2-data>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GeminiOrderBookL2Update {
+    #[serde(alias = "symbol", deserialize_with = "de_message_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub changes: Vec<GeminiLevel>,
+}
+
+impl GeminiOrderBookL2Update {
+    /// Construct an [`OrderBook`] with the provided sequence from the changes of this
+    /// [`GeminiOrderBookL2Update`].
+    pub fn into_order_book(self, sequence: u64) -> OrderBook {
+        let (bids, asks): (Vec<_>, Vec<_>) = self
+            .changes
+            .into_iter()
+            .partition(|level| level.side == Side::Buy);
+
+        OrderBook::new(sequence, None, bids, asks)
+    }
+}
+
+#[derive(Debug, Constructor)]
+pub struct GeminiOrderBookL2Meta<InstrumentKey> {
+    pub key: InstrumentKey,
+    pub sequencer: GeminiOrderBookL2Sequencer,
+}
+
+/// [`Gemini`] OrderBook Level2 sequencer.
+///
+/// [`Gemini`] does not publish update identifiers, relying on the ordering of the WebSocket,
+/// so the number of [`GeminiOrderBookL2Update`]s processed is used as the [`OrderBook`]
+/// sequence, and the first is interpreted as the snapshot.
+#[derive(Debug, Default)]
+pub struct GeminiOrderBookL2Sequencer {
+    pub updates_processed: u64,
+}
+
+impl GeminiOrderBookL2Sequencer {
+    /// Sequence the provided [`GeminiOrderBookL2Update`] into an [`OrderBookEvent`].
+    pub fn sequence(&mut self, update: GeminiOrderBookL2Update) -> OrderBookEvent {
+        let is_snapshot = self.updates_processed == 0;
+        self.updates_processed += 1;
+
+        let book = update.into_order_book(self.updates_processed);
+        if is_snapshot {
+            OrderBookEvent::Snapshot(book)
+        } else {
+            OrderBookEvent::Update(book)
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GeminiOrderBooksL2Transformer<InstrumentKey> {
+    instrument_map: Map<GeminiOrderBookL2Meta<InstrumentKey>>,
+}
+
+#[async_trait]
+impl<InstrumentKey> ExchangeTransformer<Gemini, InstrumentKey, OrderBooksL2>
+    for GeminiOrderBooksL2Transformer<InstrumentKey>
+where
+    InstrumentKey: Clone + Send,
+{
+    async fn init(
+        instrument_map: Map<InstrumentKey>,
+        _: &[MarketEvent<InstrumentKey, OrderBookEvent>],
+        _: UnboundedSender<WsMessage>,
+    ) -> Result<Self, DataError> {
+        let instrument_map = instrument_map
+            .0
+            .into_iter()
+            .map(|(sub_id, instrument_key)| {
+                let book_meta = GeminiOrderBookL2Meta::new(
+                    instrument_key,
+                    GeminiOrderBookL2Sequencer::default(),
+                );
+                (sub_id, book_meta)
+            })
+            .collect();
+
+        Ok(Self { instrument_map })
+    }
+}
+
+impl<InstrumentKey> Transformer for GeminiOrderBooksL2Transformer<InstrumentKey>
+where
+    InstrumentKey: Clone,
+{
+    type Error = DataError;
+    type Input = GeminiMessage;
+    type Output = MarketEvent<InstrumentKey, OrderBookEvent>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        // Trades are also received over the GeminiChannel::L2 stream, so are ignored
+        let GeminiMessage::L2Updates(update) = input else {
+            return vec![];
+        };
+
+        // Find Instrument associated with Input and transform
+        let instrument = match self.instrument_map.find_mut(&update.subscription_id) {
+            Ok(instrument) => instrument,
+            Err(unidentifiable) => return vec![Err(DataError::Socket(unidentifiable))],
+        };
+
+        // Gemini OrderBook updates are not timestamped by the exchange
+        let time_received = Utc::now();
+
+        vec![Ok(MarketEvent {
+            time_exchange: time_received,
+            time_received,
+            exchange: Gemini::ID,
+            instrument: instrument.key.clone(),
+            kind: instrument.sequencer.sequence(update),
+        })]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::books::Level;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_sequencer_sequence() {
+        let update = |changes| GeminiOrderBookL2Update {
+            subscription_id: SubscriptionId::from("l2|BTCUSD"),
+            changes,
+        };
+
+        let mut sequencer = GeminiOrderBookL2Sequencer::default();
+
+        // First update is the OrderBook snapshot
+        let snapshot = sequencer.sequence(update(vec![
+            GeminiLevel {
+                side: Side::Buy,
+                price: dec!(100),
+                amount: dec!(1),
+            },
+            GeminiLevel {
+                side: Side::Sell,
+                price: dec!(101),
+                amount: dec!(2),
+            },
+        ]));
+        assert_eq!(
+            snapshot,
+            OrderBookEvent::Snapshot(OrderBook::new(
+                1,
+                None,
+                vec![Level::new(100, 1)],
+                vec![Level::new(101, 2)],
+            ))
+        );
+
+        // Subsequent updates are OrderBook deltas
+        let delta = sequencer.sequence(update(vec![GeminiLevel {
+            side: Side::Sell,
+            price: dec!(101),
+            amount: dec!(0),
+        }]));
+        assert_eq!(
+            delta,
+            OrderBookEvent::Update(OrderBook::new(
+                2,
+                None,
+                Vec::<Level>::new(),
+                vec![Level::new(101, 0)],
+            ))
+        );
+    }
+}
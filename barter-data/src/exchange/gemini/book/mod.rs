@@ -0,0 +1,56 @@
+use crate::books::Level;
+use barter_integration::Side;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Level 2 OrderBook types.
+pub mod l2;
+
+/// [`Gemini`](super::Gemini) OrderBook level change, including the side of the OrderBook it
+/// applies to.
+///
+/// #### Raw Payload Examples
+/// See docs: <https://docs.gemini.com/websocket-api/#level-2-data>
+/// ```json
+/// ["buy", "9122.04", "0.00121425"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GeminiLevel {
+    pub side: Side,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub price: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub amount: Decimal,
+}
+
+impl From<GeminiLevel> for Level {
+    fn from(level: GeminiLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use rust_decimal_macros::dec;
+
+        #[test]
+        fn test_gemini_level() {
+            let input = r#"["sell", "9122.07", "0.98942292"]"#;
+            assert_eq!(
+                serde_json::from_str::<GeminiLevel>(input).unwrap(),
+                GeminiLevel {
+                    side: Side::Sell,
+                    price: dec!(9122.07),
+                    amount: dec!(0.98942292)
+                },
+            )
+        }
+    }
+}
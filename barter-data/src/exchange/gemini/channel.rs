@@ -0,0 +1,39 @@
+use super::Gemini;
+use crate::{
+    subscription::{book::OrderBooksL2, trade::PublicTrades, Subscription},
+    Identifier,
+};
+use serde::Serialize;
+
+/// Type that defines how to translate a Barter [`Subscription`] into a [`Gemini`] channel to be
+/// subscribed to.
+///
+/// See docs: <https://docs.gemini.com/websocket-api/#market-data-version-2>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct GeminiChannel(pub &'static str);
+
+impl GeminiChannel {
+    /// [`Gemini`] Level2 channel, publishing an initial OrderBook snapshot followed by
+    /// OrderBook deltas, interleaved with the real-time trades of the symbol.
+    ///
+    /// See docs: <https://docs.gemini.com/websocket-api/#level-2-data>
+    pub const L2: Self = Self("l2");
+}
+
+impl<Instrument> Identifier<GeminiChannel> for Subscription<Gemini, Instrument, PublicTrades> {
+    fn id(&self) -> GeminiChannel {
+        GeminiChannel::L2
+    }
+}
+
+impl<Instrument> Identifier<GeminiChannel> for Subscription<Gemini, Instrument, OrderBooksL2> {
+    fn id(&self) -> GeminiChannel {
+        GeminiChannel::L2
+    }
+}
+
+impl AsRef<str> for GeminiChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
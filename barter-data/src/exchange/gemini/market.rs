@@ -0,0 +1,42 @@
+use super::Gemini;
+use crate::{instrument::MarketInstrumentData, subscription::Subscription, Identifier};
+use barter_instrument::{asset::symbol::Symbol, instrument::Instrument, Keyed};
+use serde::{Deserialize, Serialize};
+use smol_str::{format_smolstr, SmolStr, StrExt};
+
+/// Type that defines how to translate a Barter [`Subscription`] into a [`Gemini`] market that
+/// can be subscribed to.
+///
+/// See docs: <https://docs.gemini.com/websocket-api/#market-data-version-2>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct GeminiMarket(pub SmolStr);
+
+impl<Kind> Identifier<GeminiMarket> for Subscription<Gemini, Instrument, Kind> {
+    fn id(&self) -> GeminiMarket {
+        gemini_market(&self.instrument.base, &self.instrument.quote)
+    }
+}
+
+impl<InstrumentKey, Kind> Identifier<GeminiMarket>
+    for Subscription<Gemini, Keyed<InstrumentKey, Instrument>, Kind>
+{
+    fn id(&self) -> GeminiMarket {
+        gemini_market(&self.instrument.value.base, &self.instrument.value.quote)
+    }
+}
+
+impl<Kind> Identifier<GeminiMarket> for Subscription<Gemini, MarketInstrumentData, Kind> {
+    fn id(&self) -> GeminiMarket {
+        GeminiMarket(self.instrument.name_exchange.clone())
+    }
+}
+
+impl AsRef<str> for GeminiMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+fn gemini_market(base: &Symbol, quote: &Symbol) -> GeminiMarket {
+    GeminiMarket(format_smolstr!("{base}{quote}").to_uppercase_smolstr())
+}
@@ -0,0 +1,111 @@
+use super::{book::l2::GeminiOrderBookL2Update, channel::GeminiChannel, trade::GeminiTrade};
+use crate::{exchange::subscription::ExchangeSub, Identifier};
+use barter_integration::subscription::SubscriptionId;
+use serde::{Deserialize, Serialize};
+
+/// [`Gemini`](super::Gemini) message variants that can be received over the
+/// [`GeminiChannel::L2`] market data stream.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.gemini.com/websocket-api/#level-2-data>
+/// #### OrderBook Level2 Snapshot Or Deltas
+/// ```json
+/// {
+///     "type": "l2_updates",
+///     "symbol": "BTCUSD",
+///     "changes": [
+///         ["buy", "9122.04", "0.00121425"],
+///         ["sell", "9122.07", "0.98942292"]
+///     ]
+/// }
+/// ```
+///
+/// #### Trade
+/// ```json
+/// {
+///     "type": "trade",
+///     "symbol": "BTCUSD",
+///     "event_id": 169841458,
+///     "timestamp": 1560976400428,
+///     "price": "9122.04",
+///     "quantity": "0.0073173",
+///     "side": "sell",
+///     "tid": 2840140800042677
+/// }
+/// ```
+///
+/// #### Heartbeat
+/// ```json
+/// {
+///     "type": "heartbeat",
+///     "timestamp": 1560976400428
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GeminiMessage {
+    L2Updates(GeminiOrderBookL2Update),
+    Trade(GeminiTrade),
+    #[serde(other)]
+    Other,
+}
+
+impl Identifier<Option<SubscriptionId>> for GeminiMessage {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            Self::L2Updates(update) => Some(update.subscription_id.clone()),
+            Self::Trade(trade) => Some(trade.subscription_id.clone()),
+            Self::Other => None,
+        }
+    }
+}
+
+/// Deserialize a [`GeminiMessage`] "symbol" (eg/ "BTCUSD") as the associated
+/// [`SubscriptionId`].
+///
+/// eg/ "l2|BTCUSD"
+pub fn de_message_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|market| ExchangeSub::from((GeminiChannel::L2, market)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_gemini_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<SubscriptionId>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: OrderBook Level2 deltas
+                input: r#"{"type": "l2_updates", "symbol": "BTCUSD", "changes": [["sell", "9160.15", "0.0"]]}"#,
+                expected: Some(SubscriptionId::from("l2|BTCUSD")),
+            },
+            TestCase {
+                // TC1: trade
+                input: r#"{"type": "trade", "symbol": "ETHUSD", "event_id": 1, "timestamp": 1560976400428, "price": "1.0", "quantity": "2.0", "side": "buy", "tid": 1}"#,
+                expected: Some(SubscriptionId::from("l2|ETHUSD")),
+            },
+            TestCase {
+                // TC2: heartbeat
+                input: r#"{"type": "heartbeat", "timestamp": 1560976400428}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<GeminiMessage>(test.input)
+                .unwrap()
+                .id();
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use self::{
+    book::l2::GeminiOrderBooksL2Transformer, channel::GeminiChannel, market::GeminiMarket,
+    message::GeminiMessage, subscription::GeminiSubResponse,
+};
+use crate::{
+    exchange::{Connector, ExchangeSub, StreamSelector},
+    instrument::InstrumentData,
+    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscription::{book::OrderBooksL2, trade::PublicTrades, Map},
+    transformer::stateless::StatelessTransformer,
+    ExchangeWsStream, NoInitialSnapshots,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_macro::{DeExchange, SerExchange};
+use serde_json::json;
+use url::Url;
+
+/// OrderBook types for [`Gemini`].
+pub mod book;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
+pub mod channel;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
+pub mod market;
+
+/// [`GeminiMessage`] variants that can be received over the [`Gemini`] market data stream.
+pub mod message;
+
+/// [`Subscription`](crate::subscription::Subscription) response type and response
+/// [`Validator`](barter_integration::Validator) for [`Gemini`].
+pub mod subscription;
+
+/// Public trade types for [`Gemini`].
+pub mod trade;
+
+/// [`Gemini`] market data server base url.
+///
+/// See docs: <https://docs.gemini.com/websocket-api/#market-data-version-2>
+pub const BASE_URL_GEMINI: &str = "wss://api.gemini.com/v2/marketdata";
+
+/// [`Gemini`] spot exchange.
+///
+/// See docs: <https://docs.gemini.com/websocket-api/#market-data-version-2>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DeExchange, SerExchange,
+)]
+pub struct Gemini;
+
+impl Connector for Gemini {
+    const ID: ExchangeId = ExchangeId::Gemini;
+    type Channel = GeminiChannel;
+    type Market = GeminiMarket;
+    type Subscriber = WebSocketSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = GeminiSubResponse;
+
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(BASE_URL_GEMINI).map_err(SocketError::UrlParse)
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        let symbols = exchange_subs
+            .into_iter()
+            .map(|ExchangeSub { market, .. }| market.0)
+            .collect::<Vec<_>>();
+
+        vec![WsMessage::text(
+            json!({
+                "type": "subscribe",
+                "subscriptions": [{
+                    "name": GeminiChannel::L2.as_ref(),
+                    "symbols": symbols,
+                }],
+            })
+            .to_string(),
+        )]
+    }
+
+    /// [`Gemini`] does not acknowledge successful subscriptions, and the first message of each
+    /// subscribed symbol is the initial OrderBook snapshot, so no responses are awaited.
+    fn expected_responses<InstrumentKey>(_: &Map<InstrumentKey>) -> usize {
+        0
+    }
+}
+
+impl<Instrument> StreamSelector<Instrument, PublicTrades> for Gemini
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Key, PublicTrades, GeminiMessage>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL2> for Gemini
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = ExchangeWsStream<GeminiOrderBooksL2Transformer<Instrument::Key>>;
+}
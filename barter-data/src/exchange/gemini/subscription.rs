@@ -0,0 +1,42 @@
+use barter_integration::{error::SocketError, Validator};
+use serde::{Deserialize, Serialize};
+
+/// [`Gemini`](super::Gemini) WebSocket error response.
+///
+/// ### Notes
+/// [`Gemini`](super::Gemini) does not acknowledge successful subscriptions, so no
+/// [`GeminiSubResponse`] is awaited during subscription validation (see
+/// [`Connector::expected_responses`](crate::exchange::Connector::expected_responses)).
+///
+/// ### Raw Payload Examples
+/// #### Subscription Error Response
+/// ```json
+/// {
+///     "result": "error",
+///     "reason": "InvalidJson",
+///     "message": "Malformed subscription request"
+/// }
+/// ```
+///
+/// See docs: <https://docs.gemini.com/websocket-api/#market-data-version-2>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct GeminiSubResponse {
+    pub result: String,
+    pub reason: String,
+}
+
+impl Validator for GeminiSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        if self.result == "error" {
+            Err(SocketError::Subscribe(format!(
+                "received failure subscription response: {}",
+                self.reason
+            )))
+        } else {
+            Ok(self)
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use super::message::{de_message_subscription_id, GeminiMessage};
+use crate::{
+    event::{MarketEvent, MarketIter},
+    subscription::trade::PublicTrade,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::{subscription::SubscriptionId, Side};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Gemini`](super::Gemini) real-time trade, where the side is that of the taker.
+///
+/// See [`GeminiMessage`] for full raw payload examples.
+///
+/// See docs: <https://docs.gemini.com/websocket-api/#level-2-data>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GeminiTrade {
+    #[serde(alias = "symbol", deserialize_with = "de_message_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(alias = "tid")]
+    pub id: u64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(
+        alias = "quantity",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub amount: f64,
+    pub side: Side,
+    #[serde(
+        alias = "timestamp",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, GeminiMessage)>
+    for MarketIter<InstrumentKey, PublicTrade>
+{
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, InstrumentKey, GeminiMessage),
+    ) -> Self {
+        // OrderBook updates are also received over the GeminiChannel::L2 stream, so are ignored
+        let GeminiMessage::Trade(trade) = message else {
+            return Self(vec![]);
+        };
+
+        Self(vec![Ok(MarketEvent {
+            time_exchange: trade.time,
+            time_received: Utc::now(),
+            exchange: exchange_id,
+            instrument,
+            kind: PublicTrade {
+                id: trade.id.to_string(),
+                price: trade.price,
+                amount: trade.amount,
+                side: trade.side,
+                aggregate_count: None,
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_gemini_trade() {
+        let input = r#"
+        {
+            "type": "trade",
+            "symbol": "BTCUSD",
+            "event_id": 169841458,
+            "timestamp": 1560976400428,
+            "price": "9122.04",
+            "quantity": "0.0073173",
+            "side": "sell",
+            "tid": 2840140800042677
+        }
+        "#;
+
+        assert_eq!(
+            serde_json::from_str::<GeminiMessage>(input).unwrap(),
+            GeminiMessage::Trade(GeminiTrade {
+                subscription_id: SubscriptionId::from("l2|BTCUSD"),
+                id: 2840140800042677,
+                price: 9122.04,
+                amount: 0.0073173,
+                side: Side::Sell,
+                time: DateTime::from_timestamp_millis(1560976400428).unwrap(),
+            })
+        );
+    }
+}
@@ -0,0 +1,785 @@
+use super::super::{channel::KrakenChannel, message::KrakenMessage, Kraken};
+use crate::{
+    books::{Level, OrderBook},
+    error::DataError,
+    event::MarketEvent,
+    exchange::{subscription::ExchangeSub, Connector},
+    subscription::{
+        book::{OrderBookEvent, OrderBooksL2},
+        Map,
+    },
+    transformer::ExchangeTransformer,
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::{datetime_utc_from_epoch_duration, extract_next},
+    protocol::websocket::WsMessage,
+    subscription::SubscriptionId,
+    Transformer,
+};
+use chrono::{DateTime, Utc};
+use derive_more::Constructor;
+use flate2::Crc;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use tokio::sync::mpsc::UnboundedSender;
+
+/// [`Kraken`] OrderBook Level2 depth subscribed to, and maintained locally to validate checksums.
+///
+/// See docs: <https://docs.kraken.com/websockets/#message-subscribe>
+pub const ORDER_BOOK_L2_DEPTH_KRAKEN: usize = 100;
+
+/// Number of [`Kraken`] OrderBook levels per side included in the checksum.
+///
+/// See docs: <https://docs.kraken.com/websockets/#book-checksum>
+pub const ORDER_BOOK_L2_CHECKSUM_DEPTH_KRAKEN: usize = 10;
+
+/// Terse type alias for an [`Kraken`] OrderBook Level2 snapshot or deltas WebSocket message.
+pub type KrakenOrderBookL2 = KrakenMessage<KrakenOrderBookL2Inner>;
+
+/// [`Kraken`] OrderBook Level2 snapshot or deltas and the associated [`SubscriptionId`].
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.kraken.com/websockets/#message-book>
+/// #### Snapshot
+/// ```json
+/// [
+///     0,
+///     {
+///         "as": [["5541.30000", "2.50700000", "1534614248.123678"]],
+///         "bs": [["5541.20000", "1.52900000", "1534614248.765567"]]
+///     },
+///     "book-100",
+///     "XBT/USD"
+/// ]
+/// ```
+///
+/// #### Update
+/// Asks & bids deltas may arrive in the same object, or in two separate objects. Republished
+/// levels contain an additional "r" element.
+/// ```json
+/// [
+///     1234,
+///     {"a": [["5541.30000", "2.50700000", "1534614248.456738", "r"]]},
+///     {"b": [["5541.30000", "0.00000000", "1534614335.345903"]], "c": "974942666"},
+///     "book-100",
+///     "XBT/USD"
+/// ]
+/// ```
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub struct KrakenOrderBookL2Inner {
+    pub subscription_id: SubscriptionId,
+    pub kind: KrakenOrderBookL2Kind,
+}
+
+/// [`Kraken`] OrderBook Level2 message kind.
+///
+/// See [`KrakenOrderBookL2Inner`] for full raw payload examples.
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub enum KrakenOrderBookL2Kind {
+    Snapshot {
+        bids: Vec<KrakenLevel>,
+        asks: Vec<KrakenLevel>,
+    },
+    Update {
+        bids: Vec<KrakenLevel>,
+        asks: Vec<KrakenLevel>,
+        checksum: u32,
+    },
+}
+
+impl KrakenOrderBookL2Kind {
+    /// Most recent [`KrakenLevel`] timestamp, if any.
+    pub fn time(&self) -> Option<DateTime<Utc>> {
+        let (Self::Snapshot { bids, asks } | Self::Update { bids, asks, .. }) = self;
+        bids.iter().chain(asks).map(|level| level.time).max()
+    }
+}
+
+/// [`Kraken`] OrderBook level.
+///
+/// #### Raw Payload Examples
+/// See docs: <https://docs.kraken.com/websockets/#message-book>
+/// ```json
+/// ["5541.30000", "2.50700000", "1534614248.456738"]
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug, Serialize)]
+pub struct KrakenLevel {
+    pub price: Decimal,
+    pub amount: Decimal,
+    pub time: DateTime<Utc>,
+}
+
+impl From<KrakenLevel> for Level {
+    fn from(level: KrakenLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+impl Identifier<Option<SubscriptionId>> for KrakenOrderBookL2Inner {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+#[derive(Debug, Constructor)]
+pub struct KrakenOrderBookL2Meta<InstrumentKey> {
+    pub key: InstrumentKey,
+    pub sequencer: KrakenOrderBookL2Sequencer,
+}
+
+/// [`Kraken`] OrderBook Level2 sequencer.
+///
+/// [`Kraken`] does not publish update identifiers, so a local [`OrderBook`] is maintained in
+/// order to validate the checksum of each update, and the number of messages processed is used
+/// as the [`OrderBook`] sequence.
+///
+/// Kraken: Maintaining The Local OrderBook
+///
+/// 1. The first message after subscribing is the snapshot of the subscribed depth.
+/// 2. Each update contains the absolute amount of each changed level, where 0 removes the level.
+/// 3. After applying an update, levels beyond the subscribed depth must be truncated, since
+///    [`Kraken`] does not send explicit removals for levels falling out of scope.
+/// 4. The CRC32 checksum of the top 10 asks (ascending) followed by the top 10 bids (descending)
+///    must match the update checksum, otherwise the OrderBook must be re-synchronised.
+///
+/// See docs: <https://docs.kraken.com/websockets/#book-checksum>
+#[derive(Debug, Default)]
+pub struct KrakenOrderBookL2Sequencer {
+    pub updates_processed: u64,
+    pub book: Option<OrderBook>,
+}
+
+impl KrakenOrderBookL2Sequencer {
+    /// Sequence the provided [`KrakenOrderBookL2Kind`] into an [`OrderBookEvent`], validating
+    /// the checksum of updates against the local [`OrderBook`].
+    ///
+    /// Levels truncated from the local [`OrderBook`] are appended to the output update with a
+    /// zero amount so downstream [`OrderBook`]s also remove them.
+    pub fn sequence(
+        &mut self,
+        kind: KrakenOrderBookL2Kind,
+    ) -> Result<Option<OrderBookEvent>, DataError> {
+        match kind {
+            KrakenOrderBookL2Kind::Snapshot { bids, asks } => {
+                self.updates_processed += 1;
+                let snapshot = OrderBook::new(self.updates_processed, None, bids, asks)
+                    .snapshot(ORDER_BOOK_L2_DEPTH_KRAKEN);
+                self.book = Some(snapshot.clone());
+                Ok(Some(OrderBookEvent::Snapshot(snapshot)))
+            }
+            KrakenOrderBookL2Kind::Update {
+                bids,
+                asks,
+                checksum,
+            } => {
+                // Updates received before the initial snapshot cannot be validated
+                let Some(book) = self.book.as_mut() else {
+                    return Ok(None);
+                };
+
+                self.updates_processed += 1;
+                let mut bids = bids.into_iter().map(Level::from).collect::<Vec<_>>();
+                let mut asks = asks.into_iter().map(Level::from).collect::<Vec<_>>();
+
+                // Apply update & truncate local OrderBook to the subscribed depth
+                book.update(OrderBookEvent::Update(OrderBook::new(
+                    self.updates_processed,
+                    None,
+                    bids.iter().copied(),
+                    asks.iter().copied(),
+                )));
+                bids.extend(truncated(book.bids().levels()));
+                asks.extend(truncated(book.asks().levels()));
+                *book = book.snapshot(ORDER_BOOK_L2_DEPTH_KRAKEN);
+
+                let actual = kraken_checksum(book);
+                if actual != checksum {
+                    return Err(DataError::InvalidChecksum {
+                        expected: checksum,
+                        actual,
+                    });
+                }
+
+                Ok(Some(OrderBookEvent::Update(OrderBook::new(
+                    self.updates_processed,
+                    None,
+                    bids,
+                    asks,
+                ))))
+            }
+        }
+    }
+}
+
+/// Generate zero amount [`Level`]s for every level beyond [`ORDER_BOOK_L2_DEPTH_KRAKEN`].
+fn truncated(levels: &[Level]) -> impl Iterator<Item = Level> + '_ {
+    levels
+        .iter()
+        .skip(ORDER_BOOK_L2_DEPTH_KRAKEN)
+        .map(|level| Level::new(level.price, Decimal::ZERO))
+}
+
+/// Calculate the [`Kraken`] CRC32 checksum of the provided [`OrderBook`].
+///
+/// The checksum input is the concatenation of the price & amount of the top 10 asks
+/// (ascending), followed by the top 10 bids (descending), where each value has its decimal
+/// point and leading zeros removed.
+///
+/// See docs: <https://docs.kraken.com/websockets/#book-checksum>
+pub fn kraken_checksum(book: &OrderBook) -> u32 {
+    let input = book
+        .asks()
+        .levels()
+        .iter()
+        .take(ORDER_BOOK_L2_CHECKSUM_DEPTH_KRAKEN)
+        .chain(
+            book.bids()
+                .levels()
+                .iter()
+                .take(ORDER_BOOK_L2_CHECKSUM_DEPTH_KRAKEN),
+        )
+        .fold(String::new(), |mut input, level| {
+            input.push_str(checksum_str(level.price).as_str());
+            input.push_str(checksum_str(level.amount).as_str());
+            input
+        });
+
+    let mut crc = Crc::new();
+    crc.update(input.as_bytes());
+    crc.sum()
+}
+
+/// Format a [`Decimal`] for the [`Kraken`] checksum input, retaining the precision the exchange
+/// sent it with.
+///
+/// eg/ "0.00000500" => "500"
+fn checksum_str(value: Decimal) -> String {
+    value
+        .to_string()
+        .replace('.', "")
+        .trim_start_matches('0')
+        .to_string()
+}
+
+#[derive(Debug)]
+pub struct KrakenOrderBooksL2Transformer<InstrumentKey> {
+    instrument_map: Map<KrakenOrderBookL2Meta<InstrumentKey>>,
+}
+
+#[async_trait]
+impl<InstrumentKey> ExchangeTransformer<Kraken, InstrumentKey, OrderBooksL2>
+    for KrakenOrderBooksL2Transformer<InstrumentKey>
+where
+    InstrumentKey: Clone + Send,
+{
+    async fn init(
+        instrument_map: Map<InstrumentKey>,
+        _: &[MarketEvent<InstrumentKey, OrderBookEvent>],
+        _: UnboundedSender<WsMessage>,
+    ) -> Result<Self, DataError> {
+        let instrument_map = instrument_map
+            .0
+            .into_iter()
+            .map(|(sub_id, instrument_key)| {
+                let book_meta = KrakenOrderBookL2Meta::new(
+                    instrument_key,
+                    KrakenOrderBookL2Sequencer::default(),
+                );
+                (sub_id, book_meta)
+            })
+            .collect();
+
+        Ok(Self { instrument_map })
+    }
+}
+
+impl<InstrumentKey> Transformer for KrakenOrderBooksL2Transformer<InstrumentKey>
+where
+    InstrumentKey: Clone,
+{
+    type Error = DataError;
+    type Input = KrakenOrderBookL2;
+    type Output = MarketEvent<InstrumentKey, OrderBookEvent>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        // Heartbeats & errors are also received over the WebSocket, so are ignored
+        let KrakenOrderBookL2::Data(book) = input else {
+            return vec![];
+        };
+
+        // Find Instrument associated with Input and transform
+        let instrument = match self.instrument_map.find_mut(&book.subscription_id) {
+            Ok(instrument) => instrument,
+            Err(unidentifiable) => return vec![Err(DataError::Socket(unidentifiable))],
+        };
+
+        let time_received = Utc::now();
+        let time_exchange = book.kind.time().unwrap_or(time_received);
+
+        match instrument.sequencer.sequence(book.kind) {
+            Ok(Some(event)) => vec![Ok(MarketEvent {
+                time_exchange,
+                time_received,
+                exchange: Kraken::ID,
+                instrument: instrument.key.clone(),
+                kind: event,
+            })],
+            Ok(None) => vec![],
+            Err(error) => vec![Err(error)],
+        }
+    }
+}
+
+/// Intermediate [`Kraken`] OrderBook Level2 object contained in a [`KrakenOrderBookL2Inner`]
+/// message, which may contain snapshot levels, delta levels and/or the checksum.
+#[derive(Deserialize)]
+struct KrakenOrderBookL2Object {
+    #[serde(default, rename = "bs")]
+    snapshot_bids: Option<Vec<KrakenLevel>>,
+    #[serde(default, rename = "as")]
+    snapshot_asks: Option<Vec<KrakenLevel>>,
+    #[serde(default, rename = "b")]
+    bids: Vec<KrakenLevel>,
+    #[serde(default, rename = "a")]
+    asks: Vec<KrakenLevel>,
+    #[serde(default, rename = "c")]
+    checksum: Option<String>,
+}
+
+/// Element following the channelID of a [`KrakenOrderBookL2Inner`] message, which is either a
+/// [`KrakenOrderBookL2Object`], or the channelName that follows the last object.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum KrakenOrderBookL2Element {
+    Object(KrakenOrderBookL2Object),
+    ChannelName(serde::de::IgnoredAny),
+}
+
+impl<'de> Deserialize<'de> for KrakenOrderBookL2Inner {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = KrakenOrderBookL2Inner;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("KrakenOrderBookL2Inner struct from the Kraken WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // KrakenOrderBookL2Inner Sequence Format:
+                // [channelID, {book}, ({book},) channelName, pair]
+                // <https://docs.kraken.com/websockets/#message-book>
+
+                // Extract deprecated channelID & ignore
+                let _: serde::de::IgnoredAny = extract_next(&mut seq, "channelID")?;
+
+                // Extract book objects until the channelName (eg/ "book-100") is reached
+                let mut objects = Vec::with_capacity(2);
+                while let KrakenOrderBookL2Element::Object(object) =
+                    extract_next(&mut seq, "channelName")?
+                {
+                    objects.push(object);
+                }
+
+                // Extract pair (eg/ "XBT/USD") & map to SubscriptionId (ie/ "book|{pair}")
+                let subscription_id = extract_next::<SeqAccessor, String>(&mut seq, "pair")
+                    .map(|market| ExchangeSub::from((KrakenChannel::ORDER_BOOK_L2, market)).id())?;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                // Merge book objects, since asks & bids deltas may be sent in separate objects
+                let mut snapshot = None;
+                let (mut bids, mut asks, mut checksum) = (vec![], vec![], None);
+                for object in objects {
+                    if object.snapshot_bids.is_some() || object.snapshot_asks.is_some() {
+                        snapshot = Some((
+                            object.snapshot_bids.unwrap_or_default(),
+                            object.snapshot_asks.unwrap_or_default(),
+                        ));
+                    }
+
+                    bids.extend(object.bids);
+                    asks.extend(object.asks);
+
+                    if let Some(object_checksum) = object.checksum {
+                        checksum = Some(
+                            object_checksum
+                                .parse::<u32>()
+                                .map_err(serde::de::Error::custom)?,
+                        );
+                    }
+                }
+
+                let kind = match (snapshot, checksum) {
+                    (Some((bids, asks)), _) => KrakenOrderBookL2Kind::Snapshot { bids, asks },
+                    (None, Some(checksum)) => KrakenOrderBookL2Kind::Update {
+                        bids,
+                        asks,
+                        checksum,
+                    },
+                    (None, None) => return Err(serde::de::Error::missing_field("c")),
+                };
+
+                Ok(KrakenOrderBookL2Inner {
+                    subscription_id,
+                    kind,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialize the KrakenOrderBookL2Inner
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for KrakenLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = KrakenLevel;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("KrakenLevel struct from the Kraken WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // KrakenLevel Sequence Format:
+                // [price, volume, timestamp, (updateType)]
+                // <https://docs.kraken.com/websockets/#message-book>
+
+                // Extract String price & parse to Decimal, retaining the exchange precision
+                let price = extract_next::<SeqAccessor, String>(&mut seq, "price")?;
+                let price = Decimal::from_str(&price).map_err(serde::de::Error::custom)?;
+
+                // Extract String volume & parse to Decimal, retaining the exchange precision
+                let amount = extract_next::<SeqAccessor, String>(&mut seq, "volume")?;
+                let amount = Decimal::from_str(&amount).map_err(serde::de::Error::custom)?;
+
+                // Extract String timestamp & parse to DateTime
+                let time = extract_next::<SeqAccessor, String>(&mut seq, "timestamp")?
+                    .parse()
+                    .map(|time| {
+                        datetime_utc_from_epoch_duration(std::time::Duration::from_secs_f64(time))
+                    })
+                    .map_err(serde::de::Error::custom)?;
+
+                // Ignore any additional elements (eg/ "r" republish updateType) or SerDe will fail
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(KrakenLevel {
+                    price,
+                    amount,
+                    time,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialize the KrakenLevel
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::kraken::message::KrakenEvent;
+    use rust_decimal_macros::dec;
+
+    fn level(price: Decimal, amount: Decimal) -> KrakenLevel {
+        KrakenLevel {
+            price,
+            amount,
+            time: DateTime::from_timestamp_millis(1582905487684).unwrap(),
+        }
+    }
+
+    /// Kraken checksum documentation example, with a checksum of 974947235.
+    ///
+    /// See docs: <https://docs.kraken.com/websockets/#book-checksum>
+    fn docs_snapshot() -> KrakenOrderBookL2Kind {
+        let asks = [
+            dec!(0.05005),
+            dec!(0.05010),
+            dec!(0.05015),
+            dec!(0.05020),
+            dec!(0.05025),
+            dec!(0.05030),
+            dec!(0.05035),
+            dec!(0.05040),
+            dec!(0.05045),
+            dec!(0.05050),
+        ];
+        let bids = [
+            dec!(0.05000),
+            dec!(0.04995),
+            dec!(0.04990),
+            dec!(0.04980),
+            dec!(0.04975),
+            dec!(0.04970),
+            dec!(0.04965),
+            dec!(0.04960),
+            dec!(0.04955),
+            dec!(0.04950),
+        ];
+
+        KrakenOrderBookL2Kind::Snapshot {
+            bids: bids
+                .into_iter()
+                .map(|price| level(price, dec!(0.00000500)))
+                .collect(),
+            asks: asks
+                .into_iter()
+                .map(|price| level(price, dec!(0.00000500)))
+                .collect(),
+        }
+    }
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_kraken_order_book_l2() {
+            struct TestCase {
+                input: &'static str,
+                expected: KrakenOrderBookL2,
+            }
+
+            let time = |secs: f64| {
+                datetime_utc_from_epoch_duration(std::time::Duration::from_secs_f64(secs))
+            };
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid snapshot
+                    input: r#"
+                    [
+                        0,
+                        {
+                            "as": [["5541.30000", "2.50700000", "1534614248.123678"]],
+                            "bs": [["5541.20000", "1.52900000", "1534614248.765567"]]
+                        },
+                        "book-100",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: KrakenOrderBookL2::Data(KrakenOrderBookL2Inner {
+                        subscription_id: SubscriptionId::from("book|XBT/USD"),
+                        kind: KrakenOrderBookL2Kind::Snapshot {
+                            bids: vec![KrakenLevel {
+                                price: dec!(5541.20000),
+                                amount: dec!(1.52900000),
+                                time: time(1534614248.765567),
+                            }],
+                            asks: vec![KrakenLevel {
+                                price: dec!(5541.30000),
+                                amount: dec!(2.50700000),
+                                time: time(1534614248.123678),
+                            }],
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC1: valid update with asks only
+                    input: r#"
+                    [
+                        1234,
+                        {
+                            "a": [["5541.30000", "2.50700000", "1534614248.456738"]],
+                            "c": "974942666"
+                        },
+                        "book-100",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: KrakenOrderBookL2::Data(KrakenOrderBookL2Inner {
+                        subscription_id: SubscriptionId::from("book|XBT/USD"),
+                        kind: KrakenOrderBookL2Kind::Update {
+                            bids: vec![],
+                            asks: vec![KrakenLevel {
+                                price: dec!(5541.30000),
+                                amount: dec!(2.50700000),
+                                time: time(1534614248.456738),
+                            }],
+                            checksum: 974942666,
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC2: valid update with asks & bids in separate objects, and republish
+                    input: r#"
+                    [
+                        1234,
+                        {"a": [["5541.30000", "2.50700000", "1534614248.456738", "r"]]},
+                        {
+                            "b": [["5541.30000", "0.00000000", "1534614335.345903"]],
+                            "c": "974942666"
+                        },
+                        "book-100",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: KrakenOrderBookL2::Data(KrakenOrderBookL2Inner {
+                        subscription_id: SubscriptionId::from("book|XBT/USD"),
+                        kind: KrakenOrderBookL2Kind::Update {
+                            bids: vec![KrakenLevel {
+                                price: dec!(5541.30000),
+                                amount: dec!(0.00000000),
+                                time: time(1534614335.345903),
+                            }],
+                            asks: vec![KrakenLevel {
+                                price: dec!(5541.30000),
+                                amount: dec!(2.50700000),
+                                time: time(1534614248.456738),
+                            }],
+                            checksum: 974942666,
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC3: heartbeat
+                    input: r#"{"event": "heartbeat"}"#,
+                    expected: KrakenOrderBookL2::Event(KrakenEvent::Heartbeat),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KrakenOrderBookL2>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_kraken_checksum() {
+        let mut sequencer = KrakenOrderBookL2Sequencer::default();
+        sequencer.sequence(docs_snapshot()).unwrap();
+
+        assert_eq!(kraken_checksum(sequencer.book.as_ref().unwrap()), 974947235);
+    }
+
+    #[test]
+    fn test_sequencer_sequence() {
+        // Update received before the snapshot is dropped
+        let mut sequencer = KrakenOrderBookL2Sequencer::default();
+        let update = KrakenOrderBookL2Kind::Update {
+            bids: vec![],
+            asks: vec![],
+            checksum: 0,
+        };
+        assert!(matches!(sequencer.sequence(update), Ok(None)));
+
+        // Snapshot is output as an OrderBookEvent::Snapshot
+        let snapshot = sequencer.sequence(docs_snapshot()).unwrap();
+        assert!(matches!(snapshot, Some(OrderBookEvent::Snapshot(_))));
+
+        // Update with a valid checksum is output as an OrderBookEvent::Update
+        let bid = level(dec!(0.04985), dec!(0.00000500));
+        let mut expected = sequencer.book.clone().unwrap();
+        expected.update(OrderBookEvent::Update(OrderBook::new(
+            0,
+            None,
+            vec![Level::from(bid)],
+            Vec::<Level>::new(),
+        )));
+        let update = KrakenOrderBookL2Kind::Update {
+            bids: vec![bid],
+            asks: vec![],
+            checksum: kraken_checksum(&expected),
+        };
+        assert_eq!(
+            sequencer.sequence(update).unwrap(),
+            Some(OrderBookEvent::Update(OrderBook::new(
+                2,
+                None,
+                vec![Level::from(bid)],
+                Vec::<Level>::new(),
+            )))
+        );
+
+        // Update with an invalid checksum is an error
+        let update = KrakenOrderBookL2Kind::Update {
+            bids: vec![level(dec!(0.04945), dec!(0.00000500))],
+            asks: vec![],
+            checksum: 974947235,
+        };
+        assert!(matches!(
+            sequencer.sequence(update),
+            Err(DataError::InvalidChecksum { .. })
+        ));
+    }
+
+    #[test]
+    fn test_sequencer_truncates_to_depth() {
+        let mut sequencer = KrakenOrderBookL2Sequencer::default();
+        sequencer
+            .sequence(KrakenOrderBookL2Kind::Snapshot {
+                bids: (1..=ORDER_BOOK_L2_DEPTH_KRAKEN)
+                    .map(|price| level(Decimal::from(price), dec!(1)))
+                    .collect(),
+                asks: vec![],
+            })
+            .unwrap();
+
+        // New best bid pushes the worst bid beyond the subscribed depth
+        let bid = level(dec!(1000), dec!(1));
+        let mut expected = sequencer.book.clone().unwrap();
+        expected.update(OrderBookEvent::Update(OrderBook::new(
+            0,
+            None,
+            vec![Level::from(bid)],
+            Vec::<Level>::new(),
+        )));
+        let expected = expected.snapshot(ORDER_BOOK_L2_DEPTH_KRAKEN);
+
+        let actual = sequencer
+            .sequence(KrakenOrderBookL2Kind::Update {
+                bids: vec![bid],
+                asks: vec![],
+                checksum: kraken_checksum(&expected),
+            })
+            .unwrap();
+
+        assert_eq!(
+            actual,
+            Some(OrderBookEvent::Update(OrderBook::new(
+                2,
+                None,
+                vec![Level::from(bid), Level::new(dec!(1), dec!(0))],
+                Vec::<Level>::new(),
+            )))
+        );
+        assert_eq!(
+            sequencer.book.unwrap().bids().levels().len(),
+            ORDER_BOOK_L2_DEPTH_KRAKEN
+        );
+    }
+}
@@ -1,12 +1,22 @@
 use self::{
-    book::l1::KrakenOrderBookL1, channel::KrakenChannel, market::KrakenMarket,
-    message::KrakenMessage, subscription::KrakenSubResponse, trade::KrakenTrades,
+    book::{
+        l1::KrakenOrderBookL1,
+        l2::{KrakenOrderBooksL2Transformer, ORDER_BOOK_L2_DEPTH_KRAKEN},
+    },
+    channel::KrakenChannel,
+    market::KrakenMarket,
+    message::KrakenMessage,
+    subscription::KrakenSubResponse,
+    trade::KrakenTrades,
 };
 use crate::{
     exchange::{Connector, ExchangeSub, StreamSelector},
     instrument::InstrumentData,
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{book::OrderBooksL1, trade::PublicTrades},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        trade::PublicTrades,
+    },
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream, NoInitialSnapshots,
 };
@@ -66,13 +76,22 @@ impl Connector for Kraken {
         exchange_subs
             .into_iter()
             .map(|ExchangeSub { channel, market }| {
+                // OrderBook Level2 subscriptions also specify the depth of the OrderBook
+                let subscription = match channel {
+                    KrakenChannel::ORDER_BOOK_L2 => json!({
+                        "name": channel.as_ref(),
+                        "depth": ORDER_BOOK_L2_DEPTH_KRAKEN
+                    }),
+                    _ => json!({
+                        "name": channel.as_ref()
+                    }),
+                };
+
                 WsMessage::Text(
                     json!({
                         "event": "subscribe",
                         "pair": [market.as_ref()],
-                        "subscription": {
-                            "name": channel.as_ref()
-                        }
+                        "subscription": subscription
                     })
                     .to_string(),
                 )
@@ -99,3 +118,11 @@ where
         StatelessTransformer<Self, Instrument::Key, OrderBooksL1, KrakenOrderBookL1>,
     >;
 }
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL2> for Kraken
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = ExchangeWsStream<KrakenOrderBooksL2Transformer<Instrument::Key>>;
+}
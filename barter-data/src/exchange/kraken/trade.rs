@@ -78,6 +78,7 @@ impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, KrakenTrades)>
                             price: trade.price,
                             amount: trade.amount,
                             side: trade.side,
+                            aggregate_count: None,
                         },
                     })
                 })
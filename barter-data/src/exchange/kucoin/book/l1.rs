@@ -0,0 +1,102 @@
+use super::{super::message::KucoinMessage, KucoinLevel};
+use crate::{
+    event::{MarketEvent, MarketIter},
+    subscription::book::OrderBookL1,
+};
+use barter_instrument::exchange::ExchangeId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Kucoin`](super::super::Kucoin) real-time OrderBook Level1
+/// (top of books) WebSocket message.
+pub type KucoinOrderBookL1 = KucoinMessage<KucoinBestBidAsk>;
+
+/// [`Kucoin`](super::super::Kucoin) best bid and ask.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/level1-bbo-market-data>
+/// ```json
+/// {
+///     "type": "message",
+///     "topic": "/spotMarket/level1:BTC-USDT",
+///     "subject": "level1",
+///     "data": {
+///         "asks": ["9989", "8"],
+///         "bids": ["9983", "10"],
+///         "timestamp": 1586948108193
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KucoinBestBidAsk {
+    #[serde(alias = "bids")]
+    pub best_bid: KucoinLevel,
+    #[serde(alias = "asks")]
+    pub best_ask: KucoinLevel,
+    #[serde(
+        alias = "timestamp",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, KucoinOrderBookL1)>
+    for MarketIter<InstrumentKey, OrderBookL1>
+{
+    fn from(
+        (exchange_id, instrument, book): (ExchangeId, InstrumentKey, KucoinOrderBookL1),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            time_exchange: book.data.time,
+            time_received: Utc::now(),
+            exchange: exchange_id,
+            instrument,
+            kind: OrderBookL1 {
+                last_update_time: book.data.time,
+                best_bid: book.data.best_bid.into(),
+                best_ask: book.data.best_ask.into(),
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::subscription::SubscriptionId;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_de_kucoin_order_book_l1() {
+        let input = r#"
+        {
+            "type": "message",
+            "topic": "/spotMarket/level1:BTC-USDT",
+            "subject": "level1",
+            "data": {
+                "asks": ["9989", "8"],
+                "bids": ["9983", "10"],
+                "timestamp": 1586948108193
+            }
+        }
+        "#;
+
+        assert_eq!(
+            serde_json::from_str::<KucoinOrderBookL1>(input).unwrap(),
+            KucoinOrderBookL1 {
+                subscription_id: SubscriptionId::from("/spotMarket/level1|BTC-USDT"),
+                data: KucoinBestBidAsk {
+                    best_bid: KucoinLevel {
+                        price: dec!(9983),
+                        amount: dec!(10)
+                    },
+                    best_ask: KucoinLevel {
+                        price: dec!(9989),
+                        amount: dec!(8)
+                    },
+                    time: DateTime::from_timestamp_millis(1586948108193).unwrap(),
+                },
+            }
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use super::{super::message::KucoinMessage, KucoinLevel};
+use crate::{
+    books::OrderBook,
+    event::{MarketEvent, MarketIter},
+    subscription::book::OrderBookEvent,
+};
+use barter_instrument::exchange::ExchangeId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Kucoin`](super::super::Kucoin) OrderBook Level2 WebSocket message.
+pub type KucoinOrderBookL2 = KucoinMessage<KucoinOrderBookL2Snapshot>;
+
+/// [`Kucoin`](super::super::Kucoin) OrderBook Level2 snapshot of the top 50 levels of each side.
+///
+/// Every message is a complete snapshot, so no sequencing of deltas is required. The
+/// snapshot carries no sequence number, so its timestamp is used as the [`OrderBook`]
+/// sequence.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/level2-50-best-ask-bid-orders>
+/// ```json
+/// {
+///     "type": "message",
+///     "topic": "/spotMarket/level2Depth50:BTC-USDT",
+///     "subject": "level2",
+///     "data": {
+///         "asks": [["9993", "3"], ["9992", "3"]],
+///         "bids": [["9988", "56"], ["9987", "15"]],
+///         "timestamp": 1586948108193
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KucoinOrderBookL2Snapshot {
+    pub bids: Vec<KucoinLevel>,
+    pub asks: Vec<KucoinLevel>,
+    #[serde(
+        alias = "timestamp",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, KucoinOrderBookL2)>
+    for MarketIter<InstrumentKey, OrderBookEvent>
+{
+    fn from(
+        (exchange_id, instrument, book): (ExchangeId, InstrumentKey, KucoinOrderBookL2),
+    ) -> Self {
+        let KucoinOrderBookL2Snapshot { bids, asks, time } = book.data;
+
+        Self(vec![Ok(MarketEvent {
+            time_exchange: time,
+            time_received: Utc::now(),
+            exchange: exchange_id,
+            instrument,
+            kind: OrderBookEvent::Snapshot(OrderBook::new(
+                u64::try_from(time.timestamp_millis()).unwrap_or_default(),
+                Some(time),
+                bids,
+                asks,
+            )),
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::subscription::SubscriptionId;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_de_kucoin_order_book_l2() {
+        let input = r#"
+        {
+            "type": "message",
+            "topic": "/spotMarket/level2Depth50:BTC-USDT",
+            "subject": "level2",
+            "data": {
+                "asks": [["9993", "3"], ["9994", "1.5"]],
+                "bids": [["9988", "56"]],
+                "timestamp": 1586948108193
+            }
+        }
+        "#;
+
+        assert_eq!(
+            serde_json::from_str::<KucoinOrderBookL2>(input).unwrap(),
+            KucoinOrderBookL2 {
+                subscription_id: SubscriptionId::from("/spotMarket/level2Depth50|BTC-USDT"),
+                data: KucoinOrderBookL2Snapshot {
+                    bids: vec![KucoinLevel {
+                        price: dec!(9988),
+                        amount: dec!(56)
+                    }],
+                    asks: vec![
+                        KucoinLevel {
+                            price: dec!(9993),
+                            amount: dec!(3)
+                        },
+                        KucoinLevel {
+                            price: dec!(9994),
+                            amount: dec!(1.5)
+                        },
+                    ],
+                    time: DateTime::from_timestamp_millis(1586948108193).unwrap(),
+                },
+            }
+        );
+    }
+}
@@ -0,0 +1,33 @@
+use crate::books::Level;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Level 1 OrderBook types (top of books).
+pub mod l1;
+
+/// Level 2 OrderBook types.
+pub mod l2;
+
+/// [`Kucoin`](super::Kucoin) OrderBook level.
+///
+/// #### Raw Payload Examples
+/// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/level2-50-best-ask-bid-orders>
+/// ```json
+/// ["9993", "3"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KucoinLevel {
+    #[serde(with = "rust_decimal::serde::str")]
+    pub price: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub amount: Decimal,
+}
+
+impl From<KucoinLevel> for Level {
+    fn from(level: KucoinLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use super::Kucoin;
+use crate::{
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        trade::PublicTrades,
+        Subscription,
+    },
+    Identifier,
+};
+use serde::Serialize;
+
+/// Type that defines how to translate a Barter [`Subscription`] into a [`Kucoin`] channel to be
+/// subscribed to.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/subscribe/introduction>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct KucoinChannel(pub &'static str);
+
+impl KucoinChannel {
+    /// [`Kucoin`] real-time trades channel.
+    ///
+    /// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/match-execution-data>
+    pub const TRADES: Self = Self("/market/match");
+
+    /// [`Kucoin`] real-time OrderBook Level1 (top of books) channel.
+    ///
+    /// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/level1-bbo-market-data>
+    pub const ORDER_BOOK_L1: Self = Self("/spotMarket/level1");
+
+    /// [`Kucoin`] OrderBook Level2 channel, publishing a snapshot of the top 50 levels of each
+    /// side every 100ms.
+    ///
+    /// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/level2-50-best-ask-bid-orders>
+    pub const ORDER_BOOK_L2: Self = Self("/spotMarket/level2Depth50");
+}
+
+impl<Instrument> Identifier<KucoinChannel> for Subscription<Kucoin, Instrument, PublicTrades> {
+    fn id(&self) -> KucoinChannel {
+        KucoinChannel::TRADES
+    }
+}
+
+impl<Instrument> Identifier<KucoinChannel> for Subscription<Kucoin, Instrument, OrderBooksL1> {
+    fn id(&self) -> KucoinChannel {
+        KucoinChannel::ORDER_BOOK_L1
+    }
+}
+
+impl<Instrument> Identifier<KucoinChannel> for Subscription<Kucoin, Instrument, OrderBooksL2> {
+    fn id(&self) -> KucoinChannel {
+        KucoinChannel::ORDER_BOOK_L2
+    }
+}
+
+impl AsRef<str> for KucoinChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
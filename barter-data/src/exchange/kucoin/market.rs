@@ -0,0 +1,42 @@
+use super::Kucoin;
+use crate::{instrument::MarketInstrumentData, subscription::Subscription, Identifier};
+use barter_instrument::{asset::symbol::Symbol, instrument::Instrument, Keyed};
+use serde::{Deserialize, Serialize};
+use smol_str::{format_smolstr, SmolStr, StrExt};
+
+/// Type that defines how to translate a Barter [`Subscription`] into a [`Kucoin`] market that can
+/// be subscribed to.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/subscribe/introduction>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KucoinMarket(pub SmolStr);
+
+impl<Kind> Identifier<KucoinMarket> for Subscription<Kucoin, Instrument, Kind> {
+    fn id(&self) -> KucoinMarket {
+        kucoin_market(&self.instrument.base, &self.instrument.quote)
+    }
+}
+
+impl<InstrumentKey, Kind> Identifier<KucoinMarket>
+    for Subscription<Kucoin, Keyed<InstrumentKey, Instrument>, Kind>
+{
+    fn id(&self) -> KucoinMarket {
+        kucoin_market(&self.instrument.value.base, &self.instrument.value.quote)
+    }
+}
+
+impl<Kind> Identifier<KucoinMarket> for Subscription<Kucoin, MarketInstrumentData, Kind> {
+    fn id(&self) -> KucoinMarket {
+        KucoinMarket(self.instrument.name_exchange.clone())
+    }
+}
+
+impl AsRef<str> for KucoinMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+fn kucoin_market(base: &Symbol, quote: &Symbol) -> KucoinMarket {
+    KucoinMarket(format_smolstr!("{base}-{quote}").to_uppercase_smolstr())
+}
@@ -0,0 +1,65 @@
+use crate::{exchange::subscription::ExchangeSub, Identifier};
+use barter_integration::subscription::SubscriptionId;
+use serde::{
+    de::{Error, Unexpected},
+    Deserialize, Serialize,
+};
+
+/// Generic [`Kucoin`](super::Kucoin) market data message, wrapping the data of a subscribed
+/// topic.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/match-execution-data>
+/// #### Trade
+/// ```json
+/// {
+///     "type": "message",
+///     "topic": "/market/match:BTC-USDT",
+///     "subject": "trade.l3match",
+///     "data": {
+///         "makerOrderId": "6712c8a8d8e1360007dd1b10",
+///         "price": "67523",
+///         "sequence": "11067996711960577",
+///         "side": "buy",
+///         "size": "0.003",
+///         "symbol": "BTC-USDT",
+///         "takerOrderId": "6712c8a9b1a1a20007b7c6a2",
+///         "time": "1729843222921000000",
+///         "tradeId": "11067996711960577",
+///         "type": "match"
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KucoinMessage<T> {
+    #[serde(alias = "topic", deserialize_with = "de_message_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub data: T,
+}
+
+impl<T> Identifier<Option<SubscriptionId>> for KucoinMessage<T> {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+/// Deserialize a [`KucoinMessage`] "topic" (eg/ "/market/match:BTC-USDT") as the associated
+/// [`SubscriptionId`].
+///
+/// eg/ "/market/match|BTC-USDT"
+pub fn de_message_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let input = <&str as Deserialize>::deserialize(deserializer)?;
+
+    input
+        .split_once(':')
+        .map(|(channel, market)| ExchangeSub::from((channel, market)).id())
+        .ok_or_else(|| {
+            Error::invalid_value(
+                Unexpected::Str(input),
+                &"topic with pattern: <channel>:<symbol>",
+            )
+        })
+}
@@ -0,0 +1,154 @@
+use self::{
+    book::{l1::KucoinOrderBookL1, l2::KucoinOrderBookL2},
+    channel::KucoinChannel,
+    market::KucoinMarket,
+    subscriber::KucoinSubscriber,
+    subscription::KucoinSubResponse,
+    trade::KucoinTrade,
+};
+use crate::{
+    exchange::{Connector, ExchangeSub, PingInterval, StreamSelector},
+    instrument::InstrumentData,
+    subscriber::validator::WebSocketSubValidator,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        trade::PublicTrades,
+    },
+    transformer::stateless::StatelessTransformer,
+    ExchangeWsStream, NoInitialSnapshots,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_macro::{DeExchange, SerExchange};
+use serde_json::json;
+use std::time::Duration;
+use url::Url;
+
+/// OrderBook types for [`Kucoin`].
+pub mod book;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
+pub mod channel;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
+pub mod market;
+
+/// Generic [`KucoinMessage<T>`](message::KucoinMessage) type common to every [`Kucoin`] market
+/// data stream.
+pub mod message;
+
+/// [`KucoinSubscriber`] that fetches a public connection token before connecting to the
+/// [`Kucoin`] WebSocket server.
+pub mod subscriber;
+
+/// [`Subscription`](crate::subscription::Subscription) response type and response
+/// [`Validator`](barter_integration::Validator) for [`Kucoin`].
+pub mod subscription;
+
+/// Public trade types for [`Kucoin`].
+pub mod trade;
+
+/// [`Kucoin`] spot server base url.
+///
+/// ### Notes
+/// Connections require a token, so the [`KucoinSubscriber`] connects to the server (and token)
+/// issued by the [`HTTP_BULLET_PUBLIC_URL_KUCOIN`] endpoint rather than this url.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/apply-connect-token/public-token-no-authentication-required->
+pub const BASE_URL_KUCOIN: &str = "wss://ws-api-spot.kucoin.com/";
+
+/// [`Kucoin`] public connection token HTTP endpoint.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/apply-connect-token/public-token-no-authentication-required->
+pub const HTTP_BULLET_PUBLIC_URL_KUCOIN: &str = "https://api.kucoin.com/api/v1/bullet-public";
+
+/// [`Kucoin`] server [`PingInterval`] duration, as advertised by the server's `pingInterval`.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/ping>
+pub const PING_INTERVAL_KUCOIN: Duration = Duration::from_secs(18);
+
+/// [`Kucoin`] spot exchange.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/introduction>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DeExchange, SerExchange,
+)]
+pub struct Kucoin;
+
+impl Connector for Kucoin {
+    const ID: ExchangeId = ExchangeId::Kucoin;
+    type Channel = KucoinChannel;
+    type Market = KucoinMarket;
+    type Subscriber = KucoinSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = KucoinSubResponse;
+
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(BASE_URL_KUCOIN).map_err(SocketError::UrlParse)
+    }
+
+    fn ping_interval() -> Option<PingInterval> {
+        Some(PingInterval {
+            interval: tokio::time::interval(PING_INTERVAL_KUCOIN),
+            ping: || {
+                WsMessage::text(
+                    json!({
+                        "id": chrono::Utc::now().timestamp_millis().to_string(),
+                        "type": "ping",
+                    })
+                    .to_string(),
+                )
+            },
+        })
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        exchange_subs
+            .into_iter()
+            .enumerate()
+            .map(|(id, ExchangeSub { channel, market })| {
+                WsMessage::text(
+                    json!({
+                        "id": id.to_string(),
+                        "type": "subscribe",
+                        "topic": format!("{}:{}", channel.as_ref(), market.as_ref()),
+                        "privateChannel": false,
+                        "response": true,
+                    })
+                    .to_string(),
+                )
+            })
+            .collect()
+    }
+}
+
+impl<Instrument> StreamSelector<Instrument, PublicTrades> for Kucoin
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Key, PublicTrades, KucoinTrade>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL1> for Kucoin
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Key, OrderBooksL1, KucoinOrderBookL1>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL2> for Kucoin
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Key, OrderBooksL2, KucoinOrderBookL2>,
+    >;
+}
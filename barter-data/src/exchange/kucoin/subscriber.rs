@@ -0,0 +1,179 @@
+use super::HTTP_BULLET_PUBLIC_URL_KUCOIN;
+use crate::{
+    exchange::{config::ConnectorConfig, Connector},
+    instrument::InstrumentData,
+    subscriber::{mapper::WebSocketSubMapper, Subscribed, Subscriber, WebSocketSubscriber},
+    subscription::{Subscription, SubscriptionKind},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::error::SocketError;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+use url::Url;
+
+/// [`Subscriber`] that applies for a [`Kucoin`](super::Kucoin) public connection token via HTTP,
+/// before connecting to the issued WebSocket server & actioning
+/// [`Subscription`]s like the [`WebSocketSubscriber`].
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/apply-connect-token/public-token-no-authentication-required->
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KucoinSubscriber;
+
+#[async_trait]
+impl Subscriber for KucoinSubscriber {
+    type SubMapper = WebSocketSubMapper;
+
+    async fn subscribe<Exchange, Instrument, Kind>(
+        config: &ConnectorConfig,
+        subscriptions: &[Subscription<Exchange, Instrument, Kind>],
+    ) -> Result<Subscribed<Instrument::Key>, SocketError>
+    where
+        Exchange: Connector + Send + Sync,
+        Kind: SubscriptionKind + Send + Sync,
+        Instrument: InstrumentData,
+        Subscription<Exchange, Instrument, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        let bullet = config
+            .http_client()?
+            .post(HTTP_BULLET_PUBLIC_URL_KUCOIN)
+            .send()
+            .await
+            .map_err(SocketError::Http)?
+            .json::<KucoinBullet>()
+            .await
+            .map_err(SocketError::Http)?;
+
+        let url = bullet.url(
+            &Utc::now()
+                .timestamp_nanos_opt()
+                .unwrap_or_default()
+                .to_string(),
+        )?;
+        debug!(exchange = %Exchange::ID, "received connection token");
+
+        WebSocketSubscriber::subscribe_url(config, url, subscriptions).await
+    }
+}
+
+/// [`Kucoin`](super::Kucoin) public connection token HTTP response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/apply-connect-token/public-token-no-authentication-required->
+/// ```json
+/// {
+///     "code": "200000",
+///     "data": {
+///         "token": "2neAiuYvAU61ZDXANAGAsiL4-iAExhsBXZxftpOeh_55i3Ysy2q2LEsEWU64mdzUOPusi34M_wGoSf7iNyEWJ4aBZXpWhrmY9jKtqkdWoFa75w3istPvPtiYB9J6i9GjsxUuhPw3BlrzazF6ghq4L_nKJ9W-eRLAanoJvyiLBKhTvTvmVNtEHE3Zqc3uQdK9.Y3wFkeb16ZSN_J2ThOP89w==",
+///         "instanceServers": [
+///             {
+///                 "endpoint": "wss://ws-api-spot.kucoin.com/",
+///                 "encrypt": true,
+///                 "protocol": "websocket",
+///                 "pingInterval": 18000,
+///                 "pingTimeout": 10000
+///             }
+///         ]
+///     }
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct KucoinBullet {
+    pub code: String,
+    pub data: KucoinBulletData,
+}
+
+/// [`KucoinBullet`] connection token & the servers it may be used to connect to.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct KucoinBulletData {
+    pub token: String,
+    #[serde(alias = "instanceServers")]
+    pub instance_servers: Vec<KucoinInstanceServer>,
+}
+
+/// [`Kucoin`](super::Kucoin) WebSocket server issued with a [`KucoinBullet`].
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct KucoinInstanceServer {
+    pub endpoint: String,
+    #[serde(alias = "pingInterval")]
+    pub ping_interval_ms: u64,
+}
+
+impl KucoinBullet {
+    /// [`KucoinBullet`] success response code.
+    pub const CODE_SUCCESS: &'static str = "200000";
+
+    /// Construct the WebSocket [`Url`] of the first issued server, authenticated with the
+    /// issued token & identified with the provided `connect_id`.
+    pub fn url(&self, connect_id: &str) -> Result<Url, SocketError> {
+        if self.code != Self::CODE_SUCCESS {
+            return Err(SocketError::Subscribe(format!(
+                "failed to apply for connection token with response code: {}",
+                self.code
+            )));
+        }
+
+        let server = self.data.instance_servers.first().ok_or_else(|| {
+            SocketError::Subscribe("connection token issued without any servers".to_string())
+        })?;
+
+        let mut url = Url::parse(&server.endpoint).map_err(SocketError::UrlParse)?;
+        url.query_pairs_mut()
+            .append_pair("token", &self.data.token)
+            .append_pair("connectId", connect_id);
+
+        Ok(url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kucoin_bullet_url() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<&'static str>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: token issued with server
+                input: r#"{
+                    "code": "200000",
+                    "data": {
+                        "token": "abc.def==",
+                        "instanceServers": [{
+                            "endpoint": "wss://ws-api-spot.kucoin.com/",
+                            "encrypt": true,
+                            "protocol": "websocket",
+                            "pingInterval": 18000,
+                            "pingTimeout": 10000
+                        }]
+                    }
+                }"#,
+                expected: Some("wss://ws-api-spot.kucoin.com/?token=abc.def%3D%3D&connectId=1"),
+            },
+            TestCase {
+                // TC1: failure response code
+                input: r#"{"code": "400100", "data": {"token": "", "instanceServers": []}}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<KucoinBullet>(test.input)
+                .unwrap()
+                .url("1")
+                .ok();
+            assert_eq!(
+                actual.as_ref().map(Url::as_str),
+                test.expected,
+                "TC{index} failed"
+            );
+        }
+    }
+}
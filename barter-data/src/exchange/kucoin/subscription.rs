@@ -0,0 +1,93 @@
+use barter_integration::{error::SocketError, Validator};
+use serde::{Deserialize, Serialize};
+
+/// [`Kucoin`](super::Kucoin) WebSocket subscription response.
+///
+/// ### Notes
+/// The "welcome" message sent by the server on connection is not a [`KucoinSubResponse`], and
+/// is therefore ignored during subscription validation.
+///
+/// ### Raw Payload Examples
+/// #### Subscription Ok Response
+/// ```json
+/// {
+///     "id": "0",
+///     "type": "ack"
+/// }
+/// ```
+///
+/// #### Subscription Error Response
+/// ```json
+/// {
+///     "id": "0",
+///     "type": "error",
+///     "code": 404,
+///     "data": "topic /market/match:BTC-USDX is not found"
+/// }
+/// ```
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/subscribe/introduction>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum KucoinSubResponse {
+    Ack {
+        id: String,
+    },
+    Error {
+        code: i64,
+        #[serde(alias = "data")]
+        message: String,
+    },
+}
+
+impl Validator for KucoinSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match self {
+            Self::Ack { .. } => Ok(self),
+            Self::Error { code, message } => Err(SocketError::Subscribe(format!(
+                "received failure subscription response code: {code} with message: {message}",
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kucoin_sub_response() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<bool>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: subscription success
+                input: r#"{"id": "0", "type": "ack"}"#,
+                expected: Some(true),
+            },
+            TestCase {
+                // TC1: subscription failure
+                input: r#"{"id": "0", "type": "error", "code": 404, "data": "topic /market/match:BTC-USDX is not found"}"#,
+                expected: Some(false),
+            },
+            TestCase {
+                // TC2: welcome message is not a subscription response
+                input: r#"{"id": "hQvf8jkno", "type": "welcome"}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<KucoinSubResponse>(test.input)
+                .ok()
+                .map(|response| response.validate().is_ok());
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use super::message::KucoinMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    subscription::trade::PublicTrade,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::Side;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Kucoin`](super::Kucoin) real-time trade WebSocket message.
+pub type KucoinTrade = KucoinMessage<KucoinTradeData>;
+
+/// [`Kucoin`](super::Kucoin) real-time trade.
+///
+/// See [`KucoinMessage`] for full raw payload examples.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/match-execution-data>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KucoinTradeData {
+    #[serde(alias = "tradeId")]
+    pub id: String,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(alias = "size", deserialize_with = "barter_integration::de::de_str")]
+    pub amount: f64,
+    pub side: Side,
+    #[serde(deserialize_with = "de_str_epoch_ns_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, KucoinTrade)>
+    for MarketIter<InstrumentKey, PublicTrade>
+{
+    fn from((exchange_id, instrument, trade): (ExchangeId, InstrumentKey, KucoinTrade)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            time_exchange: trade.data.time,
+            time_received: Utc::now(),
+            exchange: exchange_id,
+            instrument,
+            kind: PublicTrade {
+                id: trade.data.id,
+                price: trade.data.price,
+                amount: trade.data.amount,
+                side: trade.data.side,
+                aggregate_count: None,
+            },
+        })])
+    }
+}
+
+/// Deserialize a `String` epoch nanoseconds timestamp (eg/ "1729843222921000000") as a
+/// `DateTime<Utc>`.
+pub fn de_str_epoch_ns_as_datetime_utc<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    barter_integration::de::de_str::<D, i64>(deserializer).map(DateTime::from_timestamp_nanos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::subscription::SubscriptionId;
+
+    #[test]
+    fn test_de_kucoin_trade() {
+        let input = r#"
+        {
+            "type": "message",
+            "topic": "/market/match:BTC-USDT",
+            "subject": "trade.l3match",
+            "data": {
+                "makerOrderId": "6712c8a8d8e1360007dd1b10",
+                "price": "67523",
+                "sequence": "11067996711960577",
+                "side": "buy",
+                "size": "0.003",
+                "symbol": "BTC-USDT",
+                "takerOrderId": "6712c8a9b1a1a20007b7c6a2",
+                "time": "1729843222921000000",
+                "tradeId": "11067996711960577",
+                "type": "match"
+            }
+        }
+        "#;
+
+        assert_eq!(
+            serde_json::from_str::<KucoinTrade>(input).unwrap(),
+            KucoinTrade {
+                subscription_id: SubscriptionId::from("/market/match|BTC-USDT"),
+                data: KucoinTradeData {
+                    id: "11067996711960577".to_string(),
+                    price: 67523.0,
+                    amount: 0.003,
+                    side: Side::Buy,
+                    time: DateTime::from_timestamp_millis(1729843222921).unwrap(),
+                },
+            }
+        );
+    }
+}
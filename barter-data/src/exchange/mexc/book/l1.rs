@@ -0,0 +1,90 @@
+use super::super::message::MexcMessage;
+use crate::{
+    books::Level,
+    event::{MarketEvent, MarketIter},
+    subscription::book::OrderBookL1,
+};
+use barter_instrument::exchange::ExchangeId;
+use chrono::Utc;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Mexc`](super::super::Mexc) real-time OrderBook Level1
+/// (top of books) WebSocket message.
+pub type MexcOrderBookL1 = MexcMessage<MexcBookTicker>;
+
+/// [`Mexc`](super::super::Mexc) best bid and ask.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#individual-symbol-book-ticker-streams>
+/// ```json
+/// {
+///     "c": "spot@public.bookTicker.v3.api@BTCUSDT",
+///     "d": {"A": "4.70432", "B": "6.714863", "a": "20290.89", "b": "20290.88"},
+///     "s": "BTCUSDT",
+///     "t": 1678766029024
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MexcBookTicker {
+    #[serde(alias = "b", with = "rust_decimal::serde::str")]
+    pub best_bid_price: Decimal,
+    #[serde(alias = "B", with = "rust_decimal::serde::str")]
+    pub best_bid_amount: Decimal,
+    #[serde(alias = "a", with = "rust_decimal::serde::str")]
+    pub best_ask_price: Decimal,
+    #[serde(alias = "A", with = "rust_decimal::serde::str")]
+    pub best_ask_amount: Decimal,
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, MexcOrderBookL1)>
+    for MarketIter<InstrumentKey, OrderBookL1>
+{
+    fn from((exchange_id, instrument, book): (ExchangeId, InstrumentKey, MexcOrderBookL1)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            time_exchange: book.time,
+            time_received: Utc::now(),
+            exchange: exchange_id,
+            instrument,
+            kind: OrderBookL1 {
+                last_update_time: book.time,
+                best_bid: Level::new(book.data.best_bid_price, book.data.best_bid_amount),
+                best_ask: Level::new(book.data.best_ask_price, book.data.best_ask_amount),
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::subscription::SubscriptionId;
+    use chrono::DateTime;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_de_mexc_order_book_l1() {
+        let input = r#"
+        {
+            "c": "spot@public.bookTicker.v3.api@BTCUSDT",
+            "d": {"A": "4.70432", "B": "6.714863", "a": "20290.89", "b": "20290.88"},
+            "s": "BTCUSDT",
+            "t": 1678766029024
+        }
+        "#;
+
+        assert_eq!(
+            serde_json::from_str::<MexcOrderBookL1>(input).unwrap(),
+            MexcOrderBookL1 {
+                subscription_id: SubscriptionId::from("spot@public.bookTicker.v3.api|BTCUSDT"),
+                time: DateTime::from_timestamp_millis(1678766029024).unwrap(),
+                data: MexcBookTicker {
+                    best_bid_price: dec!(20290.88),
+                    best_bid_amount: dec!(6.714863),
+                    best_ask_price: dec!(20290.89),
+                    best_ask_amount: dec!(4.70432),
+                },
+            }
+        );
+    }
+}
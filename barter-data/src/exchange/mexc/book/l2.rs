@@ -0,0 +1,109 @@
+use super::{super::message::MexcMessage, MexcLevel};
+use crate::{
+    books::OrderBook,
+    event::{MarketEvent, MarketIter},
+    subscription::book::OrderBookEvent,
+};
+use barter_instrument::exchange::ExchangeId;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Mexc`](super::super::Mexc) OrderBook Level2 WebSocket message.
+pub type MexcOrderBookL2 = MexcMessage<MexcOrderBookL2Snapshot>;
+
+/// [`Mexc`](super::super::Mexc) OrderBook Level2 snapshot of the top
+/// [`ORDER_BOOK_L2_DEPTH`](super::super::channel::ORDER_BOOK_L2_DEPTH) levels of each side.
+///
+/// Every message is a complete snapshot, so no sequencing of deltas is required.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#partial-book-depth-streams>
+/// ```json
+/// {
+///     "c": "spot@public.limit.depth.v3.api@BTCUSDT@20",
+///     "d": {
+///         "asks": [{"p": "20290.89", "v": "2.2"}],
+///         "bids": [{"p": "20290.88", "v": "3.3"}],
+///         "e": "spot@public.limit.depth.v3.api",
+///         "r": "3407459756"
+///     },
+///     "s": "BTCUSDT",
+///     "t": 1678766133042
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MexcOrderBookL2Snapshot {
+    #[serde(alias = "r", deserialize_with = "barter_integration::de::de_str")]
+    pub sequence: u64,
+    pub bids: Vec<MexcLevel>,
+    pub asks: Vec<MexcLevel>,
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, MexcOrderBookL2)>
+    for MarketIter<InstrumentKey, OrderBookEvent>
+{
+    fn from((exchange_id, instrument, book): (ExchangeId, InstrumentKey, MexcOrderBookL2)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            time_exchange: book.time,
+            time_received: Utc::now(),
+            exchange: exchange_id,
+            instrument,
+            kind: OrderBookEvent::Snapshot(OrderBook::new(
+                book.data.sequence,
+                Some(book.time),
+                book.data.bids,
+                book.data.asks,
+            )),
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::subscription::SubscriptionId;
+    use chrono::DateTime;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_de_mexc_order_book_l2() {
+        let input = r#"
+        {
+            "c": "spot@public.limit.depth.v3.api@BTCUSDT@20",
+            "d": {
+                "asks": [{"p": "20290.89", "v": "2.2"}],
+                "bids": [{"p": "20290.88", "v": "3.3"}, {"p": "20290.5", "v": "1"}],
+                "e": "spot@public.limit.depth.v3.api",
+                "r": "3407459756"
+            },
+            "s": "BTCUSDT",
+            "t": 1678766133042
+        }
+        "#;
+
+        assert_eq!(
+            serde_json::from_str::<MexcOrderBookL2>(input).unwrap(),
+            MexcOrderBookL2 {
+                subscription_id: SubscriptionId::from("spot@public.limit.depth.v3.api|BTCUSDT"),
+                time: DateTime::from_timestamp_millis(1678766133042).unwrap(),
+                data: MexcOrderBookL2Snapshot {
+                    sequence: 3407459756,
+                    bids: vec![
+                        MexcLevel {
+                            price: dec!(20290.88),
+                            amount: dec!(3.3)
+                        },
+                        MexcLevel {
+                            price: dec!(20290.5),
+                            amount: dec!(1)
+                        },
+                    ],
+                    asks: vec![MexcLevel {
+                        price: dec!(20290.89),
+                        amount: dec!(2.2)
+                    }],
+                },
+            }
+        );
+    }
+}
@@ -0,0 +1,33 @@
+use crate::books::Level;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Level 1 OrderBook types (top of books).
+pub mod l1;
+
+/// Level 2 OrderBook types.
+pub mod l2;
+
+/// [`Mexc`](super::Mexc) OrderBook level.
+///
+/// #### Raw Payload Examples
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#partial-book-depth-streams>
+/// ```json
+/// {"p": "20290.89", "v": "2.2"}
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MexcLevel {
+    #[serde(alias = "p", with = "rust_decimal::serde::str")]
+    pub price: Decimal,
+    #[serde(alias = "v", with = "rust_decimal::serde::str")]
+    pub amount: Decimal,
+}
+
+impl From<MexcLevel> for Level {
+    fn from(level: MexcLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use super::{market::MexcMarket, Mexc};
+use crate::{
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        trade::PublicTrades,
+        Subscription,
+    },
+    Identifier,
+};
+use serde::Serialize;
+
+/// Number of price levels of each side of the [`MexcChannel::ORDER_BOOK_L2`] snapshots.
+///
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#partial-book-depth-streams>
+pub const ORDER_BOOK_L2_DEPTH: u8 = 20;
+
+/// Type that defines how to translate a Barter [`Subscription`] into a [`Mexc`] channel to be
+/// subscribed to.
+///
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#websocket-market-streams>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct MexcChannel(pub &'static str);
+
+impl MexcChannel {
+    /// [`Mexc`] real-time trades channel.
+    ///
+    /// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#trade-streams>
+    pub const TRADES: Self = Self("spot@public.deals.v3.api");
+
+    /// [`Mexc`] real-time OrderBook Level1 (top of books) channel.
+    ///
+    /// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#individual-symbol-book-ticker-streams>
+    pub const ORDER_BOOK_L1: Self = Self("spot@public.bookTicker.v3.api");
+
+    /// [`Mexc`] OrderBook Level2 channel, publishing a snapshot of the top
+    /// [`ORDER_BOOK_L2_DEPTH`] levels of each side.
+    ///
+    /// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#partial-book-depth-streams>
+    pub const ORDER_BOOK_L2: Self = Self("spot@public.limit.depth.v3.api");
+
+    /// Subscription topic of this [`MexcChannel`] for the provided [`MexcMarket`].
+    ///
+    /// eg/ "spot@public.deals.v3.api@BTCUSDT", or "spot@public.limit.depth.v3.api@BTCUSDT@20"
+    pub fn topic(&self, market: &MexcMarket) -> String {
+        if *self == Self::ORDER_BOOK_L2 {
+            format!("{}@{}@{ORDER_BOOK_L2_DEPTH}", self.0, market.as_ref())
+        } else {
+            format!("{}@{}", self.0, market.as_ref())
+        }
+    }
+}
+
+impl<Instrument> Identifier<MexcChannel> for Subscription<Mexc, Instrument, PublicTrades> {
+    fn id(&self) -> MexcChannel {
+        MexcChannel::TRADES
+    }
+}
+
+impl<Instrument> Identifier<MexcChannel> for Subscription<Mexc, Instrument, OrderBooksL1> {
+    fn id(&self) -> MexcChannel {
+        MexcChannel::ORDER_BOOK_L1
+    }
+}
+
+impl<Instrument> Identifier<MexcChannel> for Subscription<Mexc, Instrument, OrderBooksL2> {
+    fn id(&self) -> MexcChannel {
+        MexcChannel::ORDER_BOOK_L2
+    }
+}
+
+impl AsRef<str> for MexcChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
@@ -0,0 +1,42 @@
+use super::Mexc;
+use crate::{instrument::MarketInstrumentData, subscription::Subscription, Identifier};
+use barter_instrument::{asset::symbol::Symbol, instrument::Instrument, Keyed};
+use serde::{Deserialize, Serialize};
+use smol_str::{format_smolstr, SmolStr, StrExt};
+
+/// Type that defines how to translate a Barter [`Subscription`] into a [`Mexc`] market that can
+/// be subscribed to.
+///
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#websocket-market-streams>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct MexcMarket(pub SmolStr);
+
+impl<Kind> Identifier<MexcMarket> for Subscription<Mexc, Instrument, Kind> {
+    fn id(&self) -> MexcMarket {
+        mexc_market(&self.instrument.base, &self.instrument.quote)
+    }
+}
+
+impl<InstrumentKey, Kind> Identifier<MexcMarket>
+    for Subscription<Mexc, Keyed<InstrumentKey, Instrument>, Kind>
+{
+    fn id(&self) -> MexcMarket {
+        mexc_market(&self.instrument.value.base, &self.instrument.value.quote)
+    }
+}
+
+impl<Kind> Identifier<MexcMarket> for Subscription<Mexc, MarketInstrumentData, Kind> {
+    fn id(&self) -> MexcMarket {
+        MexcMarket(self.instrument.name_exchange.clone())
+    }
+}
+
+impl AsRef<str> for MexcMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+fn mexc_market(base: &Symbol, quote: &Symbol) -> MexcMarket {
+    MexcMarket(format_smolstr!("{base}{quote}").to_uppercase_smolstr())
+}
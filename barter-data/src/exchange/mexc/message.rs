@@ -0,0 +1,122 @@
+use super::channel::MexcChannel;
+use crate::{exchange::subscription::ExchangeSub, Identifier};
+use barter_integration::subscription::SubscriptionId;
+use chrono::{DateTime, Utc};
+use serde::{
+    de::{Error, Unexpected},
+    Deserialize, Serialize,
+};
+
+/// Generic [`Mexc`](super::Mexc) market data message, wrapping the data of a subscribed
+/// channel.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#websocket-market-streams>
+/// #### Trades
+/// ```json
+/// {
+///     "c": "spot@public.deals.v3.api@BTCUSDT",
+///     "d": {
+///         "deals": [{"S": 2, "p": "20233.84", "t": 1678765933565, "v": "0.001028"}],
+///         "e": "spot@public.deals.v3.api"
+///     },
+///     "s": "BTCUSDT",
+///     "t": 1678765933566
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MexcMessage<T> {
+    #[serde(alias = "c", deserialize_with = "de_message_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "t",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "d")]
+    pub data: T,
+}
+
+impl<T> Identifier<Option<SubscriptionId>> for MexcMessage<T> {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+/// Deserialize a [`MexcMessage`] "c" (eg/ "spot@public.deals.v3.api@BTCUSDT") as the
+/// associated [`SubscriptionId`].
+///
+/// eg/ "spot@public.deals.v3.api|BTCUSDT"
+pub fn de_message_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let input = <&str as Deserialize>::deserialize(deserializer)?;
+
+    // Channel names themselves contain an '@' (eg/ "spot@public.deals.v3.api"), and the
+    // OrderBookL2 topic is suffixed with it's depth (eg/ "...@BTCUSDT@20")
+    let channel = [
+        MexcChannel::TRADES,
+        MexcChannel::ORDER_BOOK_L1,
+        MexcChannel::ORDER_BOOK_L2,
+    ]
+    .into_iter()
+    .find(|channel| {
+        input
+            .strip_prefix(channel.as_ref())
+            .is_some_and(|rest| rest.starts_with('@'))
+    })
+    .ok_or_else(|| {
+        Error::invalid_value(
+            Unexpected::Str(input),
+            &"topic with pattern: <channel>@<symbol>",
+        )
+    })?;
+
+    let market = input[channel.as_ref().len() + 1..]
+        .split('@')
+        .next()
+        .unwrap_or_default();
+
+    Ok(ExchangeSub::from((channel, market)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_message_subscription_id() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<SubscriptionId>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: trades topic
+                input: r#""spot@public.deals.v3.api@BTCUSDT""#,
+                expected: Some(SubscriptionId::from("spot@public.deals.v3.api|BTCUSDT")),
+            },
+            TestCase {
+                // TC1: OrderBookL2 topic suffixed with depth
+                input: r#""spot@public.limit.depth.v3.api@ETHUSDT@20""#,
+                expected: Some(SubscriptionId::from(
+                    "spot@public.limit.depth.v3.api|ETHUSDT",
+                )),
+            },
+            TestCase {
+                // TC2: unknown channel
+                input: r#""spot@public.kline.v3.api@BTCUSDT@Min1""#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual =
+                de_message_subscription_id(&mut serde_json::Deserializer::from_str(test.input))
+                    .ok();
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
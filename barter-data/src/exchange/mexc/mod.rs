@@ -0,0 +1,134 @@
+use self::{
+    book::{l1::MexcOrderBookL1, l2::MexcOrderBookL2},
+    channel::MexcChannel,
+    market::MexcMarket,
+    subscription::MexcSubResponse,
+    trade::MexcTrades,
+};
+use crate::{
+    exchange::{Connector, ExchangeSub, PingInterval, StreamSelector},
+    instrument::InstrumentData,
+    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        trade::PublicTrades,
+        Map,
+    },
+    transformer::stateless::StatelessTransformer,
+    ExchangeWsStream, NoInitialSnapshots,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_macro::{DeExchange, SerExchange};
+use serde_json::json;
+use std::time::Duration;
+use url::Url;
+
+/// OrderBook types for [`Mexc`].
+pub mod book;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
+pub mod channel;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
+pub mod market;
+
+/// Generic [`MexcMessage<T>`](message::MexcMessage) type common to every [`Mexc`] market
+/// data stream.
+pub mod message;
+
+/// [`Subscription`](crate::subscription::Subscription) response type and response
+/// [`Validator`](barter_integration::Validator) for [`Mexc`].
+pub mod subscription;
+
+/// Public trade types for [`Mexc`].
+pub mod trade;
+
+/// [`Mexc`] spot server base url.
+///
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#websocket-market-streams>
+pub const BASE_URL_MEXC: &str = "wss://wbs.mexc.com/ws";
+
+/// [`Mexc`] server [`PingInterval`] duration. The server disconnects connections without
+/// a ping for 60 seconds.
+///
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#websocket-market-streams>
+pub const PING_INTERVAL_MEXC: Duration = Duration::from_secs(20);
+
+/// [`Mexc`] spot exchange.
+///
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#websocket-market-streams>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DeExchange, SerExchange,
+)]
+pub struct Mexc;
+
+impl Connector for Mexc {
+    const ID: ExchangeId = ExchangeId::Mexc;
+    type Channel = MexcChannel;
+    type Market = MexcMarket;
+    type Subscriber = WebSocketSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = MexcSubResponse;
+
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(BASE_URL_MEXC).map_err(SocketError::UrlParse)
+    }
+
+    fn ping_interval() -> Option<PingInterval> {
+        Some(PingInterval {
+            interval: tokio::time::interval(PING_INTERVAL_MEXC),
+            ping: || WsMessage::text(json!({ "method": "PING" }).to_string()),
+        })
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        let topics = exchange_subs
+            .iter()
+            .map(|sub| sub.channel.topic(&sub.market))
+            .collect::<Vec<_>>();
+
+        vec![WsMessage::text(
+            json!({
+                "method": "SUBSCRIPTION",
+                "params": topics,
+            })
+            .to_string(),
+        )]
+    }
+
+    fn expected_responses<InstrumentKey>(_: &Map<InstrumentKey>) -> usize {
+        1
+    }
+}
+
+impl<Instrument> StreamSelector<Instrument, PublicTrades> for Mexc
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Key, PublicTrades, MexcTrades>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL1> for Mexc
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Key, OrderBooksL1, MexcOrderBookL1>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL2> for Mexc
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Key, OrderBooksL2, MexcOrderBookL2>,
+    >;
+}
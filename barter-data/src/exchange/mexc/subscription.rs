@@ -0,0 +1,90 @@
+use barter_integration::{error::SocketError, Validator};
+use serde::{Deserialize, Serialize};
+
+/// Prefix of the [`MexcSubResponse`] message communicating a failed subscription.
+const SUBSCRIBE_FAILURE_PREFIX: &str = "Not Subscribed";
+
+/// [`Mexc`](super::Mexc) WebSocket subscription response.
+///
+/// ### Raw Payload Examples
+/// #### Subscription Ok Response
+/// ```json
+/// {
+///     "id": 0,
+///     "code": 0,
+///     "msg": "spot@public.deals.v3.api@BTCUSDT"
+/// }
+/// ```
+///
+/// #### Subscription Error Response
+/// ```json
+/// {
+///     "id": 0,
+///     "code": 0,
+///     "msg": "Not Subscribed successfully! [spot@public.deals.v3.api@BTCUSDX]. Reason： Blocked!"
+/// }
+/// ```
+///
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#live-subscribing-unsubscribing-to-streams>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct MexcSubResponse {
+    pub id: u64,
+    pub code: i64,
+    #[serde(alias = "msg")]
+    pub message: String,
+}
+
+impl Validator for MexcSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        if self.code == 0 && !self.message.starts_with(SUBSCRIBE_FAILURE_PREFIX) {
+            Ok(self)
+        } else {
+            Err(SocketError::Subscribe(format!(
+                "received failure subscription response code: {} with message: {}",
+                self.code, self.message,
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_mexc_sub_response() {
+        struct TestCase {
+            input: &'static str,
+            is_valid: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: subscription success
+                input: r#"{"id":0,"code":0,"msg":"spot@public.deals.v3.api@BTCUSDT"}"#,
+                is_valid: true,
+            },
+            TestCase {
+                // TC1: subscription failure communicated via message
+                input: r#"{"id":0,"code":0,"msg":"Not Subscribed successfully! [spot@public.deals.v3.api@BTCUSDX]. Reason： Blocked!"}"#,
+                is_valid: false,
+            },
+            TestCase {
+                // TC2: subscription failure communicated via code
+                input: r#"{"id":0,"code":1,"msg":"invalid params"}"#,
+                is_valid: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<MexcSubResponse>(test.input)
+                .unwrap()
+                .validate()
+                .is_ok();
+            assert_eq!(actual, test.is_valid, "TC{index} failed");
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use super::message::MexcMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    subscription::trade::PublicTrade,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::Side;
+use chrono::{DateTime, Utc};
+use serde::{
+    de::{Error, Unexpected},
+    Deserialize, Serialize,
+};
+
+/// Terse type alias for a [`Mexc`](super::Mexc) real-time trades WebSocket message.
+pub type MexcTrades = MexcMessage<MexcDeals>;
+
+/// [`Mexc`](super::Mexc) batch of real-time trades.
+///
+/// See [`MexcMessage`] for full raw payload examples.
+///
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#trade-streams>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MexcDeals {
+    pub deals: Vec<MexcTrade>,
+}
+
+/// [`Mexc`](super::Mexc) real-time trade.
+///
+/// ### Notes
+/// [`Mexc`](super::Mexc) does not publish trade identifiers, so the trade time is used as the
+/// [`PublicTrade`] id.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#trade-streams>
+/// ```json
+/// {"S": 2, "p": "20233.84", "t": 1678765933565, "v": "0.001028"}
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MexcTrade {
+    #[serde(alias = "S", deserialize_with = "de_side")]
+    pub side: Side,
+    #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(alias = "v", deserialize_with = "barter_integration::de::de_str")]
+    pub amount: f64,
+    #[serde(
+        alias = "t",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, MexcTrades)>
+    for MarketIter<InstrumentKey, PublicTrade>
+where
+    InstrumentKey: Clone,
+{
+    fn from((exchange_id, instrument, trades): (ExchangeId, InstrumentKey, MexcTrades)) -> Self {
+        trades
+            .data
+            .deals
+            .into_iter()
+            .map(|trade| {
+                Ok(MarketEvent {
+                    time_exchange: trade.time,
+                    time_received: Utc::now(),
+                    exchange: exchange_id,
+                    instrument: instrument.clone(),
+                    kind: PublicTrade {
+                        id: trade.time.timestamp_millis().to_string(),
+                        price: trade.price,
+                        amount: trade.amount,
+                        side: trade.side,
+                        aggregate_count: None,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+/// Deserialize a [`MexcTrade`] "S" (1 => buy, 2 => sell) as the associated [`Side`].
+pub fn de_side<'de, D>(deserializer: D) -> Result<Side, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    match <u8 as Deserialize>::deserialize(deserializer)? {
+        1 => Ok(Side::Buy),
+        2 => Ok(Side::Sell),
+        other => Err(Error::invalid_value(
+            Unexpected::Unsigned(u64::from(other)),
+            &"1 (buy) or 2 (sell)",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::subscription::SubscriptionId;
+
+    #[test]
+    fn test_de_mexc_trades() {
+        let input = r#"
+        {
+            "c": "spot@public.deals.v3.api@BTCUSDT",
+            "d": {
+                "deals": [
+                    {"S": 2, "p": "20233.84", "t": 1678765933565, "v": "0.001028"},
+                    {"S": 1, "p": "20233.85", "t": 1678765933566, "v": "0.5"}
+                ],
+                "e": "spot@public.deals.v3.api"
+            },
+            "s": "BTCUSDT",
+            "t": 1678765933566
+        }
+        "#;
+
+        assert_eq!(
+            serde_json::from_str::<MexcTrades>(input).unwrap(),
+            MexcTrades {
+                subscription_id: SubscriptionId::from("spot@public.deals.v3.api|BTCUSDT"),
+                time: DateTime::from_timestamp_millis(1678765933566).unwrap(),
+                data: MexcDeals {
+                    deals: vec![
+                        MexcTrade {
+                            side: Side::Sell,
+                            price: 20233.84,
+                            amount: 0.001028,
+                            time: DateTime::from_timestamp_millis(1678765933565).unwrap(),
+                        },
+                        MexcTrade {
+                            side: Side::Buy,
+                            price: 20233.85,
+                            amount: 0.5,
+                            time: DateTime::from_timestamp_millis(1678765933566).unwrap(),
+                        },
+                    ],
+                },
+            }
+        );
+    }
+}
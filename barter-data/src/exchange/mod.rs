@@ -1,12 +1,14 @@
 use self::subscription::ExchangeSub;
 use crate::{
     instrument::InstrumentData,
-    subscriber::{validator::SubscriptionValidator, Subscriber},
-    subscription::{Map, SubscriptionKind},
+    subscriber::{resubscribe::ResubscriptionNotice, validator::SubscriptionValidator, Subscriber},
+    subscription::{params::SubscriptionParams, Map, SubscriptionKind},
     MarketStream, SnapshotFetcher,
 };
 use barter_instrument::exchange::ExchangeId;
-use barter_integration::{error::SocketError, protocol::websocket::WsMessage, Validator};
+use barter_integration::{
+    environment::Environment, error::SocketError, protocol::websocket::WsMessage, Validator,
+};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::{fmt::Debug, time::Duration};
 use url::Url;
@@ -14,28 +16,57 @@ use url::Url;
 /// `BinanceSpot` & `BinanceFuturesUsd` [`Connector`] and [`StreamSelector`] implementations.
 pub mod binance;
 
+/// [`ConnectorConfig`](config::ConnectorConfig) of an exchange's market streams, such as the
+/// [`Environment`] (eg/ mainnet vs testnet) they connect to & the proxy they are routed through.
+pub mod config;
+
+/// Exchange server time endpoints used to periodically sync a
+/// [`ServerClock`](barter_integration::auth::clock::ServerClock), estimating the local clock skew.
+pub mod server_time;
+
 /// `Bitfinex` [`Connector`] and [`StreamSelector`] implementations.
 pub mod bitfinex;
 
+/// `BithumbSpot` [`Connector`] and [`StreamSelector`] implementations.
+pub mod bithumb;
+
 /// `Bitmex [`Connector`] and [`StreamSelector`] implementations.
 pub mod bitmex;
 
+/// `Bitstamp` [`Connector`] and [`StreamSelector`] implementations.
+pub mod bitstamp;
+
 /// `Bybit` ['Connector'] and ['StreamSelector'] implementation
 pub mod bybit;
 
 /// `Coinbase` [`Connector`] and [`StreamSelector`] implementations.
 pub mod coinbase;
 
+/// `Dydx` [`Connector`] and [`StreamSelector`] implementations.
+pub mod dydx;
+
 /// `GateioSpot`, `GateioFuturesUsd` & `GateioFuturesBtc` [`Connector`] and [`StreamSelector`]
 /// implementations.
 pub mod gateio;
 
+/// `Gemini` [`Connector`] and [`StreamSelector`] implementations.
+pub mod gemini;
+
 /// `Kraken` [`Connector`] and [`StreamSelector`] implementations.
 pub mod kraken;
 
+/// `Kucoin` [`Connector`] and [`StreamSelector`] implementations.
+pub mod kucoin;
+
+/// `Mexc` [`Connector`] and [`StreamSelector`] implementations.
+pub mod mexc;
+
 /// `Okx` [`Connector`] and [`StreamSelector`] implementations.
 pub mod okx;
 
+/// `UpbitSpot` [`Connector`] and [`StreamSelector`] implementations.
+pub mod upbit;
+
 /// Defines the generic [`ExchangeSub`] containing a market and channel combination used by an
 /// exchange [`Connector`] to build [`WsMessage`] subscription payloads.
 pub mod subscription;
@@ -109,6 +140,20 @@ where
     /// Base [`Url`] of the exchange server being connected with.
     fn url() -> Result<Url, SocketError>;
 
+    /// Base [`Url`] of the exchange server in the provided [`Environment`].
+    ///
+    /// Defaults to [`Self::url`] for [`Environment::Mainnet`], with no
+    /// [`Environment::Testnet`] support.
+    fn url_for(environment: Environment) -> Result<Url, SocketError> {
+        match environment {
+            Environment::Mainnet => Self::url(),
+            Environment::Testnet => Err(SocketError::Unsupported {
+                entity: Self::ID.to_string(),
+                item: environment.to_string(),
+            }),
+        }
+    }
+
     /// Defines [`PingInterval`] of custom application-level
     /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) pings for the exchange
     /// server being connected with.
@@ -134,6 +179,36 @@ where
     fn subscription_timeout() -> Duration {
         DEFAULT_SUBSCRIPTION_TIMEOUT
     }
+
+    /// Validate the [`SubscriptionParams`] of a [`Subscription`](subscription::Subscription) to
+    /// the provided [`Self::Channel`].
+    ///
+    /// Defaults to only supporting the default [`SubscriptionParams`] (ie/ exchange defaults).
+    fn validate_params(
+        channel: &Self::Channel,
+        params: &SubscriptionParams,
+    ) -> Result<(), SocketError> {
+        if params.is_default() {
+            Ok(())
+        } else {
+            Err(SocketError::Unsupported {
+                entity: Self::ID.to_string(),
+                item: format!("{} channel with {params:?}", channel.as_ref()),
+            })
+        }
+    }
+
+    /// Determine if a [`WsMessage`] received mid-stream is an exchange control message notifying
+    /// that some, or all, of the actioned [`Subscription`](subscription::Subscription)s must be
+    /// re-subscribed (eg/ "subscription invalidated").
+    ///
+    /// Recognised notices trigger a targeted resubscription of the affected
+    /// [`Subscription`](subscription::Subscription)s over the existing connection.
+    ///
+    /// Defaults to `None`, meaning that no resubscription notices are recognised.
+    fn resubscription_notice(_: &WsMessage) -> Option<ResubscriptionNotice> {
+        None
+    }
 }
 
 /// Used when an exchange has servers different
@@ -146,6 +221,24 @@ where
 pub trait ExchangeServer: Default + Debug + Clone + Send {
     const ID: ExchangeId;
     fn websocket_url() -> &'static str;
+
+    /// WebSocket url of the [`Environment::Testnet`] server, if any.
+    fn websocket_url_testnet() -> Option<&'static str> {
+        None
+    }
+
+    /// WebSocket url of the server in the provided [`Environment`].
+    fn websocket_url_for(environment: Environment) -> Result<&'static str, SocketError> {
+        match environment {
+            Environment::Mainnet => Ok(Self::websocket_url()),
+            Environment::Testnet => {
+                Self::websocket_url_testnet().ok_or_else(|| SocketError::Unsupported {
+                    entity: Self::ID.to_string(),
+                    item: environment.to_string(),
+                })
+            }
+        }
+    }
 }
 
 /// Defines the frequency and construction function for custom
@@ -1,6 +1,6 @@
 use super::Okx;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{liquidation::Liquidations, trade::PublicTrades, Subscription},
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +17,14 @@ impl OkxChannel {
     ///
     /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-trades-channel>
     pub const TRADES: Self = Self("trades");
+
+    /// [`Okx`] real-time liquidation orders channel.
+    ///
+    /// Note that this channel is subscribed to per instrument type (eg/ "SWAP") rather than per
+    /// instrument, so liquidations of every instrument of that type are received.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-liquidation-orders-channel>
+    pub const LIQUIDATIONS: Self = Self("liquidation-orders");
 }
 
 impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, PublicTrades> {
@@ -25,6 +33,12 @@ impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, Public
     }
 }
 
+impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, Liquidations> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::LIQUIDATIONS
+    }
+}
+
 impl AsRef<str> for OkxChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -0,0 +1,269 @@
+use crate::instrument::metadata::InstrumentMetadata;
+use barter_instrument::{
+    asset::symbol::Symbol,
+    exchange::ExchangeId,
+    instrument::{
+        kind::InstrumentKind,
+        spec::{
+            InstrumentSpec, InstrumentSpecNotional, InstrumentSpecPrice, InstrumentSpecQuantity,
+            OrderQuantityUnits,
+        },
+        Instrument,
+    },
+};
+use barter_integration::{
+    de::{de_str, de_str_optional},
+    error::SocketError,
+};
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
+
+/// [`Okx`](super::Okx) HTTP public instruments url. The `instType` query parameter (eg/ "SPOT",
+/// "SWAP") must be appended.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-instruments>
+pub const HTTP_INSTRUMENTS_URL_OKX: &str = "https://www.okx.com/api/v5/public/instruments";
+
+/// [`Okx`](super::Okx) public instruments response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-instruments>
+/// ```json
+/// {
+///     "code": "0",
+///     "msg": "",
+///     "data": [
+///         {
+///             "instId": "BTC-USDT-SWAP", "instType": "SWAP", "uly": "BTC-USDT",
+///             "baseCcy": "", "quoteCcy": "", "settleCcy": "USDT",
+///             "ctVal": "0.01", "ctMult": "1", "tickSz": "0.1", "lotSz": "0.01", "minSz": "0.01",
+///             "maxLmtSz": "100000000", "state": "live"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OkxInstruments {
+    pub code: SmolStr,
+    #[serde(default)]
+    pub msg: String,
+    pub data: Vec<OkxInstrument>,
+}
+
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxInstrument {
+    pub inst_id: SmolStr,
+    pub inst_type: SmolStr,
+    pub state: SmolStr,
+    #[serde(default)]
+    pub base_ccy: SmolStr,
+    #[serde(default)]
+    pub quote_ccy: SmolStr,
+    /// Underlying of derivative instruments, eg/ "BTC-USDT".
+    #[serde(default)]
+    pub uly: SmolStr,
+    #[serde(deserialize_with = "de_str")]
+    pub tick_sz: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub lot_sz: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub min_sz: f64,
+    #[serde(default, deserialize_with = "de_str_optional")]
+    pub max_lmt_sz: Option<f64>,
+    #[serde(default, deserialize_with = "de_str_optional")]
+    pub ct_val: Option<f64>,
+    #[serde(default, deserialize_with = "de_str_optional")]
+    pub ct_mult: Option<f64>,
+}
+
+impl OkxInstruments {
+    /// Map every live spot & perpetual swap [`OkxInstrument`] into [`InstrumentMetadata`].
+    pub fn into_metadata(self) -> Result<Vec<InstrumentMetadata>, SocketError> {
+        if self.code != "0" {
+            return Err(SocketError::Exchange(format!(
+                "Okx instruments error code {}: {}",
+                self.code, self.msg
+            )));
+        }
+
+        Ok(self
+            .data
+            .into_iter()
+            .filter(|instrument| instrument.state == "live")
+            .filter_map(OkxInstrument::into_metadata)
+            .collect())
+    }
+}
+
+impl OkxInstrument {
+    fn into_metadata(self) -> Option<InstrumentMetadata> {
+        let (instrument, unit, contract_multiplier) = match self.inst_type.as_str() {
+            "SPOT" => (
+                Instrument::new(
+                    self.base_ccy.as_str(),
+                    self.quote_ccy.as_str(),
+                    InstrumentKind::Spot,
+                ),
+                OrderQuantityUnits::Asset(Symbol::new(self.base_ccy.as_str())),
+                None,
+            ),
+            "SWAP" => {
+                let (base, quote) = self.uly.split_once('-')?;
+                (
+                    Instrument::new(base, quote, InstrumentKind::Perpetual),
+                    OrderQuantityUnits::Contract,
+                    Some(self.ct_val? * self.ct_mult.unwrap_or(1.0)),
+                )
+            }
+            _ => return None,
+        };
+
+        Some(InstrumentMetadata {
+            exchange: ExchangeId::Okx,
+            name_exchange: self.inst_id,
+            instrument,
+            spec: InstrumentSpec {
+                price: InstrumentSpecPrice {
+                    min: self.tick_sz,
+                    tick_size: self.tick_sz,
+                    tick_size_schedule: None,
+                },
+                quantity: InstrumentSpecQuantity {
+                    unit,
+                    min: self.min_sz,
+                    increment: self.lot_sz,
+                    max: self.max_lmt_sz,
+                    contract_multiplier,
+                },
+                notional: InstrumentSpecNotional {
+                    min: 0.0,
+                    max: None,
+                },
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_okx_instruments_into_metadata() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<Vec<InstrumentMetadata>, SocketError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: live SPOT & SWAP instruments are mapped, suspended instrument is skipped
+                input: r#"
+                {
+                    "code": "0",
+                    "msg": "",
+                    "data": [
+                        {
+                            "instId": "BTC-USDT", "instType": "SPOT", "uly": "", "baseCcy": "BTC",
+                            "quoteCcy": "USDT", "ctVal": "", "ctMult": "", "tickSz": "0.1",
+                            "lotSz": "0.00000001", "minSz": "0.00001", "maxLmtSz": "9999999999",
+                            "state": "live"
+                        },
+                        {
+                            "instId": "BTC-USDT-SWAP", "instType": "SWAP", "uly": "BTC-USDT",
+                            "baseCcy": "", "quoteCcy": "", "ctVal": "0.01", "ctMult": "1",
+                            "tickSz": "0.1", "lotSz": "0.01", "minSz": "0.01", "maxLmtSz": "100000000",
+                            "state": "live"
+                        },
+                        {
+                            "instId": "ETH-USDT", "instType": "SPOT", "uly": "", "baseCcy": "ETH",
+                            "quoteCcy": "USDT", "ctVal": "", "ctMult": "", "tickSz": "0.01",
+                            "lotSz": "0.000001", "minSz": "0.0001", "maxLmtSz": "",
+                            "state": "suspend"
+                        }
+                    ]
+                }
+                "#,
+                expected: Ok(vec![
+                    InstrumentMetadata {
+                        exchange: ExchangeId::Okx,
+                        name_exchange: SmolStr::new("BTC-USDT"),
+                        instrument: Instrument::new("btc", "usdt", InstrumentKind::Spot),
+                        spec: InstrumentSpec {
+                            price: InstrumentSpecPrice {
+                                min: 0.1,
+                                tick_size: 0.1,
+                                tick_size_schedule: None,
+                            },
+                            quantity: InstrumentSpecQuantity {
+                                unit: OrderQuantityUnits::Asset(Symbol::new("btc")),
+                                min: 0.00001,
+                                increment: 0.00000001,
+                                max: Some(9999999999.0),
+                                contract_multiplier: None,
+                            },
+                            notional: InstrumentSpecNotional {
+                                min: 0.0,
+                                max: None,
+                            },
+                        },
+                    },
+                    InstrumentMetadata {
+                        exchange: ExchangeId::Okx,
+                        name_exchange: SmolStr::new("BTC-USDT-SWAP"),
+                        instrument: Instrument::new("btc", "usdt", InstrumentKind::Perpetual),
+                        spec: InstrumentSpec {
+                            price: InstrumentSpecPrice {
+                                min: 0.1,
+                                tick_size: 0.1,
+                                tick_size_schedule: None,
+                            },
+                            quantity: InstrumentSpecQuantity {
+                                unit: OrderQuantityUnits::Contract,
+                                min: 0.01,
+                                increment: 0.01,
+                                max: Some(100000000.0),
+                                contract_multiplier: Some(0.01),
+                            },
+                            notional: InstrumentSpecNotional {
+                                min: 0.0,
+                                max: None,
+                            },
+                        },
+                    },
+                ]),
+            },
+            TestCase {
+                // TC1: non-zero error code
+                input: r#"{"code": "50011", "msg": "Too Many Requests", "data": []}"#,
+                expected: Err(SocketError::Exchange(
+                    "Okx instruments error code 50011: Too Many Requests".to_string(),
+                )),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<OkxInstruments>(test.input)
+                .unwrap()
+                .into_metadata();
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(actual), Err(expected)) => {
+                    assert_eq!(
+                        actual.to_string(),
+                        expected.to_string(),
+                        "TC{} failed",
+                        index
+                    )
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,256 @@
+use super::{channel::OkxChannel, Okx};
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    exchange::{Connector, ExchangeSub},
+    subscription::{
+        liquidation::{Liquidation, Liquidations},
+        Map,
+    },
+    transformer::ExchangeTransformer,
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{protocol::websocket::WsMessage, Side, Transformer};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
+use tokio::sync::mpsc::UnboundedSender;
+
+/// [`Okx`] real-time liquidation orders WebSocket message.
+///
+/// Liquidation orders are published per instrument type (eg/ "SWAP"), so the "arg" does not
+/// identify an instrument, and each message may contain the liquidations of many instruments.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-liquidation-orders-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "liquidation-orders",
+///     "instType": "SWAP"
+///   },
+///   "data": [
+///     {
+///       "details": [
+///         {
+///           "bkLoss": "0",
+///           "bkPx": "0.007831",
+///           "ccy": "",
+///           "posSide": "short",
+///           "side": "buy",
+///           "sz": "13",
+///           "ts": "1692266434010"
+///         }
+///       ],
+///       "instFamily": "IOST-USDT",
+///       "instId": "IOST-USDT-SWAP",
+///       "instType": "SWAP",
+///       "uly": "IOST-USDT"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidations {
+    pub data: Vec<OkxLiquidation>,
+}
+
+/// [`Okx`] liquidation orders of a single instrument.
+///
+/// See [`OkxLiquidations`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidation {
+    #[serde(rename = "instId")]
+    pub market: SmolStr,
+    pub details: Vec<OkxLiquidationDetail>,
+}
+
+/// [`Okx`] liquidation order, where the side is that of the liquidation order (eg/ "buy" when
+/// a short position is liquidated).
+///
+/// See [`OkxLiquidations`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidationDetail {
+    pub side: Side,
+    #[serde(rename = "bkPx", deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(rename = "sz", deserialize_with = "barter_integration::de::de_str")]
+    pub quantity: f64,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+/// [`Okx`] liquidations transformer.
+///
+/// Since [`OkxLiquidations`] are published for every instrument of the subscribed instrument
+/// type, each [`OkxLiquidation`] is identified using its "instId", and liquidations of
+/// instruments that were not subscribed to are skipped.
+#[derive(Debug)]
+pub struct OkxLiquidationsTransformer<InstrumentKey> {
+    instrument_map: Map<InstrumentKey>,
+}
+
+#[async_trait]
+impl<InstrumentKey> ExchangeTransformer<Okx, InstrumentKey, Liquidations>
+    for OkxLiquidationsTransformer<InstrumentKey>
+where
+    InstrumentKey: Clone + Send,
+{
+    async fn init(
+        instrument_map: Map<InstrumentKey>,
+        _: &[MarketEvent<InstrumentKey, Liquidation>],
+        _: UnboundedSender<WsMessage>,
+    ) -> Result<Self, DataError> {
+        Ok(Self { instrument_map })
+    }
+}
+
+impl<InstrumentKey> Transformer for OkxLiquidationsTransformer<InstrumentKey>
+where
+    InstrumentKey: Clone,
+{
+    type Error = DataError;
+    type Input = OkxLiquidations;
+    type Output = MarketEvent<InstrumentKey, Liquidation>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        let time_received = Utc::now();
+
+        input
+            .data
+            .into_iter()
+            .filter_map(|liquidation| {
+                // Skip liquidations of instruments that were not subscribed to
+                let subscription_id =
+                    ExchangeSub::from((OkxChannel::LIQUIDATIONS, &liquidation.market)).id();
+                let instrument = self.instrument_map.find(&subscription_id).ok()?;
+
+                Some(liquidation.details.into_iter().map(move |detail| {
+                    Ok(MarketEvent {
+                        time_exchange: detail.time,
+                        time_received,
+                        exchange: Okx::ID,
+                        instrument: instrument.clone(),
+                        kind: Liquidation {
+                            side: detail.side,
+                            price: detail.price,
+                            quantity: detail.quantity,
+                            time: detail.time,
+                        },
+                    })
+                }))
+            })
+            .flatten()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::{de::datetime_utc_from_epoch_duration, subscription::SubscriptionId};
+    use std::time::Duration;
+
+    #[test]
+    fn test_de_okx_liquidations() {
+        let input = r#"
+        {
+            "arg": {
+                "channel": "liquidation-orders",
+                "instType": "SWAP"
+            },
+            "data": [
+                {
+                    "details": [
+                        {
+                            "bkLoss": "0",
+                            "bkPx": "0.007831",
+                            "ccy": "",
+                            "posSide": "short",
+                            "side": "buy",
+                            "sz": "13",
+                            "ts": "1692266434010"
+                        }
+                    ],
+                    "instFamily": "IOST-USDT",
+                    "instId": "IOST-USDT-SWAP",
+                    "instType": "SWAP",
+                    "uly": "IOST-USDT"
+                }
+            ]
+        }
+        "#;
+
+        assert_eq!(
+            serde_json::from_str::<OkxLiquidations>(input).unwrap(),
+            OkxLiquidations {
+                data: vec![OkxLiquidation {
+                    market: SmolStr::new("IOST-USDT-SWAP"),
+                    details: vec![OkxLiquidationDetail {
+                        side: Side::Buy,
+                        price: 0.007831,
+                        quantity: 13.0,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1692266434010
+                        )),
+                    }],
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn test_okx_liquidations_transformer() {
+        let time = datetime_utc_from_epoch_duration(Duration::from_millis(1692266434010));
+        let detail = OkxLiquidationDetail {
+            side: Side::Sell,
+            price: 30000.0,
+            quantity: 2.0,
+            time,
+        };
+
+        let mut transformer = OkxLiquidationsTransformer {
+            instrument_map: Map::from_iter([(
+                SubscriptionId::from("liquidation-orders|BTC-USDT-SWAP"),
+                "instrument",
+            )]),
+        };
+
+        let actual = transformer
+            .transform(OkxLiquidations {
+                data: vec![
+                    OkxLiquidation {
+                        market: SmolStr::new("BTC-USDT-SWAP"),
+                        details: vec![detail.clone()],
+                    },
+                    OkxLiquidation {
+                        market: SmolStr::new("ETH-USDT-SWAP"),
+                        details: vec![detail],
+                    },
+                ],
+            })
+            .into_iter()
+            .map(|event| event.map(|event| (event.instrument, event.kind)))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        // Liquidations of the unsubscribed ETH-USDT-SWAP instrument are skipped
+        assert_eq!(
+            actual,
+            vec![(
+                "instrument",
+                Liquidation {
+                    side: Side::Sell,
+                    price: 30000.0,
+                    quantity: 2.0,
+                    time,
+                }
+            )]
+        );
+    }
+}
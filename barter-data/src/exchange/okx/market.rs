@@ -38,6 +38,26 @@ impl<Kind> Identifier<OkxMarket> for Subscription<Okx, MarketInstrumentData, Kin
     }
 }
 
+impl OkxMarket {
+    /// Determine the [`Okx`] instrument type (eg/ "SWAP") of this [`OkxMarket`], used by
+    /// channels that are subscribed to per instrument type rather than per instrument.
+    ///
+    /// eg/ "BTC-USDT-SWAP" -> "SWAP", "BTC-USD-230526" -> "FUTURES"
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-liquidation-orders-channel>
+    pub fn inst_type(&self) -> &'static str {
+        if self.0.ends_with("-SWAP") {
+            return "SWAP";
+        }
+
+        match self.0.split('-').count() {
+            3 => "FUTURES",
+            5 => "OPTION",
+            _ => "MARGIN",
+        }
+    }
+}
+
 impl AsRef<str> for OkxMarket {
     fn as_ref(&self) -> &str {
         &self.0
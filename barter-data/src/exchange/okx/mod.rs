@@ -1,17 +1,21 @@
 use self::{
-    channel::OkxChannel, market::OkxMarket, subscription::OkxSubResponse, trade::OkxTrades,
+    channel::OkxChannel, liquidation::OkxLiquidationsTransformer, market::OkxMarket,
+    subscription::OkxSubResponse, trade::OkxTrades,
 };
 use crate::{
     exchange::{Connector, ExchangeSub, PingInterval, StreamSelector},
     instrument::InstrumentData,
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::trade::PublicTrades,
+    subscription::{liquidation::Liquidations, trade::PublicTrades, Map},
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream, NoInitialSnapshots,
 };
 use barter_instrument::exchange::ExchangeId;
-use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_integration::{
+    environment::Environment, error::SocketError, protocol::websocket::WsMessage,
+};
 use barter_macro::{DeExchange, SerExchange};
+use fnv::FnvHashSet;
 use serde_json::json;
 use std::time::Duration;
 use url::Url;
@@ -20,6 +24,13 @@ use url::Url;
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
+/// Public instruments types used to fetch the
+/// [`InstrumentMetadata`](crate::instrument::metadata::InstrumentMetadata) of [`Okx`].
+pub mod instrument;
+
+/// Liquidation types for [`Okx`].
+pub mod liquidation;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
@@ -36,6 +47,11 @@ pub mod trade;
 /// See docs: <https://www.okx.com/docs-v5/en/#overview-api-resources-and-support>
 pub const BASE_URL_OKX: &str = "wss://wsaws.okx.com:8443/ws/v5/public";
 
+/// [`Okx`] demo trading server base url.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-demo-trading-services>
+pub const BASE_URL_OKX_DEMO: &str = "wss://wspap.okx.com:8443/ws/v5/public?brokerId=9999";
+
 /// [`Okx`] server [`PingInterval`] duration.
 ///
 /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-connect>
@@ -61,6 +77,13 @@ impl Connector for Okx {
         Url::parse(BASE_URL_OKX).map_err(SocketError::UrlParse)
     }
 
+    fn url_for(environment: Environment) -> Result<Url, SocketError> {
+        match environment {
+            Environment::Mainnet => Self::url(),
+            Environment::Testnet => Url::parse(BASE_URL_OKX_DEMO).map_err(SocketError::UrlParse),
+        }
+    }
+
     fn ping_interval() -> Option<PingInterval> {
         Some(PingInterval {
             interval: tokio::time::interval(PING_INTERVAL_OKX),
@@ -69,14 +92,42 @@ impl Connector for Okx {
     }
 
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        // Liquidation subscriptions of the same instrument type serialise to the same argument
+        let args = exchange_subs.iter().map(|sub| json!(sub)).fold(
+            Vec::with_capacity(exchange_subs.len()),
+            |mut args, arg| {
+                if !args.contains(&arg) {
+                    args.push(arg);
+                }
+                args
+            },
+        );
+
         vec![WsMessage::Text(
             json!({
                 "op": "subscribe",
-                "args": &exchange_subs,
+                "args": args,
             })
             .to_string(),
         )]
     }
+
+    fn expected_responses<InstrumentKey>(map: &Map<InstrumentKey>) -> usize {
+        // One response is received per unique subscription argument (see Okx::requests)
+        map.0
+            .keys()
+            .map(
+                |subscription_id| match subscription_id.as_ref().split_once('|') {
+                    Some((channel, market)) if channel == OkxChannel::LIQUIDATIONS.as_ref() => {
+                        (channel, OkxMarket(market.into()).inst_type())
+                    }
+                    Some((channel, market)) => (channel, market),
+                    None => (subscription_id.as_ref(), ""),
+                },
+            )
+            .collect::<FnvHashSet<_>>()
+            .len()
+    }
 }
 
 impl<Instrument> StreamSelector<Instrument, PublicTrades> for Okx
@@ -87,3 +138,40 @@ where
     type Stream =
         ExchangeWsStream<StatelessTransformer<Self, Instrument::Key, PublicTrades, OkxTrades>>;
 }
+
+impl<Instrument> StreamSelector<Instrument, Liquidations> for Okx
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = ExchangeWsStream<OkxLiquidationsTransformer<Instrument::Key>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Identifier;
+
+    #[test]
+    fn test_okx_requests_and_expected_responses() {
+        let exchange_subs = vec![
+            ExchangeSub::from((OkxChannel::TRADES, OkxMarket("BTC-USDT-SWAP".into()))),
+            ExchangeSub::from((OkxChannel::LIQUIDATIONS, OkxMarket("BTC-USDT-SWAP".into()))),
+            ExchangeSub::from((OkxChannel::LIQUIDATIONS, OkxMarket("ETH-USDT-SWAP".into()))),
+        ];
+
+        let map = exchange_subs
+            .iter()
+            .map(|sub| (sub.id(), ()))
+            .collect::<Map<()>>();
+
+        // Liquidations of both SWAP instruments share a single subscription argument
+        assert_eq!(
+            Okx::requests(exchange_subs),
+            vec![WsMessage::text(
+                r#"{"args":[{"channel":"trades","instId":"BTC-USDT-SWAP"},{"channel":"liquidation-orders","instType":"SWAP"}],"op":"subscribe"}"#
+            )]
+        );
+        assert_eq!(Okx::expected_responses(&map), 2);
+    }
+}
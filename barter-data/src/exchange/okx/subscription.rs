@@ -11,7 +11,13 @@ impl Serialize for ExchangeSub<OkxChannel, OkxMarket> {
     {
         let mut state = serializer.serialize_struct("OkxSubArg", 2)?;
         state.serialize_field("channel", self.channel.as_ref())?;
-        state.serialize_field("instId", self.market.as_ref())?;
+
+        // Liquidation orders are subscribed to per instrument type rather than per instrument
+        if self.channel == OkxChannel::LIQUIDATIONS {
+            state.serialize_field("instType", self.market.inst_type())?;
+        } else {
+            state.serialize_field("instId", self.market.as_ref())?;
+        }
         state.end()
     }
 }
@@ -125,6 +131,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_serialise_okx_sub_arg() {
+        struct TestCase {
+            input: ExchangeSub<OkxChannel, OkxMarket>,
+            expected: &'static str,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: trades are subscribed to per instrument
+                input: ExchangeSub::from((OkxChannel::TRADES, OkxMarket("BTC-USDT".into()))),
+                expected: r#"{"channel":"trades","instId":"BTC-USDT"}"#,
+            },
+            TestCase {
+                // TC1: perpetual liquidations are subscribed to per instrument type
+                input: ExchangeSub::from((
+                    OkxChannel::LIQUIDATIONS,
+                    OkxMarket("BTC-USDT-SWAP".into()),
+                )),
+                expected: r#"{"channel":"liquidation-orders","instType":"SWAP"}"#,
+            },
+            TestCase {
+                // TC2: future liquidations are subscribed to per instrument type
+                input: ExchangeSub::from((
+                    OkxChannel::LIQUIDATIONS,
+                    OkxMarket("BTC-USD-230526".into()),
+                )),
+                expected: r#"{"channel":"liquidation-orders","instType":"FUTURES"}"#,
+            },
+            TestCase {
+                // TC3: option liquidations are subscribed to per instrument type
+                input: ExchangeSub::from((
+                    OkxChannel::LIQUIDATIONS,
+                    OkxMarket("BTC-USD-230526-35000-C".into()),
+                )),
+                expected: r#"{"channel":"liquidation-orders","instType":"OPTION"}"#,
+            },
+            TestCase {
+                // TC4: spot (margin) liquidations are subscribed to per instrument type
+                input: ExchangeSub::from((OkxChannel::LIQUIDATIONS, OkxMarket("BTC-USDT".into()))),
+                expected: r#"{"channel":"liquidation-orders","instType":"MARGIN"}"#,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::to_string(&test.input).unwrap();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
     #[test]
     fn test_validate_okx_sub_response() {
         struct TestCase {
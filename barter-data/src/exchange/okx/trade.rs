@@ -110,6 +110,7 @@ impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, OkxTrades)>
                         price: trade.price,
                         amount: trade.amount,
                         side: trade.side,
+                        aggregate_count: None,
                     },
                 })
             })
@@ -0,0 +1,293 @@
+use super::config::ConnectorConfig;
+use crate::error::DataError;
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::{
+    auth::clock::ServerClock, environment::EnvironmentUrl, error::SocketError, metric::Metric,
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::{sync::Arc, time::Duration};
+use tokio::sync::mpsc::UnboundedSender;
+use tracing::{debug, warn};
+
+/// Default interval between server time samples of a [`sync_server_clock`] task.
+pub const DEFAULT_SERVER_CLOCK_SYNC_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Periodically sync the provided [`ServerClock`] of an [`ExchangeId`] using it's server time
+/// endpoint in the [`ConnectorConfig`] environment, forever.
+///
+/// A [`ServerClock`] measures the skew against a single exchange server, so must not be shared
+/// between exchanges or environments (eg/ testnet vs mainnet). The same [`ServerClock`] can be
+/// provided to an execution client [`Authenticator`](barter_integration::auth::Authenticator)
+/// of the same exchange & environment, so that signed requests are timestamped within the
+/// exchange receive window.
+///
+/// A [`Metric`] of the offset & round trip is sent after every accepted sample, if a `metric_tx`
+/// is provided. Failed or rejected samples are logged, leaving the offset unchanged.
+///
+/// eg/ `tokio::spawn(sync_server_clock(ExchangeId::Okx, ConnectorConfig::default(), clock, DEFAULT_SERVER_CLOCK_SYNC_INTERVAL, None))`
+pub async fn sync_server_clock(
+    exchange: ExchangeId,
+    config: ConnectorConfig,
+    clock: Arc<ServerClock>,
+    interval: Duration,
+    metric_tx: Option<UnboundedSender<Metric>>,
+) {
+    let mut interval = tokio::time::interval(interval);
+
+    loop {
+        interval.tick().await;
+
+        match clock.sync(|| fetch_server_time(exchange, &config)).await {
+            Ok(offset) => {
+                debug!(
+                    %exchange,
+                    environment = %config.environment,
+                    offset_ms = offset.num_milliseconds(),
+                    round_trip_ms = clock.round_trip().num_milliseconds(),
+                    "synced exchange server clock"
+                );
+
+                if let Some(metric_tx) = &metric_tx {
+                    let _ = metric_tx.send(clock.metric(exchange.to_string()));
+                }
+            }
+            Err(error) => {
+                warn!(
+                    %exchange,
+                    environment = %config.environment,
+                    ?error,
+                    "failed to sync exchange server clock"
+                );
+            }
+        }
+    }
+}
+
+/// Fetch the current server time of the provided [`ExchangeId`], using the
+/// [`Environment`](barter_integration::environment::Environment) of the provided
+/// [`ConnectorConfig`] & it's proxy, if any.
+pub async fn fetch_server_time(
+    exchange: ExchangeId,
+    config: &ConnectorConfig,
+) -> Result<DateTime<Utc>, DataError> {
+    let url = server_time_url(exchange)?.get(config.environment)?;
+    let response = config
+        .http_client()?
+        .get(url)
+        .send()
+        .await
+        .map_err(SocketError::Http)?;
+
+    match exchange {
+        ExchangeId::BinanceSpot | ExchangeId::BinanceFuturesUsd => response
+            .json::<BinanceServerTime>()
+            .await
+            .map(|time| time.server_time)
+            .map_err(|error| DataError::Socket(SocketError::Http(error))),
+        ExchangeId::BybitSpot | ExchangeId::BybitPerpetualsUsd => response
+            .json::<BybitServerTime>()
+            .await
+            .map(|time| time.time)
+            .map_err(|error| DataError::Socket(SocketError::Http(error))),
+        ExchangeId::Coinbase => response
+            .json::<CoinbaseServerTime>()
+            .await
+            .map(|time| time.iso)
+            .map_err(|error| DataError::Socket(SocketError::Http(error))),
+        ExchangeId::GateioSpot | ExchangeId::GateioPerpetualsUsd => response
+            .json::<GateioServerTime>()
+            .await
+            .map(|time| time.server_time)
+            .map_err(|error| DataError::Socket(SocketError::Http(error))),
+        ExchangeId::Okx => response
+            .json::<OkxServerTime>()
+            .await
+            .map_err(|error| DataError::Socket(SocketError::Http(error)))?
+            .server_time(),
+        _ => Err(DataError::Socket(unsupported(exchange))),
+    }
+}
+
+/// Server time endpoint of the provided [`ExchangeId`] in each
+/// [`Environment`](barter_integration::environment::Environment).
+pub fn server_time_url(exchange: ExchangeId) -> Result<EnvironmentUrl, SocketError> {
+    match exchange {
+        ExchangeId::BinanceSpot => Ok(EnvironmentUrl::new(
+            "https://api.binance.com/api/v3/time",
+            "https://testnet.binance.vision/api/v3/time",
+        )),
+        ExchangeId::BinanceFuturesUsd => Ok(EnvironmentUrl::new(
+            "https://fapi.binance.com/fapi/v1/time",
+            "https://testnet.binancefuture.com/fapi/v1/time",
+        )),
+        ExchangeId::BybitSpot | ExchangeId::BybitPerpetualsUsd => Ok(EnvironmentUrl::new(
+            "https://api.bybit.com/v5/market/time",
+            "https://api-testnet.bybit.com/v5/market/time",
+        )),
+        ExchangeId::Coinbase => Ok(EnvironmentUrl::new(
+            "https://api.exchange.coinbase.com/time",
+            "https://api-public.sandbox.exchange.coinbase.com/time",
+        )),
+        ExchangeId::GateioSpot | ExchangeId::GateioPerpetualsUsd => Ok(EnvironmentUrl::mainnet(
+            "https://api.gateio.ws/api/v4/spot/time",
+        )),
+        ExchangeId::Okx => Ok(EnvironmentUrl::mainnet(
+            "https://www.okx.com/api/v5/public/time",
+        )),
+        _ => Err(unsupported(exchange)),
+    }
+}
+
+fn unsupported(exchange: ExchangeId) -> SocketError {
+    SocketError::Unsupported {
+        entity: exchange.to_string(),
+        item: "server time endpoint".to_string(),
+    }
+}
+
+/// [`ExchangeId::BinanceSpot`] & [`ExchangeId::BinanceFuturesUsd`] server time response.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#check-server-time>
+/// ```json
+/// {"serverTime":1499827319559}
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug, Deserialize)]
+struct BinanceServerTime {
+    #[serde(rename = "serverTime", with = "chrono::serde::ts_milliseconds")]
+    server_time: DateTime<Utc>,
+}
+
+/// [`ExchangeId::BybitSpot`] & [`ExchangeId::BybitPerpetualsUsd`] server time response.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/market/time>
+/// ```json
+/// {"retCode":0,"retMsg":"OK","result":{"timeSecond":"1688639403","timeNano":"1688639403423213947"},"retExtInfo":{},"time":1688639403423}
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug, Deserialize)]
+struct BybitServerTime {
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    time: DateTime<Utc>,
+}
+
+/// [`ExchangeId::Coinbase`] server time response.
+///
+/// See docs: <https://docs.cdp.coinbase.com/exchange/reference/exchangerestapi_gettime>
+/// ```json
+/// {"iso":"2015-01-07T23:47:25.201Z","epoch":1420674445.201}
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug, Deserialize)]
+struct CoinbaseServerTime {
+    iso: DateTime<Utc>,
+}
+
+/// [`ExchangeId::GateioSpot`] & [`ExchangeId::GateioPerpetualsUsd`] server time response.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#get-server-current-time>
+/// ```json
+/// {"server_time":1597026383085}
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug, Deserialize)]
+struct GateioServerTime {
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    server_time: DateTime<Utc>,
+}
+
+/// [`ExchangeId::Okx`] server time response.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-system-time>
+/// ```json
+/// {"code":"0","data":[{"ts":"1597026383085"}],"msg":""}
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+struct OkxServerTime {
+    data: Vec<OkxServerTimeData>,
+}
+
+impl OkxServerTime {
+    /// Server time of the first `data` element, or [`DataError::ServerTimeMissing`] if empty.
+    fn server_time(&self) -> Result<DateTime<Utc>, DataError> {
+        self.data
+            .first()
+            .map(|data| data.time)
+            .ok_or(DataError::ServerTimeMissing(ExchangeId::Okx))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Deserialize)]
+struct OkxServerTimeData {
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    time: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_server_time() {
+        struct TestCase {
+            input: &'static str,
+            actual: fn(&str) -> Result<DateTime<Utc>, serde_json::Error>,
+        }
+
+        let expected = DateTime::<Utc>::from_timestamp_millis(1597026383085).unwrap();
+
+        let tests = vec![
+            TestCase {
+                // TC0: Binance
+                input: r#"{"serverTime":1597026383085}"#,
+                actual: |input| {
+                    serde_json::from_str::<BinanceServerTime>(input).map(|time| time.server_time)
+                },
+            },
+            TestCase {
+                // TC1: Bybit
+                input: r#"{"retCode":0,"retMsg":"OK","result":{"timeSecond":"1597026383","timeNano":"1597026383085213947"},"retExtInfo":{},"time":1597026383085}"#,
+                actual: |input| {
+                    serde_json::from_str::<BybitServerTime>(input).map(|time| time.time)
+                },
+            },
+            TestCase {
+                // TC2: Coinbase
+                input: r#"{"iso":"2020-08-10T02:26:23.085Z","epoch":1597026383.085}"#,
+                actual: |input| {
+                    serde_json::from_str::<CoinbaseServerTime>(input).map(|time| time.iso)
+                },
+            },
+            TestCase {
+                // TC3: Gateio
+                input: r#"{"server_time":1597026383085}"#,
+                actual: |input| {
+                    serde_json::from_str::<GateioServerTime>(input).map(|time| time.server_time)
+                },
+            },
+            TestCase {
+                // TC4: Okx
+                input: r#"{"code":"0","data":[{"ts":"1597026383085"}],"msg":""}"#,
+                actual: |input| {
+                    serde_json::from_str::<OkxServerTime>(input).map(|time| time.data[0].time)
+                },
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = (test.actual)(test.input).unwrap();
+            assert_eq!(actual, expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_okx_server_time_empty_data() {
+        let time =
+            serde_json::from_str::<OkxServerTime>(r#"{"code":"0","data":[],"msg":""}"#).unwrap();
+
+        assert!(matches!(
+            time.server_time(),
+            Err(DataError::ServerTimeMissing(ExchangeId::Okx))
+        ));
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{subscription::Subscription, Identifier};
 use barter_integration::subscription::SubscriptionId;
 use serde::Deserialize;
+use smol_str::format_smolstr;
 
 /// Defines an exchange specific market and channel combination used by an exchange
 /// [`Connector`](super::Connector) to build the
@@ -46,8 +47,10 @@ where
     Channel: AsRef<str>,
     Market: AsRef<str>,
 {
+    /// Formatted inline (without a heap allocation) for identifiers up to 23 bytes, since it
+    /// is derived from every message received.
     fn id(&self) -> SubscriptionId {
-        SubscriptionId::from(format!(
+        SubscriptionId(format_smolstr!(
             "{}|{}",
             self.channel.as_ref(),
             self.market.as_ref()
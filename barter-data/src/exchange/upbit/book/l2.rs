@@ -0,0 +1,153 @@
+use super::{super::channel::UpbitChannel, UpbitOrderBookUnit};
+use crate::{
+    books::OrderBook,
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeSub,
+    subscription::book::OrderBookEvent,
+    Identifier,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::subscription::SubscriptionId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Upbit`](super::super::Upbit) OrderBook Level2 snapshot WebSocket message.
+///
+/// ### Notes
+/// [`Upbit`](super::super::Upbit) publishes the full top of the OrderBook on every change, so
+/// each [`UpbitOrderBookL2`] is interpreted as an [`OrderBookEvent::Snapshot`], using the
+/// exchange timestamp as the [`OrderBook`] sequence.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://global-docs.upbit.com/reference/websocket-orderbook>
+/// ```json
+/// {
+///     "type": "orderbook",
+///     "code": "KRW-BTC",
+///     "timestamp": 1676965262177,
+///     "total_ask_size": 4.79158413,
+///     "total_bid_size": 2.65609625,
+///     "orderbook_units": [
+///         {
+///             "ask_price": 31887000,
+///             "bid_price": 31883000,
+///             "ask_size": 0.00184096,
+///             "bid_size": 0.26309598
+///         }
+///     ],
+///     "stream_type": "REALTIME",
+///     "level": 0
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct UpbitOrderBookL2 {
+    #[serde(alias = "code", deserialize_with = "de_ob_l2_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc")]
+    pub timestamp: DateTime<Utc>,
+    #[serde(alias = "orderbook_units")]
+    pub units: Vec<UpbitOrderBookUnit>,
+}
+
+impl Identifier<Option<SubscriptionId>> for UpbitOrderBookL2 {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, UpbitOrderBookL2)>
+    for MarketIter<InstrumentKey, OrderBookEvent>
+{
+    fn from(
+        (exchange_id, instrument, book): (ExchangeId, InstrumentKey, UpbitOrderBookL2),
+    ) -> Self {
+        let sequence = book.timestamp.timestamp_millis() as u64;
+
+        Self(vec![Ok(MarketEvent {
+            time_exchange: book.timestamp,
+            time_received: Utc::now(),
+            exchange: exchange_id,
+            instrument,
+            kind: OrderBookEvent::Snapshot(OrderBook::new(
+                sequence,
+                Some(book.timestamp),
+                book.units.iter().map(UpbitOrderBookUnit::bid),
+                book.units.iter().map(UpbitOrderBookUnit::ask),
+            )),
+        })])
+    }
+}
+
+/// Deserialize an [`UpbitOrderBookL2`] "code" (eg/ "KRW-BTC") as the associated
+/// [`SubscriptionId`] (eg/ SubscriptionId("orderbook|KRW-BTC")).
+pub fn de_ob_l2_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|code| ExchangeSub::from((UpbitChannel::ORDER_BOOK_L2, code)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::books::Level;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_upbit_order_book_l2_into_snapshot() {
+        let input = r#"
+        {
+            "type": "orderbook",
+            "code": "KRW-BTC",
+            "timestamp": 1676965262177,
+            "total_ask_size": 4.79158413,
+            "total_bid_size": 2.65609625,
+            "orderbook_units": [
+                {
+                    "ask_price": 31887000,
+                    "bid_price": 31883000,
+                    "ask_size": 0.00184096,
+                    "bid_size": 0.26309598
+                },
+                {
+                    "ask_price": 31888000,
+                    "bid_price": 31882000,
+                    "ask_size": 0.5,
+                    "bid_size": 1.25
+                }
+            ],
+            "stream_type": "REALTIME",
+            "level": 0
+        }
+        "#;
+
+        let book = serde_json::from_str::<UpbitOrderBookL2>(input).unwrap();
+        assert_eq!(
+            book.subscription_id,
+            SubscriptionId::from("orderbook|KRW-BTC")
+        );
+
+        let timestamp = DateTime::from_timestamp_millis(1676965262177).unwrap();
+        let MarketIter(events) =
+            MarketIter::<(), OrderBookEvent>::from((ExchangeId::Upbit, (), book));
+        let event = events.into_iter().next().unwrap().unwrap();
+
+        assert_eq!(event.time_exchange, timestamp);
+        assert_eq!(
+            event.kind,
+            OrderBookEvent::Snapshot(OrderBook::new(
+                1676965262177,
+                Some(timestamp),
+                vec![
+                    Level::new(dec!(31883000), dec!(0.26309598)),
+                    Level::new(dec!(31882000), dec!(1.25)),
+                ],
+                vec![
+                    Level::new(dec!(31887000), dec!(0.00184096)),
+                    Level::new(dec!(31888000), dec!(0.5)),
+                ],
+            ))
+        );
+    }
+}
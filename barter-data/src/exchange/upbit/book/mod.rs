@@ -0,0 +1,71 @@
+use crate::books::Level;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Level 2 OrderBook types.
+pub mod l2;
+
+/// [`Upbit`](super::Upbit) OrderBook unit, containing the ask and bid [`Level`] at the same
+/// depth of the OrderBook.
+///
+/// #### Raw Payload Examples
+/// See docs: <https://global-docs.upbit.com/reference/websocket-orderbook>
+/// ```json
+/// {
+///     "ask_price": 31887000,
+///     "bid_price": 31883000,
+///     "ask_size": 0.00184096,
+///     "bid_size": 0.26309598
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct UpbitOrderBookUnit {
+    pub ask_price: Decimal,
+    pub bid_price: Decimal,
+    pub ask_size: Decimal,
+    pub bid_size: Decimal,
+}
+
+impl UpbitOrderBookUnit {
+    /// Bid [`Level`] of this [`UpbitOrderBookUnit`].
+    pub fn bid(&self) -> Level {
+        Level::new(self.bid_price, self.bid_size)
+    }
+
+    /// Ask [`Level`] of this [`UpbitOrderBookUnit`].
+    pub fn ask(&self) -> Level {
+        Level::new(self.ask_price, self.ask_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use rust_decimal_macros::dec;
+
+        #[test]
+        fn test_upbit_order_book_unit() {
+            let input = r#"
+            {
+                "ask_price": 31887000,
+                "bid_price": 31883000,
+                "ask_size": 0.00184096,
+                "bid_size": 0.26309598
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<UpbitOrderBookUnit>(input).unwrap(),
+                UpbitOrderBookUnit {
+                    ask_price: dec!(31887000),
+                    bid_price: dec!(31883000),
+                    ask_size: dec!(0.00184096),
+                    bid_size: dec!(0.26309598),
+                },
+            )
+        }
+    }
+}
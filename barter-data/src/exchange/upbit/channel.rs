@@ -0,0 +1,48 @@
+use super::Upbit;
+use crate::{
+    subscription::{book::OrderBooksL2, trade::PublicTrades, Subscription},
+    Identifier,
+};
+use serde::Serialize;
+
+/// Type that defines how to translate a Barter [`Subscription`] into an [`Upbit`] channel to be
+/// subscribed to.
+///
+/// See docs: <https://global-docs.upbit.com/reference/websocket-guide>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct UpbitChannel(pub &'static str);
+
+impl UpbitChannel {
+    /// [`Upbit`] real-time trades channel.
+    ///
+    /// See docs: <https://global-docs.upbit.com/reference/websocket-trade>
+    pub const TRADES: Self = Self("trade");
+
+    /// [`Upbit`] OrderBook Level2 channel, publishing a snapshot of the top levels of each side
+    /// on every change.
+    ///
+    /// See docs: <https://global-docs.upbit.com/reference/websocket-orderbook>
+    pub const ORDER_BOOK_L2: Self = Self("orderbook");
+}
+
+impl<Server, Instrument> Identifier<UpbitChannel>
+    for Subscription<Upbit<Server>, Instrument, PublicTrades>
+{
+    fn id(&self) -> UpbitChannel {
+        UpbitChannel::TRADES
+    }
+}
+
+impl<Server, Instrument> Identifier<UpbitChannel>
+    for Subscription<Upbit<Server>, Instrument, OrderBooksL2>
+{
+    fn id(&self) -> UpbitChannel {
+        UpbitChannel::ORDER_BOOK_L2
+    }
+}
+
+impl AsRef<str> for UpbitChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
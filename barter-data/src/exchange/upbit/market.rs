@@ -0,0 +1,48 @@
+use super::Upbit;
+use crate::{instrument::MarketInstrumentData, subscription::Subscription, Identifier};
+use barter_instrument::{asset::symbol::Symbol, instrument::Instrument, Keyed};
+use serde::{Deserialize, Serialize};
+use smol_str::{format_smolstr, SmolStr, StrExt};
+
+/// Type that defines how to translate a Barter [`Subscription`] into an [`Upbit`] market that
+/// can be subscribed to.
+///
+/// ### Notes
+/// [`Upbit`] market codes are formatted quote first, (eg/ "KRW-BTC" for the btc_krw
+/// [`Instrument`]).
+///
+/// See docs: <https://global-docs.upbit.com/reference/websocket-guide>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct UpbitMarket(pub SmolStr);
+
+impl<Server, Kind> Identifier<UpbitMarket> for Subscription<Upbit<Server>, Instrument, Kind> {
+    fn id(&self) -> UpbitMarket {
+        upbit_market(&self.instrument.base, &self.instrument.quote)
+    }
+}
+
+impl<Server, InstrumentKey, Kind> Identifier<UpbitMarket>
+    for Subscription<Upbit<Server>, Keyed<InstrumentKey, Instrument>, Kind>
+{
+    fn id(&self) -> UpbitMarket {
+        upbit_market(&self.instrument.value.base, &self.instrument.value.quote)
+    }
+}
+
+impl<Server, Kind> Identifier<UpbitMarket>
+    for Subscription<Upbit<Server>, MarketInstrumentData, Kind>
+{
+    fn id(&self) -> UpbitMarket {
+        UpbitMarket(self.instrument.name_exchange.clone())
+    }
+}
+
+impl AsRef<str> for UpbitMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+fn upbit_market(base: &Symbol, quote: &Symbol) -> UpbitMarket {
+    UpbitMarket(format_smolstr!("{quote}-{base}").to_uppercase_smolstr())
+}
@@ -0,0 +1,196 @@
+use self::{
+    book::l2::UpbitOrderBookL2, channel::UpbitChannel, market::UpbitMarket,
+    subscription::UpbitSubResponse, trade::UpbitTrade,
+};
+use crate::{
+    exchange::{Connector, ExchangeServer, ExchangeSub, PingInterval, StreamSelector},
+    instrument::InstrumentData,
+    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscription::{book::OrderBooksL2, trade::PublicTrades, Map},
+    transformer::stateless::StatelessTransformer,
+    ExchangeWsStream, NoInitialSnapshots,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::{
+    environment::Environment, error::SocketError, protocol::websocket::WsMessage,
+};
+use itertools::Itertools;
+use serde::de::{Error, Unexpected};
+use serde_json::json;
+use std::{fmt::Debug, marker::PhantomData, time::Duration};
+use url::Url;
+
+/// OrderBook types for [`Upbit`].
+pub mod book;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
+pub mod channel;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
+pub mod market;
+
+/// [`ExchangeServer`] and [`StreamSelector`] implementations for [`UpbitSpot`](spot::UpbitSpot).
+pub mod spot;
+
+/// [`Subscription`](crate::subscription::Subscription) response type and response
+/// [`Validator`](barter_integration::Validator) for [`Upbit`].
+pub mod subscription;
+
+/// Public trade types for [`Upbit`].
+pub mod trade;
+
+/// [`Upbit`] server [`PingInterval`] duration. The server disconnects connections that are
+/// idle for 120 seconds.
+///
+/// See docs: <https://global-docs.upbit.com/reference/websocket-guide>
+pub const PING_INTERVAL_UPBIT: Duration = Duration::from_secs(60);
+
+/// Generic [`Upbit<Server>`](Upbit) exchange, implementing the Upbit WebSocket protocol.
+///
+/// ### Notes
+/// Used by both [`UpbitSpot`](spot::UpbitSpot) and
+/// [`BithumbSpot`](super::bithumb::BithumbSpot), since the Bithumb v2 WebSocket API is
+/// compatible with the Upbit WebSocket API.
+///
+/// See docs: <https://global-docs.upbit.com/reference/websocket-guide>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct Upbit<Server> {
+    server: PhantomData<Server>,
+}
+
+impl<Server> Connector for Upbit<Server>
+where
+    Server: ExchangeServer,
+{
+    const ID: ExchangeId = Server::ID;
+    type Channel = UpbitChannel;
+    type Market = UpbitMarket;
+    type Subscriber = WebSocketSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = UpbitSubResponse;
+
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(Server::websocket_url()).map_err(SocketError::UrlParse)
+    }
+
+    fn url_for(environment: Environment) -> Result<Url, SocketError> {
+        Url::parse(Server::websocket_url_for(environment)?).map_err(SocketError::UrlParse)
+    }
+
+    fn ping_interval() -> Option<PingInterval> {
+        Some(PingInterval {
+            interval: tokio::time::interval(PING_INTERVAL_UPBIT),
+            ping: || WsMessage::text("PING"),
+        })
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        // Upbit accepts a single request containing a ticket followed by one entry per channel
+        let mut request = vec![json!({ "ticket": format!("barter-{}", Server::ID.as_str()) })];
+        request.extend(
+            exchange_subs
+                .into_iter()
+                .into_group_map_by(|sub| sub.channel)
+                .into_iter()
+                .sorted_by_key(|(channel, _)| *channel)
+                .map(|(channel, subs)| {
+                    json!({
+                        "type": channel.as_ref(),
+                        "codes": subs.into_iter().map(|sub| sub.market.0).collect::<Vec<_>>(),
+                    })
+                }),
+        );
+        request.push(json!({ "format": "DEFAULT" }));
+
+        vec![WsMessage::text(json!(request).to_string())]
+    }
+
+    /// [`Upbit`] does not acknowledge successful subscriptions, so no responses are awaited.
+    fn expected_responses<InstrumentKey>(_: &Map<InstrumentKey>) -> usize {
+        0
+    }
+}
+
+impl<Instrument, Server> StreamSelector<Instrument, PublicTrades> for Upbit<Server>
+where
+    Instrument: InstrumentData,
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Key, PublicTrades, UpbitTrade>>;
+}
+
+impl<Instrument, Server> StreamSelector<Instrument, OrderBooksL2> for Upbit<Server>
+where
+    Instrument: InstrumentData,
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Key, OrderBooksL2, UpbitOrderBookL2>,
+    >;
+}
+
+impl<'de, Server> serde::Deserialize<'de> for Upbit<Server>
+where
+    Server: ExchangeServer,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let input = <&str as serde::Deserialize>::deserialize(deserializer)?;
+        if input == Self::ID.as_str() {
+            Ok(Self::default())
+        } else {
+            Err(Error::invalid_value(
+                Unexpected::Str(input),
+                &Self::ID.as_str(),
+            ))
+        }
+    }
+}
+
+impl<Server> serde::Serialize for Upbit<Server>
+where
+    Server: ExchangeServer,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(Self::ID.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{spot::UpbitSpot, *};
+    use smol_str::SmolStr;
+
+    #[test]
+    fn test_upbit_requests() {
+        let sub = |channel, market: &str| ExchangeSub {
+            channel,
+            market: UpbitMarket(SmolStr::new(market)),
+        };
+
+        let actual = UpbitSpot::requests(vec![
+            sub(UpbitChannel::TRADES, "KRW-BTC"),
+            sub(UpbitChannel::ORDER_BOOK_L2, "KRW-BTC"),
+            sub(UpbitChannel::TRADES, "KRW-ETH"),
+        ]);
+
+        let expected = json!([
+            { "ticket": "barter-upbit" },
+            { "type": "orderbook", "codes": ["KRW-BTC"] },
+            { "type": "trade", "codes": ["KRW-BTC", "KRW-ETH"] },
+            { "format": "DEFAULT" },
+        ]);
+
+        assert_eq!(actual, vec![WsMessage::text(expected.to_string())]);
+    }
+}
@@ -0,0 +1,22 @@
+use super::{ExchangeServer, Upbit};
+use barter_instrument::exchange::ExchangeId;
+
+/// [`UpbitSpot`] WebSocket server base url.
+///
+/// See docs: <https://global-docs.upbit.com/reference/websocket-guide>
+pub const WEBSOCKET_BASE_URL_UPBIT_SPOT: &str = "wss://api.upbit.com/websocket/v1";
+
+/// [`Upbit`] spot exchange.
+pub type UpbitSpot = Upbit<UpbitServerSpot>;
+
+/// [`Upbit`] spot [`ExchangeServer`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct UpbitServerSpot;
+
+impl ExchangeServer for UpbitServerSpot {
+    const ID: ExchangeId = ExchangeId::Upbit;
+
+    fn websocket_url() -> &'static str {
+        WEBSOCKET_BASE_URL_UPBIT_SPOT
+    }
+}
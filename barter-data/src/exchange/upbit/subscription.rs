@@ -0,0 +1,45 @@
+use barter_integration::{error::SocketError, Validator};
+use serde::{Deserialize, Serialize};
+
+/// [`Upbit`](super::Upbit) WebSocket error response.
+///
+/// ### Notes
+/// [`Upbit`](super::Upbit) does not acknowledge successful subscriptions, so no
+/// [`UpbitSubResponse`] is awaited during subscription validation (see
+/// [`Connector::expected_responses`](crate::exchange::Connector::expected_responses)).
+///
+/// ### Raw Payload Examples
+/// #### Subscription Error Response
+/// ```json
+/// {
+///     "error": {
+///         "name": "INVALID_PARAM",
+///         "message": "잘못된 요청입니다."
+///     }
+/// }
+/// ```
+///
+/// See docs: <https://global-docs.upbit.com/reference/websocket-error>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct UpbitSubResponse {
+    pub error: UpbitError,
+}
+
+/// [`Upbit`](super::Upbit) error name & message.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct UpbitError {
+    pub name: String,
+    pub message: String,
+}
+
+impl Validator for UpbitSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        Err(SocketError::Subscribe(format!(
+            "received failure subscription response: {} with message: {}",
+            self.error.name, self.error.message,
+        )))
+    }
+}
@@ -0,0 +1,183 @@
+use super::channel::UpbitChannel;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeSub,
+    subscription::trade::PublicTrade,
+    Identifier,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::{subscription::SubscriptionId, Side};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Upbit`](super::Upbit) real-time trade WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://global-docs.upbit.com/reference/websocket-trade>
+/// ```json
+/// {
+///     "type": "trade",
+///     "code": "KRW-BTC",
+///     "timestamp": 1676965262177,
+///     "trade_date": "2023-02-21",
+///     "trade_time": "07:41:02",
+///     "trade_timestamp": 1676965262139,
+///     "trade_price": 31883000,
+///     "trade_volume": 0.03231998,
+///     "ask_bid": "ASK",
+///     "prev_closing_price": 31949000,
+///     "change": "FALL",
+///     "change_price": 66000,
+///     "sequential_id": 1676965262139000,
+///     "stream_type": "REALTIME"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct UpbitTrade {
+    #[serde(alias = "code", deserialize_with = "de_trade_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(alias = "sequential_id")]
+    pub id: u64,
+    #[serde(
+        alias = "trade_timestamp",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "trade_price")]
+    pub price: f64,
+    #[serde(alias = "trade_volume")]
+    pub amount: f64,
+    #[serde(alias = "ask_bid", deserialize_with = "de_side")]
+    pub side: Side,
+}
+
+impl Identifier<Option<SubscriptionId>> for UpbitTrade {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, UpbitTrade)>
+    for MarketIter<InstrumentKey, PublicTrade>
+{
+    fn from((exchange_id, instrument, trade): (ExchangeId, InstrumentKey, UpbitTrade)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            time_exchange: trade.time,
+            time_received: Utc::now(),
+            exchange: exchange_id,
+            instrument,
+            kind: PublicTrade {
+                id: trade.id.to_string(),
+                price: trade.price,
+                amount: trade.amount,
+                side: trade.side,
+                aggregate_count: None,
+            },
+        })])
+    }
+}
+
+/// Deserialize an [`UpbitTrade`] "code" (eg/ "KRW-BTC") as the associated [`SubscriptionId`]
+/// (eg/ SubscriptionId("trade|KRW-BTC")).
+pub fn de_trade_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|code| ExchangeSub::from((UpbitChannel::TRADES, code)).id())
+}
+
+/// Deserialize an [`UpbitTrade`] "ask_bid" (eg/ "ASK") as the taker [`Side`].
+pub fn de_side<'de, D>(deserializer: D) -> Result<Side, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    match <&str as Deserialize>::deserialize(deserializer)? {
+        "BID" => Ok(Side::Buy),
+        "ASK" => Ok(Side::Sell),
+        other => Err(serde::de::Error::invalid_value(
+            serde::de::Unexpected::Str(other),
+            &"BID | ASK",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_upbit_trade() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<UpbitTrade, ()>,
+            }
+
+            let tests = vec![
+                // TC0: valid UpbitTrade taker sell
+                TestCase {
+                    input: r#"
+                    {
+                        "type": "trade",
+                        "code": "KRW-BTC",
+                        "timestamp": 1676965262177,
+                        "trade_date": "2023-02-21",
+                        "trade_time": "07:41:02",
+                        "trade_timestamp": 1676965262139,
+                        "trade_price": 31883000,
+                        "trade_volume": 0.03231998,
+                        "ask_bid": "ASK",
+                        "prev_closing_price": 31949000,
+                        "change": "FALL",
+                        "change_price": 66000,
+                        "sequential_id": 1676965262139000,
+                        "stream_type": "REALTIME"
+                    }
+                    "#,
+                    expected: Ok(UpbitTrade {
+                        subscription_id: SubscriptionId::from("trade|KRW-BTC"),
+                        id: 1676965262139000,
+                        time: DateTime::from_timestamp_millis(1676965262139).unwrap(),
+                        price: 31883000.0,
+                        amount: 0.03231998,
+                        side: Side::Sell,
+                    }),
+                },
+                // TC1: invalid UpbitTrade w/ unknown ask_bid
+                TestCase {
+                    input: r#"
+                    {
+                        "type": "trade",
+                        "code": "KRW-BTC",
+                        "trade_timestamp": 1676965262139,
+                        "trade_price": 31883000,
+                        "trade_volume": 0.03231998,
+                        "ask_bid": "NONE",
+                        "sequential_id": 1676965262139000
+                    }
+                    "#,
+                    expected: Err(()),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<UpbitTrade>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -6,6 +6,10 @@ use serde::{Deserialize, Serialize};
 use smol_str::SmolStr;
 use std::fmt::Debug;
 
+/// [`InstrumentMetadata`](metadata::InstrumentMetadata) (eg/ tick size, lot size, min notional)
+/// fetched from exchange REST APIs.
+pub mod metadata;
+
 /// Instrument related data that defines an associated unique `Id`.
 ///
 /// Verbose `InstrumentData` is often used to subscribe to market data feeds, but it's unique `Id`
@@ -0,0 +1,84 @@
+use crate::{
+    error::DataError,
+    exchange::{
+        binance::instrument::{
+            BinanceExchangeInfo, HTTP_EXCHANGE_INFO_URL_BINANCE_FUTURES_USD,
+            HTTP_EXCHANGE_INFO_URL_BINANCE_SPOT,
+        },
+        bybit::instrument::{BybitInstrumentsInfo, HTTP_INSTRUMENTS_INFO_URL_BYBIT},
+        okx::instrument::{OkxInstruments, HTTP_INSTRUMENTS_URL_OKX},
+    },
+};
+use barter_instrument::{
+    asset::symbol::Symbol,
+    exchange::ExchangeId,
+    instrument::{spec::InstrumentSpec, Instrument},
+};
+use barter_integration::error::SocketError;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use smol_str::SmolStr;
+
+/// [`Instrument`] traded on an exchange, and the exchange [`InstrumentSpec`] trading rules
+/// (eg/ tick size, quantity step size, min notional) used to round order prices & quantities.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct InstrumentMetadata {
+    pub exchange: ExchangeId,
+    /// Exchange specific name of the [`Instrument`], eg/ "BTCUSDT" or "BTC-USDT-SWAP".
+    pub name_exchange: SmolStr,
+    pub instrument: Instrument,
+    pub spec: InstrumentSpec<Symbol>,
+}
+
+/// Fetch the [`InstrumentMetadata`] of every actively trading [`Instrument`] on the provided
+/// exchange via it's public REST API.
+///
+/// Supported exchanges: [`ExchangeId::BinanceSpot`], [`ExchangeId::BinanceFuturesUsd`],
+/// [`ExchangeId::Okx`] (spot & perpetual swaps), [`ExchangeId::BybitSpot`] &
+/// [`ExchangeId::BybitPerpetualsUsd`].
+pub async fn fetch_instruments(exchange: ExchangeId) -> Result<Vec<InstrumentMetadata>, DataError> {
+    match exchange {
+        ExchangeId::BinanceSpot => Ok(fetch::<BinanceExchangeInfo>(
+            HTTP_EXCHANGE_INFO_URL_BINANCE_SPOT,
+        )
+        .await?
+        .into_metadata(exchange)),
+        ExchangeId::BinanceFuturesUsd => Ok(fetch::<BinanceExchangeInfo>(
+            HTTP_EXCHANGE_INFO_URL_BINANCE_FUTURES_USD,
+        )
+        .await?
+        .into_metadata(exchange)),
+        ExchangeId::Okx => {
+            let mut instruments = Vec::new();
+            for inst_type in ["SPOT", "SWAP"] {
+                let url = format!("{HTTP_INSTRUMENTS_URL_OKX}?instType={inst_type}");
+                instruments.extend(fetch::<OkxInstruments>(&url).await?.into_metadata()?);
+            }
+            Ok(instruments)
+        }
+        ExchangeId::BybitSpot | ExchangeId::BybitPerpetualsUsd => {
+            let category = match exchange {
+                ExchangeId::BybitSpot => "spot",
+                _ => "linear",
+            };
+            let url = format!("{HTTP_INSTRUMENTS_INFO_URL_BYBIT}?category={category}&limit=1000");
+            fetch::<BybitInstrumentsInfo>(&url)
+                .await?
+                .into_metadata(exchange)
+                .map_err(DataError::from)
+        }
+        unsupported => Err(DataError::UnsupportedInstrumentMetadata(unsupported)),
+    }
+}
+
+/// Fetch & deserialise the JSON response of the provided url.
+async fn fetch<Response>(url: &str) -> Result<Response, DataError>
+where
+    Response: DeserializeOwned,
+{
+    reqwest::get(url)
+        .await
+        .map_err(SocketError::Http)?
+        .json::<Response>()
+        .await
+        .map_err(|error| DataError::Socket(SocketError::Http(error)))
+}
@@ -1,11 +1,14 @@
 #![forbid(unsafe_code)]
 #![warn(clippy::all)]
-#![allow(clippy::pedantic, clippy::type_complexity)]
+#![allow(clippy::pedantic, clippy::type_complexity, clippy::result_large_err)]
 #![warn(
     missing_debug_implementations,
     rust_2018_idioms,
     rust_2024_compatibility
 )]
+// The crate is on the 2021 edition, and none of the `while let` stream loops flagged by newer
+// toolchains depend on the order their temporaries are dropped in.
+#![allow(tail_expr_drop_order)]
 
 //! # Barter-Data
 //! A high-performance WebSocket integration library for streaming public market data from leading cryptocurrency
@@ -89,9 +92,12 @@
 use crate::{
     error::DataError,
     event::MarketEvent,
-    exchange::{Connector, PingInterval},
+    exchange::{config::ConnectorConfig, Connector, PingInterval},
     instrument::InstrumentData,
-    subscriber::{Subscribed, Subscriber},
+    subscriber::{
+        resubscribe::{Resubscriber, ResubscribingWsStream},
+        Subscribed, Subscriber,
+    },
     subscription::{Subscription, SubscriptionKind},
     transformer::ExchangeTransformer,
 };
@@ -100,12 +106,13 @@ use barter_instrument::exchange::ExchangeId;
 use barter_integration::{
     error::SocketError,
     protocol::{
-        websocket::{WebSocketParser, WsMessage, WsSink, WsStream},
+        websocket::{WebSocketParser, WsMessage, WsSink},
         StreamParser,
     },
     ExchangeStream, Transformer,
 };
 use futures::{SinkExt, Stream, StreamExt};
+use serde::de::DeserializeOwned;
 use std::{collections::VecDeque, future::Future};
 use tokio::sync::mpsc;
 use tracing::{debug, error, warn};
@@ -141,6 +148,11 @@ pub mod instrument;
 /// a collection of sorted local Instrument [`OrderBook`](books::OrderBook)s
 pub mod books;
 
+/// Storage friendly [`DataRecord`](record::DataRecord) representation of
+/// [`MarketEvent`]s, and a [`MarketRecorder`](record::recorder::MarketRecorder) that captures
+/// live [`MarketStream`]s to rotating files.
+pub mod record;
+
 /// Generic [`ExchangeTransformer`] implementations used by [`MarketStream`]s to translate exchange
 /// specific types to normalised Barter types.
 ///
@@ -158,7 +170,8 @@ pub mod transformer;
 
 /// Convenient type alias for an [`ExchangeStream`] utilising a tungstenite
 /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket).
-pub type ExchangeWsStream<Transformer> = ExchangeStream<WebSocketParser, WsStream, Transformer>;
+pub type ExchangeWsStream<Transformer> =
+    ExchangeStream<WebSocketParser, ResubscribingWsStream, Transformer>;
 
 /// Defines a generic identification type for the implementor.
 pub trait Identifier<T> {
@@ -179,6 +192,7 @@ where
     Kind: SubscriptionKind,
 {
     async fn init<SnapFetcher>(
+        config: &ConnectorConfig,
         subscriptions: &[Subscription<Exchange, Instrument, Kind>],
     ) -> Result<Self, DataError>
     where
@@ -196,6 +210,7 @@ where
 /// - [`BinanceFuturesUsdOrderBooksL2SnapshotFetcher`](exchange::binance::futures::l2::BinanceFuturesUsdOrderBooksL2SnapshotFetcher)
 pub trait SnapshotFetcher<Exchange, Kind> {
     fn fetch_snapshots<Instrument>(
+        config: &ConnectorConfig,
         subscriptions: &[Subscription<Exchange, Instrument, Kind>],
     ) -> impl Future<Output = Result<Vec<MarketEvent<Instrument::Key, Kind::Event>>, SocketError>> + Send
     where
@@ -214,9 +229,11 @@ where
     Instrument: InstrumentData,
     Kind: SubscriptionKind + Send + Sync,
     Transformer: ExchangeTransformer<Exchange, Instrument::Key, Kind> + Send,
+    Transformer::Input: DeserializeOwned,
     Kind::Event: Send,
 {
     async fn init<SnapFetcher>(
+        config: &ConnectorConfig,
         subscriptions: &[Subscription<Exchange, Instrument, Kind>],
     ) -> Result<Self, DataError>
     where
@@ -229,10 +246,10 @@ where
             websocket,
             map: instrument_map,
             buffered_websocket_events,
-        } = Exchange::Subscriber::subscribe(subscriptions).await?;
+        } = Exchange::Subscriber::subscribe(config, subscriptions).await?;
 
         // Fetch any required initial MarketEvent snapshots
-        let initial_snapshots = SnapFetcher::fetch_snapshots(subscriptions).await?;
+        let initial_snapshots = SnapFetcher::fetch_snapshots(config, subscriptions).await?;
 
         // Split WebSocket into WsStream & WsSink components
         let (ws_sink, ws_stream) = websocket.split();
@@ -254,6 +271,13 @@ where
             ));
         }
 
+        // Wrap WsStream to action any exchange resubscription notices received mid-stream
+        let ws_stream = ResubscribingWsStream::new(
+            ws_stream,
+            Resubscriber::new(subscriptions),
+            ws_sink_tx.clone(),
+        );
+
         // Initialise Transformer associated with this Exchange and SubscriptionKind
         let mut transformer =
             Transformer::init(instrument_map, &initial_snapshots, ws_sink_tx).await?;
@@ -278,6 +302,7 @@ pub struct NoInitialSnapshots;
 
 impl<Exchange, Kind> SnapshotFetcher<Exchange, Kind> for NoInitialSnapshots {
     fn fetch_snapshots<Instrument>(
+        _: &ConnectorConfig,
         _: &[Subscription<Exchange, Instrument, Kind>],
     ) -> impl Future<Output = Result<Vec<MarketEvent<Instrument::Key, Kind::Event>>, SocketError>> + Send
     where
@@ -298,6 +323,7 @@ pub fn process_buffered_events<Protocol, StreamTransformer>(
 where
     Protocol: StreamParser,
     StreamTransformer: Transformer,
+    StreamTransformer::Input: DeserializeOwned,
 {
     events
         .into_iter()
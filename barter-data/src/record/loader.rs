@@ -0,0 +1,269 @@
+use super::DataRecord;
+use crate::{
+    error::DataError,
+    event::{DataKind, MarketEvent},
+};
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fs::File,
+    io::{BufRead, BufReader, Lines},
+    path::{Path, PathBuf},
+};
+
+/// Loads recorded newline delimited JSON [`DataRecord`]s (eg/ written by a
+/// [`MarketRecorder`](super::recorder::MarketRecorder)) for a time range, yielding the
+/// reconstructed [`MarketEvent`]s in `time_exchange` order.
+///
+/// Records from multiple sources (eg/ one file per exchange or instrument) are merged lazily, so
+/// the loader is suitable as the `Iterator` of a backtest `MarketFeed`. Each source is expected
+/// to be ordered by `time_exchange`, as is the case for a single recorded stream.
+#[derive(Debug)]
+pub struct MarketRecordLoader<Reader, InstrumentKey> {
+    sources: Vec<Lines<Reader>>,
+    heap: BinaryHeap<Reverse<(DateTime<Utc>, usize)>>,
+    next: Vec<Option<DataRecord<InstrumentKey>>>,
+    start: Option<DateTime<Utc>>,
+    end: Option<DateTime<Utc>>,
+    error: Option<DataError>,
+}
+
+impl<InstrumentKey> MarketRecordLoader<BufReader<File>, InstrumentKey>
+where
+    InstrumentKey: DeserializeOwned,
+{
+    /// Open the provided recorded files, yielding the [`MarketEvent`]s in the optional
+    /// `[start, end]` time range.
+    pub fn open<Iter, P>(
+        paths: Iter,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Self, DataError>
+    where
+        Iter: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let readers = paths
+            .into_iter()
+            .map(|path| File::open(path).map(BufReader::new))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::new(readers, start, end))
+    }
+
+    /// Open every recorded ".jsonl" file in the provided directory whose name starts with
+    /// `file_prefix`, yielding the [`MarketEvent`]s in the optional `[start, end]` time range.
+    pub fn open_directory<P>(
+        directory: P,
+        file_prefix: &str,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Self, DataError>
+    where
+        P: AsRef<Path>,
+    {
+        let mut paths = std::fs::read_dir(directory)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<PathBuf>, _>>()?;
+
+        paths.retain(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "jsonl")
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(file_prefix))
+        });
+        paths.sort();
+
+        Self::open(paths, start, end)
+    }
+}
+
+impl<Reader, InstrumentKey> MarketRecordLoader<Reader, InstrumentKey>
+where
+    Reader: BufRead,
+    InstrumentKey: DeserializeOwned,
+{
+    /// Construct a new [`MarketRecordLoader`] that merges the [`DataRecord`]s of the provided
+    /// sources, yielding the [`MarketEvent`]s in the optional `[start, end]` time range.
+    pub fn new<Iter>(
+        sources: Iter,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Self
+    where
+        Iter: IntoIterator<Item = Reader>,
+    {
+        let sources = sources.into_iter().map(BufRead::lines).collect::<Vec<_>>();
+
+        let mut loader = Self {
+            next: (0..sources.len()).map(|_| None).collect(),
+            heap: BinaryHeap::with_capacity(sources.len()),
+            sources,
+            start,
+            end,
+            error: None,
+        };
+
+        for source in 0..loader.sources.len() {
+            loader.advance(source);
+        }
+
+        loader
+    }
+
+    /// Read the next in range [`DataRecord`] of the provided source onto the merge heap.
+    fn advance(&mut self, source: usize) {
+        for line in self.sources[source].by_ref() {
+            let record = match line.map_err(DataError::from).and_then(|line| {
+                serde_json::from_str::<DataRecord<InstrumentKey>>(&line)
+                    .map_err(|error| DataError::from(std::io::Error::from(error)))
+            }) {
+                Ok(record) => record,
+                Err(error) => {
+                    self.error.get_or_insert(error);
+                    continue;
+                }
+            };
+
+            if self.start.is_some_and(|start| record.time_exchange < start) {
+                continue;
+            }
+
+            // Sources are ordered, so every remaining record is out of range
+            if self.end.is_some_and(|end| record.time_exchange > end) {
+                return;
+            }
+
+            self.heap.push(Reverse((record.time_exchange, source)));
+            self.next[source] = Some(record);
+            return;
+        }
+    }
+}
+
+impl<Reader, InstrumentKey> Iterator for MarketRecordLoader<Reader, InstrumentKey>
+where
+    Reader: BufRead,
+    InstrumentKey: DeserializeOwned,
+{
+    type Item = Result<MarketEvent<InstrumentKey, DataKind>, DataError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(error) = self.error.take() {
+            return Some(Err(error));
+        }
+
+        let Reverse((_, source)) = self.heap.pop()?;
+        let record = self.next[source]
+            .take()
+            .expect("MarketRecordLoader heap entries always have a buffered DataRecord");
+
+        self.advance(source);
+
+        Some(Ok(MarketEvent::from(record)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{record::DataRecordKind, subscription::trade::PublicTrade};
+    use barter_instrument::{
+        exchange::ExchangeId,
+        instrument::{kind::InstrumentKind, Instrument},
+    };
+    use barter_integration::Side;
+    use chrono::TimeDelta;
+    use std::io::Cursor;
+
+    fn record(exchange: ExchangeId, time_exchange: DateTime<Utc>) -> String {
+        serde_json::to_string(&DataRecord {
+            time_exchange,
+            time_received: time_exchange,
+            exchange,
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            kind: DataRecordKind::Trade(PublicTrade {
+                id: "id".to_string(),
+                price: 100.0,
+                amount: 1.0,
+                side: Side::Buy,
+                aggregate_count: None,
+            }),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_market_record_loader() {
+        struct TestCase {
+            start: Option<i64>,
+            end: Option<i64>,
+            expected: Vec<(ExchangeId, i64)>,
+        }
+
+        let base = Utc::now();
+        let time = |seconds: i64| base + TimeDelta::seconds(seconds);
+
+        let binance = [1, 3, 5]
+            .map(|seconds| record(ExchangeId::BinanceSpot, time(seconds)))
+            .join("\n");
+        let kraken = [2, 3, 6]
+            .map(|seconds| record(ExchangeId::Kraken, time(seconds)))
+            .join("\n");
+
+        let tests = vec![
+            TestCase {
+                // TC0: every record merged by time_exchange
+                start: None,
+                end: None,
+                expected: vec![
+                    (ExchangeId::BinanceSpot, 1),
+                    (ExchangeId::Kraken, 2),
+                    (ExchangeId::BinanceSpot, 3),
+                    (ExchangeId::Kraken, 3),
+                    (ExchangeId::BinanceSpot, 5),
+                    (ExchangeId::Kraken, 6),
+                ],
+            },
+            TestCase {
+                // TC1: records in the time range
+                start: Some(2),
+                end: Some(5),
+                expected: vec![
+                    (ExchangeId::Kraken, 2),
+                    (ExchangeId::BinanceSpot, 3),
+                    (ExchangeId::Kraken, 3),
+                    (ExchangeId::BinanceSpot, 5),
+                ],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let loader = MarketRecordLoader::<_, Instrument>::new(
+                [Cursor::new(binance.clone()), Cursor::new(kraken.clone())],
+                test.start.map(time),
+                test.end.map(time),
+            );
+
+            let actual = loader
+                .map(|event| {
+                    let event = event.unwrap();
+                    assert!(matches!(event.kind, DataKind::Trade(_)));
+                    (event.exchange, event.time_exchange)
+                })
+                .collect::<Vec<_>>();
+
+            let expected = test
+                .expected
+                .into_iter()
+                .map(|(exchange, seconds)| (exchange, time(seconds)))
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, expected, "TC{index} failed");
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use crate::{
+    books::{Level, OrderBook},
+    event::{CustomData, DataKind, MarketEvent},
+    subscription::{
+        book::{OrderBookEvent, OrderBookL1},
+        candle::Candle,
+        liquidation::Liquidation,
+        trade::PublicTrade,
+    },
+};
+use barter_instrument::{exchange::ExchangeId, instrument::Instrument};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`MarketRecordLoader`](loader::MarketRecordLoader) that reads recorded [`DataRecord`] files
+/// for a time range back into an ordered [`MarketEvent`] iterator suitable for backtesting.
+pub mod loader;
+
+/// [`MarketRecorder`](recorder::MarketRecorder) that captures live [`MarketEvent`]s to rotating
+/// newline delimited JSON [`DataRecord`] files.
+pub mod recorder;
+
+/// Storage friendly representation of a [`MarketEvent<InstrumentKey, DataKind>`](MarketEvent).
+///
+/// Converts losslessly to and from a [`MarketEvent`], so recorded data can be replayed.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DataRecord<InstrumentKey = Instrument> {
+    pub time_exchange: DateTime<Utc>,
+    pub time_received: DateTime<Utc>,
+    pub exchange: ExchangeId,
+    pub instrument: InstrumentKey,
+    pub kind: DataRecordKind,
+}
+
+/// Typed [`DataRecord`] payload.
+///
+/// Unlike [`DataKind`], [`OrderBook`] snapshots & deltas are distinct record types so consumers
+/// can reconstruct books without knowing the originating [`OrderBookEvent`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum DataRecordKind {
+    Trade(PublicTrade),
+    OrderBookL1(OrderBookL1),
+    OrderBookSnapshot(OrderBookRecord),
+    OrderBookDelta(OrderBookRecord),
+    Candle(Candle),
+    Liquidation(Liquidation),
+    Custom(CustomData),
+}
+
+/// [`OrderBook`] levels of an [`DataRecordKind::OrderBookSnapshot`] or
+/// [`DataRecordKind::OrderBookDelta`]. Delta levels with a zero amount remove the level.
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct OrderBookRecord {
+    pub sequence: u64,
+    pub time_engine: Option<DateTime<Utc>>,
+    pub bids: Vec<Level>,
+    pub asks: Vec<Level>,
+}
+
+impl From<&OrderBook> for OrderBookRecord {
+    fn from(book: &OrderBook) -> Self {
+        Self {
+            sequence: book.sequence,
+            time_engine: book.time_engine,
+            bids: book.bids().levels().to_vec(),
+            asks: book.asks().levels().to_vec(),
+        }
+    }
+}
+
+impl From<OrderBookRecord> for OrderBook {
+    fn from(record: OrderBookRecord) -> Self {
+        OrderBook::new(
+            record.sequence,
+            record.time_engine,
+            record.bids,
+            record.asks,
+        )
+    }
+}
+
+impl From<DataKind> for DataRecordKind {
+    fn from(kind: DataKind) -> Self {
+        match kind {
+            DataKind::Trade(trade) => Self::Trade(trade),
+            DataKind::OrderBookL1(book) => Self::OrderBookL1(book),
+            DataKind::OrderBook(OrderBookEvent::Snapshot(book)) => {
+                Self::OrderBookSnapshot(OrderBookRecord::from(&book))
+            }
+            DataKind::OrderBook(OrderBookEvent::Update(book)) => {
+                Self::OrderBookDelta(OrderBookRecord::from(&book))
+            }
+            DataKind::Candle(candle) => Self::Candle(candle),
+            DataKind::Liquidation(liquidation) => Self::Liquidation(liquidation),
+            DataKind::Custom(custom) => Self::Custom(custom),
+        }
+    }
+}
+
+impl From<DataRecordKind> for DataKind {
+    fn from(kind: DataRecordKind) -> Self {
+        match kind {
+            DataRecordKind::Trade(trade) => Self::Trade(trade),
+            DataRecordKind::OrderBookL1(book) => Self::OrderBookL1(book),
+            DataRecordKind::OrderBookSnapshot(book) => {
+                Self::OrderBook(OrderBookEvent::Snapshot(OrderBook::from(book)))
+            }
+            DataRecordKind::OrderBookDelta(book) => {
+                Self::OrderBook(OrderBookEvent::Update(OrderBook::from(book)))
+            }
+            DataRecordKind::Candle(candle) => Self::Candle(candle),
+            DataRecordKind::Liquidation(liquidation) => Self::Liquidation(liquidation),
+            DataRecordKind::Custom(custom) => Self::Custom(custom),
+        }
+    }
+}
+
+impl<InstrumentKey> From<MarketEvent<InstrumentKey, DataKind>> for DataRecord<InstrumentKey> {
+    fn from(event: MarketEvent<InstrumentKey, DataKind>) -> Self {
+        Self {
+            time_exchange: event.time_exchange,
+            time_received: event.time_received,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataRecordKind::from(event.kind),
+        }
+    }
+}
+
+impl<InstrumentKey> From<DataRecord<InstrumentKey>> for MarketEvent<InstrumentKey, DataKind> {
+    fn from(record: DataRecord<InstrumentKey>) -> Self {
+        Self {
+            time_exchange: record.time_exchange,
+            time_received: record.time_received,
+            exchange: record.exchange,
+            instrument: record.instrument,
+            kind: DataKind::from(record.kind),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_instrument::instrument::kind::InstrumentKind;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_data_record_round_trip() {
+        struct TestCase {
+            input: DataKind,
+        }
+
+        let book = OrderBook::new(
+            10,
+            None,
+            vec![Level::new(dec!(99), dec!(1)), Level::new(dec!(98), dec!(0))],
+            vec![Level::new(dec!(101), dec!(2))],
+        );
+
+        let tests = vec![
+            TestCase {
+                // TC0: OrderBook snapshot
+                input: DataKind::OrderBook(OrderBookEvent::Snapshot(book.clone())),
+            },
+            TestCase {
+                // TC1: OrderBook delta, including zero amount level
+                input: DataKind::OrderBook(OrderBookEvent::Update(book)),
+            },
+            TestCase {
+                // TC2: Candle
+                input: DataKind::Candle(Candle {
+                    close_time: Utc::now(),
+                    open: 1.0,
+                    high: 2.0,
+                    low: 0.5,
+                    close: 1.5,
+                    volume: 10.0,
+                    trade_count: 3,
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let event = MarketEvent {
+                time_exchange: Utc::now(),
+                time_received: Utc::now(),
+                exchange: ExchangeId::BinanceSpot,
+                instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                kind: test.input,
+            };
+
+            let json = serde_json::to_string(&DataRecord::from(event.clone())).unwrap();
+            let record = serde_json::from_str::<DataRecord>(&json).unwrap();
+
+            assert_eq!(MarketEvent::from(record), event, "TC{index} failed");
+        }
+    }
+}
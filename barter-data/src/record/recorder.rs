@@ -0,0 +1,245 @@
+use super::DataRecord;
+use crate::{
+    error::DataError,
+    event::{DataKind, MarketEvent},
+    streams::publish::MarketEventPublisher,
+};
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufWriter, Write},
+    path::PathBuf,
+};
+use tracing::debug;
+
+/// Configuration for constructing a [`MarketRecorder`].
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct RecorderConfig {
+    /// Directory the recorded files are written to. Created if it does not exist.
+    pub directory: PathBuf,
+    /// Prefix of every recorded file name, eg/ "binance_spot_trades".
+    pub file_prefix: String,
+    /// Rotate to a new file once the current file has reached this size in bytes.
+    pub max_file_bytes: u64,
+    /// Rotate to a new file once the current file spans this much `time_received`.
+    pub max_file_duration: TimeDelta,
+}
+
+/// File currently being recorded to.
+#[derive(Debug)]
+struct RecordFile {
+    writer: BufWriter<File>,
+    bytes: u64,
+    opened: DateTime<Utc>,
+}
+
+/// Captures [`MarketEvent`]s as newline delimited JSON [`DataRecord`]s, rotating to a new file
+/// when the configured size or duration is exceeded.
+///
+/// Implements [`MarketEventPublisher`], so it can be plugged directly into a
+/// [`StreamBuilder`](crate::streams::builder::StreamBuilder) via `publish`, or any
+/// [`Streams`](crate::streams::Streams) via
+/// [`publish_streams`](crate::streams::publish::publish_streams).
+#[derive(Debug)]
+pub struct MarketRecorder {
+    config: RecorderConfig,
+    current: Option<RecordFile>,
+    files: Vec<PathBuf>,
+}
+
+impl MarketRecorder {
+    /// Construct a new [`MarketRecorder`], creating the configured directory if required.
+    pub fn new(config: RecorderConfig) -> Result<Self, DataError> {
+        std::fs::create_dir_all(&config.directory)?;
+        Ok(Self {
+            config,
+            current: None,
+            files: Vec::new(),
+        })
+    }
+
+    /// Paths of every file recorded to, in the order they were created.
+    pub fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+
+    /// Record a [`MarketEvent`], rotating to a new file first if required.
+    pub fn record<InstrumentKey>(
+        &mut self,
+        event: MarketEvent<InstrumentKey, DataKind>,
+    ) -> Result<(), DataError>
+    where
+        InstrumentKey: Serialize,
+    {
+        let time = event.time_received;
+        let mut line =
+            serde_json::to_vec(&DataRecord::from(event)).map_err(std::io::Error::from)?;
+        line.push(b'\n');
+
+        let rotate = self.current.as_ref().is_none_or(|file| {
+            file.bytes >= self.config.max_file_bytes
+                || time - file.opened >= self.config.max_file_duration
+        });
+        if rotate {
+            self.rotate(time)?;
+        }
+
+        let file = self
+            .current
+            .as_mut()
+            .expect("MarketRecorder file is always opened by rotate");
+        file.writer.write_all(&line)?;
+        file.bytes += line.len() as u64;
+
+        Ok(())
+    }
+
+    /// Flush any buffered [`DataRecord`]s to the current file.
+    pub fn flush(&mut self) -> Result<(), DataError> {
+        if let Some(file) = &mut self.current {
+            file.writer.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Flush & close the current file, opening a new file named after the provided time.
+    fn rotate(&mut self, time: DateTime<Utc>) -> Result<(), DataError> {
+        self.flush()?;
+
+        let path = self.config.directory.join(format!(
+            "{}_{}_{:06}.jsonl",
+            self.config.file_prefix,
+            time.format("%Y%m%dT%H%M%S%.6fZ"),
+            self.files.len()
+        ));
+        debug!(path = %path.display(), "MarketRecorder rotating to new file");
+
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        self.current = Some(RecordFile {
+            writer: BufWriter::new(file),
+            bytes: 0,
+            opened: time,
+        });
+        self.files.push(path);
+
+        Ok(())
+    }
+}
+
+impl<InstrumentKey, T> MarketEventPublisher<InstrumentKey, T> for MarketRecorder
+where
+    InstrumentKey: Clone + Serialize,
+    T: Clone,
+    MarketEvent<InstrumentKey, T>: Into<MarketEvent<InstrumentKey, DataKind>>,
+{
+    fn publish(
+        &mut self,
+        _: &'static str,
+        event: &MarketEvent<InstrumentKey, T>,
+    ) -> Result<(), DataError> {
+        self.record(event.clone().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{record::DataRecordKind, subscription::trade::PublicTrade};
+    use barter_instrument::{
+        exchange::ExchangeId,
+        instrument::{kind::InstrumentKind, Instrument},
+    };
+    use barter_integration::Side;
+
+    fn trade(time_received: DateTime<Utc>) -> MarketEvent<Instrument, PublicTrade> {
+        MarketEvent {
+            time_exchange: time_received,
+            time_received,
+            exchange: ExchangeId::BinanceSpot,
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            kind: PublicTrade {
+                id: "id".to_string(),
+                price: 100.0,
+                amount: 1.0,
+                side: Side::Buy,
+                aggregate_count: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_market_recorder_rotation() {
+        struct TestCase {
+            max_file_bytes: u64,
+            max_file_duration: TimeDelta,
+            expected_records_per_file: Vec<usize>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: no rotation
+                max_file_bytes: u64::MAX,
+                max_file_duration: TimeDelta::hours(1),
+                expected_records_per_file: vec![4],
+            },
+            TestCase {
+                // TC1: rotation by size, each file exceeds max_file_bytes after a single record
+                max_file_bytes: 1,
+                max_file_duration: TimeDelta::hours(1),
+                expected_records_per_file: vec![1, 1, 1, 1],
+            },
+            TestCase {
+                // TC2: rotation by duration, events are received 1m apart
+                max_file_bytes: u64::MAX,
+                max_file_duration: TimeDelta::minutes(2),
+                expected_records_per_file: vec![2, 2],
+            },
+        ];
+
+        let start = Utc::now();
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let directory = std::env::temp_dir().join(format!(
+                "barter_market_recorder_{}_{index}",
+                std::process::id()
+            ));
+            let _ = std::fs::remove_dir_all(&directory);
+
+            let mut recorder = MarketRecorder::new(RecorderConfig {
+                directory: directory.clone(),
+                file_prefix: "trades".to_string(),
+                max_file_bytes: test.max_file_bytes,
+                max_file_duration: test.max_file_duration,
+            })
+            .unwrap();
+
+            for minute in 0..4 {
+                recorder
+                    .publish("public_trades", &trade(start + TimeDelta::minutes(minute)))
+                    .unwrap();
+            }
+            recorder.flush().unwrap();
+
+            let records_per_file = recorder
+                .files()
+                .iter()
+                .map(|path| {
+                    let contents = std::fs::read_to_string(path).unwrap();
+                    contents
+                        .lines()
+                        .map(|line| serde_json::from_str::<DataRecord>(line).unwrap())
+                        .inspect(|record| assert!(matches!(record.kind, DataRecordKind::Trade(_))))
+                        .count()
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                records_per_file, test.expected_records_per_file,
+                "TC{index} failed"
+            );
+
+            std::fs::remove_dir_all(&directory).unwrap();
+        }
+    }
+}
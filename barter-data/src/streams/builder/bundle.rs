@@ -0,0 +1,288 @@
+use crate::{
+    error::DataError,
+    streams::builder::dynamic,
+    subscription::{SubKind, Subscription},
+};
+use barter_instrument::{
+    asset::symbol::Symbol,
+    exchange::ExchangeId,
+    instrument::{kind::InstrumentKind, Instrument},
+};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Predefined bundle of [`SubKind`]s required by a common strategy archetype.
+///
+/// Each [`SubBundle`] expands into the individual [`Subscription`]s supported by an exchange
+/// [`DynamicStreams`](super::dynamic::DynamicStreams) integration. The combined output can be
+/// consumed as a single `Stream` tagged by
+/// [`DataKind`](crate::event::DataKind) via
+/// [`DynamicStreams::select_all`](super::dynamic::DynamicStreams::select_all).
+///
+/// Note that funding rate & mark price data are not yet supported, and will be added to the
+/// relevant bundles once available.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize, Display,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum SubBundle {
+    /// Deepest available order book (L2, falling back to L1) & public trades.
+    MarketMaking,
+    /// L1 order book & public trades.
+    TopOfBook,
+    /// Public trades, plus liquidations & L1 order book where the exchange supports them.
+    Derivatives,
+}
+
+/// Component of a [`SubBundle`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum Component {
+    /// First [`SubKind`] supported by the exchange, in order of preference. At least one must be
+    /// supported.
+    Required(&'static [SubKind]),
+    /// [`SubKind`] only included if supported by the exchange.
+    Optional(SubKind),
+}
+
+impl SubBundle {
+    fn components(&self) -> &'static [Component] {
+        match self {
+            SubBundle::MarketMaking => &[
+                Component::Required(&[SubKind::OrderBooksL2, SubKind::OrderBooksL1]),
+                Component::Required(&[SubKind::PublicTrades]),
+            ],
+            SubBundle::TopOfBook => &[
+                Component::Required(&[SubKind::OrderBooksL1]),
+                Component::Required(&[SubKind::PublicTrades]),
+            ],
+            SubBundle::Derivatives => &[
+                Component::Required(&[SubKind::PublicTrades]),
+                Component::Optional(SubKind::Liquidations),
+                Component::Optional(SubKind::OrderBooksL1),
+            ],
+        }
+    }
+
+    /// Expand the [`SubBundle`] into the individual [`SubKind`]s to subscribe to for the provided
+    /// exchange.
+    ///
+    /// Returns [`DataError::Unsupported`] if the exchange does not support a required component.
+    pub fn sub_kinds(&self, exchange: ExchangeId) -> Result<Vec<SubKind>, DataError> {
+        self.components()
+            .iter()
+            .filter_map(|component| match component {
+                Component::Required(preferences) => Some(
+                    preferences
+                        .iter()
+                        .find(|sub_kind| dynamic::supports(exchange, **sub_kind))
+                        .copied()
+                        .ok_or(DataError::Unsupported {
+                            exchange,
+                            sub_kind: preferences[0],
+                        }),
+                ),
+                Component::Optional(sub_kind) => {
+                    dynamic::supports(exchange, *sub_kind).then_some(Ok(*sub_kind))
+                }
+            })
+            .collect()
+    }
+}
+
+/// [`SubBundle`] subscription for a particular exchange [`Instrument`].
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct BundleSubscription<Inst = Instrument> {
+    pub exchange: ExchangeId,
+    #[serde(flatten)]
+    pub instrument: Inst,
+    pub bundle: SubBundle,
+}
+
+impl<S> From<(ExchangeId, S, S, InstrumentKind, SubBundle)> for BundleSubscription<Instrument>
+where
+    S: Into<Symbol>,
+{
+    fn from(
+        (exchange, base, quote, instrument_kind, bundle): (
+            ExchangeId,
+            S,
+            S,
+            InstrumentKind,
+            SubBundle,
+        ),
+    ) -> Self {
+        Self {
+            exchange,
+            instrument: Instrument::from((base, quote, instrument_kind)),
+            bundle,
+        }
+    }
+}
+
+impl<Inst> BundleSubscription<Inst>
+where
+    Inst: Clone,
+{
+    /// Expand the [`BundleSubscription`] into the individual [`Subscription`]s it requires.
+    pub fn expand(self) -> Result<Vec<Subscription<ExchangeId, Inst, SubKind>>, DataError> {
+        let Self {
+            exchange,
+            instrument,
+            bundle,
+        } = self;
+
+        Ok(bundle
+            .sub_kinds(exchange)?
+            .into_iter()
+            .map(|sub_kind| Subscription::new(exchange, instrument.clone(), sub_kind))
+            .collect())
+    }
+}
+
+/// Expand batches of [`BundleSubscription`]s into batches of individual [`Subscription`]s, ready
+/// to initialise via [`DynamicStreams::init`](super::dynamic::DynamicStreams::init).
+///
+/// ## Examples
+/// ```rust,ignore
+/// let streams = DynamicStreams::init(&HashMap::new(), expand_bundles([vec![
+///     (ExchangeId::BinanceFuturesUsd, "btc", "usdt", Perpetual, SubBundle::Derivatives),
+///     (ExchangeId::Kraken, "btc", "usd", Spot, SubBundle::MarketMaking),
+/// ]])?)
+/// .await?;
+///
+/// let mut combined = streams.select_all::<MarketStreamResult<Instrument, DataKind>>();
+/// ```
+pub fn expand_bundles<BatchIter, BundleIter, Bundle, Inst>(
+    batches: BatchIter,
+) -> Result<Vec<Vec<Subscription<ExchangeId, Inst, SubKind>>>, DataError>
+where
+    BatchIter: IntoIterator<Item = BundleIter>,
+    BundleIter: IntoIterator<Item = Bundle>,
+    Bundle: Into<BundleSubscription<Inst>>,
+    Inst: Clone,
+{
+    batches
+        .into_iter()
+        .map(|batch| {
+            batch
+                .into_iter()
+                .map(|bundle| bundle.into().expand())
+                .collect::<Result<Vec<_>, _>>()
+                .map(|subscriptions| subscriptions.into_iter().flatten().collect())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sub_bundle_sub_kinds() {
+        struct TestCase {
+            bundle: SubBundle,
+            exchange: ExchangeId,
+            expected: Result<Vec<SubKind>, DataError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: MarketMaking falls back to L1 when L2 is unsupported
+                bundle: SubBundle::MarketMaking,
+                exchange: ExchangeId::BinanceSpot,
+                expected: Ok(vec![SubKind::OrderBooksL1, SubKind::PublicTrades]),
+            },
+            TestCase {
+                // TC1: MarketMaking unsupported if exchange has no order books
+                bundle: SubBundle::MarketMaking,
+                exchange: ExchangeId::Coinbase,
+                expected: Err(DataError::Unsupported {
+                    exchange: ExchangeId::Coinbase,
+                    sub_kind: SubKind::OrderBooksL2,
+                }),
+            },
+            TestCase {
+                // TC2: TopOfBook
+                bundle: SubBundle::TopOfBook,
+                exchange: ExchangeId::Kraken,
+                expected: Ok(vec![SubKind::OrderBooksL1, SubKind::PublicTrades]),
+            },
+            TestCase {
+                // TC3: Derivatives includes every optional component supported
+                bundle: SubBundle::Derivatives,
+                exchange: ExchangeId::BinanceFuturesUsd,
+                expected: Ok(vec![
+                    SubKind::PublicTrades,
+                    SubKind::Liquidations,
+                    SubKind::OrderBooksL1,
+                ]),
+            },
+            TestCase {
+                // TC4: Derivatives skips unsupported optional components
+                bundle: SubBundle::Derivatives,
+                exchange: ExchangeId::Bitmex,
+                expected: Ok(vec![SubKind::PublicTrades, SubKind::Liquidations]),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.bundle.sub_kinds(test.exchange);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => assert_eq!(actual, expected, "TC{index} failed"),
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_expand_bundles() {
+        let actual = expand_bundles([vec![
+            (
+                ExchangeId::BinanceSpot,
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                SubBundle::TopOfBook,
+            ),
+            (
+                ExchangeId::Okx,
+                "btc",
+                "usdt",
+                InstrumentKind::Perpetual,
+                SubBundle::Derivatives,
+            ),
+        ]])
+        .unwrap();
+
+        let expected = vec![vec![
+            Subscription::new(
+                ExchangeId::BinanceSpot,
+                Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                SubKind::OrderBooksL1,
+            ),
+            Subscription::new(
+                ExchangeId::BinanceSpot,
+                Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                SubKind::PublicTrades,
+            ),
+            Subscription::new(
+                ExchangeId::Okx,
+                Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+                SubKind::PublicTrades,
+            ),
+            Subscription::new(
+                ExchangeId::Okx,
+                Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+                SubKind::Liquidations,
+            ),
+        ]];
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -3,9 +3,13 @@ use crate::{
     exchange::{
         binance::{futures::BinanceFuturesUsd, market::BinanceMarket, spot::BinanceSpot},
         bitfinex::{market::BitfinexMarket, Bitfinex},
+        bithumb::BithumbSpot,
         bitmex::{market::BitmexMarket, Bitmex},
+        bitstamp::{market::BitstampMarket, Bitstamp},
         bybit::{futures::BybitPerpetualsUsd, market::BybitMarket, spot::BybitSpot},
         coinbase::{market::CoinbaseMarket, Coinbase},
+        config::ConnectorConfig,
+        dydx::{market::DydxMarket, Dydx},
         gateio::{
             future::{GateioFuturesBtc, GateioFuturesUsd},
             market::GateioMarket,
@@ -13,8 +17,12 @@ use crate::{
             perpetual::{GateioPerpetualsBtc, GateioPerpetualsUsd},
             spot::GateioSpot,
         },
+        gemini::{market::GeminiMarket, Gemini},
         kraken::{market::KrakenMarket, Kraken},
+        kucoin::{market::KucoinMarket, Kucoin},
+        mexc::{market::MexcMarket, Mexc},
         okx::{market::OkxMarket, Okx},
+        upbit::{market::UpbitMarket, spot::UpbitSpot},
     },
     instrument::InstrumentData,
     streams::{
@@ -22,7 +30,7 @@ use crate::{
         reconnect::stream::ReconnectingStream,
     },
     subscription::{
-        book::{OrderBookEvent, OrderBookL1, OrderBooksL1},
+        book::{OrderBookEvent, OrderBookL1, OrderBooksL1, OrderBooksL2},
         liquidation::{Liquidation, Liquidations},
         trade::{PublicTrade, PublicTrades},
         SubKind, Subscription,
@@ -38,7 +46,7 @@ use futures::{
 };
 use futures_util::{future::try_join_all, StreamExt};
 use itertools::Itertools;
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::UnboundedReceiverStream;
 use vecmap::VecMap;
@@ -64,10 +72,14 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
     /// WebSocket `Stream` under the hood. If the batch contains more-than-one [`ExchangeId`] and/or
     /// [`SubKind`], it will be further split under the hood for compile-time reasons.
     ///
+    /// The market streams of each [`ExchangeId`] connect using it's [`ConnectorConfig`] in the
+    /// provided `connectors`, or the default [`ConnectorConfig`] if absent.
+    ///
     /// ## Examples
     /// Please see barter-data-rs/examples/dynamic_multi_stream_multi_exchange.rs for a
     /// comprehensive example of how to use this market data stream initialiser.
     pub async fn init<SubBatchIter, SubIter, Sub, Instrument>(
+        connectors: &HashMap<ExchangeId, ConnectorConfig>,
         subscription_batches: SubBatchIter,
     ) -> Result<Self, DataError>
     where
@@ -83,19 +95,46 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
         Subscription<BinanceFuturesUsd, Instrument, OrderBooksL1>: Identifier<BinanceMarket>,
         Subscription<BinanceFuturesUsd, Instrument, Liquidations>: Identifier<BinanceMarket>,
         Subscription<Bitfinex, Instrument, PublicTrades>: Identifier<BitfinexMarket>,
+        Subscription<Bitfinex, Instrument, OrderBooksL2>: Identifier<BitfinexMarket>,
+        Subscription<BithumbSpot, Instrument, PublicTrades>: Identifier<UpbitMarket>,
+        Subscription<BithumbSpot, Instrument, OrderBooksL2>: Identifier<UpbitMarket>,
         Subscription<Bitmex, Instrument, PublicTrades>: Identifier<BitmexMarket>,
+        Subscription<Bitmex, Instrument, Liquidations>: Identifier<BitmexMarket>,
+        Subscription<Bitstamp, Instrument, PublicTrades>: Identifier<BitstampMarket>,
+        Subscription<Bitstamp, Instrument, OrderBooksL2>: Identifier<BitstampMarket>,
         Subscription<BybitSpot, Instrument, PublicTrades>: Identifier<BybitMarket>,
         Subscription<BybitPerpetualsUsd, Instrument, PublicTrades>: Identifier<BybitMarket>,
+        Subscription<BybitPerpetualsUsd, Instrument, Liquidations>: Identifier<BybitMarket>,
         Subscription<Coinbase, Instrument, PublicTrades>: Identifier<CoinbaseMarket>,
+        Subscription<Dydx, Instrument, PublicTrades>: Identifier<DydxMarket>,
+        Subscription<Dydx, Instrument, OrderBooksL2>: Identifier<DydxMarket>,
         Subscription<GateioSpot, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioSpot, Instrument, OrderBooksL1>: Identifier<GateioMarket>,
+        Subscription<GateioSpot, Instrument, OrderBooksL2>: Identifier<GateioMarket>,
         Subscription<GateioFuturesUsd, Instrument, PublicTrades>: Identifier<GateioMarket>,
         Subscription<GateioFuturesBtc, Instrument, PublicTrades>: Identifier<GateioMarket>,
         Subscription<GateioPerpetualsUsd, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioPerpetualsUsd, Instrument, OrderBooksL1>: Identifier<GateioMarket>,
+        Subscription<GateioPerpetualsUsd, Instrument, OrderBooksL2>: Identifier<GateioMarket>,
         Subscription<GateioPerpetualsBtc, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioPerpetualsBtc, Instrument, OrderBooksL1>: Identifier<GateioMarket>,
+        Subscription<GateioPerpetualsBtc, Instrument, OrderBooksL2>: Identifier<GateioMarket>,
         Subscription<GateioOptions, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<Gemini, Instrument, PublicTrades>: Identifier<GeminiMarket>,
+        Subscription<Gemini, Instrument, OrderBooksL2>: Identifier<GeminiMarket>,
         Subscription<Kraken, Instrument, PublicTrades>: Identifier<KrakenMarket>,
         Subscription<Kraken, Instrument, OrderBooksL1>: Identifier<KrakenMarket>,
+        Subscription<Kraken, Instrument, OrderBooksL2>: Identifier<KrakenMarket>,
+        Subscription<Kucoin, Instrument, PublicTrades>: Identifier<KucoinMarket>,
+        Subscription<Kucoin, Instrument, OrderBooksL1>: Identifier<KucoinMarket>,
+        Subscription<Kucoin, Instrument, OrderBooksL2>: Identifier<KucoinMarket>,
+        Subscription<Mexc, Instrument, PublicTrades>: Identifier<MexcMarket>,
+        Subscription<Mexc, Instrument, OrderBooksL1>: Identifier<MexcMarket>,
+        Subscription<Mexc, Instrument, OrderBooksL2>: Identifier<MexcMarket>,
         Subscription<Okx, Instrument, PublicTrades>: Identifier<OkxMarket>,
+        Subscription<Okx, Instrument, Liquidations>: Identifier<OkxMarket>,
+        Subscription<UpbitSpot, Instrument, PublicTrades>: Identifier<UpbitMarket>,
+        Subscription<UpbitSpot, Instrument, OrderBooksL2>: Identifier<UpbitMarket>,
     {
         // Validate & dedup Subscription batches
         let batches = validate_batches(subscription_batches)?;
@@ -114,11 +153,13 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                     .map(|((exchange, sub_kind), subs)| {
                         let subs = subs.into_iter().collect::<Vec<_>>();
                         let txs = Arc::clone(&channels.txs);
+                        let config = connectors.get(&exchange).cloned().unwrap_or_default();
                         async move {
                             match (exchange, sub_kind) {
                                 (ExchangeId::BinanceSpot, SubKind::PublicTrades) => {
                                     init_market_stream(
                                         STREAM_RECONNECTION_POLICY,
+                                        config,
                                         subs.into_iter()
                                             .map(|sub| {
                                                 Subscription::new(
@@ -126,6 +167,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                                     sub.instrument,
                                                     PublicTrades,
                                                 )
+                                                .with_params(sub.params)
                                             })
                                             .collect(),
                                     )
@@ -137,6 +179,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                 (ExchangeId::BinanceSpot, SubKind::OrderBooksL1) => {
                                     init_market_stream(
                                         STREAM_RECONNECTION_POLICY,
+                                        config,
                                         subs.into_iter()
                                             .map(|sub| {
                                                 Subscription::new(
@@ -144,6 +187,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                                     sub.instrument,
                                                     OrderBooksL1,
                                                 )
+                                                .with_params(sub.params)
                                             })
                                             .collect(),
                                     )
@@ -155,6 +199,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                 (ExchangeId::BinanceFuturesUsd, SubKind::PublicTrades) => {
                                     init_market_stream(
                                         STREAM_RECONNECTION_POLICY,
+                                        config,
                                         subs.into_iter()
                                             .map(|sub| {
                                                 Subscription::new(
@@ -162,6 +207,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                                     sub.instrument,
                                                     PublicTrades,
                                                 )
+                                                .with_params(sub.params)
                                             })
                                             .collect(),
                                     )
@@ -173,6 +219,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                 (ExchangeId::BinanceFuturesUsd, SubKind::OrderBooksL1) => {
                                     init_market_stream(
                                         STREAM_RECONNECTION_POLICY,
+                                        config,
                                         subs.into_iter()
                                             .map(|sub| {
                                                 Subscription::<_, Instrument, _>::new(
@@ -180,6 +227,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                                     sub.instrument,
                                                     OrderBooksL1,
                                                 )
+                                                .with_params(sub.params)
                                             })
                                             .collect(),
                                     )
@@ -191,6 +239,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                 (ExchangeId::BinanceFuturesUsd, SubKind::Liquidations) => {
                                     init_market_stream(
                                         STREAM_RECONNECTION_POLICY,
+                                        config,
                                         subs.into_iter()
                                             .map(|sub| {
                                                 Subscription::<_, Instrument, _>::new(
@@ -198,6 +247,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                                     sub.instrument,
                                                     Liquidations,
                                                 )
+                                                .with_params(sub.params)
                                             })
                                             .collect(),
                                     )
@@ -209,6 +259,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                 (ExchangeId::Bitfinex, SubKind::PublicTrades) => {
                                     init_market_stream(
                                         STREAM_RECONNECTION_POLICY,
+                                        config,
                                         subs.into_iter()
                                             .map(|sub| {
                                                 Subscription::new(
@@ -216,6 +267,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                                     sub.instrument,
                                                     PublicTrades,
                                                 )
+                                                .with_params(sub.params)
                                             })
                                             .collect(),
                                     )
@@ -224,9 +276,70 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     .forward_to(txs.trades.get(&exchange).unwrap().clone());
                                     Ok(())
                                 }
+                                (ExchangeId::Bitfinex, SubKind::OrderBooksL2) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    Bitfinex,
+                                                    sub.instrument,
+                                                    OrderBooksL2,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.l2s.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
+                                (ExchangeId::Bithumb, SubKind::PublicTrades) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    BithumbSpot::default(),
+                                                    sub.instrument,
+                                                    PublicTrades,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.trades.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
+                                (ExchangeId::Bithumb, SubKind::OrderBooksL2) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    BithumbSpot::default(),
+                                                    sub.instrument,
+                                                    OrderBooksL2,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.l2s.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
                                 (ExchangeId::Bitmex, SubKind::PublicTrades) => {
                                     init_market_stream(
                                         STREAM_RECONNECTION_POLICY,
+                                        config,
                                         subs.into_iter()
                                             .map(|sub| {
                                                 Subscription::new(
@@ -234,6 +347,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                                     sub.instrument,
                                                     PublicTrades,
                                                 )
+                                                .with_params(sub.params)
                                             })
                                             .collect(),
                                     )
@@ -242,9 +356,70 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     .forward_to(txs.trades.get(&exchange).unwrap().clone());
                                     Ok(())
                                 }
+                                (ExchangeId::Bitmex, SubKind::Liquidations) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    Bitmex,
+                                                    sub.instrument,
+                                                    Liquidations,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.liquidations.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
+                                (ExchangeId::Bitstamp, SubKind::PublicTrades) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    Bitstamp,
+                                                    sub.instrument,
+                                                    PublicTrades,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.trades.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
+                                (ExchangeId::Bitstamp, SubKind::OrderBooksL2) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    Bitstamp,
+                                                    sub.instrument,
+                                                    OrderBooksL2,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.l2s.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
                                 (ExchangeId::BybitSpot, SubKind::PublicTrades) => {
                                     init_market_stream(
                                         STREAM_RECONNECTION_POLICY,
+                                        config,
                                         subs.into_iter()
                                             .map(|sub| {
                                                 Subscription::new(
@@ -252,6 +427,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                                     sub.instrument,
                                                     PublicTrades,
                                                 )
+                                                .with_params(sub.params)
                                             })
                                             .collect(),
                                     )
@@ -263,6 +439,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                 (ExchangeId::BybitPerpetualsUsd, SubKind::PublicTrades) => {
                                     init_market_stream(
                                         STREAM_RECONNECTION_POLICY,
+                                        config,
                                         subs.into_iter()
                                             .map(|sub| {
                                                 Subscription::new(
@@ -270,6 +447,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                                     sub.instrument,
                                                     PublicTrades,
                                                 )
+                                                .with_params(sub.params)
                                             })
                                             .collect(),
                                     )
@@ -278,9 +456,30 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     .forward_to(txs.trades.get(&exchange).unwrap().clone());
                                     Ok(())
                                 }
+                                (ExchangeId::BybitPerpetualsUsd, SubKind::Liquidations) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    BybitPerpetualsUsd::default(),
+                                                    sub.instrument,
+                                                    Liquidations,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.liquidations.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
                                 (ExchangeId::Coinbase, SubKind::PublicTrades) => {
                                     init_market_stream(
                                         STREAM_RECONNECTION_POLICY,
+                                        config,
                                         subs.into_iter()
                                             .map(|sub| {
                                                 Subscription::new(
@@ -288,6 +487,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                                     sub.instrument,
                                                     PublicTrades,
                                                 )
+                                                .with_params(sub.params)
                                             })
                                             .collect(),
                                     )
@@ -296,9 +496,50 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     .forward_to(txs.trades.get(&exchange).unwrap().clone());
                                     Ok(())
                                 }
+                                (ExchangeId::Dydx, SubKind::PublicTrades) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    Dydx,
+                                                    sub.instrument,
+                                                    PublicTrades,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.trades.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
+                                (ExchangeId::Dydx, SubKind::OrderBooksL2) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    Dydx,
+                                                    sub.instrument,
+                                                    OrderBooksL2,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.l2s.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
                                 (ExchangeId::GateioSpot, SubKind::PublicTrades) => {
                                     init_market_stream(
                                         STREAM_RECONNECTION_POLICY,
+                                        config,
                                         subs.into_iter()
                                             .map(|sub| {
                                                 Subscription::new(
@@ -306,6 +547,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                                     sub.instrument,
                                                     PublicTrades,
                                                 )
+                                                .with_params(sub.params)
                                             })
                                             .collect(),
                                     )
@@ -314,9 +556,50 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     .forward_to(txs.trades.get(&exchange).unwrap().clone());
                                     Ok(())
                                 }
+                                (ExchangeId::GateioSpot, SubKind::OrderBooksL1) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    GateioSpot::default(),
+                                                    sub.instrument,
+                                                    OrderBooksL1,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.l1s.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
+                                (ExchangeId::GateioSpot, SubKind::OrderBooksL2) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    GateioSpot::default(),
+                                                    sub.instrument,
+                                                    OrderBooksL2,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.l2s.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
                                 (ExchangeId::GateioFuturesUsd, SubKind::PublicTrades) => {
                                     init_market_stream(
                                         STREAM_RECONNECTION_POLICY,
+                                        config,
                                         subs.into_iter()
                                             .map(|sub| {
                                                 Subscription::new(
@@ -324,6 +607,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                                     sub.instrument,
                                                     PublicTrades,
                                                 )
+                                                .with_params(sub.params)
                                             })
                                             .collect(),
                                     )
@@ -335,6 +619,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                 (ExchangeId::GateioFuturesBtc, SubKind::PublicTrades) => {
                                     init_market_stream(
                                         STREAM_RECONNECTION_POLICY,
+                                        config,
                                         subs.into_iter()
                                             .map(|sub| {
                                                 Subscription::new(
@@ -342,6 +627,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                                     sub.instrument,
                                                     PublicTrades,
                                                 )
+                                                .with_params(sub.params)
                                             })
                                             .collect(),
                                     )
@@ -353,6 +639,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                 (ExchangeId::GateioPerpetualsUsd, SubKind::PublicTrades) => {
                                     init_market_stream(
                                         STREAM_RECONNECTION_POLICY,
+                                        config,
                                         subs.into_iter()
                                             .map(|sub| {
                                                 Subscription::new(
@@ -360,6 +647,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                                     sub.instrument,
                                                     PublicTrades,
                                                 )
+                                                .with_params(sub.params)
                                             })
                                             .collect(),
                                     )
@@ -368,9 +656,50 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     .forward_to(txs.trades.get(&exchange).unwrap().clone());
                                     Ok(())
                                 }
+                                (ExchangeId::GateioPerpetualsUsd, SubKind::OrderBooksL1) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    GateioPerpetualsUsd::default(),
+                                                    sub.instrument,
+                                                    OrderBooksL1,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.l1s.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
+                                (ExchangeId::GateioPerpetualsUsd, SubKind::OrderBooksL2) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    GateioPerpetualsUsd::default(),
+                                                    sub.instrument,
+                                                    OrderBooksL2,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.l2s.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
                                 (ExchangeId::GateioPerpetualsBtc, SubKind::PublicTrades) => {
                                     init_market_stream(
                                         STREAM_RECONNECTION_POLICY,
+                                        config,
                                         subs.into_iter()
                                             .map(|sub| {
                                                 Subscription::new(
@@ -378,6 +707,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                                     sub.instrument,
                                                     PublicTrades,
                                                 )
+                                                .with_params(sub.params)
                                             })
                                             .collect(),
                                     )
@@ -386,9 +716,50 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     .forward_to(txs.trades.get(&exchange).unwrap().clone());
                                     Ok(())
                                 }
+                                (ExchangeId::GateioPerpetualsBtc, SubKind::OrderBooksL1) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    GateioPerpetualsBtc::default(),
+                                                    sub.instrument,
+                                                    OrderBooksL1,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.l1s.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
+                                (ExchangeId::GateioPerpetualsBtc, SubKind::OrderBooksL2) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    GateioPerpetualsBtc::default(),
+                                                    sub.instrument,
+                                                    OrderBooksL2,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.l2s.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
                                 (ExchangeId::GateioOptions, SubKind::PublicTrades) => {
                                     init_market_stream(
                                         STREAM_RECONNECTION_POLICY,
+                                        config,
                                         subs.into_iter()
                                             .map(|sub| {
                                                 Subscription::new(
@@ -396,6 +767,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                                     sub.instrument,
                                                     PublicTrades,
                                                 )
+                                                .with_params(sub.params)
                                             })
                                             .collect(),
                                     )
@@ -404,9 +776,50 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     .forward_to(txs.trades.get(&exchange).unwrap().clone());
                                     Ok(())
                                 }
+                                (ExchangeId::Gemini, SubKind::PublicTrades) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    Gemini,
+                                                    sub.instrument,
+                                                    PublicTrades,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.trades.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
+                                (ExchangeId::Gemini, SubKind::OrderBooksL2) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    Gemini,
+                                                    sub.instrument,
+                                                    OrderBooksL2,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.l2s.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
                                 (ExchangeId::Kraken, SubKind::PublicTrades) => {
                                     init_market_stream(
                                         STREAM_RECONNECTION_POLICY,
+                                        config,
                                         subs.into_iter()
                                             .map(|sub| {
                                                 Subscription::new(
@@ -414,6 +827,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                                     sub.instrument,
                                                     PublicTrades,
                                                 )
+                                                .with_params(sub.params)
                                             })
                                             .collect(),
                                     )
@@ -425,13 +839,75 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                 (ExchangeId::Kraken, SubKind::OrderBooksL1) => {
                                     init_market_stream(
                                         STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    Kraken,
+                                                    sub.instrument,
+                                                    OrderBooksL1,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.l1s.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
+                                (ExchangeId::Kraken, SubKind::OrderBooksL2) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
                                         subs.into_iter()
                                             .map(|sub| {
                                                 Subscription::new(
                                                     Kraken,
                                                     sub.instrument,
+                                                    OrderBooksL2,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.l2s.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
+                                (ExchangeId::Kucoin, SubKind::PublicTrades) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    Kucoin,
+                                                    sub.instrument,
+                                                    PublicTrades,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.trades.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
+                                (ExchangeId::Kucoin, SubKind::OrderBooksL1) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    Kucoin,
+                                                    sub.instrument,
                                                     OrderBooksL1,
                                                 )
+                                                .with_params(sub.params)
                                             })
                                             .collect(),
                                     )
@@ -440,12 +916,130 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     .forward_to(txs.l1s.get(&exchange).unwrap().clone());
                                     Ok(())
                                 }
+                                (ExchangeId::Kucoin, SubKind::OrderBooksL2) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    Kucoin,
+                                                    sub.instrument,
+                                                    OrderBooksL2,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.l2s.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
+                                (ExchangeId::Mexc, SubKind::PublicTrades) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    Mexc,
+                                                    sub.instrument,
+                                                    PublicTrades,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.trades.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
+                                (ExchangeId::Mexc, SubKind::OrderBooksL1) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    Mexc,
+                                                    sub.instrument,
+                                                    OrderBooksL1,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.l1s.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
+                                (ExchangeId::Mexc, SubKind::OrderBooksL2) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    Mexc,
+                                                    sub.instrument,
+                                                    OrderBooksL2,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.l2s.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
                                 (ExchangeId::Okx, SubKind::PublicTrades) => {
                                     init_market_stream(
                                         STREAM_RECONNECTION_POLICY,
+                                        config,
                                         subs.into_iter()
                                             .map(|sub| {
                                                 Subscription::new(Okx, sub.instrument, PublicTrades)
+                                                    .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.trades.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
+                                (ExchangeId::Okx, SubKind::Liquidations) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(Okx, sub.instrument, Liquidations)
+                                                    .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.liquidations.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
+                                (ExchangeId::Upbit, SubKind::PublicTrades) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    UpbitSpot::default(),
+                                                    sub.instrument,
+                                                    PublicTrades,
+                                                )
+                                                .with_params(sub.params)
                                             })
                                             .collect(),
                                     )
@@ -454,6 +1048,26 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     .forward_to(txs.trades.get(&exchange).unwrap().clone());
                                     Ok(())
                                 }
+                                (ExchangeId::Upbit, SubKind::OrderBooksL2) => {
+                                    init_market_stream(
+                                        STREAM_RECONNECTION_POLICY,
+                                        config,
+                                        subs.into_iter()
+                                            .map(|sub| {
+                                                Subscription::new(
+                                                    UpbitSpot::default(),
+                                                    sub.instrument,
+                                                    OrderBooksL2,
+                                                )
+                                                .with_params(sub.params)
+                                            })
+                                            .collect(),
+                                    )
+                                    .await?
+                                    .boxed()
+                                    .forward_to(txs.l2s.get(&exchange).unwrap().clone());
+                                    Ok(())
+                                }
                                 (exchange, sub_kind) => {
                                     Err(DataError::Unsupported { exchange, sub_kind })
                                 }
@@ -608,6 +1222,47 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
     }
 }
 
+/// Determines if [`DynamicStreams`] supports streaming the provided [`SubKind`] from the
+/// provided exchange.
+pub fn supports(exchange: ExchangeId, sub_kind: SubKind) -> bool {
+    matches!(
+        (exchange, sub_kind),
+        (
+            ExchangeId::BinanceSpot,
+            SubKind::PublicTrades | SubKind::OrderBooksL1
+        ) | (
+            ExchangeId::BinanceFuturesUsd,
+            SubKind::PublicTrades | SubKind::OrderBooksL1 | SubKind::Liquidations
+        ) | (
+            ExchangeId::BybitSpot
+                | ExchangeId::Coinbase
+                | ExchangeId::GateioFuturesUsd
+                | ExchangeId::GateioFuturesBtc
+                | ExchangeId::GateioOptions,
+            SubKind::PublicTrades
+        ) | (
+            ExchangeId::Bitmex | ExchangeId::BybitPerpetualsUsd | ExchangeId::Okx,
+            SubKind::PublicTrades | SubKind::Liquidations
+        ) | (
+            ExchangeId::Bitfinex
+                | ExchangeId::Bithumb
+                | ExchangeId::Bitstamp
+                | ExchangeId::Dydx
+                | ExchangeId::Gemini
+                | ExchangeId::Upbit,
+            SubKind::PublicTrades | SubKind::OrderBooksL2
+        ) | (
+            ExchangeId::GateioSpot
+                | ExchangeId::GateioPerpetualsUsd
+                | ExchangeId::GateioPerpetualsBtc
+                | ExchangeId::Kraken
+                | ExchangeId::Kucoin
+                | ExchangeId::Mexc,
+            SubKind::PublicTrades | SubKind::OrderBooksL1 | SubKind::OrderBooksL2
+        )
+    )
+}
+
 pub fn validate_batches<SubBatchIter, SubIter, Sub, Instrument>(
     batches: SubBatchIter,
 ) -> Result<Vec<Vec<Subscription<ExchangeId, Instrument, SubKind>>>, DataError>
@@ -682,9 +1337,7 @@ where
                     }
                 }
                 SubKind::OrderBooksL2 => {
-                    if let (None, None) =
-                        (txs.l2s.get(&sub.exchange), rxs.trades.get(&sub.exchange))
-                    {
+                    if let (None, None) = (txs.l2s.get(&sub.exchange), rxs.l2s.get(&sub.exchange)) {
                         let (tx, rx) = mpsc::unbounded_channel();
                         txs.l2s.insert(sub.exchange, tx);
                         rxs.l2s.insert(sub.exchange, rx);
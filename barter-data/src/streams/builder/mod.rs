@@ -1,19 +1,20 @@
 use super::Streams;
 use crate::{
     error::DataError,
-    exchange::StreamSelector,
+    exchange::{config::ConnectorConfig, server_time::sync_server_clock, StreamSelector},
     instrument::InstrumentData,
     streams::{
         consumer::{init_market_stream, MarketStreamResult, STREAM_RECONNECTION_POLICY},
+        publish::{publish_streams, MarketEventPublisher},
         reconnect::stream::ReconnectingStream,
     },
     subscription::{Subscription, SubscriptionKind},
     Identifier,
 };
 use barter_instrument::exchange::ExchangeId;
-use barter_integration::Validator;
+use barter_integration::{auth::clock::ServerClock, Validator};
 use futures_util::StreamExt;
-use std::{collections::HashMap, fmt::Debug, future::Future, pin::Pin};
+use std::{collections::HashMap, fmt::Debug, future::Future, pin::Pin, sync::Arc, time::Duration};
 use tokio::sync::mpsc;
 
 /// Defines the [`MultiStreamBuilder`](multi::MultiStreamBuilder) API for ergonomically
@@ -26,10 +27,19 @@ pub mod multi;
 /// types.
 pub mod dynamic;
 
+/// Defines predefined [`SubBundle`](bundle::SubBundle)s of [`SubKind`]s (eg/ market making) that
+/// expand into the individual [`DynamicStreams`](dynamic::DynamicStreams) subscriptions supported
+/// by each exchange.
+pub mod bundle;
+
 /// Communicative type alias representing the [`Future`] result of a [`Subscription`] [`validate`]
 /// call generated whilst executing [`StreamBuilder::subscribe`].
 pub type SubscribeFuture = Pin<Box<dyn Future<Output = Result<(), DataError>>>>;
 
+/// Communicative type alias representing a hook applied to the initialised [`Streams`], such as
+/// teeing every [`MarketEvent`](crate::event::MarketEvent) to a [`MarketEventPublisher`].
+pub type StreamsHook<T> = Box<dyn FnOnce(Streams<T>) -> Streams<T>>;
+
 /// Builder to configure and initialise a [`Streams<MarketEvent<SubscriptionKind::Event>`](Streams) instance
 /// for a specific [`SubscriptionKind`].
 #[derive(Default)]
@@ -40,6 +50,9 @@ where
     pub channels:
         HashMap<ExchangeId, ExchangeChannel<MarketStreamResult<InstrumentKey, Kind::Event>>>,
     pub futures: Vec<SubscribeFuture>,
+    pub publisher: Option<StreamsHook<MarketStreamResult<InstrumentKey, Kind::Event>>>,
+    pub connectors: HashMap<ExchangeId, ConnectorConfig>,
+    pub clocks: HashMap<ExchangeId, (Arc<ServerClock>, Duration)>,
 }
 
 impl<InstrumentKey, Kind> Debug for StreamBuilder<InstrumentKey, Kind>
@@ -51,6 +64,9 @@ where
         f.debug_struct("StreamBuilder<InstrumentKey, SubscriptionKind>")
             .field("channels", &self.channels)
             .field("num_futures", &self.futures.len())
+            .field("publisher", &self.publisher.is_some())
+            .field("connectors", &self.connectors)
+            .field("clocks", &self.clocks)
             .finish()
     }
 }
@@ -64,9 +80,44 @@ where
         Self {
             channels: HashMap::new(),
             futures: Vec::new(),
+            publisher: None,
+            connectors: HashMap::new(),
+            clocks: HashMap::new(),
         }
     }
 
+    /// Connect the market streams of the provided [`ExchangeId`] using the provided
+    /// [`ConnectorConfig`] (eg/ to a testnet [`Environment`]), rather than the default.
+    ///
+    /// Applies to the [`Subscription`]s of the exchange added via subsequent
+    /// [`subscribe()`](StreamBuilder::subscribe()) calls.
+    ///
+    /// [`Environment`]: barter_integration::environment::Environment
+    pub fn connector(mut self, exchange: ExchangeId, config: ConnectorConfig) -> Self {
+        self.connectors.insert(exchange, config);
+        self
+    }
+
+    /// Construct a [`ServerClock`] for the provided [`ExchangeId`], synced with the exchange
+    /// server time at the provided interval once the [`StreamBuilder`] is initialised.
+    ///
+    /// The [`ServerClock`] syncs against the exchange's [`ConnectorConfig`] environment, and can
+    /// be retrieved via [`server_clock()`](StreamBuilder::server_clock()) to share with an
+    /// execution client of the same environment.
+    pub fn sync_server_clock(mut self, exchange: ExchangeId, interval: Duration) -> Self {
+        self.clocks
+            .insert(exchange, (Arc::new(ServerClock::default()), interval));
+        self
+    }
+
+    /// [`ServerClock`] of the provided [`ExchangeId`], if configured via
+    /// [`sync_server_clock()`](StreamBuilder::sync_server_clock()).
+    pub fn server_clock(&self, exchange: ExchangeId) -> Option<Arc<ServerClock>> {
+        self.clocks
+            .get(&exchange)
+            .map(|(clock, _)| Arc::clone(clock))
+    }
+
     /// Add a collection of [`Subscription`]s to the [`StreamBuilder`] that will be actioned on
     /// a distinct [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection.
     ///
@@ -91,6 +142,13 @@ where
         // '--> Add ExchangeChannel Entry if this Exchange <--> SubscriptionKind combination is new
         let exchange_tx = self.channels.entry(Exchange::ID).or_default().tx.clone();
 
+        // Determine the ConnectorConfig of this Exchange's connection
+        let config = self
+            .connectors
+            .get(&Exchange::ID)
+            .cloned()
+            .unwrap_or_default();
+
         // Add Future that once awaited will yield the Result<(), SocketError> of subscribing
         self.futures.push(Box::pin(async move {
             // Validate Subscriptions
@@ -104,7 +162,7 @@ where
             subscriptions.dedup();
 
             // Initialise a MarketEvent `ReconnectingStream`
-            init_market_stream(STREAM_RECONNECTION_POLICY, subscriptions)
+            init_market_stream(STREAM_RECONNECTION_POLICY, config, subscriptions)
                 .await?
                 .boxed()
                 .forward_to(exchange_tx);
@@ -115,9 +173,27 @@ where
         self
     }
 
+    /// Publish every [`MarketEvent<SubscriptionKind::Event>`](MarketEvent) consumed by the
+    /// initialised [`Streams`] via the provided [`MarketEventPublisher`] (eg/
+    /// the `redis` feature `RedisPublisher`), in addition to yielding them.
+    pub fn publish<Publisher>(mut self, publisher: Publisher) -> Self
+    where
+        Kind: Default,
+        InstrumentKey: Clone + Send + 'static,
+        Kind::Event: Clone + Send + 'static,
+        Publisher: MarketEventPublisher<InstrumentKey, Kind::Event> + Send + 'static,
+    {
+        let kind = Kind::default().as_str();
+        self.publisher = Some(Box::new(move |streams| {
+            publish_streams(streams, kind, publisher)
+        }));
+        self
+    }
+
     /// Spawn a [`MarketEvent<SubscriptionKind::Event>`](MarketEvent) consumer loop for each collection of
     /// [`Subscription`]s added to [`StreamBuilder`] via the
-    /// [`subscribe()`](StreamBuilder::subscribe()) method.
+    /// [`subscribe()`](StreamBuilder::subscribe()) method, and a sync task for each
+    /// [`ServerClock`].
     ///
     /// Each consumer loop distributes consumed [`MarketEvent<SubscriptionKind::Event>s`](MarketEvent) to
     /// the [`Streams`] `HashMap` returned by this method.
@@ -127,13 +203,25 @@ where
         // Await Stream initialisation perpetual and ensure success
         futures::future::try_join_all(self.futures).await?;
 
+        // Spawn a sync task for each ServerClock, using the exchange ConnectorConfig environment
+        for (exchange, (clock, interval)) in self.clocks {
+            let config = self.connectors.get(&exchange).cloned().unwrap_or_default();
+
+            tokio::spawn(sync_server_clock(exchange, config, clock, interval, None));
+        }
+
         // Construct Streams using each ExchangeChannel receiver
-        Ok(Streams {
+        let streams = Streams {
             streams: self
                 .channels
                 .into_iter()
                 .map(|(exchange, channel)| (exchange, channel.rx))
                 .collect(),
+        };
+
+        Ok(match self.publisher {
+            Some(publish) => publish(streams),
+            None => streams,
         })
     }
 }
@@ -159,3 +247,37 @@ impl<T> Default for ExchangeChannel<T> {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::trade::PublicTrades;
+    use barter_integration::environment::Environment;
+
+    #[test]
+    fn test_stream_builder_server_clock() {
+        let interval = Duration::from_secs(60);
+
+        let mainnet = StreamBuilder::<String, PublicTrades>::new()
+            .sync_server_clock(ExchangeId::BinanceSpot, interval);
+        let testnet = StreamBuilder::<String, PublicTrades>::new()
+            .connector(
+                ExchangeId::BinanceSpot,
+                ConnectorConfig::new(Environment::Testnet),
+            )
+            .sync_server_clock(ExchangeId::BinanceSpot, interval);
+
+        let clock = mainnet.server_clock(ExchangeId::BinanceSpot).unwrap();
+        assert!(Arc::ptr_eq(
+            &clock,
+            &mainnet.server_clock(ExchangeId::BinanceSpot).unwrap()
+        ));
+        assert!(mainnet.server_clock(ExchangeId::Okx).is_none());
+
+        // ServerClocks are never shared between environments
+        assert!(!Arc::ptr_eq(
+            &clock,
+            &testnet.server_clock(ExchangeId::BinanceSpot).unwrap()
+        ));
+    }
+}
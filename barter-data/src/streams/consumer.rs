@@ -1,7 +1,7 @@
 use crate::{
     error::DataError,
     event::MarketEvent,
-    exchange::StreamSelector,
+    exchange::{config::ConnectorConfig, StreamSelector},
     instrument::InstrumentData,
     streams::{
         reconnect,
@@ -38,9 +38,10 @@ pub type MarketStreamEvent<InstrumentKey, Kind> =
 /// [`Subscription`]s.
 ///
 /// The provided [`ReconnectionBackoffPolicy`] dictates how the exponential backoff scales
-/// between reconnections.
+/// between reconnections, and every (re)connection uses the provided [`ConnectorConfig`].
 pub async fn init_market_stream<Exchange, Instrument, Kind>(
     policy: ReconnectionBackoffPolicy,
+    config: ConnectorConfig,
     subscriptions: Vec<Subscription<Exchange, Instrument, Kind>>,
 ) -> Result<impl Stream<Item = MarketStreamResult<Instrument::Key, Kind::Event>>, DataError>
 where
@@ -66,13 +67,17 @@ where
         %exchange,
         ?subscriptions,
         ?policy,
+        ?config,
         ?stream_key,
         "MarketStream with auto reconnect running"
     );
 
     Ok(init_reconnecting_stream(move || {
+        let config = config.clone();
         let subscriptions = subscriptions.clone();
-        async move { Exchange::Stream::init::<Exchange::SnapFetcher>(&subscriptions).await }
+        async move {
+            Exchange::Stream::init::<Exchange::SnapFetcher>(&config, &subscriptions).await
+        }
     })
     .await?
     .with_reconnect_backoff(policy, stream_key)
@@ -0,0 +1,480 @@
+use super::publish::{instrument_key, MarketEventPublisher};
+use crate::{error::DataError, event::MarketEvent};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+use tracing::warn;
+
+/// Default maximum number of [`KafkaMessage`]s buffered before a [`KafkaSink`] produces a batch.
+pub const DEFAULT_KAFKA_BATCH_SIZE: usize = 500;
+
+/// Default maximum time a [`KafkaMessage`] is buffered before a [`KafkaSink`] produces a batch.
+pub const DEFAULT_KAFKA_LINGER: Duration = Duration::from_millis(100);
+
+/// Record that can be serialised and produced to Kafka by a [`KafkaSink`].
+pub trait KafkaRecord: Serialize {
+    /// Message key used for partitioning, so every record of the same instrument is consumed
+    /// in order.
+    fn key(&self) -> String;
+}
+
+impl<InstrumentKey, T> KafkaRecord for MarketEvent<InstrumentKey, T>
+where
+    InstrumentKey: Display + Serialize,
+    T: Serialize,
+{
+    fn key(&self) -> String {
+        format!(
+            "{}:{}",
+            self.exchange.as_str(),
+            instrument_key(&self.instrument)
+        )
+    }
+}
+
+/// Serialised [`KafkaRecord`] ready to be produced.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct KafkaMessage {
+    pub topic: String,
+    pub key: String,
+    pub payload: Vec<u8>,
+}
+
+/// Kafka client used by a [`KafkaSink`] to deliver batches of [`KafkaMessage`]s (eg/ the
+/// `kafka` feature [`RdKafkaProducer`]).
+pub trait KafkaProducer {
+    /// Produce a batch of [`KafkaMessage`]s, waiting for delivery. Returns the delivery result of
+    /// each [`KafkaMessage`], in the same order as the batch.
+    fn produce(&mut self, batch: &[KafkaMessage]) -> Vec<Result<(), DataError>>;
+}
+
+/// Configuration for constructing a [`KafkaSink`].
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct KafkaSinkConfig {
+    /// Topic used for any kind without an explicit entry in `topics`.
+    pub default_topic: String,
+    /// Topic of each kind of record, eg/ "public_trades" -> "market.trades".
+    pub topics: HashMap<String, String>,
+    pub batch_size: usize,
+    pub linger: Duration,
+}
+
+impl KafkaSinkConfig {
+    /// Construct a new [`KafkaSinkConfig`] that produces every kind of record to the provided
+    /// topic, using the default batching parameters.
+    pub fn new<S>(default_topic: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            default_topic: default_topic.into(),
+            topics: HashMap::new(),
+            batch_size: DEFAULT_KAFKA_BATCH_SIZE,
+            linger: DEFAULT_KAFKA_LINGER,
+        }
+    }
+
+    /// Topic the provided kind of record is produced to.
+    pub fn topic(&self, kind: &str) -> &str {
+        self.topics
+            .get(kind)
+            .map(String::as_str)
+            .unwrap_or(&self.default_topic)
+    }
+}
+
+/// Delivery metrics of a [`KafkaSink`], shareable with other threads via
+/// [`KafkaSink::metrics`].
+#[derive(Debug, Default)]
+pub struct KafkaSinkMetrics {
+    batches: AtomicU64,
+    delivered: AtomicU64,
+    failed: AtomicU64,
+}
+
+impl KafkaSinkMetrics {
+    /// Number of batches produced.
+    pub fn batches(&self) -> u64 {
+        self.batches.load(Ordering::Relaxed)
+    }
+
+    /// Number of records delivered.
+    pub fn delivered(&self) -> u64 {
+        self.delivered.load(Ordering::Relaxed)
+    }
+
+    /// Number of records that failed to be serialised or delivered.
+    pub fn failed(&self) -> u64 {
+        self.failed.load(Ordering::Relaxed)
+    }
+}
+
+/// Batching Kafka sink that serialises [`KafkaRecord`]s (eg/ [`MarketEvent`]s & `AccountEvent`s)
+/// as JSON, and produces them to the configured topic of their kind, keyed by instrument.
+///
+/// Buffered [`KafkaMessage`]s are produced once `batch_size` is reached, or the `linger` since the
+/// first buffered [`KafkaMessage`] has elapsed. Failed deliveries are logged and counted in the
+/// [`KafkaSinkMetrics`].
+#[derive(Debug)]
+pub struct KafkaSink<Producer> {
+    config: KafkaSinkConfig,
+    producer: Producer,
+    batch: Vec<KafkaMessage>,
+    batch_start: Option<Instant>,
+    metrics: Arc<KafkaSinkMetrics>,
+}
+
+impl<Producer> KafkaSink<Producer>
+where
+    Producer: KafkaProducer,
+{
+    /// Construct a new [`KafkaSink`] using the provided [`KafkaProducer`].
+    pub fn new(config: KafkaSinkConfig, producer: Producer) -> Self {
+        Self {
+            batch: Vec::with_capacity(config.batch_size),
+            config,
+            producer,
+            batch_start: None,
+            metrics: Arc::default(),
+        }
+    }
+
+    /// Shared handle to the [`KafkaSinkMetrics`] of this [`KafkaSink`].
+    pub fn metrics(&self) -> Arc<KafkaSinkMetrics> {
+        Arc::clone(&self.metrics)
+    }
+
+    /// Serialise and buffer a [`KafkaRecord`] of the provided kind, producing the batch if it is
+    /// full or has lingered.
+    pub fn send<Record>(&mut self, kind: &str, record: &Record) -> Result<(), DataError>
+    where
+        Record: KafkaRecord,
+    {
+        let payload = serde_json::to_vec(record).map_err(|error| {
+            self.metrics.failed.fetch_add(1, Ordering::Relaxed);
+            DataError::Publish(error.to_string())
+        })?;
+
+        self.batch.push(KafkaMessage {
+            topic: self.config.topic(kind).to_owned(),
+            key: record.key(),
+            payload,
+        });
+        let batch_start = *self.batch_start.get_or_insert_with(Instant::now);
+
+        if self.batch.len() >= self.config.batch_size || batch_start.elapsed() >= self.config.linger
+        {
+            self.flush()
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Produce every buffered [`KafkaMessage`], returning the first delivery failure.
+    pub fn flush(&mut self) -> Result<(), DataError> {
+        self.batch_start = None;
+        if self.batch.is_empty() {
+            return Ok(());
+        }
+
+        let results = self.producer.produce(&self.batch);
+        self.metrics.batches.fetch_add(1, Ordering::Relaxed);
+
+        let mut first_error = None;
+        for (message, result) in self.batch.drain(..).zip(results) {
+            match result {
+                Ok(()) => {
+                    self.metrics.delivered.fetch_add(1, Ordering::Relaxed);
+                }
+                Err(error) => {
+                    self.metrics.failed.fetch_add(1, Ordering::Relaxed);
+                    warn!(%error, topic = %message.topic, key = %message.key, "failed to deliver KafkaMessage");
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+
+        first_error.map_or(Ok(()), Err)
+    }
+}
+
+impl<Producer> Drop for KafkaSink<Producer> {
+    fn drop(&mut self) {
+        if !self.batch.is_empty() {
+            warn!(
+                dropped = self.batch.len(),
+                "KafkaSink dropped with unproduced KafkaMessages, flush before dropping"
+            );
+        }
+    }
+}
+
+impl<InstrumentKey, T, Producer> MarketEventPublisher<InstrumentKey, T> for KafkaSink<Producer>
+where
+    InstrumentKey: Display + Serialize,
+    T: Serialize,
+    Producer: KafkaProducer,
+{
+    fn publish(
+        &mut self,
+        kind: &'static str,
+        event: &MarketEvent<InstrumentKey, T>,
+    ) -> Result<(), DataError> {
+        self.send(kind, event)
+    }
+}
+
+#[cfg(feature = "kafka")]
+pub use rd::{RdKafkaProducer, DEFAULT_KAFKA_DELIVERY_TIMEOUT};
+
+#[cfg(feature = "kafka")]
+mod rd {
+    use super::{KafkaMessage, KafkaProducer};
+    use crate::error::DataError;
+    use parking_lot::Mutex;
+    use rdkafka::{
+        config::ClientConfig,
+        error::{KafkaError, RDKafkaErrorCode},
+        producer::{
+            BaseProducer, BaseRecord, DeliveryResult, Producer, ProducerContext, PurgeConfig,
+        },
+        ClientContext,
+    };
+    use std::time::Duration;
+
+    /// Default maximum time a [`RdKafkaProducer`] waits for a batch to be delivered.
+    pub const DEFAULT_KAFKA_DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// Interval an [`RdKafkaProducer`] polls for delivery reports while the librdkafka queue
+    /// is full.
+    const QUEUE_FULL_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+    /// [`KafkaProducer`] backed by an `rdkafka` [`BaseProducer`].
+    ///
+    /// Each batch is enqueued and then flushed, collecting the delivery report of every
+    /// [`KafkaMessage`]. Messages not delivered within the delivery timeout are purged & failed,
+    /// so late delivery reports never leak into the next batch.
+    pub struct RdKafkaProducer {
+        producer: BaseProducer<BatchContext>,
+        timeout: Duration,
+    }
+
+    impl std::fmt::Debug for RdKafkaProducer {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("RdKafkaProducer")
+                .field("timeout", &self.timeout)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl RdKafkaProducer {
+        /// Construct a new [`RdKafkaProducer`] from the provided [`ClientConfig`] (eg/ with
+        /// "bootstrap.servers" set), waiting up to the [`DEFAULT_KAFKA_DELIVERY_TIMEOUT`] for each
+        /// batch to be delivered.
+        pub fn new(config: &ClientConfig) -> Result<Self, DataError> {
+            Self::with_timeout(config, DEFAULT_KAFKA_DELIVERY_TIMEOUT)
+        }
+
+        /// Construct a new [`RdKafkaProducer`] from the provided [`ClientConfig`], waiting up to
+        /// the provided timeout for each batch to be delivered.
+        pub fn with_timeout(config: &ClientConfig, timeout: Duration) -> Result<Self, DataError> {
+            let producer = config
+                .create_with_context(BatchContext::default())
+                .map_err(|error| DataError::Publish(error.to_string()))?;
+
+            Ok(Self { producer, timeout })
+        }
+
+        fn enqueue(&self, index: usize, message: &KafkaMessage) -> Result<(), KafkaError> {
+            let mut record = BaseRecord::with_opaque_to(&message.topic, index)
+                .key(&message.key)
+                .payload(&message.payload);
+
+            loop {
+                match self.producer.send(record) {
+                    Ok(()) => return Ok(()),
+                    Err((KafkaError::MessageProduction(RDKafkaErrorCode::QueueFull), returned)) => {
+                        self.producer.poll(QUEUE_FULL_POLL_INTERVAL);
+                        record = returned;
+                    }
+                    Err((error, _)) => return Err(error),
+                }
+            }
+        }
+    }
+
+    impl KafkaProducer for RdKafkaProducer {
+        fn produce(&mut self, batch: &[KafkaMessage]) -> Vec<Result<(), DataError>> {
+            *self.producer.context().results.lock() = (0..batch.len()).map(|_| None).collect();
+
+            for (index, message) in batch.iter().enumerate() {
+                let enqueued = self.enqueue(index, message);
+                if let Err(error) = enqueued {
+                    let mut results = self.producer.context().results.lock();
+                    results[index] = Some(Err(DataError::Publish(error.to_string())));
+                }
+            }
+
+            if self.producer.flush(self.timeout).is_err() {
+                // Fail undelivered messages now, serving their delivery reports
+                self.producer
+                    .purge(PurgeConfig::default().queue().inflight());
+                self.producer.poll(Duration::ZERO);
+            }
+
+            std::mem::take(&mut *self.producer.context().results.lock())
+                .into_iter()
+                .map(|result| {
+                    result.unwrap_or_else(|| {
+                        Err(DataError::Publish(
+                            "KafkaMessage delivery not confirmed".to_string(),
+                        ))
+                    })
+                })
+                .collect()
+        }
+    }
+
+    /// [`ProducerContext`] collecting the delivery report of each [`KafkaMessage`] of the batch
+    /// being produced, indexed by position in the batch.
+    #[derive(Default)]
+    struct BatchContext {
+        results: Mutex<Vec<Option<Result<(), DataError>>>>,
+    }
+
+    impl ClientContext for BatchContext {}
+
+    impl ProducerContext for BatchContext {
+        type DeliveryOpaque = usize;
+
+        fn delivery(&self, delivery: &DeliveryResult<'_>, index: Self::DeliveryOpaque) {
+            let result = match delivery {
+                Ok(_) => Ok(()),
+                Err((error, _)) => Err(DataError::Publish(error.to_string())),
+            };
+
+            let mut results = self.results.lock();
+            if let Some(slot) = results.get_mut(index) {
+                *slot = Some(result);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::trade::PublicTrade;
+    use barter_instrument::{
+        exchange::ExchangeId,
+        instrument::{kind::InstrumentKind, Instrument},
+    };
+    use barter_integration::Side;
+    use chrono::Utc;
+
+    #[derive(Debug, Default)]
+    struct MockProducer {
+        batches: Vec<Vec<KafkaMessage>>,
+        fail_key: Option<String>,
+    }
+
+    impl KafkaProducer for MockProducer {
+        fn produce(&mut self, batch: &[KafkaMessage]) -> Vec<Result<(), DataError>> {
+            self.batches.push(batch.to_vec());
+            batch
+                .iter()
+                .map(|message| match &self.fail_key {
+                    Some(key) if *key == message.key => {
+                        Err(DataError::Publish("broker unavailable".to_string()))
+                    }
+                    _ => Ok(()),
+                })
+                .collect()
+        }
+    }
+
+    fn trade(base: &str) -> MarketEvent<Instrument, PublicTrade> {
+        MarketEvent {
+            time_exchange: Utc::now(),
+            time_received: Utc::now(),
+            exchange: ExchangeId::BinanceSpot,
+            instrument: Instrument::from((base, "usdt", InstrumentKind::Spot)),
+            kind: PublicTrade {
+                id: "id".to_string(),
+                price: 100.0,
+                amount: 1.0,
+                side: Side::Buy,
+                aggregate_count: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_kafka_sink_batches_and_metrics() {
+        let config = KafkaSinkConfig {
+            topics: HashMap::from([("public_trades".to_string(), "market.trades".to_string())]),
+            batch_size: 2,
+            linger: Duration::from_secs(60),
+            ..KafkaSinkConfig::new("market")
+        };
+        let producer = MockProducer {
+            fail_key: Some("binance_spot:eth_usdt_spot".to_string()),
+            ..Default::default()
+        };
+        let mut sink = KafkaSink::new(config, producer);
+        let metrics = sink.metrics();
+
+        // Buffered until the batch is full
+        sink.publish("public_trades", &trade("btc")).unwrap();
+        assert!(sink.producer.batches.is_empty());
+
+        // Full batch is produced, with the delivery failure of the eth record returned
+        assert!(sink.publish("liquidations", &trade("eth")).is_err());
+        assert_eq!(
+            sink.producer.batches[0]
+                .iter()
+                .map(|message| (message.topic.as_str(), message.key.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("market.trades", "binance_spot:btc_usdt_spot"),
+                ("market", "binance_spot:eth_usdt_spot"),
+            ]
+        );
+
+        // Partial batch is produced on flush
+        sink.publish("public_trades", &trade("btc")).unwrap();
+        sink.flush().unwrap();
+
+        assert_eq!(metrics.batches(), 2);
+        assert_eq!(metrics.delivered(), 2);
+        assert_eq!(metrics.failed(), 1);
+    }
+
+    #[cfg(feature = "kafka")]
+    #[test]
+    fn test_rdkafka_producer_fails_undeliverable_batch() {
+        let mut config = rdkafka::ClientConfig::new();
+        config
+            .set("bootstrap.servers", "127.0.0.1:1")
+            .set("message.timeout.ms", "200")
+            .set("log_level", "0");
+        let producer = RdKafkaProducer::with_timeout(&config, Duration::from_secs(5)).unwrap();
+        let mut sink = KafkaSink::new(KafkaSinkConfig::new("market"), producer);
+        let metrics = sink.metrics();
+
+        sink.publish("public_trades", &trade("btc")).unwrap();
+        sink.publish("public_trades", &trade("eth")).unwrap();
+        assert!(matches!(sink.flush(), Err(DataError::Publish(_))));
+
+        assert_eq!(metrics.batches(), 1);
+        assert_eq!(metrics.delivered(), 0);
+        assert_eq!(metrics.failed(), 2);
+    }
+}
@@ -1,6 +1,8 @@
 use self::builder::{multi::MultiStreamBuilder, StreamBuilder};
 use crate::subscription::SubscriptionKind;
 use barter_instrument::exchange::ExchangeId;
+use barter_integration::stream::{merge::TimeOrderedMerge, ExchangeTime};
+use chrono::TimeDelta;
 use fnv::FnvHashMap;
 use futures::Stream;
 use futures_util::stream::select_all;
@@ -15,6 +17,16 @@ pub mod builder;
 /// drive a re-connecting [`MarketStream`](super::MarketStream).
 pub mod consumer;
 
+/// Defines a [`KafkaSink`](kafka::KafkaSink) that produces batches of serialised
+/// [`KafkaRecord`](kafka::KafkaRecord)s (eg/ [`MarketEvent`](crate::event::MarketEvent)s) to
+/// configurable Kafka topics, eg/ via the `kafka` feature `RdKafkaProducer`.
+pub mod kafka;
+
+/// Defines the [`MarketEventPublisher`](publish::MarketEventPublisher) API & a Redis
+/// implementation (`redis` feature) for fanning out normalised [`MarketEvent`](crate::event::MarketEvent)s to other
+/// services.
+pub mod publish;
+
 /// Defines a [`ReconnectingStream`] and associated logic for generating an auto reconnecting
 /// `Stream`.
 pub mod reconnect;
@@ -54,4 +66,16 @@ impl<T> Streams<T> {
 
         select_all(all)
     }
+
+    /// Select and merge every exchange `Stream`, emitting items in `time_exchange` order within
+    /// the provided maximum skew window (see [`TimeOrderedMerge`]).
+    pub fn select_all_time_ordered(
+        self,
+        max_skew: TimeDelta,
+    ) -> TimeOrderedMerge<impl Stream<Item = T>>
+    where
+        T: ExchangeTime,
+    {
+        TimeOrderedMerge::new(self.select_all(), max_skew)
+    }
 }
@@ -0,0 +1,447 @@
+use super::{consumer::MarketStreamResult, reconnect, Streams};
+use crate::{error::DataError, event::MarketEvent};
+use barter_instrument::exchange::ExchangeId;
+use std::fmt::Display;
+use tokio::sync::mpsc;
+use tracing::{error, warn};
+
+/// Publishes normalised [`MarketEvent`]s to an external service (eg/ Redis), allowing them to be
+/// fanned out to other services.
+pub trait MarketEventPublisher<InstrumentKey, T> {
+    /// Publish a [`MarketEvent`] of the provided [`SubscriptionKind`](crate::subscription::SubscriptionKind).
+    fn publish(
+        &mut self,
+        kind: &'static str,
+        event: &MarketEvent<InstrumentKey, T>,
+    ) -> Result<(), DataError>;
+}
+
+/// Generate the key a [`MarketEvent`] is published under, eg/
+/// "barter:binance_spot:btc_usdt_spot:public_trades".
+pub fn market_event_key<InstrumentKey>(
+    prefix: &str,
+    exchange: ExchangeId,
+    instrument: &InstrumentKey,
+    kind: &'static str,
+) -> String
+where
+    InstrumentKey: Display,
+{
+    format!(
+        "{prefix}:{}:{}:{kind}",
+        exchange.as_str(),
+        instrument_key(instrument)
+    )
+}
+
+/// Generate a key safe representation of an instrument, eg/ "btc_usdt_spot".
+pub fn instrument_key<InstrumentKey>(instrument: &InstrumentKey) -> String
+where
+    InstrumentKey: Display,
+{
+    instrument
+        .to_string()
+        .replace(['(', ')'], "")
+        .replace(", ", "_")
+        .replace([' ', ',', '/', '-'], "_")
+}
+
+#[cfg(feature = "redis")]
+pub use redis_publisher::{RedisPublishMode, RedisPublisher, RedisPublisherConfig};
+
+#[cfg(feature = "redis")]
+mod redis_publisher {
+    use super::{market_event_key, MarketEventPublisher};
+    use crate::{
+        error::DataError,
+        event::MarketEvent,
+        streams::{
+            consumer::STREAM_RECONNECTION_POLICY, reconnect::stream::ReconnectionBackoffPolicy,
+        },
+    };
+    use serde::{Deserialize, Serialize};
+    use std::{
+        fmt::{Debug, Display},
+        time::Duration,
+    };
+    use tracing::{info, warn};
+
+    /// Redis destination of published [`MarketEvent`]s.
+    #[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+    pub enum RedisPublishMode {
+        /// `PUBLISH` each [`MarketEvent`] to a pub/sub channel.
+        PubSub,
+        /// `XADD` each [`MarketEvent`] to a stream, approximately trimmed to `max_len` entries.
+        Stream { max_len: Option<usize> },
+        /// Both [`RedisPublishMode::PubSub`] & [`RedisPublishMode::Stream`].
+        PubSubAndStream { max_len: Option<usize> },
+    }
+
+    /// Configuration for constructing a [`RedisPublisher`].
+    #[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+    pub struct RedisPublisherConfig {
+        pub uri: String,
+        /// Prefix of every pub/sub channel & stream key.
+        pub key_prefix: String,
+        pub mode: RedisPublishMode,
+        /// Backoff between attempts to reconnect after the Redis connection is lost. Defaults to
+        /// the [`STREAM_RECONNECTION_POLICY`].
+        #[serde(default = "default_reconnect_policy")]
+        pub reconnect: ReconnectionBackoffPolicy,
+    }
+
+    fn default_reconnect_policy() -> ReconnectionBackoffPolicy {
+        STREAM_RECONNECTION_POLICY
+    }
+
+    /// [`MarketEventPublisher`] that publishes JSON encoded [`MarketEvent`]s to Redis pub/sub
+    /// channels and/or streams, keyed by exchange, instrument & kind (see [`market_event_key`]).
+    ///
+    /// If the Redis connection is lost, the failed publish is returned as an error and the next
+    /// publish reconnects, blocking with the configured [`ReconnectionBackoffPolicy`] until Redis
+    /// is reachable again. Events received by
+    /// [`publish_streams`](super::publish_streams) meanwhile are subject to it's drop policy.
+    pub struct RedisPublisher {
+        config: RedisPublisherConfig,
+        client: redis::Client,
+        conn: Option<redis::Connection>,
+    }
+
+    impl Debug for RedisPublisher {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("RedisPublisher")
+                .field("config", &self.config)
+                .field("connected", &self.conn.is_some())
+                .finish()
+        }
+    }
+
+    impl RedisPublisher {
+        /// Construct a new [`RedisPublisher`], connecting to the configured Redis uri.
+        pub fn new(config: RedisPublisherConfig) -> Result<Self, DataError> {
+            let client = redis::Client::open(config.uri.as_str())
+                .map_err(|error| DataError::Publish(error.to_string()))?;
+            let conn = client
+                .get_connection()
+                .map_err(|error| DataError::Publish(error.to_string()))?;
+
+            Ok(Self {
+                config,
+                client,
+                conn: Some(conn),
+            })
+        }
+
+        /// Current Redis connection, reconnecting with backoff if it was lost.
+        fn connection(&mut self) -> &mut redis::Connection {
+            let conn = match self.conn.take() {
+                Some(conn) => conn,
+                None => self.reconnect(),
+            };
+            self.conn.insert(conn)
+        }
+
+        /// Reconnect to the configured Redis uri, retrying with the configured
+        /// [`ReconnectionBackoffPolicy`] until successful.
+        fn reconnect(&self) -> redis::Connection {
+            let policy = &self.config.reconnect;
+            let mut backoff_ms = policy.backoff_ms_initial;
+
+            loop {
+                match self.client.get_connection() {
+                    Ok(conn) => {
+                        info!(uri = %self.config.uri, "RedisPublisher reconnected");
+                        return conn;
+                    }
+                    Err(error) => {
+                        warn!(
+                            uri = %self.config.uri,
+                            %error,
+                            backoff_ms,
+                            "RedisPublisher failed to reconnect, retrying after backoff"
+                        );
+                        std::thread::sleep(Duration::from_millis(backoff_ms));
+                        backoff_ms = std::cmp::min(
+                            backoff_ms * policy.backoff_multiplier as u64,
+                            policy.backoff_ms_max,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    impl<InstrumentKey, T> MarketEventPublisher<InstrumentKey, T> for RedisPublisher
+    where
+        InstrumentKey: Display + Serialize,
+        T: Serialize,
+    {
+        fn publish(
+            &mut self,
+            kind: &'static str,
+            event: &MarketEvent<InstrumentKey, T>,
+        ) -> Result<(), DataError> {
+            let key = market_event_key(
+                &self.config.key_prefix,
+                event.exchange,
+                &event.instrument,
+                kind,
+            );
+            let payload = serde_json::to_string(event)
+                .map_err(|error| DataError::Publish(error.to_string()))?;
+
+            let mut pipe = redis::pipe();
+            if let RedisPublishMode::PubSub | RedisPublishMode::PubSubAndStream { .. } =
+                self.config.mode
+            {
+                pipe.cmd("PUBLISH").arg(&key).arg(&payload).ignore();
+            }
+            if let RedisPublishMode::Stream { max_len }
+            | RedisPublishMode::PubSubAndStream { max_len } = self.config.mode
+            {
+                let xadd = pipe.cmd("XADD").arg(&key);
+                if let Some(max_len) = max_len {
+                    xadd.arg("MAXLEN").arg("~").arg(max_len);
+                }
+                xadd.arg("*").arg("event").arg(&payload).ignore();
+            }
+
+            pipe.query::<()>(self.connection()).map_err(|error| {
+                // Drop the lost connection so the next publish reconnects
+                if error.is_connection_dropped()
+                    || error.is_connection_refusal()
+                    || error.is_io_error()
+                {
+                    self.conn = None;
+                }
+                DataError::Publish(error.to_string())
+            })
+        }
+    }
+}
+
+/// Default capacity of the channel buffering [`MarketEvent`]s for the [`MarketEventPublisher`]
+/// in [`publish_streams`].
+pub const DEFAULT_PUBLISH_CHANNEL_CAPACITY: usize = 4096;
+
+/// Tee every [`MarketEvent`] of the provided [`Streams`] to the [`MarketEventPublisher`], returning
+/// [`Streams`] that yield the same events as before.
+///
+/// The [`MarketEventPublisher`] runs on a dedicated thread so slow (blocking) publishes never
+/// delay the [`Streams`] consumer. Failed publishes are logged and skipped.
+///
+/// Drop policy: up to [`DEFAULT_PUBLISH_CHANNEL_CAPACITY`] [`MarketEvent`]s are buffered for the
+/// [`MarketEventPublisher`]. If it falls further behind (eg/ while reconnecting), the newest
+/// [`MarketEvent`]s are not published until the buffer has capacity again, but are still yielded
+/// by the returned [`Streams`].
+pub fn publish_streams<InstrumentKey, T, Publisher>(
+    streams: Streams<MarketStreamResult<InstrumentKey, T>>,
+    kind: &'static str,
+    mut publisher: Publisher,
+) -> Streams<MarketStreamResult<InstrumentKey, T>>
+where
+    InstrumentKey: Clone + Send + 'static,
+    T: Clone + Send + 'static,
+    Publisher: MarketEventPublisher<InstrumentKey, T> + Send + 'static,
+{
+    let (publish_tx, mut publish_rx) =
+        mpsc::channel::<MarketEvent<InstrumentKey, T>>(DEFAULT_PUBLISH_CHANNEL_CAPACITY);
+
+    std::thread::spawn(move || {
+        while let Some(event) = publish_rx.blocking_recv() {
+            if let Err(error) = publisher.publish(kind, &event) {
+                warn!(%error, exchange = %event.exchange, kind, "failed to publish MarketEvent");
+            }
+        }
+    });
+
+    let streams = streams
+        .streams
+        .into_iter()
+        .map(|(exchange, mut rx)| {
+            let (tx, tee_rx) = mpsc::unbounded_channel();
+            let publish_tx = publish_tx.clone();
+
+            tokio::spawn(async move {
+                let mut dropped = 0_u64;
+                while let Some(event) = rx.recv().await {
+                    if let reconnect::Event::Item(Ok(market)) = &event {
+                        match publish_tx.try_send(market.clone()) {
+                            Ok(()) => {}
+                            Err(mpsc::error::TrySendError::Full(_)) => {
+                                dropped += 1;
+                                if dropped.is_power_of_two() {
+                                    warn!(
+                                        %exchange,
+                                        kind,
+                                        dropped,
+                                        "MarketEventPublisher lagging, dropped MarketEvents"
+                                    );
+                                }
+                            }
+                            Err(mpsc::error::TrySendError::Closed(_)) => {
+                                error!(%exchange, "MarketEventPublisher thread stopped");
+                            }
+                        }
+                    }
+
+                    if tx.send(event).is_err() {
+                        break;
+                    }
+                }
+            });
+
+            (exchange, tee_rx)
+        })
+        .collect();
+
+    Streams { streams }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::trade::PublicTrade;
+    use barter_instrument::instrument::{kind::InstrumentKind, Instrument};
+    use barter_integration::Side;
+    use chrono::Utc;
+    use fnv::FnvHashMap;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_market_event_key() {
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        assert_eq!(
+            market_event_key(
+                "barter",
+                ExchangeId::BinanceSpot,
+                &instrument,
+                "public_trades"
+            ),
+            "barter:binance_spot:btc_usdt_spot:public_trades"
+        );
+    }
+
+    #[derive(Clone, Default)]
+    struct MockPublisher(Arc<Mutex<Vec<(&'static str, f64)>>>);
+
+    impl MarketEventPublisher<Instrument, PublicTrade> for MockPublisher {
+        fn publish(
+            &mut self,
+            kind: &'static str,
+            event: &MarketEvent<Instrument, PublicTrade>,
+        ) -> Result<(), DataError> {
+            self.0.lock().unwrap().push((kind, event.kind.price));
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_publish_streams() {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let streams = Streams {
+            streams: FnvHashMap::from_iter([(ExchangeId::BinanceSpot, rx)]),
+        };
+
+        let publisher = MockPublisher::default();
+        let mut streams = publish_streams(streams, "public_trades", publisher.clone());
+
+        tx.send(reconnect::Event::Reconnecting(ExchangeId::BinanceSpot))
+            .unwrap();
+        tx.send(reconnect::Event::Item(Ok(trade(100.0)))).unwrap();
+        drop(tx);
+
+        // Every event is still yielded by the Streams
+        let rx = streams.streams.get_mut(&ExchangeId::BinanceSpot).unwrap();
+        assert!(matches!(
+            rx.recv().await,
+            Some(reconnect::Event::Reconnecting(_))
+        ));
+        assert!(matches!(
+            rx.recv().await,
+            Some(reconnect::Event::Item(Ok(_)))
+        ));
+        assert!(rx.recv().await.is_none());
+
+        // Only MarketEvents are published
+        while publisher.0.lock().unwrap().is_empty() {
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        }
+        assert_eq!(*publisher.0.lock().unwrap(), vec![("public_trades", 100.0)]);
+    }
+
+    #[tokio::test]
+    async fn test_publish_streams_drops_newest_when_publisher_lags() {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let streams = Streams {
+            streams: FnvHashMap::from_iter([(ExchangeId::BinanceSpot, rx)]),
+        };
+
+        // Publisher blocks until released, so the publish channel fills up
+        let (release_tx, release_rx) = std::sync::mpsc::channel::<()>();
+        let release_rx = Arc::new(Mutex::new(release_rx));
+        let publisher = MockPublisher::default();
+        let blocking = BlockingPublisher {
+            inner: publisher.clone(),
+            release_rx,
+        };
+        let mut streams = publish_streams(streams, "public_trades", blocking);
+
+        let total = DEFAULT_PUBLISH_CHANNEL_CAPACITY + 100;
+        for price in 0..total {
+            tx.send(reconnect::Event::Item(Ok(trade(price as f64))))
+                .unwrap();
+        }
+        drop(tx);
+
+        // Every event is still yielded by the Streams
+        let rx = streams.streams.get_mut(&ExchangeId::BinanceSpot).unwrap();
+        let mut yielded = 0;
+        while rx.recv().await.is_some() {
+            yielded += 1;
+        }
+        assert_eq!(yielded, total);
+
+        // Release the publisher, and wait for the buffered events to be published
+        drop(release_tx);
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        // At most the channel capacity, plus the event being published, were buffered
+        let published = publisher.0.lock().unwrap().len();
+        assert!(published <= DEFAULT_PUBLISH_CHANNEL_CAPACITY + 1);
+        assert!(published < total);
+    }
+
+    struct BlockingPublisher {
+        inner: MockPublisher,
+        release_rx: Arc<Mutex<std::sync::mpsc::Receiver<()>>>,
+    }
+
+    impl MarketEventPublisher<Instrument, PublicTrade> for BlockingPublisher {
+        fn publish(
+            &mut self,
+            kind: &'static str,
+            event: &MarketEvent<Instrument, PublicTrade>,
+        ) -> Result<(), DataError> {
+            // Errors once released, ie/ the sender is dropped
+            let _ = self.release_rx.lock().unwrap().recv();
+            self.inner.publish(kind, event)
+        }
+    }
+
+    fn trade(price: f64) -> MarketEvent<Instrument, PublicTrade> {
+        MarketEvent {
+            time_exchange: Utc::now(),
+            time_received: Utc::now(),
+            exchange: ExchangeId::BinanceSpot,
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            kind: PublicTrade {
+                id: "id".to_string(),
+                price,
+                amount: 1.0,
+                side: Side::Buy,
+                aggregate_count: None,
+            },
+        }
+    }
+}
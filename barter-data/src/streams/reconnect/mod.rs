@@ -1,3 +1,5 @@
+use barter_integration::stream::ExchangeTime;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 pub mod stream;
@@ -11,6 +13,18 @@ pub enum Event<Origin, T> {
     Item(T),
 }
 
+impl<Origin, T> ExchangeTime for Event<Origin, T>
+where
+    T: ExchangeTime,
+{
+    fn time_exchange(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Event::Reconnecting(_) => None,
+            Event::Item(item) => item.time_exchange(),
+        }
+    }
+}
+
 impl<Origin, T> Event<Origin, T> {
     pub fn map<F, O>(self, op: F) -> Event<Origin, O>
     where
@@ -3,7 +3,7 @@ use self::{
     validator::SubscriptionValidator,
 };
 use crate::{
-    exchange::Connector,
+    exchange::{config::ConnectorConfig, Connector},
     instrument::InstrumentData,
     subscription::{Map, Subscription, SubscriptionKind, SubscriptionMeta},
     Identifier,
@@ -11,12 +11,13 @@ use crate::{
 use async_trait::async_trait;
 use barter_integration::{
     error::SocketError,
-    protocol::websocket::{connect, WebSocket, WsMessage},
+    protocol::websocket::{connect_via, WebSocket, WsMessage},
 };
 use futures::SinkExt;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use tracing::{debug, info};
+use url::Url;
 
 /// [`SubscriptionMapper`] implementations defining how to map a
 /// collection of Barter [`Subscription`]s into exchange specific [`SubscriptionMeta`].
@@ -26,12 +27,18 @@ pub mod mapper;
 /// validate actioned [`Subscription`]s were successful.
 pub mod validator;
 
+/// [`Resubscriber`](resubscribe::Resubscriber) that actions exchange
+/// [`ResubscriptionNotice`](resubscribe::ResubscriptionNotice)s by re-subscribing the affected
+/// [`Subscription`]s mid-stream, without a full reconnect.
+pub mod resubscribe;
+
 /// Defines how to connect to a socket and subscribe to market data streams.
 #[async_trait]
 pub trait Subscriber {
     type SubMapper: SubscriptionMapper;
 
     async fn subscribe<Exchange, Instrument, Kind>(
+        config: &ConnectorConfig,
         subscriptions: &[Subscription<Exchange, Instrument, Kind>],
     ) -> Result<Subscribed<Instrument::Key>, SocketError>
     where
@@ -58,6 +65,33 @@ impl Subscriber for WebSocketSubscriber {
     type SubMapper = WebSocketSubMapper;
 
     async fn subscribe<Exchange, Instrument, Kind>(
+        config: &ConnectorConfig,
+        subscriptions: &[Subscription<Exchange, Instrument, Kind>],
+    ) -> Result<Subscribed<Instrument::Key>, SocketError>
+    where
+        Exchange: Connector + Send + Sync,
+        Kind: SubscriptionKind + Send + Sync,
+        Instrument: InstrumentData,
+        Subscription<Exchange, Instrument, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        let url = Exchange::url_for(config.environment)?;
+        debug!(exchange = %Exchange::ID, environment = %config.environment, "selected exchange environment");
+
+        Self::subscribe_url(config, url, subscriptions).await
+    }
+}
+
+impl WebSocketSubscriber {
+    /// Connect to the provided exchange server [`Url`] (via the [`ConnectorConfig`] proxy, if
+    /// any), and action the provided [`Subscription`]s over the socket.
+    ///
+    /// Used by [`Subscriber`]s that must derive the [`Url`] at connection time (eg/ from an
+    /// exchange REST endpoint issuing connection tokens), rather than from
+    /// [`Connector::url_for`].
+    pub async fn subscribe_url<Exchange, Instrument, Kind>(
+        config: &ConnectorConfig,
+        url: Url,
         subscriptions: &[Subscription<Exchange, Instrument, Kind>],
     ) -> Result<Subscribed<Instrument::Key>, SocketError>
     where
@@ -69,18 +103,17 @@ impl Subscriber for WebSocketSubscriber {
     {
         // Define variables for logging ergonomics
         let exchange = Exchange::ID;
-        let url = Exchange::url()?;
         debug!(%exchange, %url, ?subscriptions, "subscribing to WebSocket");
 
         // Connect to exchange
-        let mut websocket = connect(url).await?;
+        let mut websocket = connect_via(url, config.proxy.as_ref()).await?;
         debug!(%exchange, ?subscriptions, "connected to WebSocket");
 
         // Map &[Subscription<Exchange, Kind>] to SubscriptionMeta
         let SubscriptionMeta {
             instrument_map,
             ws_subscriptions,
-        } = Self::SubMapper::map::<Exchange, Instrument, Kind>(subscriptions);
+        } = <Self as Subscriber>::SubMapper::map::<Exchange, Instrument, Kind>(subscriptions);
 
         // Send Subscriptions over WebSocket
         for subscription in ws_subscriptions {
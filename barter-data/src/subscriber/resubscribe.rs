@@ -0,0 +1,311 @@
+use crate::{
+    exchange::{subscription::ExchangeSub, Connector},
+    instrument::InstrumentData,
+    subscription::{Subscription, SubscriptionKind},
+    Identifier,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::{
+    protocol::websocket::{WsError, WsMessage, WsStream},
+    subscription::SubscriptionId,
+    Validator,
+};
+use futures::{Stream, StreamExt};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::sync::mpsc;
+use tracing::{debug, info, warn};
+
+/// Exchange control message notifying that actioned [`Subscription`]s have been invalidated
+/// mid-stream, and must be re-subscribed (eg/ "please resubscribe", "subscription invalidated").
+///
+/// See [`Connector::resubscription_notice`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum ResubscriptionNotice {
+    /// Every [`Subscription`] actioned over the connection is affected.
+    All,
+    /// Only the [`Subscription`]s with the provided [`SubscriptionId`]s are affected.
+    Subscriptions(Vec<SubscriptionId>),
+}
+
+/// Outcome of a [`Resubscriber`] processing a [`WsMessage`] received from the exchange.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum Intercept {
+    /// [`WsMessage`] is not a control message, and should be forwarded downstream.
+    Forward,
+    /// [`WsMessage`] is a [`ResubscriptionNotice`], and the provided subscription [`WsMessage`]s
+    /// should be sent to the exchange.
+    Resubscribe(Vec<WsMessage>),
+    /// [`WsMessage`] is a response to a resubscription request, and should be skipped.
+    Skip,
+}
+
+/// Recognises exchange [`ResubscriptionNotice`]s, and generates the subscription [`WsMessage`]s
+/// required to re-subscribe the affected [`Subscription`]s only, without a full reconnect.
+#[derive(Clone, Debug)]
+pub struct Resubscriber {
+    exchange: ExchangeId,
+    notice: fn(&WsMessage) -> Option<ResubscriptionNotice>,
+    is_subscription_response: fn(&WsMessage) -> bool,
+    requests: Vec<(SubscriptionId, Vec<WsMessage>)>,
+    pending_responses: usize,
+}
+
+impl Resubscriber {
+    /// Construct a new [`Resubscriber`] for the provided [`Subscription`]s, pre-generating the
+    /// subscription [`WsMessage`]s for each one.
+    pub fn new<Exchange, Instrument, Kind>(
+        subscriptions: &[Subscription<Exchange, Instrument, Kind>],
+    ) -> Self
+    where
+        Exchange: Connector,
+        Instrument: InstrumentData,
+        Kind: SubscriptionKind,
+        Subscription<Exchange, Instrument, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        let mut requests = subscriptions
+            .iter()
+            .map(|subscription| {
+                let exchange_sub =
+                    ExchangeSub::<Exchange::Channel, Exchange::Market>::new(subscription);
+                (exchange_sub.id(), Exchange::requests(vec![exchange_sub]))
+            })
+            .collect::<Vec<_>>();
+
+        requests.sort_by(|(a, _), (b, _)| a.cmp(b));
+        requests.dedup_by(|(a, _), (b, _)| a == b);
+
+        Self {
+            exchange: Exchange::ID,
+            notice: Exchange::resubscription_notice,
+            is_subscription_response: is_subscription_response::<Exchange>,
+            requests,
+            pending_responses: 0,
+        }
+    }
+
+    /// Process a [`WsMessage`] received from the exchange, determining if it should be forwarded
+    /// downstream, or if it is a control message that has been handled.
+    pub fn intercept(&mut self, message: &WsMessage) -> Intercept {
+        if let Some(notice) = (self.notice)(message) {
+            return self.resubscribe(notice);
+        }
+
+        if self.pending_responses > 0 && (self.is_subscription_response)(message) {
+            self.pending_responses -= 1;
+            debug!(
+                exchange = %self.exchange,
+                payload = ?message,
+                "received resubscription response"
+            );
+            return Intercept::Skip;
+        }
+
+        Intercept::Forward
+    }
+
+    fn resubscribe(&mut self, notice: ResubscriptionNotice) -> Intercept {
+        let requests = match &notice {
+            ResubscriptionNotice::All => self.requests.iter().collect::<Vec<_>>(),
+            ResubscriptionNotice::Subscriptions(ids) => self
+                .requests
+                .iter()
+                .filter(|(id, _)| ids.contains(id))
+                .collect(),
+        };
+
+        if requests.is_empty() {
+            warn!(
+                exchange = %self.exchange,
+                ?notice,
+                "received ResubscriptionNotice for unknown Subscriptions"
+            );
+            return Intercept::Skip;
+        }
+
+        info!(
+            exchange = %self.exchange,
+            ?notice,
+            subscriptions = requests.len(),
+            "received ResubscriptionNotice, re-subscribing affected Subscriptions"
+        );
+
+        self.pending_responses += requests.len();
+        Intercept::Resubscribe(
+            requests
+                .into_iter()
+                .flat_map(|(_, messages)| messages.iter().cloned())
+                .collect(),
+        )
+    }
+}
+
+/// Determine if the [`WsMessage`] is a successful [`Connector::SubResponse`].
+fn is_subscription_response<Exchange>(message: &WsMessage) -> bool
+where
+    Exchange: Connector,
+{
+    match message {
+        WsMessage::Text(text) => serde_json::from_str::<Exchange::SubResponse>(text)
+            .is_ok_and(|response| response.validate().is_ok()),
+        _ => false,
+    }
+}
+
+/// [`WsStream`] wrapper that uses a [`Resubscriber`] to action exchange
+/// [`ResubscriptionNotice`]s, sending targeted resubscription requests via the provided
+/// [`WsMessage`] transmitter.
+#[derive(Debug)]
+pub struct ResubscribingWsStream {
+    stream: WsStream,
+    resubscriber: Resubscriber,
+    ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+}
+
+impl ResubscribingWsStream {
+    pub fn new(
+        stream: WsStream,
+        resubscriber: Resubscriber,
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+    ) -> Self {
+        Self {
+            stream,
+            resubscriber,
+            ws_sink_tx,
+        }
+    }
+}
+
+impl Stream for ResubscribingWsStream {
+    type Item = Result<WsMessage, WsError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let message = match self.stream.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(message))) => message,
+                other => return other,
+            };
+
+            match self.resubscriber.intercept(&message) {
+                Intercept::Forward => return Poll::Ready(Some(Ok(message))),
+                Intercept::Resubscribe(requests) => {
+                    for request in requests {
+                        if self.ws_sink_tx.send(request).is_err() {
+                            warn!(
+                                exchange = %self.resubscriber.exchange,
+                                "failed to send resubscription request since WsSink is closed"
+                            );
+                        }
+                    }
+                }
+                Intercept::Skip => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notice(message: &WsMessage) -> Option<ResubscriptionNotice> {
+        match message.to_text().ok()? {
+            "resubscribe all" => Some(ResubscriptionNotice::All),
+            "resubscribe trades" => Some(ResubscriptionNotice::Subscriptions(vec![
+                SubscriptionId::from("trades|btcusdt"),
+            ])),
+            "resubscribe unknown" => Some(ResubscriptionNotice::Subscriptions(vec![
+                SubscriptionId::from("unknown|btcusdt"),
+            ])),
+            _ => None,
+        }
+    }
+
+    fn resubscriber() -> Resubscriber {
+        Resubscriber {
+            exchange: ExchangeId::BinanceSpot,
+            notice,
+            is_subscription_response: |message| message.to_text().ok() == Some("subscribed"),
+            requests: vec![
+                (
+                    SubscriptionId::from("book|btcusdt"),
+                    vec![WsMessage::text("subscribe book")],
+                ),
+                (
+                    SubscriptionId::from("trades|btcusdt"),
+                    vec![WsMessage::text("subscribe trades")],
+                ),
+            ],
+            pending_responses: 0,
+        }
+    }
+
+    #[test]
+    fn test_resubscriber_intercept() {
+        struct TestCase {
+            input: WsMessage,
+            expected: Intercept,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: market data is forwarded
+                input: WsMessage::text("trade"),
+                expected: Intercept::Forward,
+            },
+            TestCase {
+                // TC1: subscription response forwarded since no resubscription is pending
+                input: WsMessage::text("subscribed"),
+                expected: Intercept::Forward,
+            },
+            TestCase {
+                // TC2: targeted resubscription
+                input: WsMessage::text("resubscribe trades"),
+                expected: Intercept::Resubscribe(vec![WsMessage::text("subscribe trades")]),
+            },
+            TestCase {
+                // TC3: pending resubscription response is skipped
+                input: WsMessage::text("subscribed"),
+                expected: Intercept::Skip,
+            },
+            TestCase {
+                // TC4: notice for unknown Subscriptions is skipped
+                input: WsMessage::text("resubscribe unknown"),
+                expected: Intercept::Skip,
+            },
+            TestCase {
+                // TC5: resubscribe every Subscription
+                input: WsMessage::text("resubscribe all"),
+                expected: Intercept::Resubscribe(vec![
+                    WsMessage::text("subscribe book"),
+                    WsMessage::text("subscribe trades"),
+                ]),
+            },
+            TestCase {
+                // TC6: first pending resubscription response is skipped
+                input: WsMessage::text("subscribed"),
+                expected: Intercept::Skip,
+            },
+            TestCase {
+                // TC7: second pending resubscription response is skipped
+                input: WsMessage::text("subscribed"),
+                expected: Intercept::Skip,
+            },
+            TestCase {
+                // TC8: no more pending resubscription responses
+                input: WsMessage::text("subscribed"),
+                expected: Intercept::Forward,
+            },
+        ];
+
+        let mut resubscriber = resubscriber();
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = resubscriber.intercept(&test.input);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
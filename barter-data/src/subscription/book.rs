@@ -4,6 +4,7 @@ use barter_macro::{DeSubKind, SerSubKind};
 use chrono::{DateTime, Utc};
 use derive_more::Display;
 use rust_decimal::Decimal;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields level 1 [`OrderBook`]
@@ -35,7 +36,7 @@ impl SubscriptionKind for OrderBooksL1 {
 }
 
 /// Normalised Barter [`OrderBookL1`] snapshot containing the latest best bid and ask.
-#[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize, Serialize, JsonSchema)]
 pub struct OrderBookL1 {
     pub last_update_time: DateTime<Utc>,
     pub best_bid: Level,
@@ -115,7 +116,7 @@ impl SubscriptionKind for OrderBooksL3 {
     }
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize, JsonSchema)]
 pub enum OrderBookEvent {
     Snapshot(OrderBook),
     Update(OrderBook),
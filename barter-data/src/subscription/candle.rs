@@ -1,6 +1,7 @@
 use super::SubscriptionKind;
 use chrono::{DateTime, Utc};
 use derive_more::Display;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields [`Candle`]
@@ -30,7 +31,7 @@ impl SubscriptionKind for Candles {
 }
 
 /// Normalised Barter OHLCV [`Candle`] model.
-#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize, JsonSchema)]
 pub struct Candle {
     pub close_time: DateTime<Utc>,
     pub open: f64,
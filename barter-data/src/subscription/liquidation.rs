@@ -2,6 +2,7 @@ use super::SubscriptionKind;
 use barter_integration::Side;
 use chrono::{DateTime, Utc};
 use derive_more::Display;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields [`Liquidation`]
@@ -31,7 +32,7 @@ impl SubscriptionKind for Liquidations {
 }
 
 /// Normalised Barter [`Liquidation`] model.
-#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize, JsonSchema)]
 pub struct Liquidation {
     pub side: Side,
     pub price: f64,
@@ -1,4 +1,5 @@
-use crate::{exchange::Connector, instrument::InstrumentData};
+use self::params::SubscriptionParams;
+use crate::{exchange::Connector, instrument::InstrumentData, Identifier};
 use barter_instrument::{
     asset::symbol::Symbol,
     exchange::ExchangeId,
@@ -23,6 +24,13 @@ pub mod candle;
 /// Liquidation [`SubscriptionKind`] and the associated Barter output data model.
 pub mod liquidation;
 
+/// Typed [`Subscription`] parameters (eg/ OrderBook depth, update speed).
+pub mod params;
+
+/// Derivatives status (funding, mark price, etc.) [`SubscriptionKind`] and the associated
+/// Barter output data model.
+pub mod status;
+
 /// Public trade [`SubscriptionKind`] and the associated Barter output data model.
 pub mod trade;
 
@@ -44,6 +52,8 @@ pub struct Subscription<Exchange = ExchangeId, Inst = Instrument, Kind = SubKind
     pub instrument: Inst,
     #[serde(alias = "type")]
     pub kind: Kind,
+    #[serde(default, skip_serializing_if = "SubscriptionParams::is_default")]
+    pub params: SubscriptionParams,
 }
 
 #[derive(
@@ -122,28 +132,38 @@ impl<Instrument, Exchange, Kind> Subscription<Exchange, Instrument, Kind> {
             exchange,
             instrument: instrument.into(),
             kind,
+            params: SubscriptionParams::default(),
         }
     }
+
+    /// Use the provided [`SubscriptionParams`] for this [`Subscription`].
+    pub fn with_params(self, params: SubscriptionParams) -> Self {
+        Self { params, ..self }
+    }
 }
 
 impl<Exchange, Instrument, Kind> Validator for Subscription<Exchange, Instrument, Kind>
 where
     Exchange: Connector,
     Instrument: InstrumentData,
+    Subscription<Exchange, Instrument, Kind>: Identifier<Exchange::Channel>,
 {
     fn validate(self) -> Result<Self, SocketError>
     where
         Self: Sized,
     {
         // Validate the Exchange supports the Subscription InstrumentKind
-        if exchange_supports_instrument_kind(Exchange::ID, self.instrument.kind()) {
-            Ok(self)
-        } else {
-            Err(SocketError::Unsupported {
+        if !exchange_supports_instrument_kind(Exchange::ID, self.instrument.kind()) {
+            return Err(SocketError::Unsupported {
                 entity: Exchange::ID.to_string(),
                 item: self.instrument.kind().to_string(),
-            })
+            });
         }
+
+        // Validate the Exchange supports the Subscription params for the associated channel
+        Exchange::validate_params(&Identifier::<Exchange::Channel>::id(&self), &self.params)?;
+
+        Ok(self)
     }
 }
 
@@ -172,7 +192,7 @@ pub fn exchange_supports_instrument_kind(
 
         // Perpetual
         (
-            BinanceFuturesUsd | Bitmex | Okx | BybitPerpetualsUsd | GateioPerpetualsUsd
+            BinanceFuturesUsd | Bitfinex | Bitmex | Okx | BybitPerpetualsUsd | GateioPerpetualsUsd
             | GateioPerpetualsBtc,
             Perpetual,
         ) => true,
@@ -193,18 +213,27 @@ where
         Self: Sized,
     {
         // Validate the Exchange supports the Subscription InstrumentKind
-        if exchange_supports_instrument_kind_sub_kind(
+        if !exchange_supports_instrument_kind_sub_kind(
             &self.exchange,
             self.instrument.kind(),
             self.kind,
         ) {
-            Ok(self)
-        } else {
-            Err(SocketError::Unsupported {
+            return Err(SocketError::Unsupported {
                 entity: self.exchange.to_string(),
                 item: self.instrument.kind().to_string(),
-            })
+            });
+        }
+
+        // Exchange specific SubscriptionParams are only supported by typed Subscriptions, since
+        // they are validated by the exchange Connector
+        if !self.params.is_default() {
+            return Err(SocketError::Unsupported {
+                entity: self.exchange.to_string(),
+                item: format!("{:?}", self.params),
+            });
         }
+
+        Ok(self)
     }
 }
 
@@ -222,19 +251,26 @@ pub fn exchange_supports_instrument_kind_sub_kind(
     match (exchange_id, instrument_kind, sub_kind) {
         (BinanceSpot, Spot, PublicTrades | OrderBooksL1) => true,
         (BinanceFuturesUsd, Perpetual, PublicTrades | OrderBooksL1 | Liquidations) => true,
-        (Bitfinex, Spot, PublicTrades) => true,
-        (Bitmex, Perpetual, PublicTrades) => true,
+        (Bitfinex, Spot | Perpetual, PublicTrades | OrderBooksL2) => true,
+        (Bithumb, Spot, PublicTrades | OrderBooksL2) => true,
+        (Bitmex, Perpetual, PublicTrades | Liquidations) => true,
+        (Bitstamp, Spot, PublicTrades | OrderBooksL2) => true,
         (BybitSpot, Spot, PublicTrades) => true,
-        (BybitPerpetualsUsd, Perpetual, PublicTrades) => true,
+        (BybitPerpetualsUsd, Perpetual, PublicTrades | Liquidations) => true,
         (Coinbase, Spot, PublicTrades) => true,
-        (GateioSpot, Spot, PublicTrades) => true,
+        (Dydx, Perpetual, PublicTrades | OrderBooksL2) => true,
+        (GateioSpot, Spot, PublicTrades | OrderBooksL1 | OrderBooksL2) => true,
         (GateioFuturesUsd, Future(_), PublicTrades) => true,
         (GateioFuturesBtc, Future(_), PublicTrades) => true,
-        (GateioPerpetualsUsd, Perpetual, PublicTrades) => true,
-        (GateioPerpetualsBtc, Perpetual, PublicTrades) => true,
+        (GateioPerpetualsUsd, Perpetual, PublicTrades | OrderBooksL1 | OrderBooksL2) => true,
+        (GateioPerpetualsBtc, Perpetual, PublicTrades | OrderBooksL1 | OrderBooksL2) => true,
         (GateioOptions, Option(_), PublicTrades) => true,
-        (Kraken, Spot, PublicTrades | OrderBooksL1) => true,
-        (Okx, Spot | Future(_) | Perpetual | Option(_), PublicTrades) => true,
+        (Gemini, Spot, PublicTrades | OrderBooksL2) => true,
+        (Kraken, Spot, PublicTrades | OrderBooksL1 | OrderBooksL2) => true,
+        (Kucoin, Spot, PublicTrades | OrderBooksL1 | OrderBooksL2) => true,
+        (Mexc, Spot, PublicTrades | OrderBooksL1 | OrderBooksL2) => true,
+        (Okx, Spot | Future(_) | Perpetual | Option(_), PublicTrades | Liquidations) => true,
+        (Upbit, Spot, PublicTrades | OrderBooksL2) => true,
 
         (_, _, _) => false,
     }
@@ -299,7 +335,7 @@ mod tests {
         use super::*;
         use crate::{
             exchange::{coinbase::Coinbase, okx::Okx},
-            subscription::trade::PublicTrades,
+            subscription::{book::OrderBooksL2, trade::PublicTrades},
         };
         use barter_instrument::instrument::Instrument;
 
@@ -499,6 +535,114 @@ mod tests {
                 }
             }
         }
+
+        #[test]
+        fn test_validate_binance_futures_usd_order_books_l2_params() {
+            use crate::exchange::binance::{channel::BinanceChannel, futures::BinanceFuturesUsd};
+
+            struct TestCase {
+                input: SubscriptionParams,
+                expected: Option<BinanceChannel>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: default params use the 100ms channel
+                    input: SubscriptionParams::default(),
+                    expected: Some(BinanceChannel::ORDER_BOOK_L2),
+                },
+                TestCase {
+                    // TC1: supported 500ms update speed & depth
+                    input: SubscriptionParams {
+                        depth: Some(1000),
+                        update_speed_ms: Some(500),
+                        aggregated_trades: None,
+                    },
+                    expected: Some(BinanceChannel::ORDER_BOOK_L2_500MS),
+                },
+                TestCase {
+                    // TC2: supported 250ms standard update speed
+                    input: SubscriptionParams::update_speed_ms(250),
+                    expected: Some(BinanceChannel::ORDER_BOOK_L2_STANDARD),
+                },
+                TestCase {
+                    // TC3: unsupported 1000ms update speed
+                    input: SubscriptionParams::update_speed_ms(1000),
+                    expected: None,
+                },
+                TestCase {
+                    // TC4: unsupported depth
+                    input: SubscriptionParams::depth(250),
+                    expected: None,
+                },
+                TestCase {
+                    // TC5: aggregated trades are unsupported for OrderBook Level2
+                    input: SubscriptionParams::aggregated_trades(),
+                    expected: None,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = Subscription::from((
+                    BinanceFuturesUsd::default(),
+                    "base",
+                    "quote",
+                    InstrumentKind::Perpetual,
+                    OrderBooksL2,
+                ))
+                .with_params(test.input)
+                .validate()
+                .ok()
+                .map(|subscription| Identifier::<BinanceChannel>::id(&subscription));
+
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+
+        #[test]
+        fn test_validate_binance_public_trades_params() {
+            use crate::exchange::binance::{channel::BinanceChannel, spot::BinanceSpot};
+
+            struct TestCase {
+                input: &'static str,
+                expected: Option<BinanceChannel>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: default params use the raw trade channel
+                    input: r#"{"exchange": "binance_spot", "base": "btc", "quote": "usdt", "instrument_kind": "spot", "kind": "public_trades"}"#,
+                    expected: Some(BinanceChannel::TRADES),
+                },
+                TestCase {
+                    // TC1: aggregated trades use the aggTrade channel
+                    input: r#"{"exchange": "binance_spot", "base": "btc", "quote": "usdt", "instrument_kind": "spot", "kind": "public_trades", "params": {"aggregated_trades": true}}"#,
+                    expected: Some(BinanceChannel::AGG_TRADES),
+                },
+                TestCase {
+                    // TC2: explicitly raw trades use the raw trade channel
+                    input: r#"{"exchange": "binance_spot", "base": "btc", "quote": "usdt", "instrument_kind": "spot", "kind": "public_trades", "params": {"aggregated_trades": false}}"#,
+                    expected: Some(BinanceChannel::TRADES),
+                },
+                TestCase {
+                    // TC3: depth is unsupported for PublicTrades
+                    input: r#"{"exchange": "binance_spot", "base": "btc", "quote": "usdt", "instrument_kind": "spot", "kind": "public_trades", "params": {"aggregated_trades": true, "depth": 20}}"#,
+                    expected: None,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<
+                    Subscription<BinanceSpot, Instrument, PublicTrades>,
+                >(test.input)
+                .unwrap()
+                .validate()
+                .ok()
+                .map(|subscription| Identifier::<BinanceChannel>::id(&subscription));
+
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
     }
 
     mod instrument_map {
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+/// Optional typed [`Subscription`](super::Subscription) parameters (eg/ OrderBook depth), used
+/// to configure the exchange channel subscribed to.
+///
+/// Each exchange [`Connector`](crate::exchange::Connector) validates the parameters it supports
+/// via [`Connector::validate_params`](crate::exchange::Connector::validate_params). The default
+/// [`SubscriptionParams`] (ie/ `None` for every parameter) use the exchange defaults, and are
+/// supported by every exchange.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct SubscriptionParams {
+    /// Number of OrderBook price levels of each side (eg/ 20).
+    #[serde(default)]
+    pub depth: Option<u32>,
+
+    /// Interval between exchange updates in milliseconds (eg/ 100).
+    #[serde(default)]
+    pub update_speed_ms: Option<u32>,
+
+    /// Serve `PublicTrades` from the exchange aggregate trade stream (eg/ Binance "aggTrade"),
+    /// where trades filled at the same time, price & side from a single taker order are
+    /// published as one trade.
+    #[serde(default)]
+    pub aggregated_trades: Option<bool>,
+}
+
+impl SubscriptionParams {
+    /// Construct [`SubscriptionParams`] with the provided OrderBook depth.
+    pub fn depth(depth: u32) -> Self {
+        Self {
+            depth: Some(depth),
+            ..Self::default()
+        }
+    }
+
+    /// Construct [`SubscriptionParams`] with the provided update speed in milliseconds.
+    pub fn update_speed_ms(update_speed_ms: u32) -> Self {
+        Self {
+            update_speed_ms: Some(update_speed_ms),
+            ..Self::default()
+        }
+    }
+
+    /// Construct [`SubscriptionParams`] that serve `PublicTrades` from the exchange aggregate
+    /// trade stream.
+    pub fn aggregated_trades() -> Self {
+        Self {
+            aggregated_trades: Some(true),
+            ..Self::default()
+        }
+    }
+
+    /// Determine if these [`SubscriptionParams`] only use exchange defaults.
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
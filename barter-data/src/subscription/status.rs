@@ -0,0 +1,42 @@
+use super::SubscriptionKind;
+use chrono::{DateTime, Utc};
+use derive_more::Display;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields
+/// [`DerivativesStatus`] [`MarketEvent<T>`](crate::event::MarketEvent) events.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Debug,
+    Default,
+    Deserialize,
+    Serialize,
+    Display,
+)]
+pub struct DerivativesStatuses;
+
+impl SubscriptionKind for DerivativesStatuses {
+    type Event = DerivativesStatus;
+
+    fn as_str(&self) -> &'static str {
+        "derivatives_statuses"
+    }
+}
+
+/// Normalised Barter [`DerivativesStatus`] model, describing the funding & pricing state of a
+/// derivative instrument (eg/ a perpetual).
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize, JsonSchema)]
+pub struct DerivativesStatus {
+    pub mark_price: f64,
+    pub index_price: f64,
+    pub funding_rate: f64,
+    pub next_funding_time: Option<DateTime<Utc>>,
+    pub open_interest: Option<f64>,
+}
@@ -2,6 +2,7 @@ use super::SubscriptionKind;
 use barter_integration::Side;
 use barter_macro::{DeSubKind, SerSubKind};
 use derive_more::Display;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields [`PublicTrade`]
@@ -31,10 +32,22 @@ impl SubscriptionKind for PublicTrades {
 }
 
 /// Normalised Barter [`PublicTrade`] model.
-#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize, JsonSchema)]
 pub struct PublicTrade {
     pub id: String,
     pub price: f64,
     pub amount: f64,
     pub side: Side,
+    /// Number of exchange trades aggregated into this [`PublicTrade`] (eg/ a Binance
+    /// "aggTrade"), or `None` if it is a single raw exchange trade.
+    #[serde(default)]
+    pub aggregate_count: Option<u64>,
+}
+
+impl PublicTrade {
+    /// Determine if this [`PublicTrade`] aggregates one or more exchange trades, rather than
+    /// being a single raw exchange trade.
+    pub fn is_aggregated(&self) -> bool {
+        self.aggregate_count.is_some()
+    }
 }
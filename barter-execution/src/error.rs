@@ -1,8 +1,13 @@
 use crate::model::{order::OrderKind, ClientOrderId};
-use barter_instrument::asset::symbol::Symbol;
+use barter_instrument::{asset::symbol::Symbol, exchange::ExchangeId};
+use barter_integration::{environment::Environment, error::SocketError};
+use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+/// All errors generated in `barter-execution`. Each variant has an explicit [`ErrorClass`]
+/// (see [`ExecutionError::class`]) that determines whether a failed request should be retried,
+/// abandoned, or the client shut down.
 #[derive(Error, PartialEq, Eq, PartialOrd, Debug, Clone, Deserialize, Serialize)]
 pub enum ExecutionError {
     #[error("failed to build component due to missing attributes: {0}")]
@@ -17,6 +22,220 @@ pub enum ExecutionError {
     #[error("failed to find Order with ClientOrderId: {0}")]
     OrderNotFound(ClientOrderId),
 
+    #[error("Order with ClientOrderId {0} has already been submitted")]
+    DuplicateClientOrderId(ClientOrderId),
+
+    #[error("failed to persist ClientOrderId: {0}")]
+    ClientOrderIdStore(String),
+
     #[error("failed to open Order due to unsupported OrderKind: {0}")]
     UnsupportedOrderKind(OrderKind),
+
+    #[error("{0} does not support combo orders")]
+    UnsupportedComboOrders(ExchangeId),
+
+    #[error("invalid combo order: {0}")]
+    InvalidComboOrder(String),
+
+    #[error("combo order with ClientOrderId {0} is not marketable at it's net price")]
+    ComboNotMarketable(ClientOrderId),
+
+    #[error("{0} does not support transfers")]
+    UnsupportedTransfer(ExchangeId),
+
+    #[error("{0} does not support withdrawals")]
+    UnsupportedWithdraw(ExchangeId),
+
+    #[error("invalid transfer: {0}")]
+    InvalidTransfer(String),
+
+    #[error("{0} does not support margin configuration")]
+    UnsupportedMargin(ExchangeId),
+
+    #[error("{0} does not support fetching trade history")]
+    UnsupportedTradeHistory(ExchangeId),
+
+    #[error("invalid margin configuration: {0}")]
+    InvalidMargin(String),
+
+    #[error("connectivity error: {0}")]
+    Connectivity(String),
+
+    #[error("authentication failed: {0}")]
+    Authentication(String),
+
+    #[error("rate limited")]
+    RateLimited,
+
+    #[error("failed to deserialise exchange response: {0}")]
+    Deserialise(String),
+
+    #[error("failed to construct exchange request: {0}")]
+    Request(String),
+
+    #[error("invalid order parameters: {0}")]
+    InvalidOrderParams(String),
+
+    #[error("{0} is undergoing maintenance")]
+    VenueMaintenance(ExchangeId),
+
+    #[error("{exchange} does not support the {environment} environment")]
+    UnsupportedEnvironment {
+        exchange: ExchangeId,
+        environment: Environment,
+    },
+}
+
+/// Classification of an [`ExecutionError`], determining how the caller should react to it.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub enum ErrorClass {
+    /// Transient failure (eg/ connectivity, rate limit, maintenance) - the request may succeed if
+    /// retried later.
+    Retryable,
+    /// Request was rejected (eg/ invalid parameters, insufficient balance) - retrying the same
+    /// request will fail again, but the client remains usable.
+    Rejected,
+    /// Client is unusable (eg/ invalid credentials, incomplete configuration) - retrying any
+    /// request will fail, so the client (and any engine depending on it) should shut down.
+    Unrecoverable,
+}
+
+impl ExecutionError {
+    /// [`ErrorClass`] of the [`ExecutionError`].
+    pub fn class(&self) -> ErrorClass {
+        match self {
+            ExecutionError::Connectivity(_)
+            | ExecutionError::RateLimited
+            | ExecutionError::VenueMaintenance(_) => ErrorClass::Retryable,
+
+            ExecutionError::Simulated(_)
+            | ExecutionError::InsufficientBalance(_)
+            | ExecutionError::OrderNotFound(_)
+            | ExecutionError::DuplicateClientOrderId(_)
+            | ExecutionError::UnsupportedOrderKind(_)
+            | ExecutionError::UnsupportedComboOrders(_)
+            | ExecutionError::InvalidComboOrder(_)
+            | ExecutionError::ComboNotMarketable(_)
+            | ExecutionError::UnsupportedTransfer(_)
+            | ExecutionError::UnsupportedWithdraw(_)
+            | ExecutionError::InvalidTransfer(_)
+            | ExecutionError::UnsupportedMargin(_)
+            | ExecutionError::InvalidMargin(_)
+            | ExecutionError::UnsupportedTradeHistory(_)
+            | ExecutionError::InvalidOrderParams(_) => ErrorClass::Rejected,
+
+            ExecutionError::BuilderIncomplete(_)
+            | ExecutionError::ClientOrderIdStore(_)
+            | ExecutionError::Authentication(_)
+            | ExecutionError::Deserialise(_)
+            | ExecutionError::Request(_)
+            | ExecutionError::UnsupportedEnvironment { .. } => ErrorClass::Unrecoverable,
+        }
+    }
+
+    /// Determine if the failed request may succeed if retried later.
+    pub fn is_retryable(&self) -> bool {
+        self.class() == ErrorClass::Retryable
+    }
+
+    /// Determine if the client is unusable, and should be shut down.
+    pub fn is_unrecoverable(&self) -> bool {
+        self.class() == ErrorClass::Unrecoverable
+    }
+}
+
+impl From<SocketError> for ExecutionError {
+    fn from(error: SocketError) -> Self {
+        match error {
+            SocketError::HttpResponse(StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN, body) => {
+                ExecutionError::Authentication(body)
+            }
+            SocketError::HttpResponse(StatusCode::TOO_MANY_REQUESTS, _) => {
+                ExecutionError::RateLimited
+            }
+            SocketError::HttpResponse(status, body)
+                if status == StatusCode::BAD_REQUEST
+                    || status == StatusCode::UNPROCESSABLE_ENTITY =>
+            {
+                ExecutionError::InvalidOrderParams(body)
+            }
+            error @ (SocketError::Deserialise { .. }
+            | SocketError::DeserialiseBinary { .. }
+            | SocketError::DecodeBinary { .. }) => ExecutionError::Deserialise(error.to_string()),
+            error @ (SocketError::Serialise(_)
+            | SocketError::QueryParams(_)
+            | SocketError::UrlEncoded(_)
+            | SocketError::UrlParse(_)
+            | SocketError::Sign(_)) => ExecutionError::Request(error.to_string()),
+            error => ExecutionError::Connectivity(error.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_execution_error_from_socket_error() {
+        struct TestCase {
+            input: SocketError,
+            expected: ExecutionError,
+            expected_class: ErrorClass,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: 401 Unauthorized is an unrecoverable authentication error
+                input: SocketError::HttpResponse(StatusCode::UNAUTHORIZED, "bad key".to_string()),
+                expected: ExecutionError::Authentication("bad key".to_string()),
+                expected_class: ErrorClass::Unrecoverable,
+            },
+            TestCase {
+                // TC1: 429 Too Many Requests is a retryable rate limit
+                input: SocketError::HttpResponse(StatusCode::TOO_MANY_REQUESTS, String::new()),
+                expected: ExecutionError::RateLimited,
+                expected_class: ErrorClass::Retryable,
+            },
+            TestCase {
+                // TC2: 400 Bad Request is a rejected invalid order
+                input: SocketError::HttpResponse(StatusCode::BAD_REQUEST, "bad qty".to_string()),
+                expected: ExecutionError::InvalidOrderParams("bad qty".to_string()),
+                expected_class: ErrorClass::Rejected,
+            },
+            TestCase {
+                // TC3: other SocketErrors are retryable connectivity errors
+                input: SocketError::Sink,
+                expected: ExecutionError::Connectivity("Sink error".to_string()),
+                expected_class: ErrorClass::Retryable,
+            },
+            TestCase {
+                // TC4: failing to deserialise a response is unrecoverable
+                input: SocketError::Deserialise {
+                    error: serde_json::from_str::<u64>("{").unwrap_err(),
+                    payload: "{".to_string(),
+                },
+                expected: ExecutionError::Deserialise(
+                    "Deserialising JSON error: invalid type: map, expected u64 at line 1 column 0 \
+                     for payload: {"
+                        .to_string(),
+                ),
+                expected_class: ErrorClass::Unrecoverable,
+            },
+            TestCase {
+                // TC5: failing to sign a request is unrecoverable
+                input: SocketError::Sign("invalid secret".to_string()),
+                expected: ExecutionError::Request(
+                    "request signing error: invalid secret".to_string(),
+                ),
+                expected_class: ErrorClass::Unrecoverable,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = ExecutionError::from(test.input);
+            assert_eq!(actual.class(), test.expected_class, "TC{} failed", index);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
 }
@@ -0,0 +1,320 @@
+use super::{
+    indexer::{
+        dydx_instrument, DydxIndexerOrder, DydxOrderStatus, DydxSubaccount, DYDX_COLLATERAL_SYMBOL,
+    },
+    order::{DydxClientIds, DydxSubaccountId},
+};
+use crate::{
+    model::{
+        order::{Cancelled, OrderId},
+        trade::{SymbolFees, Trade, TradeId},
+        AccountEvent, AccountEventKind,
+    },
+    Order,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::{
+    error::SocketError,
+    protocol::websocket::{connect, WsMessage},
+    Side,
+};
+use chrono::Utc;
+use futures::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use smol_str::SmolStr;
+use std::{collections::HashMap, time::Duration};
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+/// Delay before reconnecting the dYdX v4 indexer account WebSocket after a disconnection.
+pub const ACCOUNT_STREAM_RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// dYdX v4 indexer `v4_subaccounts` channel message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_websocket#subaccounts>
+/// #### Channel Data
+/// ```json
+/// {
+///     "type": "channel_data",
+///     "connection_id": "e2a3ef4a-6c4f-4e2c-8c1f-0a4f9b8d1e0a",
+///     "message_id": 4,
+///     "id": "dydx1address/0",
+///     "channel": "v4_subaccounts",
+///     "version": "3.0.0",
+///     "contents": {
+///         "orders": [{"id": "0e0e6a2b", "clientId": "42", "clobPairId": "0", "orderFlags": "64", "side": "BUY", "size": "0.01", "totalFilled": "0.01", "price": "65000", "status": "FILLED", "ticker": "BTC-USD"}],
+///         "fills": [{"id": "5b1c", "side": "BUY", "size": "0.01", "price": "65000", "fee": "0.325", "orderId": "0e0e6a2b", "ticker": "BTC-USD"}]
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DydxAccountMessage {
+    Subscribed {
+        contents: DydxSubaccountSnapshot,
+    },
+    ChannelData {
+        contents: DydxSubaccountUpdate,
+    },
+    Error {
+        message: String,
+    },
+    #[serde(other)]
+    Other,
+}
+
+/// Initial contents of the `v4_subaccounts` channel.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DydxSubaccountSnapshot {
+    pub subaccount: DydxSubaccount,
+}
+
+/// Order & fill updates of the `v4_subaccounts` channel.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DydxSubaccountUpdate {
+    #[serde(default)]
+    pub orders: Vec<DydxIndexerOrder>,
+    #[serde(default)]
+    pub fills: Vec<DydxFill>,
+}
+
+/// dYdX v4 indexer subaccount fill, where the fee is denominated in USDC (negative for a
+/// rebate).
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DydxFill {
+    pub id: SmolStr,
+    pub side: Side,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub size: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub fee: f64,
+    #[serde(default)]
+    pub order_id: Option<SmolStr>,
+    #[serde(alias = "market")]
+    pub ticker: SmolStr,
+}
+
+/// Translates `v4_subaccounts` [`DydxAccountMessage`]s into normalised [`AccountEventKind`]s.
+///
+/// Indexer fills reference orders by their indexer id, so the Barter [`OrderId`] of each order
+/// received is tracked to attribute subsequent fills.
+#[derive(Debug)]
+pub struct DydxAccountTransformer {
+    pub subaccount_id: DydxSubaccountId,
+    pub client_ids: DydxClientIds,
+    pub order_ids: HashMap<SmolStr, OrderId>,
+}
+
+impl DydxAccountTransformer {
+    pub fn new(subaccount_id: DydxSubaccountId, client_ids: DydxClientIds) -> Self {
+        Self {
+            subaccount_id,
+            client_ids,
+            order_ids: HashMap::new(),
+        }
+    }
+
+    /// Translate the provided [`DydxAccountMessage`] into [`AccountEventKind`]s.
+    pub fn transform(&mut self, message: DydxAccountMessage) -> Vec<AccountEventKind> {
+        match message {
+            DydxAccountMessage::Subscribed { contents } => {
+                vec![AccountEventKind::Balances(vec![contents.subaccount.into()])]
+            }
+            DydxAccountMessage::ChannelData { contents } => self.transform_update(contents),
+            DydxAccountMessage::Error { message } => {
+                warn!(%message, "dYdX account WebSocket consumed error message");
+                vec![]
+            }
+            DydxAccountMessage::Other => vec![],
+        }
+    }
+
+    fn transform_update(&mut self, update: DydxSubaccountUpdate) -> Vec<AccountEventKind> {
+        let mut cancelled = Vec::new();
+        for order in update.orders {
+            let order_id = OrderId::from(order.order_id(&self.subaccount_id));
+            self.order_ids.insert(order.id.clone(), order_id);
+
+            if matches!(
+                order.status,
+                DydxOrderStatus::Canceled | DydxOrderStatus::BestEffortCanceled
+            ) {
+                let cid = self.client_ids.cid(order.client_id);
+                let open = order.into_order_open(&self.subaccount_id, cid);
+                cancelled.push(Order::<Cancelled>::from(open));
+            }
+        }
+
+        let mut events = update
+            .fills
+            .into_iter()
+            .map(|fill| {
+                let order_id = fill
+                    .order_id
+                    .map(|id| self.order_ids.get(&id).cloned().unwrap_or(OrderId(id)))
+                    .unwrap_or_else(|| OrderId::from(""));
+
+                AccountEventKind::Trade(Trade {
+                    id: TradeId(fill.id),
+                    order_id,
+                    instrument: dydx_instrument(&fill.ticker),
+                    side: fill.side,
+                    price: fill.price,
+                    quantity: fill.size,
+                    fees: SymbolFees::new(DYDX_COLLATERAL_SYMBOL, fill.fee),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if !cancelled.is_empty() {
+            events.push(AccountEventKind::OrdersCancelled(cancelled));
+        }
+
+        events
+    }
+}
+
+/// Consume the dYdX v4 indexer `v4_subaccounts` channel of the provided [`DydxSubaccountId`],
+/// sending every [`AccountEvent`] to the provided transmitter, and reconnecting after every
+/// disconnection until the receiver is dropped.
+pub async fn consume_account_events(
+    url: &'static str,
+    mut transformer: DydxAccountTransformer,
+    event_tx: mpsc::UnboundedSender<AccountEvent>,
+) {
+    while !event_tx.is_closed() {
+        if let Err(error) = consume_connection(url, &mut transformer, &event_tx).await {
+            warn!(%error, "dYdX account WebSocket disconnected - reconnecting");
+        }
+        tokio::time::sleep(ACCOUNT_STREAM_RECONNECT_DELAY).await;
+    }
+}
+
+async fn consume_connection(
+    url: &'static str,
+    transformer: &mut DydxAccountTransformer,
+    event_tx: &mpsc::UnboundedSender<AccountEvent>,
+) -> Result<(), SocketError> {
+    let mut websocket = connect(url).await?;
+
+    let subaccount_id = &transformer.subaccount_id;
+    websocket
+        .send(WsMessage::text(
+            json!({
+                "type": "subscribe",
+                "channel": "v4_subaccounts",
+                "id": format!("{}/{}", subaccount_id.owner, subaccount_id.number),
+            })
+            .to_string(),
+        ))
+        .await?;
+
+    while let Some(message) = websocket.next().await {
+        let payload = match message? {
+            WsMessage::Text(payload) => payload,
+            WsMessage::Close(frame) => {
+                return Err(SocketError::Terminated(format!("{frame:?}")));
+            }
+            _ => continue,
+        };
+
+        let message = match serde_json::from_str::<DydxAccountMessage>(&payload) {
+            Ok(message) => message,
+            Err(error) => {
+                debug!(%error, %payload, "failed to deserialise dYdX account message");
+                continue;
+            }
+        };
+
+        for kind in transformer.transform(message) {
+            let event = AccountEvent {
+                received_time: Utc::now(),
+                exchange: ExchangeId::Dydx,
+                kind,
+            };
+
+            if event_tx.send(event).is_err() {
+                return Ok(());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_instrument::instrument::{kind::InstrumentKind, Instrument};
+
+    #[test]
+    fn test_dydx_account_transformer() {
+        let input = r#"
+        {
+            "type": "channel_data",
+            "connection_id": "e2a3ef4a-6c4f-4e2c-8c1f-0a4f9b8d1e0a",
+            "message_id": 4,
+            "id": "dydx1address/0",
+            "channel": "v4_subaccounts",
+            "version": "3.0.0",
+            "contents": {
+                "orders": [
+                    {"id": "0e0e6a2b", "clientId": "42", "clobPairId": "0", "orderFlags": "64", "side": "BUY", "size": "0.01", "totalFilled": "0.005", "price": "65000", "status": "BEST_EFFORT_CANCELED", "ticker": "BTC-USD"}
+                ],
+                "fills": [
+                    {"id": "5b1c", "side": "BUY", "liquidity": "MAKER", "type": "LIMIT", "size": "0.005", "price": "65000", "fee": "-0.1", "orderId": "0e0e6a2b", "ticker": "BTC-USD", "createdAt": "2024-05-09T09:15:30.012Z"}
+                ]
+            }
+        }
+        "#;
+
+        let subaccount_id = DydxSubaccountId {
+            owner: SmolStr::new("dydx1address"),
+            number: 0,
+        };
+        let client_ids = DydxClientIds::default();
+        let mut transformer = DydxAccountTransformer::new(subaccount_id, client_ids.clone());
+
+        let message = serde_json::from_str::<DydxAccountMessage>(input).unwrap();
+        let mut actual = transformer.transform(message).into_iter();
+
+        let instrument = Instrument::from(("btc", "usd", InstrumentKind::Perpetual));
+
+        match actual.next() {
+            Some(AccountEventKind::Trade(trade)) => assert_eq!(
+                trade,
+                Trade {
+                    id: TradeId::from("5b1c"),
+                    order_id: OrderId::from("42:64:0"),
+                    instrument: instrument.clone(),
+                    side: Side::Buy,
+                    price: 65000.0,
+                    quantity: 0.005,
+                    fees: SymbolFees::new("usdc", -0.1),
+                }
+            ),
+            other => panic!("expected AccountEventKind::Trade, but got: {other:?}"),
+        }
+
+        match actual.next() {
+            Some(AccountEventKind::OrdersCancelled(cancelled)) => assert_eq!(
+                cancelled,
+                vec![Order {
+                    exchange: ExchangeId::Dydx,
+                    instrument,
+                    cid: client_ids.cid(42),
+                    side: Side::Buy,
+                    state: Cancelled::from("42:64:0"),
+                }]
+            ),
+            other => panic!("expected AccountEventKind::OrdersCancelled, but got: {other:?}"),
+        }
+
+        assert!(actual.next().is_none());
+    }
+}
@@ -0,0 +1,405 @@
+use super::{
+    market::DydxPerpetualMarket,
+    order::{DydxOrderId, DydxSubaccountId},
+};
+use crate::{
+    error::ExecutionError,
+    model::{
+        balance::{Balance, SymbolBalance},
+        order::{Open, OrderId},
+        ClientOrderId,
+    },
+    Order,
+};
+use barter_instrument::{
+    exchange::ExchangeId,
+    instrument::{kind::InstrumentKind, Instrument},
+};
+use barter_integration::{
+    error::SocketError,
+    protocol::http::{rest::RestRequest, HttpParser},
+    Side,
+};
+use chrono::{DateTime, Utc};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
+use std::{borrow::Cow, collections::HashMap};
+
+/// Symbol of the USDC collateral asset of every dYdX v4 subaccount.
+pub const DYDX_COLLATERAL_SYMBOL: &str = "usdc";
+
+/// [`HttpParser`] for dYdX v4 indexer REST responses.
+#[derive(Debug, Copy, Clone)]
+pub struct DydxParser;
+
+/// dYdX v4 indexer REST error response.
+///
+/// ### Raw Payload Examples
+/// ```json
+/// {"errors": [{"msg": "No subaccount found with address dydx1address and subaccountNumber 0"}]}
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DydxApiError {
+    pub errors: Vec<DydxApiErrorMessage>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DydxApiErrorMessage {
+    pub msg: String,
+}
+
+impl HttpParser for DydxParser {
+    type ApiError = DydxApiError;
+    type OutputError = ExecutionError;
+
+    fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError {
+        let message = error
+            .errors
+            .into_iter()
+            .map(|error| error.msg)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        ExecutionError::from(SocketError::HttpResponse(status, message))
+    }
+}
+
+/// Fetch the latest block height processed by the indexer.
+///
+/// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_api#getheight>
+#[derive(Debug, Copy, Clone)]
+pub struct FetchHeight;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DydxHeight {
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub height: u32,
+    pub time: DateTime<Utc>,
+}
+
+impl RestRequest for FetchHeight {
+    type Response = DydxHeight;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/v4/height")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Fetch the [`DydxPerpetualMarket`] of a ticker (eg/ "BTC-USD").
+///
+/// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_api#listperpetualmarkets>
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct FetchPerpetualMarket {
+    pub ticker: SmolStr,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DydxPerpetualMarkets {
+    pub markets: HashMap<SmolStr, DydxPerpetualMarket>,
+}
+
+impl RestRequest for FetchPerpetualMarket {
+    type Response = DydxPerpetualMarkets;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/v4/perpetualMarkets")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+/// Fetch the open [`DydxIndexerOrder`]s of a subaccount.
+///
+/// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_api#listorders>
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FetchOrdersOpen {
+    pub address: SmolStr,
+    pub subaccount_number: u32,
+    pub status: DydxOrderStatus,
+}
+
+impl FetchOrdersOpen {
+    pub fn new(subaccount_id: &DydxSubaccountId) -> Self {
+        Self {
+            address: subaccount_id.owner.clone(),
+            subaccount_number: subaccount_id.number,
+            status: DydxOrderStatus::Open,
+        }
+    }
+}
+
+impl RestRequest for FetchOrdersOpen {
+    type Response = Vec<DydxIndexerOrder>;
+    type QueryParams = Self;
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/v4/orders")
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        Some(self)
+    }
+}
+
+/// Fetch the [`DydxSubaccount`] collateral of a subaccount.
+///
+/// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_api#getsubaccount>
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct FetchSubaccount {
+    pub subaccount_id: DydxSubaccountId,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DydxSubaccountResponse {
+    pub subaccount: DydxSubaccount,
+}
+
+impl RestRequest for FetchSubaccount {
+    type Response = DydxSubaccountResponse;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "/v4/addresses/{}/subaccountNumber/{}",
+            self.subaccount_id.owner, self.subaccount_id.number
+        ))
+    }
+
+    fn method() -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// dYdX v4 indexer order status.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DydxOrderStatus {
+    Open,
+    BestEffortOpened,
+    Filled,
+    Canceled,
+    BestEffortCanceled,
+    Untriggered,
+    #[serde(other)]
+    Other,
+}
+
+/// dYdX v4 indexer order.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_api#listorders>
+/// ```json
+/// {
+///     "id": "0e0e6a2b-24fd-5b43-a1a0-dd56e5bc4bc4",
+///     "subaccountId": "2d1ae4b6-1d4f-5b6e-8d6a-4c4b2b0d1c2e",
+///     "clientId": "42",
+///     "clobPairId": "0",
+///     "side": "BUY",
+///     "size": "0.01",
+///     "totalFilled": "0.004",
+///     "price": "65000",
+///     "type": "LIMIT",
+///     "status": "OPEN",
+///     "timeInForce": "GTT",
+///     "reduceOnly": false,
+///     "orderFlags": "64",
+///     "goodTilBlockTime": "2024-08-07T09:15:30.000Z",
+///     "postOnly": false,
+///     "ticker": "BTC-USD"
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DydxIndexerOrder {
+    pub id: SmolStr,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub client_id: u32,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub clob_pair_id: u32,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub order_flags: u32,
+    pub side: Side,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub size: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub total_filled: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    pub status: DydxOrderStatus,
+    pub ticker: SmolStr,
+}
+
+impl DydxIndexerOrder {
+    /// [`DydxOrderId`] of this [`DydxIndexerOrder`] owned by the provided
+    /// [`DydxSubaccountId`].
+    pub fn order_id(&self, subaccount_id: &DydxSubaccountId) -> DydxOrderId {
+        DydxOrderId {
+            subaccount_id: subaccount_id.clone(),
+            client_id: self.client_id,
+            order_flags: self.order_flags,
+            clob_pair_id: self.clob_pair_id,
+        }
+    }
+
+    /// Construct an [`Order<Open>`] from this [`DydxIndexerOrder`] with the provided
+    /// [`ClientOrderId`].
+    pub fn into_order_open(
+        self,
+        subaccount_id: &DydxSubaccountId,
+        cid: ClientOrderId,
+    ) -> Order<Open> {
+        Order {
+            exchange: ExchangeId::Dydx,
+            instrument: dydx_instrument(&self.ticker),
+            cid,
+            side: self.side,
+            state: Open {
+                id: OrderId::from(self.order_id(subaccount_id)),
+                price: self.price,
+                quantity: self.size,
+                filled_quantity: self.total_filled,
+                time_in_force: Default::default(),
+            },
+        }
+    }
+}
+
+/// dYdX v4 indexer subaccount collateral.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_api#getsubaccount>
+/// ```json
+/// {
+///     "address": "dydx1address",
+///     "subaccountNumber": 0,
+///     "equity": "10512.25",
+///     "freeCollateral": "9000.5",
+///     "marginEnabled": true
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DydxSubaccount {
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub equity: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub free_collateral: f64,
+}
+
+impl From<DydxSubaccount> for SymbolBalance {
+    fn from(subaccount: DydxSubaccount) -> Self {
+        SymbolBalance::new(
+            DYDX_COLLATERAL_SYMBOL,
+            Balance::new(subaccount.equity, subaccount.free_collateral),
+        )
+    }
+}
+
+/// Determine the perpetual [`Instrument`] of the provided dYdX v4 ticker (eg/ "BTC-USD").
+pub fn dydx_instrument(ticker: &str) -> Instrument {
+    let (base, quote) = ticker.split_once('-').unwrap_or((ticker, ""));
+    Instrument::from((
+        base.to_lowercase(),
+        quote.to_lowercase(),
+        InstrumentKind::Perpetual,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_dydx_indexer_order_into_order_open() {
+        let input = r#"
+        {
+            "id": "0e0e6a2b-24fd-5b43-a1a0-dd56e5bc4bc4",
+            "subaccountId": "2d1ae4b6-1d4f-5b6e-8d6a-4c4b2b0d1c2e",
+            "clientId": "42",
+            "clobPairId": "0",
+            "side": "BUY",
+            "size": "0.01",
+            "totalFilled": "0.004",
+            "price": "65000",
+            "type": "LIMIT",
+            "status": "OPEN",
+            "timeInForce": "GTT",
+            "reduceOnly": false,
+            "orderFlags": "64",
+            "goodTilBlockTime": "2024-08-07T09:15:30.000Z",
+            "postOnly": false,
+            "ticker": "BTC-USD"
+        }
+        "#;
+
+        let subaccount_id = DydxSubaccountId {
+            owner: SmolStr::new("dydx1address"),
+            number: 0,
+        };
+        let cid = ClientOrderId(uuid::Uuid::new_v4());
+
+        let actual = serde_json::from_str::<DydxIndexerOrder>(input)
+            .unwrap()
+            .into_order_open(&subaccount_id, cid);
+
+        assert_eq!(
+            actual,
+            Order {
+                exchange: ExchangeId::Dydx,
+                instrument: Instrument::from(("btc", "usd", InstrumentKind::Perpetual)),
+                cid,
+                side: Side::Buy,
+                state: Open {
+                    id: OrderId::from("42:64:0"),
+                    price: 65000.0,
+                    quantity: 0.01,
+                    filled_quantity: 0.004,
+                    time_in_force: Default::default(),
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_de_dydx_subaccount_into_symbol_balance() {
+        let input = r#"
+        {
+            "subaccount": {
+                "address": "dydx1address",
+                "subaccountNumber": 0,
+                "equity": "10512.25",
+                "freeCollateral": "9000.5",
+                "marginEnabled": true
+            }
+        }
+        "#;
+
+        let actual = serde_json::from_str::<DydxSubaccountResponse>(input).unwrap();
+        assert_eq!(
+            SymbolBalance::from(actual.subaccount),
+            SymbolBalance::new("usdc", Balance::new(10512.25, 9000.5))
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use barter_instrument::instrument::Instrument;
+use serde::{Deserialize, Serialize};
+use smol_str::{format_smolstr, SmolStr, StrExt};
+
+/// Atomic resolution of the USDC quote asset shared by every [`DydxPerpetualMarket`].
+///
+/// See docs: <https://docs.dydx.exchange/api_integration-guides/how_to_interpret_block_data_for_trades>
+pub const QUOTE_QUANTUMS_ATOMIC_RESOLUTION: i32 = -6;
+
+/// dYdX v4 perpetual market configuration, used to quantize order sizes into base "quantums"
+/// and prices into "subticks".
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_api#listperpetualmarkets>
+/// ```json
+/// {
+///     "clobPairId": "0",
+///     "ticker": "BTC-USD",
+///     "status": "ACTIVE",
+///     "atomicResolution": -10,
+///     "quantumConversionExponent": -9,
+///     "stepBaseQuantums": 1000000,
+///     "subticksPerTick": 100000
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DydxPerpetualMarket {
+    pub ticker: SmolStr,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub clob_pair_id: u32,
+    pub atomic_resolution: i32,
+    pub quantum_conversion_exponent: i32,
+    pub step_base_quantums: u64,
+    pub subticks_per_tick: u64,
+}
+
+impl DydxPerpetualMarket {
+    /// Quantize the provided base asset size into quantums, rounded to the nearest multiple of
+    /// `step_base_quantums` (minimum of one step).
+    pub fn quantums(&self, size: f64) -> u64 {
+        let raw_quantums = size * 10_f64.powi(-self.atomic_resolution);
+        round_to_step(raw_quantums, self.step_base_quantums)
+    }
+
+    /// Quantize the provided price into subticks, rounded to the nearest multiple of
+    /// `subticks_per_tick` (minimum of one tick).
+    pub fn subticks(&self, price: f64) -> u64 {
+        let raw_subticks = price * 10_f64.powi(self.subticks_exponent());
+        round_to_step(raw_subticks, self.subticks_per_tick)
+    }
+
+    /// Base asset size of the provided quantums.
+    pub fn size(&self, quantums: u64) -> f64 {
+        quantums as f64 / 10_f64.powi(-self.atomic_resolution)
+    }
+
+    /// Price of the provided subticks.
+    pub fn price(&self, subticks: u64) -> f64 {
+        subticks as f64 / 10_f64.powi(self.subticks_exponent())
+    }
+
+    fn subticks_exponent(&self) -> i32 {
+        self.atomic_resolution - self.quantum_conversion_exponent - QUOTE_QUANTUMS_ATOMIC_RESOLUTION
+    }
+}
+
+fn round_to_step(raw: f64, step: u64) -> u64 {
+    let steps = (raw / step as f64).round() as u64;
+    steps.max(1) * step
+}
+
+/// Determine the dYdX perpetual market ticker of the provided [`Instrument`] (eg/ "BTC-USD").
+pub fn dydx_ticker(instrument: &Instrument) -> SmolStr {
+    format_smolstr!("{}-{}", instrument.base, instrument.quote).to_uppercase_smolstr()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn btc_usd() -> DydxPerpetualMarket {
+        DydxPerpetualMarket {
+            ticker: SmolStr::new("BTC-USD"),
+            clob_pair_id: 0,
+            atomic_resolution: -10,
+            quantum_conversion_exponent: -9,
+            step_base_quantums: 1_000_000,
+            subticks_per_tick: 100_000,
+        }
+    }
+
+    #[test]
+    fn test_dydx_perpetual_market_quantize() {
+        struct TestCase {
+            size: f64,
+            price: f64,
+            expected_quantums: u64,
+            expected_subticks: u64,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: size & price already multiples of the step & tick
+                size: 0.01,
+                price: 65_000.0,
+                expected_quantums: 100_000_000,
+                expected_subticks: 6_500_000_000,
+            },
+            TestCase {
+                // TC1: size & price rounded to the nearest step & tick
+                size: 0.012345,
+                price: 65_000.4,
+                expected_quantums: 123_000_000,
+                expected_subticks: 6_500_000_000,
+            },
+            TestCase {
+                // TC2: size & price below one step & tick are rounded up to the minimum
+                size: 0.00000001,
+                price: 0.1,
+                expected_quantums: 1_000_000,
+                expected_subticks: 100_000,
+            },
+        ];
+
+        let market = btc_usd();
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                market.quantums(test.size),
+                test.expected_quantums,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                market.subticks(test.price),
+                test.expected_subticks,
+                "TC{index} failed"
+            );
+        }
+
+        assert_eq!(market.size(100_000_000), 0.01);
+        assert_eq!(market.price(6_500_000_000), 65_000.0);
+    }
+
+    #[test]
+    fn test_de_dydx_perpetual_market() {
+        let input = r#"
+        {
+            "clobPairId": "0",
+            "ticker": "BTC-USD",
+            "status": "ACTIVE",
+            "oraclePrice": "65005.12",
+            "atomicResolution": -10,
+            "quantumConversionExponent": -9,
+            "stepBaseQuantums": 1000000,
+            "subticksPerTick": 100000
+        }
+        "#;
+
+        assert_eq!(
+            serde_json::from_str::<DydxPerpetualMarket>(input).unwrap(),
+            btc_usd()
+        );
+    }
+}
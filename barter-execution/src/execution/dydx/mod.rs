@@ -0,0 +1,596 @@
+use self::{
+    account::{consume_account_events, DydxAccountTransformer},
+    indexer::{DydxParser, FetchHeight, FetchOrdersOpen, FetchPerpetualMarket, FetchSubaccount},
+    market::{dydx_ticker, DydxPerpetualMarket},
+    order::{DydxCancel, DydxClientIds, DydxOrder, DydxOrderId, DydxSubaccountId},
+};
+use super::environment::{rest_base_url, websocket_private_url};
+use crate::{
+    error::ExecutionError,
+    model::{
+        balance::SymbolBalance,
+        order::{Cancelled, Open, OrderId, OrderKind, RequestCancel, RequestOpen, TimeInForce},
+        AccountEvent,
+    },
+    ExecutionClient, Order,
+};
+use async_trait::async_trait;
+use barter_instrument::{exchange::ExchangeId, instrument::Instrument};
+use barter_integration::{
+    environment::Environment,
+    protocol::http::{public::PublicNoHeaders, rest::client::RestClient},
+};
+use chrono::Utc;
+use futures::future::join_all;
+use parking_lot::RwLock;
+use smol_str::SmolStr;
+use std::{collections::HashMap, fmt::Debug};
+use tokio::sync::mpsc;
+
+/// Private account WebSocket stream of the dYdX v4 indexer.
+pub mod account;
+
+/// dYdX v4 indexer REST requests & responses.
+pub mod indexer;
+
+/// dYdX v4 perpetual market configuration used for order quantization.
+pub mod market;
+
+/// dYdX v4 order, order identifier & cancellation types.
+pub mod order;
+
+/// Signs & broadcasts dYdX v4 order transactions to a validator node.
+///
+/// ### Notes
+/// dYdX v4 orders are not placed via a REST API, but as Cosmos SDK transactions containing a
+/// `MsgPlaceOrder` or `MsgCancelOrder`, signed by the secp256k1 key of the subaccount owner and
+/// broadcast over gRPC. The [`DydxExecution`] handles the order flow (market configuration,
+/// quantization, block height expiries, and order identifiers), delegating only the signing &
+/// broadcasting to this trait so any Cosmos SDK client may be used.
+///
+/// See docs: <https://docs.dydx.exchange/api_integration-trading/placing_orders>
+#[async_trait]
+pub trait DydxBroadcaster {
+    /// Sign & broadcast a `MsgPlaceOrder` transaction containing the provided [`DydxOrder`].
+    async fn place_order(&self, order: &DydxOrder) -> Result<(), ExecutionError>;
+
+    /// Sign & broadcast a `MsgCancelOrder` transaction containing the provided [`DydxCancel`].
+    async fn cancel_order(&self, cancel: &DydxCancel) -> Result<(), ExecutionError>;
+}
+
+/// [`DydxExecution`] configuration.
+#[derive(Clone, Debug)]
+pub struct DydxConfig<Broadcaster> {
+    pub environment: Environment,
+    /// Bech32 address of the subaccount owner (eg/ "dydx1...").
+    pub address: SmolStr,
+    pub subaccount_number: u32,
+    pub broadcaster: Broadcaster,
+}
+
+/// dYdX v4 [`ExecutionClient`] implementation.
+///
+/// Account state is queried from, and streamed by, the off-chain indexer, whereas orders are
+/// broadcast on-chain via the configured [`DydxBroadcaster`].
+#[derive(Debug)]
+pub struct DydxExecution<Broadcaster> {
+    pub subaccount_id: DydxSubaccountId,
+    pub broadcaster: Broadcaster,
+    pub indexer: RestClient<'static, PublicNoHeaders, DydxParser>,
+    pub client_ids: DydxClientIds,
+    pub markets: RwLock<HashMap<SmolStr, DydxPerpetualMarket>>,
+}
+
+#[async_trait]
+impl<Broadcaster> ExecutionClient for DydxExecution<Broadcaster>
+where
+    Broadcaster: DydxBroadcaster + Debug + Send + Sync,
+{
+    const CLIENT: ExchangeId = ExchangeId::Dydx;
+    type Config = DydxConfig<Broadcaster>;
+
+    async fn init(config: Self::Config, event_tx: mpsc::UnboundedSender<AccountEvent>) -> Self {
+        let rest_url = rest_base_url(Self::CLIENT, config.environment)
+            .expect("dYdX indexer REST endpoints are defined for every Environment");
+        let websocket_url = websocket_private_url(Self::CLIENT, config.environment)
+            .expect("dYdX indexer WebSocket endpoints are defined for every Environment");
+
+        let subaccount_id = DydxSubaccountId {
+            owner: config.address,
+            number: config.subaccount_number,
+        };
+        let client_ids = DydxClientIds::default();
+
+        // Stream private account updates from the indexer
+        tokio::spawn(consume_account_events(
+            websocket_url,
+            DydxAccountTransformer::new(subaccount_id.clone(), client_ids.clone()),
+            event_tx,
+        ));
+
+        Self {
+            subaccount_id,
+            broadcaster: config.broadcaster,
+            indexer: RestClient::new(rest_url, PublicNoHeaders, DydxParser),
+            client_ids,
+            markets: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// dYdX v4 long-term orders natively expire at their good-til-block-time.
+    fn supports_time_in_force(_: &TimeInForce) -> bool {
+        true
+    }
+
+    async fn fetch_orders_open(&self) -> Result<Vec<Order<Open>>, ExecutionError> {
+        let (orders, _) = self
+            .indexer
+            .execute(FetchOrdersOpen::new(&self.subaccount_id))
+            .await?;
+
+        Ok(orders
+            .into_iter()
+            .map(|order| {
+                let cid = self.client_ids.cid(order.client_id);
+                order.into_order_open(&self.subaccount_id, cid)
+            })
+            .collect())
+    }
+
+    async fn fetch_balances(&self) -> Result<Vec<SymbolBalance>, ExecutionError> {
+        let (response, _) = self
+            .indexer
+            .execute(FetchSubaccount {
+                subaccount_id: self.subaccount_id.clone(),
+            })
+            .await?;
+
+        Ok(vec![SymbolBalance::from(response.subaccount)])
+    }
+
+    async fn open_orders(
+        &self,
+        open_requests: Vec<Order<RequestOpen>>,
+    ) -> Vec<Result<Order<Open>, ExecutionError>> {
+        join_all(
+            open_requests
+                .into_iter()
+                .map(|request| self.open_order(request)),
+        )
+        .await
+    }
+
+    async fn cancel_orders(
+        &self,
+        cancel_requests: Vec<Order<RequestCancel>>,
+    ) -> Vec<Result<Order<Cancelled>, ExecutionError>> {
+        join_all(
+            cancel_requests
+                .into_iter()
+                .map(|request| self.cancel_order(request)),
+        )
+        .await
+    }
+
+    async fn cancel_orders_all(&self) -> Result<Vec<Order<Cancelled>>, ExecutionError> {
+        let cancel_requests = self
+            .fetch_orders_open()
+            .await?
+            .iter()
+            .map(Order::from)
+            .collect::<Vec<Order<RequestCancel>>>();
+
+        self.cancel_orders(cancel_requests)
+            .await
+            .into_iter()
+            .collect()
+    }
+}
+
+impl<Broadcaster> DydxExecution<Broadcaster>
+where
+    Broadcaster: DydxBroadcaster,
+{
+    async fn open_order(&self, request: Order<RequestOpen>) -> Result<Order<Open>, ExecutionError> {
+        let market = self.market(&request.instrument).await?;
+
+        // Short-term orders expire at a block height, so only they require the current height
+        let block_height = match request.state.kind {
+            OrderKind::Market | OrderKind::ImmediateOrCancel => self.block_height().await?,
+            OrderKind::Limit | OrderKind::PostOnly => 0,
+        };
+
+        let order = DydxOrder::from_request(
+            &self.subaccount_id,
+            &market,
+            block_height,
+            Utc::now(),
+            &request,
+        )?;
+
+        // Only register the ClientOrderId once broadcast, so a failed order does not shadow the
+        // attribution of another order with the same client_id
+        self.broadcaster.place_order(&order).await?;
+        self.client_ids.register(request.cid);
+
+        Ok(Order::from((OrderId::from(&order.id), request)))
+    }
+
+    async fn cancel_order(
+        &self,
+        request: Order<RequestCancel>,
+    ) -> Result<Order<Cancelled>, ExecutionError> {
+        let id = DydxOrderId::parse(&self.subaccount_id, &request.state.id)?;
+
+        // Short-term cancels expire at a block height, so only they require the current height
+        let block_height = if id.is_short_term() {
+            self.block_height().await?
+        } else {
+            0
+        };
+
+        self.broadcaster
+            .cancel_order(&DydxCancel::new(id, block_height, Utc::now()))
+            .await?;
+
+        Ok(Order {
+            exchange: request.exchange,
+            instrument: request.instrument,
+            cid: request.cid,
+            side: request.side,
+            state: Cancelled::from(request.state.id),
+        })
+    }
+
+    /// Latest block height processed by the indexer.
+    async fn block_height(&self) -> Result<u32, ExecutionError> {
+        self.indexer
+            .execute(FetchHeight)
+            .await
+            .map(|(response, _)| response.height)
+    }
+
+    /// [`DydxPerpetualMarket`] of the provided [`Instrument`], fetched from the indexer on
+    /// first use.
+    async fn market(&self, instrument: &Instrument) -> Result<DydxPerpetualMarket, ExecutionError> {
+        let ticker = dydx_ticker(instrument);
+        if let Some(market) = self.markets.read().get(&ticker) {
+            return Ok(market.clone());
+        }
+
+        let (mut response, _) = self
+            .indexer
+            .execute(FetchPerpetualMarket {
+                ticker: ticker.clone(),
+            })
+            .await?;
+
+        let market = response.markets.remove(&ticker).ok_or_else(|| {
+            ExecutionError::InvalidOrderParams(format!("dYdX market {ticker} not found"))
+        })?;
+
+        self.markets.write().insert(ticker, market.clone());
+        Ok(market)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        execution::dydx::order::{
+            dydx_client_id, DydxGoodTil, ORDER_FLAGS_LONG_TERM, ORDER_FLAGS_SHORT_TERM,
+            SHORT_BLOCK_WINDOW,
+        },
+        model::ClientOrderId,
+    };
+    use barter_instrument::instrument::kind::InstrumentKind;
+    use barter_integration::Side;
+    use parking_lot::Mutex;
+    use std::sync::Arc;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+    use uuid::Uuid;
+
+    const HEIGHT: &str = r#"{"height":"1000","time":"2024-08-07T09:15:30.000Z"}"#;
+    const MARKETS: &str = r#"{
+        "markets": {
+            "BTC-USD": {
+                "clobPairId": "0",
+                "ticker": "BTC-USD",
+                "status": "ACTIVE",
+                "atomicResolution": -10,
+                "quantumConversionExponent": -9,
+                "stepBaseQuantums": 1000000,
+                "subticksPerTick": 100000
+            }
+        }
+    }"#;
+    const ORDERS: &str = r#"[
+        {
+            "id": "0e0e6a2b-24fd-5b43-a1a0-dd56e5bc4bc4",
+            "clientId": "42",
+            "clobPairId": "0",
+            "side": "BUY",
+            "size": "0.01",
+            "totalFilled": "0",
+            "price": "65000",
+            "status": "OPEN",
+            "orderFlags": "64",
+            "ticker": "BTC-USD"
+        },
+        {
+            "id": "1f1f7b3c-35ae-6c54-b2b1-ee67f6cd5cd5",
+            "clientId": "7",
+            "clobPairId": "0",
+            "side": "SELL",
+            "size": "0.02",
+            "totalFilled": "0",
+            "price": "66000",
+            "status": "BEST_EFFORT_OPENED",
+            "orderFlags": "0",
+            "ticker": "BTC-USD"
+        }
+    ]"#;
+
+    /// [`DydxBroadcaster`] recording every broadcast [`DydxOrder`] & [`DydxCancel`], failing
+    /// every `place_order` if configured to.
+    #[derive(Debug, Default)]
+    struct MockBroadcaster {
+        fail: bool,
+        orders: Mutex<Vec<DydxOrder>>,
+        cancels: Mutex<Vec<DydxCancel>>,
+    }
+
+    #[async_trait]
+    impl DydxBroadcaster for MockBroadcaster {
+        async fn place_order(&self, order: &DydxOrder) -> Result<(), ExecutionError> {
+            if self.fail {
+                return Err(ExecutionError::Connectivity("broadcast failed".to_string()));
+            }
+            self.orders.lock().push(order.clone());
+            Ok(())
+        }
+
+        async fn cancel_order(&self, cancel: &DydxCancel) -> Result<(), ExecutionError> {
+            self.cancels.lock().push(cancel.clone());
+            Ok(())
+        }
+    }
+
+    /// Spawn an indexer server replying to each request with the canned JSON response of the
+    /// first matching path prefix, returning it's base url & every requested path.
+    async fn indexer_server(
+        responses: Vec<(&'static str, &'static str)>,
+    ) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let paths = Arc::clone(&requests);
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 1024];
+                while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                    let read = stream.read(&mut buffer).await.unwrap();
+                    request.extend_from_slice(&buffer[..read]);
+                }
+
+                let request = String::from_utf8(request).unwrap();
+                let path = request.split_whitespace().nth(1).unwrap().to_string();
+                let (status, body) = responses
+                    .iter()
+                    .find(|(prefix, _)| path.starts_with(prefix))
+                    .map_or(
+                        ("404 Not Found", r#"{"errors":[{"msg":"not found"}]}"#),
+                        |(_, body)| ("200 OK", body),
+                    );
+                paths.lock().push(path);
+
+                let response = format!(
+                    "HTTP/1.1 {status}\r\nContent-Type: application/json\r\n\
+                     Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        (base_url, requests)
+    }
+
+    fn subaccount() -> DydxSubaccountId {
+        DydxSubaccountId {
+            owner: SmolStr::new("dydx1address"),
+            number: 0,
+        }
+    }
+
+    fn execution(base_url: String, broadcaster: MockBroadcaster) -> DydxExecution<MockBroadcaster> {
+        DydxExecution {
+            subaccount_id: subaccount(),
+            broadcaster,
+            indexer: RestClient::new(base_url, PublicNoHeaders, DydxParser),
+            client_ids: DydxClientIds::default(),
+            markets: RwLock::new(HashMap::new()),
+        }
+    }
+
+    fn request(sequence: u128, base: &str, kind: OrderKind) -> Order<RequestOpen> {
+        Order {
+            exchange: ExchangeId::Dydx,
+            instrument: (base, "usd", InstrumentKind::Perpetual).into(),
+            // Note: distinct from the ClientOrderId attributed to an unregistered client_id
+            cid: ClientOrderId(Uuid::from_u128((sequence << 96) | 1)),
+            side: Side::Buy,
+            state: RequestOpen {
+                kind,
+                price: 65_000.0,
+                quantity: 0.01,
+                time_in_force: TimeInForce::GoodUntilCancelled,
+            },
+        }
+    }
+
+    fn count(requests: &Mutex<Vec<String>>, prefix: &str) -> usize {
+        requests
+            .lock()
+            .iter()
+            .filter(|path| path.starts_with(prefix))
+            .count()
+    }
+
+    #[tokio::test]
+    async fn test_dydx_execution_open_orders() {
+        struct TestCase {
+            input: Order<RequestOpen>,
+            expected_order_flags: u32,
+            expected_block: bool,
+            expected_height_requests: usize,
+        }
+
+        let (base_url, requests) = indexer_server(vec![
+            ("/v4/height", HEIGHT),
+            ("/v4/perpetualMarkets", MARKETS),
+        ])
+        .await;
+        let execution = execution(base_url, MockBroadcaster::default());
+
+        let tests = vec![
+            TestCase {
+                // TC0: short-term Market order expires at a block height, so fetches the height
+                input: request(1, "btc", OrderKind::Market),
+                expected_order_flags: ORDER_FLAGS_SHORT_TERM,
+                expected_block: true,
+                expected_height_requests: 1,
+            },
+            TestCase {
+                // TC1: long-term Limit order expires at a block time, so skips the height
+                input: request(2, "btc", OrderKind::Limit),
+                expected_order_flags: ORDER_FLAGS_LONG_TERM,
+                expected_block: false,
+                expected_height_requests: 1,
+            },
+            TestCase {
+                // TC2: short-term ImmediateOrCancel order fetches the latest height again
+                input: request(3, "btc", OrderKind::ImmediateOrCancel),
+                expected_order_flags: ORDER_FLAGS_SHORT_TERM,
+                expected_block: true,
+                expected_height_requests: 2,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let cid = test.input.cid;
+            let mut actual = execution.open_orders(vec![test.input]).await;
+
+            let open = actual.remove(0).unwrap();
+            let order = execution.broadcaster.orders.lock().last().cloned().unwrap();
+            assert_eq!(open.cid, cid, "TC{index} failed");
+            assert_eq!(open.state.id, OrderId::from(&order.id), "TC{index} failed");
+            assert_eq!(
+                order.id.order_flags, test.expected_order_flags,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                order.good_til == DydxGoodTil::Block(1_000 + SHORT_BLOCK_WINDOW),
+                test.expected_block,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                count(&requests, "/v4/height"),
+                test.expected_height_requests,
+                "TC{index} failed"
+            );
+
+            // ClientOrderId is registered once broadcast
+            assert_eq!(
+                execution.client_ids.cid(dydx_client_id(&cid)),
+                cid,
+                "TC{index} failed"
+            );
+        }
+
+        // DydxPerpetualMarket is fetched on first use, and cached thereafter
+        assert_eq!(count(&requests, "/v4/perpetualMarkets"), 1);
+        assert!(execution.markets.read().contains_key("BTC-USD"));
+    }
+
+    #[tokio::test]
+    async fn test_dydx_execution_open_order_failures() {
+        let (base_url, requests) = indexer_server(vec![
+            ("/v4/height", HEIGHT),
+            ("/v4/perpetualMarkets", MARKETS),
+        ])
+        .await;
+        let broadcaster = MockBroadcaster {
+            fail: true,
+            ..MockBroadcaster::default()
+        };
+        let execution = execution(base_url, broadcaster);
+
+        // Failed broadcast does not register the ClientOrderId
+        let failed = request(1, "btc", OrderKind::Limit);
+        let actual = execution.open_orders(vec![failed.clone()]).await;
+        assert_eq!(
+            actual,
+            vec![Err(ExecutionError::Connectivity(
+                "broadcast failed".to_string()
+            ))]
+        );
+        assert_ne!(
+            execution.client_ids.cid(dydx_client_id(&failed.cid)),
+            failed.cid
+        );
+
+        // Unknown market is rejected before broadcast, and not cached
+        let actual = execution
+            .open_orders(vec![request(2, "eth", OrderKind::Limit)])
+            .await;
+        assert!(matches!(
+            actual.as_slice(),
+            [Err(ExecutionError::InvalidOrderParams(_))]
+        ));
+        assert_eq!(count(&requests, "/v4/perpetualMarkets"), 2);
+        assert!(!execution.markets.read().contains_key("ETH-USD"));
+        assert!(execution.broadcaster.orders.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dydx_execution_cancel_orders_all() {
+        let (base_url, requests) =
+            indexer_server(vec![("/v4/height", HEIGHT), ("/v4/orders", ORDERS)]).await;
+        let execution = execution(base_url, MockBroadcaster::default());
+
+        // Long-term order 42 was opened by this client, whereas short-term order 7 was not
+        let cid = ClientOrderId(Uuid::from_u128((42 << 96) | 1));
+        execution.client_ids.register(cid);
+
+        let mut cancelled = execution.cancel_orders_all().await.unwrap();
+        cancelled.sort_by_key(|order| order.state.id.0.clone());
+
+        assert_eq!(cancelled.len(), 2);
+        assert_eq!(cancelled[0].state.id, OrderId::from("42:64:0"));
+        assert_eq!(cancelled[0].cid, cid);
+        assert_eq!(cancelled[1].state.id, OrderId::from("7:0:0"));
+        assert_eq!(cancelled[1].cid, execution.client_ids.cid(7));
+
+        // Only the short-term cancel requires the block height
+        let mut cancels = execution.broadcaster.cancels.lock().clone();
+        cancels.sort();
+        assert_eq!(cancels[0].id.client_id, 7);
+        assert_eq!(
+            cancels[0].good_til,
+            DydxGoodTil::Block(1_000 + SHORT_BLOCK_WINDOW)
+        );
+        assert_eq!(cancels[1].id.client_id, 42);
+        assert!(matches!(cancels[1].good_til, DydxGoodTil::BlockTime(_)));
+        assert_eq!(count(&requests, "/v4/orders"), 1);
+        assert_eq!(count(&requests, "/v4/height"), 1);
+    }
+}
@@ -0,0 +1,402 @@
+use super::market::DydxPerpetualMarket;
+use crate::{
+    error::ExecutionError,
+    model::{
+        order::{OrderId, OrderKind, RequestOpen, TimeInForce},
+        ClientOrderId,
+    },
+    Order,
+};
+use barter_integration::Side;
+use chrono::{DateTime, TimeDelta, Utc};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    sync::Arc,
+};
+use uuid::Uuid;
+
+/// [`DydxOrderId::order_flags`] of a short-term order, which lives in validator memory only and
+/// expires at a block height.
+pub const ORDER_FLAGS_SHORT_TERM: u32 = 0;
+
+/// [`DydxOrderId::order_flags`] of a long-term (stateful) order, which is committed on-chain and
+/// expires at a block time.
+pub const ORDER_FLAGS_LONG_TERM: u32 = 64;
+
+/// Number of blocks after the current block height within which a short-term order (or
+/// cancel) must expire.
+///
+/// See docs: <https://docs.dydx.exchange/api_integration-trading/short_term_vs_stateful>
+pub const SHORT_BLOCK_WINDOW: u32 = 20;
+
+/// Maximum duration a long-term order may remain active, used as the expiry of
+/// [`TimeInForce::GoodUntilCancelled`] orders.
+pub const LONG_TERM_ORDER_MAX_DURATION: TimeDelta = TimeDelta::days(90);
+
+/// Duration after which a long-term order cancellation expires if not yet included in a block.
+pub const LONG_TERM_CANCEL_DURATION: TimeDelta = TimeDelta::seconds(60);
+
+/// dYdX v4 subaccount of an address, which owns every [`DydxOrder`].
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DydxSubaccountId {
+    pub owner: SmolStr,
+    pub number: u32,
+}
+
+/// Unique identifier of a dYdX v4 order, as defined by the protocol `OrderId`.
+///
+/// Used as the Barter [`OrderId`] in the format "{client_id}:{order_flags}:{clob_pair_id}",
+/// since every field is required to cancel the order.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DydxOrderId {
+    pub subaccount_id: DydxSubaccountId,
+    pub client_id: u32,
+    pub order_flags: u32,
+    pub clob_pair_id: u32,
+}
+
+impl DydxOrderId {
+    /// Determine if this [`DydxOrderId`] identifies a short-term order.
+    pub fn is_short_term(&self) -> bool {
+        self.order_flags == ORDER_FLAGS_SHORT_TERM
+    }
+
+    /// Parse the [`DydxOrderId`] owned by the provided [`DydxSubaccountId`] from a Barter
+    /// [`OrderId`].
+    pub fn parse(
+        subaccount_id: &DydxSubaccountId,
+        order_id: &OrderId,
+    ) -> Result<Self, ExecutionError> {
+        let invalid =
+            || ExecutionError::InvalidOrderParams(format!("invalid dYdX OrderId: {}", order_id.0));
+
+        let mut fields = order_id.0.split(':').map(str::parse::<u32>);
+        match (fields.next(), fields.next(), fields.next(), fields.next()) {
+            (Some(Ok(client_id)), Some(Ok(order_flags)), Some(Ok(clob_pair_id)), None) => {
+                Ok(Self {
+                    subaccount_id: subaccount_id.clone(),
+                    client_id,
+                    order_flags,
+                    clob_pair_id,
+                })
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl Display for DydxOrderId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}",
+            self.client_id, self.order_flags, self.clob_pair_id
+        )
+    }
+}
+
+/// Determine the dYdX v4 `client_id` of the provided [`ClientOrderId`], which is required to be
+/// unique amongst the open orders of a subaccount.
+pub fn dydx_client_id(cid: &ClientOrderId) -> u32 {
+    let [a, b, c, d, ..] = *cid.0.as_bytes();
+    u32::from_be_bytes([a, b, c, d])
+}
+
+/// Shared registry of the [`ClientOrderId`] associated with each dYdX v4 `client_id` opened by
+/// this client, used to attribute orders & fills received from the indexer.
+#[derive(Debug, Clone, Default)]
+pub struct DydxClientIds(Arc<RwLock<HashMap<u32, ClientOrderId>>>);
+
+impl DydxClientIds {
+    /// Register the provided [`ClientOrderId`], returning its dYdX v4 `client_id`.
+    pub fn register(&self, cid: ClientOrderId) -> u32 {
+        let client_id = dydx_client_id(&cid);
+        self.0.write().insert(client_id, cid);
+        client_id
+    }
+
+    /// [`ClientOrderId`] associated with the provided dYdX v4 `client_id`.
+    ///
+    /// Orders not opened by this client are attributed a [`ClientOrderId`] derived from their
+    /// `client_id`, such that [`dydx_client_id`] maps it back to the same `client_id`.
+    pub fn cid(&self, client_id: u32) -> ClientOrderId {
+        self.0
+            .read()
+            .get(&client_id)
+            .copied()
+            .unwrap_or_else(|| ClientOrderId(Uuid::from_u128((client_id as u128) << 96)))
+    }
+}
+
+/// dYdX v4 order time in force.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum DydxTimeInForce {
+    /// Rests on the book until filled, cancelled, or expired.
+    Unspecified,
+    ImmediateOrCancel,
+    PostOnly,
+}
+
+/// Expiry of a dYdX v4 order or cancellation, which is a block height for short-term orders
+/// and a block time for long-term orders.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum DydxGoodTil {
+    Block(u32),
+    BlockTime(DateTime<Utc>),
+}
+
+/// dYdX v4 `MsgPlaceOrder` order, quantized for its [`DydxPerpetualMarket`].
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DydxOrder {
+    pub id: DydxOrderId,
+    pub side: Side,
+    pub quantums: u64,
+    pub subticks: u64,
+    pub good_til: DydxGoodTil,
+    pub time_in_force: DydxTimeInForce,
+    pub reduce_only: bool,
+}
+
+impl DydxOrder {
+    /// Construct a [`DydxOrder`] from the provided [`Order<RequestOpen>`].
+    ///
+    /// [`OrderKind::Market`] & [`OrderKind::ImmediateOrCancel`] orders are placed as short-term
+    /// immediate-or-cancel orders expiring [`SHORT_BLOCK_WINDOW`] blocks after the provided
+    /// block height, where the [`RequestOpen`] price is the worst acceptable fill price.
+    ///
+    /// [`OrderKind::Limit`] & [`OrderKind::PostOnly`] orders are placed as long-term orders
+    /// expiring at their [`TimeInForce::GoodTillDate`], or after
+    /// [`LONG_TERM_ORDER_MAX_DURATION`] if [`TimeInForce::GoodUntilCancelled`].
+    pub fn from_request(
+        subaccount_id: &DydxSubaccountId,
+        market: &DydxPerpetualMarket,
+        block_height: u32,
+        time: DateTime<Utc>,
+        request: &Order<RequestOpen>,
+    ) -> Result<Self, ExecutionError> {
+        let RequestOpen {
+            kind,
+            price,
+            quantity,
+            time_in_force,
+        } = request.state;
+
+        if price <= 0.0 || quantity <= 0.0 {
+            return Err(ExecutionError::InvalidOrderParams(format!(
+                "dYdX order price ({price}) & quantity ({quantity}) must be positive"
+            )));
+        }
+
+        let (order_flags, good_til, time_in_force) = match kind {
+            OrderKind::Market | OrderKind::ImmediateOrCancel => (
+                ORDER_FLAGS_SHORT_TERM,
+                DydxGoodTil::Block(block_height + SHORT_BLOCK_WINDOW),
+                DydxTimeInForce::ImmediateOrCancel,
+            ),
+            OrderKind::Limit | OrderKind::PostOnly => {
+                let expiry = match time_in_force {
+                    TimeInForce::GoodUntilCancelled => time + LONG_TERM_ORDER_MAX_DURATION,
+                    TimeInForce::GoodTillDate(expiry)
+                        if expiry > time && expiry <= time + LONG_TERM_ORDER_MAX_DURATION =>
+                    {
+                        expiry
+                    }
+                    TimeInForce::GoodTillDate(expiry) => {
+                        return Err(ExecutionError::InvalidOrderParams(format!(
+                            "dYdX long-term order expiry {expiry} must be within \
+                             {LONG_TERM_ORDER_MAX_DURATION} of {time}"
+                        )))
+                    }
+                };
+
+                let time_in_force = if kind == OrderKind::PostOnly {
+                    DydxTimeInForce::PostOnly
+                } else {
+                    DydxTimeInForce::Unspecified
+                };
+
+                (
+                    ORDER_FLAGS_LONG_TERM,
+                    DydxGoodTil::BlockTime(expiry),
+                    time_in_force,
+                )
+            }
+        };
+
+        Ok(Self {
+            id: DydxOrderId {
+                subaccount_id: subaccount_id.clone(),
+                client_id: dydx_client_id(&request.cid),
+                order_flags,
+                clob_pair_id: market.clob_pair_id,
+            },
+            side: request.side,
+            quantums: market.quantums(quantity),
+            subticks: market.subticks(price),
+            good_til,
+            time_in_force,
+            reduce_only: false,
+        })
+    }
+}
+
+/// dYdX v4 `MsgCancelOrder` cancellation.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DydxCancel {
+    pub id: DydxOrderId,
+    pub good_til: DydxGoodTil,
+}
+
+impl DydxCancel {
+    /// Construct a [`DydxCancel`] of the provided [`DydxOrderId`], expiring
+    /// [`SHORT_BLOCK_WINDOW`] blocks after the provided block height for short-term orders, or
+    /// [`LONG_TERM_CANCEL_DURATION`] after the provided time for long-term orders.
+    pub fn new(id: DydxOrderId, block_height: u32, time: DateTime<Utc>) -> Self {
+        let good_til = if id.is_short_term() {
+            DydxGoodTil::Block(block_height + SHORT_BLOCK_WINDOW)
+        } else {
+            DydxGoodTil::BlockTime(time + LONG_TERM_CANCEL_DURATION)
+        };
+
+        Self { id, good_til }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_instrument::{exchange::ExchangeId, instrument::kind::InstrumentKind};
+
+    fn subaccount() -> DydxSubaccountId {
+        DydxSubaccountId {
+            owner: SmolStr::new("dydx1address"),
+            number: 0,
+        }
+    }
+
+    fn market() -> DydxPerpetualMarket {
+        DydxPerpetualMarket {
+            ticker: SmolStr::new("BTC-USD"),
+            clob_pair_id: 0,
+            atomic_resolution: -10,
+            quantum_conversion_exponent: -9,
+            step_base_quantums: 1_000_000,
+            subticks_per_tick: 100_000,
+        }
+    }
+
+    fn request(kind: OrderKind, time_in_force: TimeInForce) -> Order<RequestOpen> {
+        Order {
+            exchange: ExchangeId::Dydx,
+            instrument: ("btc", "usd", InstrumentKind::Perpetual).into(),
+            cid: ClientOrderId(Uuid::from_u128(0x0000_0001_0000_0000_0000_0000_0000_0000)),
+            side: Side::Buy,
+            state: RequestOpen {
+                kind,
+                price: 65_000.0,
+                quantity: 0.01,
+                time_in_force,
+            },
+        }
+    }
+
+    #[test]
+    fn test_dydx_order_from_request() {
+        struct TestCase {
+            input: Order<RequestOpen>,
+            expected: Result<(u32, DydxGoodTil, DydxTimeInForce), ExecutionError>,
+        }
+
+        let time = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let expiry = time + TimeDelta::hours(1);
+
+        let tests = vec![
+            TestCase {
+                // TC0: Market order is a short-term IOC order
+                input: request(OrderKind::Market, TimeInForce::GoodUntilCancelled),
+                expected: Ok((
+                    ORDER_FLAGS_SHORT_TERM,
+                    DydxGoodTil::Block(1_020),
+                    DydxTimeInForce::ImmediateOrCancel,
+                )),
+            },
+            TestCase {
+                // TC1: GoodUntilCancelled Limit order is a long-term order w/ max expiry
+                input: request(OrderKind::Limit, TimeInForce::GoodUntilCancelled),
+                expected: Ok((
+                    ORDER_FLAGS_LONG_TERM,
+                    DydxGoodTil::BlockTime(time + LONG_TERM_ORDER_MAX_DURATION),
+                    DydxTimeInForce::Unspecified,
+                )),
+            },
+            TestCase {
+                // TC2: GoodTillDate PostOnly order is a long-term order w/ native expiry
+                input: request(OrderKind::PostOnly, TimeInForce::GoodTillDate(expiry)),
+                expected: Ok((
+                    ORDER_FLAGS_LONG_TERM,
+                    DydxGoodTil::BlockTime(expiry),
+                    DydxTimeInForce::PostOnly,
+                )),
+            },
+            TestCase {
+                // TC3: GoodTillDate already expired is rejected
+                input: request(OrderKind::Limit, TimeInForce::GoodTillDate(time)),
+                expected: Err(ExecutionError::InvalidOrderParams(String::new())),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual =
+                DydxOrder::from_request(&subaccount(), &market(), 1_000, time, &test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok((order_flags, good_til, time_in_force))) => {
+                    assert_eq!(actual.id.client_id, 1, "TC{index} failed");
+                    assert_eq!(actual.id.order_flags, order_flags, "TC{index} failed");
+                    assert_eq!(actual.good_til, good_til, "TC{index} failed");
+                    assert_eq!(actual.time_in_force, time_in_force, "TC{index} failed");
+                    assert_eq!(actual.quantums, 100_000_000, "TC{index} failed");
+                    assert_eq!(actual.subticks, 6_500_000_000, "TC{index} failed");
+                }
+                (Err(ExecutionError::InvalidOrderParams(_)), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_dydx_order_id_round_trip() {
+        let id = DydxOrderId {
+            subaccount_id: subaccount(),
+            client_id: 42,
+            order_flags: ORDER_FLAGS_LONG_TERM,
+            clob_pair_id: 1,
+        };
+
+        let order_id = OrderId::from(&id);
+        assert_eq!(order_id, OrderId::from("42:64:1"));
+        assert_eq!(DydxOrderId::parse(&subaccount(), &order_id), Ok(id));
+        assert!(DydxOrderId::parse(&subaccount(), &OrderId::from("42:64")).is_err());
+    }
+
+    #[test]
+    fn test_dydx_client_ids() {
+        let client_ids = DydxClientIds::default();
+        let cid = ClientOrderId(Uuid::new_v4());
+
+        let client_id = client_ids.register(cid);
+        assert_eq!(client_ids.cid(client_id), cid);
+
+        // Unknown client_ids are attributed a ClientOrderId that maps back to the client_id
+        let unknown = client_id.wrapping_add(1);
+        assert_eq!(dydx_client_id(&client_ids.cid(unknown)), unknown);
+    }
+}
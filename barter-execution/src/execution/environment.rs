@@ -0,0 +1,183 @@
+use crate::error::ExecutionError;
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::environment::{Environment, EnvironmentUrl};
+
+/// HTTP header OKX requires on every demo trading REST request.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-demo-trading-services>
+pub const HEADER_OKX_SIMULATED_TRADING: (&str, &str) = ("x-simulated-trading", "1");
+
+/// REST & private WebSocket base urls of a venue's trading APIs in each [`Environment`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct VenueEndpoints {
+    pub rest: EnvironmentUrl,
+    pub websocket_private: EnvironmentUrl,
+}
+
+/// `BinanceSpot` trading API [`VenueEndpoints`].
+///
+/// See docs: <https://developers.binance.com/docs/binance-spot-api-docs/testnet/rest-api>
+pub const ENDPOINTS_BINANCE_SPOT: VenueEndpoints = VenueEndpoints {
+    rest: EnvironmentUrl::new("https://api.binance.com", "https://testnet.binance.vision"),
+    websocket_private: EnvironmentUrl::new(
+        "wss://stream.binance.com:9443/ws",
+        "wss://testnet.binance.vision/ws",
+    ),
+};
+
+/// `BinanceFuturesUsd` trading API [`VenueEndpoints`].
+///
+/// See docs: <https://developers.binance.com/docs/derivatives/usds-margined-futures/general-info>
+pub const ENDPOINTS_BINANCE_FUTURES_USD: VenueEndpoints = VenueEndpoints {
+    rest: EnvironmentUrl::new(
+        "https://fapi.binance.com",
+        "https://testnet.binancefuture.com",
+    ),
+    websocket_private: EnvironmentUrl::new(
+        "wss://fstream.binance.com/ws",
+        "wss://stream.binancefuture.com/ws",
+    ),
+};
+
+/// `Bybit` V5 trading API [`VenueEndpoints`], shared by spot & perpetuals.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/guide>
+pub const ENDPOINTS_BYBIT: VenueEndpoints = VenueEndpoints {
+    rest: EnvironmentUrl::new("https://api.bybit.com", "https://api-testnet.bybit.com"),
+    websocket_private: EnvironmentUrl::new(
+        "wss://stream.bybit.com/v5/private",
+        "wss://stream-testnet.bybit.com/v5/private",
+    ),
+};
+
+/// `Okx` V5 trading API [`VenueEndpoints`]. Demo trading REST requests share the production
+/// url, but must include the [`HEADER_OKX_SIMULATED_TRADING`].
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-demo-trading-services>
+pub const ENDPOINTS_OKX: VenueEndpoints = VenueEndpoints {
+    rest: EnvironmentUrl::new("https://www.okx.com", "https://www.okx.com"),
+    websocket_private: EnvironmentUrl::new(
+        "wss://ws.okx.com:8443/ws/v5/private",
+        "wss://wspap.okx.com:8443/ws/v5/private?brokerId=9999",
+    ),
+};
+
+/// `Dydx` v4 indexer [`VenueEndpoints`], serving account queries & private account updates.
+/// Orders are not placed via the indexer, but broadcast as transactions to a validator node.
+///
+/// See docs: <https://docs.dydx.exchange/infrastructure_providers-network/resources>
+pub const ENDPOINTS_DYDX: VenueEndpoints = VenueEndpoints {
+    rest: EnvironmentUrl::new(
+        "https://indexer.dydx.trade",
+        "https://indexer.v4testnet.dydx.exchange",
+    ),
+    websocket_private: EnvironmentUrl::new(
+        "wss://indexer.dydx.trade/v4/ws",
+        "wss://indexer.v4testnet.dydx.exchange/v4/ws",
+    ),
+};
+
+/// [`VenueEndpoints`] of the provided [`ExchangeId`], if known.
+pub fn endpoints(exchange: ExchangeId) -> Option<VenueEndpoints> {
+    match exchange {
+        ExchangeId::BinanceSpot => Some(ENDPOINTS_BINANCE_SPOT),
+        ExchangeId::BinanceFuturesUsd => Some(ENDPOINTS_BINANCE_FUTURES_USD),
+        ExchangeId::BybitSpot | ExchangeId::BybitPerpetualsUsd => Some(ENDPOINTS_BYBIT),
+        ExchangeId::Dydx => Some(ENDPOINTS_DYDX),
+        ExchangeId::Okx => Some(ENDPOINTS_OKX),
+        _ => None,
+    }
+}
+
+/// REST base url of the provided [`ExchangeId`] in the provided [`Environment`].
+pub fn rest_base_url(
+    exchange: ExchangeId,
+    environment: Environment,
+) -> Result<&'static str, ExecutionError> {
+    endpoints(exchange)
+        .and_then(|endpoints| endpoints.rest.get(environment).ok())
+        .ok_or(ExecutionError::UnsupportedEnvironment {
+            exchange,
+            environment,
+        })
+}
+
+/// Private WebSocket base url of the provided [`ExchangeId`] in the provided [`Environment`].
+pub fn websocket_private_url(
+    exchange: ExchangeId,
+    environment: Environment,
+) -> Result<&'static str, ExecutionError> {
+    endpoints(exchange)
+        .and_then(|endpoints| endpoints.websocket_private.get(environment).ok())
+        .ok_or(ExecutionError::UnsupportedEnvironment {
+            exchange,
+            environment,
+        })
+}
+
+/// Additional HTTP headers required on every REST request to the provided [`ExchangeId`] in the
+/// provided [`Environment`].
+pub fn environment_headers(
+    exchange: ExchangeId,
+    environment: Environment,
+) -> &'static [(&'static str, &'static str)] {
+    match (exchange, environment) {
+        (ExchangeId::Okx, Environment::Testnet) => &[HEADER_OKX_SIMULATED_TRADING],
+        _ => &[],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rest_base_url() {
+        struct TestCase {
+            exchange: ExchangeId,
+            environment: Environment,
+            expected: Result<&'static str, ExecutionError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: BinanceSpot mainnet
+                exchange: ExchangeId::BinanceSpot,
+                environment: Environment::Mainnet,
+                expected: Ok("https://api.binance.com"),
+            },
+            TestCase {
+                // TC1: BinanceSpot testnet
+                exchange: ExchangeId::BinanceSpot,
+                environment: Environment::Testnet,
+                expected: Ok("https://testnet.binance.vision"),
+            },
+            TestCase {
+                // TC2: BybitPerpetualsUsd testnet
+                exchange: ExchangeId::BybitPerpetualsUsd,
+                environment: Environment::Testnet,
+                expected: Ok("https://api-testnet.bybit.com"),
+            },
+            TestCase {
+                // TC3: exchange without known endpoints
+                exchange: ExchangeId::Kraken,
+                environment: Environment::Testnet,
+                expected: Err(ExecutionError::UnsupportedEnvironment {
+                    exchange: ExchangeId::Kraken,
+                    environment: Environment::Testnet,
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = rest_base_url(test.exchange, test.environment);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+
+        assert_eq!(
+            environment_headers(ExchangeId::Okx, Environment::Testnet),
+            &[HEADER_OKX_SIMULATED_TRADING]
+        );
+        assert!(environment_headers(ExchangeId::Okx, Environment::Mainnet).is_empty());
+    }
+}
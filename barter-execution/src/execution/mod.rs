@@ -1,5 +1,12 @@
 /// `Binance` & `BinanceFuturesUsd` [`ExecutionClient`](crate::ExecutionClient) implementations.
 pub mod binance;
 
+/// `Dydx` v4 [`ExecutionClient`](crate::ExecutionClient) implementation.
+pub mod dydx;
+
+/// REST & private WebSocket endpoints of each venue
+/// [`Environment`](barter_integration::environment::Environment) (eg/ mainnet vs testnet).
+pub mod environment;
+
 /// `Ftx` [`ExecutionClient`](crate::ExecutionClient) implementation.
 pub mod ftx;
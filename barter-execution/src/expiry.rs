@@ -0,0 +1,116 @@
+use crate::ExecutionClient;
+use chrono::{DateTime, Utc};
+use std::{sync::Arc, time::Duration};
+use tokio::time::MissedTickBehavior;
+use tracing::{error, info, warn};
+
+/// Run a periodic order expiry task, cancelling every account [`Order<Open>`] that has expired at
+/// the time of the provided clock, and whose [`TimeInForce`] is not natively enforced by the
+/// exchange (see [`ExecutionClient::cancel_orders_expired`]).
+///
+/// The clock is usually the system time (ie/ `Utc::now`), or the engine clock when backtesting,
+/// so expiry does not depend on market data arriving. Runs until cancelling fails with an
+/// unrecoverable [`ExecutionError`](crate::error::ExecutionError).
+///
+/// [`Order<Open>`]: crate::model::order::Order
+/// [`TimeInForce`]: crate::model::order::TimeInForce
+pub async fn run_order_expiry<Client, Clock>(client: Arc<Client>, clock: Clock, interval: Duration)
+where
+    Client: ExecutionClient + Send + Sync,
+    Clock: Fn() -> DateTime<Utc>,
+{
+    let mut interval = tokio::time::interval(interval);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+    loop {
+        interval.tick().await;
+
+        let cancelled = match client.cancel_orders_expired(clock()).await {
+            Ok(cancelled) => cancelled,
+            Err(error) if error.is_unrecoverable() => {
+                error!(
+                    exchange = %Client::CLIENT,
+                    %error,
+                    "stopping order expiry due to unrecoverable error"
+                );
+                break;
+            }
+            Err(error) => {
+                warn!(
+                    exchange = %Client::CLIENT,
+                    %error,
+                    "failed to fetch open orders for expiry"
+                );
+                continue;
+            }
+        };
+
+        for result in cancelled {
+            match result {
+                Ok(order) => info!(
+                    exchange = %Client::CLIENT,
+                    cid = ?order.cid,
+                    "cancelled expired GoodTillDate order"
+                ),
+                Err(error) => warn!(
+                    exchange = %Client::CLIENT,
+                    %error,
+                    "failed to cancel expired GoodTillDate order"
+                ),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::{
+            order::{OrderId, TimeInForce},
+            ClientOrderId,
+        },
+        test_util::{order_open, MockExecution},
+    };
+    use barter_integration::Side;
+    use parking_lot::Mutex;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_run_order_expiry_cancels_expired_orders() {
+        let now = Utc::now();
+        let order = |id: &str, time_in_force| {
+            let mut order = order_open(ClientOrderId(Uuid::new_v4()), Side::Buy, 100.0, 1.0, 0.0);
+            order.state.id = OrderId::from(id);
+            order.state.time_in_force = time_in_force;
+            order
+        };
+
+        let client = Arc::new(MockExecution {
+            orders: Mutex::new(vec![
+                order("expired", TimeInForce::GoodTillDate(now)),
+                order(
+                    "live",
+                    TimeInForce::GoodTillDate(now + chrono::Duration::hours(1)),
+                ),
+                order("gtc", TimeInForce::GoodUntilCancelled),
+            ]),
+        });
+
+        let task = tokio::spawn(run_order_expiry(
+            Arc::clone(&client),
+            Utc::now,
+            Duration::from_millis(10),
+        ));
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        task.abort();
+
+        let remaining = client
+            .orders
+            .lock()
+            .iter()
+            .map(|order| order.state.id.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(remaining, vec![OrderId::from("live"), OrderId::from("gtc")]);
+    }
+}
@@ -0,0 +1,581 @@
+use crate::{
+    error::ExecutionError,
+    model::{
+        balance::SymbolBalance,
+        combo::{ComboFilled, ComboOrder, RequestOpenCombo},
+        margin::MarginConfig,
+        order::{Cancelled, Open, Order, RequestCancel, RequestOpen, TimeInForce},
+        trade::TradeRecord,
+        transfer::{Transfer, TransferRequest, WithdrawRequest, Withdrawal},
+        AccountEvent, ClientOrderId,
+    },
+    ExecutionClient,
+};
+use async_trait::async_trait;
+use barter_instrument::{exchange::ExchangeId, instrument::Instrument};
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use smol_str::SmolStr;
+use std::{
+    collections::{HashMap, HashSet},
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+};
+use tokio::sync::mpsc;
+use tracing::warn;
+use uuid::Uuid;
+
+/// Persistent record of every [`ClientOrderId`] issued to an exchange.
+pub trait ClientOrderIdStore {
+    /// Determine if the [`ClientOrderId`] has already been issued.
+    fn contains(&self, cid: &ClientOrderId) -> bool;
+
+    /// Record the [`ClientOrderId`] as issued. Must only return once the [`ClientOrderId`] is
+    /// durable, since it will be submitted to the exchange immediately after.
+    fn insert(&mut self, cid: ClientOrderId) -> Result<(), ExecutionError>;
+}
+
+/// In-memory [`ClientOrderIdStore`] that does not survive a restart, useful for testing &
+/// simulations.
+impl ClientOrderIdStore for HashSet<ClientOrderId> {
+    fn contains(&self, cid: &ClientOrderId) -> bool {
+        HashSet::contains(self, cid)
+    }
+
+    fn insert(&mut self, cid: ClientOrderId) -> Result<(), ExecutionError> {
+        HashSet::insert(self, cid);
+        Ok(())
+    }
+}
+
+/// Append-only file backed [`ClientOrderIdStore`], persisting one [`ClientOrderId`] per line.
+#[derive(Debug)]
+pub struct FileClientOrderIdStore {
+    path: PathBuf,
+    file: File,
+    issued: HashSet<ClientOrderId>,
+}
+
+impl FileClientOrderIdStore {
+    /// Open the [`FileClientOrderIdStore`] at the provided path, loading every previously
+    /// issued [`ClientOrderId`]. The file is created if it does not exist.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ExecutionError> {
+        let path = path.as_ref().to_path_buf();
+        let file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(&path)
+            .map_err(store_error)?;
+
+        let mut issued = HashSet::new();
+        for line in BufReader::new(&file).lines() {
+            let line = line.map_err(store_error)?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            // A partially written last line (eg/ crash mid-write) was never submitted
+            match Uuid::parse_str(line) {
+                Ok(cid) => {
+                    issued.insert(ClientOrderId(cid));
+                }
+                Err(error) => warn!(
+                    path = %path.display(),
+                    %line,
+                    %error,
+                    "skipping invalid ClientOrderId in FileClientOrderIdStore"
+                ),
+            }
+        }
+
+        Ok(Self { path, file, issued })
+    }
+
+    /// Path of the file backing this [`FileClientOrderIdStore`].
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl ClientOrderIdStore for FileClientOrderIdStore {
+    fn contains(&self, cid: &ClientOrderId) -> bool {
+        self.issued.contains(cid)
+    }
+
+    fn insert(&mut self, cid: ClientOrderId) -> Result<(), ExecutionError> {
+        if self.issued.contains(&cid) {
+            return Ok(());
+        }
+
+        writeln!(self.file, "{cid}").map_err(store_error)?;
+        self.file.sync_data().map_err(store_error)?;
+        self.issued.insert(cid);
+        Ok(())
+    }
+}
+
+fn store_error(error: std::io::Error) -> ExecutionError {
+    ExecutionError::ClientOrderIdStore(error.to_string())
+}
+
+/// Generates deterministic [`ClientOrderId`]s for the order requests of a strategy, using an
+/// incrementing sequence number per [`Instrument`].
+///
+/// Sequences whose [`ClientOrderId`] has already been issued are skipped, so a generator
+/// restarted with the [`ClientOrderIdStore`] persisted before the restart continues after the
+/// last issued [`ClientOrderId`] rather than colliding with it.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ClientOrderIdGenerator {
+    strategy: SmolStr,
+    sequences: HashMap<Instrument, u64>,
+}
+
+impl ClientOrderIdGenerator {
+    /// Construct a new [`ClientOrderIdGenerator`] for the provided strategy, with every
+    /// [`Instrument`] sequence starting at 0.
+    pub fn new(strategy: impl Into<SmolStr>) -> Self {
+        Self {
+            strategy: strategy.into(),
+            sequences: HashMap::new(),
+        }
+    }
+
+    /// Generate the next [`ClientOrderId`] of the provided [`Instrument`] that has not been
+    /// issued according to the [`ClientOrderIdStore`].
+    pub fn next<Store>(&mut self, instrument: &Instrument, issued: &Store) -> ClientOrderId
+    where
+        Store: ClientOrderIdStore,
+    {
+        let sequence = self.sequences.entry(instrument.clone()).or_default();
+        loop {
+            let cid = ClientOrderId::deterministic(&self.strategy, instrument, *sequence);
+            *sequence += 1;
+            if !issued.contains(&cid) {
+                return cid;
+            }
+        }
+    }
+}
+
+/// Idempotent order submission, rejecting any [`Order<RequestOpen>`] whose [`ClientOrderId`]
+/// has already been issued with an [`ExecutionError::DuplicateClientOrderId`].
+///
+/// Each [`ClientOrderId`] is persisted to the [`ClientOrderIdStore`] before the request is
+/// submitted, so a crash between the two can never lead to the request being submitted twice.
+#[derive(Debug)]
+pub struct OrderDeduplicator<Store> {
+    store: Store,
+}
+
+impl<Store> OrderDeduplicator<Store>
+where
+    Store: ClientOrderIdStore,
+{
+    /// Construct a new [`OrderDeduplicator`] using the provided [`ClientOrderIdStore`].
+    pub fn new(store: Store) -> Self {
+        Self { store }
+    }
+
+    /// Reference to the [`ClientOrderIdStore`].
+    pub fn store(&self) -> &Store {
+        &self.store
+    }
+
+    /// Admit every [`Order<RequestOpen>`] whose [`ClientOrderId`] has not been issued before,
+    /// persisting it as issued. Results are returned in the same order as the requests.
+    pub fn admit(
+        &mut self,
+        requests: Vec<Order<RequestOpen>>,
+    ) -> Vec<Result<Order<RequestOpen>, ExecutionError>> {
+        requests
+            .into_iter()
+            .map(|request| {
+                if self.store.contains(&request.cid) {
+                    warn!(
+                        exchange = %request.exchange,
+                        instrument = %request.instrument,
+                        cid = %request.cid,
+                        "rejected duplicate order request"
+                    );
+                    return Err(ExecutionError::DuplicateClientOrderId(request.cid));
+                }
+
+                self.store.insert(request.cid).map(|_| request)
+            })
+            .collect()
+    }
+
+    /// Open every admitted [`Order<RequestOpen>`] using the provided [`ExecutionClient`].
+    /// Results are returned in the same order as the requests.
+    pub async fn open_orders<Client>(
+        &mut self,
+        client: &Client,
+        requests: Vec<Order<RequestOpen>>,
+    ) -> Vec<Result<Order<Open>, ExecutionError>>
+    where
+        Client: ExecutionClient + Sync,
+    {
+        open_admitted(client, self.admit(requests)).await
+    }
+}
+
+/// Open every admitted [`Order<RequestOpen>`] using the provided [`ExecutionClient`], passing
+/// through the errors of those rejected. Results are returned in the same order as the requests.
+async fn open_admitted<Client>(
+    client: &Client,
+    admitted: Vec<Result<Order<RequestOpen>, ExecutionError>>,
+) -> Vec<Result<Order<Open>, ExecutionError>>
+where
+    Client: ExecutionClient + Sync,
+{
+    let mut open_requests = Vec::new();
+    let admitted = admitted
+        .into_iter()
+        .map(|request| {
+            request.map(|request| {
+                let cid = request.cid;
+                open_requests.push(request);
+                cid
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut opened = if open_requests.is_empty() {
+        Vec::new()
+    } else {
+        client.open_orders(open_requests).await
+    }
+    .into_iter();
+
+    admitted
+        .into_iter()
+        .map(|admitted| {
+            let cid = admitted?;
+            opened
+                .next()
+                .unwrap_or(Err(ExecutionError::OrderNotFound(cid)))
+        })
+        .collect()
+}
+
+/// [`ExecutionClient`] that routes every order submission through an [`OrderDeduplicator`],
+/// so an [`Order<RequestOpen>`] whose [`ClientOrderId`] was already issued (eg/ replayed after
+/// a restart) is never submitted to the exchange twice.
+///
+/// Every other request is delegated to the inner [`ExecutionClient`].
+#[derive(Debug)]
+pub struct IdempotentExecution<Client, Store> {
+    client: Client,
+    deduplicator: Mutex<OrderDeduplicator<Store>>,
+}
+
+impl<Client, Store> IdempotentExecution<Client, Store>
+where
+    Store: ClientOrderIdStore,
+{
+    /// Construct a new [`IdempotentExecution`] wrapping the provided [`ExecutionClient`], using
+    /// the provided [`ClientOrderIdStore`].
+    pub fn new(client: Client, store: Store) -> Self {
+        Self {
+            client,
+            deduplicator: Mutex::new(OrderDeduplicator::new(store)),
+        }
+    }
+
+    /// Reference to the inner [`ExecutionClient`].
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Generate the next [`ClientOrderId`] of the provided [`Instrument`] that has not yet been
+    /// issued to the exchange.
+    pub fn next_cid(
+        &self,
+        generator: &mut ClientOrderIdGenerator,
+        instrument: &Instrument,
+    ) -> ClientOrderId {
+        generator.next(instrument, self.deduplicator.lock().store())
+    }
+}
+
+#[async_trait]
+impl<Client, Store> ExecutionClient for IdempotentExecution<Client, Store>
+where
+    Client: ExecutionClient + Send + Sync,
+    Client::Config: Send,
+    Store: ClientOrderIdStore + Send,
+{
+    const CLIENT: ExchangeId = Client::CLIENT;
+    type Config = (Client::Config, Store);
+
+    async fn init(
+        (config, store): Self::Config,
+        event_tx: mpsc::UnboundedSender<AccountEvent>,
+    ) -> Self {
+        Self::new(Client::init(config, event_tx).await, store)
+    }
+
+    async fn fetch_orders_open(&self) -> Result<Vec<Order<Open>>, ExecutionError> {
+        self.client.fetch_orders_open().await
+    }
+
+    async fn fetch_balances(&self) -> Result<Vec<SymbolBalance>, ExecutionError> {
+        self.client.fetch_balances().await
+    }
+
+    async fn open_orders(
+        &self,
+        open_requests: Vec<Order<RequestOpen>>,
+    ) -> Vec<Result<Order<Open>, ExecutionError>> {
+        let admitted = self.deduplicator.lock().admit(open_requests);
+        open_admitted(&self.client, admitted).await
+    }
+
+    async fn cancel_orders(
+        &self,
+        cancel_requests: Vec<Order<RequestCancel>>,
+    ) -> Vec<Result<Order<Cancelled>, ExecutionError>> {
+        self.client.cancel_orders(cancel_requests).await
+    }
+
+    async fn cancel_orders_all(&self) -> Result<Vec<Order<Cancelled>>, ExecutionError> {
+        self.client.cancel_orders_all().await
+    }
+
+    fn supports_time_in_force(time_in_force: &TimeInForce) -> bool {
+        Client::supports_time_in_force(time_in_force)
+    }
+
+    async fn cancel_orders_expired(
+        &self,
+        time: DateTime<Utc>,
+    ) -> Result<Vec<Result<Order<Cancelled>, ExecutionError>>, ExecutionError>
+    where
+        Self: Sync,
+    {
+        self.client.cancel_orders_expired(time).await
+    }
+
+    fn supports_combo_orders() -> bool {
+        Client::supports_combo_orders()
+    }
+
+    async fn open_combo_orders(
+        &self,
+        combo_requests: Vec<ComboOrder<RequestOpenCombo>>,
+    ) -> Vec<Result<ComboOrder<ComboFilled>, ExecutionError>> {
+        self.client.open_combo_orders(combo_requests).await
+    }
+
+    fn supports_transfers() -> bool {
+        Client::supports_transfers()
+    }
+
+    async fn transfer(&self, request: TransferRequest) -> Result<Transfer, ExecutionError> {
+        self.client.transfer(request).await
+    }
+
+    fn supports_withdrawals() -> bool {
+        Client::supports_withdrawals()
+    }
+
+    async fn withdraw(&self, request: WithdrawRequest) -> Result<Withdrawal, ExecutionError> {
+        self.client.withdraw(request).await
+    }
+
+    fn supports_margin() -> bool {
+        Client::supports_margin()
+    }
+
+    async fn set_margin(&self, config: MarginConfig) -> Result<MarginConfig, ExecutionError> {
+        self.client.set_margin(config).await
+    }
+
+    fn supports_trade_history() -> bool {
+        Client::supports_trade_history()
+    }
+
+    async fn fetch_trades(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<TradeRecord>, ExecutionError> {
+        self.client.fetch_trades(from, to).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{model::order::OrderKind, test_util::MockExecution};
+    use barter_instrument::{exchange::ExchangeId, instrument::kind::InstrumentKind};
+    use barter_integration::Side;
+
+    fn request_open(cid: ClientOrderId) -> Order<RequestOpen> {
+        Order {
+            exchange: ExchangeId::Other,
+            instrument: Instrument::from(("base", "quote", InstrumentKind::Perpetual)),
+            cid,
+            side: Side::Buy,
+            state: RequestOpen {
+                kind: OrderKind::Limit,
+                price: 100.0,
+                quantity: 1.0,
+                time_in_force: Default::default(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_client_order_id_generator_is_deterministic() {
+        let btc = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let eth = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+
+        let issued = HashSet::new();
+        let mut generator = ClientOrderIdGenerator::new("strategy_a");
+        let first = [
+            generator.next(&btc, &issued),
+            generator.next(&btc, &issued),
+            generator.next(&eth, &issued),
+        ];
+
+        // Generator with the same strategy & no issued ClientOrderIds generates the same ones
+        let mut regenerated = ClientOrderIdGenerator::new("strategy_a");
+        let replayed = [
+            regenerated.next(&btc, &issued),
+            regenerated.next(&btc, &issued),
+            regenerated.next(&eth, &issued),
+        ];
+        assert_eq!(first, replayed);
+        assert_eq!(
+            first[0],
+            ClientOrderId::deterministic("strategy_a", &btc, 0)
+        );
+        assert_eq!(first[0].0.get_version_num(), 5);
+
+        // Sequence, Instrument & strategy each change the ClientOrderId
+        assert_ne!(first[0], first[1]);
+        assert_ne!(
+            first[0],
+            ClientOrderId::deterministic("strategy_a", &eth, 0)
+        );
+        assert_ne!(
+            first[0],
+            ClientOrderId::deterministic("strategy_b", &btc, 0)
+        );
+    }
+
+    #[test]
+    fn test_client_order_id_generator_skips_issued_after_restart() {
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let cid = |sequence| ClientOrderId::deterministic("strategy", &instrument, sequence);
+
+        // ClientOrderIds issued before the restart, & persisted in the ClientOrderIdStore
+        let issued = HashSet::from([cid(0), cid(1)]);
+
+        // Restarted generator continues after the issued ClientOrderIds, rather than colliding
+        let mut restarted = ClientOrderIdGenerator::new("strategy");
+        assert_eq!(restarted.next(&instrument, &issued), cid(2));
+        assert_eq!(restarted.next(&instrument, &issued), cid(3));
+
+        let mut deduplicator = OrderDeduplicator::new(issued);
+        assert!(deduplicator.admit(vec![request_open(cid(2))])[0].is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_idempotent_execution_never_submits_duplicates() {
+        let instrument = Instrument::from(("base", "quote", InstrumentKind::Perpetual));
+        let execution = IdempotentExecution::new(MockExecution::default(), HashSet::new());
+        let mut generator = ClientOrderIdGenerator::new("strategy");
+
+        let cid = execution.next_cid(&mut generator, &instrument);
+        let opened = execution.open_orders(vec![request_open(cid)]).await;
+        assert_eq!(opened[0].as_ref().unwrap().cid, cid);
+
+        // Replayed request is rejected without being submitted to the exchange
+        let replayed = execution.open_orders(vec![request_open(cid)]).await;
+        assert_eq!(
+            replayed,
+            vec![Err(ExecutionError::DuplicateClientOrderId(cid))]
+        );
+        assert_eq!(execution.client().orders.lock().len(), 1);
+
+        // Next ClientOrderId skips the issued one
+        assert_ne!(execution.next_cid(&mut generator, &instrument), cid);
+    }
+
+    #[test]
+    fn test_order_deduplicator_admit() {
+        struct TestCase {
+            input: Vec<ClientOrderId>,
+            expected: Vec<Result<ClientOrderId, ExecutionError>>,
+        }
+
+        let instrument = Instrument::from(("base", "quote", InstrumentKind::Perpetual));
+        let cid = |sequence| ClientOrderId::deterministic("strategy", &instrument, sequence);
+
+        let mut deduplicator = OrderDeduplicator::new(HashSet::new());
+
+        let tests = vec![
+            TestCase {
+                // TC0: new ClientOrderIds are admitted
+                input: vec![cid(0), cid(1)],
+                expected: vec![Ok(cid(0)), Ok(cid(1))],
+            },
+            TestCase {
+                // TC1: replayed ClientOrderIds are rejected, new ClientOrderId is admitted
+                input: vec![cid(0), cid(2)],
+                expected: vec![
+                    Err(ExecutionError::DuplicateClientOrderId(cid(0))),
+                    Ok(cid(2)),
+                ],
+            },
+            TestCase {
+                // TC2: duplicate ClientOrderId within the same batch is rejected
+                input: vec![cid(3), cid(3)],
+                expected: vec![
+                    Ok(cid(3)),
+                    Err(ExecutionError::DuplicateClientOrderId(cid(3))),
+                ],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = deduplicator
+                .admit(test.input.into_iter().map(request_open).collect())
+                .into_iter()
+                .map(|result| result.map(|request| request.cid))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_file_client_order_id_store_survives_restart() {
+        let path = std::env::temp_dir().join(format!(
+            "barter_client_order_id_store_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        let instrument = Instrument::from(("base", "quote", InstrumentKind::Perpetual));
+        let cid = ClientOrderId::deterministic("strategy", &instrument, 0);
+
+        let mut deduplicator = OrderDeduplicator::new(FileClientOrderIdStore::open(&path).unwrap());
+        assert!(deduplicator.admit(vec![request_open(cid)])[0].is_ok());
+        drop(deduplicator);
+
+        // Restarted OrderDeduplicator rejects the replayed request
+        let mut deduplicator = OrderDeduplicator::new(FileClientOrderIdStore::open(&path).unwrap());
+        assert!(deduplicator.store().contains(&cid));
+        assert_eq!(
+            deduplicator.admit(vec![request_open(cid)])[0],
+            Err(ExecutionError::DuplicateClientOrderId(cid))
+        );
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
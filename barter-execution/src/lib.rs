@@ -25,12 +25,17 @@ use crate::{
     error::ExecutionError,
     model::{
         balance::SymbolBalance,
-        order::{Cancelled, Open, Order, OrderId, RequestCancel, RequestOpen},
+        combo::{ComboFilled, ComboOrder, RequestOpenCombo},
+        margin::MarginConfig,
+        order::{Cancelled, Open, Order, OrderId, RequestCancel, RequestOpen, TimeInForce},
+        trade::TradeRecord,
+        transfer::{Transfer, TransferRequest, WithdrawRequest, Withdrawal},
         AccountEvent,
     },
 };
 use async_trait::async_trait;
 use barter_instrument::exchange::ExchangeId;
+use chrono::{DateTime, Utc};
 use tokio::sync::mpsc;
 
 /// Errors generated during live, dry, or simulated execution.
@@ -47,6 +52,20 @@ pub mod execution;
 /// Simulated Exchange and it's associated simulated [`ExecutionClient`].
 pub mod simulated;
 
+/// Periodic reconciliation of the locally tracked open orders against those of the exchange.
+pub mod reconcile;
+
+/// Engine-side enforcement of [`TimeInForce::GoodTillDate`] on exchanges that do not support it
+/// natively, by periodically cancelling expired orders.
+pub mod expiry;
+
+/// Idempotent order submission, deduplicating order requests by [`ClientOrderId`] using a
+/// persistent [`ClientOrderIdStore`](idempotency::ClientOrderIdStore) so that requests replayed
+/// after a restart are never submitted twice.
+///
+/// [`ClientOrderId`]: model::ClientOrderId
+pub mod idempotency;
+
 /// Defines the communication with the exchange. Each exchange integration requires it's own
 /// implementation.
 #[async_trait]
@@ -82,6 +101,142 @@ pub trait ExecutionClient {
 
     /// Cancel all account [`Order<Open>`]s.
     async fn cancel_orders_all(&self) -> Result<Vec<Order<Cancelled>>, ExecutionError>;
+
+    /// Determine if the exchange natively enforces the provided [`TimeInForce`].
+    ///
+    /// Defaults to only [`TimeInForce::GoodUntilCancelled`]. Integrations for exchanges with a
+    /// native GTD time in force should override this to map [`TimeInForce::GoodTillDate`] directly.
+    fn supports_time_in_force(time_in_force: &TimeInForce) -> bool {
+        matches!(time_in_force, TimeInForce::GoodUntilCancelled)
+    }
+
+    /// Cancel every account [`Order<Open>`] that has expired at the provided time, and whose
+    /// [`TimeInForce`] is not natively enforced by the exchange.
+    ///
+    /// Called periodically by [`run_order_expiry`](expiry::run_order_expiry) to enforce
+    /// [`TimeInForce::GoodTillDate`] on exchanges that do not support it natively.
+    async fn cancel_orders_expired(
+        &self,
+        time: DateTime<Utc>,
+    ) -> Result<Vec<Result<Order<Cancelled>, ExecutionError>>, ExecutionError>
+    where
+        Self: Sync,
+    {
+        let cancel_requests = self
+            .fetch_orders_open()
+            .await?
+            .iter()
+            .filter(|order| {
+                !Self::supports_time_in_force(&order.state.time_in_force) && order.is_expired(time)
+            })
+            .map(Order::from)
+            .collect::<Vec<Order<RequestCancel>>>();
+
+        if cancel_requests.is_empty() {
+            return Ok(vec![]);
+        }
+
+        Ok(self.cancel_orders(cancel_requests).await)
+    }
+
+    /// Determine if the exchange natively supports atomic multi-leg [`ComboOrder`]s (eg/ Deribit
+    /// combo books, OKX spreads).
+    ///
+    /// Defaults to `false`.
+    fn supports_combo_orders() -> bool {
+        false
+    }
+
+    /// Open multi-leg [`ComboOrder`]s, where every leg of each [`ComboOrder`] is filled
+    /// atomically.
+    ///
+    /// Defaults to failing every request with [`ExecutionError::UnsupportedComboOrders`].
+    /// Integrations for exchanges with native combos should override this alongside
+    /// [`Self::supports_combo_orders`].
+    async fn open_combo_orders(
+        &self,
+        combo_requests: Vec<ComboOrder<RequestOpenCombo>>,
+    ) -> Vec<Result<ComboOrder<ComboFilled>, ExecutionError>> {
+        combo_requests
+            .into_iter()
+            .map(|_| Err(ExecutionError::UnsupportedComboOrders(Self::CLIENT)))
+            .collect()
+    }
+
+    /// Determine if the exchange supports [`TransferRequest`]s between the
+    /// [`Wallet`](model::transfer::Wallet)s of the account (eg/ spot to futures).
+    ///
+    /// Defaults to `false`.
+    fn supports_transfers() -> bool {
+        false
+    }
+
+    /// Transfer a [`Symbol`](barter_instrument::asset::symbol::Symbol) quantity between two
+    /// [`Wallet`](model::transfer::Wallet)s of the account.
+    ///
+    /// Defaults to failing with [`ExecutionError::UnsupportedTransfer`]. Integrations for
+    /// exchanges with a transfer API should override this alongside
+    /// [`Self::supports_transfers`].
+    async fn transfer(&self, _request: TransferRequest) -> Result<Transfer, ExecutionError> {
+        Err(ExecutionError::UnsupportedTransfer(Self::CLIENT))
+    }
+
+    /// Determine if the exchange supports [`WithdrawRequest`]s.
+    ///
+    /// Defaults to `false`.
+    fn supports_withdrawals() -> bool {
+        false
+    }
+
+    /// Withdraw a [`Symbol`](barter_instrument::asset::symbol::Symbol) quantity from the account
+    /// to an external address.
+    ///
+    /// Defaults to failing with [`ExecutionError::UnsupportedWithdraw`]. Integrations for
+    /// exchanges with a withdrawal API should override this alongside
+    /// [`Self::supports_withdrawals`].
+    async fn withdraw(&self, _request: WithdrawRequest) -> Result<Withdrawal, ExecutionError> {
+        Err(ExecutionError::UnsupportedWithdraw(Self::CLIENT))
+    }
+
+    /// Determine if the exchange supports configuring the leverage &
+    /// [`MarginMode`](model::margin::MarginMode) of an instrument.
+    ///
+    /// Defaults to `false`.
+    fn supports_margin() -> bool {
+        false
+    }
+
+    /// Set the leverage & [`MarginMode`](model::margin::MarginMode) of an instrument, returning
+    /// the [`MarginConfig`] applied by the exchange. Resulting margin ratios & liquidation prices
+    /// are yielded as [`AccountEventKind::Margin`](model::AccountEventKind::Margin) updates.
+    ///
+    /// Defaults to failing with [`ExecutionError::UnsupportedMargin`]. Integrations for
+    /// exchanges with a leverage API should override this alongside [`Self::supports_margin`].
+    async fn set_margin(&self, _config: MarginConfig) -> Result<MarginConfig, ExecutionError> {
+        Err(ExecutionError::UnsupportedMargin(Self::CLIENT))
+    }
+
+    /// Determine if the exchange supports fetching the account trade history.
+    ///
+    /// Defaults to `false`.
+    fn supports_trade_history() -> bool {
+        false
+    }
+
+    /// Fetch every client [`TradeRecord`] executed by the exchange in the time range
+    /// `[from, to)`, oldest first. Used to reconcile persisted fills against the exchange (eg/ for
+    /// accounting & tax reporting).
+    ///
+    /// Defaults to failing with [`ExecutionError::UnsupportedTradeHistory`]. Integrations for
+    /// exchanges with a trade history REST endpoint should override this alongside
+    /// [`Self::supports_trade_history`], paginating until the whole time range is fetched.
+    async fn fetch_trades(
+        &self,
+        _from: DateTime<Utc>,
+        _to: DateTime<Utc>,
+    ) -> Result<Vec<TradeRecord>, ExecutionError> {
+        Err(ExecutionError::UnsupportedTradeHistory(Self::CLIENT))
+    }
 }
 
 /// Utilities for generating common data structures required for testing.
@@ -92,14 +247,83 @@ pub mod test_util {
             ClientOrderId,
         },
         simulated::exchange::account::order::Orders,
-        Open, Order, OrderId,
+        AccountEvent, Cancelled, ExecutionClient, ExecutionError, Open, Order, OrderId,
+        RequestCancel, RequestOpen, SymbolBalance, TimeInForce,
     };
+    use async_trait::async_trait;
     use barter_data::subscription::trade::PublicTrade;
     use barter_instrument::{
         exchange::ExchangeId,
         instrument::{kind::InstrumentKind, Instrument},
     };
     use barter_integration::Side;
+    use parking_lot::Mutex;
+    use tokio::sync::mpsc;
+
+    /// [`ExecutionClient`] that opens every requested order immediately (with the
+    /// [`ClientOrderId`] as it's [`OrderId`]), and natively enforces no [`TimeInForce`] other
+    /// than [`TimeInForce::GoodUntilCancelled`].
+    #[derive(Debug, Default)]
+    pub struct MockExecution {
+        pub orders: Mutex<Vec<Order<Open>>>,
+    }
+
+    #[async_trait]
+    impl ExecutionClient for MockExecution {
+        const CLIENT: ExchangeId = ExchangeId::Other;
+        type Config = ();
+
+        async fn init(_: Self::Config, _: mpsc::UnboundedSender<AccountEvent>) -> Self {
+            Self::default()
+        }
+
+        async fn fetch_orders_open(&self) -> Result<Vec<Order<Open>>, ExecutionError> {
+            Ok(self.orders.lock().clone())
+        }
+
+        async fn fetch_balances(&self) -> Result<Vec<SymbolBalance>, ExecutionError> {
+            Ok(vec![])
+        }
+
+        async fn open_orders(
+            &self,
+            open_requests: Vec<Order<RequestOpen>>,
+        ) -> Vec<Result<Order<Open>, ExecutionError>> {
+            let mut orders = self.orders.lock();
+            open_requests
+                .into_iter()
+                .map(|request| {
+                    let open = Order::from((OrderId::from(request.cid.to_string()), request));
+                    orders.push(open.clone());
+                    Ok(open)
+                })
+                .collect()
+        }
+
+        async fn cancel_orders(
+            &self,
+            cancel_requests: Vec<Order<RequestCancel>>,
+        ) -> Vec<Result<Order<Cancelled>, ExecutionError>> {
+            let mut orders = self.orders.lock();
+            cancel_requests
+                .into_iter()
+                .map(|request| {
+                    orders.retain(|order| order.state.id != request.state.id);
+                    Ok(Order {
+                        exchange: request.exchange,
+                        instrument: request.instrument,
+                        cid: request.cid,
+                        side: request.side,
+                        state: Cancelled::from(request.state.id),
+                    })
+                })
+                .collect()
+        }
+
+        async fn cancel_orders_all(&self) -> Result<Vec<Order<Cancelled>>, ExecutionError> {
+            Ok(vec![])
+        }
+    }
 
     pub fn client_orders(
         trade_number: u64,
@@ -130,6 +354,7 @@ pub mod test_util {
                 price,
                 quantity,
                 filled_quantity: filled,
+                time_in_force: TimeInForce::default(),
             },
         }
     }
@@ -140,6 +365,7 @@ pub mod test_util {
             price,
             amount,
             side,
+            aggregate_count: None,
         }
     }
 
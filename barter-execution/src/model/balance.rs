@@ -60,6 +60,27 @@ impl BalanceDelta {
     }
 }
 
+/// Difference between the [`Balance`] of a [`Symbol`] tracked locally & the [`Balance`]
+/// reported by the exchange, exceeding the reconciliation tolerance.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BalanceDrift {
+    pub symbol: Symbol,
+    pub local: Balance,
+    pub exchange: Balance,
+}
+
+impl BalanceDrift {
+    /// Calculate the `total` (exchange - local) drift.
+    pub fn total(&self) -> f64 {
+        self.exchange.total - self.local.total
+    }
+
+    /// Calculate the `available` (exchange - local) drift.
+    pub fn available(&self) -> f64 {
+        self.exchange.available - self.local.available
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
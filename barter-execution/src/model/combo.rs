@@ -0,0 +1,327 @@
+use super::{
+    order::{Order, OrderId, OrderKind, RequestOpen, TimeInForce},
+    trade::Trade,
+    ClientOrderId,
+};
+use crate::error::ExecutionError;
+use barter_instrument::{exchange::ExchangeId, instrument::Instrument};
+use barter_integration::Side;
+use serde::{Deserialize, Serialize};
+
+/// Multi-leg combo order (eg/ vertical spread, straddle) that is executed atomically - either every
+/// [`ComboLeg`] is filled, or none are.
+///
+/// Used for exchanges that support native combos, eg/ Deribit combo books & OKX spreads.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ComboOrder<State> {
+    pub exchange: ExchangeId,
+    pub cid: ClientOrderId,
+    pub legs: Vec<ComboLeg>,
+    pub state: State,
+}
+
+/// Single leg of a [`ComboOrder`].
+///
+/// The leg quantity is the `ratio` multiplied by the [`ComboOrder`] quantity, eg/ a 1x2 ratio
+/// spread has legs with ratios of 1.0 & 2.0.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ComboLeg {
+    pub instrument: Instrument,
+    pub side: Side,
+    pub ratio: f64,
+}
+
+impl ComboLeg {
+    /// Construct a new [`ComboLeg`].
+    pub fn new<I>(instrument: I, side: Side, ratio: f64) -> Self
+    where
+        I: Into<Instrument>,
+    {
+        Self {
+            instrument: instrument.into(),
+            side,
+            ratio,
+        }
+    }
+
+    /// Calculate the quantity of this [`ComboLeg`] for the provided [`ComboOrder`] quantity.
+    pub fn quantity(&self, combo_quantity: f64) -> f64 {
+        self.ratio * combo_quantity
+    }
+
+    /// Contribution of this [`ComboLeg`] to the net price of one [`ComboOrder`] unit when executed
+    /// at the provided price. [`Side::Buy`] legs are debits (positive), and [`Side::Sell`] legs
+    /// are credits (negative).
+    pub fn net_price_contribution(&self, price: f64) -> f64 {
+        match self.side {
+            Side::Buy => self.ratio * price,
+            Side::Sell => -self.ratio * price,
+        }
+    }
+}
+
+/// The initial state of a [`ComboOrder`]. Sent to the [`ExecutionClient`](crate::ExecutionClient)
+/// for actioning.
+///
+/// The `net_price` is the limit price of one [`ComboOrder`] unit. A positive `net_price` is the
+/// maximum net debit paid, and a negative `net_price` is the minimum net credit received.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct RequestOpenCombo {
+    pub net_price: f64,
+    pub quantity: f64,
+}
+
+/// State of a [`ComboOrder`] after every [`ComboLeg`] has been filled.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ComboFilled {
+    pub id: OrderId,
+    pub net_price: f64,
+    pub quantity: f64,
+    pub trades: Vec<Trade>,
+}
+
+impl ComboOrder<RequestOpenCombo> {
+    /// Construct a vertical spread [`ComboOrder`] that buys one option & sells another of the
+    /// same kind and expiry at a different strike.
+    pub fn vertical_spread<I>(
+        exchange: ExchangeId,
+        cid: ClientOrderId,
+        long: I,
+        short: I,
+        net_price: f64,
+        quantity: f64,
+    ) -> Self
+    where
+        I: Into<Instrument>,
+    {
+        Self {
+            exchange,
+            cid,
+            legs: vec![
+                ComboLeg::new(long, Side::Buy, 1.0),
+                ComboLeg::new(short, Side::Sell, 1.0),
+            ],
+            state: RequestOpenCombo {
+                net_price,
+                quantity,
+            },
+        }
+    }
+
+    /// Construct a straddle [`ComboOrder`] that buys ([`Side::Buy`]) or sells ([`Side::Sell`])
+    /// both a call & a put with the same strike and expiry.
+    pub fn straddle<I>(
+        exchange: ExchangeId,
+        cid: ClientOrderId,
+        call: I,
+        put: I,
+        side: Side,
+        net_price: f64,
+        quantity: f64,
+    ) -> Self
+    where
+        I: Into<Instrument>,
+    {
+        Self {
+            exchange,
+            cid,
+            legs: vec![
+                ComboLeg::new(call, side, 1.0),
+                ComboLeg::new(put, side, 1.0),
+            ],
+            state: RequestOpenCombo {
+                net_price,
+                quantity,
+            },
+        }
+    }
+
+    /// Validate the [`ComboOrder`] has at least two [`ComboLeg`]s with unique [`Instrument`]s
+    /// and positive ratios, and a positive quantity.
+    pub fn validate(&self) -> Result<(), ExecutionError> {
+        if self.legs.len() < 2 {
+            return Err(ExecutionError::InvalidComboOrder(format!(
+                "requires at least 2 legs, but found {}",
+                self.legs.len()
+            )));
+        }
+
+        if let Some(leg) = self.legs.iter().find(|leg| leg.ratio <= 0.0) {
+            return Err(ExecutionError::InvalidComboOrder(format!(
+                "leg {} has non-positive ratio {}",
+                leg.instrument, leg.ratio
+            )));
+        }
+
+        if let Some((index, leg)) = self.legs.iter().enumerate().find(|(index, leg)| {
+            self.legs[..*index]
+                .iter()
+                .any(|other| other.instrument == leg.instrument)
+        }) {
+            return Err(ExecutionError::InvalidComboOrder(format!(
+                "leg {index} duplicates Instrument {}",
+                leg.instrument
+            )));
+        }
+
+        if self.state.quantity <= 0.0 {
+            return Err(ExecutionError::InvalidComboOrder(format!(
+                "non-positive quantity {}",
+                self.state.quantity
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Calculate the net price of one [`ComboOrder`] unit using the provided price of each
+    /// [`ComboLeg`] [`Instrument`]. Returns `None` if any [`ComboLeg`] price is unavailable.
+    pub fn net_price<F>(&self, leg_price: F) -> Option<f64>
+    where
+        F: Fn(&Instrument) -> Option<f64>,
+    {
+        self.legs.iter().try_fold(0.0, |net_price, leg| {
+            leg_price(&leg.instrument).map(|price| net_price + leg.net_price_contribution(price))
+        })
+    }
+
+    /// Determine if the [`ComboOrder`] would execute at the provided net price.
+    pub fn is_marketable(&self, net_price: f64) -> bool {
+        net_price <= self.state.net_price
+    }
+
+    /// Build the [`Order<RequestOpen>`] of a [`ComboLeg`] executed at the provided price.
+    pub fn leg_request(&self, leg: &ComboLeg, price: f64) -> Order<RequestOpen> {
+        Order {
+            exchange: self.exchange,
+            instrument: leg.instrument.clone(),
+            cid: self.cid,
+            side: leg.side,
+            state: RequestOpen {
+                kind: OrderKind::ImmediateOrCancel,
+                price,
+                quantity: leg.quantity(self.state.quantity),
+                time_in_force: TimeInForce::GoodUntilCancelled,
+            },
+        }
+    }
+
+    /// Transition to a [`ComboOrder<ComboFilled>`] once every [`ComboLeg`] has been filled.
+    pub fn into_filled(
+        self,
+        id: OrderId,
+        net_price: f64,
+        trades: Vec<Trade>,
+    ) -> ComboOrder<ComboFilled> {
+        ComboOrder {
+            exchange: self.exchange,
+            cid: self.cid,
+            legs: self.legs,
+            state: ComboFilled {
+                id,
+                net_price,
+                quantity: self.state.quantity,
+                trades,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_instrument::instrument::kind::InstrumentKind;
+    use uuid::Uuid;
+
+    fn instrument(base: &str) -> Instrument {
+        Instrument::from((base, "usdt", InstrumentKind::Perpetual))
+    }
+
+    fn vertical_spread(net_price: f64, quantity: f64) -> ComboOrder<RequestOpenCombo> {
+        ComboOrder::vertical_spread(
+            ExchangeId::Simulated,
+            ClientOrderId(Uuid::new_v4()),
+            instrument("long"),
+            instrument("short"),
+            net_price,
+            quantity,
+        )
+    }
+
+    #[test]
+    fn test_combo_order_validate() {
+        struct TestCase {
+            input: ComboOrder<RequestOpenCombo>,
+            expected: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: valid vertical spread
+                input: vertical_spread(5.0, 1.0),
+                expected: true,
+            },
+            TestCase {
+                // TC1: single leg
+                input: ComboOrder {
+                    legs: vec![ComboLeg::new(instrument("long"), Side::Buy, 1.0)],
+                    ..vertical_spread(5.0, 1.0)
+                },
+                expected: false,
+            },
+            TestCase {
+                // TC2: non-positive ratio
+                input: ComboOrder {
+                    legs: vec![
+                        ComboLeg::new(instrument("long"), Side::Buy, 1.0),
+                        ComboLeg::new(instrument("short"), Side::Sell, 0.0),
+                    ],
+                    ..vertical_spread(5.0, 1.0)
+                },
+                expected: false,
+            },
+            TestCase {
+                // TC3: duplicate leg Instrument
+                input: ComboOrder {
+                    legs: vec![
+                        ComboLeg::new(instrument("long"), Side::Buy, 1.0),
+                        ComboLeg::new(instrument("long"), Side::Sell, 1.0),
+                    ],
+                    ..vertical_spread(5.0, 1.0)
+                },
+                expected: false,
+            },
+            TestCase {
+                // TC4: non-positive quantity
+                input: vertical_spread(5.0, 0.0),
+                expected: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                test.input.validate().is_ok(),
+                test.expected,
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_combo_order_net_price() {
+        let spread = vertical_spread(5.0, 1.0);
+        let price = |instrument: &Instrument| match instrument.base.as_ref() {
+            "long" => Some(12.0),
+            "short" => Some(8.0),
+            _ => None,
+        };
+
+        // Net debit of the long leg minus the short leg
+        assert_eq!(spread.net_price(price), Some(4.0));
+        assert!(spread.is_marketable(4.0));
+        assert!(!spread.is_marketable(6.0));
+
+        // Missing leg price
+        assert_eq!(spread.net_price(|_| None), None);
+    }
+}
@@ -0,0 +1,431 @@
+use super::{order::OrderId, trade::TradeId, AccountEvent, AccountEventKind, ClientOrderId};
+use barter_instrument::instrument::Instrument;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Write};
+
+/// Transition in the lifecycle of a single client order, derived from an [`AccountEvent`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum OrderTransition {
+    /// Order was opened ([`AccountEventKind::OrdersNew`]).
+    Opened {
+        id: OrderId,
+        price: f64,
+        quantity: f64,
+    },
+    /// Order was reported open in a snapshot ([`AccountEventKind::OrdersOpen`]).
+    Snapshot { id: OrderId, filled_quantity: f64 },
+    /// Order was (partially) filled by a client [`Trade`](super::trade::Trade).
+    Filled {
+        trade_id: TradeId,
+        price: f64,
+        quantity: f64,
+    },
+    /// Order was cancelled ([`AccountEventKind::OrdersCancelled`]).
+    Cancelled,
+    /// Order was no longer open on the exchange during reconciliation
+    /// ([`AccountEventKind::OrdersMissing`]).
+    Missing,
+}
+
+impl Display for OrderTransition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OrderTransition::Opened {
+                id,
+                price,
+                quantity,
+            } => write!(f, "OPENED    id={} price={price} quantity={quantity}", id.0),
+            OrderTransition::Snapshot {
+                id,
+                filled_quantity,
+            } => write!(f, "SNAPSHOT  id={} filled={filled_quantity}", id.0),
+            OrderTransition::Filled {
+                trade_id,
+                price,
+                quantity,
+            } => write!(
+                f,
+                "FILLED    trade_id={} price={price} quantity={quantity}",
+                trade_id.0
+            ),
+            OrderTransition::Cancelled => write!(f, "CANCELLED"),
+            OrderTransition::Missing => write!(f, "MISSING"),
+        }
+    }
+}
+
+/// [`OrderTransition`] & the `received_time` of the [`AccountEvent`] it was derived from.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OrderTransitionRecord {
+    pub time: DateTime<Utc>,
+    pub transition: OrderTransition,
+}
+
+/// Order lifecycle invariant violated by an [`OrderHistory`]. Each variant contains the index of
+/// the offending [`OrderTransitionRecord`].
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum InvariantViolation {
+    /// Transition occurred after the order was cancelled or fully filled.
+    AfterTerminal { index: usize },
+    /// Transition time is earlier than the previous transition.
+    NonMonotonicTime { index: usize },
+    /// Order was filled before it was opened.
+    FillBeforeOpen { index: usize },
+    /// Order was opened more than once.
+    DuplicateOpen { index: usize },
+    /// Cumulative filled quantity exceeds the order quantity.
+    Overfilled {
+        index: usize,
+        filled: f64,
+        quantity: f64,
+    },
+}
+
+impl InvariantViolation {
+    /// Index of the offending [`OrderTransitionRecord`].
+    pub fn index(&self) -> usize {
+        match self {
+            InvariantViolation::AfterTerminal { index }
+            | InvariantViolation::NonMonotonicTime { index }
+            | InvariantViolation::FillBeforeOpen { index }
+            | InvariantViolation::DuplicateOpen { index }
+            | InvariantViolation::Overfilled { index, .. } => *index,
+        }
+    }
+}
+
+/// Debug utility that reconstructs the transition history of a single client order (identified
+/// by it's [`ClientOrderId`]) from a sequence of [`AccountEvent`]s, renders it as a timeline, and
+/// checks the order lifecycle invariants.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OrderHistory {
+    pub cid: ClientOrderId,
+    pub instrument: Option<Instrument>,
+    pub transitions: Vec<OrderTransitionRecord>,
+}
+
+impl OrderHistory {
+    /// Reconstruct the [`OrderHistory`] of the provided [`ClientOrderId`].
+    ///
+    /// Client [`Trade`](super::trade::Trade)s only contain the exchange [`OrderId`], so they are
+    /// attributed to the order once an [`AccountEvent`] linking the [`ClientOrderId`] to it's
+    /// [`OrderId`] has been seen.
+    pub fn from_account_events<'a, Events>(cid: ClientOrderId, events: Events) -> Self
+    where
+        Events: IntoIterator<Item = &'a AccountEvent>,
+    {
+        let mut history = Self {
+            cid,
+            instrument: None,
+            transitions: vec![],
+        };
+        let mut order_id = None;
+
+        for event in events {
+            let time = event.received_time;
+            match &event.kind {
+                AccountEventKind::OrdersNew(orders) => {
+                    for order in orders.iter().filter(|order| order.cid == cid) {
+                        order_id = Some(order.state.id.clone());
+                        history.instrument = Some(order.instrument.clone());
+                        history.push(
+                            time,
+                            OrderTransition::Opened {
+                                id: order.state.id.clone(),
+                                price: order.state.price,
+                                quantity: order.state.quantity,
+                            },
+                        );
+                    }
+                }
+                AccountEventKind::OrdersOpen(orders) => {
+                    for order in orders.iter().filter(|order| order.cid == cid) {
+                        order_id = Some(order.state.id.clone());
+                        history.instrument = Some(order.instrument.clone());
+                        history.push(
+                            time,
+                            OrderTransition::Snapshot {
+                                id: order.state.id.clone(),
+                                filled_quantity: order.state.filled_quantity,
+                            },
+                        );
+                    }
+                }
+                AccountEventKind::OrdersCancelled(orders) => {
+                    for order in orders.iter().filter(|order| order.cid == cid) {
+                        order_id = Some(order.state.id.clone());
+                        history.instrument = Some(order.instrument.clone());
+                        history.push(time, OrderTransition::Cancelled);
+                    }
+                }
+                AccountEventKind::OrdersMissing(orders) => {
+                    for order in orders.iter().filter(|order| order.cid == cid) {
+                        order_id = Some(order.state.id.clone());
+                        history.instrument = Some(order.instrument.clone());
+                        history.push(time, OrderTransition::Missing);
+                    }
+                }
+                AccountEventKind::Trade(trade) => {
+                    let is_order_trade = order_id.as_ref() == Some(&trade.order_id)
+                        && history.instrument.as_ref() == Some(&trade.instrument);
+
+                    if is_order_trade {
+                        history.push(
+                            time,
+                            OrderTransition::Filled {
+                                trade_id: trade.id.clone(),
+                                price: trade.price,
+                                quantity: trade.quantity,
+                            },
+                        );
+                    }
+                }
+                AccountEventKind::Balance(_)
+                | AccountEventKind::Balances(_)
+                | AccountEventKind::BalanceDrift(_)
+                | AccountEventKind::Margin(_) => {}
+            }
+        }
+
+        history
+    }
+
+    fn push(&mut self, time: DateTime<Utc>, transition: OrderTransition) {
+        self.transitions
+            .push(OrderTransitionRecord { time, transition });
+    }
+
+    /// Check the order lifecycle invariants, returning every [`InvariantViolation`] found:
+    /// - No transition after the order is cancelled, fully filled, or missing.
+    /// - Transition times are monotonic.
+    /// - The order is opened at most once, and before it is filled.
+    /// - The cumulative filled quantity does not exceed the order quantity.
+    pub fn check_invariants(&self) -> Vec<InvariantViolation> {
+        let mut violations = vec![];
+        let mut quantity = None;
+        let mut filled = 0.0;
+        let mut terminal = false;
+        let mut previous_time = None;
+
+        for (index, record) in self.transitions.iter().enumerate() {
+            if terminal {
+                violations.push(InvariantViolation::AfterTerminal { index });
+            }
+
+            if previous_time.is_some_and(|previous| record.time < previous) {
+                violations.push(InvariantViolation::NonMonotonicTime { index });
+            }
+            previous_time = Some(record.time);
+
+            match &record.transition {
+                OrderTransition::Opened {
+                    quantity: opened, ..
+                } => {
+                    if quantity.is_some() {
+                        violations.push(InvariantViolation::DuplicateOpen { index });
+                    }
+                    quantity = Some(*opened);
+                }
+                OrderTransition::Snapshot { .. } => {}
+                OrderTransition::Filled {
+                    quantity: trade_quantity,
+                    ..
+                } => {
+                    filled += trade_quantity;
+                    match quantity {
+                        None => violations.push(InvariantViolation::FillBeforeOpen { index }),
+                        Some(quantity) if filled > quantity => {
+                            violations.push(InvariantViolation::Overfilled {
+                                index,
+                                filled,
+                                quantity,
+                            })
+                        }
+                        Some(quantity) => terminal = filled >= quantity,
+                    }
+                }
+                OrderTransition::Cancelled | OrderTransition::Missing => terminal = true,
+            }
+        }
+
+        violations
+    }
+
+    /// Render the [`OrderHistory`] as a human readable timeline. Each transition is shown with
+    /// the time elapsed since the first transition, and any [`InvariantViolation`]s are flagged
+    /// with `!!` beneath the offending transition.
+    pub fn timeline(&self) -> String {
+        let violations = self.check_invariants();
+        let start = self.transitions.first().map(|record| record.time);
+
+        let mut timeline = format!("Order cid={}", self.cid);
+        if let Some(instrument) = &self.instrument {
+            let _ = write!(timeline, " instrument={instrument}");
+        }
+        timeline.push('\n');
+
+        for (index, record) in self.transitions.iter().enumerate() {
+            let elapsed = start
+                .map(|start| (record.time - start).num_microseconds().unwrap_or_default())
+                .unwrap_or_default() as f64
+                / 1_000_000.0;
+
+            let _ = writeln!(
+                timeline,
+                "  [{index:>3}] {} (+{elapsed:.6}s) {}",
+                record.time.format("%Y-%m-%dT%H:%M:%S%.6fZ"),
+                record.transition
+            );
+
+            for violation in violations
+                .iter()
+                .filter(|violation| violation.index() == index)
+            {
+                let _ = writeln!(timeline, "        !! {violation:?}");
+            }
+        }
+
+        timeline
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::{order::Cancelled, trade::SymbolFees},
+        test_util::{order_open, trade},
+        Order,
+    };
+    use barter_instrument::exchange::ExchangeId;
+    use barter_integration::Side;
+    use chrono::TimeDelta;
+    use uuid::Uuid;
+
+    fn event(time: DateTime<Utc>, kind: AccountEventKind) -> AccountEvent {
+        AccountEvent {
+            received_time: time,
+            exchange: ExchangeId::Simulated,
+            kind,
+        }
+    }
+
+    #[test]
+    fn test_order_history_check_invariants() {
+        struct TestCase {
+            kinds: Vec<(i64, AccountEventKind)>,
+            expected: Vec<InvariantViolation>,
+        }
+
+        let cid = ClientOrderId(Uuid::new_v4());
+        let open = order_open(cid, Side::Buy, 10.0, 1.0, 0.0);
+        let opened = AccountEventKind::OrdersNew(vec![open.clone()]);
+        let cancelled = AccountEventKind::OrdersCancelled(vec![Order::<Cancelled>::from(open)]);
+        let fill = |quantity: f64| {
+            AccountEventKind::Trade(trade(
+                TradeId::from("trade_id"),
+                Side::Buy,
+                10.0,
+                quantity,
+                SymbolFees::new("base", 0.0),
+            ))
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: valid lifecycle with partial fill then cancel
+                kinds: vec![(0, opened.clone()), (1, fill(0.5)), (2, cancelled.clone())],
+                expected: vec![],
+            },
+            TestCase {
+                // TC1: fill after cancel
+                kinds: vec![(0, opened.clone()), (1, cancelled.clone()), (2, fill(0.5))],
+                expected: vec![InvariantViolation::AfterTerminal { index: 2 }],
+            },
+            TestCase {
+                // TC2: non-monotonic time
+                kinds: vec![(5, opened.clone()), (1, fill(0.5))],
+                expected: vec![InvariantViolation::NonMonotonicTime { index: 1 }],
+            },
+            TestCase {
+                // TC3: overfilled, then cancelled after terminal full fill
+                kinds: vec![
+                    (0, opened.clone()),
+                    (1, fill(1.0)),
+                    (2, fill(0.5)),
+                    (3, cancelled.clone()),
+                ],
+                expected: vec![
+                    InvariantViolation::AfterTerminal { index: 2 },
+                    InvariantViolation::Overfilled {
+                        index: 2,
+                        filled: 1.5,
+                        quantity: 1.0,
+                    },
+                    InvariantViolation::AfterTerminal { index: 3 },
+                ],
+            },
+            TestCase {
+                // TC4: opened twice
+                kinds: vec![(0, opened.clone()), (1, opened.clone())],
+                expected: vec![InvariantViolation::DuplicateOpen { index: 1 }],
+            },
+        ];
+
+        let start = Utc::now();
+        for (index, test) in tests.into_iter().enumerate() {
+            let events = test
+                .kinds
+                .into_iter()
+                .map(|(secs, kind)| event(start + TimeDelta::seconds(secs), kind))
+                .collect::<Vec<_>>();
+
+            let history = OrderHistory::from_account_events(cid, &events);
+            assert_eq!(
+                history.check_invariants(),
+                test.expected,
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_order_history_timeline() {
+        let cid = ClientOrderId(Uuid::new_v4());
+        let open = order_open(cid, Side::Buy, 10.0, 1.0, 0.0);
+        let start = Utc::now();
+
+        let events = vec![
+            event(start, AccountEventKind::OrdersNew(vec![open.clone()])),
+            // Other client order is ignored
+            event(
+                start,
+                AccountEventKind::OrdersNew(vec![order_open(
+                    ClientOrderId(Uuid::new_v4()),
+                    Side::Sell,
+                    20.0,
+                    1.0,
+                    0.0,
+                )]),
+            ),
+            event(
+                start + TimeDelta::milliseconds(50),
+                AccountEventKind::OrdersCancelled(vec![Order::<Cancelled>::from(open.clone())]),
+            ),
+            event(
+                start + TimeDelta::milliseconds(100),
+                AccountEventKind::OrdersOpen(vec![open]),
+            ),
+        ];
+
+        let timeline = OrderHistory::from_account_events(cid, &events).timeline();
+        let lines = timeline.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 5);
+        assert!(lines[1].contains("(+0.000000s) OPENED"));
+        assert!(lines[2].contains("(+0.050000s) CANCELLED"));
+        assert!(lines[3].contains("(+0.100000s) SNAPSHOT"));
+        assert_eq!(lines[4], "        !! AfterTerminal { index: 2 }");
+    }
+}
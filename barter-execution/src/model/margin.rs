@@ -0,0 +1,137 @@
+use super::{AccountEvent, AccountEventKind};
+use barter_instrument::instrument::Instrument;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Margin mode of a leveraged [`Instrument`] position.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+pub enum MarginMode {
+    /// Margin is shared across every position of the account.
+    #[default]
+    Cross,
+    /// Margin is allocated to each position separately, limiting it's loss to that margin.
+    Isolated,
+}
+
+/// Leverage & [`MarginMode`] configuration of an [`Instrument`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MarginConfig {
+    pub instrument: Instrument,
+    pub mode: MarginMode,
+    pub leverage: f64,
+}
+
+/// Margin state of an [`Instrument`] reported by the exchange, including the current margin
+/// ratio & liquidation price of any open position.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct InstrumentMargin {
+    pub config: MarginConfig,
+    /// Maintenance margin divided by the margin balance, where `1.0` triggers liquidation.
+    pub margin_ratio: Option<f64>,
+    pub liquidation_price: Option<f64>,
+}
+
+impl From<MarginConfig> for InstrumentMargin {
+    fn from(config: MarginConfig) -> Self {
+        Self {
+            config,
+            margin_ratio: None,
+            liquidation_price: None,
+        }
+    }
+}
+
+/// Tracks the [`InstrumentMargin`] of each [`Instrument`] from a stream of [`AccountEvent`]s.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct MarginTracker {
+    margins: HashMap<Instrument, InstrumentMargin>,
+}
+
+impl MarginTracker {
+    /// Constructs a new empty [`MarginTracker`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tracked [`InstrumentMargin`] of the [`Instrument`], if any.
+    pub fn margin(&self, instrument: &Instrument) -> Option<&InstrumentMargin> {
+        self.margins.get(instrument)
+    }
+
+    /// Iterator over every tracked [`InstrumentMargin`].
+    pub fn margins(&self) -> impl Iterator<Item = &InstrumentMargin> {
+        self.margins.values()
+    }
+
+    /// Update the tracked [`InstrumentMargin`]s from the [`AccountEvent`].
+    pub fn update(&mut self, event: &AccountEvent) {
+        if let AccountEventKind::Margin(margin) = &event.kind {
+            self.margins
+                .insert(margin.config.instrument.clone(), margin.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_instrument::{exchange::ExchangeId, instrument::kind::InstrumentKind};
+    use chrono::Utc;
+
+    #[test]
+    fn test_margin_tracker_update() {
+        struct TestCase {
+            kind: AccountEventKind,
+            expected: Option<InstrumentMargin>,
+        }
+
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+        let config = MarginConfig {
+            instrument: instrument.clone(),
+            mode: MarginMode::Isolated,
+            leverage: 10.0,
+        };
+
+        let mut tracker = MarginTracker::new();
+
+        let tests = vec![
+            TestCase {
+                // TC0: non-margin AccountEvent is ignored
+                kind: AccountEventKind::Balances(vec![]),
+                expected: None,
+            },
+            TestCase {
+                // TC1: margin config is tracked
+                kind: AccountEventKind::Margin(InstrumentMargin::from(config.clone())),
+                expected: Some(InstrumentMargin::from(config.clone())),
+            },
+            TestCase {
+                // TC2: margin ratio & liquidation price update replaces the tracked margin
+                kind: AccountEventKind::Margin(InstrumentMargin {
+                    config: config.clone(),
+                    margin_ratio: Some(0.2),
+                    liquidation_price: Some(45_000.0),
+                }),
+                expected: Some(InstrumentMargin {
+                    config,
+                    margin_ratio: Some(0.2),
+                    liquidation_price: Some(45_000.0),
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            tracker.update(&AccountEvent {
+                received_time: Utc::now(),
+                exchange: ExchangeId::Simulated,
+                kind: test.kind,
+            });
+            assert_eq!(
+                tracker.margin(&instrument),
+                test.expected.as_ref(),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
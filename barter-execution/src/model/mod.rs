@@ -1,17 +1,23 @@
 use self::{
-    balance::SymbolBalance,
+    balance::{BalanceDrift, SymbolBalance},
+    margin::InstrumentMargin,
     order::{Cancelled, Open, Order},
     trade::Trade,
 };
-use barter_instrument::exchange::ExchangeId;
+use barter_data::streams::{kafka::KafkaRecord, publish::instrument_key};
+use barter_instrument::{exchange::ExchangeId, instrument::Instrument};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fmt::Formatter;
 use uuid::Uuid;
 
 pub mod balance;
+pub mod combo;
+pub mod history;
+pub mod margin;
 pub mod order;
 pub mod trade;
+pub mod transfer;
 
 /// Normalised Barter [`AccountEvent`] containing metadata about the included
 /// [`AccountEventKind`] variant. Produced by [`ExecutionClients`](crate::ExecutionClient).
@@ -29,18 +35,65 @@ pub enum AccountEventKind {
     OrdersOpen(Vec<Order<Open>>),
     OrdersNew(Vec<Order<Open>>),
     OrdersCancelled(Vec<Order<Cancelled>>),
+    /// Orders tracked locally that are no longer open on the exchange, detected by
+    /// [`reconciliation`](crate::reconcile::OrderTracker::reconcile).
+    OrdersMissing(Vec<Order<Open>>),
 
     // WebSocket Only
     Balance(SymbolBalance),
     Trade(Trade),
+    Margin(InstrumentMargin),
 
     // HTTP & WebSocket
     Balances(Vec<SymbolBalance>),
+
+    /// Balances tracked locally that drifted from those reported by the exchange, detected by
+    /// [`reconciliation`](crate::reconcile::BalanceTracker::reconcile).
+    BalanceDrift(Vec<BalanceDrift>),
+}
+
+impl KafkaRecord for AccountEvent {
+    /// Keyed by instrument (first [`Order`] instrument for batches, symbol for a balance),
+    /// falling back to the exchange for [`AccountEventKind::Balances`].
+    fn key(&self) -> String {
+        let instrument = match &self.kind {
+            AccountEventKind::OrdersOpen(orders)
+            | AccountEventKind::OrdersNew(orders)
+            | AccountEventKind::OrdersMissing(orders) => orders
+                .first()
+                .map(|order| instrument_key(&order.instrument)),
+            AccountEventKind::OrdersCancelled(orders) => orders
+                .first()
+                .map(|order| instrument_key(&order.instrument)),
+            AccountEventKind::Trade(trade) => Some(instrument_key(&trade.instrument)),
+            AccountEventKind::Margin(margin) => Some(instrument_key(&margin.config.instrument)),
+            AccountEventKind::Balance(balance) => Some(balance.symbol.to_string()),
+            AccountEventKind::Balances(_) | AccountEventKind::BalanceDrift(_) => None,
+        };
+
+        match instrument {
+            Some(instrument) => format!("{}:{instrument}", self.exchange.as_str()),
+            None => self.exchange.as_str().to_owned(),
+        }
+    }
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct ClientOrderId(pub Uuid);
 
+impl ClientOrderId {
+    /// Generate a deterministic UUIDv5 [`ClientOrderId`] from the strategy, [`Instrument`] & sequence
+    /// number of an order request (see
+    /// [`ClientOrderIdGenerator`](crate::idempotency::ClientOrderIdGenerator)).
+    pub fn deterministic(strategy: &str, instrument: &Instrument, sequence: u64) -> Self {
+        // Fixed namespace so the same order request always maps to the same UUIDv5
+        const NAMESPACE: Uuid = Uuid::from_u128(0xc387119e_0185_4a90_8dd9_86a75e92a4bf);
+
+        let name = format!("{strategy}|{instrument}|{sequence}");
+        Self(Uuid::new_v5(&NAMESPACE, name.as_bytes()))
+    }
+}
+
 impl std::fmt::Display for ClientOrderId {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
@@ -1,6 +1,7 @@
 use super::ClientOrderId;
 use barter_instrument::{asset::symbol::Symbol, exchange::ExchangeId, instrument::Instrument};
 use barter_integration::Side;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use smol_str::{SmolStr, ToSmolStr};
 use std::{
@@ -32,6 +33,42 @@ impl Display for OrderKind {
     }
 }
 
+/// [`Order`] time in force, determining how long an [`Order`] remains active before it expires.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum TimeInForce {
+    /// Remains active until filled or cancelled.
+    #[default]
+    GoodUntilCancelled,
+
+    /// Remains active until filled, cancelled, or the provided expiry time is reached.
+    ///
+    /// Mapped to the native exchange GTD time in force where supported, otherwise enforced by the
+    /// client cancelling the [`Order`] once expired (see
+    /// [`ExecutionClient::cancel_orders_expired`](crate::ExecutionClient::cancel_orders_expired)).
+    GoodTillDate(DateTime<Utc>),
+}
+
+impl TimeInForce {
+    /// Determine if the [`TimeInForce`] has expired at the provided time.
+    pub fn is_expired(&self, time: DateTime<Utc>) -> bool {
+        match self {
+            TimeInForce::GoodUntilCancelled => false,
+            TimeInForce::GoodTillDate(expiry) => time >= *expiry,
+        }
+    }
+}
+
+impl Display for TimeInForce {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeInForce::GoodUntilCancelled => write!(f, "good_until_cancelled"),
+            TimeInForce::GoodTillDate(expiry) => write!(f, "good_till_date({expiry})"),
+        }
+    }
+}
+
 #[derive(Clone, Eq, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct Order<State> {
     pub exchange: ExchangeId,
@@ -48,6 +85,8 @@ pub struct RequestOpen {
     pub kind: OrderKind,
     pub price: f64,
     pub quantity: f64,
+    #[serde(default)]
+    pub time_in_force: TimeInForce,
 }
 
 impl Order<RequestOpen> {
@@ -89,6 +128,8 @@ pub struct Open {
     pub price: f64,
     pub quantity: f64,
     pub filled_quantity: f64,
+    #[serde(default)]
+    pub time_in_force: TimeInForce,
 }
 
 impl Open {
@@ -97,6 +138,13 @@ impl Open {
     }
 }
 
+impl Order<Open> {
+    /// Determine if the [`Order<Open>`] [`TimeInForce`] has expired at the provided time.
+    pub fn is_expired(&self, time: DateTime<Utc>) -> bool {
+        self.state.time_in_force.is_expired(time)
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize)]
 pub enum OrderFill {
     Full,
@@ -110,6 +158,8 @@ impl Ord for Order<Open> {
     }
 }
 
+// Orders of opposite sides are incomparable, so the Ord implementation defers to partial_cmp
+#[allow(clippy::non_canonical_partial_ord_impl)]
 impl PartialOrd for Order<Open> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         match (self.side, other.side) {
@@ -189,11 +239,24 @@ impl From<(OrderId, Order<RequestOpen>)> for Order<Open> {
                 price: request.state.price,
                 quantity: request.state.quantity,
                 filled_quantity: 0.0,
+                time_in_force: request.state.time_in_force,
             },
         }
     }
 }
 
+impl From<&Order<Open>> for Order<RequestCancel> {
+    fn from(order: &Order<Open>) -> Self {
+        Self {
+            exchange: order.exchange,
+            instrument: order.instrument.clone(),
+            cid: order.cid,
+            side: order.side,
+            state: RequestCancel::from(order.state.id.clone()),
+        }
+    }
+}
+
 impl From<Order<Open>> for Order<Cancelled> {
     fn from(order: Order<Open>) -> Self {
         Self {
@@ -218,6 +281,47 @@ mod tests {
         assert_eq!(order.state.remaining_quantity(), 5.0)
     }
 
+    #[test]
+    fn test_time_in_force_is_expired() {
+        struct TestCase {
+            input: TimeInForce,
+            expected: bool,
+        }
+
+        let now = Utc::now();
+
+        let tests = vec![
+            TestCase {
+                // TC0: GoodUntilCancelled never expires
+                input: TimeInForce::GoodUntilCancelled,
+                expected: false,
+            },
+            TestCase {
+                // TC1: GoodTillDate expiry in the future
+                input: TimeInForce::GoodTillDate(now + chrono::Duration::seconds(1)),
+                expected: false,
+            },
+            TestCase {
+                // TC2: GoodTillDate expiry is now
+                input: TimeInForce::GoodTillDate(now),
+                expected: true,
+            },
+            TestCase {
+                // TC3: GoodTillDate expiry in the past
+                input: TimeInForce::GoodTillDate(now - chrono::Duration::seconds(1)),
+                expected: true,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                test.input.is_expired(now),
+                test.expected,
+                "TC{index} failed"
+            );
+        }
+    }
+
     #[test]
     fn test_partial_ord_order_open() {
         struct TestCase {
@@ -1,6 +1,7 @@
 use super::order::OrderId;
 use barter_instrument::{asset::symbol::Symbol, instrument::Instrument};
 use barter_integration::Side;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use smol_str::SmolStr;
 
@@ -16,6 +17,14 @@ pub struct Trade {
     pub fees: SymbolFees,
 }
 
+/// Client [`Trade`] & the time it was executed by the exchange, as returned by the exchange
+/// trade history (eg/ for reconciliation & accounting).
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct TradeRecord {
+    pub time: DateTime<Utc>,
+    pub trade: Trade,
+}
+
 /// Private [`Trade`] identifier generated by an exchange. Cannot assume this is unique across each
 /// [`Exchange`](barter_integration::model::Exchange),
 /// [`Market`](barter_integration::model::Market), or
@@ -33,6 +42,8 @@ where
 }
 
 /// [`Trade`] fees denominated in a [`Symbol`].
+///
+/// Fees are signed: a negative amount is a rebate paid by the exchange (eg/ maker rebate).
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct SymbolFees {
     pub symbol: Symbol,
@@ -50,4 +61,9 @@ impl SymbolFees {
             fees,
         }
     }
+
+    /// Determine if these [`SymbolFees`] are a rebate paid by the exchange.
+    pub fn is_rebate(&self) -> bool {
+        self.fees < 0.0
+    }
 }
@@ -0,0 +1,67 @@
+use barter_instrument::asset::symbol::Symbol;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
+
+/// Wallet (or sub-account) of an exchange account that a [`Symbol`] balance is held in.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum Wallet {
+    Spot,
+    Margin,
+    Futures,
+    Funding,
+    /// Sub-account, identified by it's exchange specific name or identifier.
+    SubAccount(SmolStr),
+}
+
+/// Request to transfer a [`Symbol`] quantity between two [`Wallet`]s of the same exchange
+/// account (eg/ moving margin from the spot to the futures wallet).
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct TransferRequest {
+    pub symbol: Symbol,
+    pub quantity: f64,
+    pub from: Wallet,
+    pub to: Wallet,
+}
+
+/// Completed [`TransferRequest`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Transfer {
+    pub id: TransferId,
+    pub time: DateTime<Utc>,
+    pub request: TransferRequest,
+}
+
+/// Request to withdraw a [`Symbol`] quantity from the exchange account to an external address.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct WithdrawRequest {
+    pub symbol: Symbol,
+    pub quantity: f64,
+    pub address: String,
+    /// Network to withdraw on (eg/ "ERC20", "TRC20"), if the [`Symbol`] supports many.
+    pub network: Option<String>,
+    /// Memo or tag required by some destination addresses.
+    pub memo: Option<String>,
+}
+
+/// Accepted [`WithdrawRequest`], and the fee deducted by the exchange.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Withdrawal {
+    pub id: TransferId,
+    pub time: DateTime<Utc>,
+    pub request: WithdrawRequest,
+    pub fee: f64,
+}
+
+/// [`Transfer`] or [`Withdrawal`] identifier generated by an exchange.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct TransferId(pub SmolStr);
+
+impl<S> From<S> for TransferId
+where
+    S: Into<SmolStr>,
+{
+    fn from(id: S) -> Self {
+        Self(id.into())
+    }
+}
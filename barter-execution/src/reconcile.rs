@@ -0,0 +1,662 @@
+use crate::{
+    model::{
+        balance::{Balance, BalanceDrift, SymbolBalance},
+        order::{Open, Order},
+        AccountEvent, AccountEventKind, ClientOrderId,
+    },
+    ExecutionClient,
+};
+use barter_instrument::asset::symbol::Symbol;
+use chrono::Utc;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+use tokio::sync::mpsc;
+use tracing::{error, info, warn};
+
+/// Drift between an [`Order<Open>`] tracked locally & the open orders of the exchange.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum OrderDrift {
+    /// Open on the exchange, but not tracked locally.
+    Unknown(Order<Open>),
+    /// Tracked locally, but no longer open on the exchange (ie/ filled or cancelled without the
+    /// [`AccountEvent`] being received).
+    Missing(Order<Open>),
+    /// Open on the exchange with a different state to the one tracked locally (eg/ a missed
+    /// partial fill).
+    Mismatch {
+        local: Order<Open>,
+        exchange: Order<Open>,
+    },
+}
+
+/// Local map of every [`Order<Open>`], keyed by [`ClientOrderId`], kept up to date from
+/// [`AccountEvent`]s & periodically [`reconciled`](Self::reconcile) against the exchange.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct OrderTracker {
+    orders: HashMap<ClientOrderId, TrackedOrder>,
+}
+
+/// [`Order<Open>`] tracked by an [`OrderTracker`], and the local [`Instant`] it was last updated.
+#[derive(Clone, PartialEq, Debug)]
+struct TrackedOrder {
+    order: Order<Open>,
+    updated: Instant,
+}
+
+impl OrderTracker {
+    /// Constructs a new empty [`OrderTracker`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every [`Order<Open>`] tracked locally.
+    pub fn orders(&self) -> impl Iterator<Item = &Order<Open>> {
+        self.orders.values().map(|tracked| &tracked.order)
+    }
+
+    /// Update the tracked [`Order<Open>`]s from the [`AccountEvent`], recording the local
+    /// [`Instant`] it was processed as their last update.
+    pub fn update(&mut self, event: &AccountEvent) {
+        self.update_at(event, Instant::now())
+    }
+
+    /// Update the tracked [`Order<Open>`]s from the [`AccountEvent`], recording the provided
+    /// local [`Instant`] it was processed as their last update.
+    pub fn update_at(&mut self, event: &AccountEvent, processed: Instant) {
+        match &event.kind {
+            AccountEventKind::OrdersOpen(orders) | AccountEventKind::OrdersNew(orders) => {
+                for order in orders {
+                    self.orders.insert(
+                        order.cid,
+                        TrackedOrder {
+                            order: order.clone(),
+                            updated: processed,
+                        },
+                    );
+                }
+            }
+            AccountEventKind::OrdersCancelled(orders) => {
+                for order in orders {
+                    self.orders.remove(&order.cid);
+                }
+            }
+            AccountEventKind::OrdersMissing(orders) => {
+                for order in orders {
+                    self.orders.remove(&order.cid);
+                }
+            }
+            AccountEventKind::Trade(trade) => {
+                let Some(cid) = self.orders.values_mut().find_map(|tracked| {
+                    let order = &mut tracked.order;
+                    (order.state.id == trade.order_id && order.instrument == trade.instrument).then(
+                        || {
+                            order.state.filled_quantity += trade.quantity;
+                            tracked.updated = processed;
+                            order.cid
+                        },
+                    )
+                }) else {
+                    return;
+                };
+
+                if self.orders[&cid].order.state.remaining_quantity() <= 0.0 {
+                    self.orders.remove(&cid);
+                }
+            }
+            AccountEventKind::Balance(_)
+            | AccountEventKind::Balances(_)
+            | AccountEventKind::BalanceDrift(_)
+            | AccountEventKind::Margin(_) => {}
+        }
+    }
+
+    /// Diff the tracked [`Order<Open>`]s against the open orders of the exchange, fetched
+    /// starting at the provided local [`Instant`], returning every [`OrderDrift`]. The exchange
+    /// is the source of truth, so the tracked [`Order<Open>`]s are corrected to match it.
+    ///
+    /// Tracked [`Order<Open>`]s updated after the fetch started may not be reflected in the
+    /// exchange open orders yet (eg/ an order opened while the request was in-flight), so they
+    /// are exempt from reconciliation until the next fetch. Both are measured with the local
+    /// monotonic clock, since exchange event times are skewed from the local clock.
+    pub fn reconcile(
+        &mut self,
+        exchange_orders: Vec<Order<Open>>,
+        fetched: Instant,
+    ) -> Vec<OrderDrift> {
+        let mut local = std::mem::take(&mut self.orders);
+        let mut drift = Vec::new();
+
+        for exchange in exchange_orders {
+            match local.remove(&exchange.cid) {
+                Some(tracked) if tracked.updated > fetched => {
+                    self.orders.insert(exchange.cid, tracked);
+                    continue;
+                }
+                None => drift.push(OrderDrift::Unknown(exchange.clone())),
+                Some(tracked) if tracked.order.state != exchange.state => {
+                    drift.push(OrderDrift::Mismatch {
+                        local: tracked.order,
+                        exchange: exchange.clone(),
+                    })
+                }
+                Some(_) => {}
+            }
+            self.orders.insert(
+                exchange.cid,
+                TrackedOrder {
+                    order: exchange,
+                    updated: fetched,
+                },
+            );
+        }
+
+        for (cid, tracked) in local {
+            if tracked.updated > fetched {
+                self.orders.insert(cid, tracked);
+            } else {
+                drift.push(OrderDrift::Missing(tracked.order));
+            }
+        }
+
+        drift
+    }
+}
+
+/// Run a periodic order reconciliation task, fetching the open orders of the exchange at every
+/// interval & [`reconciling`](OrderTracker::reconcile) them against the shared
+/// [`OrderTracker`].
+///
+/// Any [`OrderDrift`] is corrected by sending an [`AccountEventKind::OrdersOpen`] snapshot of
+/// every exchange order that is unknown or mismatched, and an
+/// [`AccountEventKind::OrdersMissing`] of every tracked order no longer open on the exchange.
+/// Runs until the [`AccountEvent`] receiver is dropped, or fetching the open orders fails with
+/// an unrecoverable [`ExecutionError`](crate::error::ExecutionError).
+pub async fn run_order_reconciliation<Client>(
+    client: Arc<Client>,
+    tracker: Arc<Mutex<OrderTracker>>,
+    event_tx: mpsc::UnboundedSender<AccountEvent>,
+    interval: Duration,
+) where
+    Client: ExecutionClient + Send + Sync,
+{
+    let mut interval = tokio::time::interval(interval);
+
+    while !event_tx.is_closed() {
+        interval.tick().await;
+
+        // Orders tracked after the fetch starts are exempt from reconciliation, since they may
+        // be missing from the fetched open orders
+        let fetched = Instant::now();
+        let exchange_orders = match client.fetch_orders_open().await {
+            Ok(orders) => orders,
+            Err(error) if error.is_unrecoverable() => {
+                error!(
+                    exchange = %Client::CLIENT,
+                    %error,
+                    "stopping order reconciliation due to unrecoverable error"
+                );
+                break;
+            }
+            Err(error) => {
+                warn!(
+                    exchange = %Client::CLIENT,
+                    %error,
+                    "failed to fetch open orders for reconciliation"
+                );
+                continue;
+            }
+        };
+
+        let drift = tracker.lock().reconcile(exchange_orders, fetched);
+        if drift.is_empty() {
+            continue;
+        }
+
+        info!(
+            exchange = %Client::CLIENT,
+            ?drift,
+            "reconciled drift between tracked & exchange open orders"
+        );
+
+        let (snapshot, missing) = drift.into_iter().fold(
+            (Vec::new(), Vec::new()),
+            |(mut snapshot, mut missing), drift| {
+                match drift {
+                    OrderDrift::Unknown(exchange) | OrderDrift::Mismatch { exchange, .. } => {
+                        snapshot.push(exchange)
+                    }
+                    OrderDrift::Missing(local) => missing.push(local),
+                }
+                (snapshot, missing)
+            },
+        );
+
+        let send = |kind| {
+            let _ = event_tx.send(AccountEvent {
+                received_time: Utc::now(),
+                exchange: Client::CLIENT,
+                kind,
+            });
+        };
+        if !snapshot.is_empty() {
+            send(AccountEventKind::OrdersOpen(snapshot));
+        }
+        if !missing.is_empty() {
+            send(AccountEventKind::OrdersMissing(missing));
+        }
+    }
+}
+
+/// Tolerance within which a tracked [`Balance`] is considered reconciled with the exchange.
+///
+/// A `total` or `available` difference is only a [`BalanceDrift`] if it exceeds both the
+/// `absolute` tolerance, and the `relative` tolerance of the exchange `total` (eg/ 0.001 for
+/// 0.1%).
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BalanceTolerance {
+    pub absolute: f64,
+    pub relative: f64,
+}
+
+impl Default for BalanceTolerance {
+    fn default() -> Self {
+        Self {
+            absolute: 1e-8,
+            relative: 0.0,
+        }
+    }
+}
+
+impl BalanceTolerance {
+    /// Determine if the local [`Balance`] is within tolerance of the exchange [`Balance`].
+    pub fn is_within(&self, local: &Balance, exchange: &Balance) -> bool {
+        let tolerance = self.absolute.max(self.relative * exchange.total.abs());
+        (exchange.total - local.total).abs() <= tolerance
+            && (exchange.available - local.available).abs() <= tolerance
+    }
+}
+
+/// Local map of every [`Symbol`] [`Balance`], kept up to date from [`AccountEvent`]s &
+/// periodically [`reconciled`](Self::reconcile) against the exchange.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct BalanceTracker {
+    balances: HashMap<Symbol, Balance>,
+}
+
+impl BalanceTracker {
+    /// Constructs a new empty [`BalanceTracker`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tracked [`Balance`] of the [`Symbol`], if any.
+    pub fn balance(&self, symbol: &Symbol) -> Option<&Balance> {
+        self.balances.get(symbol)
+    }
+
+    /// Update the tracked [`Balance`]s from the [`AccountEvent`].
+    pub fn update(&mut self, event: &AccountEvent) {
+        match &event.kind {
+            AccountEventKind::Balance(balance) => {
+                self.balances
+                    .insert(balance.symbol.clone(), balance.balance);
+            }
+            AccountEventKind::Balances(balances) => {
+                for balance in balances {
+                    self.balances
+                        .insert(balance.symbol.clone(), balance.balance);
+                }
+            }
+            AccountEventKind::BalanceDrift(drifts) => {
+                for drift in drifts {
+                    self.balances.insert(drift.symbol.clone(), drift.exchange);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Diff the tracked [`Balance`]s against those reported by the exchange, returning every
+    /// [`BalanceDrift`] exceeding the [`BalanceTolerance`]. Tracked [`Symbol`]s the exchange does
+    /// not report are assumed to have a zero [`Balance`], and are no longer tracked. The exchange
+    /// is the source of truth, so the tracked [`Balance`]s are corrected to match it.
+    pub fn reconcile(
+        &mut self,
+        exchange_balances: Vec<SymbolBalance>,
+        tolerance: &BalanceTolerance,
+    ) -> Vec<BalanceDrift> {
+        let mut local = std::mem::take(&mut self.balances);
+        let zero = Balance::new(0.0, 0.0);
+
+        let exchange_balances = exchange_balances
+            .into_iter()
+            .map(|balance| {
+                let local = local.remove(&balance.symbol).unwrap_or(zero);
+                (balance.symbol, local, balance.balance)
+            })
+            .collect::<Vec<_>>();
+
+        let mut drifts = Vec::new();
+        for (symbol, local, exchange) in exchange_balances.into_iter().chain(
+            local
+                .into_iter()
+                .map(|(symbol, local)| (symbol, local, zero)),
+        ) {
+            if !tolerance.is_within(&local, &exchange) {
+                drifts.push(BalanceDrift {
+                    symbol: symbol.clone(),
+                    local,
+                    exchange,
+                });
+            }
+
+            if exchange != zero {
+                self.balances.insert(symbol, exchange);
+            }
+        }
+
+        drifts
+    }
+}
+
+/// Balance reconciliation metrics, shareable with other threads for monitoring.
+#[derive(Debug, Default)]
+pub struct BalanceReconciliationMetrics {
+    reconciliations: AtomicU64,
+    drifts: AtomicU64,
+    failures: AtomicU64,
+}
+
+impl BalanceReconciliationMetrics {
+    /// Number of successful reconciliations.
+    pub fn reconciliations(&self) -> u64 {
+        self.reconciliations.load(Ordering::Relaxed)
+    }
+
+    /// Number of [`BalanceDrift`]s detected.
+    pub fn drifts(&self) -> u64 {
+        self.drifts.load(Ordering::Relaxed)
+    }
+
+    /// Number of failures to fetch the exchange balances.
+    pub fn failures(&self) -> u64 {
+        self.failures.load(Ordering::Relaxed)
+    }
+}
+
+/// Run a periodic balance reconciliation task, fetching the balances of the exchange at every
+/// interval & [`reconciling`](BalanceTracker::reconcile) them against the shared
+/// [`BalanceTracker`].
+///
+/// Every [`BalanceDrift`] exceeding the [`BalanceTolerance`] is logged as an audit warning, and
+/// sent as an [`AccountEventKind::BalanceDrift`]. Runs until the [`AccountEvent`] receiver is
+/// dropped, or fetching the balances fails with an unrecoverable
+/// [`ExecutionError`](crate::error::ExecutionError).
+pub async fn run_balance_reconciliation<Client>(
+    client: Arc<Client>,
+    tracker: Arc<Mutex<BalanceTracker>>,
+    event_tx: mpsc::UnboundedSender<AccountEvent>,
+    interval: Duration,
+    tolerance: BalanceTolerance,
+    metrics: Arc<BalanceReconciliationMetrics>,
+) where
+    Client: ExecutionClient + Send + Sync,
+{
+    let mut interval = tokio::time::interval(interval);
+
+    while !event_tx.is_closed() {
+        interval.tick().await;
+
+        let exchange_balances = match client.fetch_balances().await {
+            Ok(balances) => balances,
+            Err(error) if error.is_unrecoverable() => {
+                metrics.failures.fetch_add(1, Ordering::Relaxed);
+                error!(
+                    exchange = %Client::CLIENT,
+                    %error,
+                    "stopping balance reconciliation due to unrecoverable error"
+                );
+                break;
+            }
+            Err(error) => {
+                metrics.failures.fetch_add(1, Ordering::Relaxed);
+                warn!(
+                    exchange = %Client::CLIENT,
+                    %error,
+                    "failed to fetch balances for reconciliation"
+                );
+                continue;
+            }
+        };
+
+        let drifts = tracker.lock().reconcile(exchange_balances, &tolerance);
+        metrics.reconciliations.fetch_add(1, Ordering::Relaxed);
+        if drifts.is_empty() {
+            continue;
+        }
+        metrics
+            .drifts
+            .fetch_add(drifts.len() as u64, Ordering::Relaxed);
+
+        for drift in &drifts {
+            warn!(
+                exchange = %Client::CLIENT,
+                symbol = %drift.symbol,
+                total = drift.total(),
+                available = drift.available(),
+                "tracked balance drifted from exchange balance"
+            );
+        }
+
+        let _ = event_tx.send(AccountEvent {
+            received_time: Utc::now(),
+            exchange: Client::CLIENT,
+            kind: AccountEventKind::BalanceDrift(drifts),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::{order::Cancelled, trade::SymbolFees},
+        test_util::{order_open, trade},
+    };
+    use barter_instrument::exchange::ExchangeId;
+    use barter_integration::Side;
+    use uuid::Uuid;
+
+    fn event(kind: AccountEventKind) -> AccountEvent {
+        AccountEvent {
+            received_time: Utc::now(),
+            exchange: ExchangeId::Simulated,
+            kind,
+        }
+    }
+
+    #[test]
+    fn test_order_tracker_reconcile() {
+        let cid = |n| ClientOrderId(Uuid::from_u128(n));
+        let order = |n, filled| {
+            let mut order = order_open(cid(n), Side::Buy, 100.0, 2.0, filled);
+            order.state.id = format!("order_{n}").into();
+            order
+        };
+
+        let mut tracker = OrderTracker::new();
+        tracker.update(&event(AccountEventKind::OrdersNew(vec![
+            order(0, 0.0),
+            order(1, 0.0),
+            order(2, 0.0),
+            order(3, 0.0),
+        ])));
+
+        // Order 0 cancelled, & order 1 partially filled
+        tracker.update(&event(AccountEventKind::OrdersCancelled(vec![Order::<
+            Cancelled,
+        >::from(
+            order(0, 0.0),
+        )])));
+        let mut fill = trade(
+            "trade".into(),
+            Side::Buy,
+            100.0,
+            1.0,
+            SymbolFees::new("quote", 0.0),
+        );
+        fill.order_id = "order_1".into();
+        tracker.update(&event(AccountEventKind::Trade(fill)));
+        assert_eq!(tracker.orders().count(), 3);
+
+        // Exchange: order 1 unchanged, order 2 missed a partial fill, order 3 missed it's fill
+        // & cancel, & order 4 was opened by another client
+        let drift = tracker.reconcile(
+            vec![order(1, 1.0), order(2, 0.5), order(4, 0.0)],
+            Instant::now(),
+        );
+
+        assert_eq!(drift.len(), 3);
+        assert!(drift.contains(&OrderDrift::Mismatch {
+            local: order(2, 0.0),
+            exchange: order(2, 0.5)
+        }));
+        assert!(drift.contains(&OrderDrift::Unknown(order(4, 0.0))));
+        assert!(drift.contains(&OrderDrift::Missing(order(3, 0.0))));
+
+        // Tracked orders corrected to match the exchange
+        assert!(tracker
+            .reconcile(
+                vec![order(1, 1.0), order(2, 0.5), order(4, 0.0)],
+                Instant::now()
+            )
+            .is_empty());
+    }
+
+    #[test]
+    fn test_order_tracker_reconcile_exempts_orders_updated_after_fetch() {
+        let cid = |n| ClientOrderId(Uuid::from_u128(n));
+        let order = |n, filled| {
+            let mut order = order_open(cid(n), Side::Buy, 100.0, 2.0, filled);
+            order.state.id = format!("order_{n}").into();
+            order
+        };
+
+        let before = Instant::now();
+        let fetched = before + Duration::from_secs(1);
+        let after = fetched + Duration::from_secs(1);
+
+        let mut tracker = OrderTracker::new();
+        tracker.update_at(
+            &event(AccountEventKind::OrdersNew(vec![
+                order(0, 0.0),
+                order(1, 0.0),
+            ])),
+            before,
+        );
+
+        // Order 2 opened, & order 1 partially filled, while the open orders fetch was in-flight.
+        // Exchange event times are irrelevant, only the local processing Instant is compared
+        tracker.update_at(
+            &AccountEvent {
+                received_time: Utc::now() - chrono::TimeDelta::hours(1),
+                ..event(AccountEventKind::OrdersNew(vec![order(2, 0.0)]))
+            },
+            after,
+        );
+        let mut fill = trade(
+            "trade".into(),
+            Side::Buy,
+            100.0,
+            1.0,
+            SymbolFees::new("quote", 0.0),
+        );
+        fill.order_id = "order_1".into();
+        tracker.update_at(&event(AccountEventKind::Trade(fill)), after);
+
+        // Exchange snapshot predates order 2 & the order 1 fill, but order 0 is genuinely missing
+        let drift = tracker.reconcile(vec![order(1, 0.0)], fetched);
+
+        assert_eq!(drift, vec![OrderDrift::Missing(order(0, 0.0))]);
+        assert_eq!(tracker.orders().count(), 2);
+
+        // Once a later fetch reflects them, they are reconciled as usual
+        let drift = tracker.reconcile(vec![order(1, 1.0)], after + Duration::from_secs(1));
+
+        assert_eq!(drift, vec![OrderDrift::Missing(order(2, 0.0))]);
+    }
+
+    #[test]
+    fn test_balance_tracker_reconcile() {
+        struct TestCase {
+            exchange: Vec<SymbolBalance>,
+            expected: Vec<BalanceDrift>,
+        }
+
+        let tolerance = BalanceTolerance {
+            absolute: 0.01,
+            relative: 0.001,
+        };
+
+        let mut tracker = BalanceTracker::new();
+        tracker.update(&event(AccountEventKind::Balances(vec![
+            SymbolBalance::new("btc", Balance::new(1.0, 1.0)),
+            SymbolBalance::new("usdt", Balance::new(10_000.0, 5_000.0)),
+        ])));
+
+        let tests = vec![
+            TestCase {
+                // TC0: within tolerance (absolute 0.01 & 0.1% of 10,000)
+                exchange: vec![
+                    SymbolBalance::new("btc", Balance::new(1.005, 1.005)),
+                    SymbolBalance::new("usdt", Balance::new(10_005.0, 5_000.0)),
+                ],
+                expected: vec![],
+            },
+            TestCase {
+                // TC1: usdt available drifted
+                exchange: vec![
+                    SymbolBalance::new("btc", Balance::new(1.005, 1.005)),
+                    SymbolBalance::new("usdt", Balance::new(10_005.0, 4_000.0)),
+                ],
+                expected: vec![BalanceDrift {
+                    symbol: Symbol::from("usdt"),
+                    local: Balance::new(10_005.0, 5_000.0),
+                    exchange: Balance::new(10_005.0, 4_000.0),
+                }],
+            },
+            TestCase {
+                // TC2: btc no longer reported, & eth unknown
+                exchange: vec![
+                    SymbolBalance::new("usdt", Balance::new(10_005.0, 4_000.0)),
+                    SymbolBalance::new("eth", Balance::new(2.0, 2.0)),
+                ],
+                expected: vec![
+                    BalanceDrift {
+                        symbol: Symbol::from("eth"),
+                        local: Balance::new(0.0, 0.0),
+                        exchange: Balance::new(2.0, 2.0),
+                    },
+                    BalanceDrift {
+                        symbol: Symbol::from("btc"),
+                        local: Balance::new(1.005, 1.005),
+                        exchange: Balance::new(0.0, 0.0),
+                    },
+                ],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = tracker.reconcile(test.exchange, &tolerance);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
 };
 use barter_instrument::{asset::symbol::Symbol, exchange::ExchangeId, instrument::Instrument};
 use barter_integration::Side;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -35,13 +35,16 @@ impl ClientBalances {
         })
     }
 
-    /// Fetch the client [`Balance`] for every [`Symbol``].
+    /// Fetch the client [`Balance`] for every [`Symbol``], sorted by [`Symbol`].
     pub fn fetch_all(&self) -> Vec<SymbolBalance> {
-        self.0
+        let mut balances = self
+            .0
             .clone()
             .into_iter()
             .map(|(symbol, balance)| SymbolBalance::new(symbol, balance))
-            .collect()
+            .collect::<Vec<_>>();
+        balances.sort_unstable_by(|a, b| a.symbol.cmp(&b.symbol));
+        balances
     }
 
     /// Determine if the client has sufficient available [`Balance`] to execute an
@@ -61,7 +64,12 @@ impl ClientBalances {
     /// Updates the associated [`Symbol`] [`Balance`] when a client creates an [`Order<Open>`]. The
     /// nature of the [`Balance`] change will depend on if the [`Order<Open>`] is a
     /// [`Side::Buy`] or [`Side::Sell`].
-    pub fn update_from_open(&mut self, open: &Order<Open>, required_balance: f64) -> AccountEvent {
+    pub fn update_from_open(
+        &mut self,
+        open: &Order<Open>,
+        required_balance: f64,
+        time: DateTime<Utc>,
+    ) -> AccountEvent {
         let updated_balance = match open.side {
             Side::Buy => {
                 let balance = self
@@ -82,7 +90,7 @@ impl ClientBalances {
         };
 
         AccountEvent {
-            received_time: Utc::now(),
+            received_time: time,
             exchange: ExchangeId::Simulated,
             kind: AccountEventKind::Balance(updated_balance),
         }
@@ -121,7 +129,7 @@ impl ClientBalances {
     ///
     /// A [`Side::Sell`] match causes the [`Symbol`] [`Balance`] of the base to decrease by the
     /// `trade_quantity`, and the quote to increase by the `trade_quantity * price`.
-    pub fn update_from_trade(&mut self, trade: &Trade) -> AccountEvent {
+    pub fn update_from_trade(&mut self, trade: &Trade, time: DateTime<Utc>) -> AccountEvent {
         let Instrument { base, quote, .. } = &trade.instrument;
 
         // Calculate the base & quote Balance deltas
@@ -167,7 +175,7 @@ impl ClientBalances {
         let quote_balance = self.update(quote, quote_delta);
 
         AccountEvent {
-            received_time: Utc::now(),
+            received_time: time,
             exchange: ExchangeId::Simulated,
             kind: AccountEventKind::Balances(vec![
                 SymbolBalance::new(base.clone(), base_balance),
@@ -1,19 +1,28 @@
-use self::{balance::ClientBalances, order::ClientOrders};
+use self::{
+    balance::ClientBalances,
+    order::ClientOrders,
+    simulation::{FillModel, LatencyModel},
+};
 use crate::{
     model::{
-        balance::{Balance, SymbolBalance},
+        balance::{Balance, BalanceDelta, SymbolBalance},
+        combo::{ComboFilled, ComboOrder, RequestOpenCombo},
+        margin::{InstrumentMargin, MarginConfig},
         order::OrderKind,
+        transfer::{Transfer, TransferId, TransferRequest, WithdrawRequest, Withdrawal},
         AccountEvent, AccountEventKind,
     },
     Cancelled, ExecutionError, Open, Order, RequestCancel, RequestOpen,
 };
 use barter_data::subscription::trade::PublicTrade;
-use barter_instrument::{exchange::ExchangeId, instrument::Instrument};
+use barter_instrument::{asset::symbol::Symbol, exchange::ExchangeId, instrument::Instrument};
 use barter_integration::Side;
-use chrono::Utc;
-use std::{fmt::Debug, time::Duration};
+use chrono::{DateTime, Utc};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{collections::HashMap, fmt::Debug, time::Duration};
 use tokio::sync::{mpsc, oneshot};
 use tracing::warn;
+use uuid::Uuid;
 
 /// [`ClientAccount`] [`Balance`] for each [`Symbol`](barter_integration::model::Symbol) and
 /// associated balance management logic.
@@ -22,15 +31,31 @@ pub mod balance;
 /// [`ClientAccount`] [`ClientOrders`] management & matching logic.
 pub mod order;
 
+/// [`ClientAccount`] [`LatencyModel`] & [`FillModel`], sampled using a seeded RNG so simulations
+/// are reproducible.
+pub mod simulation;
+
 /// Simulated account state containing [`ClientBalances`] and [`ClientOrders`]. Details the
-/// simulated account fees and latency.
+/// simulated account fees and latency, and tracks the last traded price of each [`Instrument`]
+/// (used to fill [`ComboOrder`]s).
+///
+/// A [`ClientAccount`] built with a `seed` & `start_time` is deterministic: the same
+/// [`SimulatedEvent`](super::super::SimulatedEvent)s produce the same [`AccountEvent`]s.
 #[derive(Clone, Debug)]
 pub struct ClientAccount {
-    pub latency: Duration,
+    pub latency: LatencyModel,
+    pub fill_model: FillModel,
+    /// RNG used to sample the [`LatencyModel`] & [`FillModel`].
+    pub rng: StdRng,
+    /// Simulated time used to timestamp [`AccountEvent`]s, advanced by
+    /// [`SimulatedEvent::ExpireOrders`](super::super::SimulatedEvent::ExpireOrders) clock events.
+    /// If `None`, the system time is used.
+    pub time: Option<DateTime<Utc>>,
     pub fees_percent: f64,
     pub event_account_tx: mpsc::UnboundedSender<AccountEvent>,
     pub balances: ClientBalances,
     pub orders: ClientOrders,
+    pub last_prices: HashMap<Instrument, f64>,
 }
 
 impl ClientAccount {
@@ -41,18 +66,20 @@ impl ClientAccount {
 
     /// Send every [`Order<Open>`] for every [`Instrument`] to the client.
     pub fn fetch_orders_open(
-        &self,
+        &mut self,
         response_tx: oneshot::Sender<Result<Vec<Order<Open>>, ExecutionError>>,
     ) {
-        respond_with_latency(self.latency, response_tx, Ok(self.orders.fetch_all()));
+        let latency = self.sample_latency();
+        respond_with_latency(latency, response_tx, Ok(self.orders.fetch_all()));
     }
 
     /// Send the [`Balance`] for every [`Symbol`](barter_integration::model::Symbol) to the client.
     pub fn fetch_balances(
-        &self,
+        &mut self,
         response_tx: oneshot::Sender<Result<Vec<SymbolBalance>, ExecutionError>>,
     ) {
-        respond_with_latency(self.latency, response_tx, Ok(self.balances.fetch_all()));
+        let latency = self.sample_latency();
+        respond_with_latency(latency, response_tx, Ok(self.balances.fetch_all()));
     }
 
     /// Execute open order requests and send the response via the provided [`oneshot::Sender`].
@@ -66,7 +93,8 @@ impl ClientAccount {
             .map(|request| self.try_open_order_atomic(request))
             .collect();
 
-        respond_with_latency(self.latency, response_tx, open_results);
+        let latency = self.sample_latency();
+        respond_with_latency(latency, response_tx, open_results);
     }
 
     /// Execute an open order request, adding it to [`ClientOrders`] and updating the associated
@@ -88,11 +116,14 @@ impl ClientAccount {
         let open = self.orders.build_order_open(request);
 
         // Retrieve client Instrument Orders
+        let time = self.time();
         let orders = self.orders.orders_mut(&open.instrument)?;
 
         // Now that fallible operations have succeeded, mutate ClientBalances & ClientOrders
         orders.add_order_open(open.clone());
-        let balance_event = self.balances.update_from_open(&open, required_balance);
+        let balance_event = self
+            .balances
+            .update_from_open(&open, required_balance, time);
 
         // Send AccountEvents to client
         self.event_account_tx
@@ -101,7 +132,7 @@ impl ClientAccount {
 
         self.event_account_tx
             .send(AccountEvent {
-                received_time: Utc::now(),
+                received_time: time,
                 exchange: ExchangeId::Simulated,
                 kind: AccountEventKind::OrdersNew(vec![open.clone()]),
             })
@@ -110,6 +141,16 @@ impl ClientAccount {
         Ok(open)
     }
 
+    /// Current time of the [`ClientAccount`], used to timestamp [`AccountEvent`]s.
+    pub fn time(&self) -> DateTime<Utc> {
+        self.time.unwrap_or_else(Utc::now)
+    }
+
+    /// Sample the [`LatencyModel`] for the next response to the client.
+    fn sample_latency(&mut self) -> Duration {
+        self.latency.sample(&mut self.rng)
+    }
+
     /// Check if the [`Order<RequestOpen>`] [`OrderKind`] is supported.
     pub fn check_order_kind_support(kind: OrderKind) -> Result<(), ExecutionError> {
         match kind {
@@ -118,6 +159,133 @@ impl ClientAccount {
         }
     }
 
+    /// Execute open combo order requests and send the response via the provided
+    /// [`oneshot::Sender`].
+    pub fn open_combo_orders(
+        &mut self,
+        combo_requests: Vec<ComboOrder<RequestOpenCombo>>,
+        response_tx: oneshot::Sender<Vec<Result<ComboOrder<ComboFilled>, ExecutionError>>>,
+    ) {
+        let combo_results = combo_requests
+            .into_iter()
+            .map(|request| self.try_open_combo_order_atomic(request))
+            .collect();
+
+        let latency = self.sample_latency();
+        respond_with_latency(latency, response_tx, combo_results);
+    }
+
+    /// Execute an open combo order request, filling every [`ComboLeg`](crate::model::combo::ComboLeg)
+    /// at the last traded price of it's [`Instrument`], or none at all. Sends an [`AccountEvent`]
+    /// for the balance updates and [`Trade`](crate::model::trade::Trade) of each leg.
+    ///
+    /// Simulated [`ComboOrder`]s are fill-or-kill: if the net price of the last traded prices is
+    /// worse than the requested net price, the request fails with
+    /// [`ExecutionError::ComboNotMarketable`].
+    pub fn try_open_combo_order_atomic(
+        &mut self,
+        request: ComboOrder<RequestOpenCombo>,
+    ) -> Result<ComboOrder<ComboFilled>, ExecutionError> {
+        request.validate()?;
+
+        // Find the last traded price of each leg Instrument
+        let leg_prices = request
+            .legs
+            .iter()
+            .map(|leg| {
+                self.last_prices
+                    .get(&leg.instrument)
+                    .copied()
+                    .ok_or_else(|| {
+                        ExecutionError::Simulated(format!(
+                            "SimulatedExchange has no market price for Instrument: {}",
+                            leg.instrument
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<f64>, ExecutionError>>()?;
+
+        // Check the combo is marketable at the current net price
+        let net_price = request
+            .legs
+            .iter()
+            .zip(&leg_prices)
+            .map(|(leg, price)| leg.net_price_contribution(*price))
+            .sum::<f64>();
+
+        if !request.is_marketable(net_price) {
+            return Err(ExecutionError::ComboNotMarketable(request.cid));
+        }
+
+        // Build the Order<RequestOpen> of each leg
+        let leg_requests = request
+            .legs
+            .iter()
+            .zip(leg_prices)
+            .map(|(leg, price)| request.leg_request(leg, price))
+            .collect::<Vec<_>>();
+
+        // Check available balance is sufficient to fill every leg
+        let mut required_balances = HashMap::new();
+        for leg_request in &leg_requests {
+            let (symbol, required_balance) = leg_request.required_available_balance();
+            *required_balances.entry(symbol).or_insert(0.0) += required_balance;
+        }
+        for (symbol, required_balance) in required_balances {
+            self.balances
+                .has_sufficient_available_balance(symbol, required_balance)?;
+        }
+
+        // Now that fallible operations have succeeded, fill every leg
+        let time = self.time();
+        self.orders.increment_request_counter();
+        let id = self.orders.order_id();
+
+        let trades = leg_requests
+            .into_iter()
+            .map(|leg_request| {
+                let (_, required_balance) = leg_request.required_available_balance();
+                let quantity = leg_request.state.quantity;
+                let open = Order::from((id.clone(), leg_request));
+
+                // Reserve the required balance as if the leg was opened, then fully match it
+                let balance_event = self
+                    .balances
+                    .update_from_open(&open, required_balance, time);
+
+                let orders = self
+                    .orders
+                    .orders_mut(&open.instrument)
+                    .expect("Instrument existence checked when recording last traded price");
+                orders.trade_counter += 1;
+                let trade = orders.generate_trade(open, quantity, self.fees_percent);
+
+                let balances_event = self.balances.update_from_trade(&trade, time);
+
+                // Send AccountEvents to client
+                self.event_account_tx
+                    .send(balance_event)
+                    .expect("Client is offline - failed to send AccountEvent::Balance");
+
+                self.event_account_tx
+                    .send(balances_event)
+                    .expect("Client is offline - failed to send AccountEvent::Balances");
+
+                self.event_account_tx
+                    .send(AccountEvent {
+                        received_time: time,
+                        exchange: ExchangeId::Simulated,
+                        kind: AccountEventKind::Trade(trade.clone()),
+                    })
+                    .expect("Client is offline - failed to send AccountEvent::Trade");
+
+                trade
+            })
+            .collect();
+
+        Ok(request.into_filled(id, net_price, trades))
+    }
+
     /// Execute cancel order requests and send the response via the provided [`oneshot::Sender`].
     pub fn cancel_orders(
         &mut self,
@@ -129,7 +297,8 @@ impl ClientAccount {
             .map(|request| self.try_cancel_order_atomic(request))
             .collect();
 
-        respond_with_latency(self.latency, response_tx, cancel_results);
+        let latency = self.sample_latency();
+        respond_with_latency(latency, response_tx, cancel_results);
     }
 
     /// Execute a cancel order request, removing it from the [`ClientOrders`] and updating the
@@ -166,6 +335,7 @@ impl ClientAccount {
         };
 
         // Now that fallible operations have succeeded, mutate ClientBalances
+        let time = self.time();
         let balance_event = self.balances.update_from_cancel(&removed);
 
         // Map Order<Open> to Order<Cancelled>
@@ -174,7 +344,7 @@ impl ClientAccount {
         // Send AccountEvents to client
         self.event_account_tx
             .send(AccountEvent {
-                received_time: Utc::now(),
+                received_time: time,
                 exchange: ExchangeId::Simulated,
                 kind: AccountEventKind::OrdersCancelled(vec![cancelled.clone()]),
             })
@@ -182,7 +352,7 @@ impl ClientAccount {
 
         self.event_account_tx
             .send(AccountEvent {
-                received_time: Utc::now(),
+                received_time: time,
                 exchange: ExchangeId::Simulated,
                 kind: AccountEventKind::Balance(balance_event),
             })
@@ -197,7 +367,8 @@ impl ClientAccount {
         &mut self,
         response_tx: oneshot::Sender<Result<Vec<Order<Cancelled>>, ExecutionError>>,
     ) {
-        let removed_orders = self
+        let time = self.time();
+        let mut removed_orders = self
             .orders
             .all
             .values_mut()
@@ -209,6 +380,9 @@ impl ClientAccount {
             })
             .collect::<Vec<Order<Open>>>();
 
+        // Sort since HashMap iteration order is random, so the AccountEvents are deterministic
+        removed_orders.sort_unstable_by(|a, b| a.state.id.cmp(&b.state.id));
+
         let balance_updates = removed_orders
             .iter()
             .map(|cancelled| self.balances.update_from_cancel(cancelled))
@@ -222,7 +396,7 @@ impl ClientAccount {
         // Send AccountEvents to client
         self.event_account_tx
             .send(AccountEvent {
-                received_time: Utc::now(),
+                received_time: time,
                 exchange: ExchangeId::Simulated,
                 kind: AccountEventKind::OrdersCancelled(cancelled_orders.clone()),
             })
@@ -230,21 +404,206 @@ impl ClientAccount {
 
         self.event_account_tx
             .send(AccountEvent {
-                received_time: Utc::now(),
+                received_time: time,
                 exchange: ExchangeId::Simulated,
                 kind: AccountEventKind::Balances(balance_updates),
             })
             .expect("Client is offline - failed to send AccountEvent::Balances");
 
-        respond_with_latency(self.latency, response_tx, Ok(cancelled_orders))
+        let latency = self.sample_latency();
+        respond_with_latency(latency, response_tx, Ok(cancelled_orders))
+    }
+
+    /// Validate a [`TransferRequest`] against the available [`Balance`], and send the
+    /// [`Transfer`] response via the provided [`oneshot::Sender`].
+    ///
+    /// The [`ClientAccount`] models a single unified wallet, so a valid [`Transfer`] does not
+    /// change any [`Balance`].
+    pub fn transfer(
+        &mut self,
+        request: TransferRequest,
+        response_tx: oneshot::Sender<Result<Transfer, ExecutionError>>,
+    ) {
+        let result = self
+            .validate_transfer(&request.symbol, request.quantity)
+            .and_then(|_| {
+                if request.from == request.to {
+                    return Err(ExecutionError::InvalidTransfer(format!(
+                        "from & to are the same wallet: {:?}",
+                        request.from
+                    )));
+                }
+
+                Ok(Transfer {
+                    id: self.transfer_id(),
+                    time: self.time(),
+                    request,
+                })
+            });
+
+        let latency = self.sample_latency();
+        respond_with_latency(latency, response_tx, result);
+    }
+
+    /// Execute a [`WithdrawRequest`], deducting the quantity from the [`Balance`] of it's
+    /// [`Symbol`]. Sends an [`AccountEvent`] for the balance update, and the [`Withdrawal`]
+    /// response via the provided [`oneshot::Sender`].
+    pub fn withdraw(
+        &mut self,
+        request: WithdrawRequest,
+        response_tx: oneshot::Sender<Result<Withdrawal, ExecutionError>>,
+    ) {
+        let result = self
+            .validate_transfer(&request.symbol, request.quantity)
+            .map(|_| {
+                let time = self.time();
+                let balance = self.balances.update(
+                    &request.symbol,
+                    BalanceDelta::new(-request.quantity, -request.quantity),
+                );
+
+                self.event_account_tx
+                    .send(AccountEvent {
+                        received_time: time,
+                        exchange: ExchangeId::Simulated,
+                        kind: AccountEventKind::Balance(SymbolBalance::new(
+                            request.symbol.clone(),
+                            balance,
+                        )),
+                    })
+                    .expect("Client is offline - failed to send AccountEvent::Balance");
+
+                Withdrawal {
+                    id: self.transfer_id(),
+                    time,
+                    request,
+                    fee: 0.0,
+                }
+            });
+
+        let latency = self.sample_latency();
+        respond_with_latency(latency, response_tx, result);
+    }
+
+    /// Validate & apply the leverage & [`MarginMode`](crate::model::margin::MarginMode) of a
+    /// known [`Instrument`], sending an [`AccountEventKind::Margin`] update and the applied
+    /// [`MarginConfig`] response via the provided [`oneshot::Sender`].
+    pub fn set_margin(
+        &mut self,
+        config: MarginConfig,
+        response_tx: oneshot::Sender<Result<MarginConfig, ExecutionError>>,
+    ) {
+        let result = if !self.orders.all.contains_key(&config.instrument) {
+            Err(ExecutionError::InvalidOrderParams(format!(
+                "SimulatedExchange is not configured for Instrument: {}",
+                config.instrument
+            )))
+        } else if !config.leverage.is_finite() || config.leverage < 1.0 {
+            Err(ExecutionError::InvalidMargin(format!(
+                "leverage must be at least 1: {}",
+                config.leverage
+            )))
+        } else {
+            self.event_account_tx
+                .send(AccountEvent {
+                    received_time: self.time(),
+                    exchange: ExchangeId::Simulated,
+                    kind: AccountEventKind::Margin(InstrumentMargin::from(config.clone())),
+                })
+                .expect("Client is offline - failed to send AccountEvent::Margin");
+
+            Ok(config)
+        };
+
+        let latency = self.sample_latency();
+        respond_with_latency(latency, response_tx, result);
+    }
+
+    /// Validate a transfer or withdrawal quantity is positive, and does not exceed the available
+    /// [`Balance`] of the [`Symbol`].
+    fn validate_transfer(&self, symbol: &Symbol, quantity: f64) -> Result<(), ExecutionError> {
+        if quantity <= 0.0 {
+            return Err(ExecutionError::InvalidTransfer(format!(
+                "quantity must be positive: {quantity}"
+            )));
+        }
+
+        self.balances
+            .has_sufficient_available_balance(symbol, quantity)
+    }
+
+    /// Generate a [`TransferId`] using the seeded RNG, so simulations are reproducible.
+    fn transfer_id(&mut self) -> TransferId {
+        TransferId::from(Uuid::from_u128(self.rng.gen()).to_string())
+    }
+
+    /// Cancel every [`Order<Open>`] with a [`TimeInForce::GoodTillDate`](crate::model::order::TimeInForce::GoodTillDate)
+    /// that has expired at the provided time. Sends an [`AccountEvent`] for both the orders
+    /// cancelled and balance updates if any [`Order<Open>`]s expired.
+    pub fn expire_orders(&mut self, time: DateTime<Utc>) {
+        // Advance the simulated time, if configured
+        if let Some(current) = &mut self.time {
+            *current = time.max(*current);
+        }
+
+        let mut expired_orders = self
+            .orders
+            .all
+            .values_mut()
+            .flat_map(|orders| {
+                let bids = orders.bids.extract_if(.., |bid| bid.is_expired(time));
+                let asks = orders.asks.extract_if(.., |ask| ask.is_expired(time));
+
+                bids.chain(asks).collect::<Vec<_>>()
+            })
+            .collect::<Vec<Order<Open>>>();
+
+        // Sort since HashMap iteration order is random, so the AccountEvents are deterministic
+        expired_orders.sort_unstable_by(|a, b| a.state.id.cmp(&b.state.id));
+
+        if expired_orders.is_empty() {
+            return;
+        }
+
+        let balance_updates = expired_orders
+            .iter()
+            .map(|expired| self.balances.update_from_cancel(expired))
+            .collect();
+
+        let cancelled_orders = expired_orders
+            .into_iter()
+            .map(Order::from)
+            .collect::<Vec<Order<Cancelled>>>();
+
+        // Send AccountEvents to client
+        self.event_account_tx
+            .send(AccountEvent {
+                received_time: time,
+                exchange: ExchangeId::Simulated,
+                kind: AccountEventKind::OrdersCancelled(cancelled_orders),
+            })
+            .expect("Client is offline - failed to send AccountEvent::OrdersCancelled");
+
+        self.event_account_tx
+            .send(AccountEvent {
+                received_time: time,
+                exchange: ExchangeId::Simulated,
+                kind: AccountEventKind::Balances(balance_updates),
+            })
+            .expect("Client is offline - failed to send AccountEvent::Balances");
     }
 
     /// Determine if the incoming [`PublicTrade`] liquidity matches any [`ClientOrders`] relating
     /// to the [`Instrument`]. If there are matches, trades are simulated by client orders being
     /// taken.
+    ///
+    /// Any [`TimeInForce::GoodTillDate`](crate::model::order::TimeInForce::GoodTillDate) orders
+    /// that have expired at the current time are cancelled first, so they are never matched.
     pub fn match_orders(&mut self, instrument: Instrument, trade: PublicTrade) {
         // Client fees
         let fees_percent = self.fees_percent;
+        let time = self.time();
+        self.expire_orders(time);
 
         // Access the ClientOrders relating to the Instrument of the PublicTrade
         let orders = match self.orders.orders_mut(&instrument) {
@@ -257,8 +616,12 @@ impl ClientAccount {
             }
         };
 
+        // Record the last traded price for filling ComboOrders
+        self.last_prices.insert(instrument, trade.price);
+
         // Match client Order<Open>s to incoming PublicTrade if the liquidity intersects
         let trades = match orders.has_matching_order(&trade) {
+            Some(_) if !self.fill_model.is_fill(&mut self.rng) => return,
             Some(Side::Buy) => orders.match_bids(&trade, fees_percent),
             Some(Side::Sell) => orders.match_asks(&trade, fees_percent),
             None => return,
@@ -267,7 +630,7 @@ impl ClientAccount {
         // Apply Balance updates for each client Trade and send AccountEvents to client
         for trade in trades {
             // Update Balances
-            let balances_event = self.balances.update_from_trade(&trade);
+            let balances_event = self.balances.update_from_trade(&trade, time);
 
             self.event_account_tx
                 .send(balances_event)
@@ -275,7 +638,7 @@ impl ClientAccount {
 
             self.event_account_tx
                 .send(AccountEvent {
-                    received_time: Utc::now(),
+                    received_time: time,
                     exchange: ExchangeId::Simulated,
                     kind: AccountEventKind::Trade(trade),
                 })
@@ -303,7 +666,10 @@ pub fn respond_with_latency<Response>(
 
 #[derive(Debug, Default)]
 pub struct ClientAccountBuilder {
-    latency: Option<Duration>,
+    latency: Option<LatencyModel>,
+    fill_model: Option<FillModel>,
+    seed: Option<u64>,
+    start_time: Option<DateTime<Utc>>,
     fees_percent: Option<f64>,
     event_account_tx: Option<mpsc::UnboundedSender<AccountEvent>>,
     instruments: Option<Vec<Instrument>>,
@@ -317,9 +683,39 @@ impl ClientAccountBuilder {
         }
     }
 
-    pub fn latency(self, value: Duration) -> Self {
+    pub fn latency<Latency>(self, value: Latency) -> Self
+    where
+        Latency: Into<LatencyModel>,
+    {
         Self {
-            latency: Some(value),
+            latency: Some(value.into()),
+            ..self
+        }
+    }
+
+    /// Optional [`FillModel`], defaults to [`FillModel::Liquidity`].
+    pub fn fill_model(self, value: FillModel) -> Self {
+        Self {
+            fill_model: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional seed of the RNG used to sample the [`LatencyModel`] & [`FillModel`]. Defaults to
+    /// a random seed.
+    pub fn seed(self, value: u64) -> Self {
+        Self {
+            seed: Some(value),
+            ..self
+        }
+    }
+
+    /// Optional start time of the simulated [`ClientAccount`] time, which is then advanced by
+    /// [`SimulatedEvent::ExpireOrders`](super::super::SimulatedEvent::ExpireOrders) clock events.
+    /// Defaults to using the system time.
+    pub fn start_time(self, value: DateTime<Utc>) -> Self {
+        Self {
+            start_time: Some(value),
             ..self
         }
     }
@@ -358,6 +754,11 @@ impl ClientAccountBuilder {
             latency: self
                 .latency
                 .ok_or_else(|| ExecutionError::BuilderIncomplete("latency".to_string()))?,
+            fill_model: self.fill_model.unwrap_or_default(),
+            rng: self
+                .seed
+                .map_or_else(StdRng::from_entropy, StdRng::seed_from_u64),
+            time: self.start_time,
             fees_percent: self
                 .fees_percent
                 .ok_or_else(|| ExecutionError::BuilderIncomplete("fees_percent".to_string()))?,
@@ -371,6 +772,7 @@ impl ClientAccountBuilder {
                 .instruments
                 .map(ClientOrders::new)
                 .ok_or_else(|| ExecutionError::BuilderIncomplete("instruments".to_string()))?,
+            last_prices: HashMap::new(),
         };
 
         // Validate each Instrument base & quote Symbol has an associated Balance
@@ -430,4 +832,257 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_try_open_combo_order_atomic() {
+        use crate::model::{combo::ComboOrder, ClientOrderId};
+        use barter_instrument::{asset::symbol::Symbol, instrument::kind::InstrumentKind};
+        use uuid::Uuid;
+
+        struct TestCase {
+            net_price: f64,
+            quantity: f64,
+            expected: Result<f64, ExecutionError>,
+        }
+
+        let long = Instrument::from(("long", "usdt", InstrumentKind::Perpetual));
+        let short = Instrument::from(("short", "usdt", InstrumentKind::Perpetual));
+
+        let tests = vec![
+            TestCase {
+                // TC0: not marketable at net debit limit below the current net price
+                net_price: 3.0,
+                quantity: 1.0,
+                expected: Err(ExecutionError::ComboNotMarketable(ClientOrderId(
+                    Uuid::nil(),
+                ))),
+            },
+            TestCase {
+                // TC1: insufficient short leg base balance, so neither leg is filled
+                net_price: 5.0,
+                quantity: 20.0,
+                expected: Err(ExecutionError::InsufficientBalance(Symbol::from("short"))),
+            },
+            TestCase {
+                // TC2: both legs filled at the current net price
+                net_price: 5.0,
+                quantity: 2.0,
+                expected: Ok(4.0),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (event_account_tx, _event_account_rx) = mpsc::unbounded_channel();
+            let mut account = ClientAccount::builder()
+                .latency(Duration::default())
+                .fees_percent(0.0)
+                .event_account_tx(event_account_tx)
+                .instruments(vec![long.clone(), short.clone()])
+                .balances(ClientBalances(HashMap::from([
+                    (Symbol::from("long"), Balance::new(0.0, 0.0)),
+                    (Symbol::from("short"), Balance::new(10.0, 10.0)),
+                    (Symbol::from("usdt"), Balance::new(1000.0, 1000.0)),
+                ])))
+                .build()
+                .unwrap();
+            account.last_prices.insert(long.clone(), 12.0);
+            account.last_prices.insert(short.clone(), 8.0);
+            let balances_before = account.balances.clone();
+
+            let request = ComboOrder::vertical_spread(
+                ExchangeId::Simulated,
+                ClientOrderId(Uuid::nil()),
+                long.clone(),
+                short.clone(),
+                test.net_price,
+                test.quantity,
+            );
+
+            let actual = account.try_open_combo_order_atomic(request);
+
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual.state.net_price, expected, "TC{index} failed");
+                    assert_eq!(actual.state.trades.len(), 2, "TC{index} failed");
+                    assert_eq!(
+                        account.balances.get(&Symbol::from("long")).unwrap().total,
+                        2.0,
+                        "TC{index} failed"
+                    );
+                    assert_eq!(
+                        account.balances.get(&Symbol::from("usdt")).unwrap().total,
+                        1000.0 - (2.0 * 12.0) + (2.0 * 8.0),
+                        "TC{index} failed"
+                    );
+                }
+                (Err(actual), Err(expected)) => {
+                    assert_eq!(actual, expected, "TC{index} failed");
+                    assert_eq!(account.balances, balances_before, "TC{index} failed");
+                }
+                (actual, expected) => {
+                    panic!("TC{index} failed: actual {actual:?} != expected {expected:?}")
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_seeded_client_account_is_deterministic() {
+        use crate::model::{
+            order::{RequestOpen, TimeInForce},
+            ClientOrderId,
+        };
+        use barter_instrument::{asset::symbol::Symbol, instrument::kind::InstrumentKind};
+        use chrono::TimeDelta;
+        use uuid::Uuid;
+
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+        let start_time = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+
+        // Run a simulation with a seeded ClientAccount, returning the serialised AccountEvents
+        let simulate = |seed: u64| {
+            let (event_account_tx, mut event_account_rx) = mpsc::unbounded_channel();
+            let mut account = ClientAccount::builder()
+                .latency(Duration::default())
+                .fill_model(FillModel::Probabilistic {
+                    fill_probability: 0.5,
+                })
+                .seed(seed)
+                .start_time(start_time)
+                .fees_percent(0.0)
+                .event_account_tx(event_account_tx)
+                .instruments(vec![instrument.clone()])
+                .balances(ClientBalances(HashMap::from([
+                    (Symbol::from("btc"), Balance::new(100.0, 100.0)),
+                    (Symbol::from("usdt"), Balance::new(100_000.0, 100_000.0)),
+                ])))
+                .build()
+                .unwrap();
+
+            for index in 0..20 {
+                let side = if index % 2 == 0 {
+                    Side::Buy
+                } else {
+                    Side::Sell
+                };
+                account
+                    .try_open_order_atomic(Order {
+                        exchange: ExchangeId::Simulated,
+                        instrument: instrument.clone(),
+                        cid: ClientOrderId(Uuid::from_u128(index)),
+                        side,
+                        state: RequestOpen {
+                            kind: OrderKind::Limit,
+                            price: 100.0,
+                            quantity: 1.0,
+                            time_in_force: TimeInForce::GoodTillDate(
+                                start_time + TimeDelta::seconds(10),
+                            ),
+                        },
+                    })
+                    .unwrap();
+
+                account.match_orders(
+                    instrument.clone(),
+                    PublicTrade {
+                        id: index.to_string(),
+                        price: 100.0,
+                        amount: 0.5,
+                        side: if index % 2 == 0 {
+                            Side::Sell
+                        } else {
+                            Side::Buy
+                        },
+                        aggregate_count: None,
+                    },
+                );
+                account.expire_orders(start_time + TimeDelta::seconds(index as i64));
+            }
+
+            let mut events = Vec::new();
+            while let Ok(event) = event_account_rx.try_recv() {
+                events.push(serde_json::to_string(&event).unwrap());
+            }
+            events
+        };
+
+        let actual = simulate(7);
+
+        assert_eq!(actual, simulate(7));
+        assert!(actual.iter().any(|event| event.contains("OrdersCancelled")));
+    }
+
+    #[tokio::test]
+    async fn test_withdraw() {
+        use crate::model::transfer::WithdrawRequest;
+
+        struct TestCase {
+            quantity: f64,
+            expected_ok: bool,
+            expected_balance: Balance,
+        }
+
+        let (event_account_tx, mut event_account_rx) = mpsc::unbounded_channel();
+        let mut account = ClientAccount::builder()
+            .latency(Duration::default())
+            .fees_percent(0.0)
+            .event_account_tx(event_account_tx)
+            .instruments(vec![])
+            .balances(ClientBalances(HashMap::from([(
+                Symbol::from("usdt"),
+                Balance::new(100.0, 100.0),
+            )])))
+            .build()
+            .unwrap();
+
+        let tests = vec![
+            TestCase {
+                // TC0: non-positive quantity is rejected
+                quantity: 0.0,
+                expected_ok: false,
+                expected_balance: Balance::new(100.0, 100.0),
+            },
+            TestCase {
+                // TC1: quantity exceeding the available balance is rejected
+                quantity: 150.0,
+                expected_ok: false,
+                expected_balance: Balance::new(100.0, 100.0),
+            },
+            TestCase {
+                // TC2: valid withdrawal deducts the balance
+                quantity: 40.0,
+                expected_ok: true,
+                expected_balance: Balance::new(60.0, 60.0),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (response_tx, response_rx) = oneshot::channel();
+            account.withdraw(
+                WithdrawRequest {
+                    symbol: Symbol::from("usdt"),
+                    quantity: test.quantity,
+                    address: "0xaddress".to_string(),
+                    network: None,
+                    memo: None,
+                },
+                response_tx,
+            );
+
+            let response = response_rx.await.unwrap();
+            assert_eq!(response.is_ok(), test.expected_ok, "TC{} failed", index);
+            assert_eq!(
+                account.balances.balance(&Symbol::from("usdt")).unwrap(),
+                &test.expected_balance,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                event_account_rx.try_recv().is_ok(),
+                test.expected_ok,
+                "TC{} failed",
+                index
+            );
+        }
+    }
 }
@@ -31,20 +31,23 @@ impl ClientOrders {
     /// Return a mutable reference to the client [`Orders`] of the specified [`Instrument`].
     pub fn orders_mut(&mut self, instrument: &Instrument) -> Result<&mut Orders, ExecutionError> {
         self.all.get_mut(instrument).ok_or_else(|| {
-            ExecutionError::Simulated(format!(
+            ExecutionError::InvalidOrderParams(format!(
                 "SimulatedExchange is not configured for Instrument: {instrument}"
             ))
         })
     }
 
-    /// Fetch the bid and ask [`Order<Open>`]s for every [`Instrument`].
+    /// Fetch the bid and ask [`Order<Open>`]s for every [`Instrument`], sorted by [`OrderId`].
     pub fn fetch_all(&self) -> Vec<Order<Open>> {
-        self.all
+        let mut orders = self
+            .all
             .values()
             .flat_map(|market| [&market.bids, &market.asks])
             .flatten()
             .cloned()
-            .collect()
+            .collect::<Vec<_>>();
+        orders.sort_unstable_by(|a, b| a.state.id.cmp(&b.state.id));
+        orders
     }
 
     /// Build an [`Order<Open>`] from the provided [`Order<RequestOpen>`]. The request counter
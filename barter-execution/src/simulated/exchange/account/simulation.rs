@@ -0,0 +1,158 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Model of the network latency between the [`SimulatedExchange`](super::super::SimulatedExchange)
+/// and the client, sampled using the [`ClientAccount`](super::ClientAccount) seeded RNG.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum LatencyModel {
+    /// Every response is delayed by the same [`Duration`].
+    Fixed(Duration),
+    /// Each response is delayed by a [`Duration`] sampled uniformly from `[min, max]`.
+    Uniform { min: Duration, max: Duration },
+    /// Each response is delayed by a [`Duration`] sampled from a normal distribution, truncated
+    /// at zero.
+    Normal { mean: Duration, std_dev: Duration },
+    /// Each response is delayed by a [`Duration`] sampled from an exponential distribution,
+    /// modelling occasional large latency spikes.
+    Exponential { mean: Duration },
+}
+
+impl Default for LatencyModel {
+    fn default() -> Self {
+        Self::Fixed(Duration::ZERO)
+    }
+}
+
+impl From<Duration> for LatencyModel {
+    fn from(latency: Duration) -> Self {
+        Self::Fixed(latency)
+    }
+}
+
+impl LatencyModel {
+    /// Sample the latency [`Duration`] of the next response.
+    pub fn sample<R>(&self, rng: &mut R) -> Duration
+    where
+        R: Rng,
+    {
+        match *self {
+            Self::Fixed(latency) => latency,
+            Self::Uniform { min, max } if min >= max => min,
+            Self::Uniform { min, max } => rng.gen_range(min..=max),
+            Self::Normal { mean, std_dev } => {
+                // Box-Muller transform of two uniform samples in (0, 1]
+                let radius = (-2.0 * (1.0 - rng.gen::<f64>()).ln()).sqrt();
+                let angle = std::f64::consts::TAU * rng.gen::<f64>();
+                let sample = mean.as_secs_f64() + std_dev.as_secs_f64() * radius * angle.cos();
+                Duration::from_secs_f64(sample.max(0.0))
+            }
+            Self::Exponential { mean } => {
+                // Inverse transform of a uniform sample in (0, 1]
+                let sample = -mean.as_secs_f64() * (1.0 - rng.gen::<f64>()).ln();
+                Duration::from_secs_f64(sample)
+            }
+        }
+    }
+}
+
+/// Model of whether a [`PublicTrade`](barter_data::subscription::trade::PublicTrade) that
+/// intersects open client orders fills them, sampled using the
+/// [`ClientAccount`](super::ClientAccount) seeded RNG.
+#[derive(Copy, Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub enum FillModel {
+    /// Every intersecting `PublicTrade` fills open client orders, up to the trade liquidity.
+    #[default]
+    Liquidity,
+    /// Each intersecting `PublicTrade` fills open client orders with the provided probability
+    /// (eg/ 0.6 for 60%), modelling the uncertain queue position of client orders.
+    Probabilistic { fill_probability: f64 },
+}
+
+impl FillModel {
+    /// Determine if the next intersecting `PublicTrade` fills open client orders.
+    pub fn is_fill<R>(&self, rng: &mut R) -> bool
+    where
+        R: Rng,
+    {
+        match *self {
+            Self::Liquidity => true,
+            Self::Probabilistic { fill_probability } => {
+                rng.gen_bool(fill_probability.clamp(0.0, 1.0))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_latency_model_sample() {
+        struct TestCase {
+            model: LatencyModel,
+            expected_min: Duration,
+            expected_max: Duration,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Fixed
+                model: LatencyModel::Fixed(Duration::from_millis(50)),
+                expected_min: Duration::from_millis(50),
+                expected_max: Duration::from_millis(50),
+            },
+            TestCase {
+                // TC1: Uniform
+                model: LatencyModel::Uniform {
+                    min: Duration::from_millis(10),
+                    max: Duration::from_millis(20),
+                },
+                expected_min: Duration::from_millis(10),
+                expected_max: Duration::from_millis(20),
+            },
+            TestCase {
+                // TC2: Uniform with min > max uses min
+                model: LatencyModel::Uniform {
+                    min: Duration::from_millis(30),
+                    max: Duration::from_millis(20),
+                },
+                expected_min: Duration::from_millis(30),
+                expected_max: Duration::from_millis(30),
+            },
+            TestCase {
+                // TC3: Normal is truncated at zero
+                model: LatencyModel::Normal {
+                    mean: Duration::from_millis(1),
+                    std_dev: Duration::from_millis(10),
+                },
+                expected_min: Duration::ZERO,
+                expected_max: Duration::MAX,
+            },
+            TestCase {
+                // TC4: Exponential
+                model: LatencyModel::Exponential {
+                    mean: Duration::from_millis(5),
+                },
+                expected_min: Duration::ZERO,
+                expected_max: Duration::MAX,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut rng = StdRng::seed_from_u64(42);
+            let mut replay = StdRng::seed_from_u64(42);
+
+            for _ in 0..100 {
+                let actual = test.model.sample(&mut rng);
+                assert!(
+                    actual >= test.expected_min && actual <= test.expected_max,
+                    "TC{index} failed"
+                );
+                assert_eq!(actual, test.model.sample(&mut replay), "TC{index} failed");
+            }
+        }
+    }
+}
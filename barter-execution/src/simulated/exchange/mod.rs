@@ -1,10 +1,16 @@
 use super::{exchange::account::ClientAccount, SimulatedEvent};
 use crate::ExecutionError;
-use tokio::sync::mpsc;
+use chrono::Utc;
+use std::time::Duration;
+use tokio::{sync::mpsc, time::MissedTickBehavior};
 
 /// [`SimulatedExchange`] account balances, open orders, fees, and latency.
 pub mod account;
 
+/// Interval at which a [`SimulatedExchange`] using the system time expires
+/// [`TimeInForce::GoodTillDate`](crate::model::order::TimeInForce::GoodTillDate) orders.
+pub const ORDER_EXPIRY_INTERVAL: Duration = Duration::from_millis(100);
+
 /// [`SimulatedExchange`] that responds to [`SimulatedEvent`]s.
 #[derive(Debug)]
 pub struct SimulatedExchange {
@@ -19,30 +25,62 @@ impl SimulatedExchange {
     }
 
     /// Run the [`SimulatedExchange`] by responding to [`SimulatedEvent`]s.
+    ///
+    /// If the [`ClientAccount`] uses the system time, expired
+    /// [`TimeInForce::GoodTillDate`](crate::model::order::TimeInForce::GoodTillDate) orders are
+    /// cancelled every [`ORDER_EXPIRY_INTERVAL`]. Otherwise they are cancelled as the simulated
+    /// time is advanced by [`SimulatedEvent::ExpireOrders`] clock events.
     pub async fn run(mut self) {
-        while let Some(event) = self.event_simulated_rx.recv().await {
-            match event {
-                SimulatedEvent::FetchOrdersOpen(response_tx) => {
-                    self.account.fetch_orders_open(response_tx)
-                }
-                SimulatedEvent::FetchBalances(response_tx) => {
-                    self.account.fetch_balances(response_tx)
-                }
-                SimulatedEvent::OpenOrders((open_requests, response_tx)) => {
-                    self.account.open_orders(open_requests, response_tx)
-                }
-                SimulatedEvent::CancelOrders((cancel_requests, response_tx)) => {
-                    self.account.cancel_orders(cancel_requests, response_tx)
-                }
-                SimulatedEvent::CancelOrdersAll(response_tx) => {
-                    self.account.cancel_orders_all(response_tx)
-                }
-                SimulatedEvent::MarketTrade((instrument, trade)) => {
-                    self.account.match_orders(instrument, trade)
+        let mut expiry = tokio::time::interval(ORDER_EXPIRY_INTERVAL);
+        expiry.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+        loop {
+            tokio::select! {
+                event = self.event_simulated_rx.recv() => match event {
+                    Some(event) => self.handle(event),
+                    None => break,
+                },
+                _ = expiry.tick(), if self.account.time.is_none() => {
+                    self.account.expire_orders(Utc::now())
                 }
             }
         }
     }
+
+    /// Respond to the [`SimulatedEvent`].
+    fn handle(&mut self, event: SimulatedEvent) {
+        match event {
+            SimulatedEvent::FetchOrdersOpen(response_tx) => {
+                self.account.fetch_orders_open(response_tx)
+            }
+            SimulatedEvent::FetchBalances(response_tx) => self.account.fetch_balances(response_tx),
+            SimulatedEvent::OpenOrders((open_requests, response_tx)) => {
+                self.account.open_orders(open_requests, response_tx)
+            }
+            SimulatedEvent::CancelOrders((cancel_requests, response_tx)) => {
+                self.account.cancel_orders(cancel_requests, response_tx)
+            }
+            SimulatedEvent::CancelOrdersAll(response_tx) => {
+                self.account.cancel_orders_all(response_tx)
+            }
+            SimulatedEvent::OpenComboOrders((combo_requests, response_tx)) => {
+                self.account.open_combo_orders(combo_requests, response_tx)
+            }
+            SimulatedEvent::Transfer((request, response_tx)) => {
+                self.account.transfer(request, response_tx)
+            }
+            SimulatedEvent::Withdraw((request, response_tx)) => {
+                self.account.withdraw(request, response_tx)
+            }
+            SimulatedEvent::SetMargin((config, response_tx)) => {
+                self.account.set_margin(config, response_tx)
+            }
+            SimulatedEvent::MarketTrade((instrument, trade)) => {
+                self.account.match_orders(instrument, trade)
+            }
+            SimulatedEvent::ExpireOrders(time) => self.account.expire_orders(time),
+        }
+    }
 }
 
 #[derive(Debug, Default)]
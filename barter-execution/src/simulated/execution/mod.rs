@@ -1,5 +1,10 @@
 use crate::{
-    model::order::{Cancelled, Open, Order},
+    model::{
+        combo::{ComboFilled, ComboOrder, RequestOpenCombo},
+        margin::MarginConfig,
+        order::{Cancelled, Open, Order, TimeInForce},
+        transfer::{Transfer, TransferRequest, WithdrawRequest, Withdrawal},
+    },
     simulated::SimulatedEvent,
     AccountEvent, ExecutionClient, ExecutionError, RequestCancel, RequestOpen, SymbolBalance,
 };
@@ -23,6 +28,32 @@ impl ExecutionClient for SimulatedExecution {
         Self { request_tx }
     }
 
+    /// [`SimulatedExchange`](super::exchange::SimulatedExchange) natively expires
+    /// [`TimeInForce::GoodTillDate`] orders via [`SimulatedEvent::ExpireOrders`].
+    fn supports_time_in_force(_: &TimeInForce) -> bool {
+        true
+    }
+
+    /// [`SimulatedExchange`](super::exchange::SimulatedExchange) atomically fills
+    /// [`ComboOrder`]s via [`SimulatedEvent::OpenComboOrders`].
+    fn supports_combo_orders() -> bool {
+        true
+    }
+
+    /// [`SimulatedExchange`](super::exchange::SimulatedExchange) models a single unified
+    /// wallet, so transfers are validated against the available balance but do not change it.
+    fn supports_transfers() -> bool {
+        true
+    }
+
+    fn supports_withdrawals() -> bool {
+        true
+    }
+
+    fn supports_margin() -> bool {
+        true
+    }
+
     async fn fetch_orders_open(&self) -> Result<Vec<Order<Open>>, ExecutionError> {
         // Oneshot channel to communicate with the SimulatedExchange
         let (response_tx, response_rx) = oneshot::channel();
@@ -103,4 +134,70 @@ impl ExecutionClient for SimulatedExecution {
             .await
             .expect("SimulatedExchange is offline - failed to receive CancelOrdersAll response")
     }
+
+    async fn open_combo_orders(
+        &self,
+        combo_requests: Vec<ComboOrder<RequestOpenCombo>>,
+    ) -> Vec<Result<ComboOrder<ComboFilled>, ExecutionError>> {
+        // Oneshot channel to communicate with the SimulatedExchange
+        let (response_tx, response_rx) = oneshot::channel();
+
+        // Send OpenComboOrders request to the SimulatedExchange
+        self.request_tx
+            .send(SimulatedEvent::OpenComboOrders((
+                combo_requests,
+                response_tx,
+            )))
+            .expect("SimulatedExchange is offline - failed to send OpenComboOrders request");
+
+        // Receive OpenComboOrders response from the SimulatedExchange
+        response_rx
+            .await
+            .expect("SimulatedExchange is offline - failed to receive OpenComboOrders response")
+    }
+
+    async fn transfer(&self, request: TransferRequest) -> Result<Transfer, ExecutionError> {
+        // Oneshot channel to communicate with the SimulatedExchange
+        let (response_tx, response_rx) = oneshot::channel();
+
+        // Send Transfer request to the SimulatedExchange
+        self.request_tx
+            .send(SimulatedEvent::Transfer((request, response_tx)))
+            .expect("SimulatedExchange is offline - failed to send Transfer request");
+
+        // Receive Transfer response from the SimulatedExchange
+        response_rx
+            .await
+            .expect("SimulatedExchange is offline - failed to receive Transfer response")
+    }
+
+    async fn withdraw(&self, request: WithdrawRequest) -> Result<Withdrawal, ExecutionError> {
+        // Oneshot channel to communicate with the SimulatedExchange
+        let (response_tx, response_rx) = oneshot::channel();
+
+        // Send Withdraw request to the SimulatedExchange
+        self.request_tx
+            .send(SimulatedEvent::Withdraw((request, response_tx)))
+            .expect("SimulatedExchange is offline - failed to send Withdraw request");
+
+        // Receive Withdraw response from the SimulatedExchange
+        response_rx
+            .await
+            .expect("SimulatedExchange is offline - failed to receive Withdraw response")
+    }
+
+    async fn set_margin(&self, config: MarginConfig) -> Result<MarginConfig, ExecutionError> {
+        // Oneshot channel to communicate with the SimulatedExchange
+        let (response_tx, response_rx) = oneshot::channel();
+
+        // Send SetMargin request to the SimulatedExchange
+        self.request_tx
+            .send(SimulatedEvent::SetMargin((config, response_tx)))
+            .expect("SimulatedExchange is offline - failed to send SetMargin request");
+
+        // Receive SetMargin response from the SimulatedExchange
+        response_rx
+            .await
+            .expect("SimulatedExchange is offline - failed to receive SetMargin response")
+    }
 }
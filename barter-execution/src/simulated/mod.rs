@@ -1,6 +1,14 @@
-use crate::{Cancelled, ExecutionError, Open, Order, RequestCancel, RequestOpen, SymbolBalance};
+use crate::{
+    model::{
+        combo::{ComboFilled, ComboOrder, RequestOpenCombo},
+        margin::MarginConfig,
+        transfer::{Transfer, TransferRequest, WithdrawRequest, Withdrawal},
+    },
+    Cancelled, ExecutionError, Open, Order, RequestCancel, RequestOpen, SymbolBalance,
+};
 use barter_data::subscription::trade::PublicTrade;
 use barter_instrument::instrument::Instrument;
+use chrono::{DateTime, Utc};
 use tokio::sync::oneshot;
 
 /// Simulated Exchange using public trade `Streams` to model available market liquidity. Liquidity
@@ -17,6 +25,8 @@ pub mod execution;
 /// 1. Request sent from the [`SimulatedExecution`](execution::SimulatedExecution)
 ///    [`ExecutionClient`](crate::ExecutionClient).
 /// 2. Market events used to model available liquidity and trigger matches with open client orders.
+/// 3. Clock events used to expire open client orders with a
+///    [`TimeInForce::GoodTillDate`](crate::model::order::TimeInForce::GoodTillDate).
 #[derive(Debug)]
 pub enum SimulatedEvent {
     FetchOrdersOpen(oneshot::Sender<Result<Vec<Order<Open>>, ExecutionError>>),
@@ -34,5 +44,30 @@ pub enum SimulatedEvent {
         ),
     ),
     CancelOrdersAll(oneshot::Sender<Result<Vec<Order<Cancelled>>, ExecutionError>>),
+    OpenComboOrders(
+        (
+            Vec<ComboOrder<RequestOpenCombo>>,
+            oneshot::Sender<Vec<Result<ComboOrder<ComboFilled>, ExecutionError>>>,
+        ),
+    ),
+    Transfer(
+        (
+            TransferRequest,
+            oneshot::Sender<Result<Transfer, ExecutionError>>,
+        ),
+    ),
+    Withdraw(
+        (
+            WithdrawRequest,
+            oneshot::Sender<Result<Withdrawal, ExecutionError>>,
+        ),
+    ),
+    SetMargin(
+        (
+            MarginConfig,
+            oneshot::Sender<Result<MarginConfig, ExecutionError>>,
+        ),
+    ),
     MarketTrade((Instrument, PublicTrade)),
+    ExpireOrders(DateTime<Utc>),
 }
@@ -7,7 +7,7 @@ use barter_execution::{
     error::ExecutionError,
     model::{
         balance::{Balance, SymbolBalance},
-        order::OrderId,
+        order::{OrderId, TimeInForce},
         trade::{SymbolFees, Trade, TradeId},
         AccountEvent, AccountEventKind, ClientOrderId,
     },
@@ -19,6 +19,7 @@ use barter_instrument::{
     instrument::{kind::InstrumentKind, Instrument},
 };
 use barter_integration::Side;
+use chrono::{Duration, Utc};
 use smol_str::ToSmolStr;
 use tokio::sync::mpsc;
 use uuid::Uuid;
@@ -136,6 +137,22 @@ async fn main() {
 
     // 14. Fail to cancel limit order with OrderNotFound using incorrect OrderId
     test_14_fail_to_cancel_limit_with_order_not_found(&client).await;
+
+    // 15. Open GoodTillDate LIMIT Buy Order, and check it is only cancelled once expired
+    let test_15_ids = Ids::new(Uuid::new_v4(), 7);
+    test_15_expire_good_till_date_order(
+        &client,
+        test_15_ids,
+        &mut event_simulated_tx,
+        &mut event_account_rx,
+    )
+    .await;
+
+    // 16. Open GoodTillDate LIMIT Buy Order, and check it is cancelled once expired at the system
+    //     time without any ExpireOrders clock event
+    let test_16_ids = Ids::new(Uuid::new_v4(), 8);
+    test_16_expire_good_till_date_order_at_system_time(&client, test_16_ids, &mut event_account_rx)
+        .await;
 }
 
 // 1. Fetch initial OpenOrders when we have no open Orders.
@@ -237,6 +254,7 @@ fn test_4_send_market_event_that_does_not_match_any_open_order(
                 side: Side::Sell,
                 price: 1000.0,
                 amount: 1.0,
+                aggregate_count: None,
             },
         )))
         .unwrap();
@@ -445,6 +463,7 @@ async fn test_7_send_market_event_that_exact_full_matches_order(
                 side: Side::Sell,
                 price: 200.0,
                 amount: 1.0,
+                aggregate_count: None,
             },
         )))
         .unwrap();
@@ -676,6 +695,7 @@ async fn test_10_send_market_event_that_full_and_partial_matches_orders(
                 side: Side::Buy,
                 price: 1000.0,
                 amount: 1.5,
+                aggregate_count: None,
             },
         )))
         .unwrap();
@@ -814,7 +834,7 @@ async fn test_11_cancel_all_orders(
 ) {
     let cancelled = client.cancel_orders_all().await.unwrap();
 
-    let expected_cancelled = vec![
+    let expected_cancelled = [
         order_cancelled(
             // Bids are cancelled first
             Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
@@ -1008,3 +1028,167 @@ async fn test_14_fail_to_cancel_limit_with_order_not_found(client: &SimulatedExe
     assert_eq!(cancelled.len(), 1);
     assert_eq!(cancelled[0], expected);
 }
+
+// 15. Open GoodTillDate LIMIT Buy Order, and check it is only cancelled once expired.
+async fn test_15_expire_good_till_date_order(
+    client: &SimulatedExecution,
+    test_15_ids: Ids,
+    event_simulated_tx: &mut mpsc::UnboundedSender<SimulatedEvent>,
+    event_account_rx: &mut mpsc::UnboundedReceiver<AccountEvent>,
+) {
+    let balances_before = client.fetch_balances().await.unwrap();
+    let expiry = Utc::now() + Duration::hours(1);
+
+    let mut request = order_request_limit(
+        Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+        test_15_ids.cid,
+        Side::Buy,
+        100.0,
+        1.0,
+    );
+    request.state.time_in_force = TimeInForce::GoodTillDate(expiry);
+
+    let new_orders = client.open_orders(vec![request]).await;
+    assert_eq!(new_orders.len(), 1);
+    let new_order = new_orders[0].clone().unwrap();
+    assert_eq!(new_order.state.id, test_15_ids.id);
+    assert_eq!(
+        new_order.state.time_in_force,
+        TimeInForce::GoodTillDate(expiry)
+    );
+
+    // Consume AccountEvents for the open Balance & OrdersNew
+    assert!(event_account_rx.try_recv().is_ok());
+    assert!(event_account_rx.try_recv().is_ok());
+
+    // GoodTillDate is natively enforced by the SimulatedExchange, so no client cancels required
+    assert!(client
+        .cancel_orders_expired(expiry)
+        .await
+        .unwrap()
+        .is_empty());
+
+    // Send ExpireOrders before the GoodTillDate expiry & check no AccountEvents are sent
+    event_simulated_tx
+        .send(SimulatedEvent::ExpireOrders(expiry - Duration::seconds(1)))
+        .unwrap();
+    tokio::time::sleep(latency_50ms()).await;
+
+    match event_account_rx.try_recv() {
+        Err(mpsc::error::TryRecvError::Empty) => {}
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+
+    // Send ExpireOrders at the GoodTillDate expiry & check the order is cancelled
+    event_simulated_tx
+        .send(SimulatedEvent::ExpireOrders(expiry))
+        .unwrap();
+    tokio::time::sleep(latency_50ms()).await;
+
+    let expected_cancelled = order_cancelled(
+        Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+        test_15_ids.cid,
+        Side::Buy,
+        test_15_ids.id.clone(),
+    );
+
+    match event_account_rx.try_recv() {
+        Ok(AccountEvent {
+            kind: AccountEventKind::OrdersCancelled(cancelled),
+            ..
+        }) => {
+            assert_eq!(cancelled, vec![expected_cancelled]);
+        }
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+
+    match event_account_rx.try_recv() {
+        Ok(AccountEvent {
+            kind: AccountEventKind::Balances(balances),
+            ..
+        }) => {
+            assert_eq!(balances.len(), 1);
+            assert_eq!(balances[0].symbol, Symbol::from("usdt"));
+        }
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+
+    // Check no more AccountEvents generated
+    match event_account_rx.try_recv() {
+        Err(mpsc::error::TryRecvError::Empty) => {}
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+
+    // Check expired order is no longer open & the usdt Balance is restored
+    let orders_open = client.fetch_orders_open().await.unwrap();
+    assert!(orders_open
+        .iter()
+        .all(|order| order.state.id != test_15_ids.id));
+
+    let mut balances_after = client.fetch_balances().await.unwrap();
+    let mut balances_before = balances_before;
+    balances_after.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+    balances_before.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+    assert_eq!(balances_after, balances_before);
+}
+
+// 16. Open GoodTillDate LIMIT Buy Order, and check it is cancelled once expired at the system time
+//     without any ExpireOrders clock event.
+async fn test_16_expire_good_till_date_order_at_system_time(
+    client: &SimulatedExecution,
+    test_16_ids: Ids,
+    event_account_rx: &mut mpsc::UnboundedReceiver<AccountEvent>,
+) {
+    let expiry = Utc::now() + Duration::milliseconds(200);
+
+    let mut request = order_request_limit(
+        Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+        test_16_ids.cid,
+        Side::Buy,
+        100.0,
+        1.0,
+    );
+    request.state.time_in_force = TimeInForce::GoodTillDate(expiry);
+
+    let new_orders = client.open_orders(vec![request]).await;
+    assert_eq!(new_orders[0].as_ref().unwrap().state.id, test_16_ids.id);
+
+    // Consume AccountEvents for the open Balance & OrdersNew
+    assert!(event_account_rx.try_recv().is_ok());
+    assert!(event_account_rx.try_recv().is_ok());
+
+    // SimulatedExchange uses the system time, so expires orders every ORDER_EXPIRY_INTERVAL
+    tokio::time::sleep(std::time::Duration::from_millis(400)).await;
+
+    let expected_cancelled = order_cancelled(
+        Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+        test_16_ids.cid,
+        Side::Buy,
+        test_16_ids.id.clone(),
+    );
+
+    match event_account_rx.try_recv() {
+        Ok(AccountEvent {
+            kind: AccountEventKind::OrdersCancelled(cancelled),
+            ..
+        }) => {
+            assert_eq!(cancelled, vec![expected_cancelled]);
+        }
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+
+    let orders_open = client.fetch_orders_open().await.unwrap();
+    assert!(orders_open
+        .iter()
+        .all(|order| order.state.id != test_16_ids.id));
+}
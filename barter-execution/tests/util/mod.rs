@@ -1,7 +1,9 @@
 use barter_execution::{
     model::{
         balance::Balance,
-        order::{Cancelled, Open, Order, OrderId, OrderKind, RequestCancel, RequestOpen},
+        order::{
+            Cancelled, Open, Order, OrderId, OrderKind, RequestCancel, RequestOpen, TimeInForce,
+        },
         AccountEvent, ClientOrderId,
     },
     simulated::{
@@ -91,6 +93,7 @@ where
             kind: OrderKind::Limit,
             price,
             quantity,
+            time_in_force: TimeInForce::default(),
         },
     }
 }
@@ -118,6 +121,7 @@ where
             price,
             quantity,
             filled_quantity: filled,
+            time_in_force: TimeInForce::default(),
         },
     }
 }
@@ -1,3 +1,4 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Deserializer, Serialize};
 use smol_str::{SmolStr, StrExt};
 use std::fmt::{Display, Formatter};
@@ -5,8 +6,8 @@ use std::fmt::{Display, Formatter};
 /// Barter new type representing a currency symbol `String` identifier.
 ///
 /// eg/ "btc", "eth", "usdt", etc
-#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize)]
-pub struct Symbol(SmolStr);
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, JsonSchema)]
+pub struct Symbol(#[schemars(with = "String")] SmolStr);
 
 impl Display for Symbol {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -1,4 +1,5 @@
 use derive_more::Display;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Unique identifier for an exchange server.
@@ -10,7 +11,18 @@ use serde::{Deserialize, Serialize};
 /// For example, BinanceSpot and BinanceFuturesUsd have distinct APIs, and are therefore
 /// represented as unique variants.
 #[derive(
-    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Display,
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Deserialize,
+    Serialize,
+    Display,
+    JsonSchema,
 )]
 #[serde(rename = "exchange", rename_all = "snake_case")]
 pub enum ExchangeId {
@@ -40,6 +52,7 @@ pub enum ExchangeId {
     CoinbaseInternational,
     Cryptocom,
     Deribit,
+    Dydx,
     GateioFuturesBtc,
     GateioFuturesUsd,
     GateioOptions,
@@ -56,6 +69,7 @@ pub enum ExchangeId {
     Mexc,
     Okx,
     Poloniex,
+    Upbit,
 }
 
 impl ExchangeId {
@@ -88,6 +102,7 @@ impl ExchangeId {
             ExchangeId::CoinbaseInternational => "coinbase_international",
             ExchangeId::Cryptocom => "cryptocom",
             ExchangeId::Deribit => "deribit",
+            ExchangeId::Dydx => "dydx",
             ExchangeId::GateioFuturesBtc => "gateio_futures_btc",
             ExchangeId::GateioFuturesUsd => "gateio_futures_usd",
             ExchangeId::GateioOptions => "gateio_options",
@@ -103,6 +118,7 @@ impl ExchangeId {
             ExchangeId::Mexc => "mexc",
             ExchangeId::Okx => "okx",
             ExchangeId::Poloniex => "poloniex",
+            ExchangeId::Upbit => "upbit",
         }
     }
 }
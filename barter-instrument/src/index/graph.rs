@@ -0,0 +1,260 @@
+use crate::{
+    asset::symbol::Symbol,
+    instrument::{Instrument, InstrumentIndex},
+    Keyed,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Direction an [`Instrument`] is traded to convert between it's base & quote assets.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum LegSide {
+    /// Buy the base asset using the quote asset.
+    Buy,
+    /// Sell the base asset for the quote asset.
+    Sell,
+}
+
+/// Single conversion of a [`TriangularCycle`], from one asset to another by trading an
+/// [`Instrument`].
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct TriangularLeg {
+    pub instrument: InstrumentIndex,
+    pub from: Symbol,
+    pub to: Symbol,
+    pub side: LegSide,
+}
+
+/// Three [`TriangularLeg`]s that convert an asset back into itself via two other assets.
+///
+/// eg/ usdt -> btc (buy btc_usdt), btc -> eth (buy eth_btc), eth -> usdt (sell eth_usdt)
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct TriangularCycle {
+    pub legs: [TriangularLeg; 3],
+}
+
+impl TriangularCycle {
+    /// Asset the [`TriangularCycle`] starts & ends with.
+    pub fn start(&self) -> &Symbol {
+        &self.legs[0].from
+    }
+}
+
+/// Edge of the [`InstrumentGraph`], connecting an asset to a neighbouring asset.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+struct Edge {
+    to: Symbol,
+    instrument: InstrumentIndex,
+    side: LegSide,
+}
+
+/// Graph of [`Instrument`]s, where each asset [`Symbol`] is a node and each [`Instrument`] is an
+/// edge connecting it's base & quote assets.
+///
+/// Used by triangular arbitrage strategies to enumerate every [`TriangularCycle`] of the traded
+/// instruments. Usually constructed from spot instruments of a single exchange.
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct InstrumentGraph {
+    edges: BTreeMap<Symbol, BTreeSet<Edge>>,
+}
+
+impl FromIterator<Keyed<InstrumentIndex, Instrument>> for InstrumentGraph {
+    fn from_iter<Iter>(instruments: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = Keyed<InstrumentIndex, Instrument>>,
+    {
+        let mut graph = Self::default();
+        for Keyed { key, value } in instruments {
+            graph.insert(key, &value);
+        }
+        graph
+    }
+}
+
+impl InstrumentGraph {
+    /// Construct a new [`InstrumentGraph`] from the provided [`Instrument`]s, where each
+    /// [`InstrumentIndex`] is the position of the [`Instrument`] in the iterator.
+    pub fn new<'a, Iter>(instruments: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = &'a Instrument>,
+    {
+        let mut graph = Self::default();
+        for (index, instrument) in instruments.into_iter().enumerate() {
+            graph.insert(InstrumentIndex::new(index), instrument);
+        }
+        graph
+    }
+
+    /// Insert an [`Instrument`] edge connecting it's base & quote assets.
+    pub fn insert(&mut self, index: InstrumentIndex, instrument: &Instrument) {
+        self.edges
+            .entry(instrument.quote.clone())
+            .or_default()
+            .insert(Edge {
+                to: instrument.base.clone(),
+                instrument: index,
+                side: LegSide::Buy,
+            });
+        self.edges
+            .entry(instrument.base.clone())
+            .or_default()
+            .insert(Edge {
+                to: instrument.quote.clone(),
+                instrument: index,
+                side: LegSide::Sell,
+            });
+    }
+
+    /// Every asset [`Symbol`] in the [`InstrumentGraph`].
+    pub fn assets(&self) -> impl Iterator<Item = &Symbol> {
+        self.edges.keys()
+    }
+
+    /// Every [`TriangularCycle`] starting & ending with the provided asset, in both directions.
+    pub fn cycles_from(&self, start: &Symbol) -> Vec<TriangularCycle> {
+        let Some(first_edges) = self.edges.get(start) else {
+            return Vec::new();
+        };
+
+        let mut cycles = Vec::new();
+        for first in first_edges.iter().filter(|edge| edge.to != *start) {
+            let Some(second_edges) = self.edges.get(&first.to) else {
+                continue;
+            };
+
+            for second in second_edges
+                .iter()
+                .filter(|edge| edge.to != *start && edge.to != first.to)
+            {
+                let Some(third_edges) = self.edges.get(&second.to) else {
+                    continue;
+                };
+
+                for third in third_edges.iter().filter(|edge| edge.to == *start) {
+                    cycles.push(TriangularCycle {
+                        legs: [
+                            leg(start, first),
+                            leg(&first.to, second),
+                            leg(&second.to, third),
+                        ],
+                    });
+                }
+            }
+        }
+
+        cycles
+    }
+
+    /// Every distinct [`TriangularCycle`] in the [`InstrumentGraph`], in both directions. Each
+    /// cycle starts with it's lexicographically smallest asset.
+    pub fn cycles(&self) -> Vec<TriangularCycle> {
+        self.assets()
+            .flat_map(|start| {
+                self.cycles_from(start)
+                    .into_iter()
+                    .filter(move |cycle| cycle.legs.iter().all(|leg| leg.from >= *start))
+            })
+            .collect()
+    }
+}
+
+fn leg(from: &Symbol, edge: &Edge) -> TriangularLeg {
+    TriangularLeg {
+        instrument: edge.instrument,
+        from: from.clone(),
+        to: edge.to.clone(),
+        side: edge.side,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instrument::kind::InstrumentKind;
+
+    fn leg(instrument: usize, from: &str, to: &str, side: LegSide) -> TriangularLeg {
+        TriangularLeg {
+            instrument: InstrumentIndex::new(instrument),
+            from: Symbol::new(from),
+            to: Symbol::new(to),
+            side,
+        }
+    }
+
+    #[test]
+    fn test_instrument_graph_cycles() {
+        struct TestCase {
+            start: Option<&'static str>,
+            expected: Vec<TriangularCycle>,
+        }
+
+        let instruments = [
+            Instrument::new("btc", "usdt", InstrumentKind::Spot),
+            Instrument::new("eth", "btc", InstrumentKind::Spot),
+            Instrument::new("eth", "usdt", InstrumentKind::Spot),
+            Instrument::new("sol", "usdt", InstrumentKind::Spot),
+        ];
+        let graph = InstrumentGraph::new(&instruments);
+
+        let tests = vec![
+            TestCase {
+                // TC0: cycles from usdt in both directions
+                start: Some("usdt"),
+                expected: vec![
+                    TriangularCycle {
+                        legs: [
+                            leg(0, "usdt", "btc", LegSide::Buy),
+                            leg(1, "btc", "eth", LegSide::Buy),
+                            leg(2, "eth", "usdt", LegSide::Sell),
+                        ],
+                    },
+                    TriangularCycle {
+                        legs: [
+                            leg(2, "usdt", "eth", LegSide::Buy),
+                            leg(1, "eth", "btc", LegSide::Sell),
+                            leg(0, "btc", "usdt", LegSide::Sell),
+                        ],
+                    },
+                ],
+            },
+            TestCase {
+                // TC1: sol is not part of any cycle
+                start: Some("sol"),
+                expected: vec![],
+            },
+            TestCase {
+                // TC2: every distinct cycle starts with it's smallest asset
+                start: None,
+                expected: vec![
+                    TriangularCycle {
+                        legs: [
+                            leg(0, "btc", "usdt", LegSide::Sell),
+                            leg(2, "usdt", "eth", LegSide::Buy),
+                            leg(1, "eth", "btc", LegSide::Sell),
+                        ],
+                    },
+                    TriangularCycle {
+                        legs: [
+                            leg(1, "btc", "eth", LegSide::Buy),
+                            leg(2, "eth", "usdt", LegSide::Sell),
+                            leg(0, "usdt", "btc", LegSide::Buy),
+                        ],
+                    },
+                ],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut actual = match test.start {
+                Some(start) => graph.cycles_from(&Symbol::new(start)),
+                None => graph.cycles(),
+            };
+            actual.sort();
+
+            let mut expected = test.expected;
+            expected.sort();
+
+            assert_eq!(actual, expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,3 @@
+/// [`InstrumentGraph`](graph::InstrumentGraph) of [`Instrument`](crate::instrument::Instrument)s
+/// connected by their base & quote [`Symbol`](crate::asset::symbol::Symbol)s.
+pub mod graph;
@@ -0,0 +1,240 @@
+use crate::instrument::spec::InstrumentSpec;
+use derive_more::Display;
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
+use serde::{Deserialize, Serialize};
+
+/// Fixed-point price represented as an integer number of [`TickScale`] ticks.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Default,
+    Deserialize,
+    Serialize,
+    Display,
+)]
+pub struct Ticks(pub i64);
+
+/// Fixed-point amount represented as an integer number of [`TickScale`] lots.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Default,
+    Deserialize,
+    Serialize,
+    Display,
+)]
+pub struct Lots(pub i64);
+
+impl Lots {
+    pub fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+/// Instrument tick size & lot size used to convert prices & amounts between [`Decimal`] and
+/// fixed-point integer [`Ticks`] & [`Lots`].
+///
+/// Comparing & summing `i64`s is far cheaper than the equivalent [`Decimal`] arithmetic, so
+/// hot paths (eg/ order book updates) can convert once on ingestion and stay in integers.
+///
+/// Prices & amounts that are not a multiple of the tick & lot size are rounded to the nearest
+/// multiple.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct TickScale {
+    tick: Decimal,
+    lot: Decimal,
+}
+
+impl TickScale {
+    /// Construct a new [`TickScale`] from the provided tick size & lot size, returning `None`
+    /// if either is not positive.
+    pub fn new(tick: Decimal, lot: Decimal) -> Option<Self> {
+        (tick.is_sign_positive() && !tick.is_zero() && lot.is_sign_positive() && !lot.is_zero())
+            .then(|| Self {
+                tick: tick.normalize(),
+                lot: lot.normalize(),
+            })
+    }
+
+    /// Construct a new [`TickScale`] from the tick size & quantity increment of an
+    /// [`InstrumentSpec`], returning `None` if either is not a positive finite number.
+    pub fn from_spec<AssetKey>(spec: &InstrumentSpec<AssetKey>) -> Option<Self> {
+        Self::new(
+            Decimal::from_f64(spec.price.tick_size)?,
+            Decimal::from_f64(spec.quantity.increment)?,
+        )
+    }
+
+    /// Tick size of this [`TickScale`].
+    pub fn tick(&self) -> Decimal {
+        self.tick
+    }
+
+    /// Lot size of this [`TickScale`].
+    pub fn lot(&self) -> Decimal {
+        self.lot
+    }
+
+    /// Convert a [`Decimal`] price into [`Ticks`], returning `None` if it overflows an `i64`.
+    pub fn ticks(&self, price: Decimal) -> Option<Ticks> {
+        to_units(price, self.tick).map(Ticks)
+    }
+
+    /// Convert [`Ticks`] into a [`Decimal`] price.
+    pub fn price(&self, ticks: Ticks) -> Decimal {
+        Decimal::from(ticks.0) * self.tick
+    }
+
+    /// Convert a [`Decimal`] amount into [`Lots`], returning `None` if it overflows an `i64`.
+    pub fn lots(&self, amount: Decimal) -> Option<Lots> {
+        to_units(amount, self.lot).map(Lots)
+    }
+
+    /// Convert [`Lots`] into a [`Decimal`] amount.
+    pub fn amount(&self, lots: Lots) -> Decimal {
+        Decimal::from(lots.0) * self.lot
+    }
+
+    /// Convert an `f64` price into [`Ticks`], returning `None` if it is not finite or overflows
+    /// an `i64`.
+    pub fn ticks_f64(&self, price: f64) -> Option<Ticks> {
+        self.ticks(Decimal::from_f64(price)?)
+    }
+
+    /// Convert [`Ticks`] into an `f64` price.
+    pub fn price_f64(&self, ticks: Ticks) -> f64 {
+        self.price(ticks).to_f64().unwrap_or(f64::NAN)
+    }
+
+    /// Convert an `f64` amount into [`Lots`], returning `None` if it is not finite or overflows
+    /// an `i64`.
+    pub fn lots_f64(&self, amount: f64) -> Option<Lots> {
+        self.lots(Decimal::from_f64(amount)?)
+    }
+
+    /// Convert [`Lots`] into an `f64` amount.
+    pub fn amount_f64(&self, lots: Lots) -> f64 {
+        self.amount(lots).to_f64().unwrap_or(f64::NAN)
+    }
+}
+
+/// Convert the value into the nearest integer number of `unit`s, returning `None` if it
+/// overflows an `i64`.
+///
+/// Units that are a power of ten (eg/ 0.01) are the common case, so they are converted by
+/// rescaling the value mantissa rather than via [`Decimal`] division.
+fn to_units(value: Decimal, unit: Decimal) -> Option<i64> {
+    if unit.mantissa() == 1 {
+        let mut value = value;
+        value.rescale(unit.scale());
+        return i64::try_from(value.mantissa()).ok();
+    }
+
+    value.checked_div(unit)?.round().to_i64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_tick_scale_conversions() {
+        struct TestCase {
+            scale: TickScale,
+            price: Decimal,
+            amount: Decimal,
+            expected_ticks: Option<Ticks>,
+            expected_lots: Option<Lots>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: power of ten tick & lot sizes
+                scale: TickScale::new(dec!(0.01), dec!(0.001)).unwrap(),
+                price: dec!(16578.50),
+                amount: dec!(1.234),
+                expected_ticks: Some(Ticks(1_657_850)),
+                expected_lots: Some(Lots(1_234)),
+            },
+            TestCase {
+                // TC1: power of ten tick size rounds values between ticks to the nearest tick
+                scale: TickScale::new(dec!(0.1), dec!(1)).unwrap(),
+                price: dec!(100.06),
+                amount: dec!(2.4),
+                expected_ticks: Some(Ticks(1_001)),
+                expected_lots: Some(Lots(2)),
+            },
+            TestCase {
+                // TC2: non power of ten tick & lot sizes
+                scale: TickScale::new(dec!(0.5), dec!(0.25)).unwrap(),
+                price: dec!(100.5),
+                amount: dec!(1.75),
+                expected_ticks: Some(Ticks(201)),
+                expected_lots: Some(Lots(7)),
+            },
+            TestCase {
+                // TC3: tick size with redundant trailing zeros is normalised
+                scale: TickScale::new(dec!(0.0100), dec!(1.0)).unwrap(),
+                price: dec!(1.23),
+                amount: dec!(5),
+                expected_ticks: Some(Ticks(123)),
+                expected_lots: Some(Lots(5)),
+            },
+            TestCase {
+                // TC4: price overflowing an i64 number of ticks
+                scale: TickScale::new(dec!(0.00000001), dec!(1)).unwrap(),
+                price: Decimal::MAX,
+                amount: dec!(0),
+                expected_ticks: None,
+                expected_lots: Some(Lots(0)),
+            },
+            TestCase {
+                // TC5: non power of ten tick & lot sizes overflowing the Decimal division
+                scale: TickScale::new(dec!(0.5), dec!(0.25)).unwrap(),
+                price: Decimal::MAX,
+                amount: Decimal::MIN,
+                expected_ticks: None,
+                expected_lots: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let ticks = test.scale.ticks(test.price);
+            let lots = test.scale.lots(test.amount);
+            assert_eq!(ticks, test.expected_ticks, "TC{index} failed");
+            assert_eq!(lots, test.expected_lots, "TC{index} failed");
+
+            if let (Some(ticks), Some(lots)) = (ticks, lots) {
+                assert_eq!(
+                    test.scale.ticks(test.scale.price(ticks)),
+                    Some(ticks),
+                    "TC{index} failed"
+                );
+                assert_eq!(
+                    test.scale.lots(test.scale.amount(lots)),
+                    Some(lots),
+                    "TC{index} failed"
+                );
+            }
+        }
+
+        assert!(TickScale::new(dec!(0), dec!(1)).is_none());
+        assert!(TickScale::new(dec!(0.01), dec!(-1)).is_none());
+    }
+}
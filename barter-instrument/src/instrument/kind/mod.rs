@@ -1,4 +1,5 @@
 use crate::instrument::kind::{future::FutureContract, option::OptionContract};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
@@ -9,7 +10,9 @@ pub mod spot;
 
 /// Defines the type of [`Instrument`](Instrument) which is being traded on a
 /// given `base_quote` market.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize, JsonSchema,
+)]
 #[serde(rename_all = "snake_case")]
 pub enum InstrumentKind {
     Spot,
@@ -18,6 +21,7 @@ pub enum InstrumentKind {
     Option(OptionContract),
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for InstrumentKind {
     fn default() -> Self {
         Self::Spot
@@ -1,20 +1,30 @@
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
+/// Black-Scholes pricing, [`Greeks`](greeks::Greeks) & implied volatility of
+/// [`OptionContract`]s.
+pub mod greeks;
+
 /// Configuration of an [`InstrumentKind::Option`] contract.
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize, Serialize)]
+#[derive(
+    Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize, Serialize, JsonSchema,
+)]
 pub struct OptionContract {
     pub kind: OptionKind,
     pub exercise: OptionExercise,
     #[serde(with = "chrono::serde::ts_milliseconds")]
+    #[schemars(with = "i64")]
     pub expiry: DateTime<Utc>,
     pub strike: Decimal,
 }
 
 /// [`OptionContract`] kind - Put or Call.
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize, Serialize)]
+#[derive(
+    Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize, Serialize, JsonSchema,
+)]
 #[serde(rename_all = "lowercase")]
 pub enum OptionKind {
     #[serde(alias = "CALL", alias = "Call")]
@@ -37,7 +47,9 @@ impl Display for OptionKind {
 }
 
 /// [`OptionContract`] exercise style.
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize, Serialize)]
+#[derive(
+    Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize, Serialize, JsonSchema,
+)]
 #[serde(rename_all = "lowercase")]
 pub enum OptionExercise {
     #[serde(alias = "AMERICAN", alias = "American")]
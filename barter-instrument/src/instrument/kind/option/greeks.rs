@@ -0,0 +1,313 @@
+use crate::instrument::kind::option::{OptionContract, OptionKind};
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+
+/// Seconds in the 365 day year used to annualise the time to expiry.
+const SECONDS_PER_YEAR: f64 = 365.0 * 24.0 * 60.0 * 60.0;
+
+/// Lower & upper volatility bounds searched by [`implied_volatility`].
+const VOLATILITY_BOUNDS: (f64, f64) = (1e-6, 10.0);
+
+/// Maximum iterations used by [`implied_volatility`].
+const MAX_ITERATIONS: usize = 100;
+
+/// Market inputs used to price an [`OptionContract`] with the Black-Scholes model.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct PricingInputs {
+    /// Current price of the underlying (eg/ the index or perpetual price).
+    pub underlying_price: f64,
+    /// Annualised continuously compounded risk-free rate (eg/ 0.05 for 5%).
+    pub risk_free_rate: f64,
+    /// Time the [`OptionContract`] is priced at, used to derive the time to expiry.
+    pub time: DateTime<Utc>,
+}
+
+/// Black-Scholes sensitivities of an [`OptionContract`] price.
+#[derive(Copy, Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct Greeks {
+    /// Change in option price per unit change in the underlying price.
+    pub delta: f64,
+    /// Change in delta per unit change in the underlying price.
+    pub gamma: f64,
+    /// Change in option price per 1.0 (ie/ 100 vol point) change in volatility.
+    pub vega: f64,
+    /// Change in option price per year of time decay. Divide by 365 for the daily theta.
+    pub theta: f64,
+}
+
+/// Time to expiry of the [`OptionContract`] in years at the provided time, floored at zero.
+pub fn time_to_expiry(contract: &OptionContract, time: DateTime<Utc>) -> f64 {
+    let seconds = (contract.expiry - time).num_milliseconds() as f64 / 1000.0;
+    (seconds / SECONDS_PER_YEAR).max(0.0)
+}
+
+/// Black-Scholes price of the [`OptionContract`] using the provided volatility.
+///
+/// Exercise style is ignored, so American options are priced as European.
+pub fn price(contract: &OptionContract, inputs: &PricingInputs, volatility: f64) -> f64 {
+    let Some(model) = Model::new(contract, inputs, volatility) else {
+        return intrinsic(contract, inputs);
+    };
+
+    let discount = model.discount();
+    match contract.kind {
+        OptionKind::Call => {
+            model.spot * norm_cdf(model.d1) - model.strike * discount * norm_cdf(model.d2)
+        }
+        OptionKind::Put => {
+            model.strike * discount * norm_cdf(-model.d2) - model.spot * norm_cdf(-model.d1)
+        }
+    }
+}
+
+/// Black-Scholes [`Greeks`] of the [`OptionContract`] using the provided volatility.
+///
+/// Expired options have a delta of 1.0 (call) or -1.0 (put) if in the money, and all other
+/// [`Greeks`] zero.
+pub fn greeks(contract: &OptionContract, inputs: &PricingInputs, volatility: f64) -> Greeks {
+    let Some(model) = Model::new(contract, inputs, volatility) else {
+        let in_the_money = intrinsic(contract, inputs) > 0.0;
+        let delta = match (contract.kind, in_the_money) {
+            (_, false) => 0.0,
+            (OptionKind::Call, true) => 1.0,
+            (OptionKind::Put, true) => -1.0,
+        };
+        return Greeks {
+            delta,
+            ..Greeks::default()
+        };
+    };
+
+    let discount = model.discount();
+    let sqrt_time = model.time.sqrt();
+    let pdf_d1 = norm_pdf(model.d1);
+
+    let gamma = pdf_d1 / (model.spot * model.volatility * sqrt_time);
+    let vega = model.spot * pdf_d1 * sqrt_time;
+    let decay = -model.spot * pdf_d1 * model.volatility / (2.0 * sqrt_time);
+
+    match contract.kind {
+        OptionKind::Call => Greeks {
+            delta: norm_cdf(model.d1),
+            gamma,
+            vega,
+            theta: decay - model.rate * model.strike * discount * norm_cdf(model.d2),
+        },
+        OptionKind::Put => Greeks {
+            delta: norm_cdf(model.d1) - 1.0,
+            gamma,
+            vega,
+            theta: decay + model.rate * model.strike * discount * norm_cdf(-model.d2),
+        },
+    }
+}
+
+/// Solve the Black-Scholes implied volatility of the [`OptionContract`] from it's market price,
+/// using Newton-Raphson safeguarded by bisection.
+///
+/// Returns `None` if the option has expired, or the price is outside the no-arbitrage bounds.
+pub fn implied_volatility(
+    contract: &OptionContract,
+    inputs: &PricingInputs,
+    option_price: f64,
+) -> Option<f64> {
+    const TOLERANCE: f64 = 1e-8;
+
+    let (mut low, mut high) = VOLATILITY_BOUNDS;
+    if time_to_expiry(contract, inputs.time) <= 0.0
+        || option_price < price(contract, inputs, low)
+        || option_price > price(contract, inputs, high)
+    {
+        return None;
+    }
+
+    let mut volatility = 0.5;
+    for _ in 0..MAX_ITERATIONS {
+        let error = price(contract, inputs, volatility) - option_price;
+        if error.abs() < TOLERANCE {
+            return Some(volatility);
+        }
+
+        // Option price is increasing in volatility, so narrow the bracket around the root
+        if error > 0.0 {
+            high = volatility;
+        } else {
+            low = volatility;
+        }
+
+        let vega = greeks(contract, inputs, volatility).vega;
+        let newton = volatility - error / vega;
+        volatility = if vega > f64::EPSILON && newton > low && newton < high {
+            newton
+        } else {
+            (low + high) / 2.0
+        };
+    }
+
+    Some(volatility)
+}
+
+/// Intrinsic value of the [`OptionContract`].
+fn intrinsic(contract: &OptionContract, inputs: &PricingInputs) -> f64 {
+    let strike = contract.strike.to_f64().unwrap_or_default();
+    match contract.kind {
+        OptionKind::Call => (inputs.underlying_price - strike).max(0.0),
+        OptionKind::Put => (strike - inputs.underlying_price).max(0.0),
+    }
+}
+
+/// Black-Scholes model terms shared by the price & [`Greeks`] calculations.
+struct Model {
+    spot: f64,
+    strike: f64,
+    rate: f64,
+    volatility: f64,
+    time: f64,
+    d1: f64,
+    d2: f64,
+}
+
+impl Model {
+    /// Returns `None` if the model is degenerate (eg/ the option has expired).
+    fn new(contract: &OptionContract, inputs: &PricingInputs, volatility: f64) -> Option<Self> {
+        let time = time_to_expiry(contract, inputs.time);
+        let strike = contract.strike.to_f64()?;
+        let spot = inputs.underlying_price;
+        if time <= 0.0 || volatility <= 0.0 || strike <= 0.0 || spot <= 0.0 {
+            return None;
+        }
+
+        let volatility_time = volatility * time.sqrt();
+        let d1 = ((spot / strike).ln() + (inputs.risk_free_rate + 0.5 * volatility.powi(2)) * time)
+            / volatility_time;
+
+        Some(Self {
+            spot,
+            strike,
+            rate: inputs.risk_free_rate,
+            volatility,
+            time,
+            d1,
+            d2: d1 - volatility_time,
+        })
+    }
+
+    fn discount(&self) -> f64 {
+        (-self.rate * self.time).exp()
+    }
+}
+
+/// Standard normal probability density function.
+fn norm_pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp() / (2.0 * std::f64::consts::PI).sqrt()
+}
+
+/// Standard normal cumulative distribution function.
+fn norm_cdf(x: f64) -> f64 {
+    0.5 * erfc(-x / std::f64::consts::SQRT_2)
+}
+
+/// Complementary error function with fractional error below 1.2e-7.
+///
+/// See docs: Numerical Recipes in C, 2nd edition, section 6.2.
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let polynomial = -z * z - 1.26551223
+        + t * (1.00002368
+            + t * (0.37409196
+                + t * (0.09678418
+                    + t * (-0.18628806
+                        + t * (0.27886807
+                            + t * (-1.13520398
+                                + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277))))))));
+    let result = t * polynomial.exp();
+    if x >= 0.0 {
+        result
+    } else {
+        2.0 - result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instrument::kind::option::OptionExercise;
+    use chrono::TimeDelta;
+    use rust_decimal_macros::dec;
+
+    fn assert_approx(actual: f64, expected: f64, tolerance: f64, index: usize) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "TC{index} failed: actual {actual} != expected {expected}"
+        );
+    }
+
+    #[test]
+    fn test_black_scholes_price_greeks_and_implied_volatility() {
+        struct TestCase {
+            kind: OptionKind,
+            volatility: f64,
+            expected_price: f64,
+            expected_greeks: Greeks,
+        }
+
+        // Hull, Options Futures & Other Derivatives: S=42, K=40, r=0.1, sigma=0.2, T=0.5
+        let time = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+        let inputs = PricingInputs {
+            underlying_price: 42.0,
+            risk_free_rate: 0.1,
+            time,
+        };
+        let contract = |kind| OptionContract {
+            kind,
+            exercise: OptionExercise::European,
+            expiry: time + TimeDelta::milliseconds((0.5 * SECONDS_PER_YEAR * 1000.0) as i64),
+            strike: dec!(40),
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: call
+                kind: OptionKind::Call,
+                volatility: 0.2,
+                expected_price: 4.7594,
+                expected_greeks: Greeks {
+                    delta: 0.7791,
+                    gamma: 0.0500,
+                    vega: 8.8134,
+                    theta: -4.5590,
+                },
+            },
+            TestCase {
+                // TC1: put
+                kind: OptionKind::Put,
+                volatility: 0.2,
+                expected_price: 0.8086,
+                expected_greeks: Greeks {
+                    delta: -0.2209,
+                    gamma: 0.0500,
+                    vega: 8.8134,
+                    theta: -0.7545,
+                },
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let contract = contract(test.kind);
+
+            let actual_price = price(&contract, &inputs, test.volatility);
+            assert_approx(actual_price, test.expected_price, 1e-3, index);
+
+            let actual = greeks(&contract, &inputs, test.volatility);
+            assert_approx(actual.delta, test.expected_greeks.delta, 1e-3, index);
+            assert_approx(actual.gamma, test.expected_greeks.gamma, 1e-3, index);
+            assert_approx(actual.vega, test.expected_greeks.vega, 1e-3, index);
+            assert_approx(actual.theta, test.expected_greeks.theta, 1e-3, index);
+
+            let actual_iv = implied_volatility(&contract, &inputs, actual_price).unwrap();
+            assert_approx(actual_iv, test.volatility, 1e-6, index);
+        }
+    }
+}
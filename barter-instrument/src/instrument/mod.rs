@@ -1,11 +1,16 @@
 use crate::{asset::symbol::Symbol, instrument::kind::InstrumentKind};
 use derive_more::Display;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::fmt::Formatter;
 
 pub mod kind;
 pub mod spec;
 
+/// Fixed-point [`Ticks`](fixed::Ticks) & [`Lots`](fixed::Lots) representation of prices & amounts,
+/// keyed to an instrument tick size & lot size.
+pub mod fixed;
+
 /// Unique identifier for an [`Instrument`] traded on an exchange.
 ///
 /// Used to key data events in a memory efficient way.
@@ -19,11 +24,25 @@ pub struct InstrumentId(pub u64);
 )]
 pub struct InstrumentIndex(usize);
 
+impl InstrumentIndex {
+    /// Construct a new [`InstrumentIndex`] from the provided position in an instrument collection.
+    pub fn new(index: usize) -> Self {
+        Self(index)
+    }
+
+    /// Position of the associated [`Instrument`] in it's instrument collection.
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
 /// Barter representation of an `Instrument`. Used to uniquely identify a `base_quote` pair, and it's
 /// associated instrument type.
 ///
 /// eg/ Instrument { base: "btc", quote: "usdt", kind: Spot }
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[derive(
+    Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize, JsonSchema,
+)]
 #[serde(rename_all = "snake_case")]
 pub struct Instrument {
     pub base: Symbol,
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+/// Exchange trading rules of an [`Instrument`](super::Instrument), used to round order prices &
+/// quantities correctly.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct InstrumentSpec<AssetKey> {
     pub price: InstrumentSpecPrice,
@@ -11,6 +13,90 @@ pub struct InstrumentSpec<AssetKey> {
 pub struct InstrumentSpecPrice {
     pub min: f64,
     pub tick_size: f64,
+    /// Price dependent tick sizes, if the exchange tick size varies with the price level
+    /// (eg/ KRW quoted markets), taking precedence over the fixed `tick_size`.
+    #[serde(default)]
+    pub tick_size_schedule: Option<TickSizeSchedule>,
+}
+
+impl InstrumentSpecPrice {
+    /// Tick size applicable to an order at the provided price.
+    pub fn tick_size_at(&self, price: f64) -> f64 {
+        self.tick_size_schedule
+            .as_ref()
+            .and_then(|schedule| schedule.tick_size(price))
+            .unwrap_or(self.tick_size)
+    }
+}
+
+/// Price dependent tick size schedule, used by exchanges whose minimum price increment depends
+/// on the price level of the instrument.
+///
+/// [`TickSizeBand`]s are sorted by ascending `price_min` on construction.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct TickSizeSchedule(Vec<TickSizeBand>);
+
+/// [`TickSizeSchedule`] band, defining the tick size of prices greater than or equal to
+/// `price_min` (up to the `price_min` of the next band).
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct TickSizeBand {
+    pub price_min: f64,
+    pub tick_size: f64,
+}
+
+impl TickSizeSchedule {
+    /// Construct a new [`TickSizeSchedule`] from the provided [`TickSizeBand`]s.
+    pub fn new<Bands>(bands: Bands) -> Self
+    where
+        Bands: IntoIterator<Item = TickSizeBand>,
+    {
+        let mut bands = bands.into_iter().collect::<Vec<_>>();
+        bands.sort_by(|a, b| a.price_min.total_cmp(&b.price_min));
+        Self(bands)
+    }
+
+    /// KRW quoted market [`TickSizeSchedule`] (eg/ Upbit & Bithumb "KRW-BTC" markets).
+    ///
+    /// See docs: <https://docs.upbit.com/docs/market-info-trade-price-detail>
+    pub fn krw() -> Self {
+        Self::new(
+            [
+                (0.0, 0.000_000_01),
+                (0.000_1, 0.000_000_1),
+                (0.001, 0.000_001),
+                (0.01, 0.000_01),
+                (0.1, 0.000_1),
+                (1.0, 0.001),
+                (10.0, 0.01),
+                (100.0, 0.1),
+                (1_000.0, 1.0),
+                (10_000.0, 10.0),
+                (100_000.0, 50.0),
+                (500_000.0, 100.0),
+                (1_000_000.0, 500.0),
+                (2_000_000.0, 1_000.0),
+            ]
+            .map(|(price_min, tick_size)| TickSizeBand {
+                price_min,
+                tick_size,
+            }),
+        )
+    }
+
+    /// [`TickSizeBand`]s of this schedule, sorted by ascending `price_min`.
+    pub fn bands(&self) -> &[TickSizeBand] {
+        &self.0
+    }
+
+    /// Tick size applicable at the provided price, or `None` if the price is below the
+    /// `price_min` of every [`TickSizeBand`].
+    pub fn tick_size(&self, price: f64) -> Option<f64> {
+        self.0
+            .iter()
+            .rev()
+            .find(|band| price >= band.price_min)
+            .map(|band| band.tick_size)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize, Serialize)]
@@ -18,6 +104,13 @@ pub struct InstrumentSpecQuantity<AssetKey> {
     pub unit: OrderQuantityUnits<AssetKey>,
     pub min: f64,
     pub increment: f64,
+    /// Maximum order quantity, if the exchange enforces one.
+    #[serde(default)]
+    pub max: Option<f64>,
+    /// Amount of the underlying asset each contract represents, if the quantity is denominated
+    /// in [`OrderQuantityUnits::Contract`]s.
+    #[serde(default)]
+    pub contract_multiplier: Option<f64>,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
@@ -30,4 +123,74 @@ pub enum OrderQuantityUnits<AssetKey> {
 #[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct InstrumentSpecNotional {
     pub min: f64,
+    /// Maximum order notional value, if the exchange enforces one.
+    #[serde(default)]
+    pub max: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tick_size_schedule_krw_tick_size() {
+        struct TestCase {
+            input: f64,
+            expected: Option<f64>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: BTC-like price in the highest band
+                input: 95_000_000.0,
+                expected: Some(1_000.0),
+            },
+            TestCase {
+                // TC1: price equal to band minimum
+                input: 1_000_000.0,
+                expected: Some(500.0),
+            },
+            TestCase {
+                // TC2: price just below band minimum
+                input: 999_999.0,
+                expected: Some(100.0),
+            },
+            TestCase {
+                // TC3: sub-KRW price
+                input: 0.5,
+                expected: Some(0.000_1),
+            },
+            TestCase {
+                // TC4: negative price is below every band
+                input: -1.0,
+                expected: None,
+            },
+        ];
+
+        let schedule = TickSizeSchedule::krw();
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                schedule.tick_size(test.input),
+                test.expected,
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_instrument_spec_price_tick_size_at() {
+        let fixed = InstrumentSpecPrice {
+            min: 1.0,
+            tick_size: 1.0,
+            tick_size_schedule: None,
+        };
+        assert_eq!(fixed.tick_size_at(5_000_000.0), 1.0);
+
+        let scheduled = InstrumentSpecPrice {
+            tick_size_schedule: Some(TickSizeSchedule::krw()),
+            ..fixed
+        };
+        assert_eq!(scheduled.tick_size_at(5_000_000.0), 1_000.0);
+        assert_eq!(scheduled.tick_size_at(5_000.0), 1.0);
+    }
 }
@@ -14,6 +14,12 @@ pub mod asset;
 /// eg/ `InstrumentKind`, `OptionContract``, etc.
 pub mod instrument;
 
+/// Utilities for working with collections of [`Instrument`](instrument::Instrument)s keyed by
+/// [`InstrumentIndex`](instrument::InstrumentIndex).
+///
+/// eg/ `InstrumentGraph` for enumerating triangular arbitrage cycles.
+pub mod index;
+
 pub mod market;
 
 #[derive(
@@ -0,0 +1,92 @@
+use barter_integration::{
+    error::SocketError,
+    protocol::{
+        binary::{
+            sbe::{SbeCursor, SbeError, SbeHeader, SbeMessage},
+            BinaryExchangeStream, WebSocketBinaryParser,
+        },
+        websocket::{WsError, WsMessage},
+    },
+    Transformer,
+};
+use futures::{stream, StreamExt};
+use std::collections::VecDeque;
+
+// Convenient type alias for a `BinaryExchangeStream` decoding SBE frames from a WebSocket
+type SbeWsStream<InnerStream, Exchange> =
+    BinaryExchangeStream<WebSocketBinaryParser, InnerStream, Exchange>;
+
+/// Venue specific SBE trade message (schema 1, template 10) with a fixed exponent price.
+#[derive(Debug)]
+struct SbeTrade {
+    id: u64,
+    price: f64,
+    quantity: f64,
+}
+
+impl SbeMessage for SbeTrade {
+    const SCHEMA_ID: u16 = 1;
+    const TEMPLATE_ID: u16 = 10;
+
+    fn decode_body(_: &SbeHeader, cursor: &mut SbeCursor<'_>) -> Result<Self, SbeError> {
+        Ok(Self {
+            id: cursor.u64()?,
+            price: cursor.decimal(-2)?,
+            quantity: cursor.f64()?,
+        })
+    }
+}
+
+struct VolumeTransformer {
+    sum_of_volume: f64,
+}
+
+impl Transformer for VolumeTransformer {
+    type Error = SocketError;
+    type Input = SbeTrade;
+    type Output = f64;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        println!(
+            "SbeTrade {} @ {}: {}",
+            input.id, input.price, input.quantity
+        );
+        self.sum_of_volume += input.quantity;
+        vec![Ok(self.sum_of_volume)]
+    }
+}
+
+/// Encode an [`SbeTrade`] frame, as a venue would publish it.
+fn encode_trade(id: u64, price_mantissa: i64, quantity: f64) -> WsMessage {
+    let header = [24u16, SbeTrade::TEMPLATE_ID, SbeTrade::SCHEMA_ID, 0];
+    let payload = header
+        .into_iter()
+        .flat_map(u16::to_le_bytes)
+        .chain(id.to_le_bytes())
+        .chain(price_mantissa.to_le_bytes())
+        .chain(quantity.to_le_bytes())
+        .collect();
+
+    WsMessage::Binary(payload)
+}
+
+#[tokio::main]
+async fn main() {
+    // In-memory WebSocket stream of binary frames - a real integration would use the
+    // `WsStream` of a connected `WebSocket`
+    let frames = stream::iter(vec![
+        Ok::<_, WsError>(encode_trade(1, 6_500_012, 0.25)),
+        Ok(encode_trade(2, 6_500_150, 1.5)),
+    ]);
+
+    let mut stream: SbeWsStream<_, _> = SbeWsStream::new(
+        frames,
+        VolumeTransformer { sum_of_volume: 0.0 },
+        VecDeque::new(),
+    );
+
+    while let Some(volume) = stream.next().await {
+        println!("Sum of volume: {:?}", volume);
+    }
+}
@@ -0,0 +1,183 @@
+use chrono::{DateTime, Utc};
+use reqwest::Method;
+use std::time::Duration;
+
+/// Components of an authenticated request used to build it's venue specific canonical string.
+#[derive(Debug, Clone)]
+pub struct CanonicalRequest<'a> {
+    pub method: &'a Method,
+    /// Request path, excluding the query (eg/ `/api/v5/trade/order`).
+    pub path: &'a str,
+    /// Url encoded query, excluding any auth parameters & the leading `?`.
+    pub query: Option<&'a str>,
+    /// Serialised request body.
+    pub body: Option<&'a str>,
+    pub api_key: &'a str,
+    /// [`ServerClock`](super::clock::ServerClock) corrected request timestamp.
+    pub timestamp: DateTime<Utc>,
+    pub recv_window: Duration,
+}
+
+/// Venue specific construction of the canonical string signed to authenticate a request.
+pub trait Canonical {
+    /// Format the request timestamp as expected by the venue.
+    fn timestamp(timestamp: DateTime<Utc>) -> String;
+
+    /// Build the canonical string to sign.
+    fn canonical(request: &CanonicalRequest<'_>) -> String;
+}
+
+/// Binance canonical string: the full query (with `recvWindow` & `timestamp` appended by
+/// [`Binance::signed_query`]) followed by the request body.
+///
+/// The signature is then appended to the query as `&signature=<signature>`.
+#[derive(Debug, Copy, Clone)]
+pub struct Binance;
+
+impl Binance {
+    /// Append the `recvWindow` & `timestamp` auth parameters to the provided query. This is
+    /// the query that must be sent with the request.
+    pub fn signed_query(request: &CanonicalRequest<'_>) -> String {
+        let auth = format!(
+            "recvWindow={}&timestamp={}",
+            request.recv_window.as_millis(),
+            Self::timestamp(request.timestamp)
+        );
+
+        match request.query {
+            Some(query) if !query.is_empty() => format!("{query}&{auth}"),
+            _ => auth,
+        }
+    }
+}
+
+impl Canonical for Binance {
+    fn timestamp(timestamp: DateTime<Utc>) -> String {
+        timestamp.timestamp_millis().to_string()
+    }
+
+    fn canonical(request: &CanonicalRequest<'_>) -> String {
+        format!(
+            "{}{}",
+            Self::signed_query(request),
+            request.body.unwrap_or_default()
+        )
+    }
+}
+
+/// Bybit V5 canonical string: `timestamp + api_key + recv_window + (query | body)`.
+///
+/// Sent via the `X-BAPI-TIMESTAMP`, `X-BAPI-API-KEY`, `X-BAPI-RECV-WINDOW` & `X-BAPI-SIGN`
+/// headers.
+#[derive(Debug, Copy, Clone)]
+pub struct Bybit;
+
+impl Canonical for Bybit {
+    fn timestamp(timestamp: DateTime<Utc>) -> String {
+        timestamp.timestamp_millis().to_string()
+    }
+
+    fn canonical(request: &CanonicalRequest<'_>) -> String {
+        let payload = match *request.method {
+            Method::GET | Method::DELETE => request.query,
+            _ => request.body,
+        };
+
+        format!(
+            "{}{}{}{}",
+            Self::timestamp(request.timestamp),
+            request.api_key,
+            request.recv_window.as_millis(),
+            payload.unwrap_or_default()
+        )
+    }
+}
+
+/// OKX V5 canonical string: `timestamp + METHOD + path[?query] + body`, with an ISO-8601
+/// millisecond timestamp.
+///
+/// Sent via the `OK-ACCESS-TIMESTAMP`, `OK-ACCESS-KEY`, `OK-ACCESS-SIGN` &
+/// `OK-ACCESS-PASSPHRASE` headers. OKX has no receive window in the canonical string (see the
+/// optional `expTime` header).
+#[derive(Debug, Copy, Clone)]
+pub struct Okx;
+
+impl Canonical for Okx {
+    fn timestamp(timestamp: DateTime<Utc>) -> String {
+        timestamp.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()
+    }
+
+    fn canonical(request: &CanonicalRequest<'_>) -> String {
+        let query = match request.query {
+            Some(query) if !query.is_empty() => format!("?{query}"),
+            _ => String::new(),
+        };
+
+        format!(
+            "{}{}{}{}{}",
+            Self::timestamp(request.timestamp),
+            request.method.as_str(),
+            request.path,
+            query,
+            request.body.unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical() {
+        struct TestCase {
+            method: Method,
+            query: Option<&'static str>,
+            body: Option<&'static str>,
+            expected: [&'static str; 3],
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: GET with query
+                method: Method::GET,
+                query: Some("symbol=BTCUSDT"),
+                body: None,
+                expected: [
+                    "symbol=BTCUSDT&recvWindow=5000&timestamp=1499827319559",
+                    "1499827319559key5000symbol=BTCUSDT",
+                    "2017-07-12T02:41:59.559ZGET/api/order?symbol=BTCUSDT",
+                ],
+            },
+            TestCase {
+                // TC1: POST with body
+                method: Method::POST,
+                query: None,
+                body: Some(r#"{"qty":"1"}"#),
+                expected: [
+                    r#"recvWindow=5000&timestamp=1499827319559{"qty":"1"}"#,
+                    r#"1499827319559key5000{"qty":"1"}"#,
+                    r#"2017-07-12T02:41:59.559ZPOST/api/order{"qty":"1"}"#,
+                ],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let request = CanonicalRequest {
+                method: &test.method,
+                path: "/api/order",
+                query: test.query,
+                body: test.body,
+                api_key: "key",
+                timestamp: DateTime::<Utc>::from_timestamp_millis(1499827319559).unwrap(),
+                recv_window: Duration::from_millis(5000),
+            };
+            let actual = [
+                Binance::canonical(&request),
+                Bybit::canonical(&request),
+                Okx::canonical(&request),
+            ];
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
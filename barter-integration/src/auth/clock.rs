@@ -0,0 +1,214 @@
+use crate::{
+    error::SocketError,
+    metric::{Field, Metric, Tag},
+};
+use chrono::{DateTime, TimeDelta, Utc};
+use std::{
+    future::Future,
+    sync::atomic::{AtomicI64, Ordering},
+    time::Duration,
+};
+
+/// Default maximum round trip of a server time request for the sample to be accepted.
+pub const DEFAULT_MAX_ROUND_TRIP: Duration = Duration::from_secs(1);
+
+/// Local clock corrected for it's skew against a venue's server time, used to generate the
+/// timestamps of authenticated requests.
+///
+/// Venues reject requests timestamped ahead of their server time (eg/ Binance allows 1s), or
+/// older than the request's receive window. The server time returned by the venue was generated
+/// somewhere between the local request & response times, so the offset is measured against the
+/// response time. Corrected timestamps are therefore never ahead of the venue, and lag it by at
+/// most the round trip of the sample, which is bounded by the configured maximum.
+///
+/// Shareable between the REST & WebSocket clients of a venue (eg/ via an `Arc`), and between
+/// market data & execution, so exchange timestamps can be compared with local receive times.
+#[derive(Debug)]
+pub struct ServerClock {
+    offset_ms: AtomicI64,
+    round_trip_ms: AtomicI64,
+    max_round_trip: Duration,
+}
+
+impl Default for ServerClock {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_ROUND_TRIP)
+    }
+}
+
+impl ServerClock {
+    /// Construct a new uncorrected [`ServerClock`] that accepts server time samples with a round
+    /// trip up to the provided maximum.
+    pub fn new(max_round_trip: Duration) -> Self {
+        Self {
+            offset_ms: AtomicI64::new(0),
+            round_trip_ms: AtomicI64::new(0),
+            max_round_trip,
+        }
+    }
+
+    /// Current offset of the venue server time from the local clock.
+    pub fn offset(&self) -> TimeDelta {
+        TimeDelta::milliseconds(self.offset_ms.load(Ordering::Relaxed))
+    }
+
+    /// Round trip of the server time sample the current offset was measured from.
+    pub fn round_trip(&self) -> TimeDelta {
+        TimeDelta::milliseconds(self.round_trip_ms.load(Ordering::Relaxed))
+    }
+
+    /// Current venue server time estimate, used to timestamp authenticated requests.
+    pub fn now(&self) -> DateTime<Utc> {
+        self.corrected(Utc::now())
+    }
+
+    /// Correct the provided local time by the current offset.
+    pub fn corrected(&self, local: DateTime<Utc>) -> DateTime<Utc> {
+        local + self.offset()
+    }
+
+    /// Convert the provided venue timestamp (eg/ of a fill) onto the local clock by the current
+    /// offset.
+    pub fn local(&self, server_time: DateTime<Utc>) -> DateTime<Utc> {
+        server_time - self.offset()
+    }
+
+    /// Latency between a venue event timestamp (eg/ `MarketEvent::time_exchange`) and the local
+    /// time it was received, corrected for the clock skew between the venue & local clocks.
+    pub fn latency(&self, time_exchange: DateTime<Utc>, time_received: DateTime<Utc>) -> TimeDelta {
+        self.corrected(time_received) - time_exchange
+    }
+
+    /// Generate a [`Metric`] of the current offset & sample round trip, tagged with the
+    /// provided venue.
+    pub fn metric<S>(&self, venue: S) -> Metric
+    where
+        S: Into<String>,
+    {
+        Metric {
+            name: "server_clock",
+            time: Utc::now().timestamp_millis() as u64,
+            tags: vec![Tag::new("venue", venue)],
+            fields: vec![
+                Field::new("offset_ms", self.offset().num_milliseconds()),
+                Field::new("round_trip_ms", self.round_trip().num_milliseconds()),
+            ],
+        }
+    }
+
+    /// Update the offset from a server time sample, returning the new offset.
+    ///
+    /// The sample is rejected if it's round trip exceeds the configured maximum, leaving the
+    /// offset unchanged.
+    pub fn observe(
+        &self,
+        sent: DateTime<Utc>,
+        server_time: DateTime<Utc>,
+        received: DateTime<Utc>,
+    ) -> Result<TimeDelta, SocketError> {
+        let round_trip_ms = (received - sent).num_milliseconds();
+        let max_ms = self.max_round_trip.as_millis() as i64;
+        if round_trip_ms > max_ms {
+            return Err(SocketError::ClockSync {
+                round_trip_ms,
+                max_ms,
+            });
+        }
+
+        let offset = server_time - received;
+        self.offset_ms
+            .store(offset.num_milliseconds(), Ordering::Relaxed);
+        self.round_trip_ms.store(round_trip_ms, Ordering::Relaxed);
+        Ok(offset)
+    }
+
+    /// Sync the [`ServerClock`] using the provided venue server time request
+    /// (eg/ Binance `GET /api/v3/time`), returning the new offset.
+    pub async fn sync<FnFetch, Fut, Error>(&self, fetch: FnFetch) -> Result<TimeDelta, Error>
+    where
+        FnFetch: FnOnce() -> Fut,
+        Fut: Future<Output = Result<DateTime<Utc>, Error>>,
+        Error: From<SocketError>,
+    {
+        let sent = Utc::now();
+        let server_time = fetch().await?;
+        self.observe(sent, server_time, Utc::now())
+            .map_err(Error::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_server_clock_observe() {
+        struct TestCase {
+            sent_ms: i64,
+            server_ms: i64,
+            received_ms: i64,
+            expected: Result<i64, ()>,
+        }
+
+        let clock = ServerClock::new(Duration::from_millis(500));
+        let base = DateTime::<Utc>::from_timestamp_millis(1_700_000_000_000).unwrap();
+
+        let tests = vec![
+            TestCase {
+                // TC0: local clock behind server, offset measured against response time
+                sent_ms: 0,
+                server_ms: 5_000,
+                received_ms: 100,
+                expected: Ok(4_900),
+            },
+            TestCase {
+                // TC1: local clock ahead of server
+                sent_ms: 0,
+                server_ms: -2_000,
+                received_ms: 50,
+                expected: Ok(-2_050),
+            },
+            TestCase {
+                // TC2: round trip too large, offset unchanged
+                sent_ms: 0,
+                server_ms: 10_000,
+                received_ms: 600,
+                expected: Err(()),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = clock.observe(
+                base + TimeDelta::milliseconds(test.sent_ms),
+                base + TimeDelta::milliseconds(test.server_ms),
+                base + TimeDelta::milliseconds(test.received_ms),
+            );
+            match test.expected {
+                Ok(expected) => {
+                    assert_eq!(
+                        actual.unwrap(),
+                        TimeDelta::milliseconds(expected),
+                        "TC{index} failed"
+                    );
+                }
+                Err(()) => {
+                    assert!(
+                        matches!(actual, Err(SocketError::ClockSync { .. })),
+                        "TC{index} failed"
+                    );
+                }
+            }
+        }
+
+        assert_eq!(clock.offset(), TimeDelta::milliseconds(-2_050));
+        assert_eq!(clock.round_trip(), TimeDelta::milliseconds(50));
+        assert_eq!(clock.corrected(base), base - TimeDelta::milliseconds(2_050));
+        assert_eq!(clock.local(clock.corrected(base)), base);
+
+        // Event timestamped by the venue 100ms before it was received, measured by the local clock
+        assert_eq!(
+            clock.latency(base - TimeDelta::milliseconds(2_150), base),
+            TimeDelta::milliseconds(100)
+        );
+    }
+}
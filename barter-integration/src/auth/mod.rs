@@ -0,0 +1,157 @@
+use self::{
+    canonical::{Canonical, CanonicalRequest},
+    clock::ServerClock,
+    key::SigningKey,
+};
+use crate::{error::SocketError, protocol::http::private::encoder::Encoder};
+use reqwest::Method;
+use std::{marker::PhantomData, sync::Arc, time::Duration};
+
+/// HMAC-SHA256, Ed25519 & RSA [`SigningKey`] implementations.
+pub mod key;
+
+/// [`ServerClock`] correcting request timestamps for local clock skew against a venue's server
+/// time.
+pub mod clock;
+
+/// Per-venue [`Canonical`] string builders (eg/ [`Binance`](canonical::Binance),
+/// [`Bybit`](canonical::Bybit), [`Okx`](canonical::Okx)).
+pub mod canonical;
+
+/// Default receive window within which a venue accepts a timestamped request.
+pub const DEFAULT_RECV_WINDOW: Duration = Duration::from_secs(5);
+
+/// Authentication produced by an [`Authenticator`] for one request, to be attached as the venue
+/// expects (eg/ headers or query parameters).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Authentication {
+    /// Venue formatted request timestamp.
+    pub timestamp: String,
+    pub recv_window: Duration,
+    /// Encoded signature of the canonical string.
+    pub signature: String,
+}
+
+/// Signs REST & WebSocket requests for a venue, combining a [`SigningKey`], the venue
+/// [`Canonical`] string, a signature [`Encoder`], and a shared [`ServerClock`].
+#[derive(Debug)]
+pub struct Authenticator<Key, Venue, SigEncoder> {
+    pub api_key: String,
+    key: Key,
+    encoder: SigEncoder,
+    clock: Arc<ServerClock>,
+    recv_window: Duration,
+    phantom: PhantomData<Venue>,
+}
+
+impl<Key, Venue, SigEncoder> Authenticator<Key, Venue, SigEncoder>
+where
+    Key: SigningKey,
+    Venue: Canonical,
+    SigEncoder: Encoder,
+{
+    /// Construct a new [`Authenticator`] using the [`DEFAULT_RECV_WINDOW`].
+    pub fn new(
+        api_key: impl Into<String>,
+        key: Key,
+        encoder: SigEncoder,
+        clock: Arc<ServerClock>,
+    ) -> Self {
+        Self {
+            api_key: api_key.into(),
+            key,
+            encoder,
+            clock,
+            recv_window: DEFAULT_RECV_WINDOW,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Use the provided receive window rather than the [`DEFAULT_RECV_WINDOW`].
+    pub fn recv_window(self, recv_window: Duration) -> Self {
+        Self {
+            recv_window,
+            ..self
+        }
+    }
+
+    /// Shared [`ServerClock`] used to timestamp requests.
+    pub fn clock(&self) -> &Arc<ServerClock> {
+        &self.clock
+    }
+
+    /// Authenticate a request, timestamped by the [`ServerClock`].
+    pub fn authenticate(
+        &self,
+        method: &Method,
+        path: &str,
+        query: Option<&str>,
+        body: Option<&str>,
+    ) -> Result<Authentication, SocketError> {
+        self.authenticate_request(&CanonicalRequest {
+            method,
+            path,
+            query,
+            body,
+            api_key: &self.api_key,
+            timestamp: self.clock.now(),
+            recv_window: self.recv_window,
+        })
+    }
+
+    /// Authenticate the provided [`CanonicalRequest`] as is.
+    pub fn authenticate_request(
+        &self,
+        request: &CanonicalRequest<'_>,
+    ) -> Result<Authentication, SocketError> {
+        let signature = self.key.sign(Venue::canonical(request).as_bytes())?;
+
+        Ok(Authentication {
+            timestamp: Venue::timestamp(request.timestamp),
+            recv_window: request.recv_window,
+            signature: self.encoder.encode(signature),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{auth::canonical::Binance, protocol::http::private::encoder::HexEncoder};
+    use chrono::{DateTime, Utc};
+    use key::HmacSha256Key;
+
+    #[test]
+    fn test_authenticator_authenticate_request() {
+        let authenticator = Authenticator::<_, Binance, _>::new(
+            "key",
+            HmacSha256Key::new("NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j"),
+            HexEncoder,
+            Arc::new(ServerClock::default()),
+        );
+
+        let actual = authenticator
+            .authenticate_request(&CanonicalRequest {
+                method: &Method::POST,
+                path: "/api/v3/order",
+                query: Some(
+                    "symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1&price=0.1",
+                ),
+                body: None,
+                api_key: &authenticator.api_key,
+                timestamp: DateTime::<Utc>::from_timestamp_millis(1499827319559).unwrap(),
+                recv_window: Duration::from_millis(5000),
+            })
+            .unwrap();
+
+        assert_eq!(
+            actual,
+            Authentication {
+                timestamp: "1499827319559".to_string(),
+                recv_window: Duration::from_millis(5000),
+                signature: "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71"
+                    .to_string(),
+            }
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use crate::error::SocketError;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Venue environment connected to, allowing integration tests & staging deployments to run
+/// against venue testnets (eg/ Binance testnet, Bybit testnet, OKX demo trading) without code
+/// edits.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Environment {
+    /// Production venue.
+    #[default]
+    Mainnet,
+    /// Venue testnet, sandbox, or demo trading environment.
+    Testnet,
+}
+
+impl Display for Environment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Environment::Mainnet => "mainnet",
+            Environment::Testnet => "testnet",
+        })
+    }
+}
+
+/// Base url of a venue API in each [`Environment`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct EnvironmentUrl {
+    pub mainnet: &'static str,
+    /// `None` if the venue has no [`Environment::Testnet`] for this API.
+    pub testnet: Option<&'static str>,
+}
+
+impl EnvironmentUrl {
+    /// Construct a new [`EnvironmentUrl`] for an API available in every [`Environment`].
+    pub const fn new(mainnet: &'static str, testnet: &'static str) -> Self {
+        Self {
+            mainnet,
+            testnet: Some(testnet),
+        }
+    }
+
+    /// Construct a new [`EnvironmentUrl`] for an API only available on
+    /// [`Environment::Mainnet`].
+    pub const fn mainnet(mainnet: &'static str) -> Self {
+        Self {
+            mainnet,
+            testnet: None,
+        }
+    }
+
+    /// Base url of the API in the provided [`Environment`].
+    pub fn get(&self, environment: Environment) -> Result<&'static str, SocketError> {
+        match environment {
+            Environment::Mainnet => Ok(self.mainnet),
+            Environment::Testnet => self.testnet.ok_or_else(|| SocketError::Unsupported {
+                entity: self.mainnet.to_string(),
+                item: environment.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_environment_url_get() {
+        struct TestCase {
+            url: EnvironmentUrl,
+            environment: Environment,
+            expected: Option<&'static str>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: mainnet
+                url: EnvironmentUrl::new("wss://mainnet", "wss://testnet"),
+                environment: Environment::Mainnet,
+                expected: Some("wss://mainnet"),
+            },
+            TestCase {
+                // TC1: testnet
+                url: EnvironmentUrl::new("wss://mainnet", "wss://testnet"),
+                environment: Environment::Testnet,
+                expected: Some("wss://testnet"),
+            },
+            TestCase {
+                // TC2: testnet unsupported
+                url: EnvironmentUrl::mainnet("wss://mainnet"),
+                environment: Environment::Testnet,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.url.get(test.environment);
+            assert_eq!(actual.ok(), test.expected, "TC{index} failed");
+        }
+
+        assert_eq!(
+            serde_json::from_str::<Environment>(r#""testnet""#).unwrap(),
+            Environment::Testnet
+        );
+    }
+}
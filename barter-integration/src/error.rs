@@ -20,6 +20,9 @@ pub enum SocketError {
         payload: Vec<u8>,
     },
 
+    #[error("Decoding binary error: {error} for payload: {payload:?}")]
+    DecodeBinary { error: String, payload: Vec<u8> },
+
     #[error("Serialising JSON error: {0}")]
     Serialise(serde_json::Error),
 
@@ -59,6 +62,15 @@ pub enum SocketError {
 
     #[error("consumed error message from exchange: {0}")]
     Exchange(String),
+
+    #[error("proxy error: {0}")]
+    Proxy(String),
+
+    #[error("request signing error: {0}")]
+    Sign(String),
+
+    #[error("server time sync rejected: round trip {round_trip_ms}ms exceeds {max_ms}ms")]
+    ClockSync { round_trip_ms: i64, max_ms: i64 },
 }
 
 impl From<reqwest::Error> for SocketError {
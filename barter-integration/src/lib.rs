@@ -17,11 +17,15 @@
     missing_copy_implementations,
     rust_2018_idioms
 )]
+// SocketError::WebSocket carries the tungstenite::Error by value as part of the public API that
+// downstream crates match on, so it is not boxed to satisfy newer toolchains.
+#![allow(clippy::result_large_err)]
 
 use crate::{error::SocketError, protocol::StreamParser};
 use futures::Stream;
 use pin_project::pin_project;
-use serde::{Deserialize, Serialize};
+use schemars::JsonSchema;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::{
     collections::VecDeque,
     fmt::{Debug, Display, Formatter},
@@ -37,9 +41,22 @@ pub mod error;
 /// messages into a generic output data structure.
 pub mod protocol;
 
+/// Reusable request authentication for exchange REST & WebSocket APIs: HMAC-SHA256, Ed25519 &
+/// RSA [`SigningKey`](auth::key::SigningKey)s, per-venue canonical strings, and a replay
+/// protection safe [`ServerClock`](auth::clock::ServerClock).
+pub mod auth;
+
+/// Venue [`Environment`](environment::Environment) (eg/ mainnet vs testnet) & the base url of
+/// a venue API in each environment.
+pub mod environment;
+
 /// Contains the flexible `Metric` type used for representing real-time metrics generically.
 pub mod metric;
 
+/// `Stream` utilities, such as a [`TimeOrderedMerge`](stream::merge::TimeOrderedMerge) of
+/// several exchange streams with bounded skew.
+pub mod stream;
+
 /// Utilities to assist deserialisation.
 pub mod de;
 
@@ -61,7 +78,7 @@ pub trait Validator {
 /// `Result<Self::Output, Self::Error>`s.
 pub trait Transformer {
     type Error;
-    type Input: for<'de> Deserialize<'de>;
+    type Input;
     type Output;
     type OutputIter: IntoIterator<Item = Result<Self::Output, Self::Error>>;
     fn transform(&mut self, input: Self::Input) -> Self::OutputIter;
@@ -90,6 +107,7 @@ where
     Protocol: StreamParser,
     InnerStream: Stream<Item = Result<Protocol::Message, Protocol::Error>> + Unpin,
     StreamTransformer: Transformer,
+    StreamTransformer::Input: DeserializeOwned,
     StreamTransformer::Error: From<SocketError>,
 {
     type Item = Result<StreamTransformer::Output, StreamTransformer::Error>;
@@ -156,7 +174,9 @@ where
 }
 
 /// [`Side`] of a trade or position - Buy or Sell.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize, JsonSchema,
+)]
 pub enum Side {
     #[serde(alias = "buy", alias = "BUY", alias = "b")]
     Buy,
@@ -0,0 +1,169 @@
+use crate::{
+    error::SocketError,
+    protocol::websocket::{
+        process_close_frame, process_frame, process_ping, process_pong, WebSocket, WsError,
+        WsMessage,
+    },
+    Transformer,
+};
+use futures::Stream;
+use pin_project::pin_project;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::VecDeque,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tracing::debug;
+
+/// Simple Binary Encoding (SBE) message header, cursor & [`SbeMessage`](sbe::SbeMessage) trait
+/// for decoding typed SBE messages.
+pub mod sbe;
+
+/// Types that can be decoded from a binary payload (eg/ Simple Binary Encoding, Protobuf, or a
+/// venue specific binary format).
+pub trait BinaryDecode: Sized {
+    fn decode(payload: &[u8]) -> Result<Self, SocketError>;
+}
+
+/// `BinaryStreamParser`s are capable of parsing the binary input messages from a given stream
+/// protocol, and decoding them into a [`BinaryDecode`] `Output`, without text JSON parsing.
+pub trait BinaryStreamParser {
+    type Stream: Stream;
+    type Message;
+    type Error;
+
+    fn parse<Output>(
+        input: Result<Self::Message, Self::Error>,
+    ) -> Option<Result<Output, SocketError>>
+    where
+        Output: BinaryDecode;
+}
+
+/// [`BinaryStreamParser`] implementation for a [`WebSocket`] publishing binary frames.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct WebSocketBinaryParser;
+
+impl BinaryStreamParser for WebSocketBinaryParser {
+    type Stream = WebSocket;
+    type Message = WsMessage;
+    type Error = WsError;
+
+    fn parse<Output>(
+        input: Result<Self::Message, Self::Error>,
+    ) -> Option<Result<Output, SocketError>>
+    where
+        Output: BinaryDecode,
+    {
+        match input {
+            Ok(ws_message) => match ws_message {
+                WsMessage::Binary(binary) => process_binary_decode(binary),
+                WsMessage::Text(text) => {
+                    debug!(payload = %text, "received unexpected Text WebSocket message");
+                    None
+                }
+                WsMessage::Ping(ping) => process_ping(ping),
+                WsMessage::Pong(pong) => process_pong(pong),
+                WsMessage::Close(close_frame) => process_close_frame(close_frame),
+                WsMessage::Frame(frame) => process_frame(frame),
+            },
+            Err(ws_err) => Some(Err(SocketError::WebSocket(ws_err))),
+        }
+    }
+}
+
+/// Process a payload of `Vec<u8>` bytes by decoding it into a [`BinaryDecode`] `ExchangeMessage`.
+pub fn process_binary_decode<ExchangeMessage>(
+    payload: Vec<u8>,
+) -> Option<Result<ExchangeMessage, SocketError>>
+where
+    ExchangeMessage: BinaryDecode,
+{
+    Some(ExchangeMessage::decode(&payload).inspect_err(|error| {
+        debug!(
+            ?error,
+            ?payload,
+            action = "returning Some(Err(err))",
+            "failed to decode binary Message into domain specific Message"
+        );
+    }))
+}
+
+/// Binary equivalent of an [`ExchangeStream`](crate::ExchangeStream). It polls protocol
+/// messages from the inner [`Stream`], decodes them using the [`BinaryStreamParser`], and
+/// transforms them into the desired output data structure.
+#[derive(Debug)]
+#[pin_project]
+pub struct BinaryExchangeStream<Protocol, InnerStream, StreamTransformer>
+where
+    Protocol: BinaryStreamParser,
+    InnerStream: Stream,
+    StreamTransformer: Transformer,
+{
+    #[pin]
+    pub stream: InnerStream,
+    pub transformer: StreamTransformer,
+    pub buffer: VecDeque<Result<StreamTransformer::Output, StreamTransformer::Error>>,
+    pub protocol_marker: PhantomData<Protocol>,
+}
+
+impl<Protocol, InnerStream, StreamTransformer> Stream
+    for BinaryExchangeStream<Protocol, InnerStream, StreamTransformer>
+where
+    Protocol: BinaryStreamParser,
+    InnerStream: Stream<Item = Result<Protocol::Message, Protocol::Error>> + Unpin,
+    StreamTransformer: Transformer,
+    StreamTransformer::Input: BinaryDecode,
+    StreamTransformer::Error: From<SocketError>,
+{
+    type Item = Result<StreamTransformer::Output, StreamTransformer::Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            // Flush Self::Item buffer if it is not currently empty
+            if let Some(output) = self.buffer.pop_front() {
+                return Poll::Ready(Some(output));
+            }
+
+            // Poll inner `Stream` for next the next input protocol message
+            let input = match self.as_mut().project().stream.poll_next(cx) {
+                Poll::Ready(Some(input)) => input,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            // Decode input protocol message into `ExchangeMessage`
+            let exchange_message = match Protocol::parse::<StreamTransformer::Input>(input) {
+                Some(Ok(exchange_message)) => exchange_message,
+                Some(Err(err)) => return Poll::Ready(Some(Err(err.into()))),
+                None => continue,
+            };
+
+            // Transform `ExchangeMessage` into `Transformer::OutputIter`
+            let outputs = self.transformer.transform(exchange_message);
+            self.buffer.extend(outputs);
+        }
+    }
+}
+
+impl<Protocol, InnerStream, StreamTransformer>
+    BinaryExchangeStream<Protocol, InnerStream, StreamTransformer>
+where
+    Protocol: BinaryStreamParser,
+    InnerStream: Stream,
+    StreamTransformer: Transformer,
+{
+    pub fn new(
+        stream: InnerStream,
+        transformer: StreamTransformer,
+        buffer: VecDeque<Result<StreamTransformer::Output, StreamTransformer::Error>>,
+    ) -> Self {
+        Self {
+            stream,
+            transformer,
+            buffer,
+            protocol_marker: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use crate::{error::SocketError, protocol::binary::BinaryDecode};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Errors generated when decoding a Simple Binary Encoding (SBE) message.
+#[derive(Error, Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub enum SbeError {
+    #[error("buffer truncated: required {required} bytes at offset {offset}, but {len} available")]
+    Truncated {
+        offset: usize,
+        required: usize,
+        len: usize,
+    },
+
+    #[error("unexpected SBE schema id: expected {expected}, actual {actual}")]
+    UnexpectedSchema { expected: u16, actual: u16 },
+
+    #[error("unexpected SBE template id: expected {expected}, actual {actual}")]
+    UnexpectedTemplate { expected: u16, actual: u16 },
+}
+
+/// Standard SBE message header preceding every message root block.
+///
+/// See docs: <https://github.com/real-logic/simple-binary-encoding/wiki/Message-Headers>
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct SbeHeader {
+    /// Length of the message root block (excluding repeating groups & variable data).
+    pub block_length: u16,
+    pub template_id: u16,
+    pub schema_id: u16,
+    pub version: u16,
+}
+
+impl SbeHeader {
+    /// Encoded length of an [`SbeHeader`] in bytes.
+    pub const LEN: usize = 8;
+
+    /// Decode an [`SbeHeader`] from the [`SbeCursor`].
+    pub fn decode(cursor: &mut SbeCursor<'_>) -> Result<Self, SbeError> {
+        Ok(Self {
+            block_length: cursor.u16()?,
+            template_id: cursor.u16()?,
+            schema_id: cursor.u16()?,
+            version: cursor.u16()?,
+        })
+    }
+}
+
+/// Cursor for reading little-endian SBE primitives from a byte buffer.
+#[derive(Copy, Clone, Debug)]
+pub struct SbeCursor<'a> {
+    buffer: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> SbeCursor<'a> {
+    /// Construct a new [`SbeCursor`] at the start of the provided buffer.
+    pub fn new(buffer: &'a [u8]) -> Self {
+        Self { buffer, offset: 0 }
+    }
+
+    /// Current offset of the [`SbeCursor`] in the buffer.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Read the next `len` bytes, advancing the [`SbeCursor`].
+    pub fn bytes(&mut self, len: usize) -> Result<&'a [u8], SbeError> {
+        let bytes = self
+            .buffer
+            .get(self.offset..self.offset + len)
+            .ok_or(SbeError::Truncated {
+                offset: self.offset,
+                required: len,
+                len: self.buffer.len().saturating_sub(self.offset),
+            })?;
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    /// Advance the [`SbeCursor`] by `len` bytes (eg/ to skip fields unknown to this version).
+    pub fn skip(&mut self, len: usize) -> Result<(), SbeError> {
+        self.bytes(len).map(|_| ())
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], SbeError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.bytes(N)?);
+        Ok(array)
+    }
+
+    pub fn u8(&mut self) -> Result<u8, SbeError> {
+        self.array().map(u8::from_le_bytes)
+    }
+
+    pub fn u16(&mut self) -> Result<u16, SbeError> {
+        self.array().map(u16::from_le_bytes)
+    }
+
+    pub fn u32(&mut self) -> Result<u32, SbeError> {
+        self.array().map(u32::from_le_bytes)
+    }
+
+    pub fn u64(&mut self) -> Result<u64, SbeError> {
+        self.array().map(u64::from_le_bytes)
+    }
+
+    pub fn i64(&mut self) -> Result<i64, SbeError> {
+        self.array().map(i64::from_le_bytes)
+    }
+
+    pub fn f64(&mut self) -> Result<f64, SbeError> {
+        self.array().map(f64::from_le_bytes)
+    }
+
+    /// Read an SBE decimal with a fixed exponent (eg/ a mantissa of `12345` with an exponent of
+    /// `-2` is `123.45`).
+    pub fn decimal(&mut self, exponent: i32) -> Result<f64, SbeError> {
+        self.i64()
+            .map(|mantissa| mantissa as f64 * 10f64.powi(exponent))
+    }
+}
+
+/// Typed SBE message identified by it's schema & template id. Any type implementing
+/// [`SbeMessage`] is [`BinaryDecode`], so it can be used as the `Input` of a
+/// [`Transformer`](crate::Transformer) of a
+/// [`BinaryExchangeStream`](super::BinaryExchangeStream).
+pub trait SbeMessage: Sized {
+    const SCHEMA_ID: u16;
+    const TEMPLATE_ID: u16;
+
+    /// Decode the message root block (& any repeating groups) following the [`SbeHeader`].
+    fn decode_body(header: &SbeHeader, cursor: &mut SbeCursor<'_>) -> Result<Self, SbeError>;
+}
+
+/// Decode an [`SbeMessage`] from a payload starting with it's [`SbeHeader`].
+pub fn decode<Message>(payload: &[u8]) -> Result<Message, SbeError>
+where
+    Message: SbeMessage,
+{
+    let mut cursor = SbeCursor::new(payload);
+    let header = SbeHeader::decode(&mut cursor)?;
+
+    if header.schema_id != Message::SCHEMA_ID {
+        return Err(SbeError::UnexpectedSchema {
+            expected: Message::SCHEMA_ID,
+            actual: header.schema_id,
+        });
+    }
+
+    if header.template_id != Message::TEMPLATE_ID {
+        return Err(SbeError::UnexpectedTemplate {
+            expected: Message::TEMPLATE_ID,
+            actual: header.template_id,
+        });
+    }
+
+    Message::decode_body(&header, &mut cursor)
+}
+
+impl<Message> BinaryDecode for Message
+where
+    Message: SbeMessage,
+{
+    fn decode(payload: &[u8]) -> Result<Self, SocketError> {
+        decode(payload).map_err(|error| SocketError::DecodeBinary {
+            error: error.to_string(),
+            payload: payload.to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Trade {
+        id: u64,
+        price: f64,
+        quantity: f64,
+    }
+
+    impl SbeMessage for Trade {
+        const SCHEMA_ID: u16 = 1;
+        const TEMPLATE_ID: u16 = 2;
+
+        fn decode_body(header: &SbeHeader, cursor: &mut SbeCursor<'_>) -> Result<Self, SbeError> {
+            let start = cursor.offset();
+            let trade = Trade {
+                id: cursor.u64()?,
+                price: cursor.decimal(-2)?,
+                quantity: cursor.f64()?,
+            };
+            cursor.skip(usize::from(header.block_length) - (cursor.offset() - start))?;
+            Ok(trade)
+        }
+    }
+
+    fn encode(template_id: u16, block_length: u16, body: &[u8]) -> Vec<u8> {
+        [block_length, template_id, 1, 0]
+            .into_iter()
+            .flat_map(u16::to_le_bytes)
+            .chain(body.iter().copied())
+            .collect()
+    }
+
+    #[test]
+    fn test_decode_sbe_message() {
+        struct TestCase {
+            input: Vec<u8>,
+            expected: Result<Trade, SbeError>,
+        }
+
+        let body = [
+            7u64.to_le_bytes(),
+            12345i64.to_le_bytes(),
+            0.5f64.to_le_bytes(),
+        ]
+        .concat();
+
+        let tests = vec![
+            TestCase {
+                // TC0: valid message
+                input: encode(2, 24, &body),
+                expected: Ok(Trade {
+                    id: 7,
+                    price: 123.45,
+                    quantity: 0.5,
+                }),
+            },
+            TestCase {
+                // TC1: valid message from a newer schema version with an extra field is skipped
+                input: encode(2, 32, &[body.as_slice(), &[0; 8]].concat()),
+                expected: Ok(Trade {
+                    id: 7,
+                    price: 123.45,
+                    quantity: 0.5,
+                }),
+            },
+            TestCase {
+                // TC2: unexpected template id
+                input: encode(3, 24, &body),
+                expected: Err(SbeError::UnexpectedTemplate {
+                    expected: 2,
+                    actual: 3,
+                }),
+            },
+            TestCase {
+                // TC3: truncated body
+                input: encode(2, 24, &body[..12]),
+                expected: Err(SbeError::Truncated {
+                    offset: 16,
+                    required: 8,
+                    len: 4,
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = decode::<Trade>(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual.id, expected.id, "TC{} failed", index);
+                    assert!(
+                        (actual.price - expected.price).abs() < 1e-9,
+                        "TC{} failed",
+                        index
+                    );
+                    assert_eq!(actual.quantity, expected.quantity, "TC{} failed", index);
+                }
+                (Err(actual), Err(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (actual, expected) => {
+                    panic!("TC{index} failed: actual {actual:?} != expected {expected:?}")
+                }
+            }
+        }
+    }
+}
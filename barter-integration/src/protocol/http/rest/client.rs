@@ -1,7 +1,10 @@
 use crate::{
     error::SocketError,
     metric::{Field, Metric, Tag},
-    protocol::http::{rest::RestRequest, BuildStrategy, HttpParser},
+    protocol::{
+        http::{rest::RestRequest, BuildStrategy, HttpParser},
+        proxy::ProxyConfig,
+    },
 };
 use bytes::Bytes;
 use chrono::Utc;
@@ -138,4 +141,12 @@ impl<'a, Strategy, Parser> RestClient<'a, Strategy, Parser> {
             parser,
         }
     }
+
+    /// Route all requests through the provided [`ProxyConfig`].
+    pub fn proxy(self, proxy: &ProxyConfig) -> Result<Self, SocketError> {
+        Ok(Self {
+            http_client: proxy.http_client()?,
+            ..self
+        })
+    }
 }
@@ -10,6 +10,15 @@ pub mod websocket;
 /// exchange oriented HTTP request.
 pub mod http;
 
+/// [`ProxyConfig`](proxy::ProxyConfig) for routing WebSocket & REST traffic through a HTTP
+/// CONNECT or SOCKS5 proxy.
+pub mod proxy;
+
+/// Contains the [`BinaryStreamParser`](binary::BinaryStreamParser) abstraction for protocols
+/// publishing binary frames, a [`BinaryExchangeStream`](binary::BinaryExchangeStream), and a
+/// Simple Binary Encoding (SBE) decoder.
+pub mod binary;
+
 /// `StreamParser`s are capable of parsing the input messages from a given stream protocol
 /// (eg/ WebSocket, Financial Information eXchange (FIX), etc.) and deserialising into an `Output`.
 pub trait StreamParser {
@@ -1,9 +1,12 @@
-use crate::{error::SocketError, protocol::StreamParser};
+use crate::{
+    error::SocketError,
+    protocol::{proxy::ProxyConfig, StreamParser},
+};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::fmt::Debug;
 use tokio::net::TcpStream;
 use tokio_tungstenite::{
-    connect_async,
+    client_async_tls_with_config, connect_async,
     tungstenite::{
         client::IntoClientRequest,
         error::ProtocolError,
@@ -13,6 +16,14 @@ use tokio_tungstenite::{
 };
 use tracing::debug;
 
+/// Transparent decompression of compressed [`WebSocket`] payloads, such as gzip, zlib & raw
+/// DEFLATE compressed frames.
+///
+/// Only application level frame compression is supported. The `permessage-deflate` extension
+/// (RFC 7692) is not, since the pinned `tokio-tungstenite` neither negotiates it during the
+/// handshake nor accepts compressed (RSV1) frames.
+pub mod compression;
+
 /// Convenient type alias for a tungstenite `WebSocketStream`.
 pub type WebSocket = tokio_tungstenite::WebSocketStream<MaybeTlsStream<TcpStream>>;
 
@@ -146,6 +157,39 @@ where
         .map_err(SocketError::WebSocket)
 }
 
+/// Connect asynchronously to a [`WebSocket`] server, tunnelled through the provided
+/// [`ProxyConfig`] if any.
+pub async fn connect_via<R>(
+    request: R,
+    proxy: Option<&ProxyConfig>,
+) -> Result<WebSocket, SocketError>
+where
+    R: IntoClientRequest + Unpin + Debug,
+{
+    let Some(proxy) = proxy else {
+        return connect(request).await;
+    };
+
+    let request = request.into_client_request()?;
+    let uri = request.uri();
+    let host = uri
+        .host()
+        .ok_or_else(|| SocketError::Proxy(format!("WebSocket url has no host: {uri}")))?
+        .to_string();
+    let port = uri.port_u16().unwrap_or(match uri.scheme_str() {
+        Some("wss") => 443,
+        _ => 80,
+    });
+
+    debug!(?request, proxy = %proxy.url, "attempting to establish WebSocket connection via proxy");
+    let stream = proxy.connect(&host, port).await?;
+
+    client_async_tls_with_config(request, stream, None, None)
+        .await
+        .map(|(websocket, _)| websocket)
+        .map_err(SocketError::WebSocket)
+}
+
 /// Determine whether a [`WsError`] indicates the [`WebSocket`] has disconnected.
 pub fn is_websocket_disconnected(error: &WsError) -> bool {
     matches!(
@@ -0,0 +1,241 @@
+use super::{
+    process_binary, process_close_frame, process_frame, process_ping, process_pong, process_text,
+    WebSocket, WsError, WsMessage,
+};
+use crate::{error::SocketError, protocol::StreamParser};
+use flate2::read::{DeflateDecoder, GzDecoder, ZlibDecoder};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{fmt::Display, io::Read, marker::PhantomData};
+
+/// Maximum size of a decompressed frame or message, guarding against decompression bombs.
+pub const MAX_DECOMPRESSED_SIZE: usize = 16 * 1024 * 1024;
+
+/// Decompresses the binary payloads of a venue's [`WebSocket`] frames.
+///
+/// Payloads decompressing to more than [`MAX_DECOMPRESSED_SIZE`] bytes are rejected.
+///
+/// ### Examples
+/// - [`Gzip`]: Huobi style gzip compressed frames.
+/// - [`Deflate`]: OKX style raw DEFLATE compressed frames.
+pub trait FrameDecoder {
+    fn decode(payload: &[u8]) -> Result<Vec<u8>, SocketError>;
+}
+
+/// Gzip (RFC 1952) compressed frames.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Gzip;
+
+impl FrameDecoder for Gzip {
+    fn decode(payload: &[u8]) -> Result<Vec<u8>, SocketError> {
+        read_capped(GzDecoder::new(payload), payload)
+    }
+}
+
+/// Zlib (RFC 1950) compressed frames.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Zlib;
+
+impl FrameDecoder for Zlib {
+    fn decode(payload: &[u8]) -> Result<Vec<u8>, SocketError> {
+        read_capped(ZlibDecoder::new(payload), payload)
+    }
+}
+
+/// Raw DEFLATE (RFC 1951) compressed frames.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Deflate;
+
+impl FrameDecoder for Deflate {
+    fn decode(payload: &[u8]) -> Result<Vec<u8>, SocketError> {
+        read_capped(DeflateDecoder::new(payload), payload)
+    }
+}
+
+/// Frames that may be [`Gzip`] or [`Zlib`] compressed, detected from their header, or
+/// uncompressed.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Detect;
+
+impl FrameDecoder for Detect {
+    fn decode(payload: &[u8]) -> Result<Vec<u8>, SocketError> {
+        match payload {
+            [0x1F, 0x8B, ..] => Gzip::decode(payload),
+            [cmf, flg, ..]
+                if cmf & 0x0F == 8 && (u16::from(*cmf) << 8 | u16::from(*flg)) % 31 == 0 =>
+            {
+                Zlib::decode(payload)
+            }
+            _ => Ok(payload.to_vec()),
+        }
+    }
+}
+
+/// [`StreamParser`] for a [`WebSocket`] whose binary frames are compressed, decompressing them
+/// with the `Decoder` before deserialising. Text & control frames are processed as per the
+/// default [`WebSocketParser`](super::WebSocketParser).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DecompressingParser<Decoder> {
+    phantom: PhantomData<Decoder>,
+}
+
+impl<Decoder> StreamParser for DecompressingParser<Decoder>
+where
+    Decoder: FrameDecoder,
+{
+    type Stream = WebSocket;
+    type Message = WsMessage;
+    type Error = WsError;
+
+    fn parse<Output>(
+        input: Result<Self::Message, Self::Error>,
+    ) -> Option<Result<Output, SocketError>>
+    where
+        Output: DeserializeOwned,
+    {
+        match input {
+            Ok(ws_message) => match ws_message {
+                WsMessage::Text(text) => process_text(text),
+                WsMessage::Binary(binary) => match Decoder::decode(&binary) {
+                    Ok(payload) => process_binary(payload),
+                    Err(error) => Some(Err(error)),
+                },
+                WsMessage::Ping(ping) => process_ping(ping),
+                WsMessage::Pong(pong) => process_pong(pong),
+                WsMessage::Close(close_frame) => process_close_frame(close_frame),
+                WsMessage::Frame(frame) => process_frame(frame),
+            },
+            Err(ws_err) => Some(Err(SocketError::WebSocket(ws_err))),
+        }
+    }
+}
+
+/// Read the decompressed output of a [`FrameDecoder`], failing if it exceeds the
+/// [`MAX_DECOMPRESSED_SIZE`].
+fn read_capped(decoder: impl Read, payload: &[u8]) -> Result<Vec<u8>, SocketError> {
+    let mut output = Vec::new();
+    decoder
+        .take(MAX_DECOMPRESSED_SIZE as u64 + 1)
+        .read_to_end(&mut output)
+        .map_err(|error| decode_error(error, payload))?;
+
+    if output.len() > MAX_DECOMPRESSED_SIZE {
+        return Err(size_error(payload));
+    }
+
+    Ok(output)
+}
+
+fn size_error(payload: &[u8]) -> SocketError {
+    decode_error(
+        format!("decompressed size exceeds {MAX_DECOMPRESSED_SIZE} bytes"),
+        payload,
+    )
+}
+
+fn decode_error(error: impl Display, payload: &[u8]) -> SocketError {
+    SocketError::DecodeBinary {
+        error: error.to_string(),
+        payload: payload.to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MESSAGE: &str = r#"{"ch":"market.btcusdt.trade.detail","ts":1630000000000,"tick":{"data":[{"price":50000.5,"amount":0.1}]}}"#;
+
+    // Note: compressed with the Python zlib & gzip modules
+    const GZIP: &str = "1f8b08000000000002033dc9310e80201005d1bbfc9a6c24468bbd8ab158814482a8c1b522dc5d6d9cf24d855bc1c85252505ad4dd9757d2223e900f2a7183815e603bf6dddf4bd12570851715f0547196e80278f8360d06928f7b577047b6cdad3d5678a6a568000000";
+    const ZLIB: &str = "789c3dc9310e80201005d1bbfc9a6c24468bbd8ab158814482a8c1b522dc5d6d9cf24d855bc1c85252505ad4dd9757d2223e900f2a7183815e603bf6dddf4bd12570851715f0547196e80278f8360d06928f7b577047b6cdad3db8b31fe6";
+    const DEFLATE: &str = "3dc9310e80201005d1bbfc9a6c24468bbd8ab158814482a8c1b522dc5d6d9cf24d855bc1c85252505ad4dd9757d2223e900f2a7183815e603bf6dddf4bd12570851715f0547196e80278f8360d06928f7b577047b6cdad3d";
+
+    #[test]
+    fn test_frame_decoders() {
+        struct TestCase {
+            decoded: Result<Vec<u8>, SocketError>,
+            expected: Option<&'static [u8]>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: gzip, dynamic huffman block
+                decoded: Gzip::decode(&hex::decode(GZIP).unwrap()),
+                expected: Some(MESSAGE.as_bytes()),
+            },
+            TestCase {
+                // TC1: zlib, dynamic huffman block
+                decoded: Zlib::decode(&hex::decode(ZLIB).unwrap()),
+                expected: Some(MESSAGE.as_bytes()),
+            },
+            TestCase {
+                // TC2: raw deflate, dynamic huffman block
+                decoded: Deflate::decode(&hex::decode(DEFLATE).unwrap()),
+                expected: Some(MESSAGE.as_bytes()),
+            },
+            TestCase {
+                // TC3: raw deflate, fixed huffman block with overlapping back-reference
+                decoded: Deflate::decode(&hex::decode("4b4c8481a46400").unwrap()),
+                expected: Some(b"aaaaaaaaaabc"),
+            },
+            TestCase {
+                // TC4: raw deflate, stored block
+                decoded: Deflate::decode(&hex::decode("010500faff68656c6c6f").unwrap()),
+                expected: Some(b"hello"),
+            },
+            TestCase {
+                // TC5: detect gzip
+                decoded: Detect::decode(&hex::decode(GZIP).unwrap()),
+                expected: Some(MESSAGE.as_bytes()),
+            },
+            TestCase {
+                // TC6: detect uncompressed
+                decoded: Detect::decode(MESSAGE.as_bytes()),
+                expected: Some(MESSAGE.as_bytes()),
+            },
+            TestCase {
+                // TC7: gzip with corrupted checksum
+                decoded: Gzip::decode(&hex::decode(GZIP.replace("5678a6a5", "5678a6a6")).unwrap()),
+                expected: None,
+            },
+            TestCase {
+                // TC8: truncated raw deflate
+                decoded: Deflate::decode(&hex::decode(&DEFLATE[..40]).unwrap()),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            match test.expected {
+                Some(expected) => {
+                    assert_eq!(test.decoded.unwrap(), expected, "TC{index} failed")
+                }
+                None => assert!(
+                    matches!(test.decoded, Err(SocketError::DecodeBinary { .. })),
+                    "TC{index} failed"
+                ),
+            }
+        }
+    }
+
+    fn deflate_bomb() -> Vec<u8> {
+        use flate2::{write::DeflateEncoder, Compression};
+        use std::io::Write;
+
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::best());
+        encoder
+            .write_all(&vec![0; MAX_DECOMPRESSED_SIZE + 1])
+            .unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn test_decompressed_size_is_capped() {
+        let bomb = deflate_bomb();
+
+        assert!(matches!(
+            Deflate::decode(&bomb),
+            Err(SocketError::DecodeBinary { error, .. }) if error.contains("exceeds")
+        ));
+    }
+}
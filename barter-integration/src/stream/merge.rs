@@ -0,0 +1,280 @@
+use super::ExchangeTime;
+use chrono::{DateTime, TimeDelta, Utc};
+use futures::{
+    stream::{select_all, SelectAll},
+    Stream,
+};
+use pin_project::pin_project;
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering as AtomicOrdering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+use tracing::debug;
+
+/// Merge the provided streams into a single [`TimeOrderedMerge`] stream that emits items in
+/// exchange time order, tolerating up to `max_skew` of out of order arrival.
+pub fn merge_time_ordered<Iter, St>(
+    streams: Iter,
+    max_skew: TimeDelta,
+) -> TimeOrderedMerge<SelectAll<St>>
+where
+    Iter: IntoIterator<Item = St>,
+    St: Stream + Unpin,
+    St::Item: ExchangeTime,
+{
+    TimeOrderedMerge::new(select_all(streams), max_skew)
+}
+
+/// Metrics of a [`TimeOrderedMerge`], shareable with other threads via
+/// [`TimeOrderedMerge::metrics`].
+#[derive(Debug, Default)]
+pub struct MergeMetrics {
+    emitted: AtomicU64,
+    dropped_late: AtomicU64,
+}
+
+impl MergeMetrics {
+    /// Number of items emitted.
+    pub fn emitted(&self) -> u64 {
+        self.emitted.load(AtomicOrdering::Relaxed)
+    }
+
+    /// Number of items dropped because they arrived after a later item had already been emitted
+    /// (ie/ they were skewed by more than the `max_skew`).
+    pub fn dropped_late(&self) -> u64 {
+        self.dropped_late.load(AtomicOrdering::Relaxed)
+    }
+}
+
+/// Buffered item, ordered by exchange time & then arrival sequence.
+#[derive(Debug)]
+struct Buffered<T> {
+    time: DateTime<Utc>,
+    sequence: u64,
+    item: T,
+}
+
+impl<T> PartialEq for Buffered<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for Buffered<T> {}
+
+impl<T> PartialOrd for Buffered<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Buffered<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.time, self.sequence).cmp(&(other.time, other.sequence))
+    }
+}
+
+/// `Stream` combinator that buffers items for a `max_skew` window and emits them in
+/// [`ExchangeTime`] order.
+///
+/// An item is emitted once an item at least `max_skew` newer has arrived (or the inner `Stream`
+/// has ended), so every item skewed by less than `max_skew` is emitted in order. Items arriving
+/// after a later item has already been emitted are dropped & counted in the [`MergeMetrics`].
+/// Items without an [`ExchangeTime`] are emitted immediately.
+#[derive(Debug)]
+#[pin_project]
+pub struct TimeOrderedMerge<St>
+where
+    St: Stream,
+{
+    #[pin]
+    stream: St,
+    max_skew: TimeDelta,
+    buffer: BinaryHeap<Reverse<Buffered<St::Item>>>,
+    latest: Option<DateTime<Utc>>,
+    last_emitted: Option<DateTime<Utc>>,
+    sequence: u64,
+    finished: bool,
+    metrics: Arc<MergeMetrics>,
+}
+
+impl<St> TimeOrderedMerge<St>
+where
+    St: Stream,
+    St::Item: ExchangeTime,
+{
+    /// Construct a new [`TimeOrderedMerge`] that orders the items of the provided (usually
+    /// already merged) `Stream`.
+    pub fn new(stream: St, max_skew: TimeDelta) -> Self {
+        Self {
+            stream,
+            max_skew,
+            buffer: BinaryHeap::new(),
+            latest: None,
+            last_emitted: None,
+            sequence: 0,
+            finished: false,
+            metrics: Arc::default(),
+        }
+    }
+
+    /// Shared handle to the [`MergeMetrics`] of this [`TimeOrderedMerge`].
+    pub fn metrics(&self) -> Arc<MergeMetrics> {
+        Arc::clone(&self.metrics)
+    }
+}
+
+impl<St> Stream for TimeOrderedMerge<St>
+where
+    St: Stream,
+    St::Item: ExchangeTime,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            // Emit the oldest buffered item if it can no longer be preceded by a skewed item
+            let releasable = this.buffer.peek().is_some_and(|Reverse(oldest)| {
+                *this.finished
+                    || this
+                        .latest
+                        .is_some_and(|latest| latest - oldest.time >= *this.max_skew)
+            });
+            if releasable {
+                let Reverse(oldest) = this.buffer.pop().expect("peeked item must exist");
+                *this.last_emitted = Some(oldest.time);
+                this.metrics.emitted.fetch_add(1, AtomicOrdering::Relaxed);
+                return Poll::Ready(Some(oldest.item));
+            }
+
+            if *this.finished {
+                return Poll::Ready(None);
+            }
+
+            let item = match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => item,
+                Poll::Ready(None) => {
+                    *this.finished = true;
+                    continue;
+                }
+                Poll::Pending => return Poll::Pending,
+            };
+
+            let Some(time) = item.time_exchange() else {
+                this.metrics.emitted.fetch_add(1, AtomicOrdering::Relaxed);
+                return Poll::Ready(Some(item));
+            };
+
+            if this
+                .last_emitted
+                .is_some_and(|last_emitted| time < last_emitted)
+            {
+                debug!(%time, last_emitted = ?this.last_emitted, "TimeOrderedMerge dropped late item");
+                this.metrics
+                    .dropped_late
+                    .fetch_add(1, AtomicOrdering::Relaxed);
+                continue;
+            }
+
+            *this.latest = Some(this.latest.map_or(time, |latest| latest.max(time)));
+            *this.sequence += 1;
+            this.buffer.push(Reverse(Buffered {
+                time,
+                sequence: *this.sequence,
+                item,
+            }));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{executor::block_on, stream, StreamExt};
+
+    #[derive(Debug, PartialEq)]
+    struct Item(&'static str, Option<i64>);
+
+    impl ExchangeTime for Item {
+        fn time_exchange(&self) -> Option<DateTime<Utc>> {
+            self.1.and_then(DateTime::from_timestamp_millis)
+        }
+    }
+
+    #[test]
+    fn test_time_ordered_merge() {
+        struct TestCase {
+            max_skew: TimeDelta,
+            expected: Vec<Item>,
+            expected_dropped_late: u64,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: max_skew covers every out of order arrival
+                max_skew: TimeDelta::milliseconds(60),
+                expected: vec![
+                    Item("status", None),
+                    Item("binance", Some(100)),
+                    Item("kraken", Some(110)),
+                    Item("kraken", Some(115)),
+                    Item("binance", Some(120)),
+                    Item("kraken", Some(130)),
+                    Item("okx", Some(170)),
+                ],
+                expected_dropped_late: 0,
+            },
+            TestCase {
+                // TC1: kraken 115 is skewed by more than max_skew, so arrives late & is dropped
+                max_skew: TimeDelta::milliseconds(10),
+                expected: vec![
+                    Item("binance", Some(100)),
+                    Item("status", None),
+                    Item("kraken", Some(110)),
+                    Item("binance", Some(120)),
+                    Item("kraken", Some(130)),
+                    Item("okx", Some(170)),
+                ],
+                expected_dropped_late: 1,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            // Merged arrival order of the exchange streams
+            let arrivals = stream::iter(vec![
+                Item("binance", Some(100)),
+                Item("binance", Some(120)),
+                Item("status", None),
+                Item("kraken", Some(110)),
+                Item("kraken", Some(130)),
+                Item("okx", Some(170)),
+                Item("kraken", Some(115)),
+            ]);
+
+            let merge = TimeOrderedMerge::new(arrivals, test.max_skew);
+            let metrics = merge.metrics();
+
+            let actual = block_on(merge.collect::<Vec<_>>());
+
+            assert_eq!(actual, test.expected, "TC{index} failed");
+            assert_eq!(
+                metrics.dropped_late(),
+                test.expected_dropped_late,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                metrics.emitted(),
+                test.expected.len() as u64,
+                "TC{index} failed"
+            );
+        }
+    }
+}
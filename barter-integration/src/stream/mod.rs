@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+
+/// [`TimeOrderedMerge`](merge::TimeOrderedMerge) `Stream` combinator that merges streams from
+/// several exchanges, emitting items in exchange time order within a bounded skew window.
+pub mod merge;
+
+/// Provides the exchange timestamp used to order a `Stream` item, if it has one.
+pub trait ExchangeTime {
+    /// Exchange timestamp of the item. Items without an exchange timestamp (eg/ errors, or
+    /// connection status events) are not ordered.
+    fn time_exchange(&self) -> Option<DateTime<Utc>>;
+}
+
+impl<T, E> ExchangeTime for Result<T, E>
+where
+    T: ExchangeTime,
+{
+    fn time_exchange(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Ok(item) => item.time_exchange(),
+            Err(_) => None,
+        }
+    }
+}
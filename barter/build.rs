@@ -0,0 +1,22 @@
+fn main() {
+    #[cfg(feature = "grpc")]
+    compile_control_proto();
+}
+
+/// Generate the gRPC ControlPlane server & client from `proto/control.proto`, using a vendored
+/// `protoc` unless one is provided via the `PROTOC` environment variable.
+#[cfg(feature = "grpc")]
+fn compile_control_proto() {
+    println!("cargo:rerun-if-changed=proto/control.proto");
+    println!("cargo:rerun-if-env-changed=PROTOC");
+
+    if std::env::var_os("PROTOC").is_none() {
+        let protoc =
+            protoc_bin_vendored::protoc_bin_path().expect("failed to find vendored protoc");
+        std::env::set_var("PROTOC", protoc);
+    }
+
+    tonic_prost_build::configure()
+        .compile_protos(&["proto/control.proto"], &["proto"])
+        .expect("failed to compile proto/control.proto");
+}
@@ -0,0 +1,155 @@
+use barter::{
+    backtest::{self, error::BacktestError, BacktestGrid, ParameterGrid},
+    clock::HistoricalClock,
+    data::historical,
+    engine::trader::Trader,
+    event::EventTx,
+    execution::{
+        simulated::{Config as ExecutionConfig, SimulatedExecution},
+        Fees,
+    },
+    portfolio::{
+        allocator::DefaultAllocator, portfolio::MetaPortfolio,
+        repository::in_memory::InMemoryRepository, risk::DefaultRisk,
+    },
+    statistic::summary::{
+        trading::{Config as StatisticConfig, TradingSummary},
+        Initialiser,
+    },
+    strategy::example::{Config as StrategyConfig, RSIStrategy},
+};
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    subscription::candle::Candle,
+};
+use barter_instrument::{
+    exchange::ExchangeId,
+    instrument::{kind::InstrumentKind, Instrument},
+    market::Market,
+};
+use chrono::Utc;
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::{fs, sync::Arc};
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+const DATA_HISTORIC_CANDLES_1H: &str = "barter/examples/data/candles_1h.json";
+
+const STATISTIC_CONFIG: StatisticConfig = StatisticConfig {
+    starting_equity: dec!(10_000),
+    trading_days_per_year: 365,
+    risk_free_return: 0.0,
+};
+
+fn main() {
+    // Load the historical MarketEvents once & share them between every backtest run
+    let candles = load_json_market_event_candles();
+
+    // Define the parameter grid: every combination of RSI period & simulated exchange fee
+    let grid = ParameterGrid::product(&[7, 14, 21, 28], &[dec!(0.0005), dec!(0.001)]);
+
+    // Run a backtest for every parameter set in parallel
+    let results = BacktestGrid::default().run(grid, |(rsi_period, exchange_fee)| {
+        run_backtest(candles.clone(), *rsi_period, *exchange_fee)
+    });
+
+    // Compare each run's TradingSummary
+    results.table().printstd();
+
+    if let Some(best) = results.best_by(|summary| summary.pnl_returns.total.sum) {
+        println!(
+            "Best (rsi_period, exchange_fee) by total PnL: {:?}",
+            best.params
+        );
+    }
+}
+
+fn run_backtest(
+    candles: Vec<MarketEvent<Instrument, DataKind>>,
+    rsi_period: usize,
+    exchange_fee: Decimal,
+) -> Result<TradingSummary, BacktestError> {
+    let engine_id = Uuid::new_v4();
+    let market = Market::new(
+        ExchangeId::BinanceSpot,
+        ("btc", "usdt", InstrumentKind::Spot),
+    );
+
+    // Step a shared clock with the candle time, so every timestamp is deterministic
+    let clock = HistoricalClock::new(
+        candles
+            .first()
+            .map(|candle| candle.time_exchange)
+            .unwrap_or_default(),
+    );
+
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![market.clone()])
+            .starting_cash(dec!(10_000))
+            .repository(InMemoryRepository::<TradingSummary>::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: dec!(100),
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(STATISTIC_CONFIG)
+            .clock(clock.clone())
+            .build_and_init()
+            .map_err(|error| BacktestError::Run(error.to_string()))?,
+    ));
+
+    // Keep the Trader command transmitter alive until the historical MarketFeed is finished
+    let (_trader_command_tx, trader_command_rx) = mpsc::channel(10);
+
+    // Events are not listened to, so the dropped receiver is ignored by the EventTx
+    let (event_tx, _) = mpsc::unbounded_channel();
+
+    Trader::<_, TradingSummary, _, _, _, _>::builder()
+        .engine_id(engine_id)
+        .market(market)
+        .command_rx(trader_command_rx)
+        .event_tx(EventTx::new(event_tx))
+        .clock(clock.clone())
+        .portfolio(Arc::clone(&portfolio))
+        .data(historical::MarketFeed::new(candles))
+        .strategy(RSIStrategy::new(StrategyConfig { rsi_period }))
+        .execution(
+            SimulatedExecution::new(ExecutionConfig {
+                simulated_fees_pct: Fees {
+                    exchange: exchange_fee,
+                    slippage: dec!(0.0005),
+                    network: dec!(0),
+                },
+            })
+            .with_clock(clock),
+        )
+        .build()?
+        .run();
+
+    backtest::summarise(
+        &portfolio,
+        engine_id,
+        TradingSummary::init(STATISTIC_CONFIG),
+    )
+}
+
+fn load_json_market_event_candles() -> Vec<MarketEvent<Instrument, DataKind>> {
+    let candles = fs::read_to_string(DATA_HISTORIC_CANDLES_1H).expect("failed to read file");
+
+    let candles =
+        serde_json::from_str::<Vec<Candle>>(&candles).expect("failed to parse candles String");
+
+    candles
+        .into_iter()
+        .map(|candle| MarketEvent {
+            time_exchange: candle.close_time,
+            time_received: Utc::now(),
+            exchange: ExchangeId::BinanceSpot,
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            kind: DataKind::Candle(candle),
+        })
+        .collect()
+}
@@ -29,6 +29,7 @@ use barter_instrument::{
 };
 use futures::Stream;
 use parking_lot::Mutex;
+use rust_decimal_macros::dec;
 use std::{collections::HashMap, sync::Arc, time::Duration};
 use tokio::sync::mpsc;
 use tokio_stream::StreamExt;
@@ -60,14 +61,14 @@ async fn main() {
         MetaPortfolio::builder()
             .engine_id(engine_id)
             .markets(vec![market.clone()])
-            .starting_cash(10_000.0)
+            .starting_cash(dec!(10_000))
             .repository(InMemoryRepository::new())
             .allocation_manager(DefaultAllocator {
-                default_order_value: 100.0,
+                default_order_value: dec!(100),
             })
             .risk_manager(DefaultRisk {})
             .statistic_config(StatisticConfig {
-                starting_equity: 10_000.0,
+                starting_equity: dec!(10_000),
                 trading_days_per_year: 365,
                 risk_free_return: 0.0,
             })
@@ -94,9 +95,9 @@ async fn main() {
             .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
             .execution(SimulatedExecution::new(ExecutionConfig {
                 simulated_fees_pct: Fees {
-                    exchange: 0.1,
-                    slippage: 0.05,
-                    network: 0.0,
+                    exchange: dec!(0.1),
+                    slippage: dec!(0.05),
+                    network: dec!(0),
                 },
             }))
             .build()
@@ -114,7 +115,7 @@ async fn main() {
         .traders(traders)
         .trader_command_txs(trader_command_txs)
         .statistics_summary(TradingSummary::init(StatisticConfig {
-            starting_equity: 1000.0,
+            starting_equity: dec!(1000),
             trading_days_per_year: 365,
             risk_free_return: 0.0,
         }))
@@ -183,6 +184,10 @@ async fn listen_to_engine_events(mut event_rx: mpsc::UnboundedReceiver<Event>) {
             Event::OrderUpdate => {
                 // OrderUpdate Event occurred in Engine
             }
+            Event::OrderLifecycle(order) => {
+                // OrderEvent reached it's terminal status in Engine
+                println!("{order:?}");
+            }
             Event::Fill(fill_event) => {
                 // Fill Event occurred in Engine
                 println!("{fill_event:?}");
@@ -203,6 +208,22 @@ async fn listen_to_engine_events(mut event_rx: mpsc::UnboundedReceiver<Event>) {
                 // Balance update Event occurred in Engine
                 println!("{balance_update:?}");
             }
+            Event::MarketAnomaly(anomaly) => {
+                // MarketEvent failed a data-sanity check in Engine
+                println!("{anomaly:?}");
+            }
+            Event::UniverseUpdate(update) => {
+                // Selected universe changed in Engine
+                println!("{update:?}");
+            }
+            Event::Scheduled(scheduled) => {
+                // Scheduled task due in Engine
+                println!("{scheduled:?}");
+            }
+            Event::Custom(custom) => {
+                // Custom event injected into Engine
+                println!("{custom:?}");
+            }
         }
     }
 }
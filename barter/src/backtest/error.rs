@@ -0,0 +1,25 @@
+use crate::{engine::error::EngineError, portfolio::repository::error::RepositoryError};
+use thiserror::Error;
+
+/// All errors generated when running backtests via a
+/// [`BacktestGrid`](super::BacktestGrid).
+#[derive(Error, Debug)]
+pub enum BacktestError {
+    #[error("Failed to build backtest components: {0}")]
+    Engine(#[from] EngineError),
+
+    #[error("Failed to interact with repository")]
+    RepositoryInteractionError(#[from] RepositoryError),
+
+    #[error("Backtest run {0} panicked during execution")]
+    RunPanicked(usize),
+
+    #[error("Backtest run failed: {0}")]
+    Run(String),
+
+    #[error("No successful backtest runs in walk-forward train window {0}")]
+    NoSuccessfulRuns(usize),
+
+    #[error("Invalid walk-forward configuration: {0}")]
+    InvalidWalkForward(String),
+}
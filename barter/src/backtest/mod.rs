@@ -0,0 +1,328 @@
+use crate::{
+    backtest::error::BacktestError,
+    portfolio::repository::PositionHandler,
+    statistic::summary::{PositionSummariser, TableBuilder},
+};
+use parking_lot::Mutex;
+use prettytable::Table;
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+use smol_str::format_smolstr;
+use std::{
+    fmt::Debug,
+    num::NonZeroUsize,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+use tracing::{error, info};
+use uuid::Uuid;
+
+/// Barter backtest module specific errors.
+pub mod error;
+
+/// Walk-forward analysis that optimises parameters on rolling in-sample train windows, and
+/// evaluates them on the subsequent out-of-sample test windows.
+pub mod walk_forward;
+
+/// Collection of parameter sets, one for each backtest run executed by a [`BacktestGrid`].
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ParameterGrid<Params> {
+    pub params: Vec<Params>,
+}
+
+impl<Params> ParameterGrid<Params> {
+    /// Constructs a new [`ParameterGrid`] from the provided parameter sets.
+    pub fn new(params: Vec<Params>) -> Self {
+        Self { params }
+    }
+
+    /// Random search: reduce the [`ParameterGrid`] to `samples` parameter sets chosen at random
+    /// without replacement. The provided `seed` makes the selection reproducible.
+    pub fn random(mut self, samples: usize, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        self.params.shuffle(&mut rng);
+        self.params.truncate(samples);
+        self
+    }
+
+    /// Number of parameter sets in the [`ParameterGrid`].
+    pub fn len(&self) -> usize {
+        self.params.len()
+    }
+
+    /// Returns true if the [`ParameterGrid`] contains no parameter sets.
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+}
+
+impl<A, B> ParameterGrid<(A, B)>
+where
+    A: Clone,
+    B: Clone,
+{
+    /// Constructs a [`ParameterGrid`] containing the cartesian product of the two provided
+    /// parameter axes.
+    ///
+    /// eg/ `[14, 21] x [0.1, 0.2]` => `[(14, 0.1), (14, 0.2), (21, 0.1), (21, 0.2)]`
+    pub fn product(a: &[A], b: &[B]) -> Self {
+        Self::new(
+            a.iter()
+                .flat_map(|a| b.iter().map(move |b| (a.clone(), b.clone())))
+                .collect(),
+        )
+    }
+}
+
+impl<Params> FromIterator<Params> for ParameterGrid<Params> {
+    fn from_iter<Iter: IntoIterator<Item = Params>>(iter: Iter) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+/// Outcome of a single backtest run executed by a [`BacktestGrid`].
+#[derive(Debug)]
+pub struct BacktestRun<Params, Statistic> {
+    /// Index of the parameter set in the [`ParameterGrid`].
+    pub id: usize,
+    pub params: Params,
+    pub summary: Result<Statistic, BacktestError>,
+}
+
+/// Results of every backtest run executed by a [`BacktestGrid`], ordered by [`BacktestRun`] id.
+#[derive(Debug)]
+pub struct BacktestGridResults<Params, Statistic> {
+    pub runs: Vec<BacktestRun<Params, Statistic>>,
+}
+
+impl<Params, Statistic> BacktestGridResults<Params, Statistic> {
+    /// Iterator over every [`BacktestRun`] that completed successfully, along with it's summary.
+    pub fn successful(
+        &self,
+    ) -> impl Iterator<Item = (&BacktestRun<Params, Statistic>, &Statistic)> {
+        self.runs
+            .iter()
+            .filter_map(|run| run.summary.as_ref().ok().map(|summary| (run, summary)))
+    }
+
+    /// Iterator over every [`BacktestRun`] that failed.
+    pub fn failed(&self) -> impl Iterator<Item = &BacktestRun<Params, Statistic>> {
+        self.runs.iter().filter(|run| run.summary.is_err())
+    }
+
+    /// Find the successful [`BacktestRun`] with the largest key, as determined by the provided
+    /// key function (eg/ |summary| summary.pnl_returns.total.sum).
+    pub fn best_by<Key, F>(&self, key: F) -> Option<&BacktestRun<Params, Statistic>>
+    where
+        Key: PartialOrd,
+        F: Fn(&Statistic) -> Key,
+    {
+        self.successful()
+            .map(|(run, summary)| (run, key(summary)))
+            .reduce(|best, next| if next.1 > best.1 { next } else { best })
+            .map(|(run, _)| run)
+    }
+}
+
+impl<Params, Statistic> BacktestGridResults<Params, Statistic>
+where
+    Params: Debug,
+    Statistic: TableBuilder,
+{
+    /// Generate a comparable results [`Table`] with a row for every successful [`BacktestRun`].
+    pub fn table(&self) -> Table {
+        crate::statistic::summary::combine(self.successful().map(|(run, summary)| {
+            (
+                format_smolstr!("{}: {:?}", run.id, run.params),
+                TableRow(summary),
+            )
+        }))
+    }
+}
+
+/// Borrowed [`TableBuilder`] so [`BacktestGridResults::table`] does not require owned summaries.
+struct TableRow<'a, Statistic>(&'a Statistic);
+
+impl<Statistic> TableBuilder for TableRow<'_, Statistic>
+where
+    Statistic: TableBuilder,
+{
+    fn titles(&self) -> prettytable::Row {
+        self.0.titles()
+    }
+
+    fn row(&self) -> prettytable::Row {
+        self.0.row()
+    }
+}
+
+/// Runs many backtests in parallel, one for each parameter set in a [`ParameterGrid`].
+///
+/// Each backtest is defined by a closure that constructs & runs the backtest components
+/// (eg/ a [`Trader`](crate::engine::trader::Trader) using a historical
+/// [`MarketFeed`](crate::data::historical::MarketFeed) & a
+/// [`SimulatedExecution`](crate::execution::simulated::SimulatedExecution)) for the provided
+/// parameter set, and returns the run's statistical summary (eg/ a
+/// [`TradingSummary`](crate::statistic::summary::trading::TradingSummary)).
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct BacktestGrid {
+    /// Maximum number of backtests to run concurrently, each on it's own thread.
+    pub threads: NonZeroUsize,
+}
+
+impl Default for BacktestGrid {
+    fn default() -> Self {
+        Self {
+            threads: thread::available_parallelism().unwrap_or(NonZeroUsize::MIN),
+        }
+    }
+}
+
+impl BacktestGrid {
+    /// Constructs a new [`BacktestGrid`] that runs up to `threads` backtests concurrently.
+    pub fn new(threads: NonZeroUsize) -> Self {
+        Self { threads }
+    }
+
+    /// Run a backtest for every parameter set in the [`ParameterGrid`], distributing the runs
+    /// across the [`BacktestGrid`] threads. Blocks until every run has finished.
+    ///
+    /// A backtest that panics does not affect the other runs, and is recorded as
+    /// [`BacktestError::RunPanicked`].
+    pub fn run<Params, Statistic, Backtest>(
+        &self,
+        grid: ParameterGrid<Params>,
+        backtest: Backtest,
+    ) -> BacktestGridResults<Params, Statistic>
+    where
+        Params: Sync + Send,
+        Statistic: Send,
+        Backtest: Fn(&Params) -> Result<Statistic, BacktestError> + Sync,
+    {
+        let params = grid.params;
+        let threads = self.threads.get().min(params.len());
+        let next_run = AtomicUsize::new(0);
+        let summaries = Mutex::new(Vec::with_capacity(params.len()));
+
+        info!(runs = params.len(), threads, "running BacktestGrid");
+
+        thread::scope(|scope| {
+            for _ in 0..threads {
+                scope.spawn(|| loop {
+                    // Claim the next parameter set that has not been run
+                    let id = next_run.fetch_add(1, Ordering::Relaxed);
+                    let Some(run_params) = params.get(id) else {
+                        break;
+                    };
+
+                    let summary = catch_unwind(AssertUnwindSafe(|| backtest(run_params)))
+                        .unwrap_or_else(|_| {
+                            error!(run = id, "backtest run panicked during execution");
+                            Err(BacktestError::RunPanicked(id))
+                        });
+
+                    summaries.lock().push((id, summary));
+                });
+            }
+        });
+
+        let mut summaries = summaries.into_inner();
+        summaries.sort_unstable_by_key(|(id, _)| *id);
+
+        let runs = params
+            .into_iter()
+            .zip(summaries)
+            .map(|(params, (id, summary))| BacktestRun {
+                id,
+                params,
+                summary,
+            })
+            .collect();
+
+        BacktestGridResults { runs }
+    }
+}
+
+/// Generate a statistical summary for a finished backtest using every exited
+/// [`Position`](crate::portfolio::position::Position) associated with the engine_id.
+pub fn summarise<Portfolio, Statistic>(
+    portfolio: &Mutex<Portfolio>,
+    engine_id: Uuid,
+    mut statistic: Statistic,
+) -> Result<Statistic, BacktestError>
+where
+    Portfolio: PositionHandler,
+    Statistic: PositionSummariser,
+{
+    let exited_positions = portfolio.lock().get_exited_positions(engine_id)?;
+    statistic.generate_summary(&exited_positions);
+    Ok(statistic)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{statistic::summary::fees::FeeSummary, test_util::position};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_parameter_grid_product() {
+        let actual = ParameterGrid::product(&[14, 21], &["a", "b"]);
+
+        let expected = ParameterGrid::new(vec![(14, "a"), (14, "b"), (21, "a"), (21, "b")]);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parameter_grid_random_is_reproducible() {
+        let grid = ParameterGrid::new((0..100).collect::<Vec<_>>());
+
+        let first = grid.clone().random(10, 42);
+        let second = grid.random(10, 42);
+
+        assert_eq!(first.len(), 10);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_backtest_grid_run() {
+        let grid = ParameterGrid::new(vec![dec!(1), dec!(2), dec!(-1), dec!(3)]);
+
+        let results = BacktestGrid::new(NonZeroUsize::new(3).unwrap()).run(grid, |pnl| {
+            if pnl.is_sign_negative() {
+                panic!("negative pnl parameter");
+            }
+
+            let mut input = position();
+            input.realised_profit_loss = *pnl;
+
+            let mut summary = FeeSummary::new();
+            summary.update(&input);
+            Ok(summary)
+        });
+
+        // Runs are ordered by id & retain their parameters
+        assert_eq!(results.runs.len(), 4);
+        for (index, run) in results.runs.iter().enumerate() {
+            assert_eq!(run.id, index, "TC{index} failed");
+        }
+
+        // Panicking run is isolated
+        let failed = results.failed().map(|run| run.id).collect::<Vec<_>>();
+        assert_eq!(failed, vec![2]);
+        assert!(matches!(
+            results.runs[2].summary,
+            Err(BacktestError::RunPanicked(2))
+        ));
+
+        // Best run by realised PnL
+        let best = results
+            .best_by(|summary| summary.pnl_after_rebates)
+            .unwrap();
+        assert_eq!(best.params, dec!(3));
+
+        // Results table contains a row for every successful run
+        assert_eq!(results.table().len(), 3);
+    }
+}
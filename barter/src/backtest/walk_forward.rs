@@ -0,0 +1,406 @@
+use crate::{
+    backtest::{error::BacktestError, BacktestGrid, ParameterGrid},
+    portfolio::position::Position,
+    statistic::summary::{Initialiser, PositionSummariser},
+};
+use barter_data::event::MarketEvent;
+use chrono::{DateTime, Duration, Utc};
+use std::ops::Range;
+use tracing::info;
+
+/// Configuration for splitting a historical [`MarketEvent`] feed into walk-forward train & test
+/// windows.
+///
+/// Windows are time based, starting from the first [`MarketEvent`] `time_exchange`. Each
+/// successive window steps forward by the `test` [`Duration`], so the test windows are contiguous
+/// & non-overlapping.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct WalkForward {
+    /// [`Duration`] of each in-sample train window.
+    train: Duration,
+    /// [`Duration`] of each out-of-sample test window.
+    test: Duration,
+    /// If true, every train window starts at the first [`MarketEvent`] (ie/ expanding window),
+    /// otherwise each train window rolls forward with the test window.
+    anchored: bool,
+}
+
+/// Train & test [`MarketEvent`] index ranges for one walk-forward window.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct WalkForwardWindow {
+    pub train: Range<usize>,
+    pub test: Range<usize>,
+    pub test_start: DateTime<Utc>,
+    pub test_end: DateTime<Utc>,
+}
+
+/// Outcome of a single walk-forward window.
+#[derive(Clone, Debug)]
+pub struct WalkForwardWindowResult<Params, Statistic> {
+    pub window: WalkForwardWindow,
+    /// Parameters that scored best on the in-sample train window.
+    pub params: Params,
+    /// In-sample statistical summary of the best parameters.
+    pub train: Statistic,
+    /// Out-of-sample statistical summary of the best parameters.
+    pub test: Statistic,
+}
+
+/// Results of a walk-forward analysis, including an aggregate out-of-sample statistical summary
+/// stitched together from the exited [`Position`]s of every test window.
+#[derive(Clone, Debug)]
+pub struct WalkForwardResults<Params, Statistic> {
+    pub windows: Vec<WalkForwardWindowResult<Params, Statistic>>,
+    pub out_of_sample: Statistic,
+}
+
+impl WalkForward {
+    /// Constructs a new rolling [`WalkForward`] configuration.
+    ///
+    /// Returns a [`BacktestError::InvalidWalkForward`] if the train or test [`Duration`] is not
+    /// positive, since the windows would never step forward.
+    pub fn new(train: Duration, test: Duration) -> Result<Self, BacktestError> {
+        if train <= Duration::zero() {
+            return Err(BacktestError::InvalidWalkForward(format!(
+                "train Duration must be positive: {train}"
+            )));
+        }
+        if test <= Duration::zero() {
+            return Err(BacktestError::InvalidWalkForward(format!(
+                "test Duration must be positive: {test}"
+            )));
+        }
+
+        Ok(Self {
+            train,
+            test,
+            anchored: false,
+        })
+    }
+
+    /// [`Duration`] of each in-sample train window.
+    pub fn train(&self) -> Duration {
+        self.train
+    }
+
+    /// [`Duration`] of each out-of-sample test window.
+    pub fn test(&self) -> Duration {
+        self.test
+    }
+
+    /// Determine if the train window is anchored (expanding).
+    pub fn is_anchored(&self) -> bool {
+        self.anchored
+    }
+
+    /// Use an anchored (expanding) train window that always starts at the first [`MarketEvent`].
+    pub fn anchored(self) -> Self {
+        Self {
+            anchored: true,
+            ..self
+        }
+    }
+
+    /// Partition the provided [`MarketEvent`]s into walk-forward train & test windows.
+    ///
+    /// The [`MarketEvent`]s must be sorted by `time_exchange`. The final test window may be
+    /// shorter than the configured test [`Duration`] if the [`MarketEvent`]s run out.
+    pub fn windows<InstrumentKey, Kind>(
+        &self,
+        events: &[MarketEvent<InstrumentKey, Kind>],
+    ) -> Vec<WalkForwardWindow> {
+        let (Some(first), Some(last)) = (events.first(), events.last()) else {
+            return vec![];
+        };
+
+        // Index of the first MarketEvent at or after the provided time
+        let index_at =
+            |time: DateTime<Utc>| events.partition_point(|event| event.time_exchange < time);
+
+        let mut windows = Vec::new();
+        let mut train_start = first.time_exchange;
+
+        loop {
+            let test_start = train_start + self.train;
+            let test_end = test_start + self.test;
+
+            if test_start > last.time_exchange {
+                break;
+            }
+
+            let train_start_index = if self.anchored {
+                0
+            } else {
+                index_at(train_start)
+            };
+
+            windows.push(WalkForwardWindow {
+                train: train_start_index..index_at(test_start),
+                test: index_at(test_start)..index_at(test_end),
+                test_start,
+                test_end,
+            });
+
+            if self.anchored {
+                // Grow the train window so the next test window starts at this test_end
+                train_start = test_end - self.train;
+            } else {
+                train_start += self.test;
+            }
+        }
+
+        windows
+    }
+
+    /// Run a walk-forward analysis over the provided [`MarketEvent`]s.
+    ///
+    /// For each [`WalkForwardWindow`]:
+    /// 1. Run a backtest for every parameter set on the train window using the [`BacktestGrid`].
+    /// 2. Select the parameters whose in-sample summary has the largest `score`.
+    /// 3. Run a backtest using the selected parameters on the out-of-sample test window.
+    ///
+    /// The `backtest` closure must return every exited [`Position`] for the provided parameters &
+    /// [`MarketEvent`]s. The exited [`Position`]s of every test window are stitched together to
+    /// generate the aggregate out-of-sample statistical summary.
+    pub fn run<InstrumentKey, Kind, Params, Statistic, Backtest, Score, Key>(
+        &self,
+        grid: &BacktestGrid,
+        params: &ParameterGrid<Params>,
+        events: &[MarketEvent<InstrumentKey, Kind>],
+        statistic_config: Statistic::Config,
+        backtest: Backtest,
+        score: Score,
+    ) -> Result<WalkForwardResults<Params, Statistic>, BacktestError>
+    where
+        InstrumentKey: Sync,
+        Kind: Sync,
+        Params: Clone + Sync + Send,
+        Statistic: Initialiser + PositionSummariser + Send,
+        Statistic::Config: Sync,
+        Backtest: Fn(&Params, &[MarketEvent<InstrumentKey, Kind>]) -> Result<Vec<Position>, BacktestError>
+            + Sync,
+        Score: Fn(&Statistic) -> Key,
+        Key: PartialOrd,
+    {
+        let summarise = |positions: &[Position]| {
+            let mut statistic = Statistic::init(statistic_config);
+            statistic.generate_summary(positions);
+            statistic
+        };
+
+        let windows = self.windows(events);
+        let mut results = Vec::with_capacity(windows.len());
+        let mut out_of_sample_positions = Vec::new();
+
+        for (index, window) in windows.into_iter().enumerate() {
+            info!(
+                window = index,
+                test_start = %window.test_start,
+                test_end = %window.test_end,
+                "running walk-forward window"
+            );
+
+            // Optimise parameters in-sample
+            let train_events = &events[window.train.clone()];
+            let train_results = grid.run(params.clone(), |params| {
+                backtest(params, train_events).map(|positions| summarise(&positions))
+            });
+
+            let best = train_results
+                .best_by(&score)
+                .ok_or(BacktestError::NoSuccessfulRuns(index))?;
+
+            let best_params = best.params.clone();
+            let train = *best
+                .summary
+                .as_ref()
+                .expect("best_by only yields successful runs");
+
+            // Evaluate best parameters out-of-sample
+            let test_positions = backtest(&best_params, &events[window.test.clone()])?;
+            let test = summarise(&test_positions);
+            out_of_sample_positions.extend(test_positions);
+
+            results.push(WalkForwardWindowResult {
+                window,
+                params: best_params,
+                train,
+                test,
+            });
+        }
+
+        Ok(WalkForwardResults {
+            windows: results,
+            out_of_sample: summarise(&out_of_sample_positions),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        statistic::summary::fees::FeeSummary,
+        test_util::{market_event_trade, position},
+    };
+    use barter_data::event::DataKind;
+    use barter_instrument::instrument::Instrument;
+    use barter_integration::Side;
+    use rust_decimal_macros::dec;
+    use std::num::NonZeroUsize;
+
+    fn events(hours: i64) -> Vec<MarketEvent<Instrument, DataKind>> {
+        let start = Utc::now();
+        (0..hours)
+            .map(|hour| {
+                let mut event = market_event_trade(Side::Buy);
+                event.time_exchange = start + Duration::hours(hour);
+                event
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_walk_forward_new_rejects_non_positive_durations() {
+        struct TestCase {
+            train: Duration,
+            test: Duration,
+            expected_ok: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: positive train & test Durations
+                train: Duration::hours(4),
+                test: Duration::hours(2),
+                expected_ok: true,
+            },
+            TestCase {
+                // TC1: zero test Duration would never step forward
+                train: Duration::hours(4),
+                test: Duration::zero(),
+                expected_ok: false,
+            },
+            TestCase {
+                // TC2: negative test Duration
+                train: Duration::hours(4),
+                test: Duration::hours(-2),
+                expected_ok: false,
+            },
+            TestCase {
+                // TC3: zero train Duration
+                train: Duration::zero(),
+                test: Duration::hours(2),
+                expected_ok: false,
+            },
+            TestCase {
+                // TC4: negative train Duration
+                train: Duration::hours(-4),
+                test: Duration::hours(2),
+                expected_ok: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = WalkForward::new(test.train, test.test);
+            match (actual, test.expected_ok) {
+                (Ok(_), true) | (Err(BacktestError::InvalidWalkForward(_)), false) => {}
+                (actual, _) => panic!("TC{index} failed: {actual:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_walk_forward_windows() {
+        struct TestCase {
+            config: WalkForward,
+            expected: Vec<(Range<usize>, Range<usize>)>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: rolling windows, final test window is partial
+                config: WalkForward::new(Duration::hours(4), Duration::hours(2)).unwrap(),
+                expected: vec![(0..4, 4..6), (2..6, 6..8), (4..8, 8..9)],
+            },
+            TestCase {
+                // TC1: anchored windows
+                config: WalkForward::new(Duration::hours(4), Duration::hours(2))
+                    .unwrap()
+                    .anchored(),
+                expected: vec![(0..4, 4..6), (0..6, 6..8), (0..8, 8..9)],
+            },
+            TestCase {
+                // TC2: train window longer than the MarketEvents
+                config: WalkForward::new(Duration::hours(10), Duration::hours(2)).unwrap(),
+                expected: vec![],
+            },
+        ];
+
+        let events = events(9);
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test
+                .config
+                .windows(&events)
+                .into_iter()
+                .map(|window| (window.train, window.test))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_walk_forward_run() {
+        let events = events(9);
+        let config = WalkForward::new(Duration::hours(4), Duration::hours(2)).unwrap();
+
+        // Each MarketEvent generates one exited Position with realised PnL equal to the parameter
+        let results = config
+            .run(
+                &BacktestGrid::new(NonZeroUsize::new(2).unwrap()),
+                &ParameterGrid::new(vec![dec!(1), dec!(2), dec!(-1)]),
+                &events,
+                (),
+                |pnl, events| {
+                    Ok(events
+                        .iter()
+                        .map(|_| {
+                            let mut position = position();
+                            position.realised_profit_loss = *pnl;
+                            position
+                        })
+                        .collect())
+                },
+                |summary: &FeeSummary| summary.pnl_after_rebates,
+            )
+            .unwrap();
+
+        assert_eq!(results.windows.len(), 3);
+        for (index, window) in results.windows.iter().enumerate() {
+            assert_eq!(window.params, dec!(2), "TC{index} failed");
+        }
+
+        // Out-of-sample test windows contain 2 + 2 + 1 MarketEvents
+        assert_eq!(results.windows[0].train.pnl_after_rebates, dec!(8));
+        assert_eq!(results.windows[2].test.pnl_after_rebates, dec!(2));
+        assert_eq!(results.out_of_sample.pnl_after_rebates, dec!(10));
+    }
+
+    #[test]
+    fn test_walk_forward_run_no_successful_train_runs() {
+        let events = events(9);
+        let config = WalkForward::new(Duration::hours(4), Duration::hours(2)).unwrap();
+
+        let actual = config.run(
+            &BacktestGrid::default(),
+            &ParameterGrid::new(vec![1.0]),
+            &events,
+            (),
+            |_, _| Err(BacktestError::Run("failed".to_string())),
+            |summary: &FeeSummary| summary.pnl_after_rebates,
+        );
+
+        assert!(matches!(actual, Err(BacktestError::NoSuccessfulRuns(0))));
+    }
+}
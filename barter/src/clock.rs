@@ -0,0 +1,156 @@
+use barter_data::event::MarketEvent;
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Debug, sync::Arc};
+
+/// Source of the current time used to timestamp Engine output (eg/ `OrderEvent`, `FillEvent` &
+/// `Balance` times). Since `Position` & statistic time intervals are derived from these
+/// timestamps, a deterministic [`Clock`] makes backtest results reproducible.
+pub trait Clock: Debug + Send + Sync {
+    /// Current time according to this [`Clock`].
+    fn time(&self) -> DateTime<Utc>;
+}
+
+/// Live [`Clock`] that uses the system time.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct LiveClock;
+
+impl Clock for LiveClock {
+    fn time(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Simulated [`Clock`] that is stepped forward with the `time_exchange` of each [`MarketEvent`].
+///
+/// Cheaply cloneable handle to a shared time, so the clone stepped by a
+/// [`Trader`](crate::engine::trader::Trader) is observed by every Portfolio & Execution clone.
+#[derive(Clone, Debug)]
+pub struct HistoricalClock {
+    time: Arc<RwLock<DateTime<Utc>>>,
+}
+
+impl HistoricalClock {
+    /// Construct a new [`HistoricalClock`] starting at the provided time (eg/ the start of the
+    /// backtest data).
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self {
+            time: Arc::new(RwLock::new(start)),
+        }
+    }
+
+    /// Step the [`HistoricalClock`] forward to the provided time. Earlier times are ignored so the
+    /// clock is monotonic.
+    pub fn update(&self, time: DateTime<Utc>) {
+        let mut current = self.time.write();
+        if time > *current {
+            *current = time;
+        }
+    }
+
+    /// Step the [`HistoricalClock`] forward to the [`MarketEvent`] `time_exchange`.
+    pub fn update_from_market<InstrumentKey, T>(&self, market: &MarketEvent<InstrumentKey, T>) {
+        self.update(market.time_exchange)
+    }
+}
+
+impl Clock for HistoricalClock {
+    fn time(&self) -> DateTime<Utc> {
+        *self.time.read()
+    }
+}
+
+/// Frozen [`Clock`] that always returns the same time, useful for tests.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct FrozenClock(pub DateTime<Utc>);
+
+impl Clock for FrozenClock {
+    fn time(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+/// Shared handle to any [`Clock`] implementation, defaulting to a [`LiveClock`].
+#[derive(Clone, Debug)]
+pub struct SharedClock(Arc<dyn Clock>);
+
+impl SharedClock {
+    /// Construct a new [`SharedClock`] from the provided [`Clock`].
+    pub fn new<C>(clock: C) -> Self
+    where
+        C: Clock + 'static,
+    {
+        Self(Arc::new(clock))
+    }
+}
+
+impl Default for SharedClock {
+    fn default() -> Self {
+        Self::new(LiveClock)
+    }
+}
+
+impl PartialEq for SharedClock {
+    /// [`SharedClock`]s are equal if they share the same underlying [`Clock`].
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Clock for SharedClock {
+    fn time(&self) -> DateTime<Utc> {
+        self.0.time()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use barter_integration::Side;
+    use chrono::TimeDelta;
+
+    #[test]
+    fn test_historical_clock_update_from_market() {
+        struct TestCase {
+            input_offset: TimeDelta,
+            expected_offset: TimeDelta,
+        }
+
+        let start = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+        let clock = HistoricalClock::new(start);
+        let observer = SharedClock::new(clock.clone());
+
+        let tests = vec![
+            TestCase {
+                // TC0: step forward to first MarketEvent
+                input_offset: TimeDelta::seconds(10),
+                expected_offset: TimeDelta::seconds(10),
+            },
+            TestCase {
+                // TC1: step forward to next MarketEvent
+                input_offset: TimeDelta::seconds(15),
+                expected_offset: TimeDelta::seconds(15),
+            },
+            TestCase {
+                // TC2: out of order MarketEvent does not step the clock backwards
+                input_offset: TimeDelta::seconds(12),
+                expected_offset: TimeDelta::seconds(15),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut market = market_event_trade(Side::Buy);
+            market.time_exchange = start + test.input_offset;
+
+            clock.update_from_market(&market);
+
+            assert_eq!(
+                observer.time(),
+                start + test.expected_offset,
+                "TC{index} failed"
+            );
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use super::MarketEventObserver;
+use crate::event::Event;
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    subscription::SubKind,
+};
+use barter_instrument::{exchange::ExchangeId, instrument::Instrument};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+
+/// Configuration for constructing a [`MarketEventCache`].
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MarketEventCacheConfig {
+    /// Maximum number of [`MarketEvent`]s cached for each exchange, [`Instrument`] & [`SubKind`].
+    pub capacity: usize,
+    /// [`SubKind`]s to cache. Every [`SubKind`] is cached if `None`.
+    pub kinds: Option<Vec<SubKind>>,
+}
+
+/// Bounded cache of the most recent [`MarketEvent`]s for each exchange, [`Instrument`] &
+/// [`SubKind`] (eg/ last N trades, last M candles).
+///
+/// Maintained by every [`Trader`](crate::engine::trader::Trader) it is provided to, so
+/// strategies requiring a short lookback window can share it via indexed lookup rather than
+/// maintaining duplicate buffers.
+#[derive(Clone, PartialEq, Debug)]
+pub struct MarketEventCache {
+    config: MarketEventCacheConfig,
+    buffers:
+        HashMap<(ExchangeId, Instrument, SubKind), VecDeque<MarketEvent<Instrument, DataKind>>>,
+}
+
+impl MarketEventCache {
+    /// Construct a new empty [`MarketEventCache`].
+    pub fn new(config: MarketEventCacheConfig) -> Self {
+        Self {
+            config,
+            buffers: HashMap::new(),
+        }
+    }
+
+    /// Add a [`MarketEvent`] to the cache if it's [`SubKind`] is configured, evicting the oldest
+    /// cached [`MarketEvent`] if the buffer is full.
+    ///
+    /// [`DataKind::Custom`] events are not associated with a [`SubKind`], so are not cached.
+    pub fn update(&mut self, market: &MarketEvent<Instrument, DataKind>) {
+        let Some(kind) = sub_kind(&market.kind) else {
+            return;
+        };
+
+        let is_cached = self
+            .config
+            .kinds
+            .as_ref()
+            .is_none_or(|kinds| kinds.contains(&kind));
+
+        if !is_cached || self.config.capacity == 0 {
+            return;
+        }
+
+        let buffer = self
+            .buffers
+            .entry((market.exchange, market.instrument.clone(), kind))
+            .or_insert_with(|| VecDeque::with_capacity(self.config.capacity));
+
+        if buffer.len() == self.config.capacity {
+            buffer.pop_front();
+        }
+        buffer.push_back(market.clone());
+    }
+
+    /// Return the cached [`MarketEvent`] at the provided lookback index, where index 0 is the
+    /// most recent.
+    pub fn get(
+        &self,
+        exchange: ExchangeId,
+        instrument: &Instrument,
+        kind: SubKind,
+        index: usize,
+    ) -> Option<&MarketEvent<Instrument, DataKind>> {
+        let buffer = self.buffer(exchange, instrument, kind)?;
+        buffer
+            .len()
+            .checked_sub(index + 1)
+            .and_then(|index| buffer.get(index))
+    }
+
+    /// Iterate over the cached [`MarketEvent`]s, most recent first.
+    pub fn recent(
+        &self,
+        exchange: ExchangeId,
+        instrument: &Instrument,
+        kind: SubKind,
+    ) -> impl Iterator<Item = &MarketEvent<Instrument, DataKind>> {
+        self.buffer(exchange, instrument, kind)
+            .into_iter()
+            .flat_map(|buffer| buffer.iter().rev())
+    }
+
+    /// Number of cached [`MarketEvent`]s.
+    pub fn len(&self, exchange: ExchangeId, instrument: &Instrument, kind: SubKind) -> usize {
+        self.buffer(exchange, instrument, kind)
+            .map(VecDeque::len)
+            .unwrap_or_default()
+    }
+
+    fn buffer(
+        &self,
+        exchange: ExchangeId,
+        instrument: &Instrument,
+        kind: SubKind,
+    ) -> Option<&VecDeque<MarketEvent<Instrument, DataKind>>> {
+        self.buffers.get(&(exchange, instrument.clone(), kind))
+    }
+}
+
+impl MarketEventObserver for MarketEventCache {
+    fn on_market(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Event> {
+        self.update(market);
+        None
+    }
+}
+
+/// Determine the [`SubKind`] a [`DataKind`] was generated from, or `None` for a
+/// [`DataKind::Custom`].
+fn sub_kind(kind: &DataKind) -> Option<SubKind> {
+    match kind {
+        DataKind::Trade(_) => Some(SubKind::PublicTrades),
+        DataKind::OrderBookL1(_) => Some(SubKind::OrderBooksL1),
+        DataKind::OrderBook(_) => Some(SubKind::OrderBooksL2),
+        DataKind::Candle(_) => Some(SubKind::Candles),
+        DataKind::Liquidation(_) => Some(SubKind::Liquidations),
+        DataKind::Custom(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use barter_integration::Side;
+
+    fn trade(price: f64) -> MarketEvent<Instrument, DataKind> {
+        let mut market = market_event_trade(Side::Buy);
+        if let DataKind::Trade(trade) = &mut market.kind {
+            trade.price = price;
+        }
+        market
+    }
+
+    fn price(market: Option<&MarketEvent<Instrument, DataKind>>) -> Option<f64> {
+        match market.map(|market| &market.kind) {
+            Some(DataKind::Trade(trade)) => Some(trade.price),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_market_event_cache() {
+        struct TestCase {
+            kinds: Option<Vec<SubKind>>,
+            expected_len: usize,
+            expected_recent: Vec<f64>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: every SubKind cached, oldest events evicted
+                kinds: None,
+                expected_len: 3,
+                expected_recent: vec![4.0, 3.0, 2.0],
+            },
+            TestCase {
+                // TC1: PublicTrades configured
+                kinds: Some(vec![SubKind::PublicTrades]),
+                expected_len: 3,
+                expected_recent: vec![4.0, 3.0, 2.0],
+            },
+            TestCase {
+                // TC2: PublicTrades not configured
+                kinds: Some(vec![SubKind::Candles]),
+                expected_len: 0,
+                expected_recent: vec![],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut cache = MarketEventCache::new(MarketEventCacheConfig {
+                capacity: 3,
+                kinds: test.kinds,
+            });
+
+            for input in [1.0, 2.0, 3.0, 4.0] {
+                cache.update(&trade(input));
+            }
+
+            let market = trade(0.0);
+            let key = (market.exchange, &market.instrument, SubKind::PublicTrades);
+
+            assert_eq!(
+                cache.len(key.0, key.1, key.2),
+                test.expected_len,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                cache
+                    .recent(key.0, key.1, key.2)
+                    .map(|market| price(Some(market)).unwrap())
+                    .collect::<Vec<_>>(),
+                test.expected_recent,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                price(cache.get(key.0, key.1, key.2, 1)),
+                test.expected_recent.get(1).copied(),
+                "TC{index} failed"
+            );
+            assert_eq!(cache.get(key.0, key.1, key.2, 3), None, "TC{index} failed");
+        }
+    }
+}
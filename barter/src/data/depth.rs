@@ -0,0 +1,253 @@
+use super::MarketEventObserver;
+use crate::event::Event;
+use barter_data::{
+    books::{Level, OrderBook},
+    event::{DataKind, MarketEvent},
+    subscription::book::OrderBookEvent,
+};
+use barter_instrument::{exchange::ExchangeId, instrument::Instrument};
+use barter_integration::Side;
+use rust_decimal::prelude::ToPrimitive;
+use std::collections::HashMap;
+
+/// Full depth L2 [`OrderBook`] of each exchange [`Instrument`], for strategies to query depth,
+/// imbalance & the VWAP of consuming the book.
+///
+/// Maintained by every [`Trader`](crate::engine::trader::Trader) it is provided to, from
+/// [`OrderBookEvent`]s. An [`OrderBook`] is only tracked once its first snapshot is received,
+/// with any prior delta updates ignored.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct MarketDepth {
+    books: HashMap<Instrument, HashMap<ExchangeId, OrderBook>>,
+}
+
+impl MarketDepth {
+    /// Construct a new empty [`MarketDepth`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply the [`OrderBookEvent`] of the [`MarketEvent`] to the associated exchange
+    /// [`OrderBook`], if it contains one.
+    pub fn update(&mut self, market: &MarketEvent<Instrument, DataKind>) {
+        let DataKind::OrderBook(event) = &market.kind else {
+            return;
+        };
+
+        match event {
+            OrderBookEvent::Snapshot(snapshot) => {
+                self.books
+                    .entry(market.instrument.clone())
+                    .or_default()
+                    .insert(market.exchange, snapshot.clone());
+            }
+            OrderBookEvent::Update(_) => {
+                if let Some(book) = self
+                    .books
+                    .get_mut(&market.instrument)
+                    .and_then(|books| books.get_mut(&market.exchange))
+                {
+                    book.update(event.clone());
+                }
+            }
+        }
+    }
+
+    /// [`OrderBook`] of the [`Instrument`] on the provided exchange.
+    pub fn book(&self, exchange: ExchangeId, instrument: &Instrument) -> Option<&OrderBook> {
+        self.books.get(instrument)?.get(&exchange)
+    }
+
+    /// Cumulative amount of the best `levels` of the [`OrderBook`] bids ([`Side::Buy`]) or asks
+    /// ([`Side::Sell`]).
+    pub fn depth(
+        &self,
+        exchange: ExchangeId,
+        instrument: &Instrument,
+        side: Side,
+        levels: usize,
+    ) -> Option<f64> {
+        let book = self.book(exchange, instrument)?;
+        side_levels(book, side)
+            .iter()
+            .take(levels)
+            .map(|level| level.amount.to_f64())
+            .sum()
+    }
+
+    /// Imbalance of the best `levels` of the [`OrderBook`], in the range [-1.0, 1.0], where
+    /// positive values indicate more bid depth than ask depth.
+    ///
+    /// eg/ `(bid_depth - ask_depth) / (bid_depth + ask_depth)`
+    pub fn imbalance(
+        &self,
+        exchange: ExchangeId,
+        instrument: &Instrument,
+        levels: usize,
+    ) -> Option<f64> {
+        let bid_depth = self.depth(exchange, instrument, Side::Buy, levels)?;
+        let ask_depth = self.depth(exchange, instrument, Side::Sell, levels)?;
+
+        let total = bid_depth + ask_depth;
+        (total > 0.0).then(|| (bid_depth - ask_depth) / total)
+    }
+
+    /// Volume weighted average price of a market order of the provided [`Side`] & quantity
+    /// consuming the [`OrderBook`] (ie/ buys consume the asks, and sells consume the bids).
+    ///
+    /// Returns `None` if the [`OrderBook`] does not have enough depth to fill the quantity.
+    pub fn vwap(
+        &self,
+        exchange: ExchangeId,
+        instrument: &Instrument,
+        side: Side,
+        quantity: f64,
+    ) -> Option<f64> {
+        if quantity <= 0.0 {
+            return None;
+        }
+
+        let book = self.book(exchange, instrument)?;
+        let opposite = match side {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+        };
+
+        let mut remaining = quantity;
+        let mut notional = 0.0;
+        for level in side_levels(book, opposite) {
+            let filled = level.amount.to_f64()?.min(remaining);
+            notional += filled * level.price.to_f64()?;
+            remaining -= filled;
+
+            if remaining <= 0.0 {
+                return Some(notional / quantity);
+            }
+        }
+
+        None
+    }
+}
+
+impl MarketEventObserver for MarketDepth {
+    fn on_market(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Event> {
+        self.update(market);
+        None
+    }
+}
+
+fn side_levels(book: &OrderBook, side: Side) -> &[Level] {
+    match side {
+        Side::Buy => book.bids().levels(),
+        Side::Sell => book.asks().levels(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use rust_decimal_macros::dec;
+
+    fn book_event(event: OrderBookEvent) -> MarketEvent<Instrument, DataKind> {
+        let mut market = market_event_trade(Side::Buy);
+        market.kind = DataKind::OrderBook(event);
+        market
+    }
+
+    #[test]
+    fn test_market_depth() {
+        struct TestCase {
+            input: MarketEvent<Instrument, DataKind>,
+            expected_depth: Option<(f64, f64)>,
+            expected_imbalance: Option<f64>,
+            expected_vwap_buy: Option<f64>,
+        }
+
+        let instrument = market_event_trade(Side::Buy).instrument;
+        let mut depth = MarketDepth::new();
+
+        let tests = vec![
+            TestCase {
+                // TC0: update received before the first snapshot is ignored
+                input: book_event(OrderBookEvent::Update(OrderBook::new(
+                    1,
+                    None,
+                    vec![Level::new(dec!(99), dec!(1))],
+                    vec![Level::new(dec!(101), dec!(1))],
+                ))),
+                expected_depth: None,
+                expected_imbalance: None,
+                expected_vwap_buy: None,
+            },
+            TestCase {
+                // TC1: snapshot initialises the book
+                input: book_event(OrderBookEvent::Snapshot(OrderBook::new(
+                    2,
+                    None,
+                    vec![
+                        Level::new(dec!(100), dec!(3)),
+                        Level::new(dec!(99), dec!(3)),
+                    ],
+                    vec![
+                        Level::new(dec!(101), dec!(1)),
+                        Level::new(dec!(102), dec!(1)),
+                    ],
+                ))),
+                expected_depth: Some((6.0, 2.0)),
+                expected_imbalance: Some(0.5),
+                expected_vwap_buy: Some(101.5),
+            },
+            TestCase {
+                // TC2: update removes the best ask & adds depth to the next level
+                input: book_event(OrderBookEvent::Update(OrderBook::new(
+                    3,
+                    None,
+                    vec![],
+                    vec![
+                        Level::new(dec!(101), dec!(0)),
+                        Level::new(dec!(102), dec!(2)),
+                    ],
+                ))),
+                expected_depth: Some((6.0, 2.0)),
+                expected_imbalance: Some(0.5),
+                expected_vwap_buy: Some(102.0),
+            },
+            TestCase {
+                // TC3: update consumes bids, leaving insufficient ask depth for the vwap
+                input: book_event(OrderBookEvent::Update(OrderBook::new(
+                    4,
+                    None,
+                    vec![Level::new(dec!(99), dec!(0))],
+                    vec![Level::new(dec!(102), dec!(1))],
+                ))),
+                expected_depth: Some((3.0, 1.0)),
+                expected_imbalance: Some(0.5),
+                expected_vwap_buy: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            depth.update(&test.input);
+
+            let actual_depth = depth
+                .depth(ExchangeId::BinanceSpot, &instrument, Side::Buy, 5)
+                .zip(depth.depth(ExchangeId::BinanceSpot, &instrument, Side::Sell, 5));
+            assert_eq!(actual_depth, test.expected_depth, "TC{} failed", index);
+
+            let actual_imbalance = depth.imbalance(ExchangeId::BinanceSpot, &instrument, 5);
+            assert_eq!(
+                actual_imbalance, test.expected_imbalance,
+                "TC{} failed",
+                index
+            );
+
+            let actual_vwap_buy = depth.vwap(ExchangeId::BinanceSpot, &instrument, Side::Buy, 2.0);
+            assert_eq!(
+                actual_vwap_buy, test.expected_vwap_buy,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
@@ -0,0 +1,410 @@
+use super::{
+    pairs::{PairConfig, PairLeg, PairTracker},
+    MarketEventObserver,
+};
+use crate::event::Event;
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    subscription::book::OrderBookEvent,
+};
+use barter_instrument::{exchange::ExchangeId, instrument::Instrument};
+use barter_integration::Side;
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+
+/// Configuration for constructing [`RollingFeatures`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct FeatureConfig {
+    /// Number of most recent samples each rolling statistic is computed over.
+    pub window: usize,
+}
+
+/// Rolling statistical [`Features`] of a single exchange [`Instrument`]. Each feature is `None`
+/// until enough samples have been received to compute it.
+#[derive(Copy, Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct Features {
+    /// Population standard deviation of the per-sample log returns of the price (not annualised).
+    pub realised_volatility: Option<f64>,
+    /// Number of standard deviations the latest price is from the rolling mean price.
+    pub price_zscore: Option<f64>,
+    /// Number of standard deviations the latest best bid-ask spread is from the rolling mean
+    /// spread.
+    pub spread_zscore: Option<f64>,
+    /// `(buy volume - sell volume) / (buy volume + sell volume)` of the rolling trades, in the
+    /// range [-1, 1].
+    pub trade_flow_imbalance: Option<f64>,
+}
+
+/// Rolling window statistics of each exchange [`Instrument`] (realised volatility, price &
+/// spread z-scores, trade-flow imbalance), plus the rolling correlation between any two and the
+/// state of any registered [`PairTracker`]s.
+///
+/// Maintained by every [`Trader`](crate::engine::trader::Trader) it is provided to, so strategies
+/// can query it. Prices are sampled from trades, candle closes & best bid-ask mid prices, so
+/// instruments should be fed consistent [`DataKind`]s for their features to be comparable.
+#[derive(Clone, PartialEq, Debug)]
+pub struct RollingFeatures {
+    pub config: FeatureConfig,
+    instruments: HashMap<(ExchangeId, Instrument), InstrumentFeatures>,
+    pairs: Vec<PairTracker>,
+}
+
+impl RollingFeatures {
+    /// Construct a new empty [`RollingFeatures`] using the provided [`FeatureConfig`].
+    pub fn new(config: FeatureConfig) -> Self {
+        Self {
+            config,
+            instruments: HashMap::new(),
+            pairs: Vec::new(),
+        }
+    }
+
+    /// Register a [`PairTracker`] for the pair of legs, updated alongside the rolling statistics.
+    pub fn add_pair(&mut self, config: PairConfig) {
+        self.pairs.push(PairTracker::new(config));
+    }
+
+    /// [`PairTracker`] of the registered pair of legs.
+    pub fn pair(&self, a: &PairLeg, b: &PairLeg) -> Option<&PairTracker> {
+        self.pairs
+            .iter()
+            .find(|pair| pair.config.a == *a && pair.config.b == *b)
+    }
+
+    /// Update the rolling statistics of the [`MarketEvent`] exchange [`Instrument`].
+    pub fn update(&mut self, market: &MarketEvent<Instrument, DataKind>) {
+        for pair in &mut self.pairs {
+            pair.update(market);
+        }
+
+        let window = self.config.window;
+        let features = self
+            .instruments
+            .entry((market.exchange, market.instrument.clone()))
+            .or_insert_with(|| InstrumentFeatures::new(window));
+
+        match &market.kind {
+            DataKind::Trade(trade) => {
+                features.update_price(trade.price);
+                let signed_amount = match trade.side {
+                    Side::Buy => trade.amount,
+                    Side::Sell => -trade.amount,
+                };
+                features.signed_volume.push(signed_amount);
+                features.volume.push(trade.amount);
+            }
+            DataKind::Candle(candle) => features.update_price(candle.close),
+            kind => {
+                let Some((best_bid, best_ask)) = best_bid_ask(kind) else {
+                    return;
+                };
+                features.update_price((best_bid + best_ask) / 2.0);
+                features.spreads.push(best_ask - best_bid);
+            }
+        }
+    }
+
+    /// Latest rolling [`Features`] of the [`Instrument`] on the provided exchange.
+    pub fn features(&self, exchange: ExchangeId, instrument: &Instrument) -> Option<Features> {
+        let features = self.instruments.get(&(exchange, instrument.clone()))?;
+
+        let trade_flow_imbalance = match features.volume.sum {
+            volume if features.volume.is_full() && volume > 0.0 => {
+                Some(features.signed_volume.sum / volume)
+            }
+            _ => None,
+        };
+
+        Some(Features {
+            realised_volatility: features.returns.std_dev(),
+            price_zscore: features.prices.zscore(),
+            spread_zscore: features.spreads.zscore(),
+            trade_flow_imbalance,
+        })
+    }
+
+    /// Rolling Pearson correlation of the log returns of two exchange [`Instrument`]s.
+    ///
+    /// The most recent returns of each are paired by sample, so both instruments should be
+    /// sampled at the same frequency (eg/ candles of the same interval).
+    pub fn correlation(
+        &self,
+        a: (ExchangeId, &Instrument),
+        b: (ExchangeId, &Instrument),
+    ) -> Option<f64> {
+        let a = &self.instruments.get(&(a.0, a.1.clone()))?.returns;
+        let b = &self.instruments.get(&(b.0, b.1.clone()))?.returns;
+        if !a.is_full() || !b.is_full() {
+            return None;
+        }
+
+        let (mean_a, mean_b) = (a.mean()?, b.mean()?);
+        let (covariance, variance_a, variance_b) = a.values.iter().zip(&b.values).fold(
+            (0.0, 0.0, 0.0),
+            |(covariance, variance_a, variance_b), (a, b)| {
+                let (a, b) = (a - mean_a, b - mean_b);
+                (covariance + a * b, variance_a + a * a, variance_b + b * b)
+            },
+        );
+
+        let denominator = (variance_a * variance_b).sqrt();
+        (denominator > 0.0).then(|| covariance / denominator)
+    }
+}
+
+impl MarketEventObserver for RollingFeatures {
+    fn on_market(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Event> {
+        self.update(market);
+        None
+    }
+}
+
+/// Rolling windows of a single exchange [`Instrument`].
+#[derive(Clone, PartialEq, Debug)]
+struct InstrumentFeatures {
+    last_price: Option<f64>,
+    prices: Rolling,
+    returns: Rolling,
+    spreads: Rolling,
+    signed_volume: Rolling,
+    volume: Rolling,
+}
+
+impl InstrumentFeatures {
+    fn new(window: usize) -> Self {
+        Self {
+            last_price: None,
+            prices: Rolling::new(window),
+            returns: Rolling::new(window),
+            spreads: Rolling::new(window),
+            signed_volume: Rolling::new(window),
+            volume: Rolling::new(window),
+        }
+    }
+
+    fn update_price(&mut self, price: f64) {
+        if price <= 0.0 {
+            return;
+        }
+
+        if let Some(last_price) = self.last_price.replace(price) {
+            self.returns.push((price / last_price).ln());
+        }
+        self.prices.push(price);
+    }
+}
+
+/// Fixed-capacity window of the most recent values, maintaining their running sum & sum of
+/// squares.
+#[derive(Clone, PartialEq, Debug)]
+pub(super) struct Rolling {
+    capacity: usize,
+    pub(super) values: VecDeque<f64>,
+    sum: f64,
+    sum_squares: f64,
+}
+
+impl Rolling {
+    pub(super) fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(2);
+        Self {
+            capacity,
+            values: VecDeque::with_capacity(capacity),
+            sum: 0.0,
+            sum_squares: 0.0,
+        }
+    }
+
+    pub(super) fn push(&mut self, value: f64) {
+        if self.values.len() == self.capacity {
+            if let Some(evicted) = self.values.pop_front() {
+                self.sum -= evicted;
+                self.sum_squares -= evicted.powi(2);
+            }
+        }
+        self.values.push_back(value);
+        self.sum += value;
+        self.sum_squares += value.powi(2);
+    }
+
+    pub(super) fn is_full(&self) -> bool {
+        self.values.len() == self.capacity
+    }
+
+    pub(super) fn mean(&self) -> Option<f64> {
+        self.is_full().then(|| self.sum / self.capacity as f64)
+    }
+
+    pub(super) fn std_dev(&self) -> Option<f64> {
+        let mean = self.mean()?;
+        Some(
+            (self.sum_squares / self.capacity as f64 - mean.powi(2))
+                .max(0.0)
+                .sqrt(),
+        )
+    }
+
+    /// Z-score of the latest value, or zero if the window has no deviation.
+    pub(super) fn zscore(&self) -> Option<f64> {
+        let (mean, std_dev) = (self.mean()?, self.std_dev()?);
+        let latest = self.values.back()?;
+        Some(if std_dev > f64::EPSILON {
+            (latest - mean) / std_dev
+        } else {
+            0.0
+        })
+    }
+}
+
+/// Price sampled from the [`DataKind`]: the trade price, candle close or best bid-ask mid price.
+pub(crate) fn market_price(kind: &DataKind) -> Option<f64> {
+    match kind {
+        DataKind::Trade(trade) => Some(trade.price),
+        DataKind::Candle(candle) => Some(candle.close),
+        kind => best_bid_ask(kind).map(|(best_bid, best_ask)| (best_bid + best_ask) / 2.0),
+    }
+}
+
+fn best_bid_ask(kind: &DataKind) -> Option<(f64, f64)> {
+    let (best_bid, best_ask) = match kind {
+        DataKind::OrderBookL1(book) => (book.best_bid, book.best_ask),
+        DataKind::OrderBook(OrderBookEvent::Snapshot(book)) => (
+            *book.bids().levels().first()?,
+            *book.asks().levels().first()?,
+        ),
+        _ => return None,
+    };
+    Some((best_bid.price.to_f64()?, best_ask.price.to_f64()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use barter_data::subscription::{candle::Candle, trade::PublicTrade};
+
+    fn assert_approx(actual: Option<f64>, expected: Option<f64>, index: usize) {
+        match (actual, expected) {
+            (Some(actual), Some(expected)) => assert!(
+                (actual - expected).abs() < 1e-9,
+                "TC{index} failed: actual {actual} != expected {expected}"
+            ),
+            (actual, expected) => assert_eq!(actual, expected, "TC{} failed", index),
+        }
+    }
+
+    fn trade(
+        exchange: ExchangeId,
+        price: f64,
+        amount: f64,
+        side: Side,
+    ) -> MarketEvent<Instrument, DataKind> {
+        let mut market = market_event_trade(side);
+        market.exchange = exchange;
+        market.kind = DataKind::Trade(PublicTrade {
+            id: "id".to_string(),
+            price,
+            amount,
+            side,
+            aggregate_count: None,
+        });
+        market
+    }
+
+    fn candle(exchange: ExchangeId, close: f64) -> MarketEvent<Instrument, DataKind> {
+        let mut market = market_event_trade(Side::Buy);
+        market.exchange = exchange;
+        market.kind = DataKind::Candle(Candle {
+            close_time: market.time_exchange,
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: 1.0,
+            trade_count: 1,
+        });
+        market
+    }
+
+    #[test]
+    fn test_rolling_features() {
+        struct TestCase {
+            input: MarketEvent<Instrument, DataKind>,
+            expected_volatility: Option<f64>,
+            expected_price_zscore: Option<f64>,
+            expected_imbalance: Option<f64>,
+            expected_correlation: Option<f64>,
+        }
+
+        let (a, b) = (ExchangeId::BinanceSpot, ExchangeId::Okx);
+        let instrument = market_event_trade(Side::Buy).instrument;
+        let mut features = RollingFeatures::new(FeatureConfig { window: 2 });
+
+        let tests = vec![
+            TestCase {
+                // TC0: warming up
+                input: trade(a, 100.0, 3.0, Side::Buy),
+                expected_volatility: None,
+                expected_price_zscore: None,
+                expected_imbalance: None,
+                expected_correlation: None,
+            },
+            TestCase {
+                // TC1: price window full, single return
+                input: trade(a, 110.0, 1.0, Side::Sell),
+                expected_volatility: None,
+                expected_price_zscore: Some(1.0),
+                expected_imbalance: Some((3.0 - 1.0) / 4.0),
+                expected_correlation: None,
+            },
+            TestCase {
+                // TC2: returns window full, oldest trade evicted
+                input: trade(a, 99.0, 1.0, Side::Sell),
+                expected_volatility: Some(((110.0f64 / 100.0).ln() - (99.0f64 / 110.0).ln()) / 2.0),
+                expected_price_zscore: Some(-1.0),
+                expected_imbalance: Some(-1.0),
+                expected_correlation: None,
+            },
+            TestCase {
+                // TC3: b warming up
+                input: candle(b, 50.0),
+                expected_volatility: Some(((110.0f64 / 100.0).ln() - (99.0f64 / 110.0).ln()) / 2.0),
+                expected_price_zscore: Some(-1.0),
+                expected_imbalance: Some(-1.0),
+                expected_correlation: None,
+            },
+            TestCase {
+                // TC4: b warming up
+                input: candle(b, 60.0),
+                expected_volatility: Some(((110.0f64 / 100.0).ln() - (99.0f64 / 110.0).ln()) / 2.0),
+                expected_price_zscore: Some(-1.0),
+                expected_imbalance: Some(-1.0),
+                expected_correlation: None,
+            },
+            TestCase {
+                // TC5: b returns move with a returns (up then down)
+                input: candle(b, 55.0),
+                expected_volatility: Some(((110.0f64 / 100.0).ln() - (99.0f64 / 110.0).ln()) / 2.0),
+                expected_price_zscore: Some(-1.0),
+                expected_imbalance: Some(-1.0),
+                expected_correlation: Some(1.0),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            features.update(&test.input);
+
+            let actual = features.features(a, &instrument).unwrap();
+            assert_approx(actual.realised_volatility, test.expected_volatility, index);
+            assert_approx(actual.price_zscore, test.expected_price_zscore, index);
+            assert_approx(actual.trade_flow_imbalance, test.expected_imbalance, index);
+            assert_approx(actual.spread_zscore, None, index);
+            assert_approx(
+                features.correlation((a, &instrument), (b, &instrument)),
+                test.expected_correlation,
+                index,
+            );
+        }
+    }
+}
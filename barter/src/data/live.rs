@@ -1,4 +1,4 @@
-use super::{Feed, MarketGenerator};
+use super::{Conflate, Feed, MarketGenerator, QueueStats};
 use barter_data::{
     event::{DataKind, MarketEvent},
     streams::{consumer::MarketStreamEvent, reconnect},
@@ -8,16 +8,50 @@ use futures::{
     executor::{block_on_stream, BlockingStream},
     Stream,
 };
+use serde::{Deserialize, Serialize};
+use std::collections::{HashSet, VecDeque};
 use tokio::sync::mpsc;
+use tracing::{info, warn};
+
+/// Determines how a queue backed [`MarketFeed`] behaves when it falls behind the market (eg/
+/// during bursty market conditions).
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+pub enum OverloadPolicy {
+    /// Yield every queued event, however far behind the [`MarketFeed`] falls.
+    #[default]
+    Process,
+    /// When more than `max_depth` events are queued, drain the queue and drop every event that
+    /// is superseded by a later queued event with the same [`Conflate`] key (eg/ stale L1 books).
+    ///
+    /// Incremental order book updates are dropped along with the snapshot they apply to when a
+    /// later snapshot of the same order book is queued. Events that can never be dropped (eg/
+    /// trades & candles) are always yielded, in order.
+    Conflate { max_depth: usize },
+}
 
 /// Live [`Feed`] of market events.
 #[derive(Debug)]
 pub struct MarketFeed<Event> {
     pub market_rx: mpsc::UnboundedReceiver<Event>,
+    overload: OverloadPolicy,
+    overloaded: bool,
+    conflated: u64,
+    pending: VecDeque<Event>,
 }
 
-impl<Event> MarketGenerator<Event> for MarketFeed<Event> {
+impl<Event> MarketGenerator<Event> for MarketFeed<Event>
+where
+    Event: Conflate,
+{
     fn next(&mut self) -> Feed<Event> {
+        if let OverloadPolicy::Conflate { max_depth } = self.overload {
+            self.conflate(max_depth);
+        }
+
+        if let Some(event) = self.pending.pop_front() {
+            return Feed::Next(event);
+        }
+
         loop {
             match self.market_rx.try_recv() {
                 Ok(event) => break Feed::Next(event),
@@ -26,6 +60,13 @@ impl<Event> MarketGenerator<Event> for MarketFeed<Event> {
             }
         }
     }
+
+    fn queue_stats(&self) -> Option<QueueStats> {
+        Some(QueueStats {
+            depth: self.pending.len() + self.market_rx.len(),
+            conflated: self.conflated,
+        })
+    }
 }
 
 impl<Event> MarketFeed<Event> {
@@ -40,7 +81,75 @@ impl<Event> MarketFeed<Event> {
     ///     [`mpsc::UnboundedReceiver`] streams into a unified [`mpsc::UnboundedReceiver`].
     ///  3. Construct [`Self`] with the unified [`mpsc::UnboundedReceiver`].
     pub fn new(market_rx: mpsc::UnboundedReceiver<Event>) -> Self {
-        Self { market_rx }
+        Self {
+            market_rx,
+            overload: OverloadPolicy::default(),
+            overloaded: false,
+            conflated: 0,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Use the provided [`OverloadPolicy`] when the [`MarketFeed`] falls behind.
+    pub fn with_overload_policy(self, overload: OverloadPolicy) -> Self {
+        Self { overload, ..self }
+    }
+}
+
+impl<Event> MarketFeed<Event>
+where
+    Event: Conflate,
+{
+    /// Conflate the queued events if more than `max_depth` are queued & there are no pending
+    /// conflated events left to yield.
+    fn conflate(&mut self, max_depth: usize) {
+        if !self.pending.is_empty() {
+            return;
+        }
+
+        if self.market_rx.len() <= max_depth {
+            if self.overloaded {
+                self.overloaded = false;
+                info!(
+                    conflated = self.conflated,
+                    "MarketFeed recovered from overload"
+                );
+            }
+            return;
+        }
+
+        if !self.overloaded {
+            self.overloaded = true;
+            warn!(
+                queue_depth = self.market_rx.len(),
+                max_depth, "MarketFeed overloaded, conflating market events"
+            );
+        }
+
+        let mut queued = Vec::with_capacity(self.market_rx.len());
+        while let Ok(event) = self.market_rx.try_recv() {
+            queued.push(event);
+        }
+
+        // Retain the latest event of each conflation key, and every event without a key that is
+        // not superseded by a later queued event
+        let queued_len = queued.len();
+        let mut latest = HashSet::with_capacity(queued.len());
+        let mut retained = queued
+            .into_iter()
+            .rev()
+            .filter(|event| match event.conflation_key() {
+                Some(key) => latest.insert(key),
+                None => event
+                    .superseded_by()
+                    .is_none_or(|key| !latest.contains(&key)),
+            })
+            .collect::<Vec<_>>();
+
+        self.conflated += (queued_len - retained.len()) as u64;
+
+        retained.reverse();
+        self.pending.extend(retained);
     }
 }
 
@@ -85,3 +194,155 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{market_event_candle, market_event_trade};
+    use barter_data::{
+        books::{Level, OrderBook},
+        subscription::book::{OrderBookEvent, OrderBookL1},
+    };
+    use barter_instrument::instrument::kind::InstrumentKind;
+    use barter_integration::Side;
+
+    #[test]
+    fn test_market_feed_conflate() {
+        fn l1(price: i64) -> MarketEvent<Instrument, DataKind> {
+            let mut market = market_event_trade(Side::Buy);
+            market.kind = DataKind::OrderBookL1(OrderBookL1 {
+                last_update_time: market.time_exchange,
+                best_bid: Level::new(price, 1),
+                best_ask: Level::new(price + 1, 1),
+            });
+            market
+        }
+
+        let (market_tx, market_rx) = mpsc::unbounded_channel();
+        let mut feed = MarketFeed::new(market_rx)
+            .with_overload_policy(OverloadPolicy::Conflate { max_depth: 2 });
+
+        // Burst of 5 events exceeding the max_depth
+        let burst = vec![
+            l1(1),
+            market_event_trade(Side::Buy),
+            l1(2),
+            market_event_candle(),
+            l1(3),
+        ];
+        for market in burst {
+            market_tx.send(market).unwrap();
+        }
+        drop(market_tx);
+
+        // Stale L1 books are dropped, the trade, candle & latest L1 book are yielded in order
+        let expected = vec![
+            market_event_trade(Side::Buy).kind,
+            market_event_candle().kind,
+            l1(3).kind,
+        ];
+
+        for (index, expected) in expected.into_iter().enumerate() {
+            let Feed::Next(market) = feed.next() else {
+                panic!("TC{index} failed: expected Feed::Next")
+            };
+            assert_eq!(
+                std::mem::discriminant(&market.kind),
+                std::mem::discriminant(&expected),
+                "TC{index} failed"
+            );
+            match (market.kind, expected) {
+                (DataKind::Trade(actual), DataKind::Trade(expected)) => {
+                    assert_eq!(actual.side, expected.side, "TC{index} failed")
+                }
+                (DataKind::Candle(actual), DataKind::Candle(expected)) => {
+                    assert_eq!(actual.close, expected.close, "TC{index} failed")
+                }
+                (DataKind::OrderBookL1(actual), DataKind::OrderBookL1(expected)) => {
+                    assert_eq!(actual.best_bid, expected.best_bid, "TC{index} failed")
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        assert_eq!(
+            feed.queue_stats(),
+            Some(QueueStats {
+                depth: 0,
+                conflated: 2
+            })
+        );
+        assert_eq!(feed.next(), Feed::Finished);
+    }
+
+    #[test]
+    fn test_market_feed_conflate_drops_updates_superseded_by_snapshot() {
+        fn book(
+            base: &str,
+            event: fn(OrderBook) -> OrderBookEvent,
+            sequence: u64,
+        ) -> MarketEvent<Instrument, DataKind> {
+            let mut market = market_event_trade(Side::Buy);
+            market.instrument = Instrument::from((base, "usdt", InstrumentKind::Spot));
+            market.kind = DataKind::OrderBook(event(OrderBook::new(
+                sequence,
+                None,
+                vec![Level::new(100, 1)],
+                vec![Level::new(101, 1)],
+            )));
+            market
+        }
+
+        let (market_tx, market_rx) = mpsc::unbounded_channel();
+        let mut feed = MarketFeed::new(market_rx)
+            .with_overload_policy(OverloadPolicy::Conflate { max_depth: 2 });
+
+        // Burst interleaving [S1, U1, S2] for btc_usdt, with a trade & an eth_usdt update that
+        // a btc_usdt snapshot does not supersede
+        let burst = vec![
+            book("btc", OrderBookEvent::Snapshot, 1),
+            book("btc", OrderBookEvent::Update, 2),
+            market_event_trade(Side::Buy),
+            book("eth", OrderBookEvent::Update, 3),
+            book("btc", OrderBookEvent::Snapshot, 4),
+            book("btc", OrderBookEvent::Update, 5),
+        ];
+        for market in burst {
+            market_tx.send(market).unwrap();
+        }
+        drop(market_tx);
+
+        // S1 & the U1 applied on top of it are both superseded by S2
+        let expected = vec![
+            market_event_trade(Side::Buy),
+            book("eth", OrderBookEvent::Update, 3),
+            book("btc", OrderBookEvent::Snapshot, 4),
+            book("btc", OrderBookEvent::Update, 5),
+        ];
+
+        for (index, expected) in expected.into_iter().enumerate() {
+            let Feed::Next(market) = feed.next() else {
+                panic!("TC{index} failed: expected Feed::Next")
+            };
+            assert_eq!(market.instrument, expected.instrument, "TC{index} failed");
+            match (market.kind, expected.kind) {
+                (DataKind::Trade(actual), DataKind::Trade(expected)) => {
+                    assert_eq!(actual.side, expected.side, "TC{index} failed")
+                }
+                (DataKind::OrderBook(actual), DataKind::OrderBook(expected)) => {
+                    assert_eq!(actual, expected, "TC{index} failed")
+                }
+                _ => panic!("TC{index} failed: unexpected DataKind"),
+            }
+        }
+
+        assert_eq!(
+            feed.queue_stats(),
+            Some(QueueStats {
+                depth: 0,
+                conflated: 2
+            })
+        );
+        assert_eq!(feed.next(), Feed::Finished);
+    }
+}
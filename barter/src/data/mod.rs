@@ -1,5 +1,15 @@
+use crate::{event, execution::FillEvent};
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    subscription::book::OrderBookEvent,
+};
+use barter_instrument::{exchange::ExchangeId, instrument::Instrument};
 use chrono::{DateTime, Utc};
+use parking_lot::{Mutex, RwLock};
+use rust_decimal::Decimal;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::{fmt::Debug, hash::Hash, mem::Discriminant, sync::Arc};
 
 /// Barter data module specific errors.
 pub mod error;
@@ -10,10 +20,143 @@ pub mod live;
 /// Historical market event feed for backtesting.
 pub mod historical;
 
+/// Bounded per-instrument cache of recent market events for strategy lookback windows.
+pub mod cache;
+
+/// Data-sanity guard that flags & optionally quarantines market events implying implausible moves.
+pub mod sanity;
+
+/// Universe selection that periodically screens instruments (eg/ top N by volume, spread below X
+/// bps) to rotate the traded universe.
+pub mod universe;
+
+/// Multi-venue best bid & ask view of instruments listed on multiple exchanges, used to compute
+/// cross-venue spreads for arbitrage strategies.
+pub mod venue;
+
+/// Rolling statistical features of each instrument (eg/ realised volatility, z-scores, trade-flow
+/// imbalance & correlation) for strategies to query.
+pub mod features;
+
+/// Full depth L2 order books of each instrument, for strategies to query depth, imbalance & VWAP.
+pub mod depth;
+
+/// Pairs trading utilities (rolling OLS & Kalman hedge ratios, spread z-scores & half-life
+/// estimation) for statistical arbitrage strategies.
+pub mod pairs;
+
 /// Generates the next `Event`. Acts as the system heartbeat.
 pub trait MarketGenerator<Event> {
     /// Return the next market `Event`.
     fn next(&mut self) -> Feed<Event>;
+
+    /// Return the [`QueueStats`] of a queue backed [`MarketGenerator`] (eg/ a live feed), or
+    /// `None` if events are not queued.
+    fn queue_stats(&self) -> Option<QueueStats> {
+        None
+    }
+}
+
+/// Observes every `MarketEvent` & `FillEvent` handled by a
+/// [`Trader`](crate::engine::trader::Trader), eg/ to maintain market state shared by every
+/// [`Trader`](crate::engine::trader::Trader) & the Strategy via an `Arc<RwLock<_>>`.
+pub trait MarketEventObserver: Debug + Send {
+    /// Update from the [`MarketEvent`], returning an [`Event`](event::Event) to send if the
+    /// update is notable (eg/ the selected universe changed).
+    fn on_market(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<event::Event>;
+
+    /// Update from the [`FillEvent`]. Ignored by default.
+    fn on_fill(&mut self, _fill: &FillEvent) {}
+}
+
+impl<Observer> MarketEventObserver for Arc<RwLock<Observer>>
+where
+    Observer: MarketEventObserver + Sync,
+{
+    fn on_market(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<event::Event> {
+        self.write().on_market(market)
+    }
+
+    fn on_fill(&mut self, fill: &FillEvent) {
+        self.write().on_fill(fill)
+    }
+}
+
+impl<Observer> MarketEventObserver for Arc<Mutex<Observer>>
+where
+    Observer: MarketEventObserver,
+{
+    fn on_market(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<event::Event> {
+        self.lock().on_market(market)
+    }
+
+    fn on_fill(&mut self, fill: &FillEvent) {
+        self.lock().on_fill(fill)
+    }
+}
+
+/// Queue statistics of a queue backed [`MarketGenerator`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+pub struct QueueStats {
+    /// Number of events queued but not yet yielded.
+    pub depth: usize,
+    /// Total number of events dropped by conflation.
+    pub conflated: u64,
+}
+
+/// Market events that can be conflated when a feed is overloaded, where an event is superseded
+/// by any later event with the same conflation key.
+pub trait Conflate {
+    type Key: Eq + Hash;
+
+    /// Key identifying the state this event replaces, or `None` if the event never supersedes
+    /// another (eg/ trades, candles & incremental order book updates).
+    fn conflation_key(&self) -> Option<Self::Key>;
+
+    /// Key of a later event that renders this event stale, even though this event has no
+    /// [`Conflate::conflation_key`] of its own (eg/ an incremental order book update that is
+    /// superseded by a later order book snapshot). Defaults to `None`, so the event is never
+    /// dropped.
+    fn superseded_by(&self) -> Option<Self::Key> {
+        None
+    }
+}
+
+impl<InstrumentKey> Conflate for MarketEvent<InstrumentKey, DataKind>
+where
+    InstrumentKey: Clone + Eq + Hash,
+{
+    type Key = (ExchangeId, InstrumentKey, Discriminant<DataKind>);
+
+    fn conflation_key(&self) -> Option<Self::Key> {
+        match &self.kind {
+            // Only full state snapshots are superseded by a later event of the same kind
+            DataKind::OrderBookL1(_) | DataKind::OrderBook(OrderBookEvent::Snapshot(_)) => Some((
+                self.exchange,
+                self.instrument.clone(),
+                std::mem::discriminant(&self.kind),
+            )),
+            // Each candle summarises a distinct interval, so is never superseded
+            DataKind::Trade(_)
+            | DataKind::Candle(_)
+            | DataKind::OrderBook(OrderBookEvent::Update(_))
+            | DataKind::Liquidation(_)
+            | DataKind::Custom(_) => None,
+        }
+    }
+
+    fn superseded_by(&self) -> Option<Self::Key> {
+        match &self.kind {
+            // Incremental updates are applied on top of a snapshot, so a later snapshot of the
+            // same order book replaces them too
+            DataKind::OrderBook(OrderBookEvent::Update(_)) => Some((
+                self.exchange,
+                self.instrument.clone(),
+                std::mem::discriminant(&self.kind),
+            )),
+            _ => None,
+        }
+    }
 }
 
 /// Communicates the state of the [`Feed`] as well as the next event.
@@ -27,10 +170,12 @@ pub enum Feed<Event> {
 /// Metadata detailing the [`Candle`](barter_data::subscription::candle::Candle) or
 /// [`Trade`](barter_data::subscription::trade::PublicTrade) close price & it's associated
 /// timestamp. Used to propagate key market information in downstream Events.
-#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize, JsonSchema)]
 pub struct MarketMeta {
     /// Close value from the source market event.
-    pub close: f64,
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
+    pub close: Decimal,
     /// Exchange timestamp from the source market event.
     pub time: DateTime<Utc>,
 }
@@ -38,8 +183,81 @@ pub struct MarketMeta {
 impl Default for MarketMeta {
     fn default() -> Self {
         Self {
-            close: 100.0,
+            close: Decimal::ONE_HUNDRED,
             time: Utc::now(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{market_event_candle, market_event_trade};
+    use barter_data::{
+        books::{Level, OrderBook},
+        subscription::{book::OrderBookL1, liquidation::Liquidation},
+    };
+    use barter_integration::Side;
+
+    #[test]
+    fn test_market_event_conflation_key() {
+        struct TestCase {
+            kind: DataKind,
+            expected_keyed: bool,
+        }
+
+        let book = OrderBook::new(0, None, vec![Level::new(100, 1)], vec![Level::new(101, 1)]);
+
+        let cases = vec![
+            // TC0: L1 book snapshot is keyed
+            TestCase {
+                kind: DataKind::OrderBookL1(OrderBookL1 {
+                    last_update_time: Utc::now(),
+                    best_bid: Level::new(100, 1),
+                    best_ask: Level::new(101, 1),
+                }),
+                expected_keyed: true,
+            },
+            // TC1: full book snapshot is keyed
+            TestCase {
+                kind: DataKind::OrderBook(OrderBookEvent::Snapshot(book.clone())),
+                expected_keyed: true,
+            },
+            // TC2: incremental book update is never dropped
+            TestCase {
+                kind: DataKind::OrderBook(OrderBookEvent::Update(book)),
+                expected_keyed: false,
+            },
+            // TC3: trade is never dropped
+            TestCase {
+                kind: market_event_trade(Side::Buy).kind,
+                expected_keyed: false,
+            },
+            // TC4: candle is never dropped
+            TestCase {
+                kind: market_event_candle().kind,
+                expected_keyed: false,
+            },
+            // TC5: liquidation is never dropped
+            TestCase {
+                kind: DataKind::Liquidation(Liquidation {
+                    side: Side::Sell,
+                    price: 100.0,
+                    quantity: 1.0,
+                    time: Utc::now(),
+                }),
+                expected_keyed: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut market = market_event_trade(Side::Buy);
+            market.kind = test.kind;
+            assert_eq!(
+                market.conflation_key().is_some(),
+                test.expected_keyed,
+                "TC{index} failed"
+            );
+        }
+    }
+}
@@ -0,0 +1,434 @@
+use super::features::{market_price, Rolling};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_instrument::{exchange::ExchangeId, instrument::Instrument};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Exchange [`Instrument`] forming one leg of a [`PairTracker`] pair.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct PairLeg {
+    pub exchange: ExchangeId,
+    pub instrument: Instrument,
+}
+
+impl PairLeg {
+    fn matches(&self, market: &MarketEvent<Instrument, DataKind>) -> bool {
+        self.exchange == market.exchange && self.instrument == market.instrument
+    }
+}
+
+/// Method used to estimate the [`HedgeRatio`] of a pair.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum HedgeMethod {
+    /// Ordinary least squares regression over the last `window` samples. See [`RollingOls`].
+    Ols { window: usize },
+    /// Kalman filter with a random walk hedge ratio. See [`KalmanHedge`].
+    Kalman {
+        delta: f64,
+        observation_variance: f64,
+    },
+}
+
+/// Configuration for constructing a [`PairTracker`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct PairConfig {
+    /// Dependent leg, whose price is regressed on the price of leg `b`.
+    pub a: PairLeg,
+    /// Independent (hedge) leg.
+    pub b: PairLeg,
+    pub method: HedgeMethod,
+    /// Number of most recent spreads used to compute the spread z-score & half-life.
+    pub spread_window: usize,
+}
+
+/// Hedge ratio of a pair, where `price_a ≈ beta * price_b + alpha`.
+#[derive(Copy, Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct HedgeRatio {
+    pub beta: f64,
+    pub alpha: f64,
+}
+
+/// Latest state of a pair tracked by a [`PairTracker`].
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct PairState {
+    pub hedge_ratio: HedgeRatio,
+    /// `price_a - beta * price_b - alpha`.
+    pub spread: f64,
+    /// Number of standard deviations the spread is from it's rolling mean, once the spread window
+    /// is full.
+    pub spread_zscore: Option<f64>,
+    /// Estimated number of samples for the spread to revert half way to it's mean, if the rolling
+    /// spreads are mean reverting.
+    pub half_life: Option<f64>,
+}
+
+/// Rolling ordinary least squares estimate of the [`HedgeRatio`] over the last `window` samples.
+#[derive(Clone, PartialEq, Debug)]
+pub struct RollingOls {
+    window: usize,
+    samples: VecDeque<(f64, f64)>,
+    sum_a: f64,
+    sum_b: f64,
+    sum_ab: f64,
+    sum_bb: f64,
+}
+
+impl RollingOls {
+    /// Construct a new [`RollingOls`] over the provided window.
+    pub fn new(window: usize) -> Self {
+        let window = window.max(2);
+        Self {
+            window,
+            samples: VecDeque::with_capacity(window),
+            sum_a: 0.0,
+            sum_b: 0.0,
+            sum_ab: 0.0,
+            sum_bb: 0.0,
+        }
+    }
+
+    /// Update with the latest pair of prices, returning the [`HedgeRatio`] once the window is
+    /// full & the price of leg `b` has varied.
+    pub fn update(&mut self, price_a: f64, price_b: f64) -> Option<HedgeRatio> {
+        if self.samples.len() == self.window {
+            if let Some((a, b)) = self.samples.pop_front() {
+                self.accumulate(a, b, -1.0);
+            }
+        }
+        self.samples.push_back((price_a, price_b));
+        self.accumulate(price_a, price_b, 1.0);
+
+        if self.samples.len() < self.window {
+            return None;
+        }
+
+        let n = self.window as f64;
+        let denominator = n * self.sum_bb - self.sum_b.powi(2);
+        if denominator.abs() <= f64::EPSILON {
+            return None;
+        }
+
+        let beta = (n * self.sum_ab - self.sum_a * self.sum_b) / denominator;
+        Some(HedgeRatio {
+            beta,
+            alpha: (self.sum_a - beta * self.sum_b) / n,
+        })
+    }
+
+    fn accumulate(&mut self, a: f64, b: f64, sign: f64) {
+        self.sum_a += sign * a;
+        self.sum_b += sign * b;
+        self.sum_ab += sign * a * b;
+        self.sum_bb += sign * b * b;
+    }
+}
+
+/// Kalman filter estimate of the [`HedgeRatio`], modelling `beta` & `alpha` as random walks.
+///
+/// `delta` controls how quickly the hedge ratio adapts (eg/ 1e-4), and `observation_variance`
+/// is the variance of the price of leg `a` around the regression line (eg/ 1e-3).
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct KalmanHedge {
+    /// Variance of the random walk of each state per sample.
+    process_variance: f64,
+    observation_variance: f64,
+    /// State `[beta, alpha]`.
+    state: [f64; 2],
+    covariance: [[f64; 2]; 2],
+}
+
+impl KalmanHedge {
+    /// Construct a new [`KalmanHedge`] using the provided `delta` & `observation_variance`.
+    pub fn new(delta: f64, observation_variance: f64) -> Self {
+        Self {
+            process_variance: delta / (1.0 - delta),
+            observation_variance,
+            state: [0.0, 0.0],
+            covariance: [[0.0; 2]; 2],
+        }
+    }
+
+    /// Update with the latest pair of prices, returning the posterior [`HedgeRatio`].
+    pub fn update(&mut self, price_a: f64, price_b: f64) -> HedgeRatio {
+        let observation = [price_b, 1.0];
+
+        // Predict: state is a random walk, so only the covariance grows
+        let mut prior = self.covariance;
+        prior[0][0] += self.process_variance;
+        prior[1][1] += self.process_variance;
+
+        // Update: gain = prior * x / (x' * prior * x + observation_variance)
+        let prior_x = [
+            prior[0][0] * observation[0] + prior[0][1] * observation[1],
+            prior[1][0] * observation[0] + prior[1][1] * observation[1],
+        ];
+        let innovation_variance =
+            observation[0] * prior_x[0] + observation[1] * prior_x[1] + self.observation_variance;
+        let gain = [
+            prior_x[0] / innovation_variance,
+            prior_x[1] / innovation_variance,
+        ];
+
+        let error = price_a - (self.state[0] * observation[0] + self.state[1] * observation[1]);
+        self.state[0] += gain[0] * error;
+        self.state[1] += gain[1] * error;
+
+        // Posterior covariance: prior - gain * x' * prior (prior is symmetric)
+        for (row, gain) in gain.iter().enumerate() {
+            for column in 0..2 {
+                self.covariance[row][column] = prior[row][column] - gain * prior_x[column];
+            }
+        }
+
+        HedgeRatio {
+            beta: self.state[0],
+            alpha: self.state[1],
+        }
+    }
+}
+
+/// Estimate the half-life of mean reversion of the provided spreads, in samples, by regressing
+/// each spread change on the previous spread (ie/ fitting an AR(1) process).
+///
+/// Returns `None` if there are fewer than three spreads, or they are not mean reverting.
+pub fn half_life<'a, Iter>(spreads: Iter) -> Option<f64>
+where
+    Iter: IntoIterator<Item = &'a f64>,
+{
+    let mut spreads = spreads.into_iter();
+    let mut previous = *spreads.next()?;
+
+    let (mut n, mut sum_x, mut sum_y, mut sum_xy, mut sum_xx) = (0.0, 0.0, 0.0, 0.0, 0.0);
+    for spread in spreads {
+        let change = spread - previous;
+        n += 1.0;
+        sum_x += previous;
+        sum_y += change;
+        sum_xy += previous * change;
+        sum_xx += previous * previous;
+        previous = *spread;
+    }
+
+    let denominator = n * sum_xx - sum_x * sum_x;
+    if n < 2.0 || denominator.abs() <= f64::EPSILON {
+        return None;
+    }
+
+    let persistence = 1.0 + (n * sum_xy - sum_x * sum_y) / denominator;
+    (persistence > 0.0 && persistence < 1.0).then(|| -std::f64::consts::LN_2 / persistence.ln())
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum Estimator {
+    Ols(RollingOls),
+    Kalman(KalmanHedge),
+}
+
+/// Tracks the [`HedgeRatio`], spread, spread z-score & half-life of a pair of exchange
+/// [`Instrument`]s for statistical arbitrage strategies.
+///
+/// A sample is taken each time both legs have received a new price, so the legs should be fed
+/// the same [`DataKind`] at the same frequency (eg/ candles of the same interval). Usually
+/// registered with [`RollingFeatures::add_pair`](super::features::RollingFeatures::add_pair).
+#[derive(Clone, PartialEq, Debug)]
+pub struct PairTracker {
+    pub config: PairConfig,
+    estimator: Estimator,
+    pending_a: Option<f64>,
+    pending_b: Option<f64>,
+    spreads: Rolling,
+    state: Option<PairState>,
+}
+
+impl PairTracker {
+    /// Construct a new [`PairTracker`] using the provided [`PairConfig`].
+    pub fn new(config: PairConfig) -> Self {
+        let estimator = match config.method {
+            HedgeMethod::Ols { window } => Estimator::Ols(RollingOls::new(window)),
+            HedgeMethod::Kalman {
+                delta,
+                observation_variance,
+            } => Estimator::Kalman(KalmanHedge::new(delta, observation_variance)),
+        };
+
+        Self {
+            spreads: Rolling::new(config.spread_window),
+            config,
+            estimator,
+            pending_a: None,
+            pending_b: None,
+            state: None,
+        }
+    }
+
+    /// Update with the [`MarketEvent`] if it is for either leg, sampling the pair once both legs
+    /// have a new price.
+    pub fn update(&mut self, market: &MarketEvent<Instrument, DataKind>) {
+        let pending = if self.config.a.matches(market) {
+            &mut self.pending_a
+        } else if self.config.b.matches(market) {
+            &mut self.pending_b
+        } else {
+            return;
+        };
+
+        let Some(price) = market_price(&market.kind) else {
+            return;
+        };
+        *pending = Some(price);
+
+        if let (Some(price_a), Some(price_b)) = (self.pending_a, self.pending_b) {
+            self.pending_a = None;
+            self.pending_b = None;
+            self.sample(price_a, price_b);
+        }
+    }
+
+    /// Latest [`PairState`], once a [`HedgeRatio`] has been estimated.
+    pub fn state(&self) -> Option<&PairState> {
+        self.state.as_ref()
+    }
+
+    fn sample(&mut self, price_a: f64, price_b: f64) {
+        let hedge_ratio = match &mut self.estimator {
+            Estimator::Ols(ols) => ols.update(price_a, price_b),
+            Estimator::Kalman(kalman) => Some(kalman.update(price_a, price_b)),
+        };
+        let Some(hedge_ratio) = hedge_ratio else {
+            return;
+        };
+
+        let spread = price_a - hedge_ratio.beta * price_b - hedge_ratio.alpha;
+        self.spreads.push(spread);
+
+        self.state = Some(PairState {
+            hedge_ratio,
+            spread,
+            spread_zscore: self.spreads.zscore(),
+            half_life: half_life(&self.spreads.values),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::features::{FeatureConfig, RollingFeatures},
+        test_util::market_event_trade,
+    };
+    use barter_data::subscription::candle::Candle;
+    use barter_integration::Side;
+
+    fn candle(leg: &PairLeg, close: f64) -> MarketEvent<Instrument, DataKind> {
+        let mut market = market_event_trade(Side::Buy);
+        market.exchange = leg.exchange;
+        market.instrument = leg.instrument.clone();
+        market.kind = DataKind::Candle(Candle {
+            close_time: market.time_exchange,
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: 1.0,
+            trade_count: 1,
+        });
+        market
+    }
+
+    #[test]
+    fn test_pair_tracker() {
+        struct TestCase {
+            method: HedgeMethod,
+            prices: Vec<(f64, f64)>,
+            expected: Option<HedgeRatio>,
+            tolerance: f64,
+        }
+
+        let instrument = market_event_trade(Side::Buy).instrument;
+        let a = PairLeg {
+            exchange: ExchangeId::BinanceSpot,
+            instrument: instrument.clone(),
+        };
+        let b = PairLeg {
+            exchange: ExchangeId::Okx,
+            instrument,
+        };
+        let cointegrated = (0..1000)
+            .map(|i| {
+                let price_b = 1.0 + (i % 10) as f64;
+                (2.0 * price_b + 1.0, price_b)
+            })
+            .collect::<Vec<_>>();
+
+        let tests = vec![
+            TestCase {
+                // TC0: Ols warming up
+                method: HedgeMethod::Ols { window: 3 },
+                prices: vec![(21.0, 10.0), (25.0, 12.0)],
+                expected: None,
+                tolerance: 0.0,
+            },
+            TestCase {
+                // TC1: Ols exact fit
+                method: HedgeMethod::Ols { window: 3 },
+                prices: vec![(21.0, 10.0), (25.0, 12.0), (23.0, 11.0)],
+                expected: Some(HedgeRatio {
+                    beta: 2.0,
+                    alpha: 1.0,
+                }),
+                tolerance: 1e-9,
+            },
+            TestCase {
+                // TC2: Kalman converges to the cointegrating hedge ratio
+                method: HedgeMethod::Kalman {
+                    delta: 1e-4,
+                    observation_variance: 1e-3,
+                },
+                prices: cointegrated,
+                expected: Some(HedgeRatio {
+                    beta: 2.0,
+                    alpha: 1.0,
+                }),
+                tolerance: 1e-2,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut features = RollingFeatures::new(FeatureConfig { window: 3 });
+            features.add_pair(PairConfig {
+                a: a.clone(),
+                b: b.clone(),
+                method: test.method,
+                spread_window: 3,
+            });
+
+            for (price_a, price_b) in test.prices {
+                features.update(&candle(&a, price_a));
+                features.update(&candle(&b, price_b));
+            }
+
+            let actual = features.pair(&a, &b).unwrap().state();
+            match (actual, test.expected) {
+                (Some(actual), Some(expected)) => {
+                    let hedge_ratio = actual.hedge_ratio;
+                    assert!(
+                        (hedge_ratio.beta - expected.beta).abs() < test.tolerance
+                            && (hedge_ratio.alpha - expected.alpha).abs() < test.tolerance
+                            && actual.spread.abs() < test.tolerance,
+                        "TC{index} failed: actual {actual:?} != expected {expected:?}"
+                    );
+                }
+                (None, None) => {}
+                (actual, expected) => {
+                    panic!("TC{index} failed: actual {actual:?} != expected {expected:?}")
+                }
+            }
+        }
+
+        // Spread halves every sample
+        let spreads = [16.0, 8.0, 4.0, 2.0, 1.0];
+        let actual = half_life(&spreads).unwrap();
+        assert!((actual - 1.0).abs() < 1e-9, "half-life {actual} != 1.0");
+    }
+}
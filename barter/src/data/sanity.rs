@@ -0,0 +1,259 @@
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    subscription::book::{OrderBookEvent, OrderBookL1},
+};
+use barter_instrument::{exchange::ExchangeId, instrument::Instrument};
+use chrono::{DateTime, Utc};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Configuration for constructing a [`SanityGuard`].
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct SanityConfig {
+    /// Maximum plausible price change between consecutive [`MarketEvent`]s of an instrument, as
+    /// a fraction of the previous price (eg/ 0.1 for 10%).
+    pub max_price_change: f64,
+    /// Quarantine anomalous [`MarketEvent`]s so strategies & risk never act on them. Anomalies
+    /// are only flagged if false.
+    pub quarantine: bool,
+}
+
+/// Implausible market data detected by a [`SanityGuard`].
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize, JsonSchema)]
+pub enum AnomalyKind {
+    /// Price moved more than the configured maximum since the last accepted price.
+    PriceJump {
+        previous: f64,
+        current: f64,
+        change: f64,
+    },
+    /// Best bid price is above the best ask price.
+    CrossedBook { best_bid: f64, best_ask: f64 },
+}
+
+/// Typed anomaly event generated when a [`MarketEvent`] fails a [`SanityGuard`] check.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize, JsonSchema)]
+pub struct MarketAnomaly {
+    pub time: DateTime<Utc>,
+    pub exchange: ExchangeId,
+    pub instrument: Instrument,
+    pub kind: AnomalyKind,
+    /// True if the anomalous [`MarketEvent`] was quarantined.
+    pub quarantined: bool,
+}
+
+/// Last accepted price of an instrument, and the price of the last anomalous jump awaiting
+/// confirmation.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+struct PriceReference {
+    accepted: Option<f64>,
+    pending: Option<f64>,
+}
+
+/// Data-sanity guard that flags (and optionally quarantines) [`MarketEvent`]s implying
+/// implausible moves, such as price jumps greater than the configured maximum between
+/// consecutive events, or crossed books.
+///
+/// A price jump is accepted as genuine once the following [`MarketEvent`] confirms it (ie/ it's
+/// price is within the configured maximum of the jump), so a real move only causes a single
+/// quarantined event.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SanityGuard {
+    config: SanityConfig,
+    prices: HashMap<(ExchangeId, Instrument), PriceReference>,
+}
+
+impl SanityGuard {
+    /// Construct a new [`SanityGuard`] using the provided [`SanityConfig`].
+    pub fn new(config: SanityConfig) -> Self {
+        Self {
+            config,
+            prices: HashMap::new(),
+        }
+    }
+
+    /// Check the provided [`MarketEvent`], returning a [`MarketAnomaly`] if it is implausible.
+    pub fn check(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<MarketAnomaly> {
+        let kind = match &market.kind {
+            DataKind::Trade(trade) => self.check_price(market, trade.price),
+            DataKind::Candle(candle) => self.check_price(market, candle.close),
+            DataKind::OrderBookL1(book) => check_crossed(book.best_bid.price, book.best_ask.price)
+                .or_else(|| self.check_price(market, mid_price(book)?)),
+            // Updates only contain changed levels, so only snapshots can be checked
+            DataKind::OrderBook(OrderBookEvent::Snapshot(book)) => {
+                match (book.bids().levels().first(), book.asks().levels().first()) {
+                    (Some(best_bid), Some(best_ask)) => {
+                        check_crossed(best_bid.price, best_ask.price)
+                    }
+                    _ => None,
+                }
+            }
+            DataKind::OrderBook(OrderBookEvent::Update(_))
+            | DataKind::Liquidation(_)
+            | DataKind::Custom(_) => None,
+        }?;
+
+        Some(MarketAnomaly {
+            time: market.time_exchange,
+            exchange: market.exchange,
+            instrument: market.instrument.clone(),
+            kind,
+            quarantined: self.config.quarantine,
+        })
+    }
+
+    fn check_price(
+        &mut self,
+        market: &MarketEvent<Instrument, DataKind>,
+        current: f64,
+    ) -> Option<AnomalyKind> {
+        let reference = self
+            .prices
+            .entry((market.exchange, market.instrument.clone()))
+            .or_default();
+
+        let Some(previous) = reference.accepted else {
+            reference.accepted = Some(current);
+            return None;
+        };
+
+        let change = (current - previous) / previous;
+        let is_plausible =
+            |previous: f64| ((current - previous) / previous).abs() <= self.config.max_price_change;
+
+        if is_plausible(previous) || reference.pending.is_some_and(is_plausible) {
+            *reference = PriceReference {
+                accepted: Some(current),
+                pending: None,
+            };
+            None
+        } else {
+            reference.pending = Some(current);
+            Some(AnomalyKind::PriceJump {
+                previous,
+                current,
+                change,
+            })
+        }
+    }
+}
+
+fn check_crossed(best_bid: Decimal, best_ask: Decimal) -> Option<AnomalyKind> {
+    (best_bid > best_ask).then(|| AnomalyKind::CrossedBook {
+        best_bid: best_bid.to_f64().unwrap_or_default(),
+        best_ask: best_ask.to_f64().unwrap_or_default(),
+    })
+}
+
+fn mid_price(book: &OrderBookL1) -> Option<f64> {
+    book.mid_price().to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use barter_data::books::Level;
+    use barter_integration::Side;
+    use rust_decimal_macros::dec;
+
+    fn trade(price: f64) -> MarketEvent<Instrument, DataKind> {
+        let mut market = market_event_trade(Side::Buy);
+        if let DataKind::Trade(trade) = &mut market.kind {
+            trade.price = price;
+        }
+        market
+    }
+
+    fn book_l1(best_bid: Decimal, best_ask: Decimal) -> MarketEvent<Instrument, DataKind> {
+        let mut market = market_event_trade(Side::Buy);
+        market.kind = DataKind::OrderBookL1(OrderBookL1 {
+            last_update_time: market.time_exchange,
+            best_bid: Level::new(best_bid, dec!(1.0)),
+            best_ask: Level::new(best_ask, dec!(1.0)),
+        });
+        market
+    }
+
+    #[test]
+    fn test_sanity_guard_check() {
+        struct TestCase {
+            input: MarketEvent<Instrument, DataKind>,
+            expected: Option<AnomalyKind>,
+        }
+
+        let mut guard = SanityGuard::new(SanityConfig {
+            max_price_change: 0.1,
+            quarantine: true,
+        });
+
+        let tests = vec![
+            TestCase {
+                // TC0: first price is accepted
+                input: trade(100.0),
+                expected: None,
+            },
+            TestCase {
+                // TC1: plausible move
+                input: trade(105.0),
+                expected: None,
+            },
+            TestCase {
+                // TC2: implausible jump
+                input: trade(210.0),
+                expected: Some(AnomalyKind::PriceJump {
+                    previous: 105.0,
+                    current: 210.0,
+                    change: 1.0,
+                }),
+            },
+            TestCase {
+                // TC3: price returns to previously accepted level, so the jump was a bad tick
+                input: trade(104.0),
+                expected: None,
+            },
+            TestCase {
+                // TC4: implausible jump
+                input: trade(200.0),
+                expected: Some(AnomalyKind::PriceJump {
+                    previous: 104.0,
+                    current: 200.0,
+                    change: 96.0 / 104.0,
+                }),
+            },
+            TestCase {
+                // TC5: jump confirmed by the next event, so accepted as genuine
+                input: trade(201.0),
+                expected: None,
+            },
+            TestCase {
+                // TC6: crossed book
+                input: book_l1(dec!(202.0), dec!(201.0)),
+                expected: Some(AnomalyKind::CrossedBook {
+                    best_bid: 202.0,
+                    best_ask: 201.0,
+                }),
+            },
+            TestCase {
+                // TC7: valid book with plausible mid price
+                input: book_l1(dec!(200.0), dec!(202.0)),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = guard.check(&test.input);
+            assert_eq!(
+                actual.as_ref().map(|anomaly| anomaly.kind),
+                test.expected,
+                "TC{index} failed"
+            );
+            assert!(
+                actual.is_none_or(|anomaly| anomaly.quarantined),
+                "TC{index} failed"
+            );
+        }
+    }
+}
@@ -0,0 +1,382 @@
+use super::MarketEventObserver;
+use crate::event::Event;
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    subscription::book::OrderBookEvent,
+};
+use barter_instrument::{exchange::ExchangeId, instrument::Instrument};
+use chrono::{DateTime, TimeDelta, Utc};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeSet, HashMap, VecDeque},
+    time::Duration,
+};
+
+/// Configuration for constructing a [`UniverseSelector`].
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct UniverseConfig {
+    /// Interval between universe evaluations, measured in [`MarketEvent`] exchange time.
+    pub interval: Duration,
+    /// Rolling window over which traded quote volume is summed (eg/ 24 hours).
+    pub volume_window: Duration,
+    /// Only select the N instruments with the highest traded quote volume.
+    #[serde(default)]
+    pub top_n: Option<usize>,
+    /// Only select instruments with a traded quote volume of at least this value.
+    #[serde(default)]
+    pub min_volume: Option<f64>,
+    /// Only select instruments with a last observed bid-ask spread at or below this value, in
+    /// basis points of the mid price. Instruments without an observed spread are not filtered.
+    #[serde(default)]
+    pub max_spread_bps: Option<f64>,
+}
+
+/// Exchange [`Instrument`] that may be selected into the universe.
+#[derive(
+    Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize, JsonSchema,
+)]
+pub struct UniverseMember {
+    pub exchange: ExchangeId,
+    pub instrument: Instrument,
+}
+
+/// Change to the selected universe generated by a [`UniverseSelector`] evaluation. Used to
+/// subscribe & unsubscribe instruments dynamically.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize, JsonSchema)]
+pub struct UniverseUpdate {
+    pub time: DateTime<Utc>,
+    /// [`UniverseMember`]s that entered the universe, ordered by descending volume.
+    pub added: Vec<UniverseMember>,
+    /// [`UniverseMember`]s that left the universe.
+    pub removed: Vec<UniverseMember>,
+}
+
+/// Rolling market statistics of a [`UniverseMember`].
+#[derive(Clone, PartialEq, Debug, Default)]
+struct MemberStats {
+    volumes: VecDeque<(DateTime<Utc>, f64)>,
+    volume: f64,
+    spread_bps: Option<f64>,
+}
+
+impl MemberStats {
+    fn expire(&mut self, cutoff: DateTime<Utc>) {
+        while let Some((time, volume)) = self.volumes.front().copied() {
+            if time >= cutoff {
+                break;
+            }
+            self.volume -= volume;
+            self.volumes.pop_front();
+        }
+
+        if self.volumes.is_empty() {
+            self.volume = 0.0;
+        }
+    }
+}
+
+/// Universe management component that periodically screens every observed instrument using the
+/// [`UniverseConfig`] filters (eg/ top N by rolling volume, spread below X bps), generating a
+/// [`UniverseUpdate`] whenever the selected universe changes.
+///
+/// Traded quote volume is accumulated from [`PublicTrade`](barter_data::subscription::trade::PublicTrade)s
+/// and [`Candle`](barter_data::subscription::candle::Candle)s, and the spread from
+/// [`OrderBookL1`](barter_data::subscription::book::OrderBookL1)s & order book snapshots.
+#[derive(Clone, PartialEq, Debug)]
+pub struct UniverseSelector {
+    config: UniverseConfig,
+    stats: HashMap<UniverseMember, MemberStats>,
+    universe: BTreeSet<UniverseMember>,
+    next_evaluation: Option<DateTime<Utc>>,
+}
+
+impl UniverseSelector {
+    /// Construct a new [`UniverseSelector`] using the provided [`UniverseConfig`].
+    pub fn new(config: UniverseConfig) -> Self {
+        Self {
+            config,
+            stats: HashMap::new(),
+            universe: BTreeSet::new(),
+            next_evaluation: None,
+        }
+    }
+
+    /// Currently selected [`UniverseMember`]s.
+    pub fn universe(&self) -> impl Iterator<Item = &UniverseMember> {
+        self.universe.iter()
+    }
+
+    /// Determine if the provided exchange [`Instrument`] is in the selected universe.
+    pub fn contains(&self, exchange: ExchangeId, instrument: &Instrument) -> bool {
+        self.universe.contains(&UniverseMember {
+            exchange,
+            instrument: instrument.clone(),
+        })
+    }
+
+    /// Update the rolling statistics from the [`MarketEvent`], evaluating the universe if the
+    /// evaluation interval has elapsed. Returns a [`UniverseUpdate`] if the universe changed.
+    pub fn update(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<UniverseUpdate> {
+        let time = market.time_exchange;
+        let stats = self
+            .stats
+            .entry(UniverseMember {
+                exchange: market.exchange,
+                instrument: market.instrument.clone(),
+            })
+            .or_default();
+
+        match &market.kind {
+            DataKind::Trade(trade) => {
+                let volume = trade.price * trade.amount;
+                stats.volumes.push_back((time, volume));
+                stats.volume += volume;
+            }
+            DataKind::Candle(candle) => {
+                let volume = candle.close * candle.volume;
+                stats.volumes.push_back((time, volume));
+                stats.volume += volume;
+            }
+            DataKind::OrderBookL1(book) => {
+                stats.spread_bps = spread_bps(book.best_bid.price, book.best_ask.price)
+            }
+            DataKind::OrderBook(OrderBookEvent::Snapshot(book)) => {
+                if let (Some(best_bid), Some(best_ask)) =
+                    (book.bids().levels().first(), book.asks().levels().first())
+                {
+                    stats.spread_bps = spread_bps(best_bid.price, best_ask.price)
+                }
+            }
+            DataKind::OrderBook(OrderBookEvent::Update(_))
+            | DataKind::Liquidation(_)
+            | DataKind::Custom(_) => {}
+        }
+
+        let interval = TimeDelta::from_std(self.config.interval).ok()?;
+        match self.next_evaluation {
+            None => {
+                self.next_evaluation = Some(time + interval);
+                None
+            }
+            Some(next) if time >= next => {
+                self.next_evaluation = Some(time + interval);
+                self.evaluate(time)
+            }
+            Some(_) => None,
+        }
+    }
+
+    /// Screen every observed instrument at the provided time, returning a [`UniverseUpdate`] if
+    /// the selected universe changed.
+    pub fn evaluate(&mut self, time: DateTime<Utc>) -> Option<UniverseUpdate> {
+        if let Some(cutoff) = TimeDelta::from_std(self.config.volume_window)
+            .ok()
+            .and_then(|window| time.checked_sub_signed(window))
+        {
+            self.stats
+                .values_mut()
+                .for_each(|stats| stats.expire(cutoff));
+        }
+
+        let mut candidates = self
+            .stats
+            .iter()
+            .filter(|(_, stats)| {
+                self.config
+                    .min_volume
+                    .is_none_or(|min_volume| stats.volume >= min_volume)
+            })
+            .filter(
+                |(_, stats)| match (self.config.max_spread_bps, stats.spread_bps) {
+                    (Some(max_spread_bps), Some(spread_bps)) => spread_bps <= max_spread_bps,
+                    _ => true,
+                },
+            )
+            .collect::<Vec<_>>();
+
+        candidates.sort_by(|(member_a, a), (member_b, b)| {
+            b.volume
+                .total_cmp(&a.volume)
+                .then_with(|| member_a.cmp(member_b))
+        });
+
+        let selected = candidates
+            .into_iter()
+            .take(self.config.top_n.unwrap_or(usize::MAX))
+            .map(|(member, _)| member.clone())
+            .collect::<Vec<_>>();
+
+        let added = selected
+            .iter()
+            .filter(|member| !self.universe.contains(*member))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let selected = selected.into_iter().collect::<BTreeSet<_>>();
+        let removed = self
+            .universe
+            .difference(&selected)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if added.is_empty() && removed.is_empty() {
+            return None;
+        }
+
+        self.universe = selected;
+        Some(UniverseUpdate {
+            time,
+            added,
+            removed,
+        })
+    }
+}
+
+impl MarketEventObserver for UniverseSelector {
+    fn on_market(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Event> {
+        self.update(market).map(Event::UniverseUpdate)
+    }
+}
+
+fn spread_bps(best_bid: Decimal, best_ask: Decimal) -> Option<f64> {
+    let mid = (best_bid + best_ask) / Decimal::TWO;
+    if mid.is_zero() {
+        return None;
+    }
+    ((best_ask - best_bid) / mid * Decimal::from(10_000)).to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use barter_data::{books::Level, subscription::book::OrderBookL1};
+    use barter_instrument::instrument::kind::InstrumentKind;
+    use barter_integration::Side;
+    use rust_decimal_macros::dec;
+
+    fn member(base: &str) -> UniverseMember {
+        UniverseMember {
+            exchange: ExchangeId::BinanceSpot,
+            instrument: Instrument::new(base, "usdt", InstrumentKind::Spot),
+        }
+    }
+
+    fn market(base: &str, offset_secs: i64, kind: DataKind) -> MarketEvent<Instrument, DataKind> {
+        let mut market = market_event_trade(Side::Buy);
+        market.exchange = ExchangeId::BinanceSpot;
+        market.instrument = Instrument::new(base, "usdt", InstrumentKind::Spot);
+        market.time_exchange =
+            DateTime::<Utc>::from_timestamp(1_700_000_000 + offset_secs, 0).unwrap();
+        market.kind = kind;
+        market
+    }
+
+    fn trade(base: &str, offset_secs: i64, volume: f64) -> MarketEvent<Instrument, DataKind> {
+        let mut market = market_event_trade(Side::Buy);
+        if let DataKind::Trade(trade) = &mut market.kind {
+            trade.price = 1.0;
+            trade.amount = volume;
+        }
+        self::market(base, offset_secs, market.kind)
+    }
+
+    fn book(
+        base: &str,
+        offset_secs: i64,
+        bid: Decimal,
+        ask: Decimal,
+    ) -> MarketEvent<Instrument, DataKind> {
+        market(
+            base,
+            offset_secs,
+            DataKind::OrderBookL1(OrderBookL1 {
+                last_update_time: DateTime::<Utc>::MIN_UTC,
+                best_bid: Level::new(bid, dec!(1.0)),
+                best_ask: Level::new(ask, dec!(1.0)),
+            }),
+        )
+    }
+
+    #[test]
+    fn test_universe_selector_update() {
+        struct TestCase {
+            input: MarketEvent<Instrument, DataKind>,
+            expected: Option<UniverseUpdate>,
+        }
+
+        let mut selector = UniverseSelector::new(UniverseConfig {
+            interval: Duration::from_secs(10),
+            volume_window: Duration::from_secs(30),
+            top_n: Some(2),
+            min_volume: None,
+            max_spread_bps: Some(10.0),
+        });
+
+        let time = |offset_secs: i64| {
+            DateTime::<Utc>::from_timestamp(1_700_000_000 + offset_secs, 0).unwrap()
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: first MarketEvent schedules the first evaluation
+                input: trade("btc", 0, 100.0),
+                expected: None,
+            },
+            TestCase {
+                // TC1: no evaluation before the interval elapses
+                input: trade("eth", 1, 50.0),
+                expected: None,
+            },
+            TestCase {
+                // TC2: sol has a wide spread
+                input: book("sol", 2, dec!(99), dec!(101)),
+                expected: None,
+            },
+            TestCase {
+                // TC3: sol has the highest volume, but is filtered by it's spread
+                input: trade("sol", 3, 1000.0),
+                expected: None,
+            },
+            TestCase {
+                // TC4: first evaluation selects top 2 by volume w/ acceptable spread
+                input: trade("xrp", 10, 10.0),
+                expected: Some(UniverseUpdate {
+                    time: time(10),
+                    added: vec![member("btc"), member("eth")],
+                    removed: vec![],
+                }),
+            },
+            TestCase {
+                // TC5: sol spread tightens, but no evaluation before the interval elapses
+                input: book("sol", 15, dec!(99.99), dec!(100.01)),
+                expected: None,
+            },
+            TestCase {
+                // TC6: sol enters universe & eth leaves as it has the lower volume
+                input: trade("xrp", 20, 10.0),
+                expected: Some(UniverseUpdate {
+                    time: time(20),
+                    added: vec![member("sol")],
+                    removed: vec![member("eth")],
+                }),
+            },
+            TestCase {
+                // TC7: btc, eth & sol volume expires from the window, leaving only xrp
+                input: trade("eth", 40, 5.0),
+                expected: Some(UniverseUpdate {
+                    time: time(40),
+                    added: vec![member("xrp"), member("eth")],
+                    removed: vec![member("btc"), member("sol")],
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = selector.update(&test.input);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
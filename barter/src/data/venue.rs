@@ -0,0 +1,291 @@
+use super::MarketEventObserver;
+use crate::event::Event;
+use barter_data::{
+    books::Level,
+    event::{DataKind, MarketEvent},
+    subscription::book::OrderBookEvent,
+};
+use barter_instrument::{exchange::ExchangeId, instrument::Instrument};
+use chrono::{DateTime, TimeDelta, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Duration};
+
+/// Latest best bid & ask of an [`Instrument`] on a single exchange.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct VenueQuote {
+    pub best_bid: f64,
+    pub best_ask: f64,
+    pub time_exchange: DateTime<Utc>,
+    pub time_received: DateTime<Utc>,
+}
+
+impl VenueQuote {
+    /// Time elapsed between this [`VenueQuote`] being received and the provided time.
+    pub fn staleness(&self, time: DateTime<Utc>) -> TimeDelta {
+        time - self.time_received
+    }
+}
+
+/// Spread between buying an [`Instrument`] at the best ask of one exchange, and selling it at the
+/// best bid of another. A positive spread is a cross-venue arbitrage opportunity (before fees).
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CrossVenueSpread {
+    /// Exchange with the best ask, where the [`Instrument`] would be bought.
+    pub buy_exchange: ExchangeId,
+    pub buy_price: f64,
+    /// Exchange with the best bid, where the [`Instrument`] would be sold.
+    pub sell_exchange: ExchangeId,
+    pub sell_price: f64,
+    /// `sell_price - buy_price`.
+    pub spread: f64,
+    /// Spread in basis points of the `buy_price`.
+    pub spread_bps: f64,
+    /// Staleness of the oldest of the two [`VenueQuote`]s.
+    pub staleness: TimeDelta,
+}
+
+/// Multi-venue view of the best bid & ask of each logical [`Instrument`] (eg/ btc_usdt spot)
+/// listed on multiple exchanges, used to compute cross-venue spreads.
+///
+/// Maintained by every [`Trader`](crate::engine::trader::Trader) it is provided to, from
+/// [`OrderBookL1`](barter_data::subscription::book::OrderBookL1)s & order book snapshots, so
+/// arbitrage strategies can share it.
+#[derive(Clone, PartialEq, Debug)]
+pub struct CrossVenueBook {
+    /// [`VenueQuote`]s received longer than this ago are considered stale & ignored when
+    /// computing the best cross-venue spread.
+    pub max_staleness: Duration,
+    quotes: HashMap<Instrument, HashMap<ExchangeId, VenueQuote>>,
+}
+
+impl CrossVenueBook {
+    /// Construct a new empty [`CrossVenueBook`] with the provided maximum quote staleness.
+    pub fn new(max_staleness: Duration) -> Self {
+        Self {
+            max_staleness,
+            quotes: HashMap::new(),
+        }
+    }
+
+    /// Update the exchange [`VenueQuote`] from the [`MarketEvent`], if it contains a best bid &
+    /// ask.
+    pub fn update(&mut self, market: &MarketEvent<Instrument, DataKind>) {
+        let (best_bid, best_ask) = match &market.kind {
+            DataKind::OrderBookL1(book) => (book.best_bid, book.best_ask),
+            DataKind::OrderBook(OrderBookEvent::Snapshot(book)) => {
+                match (book.bids().levels().first(), book.asks().levels().first()) {
+                    (Some(best_bid), Some(best_ask)) => (*best_bid, *best_ask),
+                    _ => return,
+                }
+            }
+            _ => return,
+        };
+
+        let (Some(best_bid), Some(best_ask)) = (price(best_bid), price(best_ask)) else {
+            return;
+        };
+
+        self.quotes
+            .entry(market.instrument.clone())
+            .or_default()
+            .insert(
+                market.exchange,
+                VenueQuote {
+                    best_bid,
+                    best_ask,
+                    time_exchange: market.time_exchange,
+                    time_received: market.time_received,
+                },
+            );
+    }
+
+    /// [`VenueQuote`] of the [`Instrument`] on the provided exchange.
+    pub fn quote(&self, exchange: ExchangeId, instrument: &Instrument) -> Option<&VenueQuote> {
+        self.quotes.get(instrument)?.get(&exchange)
+    }
+
+    /// Every exchange [`VenueQuote`] of the [`Instrument`].
+    pub fn quotes(
+        &self,
+        instrument: &Instrument,
+    ) -> impl Iterator<Item = (&ExchangeId, &VenueQuote)> {
+        self.quotes.get(instrument).into_iter().flatten()
+    }
+
+    /// [`CrossVenueSpread`] of buying the [`Instrument`] on one exchange and selling it on
+    /// another, regardless of staleness.
+    pub fn spread(
+        &self,
+        instrument: &Instrument,
+        buy_exchange: ExchangeId,
+        sell_exchange: ExchangeId,
+        time: DateTime<Utc>,
+    ) -> Option<CrossVenueSpread> {
+        let buy = self.quote(buy_exchange, instrument)?;
+        let sell = self.quote(sell_exchange, instrument)?;
+        Some(cross_venue_spread(
+            (buy_exchange, buy),
+            (sell_exchange, sell),
+            time,
+        ))
+    }
+
+    /// Widest [`CrossVenueSpread`] of the [`Instrument`] across every pair of distinct exchanges
+    /// with a non-stale [`VenueQuote`] at the provided time.
+    pub fn best_spread(
+        &self,
+        instrument: &Instrument,
+        time: DateTime<Utc>,
+    ) -> Option<CrossVenueSpread> {
+        let max_staleness = TimeDelta::from_std(self.max_staleness).unwrap_or(TimeDelta::MAX);
+        let fresh = self
+            .quotes(instrument)
+            .filter(|(_, quote)| quote.staleness(time) <= max_staleness)
+            .map(|(exchange, quote)| (*exchange, quote))
+            .collect::<Vec<_>>();
+
+        fresh
+            .iter()
+            .flat_map(|buy| {
+                fresh
+                    .iter()
+                    .filter(move |sell| sell.0 != buy.0)
+                    .map(move |sell| cross_venue_spread(*buy, *sell, time))
+            })
+            .max_by(|a, b| {
+                a.spread
+                    .total_cmp(&b.spread)
+                    .then_with(|| b.buy_exchange.cmp(&a.buy_exchange))
+                    .then_with(|| b.sell_exchange.cmp(&a.sell_exchange))
+            })
+    }
+}
+
+impl MarketEventObserver for CrossVenueBook {
+    fn on_market(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Event> {
+        self.update(market);
+        None
+    }
+}
+
+fn cross_venue_spread(
+    (buy_exchange, buy): (ExchangeId, &VenueQuote),
+    (sell_exchange, sell): (ExchangeId, &VenueQuote),
+    time: DateTime<Utc>,
+) -> CrossVenueSpread {
+    let spread = sell.best_bid - buy.best_ask;
+    CrossVenueSpread {
+        buy_exchange,
+        buy_price: buy.best_ask,
+        sell_exchange,
+        sell_price: sell.best_bid,
+        spread,
+        spread_bps: spread / buy.best_ask * 10_000.0,
+        staleness: buy.staleness(time).max(sell.staleness(time)),
+    }
+}
+
+fn price(level: Level) -> Option<f64> {
+    level.price.to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use barter_data::subscription::book::OrderBookL1;
+    use barter_integration::Side;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    fn book_l1(
+        exchange: ExchangeId,
+        time: DateTime<Utc>,
+        bid: Decimal,
+        ask: Decimal,
+    ) -> MarketEvent<Instrument, DataKind> {
+        let mut market = market_event_trade(Side::Buy);
+        market.exchange = exchange;
+        market.time_exchange = time;
+        market.time_received = time;
+        market.kind = DataKind::OrderBookL1(OrderBookL1 {
+            last_update_time: time,
+            best_bid: Level::new(bid, dec!(1.0)),
+            best_ask: Level::new(ask, dec!(1.0)),
+        });
+        market
+    }
+
+    #[test]
+    fn test_cross_venue_book_best_spread() {
+        struct TestCase {
+            input: Option<MarketEvent<Instrument, DataKind>>,
+            time_offset: TimeDelta,
+            expected: Option<(ExchangeId, ExchangeId, f64)>,
+        }
+
+        let start = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+        let instrument = market_event_trade(Side::Buy).instrument;
+        let mut book = CrossVenueBook::new(Duration::from_secs(5));
+
+        let tests = vec![
+            TestCase {
+                // TC0: single venue has no cross-venue spread
+                input: Some(book_l1(
+                    ExchangeId::BinanceSpot,
+                    start,
+                    dec!(100),
+                    dec!(101),
+                )),
+                time_offset: TimeDelta::zero(),
+                expected: None,
+            },
+            TestCase {
+                // TC1: buy on BinanceSpot ask & sell on Okx bid
+                input: Some(book_l1(ExchangeId::Okx, start, dec!(102), dec!(103))),
+                time_offset: TimeDelta::zero(),
+                expected: Some((ExchangeId::BinanceSpot, ExchangeId::Okx, 1.0)),
+            },
+            TestCase {
+                // TC2: BybitSpot bid is wider than Okx bid
+                input: Some(book_l1(
+                    ExchangeId::BybitSpot,
+                    start + TimeDelta::seconds(3),
+                    dec!(104),
+                    dec!(105),
+                )),
+                time_offset: TimeDelta::seconds(3),
+                expected: Some((ExchangeId::BinanceSpot, ExchangeId::BybitSpot, 3.0)),
+            },
+            TestCase {
+                // TC3: BinanceSpot & Okx quotes are stale, leaving a single fresh venue
+                input: None,
+                time_offset: TimeDelta::seconds(6),
+                expected: None,
+            },
+            TestCase {
+                // TC4: fresh Okx quote has no positive spread vs BybitSpot, least negative is returned
+                input: Some(book_l1(
+                    ExchangeId::Okx,
+                    start + TimeDelta::seconds(6),
+                    dec!(104.5),
+                    dec!(105.5),
+                )),
+                time_offset: TimeDelta::seconds(6),
+                expected: Some((ExchangeId::BybitSpot, ExchangeId::Okx, -0.5)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            if let Some(market) = test.input {
+                book.update(&market);
+            }
+
+            let actual = book
+                .best_spread(&instrument, start + test.time_offset)
+                .map(|spread| (spread.buy_exchange, spread.sell_exchange, spread.spread));
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
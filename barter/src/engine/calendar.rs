@@ -0,0 +1,210 @@
+use chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Utc, Weekday,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// Recurring trading session, opening at `open` on each of the `days` and closing at `close`.
+///
+/// If `close` is at or before `open` the session closes on the following day, eg/ a CME Globex
+/// style session opening Sunday-Thursday at 17:00 and closing the next day at 16:00.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Session {
+    /// Local days of the week the session opens on.
+    pub days: Vec<Weekday>,
+    /// Local time the session opens.
+    pub open: NaiveTime,
+    /// Local time the session closes.
+    pub close: NaiveTime,
+}
+
+impl Session {
+    /// Determine if the local time is within this [`Session`], excluding sessions that would have
+    /// opened on a holiday.
+    fn contains(&self, local: NaiveDateTime, holidays: &BTreeSet<NaiveDate>) -> bool {
+        let today = local.date();
+        [today.pred_opt(), Some(today)]
+            .into_iter()
+            .flatten()
+            .filter(|date| self.days.contains(&date.weekday()) && !holidays.contains(date))
+            .any(|date| {
+                let open = date.and_time(self.open);
+                let close = if self.close > self.open {
+                    date.and_time(self.close)
+                } else {
+                    date.and_time(self.close) + TimeDelta::days(1)
+                };
+                open <= local && local < close
+            })
+    }
+}
+
+/// Period an exchange is closed outside of it's regular [`Session`]s (eg/ scheduled exchange
+/// maintenance).
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MaintenanceWindow {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub reason: String,
+}
+
+/// Trading calendar of an exchange, used by strategies, the [`Trader`](super::trader::Trader)
+/// risk checks & backtests to only trade while the exchange is open.
+///
+/// Crypto exchanges trade 24/7, so the [`Default`] calendar has no [`Session`]s and is always
+/// open, except during any [`MaintenanceWindow`]s. Traditional exchanges define their regular
+/// [`Session`]s & holidays in local time, offset from UTC by a fixed `utc_offset_minutes` (eg/
+/// -360 for US Central Standard Time). Daylight saving transitions are not modelled, so
+/// calendars spanning one should be updated when it occurs.
+#[derive(Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct TradingCalendar {
+    /// Offset of the exchange local time from UTC, in minutes.
+    #[serde(default)]
+    pub utc_offset_minutes: i32,
+    /// Regular trading [`Session`]s. If empty, the exchange is open 24/7.
+    #[serde(default)]
+    pub sessions: Vec<Session>,
+    /// Local dates on which no [`Session`] opens.
+    #[serde(default)]
+    pub holidays: BTreeSet<NaiveDate>,
+    #[serde(default)]
+    pub maintenance: Vec<MaintenanceWindow>,
+}
+
+impl TradingCalendar {
+    /// Construct a new [`TradingCalendar`] that is always open.
+    pub fn always_open() -> Self {
+        Self::default()
+    }
+
+    /// Determine if the exchange is open for trading at the provided time.
+    pub fn is_open(&self, time: DateTime<Utc>) -> bool {
+        if self.maintenance_window(time).is_some() {
+            return false;
+        }
+
+        if self.sessions.is_empty() {
+            return true;
+        }
+
+        let Some(offset) = FixedOffset::east_opt(self.utc_offset_minutes.saturating_mul(60)) else {
+            return false;
+        };
+        let local = time.with_timezone(&offset).naive_local();
+
+        self.sessions
+            .iter()
+            .any(|session| session.contains(local, &self.holidays))
+    }
+
+    /// [`MaintenanceWindow`] the exchange is closed for at the provided time, if any.
+    pub fn maintenance_window(&self, time: DateTime<Utc>) -> Option<&MaintenanceWindow> {
+        self.maintenance
+            .iter()
+            .find(|window| window.start <= time && time < window.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trading_calendar_is_open() {
+        struct TestCase {
+            calendar: TradingCalendar,
+            time: &'static str,
+            expected: bool,
+        }
+
+        let maintenance = MaintenanceWindow {
+            start: "2024-03-12T02:00:00Z".parse().unwrap(),
+            end: "2024-03-12T03:00:00Z".parse().unwrap(),
+            reason: "exchange upgrade".to_owned(),
+        };
+        let crypto = TradingCalendar {
+            maintenance: vec![maintenance.clone()],
+            ..TradingCalendar::always_open()
+        };
+
+        // CME Globex style: Sunday-Thursday 17:00 to 16:00 the next day, US Central (UTC-6)
+        let globex = TradingCalendar {
+            utc_offset_minutes: -360,
+            sessions: vec![Session {
+                days: vec![
+                    Weekday::Sun,
+                    Weekday::Mon,
+                    Weekday::Tue,
+                    Weekday::Wed,
+                    Weekday::Thu,
+                ],
+                open: NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+                close: NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+            }],
+            holidays: BTreeSet::from([NaiveDate::from_ymd_opt(2024, 3, 13).unwrap()]),
+            maintenance: vec![maintenance],
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: crypto open on a Saturday
+                calendar: crypto.clone(),
+                time: "2024-03-09T12:00:00Z",
+                expected: true,
+            },
+            TestCase {
+                // TC1: crypto closed during maintenance
+                calendar: crypto,
+                time: "2024-03-12T02:30:00Z",
+                expected: false,
+            },
+            TestCase {
+                // TC2: Globex closed on a Saturday
+                calendar: globex.clone(),
+                time: "2024-03-09T12:00:00Z",
+                expected: false,
+            },
+            TestCase {
+                // TC3: Globex open Monday 10:00 local, within Sunday 17:00 session
+                calendar: globex.clone(),
+                time: "2024-03-11T16:00:00Z",
+                expected: true,
+            },
+            TestCase {
+                // TC4: Globex closed Monday 16:30 local, between sessions
+                calendar: globex.clone(),
+                time: "2024-03-11T22:30:00Z",
+                expected: false,
+            },
+            TestCase {
+                // TC5: Globex open Monday 17:00 local, Monday session open
+                calendar: globex.clone(),
+                time: "2024-03-11T23:00:00Z",
+                expected: true,
+            },
+            TestCase {
+                // TC6: Globex closed during maintenance
+                calendar: globex.clone(),
+                time: "2024-03-12T02:30:00Z",
+                expected: false,
+            },
+            TestCase {
+                // TC7: Globex holiday session does not open Wednesday 17:00 local
+                calendar: globex.clone(),
+                time: "2024-03-14T00:00:00Z",
+                expected: false,
+            },
+            TestCase {
+                // TC8: Globex Tuesday session still open Wednesday 10:00 local
+                calendar: globex,
+                time: "2024-03-13T16:00:00Z",
+                expected: true,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.calendar.is_open(test.time.parse().unwrap());
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,381 @@
+use super::error::EngineError;
+use crate::portfolio::{
+    position::{Position, PositionId},
+    Balance, OrderEvent,
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt::Debug, fs, path::PathBuf, time::Duration};
+use tracing::warn;
+use uuid::Uuid;
+
+/// Checkpoint of the [`Engine`](super::Engine) state that is otherwise lost when the process
+/// restarts, periodically persisted to a [`CheckpointStore`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct EngineCheckpoint {
+    pub engine_id: Uuid,
+    pub time: DateTime<Utc>,
+    pub balance: Balance,
+    pub positions: Vec<Position>,
+    /// `OrderEvent`s submitted by every [`Trader`](super::trader::Trader) that had not yet been
+    /// executed at the checkpoint `time`.
+    pub orders_pending: Vec<OrderEvent>,
+}
+
+/// Fresh snapshot of the exchange account state, fetched on restart to reconcile against the
+/// latest [`EngineCheckpoint`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct AccountSnapshot {
+    pub time: DateTime<Utc>,
+    pub balance: Balance,
+    pub positions: Vec<Position>,
+    /// `OrderEvent`s still open on the exchange.
+    pub orders_open: Vec<OrderEvent>,
+}
+
+/// Difference between an [`EngineCheckpoint`] and the [`AccountSnapshot`] it was reconciled
+/// against.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum Discrepancy {
+    /// [`Balance`] changed since the checkpoint.
+    Balance {
+        checkpoint: Balance,
+        exchange: Balance,
+    },
+    /// [`Position`] quantity changed since the checkpoint, where `None` is no open [`Position`].
+    Position {
+        position_id: PositionId,
+        checkpoint: Option<Decimal>,
+        exchange: Option<Decimal>,
+    },
+    /// `OrderEvent` pending at the checkpoint that is no longer open on the exchange (ie/
+    /// executed or cancelled while the [`Engine`](super::Engine) was offline).
+    OrderResolved(OrderEvent),
+    /// `OrderEvent` open on the exchange that was not pending at the checkpoint.
+    OrderUnknown(OrderEvent),
+}
+
+/// Engine state to resume from, reconciled from an [`EngineCheckpoint`] & [`AccountSnapshot`].
+///
+/// The exchange [`AccountSnapshot`] is the source of truth, but any checkpoint [`Position`] with
+/// an unchanged quantity is preferred, since it retains it's entry metadata.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Reconciliation {
+    pub balance: Balance,
+    pub positions: Vec<Position>,
+    /// `OrderEvent`s to resume tracking as in-flight.
+    pub orders_pending: Vec<OrderEvent>,
+    pub discrepancies: Vec<Discrepancy>,
+}
+
+/// Reconcile the latest [`EngineCheckpoint`], if any, against a fresh [`AccountSnapshot`].
+pub fn reconcile(
+    checkpoint: Option<EngineCheckpoint>,
+    snapshot: AccountSnapshot,
+) -> Reconciliation {
+    let Some(checkpoint) = checkpoint else {
+        return Reconciliation {
+            balance: snapshot.balance,
+            discrepancies: snapshot
+                .orders_open
+                .iter()
+                .cloned()
+                .map(Discrepancy::OrderUnknown)
+                .collect(),
+            positions: snapshot.positions,
+            orders_pending: snapshot.orders_open,
+        };
+    };
+
+    let mut discrepancies = Vec::new();
+
+    if checkpoint.balance.total != snapshot.balance.total
+        || checkpoint.balance.available != snapshot.balance.available
+    {
+        discrepancies.push(Discrepancy::Balance {
+            checkpoint: checkpoint.balance,
+            exchange: snapshot.balance,
+        });
+    }
+
+    let mut checkpoint_positions = checkpoint
+        .positions
+        .into_iter()
+        .map(|position| (position.position_id.clone(), position))
+        .collect::<HashMap<_, _>>();
+
+    let mut positions = Vec::with_capacity(snapshot.positions.len());
+    for position in snapshot.positions {
+        match checkpoint_positions.remove(&position.position_id) {
+            Some(checkpoint) if checkpoint.quantity == position.quantity => {
+                positions.push(checkpoint);
+            }
+            checkpoint => {
+                discrepancies.push(Discrepancy::Position {
+                    position_id: position.position_id.clone(),
+                    checkpoint: checkpoint.map(|checkpoint| checkpoint.quantity),
+                    exchange: Some(position.quantity),
+                });
+                positions.push(position);
+            }
+        }
+    }
+    discrepancies.extend(checkpoint_positions.into_values().map(|checkpoint| {
+        Discrepancy::Position {
+            position_id: checkpoint.position_id,
+            checkpoint: Some(checkpoint.quantity),
+            exchange: None,
+        }
+    }));
+
+    let mut orders_unmatched = snapshot.orders_open.iter().collect::<Vec<_>>();
+    for order in checkpoint.orders_pending {
+        match orders_unmatched
+            .iter()
+            .position(|open| is_same_order(open, &order))
+        {
+            Some(index) => {
+                orders_unmatched.swap_remove(index);
+            }
+            None => discrepancies.push(Discrepancy::OrderResolved(order)),
+        }
+    }
+    discrepancies.extend(
+        orders_unmatched
+            .into_iter()
+            .cloned()
+            .map(Discrepancy::OrderUnknown),
+    );
+
+    Reconciliation {
+        balance: snapshot.balance,
+        positions,
+        orders_pending: snapshot.orders_open,
+        discrepancies,
+    }
+}
+
+/// Determine if two `OrderEvent`s are for the same market, decision & quantity.
+fn is_same_order(a: &OrderEvent, b: &OrderEvent) -> bool {
+    a.exchange == b.exchange
+        && a.instrument == b.instrument
+        && a.decision == b.decision
+        && a.quantity == b.quantity
+}
+
+/// Handles the reading & writing of [`EngineCheckpoint`]s to/from the persistence layer.
+pub trait CheckpointStore: Debug {
+    /// Upsert the [`EngineCheckpoint`] using it's engine_id.
+    fn save(&mut self, checkpoint: &EngineCheckpoint) -> Result<(), EngineError>;
+
+    /// Get the latest [`EngineCheckpoint`] saved for the engine_id, if any.
+    fn load(&mut self, engine_id: Uuid) -> Result<Option<EngineCheckpoint>, EngineError>;
+}
+
+/// In-Memory [`CheckpointStore`]. Checkpoints do not survive a process restart, so it is only
+/// useful for testing & backtests.
+#[derive(Debug, Default)]
+pub struct InMemoryCheckpointStore {
+    checkpoints: HashMap<Uuid, EngineCheckpoint>,
+}
+
+impl CheckpointStore for InMemoryCheckpointStore {
+    fn save(&mut self, checkpoint: &EngineCheckpoint) -> Result<(), EngineError> {
+        self.checkpoints
+            .insert(checkpoint.engine_id, checkpoint.clone());
+        Ok(())
+    }
+
+    fn load(&mut self, engine_id: Uuid) -> Result<Option<EngineCheckpoint>, EngineError> {
+        Ok(self.checkpoints.get(&engine_id).cloned())
+    }
+}
+
+/// [`CheckpointStore`] persisting each [`EngineCheckpoint`] as a JSON file in the provided
+/// directory.
+///
+/// The file is written atomically (via a temporary file rename), so a crash while saving leaves
+/// the previous [`EngineCheckpoint`] intact.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct FileCheckpointStore {
+    directory: PathBuf,
+}
+
+impl CheckpointStore for FileCheckpointStore {
+    fn save(&mut self, checkpoint: &EngineCheckpoint) -> Result<(), EngineError> {
+        let path = self.path(checkpoint.engine_id);
+        let path_tmp = path.with_extension("json.tmp");
+
+        fs::create_dir_all(&self.directory)?;
+        fs::write(&path_tmp, serde_json::to_vec(checkpoint)?)?;
+        fs::rename(path_tmp, path)?;
+        Ok(())
+    }
+
+    fn load(&mut self, engine_id: Uuid) -> Result<Option<EngineCheckpoint>, EngineError> {
+        match fs::read(self.path(engine_id)) {
+            Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error.into()),
+        }
+    }
+}
+
+impl FileCheckpointStore {
+    /// Constructs a new [`FileCheckpointStore`] persisting to the provided directory.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    fn path(&self, engine_id: Uuid) -> PathBuf {
+        self.directory.join(format!("checkpoint_{engine_id}.json"))
+    }
+}
+
+/// Periodically saves an [`EngineCheckpoint`] to it's [`CheckpointStore`] while the
+/// [`Engine`](super::Engine) runs, and loads the latest on restart.
+#[derive(Debug)]
+pub struct Checkpointer {
+    store: Box<dyn CheckpointStore + Send>,
+    interval: Duration,
+}
+
+impl Checkpointer {
+    /// Constructs a new [`Checkpointer`] saving to the [`CheckpointStore`] at every interval.
+    pub fn new<Store>(store: Store, interval: Duration) -> Self
+    where
+        Store: CheckpointStore + Send + 'static,
+    {
+        Self {
+            store: Box::new(store),
+            interval,
+        }
+    }
+
+    /// Interval between each saved [`EngineCheckpoint`].
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Save the [`EngineCheckpoint`], logging any failure since the next interval will retry.
+    pub fn save(&mut self, checkpoint: &EngineCheckpoint) {
+        if let Err(error) = self.store.save(checkpoint) {
+            warn!(
+                engine_id = %checkpoint.engine_id,
+                %error,
+                "failed to save EngineCheckpoint"
+            );
+        }
+    }
+
+    /// Load the latest [`EngineCheckpoint`] saved for the engine_id, if any.
+    pub fn load(&mut self, engine_id: Uuid) -> Result<Option<EngineCheckpoint>, EngineError> {
+        self.store.load(engine_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{order_event, position};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_reconcile() {
+        let time = Utc::now();
+        let balance = Balance::new(time, dec!(1000), dec!(500));
+
+        let position_unchanged = Position {
+            quantity: dec!(1),
+            ..position()
+        };
+        let position_changed = Position {
+            position_id: "changed".into(),
+            quantity: dec!(2),
+            ..position()
+        };
+        let position_closed = Position {
+            position_id: "closed".into(),
+            ..position()
+        };
+
+        let order_open = OrderEvent {
+            quantity: dec!(1),
+            ..order_event()
+        };
+        let order_filled = OrderEvent {
+            quantity: dec!(2),
+            ..order_event()
+        };
+        let order_unknown = OrderEvent {
+            quantity: dec!(3),
+            ..order_event()
+        };
+
+        let checkpoint = EngineCheckpoint {
+            engine_id: Uuid::new_v4(),
+            time,
+            balance,
+            positions: vec![
+                position_unchanged.clone(),
+                position_changed.clone(),
+                position_closed.clone(),
+            ],
+            orders_pending: vec![order_open.clone(), order_filled.clone()],
+        };
+
+        let exchange_position_unchanged = Position {
+            enter_avg_price_gross: dec!(999),
+            ..position_unchanged.clone()
+        };
+        let exchange_position_changed = Position {
+            quantity: dec!(3),
+            ..position_changed
+        };
+        let snapshot = AccountSnapshot {
+            time,
+            balance: Balance::new(time, dec!(1100), dec!(600)),
+            positions: vec![
+                exchange_position_unchanged,
+                exchange_position_changed.clone(),
+            ],
+            orders_open: vec![order_open.clone(), order_unknown.clone()],
+        };
+
+        let actual = reconcile(Some(checkpoint), snapshot.clone());
+
+        assert_eq!(actual.balance, snapshot.balance);
+        assert_eq!(
+            actual.positions,
+            vec![position_unchanged, exchange_position_changed]
+        );
+        assert_eq!(
+            actual.orders_pending,
+            vec![order_open, order_unknown.clone()]
+        );
+        assert_eq!(
+            actual.discrepancies,
+            vec![
+                Discrepancy::Balance {
+                    checkpoint: balance,
+                    exchange: snapshot.balance
+                },
+                Discrepancy::Position {
+                    position_id: "changed".into(),
+                    checkpoint: Some(dec!(2)),
+                    exchange: Some(dec!(3)),
+                },
+                Discrepancy::Position {
+                    position_id: "closed".into(),
+                    checkpoint: Some(position_closed.quantity),
+                    exchange: None,
+                },
+                Discrepancy::OrderResolved(order_filled),
+                Discrepancy::OrderUnknown(order_unknown),
+            ]
+        );
+    }
+}
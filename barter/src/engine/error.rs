@@ -1,4 +1,6 @@
-use crate::portfolio::repository::error::RepositoryError;
+use crate::{execution::error::ExecutionError, portfolio::repository::error::RepositoryError};
+use barter_instrument::exchange::ExchangeId;
+use std::time::Duration;
 use thiserror::Error;
 
 /// All errors generated in barter-engine.
@@ -7,6 +9,30 @@ pub enum EngineError {
     #[error("Failed to build struct due to missing attributes: {0}")]
     BuilderIncomplete(&'static str),
 
+    #[error("Invalid schedule: {0}")]
+    InvalidSchedule(String),
+
+    #[error("Failed to read or write checkpoint: {0}")]
+    CheckpointIo(#[from] std::io::Error),
+
+    #[error("Failed to (de)serialise checkpoint: {0}")]
+    CheckpointSerde(#[from] serde_json::Error),
+
+    #[error("Failed to serialise statistics summary: {0}")]
+    SummarySerde(serde_json::Error),
+
+    #[error("Failed to execute request: {0}")]
+    Execution(#[from] ExecutionError),
+
+    #[error("No Trader is associated with exchange: {0}")]
+    ExchangeNotFound(ExchangeId),
+
+    #[error("Trader dropped the request before responding")]
+    TraderOffline,
+
+    #[error("Trader for market {market} did not respond within {timeout:?}")]
+    TraderTimeout { market: String, timeout: Duration },
+
     #[error("Failed to interact with repository")]
     RepositoryInteractionError(#[from] RepositoryError),
 }
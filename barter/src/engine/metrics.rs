@@ -0,0 +1,458 @@
+use crate::{data::QueueStats, event::Event, execution::lifecycle::OrderLifecycle};
+use barter_instrument::exchange::ExchangeId;
+use chrono::TimeDelta;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// Default window over which the [`EngineMetrics`] events per second rate is measured.
+pub const DEFAULT_RATE_WINDOW: Duration = Duration::from_secs(1);
+
+/// Default number of most recent order latencies of each exchange used to calculate the
+/// [`EngineMetrics`] order latency percentiles.
+pub const DEFAULT_ORDER_LATENCY_SAMPLES: usize = 1000;
+
+/// Kind of [`Event`] handled by the [`Trader`](super::trader::Trader) event loop, used to label
+/// [`EngineMetrics`] processing latencies.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum EventKind {
+    Market,
+    Signal,
+    SignalForceExit,
+    OrderNew,
+    Fill,
+    Scheduled,
+    Custom,
+    Other,
+}
+
+impl From<&Event> for EventKind {
+    fn from(event: &Event) -> Self {
+        match event {
+            Event::Market(_) => Self::Market,
+            Event::Signal(_) => Self::Signal,
+            Event::SignalForceExit(_) => Self::SignalForceExit,
+            Event::OrderNew(_) => Self::OrderNew,
+            Event::Fill(_) => Self::Fill,
+            Event::Scheduled(_) => Self::Scheduled,
+            Event::Custom(_) => Self::Custom,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Processing latency statistics of one [`EventKind`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct LatencyStats {
+    pub count: u64,
+    pub total: Duration,
+    pub max: Duration,
+}
+
+impl LatencyStats {
+    /// Update the [`LatencyStats`] with a new latency observation.
+    pub fn update(&mut self, latency: Duration) {
+        self.count += 1;
+        self.total += latency;
+        self.max = self.max.max(latency);
+    }
+
+    /// Mean latency of every observation, or [`Duration::ZERO`] if there are none.
+    pub fn mean(&self) -> Duration {
+        match self.count {
+            0 => Duration::ZERO,
+            count => Duration::from_nanos((self.total.as_nanos() / u128::from(count)) as u64),
+        }
+    }
+}
+
+/// Latency percentiles of the most recent observations in a [`LatencySamples`] window.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct LatencyPercentiles {
+    pub count: usize,
+    pub p50: Duration,
+    pub p90: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+}
+
+/// Bounded window of the most recent latency observations, used to calculate
+/// [`LatencyPercentiles`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct LatencySamples {
+    capacity: usize,
+    samples: VecDeque<Duration>,
+}
+
+impl LatencySamples {
+    /// Construct a new empty [`LatencySamples`] window retaining at most `capacity` observations.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            samples: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Record a new latency observation, evicting the oldest if the window is full.
+    pub fn record(&mut self, latency: Duration) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(latency);
+    }
+
+    /// Calculate the nearest-rank [`LatencyPercentiles`] of the observations in the window.
+    pub fn percentiles(&self) -> LatencyPercentiles {
+        let mut sorted = self.samples.iter().copied().collect::<Vec<_>>();
+        sorted.sort_unstable();
+
+        let percentile = |percentile: f64| {
+            let rank = (percentile * sorted.len() as f64).ceil() as usize;
+            sorted
+                .get(rank.saturating_sub(1))
+                .copied()
+                .unwrap_or_default()
+        };
+
+        LatencyPercentiles {
+            count: sorted.len(),
+            p50: percentile(0.50),
+            p90: percentile(0.90),
+            p99: percentile(0.99),
+            max: sorted.last().copied().unwrap_or_default(),
+        }
+    }
+}
+
+/// Order acknowledgement & fill [`LatencyPercentiles`] of one exchange.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct OrderLatencyPercentiles {
+    /// Latency between an `OrderEvent` being sent and acknowledged.
+    pub ack: LatencyPercentiles,
+    /// Latency between an `OrderEvent` being sent and it's first `FillEvent`.
+    pub fill: LatencyPercentiles,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct OrderLatencySamples {
+    ack: LatencySamples,
+    fill: LatencySamples,
+}
+
+/// Event queue metrics of the [`Engine`](super::Engine) market data feed, usually shared by
+/// every [`Trader`](super::trader::Trader).
+///
+/// Tracks the feed queue depth (if the [`MarketGenerator`](crate::data::MarketGenerator)
+/// is queue backed), the number of conflated events, the events per second consumed from the
+/// feed, the processing latency of each [`EventKind`], and the order acknowledgement & fill
+/// latency percentiles of each exchange. Use an [`EngineMetricsSnapshot`] to observe them from
+/// elsewhere (eg/ a monitoring task).
+#[derive(Clone, Debug)]
+pub struct EngineMetrics {
+    window: Duration,
+    window_start: Option<Instant>,
+    window_events: u64,
+    events_per_sec: f64,
+    queue_depth: usize,
+    max_queue_depth: usize,
+    conflated: u64,
+    latencies: HashMap<EventKind, LatencyStats>,
+    order_latency_samples: usize,
+    order_latencies: HashMap<ExchangeId, OrderLatencySamples>,
+}
+
+impl Default for EngineMetrics {
+    fn default() -> Self {
+        Self::new(DEFAULT_RATE_WINDOW)
+    }
+}
+
+impl EngineMetrics {
+    /// Construct a new [`EngineMetrics`] measuring the events per second rate over the
+    /// provided window.
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            window_start: None,
+            window_events: 0,
+            events_per_sec: 0.0,
+            queue_depth: 0,
+            max_queue_depth: 0,
+            conflated: 0,
+            latencies: HashMap::new(),
+            order_latency_samples: DEFAULT_ORDER_LATENCY_SAMPLES,
+            order_latencies: HashMap::new(),
+        }
+    }
+
+    /// Use the provided number of most recent order latencies of each exchange to calculate the
+    /// order latency percentiles. Defaults to [`DEFAULT_ORDER_LATENCY_SAMPLES`].
+    pub fn with_order_latency_samples(self, samples: usize) -> Self {
+        Self {
+            order_latency_samples: samples,
+            ..self
+        }
+    }
+
+    /// Record an event consumed from the feed at the provided [`Instant`], along with the
+    /// feed [`QueueStats`] (if any) observed after consuming it.
+    pub fn record_feed(&mut self, now: Instant, queue: Option<QueueStats>) {
+        if let Some(queue) = queue {
+            self.queue_depth = queue.depth;
+            self.max_queue_depth = self.max_queue_depth.max(queue.depth);
+            self.conflated = queue.conflated;
+        }
+
+        let window_start = *self.window_start.get_or_insert(now);
+        self.window_events += 1;
+
+        let elapsed = now.saturating_duration_since(window_start);
+        if elapsed >= self.window {
+            self.events_per_sec = self.window_events as f64 / elapsed.as_secs_f64();
+            self.window_start = Some(now);
+            self.window_events = 0;
+        }
+    }
+
+    /// Record the processing latency of an [`EventKind`].
+    pub fn record_latency(&mut self, kind: EventKind, latency: Duration) {
+        self.latencies.entry(kind).or_default().update(latency);
+    }
+
+    /// Record the acknowledgement & fill latency of a completed [`OrderLifecycle`].
+    pub fn record_order(&mut self, order: &OrderLifecycle) {
+        let capacity = self.order_latency_samples;
+        let samples = self
+            .order_latencies
+            .entry(order.exchange)
+            .or_insert_with(|| OrderLatencySamples {
+                ack: LatencySamples::new(capacity),
+                fill: LatencySamples::new(capacity),
+            });
+
+        if let Some(ack) = order.timestamps.ack_latency() {
+            samples.ack.record(duration(ack));
+        }
+
+        if let Some(fill) = order.timestamps.fill_latency() {
+            samples.fill.record(duration(fill));
+        }
+    }
+
+    /// Generate an [`EngineMetricsSnapshot`] of the current metrics.
+    pub fn snapshot(&self) -> EngineMetricsSnapshot {
+        EngineMetricsSnapshot {
+            queue_depth: self.queue_depth,
+            max_queue_depth: self.max_queue_depth,
+            conflated: self.conflated,
+            events_per_sec: self.events_per_sec,
+            latencies: self.latencies.clone(),
+            order_latencies: self
+                .order_latencies
+                .iter()
+                .map(|(exchange, samples)| {
+                    let percentiles = OrderLatencyPercentiles {
+                        ack: samples.ack.percentiles(),
+                        fill: samples.fill.percentiles(),
+                    };
+                    (*exchange, percentiles)
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Negative latencies (eg/ from clock adjustments) are recorded as zero.
+fn duration(latency: TimeDelta) -> Duration {
+    latency.to_std().unwrap_or_default()
+}
+
+/// Snapshot of the [`EngineMetrics`].
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct EngineMetricsSnapshot {
+    /// Feed queue depth observed after the last consumed event.
+    pub queue_depth: usize,
+    /// Maximum feed queue depth observed.
+    pub max_queue_depth: usize,
+    /// Number of market events dropped by the feed
+    /// [`OverloadPolicy`](crate::data::live::OverloadPolicy).
+    pub conflated: u64,
+    /// Events per second consumed from the feed over the last complete rate window.
+    pub events_per_sec: f64,
+    /// Processing latency of each [`EventKind`].
+    pub latencies: HashMap<EventKind, LatencyStats>,
+    /// Order acknowledgement & fill [`LatencyPercentiles`] of each exchange.
+    pub order_latencies: HashMap<ExchangeId, OrderLatencyPercentiles>,
+}
+
+/// Records the processing latency of an [`EventKind`] to the [`EngineMetrics`] when dropped.
+#[derive(Debug)]
+pub(super) struct EventTimer {
+    metrics: Arc<Mutex<EngineMetrics>>,
+    kind: EventKind,
+    start: Instant,
+}
+
+impl EventTimer {
+    pub(super) fn start(metrics: &Arc<Mutex<EngineMetrics>>, event: &Event) -> Self {
+        Self {
+            metrics: Arc::clone(metrics),
+            kind: EventKind::from(event),
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Drop for EventTimer {
+    fn drop(&mut self) {
+        self.metrics
+            .lock()
+            .record_latency(self.kind, self.start.elapsed());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_engine_metrics_record_feed() {
+        struct TestCase {
+            elapsed: Duration,
+            queue: Option<QueueStats>,
+            expected: EngineMetricsSnapshot,
+        }
+
+        let start = Instant::now();
+        let mut metrics = EngineMetrics::new(Duration::from_secs(1));
+
+        let tests = vec![
+            TestCase {
+                // TC0: first event starts the rate window
+                elapsed: Duration::ZERO,
+                queue: Some(QueueStats {
+                    depth: 10,
+                    conflated: 0,
+                }),
+                expected: EngineMetricsSnapshot {
+                    queue_depth: 10,
+                    max_queue_depth: 10,
+                    ..EngineMetricsSnapshot::default()
+                },
+            },
+            TestCase {
+                // TC1: event within the rate window, queue draining
+                elapsed: Duration::from_millis(500),
+                queue: Some(QueueStats {
+                    depth: 4,
+                    conflated: 2,
+                }),
+                expected: EngineMetricsSnapshot {
+                    queue_depth: 4,
+                    max_queue_depth: 10,
+                    conflated: 2,
+                    ..EngineMetricsSnapshot::default()
+                },
+            },
+            TestCase {
+                // TC2: event completing the rate window, from a feed without a queue
+                elapsed: Duration::from_secs(2),
+                queue: None,
+                expected: EngineMetricsSnapshot {
+                    queue_depth: 4,
+                    max_queue_depth: 10,
+                    conflated: 2,
+                    events_per_sec: 1.5,
+                    ..EngineMetricsSnapshot::default()
+                },
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            metrics.record_feed(start + test.elapsed, test.queue);
+            assert_eq!(metrics.snapshot(), test.expected, "TC{index} failed");
+        }
+
+        metrics.record_latency(EventKind::Market, Duration::from_micros(10));
+        metrics.record_latency(EventKind::Market, Duration::from_micros(30));
+        assert_eq!(
+            metrics.snapshot().latencies[&EventKind::Market],
+            LatencyStats {
+                count: 2,
+                total: Duration::from_micros(40),
+                max: Duration::from_micros(30),
+            }
+        );
+        assert_eq!(
+            metrics.snapshot().latencies[&EventKind::Market].mean(),
+            Duration::from_micros(20)
+        );
+    }
+
+    #[test]
+    fn test_latency_samples_percentiles() {
+        struct TestCase {
+            input: Vec<u64>,
+            expected: LatencyPercentiles,
+        }
+
+        let ms = Duration::from_millis;
+
+        let tests = vec![
+            TestCase {
+                // TC0: no observations
+                input: vec![],
+                expected: LatencyPercentiles::default(),
+            },
+            TestCase {
+                // TC1: single observation
+                input: vec![5],
+                expected: LatencyPercentiles {
+                    count: 1,
+                    p50: ms(5),
+                    p90: ms(5),
+                    p99: ms(5),
+                    max: ms(5),
+                },
+            },
+            TestCase {
+                // TC2: unordered observations
+                input: vec![10, 1, 9, 2, 8, 3, 7, 4, 6, 5],
+                expected: LatencyPercentiles {
+                    count: 10,
+                    p50: ms(5),
+                    p90: ms(9),
+                    p99: ms(10),
+                    max: ms(10),
+                },
+            },
+            TestCase {
+                // TC3: oldest observations are evicted once the window is full
+                input: (1..=20).collect(),
+                expected: LatencyPercentiles {
+                    count: 10,
+                    p50: ms(15),
+                    p90: ms(19),
+                    p99: ms(20),
+                    max: ms(20),
+                },
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut samples = LatencySamples::new(10);
+            test.input
+                .into_iter()
+                .for_each(|latency| samples.record(ms(latency)));
+            assert_eq!(samples.percentiles(), test.expected, "TC{index} failed");
+        }
+    }
+}
@@ -1,26 +1,41 @@
 use crate::{
     data::MarketGenerator,
-    engine::{error::EngineError, trader::Trader},
+    engine::{
+        checkpoint::{AccountSnapshot, Checkpointer, EngineCheckpoint, Reconciliation},
+        error::EngineError,
+        shutdown::{ShutdownPolicy, SHUTDOWN_POLL_INTERVAL},
+        trader::Trader,
+    },
     event::{Event, MessageTransmitter},
     execution::ExecutionClient,
     portfolio::{
         position::Position,
-        repository::{PositionHandler, StatisticHandler},
-        FillUpdater, MarketUpdater, OrderGenerator,
+        repository::{BalanceHandler, PositionHandler, StatisticHandler},
+        Balance, FillUpdater, MarketUpdater, OrderEvent, OrderGenerator,
     },
-    statistic::summary::{PositionSummariser, TableBuilder},
+    statistic::summary::{PositionSummariser, ProgressiveSummariser, TableBuilder},
     strategy::SignalGenerator,
+    system::topology::{pin_current_thread_or_warn, EngineRuntime, ThreadTopology},
 };
-use barter_data::event::{DataKind, MarketEvent};
+use barter_data::event::{CustomData, DataKind, MarketEvent};
+use barter_execution::model::transfer::{Transfer, TransferRequest, WithdrawRequest, Withdrawal};
 use barter_instrument::{
+    exchange::ExchangeId,
     instrument::Instrument,
     market::{Market, MarketId},
 };
+use chrono::{DateTime, Utc};
 use parking_lot::Mutex;
 use prettytable::Table;
-use serde::Serialize;
-use smol_str::ToSmolStr;
-use std::{collections::HashMap, fmt::Debug, sync::Arc, thread};
+use serde::{Deserialize, Serialize};
+use smol_str::{SmolStr, ToSmolStr};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Debug,
+    sync::Arc,
+    thread,
+    time::Duration,
+};
 use tokio::sync::{mpsc, oneshot};
 use tracing::{error, info, warn};
 use uuid::Uuid;
@@ -28,11 +43,40 @@ use uuid::Uuid;
 /// Barter Engine module specific errors.
 pub mod error;
 
+/// Default time an [`Engine`] waits for each [`Trader`] to respond to a request (eg/ for it's
+/// in-flight `OrderEvent`s), since a [`Trader`] only responds between `MarketEvent`s.
+pub const DEFAULT_TRADER_RESPONSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// High-priority [`PriorityCommand`](priority::PriorityCommand) path for emergency commands
+/// (eg/ EmergencyStop, CancelAll) that bypasses the [`Trader`] market data feed & [`Command`] queue.
+pub mod priority;
+
 /// Contains the trading event loop for a Trader capable of trading a single market pair. A Trader
 /// has its own Data handler, Strategy & Execution handler, as well as shared access to a global
 /// Portfolio instance.
 pub mod trader;
 
+/// Cron-like [`Scheduler`](schedule::Scheduler) of time-based tasks (eg/ flatten at 00:00 UTC,
+/// rebalance every 5 minutes) actioned by each [`Trader`].
+pub mod schedule;
+
+/// Exchange [`TradingCalendar`](calendar::TradingCalendar)s of trading sessions, holidays &
+/// maintenance windows, so orders are only generated while an exchange is open.
+pub mod calendar;
+
+/// Periodic [`EngineCheckpoint`](checkpoint::EngineCheckpoint)s of the [`Engine`] state, and
+/// their reconciliation against a fresh [`AccountSnapshot`](checkpoint::AccountSnapshot) when
+/// restarting.
+pub mod checkpoint;
+
+/// Event queue [`EngineMetrics`](metrics::EngineMetrics) of the [`Trader`] market data feeds
+/// (queue depth, events per second & per [`Event`] processing latency).
+pub mod metrics;
+
+/// [`ShutdownPolicy`](shutdown::ShutdownPolicy) determining how an [`Engine`] handles it's open
+/// orders & [`Position`]s when gracefully shutting down.
+pub mod shutdown;
+
 /// Commands that can be actioned by an [`Engine`] and it's associated [`Trader`]s.
 #[derive(Debug)]
 pub enum Command {
@@ -40,15 +84,112 @@ pub enum Command {
     /// `oneshot::Sender`. Involves the [`Engine`] only.
     FetchOpenPositions(oneshot::Sender<Result<Vec<Position>, EngineError>>),
 
-    /// Terminate every running [`Trader`] associated with this [`Engine`]. Involves all [`Trader`]s.
+    /// Requests a serialisable [`EngineState`] snapshot of the [`Engine`]'s balance, open
+    /// [`Position`]s & in-flight `OrderEvent`s, and sends it on the provided `oneshot::Sender`.
+    /// Involves the [`Engine`] & all [`Trader`]s.
+    RequestSnapshot(oneshot::Sender<Result<EngineState, EngineError>>),
+
+    /// Fetches the trading session summary so far, with it's time dependent statistics calculated
+    /// up to `now` (see [`ProgressiveSummariser`]), and sends it on the provided
+    /// `oneshot::Sender`. Involves the [`Engine`] only.
+    FetchSummary {
+        now: DateTime<Utc>,
+        response_tx: oneshot::Sender<Result<SessionSummary, EngineError>>,
+    },
+
+    /// Fetches the `OrderEvent`s submitted by the [`Trader`]s that have not yet been executed, and
+    /// sends them on the provided `oneshot::Sender`. Involves all [`Trader`]s.
+    FetchOrdersPending(oneshot::Sender<Result<Vec<OrderEvent>, EngineError>>),
+
+    /// Gracefully shut down the [`Engine`] according to it's configured [`ShutdownPolicy`],
+    /// terminating every running [`Trader`]. Involves all [`Trader`]s.
     Terminate(String),
 
+    /// Gracefully shut down the [`Engine`] according to the provided [`ShutdownPolicy`] (rather
+    /// than it's configured one), terminating every running [`Trader`]. Involves all [`Trader`]s.
+    Shutdown {
+        reason: String,
+        policy: ShutdownPolicy,
+    },
+
+    /// Cancel every order that has been generated, but has not yet been sent to the
+    /// [`ExecutionClient`]. Involves all [`Trader`]s.
+    CancelAllOrders,
+
     /// Exit every open [`Position`] associated with this [`Engine`]. Involves all [`Trader`]s.
     ExitAllPositions,
 
     /// Exit a [`Position`]. Uses the [`Market`] provided to route this [`Command`] to the relevant
     /// [`Trader`] instance. Involves one [`Trader`].
     ExitPosition(Market),
+
+    /// Update the Strategy configuration of the [`Trader`] of the provided [`Market`], or of every
+    /// [`Trader`] if `None`, without restarting the [`Engine`]. The serialised config is applied
+    /// via [`SignalGenerator::update_config`]. Involves one or all [`Trader`]s.
+    UpdateStrategyConfig {
+        market: Option<Market>,
+        config: serde_json::Value,
+    },
+
+    /// Inject a downstream defined custom event (eg/ news, model signals) into the event loop of
+    /// the [`Trader`] of the provided [`Market`], or of every [`Trader`] if `None`. The
+    /// [`Trader`] processes it in order with it's market data as an [`Event::Custom`], via
+    /// [`SignalGenerator::on_custom`]. Involves one or all [`Trader`]s.
+    Custom {
+        market: Option<Market>,
+        event: CustomData,
+    },
+
+    /// Transfer a quantity between two wallets of the provided exchange account via the
+    /// [`ExecutionClient`], and send the result on the provided `oneshot::Sender`. Uses the
+    /// exchange to route this [`Command`] to a relevant [`Trader`]. Involves one [`Trader`].
+    Transfer {
+        exchange: ExchangeId,
+        request: TransferRequest,
+        response_tx: oneshot::Sender<Result<Transfer, EngineError>>,
+    },
+
+    /// Withdraw a quantity from the provided exchange account via the [`ExecutionClient`], and
+    /// send the result on the provided `oneshot::Sender`. Uses the exchange to route this
+    /// [`Command`] to a relevant [`Trader`]. Involves one [`Trader`].
+    Withdraw {
+        exchange: ExchangeId,
+        request: WithdrawRequest,
+        response_tx: oneshot::Sender<Result<Withdrawal, EngineError>>,
+    },
+}
+
+/// Snapshot of the current [`Engine`] state, sent in response to a [`Command::RequestSnapshot`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct EngineState {
+    pub engine_id: Uuid,
+    pub time: DateTime<Utc>,
+    pub balance: Balance,
+    pub positions: Vec<Position>,
+    /// `OrderEvent`s submitted by every [`Trader`] that have not yet been executed.
+    pub orders_pending: Vec<OrderEvent>,
+}
+
+impl From<EngineState> for EngineCheckpoint {
+    fn from(state: EngineState) -> Self {
+        Self {
+            engine_id: state.engine_id,
+            time: state.time,
+            balance: state.balance,
+            positions: state.positions,
+            orders_pending: state.orders_pending,
+        }
+    }
+}
+
+/// Trading session summary so far, sent in response to a [`Command::FetchSummary`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct SessionSummary {
+    pub time: DateTime<Utc>,
+    /// Serialised statistics summary of each [`Market`] traded, keyed by [`MarketId`].
+    pub markets: BTreeMap<SmolStr, serde_json::Value>,
+    /// Serialised statistics summary across every [`Market`] traded.
+    pub total: serde_json::Value,
 }
 
 /// Lego components for constructing an [`Engine`] via the new() constructor method.
@@ -77,6 +218,17 @@ where
     /// Uses trading session's exited [`Position`]s to calculate an average statistical summary
     /// across all [`Market`]s traded.
     pub statistics_summary: Statistic,
+    /// Optional [`Checkpointer`] that periodically saves an [`EngineCheckpoint`] while running,
+    /// and loads the latest when [`restoring`](Engine::restore) after a restart.
+    pub checkpoint: Option<Checkpointer>,
+    /// [`ThreadTopology`] determining the core affinity of each [`Trader`] thread, and how the
+    /// [`Engine`] loop is [`run`](Engine::run_with_topology).
+    pub topology: ThreadTopology,
+    /// [`ShutdownPolicy`] actioned when the [`Engine`] receives a [`Command::Terminate`].
+    pub shutdown_policy: ShutdownPolicy,
+    /// Maximum time the [`Engine`] waits for each [`Trader`] to respond to a request, so a
+    /// [`Trader`] stalled waiting on it's next `MarketEvent` cannot block the [`Command`] loop.
+    pub trader_response_timeout: Duration,
 }
 
 /// Multi-threaded Trading Engine capable of trading with an arbitrary number of [`Trader`]s, one
@@ -92,6 +244,7 @@ where
     EventTx: MessageTransmitter<Event>,
     Statistic: PositionSummariser + Serialize + Send,
     Portfolio: PositionHandler
+        + BalanceHandler
         + StatisticHandler<Statistic>
         + MarketUpdater
         + OrderGenerator
@@ -118,14 +271,29 @@ where
     /// Uses trading session's exited [`Position`]s to calculate an average statistical summary
     /// across all [`Market`]s traded.
     statistics_summary: Statistic,
+    /// Number of exited [`Position`]s already summarised by the `statistics_summary`, so it is
+    /// updated incrementally.
+    summarised_positions: usize,
+    /// Optional [`Checkpointer`] that periodically saves an [`EngineCheckpoint`] while running,
+    /// and loads the latest when [`restoring`](Engine::restore) after a restart.
+    checkpoint: Option<Checkpointer>,
+    /// [`ThreadTopology`] determining the core affinity of each [`Trader`] thread, and how the
+    /// [`Engine`] loop is [`run`](Engine::run_with_topology).
+    topology: ThreadTopology,
+    /// [`ShutdownPolicy`] actioned when the [`Engine`] receives a [`Command::Terminate`].
+    shutdown_policy: ShutdownPolicy,
+    /// Maximum time the [`Engine`] waits for each [`Trader`] to respond to a request, so a
+    /// [`Trader`] stalled waiting on it's next `MarketEvent` cannot block the [`Command`] loop.
+    trader_response_timeout: Duration,
 }
 
 impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
     Engine<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
 where
     EventTx: MessageTransmitter<Event> + Send + 'static,
-    Statistic: PositionSummariser + TableBuilder + Serialize + Send + 'static,
+    Statistic: ProgressiveSummariser + TableBuilder + Serialize + Send + 'static,
     Portfolio: PositionHandler
+        + BalanceHandler
         + StatisticHandler<Statistic>
         + MarketUpdater
         + OrderGenerator
@@ -149,6 +317,11 @@ where
             traders: lego.traders,
             trader_command_txs: lego.trader_command_txs,
             statistics_summary: lego.statistics_summary,
+            summarised_positions: 0,
+            checkpoint: lego.checkpoint,
+            topology: lego.topology,
+            shutdown_policy: lego.shutdown_policy,
+            trader_response_timeout: lego.trader_response_timeout,
         }
     }
 
@@ -166,6 +339,13 @@ where
         // Run Traders on threads & send notification when they have stopped organically
         let mut notify_traders_stopped = self.run_traders().await;
 
+        let mut checkpoint_interval = self.checkpoint.as_ref().map(|checkpointer| {
+            // Skipped ticks are not burst after a checkpoint waits on a stalled Trader
+            let mut interval = tokio::time::interval(checkpointer.interval());
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            interval
+        });
+
         loop {
             // Action received commands from remote, or wait for all Traders to stop organically
             tokio::select! {
@@ -173,22 +353,55 @@ where
                     break;
                 },
 
+                _ = tick(&mut checkpoint_interval) => {
+                    self.save_checkpoint().await;
+                },
+
                 command = self.command_rx.recv() => {
                     if let Some(command) = command {
                         match command {
                             Command::FetchOpenPositions(positions_tx) => {
                                 self.fetch_open_positions(positions_tx).await;
                             },
-                            Command::Terminate(message) => {
-                                self.terminate_traders(message).await;
+                            Command::RequestSnapshot(state_tx) => {
+                                self.request_snapshot(state_tx).await;
+                            },
+                            Command::FetchSummary { now, response_tx } => {
+                                self.fetch_summary(now, response_tx);
+                            },
+                            Command::Terminate(reason) => {
+                                let policy = self.shutdown_policy;
+                                self.shutdown(reason, policy, &mut notify_traders_stopped).await;
                                 break;
                             },
+                            Command::Shutdown { reason, policy } => {
+                                self.shutdown(reason, policy, &mut notify_traders_stopped).await;
+                                break;
+                            },
+                            Command::CancelAllOrders => {
+                                self.cancel_all_orders().await;
+                            },
                             Command::ExitPosition(market) => {
                                 self.exit_position(market).await;
                             },
                             Command::ExitAllPositions => {
                                 self.exit_all_positions().await;
                             },
+                            Command::UpdateStrategyConfig { market, config } => {
+                                self.update_strategy_config(market, config).await;
+                            },
+                            Command::Custom { market, event } => {
+                                self.send_custom_event(market, event).await;
+                            },
+                            Command::Transfer { exchange, request, response_tx } => {
+                                self.transfer(exchange, request, response_tx).await;
+                            },
+                            Command::Withdraw { exchange, request, response_tx } => {
+                                self.withdraw(exchange, request, response_tx).await;
+                            },
+                            Command::FetchOrdersPending(orders_tx) => {
+                                self.send_orders_pending(orders_tx).await;
+                            },
                         }
                     } else {
                         // Terminate traders due to dropped receiver
@@ -202,24 +415,110 @@ where
         self.generate_session_summary().printstd();
     }
 
+    /// Run the trading [`Engine`] according to the [`EngineRuntime`] of it's [`ThreadTopology`],
+    /// completing when the [`Engine`] stops.
+    ///
+    /// [`EngineRuntime::Task`] runs the loop on the caller's task, equivalent to
+    /// [`run`](Self::run). [`EngineRuntime::DedicatedThread`] runs the loop on a new thread
+    /// (pinned to the `engine` core, if any) driving it's own single threaded tokio runtime.
+    pub async fn run_with_topology(self) {
+        match self.topology.engine_runtime {
+            EngineRuntime::Task => self.run().await,
+            EngineRuntime::DedicatedThread => {
+                let core = self.topology.engine;
+                let (stopped_tx, stopped_rx) = oneshot::channel();
+
+                thread::spawn(move || {
+                    pin_current_thread_or_warn(core, "engine");
+                    match tokio::runtime::Builder::new_current_thread()
+                        .enable_all()
+                        .build()
+                    {
+                        Ok(runtime) => runtime.block_on(self.run()),
+                        Err(error) => error!(%error, "failed to build Engine thread runtime"),
+                    }
+                    let _ = stopped_tx.send(());
+                });
+
+                let _ = stopped_rx.await;
+            }
+        }
+    }
+
+    /// Restore the [`Engine`] state before it is run, by reconciling the latest
+    /// [`EngineCheckpoint`] (if any) against a fresh [`AccountSnapshot`] of the exchange account.
+    ///
+    /// The reconciled [`Balance`](crate::portfolio::Balance) & open [`Position`]s replace those
+    /// of the Portfolio, and each [`Trader`] resumes tracking it's in-flight `OrderEvent`s. Every
+    /// [`Discrepancy`](checkpoint::Discrepancy) is logged & returned in the [`Reconciliation`].
+    pub fn restore(&mut self, snapshot: AccountSnapshot) -> Result<Reconciliation, EngineError> {
+        let checkpoint = match &mut self.checkpoint {
+            Some(checkpointer) => checkpointer.load(self.engine_id)?,
+            None => None,
+        };
+
+        let reconciliation = checkpoint::reconcile(checkpoint, snapshot);
+        for discrepancy in &reconciliation.discrepancies {
+            warn!(
+                engine_id = %self.engine_id,
+                ?discrepancy,
+                "EngineCheckpoint differs from AccountSnapshot"
+            );
+        }
+
+        {
+            let mut portfolio = self.portfolio.lock();
+            portfolio.set_balance(self.engine_id, reconciliation.balance)?;
+
+            let positions_stale =
+                portfolio.get_open_positions(self.engine_id, self.trader_command_txs.keys())?;
+            for position in positions_stale {
+                portfolio.remove_position(&position.position_id)?;
+            }
+
+            for position in &reconciliation.positions {
+                portfolio.set_open_position(position.clone())?;
+            }
+        }
+
+        for trader in self.traders.iter_mut() {
+            trader.restore_orders_pending(&reconciliation.orders_pending);
+        }
+
+        info!(
+            engine_id = %self.engine_id,
+            positions = reconciliation.positions.len(),
+            orders_pending = reconciliation.orders_pending.len(),
+            discrepancies = reconciliation.discrepancies.len(),
+            "restored Engine state"
+        );
+
+        Ok(reconciliation)
+    }
+
     /// Runs each [`Trader`] it's own thread. Sends a message on the returned `mpsc::Receiver<bool>`
     /// if all the [`Trader`]s have stopped organically (eg/ due to a finished [`MarketEvent`] feed).
     async fn run_traders(&mut self) -> mpsc::Receiver<bool> {
         // Extract Traders out of the Engine so we can move them into threads
         let traders = std::mem::take(&mut self.traders);
 
-        // Run each Trader instance on it's own thread
+        // Run each Trader instance on it's own thread, pinned to it's core (if any)
         let mut thread_handles = Vec::with_capacity(traders.len());
-        for trader in traders.into_iter() {
-            let handle = thread::spawn(move || trader.run());
+        for (index, trader) in traders.into_iter().enumerate() {
+            let core = self.topology.trader_core(index);
+            let handle = thread::spawn(move || {
+                pin_current_thread_or_warn(core, "trader");
+                trader.run()
+            });
             thread_handles.push(handle);
         }
 
         // Create channel to notify the Engine when the Traders have stopped organically
         let (notify_tx, notify_rx) = mpsc::channel(1);
 
-        // Create Task that notifies Engine when the Traders have stopped organically
-        tokio::spawn(async move {
+        // Create blocking Task that notifies Engine when the Traders have stopped organically,
+        // joining the Trader threads off the async workers so Engine timeouts are still driven
+        tokio::task::spawn_blocking(move || {
             for handle in thread_handles {
                 if let Err(err) = handle.join() {
                     error!(
@@ -229,7 +528,7 @@ where
                 }
             }
 
-            let _ = notify_tx.send(true).await;
+            let _ = notify_tx.blocking_send(true);
         });
 
         notify_rx
@@ -255,12 +554,296 @@ where
         }
     }
 
+    /// Sends an [`EngineState`] snapshot on the provided `oneshot::Sender`.
+    ///
+    /// Note that each [`Trader`] only responds between `MarketEvent`s, so a snapshot waits up to
+    /// the `trader_response_timeout` for every [`Trader`] to receive it's next `MarketEvent`,
+    /// sending an [`EngineError::TraderTimeout`] if any does not.
+    async fn request_snapshot(&self, state_tx: oneshot::Sender<Result<EngineState, EngineError>>) {
+        if state_tx.send(self.fetch_state().await).is_err() {
+            warn!(
+                why = "oneshot receiver dropped",
+                "cannot action Command::RequestSnapshot"
+            );
+        }
+    }
+
+    /// Sends the `OrderEvent`s submitted by every [`Trader`] that have not yet been executed on
+    /// the provided `oneshot::Sender`.
+    async fn send_orders_pending(
+        &self,
+        orders_tx: oneshot::Sender<Result<Vec<OrderEvent>, EngineError>>,
+    ) {
+        if orders_tx.send(self.fetch_orders_pending().await).is_err() {
+            warn!(
+                why = "oneshot receiver dropped",
+                "cannot action Command::FetchOrdersPending"
+            );
+        }
+    }
+
+    /// Fetch an [`EngineState`] snapshot of the Portfolio state & every [`Trader`]'s in-flight
+    /// `OrderEvent`s.
+    async fn fetch_state(&self) -> Result<EngineState, EngineError> {
+        let orders_pending = self.fetch_orders_pending().await?;
+
+        let mut portfolio = self.portfolio.lock();
+        let balance = portfolio.get_balance(self.engine_id)?;
+        let positions =
+            portfolio.get_open_positions(self.engine_id, self.trader_command_txs.keys())?;
+
+        Ok(EngineState {
+            engine_id: self.engine_id,
+            time: Utc::now(),
+            balance,
+            positions,
+            orders_pending,
+        })
+    }
+
+    /// Sends the trading session summary so far, as of `now`, on the provided `oneshot::Sender`.
+    fn fetch_summary(
+        &mut self,
+        now: DateTime<Utc>,
+        response_tx: oneshot::Sender<Result<SessionSummary, EngineError>>,
+    ) {
+        self.update_session_summary();
+
+        if response_tx.send(self.session_summary(now)).is_err() {
+            warn!(
+                why = "oneshot receiver dropped",
+                "cannot action Command::FetchSummary"
+            );
+        }
+    }
+
+    /// Generate the [`SessionSummary`] so far, as of `now`, from the Portfolio's statistics per
+    /// [`Market`] & the total statistics across all [`Market`]s.
+    fn session_summary(&self, now: DateTime<Utc>) -> Result<SessionSummary, EngineError> {
+        let markets = self
+            .trader_command_txs
+            .keys()
+            .map(|market| {
+                let market_id = MarketId::from(market);
+                let statistics = self.portfolio.lock().get_statistics(&market_id)?;
+                let summary = serde_json::to_value(statistics.summary_so_far(now))
+                    .map_err(EngineError::SummarySerde)?;
+                Ok((market_id.0, summary))
+            })
+            .collect::<Result<BTreeMap<_, _>, EngineError>>()?;
+
+        Ok(SessionSummary {
+            time: now,
+            markets,
+            total: serde_json::to_value(self.statistics_summary.summary_so_far(now))
+                .map_err(EngineError::SummarySerde)?,
+        })
+    }
+
+    /// Update the total statistics summary with the [`Position`]s exited since it was last
+    /// updated, rather than regenerating it from every exited [`Position`].
+    fn update_session_summary(&mut self) {
+        let exited_positions = self.portfolio.lock().get_exited_positions(self.engine_id);
+
+        match exited_positions {
+            Ok(exited_positions) => {
+                if let Some(new_positions) = exited_positions.get(self.summarised_positions..) {
+                    self.statistics_summary.generate_summary(new_positions);
+                    self.summarised_positions = exited_positions.len();
+                }
+            }
+            Err(error) => {
+                warn!(
+                    ?error,
+                    why = "failed to get exited Positions from Portfolio's repository",
+                    "failed to update Statistics summary for trading session"
+                );
+            }
+        }
+    }
+
+    /// Save an [`EngineCheckpoint`] of the Portfolio state & every [`Trader`]'s in-flight
+    /// `OrderEvent`s using the [`Checkpointer`].
+    ///
+    /// Note that each [`Trader`] only responds between `MarketEvent`s, so a checkpoint waits up
+    /// to the `trader_response_timeout` for every [`Trader`] to receive it's next `MarketEvent`.
+    /// If any [`Trader`] does not respond in time the checkpoint is skipped, leaving the previous
+    /// [`EngineCheckpoint`] in place until the next interval.
+    async fn save_checkpoint(&mut self) {
+        if self.checkpoint.is_none() {
+            return;
+        }
+
+        let state = match self.fetch_state().await {
+            Ok(state) => state,
+            Err(error @ EngineError::TraderTimeout { .. }) => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    %error,
+                    action = "skipping EngineCheckpoint until next interval",
+                    "failed to fetch Trader in-flight OrderEvents for EngineCheckpoint"
+                );
+                return;
+            }
+            Err(error) => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    ?error,
+                    "failed to get Portfolio state for EngineCheckpoint"
+                );
+                return;
+            }
+        };
+
+        if let Some(checkpointer) = &mut self.checkpoint {
+            checkpointer.save(&EngineCheckpoint::from(state));
+        }
+    }
+
+    /// Fetches the `OrderEvent`s submitted by every [`Trader`] that have not yet been executed.
+    ///
+    /// Returns an [`EngineError::TraderTimeout`] if any [`Trader`] does not respond within the
+    /// `trader_response_timeout`.
+    async fn fetch_orders_pending(&self) -> Result<Vec<OrderEvent>, EngineError> {
+        let mut orders_pending = Vec::new();
+
+        for (market, command_tx) in self.trader_command_txs.iter() {
+            let fetch_orders = async {
+                let (orders_tx, orders_rx) = oneshot::channel();
+
+                if command_tx
+                    .send(Command::FetchOrdersPending(orders_tx))
+                    .await
+                    .is_err()
+                {
+                    error!(
+                        market = &*format!("{:?}", market),
+                        why = "dropped receiver",
+                        "failed to send Command::FetchOrdersPending to Trader command_rx"
+                    );
+                    return Ok(Vec::new());
+                }
+
+                orders_rx.await.unwrap_or_else(|_| {
+                    warn!(
+                        market = &*format!("{:?}", market),
+                        why = "oneshot sender dropped",
+                        "failed to fetch Trader in-flight OrderEvents"
+                    );
+                    Ok(Vec::new())
+                })
+            };
+
+            match tokio::time::timeout(self.trader_response_timeout, fetch_orders).await {
+                Ok(orders) => orders_pending.extend(orders?),
+                Err(_) => {
+                    warn!(
+                        market = &*format!("{:?}", market),
+                        timeout = ?self.trader_response_timeout,
+                        "Trader did not respond to Command::FetchOrdersPending"
+                    );
+                    return Err(EngineError::TraderTimeout {
+                        market: format!("{:?}", market),
+                        timeout: self.trader_response_timeout,
+                    });
+                }
+            }
+        }
+
+        Ok(orders_pending)
+    }
+
+    /// Gracefully shut down the [`Engine`] according to the provided [`ShutdownPolicy`]:
+    /// cancel open orders & exit open [`Position`]s (if configured), wait for in-flight orders to
+    /// resolve, then terminate every [`Trader`] & wait for them to stop. Each wait is bounded by
+    /// the [`ShutdownPolicy`] timeout.
+    async fn shutdown(
+        &self,
+        reason: String,
+        policy: ShutdownPolicy,
+        notify_traders_stopped: &mut mpsc::Receiver<bool>,
+    ) {
+        info!(
+            engine_id = %self.engine_id,
+            %reason,
+            ?policy,
+            "Engine shutting down"
+        );
+
+        if policy.cancel_orders {
+            self.cancel_all_orders().await;
+        }
+
+        if policy.close_positions {
+            self.exit_all_positions().await;
+        }
+
+        if tokio::time::timeout(policy.timeout, self.await_in_flight_resolved(policy))
+            .await
+            .is_err()
+        {
+            warn!(
+                engine_id = %self.engine_id,
+                timeout = ?policy.timeout,
+                action = "terminating Traders regardless",
+                "in-flight orders did not resolve before shutdown timeout"
+            );
+        }
+
+        // Stalled Traders may have a full command_rx (eg/ queued checkpoint requests), in which
+        // case they synthesise a Command::Terminate once the Engine drops it's command_txs
+        if tokio::time::timeout(policy.timeout, self.terminate_traders(reason))
+            .await
+            .is_err()
+        {
+            warn!(
+                engine_id = %self.engine_id,
+                timeout = ?policy.timeout,
+                "Traders did not receive Command::Terminate before shutdown timeout"
+            );
+        }
+
+        if tokio::time::timeout(policy.timeout, notify_traders_stopped.recv())
+            .await
+            .is_err()
+        {
+            warn!(
+                engine_id = %self.engine_id,
+                timeout = ?policy.timeout,
+                "Traders did not stop before shutdown timeout"
+            );
+        }
+    }
+
+    /// Wait until no [`Trader`] has an in-flight `OrderEvent` and, if the [`ShutdownPolicy`]
+    /// closes positions, until the Portfolio has no open [`Position`]s.
+    ///
+    /// Note that each [`Trader`] only responds between `MarketEvent`s, so this waits for every
+    /// [`Trader`] to receive it's next `MarketEvent`.
+    async fn await_in_flight_resolved(&self, policy: ShutdownPolicy) {
+        loop {
+            let orders_resolved = self
+                .fetch_orders_pending()
+                .await
+                .is_ok_and(|orders| orders.is_empty());
+
+            let positions_resolved = !policy.close_positions
+                || self
+                    .portfolio
+                    .lock()
+                    .get_open_positions(self.engine_id, self.trader_command_txs.keys())
+                    .is_ok_and(|positions| positions.is_empty());
+
+            if orders_resolved && positions_resolved {
+                return;
+            }
+
+            tokio::time::sleep(SHUTDOWN_POLL_INTERVAL).await;
+        }
+    }
+
     /// Terminate every running [`Trader`] associated with this [`Engine`].
     async fn terminate_traders(&self, message: String) {
-        // Firstly, exit all Positions
-        self.exit_all_positions().await;
-        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
-
         // Distribute Command::Terminate to all the Engine's Traders
         for (market, command_tx) in self.trader_command_txs.iter() {
             if command_tx
@@ -277,6 +860,20 @@ where
         }
     }
 
+    /// Cancel every order of every [`Trader`] that has not yet been sent to the
+    /// [`ExecutionClient`].
+    async fn cancel_all_orders(&self) {
+        for (market, command_tx) in self.trader_command_txs.iter() {
+            if command_tx.send(Command::CancelAllOrders).await.is_err() {
+                error!(
+                    market = &*format!("{:?}", market),
+                    why = "dropped receiver",
+                    "failed to send Command::CancelAllOrders to Trader command_rx"
+                );
+            }
+        }
+    }
+
     /// Exit every open [`Position`] associated with this [`Engine`].
     async fn exit_all_positions(&self) {
         for (market, command_tx) in self.trader_command_txs.iter() {
@@ -288,7 +885,7 @@ where
                 error!(
                     market = &*format!("{:?}", market),
                     why = "dropped receiver",
-                    "failed to send Command::Terminate to Trader command_rx"
+                    "failed to send Command::ExitPosition to Trader command_rx"
                 );
             }
         }
@@ -306,7 +903,7 @@ where
                 error!(
                     market = &*format!("{:?}", market_ref),
                     why = "dropped receiver",
-                    "failed to send Command::Terminate to Trader command_rx"
+                    "failed to send Command::ExitPosition to Trader command_rx"
                 );
             }
         } else {
@@ -318,9 +915,166 @@ where
         }
     }
 
+    /// Update the Strategy configuration of the [`Trader`] of the provided [`Market`], or of every
+    /// [`Trader`] if `None`.
+    async fn update_strategy_config(&self, market: Option<Market>, config: serde_json::Value) {
+        let command_txs = self.market_command_txs(market.as_ref());
+
+        if command_txs.is_empty() {
+            warn!(
+                market = &*format!("{:?}", market),
+                why = "Engine has no trader_command_tx associated with provided Market",
+                "failed to update Strategy config"
+            );
+        }
+
+        for (market, command_tx) in command_txs {
+            if command_tx
+                .send(Command::UpdateStrategyConfig {
+                    market: Some(market.clone()),
+                    config: config.clone(),
+                })
+                .await
+                .is_err()
+            {
+                error!(
+                    market = &*format!("{:?}", market),
+                    why = "dropped receiver",
+                    "failed to send Command::UpdateStrategyConfig to Trader command_rx"
+                );
+            }
+        }
+    }
+
+    /// Send the custom event to the [`Trader`] of the provided [`Market`], or to every [`Trader`]
+    /// if `None`.
+    async fn send_custom_event(&self, market: Option<Market>, event: CustomData) {
+        let command_txs = self.market_command_txs(market.as_ref());
+
+        if command_txs.is_empty() {
+            warn!(
+                market = &*format!("{:?}", market),
+                name = %event.name,
+                why = "Engine has no trader_command_tx associated with provided Market",
+                "failed to send custom event"
+            );
+        }
+
+        for (market, command_tx) in command_txs {
+            if command_tx
+                .send(Command::Custom {
+                    market: Some(market.clone()),
+                    event: event.clone(),
+                })
+                .await
+                .is_err()
+            {
+                error!(
+                    market = &*format!("{:?}", market),
+                    why = "dropped receiver",
+                    "failed to send Command::Custom to Trader command_rx"
+                );
+            }
+        }
+    }
+
+    /// Every [`Trader`] `command_tx` of the provided [`Market`], or of every [`Trader`] if `None`.
+    fn market_command_txs(
+        &self,
+        market: Option<&Market>,
+    ) -> Vec<(&Market, &mpsc::Sender<Command>)> {
+        self.trader_command_txs
+            .iter()
+            .filter(|(trader_market, _)| market.is_none_or(|market| market == *trader_market))
+            .collect()
+    }
+
+    /// Route a [`Command::Transfer`] to a [`Trader`] of the provided exchange, since the
+    /// [`ExecutionClient`] is owned by each [`Trader`].
+    async fn transfer(
+        &self,
+        exchange: ExchangeId,
+        request: TransferRequest,
+        response_tx: oneshot::Sender<Result<Transfer, EngineError>>,
+    ) {
+        let Some(command_tx) = self.exchange_command_tx(exchange) else {
+            let _ = response_tx.send(Err(EngineError::ExchangeNotFound(exchange)));
+            return;
+        };
+
+        let command = Command::Transfer {
+            exchange,
+            request,
+            response_tx,
+        };
+
+        if let Err(mpsc::error::SendError(Command::Transfer { response_tx, .. })) =
+            command_tx.send(command).await
+        {
+            error!(
+                %exchange,
+                why = "dropped receiver",
+                "failed to send Command::Transfer to Trader command_rx"
+            );
+            let _ = response_tx.send(Err(EngineError::TraderOffline));
+        }
+    }
+
+    /// Route a [`Command::Withdraw`] to a [`Trader`] of the provided exchange, since the
+    /// [`ExecutionClient`] is owned by each [`Trader`].
+    async fn withdraw(
+        &self,
+        exchange: ExchangeId,
+        request: WithdrawRequest,
+        response_tx: oneshot::Sender<Result<Withdrawal, EngineError>>,
+    ) {
+        let Some(command_tx) = self.exchange_command_tx(exchange) else {
+            let _ = response_tx.send(Err(EngineError::ExchangeNotFound(exchange)));
+            return;
+        };
+
+        let command = Command::Withdraw {
+            exchange,
+            request,
+            response_tx,
+        };
+
+        if let Err(mpsc::error::SendError(Command::Withdraw { response_tx, .. })) =
+            command_tx.send(command).await
+        {
+            error!(
+                %exchange,
+                why = "dropped receiver",
+                "failed to send Command::Withdraw to Trader command_rx"
+            );
+            let _ = response_tx.send(Err(EngineError::TraderOffline));
+        }
+    }
+
+    /// Find the [`Command`] transmitter of a [`Trader`] trading on the provided exchange.
+    fn exchange_command_tx(&self, exchange: ExchangeId) -> Option<&mpsc::Sender<Command>> {
+        let command_tx = self
+            .trader_command_txs
+            .iter()
+            .find_map(|(market, command_tx)| (market.exchange == exchange).then_some(command_tx));
+
+        if command_tx.is_none() {
+            warn!(
+                %exchange,
+                why = "Engine has no trader_command_tx associated with provided exchange",
+                "failed to route Command to Trader"
+            );
+        }
+
+        command_tx
+    }
+
     /// Generate a trading session summary. Uses the Portfolio's statistics per [`Market`] in
     /// combination with the average statistics across all [`Market`]s traded.
     fn generate_session_summary(mut self) -> Table {
+        // Update average statistics across all markets using session's remaining exited Positions
+        self.update_session_summary();
+
         // Fetch statistics for each Market
         let stats_per_market = self.trader_command_txs.into_keys().filter_map(|market| {
             let market_id = MarketId::from(&market);
@@ -338,21 +1092,6 @@ where
             }
         });
 
-        // Generate average statistics across all markets using session's exited Positions
-        self.portfolio
-            .lock()
-            .get_exited_positions(self.engine_id)
-            .map(|exited_positions| {
-                self.statistics_summary.generate_summary(&exited_positions);
-            })
-            .unwrap_or_else(|error| {
-                warn!(
-                    ?error,
-                    why = "failed to get exited Positions from Portfolio's repository",
-                    "failed to generate Statistics summary for trading session"
-                );
-            });
-
         // Combine Total & Per-Market Statistics Into Table
         crate::statistic::summary::combine(
             stats_per_market.chain([("Total".to_smolstr(), self.statistics_summary)]),
@@ -377,6 +1116,10 @@ where
     traders: Option<Vec<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>>>,
     trader_command_txs: Option<HashMap<Market, mpsc::Sender<Command>>>,
     statistics_summary: Option<Statistic>,
+    checkpoint: Option<Checkpointer>,
+    topology: Option<ThreadTopology>,
+    shutdown_policy: Option<ShutdownPolicy>,
+    trader_response_timeout: Option<Duration>,
 }
 
 impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
@@ -385,6 +1128,7 @@ where
     EventTx: MessageTransmitter<Event>,
     Statistic: PositionSummariser + Serialize + Send,
     Portfolio: PositionHandler
+        + BalanceHandler
         + StatisticHandler<Statistic>
         + MarketUpdater
         + OrderGenerator
@@ -402,6 +1146,10 @@ where
             traders: None,
             trader_command_txs: None,
             statistics_summary: None,
+            checkpoint: None,
+            topology: None,
+            shutdown_policy: None,
+            trader_response_timeout: None,
         }
     }
 
@@ -450,6 +1198,34 @@ where
         }
     }
 
+    pub fn checkpoint(self, value: Checkpointer) -> Self {
+        Self {
+            checkpoint: Some(value),
+            ..self
+        }
+    }
+
+    pub fn topology(self, value: ThreadTopology) -> Self {
+        Self {
+            topology: Some(value),
+            ..self
+        }
+    }
+
+    pub fn shutdown_policy(self, value: ShutdownPolicy) -> Self {
+        Self {
+            shutdown_policy: Some(value),
+            ..self
+        }
+    }
+
+    pub fn trader_response_timeout(self, value: Duration) -> Self {
+        Self {
+            trader_response_timeout: Some(value),
+            ..self
+        }
+    }
+
     pub fn build(
         self,
     ) -> Result<Engine<EventTx, Statistic, Portfolio, Data, Strategy, Execution>, EngineError> {
@@ -472,6 +1248,24 @@ where
             statistics_summary: self
                 .statistics_summary
                 .ok_or(EngineError::BuilderIncomplete("statistics_summary"))?,
+            summarised_positions: 0,
+            checkpoint: self.checkpoint,
+            topology: self.topology.unwrap_or_default(),
+            shutdown_policy: self.shutdown_policy.unwrap_or_default(),
+            trader_response_timeout: self
+                .trader_response_timeout
+                .unwrap_or(DEFAULT_TRADER_RESPONSE_TIMEOUT),
         })
     }
 }
+
+/// Wait for the next tick of the optional [`Interval`](tokio::time::Interval), or forever if
+/// there is none.
+async fn tick(interval: &mut Option<tokio::time::Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
@@ -0,0 +1,129 @@
+use tokio::sync::broadcast;
+use tracing::warn;
+
+/// Default capacity of the [`priority_channel`]. Emergency [`PriorityCommand`]s are rare, so this
+/// only needs to cover a short burst.
+pub const DEFAULT_PRIORITY_CHANNEL_CAPACITY: usize = 16;
+
+/// Emergency commands actioned by every [`Trader`](super::trader::Trader) subscribed to the
+/// [`priority_channel`].
+///
+/// Unlike a [`Command`](super::Command), a [`PriorityCommand`] is polled before the
+/// [`Trader`](super::trader::Trader) generates the next `MarketEvent`, and before each queued
+/// `Event` is handled, so it is never stuck behind a deep market data backlog.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum PriorityCommand {
+    /// Immediately stop every [`Trader`](super::trader::Trader), without generating any further
+    /// orders (including [`Position`](crate::portfolio::position::Position) exits).
+    EmergencyStop(String),
+
+    /// Cancel every order that has been generated, or is about to be generated from a `Signal`,
+    /// but has not yet been sent to the `ExecutionClient`.
+    CancelAll,
+
+    /// Stop generating orders from Strategy `Signal`s until a [`PriorityCommand::EnableTrading`]
+    /// is received. Market data, fills & forced [`Position`] exits are still actioned.
+    ///
+    /// [`Position`]: crate::portfolio::position::Position
+    DisableTrading(String),
+
+    /// Resume generating orders from Strategy `Signal`s after a
+    /// [`PriorityCommand::DisableTrading`].
+    EnableTrading,
+}
+
+/// Construct a new [`PriorityCommand`] channel. Each [`Trader`](super::trader::Trader) requires
+/// it's own [`PriorityCommandRx`], see [`PriorityCommandTx::subscribe`].
+pub fn priority_channel(capacity: usize) -> (PriorityCommandTx, PriorityCommandRx) {
+    let (tx, rx) = broadcast::channel(capacity);
+    (PriorityCommandTx { tx }, PriorityCommandRx { rx })
+}
+
+/// Transmitter for broadcasting [`PriorityCommand`]s to every subscribed
+/// [`Trader`](super::trader::Trader).
+#[derive(Clone, Debug)]
+pub struct PriorityCommandTx {
+    tx: broadcast::Sender<PriorityCommand>,
+}
+
+impl PriorityCommandTx {
+    /// Broadcast a [`PriorityCommand`], returning the number of [`PriorityCommandRx`]s it was
+    /// sent to.
+    pub fn send(&self, command: PriorityCommand) -> usize {
+        self.tx.send(command).unwrap_or_else(|error| {
+            warn!(
+                command = ?error.0,
+                why = "no PriorityCommandRx subscribed",
+                "failed to send PriorityCommand"
+            );
+            0
+        })
+    }
+
+    /// Construct a new [`PriorityCommandRx`] that receives every [`PriorityCommand`] sent after
+    /// this call.
+    pub fn subscribe(&self) -> PriorityCommandRx {
+        PriorityCommandRx {
+            rx: self.tx.subscribe(),
+        }
+    }
+}
+
+/// Receiver for [`PriorityCommand`]s broadcast by a [`PriorityCommandTx`].
+#[derive(Debug)]
+pub struct PriorityCommandRx {
+    rx: broadcast::Receiver<PriorityCommand>,
+}
+
+impl PriorityCommandRx {
+    /// Returns the next [`PriorityCommand`] if one has been received, without blocking.
+    ///
+    /// If the receiver has lagged behind, the oldest [`PriorityCommand`]s are skipped and the
+    /// next available one is returned.
+    pub fn try_recv(&mut self) -> Option<PriorityCommand> {
+        loop {
+            match self.rx.try_recv() {
+                Ok(command) => return Some(command),
+                Err(broadcast::error::TryRecvError::Lagged(skipped)) => {
+                    warn!(
+                        skipped,
+                        "PriorityCommandRx lagged, skipping oldest PriorityCommands"
+                    );
+                }
+                Err(
+                    broadcast::error::TryRecvError::Empty | broadcast::error::TryRecvError::Closed,
+                ) => return None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_priority_command_rx_try_recv() {
+        let (tx, mut rx_first) = priority_channel(2);
+        let mut rx_second = tx.subscribe();
+
+        assert_eq!(rx_first.try_recv(), None);
+        assert_eq!(tx.send(PriorityCommand::CancelAll), 2);
+        assert_eq!(rx_first.try_recv(), Some(PriorityCommand::CancelAll));
+        assert_eq!(rx_first.try_recv(), None);
+
+        // Lagged receiver skips the oldest PriorityCommands
+        tx.send(PriorityCommand::EmergencyStop("first".to_owned()));
+        tx.send(PriorityCommand::EmergencyStop("second".to_owned()));
+        assert_eq!(
+            rx_second.try_recv(),
+            Some(PriorityCommand::EmergencyStop("first".to_owned()))
+        );
+
+        drop(tx);
+        assert_eq!(
+            rx_first.try_recv(),
+            Some(PriorityCommand::EmergencyStop("first".to_owned()))
+        );
+    }
+}
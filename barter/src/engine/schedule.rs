@@ -0,0 +1,373 @@
+use super::error::EngineError;
+use chrono::{DateTime, Datelike, NaiveDate, TimeDelta, Timelike, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, str::FromStr, time::Duration};
+
+/// Maximum number of days searched for the next time matching a [`CronSchedule`], covering the
+/// rarest valid expressions (eg/ 29th of February).
+const MAX_CRON_SEARCH_DAYS: u32 = 366 * 4 + 1;
+
+/// Action performed when a [`ScheduledTask`] is due.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduledAction {
+    /// Exit the open [`Position`](crate::portfolio::position::Position) of each
+    /// [`Trader`](super::trader::Trader) (eg/ flatten at 00:00 UTC).
+    ExitPosition,
+    /// Call [`SignalGenerator::on_schedule`](crate::strategy::SignalGenerator::on_schedule), so
+    /// the Strategy can act on time rather than market data (eg/ rebalance every 5 minutes).
+    Strategy,
+}
+
+/// Time-based task actioned by each [`Trader`](super::trader::Trader) with a [`Scheduler`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ScheduledTask {
+    /// Unique name of the task, propagated to each [`ScheduledEvent`] (eg/ "daily_flatten").
+    pub name: String,
+    pub schedule: Schedule,
+    pub action: ScheduledAction,
+}
+
+/// Occurrence of a [`ScheduledTask`] being due, sent to the audit trail as an
+/// [`Event::Scheduled`](crate::event::Event).
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize, JsonSchema)]
+pub struct ScheduledEvent {
+    pub name: String,
+    /// Time the [`ScheduledTask`] was due.
+    pub time: DateTime<Utc>,
+    pub action: ScheduledAction,
+}
+
+/// When a [`ScheduledTask`] is due.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Schedule {
+    /// Cron-like expression, eg/ "0 0 * * *" for 00:00 UTC daily.
+    Cron(CronSchedule),
+    /// Fixed interval aligned to the Unix epoch, eg/ every 5 minutes at :00, :05, :10, etc.
+    Every(Duration),
+}
+
+impl Schedule {
+    /// Next time this [`Schedule`] is due, strictly after the provided time.
+    pub fn next_after(&self, time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            Schedule::Cron(cron) => cron.next_after(time),
+            Schedule::Every(interval) => {
+                let interval = i64::try_from(interval.as_millis()).ok()?.max(1);
+                let elapsed = time.timestamp_millis().div_euclid(interval);
+                DateTime::from_timestamp_millis((elapsed + 1) * interval)
+            }
+        }
+    }
+}
+
+/// Five field cron expression "minute hour day-of-month month day-of-week" evaluated in UTC.
+///
+/// Each field supports `*`, values, ranges (`a-b`), steps (`*/n`, `a-b/n`) and comma separated
+/// lists. Day-of-week is 0-6 from Sunday (7 is also accepted as Sunday). As with cron, if both
+/// day-of-month & day-of-week are restricted, a day matching either is due.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct CronSchedule {
+    expression: String,
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    day_of_month_restricted: bool,
+    day_of_week_restricted: bool,
+}
+
+impl CronSchedule {
+    /// Next time matching this [`CronSchedule`], strictly after the provided time.
+    pub fn next_after(&self, time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let start = time
+            .with_second(0)?
+            .with_nanosecond(0)?
+            .checked_add_signed(TimeDelta::minutes(1))?;
+
+        let mut date = start.date_naive();
+        for _ in 0..MAX_CRON_SEARCH_DAYS {
+            if self.matches_date(date) {
+                let times = (0..24)
+                    .filter(|hour| bit(self.hours, *hour))
+                    .flat_map(|hour| {
+                        (0..60)
+                            .filter(|minute| bit(self.minutes, *minute))
+                            .map(move |minute| (hour, minute))
+                    })
+                    .filter_map(|(hour, minute)| date.and_hms_opt(hour, minute, 0));
+
+                for time in times {
+                    let time = time.and_utc();
+                    if time >= start {
+                        return Some(time);
+                    }
+                }
+            }
+            date = date.succ_opt()?;
+        }
+
+        None
+    }
+
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        if !bit(self.months, date.month()) {
+            return false;
+        }
+
+        let day_of_month = bit(self.days_of_month, date.day());
+        let day_of_week = bit(self.days_of_week, date.weekday().num_days_from_sunday());
+        match (self.day_of_month_restricted, self.day_of_week_restricted) {
+            (true, true) => day_of_month || day_of_week,
+            _ => day_of_month && day_of_week,
+        }
+    }
+}
+
+impl FromStr for CronSchedule {
+    type Err = EngineError;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| {
+            EngineError::InvalidSchedule(format!("cron expression \"{expression}\" {reason}"))
+        };
+
+        let fields = expression.split_whitespace().collect::<Vec<_>>();
+        let [minutes, hours, days_of_month, months, days_of_week] = fields.as_slice() else {
+            return Err(invalid("must have 5 fields"));
+        };
+
+        let mut days_of_week =
+            parse_field(days_of_week, 0, 7).ok_or_else(|| invalid("has an invalid day-of-week"))?;
+        // 7 is an alias for Sunday
+        if bit(days_of_week, 7) {
+            days_of_week |= 1;
+        }
+
+        Ok(Self {
+            expression: expression.to_owned(),
+            minutes: parse_field(minutes, 0, 59).ok_or_else(|| invalid("has an invalid minute"))?,
+            hours: parse_field(hours, 0, 23).ok_or_else(|| invalid("has an invalid hour"))?,
+            days_of_month: parse_field(days_of_month, 1, 31)
+                .ok_or_else(|| invalid("has an invalid day-of-month"))?,
+            months: parse_field(months, 1, 12).ok_or_else(|| invalid("has an invalid month"))?,
+            days_of_week,
+            day_of_month_restricted: !fields[2].starts_with('*'),
+            day_of_week_restricted: !fields[4].starts_with('*'),
+        })
+    }
+}
+
+impl TryFrom<String> for CronSchedule {
+    type Error = EngineError;
+
+    fn try_from(expression: String) -> Result<Self, Self::Error> {
+        expression.parse()
+    }
+}
+
+impl From<CronSchedule> for String {
+    fn from(cron: CronSchedule) -> Self {
+        cron.expression
+    }
+}
+
+impl Display for CronSchedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.expression)
+    }
+}
+
+/// Parse a comma separated cron field into a bitmask of the allowed values.
+fn parse_field(field: &str, min: u32, max: u32) -> Option<u64> {
+    field.split(',').try_fold(0u64, |mask, part| {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u32>().ok().filter(|step| *step > 0)?),
+            None => (part, 1),
+        };
+
+        let (start, end) = match range {
+            "*" => (min, max),
+            range => match range.split_once('-') {
+                Some((start, end)) => (start.parse().ok()?, end.parse().ok()?),
+                None => {
+                    let value = range.parse().ok()?;
+                    (value, value)
+                }
+            },
+        };
+
+        if start < min || end > max || start > end {
+            return None;
+        }
+
+        Some(
+            (start..=end)
+                .step_by(step as usize)
+                .fold(mask, |mask, value| mask | (1 << value)),
+        )
+    })
+}
+
+fn bit(mask: u64, value: u32) -> bool {
+    mask & (1 << value) != 0
+}
+
+/// Tracks when each [`ScheduledTask`] is next due, generating a [`ScheduledEvent`] each time one
+/// is.
+///
+/// Polled by the [`Trader`](super::trader::Trader) with the exchange time of each `MarketEvent`,
+/// so scheduled tasks are deterministic in backtests. Each task is due at most once per poll,
+/// so tasks missed during a gap in market data are only actioned once.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Scheduler {
+    /// Each [`ScheduledTask`] & when it is next due, initialised on the first poll.
+    tasks: Vec<(ScheduledTask, Option<Option<DateTime<Utc>>>)>,
+}
+
+impl Scheduler {
+    /// Construct a new [`Scheduler`] of the provided [`ScheduledTask`]s. Each task is first due
+    /// after the first poll.
+    pub fn new(tasks: impl IntoIterator<Item = ScheduledTask>) -> Self {
+        Self {
+            tasks: tasks.into_iter().map(|task| (task, None)).collect(),
+        }
+    }
+
+    /// Generate a [`ScheduledEvent`] for each [`ScheduledTask`] due at or before the provided
+    /// time, ordered by due time.
+    pub fn poll(&mut self, time: DateTime<Utc>) -> Vec<ScheduledEvent> {
+        let mut events = Vec::new();
+        for (task, next) in &mut self.tasks {
+            let Some(due) = *next.get_or_insert_with(|| task.schedule.next_after(time)) else {
+                continue;
+            };
+
+            if due <= time {
+                events.push(ScheduledEvent {
+                    name: task.name.clone(),
+                    time: due,
+                    action: task.action,
+                });
+                *next = Some(task.schedule.next_after(time));
+            }
+        }
+
+        events.sort_by_key(|event| event.time);
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_schedule_next_after() {
+        struct TestCase {
+            schedule: &'static str,
+            time: &'static str,
+            expected: Option<&'static str>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: daily at 00:00 UTC
+                schedule: "0 0 * * *",
+                time: "2024-03-10T13:45:12Z",
+                expected: Some("2024-03-11T00:00:00Z"),
+            },
+            TestCase {
+                // TC1: every 5 minutes, strictly after an aligned time
+                schedule: "*/5 * * * *",
+                time: "2024-03-10T13:45:00Z",
+                expected: Some("2024-03-10T13:50:00Z"),
+            },
+            TestCase {
+                // TC2: funding timestamps
+                schedule: "0 0,8,16 * * *",
+                time: "2024-03-10T16:00:00.001Z",
+                expected: Some("2024-03-11T00:00:00Z"),
+            },
+            TestCase {
+                // TC3: weekdays only, 2024-03-09 is a Saturday
+                schedule: "30 9 * * 1-5",
+                time: "2024-03-09T10:00:00Z",
+                expected: Some("2024-03-11T09:30:00Z"),
+            },
+            TestCase {
+                // TC4: day-of-month or day-of-week (Sunday as 7)
+                schedule: "0 12 15 * 7",
+                time: "2024-03-11T00:00:00Z",
+                expected: Some("2024-03-15T12:00:00Z"),
+            },
+            TestCase {
+                // TC5: leap day
+                schedule: "0 0 29 2 *",
+                time: "2024-03-01T00:00:00Z",
+                expected: Some("2028-02-29T00:00:00Z"),
+            },
+            TestCase {
+                // TC6: invalid expression
+                schedule: "60 * * * *",
+                time: "2024-03-01T00:00:00Z",
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test
+                .schedule
+                .parse::<CronSchedule>()
+                .ok()
+                .and_then(|cron| cron.next_after(time(test.time)));
+            assert_eq!(actual, test.expected.map(time), "TC{} failed", index);
+        }
+
+        // Interval aligned to the Unix epoch
+        let every = Schedule::Every(Duration::from_secs(300));
+        assert_eq!(
+            every.next_after(time("2024-03-10T13:47:12Z")),
+            Some(time("2024-03-10T13:50:00Z"))
+        );
+
+        // Each task is due at most once per poll
+        let mut scheduler = Scheduler::new([
+            ScheduledTask {
+                name: "rebalance".to_owned(),
+                schedule: every,
+                action: ScheduledAction::Strategy,
+            },
+            ScheduledTask {
+                name: "flatten".to_owned(),
+                schedule: Schedule::Cron("0 0 * * *".parse().unwrap()),
+                action: ScheduledAction::ExitPosition,
+            },
+        ]);
+        assert!(scheduler.poll(time("2024-03-10T23:52:00Z")).is_empty());
+        assert_eq!(
+            scheduler.poll(time("2024-03-11T00:07:00Z")),
+            vec![
+                ScheduledEvent {
+                    name: "rebalance".to_owned(),
+                    time: time("2024-03-10T23:55:00Z"),
+                    action: ScheduledAction::Strategy,
+                },
+                ScheduledEvent {
+                    name: "flatten".to_owned(),
+                    time: time("2024-03-11T00:00:00Z"),
+                    action: ScheduledAction::ExitPosition,
+                },
+            ]
+        );
+        assert!(scheduler.poll(time("2024-03-11T00:09:00Z")).is_empty());
+    }
+}
@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Default time an [`Engine`](super::Engine) waits for in-flight orders & [`Position`] exits to
+/// resolve, and then for it's [`Trader`](super::trader::Trader)s to stop, when shutting down.
+///
+/// [`Position`]: crate::portfolio::position::Position
+pub const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Interval at which a shutting down [`Engine`](super::Engine) polls whether it's in-flight
+/// orders & [`Position`](crate::portfolio::position::Position) exits have resolved.
+pub const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Policy actioned by an [`Engine`](super::Engine) when it receives a
+/// [`Command::Terminate`](super::Command::Terminate) or [`Command::Shutdown`](super::Command::Shutdown).
+///
+/// Shutdown happens in order:
+/// 1. Cancel every order not yet sent to the `ExecutionClient` (if `cancel_orders`).
+/// 2. Exit every open [`Position`](crate::portfolio::position::Position) (if `close_positions`).
+/// 3. Wait up to the `timeout` for in-flight orders (& exits) to resolve.
+/// 4. Terminate every [`Trader`](super::trader::Trader), which flushes it's `EventTx` audit
+///    trail & stops it's market data feed, and wait up to the `timeout` for them to stop.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ShutdownPolicy {
+    pub cancel_orders: bool,
+    pub close_positions: bool,
+    pub timeout: Duration,
+}
+
+impl Default for ShutdownPolicy {
+    fn default() -> Self {
+        Self {
+            cancel_orders: true,
+            close_positions: true,
+            timeout: DEFAULT_SHUTDOWN_TIMEOUT,
+        }
+    }
+}
+
+impl ShutdownPolicy {
+    /// [`ShutdownPolicy`] that leaves every open order & [`Position`] untouched, only waiting for
+    /// the [`Trader`](super::trader::Trader)s to stop.
+    ///
+    /// [`Position`]: crate::portfolio::position::Position
+    pub fn stop_only(timeout: Duration) -> Self {
+        Self {
+            cancel_orders: false,
+            close_positions: false,
+            timeout,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shutdown_policy_deserialise_with_defaults() {
+        struct TestCase {
+            input: &'static str,
+            expected: ShutdownPolicy,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: empty policy uses defaults
+                input: r#"{}"#,
+                expected: ShutdownPolicy::default(),
+            },
+            TestCase {
+                // TC1: keep positions open
+                input: r#"{"close_positions":false}"#,
+                expected: ShutdownPolicy {
+                    close_positions: false,
+                    ..ShutdownPolicy::default()
+                },
+            },
+            TestCase {
+                // TC2: leave orders open with a custom timeout
+                input: r#"{"cancel_orders":false,"timeout":{"secs":1,"nanos":0}}"#,
+                expected: ShutdownPolicy {
+                    cancel_orders: false,
+                    timeout: Duration::from_secs(1),
+                    ..ShutdownPolicy::default()
+                },
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<ShutdownPolicy>(test.input).unwrap();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -1,20 +1,42 @@
-use super::{error::EngineError, Command};
+use super::{
+    calendar::TradingCalendar,
+    error::EngineError,
+    metrics::{EngineMetrics, EventTimer},
+    priority::{PriorityCommand, PriorityCommandRx},
+    schedule::{ScheduledAction, Scheduler},
+    Command,
+};
 use crate::{
-    data::{Feed, MarketGenerator},
+    clock::{Clock, HistoricalClock},
+    data::{sanity::SanityGuard, Feed, MarketEventObserver, MarketGenerator},
     event::{Event, MessageTransmitter},
-    execution::ExecutionClient,
-    portfolio::{FillUpdater, MarketUpdater, OrderGenerator},
+    execution::{
+        lifecycle::{OrderLifecycle, OrderLifecycleTracker, OrderTimeout},
+        validation::OrderValidator,
+        ExecutionClient,
+    },
+    portfolio::{FillUpdater, MarketUpdater, OrderEvent, OrderGenerator},
     strategy::{SignalForceExit, SignalGenerator},
 };
 use barter_data::event::{DataKind, MarketEvent};
+use barter_execution::error::ErrorClass;
 use barter_instrument::{instrument::Instrument, market::Market};
+use barter_integration::auth::clock::ServerClock;
+use chrono::{DateTime, Utc};
 use parking_lot::Mutex;
 use serde::Serialize;
-use std::{collections::VecDeque, fmt::Debug, marker::PhantomData, sync::Arc};
+use std::{
+    collections::VecDeque, fmt::Debug, marker::PhantomData, ops::ControlFlow, sync::Arc,
+    time::Instant,
+};
 use tokio::sync::mpsc;
-use tracing::{debug, info, warn};
+use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
+/// Maximum number of times a [`Trader`] submits an `OrderEvent` that fails with a
+/// [`ErrorClass::Retryable`] error, before abandoning it.
+pub const MAX_ORDER_SUBMIT_ATTEMPTS: u32 = 3;
+
 /// Lego components for constructing a [`Trader`] via the new() constructor method.
 #[derive(Debug)]
 pub struct TraderLego<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
@@ -33,6 +55,36 @@ where
     pub market: Market,
     /// mpsc::Receiver for receiving [`Command`]s from a remote source.
     pub command_rx: mpsc::Receiver<Command>,
+    /// Optional receiver for emergency [`PriorityCommand`]s, polled before any other input.
+    pub priority_rx: Option<PriorityCommandRx>,
+    /// Optional [`ServerClock`] of the [`Market`] exchange (eg/ shared with it's market data
+    /// streams), used to convert `FillEvent` exchange times onto the local clock before they are
+    /// compared with the local `OrderEvent` timestamps.
+    pub server_clock: Option<Arc<ServerClock>>,
+    /// Optional [`SanityGuard`] that flags, and optionally quarantines, implausible
+    /// `MarketEvent`s before they reach the Strategy & Portfolio.
+    pub sanity_guard: Option<SanityGuard>,
+    /// Optional [`HistoricalClock`] (usually shared with the Portfolio & Execution) stepped with
+    /// every `MarketEvent` `time_exchange`, for deterministic backtest timestamps.
+    pub clock: Option<HistoricalClock>,
+    /// Optional [`OrderValidator`] that normalises every `OrderEvent` to the exchange instrument
+    /// specification, rejecting invalid orders before they are sent for execution.
+    pub order_validator: Option<OrderValidator>,
+    /// Optional [`Scheduler`] polled with every `MarketEvent` exchange time, sending an
+    /// [`Event::Scheduled`] each time a scheduled task is due.
+    pub scheduler: Option<Scheduler>,
+    /// Optional [`TradingCalendar`] of the [`Market`] exchange, used to reject every
+    /// `OrderEvent` generated while the exchange is closed (eg/ outside it's session).
+    pub calendar: Option<TradingCalendar>,
+    /// Optional [`OrderTimeout`] used to time out every in-flight `OrderEvent` the exchange
+    /// never responded to, swept with every `MarketEvent`.
+    pub order_timeout: Option<OrderTimeout>,
+    /// Optional [`EngineMetrics`] (usually shared by every [`Trader`]) updated with the feed
+    /// queue depth & rate, and the processing latency of every [`Event`].
+    pub metrics: Option<Arc<Mutex<EngineMetrics>>>,
+    /// [`MarketEventObserver`]s updated with every `MarketEvent` & `FillEvent` (eg/ a
+    /// [`MarketEventCache`](crate::data::cache::MarketEventCache) shared with the Strategy).
+    pub observers: Vec<Box<dyn MarketEventObserver>>,
     /// [`Event`] transmitter for sending every [`Event`] the [`Trader`] encounters to an external sink.
     pub event_tx: EventTx,
     /// Shared-access to a global Portfolio instance that implements [`MarketUpdater`],
@@ -69,11 +121,50 @@ where
     market: Market,
     /// `mpsc::Receiver` for receiving [`Command`]s from a remote source.
     command_rx: mpsc::Receiver<Command>,
+    /// Optional receiver for emergency [`PriorityCommand`]s, polled before any other input.
+    priority_rx: Option<PriorityCommandRx>,
+    /// Optional [`ServerClock`] of the [`Market`] exchange (eg/ shared with it's market data
+    /// streams), used to convert `FillEvent` exchange times onto the local clock before they are
+    /// compared with the local `OrderEvent` timestamps.
+    server_clock: Option<Arc<ServerClock>>,
+    /// Optional [`SanityGuard`] that flags, and optionally quarantines, implausible
+    /// `MarketEvent`s before they reach the Strategy & Portfolio.
+    sanity_guard: Option<SanityGuard>,
+    /// Optional [`HistoricalClock`] (usually shared with the Portfolio & Execution) stepped with
+    /// every `MarketEvent` `time_exchange`, for deterministic backtest timestamps.
+    clock: Option<HistoricalClock>,
+    /// Optional [`OrderValidator`] that normalises every `OrderEvent` to the exchange instrument
+    /// specification, rejecting invalid orders before they are sent for execution.
+    order_validator: Option<OrderValidator>,
+    /// Optional [`Scheduler`] polled with every `MarketEvent` exchange time, sending an
+    /// [`Event::Scheduled`] each time a scheduled task is due.
+    scheduler: Option<Scheduler>,
+    /// Optional [`TradingCalendar`] of the [`Market`] exchange, used to reject every
+    /// `OrderEvent` generated while the exchange is closed (eg/ outside it's session).
+    calendar: Option<TradingCalendar>,
+    /// Optional [`OrderTimeout`] used to time out every in-flight `OrderEvent` the exchange
+    /// never responded to, swept with every `MarketEvent`.
+    order_timeout: Option<OrderTimeout>,
+    /// Optional [`EngineMetrics`] (usually shared by every [`Trader`]) updated with the feed
+    /// queue depth & rate, and the processing latency of every [`Event`].
+    metrics: Option<Arc<Mutex<EngineMetrics>>>,
+    /// [`MarketEventObserver`]s updated with every `MarketEvent` & `FillEvent` (eg/ a
+    /// [`MarketEventCache`](crate::data::cache::MarketEventCache) shared with the Strategy).
+    observers: Vec<Box<dyn MarketEventObserver>>,
     /// [`Event`] transmitter for sending every [`Event`] the [`Trader`] encounters to an external
     /// sink.
     event_tx: EventTx,
     /// Queue for storing [`Event`]s used by the trading loop in the run() method.
     event_q: VecDeque<Event>,
+    /// `OrderEvent`s that failed to submit with a [`ErrorClass::Retryable`] error, & their
+    /// number of attempts so far, resubmitted with the next `MarketEvent`.
+    orders_retry: VecDeque<(OrderEvent, u32)>,
+    /// Whether `OrderEvent`s are generated from Strategy `Signal`s, toggled by
+    /// [`PriorityCommand::DisableTrading`] & [`PriorityCommand::EnableTrading`].
+    trading_enabled: bool,
+    /// Tracks the [`OrderLifecycle`] of every in-flight `OrderEvent`, sending an
+    /// [`Event::OrderLifecycle`] once each reaches it's terminal status.
+    order_lifecycle: OrderLifecycleTracker,
     /// Shared-access to a global Portfolio instance that implements [`MarketUpdater`],
     /// [`OrderGenerator`] & [`FillUpdater`].
     portfolio: Arc<Mutex<Portfolio>>,
@@ -108,8 +199,21 @@ where
             engine_id: lego.engine_id,
             market: lego.market,
             command_rx: lego.command_rx,
+            priority_rx: lego.priority_rx,
+            server_clock: lego.server_clock,
+            sanity_guard: lego.sanity_guard,
+            clock: lego.clock,
+            order_validator: lego.order_validator,
+            scheduler: lego.scheduler,
+            calendar: lego.calendar,
+            order_timeout: lego.order_timeout,
+            metrics: lego.metrics,
+            observers: lego.observers,
             event_tx: lego.event_tx,
             event_q: VecDeque::with_capacity(4),
+            orders_retry: VecDeque::new(),
+            trading_enabled: true,
+            order_lifecycle: OrderLifecycleTracker::default(),
             portfolio: lego.portfolio,
             data: lego.data,
             strategy: lego.strategy,
@@ -124,19 +228,86 @@ where
     }
 
     /// Run the trading event-loop for this [`Trader`] instance. Loop will run until [`Trader`]
-    /// receives a [`Command::Terminate`] via the mpsc::Receiver command_rx, or the
-    /// [`MarketGenerator`] yields [`Feed::Finished`].
+    /// receives a [`Command::Terminate`] via the mpsc::Receiver command_rx, a
+    /// [`PriorityCommand::EmergencyStop`], or the [`MarketGenerator`] yields [`Feed::Finished`].
     pub fn run(mut self) {
         // Run trading loop for this Trader instance
         'trading: loop {
+            // Action emergency PriorityCommands before any other input
+            if self.action_priority_commands().is_break() {
+                break 'trading;
+            }
+
             // Check for new remote Commands before continuing to generate another MarketEvent
             while let Some(command) = self.receive_remote_command() {
                 match command {
                     Command::Terminate(_) => break 'trading,
+                    Command::CancelAllOrders => {
+                        let cancelled = self.cancel_orders();
+                        info!(
+                            engine_id = %self.engine_id,
+                            market = ?self.market,
+                            cancelled,
+                            "Trader actioned Command::CancelAllOrders"
+                        );
+                    }
                     Command::ExitPosition(market) => {
                         self.event_q
                             .push_back(Event::SignalForceExit(SignalForceExit::from(market)));
                     }
+                    Command::UpdateStrategyConfig { config, .. } => {
+                        self.update_strategy_config(config);
+                    }
+                    Command::Custom { event, .. } => {
+                        self.event_tx.send(Event::Custom(event.clone()));
+                        self.event_q.push_back(Event::Custom(event));
+                    }
+                    Command::FetchOrdersPending(orders_tx) => {
+                        if orders_tx.send(Ok(self.execution.orders_pending())).is_err() {
+                            warn!(
+                                engine_id = %self.engine_id,
+                                market = ?self.market,
+                                why = "oneshot receiver dropped",
+                                "cannot action Command::FetchOrdersPending"
+                            );
+                        }
+                    }
+                    Command::Transfer {
+                        exchange,
+                        request,
+                        response_tx,
+                    } => {
+                        let result = self
+                            .execution
+                            .transfer(exchange, request)
+                            .map_err(EngineError::from);
+                        if response_tx.send(result).is_err() {
+                            warn!(
+                                engine_id = %self.engine_id,
+                                market = ?self.market,
+                                why = "oneshot receiver dropped",
+                                "cannot action Command::Transfer"
+                            );
+                        }
+                    }
+                    Command::Withdraw {
+                        exchange,
+                        request,
+                        response_tx,
+                    } => {
+                        let result = self
+                            .execution
+                            .withdraw(exchange, request)
+                            .map_err(EngineError::from);
+                        if response_tx.send(result).is_err() {
+                            warn!(
+                                engine_id = %self.engine_id,
+                                market = ?self.market,
+                                why = "oneshot receiver dropped",
+                                "cannot action Command::Withdraw"
+                            );
+                        }
+                    }
                     _ => continue,
                 }
             }
@@ -144,6 +315,15 @@ where
             // If the Feed<MarketEvent> yields, populate event_q with the next MarketEvent
             match self.data.next() {
                 Feed::Next(market) => {
+                    if let Some(metrics) = &self.metrics {
+                        metrics
+                            .lock()
+                            .record_feed(Instant::now(), self.data.queue_stats());
+                    }
+                    if self.check_market_sanity(&market).is_break() {
+                        continue 'trading;
+                    }
+                    self.poll_scheduler(&market);
                     self.event_tx.send(Event::Market(market.clone()));
                     self.event_q.push_back(Event::Market(market));
                 }
@@ -161,25 +341,80 @@ where
 
             // Handle Events in the event_q
             // '--> While loop will break when event_q is empty and requires another MarketEvent
-            while let Some(event) = self.event_q.pop_front() {
+            loop {
+                // Action emergency PriorityCommands before handling each queued Event
+                if self.action_priority_commands().is_break() {
+                    break 'trading;
+                }
+
+                let Some(event) = self.event_q.pop_front() else {
+                    break;
+                };
+
+                // Records the Event processing latency when dropped at the end of this iteration
+                let _timer = self
+                    .metrics
+                    .as_ref()
+                    .map(|metrics| EventTimer::start(metrics, &event));
+
                 match event {
                     Event::Market(market) => {
+                        if let Some(clock) = &self.clock {
+                            clock.update_from_market(&market);
+                        }
+
+                        self.sweep_orders_timed_out();
+
+                        if self.resubmit_orders_retry().is_break() {
+                            break 'trading;
+                        }
+
+                        for observer in &mut self.observers {
+                            if let Some(event) = observer.on_market(&market) {
+                                self.event_tx.send(event);
+                            }
+                        }
+
+                        self.execution.update_from_market(&market);
+
+                        for fill in self.execution.poll_fills() {
+                            self.event_tx.send(Event::Fill(fill.clone()));
+                            self.event_q.push_back(Event::Fill(fill));
+                        }
+
                         if let Some(signal) = self.strategy.generate_signal(&market) {
                             self.event_tx.send(Event::Signal(signal.clone()));
                             self.event_q.push_back(Event::Signal(signal));
                         }
 
-                        if let Some(position_update) = self
-                            .portfolio
-                            .lock()
-                            .update_from_market(&market)
-                            .expect("failed to update Portfolio from market")
-                        {
-                            self.event_tx.send(Event::PositionUpdate(position_update));
+                        let position_update = self.portfolio.lock().update_from_market(&market);
+                        match position_update {
+                            Ok(Some(position_update)) => {
+                                self.event_tx.send(Event::PositionUpdate(position_update));
+                            }
+                            Ok(None) => {}
+                            Err(error) => {
+                                warn!(
+                                    engine_id = %self.engine_id,
+                                    instrument = %market.instrument,
+                                    %error,
+                                    action = "skipping Position update",
+                                    "Trader failed to update Portfolio from MarketEvent"
+                                );
+                            }
                         }
                     }
 
                     Event::Signal(signal) => {
+                        if !self.trading_enabled {
+                            debug!(
+                                engine_id = %self.engine_id,
+                                market = ?self.market,
+                                "Trader ignoring Signal while trading is disabled"
+                            );
+                            continue;
+                        }
+
                         if let Some(order) = self
                             .portfolio
                             .lock()
@@ -204,16 +439,51 @@ where
                     }
 
                     Event::OrderNew(order) => {
-                        let fill = self
-                            .execution
-                            .generate_fill(&order)
-                            .expect("failed to generate Fill");
+                        if !self.is_exchange_open(&order) {
+                            continue;
+                        }
+
+                        let Some(order) = self.validate_order(order) else {
+                            continue;
+                        };
+
+                        if self.submit_order(order, 1).is_break() {
+                            break 'trading;
+                        }
+                    }
+
+                    Event::Scheduled(scheduled) => match scheduled.action {
+                        ScheduledAction::ExitPosition => {
+                            self.event_q
+                                .push_back(Event::SignalForceExit(SignalForceExit::from(
+                                    self.market.clone(),
+                                )));
+                        }
+                        ScheduledAction::Strategy => {
+                            if let Some(signal) = self.strategy.on_schedule(&scheduled) {
+                                self.event_tx.send(Event::Signal(signal.clone()));
+                                self.event_q.push_back(Event::Signal(signal));
+                            }
+                        }
+                    },
 
-                        self.event_tx.send(Event::Fill(fill.clone()));
-                        self.event_q.push_back(Event::Fill(fill));
+                    Event::Custom(custom) => {
+                        if let Some(signal) = self.strategy.on_custom(&custom) {
+                            self.event_tx.send(Event::Signal(signal.clone()));
+                            self.event_q.push_back(Event::Signal(signal));
+                        }
                     }
 
                     Event::Fill(fill) => {
+                        let fill_time = self.local_time(fill.time);
+                        if let Some(order) = self.order_lifecycle.filled(&fill, fill_time) {
+                            self.record_order_lifecycle(order);
+                        }
+
+                        for observer in &mut self.observers {
+                            observer.on_fill(&fill);
+                        }
+
                         let fill_side_effect_events = self
                             .portfolio
                             .lock()
@@ -232,6 +502,347 @@ where
                 "Trader trading loop stopped"
             );
         }
+
+        // Flush the audit trail before the Trader (& it's market data feed) is dropped
+        self.event_tx.flush();
+    }
+
+    /// Resume tracking the in-flight `OrderEvent`s of this [`Trader`]'s [`Market`] that were
+    /// restored from an [`EngineCheckpoint`](super::checkpoint::EngineCheckpoint), ignoring
+    /// those of any other [`Market`].
+    pub(super) fn restore_orders_pending(&mut self, orders: &[OrderEvent]) {
+        let orders = orders
+            .iter()
+            .filter(|order| {
+                order.exchange == self.market.exchange && order.instrument == self.market.instrument
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if orders.is_empty() {
+            return;
+        }
+
+        info!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            orders = orders.len(),
+            "Trader resuming in-flight OrderEvents"
+        );
+        orders
+            .iter()
+            .for_each(|order| self.order_lifecycle.restored(order));
+        self.execution.restore_orders_pending(orders);
+    }
+
+    /// Action every [`PriorityCommand`] received since the last poll. Returns
+    /// [`ControlFlow::Break`] if the [`Trader`] must stop immediately.
+    fn action_priority_commands(&mut self) -> ControlFlow<()> {
+        while let Some(command) = self
+            .priority_rx
+            .as_mut()
+            .and_then(PriorityCommandRx::try_recv)
+        {
+            match command {
+                PriorityCommand::EmergencyStop(reason) => {
+                    warn!(
+                        engine_id = %self.engine_id,
+                        market = ?self.market,
+                        %reason,
+                        "Trader received PriorityCommand::EmergencyStop"
+                    );
+                    return ControlFlow::Break(());
+                }
+                PriorityCommand::CancelAll => {
+                    let cancelled = self.cancel_orders();
+                    warn!(
+                        engine_id = %self.engine_id,
+                        market = ?self.market,
+                        cancelled,
+                        "Trader received PriorityCommand::CancelAll"
+                    );
+                }
+                PriorityCommand::DisableTrading(reason) => {
+                    self.trading_enabled = false;
+                    warn!(
+                        engine_id = %self.engine_id,
+                        market = ?self.market,
+                        %reason,
+                        "Trader received PriorityCommand::DisableTrading"
+                    );
+                }
+                PriorityCommand::EnableTrading => {
+                    self.trading_enabled = true;
+                    info!(
+                        engine_id = %self.engine_id,
+                        market = ?self.market,
+                        "Trader received PriorityCommand::EnableTrading"
+                    );
+                }
+            }
+        }
+
+        ControlFlow::Continue(())
+    }
+
+    /// Cancel every order that has been generated, or is about to be generated from a `Signal`,
+    /// but has not yet been sent to the [`ExecutionClient`], returning the number cancelled.
+    fn cancel_orders(&mut self) -> usize {
+        let queued = self.event_q.len() + self.orders_retry.len();
+        self.event_q.retain(|event| !is_cancellable(event));
+        self.orders_retry.clear();
+        let cancelled_pending = self.execution.cancel_orders_pending();
+        for order in self
+            .order_lifecycle
+            .cancelled(&cancelled_pending, self.time())
+        {
+            self.record_order_lifecycle(order);
+        }
+
+        queued - self.event_q.len() + cancelled_pending.len()
+    }
+
+    /// Submit the `OrderEvent` to the [`ExecutionClient`] for the provided attempt, queueing any
+    /// immediate `FillEvent`. If submission fails, the `OrderEvent` is resubmitted with the next
+    /// `MarketEvent` if the error is [`ErrorClass::Retryable`] & attempts remain, otherwise it is
+    /// abandoned. Returns [`ControlFlow::Break`] if the error is [`ErrorClass::Unrecoverable`],
+    /// since the [`Trader`] cannot execute any `OrderEvent`.
+    fn submit_order(&mut self, order: OrderEvent, attempt: u32) -> ControlFlow<()> {
+        self.order_lifecycle.sent(&order, self.time());
+
+        let error = match self.execution.submit_order(&order) {
+            Ok(fill) => {
+                self.order_lifecycle.acknowledged(&order.cid, self.time());
+
+                if let Some(fill) = fill {
+                    self.event_tx.send(Event::Fill(fill.clone()));
+                    self.event_q.push_back(Event::Fill(fill));
+                }
+                return ControlFlow::Continue(());
+            }
+            Err(error) => error,
+        };
+
+        if let Some(lifecycle) = self.order_lifecycle.rejected(&order.cid, self.time()) {
+            self.record_order_lifecycle(lifecycle);
+        }
+
+        match error.class() {
+            ErrorClass::Retryable if attempt < MAX_ORDER_SUBMIT_ATTEMPTS => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    %error,
+                    attempt,
+                    action = "resubmitting OrderEvent with next MarketEvent",
+                    "Trader failed to submit OrderEvent"
+                );
+                self.orders_retry.push_back((order, attempt + 1));
+                ControlFlow::Continue(())
+            }
+            ErrorClass::Retryable | ErrorClass::Rejected => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    %error,
+                    attempt,
+                    action = "abandoning OrderEvent",
+                    "Trader failed to submit OrderEvent"
+                );
+                ControlFlow::Continue(())
+            }
+            ErrorClass::Unrecoverable => {
+                error!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    %error,
+                    action = "stopping Trader",
+                    "Trader failed to submit OrderEvent with unrecoverable error"
+                );
+                ControlFlow::Break(())
+            }
+        }
+    }
+
+    /// Resubmit every `OrderEvent` that previously failed with a [`ErrorClass::Retryable`]
+    /// error. Returns [`ControlFlow::Break`] if the [`Trader`] must stop.
+    fn resubmit_orders_retry(&mut self) -> ControlFlow<()> {
+        for (order, attempt) in std::mem::take(&mut self.orders_retry) {
+            self.submit_order(order, attempt)?;
+        }
+
+        ControlFlow::Continue(())
+    }
+
+    /// Time out every in-flight `OrderEvent` generated longer than the [`OrderTimeout`] ago,
+    /// expiring it from the [`ExecutionClient`] & sending it's [`Event::OrderLifecycle`]. If
+    /// configured, the [`ExecutionClient`] is then requested to reconcile them with the exchange.
+    fn sweep_orders_timed_out(&mut self) {
+        let Some(order_timeout) = self.order_timeout else {
+            return;
+        };
+
+        let time = self.time();
+        let Some(generated_before) = order_timeout.generated_before(time) else {
+            return;
+        };
+
+        let timed_out = self.order_lifecycle.timed_out(generated_before, time);
+        let expired = self.execution.expire_orders_pending(generated_before);
+        if timed_out.is_empty() && expired == 0 {
+            return;
+        }
+
+        warn!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            timed_out = timed_out.len(),
+            expired,
+            timeout = ?order_timeout.timeout,
+            "Trader timed out in-flight OrderEvents"
+        );
+
+        if order_timeout.reconcile {
+            self.execution.reconcile_orders(&timed_out);
+        }
+
+        for order in timed_out {
+            self.record_order_lifecycle(order);
+        }
+    }
+
+    /// Record the latency of an [`OrderLifecycle`] that reached it's terminal status to the
+    /// [`EngineMetrics`], and send it to the audit trail as an [`Event::OrderLifecycle`].
+    fn record_order_lifecycle(&mut self, order: OrderLifecycle) {
+        if let Some(metrics) = &self.metrics {
+            metrics.lock().record_order(&order);
+        }
+
+        self.event_tx.send(Event::OrderLifecycle(order));
+    }
+
+    /// Current time according to the [`HistoricalClock`] if backtesting, otherwise the system
+    /// time.
+    fn time(&self) -> DateTime<Utc> {
+        self.clock.as_ref().map_or_else(Utc::now, Clock::time)
+    }
+
+    /// Check the `MarketEvent` using the [`SanityGuard`], sending any [`Event::MarketAnomaly`].
+    /// Returns [`ControlFlow::Break`] if the `MarketEvent` is quarantined.
+    fn check_market_sanity(
+        &mut self,
+        market: &MarketEvent<Instrument, DataKind>,
+    ) -> ControlFlow<()> {
+        let Some(anomaly) = self
+            .sanity_guard
+            .as_mut()
+            .and_then(|guard| guard.check(market))
+        else {
+            return ControlFlow::Continue(());
+        };
+
+        warn!(
+            engine_id = %self.engine_id,
+            market = ?self.market,
+            ?anomaly,
+            "MarketEvent failed sanity check"
+        );
+
+        let quarantined = anomaly.quarantined;
+        self.event_tx.send(Event::MarketAnomaly(anomaly));
+
+        if quarantined {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+
+    /// Determine if the [`TradingCalendar`] exchange is open at the `OrderEvent` market time.
+    /// `OrderEvent`s generated while the exchange is closed must not be executed.
+    fn is_exchange_open(&self, order: &OrderEvent) -> bool {
+        let Some(calendar) = &self.calendar else {
+            return true;
+        };
+
+        let is_open = calendar.is_open(order.market_meta.time);
+        if !is_open {
+            warn!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                time = %order.market_meta.time,
+                maintenance = ?calendar.maintenance_window(order.market_meta.time),
+                "OrderEvent rejected while exchange is closed"
+            );
+        }
+        is_open
+    }
+
+    /// Normalise the `OrderEvent` using the [`OrderValidator`]. Returns `None` if the
+    /// `OrderEvent` violates the exchange instrument specification, and must not be executed.
+    fn validate_order(&self, order: OrderEvent) -> Option<OrderEvent> {
+        let Some(validator) = &self.order_validator else {
+            return Some(order);
+        };
+
+        match validator.validate(order) {
+            Ok(order) => Some(order),
+            Err(error) => {
+                warn!(
+                    engine_id = %self.engine_id,
+                    market = ?self.market,
+                    %error,
+                    "OrderEvent rejected before execution"
+                );
+                None
+            }
+        }
+    }
+
+    /// Poll the [`Scheduler`] with the `MarketEvent` exchange time, sending & queueing an
+    /// [`Event::Scheduled`] for each scheduled task that is due.
+    fn poll_scheduler(&mut self, market: &MarketEvent<Instrument, DataKind>) {
+        let Some(scheduler) = &mut self.scheduler else {
+            return;
+        };
+
+        for scheduled in scheduler.poll(market.time_exchange) {
+            info!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                task = %scheduled.name,
+                time = %scheduled.time,
+                "scheduled task due"
+            );
+            self.event_tx.send(Event::Scheduled(scheduled.clone()));
+            self.event_q.push_back(Event::Scheduled(scheduled));
+        }
+    }
+
+    /// Update the Strategy configuration from the serialised config, logging the outcome. A
+    /// rejected config leaves the current Strategy configuration in place.
+    fn update_strategy_config(&mut self, config: serde_json::Value) {
+        match self.strategy.update_config(config) {
+            Ok(()) => info!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                "Strategy config updated"
+            ),
+            Err(error) => warn!(
+                engine_id = %self.engine_id,
+                market = ?self.market,
+                %error,
+                "Strategy config update rejected"
+            ),
+        }
+    }
+
+    /// Convert the provided exchange time onto the local clock using the [`ServerClock`], if
+    /// any, leaving it unchanged otherwise.
+    fn local_time(&self, time_exchange: DateTime<Utc>) -> DateTime<Utc> {
+        self.server_clock
+            .as_ref()
+            .map_or(time_exchange, |clock| clock.local(time_exchange))
     }
 
     /// Returns a [`Command`] if one has been received.
@@ -262,6 +873,12 @@ where
     }
 }
 
+/// Determine if a queued [`Event`] is cancelled by a [`PriorityCommand::CancelAll`]. Forced
+/// [`Position`](crate::portfolio::position::Position) exits are not cancelled.
+fn is_cancellable(event: &Event) -> bool {
+    matches!(event, Event::Signal(_) | Event::OrderNew(_))
+}
+
 /// Builder to construct [`Trader`] instances.
 #[derive(Debug, Default)]
 pub struct TraderBuilder<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
@@ -276,6 +893,16 @@ where
     engine_id: Option<Uuid>,
     market: Option<Market>,
     command_rx: Option<mpsc::Receiver<Command>>,
+    priority_rx: Option<PriorityCommandRx>,
+    server_clock: Option<Arc<ServerClock>>,
+    sanity_guard: Option<SanityGuard>,
+    clock: Option<HistoricalClock>,
+    order_validator: Option<OrderValidator>,
+    scheduler: Option<Scheduler>,
+    calendar: Option<TradingCalendar>,
+    order_timeout: Option<OrderTimeout>,
+    metrics: Option<Arc<Mutex<EngineMetrics>>>,
+    observers: Vec<Box<dyn MarketEventObserver>>,
     event_tx: Option<EventTx>,
     portfolio: Option<Arc<Mutex<Portfolio>>>,
     data: Option<Data>,
@@ -299,6 +926,16 @@ where
             engine_id: None,
             market: None,
             command_rx: None,
+            priority_rx: None,
+            server_clock: None,
+            sanity_guard: None,
+            clock: None,
+            order_validator: None,
+            scheduler: None,
+            calendar: None,
+            order_timeout: None,
+            metrics: None,
+            observers: Vec::new(),
             event_tx: None,
             portfolio: None,
             data: None,
@@ -329,6 +966,79 @@ where
         }
     }
 
+    pub fn priority_rx(self, value: PriorityCommandRx) -> Self {
+        Self {
+            priority_rx: Some(value),
+            ..self
+        }
+    }
+
+    pub fn server_clock(self, value: Arc<ServerClock>) -> Self {
+        Self {
+            server_clock: Some(value),
+            ..self
+        }
+    }
+
+    pub fn sanity_guard(self, value: SanityGuard) -> Self {
+        Self {
+            sanity_guard: Some(value),
+            ..self
+        }
+    }
+
+    pub fn clock(self, value: HistoricalClock) -> Self {
+        Self {
+            clock: Some(value),
+            ..self
+        }
+    }
+
+    pub fn order_validator(self, value: OrderValidator) -> Self {
+        Self {
+            order_validator: Some(value),
+            ..self
+        }
+    }
+
+    pub fn scheduler(self, value: Scheduler) -> Self {
+        Self {
+            scheduler: Some(value),
+            ..self
+        }
+    }
+
+    pub fn calendar(self, value: TradingCalendar) -> Self {
+        Self {
+            calendar: Some(value),
+            ..self
+        }
+    }
+
+    pub fn order_timeout(self, value: OrderTimeout) -> Self {
+        Self {
+            order_timeout: Some(value),
+            ..self
+        }
+    }
+
+    pub fn metrics(self, value: Arc<Mutex<EngineMetrics>>) -> Self {
+        Self {
+            metrics: Some(value),
+            ..self
+        }
+    }
+
+    /// Add a [`MarketEventObserver`] (eg/ an `Arc<RwLock<MarketDepth>>` shared with the Strategy)
+    /// to update with every `MarketEvent` & `FillEvent`.
+    pub fn observer<Observer>(mut self, value: Observer) -> Self
+    where
+        Observer: MarketEventObserver + 'static,
+    {
+        self.observers.push(Box::new(value));
+        self
+    }
+
     pub fn event_tx(self, value: EventTx) -> Self {
         Self {
             event_tx: Some(value),
@@ -377,10 +1087,23 @@ where
             command_rx: self
                 .command_rx
                 .ok_or(EngineError::BuilderIncomplete("command_rx"))?,
+            priority_rx: self.priority_rx,
+            server_clock: self.server_clock,
+            sanity_guard: self.sanity_guard,
+            clock: self.clock,
+            order_validator: self.order_validator,
+            scheduler: self.scheduler,
+            calendar: self.calendar,
+            order_timeout: self.order_timeout,
+            metrics: self.metrics,
+            observers: self.observers,
             event_tx: self
                 .event_tx
                 .ok_or(EngineError::BuilderIncomplete("event_tx"))?,
             event_q: VecDeque::with_capacity(2),
+            orders_retry: VecDeque::new(),
+            trading_enabled: true,
+            order_lifecycle: OrderLifecycleTracker::default(),
             portfolio: self
                 .portfolio
                 .ok_or(EngineError::BuilderIncomplete("portfolio"))?,
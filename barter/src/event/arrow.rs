@@ -0,0 +1,490 @@
+use crate::{execution::FillEvent, portfolio::position::Position};
+use ::arrow::{
+    array::{
+        ArrayRef, Decimal128Array, Float64Array, StringArray, TimestampMicrosecondArray,
+        UInt64Array,
+    },
+    datatypes::{Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_instrument::instrument::Instrument;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use std::sync::Arc;
+
+/// Construct a [`RecordBatch`] of the provided named columns, none of which contain nulls.
+fn record_batch(columns: Vec<(&'static str, ArrayRef)>) -> Result<RecordBatch, ArrowError> {
+    let schema = Schema::new(
+        columns
+            .iter()
+            .map(|(name, array)| Field::new(*name, array.data_type().clone(), false))
+            .collect::<Vec<_>>(),
+    );
+
+    RecordBatch::try_new(
+        Arc::new(schema),
+        columns.into_iter().map(|(_, array)| array).collect(),
+    )
+}
+
+fn uint64(values: impl Iterator<Item = u64>) -> ArrayRef {
+    Arc::new(UInt64Array::from_iter_values(values))
+}
+
+fn float64(values: impl Iterator<Item = f64>) -> ArrayRef {
+    Arc::new(Float64Array::from_iter_values(values))
+}
+
+/// `Timestamp(Microsecond, "UTC")` array.
+fn timestamp(values: impl Iterator<Item = DateTime<Utc>>) -> ArrayRef {
+    Arc::new(
+        TimestampMicrosecondArray::from_iter_values(values.map(|time| time.timestamp_micros()))
+            .with_timezone("UTC"),
+    )
+}
+
+fn utf8<S>(values: impl Iterator<Item = S>) -> ArrayRef
+where
+    S: AsRef<str>,
+{
+    Arc::new(StringArray::from_iter_values(values))
+}
+
+/// `Decimal128(38, scale)` array using the largest scale of the provided [`Decimal`]s that every
+/// mantissa fits into, rounding values with a larger scale.
+fn decimal128(values: impl Iterator<Item = Decimal>) -> Result<ArrayRef, ArrowError> {
+    let values = values.collect::<Vec<_>>();
+    let max_scale = values.iter().map(Decimal::scale).max().unwrap_or_default();
+
+    let (mantissas, scale) = (1..=max_scale)
+        .rev()
+        .find_map(|scale| {
+            values
+                .iter()
+                .map(|value| rescaled_mantissa(*value, scale))
+                .collect::<Option<Vec<_>>>()
+                .map(|mantissas| (mantissas, scale))
+        })
+        .unwrap_or_else(|| {
+            // Every 96-bit Decimal mantissa fits into an i128 at scale 0
+            let mantissas = values
+                .iter()
+                .map(|value| value.round_dp(0).mantissa())
+                .collect();
+            (mantissas, 0)
+        });
+
+    // Decimal scale is at most 28, so it always fits into an i8
+    let array = Decimal128Array::from(mantissas).with_precision_and_scale(38, scale as i8)?;
+    Ok(Arc::new(array))
+}
+
+fn rescaled_mantissa(value: Decimal, scale: u32) -> Option<i128> {
+    let value = value.round_dp(scale);
+    value
+        .mantissa()
+        .checked_mul(10_i128.checked_pow(scale - value.scale())?)
+}
+
+/// Collect every [`MarketEvent`] into a [`RecordBatch`], with the [`DataKind`] name & it's
+/// JSON encoding in the `kind` & `data` columns.
+pub fn market_events<'a, I>(events: I) -> Result<RecordBatch, ArrowError>
+where
+    I: IntoIterator<Item = &'a MarketEvent<Instrument, DataKind>>,
+{
+    let events = events.into_iter().collect::<Vec<_>>();
+
+    record_batch(vec![
+        (
+            "time_exchange",
+            timestamp(events.iter().map(|event| event.time_exchange)),
+        ),
+        (
+            "time_received",
+            timestamp(events.iter().map(|event| event.time_received)),
+        ),
+        (
+            "exchange",
+            utf8(events.iter().map(|event| event.exchange.as_str())),
+        ),
+        (
+            "instrument",
+            utf8(events.iter().map(|event| event.instrument.to_string())),
+        ),
+        (
+            "kind",
+            utf8(events.iter().map(|event| data_kind_name(&event.kind))),
+        ),
+        (
+            "data",
+            utf8(events.iter().map(|event| {
+                // DataKind serialisation is infallible, since it contains only string map keys
+                serde_json::to_string(&event.kind).unwrap_or_default()
+            })),
+        ),
+    ])
+}
+
+/// Collect the [`PublicTrade`](barter_data::subscription::trade::PublicTrade) of every
+/// [`DataKind::Trade`] [`MarketEvent`] into a [`RecordBatch`].
+pub fn trades<'a, I>(events: I) -> Result<RecordBatch, ArrowError>
+where
+    I: IntoIterator<Item = &'a MarketEvent<Instrument, DataKind>>,
+{
+    let trades = events
+        .into_iter()
+        .filter_map(|event| match &event.kind {
+            DataKind::Trade(trade) => Some((event, trade)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    record_batch(vec![
+        (
+            "time_exchange",
+            timestamp(trades.iter().map(|(event, _)| event.time_exchange)),
+        ),
+        (
+            "time_received",
+            timestamp(trades.iter().map(|(event, _)| event.time_received)),
+        ),
+        (
+            "exchange",
+            utf8(trades.iter().map(|(event, _)| event.exchange.as_str())),
+        ),
+        (
+            "instrument",
+            utf8(trades.iter().map(|(event, _)| event.instrument.to_string())),
+        ),
+        ("id", utf8(trades.iter().map(|(_, trade)| &trade.id))),
+        (
+            "price",
+            float64(trades.iter().map(|(_, trade)| trade.price)),
+        ),
+        (
+            "amount",
+            float64(trades.iter().map(|(_, trade)| trade.amount)),
+        ),
+        (
+            "side",
+            utf8(trades.iter().map(|(_, trade)| trade.side.to_string())),
+        ),
+    ])
+}
+
+/// Collect the [`Candle`](barter_data::subscription::candle::Candle) of every
+/// [`DataKind::Candle`] [`MarketEvent`] into a [`RecordBatch`].
+pub fn candles<'a, I>(events: I) -> Result<RecordBatch, ArrowError>
+where
+    I: IntoIterator<Item = &'a MarketEvent<Instrument, DataKind>>,
+{
+    let candles = events
+        .into_iter()
+        .filter_map(|event| match &event.kind {
+            DataKind::Candle(candle) => Some((event, candle)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    record_batch(vec![
+        (
+            "time_exchange",
+            timestamp(candles.iter().map(|(event, _)| event.time_exchange)),
+        ),
+        (
+            "exchange",
+            utf8(candles.iter().map(|(event, _)| event.exchange.as_str())),
+        ),
+        (
+            "instrument",
+            utf8(
+                candles
+                    .iter()
+                    .map(|(event, _)| event.instrument.to_string()),
+            ),
+        ),
+        (
+            "close_time",
+            timestamp(candles.iter().map(|(_, candle)| candle.close_time)),
+        ),
+        (
+            "open",
+            float64(candles.iter().map(|(_, candle)| candle.open)),
+        ),
+        (
+            "high",
+            float64(candles.iter().map(|(_, candle)| candle.high)),
+        ),
+        ("low", float64(candles.iter().map(|(_, candle)| candle.low))),
+        (
+            "close",
+            float64(candles.iter().map(|(_, candle)| candle.close)),
+        ),
+        (
+            "volume",
+            float64(candles.iter().map(|(_, candle)| candle.volume)),
+        ),
+        (
+            "trade_count",
+            uint64(candles.iter().map(|(_, candle)| candle.trade_count)),
+        ),
+    ])
+}
+
+/// Collect every [`FillEvent`] into a [`RecordBatch`].
+pub fn fills<'a, I>(fills: I) -> Result<RecordBatch, ArrowError>
+where
+    I: IntoIterator<Item = &'a FillEvent>,
+{
+    let fills = fills.into_iter().collect::<Vec<_>>();
+
+    record_batch(vec![
+        ("time", timestamp(fills.iter().map(|fill| fill.time))),
+        (
+            "exchange",
+            utf8(fills.iter().map(|fill| fill.exchange.as_str())),
+        ),
+        (
+            "instrument",
+            utf8(fills.iter().map(|fill| fill.instrument.to_string())),
+        ),
+        (
+            "decision",
+            utf8(fills.iter().map(|fill| format!("{:?}", fill.decision))),
+        ),
+        (
+            "quantity",
+            decimal128(fills.iter().map(|fill| fill.quantity))?,
+        ),
+        (
+            "fill_value_gross",
+            decimal128(fills.iter().map(|fill| fill.fill_value_gross))?,
+        ),
+        (
+            "fees_total",
+            decimal128(fills.iter().map(|fill| fill.fees.calculate_total_fees()))?,
+        ),
+    ])
+}
+
+/// Collect every closed [`Position`] into a [`RecordBatch`]. Accounting fields are exported as
+/// `Decimal128` so they reconcile exactly.
+pub fn positions<'a, I>(positions: I) -> Result<RecordBatch, ArrowError>
+where
+    I: IntoIterator<Item = &'a Position>,
+{
+    let positions = positions.into_iter().collect::<Vec<_>>();
+    let decimal = |field: fn(&Position) -> Decimal| {
+        decimal128(positions.iter().map(|position| field(position)))
+    };
+
+    record_batch(vec![
+        (
+            "position_id",
+            utf8(positions.iter().map(|position| &position.position_id)),
+        ),
+        (
+            "exchange",
+            utf8(positions.iter().map(|position| position.exchange.as_str())),
+        ),
+        (
+            "instrument",
+            utf8(
+                positions
+                    .iter()
+                    .map(|position| position.instrument.to_string()),
+            ),
+        ),
+        (
+            "side",
+            utf8(positions.iter().map(|position| position.side.to_string())),
+        ),
+        (
+            "enter_time",
+            timestamp(positions.iter().map(|position| position.meta.enter_time)),
+        ),
+        (
+            "exit_time",
+            timestamp(positions.iter().map(|position| position.meta.update_time)),
+        ),
+        ("quantity", decimal(|position| position.quantity)?),
+        (
+            "enter_avg_price_gross",
+            decimal(|position| position.enter_avg_price_gross)?,
+        ),
+        (
+            "exit_avg_price_gross",
+            decimal(|position| position.exit_avg_price_gross)?,
+        ),
+        (
+            "enter_fees_total",
+            decimal(|position| position.enter_fees_total)?,
+        ),
+        (
+            "exit_fees_total",
+            decimal(|position| position.exit_fees_total)?,
+        ),
+        (
+            "realised_profit_loss",
+            decimal(|position| position.realised_profit_loss)?,
+        ),
+    ])
+}
+
+fn data_kind_name(kind: &DataKind) -> &'static str {
+    match kind {
+        DataKind::Trade(_) => "trade",
+        DataKind::OrderBookL1(_) => "order_book_l1",
+        DataKind::OrderBook(_) => "order_book",
+        DataKind::Candle(_) => "candle",
+        DataKind::Liquidation(_) => "liquidation",
+        DataKind::Custom(_) => "custom",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{fill_event, market_event_candle, market_event_trade, position};
+    use ::arrow::{
+        array::{Array, AsArray},
+        datatypes::{DataType, Decimal128Type, TimeUnit, TimestampMicrosecondType},
+    };
+    use barter_integration::Side;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_decimal128_common_scale() {
+        struct TestCase {
+            input: Vec<Decimal>,
+            expected_values: Vec<i128>,
+            expected_scale: i8,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: values are rescaled to the largest scale
+                input: vec![dec!(1.5), dec!(-0.25), dec!(10)],
+                expected_values: vec![150, -25, 1000],
+                expected_scale: 2,
+            },
+            TestCase {
+                // TC1: scale is reduced until the largest mantissa fits, rounding
+                input: vec![Decimal::MAX, dec!(0.0000000001)],
+                expected_values: vec![Decimal::MAX.mantissa() * 1_000_000_000, 0],
+                expected_scale: 9,
+            },
+            TestCase {
+                // TC2: empty column
+                input: vec![],
+                expected_values: vec![],
+                expected_scale: 0,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = decimal128(test.input.into_iter()).unwrap();
+            let actual = actual.as_primitive::<Decimal128Type>();
+            assert_eq!(
+                actual.values().to_vec(),
+                test.expected_values,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                actual.data_type(),
+                &DataType::Decimal128(38, test.expected_scale),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_market_event_batches() {
+        let events = vec![
+            market_event_trade(Side::Buy),
+            market_event_candle(),
+            market_event_trade(Side::Sell),
+        ];
+
+        let all = market_events(&events).unwrap();
+        assert_eq!(all.num_rows(), 3);
+        assert_eq!(
+            all.column_by_name("kind")
+                .unwrap()
+                .as_string::<i32>()
+                .value(1),
+            "candle"
+        );
+
+        let trades = trades(&events).unwrap();
+        assert_eq!(trades.num_rows(), 2);
+        assert_eq!(
+            trades
+                .column_by_name("side")
+                .unwrap()
+                .as_string::<i32>()
+                .value(1),
+            "sell"
+        );
+        let time_exchange = trades.column_by_name("time_exchange").unwrap();
+        assert_eq!(
+            time_exchange.data_type(),
+            &DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()))
+        );
+        assert_eq!(
+            time_exchange
+                .as_primitive::<TimestampMicrosecondType>()
+                .values()
+                .to_vec(),
+            vec![
+                events[0].time_exchange.timestamp_micros(),
+                events[2].time_exchange.timestamp_micros(),
+            ]
+        );
+
+        let candles = candles(&events).unwrap();
+        assert_eq!(candles.num_rows(), 1);
+        let trade_count = candles.schema().fields().last().cloned().unwrap();
+        assert_eq!(trade_count.name(), "trade_count");
+        assert_eq!(trade_count.data_type(), &DataType::UInt64);
+    }
+
+    #[test]
+    fn test_fill_and_position_batches() {
+        let mut fill = fill_event();
+        fill.quantity = dec!(0.5);
+        let fills = fills([&fill, &fill_event()]).unwrap();
+        assert_eq!(fills.num_rows(), 2);
+        assert_eq!(
+            fills.column_by_name("quantity").unwrap().data_type(),
+            &DataType::Decimal128(38, 1)
+        );
+
+        let mut closed = position();
+        closed.realised_profit_loss = dec!(12.345);
+        let positions = positions([&closed]).unwrap();
+        let realised_profit_loss = positions.column_by_name("realised_profit_loss").unwrap();
+        assert_eq!(
+            realised_profit_loss.data_type(),
+            &DataType::Decimal128(38, 3)
+        );
+        assert_eq!(
+            realised_profit_loss
+                .as_primitive::<Decimal128Type>()
+                .value(0),
+            12345
+        );
+        assert_eq!(
+            positions
+                .column_by_name("position_id")
+                .unwrap()
+                .as_string::<i32>()
+                .value(0),
+            closed.position_id
+        );
+    }
+}
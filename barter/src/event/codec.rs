@@ -0,0 +1,279 @@
+use super::{error::CodecError, Event, MessageTransmitter};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tracing::warn;
+
+/// Current version of the [`Codec`] frame header. Incremented whenever the header layout or the
+/// serialised [`Event`] model changes in a way that is not backwards compatible.
+pub const CODEC_VERSION: u8 = 1;
+
+/// Number of bytes in a [`Codec`] frame header: `[version, format]`.
+const HEADER_LEN: usize = 2;
+
+/// Serialisation [`Format`]s supported when transporting Barter [`Event`]s between processes.
+///
+/// JSON is human-readable and the default. Bincode & MessagePack are compact binary formats that
+/// reduce bandwidth and serialisation CPU for high-throughput deployments.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Format {
+    #[default]
+    Json,
+    Bincode,
+    MessagePack,
+}
+
+impl Format {
+    /// Return the `u8` tag used to identify this [`Format`] in a [`Codec`] frame header.
+    pub fn tag(&self) -> u8 {
+        match self {
+            Format::Json => 0,
+            Format::Bincode => 1,
+            Format::MessagePack => 2,
+        }
+    }
+
+    /// Determine the [`Format`] associated with the provided frame header tag.
+    pub fn from_tag(tag: u8) -> Result<Self, CodecError> {
+        match tag {
+            0 => Ok(Format::Json),
+            1 => Ok(Format::Bincode),
+            2 => Ok(Format::MessagePack),
+            unknown => Err(CodecError::UnknownFormat(unknown)),
+        }
+    }
+
+    /// Negotiate the [`Format`] to use with a remote peer. Returns the first of the `preferred`
+    /// [`Format`]s that the peer also `supports`, or `None` if there is no common [`Format`].
+    pub fn negotiate(preferred: &[Format], supported: &[Format]) -> Option<Format> {
+        preferred
+            .iter()
+            .find(|format| supported.contains(format))
+            .copied()
+    }
+}
+
+/// Encodes messages (eg/ [`Event`]s) into version tagged byte frames using the configured
+/// [`Format`], and decodes frames produced by any [`Codec`] version this build supports.
+///
+/// Frame layout: `[version: u8][format: u8][payload: ..]`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct Codec {
+    pub format: Format,
+}
+
+impl Codec {
+    /// Constructs a new [`Codec`] that encodes frames using the provided [`Format`].
+    pub fn new(format: Format) -> Self {
+        Self { format }
+    }
+
+    /// Serialise the provided message into a version tagged frame.
+    pub fn encode<T>(&self, message: &T) -> Result<Vec<u8>, CodecError>
+    where
+        T: Serialize,
+    {
+        let mut frame = vec![CODEC_VERSION, self.format.tag()];
+
+        match self.format {
+            Format::Json => serde_json::to_writer(&mut frame, message)?,
+            Format::Bincode => bincode::serialize_into(&mut frame, message)?,
+            Format::MessagePack => rmp_serde::encode::write_named(&mut frame, message)?,
+        }
+
+        Ok(frame)
+    }
+
+    /// Deserialise a message from the provided frame. The [`Format`] is read from the frame header,
+    /// so any [`Codec`] can decode frames encoded by a peer using a different [`Format`].
+    pub fn decode<T>(frame: &[u8]) -> Result<T, CodecError>
+    where
+        T: DeserializeOwned,
+    {
+        if frame.len() < HEADER_LEN {
+            return Err(CodecError::FrameTruncated(frame.len()));
+        }
+
+        let (header, payload) = frame.split_at(HEADER_LEN);
+
+        if header[0] != CODEC_VERSION {
+            return Err(CodecError::UnsupportedVersion(header[0]));
+        }
+
+        match Format::from_tag(header[1])? {
+            Format::Json => Ok(serde_json::from_slice(payload)?),
+            Format::Bincode => Ok(bincode::deserialize(payload)?),
+            Format::MessagePack => Ok(rmp_serde::from_slice(payload)?),
+        }
+    }
+}
+
+/// Transmitter for sending Barter [`Event`]s to an external sink as [`Codec`] encoded byte frames.
+/// Useful when bridging [`Event`]s to other processes (eg/ via Redis or NATS).
+#[derive(Debug, Clone)]
+pub struct EncodedEventTx {
+    /// Flag to communicate if the external frame receiver has been dropped.
+    receiver_dropped: bool,
+    /// [`Codec`] used to encode each [`Event`] into a byte frame.
+    codec: Codec,
+    /// Frame channel transmitter to send encoded [`Event`]s to an external sink.
+    frame_tx: mpsc::UnboundedSender<Vec<u8>>,
+}
+
+impl MessageTransmitter<Event> for EncodedEventTx {
+    fn send(&mut self, message: Event) {
+        if self.receiver_dropped {
+            return;
+        }
+
+        let frame = match self.codec.encode(&message) {
+            Ok(frame) => frame,
+            Err(error) => {
+                warn!(?error, format = ?self.codec.format, "failed to encode Event");
+                return;
+            }
+        };
+
+        if self.frame_tx.send(frame).is_err() {
+            warn!(
+                action = "setting receiver_dropped = true",
+                why = "frame receiver dropped",
+                "cannot send encoded Events"
+            );
+            self.receiver_dropped = true;
+        }
+    }
+
+    fn send_many(&mut self, messages: Vec<Event>) {
+        messages
+            .into_iter()
+            .for_each(|message| MessageTransmitter::send(self, message))
+    }
+}
+
+impl EncodedEventTx {
+    /// Constructs a new [`EncodedEventTx`] instance using the provided [`Codec`] and channel
+    /// transmitter.
+    pub fn new(codec: Codec, frame_tx: mpsc::UnboundedSender<Vec<u8>>) -> Self {
+        Self {
+            receiver_dropped: false,
+            codec,
+            frame_tx,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{fill_event, market_event_trade, order_event, position, signal};
+    use barter_integration::Side;
+
+    #[test]
+    fn test_codec_round_trip_events() {
+        let events = [
+            Event::Market(market_event_trade(Side::Buy)),
+            Event::Signal(signal()),
+            Event::OrderNew(order_event()),
+            Event::Fill(fill_event()),
+            Event::PositionNew(Box::new(position())),
+        ];
+
+        for format in [Format::Json, Format::Bincode, Format::MessagePack] {
+            let codec = Codec::new(format);
+
+            for (index, event) in events.iter().enumerate() {
+                let frame = codec.encode(event).unwrap();
+                assert_eq!(frame[0], CODEC_VERSION);
+                assert_eq!(frame[1], format.tag());
+
+                let actual = Codec::decode::<Event>(&frame).unwrap();
+                assert_eq!(&actual, event, "TC{index} failed for {format:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_codec_decode_invalid_header() {
+        struct TestCase {
+            input: Vec<u8>,
+            expected: CodecError,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: frame shorter than header
+                input: vec![CODEC_VERSION],
+                expected: CodecError::FrameTruncated(1),
+            },
+            TestCase {
+                // TC1: unsupported version
+                input: vec![CODEC_VERSION + 1, Format::Json.tag()],
+                expected: CodecError::UnsupportedVersion(CODEC_VERSION + 1),
+            },
+            TestCase {
+                // TC2: unknown format tag
+                input: vec![CODEC_VERSION, 99],
+                expected: CodecError::UnknownFormat(99),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = Codec::decode::<Event>(&test.input).unwrap_err();
+            assert_eq!(
+                actual.to_string(),
+                test.expected.to_string(),
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_negotiate() {
+        struct TestCase {
+            preferred: Vec<Format>,
+            supported: Vec<Format>,
+            expected: Option<Format>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: first preferred Format is supported
+                preferred: vec![Format::Bincode, Format::Json],
+                supported: vec![Format::Json, Format::Bincode],
+                expected: Some(Format::Bincode),
+            },
+            TestCase {
+                // TC1: fall back to next preferred Format
+                preferred: vec![Format::MessagePack, Format::Json],
+                supported: vec![Format::Json],
+                expected: Some(Format::Json),
+            },
+            TestCase {
+                // TC2: no common Format
+                preferred: vec![Format::MessagePack],
+                supported: vec![Format::Bincode],
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = Format::negotiate(&test.preferred, &test.supported);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_encoded_event_tx_sends_frames() {
+        let (frame_tx, mut frame_rx) = mpsc::unbounded_channel();
+        let mut event_tx = EncodedEventTx::new(Codec::new(Format::Bincode), frame_tx);
+
+        let event = Event::Fill(fill_event());
+        event_tx.send(event.clone());
+
+        let frame = frame_rx.try_recv().unwrap();
+        assert_eq!(Codec::decode::<Event>(&frame).unwrap(), event);
+    }
+}
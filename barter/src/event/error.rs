@@ -0,0 +1,52 @@
+use thiserror::Error;
+
+/// All errors generated when encoding or decoding Barter [`Event`](super::Event) frames.
+#[derive(Error, Debug)]
+pub enum CodecError {
+    #[error("frame is too short to contain a codec header: {0} bytes")]
+    FrameTruncated(usize),
+
+    #[error("unsupported codec version: {0}")]
+    UnsupportedVersion(u8),
+
+    #[error("unknown codec format tag: {0}")]
+    UnknownFormat(u8),
+
+    #[error("JSON codec error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("bincode codec error: {0}")]
+    Bincode(#[from] bincode::Error),
+
+    #[error("MessagePack encode error: {0}")]
+    MessagePackEncode(#[from] rmp_serde::encode::Error),
+
+    #[error("MessagePack decode error: {0}")]
+    MessagePackDecode(#[from] rmp_serde::decode::Error),
+}
+
+/// All errors generated when writing or replaying an [`EventJournal`](super::journal::EventJournal).
+#[derive(Error, Debug)]
+pub enum JournalError {
+    #[error("journal I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("journal codec error: {0}")]
+    Codec(#[from] CodecError),
+
+    #[error("journal record is too large to be length prefixed: {0} bytes")]
+    RecordTooLarge(usize),
+
+    #[error("journal sequence gap: expected {expected}, found {actual}")]
+    SequenceGap { expected: u64, actual: u64 },
+}
+
+/// All errors generated when replaying a journal with a [`Replayer`](super::replay::Replayer).
+#[derive(Error, Debug)]
+pub enum ReplayError {
+    #[error("replay journal error: {0}")]
+    Journal(#[from] JournalError),
+
+    #[error("replay Portfolio error: {0}")]
+    Portfolio(#[from] crate::portfolio::error::PortfolioError),
+}
@@ -0,0 +1,529 @@
+use super::{codec::Codec, error::JournalError, Event, MessageTransmitter};
+use crate::portfolio::{
+    position::{Position, PositionExit, PositionId, PositionUpdate},
+    Balance,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs::{File, OpenOptions},
+    io::{BufReader, BufWriter, ErrorKind, Read, Write},
+    path::Path,
+};
+use tracing::warn;
+
+/// Number of bytes in a journal record length prefix.
+const LENGTH_PREFIX_LEN: usize = 4;
+
+/// [`Event`] persisted in an [`EventJournal`], tagged with it's position in the journal.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize, JsonSchema)]
+pub struct JournalRecord {
+    /// Monotonically increasing sequence number, starting at zero for the first [`Event`] in the
+    /// journal.
+    pub sequence: u64,
+    pub event: Event,
+}
+
+/// Append-only [`Event`] journal, used for post-incident forensics & replay.
+///
+/// Each [`JournalRecord`] is written as a little-endian `u32` length prefix, followed by a
+/// [`Codec`] encoded frame. Since frames are self describing, journals written with any
+/// [`Format`](super::codec::Format) can be replayed by [`JournalReader`].
+///
+/// Implements [`MessageTransmitter<Event>`], so it can be used as the Trader `EventTx`.
+#[derive(Debug)]
+pub struct EventJournal<W>
+where
+    W: Write,
+{
+    /// Flag to communicate if a previous write failed, and the journal is no longer being written.
+    write_failed: bool,
+    codec: Codec,
+    sequence: u64,
+    writer: W,
+}
+
+impl EventJournal<BufWriter<File>> {
+    /// Open the journal file at the provided path, creating it if it does not exist.
+    ///
+    /// Since an existing journal is appended to, the provided `next_sequence` should be the
+    /// number of [`JournalRecord`]s it already contains (see [`JournalReader::record_count`]).
+    pub fn open<P>(path: P, codec: Codec, next_sequence: u64) -> Result<Self, JournalError>
+    where
+        P: AsRef<Path>,
+    {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self::new(BufWriter::new(file), codec, next_sequence))
+    }
+}
+
+impl<W> EventJournal<W>
+where
+    W: Write,
+{
+    /// Constructs a new [`EventJournal`] that writes [`JournalRecord`]s to the provided writer,
+    /// starting at the provided sequence.
+    pub fn new(writer: W, codec: Codec, sequence: u64) -> Self {
+        Self {
+            write_failed: false,
+            codec,
+            sequence,
+            writer,
+        }
+    }
+
+    /// Append an [`Event`] to the journal, returning the sequence it was assigned.
+    ///
+    /// Note that the underlying writer is not flushed, see [`Self::flush`].
+    pub fn append(&mut self, event: Event) -> Result<u64, JournalError> {
+        let sequence = self.sequence;
+        let frame = self.codec.encode(&JournalRecord { sequence, event })?;
+
+        let length =
+            u32::try_from(frame.len()).map_err(|_| JournalError::RecordTooLarge(frame.len()))?;
+        self.writer.write_all(&length.to_le_bytes())?;
+        self.writer.write_all(&frame)?;
+
+        self.sequence += 1;
+        Ok(sequence)
+    }
+
+    /// Flush any buffered [`JournalRecord`]s to the underlying writer.
+    pub fn flush(&mut self) -> Result<(), JournalError> {
+        self.writer.flush().map_err(JournalError::from)
+    }
+
+    /// Sequence that will be assigned to the next appended [`Event`].
+    pub fn next_sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// Consume the [`EventJournal`], returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn append_and_log(&mut self, event: Event) {
+        if let Err(error) = self.append(event) {
+            warn!(
+                ?error,
+                action = "setting write_failed = true",
+                "failed to append Event to EventJournal"
+            );
+            self.write_failed = true;
+        }
+    }
+
+    fn flush_and_log(&mut self) {
+        if let Err(error) = self.flush() {
+            warn!(
+                ?error,
+                action = "setting write_failed = true",
+                "failed to flush EventJournal"
+            );
+            self.write_failed = true;
+        }
+    }
+}
+
+impl<W> MessageTransmitter<Event> for EventJournal<W>
+where
+    W: Write,
+{
+    fn send(&mut self, message: Event) {
+        if self.write_failed {
+            return;
+        }
+
+        self.append_and_log(message);
+        self.flush_and_log();
+    }
+
+    fn send_many(&mut self, messages: Vec<Event>) {
+        if self.write_failed {
+            return;
+        }
+
+        for message in messages {
+            self.append_and_log(message);
+        }
+        self.flush_and_log();
+    }
+
+    fn flush(&mut self) {
+        if self.write_failed {
+            return;
+        }
+
+        self.flush_and_log();
+    }
+}
+
+/// Reads the [`JournalRecord`]s written by an [`EventJournal`], in order.
+#[derive(Debug)]
+pub struct JournalReader<R>
+where
+    R: Read,
+{
+    reader: R,
+}
+
+impl JournalReader<BufReader<File>> {
+    /// Open the journal file at the provided path for replay.
+    pub fn open<P>(path: P) -> Result<Self, JournalError>
+    where
+        P: AsRef<Path>,
+    {
+        Ok(Self::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+impl<R> JournalReader<R>
+where
+    R: Read,
+{
+    pub fn new(reader: R) -> Self {
+        Self { reader }
+    }
+
+    /// Read the next [`JournalRecord`], returning `None` at the end of the journal.
+    ///
+    /// A partially written final record (eg/ due to a crash mid-write) is treated as the end of
+    /// the journal.
+    pub fn next_record(&mut self) -> Result<Option<JournalRecord>, JournalError> {
+        let mut length = [0u8; LENGTH_PREFIX_LEN];
+        if !self.read_exact_or_eof(&mut length)? {
+            return Ok(None);
+        }
+
+        let mut frame = vec![0u8; u32::from_le_bytes(length) as usize];
+        if !self.read_exact_or_eof(&mut frame)? {
+            return Ok(None);
+        }
+
+        Codec::decode(&frame).map(Some).map_err(JournalError::from)
+    }
+
+    fn read_exact_or_eof(&mut self, buffer: &mut [u8]) -> Result<bool, JournalError> {
+        match self.reader.read_exact(buffer) {
+            Ok(()) => Ok(true),
+            Err(error) if error.kind() == ErrorKind::UnexpectedEof => Ok(false),
+            Err(error) => Err(JournalError::from(error)),
+        }
+    }
+
+    /// Count the remaining [`JournalRecord`]s in the journal.
+    pub fn record_count(mut self) -> Result<u64, JournalError> {
+        let mut count = 0;
+        while self.next_record()?.is_some() {
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+impl<R> Iterator for JournalReader<R>
+where
+    R: Read,
+{
+    type Item = Result<JournalRecord, JournalError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_record().transpose()
+    }
+}
+
+/// Typed consumer of a persisted journal that validates every [`JournalRecord`] as it is
+/// deserialised, yielding a [`JournalError::SequenceGap`] if a record is missing or out of order.
+#[derive(Debug)]
+pub struct JournalConsumer<R>
+where
+    R: Read,
+{
+    reader: JournalReader<R>,
+    next_sequence: Option<u64>,
+}
+
+impl<R> JournalConsumer<R>
+where
+    R: Read,
+{
+    pub fn new(reader: JournalReader<R>) -> Self {
+        Self {
+            reader,
+            next_sequence: None,
+        }
+    }
+
+    /// Read & validate the next [`JournalRecord`], returning `None` at the end of the journal.
+    pub fn next_record(&mut self) -> Result<Option<JournalRecord>, JournalError> {
+        let Some(record) = self.reader.next_record()? else {
+            return Ok(None);
+        };
+
+        match self.next_sequence {
+            Some(expected) if expected != record.sequence => Err(JournalError::SequenceGap {
+                expected,
+                actual: record.sequence,
+            }),
+            _ => {
+                self.next_sequence = Some(record.sequence + 1);
+                Ok(Some(record))
+            }
+        }
+    }
+}
+
+impl<R> Iterator for JournalConsumer<R>
+where
+    R: Read,
+{
+    type Item = Result<JournalRecord, JournalError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_record().transpose()
+    }
+}
+
+/// Portfolio state reconstructed by replaying journaled [`Event`]s.
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct JournalState {
+    /// Sequence of the last applied [`JournalRecord`].
+    pub sequence: Option<u64>,
+    pub balance: Option<Balance>,
+    pub open_positions: HashMap<PositionId, Position>,
+    pub exited_positions: Vec<Position>,
+}
+
+impl JournalState {
+    /// Reconstruct the [`JournalState`] as it was after the [`JournalRecord`] with the provided
+    /// sequence was applied. If `sequence` is `None`, every [`JournalRecord`] is applied.
+    pub fn replay<Records>(records: Records, sequence: Option<u64>) -> Result<Self, JournalError>
+    where
+        Records: IntoIterator<Item = Result<JournalRecord, JournalError>>,
+    {
+        let mut state = Self::default();
+
+        for record in records {
+            let record = record?;
+            if sequence.is_some_and(|sequence| record.sequence > sequence) {
+                break;
+            }
+            state.apply(record);
+        }
+
+        Ok(state)
+    }
+
+    /// Apply the next [`JournalRecord`] to the [`JournalState`].
+    pub fn apply(&mut self, record: JournalRecord) {
+        self.sequence = Some(record.sequence);
+
+        match record.event {
+            Event::PositionNew(position) => {
+                self.open_positions
+                    .insert(position.position_id.clone(), *position);
+            }
+            Event::PositionUpdate(update) => self.apply_position_update(update),
+            Event::PositionExit(exit) => self.apply_position_exit(exit),
+            Event::Balance(balance) => self.balance = Some(balance),
+            _ => {}
+        }
+    }
+
+    fn apply_position_update(&mut self, update: PositionUpdate) {
+        let Some(position) = self.open_positions.get_mut(&update.position_id) else {
+            warn!(position_id = %update.position_id, "replayed PositionUpdate for unknown Position");
+            return;
+        };
+
+        position.meta.update_time = update.update_time;
+        position.current_symbol_price = update.current_symbol_price;
+        position.current_value_gross = update.current_value_gross;
+        position.unrealised_profit_loss = update.unrealised_profit_loss;
+    }
+
+    fn apply_position_exit(&mut self, exit: PositionExit) {
+        let Some(mut position) = self.open_positions.remove(&exit.position_id) else {
+            warn!(position_id = %exit.position_id, "replayed PositionExit for unknown Position");
+            return;
+        };
+
+        position.meta.update_time = exit.exit_time;
+        position.meta.exit_balance = Some(exit.exit_balance);
+        position.exit_fees = exit.exit_fees;
+        position.exit_fees_total = exit.exit_fees_total;
+        position.exit_avg_price_gross = exit.exit_avg_price_gross;
+        position.exit_value_gross = exit.exit_value_gross;
+        position.realised_profit_loss = exit.realised_profit_loss;
+        position.unrealised_profit_loss = exit.realised_profit_loss;
+
+        self.exited_positions.push(position);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::codec::Format,
+        test_util::{fill_event, position},
+    };
+    use chrono::Utc;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    fn events() -> Vec<Event> {
+        let position = position();
+
+        vec![
+            Event::Balance(Balance::new(Utc::now(), dec!(1000), dec!(1000))),
+            Event::Fill(fill_event()),
+            Event::PositionNew(Box::new(position.clone())),
+            Event::PositionUpdate(PositionUpdate {
+                position_id: position.position_id.clone(),
+                update_time: Utc::now(),
+                current_symbol_price: dec!(2),
+                current_value_gross: dec!(2),
+                unrealised_profit_loss: dec!(1),
+            }),
+            Event::PositionExit(PositionExit {
+                position_id: position.position_id.clone(),
+                exit_time: Utc::now(),
+                exit_balance: Balance::new(Utc::now(), dec!(1010), dec!(1010)),
+                exit_fees: Default::default(),
+                exit_fees_total: dec!(0),
+                exit_avg_price_gross: dec!(3),
+                exit_value_gross: dec!(3),
+                realised_profit_loss: dec!(10),
+            }),
+        ]
+    }
+
+    #[test]
+    fn test_journal_round_trip() {
+        let events = events();
+
+        for format in [Format::Json, Format::Bincode] {
+            let mut journal = EventJournal::new(Vec::new(), Codec::new(format), 0);
+            journal.send_many(events.clone());
+
+            let bytes = journal.into_inner();
+            let actual = JournalReader::new(bytes.as_slice())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+
+            let expected = events
+                .iter()
+                .cloned()
+                .enumerate()
+                .map(|(sequence, event)| JournalRecord {
+                    sequence: sequence as u64,
+                    event,
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, expected, "failed for {format:?}");
+
+            // Partially written final record is treated as the end of the journal
+            let truncated = &bytes[..bytes.len() - 1];
+            assert_eq!(JournalReader::new(truncated).record_count().unwrap(), 4);
+        }
+    }
+
+    #[test]
+    fn test_journal_consumer_sequence_gap() {
+        let mut journal = EventJournal::new(Vec::new(), Codec::default(), 0);
+        journal.send(Event::Balance(Balance::new(
+            Utc::now(),
+            dec!(1000),
+            dec!(1000),
+        )));
+        journal.sequence += 1;
+        journal.send(Event::Balance(Balance::new(
+            Utc::now(),
+            dec!(1000),
+            dec!(1000),
+        )));
+        let bytes = journal.into_inner();
+
+        let mut consumer = JournalConsumer::new(JournalReader::new(bytes.as_slice()));
+        assert_eq!(consumer.next().unwrap().unwrap().sequence, 0);
+        assert!(matches!(
+            consumer.next(),
+            Some(Err(JournalError::SequenceGap {
+                expected: 1,
+                actual: 2
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_journal_state_replay() {
+        struct TestCase {
+            sequence: Option<u64>,
+            expected_open: usize,
+            expected_exited: usize,
+            expected_unrealised: Option<Decimal>,
+        }
+
+        let mut journal = EventJournal::new(Vec::new(), Codec::default(), 0);
+        journal.send_many(events());
+        let bytes = journal.into_inner();
+
+        let tests = vec![
+            TestCase {
+                // TC0: replay before Position is opened
+                sequence: Some(1),
+                expected_open: 0,
+                expected_exited: 0,
+                expected_unrealised: None,
+            },
+            TestCase {
+                // TC1: replay until Position is updated
+                sequence: Some(3),
+                expected_open: 1,
+                expected_exited: 0,
+                expected_unrealised: Some(dec!(1)),
+            },
+            TestCase {
+                // TC2: replay every Event
+                sequence: None,
+                expected_open: 0,
+                expected_exited: 1,
+                expected_unrealised: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual =
+                JournalState::replay(JournalReader::new(bytes.as_slice()), test.sequence).unwrap();
+
+            assert_eq!(
+                actual.balance.unwrap().total,
+                dec!(1000),
+                "TC{index} failed"
+            );
+            assert_eq!(
+                actual.open_positions.len(),
+                test.expected_open,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                actual.exited_positions.len(),
+                test.expected_exited,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                actual
+                    .open_positions
+                    .values()
+                    .next()
+                    .map(|position| position.unrealised_profit_loss),
+                test.expected_unrealised,
+                "TC{index} failed"
+            );
+        }
+    }
+}
@@ -1,34 +1,68 @@
 use crate::{
-    execution::FillEvent,
+    data::{sanity::MarketAnomaly, universe::UniverseUpdate},
+    engine::schedule::ScheduledEvent,
+    execution::{lifecycle::OrderLifecycle, FillEvent},
     portfolio::{
         position::{Position, PositionExit, PositionUpdate},
         Balance, OrderEvent,
     },
     strategy::{Signal, SignalForceExit},
 };
-use barter_data::event::{DataKind, MarketEvent};
+use barter_data::event::{CustomData, DataKind, MarketEvent};
 use barter_instrument::instrument::Instrument;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use tokio::sync::mpsc;
 use tracing::warn;
 
+/// [`Codec`](codec::Codec) for encoding [`Event`]s into compact, version tagged byte frames
+/// using a selectable serialisation [`Format`](codec::Format).
+pub mod codec;
+
+/// All errors generated when encoding, decoding or journaling [`Event`]s.
+pub mod error;
+
+/// Event-sourcing [`Replayer`](replay::Replayer) that rebuilds Portfolio state from a journal of
+/// [`Event`]s, verifying the replay is in parity with the journal.
+pub mod replay;
+
+/// JSON schema export of the [`Event`] & [`JournalRecord`](journal::JournalRecord) models, for
+/// consuming Barter output from other languages.
+pub mod schema;
+
+/// Arrow [`RecordBatch`](::arrow::record_batch::RecordBatch) export of `MarketEvent`s, fills &
+/// closed positions, for zero-copy handoff to Polars or DataFusion.
+#[cfg(feature = "arrow")]
+pub mod arrow;
+
+/// Append-only [`Event`] journal persisted to disk, with replay to reconstruct portfolio state at
+/// any sequence.
+pub mod journal;
+
 /// Events that occur when bartering. [`MarketEvent`], [`Signal`], [`OrderEvent`], and
 /// [`FillEvent`] are vital to the [`Trader`](crate::engine::trader::Trader) event loop, dictating
 /// the trading sequence. The [`PositionExit`] Event is a representation of work done by the
-/// system, and is useful for analysing performance & reconciliations.
-#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+/// system, and is useful for analysing performance & reconciliations. [`Event::Custom`] carries
+/// downstream defined events (eg/ news, model signals) injected via a
+/// [`Command::Custom`](crate::engine::Command), processed in order alongside market data.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize, JsonSchema)]
 pub enum Event {
     Market(MarketEvent<Instrument, DataKind>),
     Signal(Signal),
     SignalForceExit(SignalForceExit),
     OrderNew(OrderEvent),
     OrderUpdate,
+    OrderLifecycle(OrderLifecycle),
     Fill(FillEvent),
-    PositionNew(Position),
+    PositionNew(Box<Position>),
     PositionUpdate(PositionUpdate),
     PositionExit(PositionExit),
     Balance(Balance),
+    MarketAnomaly(MarketAnomaly),
+    UniverseUpdate(UniverseUpdate),
+    Scheduled(ScheduledEvent),
+    Custom(CustomData),
 }
 
 /// Message transmitter for sending Barter messages to downstream consumers.
@@ -38,6 +72,10 @@ pub trait MessageTransmitter<Message> {
 
     /// Attempts to send many messages to an external message subscriber.
     fn send_many(&mut self, messages: Vec<Message>);
+
+    /// Flush any buffered messages to the external message subscriber. Called by a
+    /// [`Trader`](crate::engine::trader::Trader) once it has stopped trading.
+    fn flush(&mut self) {}
 }
 
 /// Transmitter for sending Barter [`Event`]s to an external sink. Useful for event-sourcing,
@@ -0,0 +1,270 @@
+use super::{
+    error::{JournalError, ReplayError},
+    journal::{JournalRecord, JournalState},
+    Event,
+};
+use crate::portfolio::{FillUpdater, MarketUpdater};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use tracing::warn;
+
+/// Difference between a journaled [`Event`] and the [`Event`] generated by the Portfolio when
+/// replaying the journal.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Divergence {
+    /// Sequence of the journaled [`Event`], or the last [`JournalRecord`] if the replayed
+    /// Portfolio generated an [`Event`] that was never journaled.
+    pub sequence: u64,
+    pub journaled: Option<Event>,
+    pub replayed: Option<Event>,
+}
+
+/// Outcome of a [`Replayer`] run.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ReplayReport {
+    /// Number of [`JournalRecord`]s replayed.
+    pub records: u64,
+    /// State reconstructed from the journaled [`Event`]s.
+    pub state: JournalState,
+    /// Every journaled [`Event`] that the replayed Portfolio did not reproduce. Empty if the
+    /// journal & the replay are in parity.
+    pub divergences: Vec<Divergence>,
+}
+
+/// Event-sourcing replay harness that deterministically reconstructs Portfolio state from a
+/// journal of [`Event`]s (eg/ read via a [`JournalConsumer`](super::journal::JournalConsumer),
+/// which asserts sequence continuity).
+///
+/// Every journaled [`Event::Market`] & [`Event::Fill`] is re-applied to the provided Portfolio,
+/// and the Portfolio generated [`Event`]s are verified against those journaled. This allows
+/// verifying live/backtest parity, and debugging how a Portfolio reached it's current state.
+///
+/// Note that the Portfolio must be initialised identically to the one that generated the
+/// journal (eg/ same engine_id & starting cash).
+#[derive(Debug)]
+pub struct Replayer<Portfolio> {
+    pub portfolio: Portfolio,
+    records: u64,
+    state: JournalState,
+    replayed: VecDeque<Event>,
+    divergences: Vec<Divergence>,
+}
+
+impl<Portfolio> Replayer<Portfolio>
+where
+    Portfolio: MarketUpdater + FillUpdater,
+{
+    pub fn new(portfolio: Portfolio) -> Self {
+        Self {
+            portfolio,
+            records: 0,
+            state: JournalState::default(),
+            replayed: VecDeque::new(),
+            divergences: Vec::new(),
+        }
+    }
+
+    /// Replay every [`JournalRecord`] up to & including the provided sequence. If `until` is
+    /// `None`, the entire journal is replayed.
+    pub fn replay<Records>(
+        mut self,
+        records: Records,
+        until: Option<u64>,
+    ) -> Result<ReplayReport, ReplayError>
+    where
+        Records: IntoIterator<Item = Result<JournalRecord, JournalError>>,
+    {
+        for record in records {
+            let record = record?;
+            if until.is_some_and(|until| record.sequence > until) {
+                break;
+            }
+            self.apply(record)?;
+        }
+
+        Ok(self.finish())
+    }
+
+    /// Replay the next [`JournalRecord`].
+    pub fn apply(&mut self, record: JournalRecord) -> Result<(), ReplayError> {
+        self.records += 1;
+
+        match &record.event {
+            // Inputs re-applied to the Portfolio
+            Event::Market(market) => {
+                if let Some(update) = self.portfolio.update_from_market(market)? {
+                    self.replayed.push_back(Event::PositionUpdate(update));
+                }
+            }
+            Event::Fill(fill) => {
+                self.replayed.extend(self.portfolio.update_from_fill(fill)?);
+            }
+
+            // Outputs verified against the Portfolio generated Events
+            Event::PositionNew(_)
+            | Event::PositionUpdate(_)
+            | Event::PositionExit(_)
+            | Event::Balance(_) => {
+                let replayed = self.replayed.pop_front();
+                if replayed.as_ref() != Some(&record.event) {
+                    warn!(
+                        sequence = record.sequence,
+                        journaled = ?record.event,
+                        ?replayed,
+                        "replayed Portfolio diverged from journal"
+                    );
+                    self.divergences.push(Divergence {
+                        sequence: record.sequence,
+                        journaled: Some(record.event.clone()),
+                        replayed,
+                    });
+                }
+            }
+
+            // Strategy & execution decisions are not re-generated
+            Event::Signal(_)
+            | Event::SignalForceExit(_)
+            | Event::OrderNew(_)
+            | Event::OrderUpdate
+            | Event::OrderLifecycle(_)
+            | Event::MarketAnomaly(_)
+            | Event::UniverseUpdate(_)
+            | Event::Scheduled(_)
+            | Event::Custom(_) => {}
+        }
+
+        self.state.apply(record);
+        Ok(())
+    }
+
+    /// Finish the replay, generating a [`ReplayReport`]. Any Portfolio generated [`Event`]s that
+    /// were never journaled are reported as [`Divergence`]s.
+    pub fn finish(mut self) -> ReplayReport {
+        let sequence = self.state.sequence.unwrap_or_default();
+        self.divergences
+            .extend(self.replayed.drain(..).map(|replayed| Divergence {
+                sequence,
+                journaled: None,
+                replayed: Some(replayed),
+            }));
+
+        ReplayReport {
+            records: self.records,
+            state: self.state,
+            divergences: self.divergences,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::{
+            codec::Codec,
+            journal::{EventJournal, JournalConsumer, JournalReader},
+            MessageTransmitter,
+        },
+        portfolio::{
+            allocator::DefaultAllocator, portfolio::MetaPortfolio,
+            repository::in_memory::InMemoryRepository, risk::DefaultRisk,
+        },
+        statistic::summary::pnl::PnLReturnSummary,
+        test_util::{fill_event, market_event_trade},
+    };
+    use barter_instrument::{
+        exchange::ExchangeId,
+        instrument::{kind::InstrumentKind, Instrument},
+        market::Market,
+    };
+    use barter_integration::Side;
+    use rust_decimal_macros::dec;
+    use uuid::Uuid;
+
+    type Portfolio = MetaPortfolio<
+        InMemoryRepository<PnLReturnSummary>,
+        DefaultAllocator,
+        DefaultRisk,
+        PnLReturnSummary,
+    >;
+
+    fn portfolio(engine_id: Uuid) -> Portfolio {
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![Market::new(
+                ExchangeId::BinanceSpot,
+                Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
+            )])
+            .starting_cash(dec!(1000))
+            .repository(InMemoryRepository::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: dec!(100),
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(())
+            .build_and_init()
+            .unwrap()
+    }
+
+    /// Generate a journal in the same order as a Trader would.
+    fn journal(engine_id: Uuid) -> Vec<u8> {
+        let mut portfolio = portfolio(engine_id);
+        let mut journal = EventJournal::new(Vec::new(), Codec::default(), 0);
+
+        let enter = fill_event();
+        let mut market = market_event_trade(Side::Buy);
+        market.instrument = enter.instrument.clone();
+
+        journal.send(Event::Fill(enter.clone()));
+        journal.send_many(portfolio.update_from_fill(&enter).unwrap());
+        journal.send(Event::Market(market.clone()));
+        if let Some(update) = portfolio.update_from_market(&market).unwrap() {
+            journal.send(Event::PositionUpdate(update));
+        }
+
+        journal.into_inner()
+    }
+
+    #[test]
+    fn test_replayer_parity() {
+        let engine_id = Uuid::new_v4();
+        let bytes = journal(engine_id);
+
+        let actual = Replayer::new(portfolio(engine_id))
+            .replay(
+                JournalConsumer::new(JournalReader::new(bytes.as_slice())),
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(actual.records, 5);
+        assert_eq!(actual.divergences, vec![]);
+        assert_eq!(actual.state.open_positions.len(), 1);
+        assert_eq!(actual.state.balance.unwrap().available, dec!(900));
+    }
+
+    #[test]
+    fn test_replayer_divergence() {
+        let bytes = journal(Uuid::new_v4());
+
+        // Portfolio initialised with a different engine_id generates different PositionIds
+        let actual = Replayer::new(portfolio(Uuid::new_v4()))
+            .replay(
+                JournalConsumer::new(JournalReader::new(bytes.as_slice())),
+                Some(2),
+            )
+            .unwrap();
+
+        assert_eq!(actual.records, 3);
+        assert_eq!(actual.divergences.len(), 1);
+        assert_eq!(actual.divergences[0].sequence, 1);
+        assert!(matches!(
+            actual.divergences[0].journaled,
+            Some(Event::PositionNew(_))
+        ));
+        assert!(matches!(
+            actual.divergences[0].replayed,
+            Some(Event::PositionNew(_))
+        ));
+    }
+}
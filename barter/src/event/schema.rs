@@ -0,0 +1,85 @@
+use super::{codec::CODEC_VERSION, journal::JournalRecord, Event};
+use schemars::{schema_for, Schema};
+
+/// Path of the checked-in [`JournalRecord`] JSON schema, relative to the barter crate root.
+///
+/// Tooling in other languages should consume this file rather than depending on the barter crate.
+/// It is kept up to date by the `test_journal_record_schema_is_up_to_date` unit test.
+pub const JOURNAL_RECORD_SCHEMA_PATH: &str = "schema/journal_record.schema.json";
+
+/// Generate the JSON schema of a JSON encoded [`Event`].
+pub fn event_schema() -> Schema {
+    with_codec_version(schema_for!(Event))
+}
+
+/// Generate the JSON schema of a JSON encoded [`JournalRecord`], as persisted by an
+/// [`EventJournal`](super::journal::EventJournal) using [`Format::Json`](super::codec::Format).
+pub fn journal_record_schema() -> Schema {
+    with_codec_version(schema_for!(JournalRecord))
+}
+
+/// Tag the [`Schema`] with the [`CODEC_VERSION`] it describes, so consumers can detect a
+/// mismatch with the version byte of each frame.
+fn with_codec_version(mut schema: Schema) -> Schema {
+    schema.insert("x-codec-version".to_owned(), CODEC_VERSION.into());
+    schema
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{fill_event, market_event_trade, position, signal};
+    use barter_integration::Side;
+
+    #[test]
+    fn test_journal_record_schema_is_up_to_date() {
+        let path =
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(JOURNAL_RECORD_SCHEMA_PATH);
+        let expected = serde_json::to_string_pretty(&journal_record_schema()).unwrap() + "\n";
+
+        if std::env::var_os("BARTER_UPDATE_SCHEMA").is_some() {
+            std::fs::write(&path, &expected).unwrap();
+        }
+
+        let actual = std::fs::read_to_string(&path).unwrap_or_default();
+        assert_eq!(
+            actual, expected,
+            "{JOURNAL_RECORD_SCHEMA_PATH} is out of date, re-run with BARTER_UPDATE_SCHEMA=1"
+        );
+    }
+
+    #[test]
+    fn test_event_schema_describes_events() {
+        let schema = serde_json::to_value(event_schema()).unwrap();
+        let variants = schema["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .flat_map(|variant| {
+                variant["required"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .chain(variant["enum"].as_array().into_iter().flatten())
+                    .filter_map(|name| name.as_str().map(str::to_owned))
+            })
+            .collect::<Vec<_>>();
+
+        let events = [
+            Event::Market(market_event_trade(Side::Buy)),
+            Event::Signal(signal()),
+            Event::Fill(fill_event()),
+            Event::PositionNew(Box::new(position())),
+            Event::OrderUpdate,
+        ];
+
+        for (index, event) in events.iter().enumerate() {
+            let variant = match serde_json::to_value(event).unwrap() {
+                serde_json::Value::Object(object) => object.keys().next().unwrap().clone(),
+                serde_json::Value::String(unit) => unit,
+                other => panic!("unexpected Event encoding: {other}"),
+            };
+            assert!(variants.contains(&variant), "TC{index} failed");
+        }
+    }
+}
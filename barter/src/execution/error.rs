@@ -1,8 +1,74 @@
+use barter_execution::error::ErrorClass;
+use barter_instrument::{exchange::ExchangeId, instrument::Instrument};
+use rust_decimal::Decimal;
 use thiserror::Error;
 
 /// All errors generated in the barter::execution module.
-#[derive(Error, Copy, Clone, Debug)]
+#[derive(Error, Clone, Debug)]
 pub enum ExecutionError {
     #[error("Failed to build struct due to missing attributes: {0}")]
     BuilderIncomplete(&'static str),
+
+    #[error("ExecutionClient does not support transfers for exchange: {0}")]
+    UnsupportedTransfer(ExchangeId),
+
+    #[error("ExecutionClient does not support withdrawals for exchange: {0}")]
+    UnsupportedWithdraw(ExchangeId),
+
+    #[error("{field} cannot be represented as a Decimal: {value}")]
+    NonDecimal { field: &'static str, value: f64 },
+
+    #[error("ExecutionClient error: {0}")]
+    Client(#[from] barter_execution::error::ExecutionError),
+}
+
+impl ExecutionError {
+    /// [`ErrorClass`] of the [`ExecutionError`], determining whether the failed request should be
+    /// retried, abandoned, or the [`Trader`](crate::engine::trader::Trader) stopped.
+    pub fn class(&self) -> ErrorClass {
+        match self {
+            ExecutionError::Client(error) => error.class(),
+            ExecutionError::UnsupportedTransfer(_)
+            | ExecutionError::UnsupportedWithdraw(_)
+            | ExecutionError::NonDecimal { .. } => ErrorClass::Rejected,
+            ExecutionError::BuilderIncomplete(_) => ErrorClass::Unrecoverable,
+        }
+    }
+}
+
+/// Errors generated when validating an [`OrderEvent`](crate::portfolio::OrderEvent) before it is
+/// sent for execution.
+#[derive(Error, Clone, PartialEq, Debug)]
+pub enum OrderError {
+    #[error("OrderEvent for {instrument} violates InstrumentSpec: {violation}")]
+    SpecViolation {
+        instrument: Instrument,
+        violation: SpecViolation,
+    },
+
+    #[error("InstrumentSpec {field} cannot be represented as a Decimal: {value}")]
+    NonDecimal { field: &'static str, value: f64 },
+
+    #[error("OrderEvent {field} cannot be represented as an f64: {value}")]
+    NonFloat { field: &'static str, value: Decimal },
+}
+
+/// [`InstrumentSpec`](barter_instrument::instrument::spec::InstrumentSpec) rule violated by an
+/// [`OrderEvent`](crate::portfolio::OrderEvent), after it's price & quantity have been rounded.
+#[derive(Error, Copy, Clone, PartialEq, Debug)]
+pub enum SpecViolation {
+    #[error("price {price} is below the minimum price {min}")]
+    PriceBelowMin { price: Decimal, min: Decimal },
+
+    #[error("quantity {quantity} is below the minimum quantity {min}")]
+    QuantityBelowMin { quantity: Decimal, min: Decimal },
+
+    #[error("quantity {quantity} is above the maximum quantity {max}")]
+    QuantityAboveMax { quantity: Decimal, max: Decimal },
+
+    #[error("notional {notional} is below the minimum notional {min}")]
+    NotionalBelowMin { notional: Decimal, min: Decimal },
+
+    #[error("notional {notional} is above the maximum notional {max}")]
+    NotionalAboveMax { notional: Decimal, max: Decimal },
 }
@@ -0,0 +1,77 @@
+use barter_execution::simulated::exchange::account::simulation::LatencyModel;
+use barter_instrument::exchange::ExchangeId;
+use chrono::TimeDelta;
+use rand::{rngs::StdRng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// [`LatencyModel`]s of the round trip between a trading system and an exchange.
+#[derive(Copy, Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct LatencyProfile {
+    /// Delay between a `MarketEvent` occurring on the exchange and it reaching the Strategy.
+    pub market_data: LatencyModel,
+    /// Delay between an `OrderEvent` being generated and it reaching the exchange.
+    pub order_submit: LatencyModel,
+    /// Delay between a cancel being issued and it reaching the exchange.
+    pub order_cancel: LatencyModel,
+}
+
+/// Configuration for constructing a [`LatencySimulator`].
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct LatencyConfig {
+    /// Seed of the RNG used to sample every [`LatencyModel`], so runs are reproducible.
+    pub seed: u64,
+    /// [`LatencyProfile`] used for any [`ExchangeId`] without a specific profile.
+    pub default: LatencyProfile,
+    /// [`ExchangeId`] specific [`LatencyProfile`]s.
+    #[serde(default)]
+    pub exchanges: HashMap<ExchangeId, LatencyProfile>,
+}
+
+/// Samples the market data, order submit & order cancel latency of each exchange from the
+/// configured [`LatencyProfile`]s.
+#[derive(Clone, PartialEq, Debug)]
+pub struct LatencySimulator {
+    config: LatencyConfig,
+    rng: StdRng,
+}
+
+impl LatencySimulator {
+    /// Constructs a new [`LatencySimulator`] using the provided [`LatencyConfig`].
+    pub fn new(config: LatencyConfig) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(config.seed),
+            config,
+        }
+    }
+
+    /// [`LatencyProfile`] of the provided [`ExchangeId`].
+    pub fn profile(&self, exchange: ExchangeId) -> &LatencyProfile {
+        self.config
+            .exchanges
+            .get(&exchange)
+            .unwrap_or(&self.config.default)
+    }
+
+    /// Sample the market data propagation delay of the provided [`ExchangeId`].
+    pub fn market_data(&mut self, exchange: ExchangeId) -> TimeDelta {
+        let model = self.profile(exchange).market_data;
+        self.sample(model)
+    }
+
+    /// Sample the order submit latency of the provided [`ExchangeId`].
+    pub fn order_submit(&mut self, exchange: ExchangeId) -> TimeDelta {
+        let model = self.profile(exchange).order_submit;
+        self.sample(model)
+    }
+
+    /// Sample the order cancel latency of the provided [`ExchangeId`].
+    pub fn order_cancel(&mut self, exchange: ExchangeId) -> TimeDelta {
+        let model = self.profile(exchange).order_cancel;
+        self.sample(model)
+    }
+
+    fn sample(&mut self, model: LatencyModel) -> TimeDelta {
+        TimeDelta::from_std(model.sample(&mut self.rng)).unwrap_or(TimeDelta::MAX)
+    }
+}
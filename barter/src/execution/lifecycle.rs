@@ -0,0 +1,330 @@
+use crate::{execution::FillEvent, portfolio::OrderEvent, strategy::Decision};
+use barter_execution::model::ClientOrderId;
+use barter_instrument::{exchange::ExchangeId, instrument::Instrument};
+use chrono::{DateTime, TimeDelta, Utc};
+use rust_decimal::Decimal;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Duration};
+use uuid::Uuid;
+
+/// Terminal status of an [`OrderLifecycle`].
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize, JsonSchema,
+)]
+pub enum OrderStatus {
+    Filled,
+    Cancelled,
+    /// The exchange never responded to the in-flight [`OrderEvent`] within the
+    /// [`OrderTimeout`].
+    TimedOut,
+    /// The [`ExecutionClient`](super::ExecutionClient) failed to submit the [`OrderEvent`].
+    Rejected,
+}
+
+/// Configuration of the [`Trader`](crate::engine::trader::Trader) sweeper that times out
+/// in-flight [`OrderEvent`]s the exchange never responded to.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OrderTimeout {
+    /// Maximum time an [`OrderEvent`] can be in-flight after it was generated.
+    pub timeout: Duration,
+    /// Request the [`ExecutionClient`](super::ExecutionClient) reconciles every timed out
+    /// [`OrderEvent`] against the exchange.
+    pub reconcile: bool,
+}
+
+impl OrderTimeout {
+    /// Construct a new [`OrderTimeout`] with the provided timeout, without reconciliation.
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            reconcile: false,
+        }
+    }
+
+    /// Request the [`ExecutionClient`](super::ExecutionClient) reconciles every timed out
+    /// [`OrderEvent`] against the exchange.
+    pub fn with_reconcile(self) -> Self {
+        Self {
+            reconcile: true,
+            ..self
+        }
+    }
+
+    /// Time before which in-flight [`OrderEvent`]s must have been generated to be timed out at
+    /// the provided time, or `None` if no [`OrderEvent`] can have timed out yet.
+    pub fn generated_before(&self, time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        time.checked_sub_signed(TimeDelta::from_std(self.timeout).ok()?)
+    }
+}
+
+/// Timestamps of each stage of an [`OrderEvent`] lifecycle.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize, JsonSchema,
+)]
+pub struct OrderTimestamps {
+    /// Time the [`OrderEvent`] was generated.
+    pub created: DateTime<Utc>,
+    /// Time the [`OrderEvent`] was submitted to the
+    /// [`ExecutionClient`](super::ExecutionClient), or `None` if it was in-flight before a
+    /// restart.
+    pub sent: Option<DateTime<Utc>>,
+    /// Time the [`ExecutionClient`](super::ExecutionClient) accepted the submitted
+    /// [`OrderEvent`].
+    pub acknowledged: Option<DateTime<Utc>>,
+    /// Time of the first [`FillEvent`].
+    pub first_fill: Option<DateTime<Utc>>,
+    /// Time the [`OrderEvent`] reached it's terminal [`OrderStatus`].
+    pub terminal: Option<DateTime<Utc>>,
+}
+
+impl OrderTimestamps {
+    /// Latency between the [`OrderEvent`] being sent and acknowledged.
+    pub fn ack_latency(&self) -> Option<TimeDelta> {
+        Some(self.acknowledged? - self.sent?)
+    }
+
+    /// Latency between the [`OrderEvent`] being sent and it's first [`FillEvent`].
+    pub fn fill_latency(&self) -> Option<TimeDelta> {
+        Some(self.first_fill? - self.sent?)
+    }
+}
+
+/// Lifecycle of a single [`OrderEvent`], sent to the audit trail as an
+/// [`Event::OrderLifecycle`](crate::event::Event) once it reaches a terminal [`OrderStatus`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize, JsonSchema)]
+pub struct OrderLifecycle {
+    #[schemars(with = "Uuid")]
+    pub cid: ClientOrderId,
+    pub exchange: ExchangeId,
+    pub instrument: Instrument,
+    pub decision: Decision,
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
+    pub quantity: Decimal,
+    /// Terminal [`OrderStatus`], or `None` if the [`OrderEvent`] is still in-flight.
+    pub status: Option<OrderStatus>,
+    pub timestamps: OrderTimestamps,
+}
+
+impl OrderLifecycle {
+    fn new(order: &OrderEvent, sent: Option<DateTime<Utc>>) -> Self {
+        Self {
+            cid: order.cid,
+            exchange: order.exchange,
+            instrument: order.instrument.clone(),
+            decision: order.decision,
+            quantity: order.quantity,
+            status: None,
+            timestamps: OrderTimestamps {
+                created: order.time,
+                sent,
+                acknowledged: None,
+                first_fill: None,
+                terminal: None,
+            },
+        }
+    }
+
+    fn terminate(mut self, status: OrderStatus, time: DateTime<Utc>) -> Self {
+        self.status = Some(status);
+        self.timestamps.terminal = Some(time);
+        self
+    }
+}
+
+/// Tracks the [`OrderLifecycle`] of every in-flight [`OrderEvent`] of a single market.
+///
+/// In-flight [`OrderEvent`]s are keyed by their [`ClientOrderId`], so each [`FillEvent`] is
+/// matched to the [`OrderEvent`] it executed, regardless of the order fills arrive in.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct OrderLifecycleTracker {
+    in_flight: HashMap<ClientOrderId, OrderLifecycle>,
+}
+
+impl OrderLifecycleTracker {
+    /// Start tracking an [`OrderEvent`] submitted to the
+    /// [`ExecutionClient`](super::ExecutionClient) at the provided time.
+    pub fn sent(&mut self, order: &OrderEvent, time: DateTime<Utc>) {
+        self.in_flight
+            .insert(order.cid, OrderLifecycle::new(order, Some(time)));
+    }
+
+    /// Resume tracking an [`OrderEvent`] that was in-flight before a restart.
+    pub fn restored(&mut self, order: &OrderEvent) {
+        self.in_flight
+            .insert(order.cid, OrderLifecycle::new(order, None));
+    }
+
+    /// Record the in-flight [`OrderEvent`] as acknowledged at the provided time.
+    pub fn acknowledged(&mut self, cid: &ClientOrderId, time: DateTime<Utc>) {
+        if let Some(order) = self.in_flight.get_mut(cid) {
+            order.timestamps.acknowledged.get_or_insert(time);
+        }
+    }
+
+    /// Complete the [`OrderLifecycle`] of the in-flight [`OrderEvent`] the
+    /// [`ExecutionClient`](super::ExecutionClient) failed to submit.
+    pub fn rejected(&mut self, cid: &ClientOrderId, time: DateTime<Utc>) -> Option<OrderLifecycle> {
+        let order = self.in_flight.remove(cid)?;
+        Some(order.terminate(OrderStatus::Rejected, time))
+    }
+
+    /// Complete the [`OrderLifecycle`] of the in-flight [`OrderEvent`] executed by the
+    /// [`FillEvent`], or return `None` if it is not tracked.
+    ///
+    /// The provided fill time must be on the same clock as the other [`OrderTimestamps`] (ie/ the
+    /// exchange fill time corrected for the exchange clock skew).
+    pub fn filled(&mut self, fill: &FillEvent, time: DateTime<Utc>) -> Option<OrderLifecycle> {
+        let mut order = self.in_flight.remove(&fill.cid)?;
+        order.timestamps.first_fill.get_or_insert(time);
+        Some(order.terminate(OrderStatus::Filled, time))
+    }
+
+    /// Complete the [`OrderLifecycle`] of each provided in-flight [`OrderEvent`], which were
+    /// cancelled before reaching the exchange.
+    pub fn cancelled(
+        &mut self,
+        cids: &[ClientOrderId],
+        time: DateTime<Utc>,
+    ) -> Vec<OrderLifecycle> {
+        cids.iter()
+            .filter_map(|cid| self.in_flight.remove(cid))
+            .map(|order| order.terminate(OrderStatus::Cancelled, time))
+            .collect()
+    }
+
+    /// Complete the [`OrderLifecycle`] of every in-flight [`OrderEvent`] generated before the
+    /// provided time, oldest first, since the exchange never responded to them.
+    pub fn timed_out(
+        &mut self,
+        generated_before: DateTime<Utc>,
+        time: DateTime<Utc>,
+    ) -> Vec<OrderLifecycle> {
+        let (mut timed_out, in_flight) = std::mem::take(&mut self.in_flight)
+            .into_iter()
+            .partition::<Vec<_>, _>(|(_, order)| order.timestamps.created < generated_before);
+        self.in_flight = in_flight.into_iter().collect();
+
+        timed_out.sort_by_key(|(_, order)| order.timestamps.created);
+        timed_out
+            .into_iter()
+            .map(|(_, order)| order.terminate(OrderStatus::TimedOut, time))
+            .collect()
+    }
+
+    /// Every in-flight [`OrderLifecycle`], in no particular order.
+    pub fn in_flight(&self) -> impl Iterator<Item = &OrderLifecycle> {
+        self.in_flight.values()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{fill_event, order_event};
+
+    #[test]
+    fn test_order_lifecycle_tracker() {
+        let start = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+        let ms = TimeDelta::milliseconds;
+
+        let order = |sequence| OrderEvent {
+            time: start,
+            cid: ClientOrderId(Uuid::from_u128(sequence)),
+            ..order_event()
+        };
+        let fill = |sequence, time| FillEvent {
+            time,
+            cid: ClientOrderId(Uuid::from_u128(sequence)),
+            ..fill_event()
+        };
+        let (first, second, restored) = (order(1), order(2), order(3));
+
+        let mut tracker = OrderLifecycleTracker::default();
+
+        // Two OrderEvents sent & acknowledged, one OrderEvent restored
+        tracker.sent(&first, start + ms(1));
+        tracker.acknowledged(&first.cid, start + ms(11));
+        tracker.sent(&second, start + ms(2));
+        tracker.acknowledged(&second.cid, start + ms(32));
+        tracker.restored(&restored);
+        assert_eq!(tracker.in_flight().count(), 3);
+
+        // Most recent OrderEvent is filled before the oldest, by an exchange with a clock 2s ahead
+        let filled = tracker
+            .filled(&fill(2, start + ms(2051)), start + ms(51))
+            .unwrap();
+        assert_eq!(filled.cid, second.cid);
+        assert_eq!(filled.status, Some(OrderStatus::Filled));
+        assert_eq!(filled.timestamps.ack_latency(), Some(ms(30)));
+        assert_eq!(filled.timestamps.fill_latency(), Some(ms(49)));
+        assert_eq!(filled.timestamps.terminal, Some(start + ms(51)));
+
+        // Restored OrderEvent is cancelled & has no sent time
+        let cancelled = tracker.cancelled(&[restored.cid], start + ms(60));
+        assert_eq!(cancelled.len(), 1);
+        assert_eq!(cancelled[0].cid, restored.cid);
+        assert_eq!(cancelled[0].status, Some(OrderStatus::Cancelled));
+        assert_eq!(cancelled[0].timestamps.sent, None);
+        assert_eq!(cancelled[0].timestamps.ack_latency(), None);
+
+        // Fill of an untracked OrderEvent is ignored
+        assert_eq!(
+            tracker.filled(&fill(4, start + ms(70)), start + ms(70)),
+            None
+        );
+        assert_eq!(tracker.in_flight().count(), 1);
+
+        // Remaining OrderEvent is filled
+        let filled = tracker
+            .filled(&fill(1, start + ms(100)), start + ms(100))
+            .unwrap();
+        assert_eq!(filled.cid, first.cid);
+        assert_eq!(filled.timestamps.ack_latency(), Some(ms(10)));
+        assert_eq!(filled.timestamps.fill_latency(), Some(ms(99)));
+
+        assert_eq!(
+            tracker.filled(&fill(1, start + ms(100)), start + ms(100)),
+            None
+        );
+        assert!(tracker.cancelled(&[first.cid], start).is_empty());
+
+        // OrderEvent the ExecutionClient failed to submit is rejected
+        tracker.sent(&first, start + ms(110));
+        let rejected = tracker.rejected(&first.cid, start + ms(111)).unwrap();
+        assert_eq!(rejected.status, Some(OrderStatus::Rejected));
+        assert_eq!(rejected.timestamps.terminal, Some(start + ms(111)));
+        assert_eq!(tracker.rejected(&first.cid, start), None);
+    }
+
+    #[test]
+    fn test_order_lifecycle_tracker_timed_out() {
+        let start = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+        let timeout = OrderTimeout::new(Duration::from_secs(10));
+        let mut tracker = OrderLifecycleTracker::default();
+
+        for created in [start, start + TimeDelta::seconds(5)] {
+            let order = OrderEvent {
+                time: created,
+                ..order_event()
+            };
+            tracker.sent(&order, created);
+        }
+
+        // No OrderEvent in-flight for longer than the timeout
+        let time = start + TimeDelta::seconds(10);
+        let generated_before = timeout.generated_before(time).unwrap();
+        assert!(tracker.timed_out(generated_before, time).is_empty());
+
+        // Oldest OrderEvent times out
+        let time = start + TimeDelta::seconds(12);
+        let generated_before = timeout.generated_before(time).unwrap();
+        let timed_out = tracker.timed_out(generated_before, time);
+        assert_eq!(timed_out.len(), 1);
+        assert_eq!(timed_out[0].status, Some(OrderStatus::TimedOut));
+        assert_eq!(timed_out[0].timestamps.created, start);
+        assert_eq!(timed_out[0].timestamps.terminal, Some(time));
+        assert_eq!(tracker.in_flight().count(), 1);
+    }
+}
@@ -1,8 +1,19 @@
-use crate::{data::MarketMeta, portfolio::OrderEvent, strategy::Decision};
+use crate::{
+    data::MarketMeta, execution::lifecycle::OrderLifecycle, portfolio::OrderEvent,
+    strategy::Decision,
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_execution::model::{
+    transfer::{Transfer, TransferRequest, WithdrawRequest, Withdrawal},
+    ClientOrderId,
+};
 use barter_instrument::{exchange::ExchangeId, instrument::Instrument};
 use chrono::{DateTime, Utc};
 use error::ExecutionError;
+use rust_decimal::Decimal;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 /// Barter execution module specific errors.
 pub mod error;
@@ -10,17 +21,123 @@ pub mod error;
 /// Handlers for simulated and live [`OrderEvent`] execution.
 pub mod simulated;
 
+/// Per exchange market data, order submit & order cancel
+/// [`LatencySimulator`](latency::LatencySimulator) used when simulating [`OrderEvent`] execution.
+pub mod latency;
+
+/// Configurable [`SlippageModel`](slippage::SlippageModel)s used when simulating
+/// [`OrderEvent`] execution.
+pub mod slippage;
+
+/// [`RoutedExecution`](mode::RoutedExecution) of `OrderEvent`s to live or dry-run execution,
+/// depending on the [`ExecutionMode`](mode::ExecutionMode) of each exchange.
+pub mod mode;
+
+/// [`OrderValidator`](validation::OrderValidator) that normalises [`OrderEvent`]s to exchange
+/// instrument specifications before execution.
+pub mod validation;
+
+/// [`OrderLifecycle`](lifecycle::OrderLifecycle) timestamps of each [`OrderEvent`], from
+/// creation to it's terminal status, used to measure order acknowledgement & fill latency.
+pub mod lifecycle;
+
 /// Generates a result [`FillEvent`] by executing an [`OrderEvent`].
 pub trait ExecutionClient {
     /// Return a [`FillEvent`] from executing the input [`OrderEvent`].
     fn generate_fill(&self, order: &OrderEvent) -> Result<FillEvent, ExecutionError>;
+
+    /// Update any internal market state (eg/ recorded order books) from the input [`MarketEvent`].
+    ///
+    /// Default implementation is a no-op.
+    fn update_from_market(&mut self, _market: &MarketEvent<Instrument, DataKind>) {}
+
+    /// Submit the input [`OrderEvent`] for execution, returning the [`FillEvent`] if it was
+    /// executed immediately. Clients modelling order submit latency may instead return `None`,
+    /// and yield the [`FillEvent`] from a later [`poll_fills`](Self::poll_fills).
+    ///
+    /// Default implementation executes immediately using [`generate_fill`](Self::generate_fill).
+    fn submit_order(&mut self, order: &OrderEvent) -> Result<Option<FillEvent>, ExecutionError> {
+        self.generate_fill(order).map(Some)
+    }
+
+    /// Return the [`FillEvent`]s of previously submitted [`OrderEvent`]s that have since been
+    /// executed.
+    ///
+    /// Default implementation returns no [`FillEvent`]s.
+    fn poll_fills(&mut self) -> Vec<FillEvent> {
+        Vec::new()
+    }
+
+    /// Cancel every submitted [`OrderEvent`] that has not yet been executed, returning the
+    /// [`ClientOrderId`] of each [`OrderEvent`] cancelled.
+    ///
+    /// Default implementation cancels nothing.
+    fn cancel_orders_pending(&mut self) -> Vec<ClientOrderId> {
+        Vec::new()
+    }
+
+    /// Return every submitted [`OrderEvent`] that has not yet been executed, so it can be
+    /// checkpointed.
+    ///
+    /// Default implementation returns no [`OrderEvent`]s.
+    fn orders_pending(&self) -> Vec<OrderEvent> {
+        Vec::new()
+    }
+
+    /// Resume tracking the [`OrderEvent`]s that were in-flight before a restart, yielding their
+    /// [`FillEvent`]s from a later [`poll_fills`](Self::poll_fills) once executed.
+    ///
+    /// Default implementation tracks nothing.
+    fn restore_orders_pending(&mut self, _orders: Vec<OrderEvent>) {}
+
+    /// Stop tracking every submitted [`OrderEvent`] generated before the provided time that has
+    /// not yet been executed, since the exchange never responded to it. Returns the number of
+    /// [`OrderEvent`]s expired.
+    ///
+    /// Default implementation expires nothing.
+    fn expire_orders_pending(&mut self, _generated_before: DateTime<Utc>) -> usize {
+        0
+    }
+
+    /// Query the exchange for the latest state of the provided [`OrderLifecycle`]s that timed
+    /// out in-flight, yielding the [`FillEvent`]s of any that were executed from a later
+    /// [`poll_fills`](Self::poll_fills).
+    ///
+    /// Default implementation queries nothing.
+    fn reconcile_orders(&mut self, _orders: &[OrderLifecycle]) {}
+
+    /// Transfer a quantity between two [`Wallet`](barter_execution::model::transfer::Wallet)s of
+    /// the provided exchange account (eg/ moving margin from the spot to the futures wallet).
+    ///
+    /// Default implementation does not support transfers.
+    fn transfer(
+        &mut self,
+        exchange: ExchangeId,
+        _request: TransferRequest,
+    ) -> Result<Transfer, ExecutionError> {
+        Err(ExecutionError::UnsupportedTransfer(exchange))
+    }
+
+    /// Withdraw a quantity from the provided exchange account to an external address.
+    ///
+    /// Default implementation does not support withdrawals.
+    fn withdraw(
+        &mut self,
+        exchange: ExchangeId,
+        _request: WithdrawRequest,
+    ) -> Result<Withdrawal, ExecutionError> {
+        Err(ExecutionError::UnsupportedWithdraw(exchange))
+    }
 }
 
 /// Fills are journals of work done by an Execution handler. These are sent back to the portfolio,
 /// so it can apply updates.
-#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize, JsonSchema)]
 pub struct FillEvent {
     pub time: DateTime<Utc>,
+    /// [`ClientOrderId`] of the executed [`OrderEvent`].
+    #[schemars(with = "Uuid")]
+    pub cid: ClientOrderId,
     pub exchange: ExchangeId,
     pub instrument: Instrument,
     /// Metadata propagated from source MarketEvent
@@ -28,9 +145,13 @@ pub struct FillEvent {
     /// LONG, CloseLong, SHORT or CloseShort
     pub decision: Decision,
     /// +ve or -ve Quantity depending on Decision
-    pub quantity: f64,
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
+    pub quantity: Decimal,
     /// abs(Quantity) * ClosePrice, excluding TotalFees
-    pub fill_value_gross: f64,
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
+    pub fill_value_gross: Decimal,
     /// All fee types incurred when executing an [`OrderEvent`], and their associated [`FeeAmount`].
     pub fees: Fees,
 }
@@ -45,36 +166,59 @@ impl FillEvent {
 }
 
 /// All potential fees incurred by a [`FillEvent`].
-#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+#[derive(
+    Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize, JsonSchema,
+)]
 pub struct Fees {
     /// Fee taken by the exchange/broker (eg/ commission).
+    ///
+    /// Signed: a negative amount is a rebate paid by the exchange (eg/ maker rebate).
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
     pub exchange: FeeAmount,
     /// OrderBook slippage modelled as a fee.
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
     pub slippage: FeeAmount,
     /// Fee incurred by any required network transactions (eg/ GAS).
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
     pub network: FeeAmount,
 }
 
 impl Fees {
-    /// Calculates the sum of every [FeeAmount] in [Fees].
-    pub fn calculate_total_fees(&self) -> f64 {
+    /// Calculates the sum of every [FeeAmount] in [Fees], net of any exchange rebate.
+    pub fn calculate_total_fees(&self) -> FeeAmount {
         self.exchange + self.network + self.slippage
     }
+
+    /// Calculates the sum of every [FeeAmount] paid in [Fees], excluding any exchange rebate.
+    pub fn calculate_fees_paid(&self) -> FeeAmount {
+        self.exchange.max(Decimal::ZERO) + self.network + self.slippage
+    }
+
+    /// Returns the rebate paid by the exchange as a positive [FeeAmount], or zero if the
+    /// exchange fee was not a rebate.
+    pub fn rebate(&self) -> FeeAmount {
+        (-self.exchange).max(Decimal::ZERO)
+    }
 }
 
-/// Communicative type alias for Fee amount as f64.
-pub type FeeAmount = f64;
+/// Communicative type alias for Fee amount as a [`Decimal`], so fees sum exactly to those of
+/// exchange statements.
+pub type FeeAmount = Decimal;
 
 /// Builder to construct [FillEvent] instances.
 #[derive(Debug, Default)]
 pub struct FillEventBuilder {
     pub time: Option<DateTime<Utc>>,
+    pub cid: Option<ClientOrderId>,
     pub exchange: Option<ExchangeId>,
     pub instrument: Option<Instrument>,
     pub market_meta: Option<MarketMeta>,
     pub decision: Option<Decision>,
-    pub quantity: Option<f64>,
-    pub fill_value_gross: Option<f64>,
+    pub quantity: Option<Decimal>,
+    pub fill_value_gross: Option<Decimal>,
     pub fees: Option<Fees>,
 }
 
@@ -90,6 +234,13 @@ impl FillEventBuilder {
         }
     }
 
+    pub fn cid(self, value: ClientOrderId) -> Self {
+        Self {
+            cid: Some(value),
+            ..self
+        }
+    }
+
     pub fn exchange(self, value: ExchangeId) -> Self {
         Self {
             exchange: Some(value),
@@ -118,14 +269,14 @@ impl FillEventBuilder {
         }
     }
 
-    pub fn quantity(self, value: f64) -> Self {
+    pub fn quantity(self, value: Decimal) -> Self {
         Self {
             quantity: Some(value),
             ..self
         }
     }
 
-    pub fn fill_value_gross(self, value: f64) -> Self {
+    pub fn fill_value_gross(self, value: Decimal) -> Self {
         Self {
             fill_value_gross: Some(value),
             ..self
@@ -142,6 +293,7 @@ impl FillEventBuilder {
     pub fn build(self) -> Result<FillEvent, ExecutionError> {
         Ok(FillEvent {
             time: self.time.ok_or(ExecutionError::BuilderIncomplete("time"))?,
+            cid: self.cid.ok_or(ExecutionError::BuilderIncomplete("cid"))?,
             exchange: self
                 .exchange
                 .ok_or(ExecutionError::BuilderIncomplete("exchange"))?,
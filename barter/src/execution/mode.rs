@@ -0,0 +1,243 @@
+use crate::{
+    execution::{
+        error::ExecutionError, lifecycle::OrderLifecycle, simulated::SimulatedExecution,
+        ExecutionClient, FillEvent,
+    },
+    portfolio::OrderEvent,
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_execution::model::{
+    transfer::{Transfer, TransferRequest, WithdrawRequest, Withdrawal},
+    ClientOrderId,
+};
+use barter_instrument::{exchange::ExchangeId, instrument::Instrument};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Execution mode of an exchange.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+pub enum ExecutionMode {
+    /// `OrderEvent`s are executed by the live [`ExecutionClient`].
+    Live,
+    /// `OrderEvent`s are executed by a [`SimulatedExecution`] fed by the live market data
+    /// (ie/ paper trading).
+    #[default]
+    DryRun,
+}
+
+/// Configuration of the [`ExecutionMode`] of each exchange.
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct ExecutionModeConfig {
+    /// [`ExecutionMode`] used for any [`ExchangeId`] without a specific mode.
+    pub default: ExecutionMode,
+    /// [`ExchangeId`] specific [`ExecutionMode`]s.
+    #[serde(default)]
+    pub exchanges: HashMap<ExchangeId, ExecutionMode>,
+}
+
+impl ExecutionModeConfig {
+    /// [`ExecutionMode`] of the provided [`ExchangeId`].
+    pub fn mode(&self, exchange: ExchangeId) -> ExecutionMode {
+        self.exchanges
+            .get(&exchange)
+            .copied()
+            .unwrap_or(self.default)
+    }
+}
+
+/// [`ExecutionClient`] that routes each `OrderEvent` to the live [`ExecutionClient`] or the
+/// dry-run [`SimulatedExecution`], depending on the [`ExecutionMode`] of it's exchange.
+///
+/// Enables staged rollouts within one [`Engine`](crate::engine::Engine) (eg/ live on Binance,
+/// paper trading on OKX). Every `MarketEvent` updates both clients, so dry-run `OrderEvent`s are
+/// filled using the live market data.
+#[derive(Debug)]
+pub struct RoutedExecution<Live> {
+    config: ExecutionModeConfig,
+    live: Live,
+    dry_run: SimulatedExecution,
+}
+
+impl<Live> ExecutionClient for RoutedExecution<Live>
+where
+    Live: ExecutionClient,
+{
+    fn generate_fill(&self, order: &OrderEvent) -> Result<FillEvent, ExecutionError> {
+        match self.config.mode(order.exchange) {
+            ExecutionMode::Live => self.live.generate_fill(order),
+            ExecutionMode::DryRun => self.dry_run.generate_fill(order),
+        }
+    }
+
+    fn update_from_market(&mut self, market: &MarketEvent<Instrument, DataKind>) {
+        self.live.update_from_market(market);
+        self.dry_run.update_from_market(market);
+    }
+
+    fn submit_order(&mut self, order: &OrderEvent) -> Result<Option<FillEvent>, ExecutionError> {
+        match self.config.mode(order.exchange) {
+            ExecutionMode::Live => self.live.submit_order(order),
+            ExecutionMode::DryRun => self.dry_run.submit_order(order),
+        }
+    }
+
+    fn poll_fills(&mut self) -> Vec<FillEvent> {
+        let mut fills = self.live.poll_fills();
+        fills.extend(self.dry_run.poll_fills());
+        fills
+    }
+
+    fn cancel_orders_pending(&mut self) -> Vec<ClientOrderId> {
+        let mut cancelled = self.live.cancel_orders_pending();
+        cancelled.extend(self.dry_run.cancel_orders_pending());
+        cancelled
+    }
+
+    fn orders_pending(&self) -> Vec<OrderEvent> {
+        let mut orders = self.live.orders_pending();
+        orders.extend(self.dry_run.orders_pending());
+        orders
+    }
+
+    fn restore_orders_pending(&mut self, orders: Vec<OrderEvent>) {
+        let (live, dry_run) = orders
+            .into_iter()
+            .partition(|order| self.config.mode(order.exchange) == ExecutionMode::Live);
+
+        self.live.restore_orders_pending(live);
+        self.dry_run.restore_orders_pending(dry_run);
+    }
+
+    fn expire_orders_pending(&mut self, generated_before: DateTime<Utc>) -> usize {
+        self.live.expire_orders_pending(generated_before)
+            + self.dry_run.expire_orders_pending(generated_before)
+    }
+
+    fn reconcile_orders(&mut self, orders: &[OrderLifecycle]) {
+        let (live, dry_run): (Vec<_>, Vec<_>) = orders
+            .iter()
+            .cloned()
+            .partition(|order| self.config.mode(order.exchange) == ExecutionMode::Live);
+
+        self.live.reconcile_orders(&live);
+        self.dry_run.reconcile_orders(&dry_run);
+    }
+
+    fn transfer(
+        &mut self,
+        exchange: ExchangeId,
+        request: TransferRequest,
+    ) -> Result<Transfer, ExecutionError> {
+        match self.config.mode(exchange) {
+            ExecutionMode::Live => self.live.transfer(exchange, request),
+            ExecutionMode::DryRun => self.dry_run.transfer(exchange, request),
+        }
+    }
+
+    fn withdraw(
+        &mut self,
+        exchange: ExchangeId,
+        request: WithdrawRequest,
+    ) -> Result<Withdrawal, ExecutionError> {
+        match self.config.mode(exchange) {
+            ExecutionMode::Live => self.live.withdraw(exchange, request),
+            ExecutionMode::DryRun => self.dry_run.withdraw(exchange, request),
+        }
+    }
+}
+
+impl<Live> RoutedExecution<Live> {
+    /// Constructs a new [`RoutedExecution`] using the provided [`ExecutionModeConfig`], live
+    /// [`ExecutionClient`] & dry-run [`SimulatedExecution`].
+    pub fn new(config: ExecutionModeConfig, live: Live, dry_run: SimulatedExecution) -> Self {
+        Self {
+            config,
+            live,
+            dry_run,
+        }
+    }
+
+    /// [`ExecutionMode`] of the provided [`ExchangeId`].
+    pub fn mode(&self, exchange: ExchangeId) -> ExecutionMode {
+        self.config.mode(exchange)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        execution::{simulated::Config, Fees},
+        test_util::order_event,
+    };
+
+    #[derive(Debug, Default)]
+    struct MockLive {
+        submitted: Vec<OrderEvent>,
+    }
+
+    impl ExecutionClient for MockLive {
+        fn generate_fill(&self, _: &OrderEvent) -> Result<FillEvent, ExecutionError> {
+            unimplemented!()
+        }
+
+        fn submit_order(
+            &mut self,
+            order: &OrderEvent,
+        ) -> Result<Option<FillEvent>, ExecutionError> {
+            self.submitted.push(order.clone());
+            Ok(None)
+        }
+    }
+
+    #[test]
+    fn test_routed_execution_submit_order() {
+        struct TestCase {
+            exchange: ExchangeId,
+            expected_live: bool,
+        }
+
+        let mut execution = RoutedExecution::new(
+            ExecutionModeConfig {
+                default: ExecutionMode::DryRun,
+                exchanges: HashMap::from([(ExchangeId::BinanceSpot, ExecutionMode::Live)]),
+            },
+            MockLive::default(),
+            SimulatedExecution::new(Config {
+                simulated_fees_pct: Fees::default(),
+            }),
+        );
+
+        let tests = vec![
+            TestCase {
+                // TC0: live exchange
+                exchange: ExchangeId::BinanceSpot,
+                expected_live: true,
+            },
+            TestCase {
+                // TC1: default dry-run exchange
+                exchange: ExchangeId::Okx,
+                expected_live: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let order = OrderEvent {
+                exchange: test.exchange,
+                ..order_event()
+            };
+            let submitted = execution.live.submitted.len();
+
+            let fill = execution.submit_order(&order).unwrap();
+
+            assert_eq!(
+                execution.live.submitted.len() > submitted,
+                test.expected_live,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(fill.is_none(), test.expected_live, "TC{} failed", index);
+        }
+    }
+}
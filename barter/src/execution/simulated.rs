@@ -1,10 +1,26 @@
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use rust_decimal::{prelude::FromPrimitive, Decimal};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::warn;
 
 use crate::{
-    execution::{error::ExecutionError, ExecutionClient, Fees, FillEvent},
+    clock::{Clock, SharedClock},
+    data::MarketMeta,
+    execution::{
+        error::ExecutionError,
+        latency::{LatencyConfig, LatencySimulator},
+        slippage::SlippageModel,
+        ExecutionClient, Fees, FillEvent,
+    },
     portfolio::OrderEvent,
 };
+use barter_data::{
+    books::OrderBook,
+    event::{DataKind, MarketEvent},
+};
+use barter_execution::model::ClientOrderId;
+use barter_instrument::instrument::Instrument;
 
 /// Configuration for constructing a [`SimulatedExecution`] via the new() constructor method.
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
@@ -13,29 +29,153 @@ pub struct Config {
     pub simulated_fees_pct: Fees,
 }
 
-#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
 /// Simulated execution handler that executes [`OrderEvent`]s to generate [`FillEvent`]s via a
 /// simulated broker interaction.
 pub struct SimulatedExecution {
     fees_pct: Fees,
+    /// Default [`SlippageModel`] used for any [`Instrument`] without a specific model.
+    slippage: SlippageModel,
+    /// [`Instrument`] specific [`SlippageModel`]s.
+    slippage_instruments: HashMap<Instrument, SlippageModel>,
+    /// Latest recorded [`OrderBook`] for each [`Instrument`], used by book dependent
+    /// [`SlippageModel`]s.
+    books: HashMap<Instrument, OrderBook>,
+    /// [`Clock`] used to timestamp generated [`FillEvent`]s.
+    #[serde(skip)]
+    clock: SharedClock,
+    /// Optional [`LatencySimulator`] used to delay each submitted [`OrderEvent`] until it
+    /// reaches the exchange.
+    #[serde(skip)]
+    latency: Option<LatencySimulator>,
+    /// Submitted [`OrderEvent`]s in-flight to the exchange, and their exchange arrival time.
+    #[serde(skip)]
+    pending: Vec<(DateTime<Utc>, OrderEvent)>,
+    /// [`FillEvent`]s of executed in-flight [`OrderEvent`]s, yet to be polled.
+    #[serde(skip)]
+    fills: Vec<FillEvent>,
+    /// Exchange time of the latest [`MarketEvent`].
+    #[serde(skip)]
+    time_exchange: Option<DateTime<Utc>>,
 }
 
 impl ExecutionClient for SimulatedExecution {
     fn generate_fill(&self, order: &OrderEvent) -> Result<FillEvent, ExecutionError> {
+        let quantity = order.quantity;
+        let price = order.market_meta.close;
+
         // Assume (for now) that all orders are filled at the market price
-        let fill_value_gross = SimulatedExecution::calculate_fill_value_gross(order);
+        let fill_value_gross = SimulatedExecution::calculate_fill_value_gross(quantity, price);
+
+        // Add any modelled slippage to the simulated percentage fees
+        let mut fees = self.calculate_fees(&fill_value_gross);
+        fees.slippage += self.calculate_slippage(&order.instrument, quantity, fill_value_gross);
 
         Ok(FillEvent {
-            time: Utc::now(),
+            time: self.clock.time(),
+            cid: order.cid,
             exchange: order.exchange,
             instrument: order.instrument.clone(),
             market_meta: order.market_meta,
             decision: order.decision,
-            quantity: order.quantity,
+            quantity,
             fill_value_gross,
-            fees: self.calculate_fees(&fill_value_gross),
+            fees,
         })
     }
+
+    fn update_from_market(&mut self, market: &MarketEvent<Instrument, DataKind>) {
+        match &market.kind {
+            DataKind::OrderBookL1(book) => {
+                self.books.insert(
+                    market.instrument.clone(),
+                    OrderBook::new(0, None, [book.best_bid], [book.best_ask]),
+                );
+            }
+            DataKind::OrderBook(event) => {
+                self.books
+                    .entry(market.instrument.clone())
+                    .or_default()
+                    .update(event.clone());
+            }
+            _ => {}
+        }
+
+        if self.latency.is_some() {
+            self.time_exchange = self.time_exchange.max(Some(market.time_exchange));
+        }
+
+        if !self.pending.is_empty() {
+            self.execute_orders_arrived(market);
+        }
+    }
+
+    fn submit_order(&mut self, order: &OrderEvent) -> Result<Option<FillEvent>, ExecutionError> {
+        let Some(latency) = &mut self.latency else {
+            return self.generate_fill(order).map(Some);
+        };
+
+        // OrderEvent is generated after the MarketEvent propagates to the Strategy, and then
+        // reaches the exchange after the order submit latency
+        let arrival = order.market_meta.time
+            + latency.market_data(order.exchange)
+            + latency.order_submit(order.exchange);
+
+        self.pending.push((arrival, order.clone()));
+        Ok(None)
+    }
+
+    fn poll_fills(&mut self) -> Vec<FillEvent> {
+        std::mem::take(&mut self.fills)
+    }
+
+    fn cancel_orders_pending(&mut self) -> Vec<ClientOrderId> {
+        let Some(latency) = &mut self.latency else {
+            return Vec::new();
+        };
+
+        let (pending, cancelled) = match self.time_exchange {
+            // Cancel is issued once the latest MarketEvent propagates to the Trader, and only
+            // cancels OrderEvents that have not reached the exchange before it
+            Some(time_exchange) => {
+                std::mem::take(&mut self.pending)
+                    .into_iter()
+                    .partition(|(arrival, order)| {
+                        let cancel_arrival = time_exchange
+                            + latency.market_data(order.exchange)
+                            + latency.order_cancel(order.exchange);
+                        *arrival <= cancel_arrival
+                    })
+            }
+            None => (Vec::new(), std::mem::take(&mut self.pending)),
+        };
+        self.pending = pending;
+
+        cancelled.into_iter().map(|(_, order)| order.cid).collect()
+    }
+
+    fn orders_pending(&self) -> Vec<OrderEvent> {
+        self.pending
+            .iter()
+            .map(|(_, order)| order.clone())
+            .collect()
+    }
+
+    fn restore_orders_pending(&mut self, orders: Vec<OrderEvent>) {
+        // OrderEvents submitted before the restart have already reached the exchange
+        self.pending.extend(
+            orders
+                .into_iter()
+                .map(|order| (order.market_meta.time, order)),
+        );
+    }
+
+    fn expire_orders_pending(&mut self, generated_before: DateTime<Utc>) -> usize {
+        let queued = self.pending.len();
+        self.pending
+            .retain(|(_, order)| order.time >= generated_before);
+        queued - self.pending.len()
+    }
 }
 
 impl SimulatedExecution {
@@ -43,50 +183,169 @@ impl SimulatedExecution {
     pub fn new(cfg: Config) -> Self {
         Self {
             fees_pct: cfg.simulated_fees_pct,
+            ..Self::default()
+        }
+    }
+
+    /// Use the provided [`Clock`] to timestamp generated [`FillEvent`]s. Defaults to a
+    /// [`LiveClock`](crate::clock::LiveClock).
+    pub fn with_clock<C>(self, clock: C) -> Self
+    where
+        C: Clock + 'static,
+    {
+        Self {
+            clock: SharedClock::new(clock),
+            ..self
+        }
+    }
+
+    /// Use a [`LatencySimulator`] constructed from the provided [`LatencyConfig`] to delay each
+    /// submitted [`OrderEvent`] until it reaches the exchange, filling it at the first market
+    /// price at or after it's arrival.
+    pub fn with_latency(self, config: LatencyConfig) -> Self {
+        Self {
+            latency: Some(LatencySimulator::new(config)),
+            ..self
+        }
+    }
+
+    /// Use the provided [`SlippageModel`] for every [`Instrument`] without a specific model.
+    pub fn with_slippage(self, model: SlippageModel) -> Self {
+        Self {
+            slippage: model,
+            ..self
+        }
+    }
+
+    /// Use the provided [`SlippageModel`] for the specified [`Instrument`].
+    pub fn with_instrument_slippage(
+        mut self,
+        instrument: Instrument,
+        model: SlippageModel,
+    ) -> Self {
+        self.slippage_instruments.insert(instrument, model);
+        self
+    }
+
+    /// Execute every in-flight [`OrderEvent`] for the [`MarketEvent`] [`Instrument`] that has
+    /// reached the exchange, filling it at the [`MarketEvent`] price.
+    fn execute_orders_arrived(&mut self, market: &MarketEvent<Instrument, DataKind>) {
+        let price = match self.market_price(market) {
+            Ok(Some(price)) => price,
+            Ok(None) => return,
+            Err(error) => {
+                warn!(?error, instrument = %market.instrument, "failed to determine in-flight OrderEvent fill price");
+                return;
+            }
+        };
+
+        let (arrived, pending) = std::mem::take(&mut self.pending)
+            .into_iter()
+            .partition::<Vec<_>, _>(|(arrival, order)| {
+                order.exchange == market.exchange
+                    && order.instrument == market.instrument
+                    && *arrival <= market.time_exchange
+            });
+        self.pending = pending;
+
+        for (_, mut order) in arrived {
+            order.market_meta = MarketMeta {
+                close: price,
+                time: market.time_exchange,
+            };
+
+            match self.generate_fill(&order) {
+                Ok(mut fill) => {
+                    fill.time = market.time_exchange;
+                    self.fills.push(fill);
+                }
+                Err(error) => warn!(?error, ?order, "failed to fill in-flight OrderEvent"),
+            }
+        }
+    }
+
+    /// Determine the price an in-flight [`OrderEvent`] is filled at from the [`MarketEvent`].
+    ///
+    /// Returns `None` if the [`MarketEvent`] has no price, and an error if it's price is not
+    /// finite.
+    fn market_price(
+        &self,
+        market: &MarketEvent<Instrument, DataKind>,
+    ) -> Result<Option<Decimal>, ExecutionError> {
+        match &market.kind {
+            DataKind::Trade(trade) => to_decimal("trade.price", trade.price).map(Some),
+            DataKind::Candle(candle) => to_decimal("candle.close", candle.close).map(Some),
+            DataKind::OrderBookL1(book) => Ok(Some(book.mid_price())),
+            DataKind::OrderBook(_) => Ok(self
+                .books
+                .get(&market.instrument)
+                .and_then(OrderBook::mid_price)),
+            DataKind::Liquidation(_) | DataKind::Custom(_) => Ok(None),
         }
     }
 
-    /// Calculates the simulated gross fill value (excluding TotalFees) based on the input [`OrderEvent`].
-    fn calculate_fill_value_gross(order: &OrderEvent) -> f64 {
-        order.quantity.abs() * order.market_meta.close
+    /// Calculates the simulated gross fill value (excluding TotalFees) of filling the quantity at
+    /// the provided price.
+    fn calculate_fill_value_gross(quantity: Decimal, price: Decimal) -> Decimal {
+        quantity.abs() * price
     }
 
     /// Calculates the simulated [`Fees`] a [`FillEvent`] will incur, based on the input [`OrderEvent`].
-    fn calculate_fees(&self, fill_value_gross: &f64) -> Fees {
+    fn calculate_fees(&self, fill_value_gross: &Decimal) -> Fees {
         Fees {
             exchange: self.fees_pct.exchange * fill_value_gross,
             slippage: self.fees_pct.slippage * fill_value_gross,
             network: self.fees_pct.network * fill_value_gross,
         }
     }
+
+    /// Calculates the slippage of filling the quantity of an [`Instrument`] using the
+    /// [`SlippageModel`] selected for it.
+    fn calculate_slippage(
+        &self,
+        instrument: &Instrument,
+        quantity: Decimal,
+        fill_value_gross: Decimal,
+    ) -> Decimal {
+        self.slippage_instruments
+            .get(instrument)
+            .unwrap_or(&self.slippage)
+            .calculate(quantity, fill_value_gross, self.books.get(instrument))
+    }
+}
+
+/// Convert a [`MarketEvent`] `f64` price to a [`Decimal`], failing if it is not finite.
+fn to_decimal(field: &'static str, value: f64) -> Result<Decimal, ExecutionError> {
+    Decimal::from_f64(value).ok_or(ExecutionError::NonDecimal { field, value })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::test_util::order_event;
+    use rust_decimal_macros::dec;
 
     #[test]
     fn should_generate_ok_fill_event_with_valid_order_event_provided() {
         let simulated_execution = SimulatedExecution::new(Config {
             simulated_fees_pct: Fees {
-                exchange: 0.1,
-                slippage: 0.05,
-                network: 0.0,
+                exchange: dec!(0.1),
+                slippage: dec!(0.05),
+                network: dec!(0),
             },
         });
 
         let mut input_order = order_event();
-        input_order.quantity = 10.0;
-        input_order.market_meta.close = 10.0;
+        input_order.quantity = dec!(10);
+        input_order.market_meta.close = dec!(10);
 
         let actual_result = simulated_execution.generate_fill(&input_order);
 
-        let expected_fill_value_gross = 100.0;
+        let expected_fill_value_gross = dec!(100);
         let expected_fees = Fees {
-            exchange: 10.0,
-            slippage: 5.0,
-            network: 0.0,
+            exchange: dec!(10),
+            slippage: dec!(5),
+            network: dec!(0),
         };
 
         assert!(actual_result.is_ok());
@@ -97,50 +356,271 @@ mod tests {
 
     #[test]
     fn should_calculate_fill_value_gross_correctly() {
-        let mut input_order = order_event();
-        input_order.quantity = 100.0;
-        input_order.market_meta.close = 10.0;
-
-        let actual = SimulatedExecution::calculate_fill_value_gross(&input_order);
+        let actual = SimulatedExecution::calculate_fill_value_gross(dec!(100), dec!(10));
 
-        let expected = 100.0 * 10.0;
+        let expected = dec!(100) * dec!(10);
 
         assert_eq!(actual, expected)
     }
 
     #[test]
     fn should_calculate_fill_value_gross_correctly_with_negative_order_quantity_provided() {
+        let actual = SimulatedExecution::calculate_fill_value_gross(-dec!(100), dec!(10));
+
+        let expected = dec!(100) * dec!(10);
+
+        assert_eq!(actual, expected)
+    }
+
+    #[test]
+    fn should_generate_fill_with_exact_decimal_fill_value_gross() {
+        let simulated_execution = SimulatedExecution::new(Config::default());
+
         let mut input_order = order_event();
-        input_order.quantity = -(100.0);
-        input_order.market_meta.close = 10.0;
+        input_order.quantity = dec!(0.1);
+        input_order.market_meta.close = dec!(0.3);
+
+        let actual = simulated_execution.generate_fill(&input_order).unwrap();
 
-        let actual = SimulatedExecution::calculate_fill_value_gross(&input_order);
+        // f64 would yield 0.030000000000000002
+        assert_eq!(actual.quantity, dec!(0.1));
+        assert_eq!(actual.fill_value_gross, dec!(0.03));
+    }
+
+    #[test]
+    fn should_fail_to_determine_market_price_of_non_finite_trade_price() {
+        use crate::test_util::market_event_trade;
+        use barter_integration::Side;
 
-        let expected = (100.0 * 10.0) as f64;
+        let simulated_execution = SimulatedExecution::new(Config::default());
 
-        assert_eq!(actual, expected)
+        let mut market = market_event_trade(Side::Buy);
+        if let DataKind::Trade(trade) = &mut market.kind {
+            trade.price = f64::NAN;
+        }
+
+        assert!(matches!(
+            simulated_execution.market_price(&market),
+            Err(ExecutionError::NonDecimal {
+                field: "trade.price",
+                ..
+            })
+        ));
     }
 
     #[test]
     fn should_calculate_simulated_fees_correctly() {
         let simulated_execution = SimulatedExecution::new(Config {
             simulated_fees_pct: Fees {
-                exchange: 0.5,
-                slippage: 0.1,
-                network: 0.001,
+                exchange: dec!(0.5),
+                slippage: dec!(0.1),
+                network: dec!(0.001),
             },
         });
 
-        let input_fill_value_gross = 100.0;
+        let input_fill_value_gross = dec!(100);
 
         let actual_result = simulated_execution.calculate_fees(&input_fill_value_gross);
 
         let expected = Fees {
-            exchange: 50.0,
-            slippage: 10.0,
-            network: 0.1,
+            exchange: dec!(50),
+            slippage: dec!(10),
+            network: dec!(0.1),
         };
 
         assert_eq!(actual_result, expected)
     }
+
+    #[test]
+    fn should_add_instrument_slippage_model_to_fees() {
+        let mut input_order = order_event();
+        input_order.quantity = dec!(2);
+        input_order.market_meta.close = dec!(100);
+
+        let simulated_execution = SimulatedExecution::new(Config {
+            simulated_fees_pct: Fees {
+                exchange: dec!(0),
+                slippage: dec!(0.01),
+                network: dec!(0),
+            },
+        })
+        .with_slippage(SlippageModel::FixedBps(dec!(1000)))
+        .with_instrument_slippage(
+            input_order.instrument.clone(),
+            SlippageModel::FixedBps(dec!(10)),
+        );
+
+        let actual = simulated_execution.generate_fill(&input_order).unwrap();
+
+        // 1% simulated slippage + 10bps instrument specific slippage
+        assert_eq!(actual.fees.slippage, dec!(2) + dec!(0.2));
+    }
+
+    #[test]
+    fn should_use_recorded_order_book_for_depth_consuming_slippage() {
+        use crate::test_util::market_event_trade;
+        use barter_data::{
+            books::{Level, OrderBook},
+            subscription::book::OrderBookEvent,
+        };
+        use barter_integration::Side;
+
+        let mut input_order = order_event();
+        input_order.quantity = dec!(-2);
+        input_order.market_meta.close = dec!(100);
+
+        let mut simulated_execution =
+            SimulatedExecution::new(Config::default()).with_slippage(SlippageModel::DepthConsuming);
+
+        // No OrderBook recorded yet, so no slippage
+        let actual = simulated_execution.generate_fill(&input_order).unwrap();
+        assert_eq!(actual.fees.slippage, dec!(0));
+
+        // Record OrderBook snapshot for the order Instrument
+        let mut market = market_event_trade(Side::Buy);
+        market.instrument = input_order.instrument.clone();
+        market.kind = DataKind::OrderBook(OrderBookEvent::Snapshot(OrderBook::new(
+            0,
+            None,
+            vec![Level::new(dec!(99), dec!(1)), Level::new(dec!(98), dec!(2))],
+            vec![Level::new(dec!(101), dec!(1))],
+        )));
+        simulated_execution.update_from_market(&market);
+
+        // Sell of 2 contracts walks two bid Levels: 1 @ 99 & 1 @ 98
+        let actual = simulated_execution.generate_fill(&input_order).unwrap();
+        assert_eq!(actual.fees.slippage, dec!(1));
+    }
+
+    #[test]
+    fn should_timestamp_fill_with_clock() {
+        use crate::clock::FrozenClock;
+
+        let time = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+        let simulated_execution =
+            SimulatedExecution::new(Config::default()).with_clock(FrozenClock(time));
+
+        let actual = simulated_execution.generate_fill(&order_event()).unwrap();
+
+        assert_eq!(actual.time, time);
+    }
+
+    #[test]
+    fn should_fill_in_flight_order_after_latency() {
+        use crate::{execution::latency::LatencyProfile, test_util::market_event_trade};
+        use barter_data::subscription::trade::PublicTrade;
+        use barter_execution::simulated::exchange::account::simulation::LatencyModel;
+        use barter_integration::Side;
+        use chrono::TimeDelta;
+        use std::time::Duration;
+
+        struct TestCase {
+            market_offsets_millis: Vec<i64>,
+            cancel_after_millis: Option<i64>,
+            expected_fill_offset_millis: Option<i64>,
+        }
+
+        // Order reaches the exchange 1ms (market data) + 2ms (order submit) after the MarketEvent
+        let latency = LatencyConfig {
+            seed: 0,
+            default: LatencyProfile {
+                market_data: LatencyModel::Fixed(Duration::from_millis(1)),
+                order_submit: LatencyModel::Fixed(Duration::from_millis(2)),
+                order_cancel: LatencyModel::Fixed(Duration::from_millis(1)),
+            },
+            exchanges: HashMap::new(),
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: filled at the first MarketEvent at or after the order arrival
+                market_offsets_millis: vec![2, 4, 5],
+                cancel_after_millis: None,
+                expected_fill_offset_millis: Some(4),
+            },
+            TestCase {
+                // TC1: cancel reaches the exchange (0ms + 1ms + 1ms) before the order arrival
+                market_offsets_millis: vec![0, 4],
+                cancel_after_millis: Some(0),
+                expected_fill_offset_millis: None,
+            },
+            TestCase {
+                // TC2: cancel reaches the exchange (2ms + 1ms + 1ms) after the order arrival
+                market_offsets_millis: vec![2, 4],
+                cancel_after_millis: Some(2),
+                expected_fill_offset_millis: Some(4),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut simulated_execution =
+                SimulatedExecution::new(Config::default()).with_latency(latency.clone());
+
+            let order = order_event();
+            let base_time = order.market_meta.time;
+            assert_eq!(simulated_execution.submit_order(&order).unwrap(), None);
+
+            let mut fills = Vec::new();
+            for offset in test.market_offsets_millis {
+                let mut market = market_event_trade(Side::Buy);
+                market.exchange = order.exchange;
+                market.instrument = order.instrument.clone();
+                market.time_exchange = base_time + TimeDelta::milliseconds(offset);
+                market.kind = DataKind::Trade(PublicTrade {
+                    id: offset.to_string(),
+                    price: 100.0 + offset as f64,
+                    amount: 1.0,
+                    side: Side::Buy,
+                    aggregate_count: None,
+                });
+                simulated_execution.update_from_market(&market);
+                fills.extend(simulated_execution.poll_fills());
+
+                if test.cancel_after_millis == Some(offset) {
+                    simulated_execution.cancel_orders_pending();
+                }
+            }
+
+            let actual = fills
+                .iter()
+                .map(|fill| (fill.time, fill.market_meta.close))
+                .collect::<Vec<_>>();
+            let expected = test
+                .expected_fill_offset_millis
+                .map(|offset| {
+                    (
+                        base_time + TimeDelta::milliseconds(offset),
+                        dec!(100) + Decimal::from(offset),
+                    )
+                })
+                .into_iter()
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn should_expire_in_flight_orders_generated_before_time() {
+        use chrono::TimeDelta;
+
+        let mut simulated_execution =
+            SimulatedExecution::new(Config::default()).with_latency(LatencyConfig::default());
+
+        let start = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+        for offset in [0, 10] {
+            let mut order = order_event();
+            order.time = start + TimeDelta::seconds(offset);
+            assert_eq!(simulated_execution.submit_order(&order).unwrap(), None);
+        }
+
+        let expired = simulated_execution.expire_orders_pending(start + TimeDelta::seconds(5));
+
+        assert_eq!(expired, 1);
+        assert_eq!(simulated_execution.orders_pending().len(), 1);
+        assert_eq!(
+            simulated_execution.orders_pending()[0].time,
+            start + TimeDelta::seconds(10)
+        );
+    }
 }
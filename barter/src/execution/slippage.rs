@@ -0,0 +1,190 @@
+use crate::execution::FeeAmount;
+use barter_data::books::{Level, OrderBook};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Number of basis points in one unit (eg/ 1.0 == 10,000 bps).
+const BPS_PER_UNIT: Decimal = Decimal::from_parts(10_000, 0, 0, false, 0);
+
+/// Models the slippage a simulated [`OrderEvent`](crate::portfolio::OrderEvent) incurs, returned
+/// as a [`FeeAmount`] in the quote currency.
+///
+/// Book dependent models ([`SlippageModel::SpreadProportional`] & [`SlippageModel::DepthConsuming`])
+/// use the latest recorded [`OrderBook`] for the instrument. If no [`OrderBook`] has been recorded
+/// they yield zero slippage.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SlippageModel {
+    /// No modelled slippage.
+    #[default]
+    None,
+
+    /// Fixed number of basis points of the gross fill value.
+    FixedBps(Decimal),
+
+    /// Proportion of the quoted half-spread, multiplied by the order quantity.
+    ///
+    /// eg/ `factor` of 1.0 models crossing the spread from the mid-price.
+    SpreadProportional { factor: Decimal },
+
+    /// Walks the recorded L2 [`OrderBook`] on the side the order consumes, and charges the
+    /// difference between the volume weighted fill price and the best price. Any quantity in
+    /// excess of the recorded depth is filled at the worst recorded [`Level`].
+    DepthConsuming,
+}
+
+impl SlippageModel {
+    /// Calculate the slippage [`FeeAmount`] of filling the provided +ve (buy) or -ve (sell)
+    /// quantity.
+    pub fn calculate(
+        &self,
+        quantity: Decimal,
+        fill_value_gross: Decimal,
+        book: Option<&OrderBook>,
+    ) -> FeeAmount {
+        match self {
+            SlippageModel::None => Decimal::ZERO,
+            SlippageModel::FixedBps(bps) => fill_value_gross * bps / BPS_PER_UNIT,
+            SlippageModel::SpreadProportional { factor } => book
+                .and_then(half_spread)
+                .map(|half_spread| factor * half_spread * quantity.abs())
+                .unwrap_or_default(),
+            SlippageModel::DepthConsuming => book
+                .and_then(|book| {
+                    if quantity.is_sign_positive() {
+                        depth_slippage(book.asks().levels(), quantity.abs())
+                    } else {
+                        depth_slippage(book.bids().levels(), quantity.abs())
+                    }
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Calculate half of the quoted spread of the provided [`OrderBook`], if both sides have a
+/// best [`Level`].
+fn half_spread(book: &OrderBook) -> Option<Decimal> {
+    let best_bid = book.bids().levels().first()?.price;
+    let best_ask = book.asks().levels().first()?.price;
+    Some((best_ask - best_bid).max(Decimal::ZERO) / Decimal::TWO)
+}
+
+/// Calculate the cost of consuming `quantity` from the provided sorted [`Level`]s (best first),
+/// relative to filling the entire `quantity` at the best [`Level`] price.
+fn depth_slippage(levels: &[Level], quantity: Decimal) -> Option<Decimal> {
+    let best_price = levels.first()?.price;
+
+    let mut remaining = quantity;
+    let mut cost = Decimal::ZERO;
+    let mut worst_price = best_price;
+
+    for level in levels {
+        if remaining <= Decimal::ZERO {
+            break;
+        }
+
+        let consumed = remaining.min(level.amount);
+
+        cost += (level.price - best_price).abs() * consumed;
+        remaining -= consumed;
+        worst_price = level.price;
+    }
+
+    // Fill any quantity beyond the recorded depth at the worst recorded Level
+    if remaining > Decimal::ZERO {
+        cost += (worst_price - best_price).abs() * remaining;
+    }
+
+    Some(cost)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn book() -> OrderBook {
+        OrderBook::new(
+            0,
+            None,
+            vec![Level::new(dec!(99), dec!(1)), Level::new(dec!(98), dec!(2))],
+            vec![
+                Level::new(dec!(101), dec!(1)),
+                Level::new(dec!(102), dec!(1)),
+                Level::new(dec!(104), dec!(1)),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_slippage_model_calculate() {
+        struct TestCase {
+            model: SlippageModel,
+            quantity: Decimal,
+            book: Option<OrderBook>,
+            expected: FeeAmount,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: no slippage
+                model: SlippageModel::None,
+                quantity: dec!(1),
+                book: Some(book()),
+                expected: dec!(0),
+            },
+            TestCase {
+                // TC1: fixed bps of gross fill value
+                model: SlippageModel::FixedBps(dec!(10)),
+                quantity: dec!(1),
+                book: None,
+                expected: dec!(0.1),
+            },
+            TestCase {
+                // TC2: spread proportional w/ factor 1.0 => half-spread per contract
+                model: SlippageModel::SpreadProportional { factor: dec!(1) },
+                quantity: dec!(-2),
+                book: Some(book()),
+                expected: dec!(2),
+            },
+            TestCase {
+                // TC3: spread proportional w/o recorded book
+                model: SlippageModel::SpreadProportional { factor: dec!(1) },
+                quantity: dec!(1),
+                book: None,
+                expected: dec!(0),
+            },
+            TestCase {
+                // TC4: depth consuming buy within best ask Level
+                model: SlippageModel::DepthConsuming,
+                quantity: dec!(1),
+                book: Some(book()),
+                expected: dec!(0),
+            },
+            TestCase {
+                // TC5: depth consuming buy walks three ask Levels (101, 102 & 104)
+                model: SlippageModel::DepthConsuming,
+                quantity: dec!(2.5),
+                book: Some(book()),
+                expected: dec!(1) + (dec!(3) * dec!(0.5)),
+            },
+            TestCase {
+                // TC6: depth consuming sell beyond recorded bid depth
+                model: SlippageModel::DepthConsuming,
+                quantity: dec!(-4),
+                book: Some(book()),
+                expected: (dec!(1) * dec!(2)) + (dec!(1) * dec!(1)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let fill_value_gross = test.quantity.abs() * dec!(100);
+
+            let actual = test
+                .model
+                .calculate(test.quantity, fill_value_gross, test.book.as_ref());
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
@@ -0,0 +1,314 @@
+use crate::{
+    execution::error::{OrderError, SpecViolation},
+    portfolio::OrderEvent,
+};
+use barter_data::instrument::metadata::InstrumentMetadata;
+use barter_instrument::{
+    asset::symbol::Symbol,
+    exchange::ExchangeId,
+    instrument::{
+        fixed::{Lots, TickScale, Ticks},
+        spec::{InstrumentSpec, OrderQuantityUnits},
+        Instrument,
+    },
+};
+use rust_decimal::{
+    prelude::{FromPrimitive, Signed, ToPrimitive},
+    Decimal, RoundingStrategy,
+};
+use std::collections::HashMap;
+
+/// Normalises [`OrderEvent`]s to the exchange [`InstrumentSpec`] before they are sent for
+/// execution, rejecting any that would be rejected by the exchange.
+///
+/// [`OrderEvent`]s for an [`Instrument`] without an [`InstrumentSpec`] are passed through
+/// unchanged.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct OrderValidator {
+    specs: HashMap<(ExchangeId, Instrument), InstrumentSpec<Symbol>>,
+}
+
+impl FromIterator<InstrumentMetadata> for OrderValidator {
+    fn from_iter<Iter>(metadata: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = InstrumentMetadata>,
+    {
+        Self {
+            specs: metadata
+                .into_iter()
+                .map(|metadata| ((metadata.exchange, metadata.instrument), metadata.spec))
+                .collect(),
+        }
+    }
+}
+
+impl OrderValidator {
+    /// Insert the [`InstrumentSpec`] of the provided exchange [`Instrument`], replacing any
+    /// existing [`InstrumentSpec`].
+    pub fn insert(
+        &mut self,
+        exchange: ExchangeId,
+        instrument: Instrument,
+        spec: InstrumentSpec<Symbol>,
+    ) {
+        self.specs.insert((exchange, instrument), spec);
+    }
+
+    /// [`InstrumentSpec`] of the provided exchange [`Instrument`], if known.
+    pub fn spec(
+        &self,
+        exchange: ExchangeId,
+        instrument: &Instrument,
+    ) -> Option<&InstrumentSpec<Symbol>> {
+        self.specs.get(&(exchange, instrument.clone()))
+    }
+
+    /// [`TickScale`] of the provided exchange [`Instrument`], if it's [`InstrumentSpec`] is known.
+    pub fn tick_scale(&self, exchange: ExchangeId, instrument: &Instrument) -> Option<TickScale> {
+        self.spec(exchange, instrument)
+            .and_then(TickScale::from_spec)
+    }
+
+    /// Validate the [`OrderEvent`] (see [`Self::validate`]), and convert it's normalised price &
+    /// quantity into fixed-point [`Ticks`] & [`Lots`], for execution paths that work in integer
+    /// ticks & lots.
+    ///
+    /// Returns `None` ticks & lots if the [`Instrument`] has no known [`InstrumentSpec`].
+    pub fn validate_fixed(
+        &self,
+        order: OrderEvent,
+    ) -> Result<(OrderEvent, Option<(Ticks, Lots)>), OrderError> {
+        let order = self.validate(order)?;
+        let fixed = self
+            .tick_scale(order.exchange, &order.instrument)
+            .and_then(|scale| {
+                Some((
+                    scale.ticks(order.market_meta.close)?,
+                    scale.lots(order.quantity)?,
+                ))
+            });
+        Ok((order, fixed))
+    }
+
+    /// Round the [`OrderEvent`] price to the tick size and quantity (towards zero) to the step
+    /// size, returning an [`OrderError::SpecViolation`] if the normalised [`OrderEvent`] breaches
+    /// a min/max price, quantity or notional rule.
+    ///
+    /// The [`OrderEvent`] price is the `MarketMeta` close it will be executed at.
+    pub fn validate(&self, mut order: OrderEvent) -> Result<OrderEvent, OrderError> {
+        let Some(spec) = self.spec(order.exchange, &order.instrument) else {
+            return Ok(order);
+        };
+
+        let violation = |violation| OrderError::SpecViolation {
+            instrument: order.instrument.clone(),
+            violation,
+        };
+
+        let close = order.market_meta.close;
+        let tick_size = spec
+            .price
+            .tick_size_at(close.to_f64().ok_or(OrderError::NonFloat {
+                field: "market_meta.close",
+                value: close,
+            })?);
+
+        let price = round_nearest(close, to_decimal("price.tick_size", tick_size)?);
+        let min = to_decimal("price.min", spec.price.min)?;
+        if price < min {
+            return Err(violation(SpecViolation::PriceBelowMin { price, min }));
+        }
+
+        let quantity = round_towards_zero(
+            order.quantity.abs(),
+            to_decimal("quantity.increment", spec.quantity.increment)?,
+        ) * order.quantity.signum();
+        let min = to_decimal("quantity.min", spec.quantity.min)?;
+        if quantity.abs() < min || quantity.is_zero() {
+            return Err(violation(SpecViolation::QuantityBelowMin { quantity, min }));
+        }
+        if let Some(max) = spec.quantity.max {
+            let max = to_decimal("quantity.max", max)?;
+            if quantity.abs() > max {
+                return Err(violation(SpecViolation::QuantityAboveMax { quantity, max }));
+            }
+        }
+
+        let notional = match spec.quantity.unit {
+            OrderQuantityUnits::Quote => quantity.abs(),
+            OrderQuantityUnits::Contract => {
+                let multiplier = spec
+                    .quantity
+                    .contract_multiplier
+                    .map(|multiplier| to_decimal("quantity.contract_multiplier", multiplier))
+                    .transpose()?
+                    .unwrap_or(Decimal::ONE);
+                quantity.abs() * price * multiplier
+            }
+            OrderQuantityUnits::Asset(_) => quantity.abs() * price,
+        };
+        let min = to_decimal("notional.min", spec.notional.min)?;
+        if notional < min {
+            return Err(violation(SpecViolation::NotionalBelowMin { notional, min }));
+        }
+        if let Some(max) = spec.notional.max {
+            let max = to_decimal("notional.max", max)?;
+            if notional > max {
+                return Err(violation(SpecViolation::NotionalAboveMax { notional, max }));
+            }
+        }
+
+        order.market_meta.close = price;
+        order.quantity = quantity;
+        Ok(order)
+    }
+}
+
+/// Convert an `f64` [`InstrumentSpec`] field to a [`Decimal`], returning an
+/// [`OrderError::NonDecimal`] if it is not finite.
+fn to_decimal(field: &'static str, value: f64) -> Result<Decimal, OrderError> {
+    Decimal::from_f64(value).ok_or(OrderError::NonDecimal { field, value })
+}
+
+/// Round the value to the nearest multiple of the increment.
+fn round_nearest(value: Decimal, increment: Decimal) -> Decimal {
+    if increment <= Decimal::ZERO {
+        return value;
+    }
+    (value / increment).round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+        * increment
+}
+
+/// Round the non-negative value down to a multiple of the increment.
+fn round_towards_zero(value: Decimal, increment: Decimal) -> Decimal {
+    if increment <= Decimal::ZERO {
+        return value;
+    }
+    (value / increment).floor() * increment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::order_event;
+    use barter_instrument::instrument::spec::{
+        InstrumentSpecNotional, InstrumentSpecPrice, InstrumentSpecQuantity,
+    };
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_order_validator_validate() {
+        struct TestCase {
+            input_close: Decimal,
+            input_quantity: Decimal,
+            expected: Result<(Decimal, Decimal), SpecViolation>,
+        }
+
+        let order = order_event();
+        let validator = OrderValidator::from_iter([InstrumentMetadata {
+            exchange: order.exchange,
+            name_exchange: "ETHUSDT".into(),
+            instrument: order.instrument.clone(),
+            spec: InstrumentSpec {
+                price: InstrumentSpecPrice {
+                    min: 0.01,
+                    tick_size: 0.01,
+                    tick_size_schedule: None,
+                },
+                quantity: InstrumentSpecQuantity {
+                    unit: OrderQuantityUnits::Asset(Symbol::new("eth")),
+                    min: 0.001,
+                    increment: 0.001,
+                    max: Some(100.0),
+                    contract_multiplier: None,
+                },
+                notional: InstrumentSpecNotional {
+                    min: 5.0,
+                    max: None,
+                },
+            },
+        }]);
+
+        let tests = vec![
+            TestCase {
+                // TC0: price rounded to tick size & quantity rounded down to step size
+                input_close: dec!(1000.004),
+                input_quantity: dec!(0.0123456),
+                expected: Ok((dec!(1000.0), dec!(0.012))),
+            },
+            TestCase {
+                // TC1: negative quantity rounded towards zero
+                input_close: dec!(1000.0),
+                input_quantity: dec!(-0.0129),
+                expected: Ok((dec!(1000.0), dec!(-0.012))),
+            },
+            TestCase {
+                // TC2: quantity exactly on a step is not rounded down
+                input_close: dec!(1000.0),
+                input_quantity: dec!(0.3),
+                expected: Ok((dec!(1000.0), dec!(0.3))),
+            },
+            TestCase {
+                // TC3: quantity below min after rounding
+                input_close: dec!(1000.0),
+                input_quantity: dec!(0.0009),
+                expected: Err(SpecViolation::QuantityBelowMin {
+                    quantity: dec!(0.0),
+                    min: dec!(0.001),
+                }),
+            },
+            TestCase {
+                // TC4: quantity above max
+                input_close: dec!(10.0),
+                input_quantity: dec!(200.0),
+                expected: Err(SpecViolation::QuantityAboveMax {
+                    quantity: dec!(200.0),
+                    max: dec!(100.0),
+                }),
+            },
+            TestCase {
+                // TC5: notional below min
+                input_close: dec!(1000.0),
+                input_quantity: dec!(0.004),
+                expected: Err(SpecViolation::NotionalBelowMin {
+                    notional: dec!(4.0),
+                    min: dec!(5.0),
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut input = order.clone();
+            input.market_meta.close = test.input_close;
+            input.quantity = test.input_quantity;
+
+            match (validator.validate(input.clone()), test.expected) {
+                (Ok(actual), Ok((expected_close, expected_quantity))) => {
+                    assert_eq!(actual.market_meta.close, expected_close, "TC{index} failed");
+                    assert_eq!(actual.quantity, expected_quantity, "TC{index} failed");
+
+                    let (_, fixed) = validator.validate_fixed(input).unwrap();
+                    assert_eq!(
+                        fixed,
+                        Some((
+                            Ticks((expected_close * dec!(100)).to_i64().unwrap()),
+                            Lots((expected_quantity * dec!(1000)).to_i64().unwrap())
+                        )),
+                        "TC{index} failed"
+                    );
+                }
+                (Err(actual), Err(expected)) => {
+                    let expected = OrderError::SpecViolation {
+                        instrument: order.instrument.clone(),
+                        violation: expected,
+                    };
+                    assert_eq!(actual, expected, "TC{index} failed");
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
@@ -96,6 +96,7 @@
 //! };
 //! use std::marker::PhantomData;
 //! use uuid::Uuid;
+//! use rust_decimal_macros::dec;
 //! use barter_instrument::exchange::ExchangeId;
 //! use barter_instrument::instrument::kind::InstrumentKind;
 //! use barter_instrument::market::Market;
@@ -104,11 +105,11 @@
 //!     engine_id: Uuid::new_v4(),
 //!     markets: vec![Market::new(ExchangeId::BinanceSpot, ("btc", "usdt", InstrumentKind::Spot))],
 //!     repository: InMemoryRepository::new(),
-//!     allocator: DefaultAllocator{ default_order_value: 100.0 },
+//!     allocator: DefaultAllocator{ default_order_value: dec!(100) },
 //!     risk: DefaultRisk{},
-//!     starting_cash: 10000.0,
+//!     starting_cash: dec!(10000),
 //!     statistic_config: StatisticConfig {
-//!         starting_equity: 10000.0 ,
+//!         starting_equity: dec!(10000),
 //!         trading_days_per_year: 365,
 //!         risk_free_return: 0.0
 //!     },
@@ -146,12 +147,13 @@
 //!         Fees, ExecutionClient,
 //!     }
 //! };
+//! use rust_decimal_macros::dec;
 //!
 //! let config = ExecutionConfig {
 //!     simulated_fees_pct: Fees {
-//!         exchange: 0.1,
-//!         slippage: 0.05, // Simulated slippage modelled as a Fee
-//!         network: 0.0,
+//!         exchange: dec!(0.1),
+//!         slippage: dec!(0.05), // Simulated slippage modelled as a Fee
+//!         network: dec!(0),
 //!     }
 //! };
 //!
@@ -172,12 +174,13 @@
 //!         Initialiser, PositionSummariser, TableBuilder
 //!     }
 //! };
+//! use rust_decimal_macros::dec;
 //!
 //! // Do some automated trading with barter components that generates a vector of closed Positions
 //! let positions = vec![test_util::position(), test_util::position()];
 //!
 //! let config = StatisticConfig {
-//!     starting_equity: 10000.0,
+//!     starting_equity: dec!(10000),
 //!     trading_days_per_year: 253,
 //!     risk_free_return: 0.5,
 //! };
@@ -244,6 +247,39 @@ pub mod statistic;
 /// Execution components, as well as shared access to a global Portfolio.
 pub mod engine;
 
+/// Defines the Clock trait used to timestamp Engine output, with live, historical (stepped with
+/// market event time) & frozen implementations for deterministic backtests and tests.
+pub mod clock;
+
+/// Tooling for running many backtests in parallel across a grid (or random search) of parameters,
+/// and comparing each run's statistical summary in a single results table.
+pub mod backtest;
+
+/// Transport agnostic control plane for a running Engine, exposing the Engine Command API and
+/// state snapshots to external tooling (eg/ risk dashboards). The gRPC service defined in
+/// `proto/control.proto` is served by the `grpc` feature.
+pub mod server;
+
+/// Typed configuration of full trading systems, along with a builder that materialises the
+/// Portfolio, Traders & Engine it describes. Also contains the thread topology & core affinity
+/// configuration for latency sensitive deployments.
+pub mod system;
+
+/// Persists the fills, orders, positions & balance snapshots of an Engine into SQLite or
+/// Postgres, along with the schema & query helpers of the resulting trade history.
+#[cfg(feature = "sql")]
+pub mod storage;
+
+/// Commonly used types across barter, barter-data, barter-execution, barter-instrument &
+/// barter-integration, re-exported with non-overlapping names.
+pub mod prelude;
+
+/// Re-exported Barter ecosystem crates, so a trading system only needs to depend on `barter`.
+pub use barter_data;
+pub use barter_execution;
+pub use barter_instrument;
+pub use barter_integration;
+
 #[macro_use]
 extern crate prettytable;
 
@@ -258,14 +294,17 @@ pub mod test_util {
         event::{DataKind, MarketEvent},
         subscription::{candle::Candle, trade::PublicTrade},
     };
+    use barter_execution::model::ClientOrderId;
     use barter_instrument::{
         exchange::ExchangeId,
         instrument::{kind::InstrumentKind, Instrument},
     };
     use barter_integration::Side;
     use chrono::Utc;
+    use rust_decimal::Decimal;
     use smol_str::ToSmolStr;
     use std::ops::Add;
+    use uuid::Uuid;
 
     /// Build a [`MarketEvent`] of [`DataKind::PublicTrade`](DataKind) with the provided [`Side`].
     pub fn market_event_trade(side: Side) -> MarketEvent<Instrument, DataKind> {
@@ -279,6 +318,7 @@ pub mod test_util {
                 price: 1000.0,
                 amount: 1.0,
                 side,
+                aggregate_count: None,
             }),
         }
     }
@@ -318,11 +358,12 @@ pub mod test_util {
     pub fn order_event() -> OrderEvent {
         OrderEvent {
             time: Utc::now(),
+            cid: ClientOrderId(Uuid::new_v4()),
             exchange: ExchangeId::BinanceSpot,
             instrument: Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
             market_meta: MarketMeta::default(),
             decision: Decision::default(),
-            quantity: 1.0,
+            quantity: Decimal::ONE,
             order_type: OrderType::default(),
         }
     }
@@ -331,12 +372,13 @@ pub mod test_util {
     pub fn fill_event() -> FillEvent {
         FillEvent {
             time: Utc::now(),
+            cid: ClientOrderId(Uuid::new_v4()),
             exchange: ExchangeId::BinanceSpot,
             instrument: Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
             market_meta: Default::default(),
             decision: Decision::default(),
-            quantity: 1.0,
-            fill_value_gross: 100.0,
+            quantity: Decimal::ONE,
+            fill_value_gross: Decimal::ONE_HUNDRED,
             fees: Fees::default(),
         }
     }
@@ -349,19 +391,19 @@ pub mod test_util {
             instrument: Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
             meta: Default::default(),
             side: Side::Buy,
-            quantity: 1.0,
+            quantity: Decimal::ONE,
             enter_fees: Default::default(),
-            enter_fees_total: 0.0,
-            enter_avg_price_gross: 100.0,
-            enter_value_gross: 100.0,
+            enter_fees_total: Decimal::ZERO,
+            enter_avg_price_gross: Decimal::ONE_HUNDRED,
+            enter_value_gross: Decimal::ONE_HUNDRED,
             exit_fees: Default::default(),
-            exit_fees_total: 0.0,
-            exit_avg_price_gross: 0.0,
-            exit_value_gross: 0.0,
-            current_symbol_price: 100.0,
-            current_value_gross: 100.0,
-            unrealised_profit_loss: 0.0,
-            realised_profit_loss: 0.0,
+            exit_fees_total: Decimal::ZERO,
+            exit_avg_price_gross: Decimal::ZERO,
+            exit_value_gross: Decimal::ZERO,
+            current_symbol_price: Decimal::ONE_HUNDRED,
+            current_value_gross: Decimal::ONE_HUNDRED,
+            unrealised_profit_loss: Decimal::ZERO,
+            realised_profit_loss: Decimal::ZERO,
         }
     }
 }
@@ -1,4 +1,5 @@
 use crate::portfolio::repository::error::RepositoryError;
+use rust_decimal::Decimal;
 use thiserror::Error;
 
 /// All errors generated in the barter::portfolio module.
@@ -19,6 +20,15 @@ pub enum PortfolioError {
     #[error("Cannot generate PositionExit from Position that has not been exited")]
     PositionExit,
 
+    #[error("{field} cannot be represented as a Decimal: {value}")]
+    NonDecimal { field: &'static str, value: f64 },
+
+    #[error("{field} cannot be represented as an f64: {value}")]
+    NonFloat { field: &'static str, value: Decimal },
+
+    #[error("Cannot allocate an OrderEvent quantity at a non-positive close: {0}")]
+    NonPositiveClose(Decimal),
+
     #[error("Failed to interact with repository")]
     RepositoryInteraction(#[from] RepositoryError),
 }
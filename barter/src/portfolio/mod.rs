@@ -6,8 +6,11 @@ use crate::{
     strategy::{Decision, Signal, SignalForceExit},
 };
 use barter_data::event::{DataKind, MarketEvent};
+use barter_execution::model::ClientOrderId;
 use barter_instrument::{exchange::ExchangeId, instrument::Instrument};
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -32,6 +35,11 @@ pub mod repository;
 /// Logic for evaluating the risk associated with a proposed [`OrderEvent`].
 pub mod risk;
 
+/// EWMA covariance estimate of instrument returns used to compute the portfolio volatility &
+/// Value-at-Risk, and a [`VolatilityTarget`](volatility::VolatilityTarget) risk rule that
+/// rejects or scales [`OrderEvent`]s exceeding a volatility target.
+pub mod volatility;
+
 /// Updates the Portfolio from an input [`MarketEvent`].
 pub trait MarketUpdater {
     /// Determines if the Portfolio has an open Position relating to the input [`MarketEvent`]. If
@@ -66,9 +74,12 @@ pub trait FillUpdater {
 
 /// Orders are generated by the portfolio and details work to be done by an Execution handler to
 /// open a trade.
-#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize, JsonSchema)]
 pub struct OrderEvent {
     pub time: DateTime<Utc>,
+    /// Unique identifier of the order, carried by the [`FillEvent`] that executes it.
+    #[schemars(with = "Uuid")]
+    pub cid: ClientOrderId,
     pub exchange: ExchangeId,
     pub instrument: Instrument,
     /// Metadata propagated from source MarketEvent
@@ -76,7 +87,9 @@ pub struct OrderEvent {
     /// LONG, CloseLong, SHORT or CloseShort
     pub decision: Decision,
     /// +ve or -ve Quantity depending on Decision
-    pub quantity: f64,
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
+    pub quantity: Decimal,
     /// MARKET, LIMIT etc
     pub order_type: OrderType,
 }
@@ -92,13 +105,16 @@ impl OrderEvent {
 }
 
 /// Type of order the portfolio wants the execution::handler to place.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize, JsonSchema,
+)]
 pub enum OrderType {
     Market,
     Limit,
     Bracket,
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for OrderType {
     fn default() -> Self {
         Self::Market
@@ -109,11 +125,12 @@ impl Default for OrderType {
 #[derive(Debug, Default)]
 pub struct OrderEventBuilder {
     pub time: Option<DateTime<Utc>>,
+    pub cid: Option<ClientOrderId>,
     pub exchange: Option<ExchangeId>,
     pub instrument: Option<Instrument>,
     pub market_meta: Option<MarketMeta>,
     pub decision: Option<Decision>,
-    pub quantity: Option<f64>,
+    pub quantity: Option<Decimal>,
     pub order_type: Option<OrderType>,
 }
 
@@ -129,6 +146,13 @@ impl OrderEventBuilder {
         }
     }
 
+    pub fn cid(self, value: ClientOrderId) -> Self {
+        Self {
+            cid: Some(value),
+            ..self
+        }
+    }
+
     pub fn exchange(self, value: ExchangeId) -> Self {
         Self {
             exchange: Some(value),
@@ -157,7 +181,7 @@ impl OrderEventBuilder {
         }
     }
 
-    pub fn quantity(self, value: f64) -> Self {
+    pub fn quantity(self, value: Decimal) -> Self {
         Self {
             quantity: Some(value),
             ..self
@@ -174,6 +198,7 @@ impl OrderEventBuilder {
     pub fn build(self) -> Result<OrderEvent, PortfolioError> {
         Ok(OrderEvent {
             time: self.time.ok_or(PortfolioError::BuilderIncomplete("time"))?,
+            cid: self.cid.ok_or(PortfolioError::BuilderIncomplete("cid"))?,
             exchange: self
                 .exchange
                 .ok_or(PortfolioError::BuilderIncomplete("exchange"))?,
@@ -200,26 +225,32 @@ impl OrderEventBuilder {
 pub type BalanceId = String;
 
 /// Total and available balance at a point in time.
-#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+///
+/// Balances are [`Decimal`] so that they sum exactly to those of exchange statements.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize, JsonSchema)]
 pub struct Balance {
     pub time: DateTime<Utc>,
-    pub total: f64,
-    pub available: f64,
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
+    pub total: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
+    pub available: Decimal,
 }
 
 impl Default for Balance {
     fn default() -> Self {
         Self {
             time: Utc::now(),
-            total: 0.0,
-            available: 0.0,
+            total: Decimal::ZERO,
+            available: Decimal::ZERO,
         }
     }
 }
 
 impl Balance {
     /// Construct a new [`Balance`] using the provided total & available balance values.
-    pub fn new(time: DateTime<Utc>, total: f64, available: f64) -> Self {
+    pub fn new(time: DateTime<Utc>, total: Decimal, available: Decimal) -> Self {
         Self {
             time,
             total,
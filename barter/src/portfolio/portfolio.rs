@@ -10,6 +10,7 @@ use super::{
     Balance, FillUpdater, MarketUpdater, OrderEvent, OrderGenerator, OrderType,
 };
 use crate::{
+    clock::{Clock, SharedClock},
     data::MarketMeta,
     event::Event,
     execution::FillEvent,
@@ -17,12 +18,13 @@ use crate::{
     strategy::{Decision, Signal, SignalForceExit, SignalStrength},
 };
 use barter_data::event::{DataKind, MarketEvent};
+use barter_execution::model::ClientOrderId;
 use barter_instrument::{
     instrument::Instrument,
     market::{Market, MarketId},
 };
 use barter_integration::Side;
-use chrono::Utc;
+use rust_decimal::Decimal;
 use serde::Serialize;
 use std::{collections::HashMap, marker::PhantomData};
 use tracing::info;
@@ -51,7 +53,7 @@ where
     /// Risk manager implements [`OrderEvaluator`].
     pub risk: RiskManager,
     /// Cash balance a [`MetaPortfolio`] starts with.
-    pub starting_cash: f64,
+    pub starting_cash: Decimal,
     /// Configuration used to initialise the Statistics for every Market's performance tracked by a
     /// [`MetaPortfolio`].
     pub statistic_config: Statistic::Config,
@@ -77,6 +79,8 @@ where
     allocation_manager: Allocator,
     /// Risk manager implements [`OrderEvaluator`].
     risk_manager: RiskManager,
+    /// [`Clock`] used to timestamp generated [`OrderEvent`]s & the initial [`Balance`].
+    clock: SharedClock,
     _statistic_marker: PhantomData<Statistic>,
 }
 
@@ -99,7 +103,7 @@ where
         // Update Position if Portfolio has an open Position for that Symbol-Exchange combination
         if let Some(mut position) = self.repository.get_open_position(&position_id)? {
             // Derive PositionUpdate event that communicates the open Position's change in state
-            if let Some(position_update) = position.update(market) {
+            if let Some(position_update) = position.update(market)? {
                 // Save updated open Position in the repository
                 self.repository.set_open_position(position)?;
                 return Ok(Some(position_update));
@@ -139,18 +143,19 @@ where
 
         // Construct mutable OrderEvent that can be modified by Allocation & Risk management
         let mut order = OrderEvent {
-            time: Utc::now(),
+            time: self.clock.time(),
+            cid: ClientOrderId(Uuid::new_v4()),
             exchange: signal.exchange,
             instrument: signal.instrument.clone(),
             market_meta: signal.market_meta,
             decision: *signal_decision,
-            quantity: 0.0,
+            quantity: Decimal::ZERO,
             order_type: OrderType::default(),
         };
 
         // Manage OrderEvent size allocation
         self.allocation_manager
-            .allocate_order(&mut order, position, *signal_strength);
+            .allocate_order(&mut order, position, *signal_strength)?;
 
         // Manage global risk when evaluating OrderEvent - keep the same, refine or cancel
         Ok(self.risk_manager.evaluate_order(order))
@@ -178,7 +183,8 @@ where
         };
 
         Ok(Some(OrderEvent {
-            time: Utc::now(),
+            time: self.clock.time(),
+            cid: ClientOrderId(Uuid::new_v4()),
             exchange: signal.exchange,
             instrument: signal.instrument,
             market_meta: MarketMeta {
@@ -186,7 +192,7 @@ where
                 time: position.meta.update_time,
             },
             decision: position.determine_exit_decision(),
-            quantity: 0.0 - position.quantity,
+            quantity: -position.quantity,
             order_type: OrderType::Market,
         }))
     }
@@ -242,7 +248,7 @@ where
             None => {
                 // Enter new Position, & add the PositionNew event to Vec<Event>
                 let position = Position::enter(self.engine_id, fill)?;
-                generated_events.push(Event::PositionNew(position.clone()));
+                generated_events.push(Event::PositionNew(Box::new(position.clone())));
 
                 // Update Portfolio Balance.available on Position entry
                 balance.available += -position.enter_value_gross - position.enter_fees_total;
@@ -306,6 +312,23 @@ where
     }
 }
 
+impl<Repository, Allocator, RiskManager, Statistic> BalanceHandler
+    for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
+where
+    Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+    Allocator: OrderAllocator,
+    RiskManager: OrderEvaluator,
+    Statistic: Initialiser + PositionSummariser,
+{
+    fn set_balance(&mut self, _: Uuid, balance: Balance) -> Result<(), RepositoryError> {
+        self.repository.set_balance(self.engine_id, balance)
+    }
+
+    fn get_balance(&mut self, _: Uuid) -> Result<Balance, RepositoryError> {
+        self.repository.get_balance(self.engine_id)
+    }
+}
+
 impl<Repository, Allocator, RiskManager, Statistic> StatisticHandler<Statistic>
     for MetaPortfolio<Repository, Allocator, RiskManager, Statistic>
 where
@@ -346,6 +369,7 @@ where
             repository: lego.repository,
             allocation_manager: lego.allocator,
             risk_manager: lego.risk,
+            clock: SharedClock::default(),
             _statistic_marker: PhantomData,
         };
 
@@ -359,7 +383,7 @@ where
     /// Statistics every market provided, as well as starting `AvailableCash` & `TotalEquity`.
     pub fn bootstrap_repository<Markets, Id>(
         &mut self,
-        starting_cash: f64,
+        starting_cash: Decimal,
         markets: Markets,
         statistic_config: Statistic::Config,
     ) -> Result<(), PortfolioError>
@@ -371,7 +395,7 @@ where
         self.repository.set_balance(
             self.engine_id,
             Balance {
-                time: Utc::now(),
+                time: self.clock.time(),
                 total: starting_cash,
                 available: starting_cash,
             },
@@ -394,7 +418,7 @@ where
     fn no_cash_to_enter_new_position(&mut self) -> Result<bool, PortfolioError> {
         self.repository
             .get_balance(self.engine_id)
-            .map(|balance| balance.available == 0.0)
+            .map(|balance| balance.available.is_zero())
             .map_err(PortfolioError::RepositoryInteraction)
     }
 }
@@ -409,11 +433,12 @@ where
 {
     engine_id: Option<Uuid>,
     markets: Option<Vec<Market>>,
-    starting_cash: Option<f64>,
+    starting_cash: Option<Decimal>,
     repository: Option<Repository>,
     allocation_manager: Option<Allocator>,
     risk_manager: Option<RiskManager>,
     statistic_config: Option<Statistic::Config>,
+    clock: Option<SharedClock>,
     _statistic_marker: Option<PhantomData<Statistic>>,
 }
 
@@ -434,6 +459,7 @@ where
             allocation_manager: None,
             risk_manager: None,
             statistic_config: None,
+            clock: None,
             _statistic_marker: None,
         }
     }
@@ -452,7 +478,7 @@ where
         }
     }
 
-    pub fn starting_cash(self, value: f64) -> Self {
+    pub fn starting_cash(self, value: Decimal) -> Self {
         Self {
             starting_cash: Some(value),
             ..self
@@ -487,6 +513,18 @@ where
         }
     }
 
+    /// Use the provided [`Clock`] to timestamp generated [`OrderEvent`]s. Defaults to a
+    /// [`LiveClock`](crate::clock::LiveClock).
+    pub fn clock<C>(self, value: C) -> Self
+    where
+        C: Clock + 'static,
+    {
+        Self {
+            clock: Some(SharedClock::new(value)),
+            ..self
+        }
+    }
+
     pub fn build_and_init(
         self,
     ) -> Result<MetaPortfolio<Repository, Allocator, RiskManager, Statistic>, PortfolioError> {
@@ -504,6 +542,7 @@ where
             risk_manager: self
                 .risk_manager
                 .ok_or(PortfolioError::BuilderIncomplete("risk_manager"))?,
+            clock: self.clock.unwrap_or_default(),
             _statistic_marker: PhantomData,
         };
 
@@ -552,6 +591,8 @@ pub fn parse_signal_decisions<'a>(
 }
 
 #[cfg(test)]
+// Each test overrides the MockRepository methods it needs after defaulting the rest
+#[allow(clippy::field_reassign_with_default)]
 pub mod tests {
     use super::*;
     use crate::{
@@ -568,6 +609,8 @@ pub mod tests {
         exchange::ExchangeId,
         instrument::{kind::InstrumentKind, Instrument},
     };
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
     use smol_str::SmolStr;
 
     #[derive(Default)]
@@ -596,7 +639,7 @@ pub mod tests {
         fn set_open_position(&mut self, position: Position) -> Result<(), RepositoryError> {
             self.position = Some(
                 Position::builder()
-                    .side(position.side.clone())
+                    .side(position.side)
                     .current_symbol_price(position.current_symbol_price)
                     .current_value_gross(position.current_value_gross)
                     .enter_fees_total(position.enter_fees_total)
@@ -687,10 +730,10 @@ pub mod tests {
     {
         let builder = MetaPortfolio::builder()
             .engine_id(Uuid::new_v4())
-            .starting_cash(1000.0)
+            .starting_cash(dec!(1000))
             .repository(mock_repository)
             .allocation_manager(DefaultAllocator {
-                default_order_value: 100.0,
+                default_order_value: dec!(100),
             })
             .risk_manager(DefaultRisk {});
 
@@ -717,6 +760,7 @@ pub mod tests {
             risk_manager: builder
                 .risk_manager
                 .ok_or(PortfolioError::BuilderIncomplete("risk_manager"))?,
+            clock: builder.clock.unwrap_or_default(),
             _statistic_marker: Default::default(),
         })
     }
@@ -737,11 +781,11 @@ pub mod tests {
             Ok(Some({
                 let mut input_position = position();
                 input_position.side = Side::Buy;
-                input_position.quantity = 1.0;
-                input_position.enter_fees_total = 3.0;
-                input_position.current_symbol_price = 100.0;
-                input_position.current_value_gross = 100.0;
-                input_position.unrealised_profit_loss = -3.0; // -3.0 from entry fees
+                input_position.quantity = dec!(1);
+                input_position.enter_fees_total = dec!(3);
+                input_position.current_symbol_price = dec!(100);
+                input_position.current_value_gross = dec!(100);
+                input_position.unrealised_profit_loss = dec!(-3); // -3.0 from entry fees
                 input_position
             }))
         });
@@ -764,17 +808,17 @@ pub mod tests {
             .unwrap();
         let updated_position = portfolio.repository.position.unwrap();
 
-        assert_eq!(updated_position.current_symbol_price.unwrap(), 200.0);
-        assert_eq!(updated_position.current_value_gross.unwrap(), 200.0);
+        assert_eq!(updated_position.current_symbol_price.unwrap(), dec!(200));
+        assert_eq!(updated_position.current_value_gross.unwrap(), dec!(200));
 
         // Unreal PnL Long = current_value_gross - enter_value_gross - enter_fees_total*2
         assert_eq!(
             updated_position.unrealised_profit_loss.unwrap(),
-            200.0 - 100.0 - 6.0
+            dec!(200) - dec!(100) - dec!(6)
         );
         assert_eq!(
             result_pos_update.unrealised_profit_loss,
-            200.0 - 100.0 - 6.0
+            dec!(200) - dec!(100) - dec!(6)
         );
     }
 
@@ -786,11 +830,11 @@ pub mod tests {
             Ok(Some({
                 let mut input_position = position();
                 input_position.side = Side::Buy;
-                input_position.quantity = 1.0;
-                input_position.enter_fees_total = 3.0;
-                input_position.current_symbol_price = 100.0;
-                input_position.current_value_gross = 100.0;
-                input_position.unrealised_profit_loss = -3.0; // -3.0 from entry fees
+                input_position.quantity = dec!(1);
+                input_position.enter_fees_total = dec!(3);
+                input_position.current_symbol_price = dec!(100);
+                input_position.current_value_gross = dec!(100);
+                input_position.unrealised_profit_loss = dec!(-3); // -3.0 from entry fees
                 input_position
             }))
         });
@@ -812,14 +856,17 @@ pub mod tests {
             .unwrap();
         let updated_position = portfolio.repository.position.unwrap();
 
-        assert_eq!(updated_position.current_symbol_price.unwrap(), 50.0);
-        assert_eq!(updated_position.current_value_gross.unwrap(), 50.0);
+        assert_eq!(updated_position.current_symbol_price.unwrap(), dec!(50));
+        assert_eq!(updated_position.current_value_gross.unwrap(), dec!(50));
         // Unreal PnL Long = current_value_gross - enter_value_gross - enter_fees_total*2
         assert_eq!(
             updated_position.unrealised_profit_loss.unwrap(),
-            50.0 - 100.0 - 6.0
+            dec!(50) - dec!(100) - dec!(6)
+        );
+        assert_eq!(
+            result_pos_update.unrealised_profit_loss,
+            dec!(50) - dec!(100) - dec!(6)
         );
-        assert_eq!(result_pos_update.unrealised_profit_loss, 50.0 - 100.0 - 6.0);
     }
 
     #[test]
@@ -830,11 +877,11 @@ pub mod tests {
             Ok(Some({
                 let mut input_position = position();
                 input_position.side = Side::Sell;
-                input_position.quantity = -1.0;
-                input_position.enter_fees_total = 3.0;
-                input_position.current_symbol_price = 100.0;
-                input_position.current_value_gross = 100.0;
-                input_position.unrealised_profit_loss = -3.0; // -3.0 from entry fees
+                input_position.quantity = dec!(-1);
+                input_position.enter_fees_total = dec!(3);
+                input_position.current_symbol_price = dec!(100);
+                input_position.current_value_gross = dec!(100);
+                input_position.unrealised_profit_loss = dec!(-3); // -3.0 from entry fees
                 input_position
             }))
         });
@@ -857,14 +904,17 @@ pub mod tests {
             .unwrap();
         let updated_position = portfolio.repository.position.unwrap();
 
-        assert_eq!(updated_position.current_symbol_price.unwrap(), 50.0);
-        assert_eq!(updated_position.current_value_gross.unwrap(), 50.0);
+        assert_eq!(updated_position.current_symbol_price.unwrap(), dec!(50));
+        assert_eq!(updated_position.current_value_gross.unwrap(), dec!(50));
         // Unreal PnL Short = enter_value_gross - current_value_gross - enter_fees_total*2
         assert_eq!(
             updated_position.unrealised_profit_loss.unwrap(),
-            100.0 - 50.0 - 6.0
+            dec!(100) - dec!(50) - dec!(6)
+        );
+        assert_eq!(
+            result_pos_update.unrealised_profit_loss,
+            dec!(100) - dec!(50) - dec!(6)
         );
-        assert_eq!(result_pos_update.unrealised_profit_loss, 100.0 - 50.0 - 6.0);
     }
 
     #[test]
@@ -875,11 +925,11 @@ pub mod tests {
             Ok(Some({
                 let mut input_position = position();
                 input_position.side = Side::Sell;
-                input_position.quantity = -1.0;
-                input_position.enter_fees_total = 3.0;
-                input_position.current_symbol_price = 100.0;
-                input_position.current_value_gross = 100.0;
-                input_position.unrealised_profit_loss = -3.0; // -3.0 from entry fees
+                input_position.quantity = dec!(-1);
+                input_position.enter_fees_total = dec!(3);
+                input_position.current_symbol_price = dec!(100);
+                input_position.current_value_gross = dec!(100);
+                input_position.unrealised_profit_loss = dec!(-3); // -3.0 from entry fees
                 input_position
             }))
         });
@@ -902,16 +952,16 @@ pub mod tests {
             .unwrap();
         let updated_position = portfolio.repository.position.unwrap();
 
-        assert_eq!(updated_position.current_symbol_price.unwrap(), 200.0);
-        assert_eq!(updated_position.current_value_gross.unwrap(), 200.0);
+        assert_eq!(updated_position.current_symbol_price.unwrap(), dec!(200));
+        assert_eq!(updated_position.current_value_gross.unwrap(), dec!(200));
         // Unreal PnL Short = enter_value_gross - current_value_gross - enter_fees_total*2
         assert_eq!(
             updated_position.unrealised_profit_loss.unwrap(),
-            100.0 - 200.0 - 6.0
+            dec!(100) - dec!(200) - dec!(6)
         );
         assert_eq!(
             result_pos_update.unrealised_profit_loss,
-            100.0 - 200.0 - 6.0
+            dec!(100) - dec!(200) - dec!(6)
         );
     }
 
@@ -923,8 +973,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 0.0,
+                total: dec!(100),
+                available: dec!(0),
             })
         });
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
@@ -945,8 +995,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 0.0,
+                total: dec!(100),
+                available: dec!(0),
             })
         });
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
@@ -967,8 +1017,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 100.0,
+                total: dec!(100),
+                available: dec!(100),
             })
         });
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
@@ -992,8 +1042,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 100.0,
+                total: dec!(100),
+                available: dec!(100),
             })
         });
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
@@ -1024,8 +1074,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 100.0,
+                total: dec!(100),
+                available: dec!(100),
             })
         });
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
@@ -1056,8 +1106,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 100.0,
-                available: 100.0,
+                total: dec!(100),
+                available: dec!(100),
             })
         });
         let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
@@ -1082,7 +1132,7 @@ pub mod tests {
             Ok(Some({
                 let mut position = position();
                 position.side = Side::Buy;
-                position.quantity = 100.0;
+                position.quantity = dec!(100);
                 position
             }))
         });
@@ -1098,7 +1148,7 @@ pub mod tests {
             .unwrap();
 
         assert_eq!(actual.decision, Decision::CloseLong);
-        assert_eq!(actual.quantity, -100.0);
+        assert_eq!(actual.quantity, dec!(-100));
         assert_eq!(actual.order_type, OrderType::Market)
     }
 
@@ -1110,7 +1160,7 @@ pub mod tests {
             Ok(Some({
                 let mut position = position();
                 position.side = Side::Sell;
-                position.quantity = -100.0;
+                position.quantity = dec!(-100);
                 position
             }))
         });
@@ -1126,7 +1176,7 @@ pub mod tests {
             .unwrap();
 
         assert_eq!(actual.decision, Decision::CloseShort);
-        assert_eq!(actual.quantity, 100.0);
+        assert_eq!(actual.quantity, dec!(100));
         assert_eq!(actual.order_type, OrderType::Market)
     }
 
@@ -1152,8 +1202,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 200.0,
+                total: dec!(200),
+                available: dec!(200),
             })
         });
         mock_repository.remove_position = Some(|_| Ok(None));
@@ -1164,12 +1214,12 @@ pub mod tests {
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = dec!(1);
+        input_fill.fill_value_gross = dec!(100);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
 
         let result = portfolio.update_from_fill(&input_fill);
@@ -1179,9 +1229,9 @@ pub mod tests {
 
         assert!(result.is_ok());
         assert_eq!(entered_position.side.unwrap(), Side::Buy);
-        assert_eq!(entered_position.enter_value_gross.unwrap(), 100.0);
-        assert_eq!(entered_position.enter_fees_total.unwrap(), 3.0);
-        assert_eq!(updated_cash, 200.0 - 100.0 - 3.0); // cash += enter_value_gross - enter_fees
+        assert_eq!(entered_position.enter_value_gross.unwrap(), dec!(100));
+        assert_eq!(entered_position.enter_fees_total.unwrap(), dec!(3));
+        assert_eq!(updated_cash, dec!(200) - dec!(100) - dec!(3)); // cash += enter_value_gross - enter_fees
     }
 
     #[test]
@@ -1191,8 +1241,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 200.0,
+                total: dec!(200),
+                available: dec!(200),
             })
         });
         mock_repository.remove_position = Some(|_| Ok(None));
@@ -1203,12 +1253,12 @@ pub mod tests {
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = dec!(-1);
+        input_fill.fill_value_gross = dec!(100);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
 
         let result = portfolio.update_from_fill(&input_fill);
@@ -1218,9 +1268,9 @@ pub mod tests {
 
         assert!(result.is_ok());
         assert_eq!(entered_position.side.unwrap(), Side::Sell);
-        assert_eq!(entered_position.enter_value_gross.unwrap(), 100.0);
-        assert_eq!(entered_position.enter_fees_total.unwrap(), 3.0);
-        assert_eq!(updated_cash, 200.0 - 100.0 - 3.0); // cash += enter_value_gross - enter_fees
+        assert_eq!(entered_position.enter_value_gross.unwrap(), dec!(100));
+        assert_eq!(entered_position.enter_fees_total.unwrap(), dec!(3));
+        assert_eq!(updated_cash, dec!(200) - dec!(100) - dec!(3)); // cash += enter_value_gross - enter_fees
     }
 
     #[test]
@@ -1230,8 +1280,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 97.0,
+                total: dec!(200),
+                available: dec!(97),
             })
         });
         mock_repository.remove_position = Some(|_| {
@@ -1239,9 +1289,9 @@ pub mod tests {
                 Some({
                     let mut input_position = position();
                     input_position.side = Side::Buy;
-                    input_position.quantity = 1.0;
-                    input_position.enter_fees_total = 3.0;
-                    input_position.enter_value_gross = 100.0;
+                    input_position.quantity = dec!(1);
+                    input_position.enter_fees_total = dec!(3);
+                    input_position.enter_value_gross = dec!(100);
                     input_position
                 })
             })
@@ -1255,12 +1305,12 @@ pub mod tests {
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 200.0;
+        input_fill.quantity = dec!(-1);
+        input_fill.fill_value_gross = dec!(200);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
 
         let result = portfolio.update_from_fill(&input_fill);
@@ -1271,9 +1321,12 @@ pub mod tests {
         assert!(result.is_ok());
         // LONG result_profit_loss = exit_value_gross - enter_value_gross - total_fees
         // cash += enter_value_gross + result_profit_loss + enter_fees_total
-        assert_eq!(updated_cash, 97.0 + 100.0 + (200.0 - 100.0 - 6.0) + 3.0);
+        assert_eq!(
+            updated_cash,
+            dec!(97) + dec!(100) + (dec!(200) - dec!(100) - dec!(6)) + dec!(3)
+        );
         // value += result_profit_loss
-        assert_eq!(updated_value, 200.0 + (200.0 - 100.0 - 6.0));
+        assert_eq!(updated_value, dec!(200) + (dec!(200) - dec!(100) - dec!(6)));
     }
 
     #[test]
@@ -1283,8 +1336,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 97.0,
+                total: dec!(200),
+                available: dec!(97),
             })
         });
         mock_repository.remove_position = Some(|_| {
@@ -1292,9 +1345,9 @@ pub mod tests {
                 Some({
                     let mut input_position = position();
                     input_position.side = Side::Buy;
-                    input_position.quantity = 1.0;
-                    input_position.enter_fees_total = 3.0;
-                    input_position.enter_value_gross = 100.0;
+                    input_position.quantity = dec!(1);
+                    input_position.enter_fees_total = dec!(3);
+                    input_position.enter_value_gross = dec!(100);
                     input_position
                 })
             })
@@ -1308,12 +1361,12 @@ pub mod tests {
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 50.0;
+        input_fill.quantity = dec!(-1);
+        input_fill.fill_value_gross = dec!(50);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
 
         let result = portfolio.update_from_fill(&input_fill);
@@ -1324,9 +1377,12 @@ pub mod tests {
         assert!(result.is_ok());
         // LONG result_profit_loss = exit_value_gross - enter_value_gross - total_fees
         // cash += enter_value_gross + result_profit_loss + enter_fees_total
-        assert_eq!(updated_cash, 97.0 + 100.0 + (50.0 - 100.0 - 6.0) + 3.0);
+        assert_eq!(
+            updated_cash,
+            dec!(97) + dec!(100) + (dec!(50) - dec!(100) - dec!(6)) + dec!(3)
+        );
         // value += result_profit_loss
-        assert_eq!(updated_value, 200.0 + (50.0 - 100.0 - 6.0));
+        assert_eq!(updated_value, dec!(200) + (dec!(50) - dec!(100) - dec!(6)));
     }
 
     #[test]
@@ -1336,8 +1392,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 97.0,
+                total: dec!(200),
+                available: dec!(97),
             })
         });
         mock_repository.remove_position = Some(|_| {
@@ -1345,9 +1401,9 @@ pub mod tests {
                 Some({
                     let mut input_position = position();
                     input_position.side = Side::Sell;
-                    input_position.quantity = -1.0;
-                    input_position.enter_fees_total = 3.0;
-                    input_position.enter_value_gross = 100.0;
+                    input_position.quantity = dec!(-1);
+                    input_position.enter_fees_total = dec!(3);
+                    input_position.enter_value_gross = dec!(100);
                     input_position
                 })
             })
@@ -1361,12 +1417,12 @@ pub mod tests {
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 50.0;
+        input_fill.quantity = dec!(1);
+        input_fill.fill_value_gross = dec!(50);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
 
         let result = portfolio.update_from_fill(&input_fill);
@@ -1377,9 +1433,12 @@ pub mod tests {
         assert!(result.is_ok());
         // SHORT result_profit_loss = enter_value_gross - exit_value_gross - total_fees
         // cash += enter_value_gross + result_profit_loss + enter_fees_total
-        assert_eq!(updated_cash, 97.0 + 100.0 + (100.0 - 50.0 - 6.0) + 3.0);
+        assert_eq!(
+            updated_cash,
+            dec!(97) + dec!(100) + (dec!(100) - dec!(50) - dec!(6)) + dec!(3)
+        );
         // value += result_profit_loss
-        assert_eq!(updated_value, 200.0 + (100.0 - 50.0 - 6.0));
+        assert_eq!(updated_value, dec!(200) + (dec!(100) - dec!(50) - dec!(6)));
     }
 
     #[test]
@@ -1389,8 +1448,8 @@ pub mod tests {
         mock_repository.get_balance = Some(|_| {
             Ok(Balance {
                 time: Utc::now(),
-                total: 200.0,
-                available: 97.0,
+                total: dec!(200),
+                available: dec!(97),
             })
         });
         mock_repository.remove_position = Some(|_| {
@@ -1398,9 +1457,9 @@ pub mod tests {
                 Some({
                     let mut input_position = position();
                     input_position.side = Side::Sell;
-                    input_position.quantity = -1.0;
-                    input_position.enter_fees_total = 3.0;
-                    input_position.enter_value_gross = 100.0;
+                    input_position.quantity = dec!(-1);
+                    input_position.enter_fees_total = dec!(3);
+                    input_position.enter_value_gross = dec!(100);
                     input_position
                 })
             })
@@ -1414,12 +1473,12 @@ pub mod tests {
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 150.0;
+        input_fill.quantity = dec!(1);
+        input_fill.fill_value_gross = dec!(150);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
 
         let result = portfolio.update_from_fill(&input_fill);
@@ -1430,9 +1489,12 @@ pub mod tests {
         assert!(result.is_ok());
         // SHORT result_profit_loss = enter_value_gross - exit_value_gross - total_fees
         // cash += enter_value_gross + result_profit_loss + enter_fees_total
-        assert_eq!(updated_cash, 97.0 + 100.0 + (100.0 - 150.0 - 6.0) + 3.0);
+        assert_eq!(
+            updated_cash,
+            dec!(97) + dec!(100) + (dec!(100) - dec!(150) - dec!(6)) + dec!(3)
+        );
         // value += result_profit_loss
-        assert_eq!(updated_value, 200.0 + (100.0 - 150.0 - 6.0));
+        assert_eq!(updated_value, dec!(200) + (dec!(100) - dec!(150) - dec!(6)));
     }
 
     #[test]
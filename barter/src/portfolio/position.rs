@@ -7,7 +7,11 @@ use barter_data::event::{DataKind, MarketEvent};
 use barter_instrument::{exchange::ExchangeId, instrument::Instrument};
 use barter_integration::Side;
 use chrono::{DateTime, Utc};
-use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use smol_str::{format_smolstr, SmolStr};
 use std::convert::TryFrom;
@@ -23,7 +27,12 @@ pub trait PositionEnterer {
 pub trait PositionUpdater {
     /// Updates an open [`Position`] using the latest input [`MarketEvent`], returning a
     /// [`PositionUpdate`] that communicates the open [`Position`]'s change in state.
-    fn update(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<PositionUpdate>;
+    ///
+    /// Returns an error if the [`MarketEvent`] price cannot be represented as a [`Decimal`].
+    fn update(
+        &mut self,
+        market: &MarketEvent<Instrument, DataKind>,
+    ) -> Result<Option<PositionUpdate>, PortfolioError>;
 }
 
 /// Exits an open [`Position`].
@@ -46,9 +55,13 @@ pub fn determine_position_id(
 }
 
 /// Data encapsulating the state of an ongoing or closed [`Position`].
-#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+///
+/// Quantities, values & P&L are [`Decimal`] so that they sum exactly to those of exchange
+/// statements.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize, JsonSchema)]
 pub struct Position {
     /// Unique identifier for a [`Position`] generated from an engine_id, [`Exchange`] & [`Instrument`].
+    #[schemars(with = "String")]
     pub position_id: PositionId,
 
     /// Metadata detailing trace UUIDs, timestamps & equity associated with entering, updating & exiting.
@@ -68,43 +81,65 @@ pub struct Position {
     pub side: Side,
 
     /// +ve or -ve quantity of symbol contracts opened.
-    pub quantity: f64,
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
+    pub quantity: Decimal,
 
     /// All fees types incurred from entering a [`Position`], and their associated [`FeeAmount`].
     pub enter_fees: Fees,
 
     /// Total of enter_fees incurred. Sum of every [`FeeAmount`] in [`Fees`] when entering a [`Position`].
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
     pub enter_fees_total: FeeAmount,
 
     /// Enter average price excluding the entry_fees_total.
-    pub enter_avg_price_gross: f64,
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
+    pub enter_avg_price_gross: Decimal,
 
     /// abs(Quantity) * enter_avg_price_gross.
-    pub enter_value_gross: f64,
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
+    pub enter_value_gross: Decimal,
 
     /// All fees types incurred from exiting a [`Position`], and their associated [`FeeAmount`].
     pub exit_fees: Fees,
 
     /// Total of exit_fees incurred. Sum of every [`FeeAmount`] in [`Fees`] when entering a [`Position`].
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
     pub exit_fees_total: FeeAmount,
 
     /// Exit average price excluding the exit_fees_total.
-    pub exit_avg_price_gross: f64,
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
+    pub exit_avg_price_gross: Decimal,
 
     /// abs(Quantity) * exit_avg_price_gross.
-    pub exit_value_gross: f64,
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
+    pub exit_value_gross: Decimal,
 
     /// Symbol current close price.
-    pub current_symbol_price: f64,
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
+    pub current_symbol_price: Decimal,
 
     /// abs(Quantity) * current_symbol_price.
-    pub current_value_gross: f64,
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
+    pub current_value_gross: Decimal,
 
     /// Unrealised P&L whilst the [`Position`] is open.
-    pub unrealised_profit_loss: f64,
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
+    pub unrealised_profit_loss: Decimal,
 
     /// Realised P&L after the [`Position`] has closed.
-    pub realised_profit_loss: f64,
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
+    pub realised_profit_loss: Decimal,
 }
 
 impl PositionEnterer for Position {
@@ -123,7 +158,7 @@ impl PositionEnterer for Position {
         let enter_avg_price_gross = Position::calculate_avg_price_gross(fill);
 
         // Unreal profit & loss
-        let unrealised_profit_loss = -enter_fees_total * 2.0;
+        let unrealised_profit_loss = -enter_fees_total * Decimal::TWO;
 
         Ok(Position {
             position_id: determine_position_id(engine_id, &fill.exchange, &fill.instrument),
@@ -137,25 +172,30 @@ impl PositionEnterer for Position {
             enter_avg_price_gross,
             enter_value_gross: fill.fill_value_gross,
             exit_fees: Fees::default(),
-            exit_fees_total: 0.0,
-            exit_avg_price_gross: 0.0,
-            exit_value_gross: 0.0,
+            exit_fees_total: Decimal::ZERO,
+            exit_avg_price_gross: Decimal::ZERO,
+            exit_value_gross: Decimal::ZERO,
             current_symbol_price: enter_avg_price_gross,
             current_value_gross: fill.fill_value_gross,
             unrealised_profit_loss,
-            realised_profit_loss: 0.0,
+            realised_profit_loss: Decimal::ZERO,
         })
     }
 }
 
 impl PositionUpdater for Position {
-    fn update(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<PositionUpdate> {
+    fn update(
+        &mut self,
+        market: &MarketEvent<Instrument, DataKind>,
+    ) -> Result<Option<PositionUpdate>, PortfolioError> {
         // Determine close from MarketEvent
         let close = match &market.kind {
-            DataKind::Trade(trade) => trade.price,
-            DataKind::Candle(candle) => candle.close,
-            DataKind::OrderBookL1(book_l1) => book_l1.volume_weighed_mid_price().to_f64()?,
-            DataKind::OrderBook(_) | DataKind::Liquidation(_) => return None,
+            DataKind::Trade(trade) => to_decimal("trade.price", trade.price)?,
+            DataKind::Candle(candle) => to_decimal("candle.close", candle.close)?,
+            DataKind::OrderBookL1(book_l1) => book_l1.volume_weighed_mid_price(),
+            DataKind::OrderBook(_) | DataKind::Liquidation(_) | DataKind::Custom(_) => {
+                return Ok(None)
+            }
         };
 
         self.meta.update_time = market.time_exchange;
@@ -169,10 +209,15 @@ impl PositionUpdater for Position {
         self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
 
         // Return a PositionUpdate event that communicates the change in state
-        Some(PositionUpdate::from(self))
+        Ok(Some(PositionUpdate::from(self)))
     }
 }
 
+/// Convert the provided market `f64` into a [`Decimal`], failing if it is not finite.
+fn to_decimal(field: &'static str, value: f64) -> Result<Decimal, PortfolioError> {
+    Decimal::from_f64(value).ok_or(PortfolioError::NonDecimal { field, value })
+}
+
 impl PositionExiter for Position {
     fn exit(
         &mut self,
@@ -211,9 +256,12 @@ impl Position {
     }
 
     /// Calculates the [`Position::enter_avg_price_gross`] or [`Position::exit_avg_price_gross`] of
-    /// a [`FillEvent`].
-    pub fn calculate_avg_price_gross(fill: &FillEvent) -> f64 {
-        (fill.fill_value_gross / fill.quantity).abs()
+    /// a [`FillEvent`], or zero if the [`FillEvent`] has zero quantity.
+    pub fn calculate_avg_price_gross(fill: &FillEvent) -> Decimal {
+        fill.fill_value_gross
+            .checked_div(fill.quantity)
+            .unwrap_or_default()
+            .abs()
     }
 
     /// Determine the [`Position`] entry [`Side`] by analysing the input [`FillEvent`].
@@ -237,8 +285,8 @@ impl Position {
     }
 
     /// Calculate the approximate [`Position::unrealised_profit_loss`] of a [`Position`].
-    pub fn calculate_unrealised_profit_loss(&self) -> f64 {
-        let approx_total_fees = self.enter_fees_total * 2.0;
+    pub fn calculate_unrealised_profit_loss(&self) -> Decimal {
+        let approx_total_fees = self.enter_fees_total * Decimal::TWO;
 
         match self.side {
             Side::Buy => self.current_value_gross - self.enter_value_gross - approx_total_fees,
@@ -247,7 +295,7 @@ impl Position {
     }
 
     /// Calculate the exact [`Position::realised_profit_loss`] of a [`Position`].
-    pub fn calculate_realised_profit_loss(&self) -> f64 {
+    pub fn calculate_realised_profit_loss(&self) -> Decimal {
         let total_fees = self.enter_fees_total + self.exit_fees_total;
 
         match self.side {
@@ -256,10 +304,21 @@ impl Position {
         }
     }
 
+    /// Calculate the total exchange rebates earned entering & exiting a [`Position`].
+    pub fn calculate_rebates_total(&self) -> FeeAmount {
+        self.enter_fees.rebate() + self.exit_fees.rebate()
+    }
+
     /// Calculate the PnL return of a closed [`Position`] - assumed [`Position::realised_profit_loss`] is
     /// appropriately calculated.
+    ///
+    /// Returns are ratios used by the statistics, so are `f64`. Returns zero if the
+    /// [`Position::enter_value_gross`] is zero.
     pub fn calculate_profit_loss_return(&self) -> f64 {
-        self.realised_profit_loss / self.enter_value_gross
+        self.realised_profit_loss
+            .checked_div(self.enter_value_gross)
+            .and_then(|pnl_return| pnl_return.to_f64())
+            .unwrap_or_default()
     }
 }
 
@@ -271,19 +330,19 @@ pub struct PositionBuilder {
     pub instrument: Option<Instrument>,
     pub meta: Option<PositionMeta>,
     pub side: Option<Side>,
-    pub quantity: Option<f64>,
+    pub quantity: Option<Decimal>,
     pub enter_fees: Option<Fees>,
     pub enter_fees_total: Option<FeeAmount>,
-    pub enter_avg_price_gross: Option<f64>,
-    pub enter_value_gross: Option<f64>,
+    pub enter_avg_price_gross: Option<Decimal>,
+    pub enter_value_gross: Option<Decimal>,
     pub exit_fees: Option<Fees>,
     pub exit_fees_total: Option<FeeAmount>,
-    pub exit_avg_price_gross: Option<f64>,
-    pub exit_value_gross: Option<f64>,
-    pub current_symbol_price: Option<f64>,
-    pub current_value_gross: Option<f64>,
-    pub unrealised_profit_loss: Option<f64>,
-    pub realised_profit_loss: Option<f64>,
+    pub exit_avg_price_gross: Option<Decimal>,
+    pub exit_value_gross: Option<Decimal>,
+    pub current_symbol_price: Option<Decimal>,
+    pub current_value_gross: Option<Decimal>,
+    pub unrealised_profit_loss: Option<Decimal>,
+    pub realised_profit_loss: Option<Decimal>,
 }
 
 impl PositionBuilder {
@@ -326,7 +385,7 @@ impl PositionBuilder {
         }
     }
 
-    pub fn quantity(self, value: f64) -> Self {
+    pub fn quantity(self, value: Decimal) -> Self {
         Self {
             quantity: Some(value),
             ..self
@@ -347,14 +406,14 @@ impl PositionBuilder {
         }
     }
 
-    pub fn enter_avg_price_gross(self, value: f64) -> Self {
+    pub fn enter_avg_price_gross(self, value: Decimal) -> Self {
         Self {
             enter_avg_price_gross: Some(value),
             ..self
         }
     }
 
-    pub fn enter_value_gross(self, value: f64) -> Self {
+    pub fn enter_value_gross(self, value: Decimal) -> Self {
         Self {
             enter_value_gross: Some(value),
             ..self
@@ -375,42 +434,42 @@ impl PositionBuilder {
         }
     }
 
-    pub fn exit_avg_price_gross(self, value: f64) -> Self {
+    pub fn exit_avg_price_gross(self, value: Decimal) -> Self {
         Self {
             exit_avg_price_gross: Some(value),
             ..self
         }
     }
 
-    pub fn exit_value_gross(self, value: f64) -> Self {
+    pub fn exit_value_gross(self, value: Decimal) -> Self {
         Self {
             exit_value_gross: Some(value),
             ..self
         }
     }
 
-    pub fn current_symbol_price(self, value: f64) -> Self {
+    pub fn current_symbol_price(self, value: Decimal) -> Self {
         Self {
             current_symbol_price: Some(value),
             ..self
         }
     }
 
-    pub fn current_value_gross(self, value: f64) -> Self {
+    pub fn current_value_gross(self, value: Decimal) -> Self {
         Self {
             current_value_gross: Some(value),
             ..self
         }
     }
 
-    pub fn unrealised_profit_loss(self, value: f64) -> Self {
+    pub fn unrealised_profit_loss(self, value: Decimal) -> Self {
         Self {
             unrealised_profit_loss: Some(value),
             ..self
         }
     }
 
-    pub fn realised_profit_loss(self, value: f64) -> Self {
+    pub fn realised_profit_loss(self, value: Decimal) -> Self {
         Self {
             realised_profit_loss: Some(value),
             ..self
@@ -475,7 +534,7 @@ impl PositionBuilder {
 
 /// Metadata detailing the trace UUIDs & timestamps associated with entering, updating & exiting
 /// a [`Position`].
-#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize, JsonSchema)]
 pub struct PositionMeta {
     /// [`FillEvent`] timestamp that triggered the entering of this [`Position`].
     pub enter_time: DateTime<Utc>,
@@ -498,18 +557,25 @@ impl Default for PositionMeta {
 }
 
 /// [`Position`] update event. Occurs as a result of receiving new [`MarketEvent`] data.
-#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize, JsonSchema)]
 pub struct PositionUpdate {
     /// Unique identifier for a [`Position`], generated from an exchange, symbol, and enter_time.
+    #[schemars(with = "String")]
     pub position_id: SmolStr,
     /// Event timestamp of the last event to trigger a [`Position`] update.
     pub update_time: DateTime<Utc>,
     /// Symbol current close price.
-    pub current_symbol_price: f64,
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
+    pub current_symbol_price: Decimal,
     /// abs(Quantity) * current_symbol_price.
-    pub current_value_gross: f64,
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
+    pub current_value_gross: Decimal,
     /// Unrealised P&L whilst the [`Position`] is open.
-    pub unrealised_profit_loss: f64,
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
+    pub unrealised_profit_loss: Decimal,
 }
 
 impl From<&mut Position> for PositionUpdate {
@@ -525,9 +591,10 @@ impl From<&mut Position> for PositionUpdate {
 }
 
 /// [`Position`] exit event. Occurs as a result of a [`FillEvent`] that exits a [`Position`].
-#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize, JsonSchema)]
 pub struct PositionExit {
     /// Unique identifier for a [`Position`], generated from an exchange, symbol, and enter_time.
+    #[schemars(with = "String")]
     pub position_id: SmolStr,
 
     /// [`FillEvent`] timestamp that triggered the exiting of this [`Position`].
@@ -540,16 +607,24 @@ pub struct PositionExit {
     pub exit_fees: Fees,
 
     /// Total of exit_fees incurred. Sum of every [`FeeAmount`] in [`Fees`] when entering a [`Position`].
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
     pub exit_fees_total: FeeAmount,
 
     /// Exit average price excluding the exit_fees_total.
-    pub exit_avg_price_gross: f64,
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
+    pub exit_avg_price_gross: Decimal,
 
     /// abs(Quantity) * exit_avg_price_gross.
-    pub exit_value_gross: f64,
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
+    pub exit_value_gross: Decimal,
 
     /// Realised P&L after the [`Position`] has closed.
-    pub realised_profit_loss: f64,
+    #[serde(with = "rust_decimal::serde::str")]
+    #[schemars(with = "Decimal")]
+    pub realised_profit_loss: Decimal,
 }
 
 impl TryFrom<&mut Position> for PositionExit {
@@ -577,24 +652,25 @@ mod tests {
     use super::*;
     use crate::test_util::{fill_event, market_event_trade, position};
     use barter_integration::Side;
+    use rust_decimal_macros::dec;
 
     #[test]
     fn enter_new_position_with_long_decision_provided() {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = dec!(1);
+        input_fill.fill_value_gross = dec!(100);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
 
         let position = Position::enter(Uuid::new_v4(), &input_fill).unwrap();
 
         assert_eq!(position.side, Side::Buy);
         assert_eq!(position.quantity, input_fill.quantity);
-        assert_eq!(position.enter_fees_total, 3.0);
+        assert_eq!(position.enter_fees_total, dec!(3));
         assert_eq!(position.enter_fees.exchange, input_fill.fees.exchange);
         assert_eq!(position.enter_fees.slippage, input_fill.fees.slippage);
         assert_eq!(position.enter_fees.network, input_fill.fees.network);
@@ -603,35 +679,35 @@ mod tests {
             (input_fill.fill_value_gross / input_fill.quantity.abs())
         );
         assert_eq!(position.enter_value_gross, input_fill.fill_value_gross);
-        assert_eq!(position.exit_fees_total, 0.0);
-        assert_eq!(position.exit_avg_price_gross, 0.0);
-        assert_eq!(position.exit_value_gross, 0.0);
+        assert_eq!(position.exit_fees_total, dec!(0));
+        assert_eq!(position.exit_avg_price_gross, dec!(0));
+        assert_eq!(position.exit_value_gross, dec!(0));
         assert_eq!(
             position.current_symbol_price,
             (input_fill.fill_value_gross / input_fill.quantity.abs())
         );
         assert_eq!(position.current_value_gross, input_fill.fill_value_gross);
-        assert_eq!(position.unrealised_profit_loss, -6.0); // -2 * enter_fees_total
-        assert_eq!(position.realised_profit_loss, 0.0);
+        assert_eq!(position.unrealised_profit_loss, dec!(-6)); // -2 * enter_fees_total
+        assert_eq!(position.realised_profit_loss, dec!(0));
     }
 
     #[test]
     fn enter_new_position_with_short_decision_provided() {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = dec!(-1);
+        input_fill.fill_value_gross = dec!(100);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
 
         let position = Position::enter(Uuid::new_v4(), &input_fill).unwrap();
 
         assert_eq!(position.side, Side::Sell);
         assert_eq!(position.quantity, input_fill.quantity);
-        assert_eq!(position.enter_fees_total, 3.0);
+        assert_eq!(position.enter_fees_total, dec!(3));
         assert_eq!(position.enter_fees.exchange, input_fill.fees.exchange);
         assert_eq!(position.enter_fees.slippage, input_fill.fees.slippage);
         assert_eq!(position.enter_fees.network, input_fill.fees.network);
@@ -640,31 +716,31 @@ mod tests {
             (input_fill.fill_value_gross / input_fill.quantity.abs())
         );
         assert_eq!(position.enter_value_gross, input_fill.fill_value_gross);
-        assert_eq!(position.exit_fees_total, 0.0);
-        assert_eq!(position.exit_avg_price_gross, 0.0);
-        assert_eq!(position.exit_value_gross, 0.0);
+        assert_eq!(position.exit_fees_total, dec!(0));
+        assert_eq!(position.exit_avg_price_gross, dec!(0));
+        assert_eq!(position.exit_value_gross, dec!(0));
         assert_eq!(
             position.current_symbol_price,
             (input_fill.fill_value_gross / input_fill.quantity.abs())
         );
         assert_eq!(position.current_value_gross, input_fill.fill_value_gross);
-        assert_eq!(position.unrealised_profit_loss, -6.0); // -2 * enter_fees_total
-        assert_eq!(position.realised_profit_loss, 0.0);
+        assert_eq!(position.unrealised_profit_loss, dec!(-6)); // -2 * enter_fees_total
+        assert_eq!(position.realised_profit_loss, dec!(0));
     }
 
     #[test]
     fn enter_new_position_and_return_err_with_close_long_decision_provided() -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = dec!(-1);
+        input_fill.fill_value_gross = dec!(100);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
 
-        if let Err(_) = Position::enter(Uuid::new_v4(), &input_fill) {
+        if Position::enter(Uuid::new_v4(), &input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -678,15 +754,15 @@ mod tests {
     {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = dec!(1);
+        input_fill.fill_value_gross = dec!(100);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
 
-        if let Err(_) = Position::enter(Uuid::new_v4(), &input_fill) {
+        if Position::enter(Uuid::new_v4(), &input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -700,15 +776,15 @@ mod tests {
     ) -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
-        input_fill.quantity = -1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = dec!(-1);
+        input_fill.fill_value_gross = dec!(100);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
 
-        if let Err(_) = Position::enter(Uuid::new_v4(), &input_fill) {
+        if Position::enter(Uuid::new_v4(), &input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -722,15 +798,15 @@ mod tests {
     ) -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
-        input_fill.quantity = 1.0;
-        input_fill.fill_value_gross = 100.0;
+        input_fill.quantity = dec!(1);
+        input_fill.fill_value_gross = dec!(100);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
 
-        if let Err(_) = Position::enter(Uuid::new_v4(), &input_fill) {
+        if Position::enter(Uuid::new_v4(), &input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -744,18 +820,18 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Buy;
-        position.quantity = 1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = dec!(1);
+        position.enter_fees_total = dec!(3);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = dec!(100);
+        position.enter_value_gross = dec!(100);
+        position.current_symbol_price = dec!(100);
+        position.current_value_gross = dec!(100);
+        position.unrealised_profit_loss = position.enter_fees_total * -dec!(2);
 
         // Input MarketEvent
         let mut input_market = market_event_trade(Side::Buy);
@@ -767,24 +843,20 @@ mod tests {
         };
 
         // Update Position
-        position.update(&input_market);
+        position.update(&input_market).unwrap();
 
         // Assert update hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Buy);
-        assert_eq!(position.quantity, 1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, dec!(1));
+        assert_eq!(position.enter_fees_total, dec!(3));
+        assert_eq!(position.enter_fees.exchange, dec!(1));
+        assert_eq!(position.enter_fees.slippage, dec!(1));
+        assert_eq!(position.enter_fees.network, dec!(1));
+        assert_eq!(position.enter_avg_price_gross, dec!(100));
+        assert_eq!(position.enter_value_gross, dec!(100));
 
         // Assert updated fields are correct
-        let close = match &input_market.kind {
-            DataKind::Trade(trade) => trade.price,
-            DataKind::Candle(candle) => candle.close,
-            _ => todo!(),
-        };
+        let close = dec!(200);
         assert_eq!(position.current_symbol_price, close);
         assert_eq!(
             position.current_value_gross,
@@ -792,7 +864,10 @@ mod tests {
         );
 
         // current_value_gross - enter_value_gross - approx_total_fees
-        assert_eq!(position.unrealised_profit_loss, (200.0 - 100.0 - 6.0));
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (dec!(200) - dec!(100) - dec!(6))
+        );
     }
 
     #[test]
@@ -800,18 +875,18 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Buy;
-        position.quantity = 1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = dec!(1);
+        position.enter_fees_total = dec!(3);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = dec!(100);
+        position.enter_value_gross = dec!(100);
+        position.current_symbol_price = dec!(100);
+        position.current_value_gross = dec!(100);
+        position.unrealised_profit_loss = position.enter_fees_total * -dec!(2);
 
         // Input MarketEvent
         let mut input_market = market_event_trade(Side::Sell);
@@ -824,24 +899,20 @@ mod tests {
         };
 
         // Update Position
-        position.update(&input_market);
+        position.update(&input_market).unwrap();
 
         // Assert update hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Buy);
-        assert_eq!(position.quantity, 1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, dec!(1));
+        assert_eq!(position.enter_fees_total, dec!(3));
+        assert_eq!(position.enter_fees.exchange, dec!(1));
+        assert_eq!(position.enter_fees.slippage, dec!(1));
+        assert_eq!(position.enter_fees.network, dec!(1));
+        assert_eq!(position.enter_avg_price_gross, dec!(100));
+        assert_eq!(position.enter_value_gross, dec!(100));
 
         // Assert updated fields are correct
-        let close = match &input_market.kind {
-            DataKind::Trade(trade) => trade.price,
-            DataKind::Candle(candle) => candle.close,
-            _ => todo!(),
-        };
+        let close = dec!(50);
         assert_eq!(position.current_symbol_price, close);
         assert_eq!(
             position.current_value_gross,
@@ -849,7 +920,10 @@ mod tests {
         );
 
         // current_value_gross - enter_value_gross - approx_total_fees
-        assert_eq!(position.unrealised_profit_loss, (50.0 - 100.0 - 6.0));
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (dec!(50) - dec!(100) - dec!(6))
+        );
     }
 
     #[test]
@@ -857,18 +931,18 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = dec!(-1);
+        position.enter_fees_total = dec!(3);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = dec!(100);
+        position.enter_value_gross = dec!(100);
+        position.current_symbol_price = dec!(100);
+        position.current_value_gross = dec!(100);
+        position.unrealised_profit_loss = position.enter_fees_total * -dec!(2);
 
         // Input MarketEvent
         let mut input_market = market_event_trade(Side::Buy);
@@ -881,24 +955,20 @@ mod tests {
         };
 
         // Update Position
-        position.update(&input_market);
+        position.update(&input_market).unwrap();
 
         // Assert update hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Sell);
-        assert_eq!(position.quantity, -1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, dec!(-1));
+        assert_eq!(position.enter_fees_total, dec!(3));
+        assert_eq!(position.enter_fees.exchange, dec!(1));
+        assert_eq!(position.enter_fees.slippage, dec!(1));
+        assert_eq!(position.enter_fees.network, dec!(1));
+        assert_eq!(position.enter_avg_price_gross, dec!(100));
+        assert_eq!(position.enter_value_gross, dec!(100));
 
         // Assert updated fields are correct
-        let close = match &input_market.kind {
-            DataKind::Trade(trade) => trade.price,
-            DataKind::Candle(candle) => candle.close,
-            _ => todo!(),
-        };
+        let close = dec!(50);
         assert_eq!(position.current_symbol_price, close);
         assert_eq!(
             position.current_value_gross,
@@ -906,7 +976,10 @@ mod tests {
         );
 
         // enter_value_gross - current_value_gross - approx_total_fees
-        assert_eq!(position.unrealised_profit_loss, (100.0 - 50.0 - 6.0));
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (dec!(100) - dec!(50) - dec!(6))
+        );
     }
 
     #[test]
@@ -914,18 +987,18 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = dec!(-1);
+        position.enter_fees_total = dec!(3);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = dec!(100);
+        position.enter_value_gross = dec!(100);
+        position.current_symbol_price = dec!(100);
+        position.current_value_gross = dec!(100);
+        position.unrealised_profit_loss = position.enter_fees_total * -dec!(2);
 
         // Input MarketEvent
         let mut input_market = market_event_trade(Side::Sell);
@@ -938,24 +1011,20 @@ mod tests {
         };
 
         // Update Position
-        position.update(&input_market);
+        position.update(&input_market).unwrap();
 
         // Assert update hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Sell);
-        assert_eq!(position.quantity, -1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, dec!(-1));
+        assert_eq!(position.enter_fees_total, dec!(3));
+        assert_eq!(position.enter_fees.exchange, dec!(1));
+        assert_eq!(position.enter_fees.slippage, dec!(1));
+        assert_eq!(position.enter_fees.network, dec!(1));
+        assert_eq!(position.enter_avg_price_gross, dec!(100));
+        assert_eq!(position.enter_value_gross, dec!(100));
 
         // Assert updated fields are correct
-        let close = match &input_market.kind {
-            DataKind::Trade(trade) => trade.price,
-            DataKind::Candle(candle) => candle.close,
-            _ => todo!(),
-        };
+        let close = dec!(200);
         assert_eq!(position.current_symbol_price, close);
         assert_eq!(
             position.current_value_gross,
@@ -963,7 +1032,31 @@ mod tests {
         );
 
         // enter_value_gross - current_value_gross - approx_total_fees
-        assert_eq!(position.unrealised_profit_loss, (100.0 - 200.0 - 6.0));
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (dec!(100) - dec!(200) - dec!(6))
+        );
+    }
+
+    #[test]
+    fn update_position_with_non_finite_price_and_return_err() {
+        let mut position = position();
+        position.current_symbol_price = dec!(100);
+
+        let mut input_market = market_event_trade(Side::Buy);
+        match input_market.kind {
+            DataKind::Trade(ref mut trade) => trade.price = f64::NAN,
+            _ => todo!(),
+        };
+
+        assert!(matches!(
+            position.update(&input_market),
+            Err(PortfolioError::NonDecimal {
+                field: "trade.price",
+                ..
+            })
+        ));
+        assert_eq!(position.current_symbol_price, dec!(100));
     }
 
     #[test]
@@ -971,35 +1064,35 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Buy;
-        position.quantity = 1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = dec!(1);
+        position.enter_fees_total = dec!(3);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = dec!(100);
+        position.enter_value_gross = dec!(100);
+        position.current_symbol_price = dec!(100);
+        position.current_value_gross = dec!(100);
+        position.unrealised_profit_loss = position.enter_fees_total * -dec!(2);
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: dec!(10000),
+            available: dec!(10000),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
         input_fill.quantity = -position.quantity;
-        input_fill.fill_value_gross = 200.0;
+        input_fill.fill_value_gross = dec!(200);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
 
         // Exit Position
@@ -1007,19 +1100,19 @@ mod tests {
 
         // Assert exit hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Buy);
-        assert_eq!(position.quantity, 1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, dec!(1));
+        assert_eq!(position.enter_fees_total, dec!(3));
+        assert_eq!(position.enter_fees.exchange, dec!(1));
+        assert_eq!(position.enter_fees.slippage, dec!(1));
+        assert_eq!(position.enter_fees.network, dec!(1));
+        assert_eq!(position.enter_avg_price_gross, dec!(100));
+        assert_eq!(position.enter_value_gross, dec!(100));
 
         // Assert fields changed by exit are correct
-        assert_eq!(position.exit_fees_total, 3.0);
-        assert_eq!(position.exit_fees.exchange, 1.0);
-        assert_eq!(position.exit_fees.slippage, 1.0);
-        assert_eq!(position.exit_fees.network, 1.0);
+        assert_eq!(position.exit_fees_total, dec!(3));
+        assert_eq!(position.exit_fees.exchange, dec!(1));
+        assert_eq!(position.exit_fees.slippage, dec!(1));
+        assert_eq!(position.exit_fees.network, dec!(1));
         assert_eq!(position.exit_value_gross, input_fill.fill_value_gross);
         assert_eq!(
             position.exit_avg_price_gross,
@@ -1027,13 +1120,19 @@ mod tests {
         );
 
         // exit_value_gross - enter_value_gross - total_fees
-        assert_eq!(position.realised_profit_loss, (200.0 - 100.0 - 6.0));
-        assert_eq!(position.unrealised_profit_loss, (200.0 - 100.0 - 6.0));
+        assert_eq!(
+            position.realised_profit_loss,
+            (dec!(200) - dec!(100) - dec!(6))
+        );
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (dec!(200) - dec!(100) - dec!(6))
+        );
 
         // Assert EquityPoint on Exit is correct
         assert_eq!(
             position.meta.exit_balance.unwrap().total,
-            current_balance.total + (200.0 - 100.0 - 6.0)
+            current_balance.total + (dec!(200) - dec!(100) - dec!(6))
         )
     }
 
@@ -1042,35 +1141,35 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Buy;
-        position.quantity = 1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = dec!(1);
+        position.enter_fees_total = dec!(3);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = dec!(100);
+        position.enter_value_gross = dec!(100);
+        position.current_symbol_price = dec!(100);
+        position.current_value_gross = dec!(100);
+        position.unrealised_profit_loss = position.enter_fees_total * -dec!(2);
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: dec!(10000),
+            available: dec!(10000),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
         input_fill.quantity = -position.quantity;
-        input_fill.fill_value_gross = 50.0;
+        input_fill.fill_value_gross = dec!(50);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
 
         // Exit Position
@@ -1078,19 +1177,19 @@ mod tests {
 
         // Assert exit hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Buy);
-        assert_eq!(position.quantity, 1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, dec!(1));
+        assert_eq!(position.enter_fees_total, dec!(3));
+        assert_eq!(position.enter_fees.exchange, dec!(1));
+        assert_eq!(position.enter_fees.slippage, dec!(1));
+        assert_eq!(position.enter_fees.network, dec!(1));
+        assert_eq!(position.enter_avg_price_gross, dec!(100));
+        assert_eq!(position.enter_value_gross, dec!(100));
 
         // Assert fields changed by exit are correct
-        assert_eq!(position.exit_fees_total, 3.0);
-        assert_eq!(position.exit_fees.exchange, 1.0);
-        assert_eq!(position.exit_fees.slippage, 1.0);
-        assert_eq!(position.exit_fees.network, 1.0);
+        assert_eq!(position.exit_fees_total, dec!(3));
+        assert_eq!(position.exit_fees.exchange, dec!(1));
+        assert_eq!(position.exit_fees.slippage, dec!(1));
+        assert_eq!(position.exit_fees.network, dec!(1));
         assert_eq!(position.exit_value_gross, input_fill.fill_value_gross);
         assert_eq!(
             position.exit_avg_price_gross,
@@ -1098,13 +1197,19 @@ mod tests {
         );
 
         // exit_value_gross - enter_value_gross - total_fees
-        assert_eq!(position.realised_profit_loss, (50.0 - 100.0 - 6.0));
-        assert_eq!(position.unrealised_profit_loss, (50.0 - 100.0 - 6.0));
+        assert_eq!(
+            position.realised_profit_loss,
+            (dec!(50) - dec!(100) - dec!(6))
+        );
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (dec!(50) - dec!(100) - dec!(6))
+        );
 
         // Assert EquityPoint on Exit is correct
         assert_eq!(
             position.meta.exit_balance.unwrap().total,
-            current_balance.total + (50.0 - 100.0 - 6.0)
+            current_balance.total + (dec!(50) - dec!(100) - dec!(6))
         )
     }
 
@@ -1113,35 +1218,35 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = dec!(-1);
+        position.enter_fees_total = dec!(3);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = dec!(100);
+        position.enter_value_gross = dec!(100);
+        position.current_symbol_price = dec!(100);
+        position.current_value_gross = dec!(100);
+        position.unrealised_profit_loss = position.enter_fees_total * -dec!(2);
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: dec!(10000),
+            available: dec!(10000),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
         input_fill.quantity = -position.quantity;
-        input_fill.fill_value_gross = 50.0;
+        input_fill.fill_value_gross = dec!(50);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
 
         // Exit Position
@@ -1149,19 +1254,19 @@ mod tests {
 
         // Assert exit hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Sell);
-        assert_eq!(position.quantity, -1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, dec!(-1));
+        assert_eq!(position.enter_fees_total, dec!(3));
+        assert_eq!(position.enter_fees.exchange, dec!(1));
+        assert_eq!(position.enter_fees.slippage, dec!(1));
+        assert_eq!(position.enter_fees.network, dec!(1));
+        assert_eq!(position.enter_avg_price_gross, dec!(100));
+        assert_eq!(position.enter_value_gross, dec!(100));
 
         // Assert fields changed by exit are correct
-        assert_eq!(position.exit_fees_total, 3.0);
-        assert_eq!(position.exit_fees.exchange, 1.0);
-        assert_eq!(position.exit_fees.slippage, 1.0);
-        assert_eq!(position.exit_fees.network, 1.0);
+        assert_eq!(position.exit_fees_total, dec!(3));
+        assert_eq!(position.exit_fees.exchange, dec!(1));
+        assert_eq!(position.exit_fees.slippage, dec!(1));
+        assert_eq!(position.exit_fees.network, dec!(1));
         assert_eq!(position.exit_value_gross, input_fill.fill_value_gross);
         assert_eq!(
             position.exit_avg_price_gross,
@@ -1169,13 +1274,19 @@ mod tests {
         );
 
         // enter_value_gross - current_value_gross - approx_total_fees
-        assert_eq!(position.realised_profit_loss, (100.0 - 50.0 - 6.0));
-        assert_eq!(position.unrealised_profit_loss, (100.0 - 50.0 - 6.0));
+        assert_eq!(
+            position.realised_profit_loss,
+            (dec!(100) - dec!(50) - dec!(6))
+        );
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (dec!(100) - dec!(50) - dec!(6))
+        );
 
         // Assert EquityPoint on Exit is correct
         assert_eq!(
             position.meta.exit_balance.unwrap().total,
-            current_balance.total + (100.0 - 50.0 - 6.0)
+            current_balance.total + (dec!(100) - dec!(50) - dec!(6))
         )
     }
 
@@ -1184,35 +1295,35 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = dec!(-1);
+        position.enter_fees_total = dec!(3);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = dec!(100);
+        position.enter_value_gross = dec!(100);
+        position.current_symbol_price = dec!(100);
+        position.current_value_gross = dec!(100);
+        position.unrealised_profit_loss = position.enter_fees_total * -dec!(2);
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: dec!(10000),
+            available: dec!(10000),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
         input_fill.quantity = -position.quantity;
-        input_fill.fill_value_gross = 200.0;
+        input_fill.fill_value_gross = dec!(200);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
 
         // Exit Position
@@ -1220,19 +1331,19 @@ mod tests {
 
         // Assert exit hasn't changed fields that are constant after creation
         assert_eq!(position.side, Side::Sell);
-        assert_eq!(position.quantity, -1.0);
-        assert_eq!(position.enter_fees_total, 3.0);
-        assert_eq!(position.enter_fees.exchange, 1.0);
-        assert_eq!(position.enter_fees.slippage, 1.0);
-        assert_eq!(position.enter_fees.network, 1.0);
-        assert_eq!(position.enter_avg_price_gross, 100.0);
-        assert_eq!(position.enter_value_gross, 100.0);
+        assert_eq!(position.quantity, dec!(-1));
+        assert_eq!(position.enter_fees_total, dec!(3));
+        assert_eq!(position.enter_fees.exchange, dec!(1));
+        assert_eq!(position.enter_fees.slippage, dec!(1));
+        assert_eq!(position.enter_fees.network, dec!(1));
+        assert_eq!(position.enter_avg_price_gross, dec!(100));
+        assert_eq!(position.enter_value_gross, dec!(100));
 
         // Assert fields changed by exit are correct
-        assert_eq!(position.exit_fees_total, 3.0);
-        assert_eq!(position.exit_fees.exchange, 1.0);
-        assert_eq!(position.exit_fees.slippage, 1.0);
-        assert_eq!(position.exit_fees.network, 1.0);
+        assert_eq!(position.exit_fees_total, dec!(3));
+        assert_eq!(position.exit_fees.exchange, dec!(1));
+        assert_eq!(position.exit_fees.slippage, dec!(1));
+        assert_eq!(position.exit_fees.network, dec!(1));
         assert_eq!(position.exit_value_gross, input_fill.fill_value_gross);
         assert_eq!(
             position.exit_avg_price_gross,
@@ -1240,13 +1351,19 @@ mod tests {
         );
 
         // enter_value_gross - current_value_gross - approx_total_fees
-        assert_eq!(position.realised_profit_loss, (100.0 - 200.0 - 6.0));
-        assert_eq!(position.unrealised_profit_loss, (100.0 - 200.0 - 6.0));
+        assert_eq!(
+            position.realised_profit_loss,
+            (dec!(100) - dec!(200) - dec!(6))
+        );
+        assert_eq!(
+            position.unrealised_profit_loss,
+            (dec!(100) - dec!(200) - dec!(6))
+        );
 
         // Assert EquityPoint on Exit is correct
         assert_eq!(
             position.meta.exit_balance.unwrap().total,
-            current_balance.total + (100.0 - 200.0 - 6.0)
+            current_balance.total + (dec!(100) - dec!(200) - dec!(6))
         )
     }
 
@@ -1255,39 +1372,39 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = dec!(-1);
+        position.enter_fees_total = dec!(3);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = dec!(100);
+        position.enter_value_gross = dec!(100);
+        position.current_symbol_price = dec!(100);
+        position.current_value_gross = dec!(100);
+        position.unrealised_profit_loss = position.enter_fees_total * -dec!(2);
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: dec!(10000),
+            available: dec!(10000),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
         input_fill.quantity = position.quantity;
-        input_fill.fill_value_gross = 200.0;
+        input_fill.fill_value_gross = dec!(200);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
 
         // Exit Position
-        if let Err(_) = position.exit(current_balance, &input_fill) {
+        if position.exit(current_balance, &input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -1301,39 +1418,39 @@ mod tests {
         // Initial Position
         let mut position = position();
         position.side = Side::Sell;
-        position.quantity = -1.0;
-        position.enter_fees_total = 3.0;
+        position.quantity = dec!(-1);
+        position.enter_fees_total = dec!(3);
         position.enter_fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
-        position.enter_avg_price_gross = 100.0;
-        position.enter_value_gross = 100.0;
-        position.current_symbol_price = 100.0;
-        position.current_value_gross = 100.0;
-        position.unrealised_profit_loss = position.enter_fees_total * -2.0;
+        position.enter_avg_price_gross = dec!(100);
+        position.enter_value_gross = dec!(100);
+        position.current_symbol_price = dec!(100);
+        position.current_value_gross = dec!(100);
+        position.unrealised_profit_loss = position.enter_fees_total * -dec!(2);
 
         // Input Portfolio Current Balance
         let current_balance = Balance {
             time: Utc::now(),
-            total: 10000.0,
-            available: 10000.0,
+            total: dec!(10000),
+            available: dec!(10000),
         };
 
         // Input FillEvent
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
         input_fill.quantity = -position.quantity;
-        input_fill.fill_value_gross = 200.0;
+        input_fill.fill_value_gross = dec!(200);
         input_fill.fees = Fees {
-            exchange: 1.0,
-            slippage: 1.0,
-            network: 1.0,
+            exchange: dec!(1),
+            slippage: dec!(1),
+            network: dec!(1),
         };
 
         // Exit Position
-        if let Err(_) = position.exit(current_balance, &input_fill) {
+        if position.exit(current_balance, &input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -1345,30 +1462,30 @@ mod tests {
     #[test]
     fn calculate_avg_price_gross_correctly_with_positive_quantity() {
         let mut input_fill = fill_event();
-        input_fill.fill_value_gross = 1000.0;
-        input_fill.quantity = 1.0;
+        input_fill.fill_value_gross = dec!(1000);
+        input_fill.quantity = dec!(1);
 
         let actual = Position::calculate_avg_price_gross(&input_fill);
 
-        assert_eq!(actual, 1000.0)
+        assert_eq!(actual, dec!(1000))
     }
 
     #[test]
     fn calculate_avg_price_gross_correctly_with_negative_quantity() {
         let mut input_fill = fill_event();
-        input_fill.fill_value_gross = 1000.0;
-        input_fill.quantity = -1.0;
+        input_fill.fill_value_gross = dec!(1000);
+        input_fill.quantity = dec!(-1);
 
         let actual = Position::calculate_avg_price_gross(&input_fill);
 
-        assert_eq!(actual, 1000.0)
+        assert_eq!(actual, dec!(1000))
     }
 
     #[test]
     fn parse_entry_side_as_long_with_positive_quantity_long_decision_provided() {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
-        input_fill.quantity = 1.0;
+        input_fill.quantity = dec!(1);
 
         let actual = Position::parse_entry_side(&input_fill).unwrap();
 
@@ -1379,7 +1496,7 @@ mod tests {
     fn parse_entry_side_as_short_with_negative_quantity_short_decision_provided() {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
-        input_fill.quantity = -1.0;
+        input_fill.quantity = dec!(-1);
 
         let actual = Position::parse_entry_side(&input_fill).unwrap();
 
@@ -1390,9 +1507,9 @@ mod tests {
     fn parse_entry_side_and_return_err_with_close_long_decision_provided() -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseLong;
-        input_fill.quantity = -1.0;
+        input_fill.quantity = dec!(-1);
 
-        if let Err(_) = Position::parse_entry_side(&input_fill) {
+        if Position::parse_entry_side(&input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -1405,9 +1522,9 @@ mod tests {
     fn parse_entry_side_and_return_err_with_close_short_decision_provided() -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::CloseShort;
-        input_fill.quantity = 1.0;
+        input_fill.quantity = dec!(1);
 
-        if let Err(_) = Position::parse_entry_side(&input_fill) {
+        if Position::parse_entry_side(&input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -1421,9 +1538,9 @@ mod tests {
     ) -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Long;
-        input_fill.quantity = -1.0;
+        input_fill.quantity = dec!(-1);
 
-        if let Err(_) = Position::parse_entry_side(&input_fill) {
+        if Position::parse_entry_side(&input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -1437,9 +1554,9 @@ mod tests {
     ) -> Result<(), String> {
         let mut input_fill = fill_event();
         input_fill.decision = Decision::Short;
-        input_fill.quantity = 1.0;
+        input_fill.quantity = dec!(1);
 
-        if let Err(_) = Position::parse_entry_side(&input_fill) {
+        if Position::parse_entry_side(&input_fill).is_err() {
             Ok(())
         } else {
             Err(String::from(
@@ -1452,33 +1569,33 @@ mod tests {
     fn calculate_unreal_profit_loss() {
         let mut long_win = position(); // Expected PnL = +8.0
         long_win.side = Side::Buy;
-        long_win.enter_value_gross = 100.0;
-        long_win.enter_fees_total = 1.0;
-        long_win.current_value_gross = 110.0;
+        long_win.enter_value_gross = dec!(100);
+        long_win.enter_fees_total = dec!(1);
+        long_win.current_value_gross = dec!(110);
 
         let mut long_lose = position(); // Expected PnL = -12.0
         long_lose.side = Side::Buy;
-        long_lose.enter_value_gross = 100.0;
-        long_lose.enter_fees_total = 1.0;
-        long_lose.current_value_gross = 90.0;
+        long_lose.enter_value_gross = dec!(100);
+        long_lose.enter_fees_total = dec!(1);
+        long_lose.current_value_gross = dec!(90);
 
         let mut short_win = position(); // Expected PnL = +8.0
         short_win.side = Side::Sell;
-        short_win.enter_value_gross = 100.0;
-        short_win.enter_fees_total = 1.0;
-        short_win.current_value_gross = 90.0;
+        short_win.enter_value_gross = dec!(100);
+        short_win.enter_fees_total = dec!(1);
+        short_win.current_value_gross = dec!(90);
 
         let mut short_lose = position(); // Expected PnL = -12.0
         short_lose.side = Side::Sell;
-        short_lose.enter_value_gross = 100.0;
-        short_lose.enter_fees_total = 1.0;
-        short_lose.current_value_gross = 110.0;
+        short_lose.enter_value_gross = dec!(100);
+        short_lose.enter_fees_total = dec!(1);
+        short_lose.current_value_gross = dec!(110);
 
         let inputs = vec![long_win, long_lose, short_win, short_lose];
 
-        let expected_pnl = vec![8.0, -12.0, 8.0, -12.0];
+        let expected_pnl = vec![dec!(8), dec!(-12), dec!(8), dec!(-12)];
 
-        for (position, expected) in inputs.into_iter().zip(expected_pnl.into_iter()) {
+        for (position, expected) in inputs.into_iter().zip(expected_pnl) {
             let actual = position.calculate_unrealised_profit_loss();
             assert_eq!(actual, expected);
         }
@@ -1488,37 +1605,37 @@ mod tests {
     fn calculate_realised_profit_loss() {
         let mut long_win = position(); // Expected PnL = +18.0
         long_win.side = Side::Buy;
-        long_win.enter_value_gross = 100.0;
-        long_win.enter_fees_total = 1.0;
-        long_win.exit_value_gross = 120.0;
-        long_win.exit_fees_total = 1.0;
+        long_win.enter_value_gross = dec!(100);
+        long_win.enter_fees_total = dec!(1);
+        long_win.exit_value_gross = dec!(120);
+        long_win.exit_fees_total = dec!(1);
 
         let mut long_lose = position(); // Expected PnL = -22.0
         long_lose.side = Side::Buy;
-        long_lose.enter_value_gross = 100.0;
-        long_lose.enter_fees_total = 1.0;
-        long_lose.exit_value_gross = 80.0;
-        long_lose.exit_fees_total = 1.0;
+        long_lose.enter_value_gross = dec!(100);
+        long_lose.enter_fees_total = dec!(1);
+        long_lose.exit_value_gross = dec!(80);
+        long_lose.exit_fees_total = dec!(1);
 
         let mut short_win = position(); // Expected PnL = +18.0
         short_win.side = Side::Sell;
-        short_win.enter_value_gross = 100.0;
-        short_win.enter_fees_total = 1.0;
-        short_win.exit_value_gross = 80.0;
-        short_win.exit_fees_total = 1.0;
+        short_win.enter_value_gross = dec!(100);
+        short_win.enter_fees_total = dec!(1);
+        short_win.exit_value_gross = dec!(80);
+        short_win.exit_fees_total = dec!(1);
 
         let mut short_lose = position(); // Expected PnL = -22.0
         short_lose.side = Side::Sell;
-        short_lose.enter_value_gross = 100.0;
-        short_lose.enter_fees_total = 1.0;
-        short_lose.exit_value_gross = 120.0;
-        short_lose.exit_fees_total = 1.0;
+        short_lose.enter_value_gross = dec!(100);
+        short_lose.enter_fees_total = dec!(1);
+        short_lose.exit_value_gross = dec!(120);
+        short_lose.exit_fees_total = dec!(1);
 
         let inputs = vec![long_win, long_lose, short_win, short_lose];
 
-        let expected_pnl = vec![18.0, -22.0, 18.0, -22.0];
+        let expected_pnl = vec![dec!(18), dec!(-22), dec!(18), dec!(-22)];
 
-        for (position, expected) in inputs.into_iter().zip(expected_pnl.into_iter()) {
+        for (position, expected) in inputs.into_iter().zip(expected_pnl) {
             let actual = position.calculate_realised_profit_loss();
             assert_eq!(actual, expected);
         }
@@ -1528,29 +1645,29 @@ mod tests {
     fn calculate_profit_loss_return() {
         let mut long_win = position(); // Expected Return = 0.08
         long_win.side = Side::Buy;
-        long_win.enter_value_gross = 100.0;
-        long_win.realised_profit_loss = 8.0;
+        long_win.enter_value_gross = dec!(100);
+        long_win.realised_profit_loss = dec!(8);
 
         let mut long_lose = position(); // Expected Return = -0.12
         long_lose.side = Side::Buy;
-        long_lose.enter_value_gross = 100.0;
-        long_lose.realised_profit_loss = -12.0;
+        long_lose.enter_value_gross = dec!(100);
+        long_lose.realised_profit_loss = dec!(-12);
 
         let mut short_win = position(); // Expected Return = 0.08
         short_win.side = Side::Sell;
-        short_win.enter_value_gross = 100.0;
-        short_win.realised_profit_loss = 8.0;
+        short_win.enter_value_gross = dec!(100);
+        short_win.realised_profit_loss = dec!(8);
 
         let mut short_lose = position(); // Expected Return = -0.12
         short_lose.side = Side::Sell;
-        short_lose.enter_value_gross = 100.0;
-        short_lose.realised_profit_loss = -12.0;
+        short_lose.enter_value_gross = dec!(100);
+        short_lose.realised_profit_loss = dec!(-12);
 
         let inputs = vec![long_win, long_lose, short_win, short_lose];
 
         let expected_return = vec![0.08, -0.12, 0.08, -0.12];
 
-        for (position, expected) in inputs.into_iter().zip(expected_return.into_iter()) {
+        for (position, expected) in inputs.into_iter().zip(expected_return) {
             let actual = position.calculate_profit_loss_return();
             assert_eq!(actual, expected);
         }
@@ -1571,9 +1688,9 @@ mod tests {
     #[test]
     fn position_update_from_position() {
         let mut input_position = position();
-        input_position.current_symbol_price = 100.0;
-        input_position.current_value_gross = 200.0;
-        input_position.unrealised_profit_loss = 150.0;
+        input_position.current_symbol_price = dec!(100);
+        input_position.current_value_gross = dec!(200);
+        input_position.unrealised_profit_loss = dec!(150);
 
         let actual_update = PositionUpdate::from(&mut input_position);
 
@@ -1599,19 +1716,19 @@ mod tests {
         exited_position.meta.update_time = time;
         exited_position.meta.exit_balance = Some(Balance {
             time,
-            total: 0.0,
-            available: 0.0,
+            total: dec!(0),
+            available: dec!(0),
         });
 
         exited_position.exit_fees = Fees {
-            exchange: 0.0,
-            slippage: 0.0,
-            network: 0.0,
+            exchange: dec!(0),
+            slippage: dec!(0),
+            network: dec!(0),
         };
-        exited_position.exit_fees_total = 0.0;
-        exited_position.exit_avg_price_gross = 100.0;
-        exited_position.exit_value_gross = 100.0;
-        exited_position.realised_profit_loss = 100.0;
+        exited_position.exit_fees_total = dec!(0);
+        exited_position.exit_avg_price_gross = dec!(100);
+        exited_position.exit_value_gross = dec!(100);
+        exited_position.realised_profit_loss = dec!(100);
 
         let actual_exit = PositionExit::try_from(&mut exited_position).unwrap();
 
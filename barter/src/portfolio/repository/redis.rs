@@ -85,7 +85,7 @@ where
         let position = self.get_open_position(position_id)?;
 
         self.conn
-            .del(position_id.as_str())
+            .del::<_, ()>(position_id.as_str())
             .map_err(|_| RepositoryError::DeleteError)?;
 
         Ok(position)
@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-use crate::portfolio::{OrderEvent, OrderType};
+use crate::portfolio::{error::PortfolioError, OrderEvent, OrderType};
+use barter_instrument::instrument::{kind::InstrumentKind, Instrument};
+use rust_decimal::prelude::ToPrimitive;
+use std::collections::HashMap;
+use tracing::warn;
 
 /// Evaluates the risk associated with an [`OrderEvent`] to determine if it should be actioned. It
 /// can also amend the order (eg/ [`OrderType`]) to better fit the risk strategy required for
@@ -34,3 +38,202 @@ impl DefaultRisk {
         false
     }
 }
+
+/// Configuration of a [`LiquidationRisk`] check.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct LiquidationRiskConfig {
+    /// Leverage used for any [`Instrument`] without a specific leverage.
+    pub default_leverage: f64,
+    /// [`Instrument`] specific leverage (eg/ as configured via the execution layer).
+    #[serde(default)]
+    pub leverage: HashMap<Instrument, f64>,
+    /// Maintenance margin rate of the exchange (eg/ 0.005 for 0.5%).
+    pub maintenance_margin_rate: f64,
+    /// Minimum distance between the estimated liquidation price & the mark price, as a fraction
+    /// of the mark price (eg/ 0.1 for 10%).
+    pub min_distance: f64,
+}
+
+impl LiquidationRiskConfig {
+    /// [`LiquidationRiskConfig`] that never refuses an [`OrderEvent`].
+    pub fn disabled() -> Self {
+        Self {
+            default_leverage: 1.0,
+            leverage: HashMap::new(),
+            maintenance_margin_rate: 0.0,
+            min_distance: 0.0,
+        }
+    }
+}
+
+/// Risk rule that refuses derivative entry [`OrderEvent`]s whose estimated liquidation price
+/// would be within [`LiquidationRiskConfig::min_distance`] of the current mark price, before
+/// delegating to the wrapped [`OrderEvaluator`].
+///
+/// The mark price is the latest market price propagated in the [`OrderEvent`]
+/// [`MarketMeta`](crate::data::MarketMeta), and liquidation prices are estimated for an isolated
+/// margin position entered at that price.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct LiquidationRisk<Risk> {
+    pub config: LiquidationRiskConfig,
+    pub risk: Risk,
+}
+
+impl<Risk> OrderEvaluator for LiquidationRisk<Risk>
+where
+    Risk: OrderEvaluator,
+{
+    const DEFAULT_ORDER_TYPE: OrderType = Risk::DEFAULT_ORDER_TYPE;
+
+    fn evaluate_order(&self, order: OrderEvent) -> Option<OrderEvent> {
+        match self.liquidation_distance(&order) {
+            Ok(Some(distance)) if distance < self.config.min_distance => {
+                warn!(
+                    instrument = %order.instrument,
+                    distance,
+                    min_distance = self.config.min_distance,
+                    "refusing OrderEvent with an estimated liquidation price too close to the mark price"
+                );
+                return None;
+            }
+            Ok(_) => {}
+            Err(error) => {
+                warn!(
+                    instrument = %order.instrument,
+                    %error,
+                    "refusing OrderEvent with a mark price that cannot be used to estimate it's liquidation price"
+                );
+                return None;
+            }
+        }
+
+        self.risk.evaluate_order(order)
+    }
+}
+
+impl<Risk> LiquidationRisk<Risk> {
+    /// Leverage of the provided [`Instrument`].
+    pub fn leverage(&self, instrument: &Instrument) -> f64 {
+        self.config
+            .leverage
+            .get(instrument)
+            .copied()
+            .unwrap_or(self.config.default_leverage)
+    }
+
+    /// Distance between the estimated liquidation price of the entry [`OrderEvent`] & the mark
+    /// price, as a fraction of the mark price. Returns `None` for exit [`OrderEvent`]s and spot
+    /// [`Instrument`]s, which cannot be liquidated.
+    pub fn liquidation_distance(&self, order: &OrderEvent) -> Result<Option<f64>, PortfolioError> {
+        if order.decision.is_exit() || order.instrument.kind == InstrumentKind::Spot {
+            return Ok(None);
+        }
+
+        let close = order.market_meta.close;
+        let mark_price = close.to_f64().ok_or(PortfolioError::NonFloat {
+            field: "market_meta.close",
+            value: close,
+        })?;
+        let liquidation_price = estimate_liquidation_price(
+            mark_price,
+            self.leverage(&order.instrument),
+            self.config.maintenance_margin_rate,
+            order.decision.is_long(),
+        );
+
+        Ok(Some((mark_price - liquidation_price).abs() / mark_price))
+    }
+}
+
+/// Estimate the liquidation price of an isolated margin position entered at the provided price.
+pub fn estimate_liquidation_price(
+    entry_price: f64,
+    leverage: f64,
+    maintenance_margin_rate: f64,
+    is_long: bool,
+) -> f64 {
+    if is_long {
+        entry_price * (1.0 - 1.0 / leverage) / (1.0 - maintenance_margin_rate)
+    } else {
+        entry_price * (1.0 + 1.0 / leverage) / (1.0 + maintenance_margin_rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{strategy::Decision, test_util::order_event};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_liquidation_risk_evaluate_order() {
+        struct TestCase {
+            kind: InstrumentKind,
+            decision: Decision,
+            leverage: f64,
+            expected_some: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: low leverage long is accepted
+                kind: InstrumentKind::Perpetual,
+                decision: Decision::Long,
+                leverage: 2.0,
+                expected_some: true,
+            },
+            TestCase {
+                // TC1: high leverage long is refused
+                kind: InstrumentKind::Perpetual,
+                decision: Decision::Long,
+                leverage: 20.0,
+                expected_some: false,
+            },
+            TestCase {
+                // TC2: high leverage short is refused
+                kind: InstrumentKind::Perpetual,
+                decision: Decision::Short,
+                leverage: 20.0,
+                expected_some: false,
+            },
+            TestCase {
+                // TC3: high leverage exit is accepted
+                kind: InstrumentKind::Perpetual,
+                decision: Decision::CloseLong,
+                leverage: 20.0,
+                expected_some: true,
+            },
+            TestCase {
+                // TC4: spot is never liquidated
+                kind: InstrumentKind::Spot,
+                decision: Decision::Long,
+                leverage: 20.0,
+                expected_some: true,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let risk = LiquidationRisk {
+                config: LiquidationRiskConfig {
+                    default_leverage: test.leverage,
+                    leverage: HashMap::new(),
+                    maintenance_margin_rate: 0.005,
+                    min_distance: 0.1,
+                },
+                risk: DefaultRisk {},
+            };
+
+            let mut order = order_event();
+            order.instrument = Instrument::from(("btc", "usdt", test.kind));
+            order.decision = test.decision;
+            order.market_meta.close = dec!(100);
+
+            assert_eq!(
+                risk.evaluate_order(order).is_some(),
+                test.expected_some,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
@@ -0,0 +1,461 @@
+use crate::{
+    data::{features::market_price, MarketEventObserver},
+    event::Event,
+    execution::FillEvent,
+    portfolio::{error::PortfolioError, risk::OrderEvaluator, OrderEvent, OrderType},
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_instrument::{exchange::ExchangeId, instrument::Instrument};
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tracing::warn;
+
+/// Action taken by a [`VolatilityTarget`] for an [`OrderEvent`] that would push the portfolio
+/// volatility above the target.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+pub enum TargetAction {
+    /// Refuse the [`OrderEvent`].
+    #[default]
+    Reject,
+    /// Scale down the [`OrderEvent`] quantity so the portfolio volatility meets the target.
+    Scale,
+}
+
+/// Configuration for constructing a [`PortfolioRisk`].
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct VolatilityConfig {
+    /// EWMA decay factor of the covariance estimate (eg/ 0.94 for RiskMetrics).
+    pub lambda: f64,
+    /// Interval (of `MarketEvent` exchange time) between each sample of instrument returns.
+    pub sample_interval: Duration,
+    /// Number of standard deviations of the Value-at-Risk (eg/ 1.645 for 95% confidence).
+    pub confidence_z: f64,
+    /// Maximum portfolio volatility (in quote currency per sample interval).
+    pub target_volatility: f64,
+    pub action: TargetAction,
+}
+
+/// Current portfolio [`RiskEstimate`], in quote currency per sample interval.
+#[derive(Copy, Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct RiskEstimate {
+    /// Standard deviation of the portfolio value.
+    pub volatility: f64,
+    /// Parametric Value-at-Risk of the portfolio value at the configured confidence.
+    pub value_at_risk: f64,
+}
+
+/// Exponentially weighted (EWMA) covariance estimate of exchange [`Instrument`] log returns,
+/// combined with the net position of each to estimate the portfolio volatility & Value-at-Risk.
+///
+/// Maintained by every [`Trader`](crate::engine::trader::Trader) it is provided to, so
+/// strategies can query the current [`RiskEstimate`]. Returns of every instrument are sampled
+/// together each [`VolatilityConfig::sample_interval`], so their covariances are synchronous.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PortfolioRisk {
+    pub config: VolatilityConfig,
+    index: HashMap<(ExchangeId, Instrument), usize>,
+    prices: Vec<f64>,
+    sampled_prices: Vec<Option<f64>>,
+    positions: Vec<f64>,
+    covariance: Vec<Vec<f64>>,
+    samples: usize,
+    next_sample: Option<DateTime<Utc>>,
+}
+
+impl PortfolioRisk {
+    /// Construct a new empty [`PortfolioRisk`] using the provided [`VolatilityConfig`].
+    pub fn new(config: VolatilityConfig) -> Self {
+        Self {
+            config,
+            index: HashMap::new(),
+            prices: Vec::new(),
+            sampled_prices: Vec::new(),
+            positions: Vec::new(),
+            covariance: Vec::new(),
+            samples: 0,
+            next_sample: None,
+        }
+    }
+
+    /// Update the latest price of the [`MarketEvent`] exchange [`Instrument`], sampling the
+    /// returns of every instrument if the sample interval has elapsed.
+    pub fn update_from_market(&mut self, market: &MarketEvent<Instrument, DataKind>) {
+        let Some(price) = market_price(&market.kind).filter(|price| *price > 0.0) else {
+            return;
+        };
+
+        let index = self.index_of(market.exchange, &market.instrument);
+        self.prices[index] = price;
+
+        match self.next_sample {
+            Some(next_sample) if market.time_exchange < next_sample => {}
+            _ => {
+                self.sample();
+                let interval = chrono::Duration::from_std(self.config.sample_interval)
+                    .unwrap_or(chrono::Duration::MAX);
+                self.next_sample = market.time_exchange.checked_add_signed(interval);
+            }
+        }
+    }
+
+    /// Update the net position of the [`FillEvent`] exchange [`Instrument`].
+    pub fn update_from_fill(&mut self, fill: &FillEvent) -> Result<(), PortfolioError> {
+        let quantity = to_f64("quantity", fill.quantity)?;
+        let close = to_f64("market_meta.close", fill.market_meta.close)?;
+
+        let index = self.index_of(fill.exchange, &fill.instrument);
+        self.positions[index] += quantity;
+
+        if self.prices[index] <= 0.0 {
+            self.prices[index] = close;
+        }
+
+        Ok(())
+    }
+
+    /// EWMA covariance of the log returns of two exchange [`Instrument`]s, once sampled.
+    pub fn covariance(
+        &self,
+        a: (ExchangeId, &Instrument),
+        b: (ExchangeId, &Instrument),
+    ) -> Option<f64> {
+        if self.samples == 0 {
+            return None;
+        }
+        let a = self.index.get(&(a.0, a.1.clone()))?;
+        let b = self.index.get(&(b.0, b.1.clone()))?;
+        Some(self.covariance[*a][*b])
+    }
+
+    /// Current portfolio [`RiskEstimate`], once the instrument returns have been sampled.
+    pub fn estimate(&self) -> Option<RiskEstimate> {
+        let volatility = self.volatility(&self.exposures())?;
+        Some(RiskEstimate {
+            volatility,
+            value_at_risk: self.config.confidence_z * volatility,
+        })
+    }
+
+    /// Portfolio volatility of the provided quote currency exposure of each instrument.
+    fn volatility(&self, exposures: &[f64]) -> Option<f64> {
+        (self.samples > 0).then(|| self.quadratic(exposures, exposures).max(0.0).sqrt())
+    }
+
+    /// `a' * covariance * b`
+    fn quadratic(&self, a: &[f64], b: &[f64]) -> f64 {
+        a.iter()
+            .zip(&self.covariance)
+            .map(|(a, row)| a * row.iter().zip(b).map(|(cov, b)| cov * b).sum::<f64>())
+            .sum()
+    }
+
+    /// Fraction of the [`OrderEvent`] quantity that keeps the portfolio volatility within the
+    /// target. Returns `None` if the full quantity is within the target, or reduces the
+    /// portfolio volatility, or the returns have not been sampled yet.
+    fn order_scale(&self, order: &OrderEvent) -> Result<Option<f64>, PortfolioError> {
+        let Some(index) = self
+            .index
+            .get(&(order.exchange, order.instrument.clone()))
+            .copied()
+        else {
+            return Ok(None);
+        };
+        let price = match self.prices[index] {
+            price if price > 0.0 => price,
+            _ => to_f64("market_meta.close", order.market_meta.close)?,
+        };
+
+        let exposures = self.exposures();
+        let mut delta = vec![0.0; exposures.len()];
+        delta[index] = to_f64("quantity", order.quantity)? * price;
+        let after = exposures
+            .iter()
+            .zip(&delta)
+            .map(|(exposure, delta)| exposure + delta)
+            .collect::<Vec<_>>();
+
+        let target = self.config.target_volatility;
+        let (Some(before), Some(after)) = (self.volatility(&exposures), self.volatility(&after))
+        else {
+            return Ok(None);
+        };
+        if after <= target || after <= before {
+            return Ok(None);
+        }
+
+        // Solve |exposures + scale * delta| = target for the largest scale in [0, 1]
+        let a = self.quadratic(&delta, &delta);
+        let b = 2.0 * self.quadratic(&exposures, &delta);
+        let c = self.quadratic(&exposures, &exposures) - target * target;
+        let discriminant = b * b - 4.0 * a * c;
+        if c > 0.0 || discriminant < 0.0 {
+            return Ok(Some(0.0));
+        }
+
+        Ok(Some(
+            ((-b + discriminant.sqrt()) / (2.0 * a)).clamp(0.0, 1.0),
+        ))
+    }
+
+    /// Quote currency exposure of each instrument net position.
+    fn exposures(&self) -> Vec<f64> {
+        self.positions
+            .iter()
+            .zip(&self.prices)
+            .map(|(position, price)| position * price)
+            .collect()
+    }
+
+    /// Sample the log return of every instrument since the previous sample, and update the
+    /// EWMA covariance estimate. Instruments without a previous sample price have a zero return.
+    fn sample(&mut self) {
+        let initialised = self.sampled_prices.iter().any(Option::is_some);
+        let returns = self
+            .prices
+            .iter()
+            .zip(&self.sampled_prices)
+            .map(|(price, sampled)| match sampled {
+                Some(sampled) if *price > 0.0 => (price / sampled).ln(),
+                _ => 0.0,
+            })
+            .collect::<Vec<_>>();
+
+        self.sampled_prices = self
+            .prices
+            .iter()
+            .map(|price| (*price > 0.0).then_some(*price))
+            .collect();
+
+        if !initialised {
+            return;
+        }
+
+        let lambda = self.config.lambda;
+        for (row, a) in self.covariance.iter_mut().zip(&returns) {
+            for (cov, b) in row.iter_mut().zip(&returns) {
+                *cov = lambda * *cov + (1.0 - lambda) * a * b;
+            }
+        }
+        self.samples += 1;
+    }
+
+    /// Index of the exchange [`Instrument`], growing the state for a new instrument.
+    fn index_of(&mut self, exchange: ExchangeId, instrument: &Instrument) -> usize {
+        if let Some(index) = self.index.get(&(exchange, instrument.clone())) {
+            return *index;
+        }
+
+        let index = self.prices.len();
+        self.index.insert((exchange, instrument.clone()), index);
+        self.prices.push(0.0);
+        self.sampled_prices.push(None);
+        self.positions.push(0.0);
+        for row in &mut self.covariance {
+            row.push(0.0);
+        }
+        self.covariance.push(vec![0.0; index + 1]);
+        index
+    }
+}
+
+impl MarketEventObserver for PortfolioRisk {
+    fn on_market(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Event> {
+        self.update_from_market(market);
+        None
+    }
+
+    fn on_fill(&mut self, fill: &FillEvent) {
+        if let Err(error) = self.update_from_fill(fill) {
+            warn!(
+                instrument = %fill.instrument,
+                %error,
+                "PortfolioRisk failed to update the net position from a FillEvent"
+            );
+        }
+    }
+}
+
+/// Convert a [`Decimal`] to an `f64` for the covariance estimate, failing if it cannot be
+/// represented.
+fn to_f64(field: &'static str, value: Decimal) -> Result<f64, PortfolioError> {
+    value
+        .to_f64()
+        .ok_or(PortfolioError::NonFloat { field, value })
+}
+
+/// Risk rule that rejects, or scales down, entry [`OrderEvent`]s which would increase the
+/// [`PortfolioRisk`] volatility above the [`VolatilityConfig::target_volatility`], before
+/// delegating to the wrapped [`OrderEvaluator`].
+#[derive(Debug)]
+pub struct VolatilityTarget<Risk> {
+    pub portfolio_risk: Arc<RwLock<PortfolioRisk>>,
+    pub risk: Risk,
+}
+
+impl<Risk> OrderEvaluator for VolatilityTarget<Risk>
+where
+    Risk: OrderEvaluator,
+{
+    const DEFAULT_ORDER_TYPE: OrderType = Risk::DEFAULT_ORDER_TYPE;
+
+    fn evaluate_order(&self, mut order: OrderEvent) -> Option<OrderEvent> {
+        if order.decision.is_exit() {
+            return self.risk.evaluate_order(order);
+        }
+
+        let order_scale = self.portfolio_risk.read().order_scale(&order);
+        let scale = match order_scale {
+            Ok(Some(scale)) => scale,
+            Ok(None) => return self.risk.evaluate_order(order),
+            Err(error) => {
+                warn!(
+                    instrument = %order.instrument,
+                    %error,
+                    "refusing OrderEvent that cannot be evaluated against the portfolio volatility target"
+                );
+                return None;
+            }
+        };
+
+        let action = self.portfolio_risk.read().config.action;
+        match (action, Decimal::from_f64(scale)) {
+            (TargetAction::Scale, Some(scale)) if scale > Decimal::ZERO => {
+                order.quantity *= scale;
+                self.risk.evaluate_order(order)
+            }
+            _ => {
+                warn!(
+                    instrument = %order.instrument,
+                    scale,
+                    ?action,
+                    "refusing OrderEvent that would exceed the portfolio volatility target"
+                );
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        portfolio::risk::DefaultRisk,
+        strategy::Decision,
+        test_util::{fill_event, market_event_trade, order_event},
+    };
+    use barter_integration::Side;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_volatility_target_evaluate_order() {
+        struct TestCase {
+            action: TargetAction,
+            decision: Decision,
+            quantity: Decimal,
+            expected: Option<f64>,
+        }
+
+        let config = VolatilityConfig {
+            lambda: 0.5,
+            sample_interval: Duration::from_secs(1),
+            confidence_z: 1.645,
+            target_volatility: 50.0,
+            action: TargetAction::Reject,
+        };
+
+        // Sample a 10% return => variance of (1 - lambda) * ln(1.1)^2
+        let mut portfolio_risk = PortfolioRisk::new(config);
+        let mut market = market_event_trade(Side::Buy);
+        for (seconds, price) in [(0, 1000.0), (1, 1100.0)] {
+            market.time_exchange = DateTime::<Utc>::from_timestamp(seconds, 0).unwrap();
+            if let DataKind::Trade(trade) = &mut market.kind {
+                trade.price = price;
+            }
+            portfolio_risk.update_from_market(&market);
+        }
+
+        let mut fill = fill_event();
+        fill.exchange = market.exchange;
+        fill.instrument = market.instrument.clone();
+        fill.quantity = dec!(0.5);
+        portfolio_risk.update_from_fill(&fill).unwrap();
+
+        // Volatility of 0.5 @ 1100 = 550 * ln(1.1) * sqrt(0.5) ~= 37.07
+        let volatility = 550.0 * 1.1_f64.ln() * 0.5_f64.sqrt();
+        let estimate = portfolio_risk.estimate().unwrap();
+        assert!((estimate.volatility - volatility).abs() < 1e-9);
+        assert!((estimate.value_at_risk - 1.645 * volatility).abs() < 1e-9);
+
+        let portfolio_risk = Arc::new(RwLock::new(portfolio_risk));
+
+        let tests = vec![
+            TestCase {
+                // TC0: order within the target is accepted unchanged
+                action: TargetAction::Reject,
+                decision: Decision::Long,
+                quantity: dec!(0.1),
+                expected: Some(0.1),
+            },
+            TestCase {
+                // TC1: order exceeding the target is rejected
+                action: TargetAction::Reject,
+                decision: Decision::Long,
+                quantity: dec!(1.0),
+                expected: None,
+            },
+            TestCase {
+                // TC2: order exceeding the target is scaled to meet the target
+                action: TargetAction::Scale,
+                decision: Decision::Long,
+                quantity: dec!(1.0),
+                expected: Some(50.0 / volatility * 0.5 - 0.5),
+            },
+            TestCase {
+                // TC3: order reducing the volatility is accepted unchanged
+                action: TargetAction::Reject,
+                decision: Decision::Short,
+                quantity: dec!(-0.5),
+                expected: Some(-0.5),
+            },
+            TestCase {
+                // TC4: exit order is accepted unchanged
+                action: TargetAction::Reject,
+                decision: Decision::CloseLong,
+                quantity: dec!(5.0),
+                expected: Some(5.0),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            portfolio_risk.write().config.action = test.action;
+            let target = VolatilityTarget {
+                portfolio_risk: Arc::clone(&portfolio_risk),
+                risk: DefaultRisk {},
+            };
+
+            let mut order = order_event();
+            order.exchange = market.exchange;
+            order.instrument = market.instrument.clone();
+            order.decision = test.decision;
+            order.quantity = test.quantity;
+
+            let actual = target
+                .evaluate_order(order)
+                .map(|order| order.quantity.to_f64().unwrap());
+            match (actual, test.expected) {
+                (Some(actual), Some(expected)) => assert!(
+                    (actual - expected).abs() < 1e-9,
+                    "TC{index} failed: actual {actual} != expected {expected}"
+                ),
+                (actual, expected) => assert_eq!(actual, expected, "TC{} failed", index),
+            }
+        }
+    }
+}
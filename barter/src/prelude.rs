@@ -0,0 +1,80 @@
+//! Assembling a trading system otherwise requires imports from barter, barter-data,
+//! barter-execution, barter-instrument & barter-integration, several of which define types with
+//! the same name (eg/ `ExecutionClient`, `SimulatedExecution`, `MarketFeed`, `Config`).
+//!
+//! ```
+//! use barter::prelude::*;
+//!
+//! let market: Market = Market::new(ExchangeId::BinanceSpot, ("btc", "usdt", InstrumentKind::Spot));
+//!
+//! let mut data = HistoricalMarketFeed::new([barter::test_util::market_event_trade(Side::Buy)].into_iter());
+//! let mut strategy = RSIStrategy::new(StrategyConfig { rsi_period: 14 });
+//!
+//! while let Feed::Next(market_event) = data.next() {
+//!     let signal: Option<Signal> = strategy.generate_signal(&market_event);
+//! }
+//! ```
+
+pub use crate::{
+    data::{
+        historical::MarketFeed as HistoricalMarketFeed, live::MarketFeed as LiveMarketFeed, Feed,
+        MarketGenerator, MarketMeta,
+    },
+    engine::{error::EngineError, trader::Trader, Command, Engine, EngineState},
+    event::{Event, EventTx, MessageTransmitter},
+    execution::{
+        simulated::{Config as SimulatedExecutionConfig, SimulatedExecution},
+        ExecutionClient, Fees, FillEvent,
+    },
+    portfolio::{
+        allocator::{DefaultAllocator, OrderAllocator},
+        portfolio::{MetaPortfolio, PortfolioLego},
+        position::{Position, PositionExit, PositionUpdate},
+        repository::in_memory::InMemoryRepository,
+        risk::{DefaultRisk, OrderEvaluator},
+        Balance, FillUpdater, MarketUpdater, OrderEvent, OrderGenerator, OrderType,
+    },
+    statistic::summary::{
+        trading::{Config as StatisticConfig, TradingSummary},
+        Initialiser, PositionSummariser, TableBuilder,
+    },
+    strategy::{
+        example::{Config as StrategyConfig, RSIStrategy},
+        Decision, Signal, SignalForceExit, SignalGenerator, SignalStrength,
+    },
+};
+
+pub use barter_data::{
+    error::DataError,
+    event::{DataKind, MarketEvent},
+    streams::Streams,
+    subscription::{
+        book::{OrderBookL1, OrderBooksL1, OrderBooksL2},
+        candle::{Candle, Candles},
+        liquidation::{Liquidation, Liquidations},
+        trade::{PublicTrade, PublicTrades},
+    },
+};
+
+/// Exchange account types of barter-execution, renamed where they overlap with the Engine's own
+/// execution & portfolio models.
+pub use barter_execution::{
+    error::ExecutionError as ExchangeExecutionError,
+    model::{
+        balance::{Balance as AccountBalance, SymbolBalance},
+        order::{Order, OrderKind, TimeInForce},
+        trade::Trade as AccountTrade,
+        AccountEvent, AccountEventKind, ClientOrderId,
+    },
+    simulated::execution::SimulatedExecution as SimulatedExchangeExecution,
+    ExecutionClient as ExchangeExecutionClient,
+};
+
+pub use barter_instrument::{
+    asset::symbol::Symbol,
+    exchange::ExchangeId,
+    instrument::{kind::InstrumentKind, Instrument},
+    market::Market,
+};
+
+pub use barter_integration::Side;
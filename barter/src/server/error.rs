@@ -0,0 +1,25 @@
+use crate::engine::error::EngineError;
+use thiserror::Error;
+
+/// All errors generated in the barter::server module.
+#[derive(Error, Debug)]
+pub enum ServerError {
+    #[error("Engine is offline: Command channel closed")]
+    EngineOffline,
+
+    #[error("ControlPlane was constructed without a PriorityCommandTx")]
+    PriorityChannelMissing,
+
+    #[error("Engine failed to action ControlRequest: {0}")]
+    Engine(#[from] EngineError),
+
+    #[error("Message bus error: {0}")]
+    MessageBus(String),
+
+    #[error("Server IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[cfg(feature = "grpc")]
+    #[error("gRPC transport error: {0}")]
+    Transport(#[from] tonic::transport::Error),
+}
@@ -0,0 +1,278 @@
+use crate::server::{
+    error::ServerError, ControlPlane, ControlRequest, ControlResponse, TradingState,
+};
+use std::net::SocketAddr;
+use tokio::net::TcpListener;
+use tokio_stream::wrappers::TcpListenerStream;
+use tonic::{Request, Response, Status};
+use tracing::info;
+
+/// Types & gRPC server/client generated from `proto/control.proto`.
+pub mod proto {
+    tonic::include_proto!("barter.control.v1");
+}
+
+use proto::control_plane_server::{ControlPlane as ControlPlaneService, ControlPlaneServer};
+
+/// gRPC server exposing a [`ControlPlane`] via the `barter.control.v1.ControlPlane` service
+/// defined in `proto/control.proto`.
+#[derive(Debug)]
+pub struct GrpcServer {
+    listener: TcpListener,
+    control: ControlPlane,
+}
+
+impl GrpcServer {
+    /// Bind a new [`GrpcServer`] serving the provided [`ControlPlane`] to the provided address.
+    pub async fn bind(addr: SocketAddr, control: ControlPlane) -> Result<Self, ServerError> {
+        Ok(Self {
+            listener: TcpListener::bind(addr).await?,
+            control,
+        })
+    }
+
+    /// Local address the [`GrpcServer`] is bound to.
+    pub fn local_addr(&self) -> Result<SocketAddr, ServerError> {
+        self.listener.local_addr().map_err(ServerError::from)
+    }
+
+    /// Run the [`GrpcServer`], serving client requests until the listener fails.
+    pub async fn run(self) -> Result<(), ServerError> {
+        info!(addr = ?self.listener.local_addr(), "GrpcServer accepting connections");
+
+        tonic::transport::Server::builder()
+            .add_service(ControlPlaneServer::new(ControlGrpc {
+                control: self.control,
+            }))
+            .serve_with_incoming(TcpListenerStream::new(self.listener))
+            .await
+            .map_err(ServerError::from)
+    }
+}
+
+/// [`ControlPlaneService`] implementation delegating every rpc to [`ControlPlane::handle`].
+#[derive(Debug)]
+struct ControlGrpc {
+    control: ControlPlane,
+}
+
+#[tonic::async_trait]
+impl ControlPlaneService for ControlGrpc {
+    async fn fetch_open_positions(
+        &self,
+        _: Request<proto::FetchOpenPositionsRequest>,
+    ) -> Result<Response<proto::FetchOpenPositionsResponse>, Status> {
+        let positions = self
+            .control
+            .fetch_open_positions()
+            .await?
+            .iter()
+            .map(to_json)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Response::new(proto::FetchOpenPositionsResponse {
+            positions_json: positions,
+        }))
+    }
+
+    async fn request_snapshot(
+        &self,
+        _: Request<proto::RequestSnapshotRequest>,
+    ) -> Result<Response<proto::RequestSnapshotResponse>, Status> {
+        let state = self.control.request_snapshot().await?;
+
+        Ok(Response::new(proto::RequestSnapshotResponse {
+            state_json: to_json(&state)?,
+        }))
+    }
+
+    async fn exit_positions(
+        &self,
+        request: Request<proto::ExitPositionsRequest>,
+    ) -> Result<Response<proto::Ack>, Status> {
+        let market = request
+            .into_inner()
+            .market_json
+            .as_deref()
+            .map(from_json)
+            .transpose()?;
+
+        self.handle(ControlRequest::ExitPositions(market)).await
+    }
+
+    async fn cancel_all(
+        &self,
+        _: Request<proto::CancelAllRequest>,
+    ) -> Result<Response<proto::Ack>, Status> {
+        self.handle(ControlRequest::CancelAll).await
+    }
+
+    async fn set_trading_state(
+        &self,
+        request: Request<proto::SetTradingStateRequest>,
+    ) -> Result<Response<proto::Ack>, Status> {
+        let request = request.into_inner();
+        let state = match proto::TradingState::try_from(request.state) {
+            Ok(proto::TradingState::Enabled) => TradingState::Enabled,
+            Ok(proto::TradingState::Disabled) => TradingState::Disabled,
+            Ok(proto::TradingState::Unspecified) | Err(_) => {
+                return Err(Status::invalid_argument(format!(
+                    "invalid TradingState: {}",
+                    request.state
+                )))
+            }
+        };
+
+        self.handle(ControlRequest::SetTradingState {
+            state,
+            reason: request.reason,
+        })
+        .await
+    }
+
+    async fn terminate(
+        &self,
+        request: Request<proto::TerminateRequest>,
+    ) -> Result<Response<proto::Ack>, Status> {
+        self.handle(ControlRequest::Terminate(request.into_inner().reason))
+            .await
+    }
+
+    async fn update_strategy_config(
+        &self,
+        request: Request<proto::UpdateStrategyConfigRequest>,
+    ) -> Result<Response<proto::Ack>, Status> {
+        let request = request.into_inner();
+        let market = request.market_json.as_deref().map(from_json).transpose()?;
+        let config = from_json(&request.config_json)?;
+
+        self.handle(ControlRequest::UpdateStrategyConfig { market, config })
+            .await
+    }
+}
+
+impl ControlGrpc {
+    /// Action a [`ControlRequest`] that is acknowledged with a [`ControlResponse::Ack`].
+    async fn handle(&self, request: ControlRequest) -> Result<Response<proto::Ack>, Status> {
+        match self.control.handle(request).await? {
+            ControlResponse::Ack { traders } => Ok(Response::new(proto::Ack {
+                traders: traders.map(|traders| traders as u64),
+            })),
+            response => Err(Status::internal(format!(
+                "unexpected ControlResponse: {response:?}"
+            ))),
+        }
+    }
+}
+
+impl From<ServerError> for Status {
+    fn from(error: ServerError) -> Self {
+        let message = error.to_string();
+        match error {
+            ServerError::EngineOffline | ServerError::MessageBus(_) => Status::unavailable(message),
+            ServerError::PriorityChannelMissing => Status::failed_precondition(message),
+            ServerError::Engine(_) | ServerError::Io(_) | ServerError::Transport(_) => {
+                Status::internal(message)
+            }
+        }
+    }
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> Result<String, Status> {
+    serde_json::to_string(value).map_err(|error| Status::internal(error.to_string()))
+}
+
+fn from_json<T: serde::de::DeserializeOwned>(value: &str) -> Result<T, Status> {
+    serde_json::from_str(value).map_err(|error| Status::invalid_argument(error.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::{
+            priority::{priority_channel, PriorityCommand},
+            Command,
+        },
+        test_util::position,
+    };
+    use proto::control_plane_client::ControlPlaneClient;
+    use tokio::sync::mpsc;
+
+    #[tokio::test]
+    async fn test_grpc_server_routes_rpcs_to_control_plane() {
+        let (command_tx, mut command_rx) = mpsc::channel(10);
+        let (priority_tx, mut priority_rx) = priority_channel(10);
+        let control = ControlPlane::new(command_tx).with_priority_tx(priority_tx);
+
+        let server = GrpcServer::bind("127.0.0.1:0".parse().unwrap(), control)
+            .await
+            .unwrap();
+        let addr = server.local_addr().unwrap();
+        tokio::spawn(server.run());
+
+        // Mock Engine responding to Command::FetchOpenPositions
+        let open_position = position();
+        let engine_position = open_position.clone();
+        tokio::spawn(async move {
+            while let Some(command) = command_rx.recv().await {
+                if let Command::FetchOpenPositions(positions_tx) = command {
+                    positions_tx
+                        .send(Ok(vec![engine_position.clone()]))
+                        .unwrap();
+                }
+            }
+        });
+
+        let mut client = ControlPlaneClient::connect(format!("http://{addr}"))
+            .await
+            .unwrap();
+
+        let positions = client
+            .fetch_open_positions(proto::FetchOpenPositionsRequest {})
+            .await
+            .unwrap()
+            .into_inner()
+            .positions_json;
+        assert_eq!(
+            positions,
+            vec![serde_json::to_string(&open_position).unwrap()]
+        );
+
+        for (state, expected) in [
+            (
+                proto::TradingState::Disabled,
+                PriorityCommand::DisableTrading("risk limit".to_owned()),
+            ),
+            (proto::TradingState::Enabled, PriorityCommand::EnableTrading),
+        ] {
+            let ack = client
+                .set_trading_state(proto::SetTradingStateRequest {
+                    state: state.into(),
+                    reason: "risk limit".to_owned(),
+                })
+                .await
+                .unwrap()
+                .into_inner();
+            assert_eq!(ack.traders, Some(1));
+            assert_eq!(priority_rx.try_recv(), Some(expected));
+        }
+
+        let status = client
+            .set_trading_state(proto::SetTradingStateRequest {
+                state: proto::TradingState::Unspecified.into(),
+                reason: String::new(),
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+        let status = client
+            .exit_positions(proto::ExitPositionsRequest {
+                market_json: Some("not a market".to_owned()),
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+}
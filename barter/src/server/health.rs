@@ -0,0 +1,583 @@
+use crate::{
+    engine::Command,
+    event::{Event, MessageTransmitter},
+    server::error::ServerError,
+    system::supervisor::{ComponentExit, ComponentStatus, HealthEvent},
+};
+use barter_execution::model::AccountEvent;
+use barter_instrument::exchange::ExchangeId;
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
+use std::{collections::BTreeMap, net::SocketAddr, sync::Arc, time::Duration};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+    sync::{mpsc, oneshot},
+};
+use tracing::{debug, info, warn};
+
+/// Staleness thresholds used by a [`HealthMonitor`] to determine the [`HealthStatus`] of each
+/// stream & the [`Engine`](crate::engine::Engine) loop.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct HealthConfig {
+    /// Maximum age of the last market data event of a connected exchange before it is
+    /// [`HealthStatus::Degraded`].
+    pub max_market_data_age: Duration,
+    /// Maximum age of the last account event of a connected exchange before it is
+    /// [`HealthStatus::Degraded`]. Account streams are often quiet, so this is usually generous.
+    pub max_account_age: Duration,
+    /// Maximum age of the last [`Engine`](crate::engine::Engine) heartbeat before the loop is
+    /// considered dead.
+    pub max_engine_age: Duration,
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self {
+            max_market_data_age: Duration::from_secs(30),
+            max_account_age: Duration::from_secs(300),
+            max_engine_age: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Health of a trading system, or one of it's parts. Ordered from best to worst.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    Healthy,
+    Degraded,
+    Unhealthy,
+}
+
+/// Health of an exchange market data or account stream.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct StreamHealth {
+    pub status: HealthStatus,
+    pub connected: bool,
+    pub last_event: Option<DateTime<Utc>>,
+    pub last_event_age_ms: Option<i64>,
+}
+
+/// Liveness of the [`Engine`](crate::engine::Engine) loop, determined by the age of it's last
+/// heartbeat (eg/ a response to a [`HealthMonitor::probe_engine`]).
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct EngineHealth {
+    pub status: HealthStatus,
+    pub last_heartbeat: Option<DateTime<Utc>>,
+    pub last_heartbeat_age_ms: Option<i64>,
+}
+
+/// Structured health of a trading system at a point in time, generated by a [`HealthMonitor`].
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct HealthReport {
+    pub time: DateTime<Utc>,
+    /// Worst [`HealthStatus`] of every stream, the [`Engine`](crate::engine::Engine) loop & every
+    /// supervised component.
+    pub status: HealthStatus,
+    /// [`Engine`](crate::engine::Engine) loop is alive (eg/ for a Kubernetes liveness probe).
+    pub live: bool,
+    /// Trading system is not [`HealthStatus::Unhealthy`] (eg/ for a Kubernetes readiness probe).
+    pub ready: bool,
+    pub market_data: BTreeMap<ExchangeId, StreamHealth>,
+    pub account: BTreeMap<ExchangeId, StreamHealth>,
+    pub engine: EngineHealth,
+    /// Latest [`ComponentStatus`] of every [`Supervisor`](crate::system::supervisor::Supervisor)
+    /// component, keyed by component name.
+    pub components: BTreeMap<SmolStr, ComponentStatus>,
+}
+
+/// Connectivity & last event time of an exchange stream.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+struct StreamState {
+    connected: bool,
+    last_event: Option<DateTime<Utc>>,
+}
+
+impl StreamState {
+    fn health(&self, now: DateTime<Utc>, max_age: Duration) -> StreamHealth {
+        let last_event_age = self.last_event.map(|last_event| now - last_event);
+
+        let status = match last_event_age {
+            _ if !self.connected => HealthStatus::Unhealthy,
+            Some(age) if !exceeds(age, max_age) => HealthStatus::Healthy,
+            _ => HealthStatus::Degraded,
+        };
+
+        StreamHealth {
+            status,
+            connected: self.connected,
+            last_event: self.last_event,
+            last_event_age_ms: last_event_age.map(|age| age.num_milliseconds()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct HealthState {
+    market_data: BTreeMap<ExchangeId, StreamState>,
+    account: BTreeMap<ExchangeId, StreamState>,
+    engine_heartbeat: Option<DateTime<Utc>>,
+    components: BTreeMap<SmolStr, ComponentStatus>,
+}
+
+/// Shared, cheaply cloneable tracker of the health of a trading system, queryable via
+/// [`HealthMonitor::report`] or served over HTTP by a [`HealthServer`].
+///
+/// Implements [`MessageTransmitter<Event>`], so it can be used as (or alongside) the Trader
+/// `EventTx` to track the last market data event of each exchange.
+#[derive(Clone, Debug)]
+pub struct HealthMonitor {
+    config: HealthConfig,
+    state: Arc<RwLock<HealthState>>,
+}
+
+impl MessageTransmitter<Event> for HealthMonitor {
+    fn send(&mut self, message: Event) {
+        if let Event::Market(market) = message {
+            self.record_market_data(market.exchange, market.time_received);
+        }
+    }
+
+    fn send_many(&mut self, messages: Vec<Event>) {
+        messages
+            .into_iter()
+            .for_each(|message| MessageTransmitter::send(self, message))
+    }
+}
+
+impl HealthMonitor {
+    /// Construct a new [`HealthMonitor`] with the provided [`HealthConfig`].
+    pub fn new(config: HealthConfig) -> Self {
+        Self {
+            config,
+            state: Arc::default(),
+        }
+    }
+
+    /// Expect a market data stream for the provided exchange, so it is reported as disconnected
+    /// until it's first event is recorded.
+    pub fn register_market_data(&self, exchange: ExchangeId) {
+        self.state.write().market_data.entry(exchange).or_default();
+    }
+
+    /// Expect an account stream for the provided exchange, so it is reported as disconnected
+    /// until it's first event is recorded.
+    pub fn register_account(&self, exchange: ExchangeId) {
+        self.state.write().account.entry(exchange).or_default();
+    }
+
+    /// Set the connectivity of the market data stream of the provided exchange.
+    pub fn set_market_data_connected(&self, exchange: ExchangeId, connected: bool) {
+        self.state
+            .write()
+            .market_data
+            .entry(exchange)
+            .or_default()
+            .connected = connected;
+    }
+
+    /// Set the connectivity of the account stream of the provided exchange.
+    pub fn set_account_connected(&self, exchange: ExchangeId, connected: bool) {
+        self.state
+            .write()
+            .account
+            .entry(exchange)
+            .or_default()
+            .connected = connected;
+    }
+
+    /// Record a market data event of the provided exchange, implying it's stream is connected.
+    pub fn record_market_data(&self, exchange: ExchangeId, time: DateTime<Utc>) {
+        let mut state = self.state.write();
+        record(state.market_data.entry(exchange).or_default(), time);
+    }
+
+    /// Record an [`AccountEvent`], implying the account stream of it's exchange is connected.
+    pub fn record_account(&self, event: &AccountEvent) {
+        let mut state = self.state.write();
+        record(
+            state.account.entry(event.exchange).or_default(),
+            event.received_time,
+        );
+    }
+
+    /// Record a heartbeat of the [`Engine`](crate::engine::Engine) loop.
+    pub fn record_engine_heartbeat(&self, time: DateTime<Utc>) {
+        let mut state = self.state.write();
+        if state.engine_heartbeat < Some(time) {
+            state.engine_heartbeat = Some(time);
+        }
+    }
+
+    /// Record the latest [`ComponentStatus`] of a supervised component.
+    pub fn record_health_event(&self, event: &HealthEvent) {
+        self.state
+            .write()
+            .components
+            .insert(event.component.name.clone(), event.status.clone());
+    }
+
+    /// Probe the liveness of the [`Engine`](crate::engine::Engine) loop by sending it a
+    /// [`Command::FetchOpenPositions`], recording a heartbeat if it responds within the
+    /// provided timeout. Returns `true` if the [`Engine`](crate::engine::Engine) responded.
+    pub async fn probe_engine(
+        &self,
+        command_tx: &mpsc::Sender<Command>,
+        timeout: Duration,
+    ) -> bool {
+        let (positions_tx, positions_rx) = oneshot::channel();
+        if command_tx
+            .send(Command::FetchOpenPositions(positions_tx))
+            .await
+            .is_err()
+        {
+            return false;
+        }
+
+        match tokio::time::timeout(timeout, positions_rx).await {
+            Ok(Ok(_)) => {
+                self.record_engine_heartbeat(Utc::now());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Periodically [`probe`](Self::probe_engine) the [`Engine`](crate::engine::Engine) loop
+    /// until it's [`Command`] channel is closed.
+    pub async fn run_engine_probe(self, command_tx: mpsc::Sender<Command>, interval: Duration) {
+        let mut interval = tokio::time::interval(interval);
+        while !command_tx.is_closed() {
+            interval.tick().await;
+            self.probe_engine(&command_tx, self.config.max_engine_age)
+                .await;
+        }
+    }
+
+    /// Record every [`HealthEvent`] received from a
+    /// [`Supervisor`](crate::system::supervisor::Supervisor) until the channel is closed.
+    pub async fn run_health_events(self, mut health_rx: mpsc::UnboundedReceiver<HealthEvent>) {
+        while let Some(event) = health_rx.recv().await {
+            self.record_health_event(&event);
+        }
+    }
+
+    /// Generate a [`HealthReport`] of the trading system at the provided time.
+    pub fn report(&self, now: DateTime<Utc>) -> HealthReport {
+        let state = self.state.read();
+
+        let market_data = state
+            .market_data
+            .iter()
+            .map(|(exchange, stream)| {
+                (
+                    *exchange,
+                    stream.health(now, self.config.max_market_data_age),
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let account = state
+            .account
+            .iter()
+            .map(|(exchange, stream)| (*exchange, stream.health(now, self.config.max_account_age)))
+            .collect::<BTreeMap<_, _>>();
+
+        let heartbeat_age = state.engine_heartbeat.map(|heartbeat| now - heartbeat);
+        let engine = EngineHealth {
+            status: match heartbeat_age {
+                Some(age) if !exceeds(age, self.config.max_engine_age) => HealthStatus::Healthy,
+                _ => HealthStatus::Unhealthy,
+            },
+            last_heartbeat: state.engine_heartbeat,
+            last_heartbeat_age_ms: heartbeat_age.map(|age| age.num_milliseconds()),
+        };
+
+        let status = market_data
+            .values()
+            .chain(account.values())
+            .map(|stream| stream.status)
+            .chain(state.components.values().map(component_health))
+            .chain([engine.status])
+            .max()
+            .unwrap_or(HealthStatus::Healthy);
+
+        HealthReport {
+            time: now,
+            status,
+            live: engine.status != HealthStatus::Unhealthy,
+            ready: status != HealthStatus::Unhealthy,
+            market_data,
+            account,
+            engine,
+            components: state.components.clone(),
+        }
+    }
+}
+
+/// Record an event of a stream at the provided time, implying it is connected.
+fn record(stream: &mut StreamState, time: DateTime<Utc>) {
+    stream.connected = true;
+    if stream.last_event < Some(time) {
+        stream.last_event = Some(time);
+    }
+}
+
+/// Determines if the provided age exceeds the maximum age.
+fn exceeds(age: chrono::Duration, max_age: Duration) -> bool {
+    chrono::Duration::from_std(max_age).is_ok_and(|max_age| age > max_age)
+}
+
+/// [`HealthStatus`] of a supervised component with the provided [`ComponentStatus`].
+fn component_health(status: &ComponentStatus) -> HealthStatus {
+    match status {
+        ComponentStatus::Running { .. } | ComponentStatus::Stopped(ComponentExit::Completed) => {
+            HealthStatus::Healthy
+        }
+        ComponentStatus::Restarting { .. } => HealthStatus::Degraded,
+        ComponentStatus::Stopped(_) | ComponentStatus::GaveUp { .. } => HealthStatus::Unhealthy,
+    }
+}
+
+/// Maximum size in bytes of the HTTP request line & headers accepted by the [`HealthServer`].
+pub const MAX_HEALTH_REQUEST_BYTES: u64 = 8 * 1024;
+
+/// Maximum time the [`HealthServer`] waits for a client to send it's HTTP request line &
+/// headers before closing the connection.
+pub const HEALTH_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Minimal HTTP server exposing the [`HealthReport`] of a [`HealthMonitor`] as JSON, so probes &
+/// dashboards do not have to scrape logs:
+/// - `GET /health`: `200` unless [`HealthStatus::Unhealthy`], else `503`.
+/// - `GET /live`: `200` if the [`Engine`](crate::engine::Engine) loop is alive, else `503`.
+/// - `GET /ready`: `200` if the trading system is ready, else `503`.
+#[derive(Debug)]
+pub struct HealthServer {
+    listener: TcpListener,
+    monitor: HealthMonitor,
+}
+
+impl HealthServer {
+    /// Bind a new [`HealthServer`] serving the provided [`HealthMonitor`] to the provided address.
+    pub async fn bind(addr: SocketAddr, monitor: HealthMonitor) -> Result<Self, ServerError> {
+        Ok(Self {
+            listener: TcpListener::bind(addr).await?,
+            monitor,
+        })
+    }
+
+    /// Local address the [`HealthServer`] is bound to.
+    pub fn local_addr(&self) -> Result<SocketAddr, ServerError> {
+        self.listener.local_addr().map_err(ServerError::from)
+    }
+
+    /// Run the [`HealthServer`], accepting client connections forever. Failing to accept a
+    /// connection (eg/ file descriptor exhaustion) is logged, and the next connection accepted.
+    pub async fn run(self) {
+        info!(addr = ?self.listener.local_addr(), "HealthServer accepting connections");
+
+        loop {
+            let (stream, client) = match self.listener.accept().await {
+                Ok(connection) => connection,
+                Err(error) => {
+                    warn!(?error, "HealthServer failed to accept connection");
+                    continue;
+                }
+            };
+            let monitor = self.monitor.clone();
+            tokio::spawn(async move {
+                if let Err(error) = respond(stream, &monitor).await {
+                    debug!(%client, ?error, "HealthServer failed to respond to client");
+                }
+            });
+        }
+    }
+}
+
+/// Respond to a single HTTP request with the current [`HealthReport`].
+async fn respond(stream: TcpStream, monitor: &HealthMonitor) -> Result<(), ServerError> {
+    let mut stream = BufReader::new(stream);
+
+    let request = tokio::time::timeout(HEALTH_REQUEST_TIMEOUT, read_request(&mut stream))
+        .await
+        .map_err(|_| {
+            std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                "timed out reading HTTP request",
+            )
+        })??;
+
+    let mut parts = request.split_whitespace();
+    let report = monitor.report(Utc::now());
+    let ok = match (parts.next(), parts.next()) {
+        (Some("GET"), Some("/health")) => Some(report.status != HealthStatus::Unhealthy),
+        (Some("GET"), Some("/live")) => Some(report.live),
+        (Some("GET"), Some("/ready")) => Some(report.ready),
+        _ => None,
+    };
+
+    let (status, body) = match ok {
+        Some(true) => ("200 OK", serde_json::to_string(&report)),
+        Some(false) => ("503 Service Unavailable", serde_json::to_string(&report)),
+        None => ("404 Not Found", Ok(String::from("{}"))),
+    };
+    let body = body.unwrap_or_else(|error| {
+        warn!(?error, "HealthServer failed to serialise HealthReport");
+        String::from("{}")
+    });
+
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+        Connection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.get_mut().write_all(response.as_bytes()).await?;
+    stream.get_mut().shutdown().await?;
+
+    Ok(())
+}
+
+/// Read the HTTP request line (eg/ "GET /health HTTP/1.1") and skip the headers that follow it
+/// until an empty line, reading at most [`MAX_HEALTH_REQUEST_BYTES`].
+async fn read_request(stream: &mut BufReader<TcpStream>) -> Result<String, ServerError> {
+    let mut stream = stream.take(MAX_HEALTH_REQUEST_BYTES);
+
+    let mut request = String::new();
+    stream.read_line(&mut request).await?;
+    let mut header = String::new();
+    while stream.read_line(&mut header).await? > 2 {
+        header.clear();
+    }
+
+    if stream.limit() == 0 {
+        return Err(ServerError::from(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "HTTP request exceeds MAX_HEALTH_REQUEST_BYTES",
+        )));
+    }
+
+    Ok(request)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::supervisor::{Component, ComponentKind};
+    use tokio::io::AsyncReadExt;
+
+    #[test]
+    fn test_health_monitor_report() {
+        let now = Utc::now();
+        let seconds_ago = |seconds| now - chrono::Duration::seconds(seconds);
+
+        let monitor = HealthMonitor::new(HealthConfig {
+            max_market_data_age: Duration::from_secs(30),
+            max_account_age: Duration::from_secs(300),
+            max_engine_age: Duration::from_secs(10),
+        });
+
+        // Market data: one fresh, one stale & one never connected
+        monitor.record_market_data(ExchangeId::BinanceSpot, seconds_ago(1));
+        monitor.record_market_data(ExchangeId::Kraken, seconds_ago(60));
+        monitor.register_market_data(ExchangeId::Coinbase);
+        monitor.record_engine_heartbeat(seconds_ago(2));
+
+        struct TestCase {
+            exchange: ExchangeId,
+            expected: HealthStatus,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: fresh market data is healthy
+                exchange: ExchangeId::BinanceSpot,
+                expected: HealthStatus::Healthy,
+            },
+            TestCase {
+                // TC1: stale market data is degraded
+                exchange: ExchangeId::Kraken,
+                expected: HealthStatus::Degraded,
+            },
+            TestCase {
+                // TC2: disconnected market data is unhealthy
+                exchange: ExchangeId::Coinbase,
+                expected: HealthStatus::Unhealthy,
+            },
+        ];
+
+        let report = monitor.report(now);
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = report.market_data[&test.exchange].status;
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+        assert_eq!(report.status, HealthStatus::Unhealthy);
+        assert!(report.live);
+        assert!(!report.ready);
+
+        // Reconnected market data & restarting component leaves the system degraded
+        monitor.record_market_data(ExchangeId::Coinbase, seconds_ago(1));
+        monitor.record_health_event(&HealthEvent {
+            time: now,
+            component: Component::new(ComponentKind::Execution, "binance_spot"),
+            status: ComponentStatus::Restarting {
+                restarts: 1,
+                delay: Duration::from_millis(100),
+            },
+        });
+        let report = monitor.report(now);
+        assert_eq!(report.status, HealthStatus::Degraded);
+        assert!(report.ready);
+
+        // Engine heartbeat is stale, so the Engine loop is no longer live
+        let report = monitor.report(now + chrono::Duration::seconds(10));
+        assert_eq!(report.engine.status, HealthStatus::Unhealthy);
+        assert!(!report.live);
+    }
+
+    #[tokio::test]
+    async fn test_health_server_serves_probes() {
+        let monitor = HealthMonitor::new(HealthConfig::default());
+        monitor.record_engine_heartbeat(Utc::now());
+        monitor.set_account_connected(ExchangeId::BinanceSpot, false);
+
+        let server = HealthServer::bind("127.0.0.1:0".parse().unwrap(), monitor)
+            .await
+            .unwrap();
+        let addr = server.local_addr().unwrap();
+        tokio::spawn(server.run());
+
+        let get = |path: &'static str| async move {
+            let mut stream = TcpStream::connect(addr).await.unwrap();
+            stream
+                .write_all(format!("GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n").as_bytes())
+                .await
+                .unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).await.unwrap();
+            response
+        };
+
+        assert!(get("/live").await.starts_with("HTTP/1.1 200 OK"));
+        assert!(get("/ready").await.starts_with("HTTP/1.1 503"));
+        assert!(get("/metrics").await.starts_with("HTTP/1.1 404"));
+
+        let response = get("/health").await;
+        let (_, body) = response.split_once("\r\n\r\n").unwrap();
+        let report = serde_json::from_str::<HealthReport>(body).unwrap();
+        assert_eq!(report.status, HealthStatus::Unhealthy);
+        assert!(!report.account[&ExchangeId::BinanceSpot].connected);
+
+        // Oversized request is rejected without a response, & the HealthServer keeps serving
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        let oversized = vec![b'a'; MAX_HEALTH_REQUEST_BYTES as usize + 1];
+        stream.write_all(&oversized).await.unwrap();
+        let mut response = String::new();
+        let _ = stream.read_to_string(&mut response).await;
+        assert!(response.is_empty());
+        assert!(get("/live").await.starts_with("HTTP/1.1 200 OK"));
+    }
+}
@@ -0,0 +1,515 @@
+use crate::server::{error::ServerError, ControlPlane, ControlRequest};
+use async_trait::async_trait;
+use barter_data::event::CustomData;
+use barter_instrument::market::Market;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
+use tokio::sync::mpsc;
+use tracing::{debug, info, warn};
+
+/// Message published to an external message bus (eg/ by an ML model running in Python) to drive
+/// the [`Engine`](crate::engine::Engine), encoded as JSON.
+///
+/// eg/ `{"sequence":1,"kind":{"Custom":{"market":null,"name":"news","payload":{"sentiment":0.8}}}}`
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct IngestMessage {
+    /// Publisher session identifier (eg/ it's start time in milliseconds), increased every time
+    /// the publisher restarts. A new session resets the sequence tracking, so a restarted
+    /// publisher is not mistaken for redelivery. Defaults to 0 if the publisher has no sessions.
+    #[serde(default)]
+    pub session: u64,
+    /// Publisher sequence number, starting from 1 & incremented by one for every message of the
+    /// session. Used to drop redelivered messages & detect those that were missed.
+    pub sequence: u64,
+    pub kind: IngestKind,
+}
+
+/// Kind of [`IngestMessage`] routed to the [`Engine`](crate::engine::Engine).
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum IngestKind {
+    /// [`ControlRequest`] actioned via the [`ControlPlane`]. Any response is discarded.
+    Control(ControlRequest),
+
+    /// Custom event sent to the [`Trader`](crate::engine::trader::Trader) of the provided
+    /// [`Market`], or to every [`Trader`](crate::engine::trader::Trader) if `None`. The payload
+    /// is decoded by the Strategy as the [`CustomKind`](barter_data::event::CustomKind) with the
+    /// provided name.
+    Custom {
+        market: Option<Market>,
+        name: SmolStr,
+        payload: serde_json::Value,
+    },
+}
+
+/// Audit record of a single message received by an [`Ingestor`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct IngestRecord {
+    /// Time the message was received.
+    pub time: DateTime<Utc>,
+    /// [`IngestMessage`] sequence, or `None` if the message could not be deserialised.
+    pub sequence: Option<u64>,
+    /// Number of sequences missed between the previously routed message and this one.
+    pub missed: u64,
+    pub outcome: IngestOutcome,
+}
+
+/// Outcome of a single message received by an [`Ingestor`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum IngestOutcome {
+    /// [`IngestMessage`] was routed to the [`Engine`](crate::engine::Engine).
+    Routed,
+    /// [`IngestMessage`] sequence was already routed, or belongs to a previous publisher session,
+    /// so it was dropped.
+    Duplicate,
+    /// Message was invalid, or rejected by the [`ControlPlane`].
+    Rejected(String),
+}
+
+/// Source of raw messages from an external message bus, consumed by an [`Ingestor`].
+///
+/// `NatsSource` (`nats` feature) subscribes to a NATS subject, and a `mpsc::Receiver<Vec<u8>>`
+/// can be fed by any other transport (eg/ a ZeroMQ SUB socket).
+#[async_trait]
+pub trait IngestSource {
+    /// Receive the next raw message payload, or `None` if the source has closed.
+    async fn recv(&mut self) -> Result<Option<Vec<u8>>, ServerError>;
+}
+
+#[async_trait]
+impl IngestSource for mpsc::Receiver<Vec<u8>> {
+    async fn recv(&mut self) -> Result<Option<Vec<u8>>, ServerError> {
+        Ok(mpsc::Receiver::recv(self).await)
+    }
+}
+
+/// Input adapter converting [`IngestMessage`]s from an external message bus into
+/// [`Engine`](crate::engine::Engine) [`Command`](crate::engine::Command)s, via the
+/// [`ControlPlane`].
+///
+/// Messages are routed in sequence order: redelivered sequences are dropped, and missed
+/// sequences are logged. A new publisher session (ie/ a restarted publisher) resets the sequence
+/// tracking. Every received message generates an [`IngestRecord`], which is sent to the optional
+/// audit transmitter.
+#[derive(Debug)]
+pub struct Ingestor {
+    control: ControlPlane,
+    audit_tx: Option<mpsc::UnboundedSender<IngestRecord>>,
+    last_session: u64,
+    last_sequence: Option<u64>,
+}
+
+impl Ingestor {
+    /// Construct a new [`Ingestor`] that routes [`IngestMessage`]s via the provided
+    /// [`ControlPlane`].
+    pub fn new(control: ControlPlane) -> Self {
+        Self {
+            control,
+            audit_tx: None,
+            last_session: 0,
+            last_sequence: None,
+        }
+    }
+
+    /// Send an [`IngestRecord`] of every received message to the provided audit transmitter.
+    pub fn with_audit_tx(self, audit_tx: mpsc::UnboundedSender<IngestRecord>) -> Self {
+        Self {
+            audit_tx: Some(audit_tx),
+            ..self
+        }
+    }
+
+    /// Sequence of the most recently routed [`IngestMessage`].
+    pub fn last_sequence(&self) -> Option<u64> {
+        self.last_sequence
+    }
+
+    /// Run the [`Ingestor`], routing every message from the [`IngestSource`] until it closes or
+    /// the [`Engine`](crate::engine::Engine) goes offline.
+    pub async fn run<Source>(mut self, mut source: Source) -> Result<(), ServerError>
+    where
+        Source: IngestSource + Send,
+    {
+        info!("Ingestor routing messages to Engine");
+
+        while let Some(payload) = source.recv().await? {
+            let record = self.ingest(&payload).await?;
+            if let Some(audit_tx) = &self.audit_tx {
+                let _ = audit_tx.send(record);
+            }
+        }
+
+        info!(last_sequence = ?self.last_sequence, "Ingestor source closed");
+        Ok(())
+    }
+
+    /// Route a single raw message payload to the [`Engine`](crate::engine::Engine), returning
+    /// the [`IngestRecord`] of the outcome. Only fails if the [`Engine`](crate::engine::Engine)
+    /// is offline.
+    pub async fn ingest(&mut self, payload: &[u8]) -> Result<IngestRecord, ServerError> {
+        let time = Utc::now();
+
+        let message = match serde_json::from_slice::<IngestMessage>(payload) {
+            Ok(message) => message,
+            Err(error) => {
+                warn!(%error, "Ingestor rejected invalid message");
+                return Ok(IngestRecord {
+                    time,
+                    sequence: None,
+                    missed: 0,
+                    outcome: IngestOutcome::Rejected(error.to_string()),
+                });
+            }
+        };
+
+        let IngestMessage {
+            session, sequence, ..
+        } = message;
+
+        let missed = match self.last_sequence {
+            // Publisher restarted, so every sequence of the new session before this was missed
+            Some(_) if session > self.last_session => {
+                info!(
+                    session,
+                    last_session = self.last_session,
+                    "Ingestor detected new publisher session"
+                );
+                sequence.saturating_sub(1)
+            }
+            Some(last) if session < self.last_session || sequence <= last => {
+                debug!(session, sequence, "Ingestor dropped duplicate message");
+                return Ok(IngestRecord {
+                    time,
+                    sequence: Some(sequence),
+                    missed: 0,
+                    outcome: IngestOutcome::Duplicate,
+                });
+            }
+            Some(last) => sequence.saturating_sub(last + 1),
+            None => 0,
+        };
+        if missed > 0 {
+            warn!(
+                sequence,
+                missed,
+                last_sequence = ?self.last_sequence,
+                "Ingestor missed messages"
+            );
+        }
+        self.last_session = session;
+        self.last_sequence = Some(sequence);
+
+        let result = match message.kind {
+            IngestKind::Control(request) => self.control.handle(request).await.map(|_| ()),
+            IngestKind::Custom {
+                market,
+                name,
+                payload,
+            } => self
+                .control
+                .send_custom_event(
+                    market,
+                    CustomData {
+                        name,
+                        payload: payload.to_string(),
+                    },
+                )
+                .await
+                .map(|_| ()),
+        };
+
+        let outcome = match result {
+            Ok(()) => IngestOutcome::Routed,
+            Err(ServerError::EngineOffline) => return Err(ServerError::EngineOffline),
+            Err(error) => {
+                warn!(sequence, %error, "Ingestor message rejected by ControlPlane");
+                IngestOutcome::Rejected(error.to_string())
+            }
+        };
+
+        Ok(IngestRecord {
+            time,
+            sequence: Some(sequence),
+            missed,
+            outcome,
+        })
+    }
+}
+
+/// [`IngestSource`] subscribed to a single NATS subject via an [`async_nats::Client`], which
+/// handles authentication, TLS, message headers & reconnecting to the server.
+#[cfg(feature = "nats")]
+#[derive(Debug)]
+pub struct NatsSource {
+    client: async_nats::Client,
+    subscriber: async_nats::Subscriber,
+}
+
+#[cfg(feature = "nats")]
+impl NatsSource {
+    /// Connect to the NATS server at the provided address (eg/ "nats://localhost:4222") and
+    /// subscribe to the subject (eg/ "barter.signals").
+    pub async fn connect(
+        addrs: impl async_nats::ToServerAddrs,
+        subject: &str,
+    ) -> Result<Self, ServerError> {
+        Self::connect_with_options(addrs, async_nats::ConnectOptions::new(), subject).await
+    }
+
+    /// Connect to the NATS server at the provided address using the provided
+    /// [`async_nats::ConnectOptions`] (eg/ credentials, token, TLS, reconnect delay), and
+    /// subscribe to the subject.
+    pub async fn connect_with_options(
+        addrs: impl async_nats::ToServerAddrs,
+        options: async_nats::ConnectOptions,
+        subject: &str,
+    ) -> Result<Self, ServerError> {
+        let client = options
+            .name("barter")
+            .connect(addrs)
+            .await
+            .map_err(|error| ServerError::MessageBus(error.to_string()))?;
+
+        let subscriber = client
+            .subscribe(subject.to_owned())
+            .await
+            .map_err(|error| ServerError::MessageBus(error.to_string()))?;
+
+        info!(%subject, "NatsSource subscribed");
+        Ok(Self { client, subscriber })
+    }
+
+    /// [`async_nats::Client`] of the subscription, eg/ to monitor the connection state.
+    pub fn client(&self) -> &async_nats::Client {
+        &self.client
+    }
+}
+
+#[cfg(feature = "nats")]
+#[async_trait]
+impl IngestSource for NatsSource {
+    async fn recv(&mut self) -> Result<Option<Vec<u8>>, ServerError> {
+        use futures::StreamExt;
+
+        Ok(self
+            .subscriber
+            .next()
+            .await
+            .map(|message| message.payload.to_vec()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::Command;
+
+    fn message(session: u64, sequence: u64, sentiment: f64) -> Vec<u8> {
+        serde_json::to_vec(&IngestMessage {
+            session,
+            sequence,
+            kind: IngestKind::Custom {
+                market: None,
+                name: SmolStr::new("news"),
+                payload: serde_json::json!({ "sentiment": sentiment }),
+            },
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_ingestor_ingest() {
+        struct TestCase {
+            input: Vec<u8>,
+            expected_sequence: Option<u64>,
+            expected_missed: u64,
+            expected_routed: bool,
+        }
+
+        let (command_tx, mut command_rx) = mpsc::channel(10);
+        let mut ingestor = Ingestor::new(ControlPlane::new(command_tx));
+
+        let tests = vec![
+            TestCase {
+                // TC0: first message is routed
+                input: message(0, 1, 0.5),
+                expected_sequence: Some(1),
+                expected_missed: 0,
+                expected_routed: true,
+            },
+            TestCase {
+                // TC1: redelivered message is dropped
+                input: message(0, 1, 0.5),
+                expected_sequence: Some(1),
+                expected_missed: 0,
+                expected_routed: false,
+            },
+            TestCase {
+                // TC2: message after a gap is routed, recording the missed sequences
+                input: message(0, 4, -0.5),
+                expected_sequence: Some(4),
+                expected_missed: 2,
+                expected_routed: true,
+            },
+            TestCase {
+                // TC3: invalid message is rejected
+                input: b"not json".to_vec(),
+                expected_sequence: None,
+                expected_missed: 0,
+                expected_routed: false,
+            },
+            TestCase {
+                // TC4: restarted publisher session resets the sequence tracking
+                input: message(1, 1, 0.2),
+                expected_sequence: Some(1),
+                expected_missed: 0,
+                expected_routed: true,
+            },
+            TestCase {
+                // TC5: redelivered message from the previous publisher session is dropped
+                input: message(0, 5, 0.1),
+                expected_sequence: Some(5),
+                expected_missed: 0,
+                expected_routed: false,
+            },
+            TestCase {
+                // TC6: restarted publisher session after a gap records the missed sequences
+                input: message(2, 3, 0.3),
+                expected_sequence: Some(3),
+                expected_missed: 2,
+                expected_routed: true,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = ingestor.ingest(&test.input).await.unwrap();
+            assert_eq!(
+                actual.sequence, test.expected_sequence,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(actual.missed, test.expected_missed, "TC{} failed", index);
+            assert_eq!(
+                actual.outcome == IngestOutcome::Routed,
+                test.expected_routed,
+                "TC{} failed",
+                index
+            );
+
+            let routed = command_rx.try_recv();
+            match routed {
+                Ok(Command::Custom { market, event }) => {
+                    assert!(test.expected_routed, "TC{} failed", index);
+                    assert_eq!(market, None, "TC{} failed", index);
+                    assert_eq!(event.name, "news", "TC{} failed", index);
+                }
+                Ok(command) => panic!("TC{index} failed: unexpected {command:?}"),
+                Err(_) => assert!(!test.expected_routed, "TC{} failed", index),
+            }
+        }
+
+        assert_eq!(ingestor.last_sequence(), Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_ingestor_run_from_channel_source() {
+        let (command_tx, mut command_rx) = mpsc::channel(10);
+        let (audit_tx, mut audit_rx) = mpsc::unbounded_channel();
+        let (source_tx, source_rx) = mpsc::channel(10);
+
+        for payload in [message(0, 1, 0.5), message(0, 2, 0.8)] {
+            source_tx.send(payload).await.unwrap();
+        }
+        drop(source_tx);
+
+        Ingestor::new(ControlPlane::new(command_tx))
+            .with_audit_tx(audit_tx)
+            .run(source_rx)
+            .await
+            .unwrap();
+
+        let mut payloads = vec![];
+        while let Ok(Command::Custom { event, .. }) = command_rx.try_recv() {
+            payloads.push(event.payload);
+        }
+        assert_eq!(
+            payloads,
+            vec![r#"{"sentiment":0.5}"#, r#"{"sentiment":0.8}"#]
+        );
+
+        let mut sequences = vec![];
+        while let Ok(record) = audit_rx.try_recv() {
+            assert_eq!(record.outcome, IngestOutcome::Routed);
+            sequences.push(record.sequence);
+        }
+        assert_eq!(sequences, vec![Some(1), Some(2)]);
+    }
+
+    #[cfg(feature = "nats")]
+    #[tokio::test]
+    async fn test_nats_source_receives_messages_with_headers() {
+        use tokio::{
+            io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+            net::TcpListener,
+        };
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        // Mock NATS server that publishes a MSG & a HMSG (ie/ with headers) once subscribed
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (reader, mut writer) = stream.into_split();
+            let mut reader = BufReader::new(reader);
+
+            writer
+                .write_all(b"INFO {\"server_id\":\"mock\",\"headers\":true}\r\n")
+                .await
+                .unwrap();
+
+            let mut line = String::new();
+            while reader.read_line(&mut line).await.unwrap() > 0 {
+                if line.starts_with("PING") {
+                    writer.write_all(b"PONG\r\n").await.unwrap();
+                } else if let Some(sid) = line.strip_prefix("SUB barter.signals ") {
+                    let sid = sid.trim_end();
+
+                    let payload = message(0, 1, 0.5);
+                    let mut frame =
+                        format!("MSG barter.signals {sid} {}\r\n", payload.len()).into_bytes();
+                    frame.extend(payload);
+                    frame.extend(b"\r\n");
+
+                    let headers = b"NATS/1.0\r\nNats-Msg-Id: 2\r\n\r\n";
+                    let payload = message(0, 2, 0.8);
+                    frame.extend(
+                        format!(
+                            "HMSG barter.signals {sid} {} {}\r\n",
+                            headers.len(),
+                            headers.len() + payload.len()
+                        )
+                        .into_bytes(),
+                    );
+                    frame.extend(headers);
+                    frame.extend(payload);
+                    frame.extend(b"\r\n");
+
+                    writer.write_all(&frame).await.unwrap();
+                }
+                line.clear();
+            }
+        });
+
+        let mut source = NatsSource::connect(addr.to_string(), "barter.signals")
+            .await
+            .unwrap();
+
+        for expected in [message(0, 1, 0.5), message(0, 2, 0.8)] {
+            let actual = tokio::time::timeout(std::time::Duration::from_secs(1), source.recv())
+                .await
+                .expect("NatsSource failed to receive message")
+                .unwrap();
+            assert_eq!(actual, Some(expected));
+        }
+    }
+}
@@ -0,0 +1,387 @@
+use crate::{
+    engine::{
+        priority::{PriorityCommand, PriorityCommandTx},
+        Command, EngineState,
+    },
+    portfolio::position::Position,
+    server::{error::ServerError, push::PushTx},
+};
+use barter_data::event::CustomData;
+use barter_instrument::market::Market;
+use serde::{Deserialize, Serialize};
+use tokio::sync::{mpsc, oneshot};
+
+/// Barter server module specific errors.
+pub mod error;
+
+/// Input adapter routing messages from an external message bus (eg/ NATS) into the Engine as
+/// [`Command`]s & custom events, with sequencing & an audit trail.
+pub mod ingest;
+
+/// Embedded WebSocket server streaming Engine outputs (fills, positions, balances, trading state)
+/// as JSON to connected clients.
+pub mod push;
+
+/// Structured [`HealthReport`](health::HealthReport) of market data & account stream
+/// connectivity, last event ages & [`Engine`](crate::engine::Engine) loop liveness, optionally
+/// served over HTTP for probes & dashboards.
+pub mod health;
+
+/// gRPC server exposing the [`ControlPlane`] via the service defined in `proto/control.proto`.
+#[cfg(feature = "grpc")]
+pub mod grpc;
+
+/// Requests supported by the [`ControlPlane`]. See `proto/control.proto` for the equivalent gRPC
+/// service definition.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum ControlRequest {
+    /// Fetch every open [`Position`] of the [`Engine`](crate::engine::Engine).
+    FetchOpenPositions,
+
+    /// Request an [`EngineState`] snapshot of the [`Engine`](crate::engine::Engine) balance,
+    /// open [`Position`]s & in-flight orders.
+    RequestSnapshot,
+
+    /// Exit the open [`Position`] of the provided [`Market`], or every open [`Position`] if
+    /// `None`.
+    ExitPositions(Option<Market>),
+
+    /// Cancel every order that has not yet been sent to the `ExecutionClient`.
+    CancelAll,
+
+    /// Enable or disable trading.
+    SetTradingState { state: TradingState, reason: String },
+
+    /// Gracefully shut down the [`Engine`](crate::engine::Engine) according to it's configured
+    /// [`ShutdownPolicy`](crate::engine::shutdown::ShutdownPolicy), terminating every
+    /// [`Trader`](crate::engine::trader::Trader).
+    Terminate(String),
+
+    /// Update the Strategy configuration of the [`Trader`](crate::engine::trader::Trader) of the
+    /// provided [`Market`], or of every [`Trader`](crate::engine::trader::Trader) if `None`.
+    UpdateStrategyConfig {
+        market: Option<Market>,
+        config: serde_json::Value,
+    },
+}
+
+/// Trading state of a running [`Engine`](crate::engine::Engine).
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub enum TradingState {
+    Enabled,
+    Disabled,
+}
+
+/// Responses generated by the [`ControlPlane`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum ControlResponse {
+    OpenPositions(Vec<Position>),
+
+    Snapshot(EngineState),
+
+    /// [`ControlRequest`] was delivered. Contains the number of
+    /// [`Trader`](crate::engine::trader::Trader)s it was delivered to, if known.
+    Ack {
+        traders: Option<usize>,
+    },
+}
+
+/// Control plane for a running [`Engine`](crate::engine::Engine), allowing external tooling
+/// (eg/ risk dashboards, ops scripts) to action [`ControlRequest`]s and fetch state snapshots.
+///
+/// Transport agnostic: the `GrpcServer` generated from `proto/control.proto` (`grpc` feature), or
+/// any other transport, delegates each rpc to [`ControlPlane::handle`]. [`ControlRequest`]s are
+/// routed to the [`Engine`](crate::engine::Engine) [`Command`] channel, and emergency requests are
+/// routed to the [`PriorityCommand`] channel so they are actioned ahead of queued market data.
+#[derive(Clone, Debug)]
+pub struct ControlPlane {
+    command_tx: mpsc::Sender<Command>,
+    priority_tx: Option<PriorityCommandTx>,
+    push_tx: Option<PushTx>,
+}
+
+impl ControlPlane {
+    /// Construct a new [`ControlPlane`] using the [`Engine`](crate::engine::Engine) [`Command`]
+    /// transmitter.
+    pub fn new(command_tx: mpsc::Sender<Command>) -> Self {
+        Self {
+            command_tx,
+            priority_tx: None,
+            push_tx: None,
+        }
+    }
+
+    /// Route [`ControlRequest::CancelAll`] & [`ControlRequest::SetTradingState`] requests via the
+    /// provided [`PriorityCommandTx`].
+    pub fn with_priority_tx(self, priority_tx: PriorityCommandTx) -> Self {
+        Self {
+            priority_tx: Some(priority_tx),
+            ..self
+        }
+    }
+
+    /// Broadcast every [`TradingState`] switch to the clients of the
+    /// [`PushServer`](push::PushServer) associated with the provided [`PushTx`].
+    pub fn with_push_tx(self, push_tx: PushTx) -> Self {
+        Self {
+            push_tx: Some(push_tx),
+            ..self
+        }
+    }
+
+    /// Action the provided [`ControlRequest`].
+    pub async fn handle(&self, request: ControlRequest) -> Result<ControlResponse, ServerError> {
+        match request {
+            ControlRequest::FetchOpenPositions => self
+                .fetch_open_positions()
+                .await
+                .map(ControlResponse::OpenPositions),
+            ControlRequest::RequestSnapshot => {
+                self.request_snapshot().await.map(ControlResponse::Snapshot)
+            }
+            ControlRequest::ExitPositions(market) => self.exit_positions(market).await,
+            ControlRequest::CancelAll => self.cancel_all(),
+            ControlRequest::SetTradingState { state, reason } => {
+                self.set_trading_state(state, reason)
+            }
+            ControlRequest::Terminate(reason) => self.terminate(reason).await,
+            ControlRequest::UpdateStrategyConfig { market, config } => {
+                self.update_strategy_config(market, config).await
+            }
+        }
+    }
+
+    /// Fetch a snapshot of every open [`Position`] of the [`Engine`](crate::engine::Engine).
+    pub async fn fetch_open_positions(&self) -> Result<Vec<Position>, ServerError> {
+        let (positions_tx, positions_rx) = oneshot::channel();
+        self.send(Command::FetchOpenPositions(positions_tx)).await?;
+
+        positions_rx
+            .await
+            .map_err(|_| ServerError::EngineOffline)?
+            .map_err(ServerError::from)
+    }
+
+    /// Request an [`EngineState`] snapshot of the [`Engine`](crate::engine::Engine), without
+    /// subscribing to the full audit stream.
+    pub async fn request_snapshot(&self) -> Result<EngineState, ServerError> {
+        let (state_tx, state_rx) = oneshot::channel();
+        self.send(Command::RequestSnapshot(state_tx)).await?;
+
+        state_rx
+            .await
+            .map_err(|_| ServerError::EngineOffline)?
+            .map_err(ServerError::from)
+    }
+
+    /// Exit the open [`Position`] of the provided [`Market`], or every open [`Position`] if
+    /// `None`.
+    pub async fn exit_positions(
+        &self,
+        market: Option<Market>,
+    ) -> Result<ControlResponse, ServerError> {
+        let command = match market {
+            Some(market) => Command::ExitPosition(market),
+            None => Command::ExitAllPositions,
+        };
+
+        self.send(command)
+            .await
+            .map(|_| ControlResponse::Ack { traders: None })
+    }
+
+    /// Cancel every order that has not yet been sent to the `ExecutionClient`.
+    pub fn cancel_all(&self) -> Result<ControlResponse, ServerError> {
+        self.send_priority(PriorityCommand::CancelAll)
+    }
+
+    /// Enable or disable trading. While disabled, every [`Trader`](crate::engine::trader::Trader)
+    /// stops generating orders from Strategy `Signal`s, but still actions market data, fills &
+    /// [`Position`] exits.
+    ///
+    /// Once switched, the new [`TradingState`] is pushed to every connected
+    /// [`PushServer`](push::PushServer) client, if configured.
+    pub fn set_trading_state(
+        &self,
+        state: TradingState,
+        reason: String,
+    ) -> Result<ControlResponse, ServerError> {
+        let response = match state {
+            TradingState::Enabled => self.send_priority(PriorityCommand::EnableTrading),
+            TradingState::Disabled => self.send_priority(PriorityCommand::DisableTrading(reason)),
+        }?;
+
+        if let Some(push_tx) = &self.push_tx {
+            push_tx.push_trading_state(state);
+        }
+
+        Ok(response)
+    }
+
+    /// Gracefully shut down the [`Engine`](crate::engine::Engine) according to it's configured
+    /// [`ShutdownPolicy`](crate::engine::shutdown::ShutdownPolicy), terminating every
+    /// [`Trader`](crate::engine::trader::Trader).
+    pub async fn terminate(&self, reason: String) -> Result<ControlResponse, ServerError> {
+        self.send(Command::Terminate(reason))
+            .await
+            .map(|_| ControlResponse::Ack { traders: None })
+    }
+
+    /// Update the Strategy configuration of the [`Trader`](crate::engine::trader::Trader) of the
+    /// provided [`Market`], or of every [`Trader`](crate::engine::trader::Trader) if `None`.
+    pub async fn update_strategy_config(
+        &self,
+        market: Option<Market>,
+        config: serde_json::Value,
+    ) -> Result<ControlResponse, ServerError> {
+        self.send(Command::UpdateStrategyConfig { market, config })
+            .await
+            .map(|_| ControlResponse::Ack { traders: None })
+    }
+
+    /// Send a custom event to the [`Trader`](crate::engine::trader::Trader) of the provided
+    /// [`Market`], or to every [`Trader`](crate::engine::trader::Trader) if `None`.
+    pub async fn send_custom_event(
+        &self,
+        market: Option<Market>,
+        event: CustomData,
+    ) -> Result<ControlResponse, ServerError> {
+        self.send(Command::Custom { market, event })
+            .await
+            .map(|_| ControlResponse::Ack { traders: None })
+    }
+
+    async fn send(&self, command: Command) -> Result<(), ServerError> {
+        self.command_tx
+            .send(command)
+            .await
+            .map_err(|_| ServerError::EngineOffline)
+    }
+
+    fn send_priority(&self, command: PriorityCommand) -> Result<ControlResponse, ServerError> {
+        let priority_tx = self
+            .priority_tx
+            .as_ref()
+            .ok_or(ServerError::PriorityChannelMissing)?;
+
+        Ok(ControlResponse::Ack {
+            traders: Some(priority_tx.send(command)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{engine::priority::priority_channel, portfolio::Balance, test_util::position};
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_control_plane_handle() {
+        let (command_tx, mut command_rx) = mpsc::channel(10);
+        let (priority_tx, mut priority_rx) = priority_channel(10);
+        let control = ControlPlane::new(command_tx).with_priority_tx(priority_tx);
+        let open_position = position();
+        let state = EngineState {
+            engine_id: Uuid::new_v4(),
+            time: Utc::now(),
+            balance: Balance::default(),
+            positions: vec![open_position.clone()],
+            orders_pending: vec![],
+        };
+
+        // Mock Engine responding to Command::FetchOpenPositions & Command::RequestSnapshot
+        let engine_position = open_position.clone();
+        let engine_state = state.clone();
+        let engine = tokio::spawn(async move {
+            let mut received = vec![];
+            while let Some(command) = command_rx.recv().await {
+                match command {
+                    Command::FetchOpenPositions(positions_tx) => {
+                        positions_tx
+                            .send(Ok(vec![engine_position.clone()]))
+                            .unwrap();
+                    }
+                    Command::RequestSnapshot(state_tx) => {
+                        state_tx.send(Ok(engine_state.clone())).unwrap();
+                    }
+                    command => received.push(command),
+                }
+            }
+            received
+        });
+
+        assert_eq!(
+            control
+                .handle(ControlRequest::FetchOpenPositions)
+                .await
+                .unwrap(),
+            ControlResponse::OpenPositions(vec![open_position])
+        );
+
+        assert_eq!(
+            control
+                .handle(ControlRequest::RequestSnapshot)
+                .await
+                .unwrap(),
+            ControlResponse::Snapshot(state)
+        );
+
+        control
+            .handle(ControlRequest::ExitPositions(None))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            control.handle(ControlRequest::CancelAll).await.unwrap(),
+            ControlResponse::Ack { traders: Some(1) }
+        );
+        assert_eq!(priority_rx.try_recv(), Some(PriorityCommand::CancelAll));
+
+        control
+            .handle(ControlRequest::SetTradingState {
+                state: TradingState::Disabled,
+                reason: "risk limit".to_owned(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            priority_rx.try_recv(),
+            Some(PriorityCommand::DisableTrading("risk limit".to_owned()))
+        );
+
+        control
+            .handle(ControlRequest::SetTradingState {
+                state: TradingState::Enabled,
+                reason: String::new(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(priority_rx.try_recv(), Some(PriorityCommand::EnableTrading));
+
+        control
+            .handle(ControlRequest::UpdateStrategyConfig {
+                market: None,
+                config: serde_json::json!({ "rsi_period": 7 }),
+            })
+            .await
+            .unwrap();
+
+        control
+            .handle(ControlRequest::Terminate("done".to_owned()))
+            .await
+            .unwrap();
+
+        drop(control);
+        let received = engine.await.unwrap();
+        assert!(matches!(
+            received.as_slice(),
+            [
+                Command::ExitAllPositions,
+                Command::UpdateStrategyConfig { market: None, config },
+                Command::Terminate(reason),
+            ] if config["rsi_period"] == 7 && reason == "done"
+        ));
+    }
+}
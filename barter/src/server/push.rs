@@ -0,0 +1,324 @@
+use crate::{
+    event::{Event, MessageTransmitter},
+    execution::FillEvent,
+    portfolio::{
+        position::{Position, PositionExit, PositionUpdate},
+        Balance,
+    },
+    server::{error::ServerError, TradingState},
+};
+use futures::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, net::SocketAddr};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::broadcast,
+};
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{debug, info, warn};
+
+/// Default capacity of the [`PushTx`] broadcast channel. Clients that lag further behind than
+/// this skip the oldest [`PushUpdate`]s.
+pub const DEFAULT_PUSH_CHANNEL_CAPACITY: usize = 1024;
+
+/// Category of [`PushUpdate`] streamed to clients.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub enum PushTopic {
+    Fills,
+    Positions,
+    Balances,
+    TradingState,
+}
+
+impl PushTopic {
+    /// Every [`PushTopic`].
+    pub const ALL: [PushTopic; 4] = [
+        PushTopic::Fills,
+        PushTopic::Positions,
+        PushTopic::Balances,
+        PushTopic::TradingState,
+    ];
+}
+
+/// Engine output streamed to clients as JSON, eg/ `{"PositionNew": { .. }}`.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum PushUpdate {
+    Fill(FillEvent),
+    PositionNew(Box<Position>),
+    PositionUpdate(PositionUpdate),
+    PositionExit(PositionExit),
+    Balance(Balance),
+    TradingState(TradingState),
+}
+
+impl PushUpdate {
+    /// Map an [`Event`] to a [`PushUpdate`], if it is an Engine output that can be streamed.
+    pub fn from_event(event: Event) -> Option<Self> {
+        match event {
+            Event::Fill(fill) => Some(Self::Fill(fill)),
+            Event::PositionNew(position) => Some(Self::PositionNew(position)),
+            Event::PositionUpdate(update) => Some(Self::PositionUpdate(update)),
+            Event::PositionExit(exit) => Some(Self::PositionExit(exit)),
+            Event::Balance(balance) => Some(Self::Balance(balance)),
+            Event::Market(_)
+            | Event::Signal(_)
+            | Event::SignalForceExit(_)
+            | Event::OrderNew(_)
+            | Event::OrderUpdate
+            | Event::OrderLifecycle(_)
+            | Event::MarketAnomaly(_)
+            | Event::UniverseUpdate(_)
+            | Event::Scheduled(_)
+            | Event::Custom(_) => None,
+        }
+    }
+
+    /// [`PushTopic`] this [`PushUpdate`] belongs to.
+    pub fn topic(&self) -> PushTopic {
+        match self {
+            PushUpdate::Fill(_) => PushTopic::Fills,
+            PushUpdate::PositionNew(_)
+            | PushUpdate::PositionUpdate(_)
+            | PushUpdate::PositionExit(_) => PushTopic::Positions,
+            PushUpdate::Balance(_) => PushTopic::Balances,
+            PushUpdate::TradingState(_) => PushTopic::TradingState,
+        }
+    }
+}
+
+/// [`Event`] transmitter that broadcasts the selected [`PushTopic`]s to every client connected
+/// to the associated [`PushServer`].
+#[derive(Clone, Debug)]
+pub struct PushTx {
+    topics: HashSet<PushTopic>,
+    updates_tx: broadcast::Sender<PushUpdate>,
+}
+
+impl MessageTransmitter<Event> for PushTx {
+    fn send(&mut self, message: Event) {
+        if let Some(update) = PushUpdate::from_event(message) {
+            self.push(update);
+        }
+    }
+
+    fn send_many(&mut self, messages: Vec<Event>) {
+        messages
+            .into_iter()
+            .for_each(|message| MessageTransmitter::send(self, message))
+    }
+}
+
+impl PushTx {
+    /// Broadcast a [`PushUpdate`] to every connected client, if it's [`PushTopic`] is selected.
+    pub fn push(&self, update: PushUpdate) {
+        if !self.topics.contains(&update.topic()) {
+            return;
+        }
+
+        // Error only indicates there are currently no connected clients
+        let _ = self.updates_tx.send(update);
+    }
+
+    /// Broadcast a change in [`TradingState`] to every connected client.
+    pub fn push_trading_state(&self, state: TradingState) {
+        self.push(PushUpdate::TradingState(state))
+    }
+
+    /// Number of currently connected clients.
+    pub fn clients(&self) -> usize {
+        self.updates_tx.receiver_count()
+    }
+}
+
+/// Embedded WebSocket server that streams [`PushUpdate`]s as JSON text messages to every
+/// connected client, allowing lightweight UIs to follow the Engine without polling.
+#[derive(Debug)]
+pub struct PushServer {
+    listener: TcpListener,
+    updates_tx: broadcast::Sender<PushUpdate>,
+}
+
+impl PushServer {
+    /// Bind a new [`PushServer`] to the provided address, returning the [`PushTx`] used to
+    /// broadcast the selected [`PushTopic`]s.
+    pub async fn bind(
+        addr: SocketAddr,
+        topics: impl IntoIterator<Item = PushTopic>,
+    ) -> Result<(Self, PushTx), ServerError> {
+        let listener = TcpListener::bind(addr).await?;
+        let (updates_tx, _) = broadcast::channel(DEFAULT_PUSH_CHANNEL_CAPACITY);
+
+        let push_tx = PushTx {
+            topics: topics.into_iter().collect(),
+            updates_tx: updates_tx.clone(),
+        };
+
+        Ok((
+            Self {
+                listener,
+                updates_tx,
+            },
+            push_tx,
+        ))
+    }
+
+    /// Local address the [`PushServer`] is bound to.
+    pub fn local_addr(&self) -> Result<SocketAddr, ServerError> {
+        self.listener.local_addr().map_err(ServerError::from)
+    }
+
+    /// Run the [`PushServer`], accepting client connections forever. Failing to accept a
+    /// connection (eg/ file descriptor exhaustion) is logged, and the next connection accepted.
+    pub async fn run(self) {
+        info!(addr = ?self.listener.local_addr(), "PushServer accepting connections");
+
+        loop {
+            let (stream, client) = match self.listener.accept().await {
+                Ok(connection) => connection,
+                Err(error) => {
+                    warn!(?error, "PushServer failed to accept connection");
+                    continue;
+                }
+            };
+            let updates_rx = self.updates_tx.subscribe();
+            tokio::spawn(stream_updates(stream, client, updates_rx));
+        }
+    }
+}
+
+/// Stream every [`PushUpdate`] to a single client until it disconnects.
+async fn stream_updates(
+    stream: TcpStream,
+    client: SocketAddr,
+    mut updates_rx: broadcast::Receiver<PushUpdate>,
+) {
+    let mut websocket = match tokio_tungstenite::accept_async(stream).await {
+        Ok(websocket) => websocket,
+        Err(error) => {
+            warn!(%client, ?error, "PushServer failed WebSocket handshake");
+            return;
+        }
+    };
+    debug!(%client, "PushServer client connected");
+
+    loop {
+        tokio::select! {
+            update = updates_rx.recv() => {
+                let update = match update {
+                    Ok(update) => update,
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!(%client, skipped, "PushServer client lagged, skipping oldest PushUpdates");
+                        continue;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
+
+                let json = match serde_json::to_string(&update) {
+                    Ok(json) => json,
+                    Err(error) => {
+                        warn!(%client, ?error, ?update, "PushServer failed to serialise PushUpdate");
+                        continue;
+                    }
+                };
+
+                if websocket.send(Message::Text(json)).await.is_err() {
+                    break;
+                }
+            }
+            message = websocket.next() => {
+                match message {
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                    Some(Ok(_)) => {}
+                }
+            }
+        }
+    }
+
+    debug!(%client, "PushServer client disconnected");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::priority::priority_channel,
+        server::ControlPlane,
+        test_util::{fill_event, position},
+    };
+    use std::time::Duration;
+    use tokio::sync::mpsc;
+
+    #[tokio::test]
+    async fn test_push_server_streams_selected_topics() {
+        let (server, mut push_tx) = PushServer::bind(
+            "127.0.0.1:0".parse().unwrap(),
+            [PushTopic::Positions, PushTopic::TradingState],
+        )
+        .await
+        .unwrap();
+        let addr = server.local_addr().unwrap();
+        tokio::spawn(server.run());
+
+        let (mut client, _) = tokio_tungstenite::connect_async(format!("ws://{addr}"))
+            .await
+            .unwrap();
+
+        while push_tx.clients() == 0 {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+
+        // Fills are not selected, so are not streamed
+        let open_position = position();
+        push_tx.send_many(vec![
+            Event::Fill(fill_event()),
+            Event::PositionNew(Box::new(open_position.clone())),
+        ]);
+        push_tx.push_trading_state(TradingState::Disabled);
+
+        let mut actual = vec![];
+        while actual.len() < 2 {
+            if let Message::Text(json) = client.next().await.unwrap().unwrap() {
+                actual.push(serde_json::from_str::<PushUpdate>(&json).unwrap());
+            }
+        }
+
+        assert_eq!(
+            actual,
+            vec![
+                PushUpdate::PositionNew(Box::new(open_position)),
+                PushUpdate::TradingState(TradingState::Disabled),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_control_plane_pushes_trading_state_switch() {
+        let (_server, push_tx) =
+            PushServer::bind("127.0.0.1:0".parse().unwrap(), [PushTopic::TradingState])
+                .await
+                .unwrap();
+        let mut updates_rx = push_tx.updates_tx.subscribe();
+
+        let (command_tx, _command_rx) = mpsc::channel(10);
+        let (priority_tx, _priority_rx) = priority_channel(10);
+        let control = ControlPlane::new(command_tx)
+            .with_priority_tx(priority_tx)
+            .with_push_tx(push_tx);
+
+        control
+            .set_trading_state(TradingState::Disabled, "risk limit".to_owned())
+            .unwrap();
+        control
+            .set_trading_state(TradingState::Enabled, String::new())
+            .unwrap();
+
+        assert_eq!(
+            updates_rx.try_recv().unwrap(),
+            PushUpdate::TradingState(TradingState::Disabled)
+        );
+        assert_eq!(
+            updates_rx.try_recv().unwrap(),
+            PushUpdate::TradingState(TradingState::Enabled)
+        );
+    }
+}
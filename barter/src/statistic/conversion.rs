@@ -0,0 +1,160 @@
+use crate::{execution::Fees, portfolio::position::Position, statistic::error::StatisticError};
+use barter_instrument::asset::symbol::Symbol;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Converts amounts denominated in a quote currency (eg/ "usdt", "btc") into a single reporting
+/// currency, so statistics generated across mixed-quote [`Position`]s share the same units.
+pub trait QuoteConverter {
+    /// Currency all converted amounts are denominated in.
+    fn reporting_currency(&self) -> &Symbol;
+
+    /// Rate to convert one unit of the provided quote currency into the reporting currency at the
+    /// provided time, if known.
+    fn rate(&self, quote: &Symbol, time: DateTime<Utc>) -> Option<Decimal>;
+}
+
+/// [`QuoteConverter`] using fixed conversion rates, eg/ end of session rates for a backtest.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct StaticRates {
+    pub reporting_currency: Symbol,
+    pub rates: HashMap<Symbol, Decimal>,
+}
+
+impl StaticRates {
+    pub fn new(reporting_currency: impl Into<Symbol>) -> Self {
+        Self {
+            reporting_currency: reporting_currency.into(),
+            rates: HashMap::new(),
+        }
+    }
+
+    /// Add the rate to convert one unit of the provided quote currency into the reporting
+    /// currency.
+    pub fn with_rate(mut self, quote: impl Into<Symbol>, rate: Decimal) -> Self {
+        self.rates.insert(quote.into(), rate);
+        self
+    }
+}
+
+impl QuoteConverter for StaticRates {
+    fn reporting_currency(&self) -> &Symbol {
+        &self.reporting_currency
+    }
+
+    fn rate(&self, quote: &Symbol, _: DateTime<Utc>) -> Option<Decimal> {
+        match quote == &self.reporting_currency {
+            true => Some(Decimal::ONE),
+            false => self.rates.get(quote).copied(),
+        }
+    }
+}
+
+/// Normalise a [`Position`] by converting every quote denominated value into the
+/// [`QuoteConverter`] reporting currency, using the rate at the [`Position`] exit time (or last
+/// update time if it is not exited).
+///
+/// Ratio based values (eg/ PnL returns) and the [`PositionMeta`](crate::portfolio::position::PositionMeta)
+/// equity balances are left unchanged.
+pub fn normalise_position<Converter>(
+    position: &Position,
+    converter: &Converter,
+) -> Result<Position, StatisticError>
+where
+    Converter: QuoteConverter,
+{
+    let quote = &position.instrument.quote;
+    let time = position
+        .meta
+        .exit_balance
+        .map(|exit_balance| exit_balance.time)
+        .unwrap_or(position.meta.update_time);
+
+    let rate =
+        converter
+            .rate(quote, time)
+            .ok_or_else(|| StatisticError::MissingConversionRate {
+                quote: quote.clone(),
+                reporting: converter.reporting_currency().clone(),
+            })?;
+
+    let convert_fees = |fees: Fees| Fees {
+        exchange: fees.exchange * rate,
+        slippage: fees.slippage * rate,
+        network: fees.network * rate,
+    };
+
+    Ok(Position {
+        enter_fees: convert_fees(position.enter_fees),
+        enter_fees_total: position.enter_fees_total * rate,
+        enter_avg_price_gross: position.enter_avg_price_gross * rate,
+        enter_value_gross: position.enter_value_gross * rate,
+        exit_fees: convert_fees(position.exit_fees),
+        exit_fees_total: position.exit_fees_total * rate,
+        exit_avg_price_gross: position.exit_avg_price_gross * rate,
+        exit_value_gross: position.exit_value_gross * rate,
+        current_symbol_price: position.current_symbol_price * rate,
+        current_value_gross: position.current_value_gross * rate,
+        unrealised_profit_loss: position.unrealised_profit_loss * rate,
+        realised_profit_loss: position.realised_profit_loss * rate,
+        ..position.clone()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::position;
+    use barter_instrument::instrument::{kind::InstrumentKind, Instrument};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_normalise_position() {
+        struct TestCase {
+            quote: &'static str,
+            expected: Result<Decimal, StatisticError>,
+        }
+
+        let converter = StaticRates::new("usd")
+            .with_rate("usdt", dec!(0.5))
+            .with_rate("btc", dec!(100));
+
+        let tests = vec![
+            TestCase {
+                // TC0: reporting currency is unchanged
+                quote: "usd",
+                expected: Ok(dec!(10)),
+            },
+            TestCase {
+                // TC1: stablecoin quote converted
+                quote: "usdt",
+                expected: Ok(dec!(5)),
+            },
+            TestCase {
+                // TC2: crypto quote converted
+                quote: "btc",
+                expected: Ok(dec!(1000)),
+            },
+            TestCase {
+                // TC3: missing conversion rate
+                quote: "eth",
+                expected: Err(StatisticError::MissingConversionRate {
+                    quote: Symbol::from("eth"),
+                    reporting: Symbol::from("usd"),
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut input = position();
+            input.instrument = Instrument::from(("base", test.quote, InstrumentKind::Spot));
+            input.realised_profit_loss = dec!(10);
+
+            let actual = normalise_position(&input, &converter)
+                .map(|position| position.realised_profit_loss);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
@@ -1,11 +1,22 @@
+use barter_instrument::asset::symbol::Symbol;
+use rust_decimal::Decimal;
 use thiserror::Error;
 
 /// All errors generated in the barter::statistic module.
-#[derive(Error, Copy, Clone, Debug)]
+#[derive(Error, Clone, Eq, PartialEq, Debug)]
 pub enum StatisticError {
     #[error("Failed to build struct due to missing attributes: {0}")]
     BuilderIncomplete(&'static str),
 
     #[error("Failed to build struct due to insufficient metrics provided")]
     BuilderNoMetricsProvided,
+
+    #[error("Failed to convert {quote} into reporting currency {reporting}: missing rate")]
+    MissingConversionRate { quote: Symbol, reporting: Symbol },
+
+    #[error("Failed to convert {field}: {value} into an f64")]
+    NonFloat { field: &'static str, value: Decimal },
+
+    #[error("Failed to calculate drawdown from a non-positive equity peak: {0}")]
+    NonPositiveEquityPeak(Decimal),
 }
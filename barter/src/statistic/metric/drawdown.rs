@@ -1,8 +1,9 @@
 use crate::statistic::{
-    algorithm::welford_online, de_duration_from_secs, dispersion::Range, metric::EquityPoint,
+    algorithm::welford_online, de_duration_from_secs, error::StatisticError, metric::EquityPoint,
     se_duration_as_secs,
 };
 use chrono::{DateTime, Duration, Utc};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::{Deserialize, Serialize};
 
 /// [`Drawdown`] is the peak-to-trough decline of the Portfolio, or investment, during a specific
@@ -11,7 +12,7 @@ use serde::{Deserialize, Serialize};
 /// See documentation: <https://www.investopedia.com/terms/d/drawdown.asp>
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct Drawdown {
-    pub equity_range: Range,
+    pub equity_range: EquityRange,
     pub drawdown: f64,
     pub start_time: DateTime<Utc>,
     #[serde(
@@ -21,6 +22,13 @@ pub struct Drawdown {
     pub duration: Duration,
 }
 
+/// Peak & trough equity of a [`Drawdown`] period.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct EquityRange {
+    pub high: Decimal,
+    pub low: Decimal,
+}
+
 impl Default for Drawdown {
     fn default() -> Self {
         Self {
@@ -34,10 +42,9 @@ impl Default for Drawdown {
 
 impl Drawdown {
     /// Initialises a new [`Drawdown`] using the starting equity as the first peak.
-    pub fn init(starting_equity: f64) -> Self {
+    pub fn init(starting_equity: Decimal) -> Self {
         Self {
-            equity_range: Range {
-                activated: true,
+            equity_range: EquityRange {
                 high: starting_equity,
                 low: starting_equity,
             },
@@ -50,31 +57,41 @@ impl Drawdown {
     /// Updates the [`Drawdown`] using the latest input [`EquityPoint`] of the Portfolio. If the drawdown
     /// period has ended (investment recovers from a trough back above the previous peak), the
     /// function return Some(Drawdown), else None is returned.
-    pub fn update(&mut self, current: EquityPoint) -> Option<Drawdown> {
-        match (
-            self.is_waiting_for_peak(),
-            current.total > self.equity_range.high,
-        ) {
+    ///
+    /// Returns a [`StatisticError`] if the drawdown ratio cannot be calculated (eg/ non-positive
+    /// equity peak), leaving the [`Drawdown`] unchanged.
+    pub fn update(&mut self, current: EquityPoint) -> Result<Option<Drawdown>, StatisticError> {
+        let total = current.total;
+
+        match (self.is_waiting_for_peak(), total > self.equity_range.high) {
             // A) No current drawdown - waiting for next equity peak (waiting for B)
             (true, true) => {
-                self.equity_range.high = current.total;
-                None
+                self.equity_range.high = total;
+                Ok(None)
             }
 
             // B) Start of new drawdown - previous equity point set peak & current equity lower
             (true, false) => {
+                let equity_range = EquityRange {
+                    high: self.equity_range.high,
+                    low: total,
+                };
+                self.drawdown = equity_range.calculate()?;
+                self.equity_range = equity_range;
                 self.start_time = current.time;
-                self.equity_range.low = current.total;
-                self.drawdown = self.calculate();
-                None
+                Ok(None)
             }
 
             // C) Continuation of drawdown - equity lower than most recent peak
             (false, false) => {
+                let equity_range = EquityRange {
+                    high: self.equity_range.high,
+                    low: self.equity_range.low.min(total),
+                };
+                self.drawdown = equity_range.calculate()?;
+                self.equity_range = equity_range;
                 self.duration = current.time.signed_duration_since(self.start_time);
-                self.equity_range.update(current.total);
-                self.drawdown = self.calculate(); // I don't need to calculate this now if I don't want
-                None
+                Ok(None)
             }
 
             // D) End of drawdown - equity has reached new peak (enters A)
@@ -92,9 +109,9 @@ impl Drawdown {
                 self.duration = Duration::zero();
 
                 // Set new equity peak in preparation for next iteration
-                self.equity_range.high = current.total;
+                self.equity_range.high = total;
 
-                Some(finished_drawdown)
+                Ok(Some(finished_drawdown))
             }
         }
     }
@@ -104,12 +121,24 @@ impl Drawdown {
     pub fn is_waiting_for_peak(&self) -> bool {
         self.drawdown == 0.0
     }
+}
 
-    /// Calculates the value of the [`Drawdown`] in the specific period. Uses the formula:
+impl EquityRange {
+    /// Calculates the [`Drawdown`] ratio of the [`EquityRange`]. Uses the formula:
     /// [`Drawdown`] = (range_low - range_high) / range_high
-    pub fn calculate(&self) -> f64 {
-        // range_low - range_high / range_high
-        (-self.equity_range.calculate()) / self.equity_range.high
+    ///
+    /// The ratio is calculated with [`Decimal`] precision, and only the result is converted
+    /// to `f64`.
+    pub fn calculate(&self) -> Result<f64, StatisticError> {
+        if self.high <= Decimal::ZERO {
+            return Err(StatisticError::NonPositiveEquityPeak(self.high));
+        }
+
+        let ratio = (self.low - self.high) / self.high;
+        ratio.to_f64().ok_or(StatisticError::NonFloat {
+            field: "drawdown",
+            value: ratio,
+        })
     }
 }
 
@@ -196,6 +225,7 @@ impl AvgDrawdown {
 mod tests {
     use super::*;
     use crate::statistic::metric::EquityPoint;
+    use rust_decimal_macros::dec;
     use std::ops::Add;
 
     #[test]
@@ -205,11 +235,10 @@ mod tests {
             expected_drawdown: Drawdown,
         }
         let base_time = Utc::now();
-        let starting_equity = 100.0;
+        let starting_equity = dec!(100);
 
         let mut drawdown = Drawdown {
-            equity_range: Range {
-                activated: true,
+            equity_range: EquityRange {
                 high: starting_equity,
                 low: starting_equity,
             },
@@ -222,14 +251,13 @@ mod tests {
             TestCase {
                 // Test case 0: No current drawdown
                 input_equity: EquityPoint {
-                    total: 110.0,
+                    total: dec!(110),
                     time: base_time.add(Duration::days(1)),
                 },
                 expected_drawdown: Drawdown {
-                    equity_range: Range {
-                        activated: true,
-                        high: 110.0,
-                        low: 100.0,
+                    equity_range: EquityRange {
+                        high: dec!(110),
+                        low: dec!(100),
                     },
                     drawdown: 0.0,
                     start_time: base_time,
@@ -239,14 +267,13 @@ mod tests {
             TestCase {
                 // Test case 1: Start of new drawdown w/ lower equity than peak
                 input_equity: EquityPoint {
-                    total: 100.0,
+                    total: dec!(100),
                     time: base_time.add(Duration::days(2)),
                 },
                 expected_drawdown: Drawdown {
-                    equity_range: Range {
-                        activated: true,
-                        high: 110.0,
-                        low: 100.0,
+                    equity_range: EquityRange {
+                        high: dec!(110),
+                        low: dec!(100),
                     },
                     drawdown: (-10.0 / 110.0),
                     start_time: base_time.add(Duration::days(2)),
@@ -256,14 +283,13 @@ mod tests {
             TestCase {
                 // Test case 2: Continuation of drawdown w/ lower equity than previous
                 input_equity: EquityPoint {
-                    total: 90.0,
+                    total: dec!(90),
                     time: base_time.add(Duration::days(3)),
                 },
                 expected_drawdown: Drawdown {
-                    equity_range: Range {
-                        activated: true,
-                        high: 110.0,
-                        low: 90.0,
+                    equity_range: EquityRange {
+                        high: dec!(110),
+                        low: dec!(90),
                     },
                     drawdown: (-20.0 / 110.0),
                     start_time: base_time.add(Duration::days(2)),
@@ -273,14 +299,13 @@ mod tests {
             TestCase {
                 // Test case 3: Continuation of drawdown w/ higher equity than previous but not higher than peak
                 input_equity: EquityPoint {
-                    total: 95.0,
+                    total: dec!(95),
                     time: base_time.add(Duration::days(4)),
                 },
                 expected_drawdown: Drawdown {
-                    equity_range: Range {
-                        activated: true,
-                        high: 110.0,
-                        low: 90.0,
+                    equity_range: EquityRange {
+                        high: dec!(110),
+                        low: dec!(90),
                     },
                     drawdown: (-20.0 / 110.0),
                     start_time: base_time.add(Duration::days(2)),
@@ -290,14 +315,13 @@ mod tests {
             TestCase {
                 // Test case 4: End of drawdown w/ equity higher than peak
                 input_equity: EquityPoint {
-                    total: 120.0,
+                    total: dec!(120),
                     time: base_time.add(Duration::days(5)),
                 },
                 expected_drawdown: Drawdown {
-                    equity_range: Range {
-                        activated: true,
-                        high: 120.0,
-                        low: 90.0,
+                    equity_range: EquityRange {
+                        high: dec!(120),
+                        low: dec!(90),
                     },
                     drawdown: 0.0,
                     start_time: base_time.add(Duration::days(2)),
@@ -307,14 +331,13 @@ mod tests {
             TestCase {
                 // Test case 5: No current drawdown w/ residual start_time from previous
                 input_equity: EquityPoint {
-                    total: 200.0,
+                    total: dec!(200),
                     time: base_time.add(Duration::days(6)),
                 },
                 expected_drawdown: Drawdown {
-                    equity_range: Range {
-                        activated: true,
-                        high: 200.0,
-                        low: 90.0,
+                    equity_range: EquityRange {
+                        high: dec!(200),
+                        low: dec!(90),
                     },
                     drawdown: 0.0,
                     start_time: base_time.add(Duration::days(2)),
@@ -324,14 +347,13 @@ mod tests {
             TestCase {
                 // Test case 6: Start of new drawdown w/ lower equity than peak & residual fields from previous drawdown
                 input_equity: EquityPoint {
-                    total: 180.0,
+                    total: dec!(180),
                     time: base_time.add(Duration::days(7)),
                 },
                 expected_drawdown: Drawdown {
-                    equity_range: Range {
-                        activated: true,
-                        high: 200.0,
-                        low: 180.0,
+                    equity_range: EquityRange {
+                        high: dec!(200),
+                        low: dec!(180),
                     },
                     drawdown: (-20.0 / 200.0),
                     start_time: base_time.add(Duration::days(7)),
@@ -341,14 +363,13 @@ mod tests {
             TestCase {
                 // Test case 7: Continuation of drawdown w/ equity equal to peak
                 input_equity: EquityPoint {
-                    total: 200.0,
+                    total: dec!(200),
                     time: base_time.add(Duration::days(8)),
                 },
                 expected_drawdown: Drawdown {
-                    equity_range: Range {
-                        activated: true,
-                        high: 200.0,
-                        low: 180.0,
+                    equity_range: EquityRange {
+                        high: dec!(200),
+                        low: dec!(180),
                     },
                     drawdown: (-20.0 / 200.0),
                     start_time: base_time.add(Duration::days(7)),
@@ -358,14 +379,13 @@ mod tests {
             TestCase {
                 // Test case 8: End of drawdown w/ equity higher than peak
                 input_equity: EquityPoint {
-                    total: 200.01,
+                    total: dec!(200.01),
                     time: base_time.add(Duration::days(9)),
                 },
                 expected_drawdown: Drawdown {
-                    equity_range: Range {
-                        activated: true,
-                        high: 200.01,
-                        low: 180.0,
+                    equity_range: EquityRange {
+                        high: dec!(200.01),
+                        low: dec!(180),
                     },
                     drawdown: 0.0,
                     start_time: base_time.add(Duration::days(7)),
@@ -375,11 +395,28 @@ mod tests {
         ];
 
         for (index, test) in test_cases.into_iter().enumerate() {
-            drawdown.update(test.input_equity);
+            drawdown.update(test.input_equity).unwrap();
             assert_eq!(drawdown, test.expected_drawdown, "Test case: {:?}", index)
         }
     }
 
+    #[test]
+    fn drawdown_update_with_non_positive_peak_and_return_err() {
+        let mut drawdown = Drawdown::init(Decimal::ZERO);
+        let before = drawdown;
+
+        let result = drawdown.update(EquityPoint {
+            total: dec!(-10),
+            time: Utc::now(),
+        });
+
+        assert_eq!(
+            result,
+            Err(StatisticError::NonPositiveEquityPeak(Decimal::ZERO))
+        );
+        assert_eq!(drawdown, before);
+    }
+
     #[test]
     fn max_drawdown_update() {
         struct TestCase {
@@ -395,20 +432,18 @@ mod tests {
             TestCase {
                 // Test case 0: First ever drawdown
                 input_drawdown: Drawdown {
-                    equity_range: Range {
-                        activated: true,
-                        high: 115.0,
-                        low: 90.0,
+                    equity_range: EquityRange {
+                        high: dec!(115),
+                        low: dec!(90),
                     },
                     drawdown: (-25.0 / 110.0),
                     start_time: base_time,
                     duration: Duration::days(2),
                 },
                 expected_drawdown: Drawdown {
-                    equity_range: Range {
-                        activated: true,
-                        high: 115.0,
-                        low: 90.0,
+                    equity_range: EquityRange {
+                        high: dec!(115),
+                        low: dec!(90),
                     },
                     drawdown: (-25.0 / 110.0),
                     start_time: base_time,
@@ -418,20 +453,18 @@ mod tests {
             TestCase {
                 // Test case 1: Larger drawdown
                 input_drawdown: Drawdown {
-                    equity_range: Range {
-                        activated: true,
-                        high: 200.0,
-                        low: 90.0,
+                    equity_range: EquityRange {
+                        high: dec!(200),
+                        low: dec!(90),
                     },
                     drawdown: (-110.0 / 200.0),
                     start_time: base_time.add(Duration::days(3)),
                     duration: Duration::days(1),
                 },
                 expected_drawdown: Drawdown {
-                    equity_range: Range {
-                        activated: true,
-                        high: 200.0,
-                        low: 90.0,
+                    equity_range: EquityRange {
+                        high: dec!(200),
+                        low: dec!(90),
                     },
                     drawdown: (-110.0 / 200.0),
                     start_time: base_time.add(Duration::days(3)),
@@ -441,20 +474,18 @@ mod tests {
             TestCase {
                 // Test case 1: Smaller drawdown
                 input_drawdown: Drawdown {
-                    equity_range: Range {
-                        activated: true,
-                        high: 300.0,
-                        low: 290.0,
+                    equity_range: EquityRange {
+                        high: dec!(300),
+                        low: dec!(290),
                     },
                     drawdown: (-10.0 / 300.0),
                     start_time: base_time.add(Duration::days(8)),
                     duration: Duration::days(1),
                 },
                 expected_drawdown: Drawdown {
-                    equity_range: Range {
-                        activated: true,
-                        high: 200.0,
-                        low: 90.0,
+                    equity_range: EquityRange {
+                        high: dec!(200),
+                        low: dec!(90),
                     },
                     drawdown: (-110.0 / 200.0),
                     start_time: base_time.add(Duration::days(3)),
@@ -464,20 +495,18 @@ mod tests {
             TestCase {
                 // Test case 1: Largest drawdown
                 input_drawdown: Drawdown {
-                    equity_range: Range {
-                        activated: true,
-                        high: 10000.0,
-                        low: 0.1,
+                    equity_range: EquityRange {
+                        high: dec!(10000),
+                        low: dec!(0.1),
                     },
                     drawdown: (-9999.9 / 10000.0),
                     start_time: base_time.add(Duration::days(12)),
                     duration: Duration::days(20),
                 },
                 expected_drawdown: Drawdown {
-                    equity_range: Range {
-                        activated: true,
-                        high: 10000.0,
-                        low: 0.1,
+                    equity_range: EquityRange {
+                        high: dec!(10000),
+                        low: dec!(0.1),
                     },
                     drawdown: (-9999.9 / 10000.0),
                     start_time: base_time.add(Duration::days(12)),
@@ -511,10 +540,9 @@ mod tests {
             TestCase {
                 // Test case 0: First ever drawdown
                 input_drawdown: Drawdown {
-                    equity_range: Range {
-                        activated: true,
-                        high: 100.0,
-                        low: 50.0,
+                    equity_range: EquityRange {
+                        high: dec!(100),
+                        low: dec!(50),
                     },
                     drawdown: (-50.0 / 100.0),
                     start_time: base_time,
@@ -530,10 +558,9 @@ mod tests {
             TestCase {
                 // Test case 1
                 input_drawdown: Drawdown {
-                    equity_range: Range {
-                        activated: true,
-                        high: 200.0,
-                        low: 100.0,
+                    equity_range: EquityRange {
+                        high: dec!(200),
+                        low: dec!(100),
                     },
                     drawdown: (-100.0 / 200.0),
                     start_time: base_time,
@@ -549,10 +576,9 @@ mod tests {
             TestCase {
                 // Test case 2
                 input_drawdown: Drawdown {
-                    equity_range: Range {
-                        activated: true,
-                        high: 1000.0,
-                        low: 820.0,
+                    equity_range: EquityRange {
+                        high: dec!(1000),
+                        low: dec!(820),
                     },
                     drawdown: (-180.0 / 1000.0),
                     start_time: base_time,
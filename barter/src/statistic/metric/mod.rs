@@ -3,6 +3,7 @@ use crate::{
     statistic::summary::PositionSummariser,
 };
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 pub mod drawdown;
@@ -12,14 +13,14 @@ pub mod ratio;
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct EquityPoint {
     pub time: DateTime<Utc>,
-    pub total: f64,
+    pub total: Decimal,
 }
 
 impl Default for EquityPoint {
     fn default() -> Self {
         Self {
             time: Utc::now(),
-            total: 0.0,
+            total: Decimal::ZERO,
         }
     }
 }
@@ -55,16 +56,20 @@ mod tests {
     use super::*;
     use crate::test_util::position;
     use chrono::Duration;
+    use rust_decimal_macros::dec;
     use std::ops::Add;
 
     #[test]
     fn equity_point_update() {
-        fn equity_update_position_closed(exit_time: DateTime<Utc>, result_pnl: f64) -> Position {
+        fn equity_update_position_closed(
+            exit_time: DateTime<Utc>,
+            result_pnl: Decimal,
+        ) -> Position {
             let mut position = position();
             position.meta.exit_balance = Some(Balance {
                 time: exit_time,
-                total: 100.0,
-                available: 100.0,
+                total: dec!(100),
+                available: dec!(100),
             });
             position.realised_profit_loss = result_pnl;
             position
@@ -72,7 +77,7 @@ mod tests {
 
         fn equity_update_position_open(
             last_update_time: DateTime<Utc>,
-            unreal_pnl: f64,
+            unreal_pnl: Decimal,
         ) -> Position {
             let mut position = position();
             position.meta.exit_balance = None;
@@ -83,7 +88,7 @@ mod tests {
 
         struct TestCase {
             position: Position,
-            expected_equity: f64,
+            expected_equity: Decimal,
             expected_time: DateTime<Utc>,
         }
 
@@ -91,46 +96,55 @@ mod tests {
 
         let mut equity_point = EquityPoint {
             time: base_time,
-            total: 100.0,
+            total: dec!(100),
         };
 
         let test_cases = vec![
             TestCase {
-                position: equity_update_position_closed(base_time.add(Duration::days(1)), 10.0),
-                expected_equity: 110.0,
+                position: equity_update_position_closed(base_time.add(Duration::days(1)), dec!(10)),
+                expected_equity: dec!(110),
                 expected_time: base_time.add(Duration::days(1)),
             },
             TestCase {
-                position: equity_update_position_open(base_time.add(Duration::days(2)), -10.0),
-                expected_equity: 100.0,
+                position: equity_update_position_open(base_time.add(Duration::days(2)), dec!(-10)),
+                expected_equity: dec!(100),
                 expected_time: base_time.add(Duration::days(2)),
             },
             TestCase {
-                position: equity_update_position_closed(base_time.add(Duration::days(3)), -55.9),
-                expected_equity: 44.1,
+                position: equity_update_position_closed(
+                    base_time.add(Duration::days(3)),
+                    dec!(-55.9),
+                ),
+                expected_equity: dec!(44.1),
                 expected_time: base_time.add(Duration::days(3)),
             },
             TestCase {
-                position: equity_update_position_open(base_time.add(Duration::days(4)), 68.7),
-                expected_equity: 112.8,
+                position: equity_update_position_open(base_time.add(Duration::days(4)), dec!(68.7)),
+                expected_equity: dec!(112.8),
                 expected_time: base_time.add(Duration::days(4)),
             },
             TestCase {
-                position: equity_update_position_closed(base_time.add(Duration::days(5)), 99999.0),
-                expected_equity: 100111.8,
+                position: equity_update_position_closed(
+                    base_time.add(Duration::days(5)),
+                    dec!(99999),
+                ),
+                expected_equity: dec!(100111.8),
                 expected_time: base_time.add(Duration::days(5)),
             },
             TestCase {
-                position: equity_update_position_open(base_time.add(Duration::days(5)), 0.2),
-                expected_equity: 100112.0,
+                position: equity_update_position_open(base_time.add(Duration::days(5)), dec!(0.2)),
+                expected_equity: dec!(100112),
                 expected_time: base_time.add(Duration::days(5)),
             },
         ];
 
         for (index, test) in test_cases.into_iter().enumerate() {
             equity_point.update(&test.position);
-            let equity_diff = equity_point.total - test.expected_equity;
-            assert!(equity_diff < 1e-10, "Test case {} failed at assert", index);
+            assert_eq!(
+                equity_point.total, test.expected_equity,
+                "Test case {} failed to assert_eq",
+                index
+            );
             assert_eq!(
                 equity_point.time, test.expected_time,
                 "Test case {} failed to assert_eq",
@@ -1,4 +1,5 @@
 use crate::statistic::summary::pnl::PnLReturnSummary;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 pub trait Ratio {
@@ -84,12 +85,11 @@ impl SortinoRatio {
         // Update Trades Per Day
         self.trades_per_day = pnl_returns.trades_per_day;
 
-        // Calculate Sortino Ratio Per Trade
-        self.sortino_ratio_per_trade = match pnl_returns.losses.dispersion.std_dev == 0.0 {
+        // Calculate Sortino Ratio Per Trade using the downside deviation of all returns
+        self.sortino_ratio_per_trade = match pnl_returns.downside_deviation == 0.0 {
             true => 0.0,
             false => {
-                (pnl_returns.total.mean - self.risk_free_return)
-                    / pnl_returns.losses.dispersion.std_dev
+                (pnl_returns.total.mean - self.risk_free_return) / pnl_returns.downside_deviation
             }
         };
     }
@@ -133,6 +133,69 @@ impl CalmarRatio {
     }
 }
 
+/// [`OmegaRatio`] is the probability weighted ratio of gains versus losses relative to a threshold
+/// return (the risk free return). Unlike the [`SharpeRatio`], it considers every moment of the
+/// returns distribution.
+///
+/// See documentation: <https://www.investopedia.com/terms/o/omega-ratio.asp>
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct OmegaRatio {
+    pub threshold: f64,
+    pub gains: f64,
+    pub losses: f64,
+    pub omega_ratio: f64,
+}
+
+impl OmegaRatio {
+    pub fn init(threshold: f64) -> Self {
+        Self {
+            threshold,
+            ..Self::default()
+        }
+    }
+
+    /// Updates the [`OmegaRatio`] using the latest PnL return.
+    pub fn update(&mut self, pnl_return: f64) {
+        let excess_return = pnl_return - self.threshold;
+        match excess_return.is_sign_positive() {
+            true => self.gains += excess_return,
+            false => self.losses -= excess_return,
+        }
+
+        self.omega_ratio = match self.losses == 0.0 {
+            true => 0.0,
+            false => self.gains / self.losses,
+        };
+    }
+}
+
+/// [`ProfitFactor`] is the ratio of gross profit to gross loss across every closed trade.
+///
+/// Gross profit, gross loss & their ratio are calculated exactly as [`Decimal`]s.
+///
+/// See documentation: <https://www.investopedia.com/articles/fundamental-analysis/10/strategy-performance-reports.asp>
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct ProfitFactor {
+    pub gross_profit: Decimal,
+    pub gross_loss: Decimal,
+    pub profit_factor: Decimal,
+}
+
+impl ProfitFactor {
+    /// Updates the [`ProfitFactor`] using the latest realised PnL.
+    pub fn update(&mut self, realised_profit_loss: Decimal) {
+        match realised_profit_loss.is_sign_positive() {
+            true => self.gross_profit += realised_profit_loss,
+            false => self.gross_loss -= realised_profit_loss,
+        }
+
+        self.profit_factor = self
+            .gross_profit
+            .checked_div(self.gross_loss)
+            .unwrap_or_default();
+    }
+}
+
 pub fn calculate_daily(ratio_per_trade: f64, trades_per_day: f64) -> f64 {
     ratio_per_trade * trades_per_day.sqrt()
 }
@@ -145,6 +208,7 @@ pub fn calculate_annual(ratio_per_trade: f64, trades_per_day: f64, trading_days:
 mod tests {
     use super::*;
     use crate::statistic::summary::pnl::PnLReturnSummary;
+    use rust_decimal_macros::dec;
 
     fn sharpe_ratio_input(count: u64, mean: f64, std_dev: f64) -> PnLReturnSummary {
         let mut pnl_returns = PnLReturnSummary::new();
@@ -154,11 +218,11 @@ mod tests {
         pnl_returns
     }
 
-    fn sortino_update_input(count: u64, mean: f64, loss_std_dev: f64) -> PnLReturnSummary {
+    fn sortino_update_input(count: u64, mean: f64, downside_deviation: f64) -> PnLReturnSummary {
         let mut pnl_returns = PnLReturnSummary::new();
         pnl_returns.total.count = count;
         pnl_returns.total.mean = mean;
-        pnl_returns.losses.dispersion.std_dev = loss_std_dev;
+        pnl_returns.downside_deviation = downside_deviation;
         pnl_returns
     }
 
@@ -225,9 +289,9 @@ mod tests {
             expected_sortino: f64,
         }
 
-        // Returns       = [0.1, 0.2, 0.3, 0.4, -0.4, -0.6, -0.7]
-        // Means         = [0.1, 0.15, 0.2, 0.25, 0.12, 0.0, -0.1]
-        // Loss Std. Dev = [0.0, 0.0, 0.0, 0.0, 0.0, 0.1, 0.12472191]
+        // Returns            = [0.1, 0.2, 0.3, 0.4, -0.4, -0.6, -0.7]
+        // Means              = [0.1, 0.15, 0.2, 0.25, 0.12, 0.0, -0.1]
+        // Downside Deviation = [0.0, 0.0, 0.0, 0.0, (0.16/5).sqrt(), (0.52/6).sqrt(), (1.01/7).sqrt()]
 
         let test_cases = vec![
             TestCase {
@@ -252,25 +316,25 @@ mod tests {
             },
             TestCase {
                 // Test case 4: 5th trade, -40% profit
-                input_return: sortino_update_input(5, 0.12, 0.0),
-                expected_sortino: 0.0,
+                input_return: sortino_update_input(5, 0.12, (0.16_f64 / 5.0).sqrt()),
+                expected_sortino: 0.12 / (0.16_f64 / 5.0).sqrt(),
             },
             TestCase {
                 // Test case 5: 6th trade, -60% profit
-                input_return: sortino_update_input(6, 0.0, 0.1),
+                input_return: sortino_update_input(6, 0.0, (0.52_f64 / 6.0).sqrt()),
                 expected_sortino: 0.0,
             },
             TestCase {
-                // Test case 5: 6th trade, -70% profit
-                input_return: sortino_update_input(7, -0.1, 0.12472191),
-                expected_sortino: -0.8017837443,
+                // Test case 6: 7th trade, -70% profit
+                input_return: sortino_update_input(7, -0.1, (1.01_f64 / 7.0).sqrt()),
+                expected_sortino: -0.1 / (1.01_f64 / 7.0).sqrt(),
             },
         ];
 
         for (index, test) in test_cases.into_iter().enumerate() {
             sortino.update(&test.input_return);
             let sortino_diff = sortino.sortino_ratio_per_trade - test.expected_sortino;
-            assert!(sortino_diff < 1e-10, "Test case: {:?}", index);
+            assert!(sortino_diff.abs() < 1e-10, "Test case: {:?}", index);
         }
     }
 
@@ -451,4 +515,44 @@ mod tests {
             assert_eq!(actual, test.expected_annual)
         }
     }
+
+    #[test]
+    fn omega_ratio_update() {
+        let mut omega = OmegaRatio::init(0.05);
+
+        // Excess Returns = [0.05, -0.15, 0.25, -0.05]
+        let test_cases = vec![
+            (0.1, 0.0),
+            (-0.1, 0.05 / 0.15),
+            (0.3, 0.3 / 0.15),
+            (0.0, 0.3 / 0.2),
+        ];
+
+        for (index, (input, expected)) in test_cases.into_iter().enumerate() {
+            omega.update(input);
+            let omega_diff = omega.omega_ratio - expected;
+            assert!(omega_diff.abs() < 1e-10, "Test case: {:?}", index);
+        }
+    }
+
+    #[test]
+    fn profit_factor_update() {
+        let mut profit_factor = ProfitFactor::default();
+
+        let test_cases = vec![
+            (dec!(50), dec!(0)),
+            (dec!(-20), dec!(2.5)),
+            (dec!(30), dec!(4)),
+            (dec!(-80), dec!(0.8)),
+        ];
+
+        for (index, (input, expected)) in test_cases.into_iter().enumerate() {
+            profit_factor.update(input);
+            assert_eq!(
+                profit_factor.profit_factor, expected,
+                "Test case: {:?}",
+                index
+            );
+        }
+    }
 }
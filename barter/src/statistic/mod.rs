@@ -2,9 +2,11 @@ use chrono::Duration;
 use serde::{Deserialize, Deserializer, Serializer};
 
 pub mod algorithm;
+pub mod conversion;
 pub mod dispersion;
 pub mod error;
 pub mod metric;
+pub mod monte_carlo;
 pub mod summary;
 
 /// Serialize a [`Duration`] into a `u64` representing the associated seconds.
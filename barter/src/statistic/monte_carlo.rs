@@ -0,0 +1,271 @@
+use crate::{
+    portfolio::position::Position,
+    statistic::summary::{data::DataSummary, TableBuilder},
+};
+use prettytable::Row;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for generating a [`MonteCarloSummary`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Config {
+    /// Number of resampled trade sequences to simulate.
+    pub simulations: usize,
+    pub starting_equity: f64,
+    pub risk_free_return: f64,
+    /// Confidence level of the reported intervals (eg/ 0.95).
+    pub confidence: f64,
+    /// Seed of the random number generator, making the resampling reproducible.
+    pub seed: u64,
+}
+
+/// Sorted distribution of a statistic across every simulated trade sequence.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct Distribution {
+    pub values: Vec<f64>,
+}
+
+impl Distribution {
+    /// Constructs a new [`Distribution`] from the provided values, sorting them in ascending order.
+    pub fn new(mut values: Vec<f64>) -> Self {
+        values.sort_by(f64::total_cmp);
+        Self { values }
+    }
+
+    /// Calculates the [`DataSummary`] (count, mean, dispersion, etc.) of the [`Distribution`].
+    pub fn summary(&self) -> DataSummary {
+        self.values
+            .iter()
+            .fold(DataSummary::default(), |mut summary, value| {
+                summary.update(*value);
+                summary
+            })
+    }
+
+    /// Calculates the value at the provided percentile (0.0 to 1.0), linearly interpolating between
+    /// the closest ranks. Returns `NaN` if the [`Distribution`] is empty.
+    pub fn percentile(&self, percentile: f64) -> f64 {
+        let Some(last) = self.values.len().checked_sub(1) else {
+            return f64::NAN;
+        };
+
+        let rank = percentile.clamp(0.0, 1.0) * last as f64;
+        let lower = rank.floor() as usize;
+        let upper = rank.ceil() as usize;
+
+        self.values[lower] + (self.values[upper] - self.values[lower]) * (rank - lower as f64)
+    }
+
+    /// Calculates the central (lower, upper) interval that contains the provided confidence level
+    /// proportion of the [`Distribution`].
+    ///
+    /// eg/ A confidence of 0.95 yields the (2.5th, 97.5th) percentiles.
+    pub fn confidence_interval(&self, confidence: f64) -> (f64, f64) {
+        let tail = (1.0 - confidence) / 2.0;
+        (self.percentile(tail), self.percentile(1.0 - tail))
+    }
+}
+
+/// Monte Carlo analysis of a sequence of closed trades.
+///
+/// A single backtest produces one path of trade outcomes, so point estimates such as max drawdown
+/// are misleading for risk sizing. The [`MonteCarloSummary`] bootstraps (samples with replacement)
+/// the closed trades into many alternative sequences of equal length, and reports the
+/// [`Distribution`] of each statistic across them.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MonteCarloSummary {
+    pub config: Config,
+    /// Number of closed trades in each simulated sequence.
+    pub trades: usize,
+    /// Max drawdown of each sequence's equity curve, calculated using the same formula as
+    /// [`Drawdown`](crate::statistic::metric::drawdown::Drawdown) (ie/ a negative proportion).
+    pub max_drawdown: Distribution,
+    /// Per trade Sharpe Ratio of each sequence's PnL returns.
+    pub sharpe_ratio: Distribution,
+    /// Final equity of each sequence.
+    pub terminal_equity: Distribution,
+}
+
+impl MonteCarloSummary {
+    /// Generate a [`MonteCarloSummary`] by resampling the provided closed [`Position`]s.
+    pub fn generate(config: Config, positions: &[Position]) -> Self {
+        // Simulated equity curves are statistical estimates, so are resampled as f64s
+        let trades = positions
+            .iter()
+            .map(|position| {
+                (
+                    position.realised_profit_loss.to_f64().unwrap_or_default(),
+                    position.calculate_profit_loss_return(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut rng = StdRng::seed_from_u64(config.seed);
+        let mut max_drawdowns = Vec::with_capacity(config.simulations);
+        let mut sharpe_ratios = Vec::with_capacity(config.simulations);
+        let mut terminal_equities = Vec::with_capacity(config.simulations);
+
+        for _ in 0..config.simulations {
+            let mut equity = config.starting_equity;
+            let mut peak = config.starting_equity;
+            let mut max_drawdown: f64 = 0.0;
+            let mut returns = DataSummary::default();
+
+            for _ in 0..trades.len() {
+                let (pnl, pnl_return) = trades[rng.gen_range(0..trades.len())];
+
+                equity += pnl;
+                peak = peak.max(equity);
+                max_drawdown = max_drawdown.min((equity - peak) / peak);
+                returns.update(pnl_return);
+            }
+
+            let sharpe_ratio = match returns.dispersion.std_dev == 0.0 {
+                true => 0.0,
+                false => (returns.mean - config.risk_free_return) / returns.dispersion.std_dev,
+            };
+
+            max_drawdowns.push(max_drawdown);
+            sharpe_ratios.push(sharpe_ratio);
+            terminal_equities.push(equity);
+        }
+
+        Self {
+            config,
+            trades: trades.len(),
+            max_drawdown: Distribution::new(max_drawdowns),
+            sharpe_ratio: Distribution::new(sharpe_ratios),
+            terminal_equity: Distribution::new(terminal_equities),
+        }
+    }
+}
+
+impl TableBuilder for MonteCarloSummary {
+    fn titles(&self) -> Row {
+        row![
+            "Simulations",
+            "Trades",
+            "Confidence",
+            "Mean Max Drawdown",
+            "Max Drawdown Interval",
+            "Mean Sharpe Ratio",
+            "Sharpe Ratio Interval",
+            "Mean Terminal Equity",
+            "Terminal Equity Interval",
+        ]
+    }
+
+    fn row(&self) -> Row {
+        let interval = |distribution: &Distribution| {
+            let (lower, upper) = distribution.confidence_interval(self.config.confidence);
+            format!("[{lower:.3}, {upper:.3}]")
+        };
+
+        row![
+            self.config.simulations.to_string(),
+            self.trades.to_string(),
+            format!("{:.3}", self.config.confidence),
+            format!("{:.3}", self.max_drawdown.summary().mean),
+            interval(&self.max_drawdown),
+            format!("{:.3}", self.sharpe_ratio.summary().mean),
+            interval(&self.sharpe_ratio),
+            format!("{:.3}", self.terminal_equity.summary().mean),
+            interval(&self.terminal_equity),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::position;
+    use rust_decimal::{prelude::FromPrimitive, Decimal};
+
+    fn config(seed: u64) -> Config {
+        Config {
+            simulations: 100,
+            starting_equity: 1000.0,
+            risk_free_return: 0.0,
+            confidence: 0.9,
+            seed,
+        }
+    }
+
+    fn positions(pnls: &[f64]) -> Vec<Position> {
+        pnls.iter()
+            .map(|pnl| {
+                let mut position = position();
+                position.realised_profit_loss = Decimal::from_f64(*pnl).unwrap();
+                position
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_distribution_percentile() {
+        struct TestCase {
+            values: Vec<f64>,
+            percentile: f64,
+            expected: f64,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: median of odd number of values
+                values: vec![3.0, 1.0, 2.0],
+                percentile: 0.5,
+                expected: 2.0,
+            },
+            TestCase {
+                // TC1: interpolated between closest ranks
+                values: vec![10.0, 0.0, 20.0, 30.0, 40.0],
+                percentile: 0.1,
+                expected: 4.0,
+            },
+            TestCase {
+                // TC2: percentile is clamped
+                values: vec![1.0, 2.0],
+                percentile: 1.5,
+                expected: 2.0,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = Distribution::new(test.values).percentile(test.percentile);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+
+        assert!(Distribution::default().percentile(0.5).is_nan());
+    }
+
+    #[test]
+    fn test_monte_carlo_summary_identical_trades() {
+        let summary = MonteCarloSummary::generate(config(1), &positions(&[10.0; 5]));
+
+        assert_eq!(summary.trades, 5);
+        assert_eq!(summary.terminal_equity.values, vec![1050.0; 100]);
+        assert_eq!(summary.max_drawdown.values, vec![0.0; 100]);
+        assert_eq!(summary.sharpe_ratio.values, vec![0.0; 100]);
+    }
+
+    #[test]
+    fn test_monte_carlo_summary_resamples_trades() {
+        let positions = positions(&[50.0, -100.0, 20.0, -10.0, 80.0]);
+
+        let summary = MonteCarloSummary::generate(config(7), &positions);
+
+        // Resampling is reproducible for the same seed
+        assert_eq!(summary, MonteCarloSummary::generate(config(7), &positions));
+
+        // Resampling with replacement generates a spread of outcomes
+        let (lower, upper) = summary.terminal_equity.confidence_interval(0.9);
+        assert!(lower < 1040.0 && 1040.0 < upper);
+        assert!(summary.max_drawdown.percentile(0.0) <= -0.1);
+        assert!(summary
+            .max_drawdown
+            .values
+            .iter()
+            .all(|drawdown| *drawdown <= 0.0));
+    }
+}
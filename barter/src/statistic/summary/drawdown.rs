@@ -9,7 +9,9 @@ use crate::{
     },
 };
 use prettytable::Row;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use tracing::warn;
 
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct DrawdownSummary {
@@ -27,9 +29,17 @@ impl PositionSummariser for DrawdownSummary {
         };
 
         // Updates
-        if let Some(ended_drawdown) = self.current_drawdown.update(equity_point) {
-            self.avg_drawdown.update(&ended_drawdown);
-            self.max_drawdown.update(&ended_drawdown);
+        match self.current_drawdown.update(equity_point) {
+            Ok(Some(ended_drawdown)) => {
+                self.avg_drawdown.update(&ended_drawdown);
+                self.max_drawdown.update(&ended_drawdown);
+            }
+            Ok(None) => {}
+            Err(error) => warn!(
+                %error,
+                equity = %equity_point.total,
+                "DrawdownSummary skipped EquityPoint it could not calculate a drawdown for"
+            ),
         }
     }
 }
@@ -55,7 +65,7 @@ impl TableBuilder for DrawdownSummary {
 }
 
 impl DrawdownSummary {
-    pub fn new(starting_equity: f64) -> Self {
+    pub fn new(starting_equity: Decimal) -> Self {
         Self {
             current_drawdown: Drawdown::init(starting_equity),
             avg_drawdown: AvgDrawdown::init(),
@@ -0,0 +1,171 @@
+use crate::{portfolio::position::Position, statistic::metric::EquityPoint};
+use chrono::{DateTime, Utc};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+/// Time-indexed equity curve & drawdown series, generated from exited [`Position`]s.
+///
+/// Complements the scalar aggregates of the [`DrawdownSummary`](super::drawdown::DrawdownSummary)
+/// by exposing every point, eg/ for plotting. Serialise to JSON via `serde`, or to CSV via
+/// [`Self::write_csv`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct EquityCurve {
+    pub starting_equity: Decimal,
+    pub points: Vec<EquityCurvePoint>,
+}
+
+/// Portfolio equity & drawdown at the time a [`Position`] was exited.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct EquityCurvePoint {
+    pub time: DateTime<Utc>,
+    pub equity: Decimal,
+    /// Highest equity up to & including this point.
+    pub peak_equity: Decimal,
+    /// Decline from the peak equity, calculated using the same formula as
+    /// [`Drawdown`](crate::statistic::metric::drawdown::Drawdown)
+    /// (ie/ zero or a negative proportion).
+    pub drawdown: f64,
+}
+
+impl EquityCurve {
+    /// CSV header matching the columns generated by [`Self::write_csv`].
+    pub const CSV_HEADER: &'static str = "time,equity,peak_equity,drawdown";
+
+    pub fn new(starting_equity: Decimal) -> Self {
+        Self {
+            starting_equity,
+            points: Vec::new(),
+        }
+    }
+
+    /// Generate an [`EquityCurve`] from the provided [`Position`]s, ordered by exit time.
+    pub fn generate(starting_equity: Decimal, positions: &[Position]) -> Self {
+        let mut positions = positions
+            .iter()
+            .filter(|position| position.meta.exit_balance.is_some())
+            .collect::<Vec<_>>();
+        positions.sort_by_key(|position| position.meta.exit_balance.map(|balance| balance.time));
+
+        positions
+            .into_iter()
+            .fold(Self::new(starting_equity), |mut curve, position| {
+                curve.update(position);
+                curve
+            })
+    }
+
+    /// Append the [`EquityCurvePoint`] associated with the input [`Position`]. Only exited
+    /// [`Position`]s are included, since the equity is the [`Position`] exit
+    /// [`Balance`](crate::portfolio::Balance) total.
+    pub fn update(&mut self, position: &Position) {
+        let Some(exit_balance) = position.meta.exit_balance else {
+            return;
+        };
+
+        let equity = EquityPoint::from(exit_balance);
+        let peak_equity = self
+            .points
+            .last()
+            .map_or(self.starting_equity, |last| last.peak_equity)
+            .max(equity.total);
+
+        // Drawdown is a ratio, so is calculated as an f64 like the Drawdown metric
+        let peak = peak_equity.to_f64().unwrap_or_default();
+        let drawdown = if peak == 0.0 {
+            0.0
+        } else {
+            (equity.total.to_f64().unwrap_or_default() - peak) / peak
+        };
+
+        self.points.push(EquityCurvePoint {
+            time: equity.time,
+            equity: equity.total,
+            peak_equity,
+            drawdown,
+        });
+    }
+
+    /// Time-indexed equity series.
+    pub fn equity(&self) -> impl Iterator<Item = (DateTime<Utc>, Decimal)> + '_ {
+        self.points.iter().map(|point| (point.time, point.equity))
+    }
+
+    /// Time-indexed drawdown series.
+    pub fn drawdown(&self) -> impl Iterator<Item = (DateTime<Utc>, f64)> + '_ {
+        self.points.iter().map(|point| (point.time, point.drawdown))
+    }
+
+    /// Write the [`EquityCurve`] as CSV, including a header row.
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writeln!(writer, "{}", Self::CSV_HEADER)?;
+        self.points.iter().try_for_each(|point| {
+            writeln!(
+                writer,
+                "{},{},{},{}",
+                point.time.to_rfc3339(),
+                point.equity,
+                point.peak_equity,
+                point.drawdown
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{portfolio::Balance, test_util::position};
+    use chrono::Duration;
+    use rust_decimal_macros::dec;
+
+    fn exited_position(time: DateTime<Utc>, equity: Decimal) -> Position {
+        let mut position = position();
+        position.meta.exit_balance = Some(Balance {
+            time,
+            total: equity,
+            available: equity,
+        });
+        position
+    }
+
+    #[test]
+    fn test_equity_curve_generate() {
+        let base = Utc::now();
+        let day = |days| base + Duration::days(days);
+
+        // Positions are out of order, and the non-exited Position is ignored
+        let curve = EquityCurve::generate(
+            dec!(100),
+            &[
+                exited_position(day(2), dec!(80)),
+                position(),
+                exited_position(day(1), dec!(110)),
+                exited_position(day(3), dec!(120)),
+            ],
+        );
+
+        let actual = curve.drawdown().collect::<Vec<_>>();
+        let expected = vec![
+            (day(1), 0.0),
+            (day(2), (80.0 - 110.0) / 110.0),
+            (day(3), 0.0),
+        ];
+        assert_eq!(actual, expected);
+
+        let actual = curve.equity().map(|(_, equity)| equity).collect::<Vec<_>>();
+        assert_eq!(actual, vec![dec!(110), dec!(80), dec!(120)]);
+
+        let mut csv = Vec::new();
+        curve.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let mut lines = csv.lines();
+
+        assert_eq!(lines.next(), Some(EquityCurve::CSV_HEADER));
+        assert_eq!(
+            lines.next().unwrap(),
+            format!("{},110,110,0", day(1).to_rfc3339())
+        );
+        assert_eq!(lines.count(), 2);
+    }
+}
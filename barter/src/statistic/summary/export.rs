@@ -0,0 +1,149 @@
+use crate::statistic::summary::{equity::EquityCurve, TableBuilder};
+use prettytable::Row;
+use rust_decimal::prelude::ToPrimitive;
+use std::fmt::Write;
+
+/// Width of the [`equity_curve_svg`] in pixels.
+const SVG_WIDTH: f64 = 800.0;
+
+/// Height of the [`equity_curve_svg`] in pixels.
+const SVG_HEIGHT: f64 = 300.0;
+
+/// Padding around the plot area of the [`equity_curve_svg`] in pixels.
+const SVG_PADDING: f64 = 40.0;
+
+/// Inline stylesheet of the HTML tear sheet, so it is self-contained.
+const STYLE: &str = "body{font-family:sans-serif;margin:2em;color:#222}\
+table{border-collapse:collapse;margin-bottom:1.5em}\
+th,td{border:1px solid #ccc;padding:4px 10px;text-align:right}\
+th{background:#f4f4f4}h2{font-size:1.1em}";
+
+/// Cell contents of a [`Row`].
+fn cells(row: &Row) -> impl Iterator<Item = String> + '_ {
+    row.iter().map(|cell| cell.get_content())
+}
+
+/// Quote a CSV field if it contains a delimiter, quote or newline.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Escape text for inclusion in HTML.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Generate a CSV header row & value row from the [`TableBuilder`] titles & row.
+pub fn csv<T: TableBuilder>(builder: &T) -> String {
+    let join = |row: Row| {
+        cells(&row)
+            .map(|cell| csv_field(&cell))
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+    format!("{}\n{}\n", join(builder.titles()), join(builder.row()))
+}
+
+/// Generate a titled HTML `<table>` from the [`TableBuilder`] titles & row.
+pub fn html_table<T: TableBuilder>(title: &str, builder: &T) -> String {
+    let mut html = format!("<h2>{}</h2>\n<table>\n<tr>", escape_html(title));
+    for title in cells(&builder.titles()) {
+        let _ = write!(html, "<th>{}</th>", escape_html(&title));
+    }
+    html.push_str("</tr>\n<tr>");
+    for cell in cells(&builder.row()) {
+        let _ = write!(html, "<td>{}</td>", escape_html(&cell));
+    }
+    html.push_str("</tr>\n</table>\n");
+    html
+}
+
+/// Generate an inline SVG line chart of the [`EquityCurve`] equity & peak equity, starting from
+/// the starting equity.
+pub fn equity_curve_svg(curve: &EquityCurve) -> String {
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{SVG_WIDTH}\" \
+        height=\"{SVG_HEIGHT}\" viewBox=\"0 0 {SVG_WIDTH} {SVG_HEIGHT}\">\n\
+        <rect width=\"100%\" height=\"100%\" fill=\"white\" stroke=\"#ccc\"/>\n"
+    );
+
+    if curve.points.is_empty() {
+        let _ = writeln!(
+            svg,
+            "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\">No exited positions</text>",
+            SVG_WIDTH / 2.0,
+            SVG_HEIGHT / 2.0
+        );
+        svg.push_str("</svg>\n");
+        return svg;
+    }
+
+    let to_f64 = |value: rust_decimal::Decimal| value.to_f64().unwrap_or_default();
+    let starting_equity = to_f64(curve.starting_equity);
+    let equity = std::iter::once(starting_equity)
+        .chain(curve.points.iter().map(|point| to_f64(point.equity)))
+        .collect::<Vec<_>>();
+    let peak_equity = std::iter::once(starting_equity)
+        .chain(curve.points.iter().map(|point| to_f64(point.peak_equity)))
+        .collect::<Vec<_>>();
+
+    let min = equity.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = peak_equity
+        .iter()
+        .copied()
+        .fold(f64::NEG_INFINITY, f64::max);
+    let range = if max > min { max - min } else { 1.0 };
+
+    // Points are evenly spaced, since the equity only changes when a Position is exited
+    let x_step = (SVG_WIDTH - 2.0 * SVG_PADDING) / (equity.len() - 1).max(1) as f64;
+    let polyline = |series: &[f64]| {
+        series
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let x = SVG_PADDING + index as f64 * x_step;
+                let y = SVG_HEIGHT
+                    - SVG_PADDING
+                    - (value - min) / range * (SVG_HEIGHT - 2.0 * SVG_PADDING);
+                format!("{x:.1},{y:.1}")
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    let _ = writeln!(
+        svg,
+        "<polyline fill=\"none\" stroke=\"#999\" stroke-dasharray=\"4\" points=\"{}\"/>",
+        polyline(&peak_equity)
+    );
+    let _ = writeln!(
+        svg,
+        "<polyline fill=\"none\" stroke=\"#1f77b4\" stroke-width=\"2\" points=\"{}\"/>",
+        polyline(&equity)
+    );
+    let _ = writeln!(
+        svg,
+        "<text x=\"{SVG_PADDING}\" y=\"{}\" font-size=\"12\">{max:.2}</text>\n\
+        <text x=\"{SVG_PADDING}\" y=\"{}\" font-size=\"12\">{min:.2}</text>",
+        SVG_PADDING - 8.0,
+        SVG_HEIGHT - SVG_PADDING + 16.0,
+    );
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Generate a self-contained HTML document from the provided body.
+pub fn html_document(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n\
+        <style>{STYLE}</style>\n</head>\n<body>\n<h1>{title}</h1>\n{body}</body>\n</html>\n",
+        title = escape_html(title),
+    )
+}
@@ -0,0 +1,168 @@
+use crate::{
+    portfolio::position::Position,
+    statistic::summary::{Initialiser, PositionSummariser, TableBuilder},
+};
+use prettytable::Row;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Summarises the fees paid and exchange rebates earned across every closed [`Position`], so
+/// rebate-harvesting maker strategies can compare PnL before and after rebates.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct FeeSummary {
+    /// Sum of every fee paid, excluding exchange rebates.
+    pub fees_paid: Decimal,
+    /// Sum of every exchange rebate earned (eg/ maker rebates).
+    pub rebates_earned: Decimal,
+    /// Fees paid net of rebates earned. Negative if rebates exceed fees paid.
+    pub fees_net: Decimal,
+    /// Realised PnL excluding exchange rebates.
+    pub pnl_before_rebates: Decimal,
+    /// Realised PnL net of fees paid and rebates earned.
+    pub pnl_after_rebates: Decimal,
+}
+
+impl Initialiser for FeeSummary {
+    type Config = ();
+
+    fn init(_: Self::Config) -> Self {
+        Self::default()
+    }
+}
+
+impl PositionSummariser for FeeSummary {
+    fn update(&mut self, position: &Position) {
+        let rebates = position.calculate_rebates_total();
+
+        self.fees_paid +=
+            position.enter_fees.calculate_fees_paid() + position.exit_fees.calculate_fees_paid();
+        self.rebates_earned += rebates;
+        self.fees_net = self.fees_paid - self.rebates_earned;
+
+        self.pnl_after_rebates += position.realised_profit_loss;
+        self.pnl_before_rebates += position.realised_profit_loss - rebates;
+    }
+}
+
+impl TableBuilder for FeeSummary {
+    fn titles(&self) -> Row {
+        row![
+            "Fees Paid",
+            "Rebates Earned",
+            "Net Fees",
+            "PnL Before Rebates",
+            "PnL After Rebates",
+        ]
+    }
+
+    fn row(&self) -> Row {
+        row![
+            format!("{:.3}", self.fees_paid),
+            format!("{:.3}", self.rebates_earned),
+            format!("{:.3}", self.fees_net),
+            format!("{:.3}", self.pnl_before_rebates),
+            format!("{:.3}", self.pnl_after_rebates),
+        ]
+    }
+}
+
+impl FeeSummary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{execution::Fees, test_util::position};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_fee_summary_update() {
+        struct TestCase {
+            enter_fees: Fees,
+            exit_fees: Fees,
+            realised_profit_loss: Decimal,
+            expected: FeeSummary,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: taker fees only, no rebates
+                enter_fees: Fees {
+                    exchange: dec!(1),
+                    slippage: dec!(0.5),
+                    network: dec!(0),
+                },
+                exit_fees: Fees {
+                    exchange: dec!(1),
+                    slippage: dec!(0.5),
+                    network: dec!(0),
+                },
+                realised_profit_loss: dec!(7),
+                expected: FeeSummary {
+                    fees_paid: dec!(3),
+                    rebates_earned: dec!(0),
+                    fees_net: dec!(3),
+                    pnl_before_rebates: dec!(7),
+                    pnl_after_rebates: dec!(7),
+                },
+            },
+            TestCase {
+                // TC1: maker rebate on entry & exit
+                enter_fees: Fees {
+                    exchange: dec!(-0.25),
+                    slippage: dec!(0),
+                    network: dec!(0),
+                },
+                exit_fees: Fees {
+                    exchange: dec!(-0.25),
+                    slippage: dec!(0),
+                    network: dec!(0),
+                },
+                realised_profit_loss: dec!(1.5),
+                expected: FeeSummary {
+                    fees_paid: dec!(0),
+                    rebates_earned: dec!(0.5),
+                    fees_net: dec!(-0.5),
+                    pnl_before_rebates: dec!(1),
+                    pnl_after_rebates: dec!(1.5),
+                },
+            },
+            TestCase {
+                // TC2: maker rebate on entry, taker fee & network fee on exit
+                enter_fees: Fees {
+                    exchange: dec!(-0.5),
+                    slippage: dec!(0),
+                    network: dec!(0),
+                },
+                exit_fees: Fees {
+                    exchange: dec!(1),
+                    slippage: dec!(0),
+                    network: dec!(0.25),
+                },
+                realised_profit_loss: dec!(-2),
+                expected: FeeSummary {
+                    fees_paid: dec!(1.25),
+                    rebates_earned: dec!(0.5),
+                    fees_net: dec!(0.75),
+                    pnl_before_rebates: dec!(-2.5),
+                    pnl_after_rebates: dec!(-2),
+                },
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut input = position();
+            input.enter_fees = test.enter_fees;
+            input.exit_fees = test.exit_fees;
+            input.realised_profit_loss = test.realised_profit_loss;
+
+            let mut actual = FeeSummary::new();
+            actual.update(&input);
+
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
@@ -1,9 +1,15 @@
 pub mod data;
 pub mod drawdown;
+pub mod equity;
+pub mod export;
+pub mod fees;
+pub mod performance;
+pub mod periodic;
 pub mod pnl;
 pub mod trading;
 
 use crate::portfolio::position::Position;
+use chrono::{DateTime, Utc};
 use prettytable::{Cell, Row, Table};
 use smol_str::SmolStr;
 
@@ -21,6 +27,18 @@ pub trait PositionSummariser: Copy {
     }
 }
 
+/// [`PositionSummariser`] that is updated incrementally during a live trading session, and can be
+/// queried at any time with it's time dependent statistics (eg/ trades per day, annualised
+/// ratios, CAGR) calculated over the interval elapsed up to `now`, rather than only up to the last
+/// [`Position`] update.
+pub trait ProgressiveSummariser: PositionSummariser {
+    /// Summary so far, as of `now`. Defaults to the summary as of the last [`Position`] update,
+    /// for summaries without time dependent statistics.
+    fn summary_so_far(&self, _now: DateTime<Utc>) -> Self {
+        *self
+    }
+}
+
 pub trait TableBuilder {
     fn titles(&self) -> Row;
     fn row(&self) -> Row;
@@ -0,0 +1,360 @@
+use crate::{
+    portfolio::position::Position,
+    statistic::{
+        de_duration_from_secs,
+        metric::ratio::{OmegaRatio, ProfitFactor},
+        se_duration_as_secs,
+        summary::{PositionSummariser, ProgressiveSummariser, TableBuilder},
+    },
+};
+use chrono::{DateTime, Duration, Utc};
+use prettytable::Row;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct PerformanceSummary {
+    pub starting_equity: Decimal,
+    pub equity: Decimal,
+    pub start_time: Option<DateTime<Utc>>,
+    pub cagr: f64,
+    pub omega_ratio: OmegaRatio,
+    pub profit_factor: ProfitFactor,
+    pub exposure: ExposureSummary,
+    pub streaks: StreakSummary,
+    pub time_under_water: TimeUnderWater,
+}
+
+impl Default for PerformanceSummary {
+    fn default() -> Self {
+        Self::new(Decimal::ZERO, 0.0)
+    }
+}
+
+impl PositionSummariser for PerformanceSummary {
+    fn update(&mut self, position: &Position) {
+        let start_time = *self.start_time.get_or_insert(position.meta.enter_time);
+        let exit_time = exit_time(position);
+
+        // Equity curve is the starting equity plus the cumulative realised PnL
+        self.equity += position.realised_profit_loss;
+        self.cagr = calculate_cagr(
+            self.starting_equity.to_f64().unwrap_or_default(),
+            self.equity.to_f64().unwrap_or_default(),
+            exit_time.signed_duration_since(start_time),
+        );
+
+        self.omega_ratio
+            .update(position.calculate_profit_loss_return());
+        self.profit_factor.update(position.realised_profit_loss);
+        self.exposure.update(position);
+        self.streaks.update(position.realised_profit_loss);
+        self.time_under_water.update(self.equity, exit_time);
+    }
+}
+
+impl ProgressiveSummariser for PerformanceSummary {
+    /// CAGR is annualised over the interval from the first [`Position`] entry up to `now`, and
+    /// any ongoing underwater period is extended up to `now`.
+    fn summary_so_far(&self, now: DateTime<Utc>) -> Self {
+        let mut summary = *self;
+        if let Some(start_time) = summary.start_time {
+            summary.cagr = calculate_cagr(
+                summary.starting_equity.to_f64().unwrap_or_default(),
+                summary.equity.to_f64().unwrap_or_default(),
+                now.signed_duration_since(start_time),
+            );
+        }
+        summary.time_under_water = summary.time_under_water.so_far(now);
+        summary
+    }
+}
+
+impl TableBuilder for PerformanceSummary {
+    fn titles(&self) -> Row {
+        row![
+            "CAGR",
+            "Omega Ratio",
+            "Profit Factor",
+            "Avg. Exposure Days",
+            "Max Exposure Days",
+            "Max Win Streak",
+            "Max Loss Streak",
+            "Max Time Under Water Days",
+            "Total Time Under Water Days",
+        ]
+    }
+
+    fn row(&self) -> Row {
+        row![
+            format!("{:.3}", self.cagr),
+            format!("{:.3}", self.omega_ratio.omega_ratio),
+            format!("{:.3}", self.profit_factor.profit_factor),
+            format!("{:.3}", days(self.exposure.mean_duration)),
+            format!("{:.3}", days(self.exposure.max_duration)),
+            self.streaks.max_win_streak.to_string(),
+            self.streaks.max_loss_streak.to_string(),
+            format!("{:.3}", days(self.time_under_water.max_duration)),
+            format!("{:.3}", days(self.time_under_water.total_duration)),
+        ]
+    }
+}
+
+impl PerformanceSummary {
+    pub fn new(starting_equity: Decimal, risk_free_return: f64) -> Self {
+        Self {
+            starting_equity,
+            equity: starting_equity,
+            start_time: None,
+            cagr: 0.0,
+            omega_ratio: OmegaRatio::init(risk_free_return),
+            profit_factor: ProfitFactor::default(),
+            exposure: ExposureSummary::default(),
+            streaks: StreakSummary::default(),
+            time_under_water: TimeUnderWater::new(starting_equity),
+        }
+    }
+}
+
+/// Time each [`Position`] was exposed to the market, from entry until exit.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct ExposureSummary {
+    pub count: u64,
+    #[serde(
+        deserialize_with = "de_duration_from_secs",
+        serialize_with = "se_duration_as_secs"
+    )]
+    pub mean_duration: Duration,
+    #[serde(
+        deserialize_with = "de_duration_from_secs",
+        serialize_with = "se_duration_as_secs"
+    )]
+    pub max_duration: Duration,
+    #[serde(
+        deserialize_with = "de_duration_from_secs",
+        serialize_with = "se_duration_as_secs"
+    )]
+    pub total_duration: Duration,
+}
+
+impl ExposureSummary {
+    pub fn update(&mut self, position: &Position) {
+        let duration = exit_time(position).signed_duration_since(position.meta.enter_time);
+
+        self.count += 1;
+        self.total_duration += duration;
+        self.max_duration = self.max_duration.max(duration);
+        self.mean_duration = self.total_duration / self.count as i32;
+    }
+}
+
+/// Consecutive winning & losing trade streaks. A trade with a negative realised PnL is a loss,
+/// otherwise it is a win.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct StreakSummary {
+    /// Current streak length, positive for consecutive wins & negative for consecutive losses.
+    pub current_streak: i64,
+    pub max_win_streak: u64,
+    pub max_loss_streak: u64,
+}
+
+impl StreakSummary {
+    pub fn update(&mut self, realised_profit_loss: Decimal) {
+        match (realised_profit_loss.is_sign_negative(), self.current_streak) {
+            // Continuation of losing streak
+            (true, streak) if streak < 0 => self.current_streak -= 1,
+            // Start of losing streak
+            (true, _) => self.current_streak = -1,
+            // Continuation of winning streak
+            (false, streak) if streak > 0 => self.current_streak += 1,
+            // Start of winning streak
+            (false, _) => self.current_streak = 1,
+        }
+
+        let streak = self.current_streak.unsigned_abs();
+        match self.current_streak.is_negative() {
+            true => self.max_loss_streak = self.max_loss_streak.max(streak),
+            false => self.max_win_streak = self.max_win_streak.max(streak),
+        }
+    }
+}
+
+/// Time the equity curve spent below it's previous peak (ie/ in a drawdown).
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct TimeUnderWater {
+    pub peak_equity: Decimal,
+    /// Start of the current underwater period, if the equity is below it's peak.
+    pub start_time: Option<DateTime<Utc>>,
+    #[serde(
+        deserialize_with = "de_duration_from_secs",
+        serialize_with = "se_duration_as_secs"
+    )]
+    pub max_duration: Duration,
+    /// Total duration of every finished underwater period.
+    #[serde(
+        deserialize_with = "de_duration_from_secs",
+        serialize_with = "se_duration_as_secs"
+    )]
+    pub total_duration: Duration,
+}
+
+impl TimeUnderWater {
+    pub fn new(starting_equity: Decimal) -> Self {
+        Self {
+            peak_equity: starting_equity,
+            ..Self::default()
+        }
+    }
+
+    pub fn update(&mut self, equity: Decimal, time: DateTime<Utc>) {
+        match (equity < self.peak_equity, self.start_time) {
+            // Start of underwater period
+            (true, None) => self.start_time = Some(time),
+
+            // Continuation of underwater period
+            (true, Some(start_time)) => {
+                self.max_duration = self.max_duration.max(time - start_time);
+            }
+
+            // End of underwater period - equity recovered to a new peak
+            (false, Some(start_time)) => {
+                let duration = time - start_time;
+                self.max_duration = self.max_duration.max(duration);
+                self.total_duration += duration;
+                self.start_time = None;
+                self.peak_equity = equity;
+            }
+
+            // New equity peak
+            (false, None) => self.peak_equity = equity,
+        }
+    }
+
+    /// [`TimeUnderWater`] as of `now`, including the ongoing underwater period (if any).
+    pub fn so_far(&self, now: DateTime<Utc>) -> Self {
+        let mut time_under_water = *self;
+        if let Some(start_time) = self.start_time {
+            time_under_water.max_duration = self.max_duration.max(now - start_time);
+        }
+        time_under_water
+    }
+}
+
+/// Calculates the Compound Annual Growth Rate between the starting & ending equity over the
+/// provided [`Duration`], using 365 calendar days per year.
+///
+/// See documentation: <https://www.investopedia.com/terms/c/cagr.asp>
+pub fn calculate_cagr(starting_equity: f64, ending_equity: f64, duration: Duration) -> f64 {
+    let years = days(duration) / 365.0;
+    match years <= 0.0 || starting_equity <= 0.0 {
+        true => 0.0,
+        false => (ending_equity / starting_equity).powf(years.recip()) - 1.0,
+    }
+}
+
+/// Exit time of a [`Position`], estimated with the last update time if it is not exited.
+fn exit_time(position: &Position) -> DateTime<Utc> {
+    position
+        .meta
+        .exit_balance
+        .map(|exit_balance| exit_balance.time)
+        .unwrap_or(position.meta.update_time)
+}
+
+fn days(duration: Duration) -> f64 {
+    duration.num_seconds() as f64 / 86400.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{portfolio::Balance, test_util::position};
+    use rust_decimal_macros::dec;
+
+    fn exited_position(
+        enter_time: DateTime<Utc>,
+        exit_time: DateTime<Utc>,
+        realised_profit_loss: Decimal,
+    ) -> Position {
+        let mut position = position();
+        position.meta.enter_time = enter_time;
+        position.meta.exit_balance = Some(Balance {
+            time: exit_time,
+            total: Decimal::ZERO,
+            available: Decimal::ZERO,
+        });
+        position.realised_profit_loss = realised_profit_loss;
+        position
+    }
+
+    #[test]
+    fn test_calculate_cagr() {
+        struct TestCase {
+            starting_equity: f64,
+            ending_equity: f64,
+            duration: Duration,
+            expected: f64,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: equity doubles in one year
+                starting_equity: 100.0,
+                ending_equity: 200.0,
+                duration: Duration::days(365),
+                expected: 1.0,
+            },
+            TestCase {
+                // TC1: equity quadruples in two years
+                starting_equity: 100.0,
+                ending_equity: 400.0,
+                duration: Duration::days(730),
+                expected: 1.0,
+            },
+            TestCase {
+                // TC2: zero duration
+                starting_equity: 100.0,
+                ending_equity: 200.0,
+                duration: Duration::zero(),
+                expected: 0.0,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = calculate_cagr(test.starting_equity, test.ending_equity, test.duration);
+            assert!((actual - test.expected).abs() < 1e-10, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_performance_summary_update() {
+        let base_time = Utc::now();
+        let day = |days| base_time + Duration::days(days);
+
+        let mut summary = PerformanceSummary::new(dec!(1000), 0.0);
+        summary.generate_summary(&[
+            exited_position(day(0), day(1), dec!(100)),
+            exited_position(day(1), day(4), dec!(-50)),
+            exited_position(day(4), day(5), dec!(-20)),
+            exited_position(day(5), day(6), dec!(-10)),
+            exited_position(day(6), day(10), dec!(200)),
+        ]);
+
+        assert_eq!(summary.equity, dec!(1220));
+        assert_eq!(summary.profit_factor.profit_factor, dec!(3.75));
+
+        assert_eq!(summary.exposure.count, 5);
+        assert_eq!(summary.exposure.max_duration, Duration::days(4));
+        assert_eq!(summary.exposure.mean_duration, Duration::hours(48));
+
+        assert_eq!(summary.streaks.max_win_streak, 1);
+        assert_eq!(summary.streaks.max_loss_streak, 3);
+        assert_eq!(summary.streaks.current_streak, 1);
+
+        // Underwater from the 2nd trade exit (day 4) until recovering at the 5th (day 10)
+        assert_eq!(summary.time_under_water.max_duration, Duration::days(6));
+        assert_eq!(summary.time_under_water.total_duration, Duration::days(6));
+        assert_eq!(summary.time_under_water.start_time, None);
+        assert_eq!(summary.time_under_water.peak_equity, dec!(1220));
+    }
+}
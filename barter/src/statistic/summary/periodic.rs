@@ -0,0 +1,274 @@
+use crate::portfolio::position::Position;
+use barter_instrument::{
+    instrument::Instrument,
+    market::{Market, MarketId},
+};
+use chrono::{DateTime, Datelike, Days, NaiveDate, Utc};
+use prettytable::{Row, Table};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Calendar period used to bucket [`PeriodicReturns`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Period {
+    Daily,
+    /// ISO weeks, starting on Monday.
+    Weekly,
+    Monthly,
+}
+
+impl Period {
+    /// Determine the first date of the [`Period`] bucket containing the provided time.
+    pub fn bucket(&self, time: DateTime<Utc>) -> NaiveDate {
+        let date = time.date_naive();
+        match self {
+            Period::Daily => date,
+            Period::Weekly => date
+                .checked_sub_days(Days::new(date.weekday().num_days_from_monday() as u64))
+                .unwrap_or(date),
+            Period::Monthly => date.with_day(1).unwrap_or(date),
+        }
+    }
+}
+
+/// Realised PnL of the [`Position`]s exited within one [`Period`] bucket.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct PeriodReturn {
+    /// Portfolio equity at the start of the [`Period`] bucket.
+    pub start_equity: Decimal,
+    pub pnl: Decimal,
+    pub trades: u64,
+}
+
+impl PeriodReturn {
+    fn new(start_equity: Decimal) -> Self {
+        Self {
+            start_equity,
+            pnl: Decimal::ZERO,
+            trades: 0,
+        }
+    }
+
+    /// Realised PnL as a proportion of the portfolio equity at the start of the [`Period`] bucket.
+    ///
+    /// For a per-[`Market`] [`PeriodReturn`], this is the [`Market`]'s contribution to the
+    /// aggregate portfolio return.
+    pub fn return_pct(&self) -> f64 {
+        self.pnl
+            .checked_div(self.start_equity)
+            .and_then(|return_pct| return_pct.to_f64())
+            .unwrap_or_default()
+    }
+}
+
+/// Calendar bucketed realised PnL & returns, both aggregated across the portfolio & broken down
+/// per [`Market`]. Generated in one pass through the exited [`Position`]s.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct PeriodicReturns {
+    pub period: Period,
+    /// Portfolio equity after every [`Position`] processed so far.
+    pub equity: Decimal,
+    pub aggregate: BTreeMap<NaiveDate, PeriodReturn>,
+    pub markets: BTreeMap<MarketId, BTreeMap<NaiveDate, PeriodReturn>>,
+}
+
+impl PeriodicReturns {
+    pub fn new(period: Period, starting_equity: Decimal) -> Self {
+        Self {
+            period,
+            equity: starting_equity,
+            aggregate: BTreeMap::new(),
+            markets: BTreeMap::new(),
+        }
+    }
+
+    /// Generate [`PeriodicReturns`] from the provided [`Position`]s, ordered by exit time.
+    pub fn generate(period: Period, starting_equity: Decimal, positions: &[Position]) -> Self {
+        let mut positions = positions.iter().collect::<Vec<_>>();
+        positions.sort_by_key(|position| exit_time(position));
+
+        positions.into_iter().fold(
+            Self::new(period, starting_equity),
+            |mut returns, position| {
+                returns.update(position);
+                returns
+            },
+        )
+    }
+
+    /// Update the [`PeriodicReturns`] with the next [`Position`], which must not have exited before
+    /// any previously processed [`Position`].
+    pub fn update(&mut self, position: &Position) {
+        let bucket = self.period.bucket(exit_time(position));
+        let pnl = position.realised_profit_loss;
+
+        let aggregate = self
+            .aggregate
+            .entry(bucket)
+            .or_insert_with(|| PeriodReturn::new(self.equity));
+        aggregate.pnl += pnl;
+        aggregate.trades += 1;
+        let start_equity = aggregate.start_equity;
+
+        let market = self
+            .markets
+            .entry(MarketId::from(&Market::<Instrument>::new(
+                position.exchange,
+                position.instrument.clone(),
+            )))
+            .or_default()
+            .entry(bucket)
+            .or_insert_with(|| PeriodReturn::new(start_equity));
+        market.pnl += pnl;
+        market.trades += 1;
+
+        self.equity += pnl;
+    }
+
+    /// Generate a [`Table`] of the aggregate [`PeriodReturn`]s, with a row per [`Period`] bucket.
+    pub fn table(&self) -> Table {
+        table(self.aggregate.iter())
+    }
+
+    /// Generate a [`Table`] of the provided [`Market`]'s [`PeriodReturn`]s, with a row per
+    /// [`Period`] bucket.
+    pub fn market_table(&self, market: &MarketId) -> Table {
+        table(self.markets.get(market).into_iter().flatten())
+    }
+}
+
+fn table<'a, Iter>(returns: Iter) -> Table
+where
+    Iter: IntoIterator<Item = (&'a NaiveDate, &'a PeriodReturn)>,
+{
+    let mut table = Table::new();
+    table.set_titles(row!["Period", "Trades", "PnL", "Return %"]);
+
+    for (bucket, period_return) in returns {
+        table.add_row(Row::from([
+            bucket.to_string(),
+            period_return.trades.to_string(),
+            format!("{:.3}", period_return.pnl),
+            format!("{:.3}", period_return.return_pct() * 100.0),
+        ]));
+    }
+
+    table
+}
+
+/// Exit time of a [`Position`], estimated with the last update time if it is not exited.
+fn exit_time(position: &Position) -> DateTime<Utc> {
+    position
+        .meta
+        .exit_balance
+        .map(|exit_balance| exit_balance.time)
+        .unwrap_or(position.meta.update_time)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{portfolio::Balance, test_util::position};
+    use barter_instrument::{exchange::ExchangeId, instrument::kind::InstrumentKind};
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    fn exited_position(
+        time: DateTime<Utc>,
+        instrument: (&str, &str),
+        realised_profit_loss: Decimal,
+    ) -> Position {
+        let mut position = position();
+        position.instrument = Instrument::from((instrument.0, instrument.1, InstrumentKind::Spot));
+        position.realised_profit_loss = realised_profit_loss;
+        position.meta.exit_balance = Some(Balance {
+            time,
+            total: Decimal::ZERO,
+            available: Decimal::ZERO,
+        });
+        position
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_period_bucket() {
+        struct TestCase {
+            period: Period,
+            input: DateTime<Utc>,
+            expected: NaiveDate,
+        }
+
+        // Thursday
+        let time = Utc.with_ymd_and_hms(2024, 2, 29, 13, 30, 0).unwrap();
+
+        let tests = vec![
+            TestCase {
+                // TC0: daily
+                period: Period::Daily,
+                input: time,
+                expected: date(2024, 2, 29),
+            },
+            TestCase {
+                // TC1: weekly buckets start on the previous Monday
+                period: Period::Weekly,
+                input: time,
+                expected: date(2024, 2, 26),
+            },
+            TestCase {
+                // TC2: monthly
+                period: Period::Monthly,
+                input: time,
+                expected: date(2024, 2, 1),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.period.bucket(test.input);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_periodic_returns_generate() {
+        let time = |month, day| Utc.with_ymd_and_hms(2024, month, day, 12, 0, 0).unwrap();
+
+        let returns = PeriodicReturns::generate(
+            Period::Monthly,
+            dec!(1000),
+            &[
+                exited_position(time(2, 10), ("eth", "usdt"), dec!(-50)),
+                exited_position(time(1, 5), ("btc", "usdt"), dec!(100)),
+                exited_position(time(2, 1), ("btc", "usdt"), dec!(160)),
+            ],
+        );
+
+        assert_eq!(returns.equity, dec!(1210));
+
+        let january = returns.aggregate[&date(2024, 1, 1)];
+        assert_eq!(january.start_equity, dec!(1000));
+        assert_eq!(january.return_pct(), 0.1);
+
+        let february = returns.aggregate[&date(2024, 2, 1)];
+        assert_eq!(february.start_equity, dec!(1100));
+        assert_eq!(february.pnl, dec!(110));
+        assert_eq!(february.trades, 2);
+        assert_eq!(february.return_pct(), 0.1);
+
+        // Market contributions use the aggregate start equity of the bucket
+        let eth = MarketId::from(&Market::<Instrument>::new(
+            ExchangeId::BinanceSpot,
+            Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
+        ));
+        let eth_february = returns.markets[&eth][&date(2024, 2, 1)];
+        assert_eq!(eth_february.start_equity, dec!(1100));
+        assert_eq!(eth_february.pnl, dec!(-50));
+
+        assert_eq!(returns.table().len(), 2);
+        assert_eq!(returns.market_table(&eth).len(), 1);
+    }
+}
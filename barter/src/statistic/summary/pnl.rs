@@ -2,12 +2,15 @@ use crate::{
     portfolio::position::Position,
     statistic::{
         de_duration_from_secs, se_duration_as_secs,
-        summary::{data::DataSummary, Initialiser, PositionSummariser, TableBuilder},
+        summary::{
+            data::DataSummary, Initialiser, PositionSummariser, ProgressiveSummariser, TableBuilder,
+        },
     },
 };
 use barter_integration::Side;
 use chrono::{DateTime, Duration, Utc};
 use prettytable::Row;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
@@ -21,6 +24,13 @@ pub struct PnLReturnSummary {
     pub trades_per_day: f64,
     pub total: DataSummary,
     pub losses: DataSummary,
+    /// Downside deviation of every PnL return (ie/ square root of the second order lower partial
+    /// moment, using a target return of zero).
+    #[serde(default)]
+    pub downside_deviation: f64,
+    /// Sum of squared negative PnL returns, used to calculate the downside deviation.
+    #[serde(default)]
+    pub downside_sum_squares: f64,
 }
 
 impl Initialiser for PnLReturnSummary {
@@ -39,6 +49,8 @@ impl Default for PnLReturnSummary {
             trades_per_day: 0.0,
             total: DataSummary::default(),
             losses: DataSummary::default(),
+            downside_deviation: 0.0,
+            downside_sum_squares: 0.0,
         }
     }
 }
@@ -63,7 +75,24 @@ impl PositionSummariser for PnLReturnSummary {
         // Update Loss PnL Returns if relevant
         if pnl_return.is_sign_negative() {
             self.losses.update(pnl_return);
+            self.downside_sum_squares += pnl_return.powi(2);
         }
+
+        // Update Downside Deviation, which includes non-negative PnL Returns as zero
+        self.downside_deviation = (self.downside_sum_squares / self.total.count as f64).sqrt();
+    }
+}
+
+impl ProgressiveSummariser for PnLReturnSummary {
+    /// Trades per day is calculated over the interval from the first trade up to `now`, so idle
+    /// time since the last [`Position`] update dilutes it.
+    fn summary_so_far(&self, now: DateTime<Utc>) -> Self {
+        let mut summary = *self;
+        if summary.total.count > 0 && now > summary.time + summary.duration {
+            summary.duration = now.signed_duration_since(summary.time);
+            summary.update_trades_per_day();
+        }
+        summary
     }
 }
 
@@ -101,7 +130,7 @@ impl TableBuilder for PnLReturnSummary {
 }
 
 impl PnLReturnSummary {
-    const SECONDS_IN_DAY: f64 = 86400.0;
+    const MILLISECONDS_IN_DAY: f64 = 86_400_000.0;
 
     pub fn new() -> Self {
         Self {
@@ -110,6 +139,8 @@ impl PnLReturnSummary {
             trades_per_day: 0.0,
             total: Default::default(),
             losses: Default::default(),
+            downside_deviation: 0.0,
+            downside_sum_squares: 0.0,
         }
     }
 
@@ -123,46 +154,59 @@ impl PnLReturnSummary {
         }
     }
 
+    /// Update the trades per day over the trading session duration. Partial days are measured
+    /// to the millisecond, so short sessions are not over or under annualised. Trades per day
+    /// is zero until the session has a positive duration.
     pub fn update_trades_per_day(&mut self) {
-        self.trades_per_day = self.total.count as f64
-            / (self.duration.num_seconds() as f64 / PnLReturnSummary::SECONDS_IN_DAY)
+        let days = self.duration.num_milliseconds() as f64 / PnLReturnSummary::MILLISECONDS_IN_DAY;
+
+        self.trades_per_day = match days > 0.0 {
+            true => self.total.count as f64 / days,
+            false => 0.0,
+        }
     }
 }
 
+/// Realised PnL summed exactly as [`Decimal`]s, so it reconciles with exchange statements.
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
 pub struct ProfitLossSummary {
-    pub long_contracts: f64,
-    pub long_pnl: f64,
-    pub long_pnl_per_contract: f64,
-    pub short_contracts: f64,
-    pub short_pnl: f64,
-    pub short_pnl_per_contract: f64,
-    pub total_contracts: f64,
-    pub total_pnl: f64,
-    pub total_pnl_per_contract: f64,
+    pub long_contracts: Decimal,
+    pub long_pnl: Decimal,
+    pub long_pnl_per_contract: Decimal,
+    pub short_contracts: Decimal,
+    pub short_pnl: Decimal,
+    pub short_pnl_per_contract: Decimal,
+    pub total_contracts: Decimal,
+    pub total_pnl: Decimal,
+    pub total_pnl_per_contract: Decimal,
 }
 
 impl PositionSummariser for ProfitLossSummary {
     fn update(&mut self, position: &Position) {
         self.total_contracts += position.quantity.abs();
         self.total_pnl += position.realised_profit_loss;
-        self.total_pnl_per_contract = self.total_pnl / self.total_contracts;
+        self.total_pnl_per_contract = per_contract(self.total_pnl, self.total_contracts);
 
         match position.side {
             Side::Buy => {
                 self.long_contracts += position.quantity.abs();
                 self.long_pnl += position.realised_profit_loss;
-                self.long_pnl_per_contract = self.long_pnl / self.long_contracts;
+                self.long_pnl_per_contract = per_contract(self.long_pnl, self.long_contracts);
             }
             Side::Sell => {
                 self.short_contracts += position.quantity.abs();
                 self.short_pnl += position.realised_profit_loss;
-                self.short_pnl_per_contract = self.short_pnl / self.short_contracts;
+                self.short_pnl_per_contract = per_contract(self.short_pnl, self.short_contracts);
             }
         }
     }
 }
 
+/// PnL per contract, or zero if no contracts have been traded.
+fn per_contract(pnl: Decimal, contracts: Decimal) -> Decimal {
+    pnl.checked_div(contracts).unwrap_or_default()
+}
+
 impl TableBuilder for ProfitLossSummary {
     fn titles(&self) -> Row {
         row![
@@ -238,8 +282,8 @@ mod tests {
         let mut input_position = position();
         input_position.meta.exit_balance = Some(Balance {
             time: base_time.checked_add_signed(Duration::days(15)).unwrap(),
-            total: 0.0,
-            available: 0.0,
+            total: Decimal::ZERO,
+            available: Decimal::ZERO,
         });
 
         pnl_return_view.update_trading_session_duration(&input_position);
@@ -248,4 +292,69 @@ mod tests {
 
         assert_eq!(pnl_return_view.duration, expected);
     }
+
+    #[test]
+    fn summary_so_far_calculates_trades_per_day_up_to_now() {
+        struct TestCase {
+            count: u64,
+            duration: Duration,
+            now: Duration,
+            expected_trades_per_day: f64,
+        }
+
+        let base_time = Utc::now();
+
+        let tests = vec![
+            TestCase {
+                // TC0: no trades, so no trades per day regardless of elapsed time
+                count: 0,
+                duration: Duration::zero(),
+                now: Duration::days(2),
+                expected_trades_per_day: 0.0,
+            },
+            TestCase {
+                // TC1: idle time since the last trade dilutes trades per day
+                count: 4,
+                duration: Duration::days(1),
+                now: Duration::days(2),
+                expected_trades_per_day: 2.0,
+            },
+            TestCase {
+                // TC2: partial day measured precisely
+                count: 3,
+                duration: Duration::hours(6),
+                now: Duration::hours(12),
+                expected_trades_per_day: 6.0,
+            },
+            TestCase {
+                // TC3: now before the last update leaves the summary unchanged
+                count: 2,
+                duration: Duration::days(1),
+                now: Duration::hours(1),
+                expected_trades_per_day: 2.0,
+            },
+            TestCase {
+                // TC4: zero duration session has no trades per day, rather than infinite
+                count: 1,
+                duration: Duration::zero(),
+                now: Duration::zero(),
+                expected_trades_per_day: 0.0,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut summary = PnLReturnSummary::new();
+            summary.time = base_time;
+            summary.total.count = test.count;
+            summary.duration = test.duration;
+            summary.update_trades_per_day();
+
+            let actual = summary.summary_so_far(base_time + test.now);
+            assert_eq!(
+                actual.trades_per_day, test.expected_trades_per_day,
+                "TC{} failed",
+                index
+            );
+        }
+    }
 }
@@ -1,21 +1,29 @@
 use crate::{
     portfolio::position::Position,
     statistic::{
+        conversion::{normalise_position, QuoteConverter},
+        error::StatisticError,
         metric::ratio::{CalmarRatio, Ratio, SharpeRatio, SortinoRatio},
         summary::{
-            drawdown::DrawdownSummary, pnl::PnLReturnSummary, Initialiser, PositionSummariser,
-            TableBuilder,
+            drawdown::DrawdownSummary,
+            equity::EquityCurve,
+            export::{csv, equity_curve_svg, html_document, html_table},
+            fees::FeeSummary,
+            performance::PerformanceSummary,
+            pnl::PnLReturnSummary,
+            Initialiser, PositionSummariser, ProgressiveSummariser, TableBuilder,
         },
     },
 };
 use chrono::{DateTime, Duration, Utc};
 use prettytable::{Cell, Row};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Configuration for initialising a [`TradingSummary`] via the init() constructor method.
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct Config {
-    pub starting_equity: f64,
+    pub starting_equity: Decimal,
     pub trading_days_per_year: usize,
     pub risk_free_return: f64,
 }
@@ -25,6 +33,10 @@ pub struct TradingSummary {
     pub pnl_returns: PnLReturnSummary,
     pub drawdown: DrawdownSummary,
     pub tear_sheet: TearSheet,
+    #[serde(default)]
+    pub fees: FeeSummary,
+    #[serde(default)]
+    pub performance: PerformanceSummary,
 }
 
 impl Initialiser for TradingSummary {
@@ -35,6 +47,8 @@ impl Initialiser for TradingSummary {
             pnl_returns: PnLReturnSummary::new(),
             drawdown: DrawdownSummary::new(config.starting_equity),
             tear_sheet: TearSheet::new(config.risk_free_return),
+            fees: FeeSummary::new(),
+            performance: PerformanceSummary::new(config.starting_equity, config.risk_free_return),
         }
     }
 }
@@ -44,6 +58,86 @@ impl PositionSummariser for TradingSummary {
         self.pnl_returns.update(position);
         self.drawdown.update(position);
         self.tear_sheet.update(&self.pnl_returns, &self.drawdown);
+        self.fees.update(position);
+        self.performance.update(position);
+    }
+}
+
+impl ProgressiveSummariser for TradingSummary {
+    /// Time dependent statistics (ie/ trades per day, the [`TearSheet`] ratios & CAGR) are
+    /// calculated over the interval elapsed up to `now`. Use the engine or market time (rather
+    /// than the wall clock) when summarising a backtest.
+    fn summary_so_far(&self, now: DateTime<Utc>) -> Self {
+        let mut summary = *self;
+        summary.pnl_returns = self.pnl_returns.summary_so_far(now);
+        summary.performance = self.performance.summary_so_far(now);
+        summary
+            .tear_sheet
+            .update(&summary.pnl_returns, &summary.drawdown);
+        summary
+    }
+}
+
+impl TradingSummary {
+    /// Update the [`TradingSummary`] with the next [`Position`], first converting it's quote
+    /// denominated values into the [`QuoteConverter`] reporting currency.
+    ///
+    /// Use when trading instruments with different quote currencies (eg/ "usdt" & "btc"), so the
+    /// aggregated statistics are not a mix of units.
+    pub fn update_normalised<Converter>(
+        &mut self,
+        position: &Position,
+        converter: &Converter,
+    ) -> Result<(), StatisticError>
+    where
+        Converter: QuoteConverter,
+    {
+        self.update(&normalise_position(position, converter)?);
+        Ok(())
+    }
+
+    /// Generate the [`TradingSummary`] from [`Position`]s normalised into the [`QuoteConverter`]
+    /// reporting currency. See [`Self::update_normalised`].
+    pub fn generate_normalised_summary<Converter>(
+        &mut self,
+        positions: &[Position],
+        converter: &Converter,
+    ) -> Result<(), StatisticError>
+    where
+        Converter: QuoteConverter,
+    {
+        positions
+            .iter()
+            .try_for_each(|position| self.update_normalised(position, converter))
+    }
+
+    /// Serialise the [`TradingSummary`] as pretty printed JSON, eg/ for archiving backtest
+    /// results.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Generate a CSV header row & value row containing every column of the [`TradingSummary`]
+    /// table, formatted as they are when printed.
+    pub fn to_csv(&self) -> String {
+        csv(self)
+    }
+
+    /// Generate a self-contained HTML tear sheet containing a table for each component summary
+    /// & an inline SVG chart of the provided [`EquityCurve`], eg/ for sharing from CI backtest
+    /// runs.
+    pub fn to_html(&self, title: &str, equity_curve: &EquityCurve) -> String {
+        let body = [
+            html_table("PnL Returns", &self.pnl_returns),
+            html_table("Risk Adjusted Returns", &self.tear_sheet),
+            html_table("Drawdown", &self.drawdown),
+            html_table("Fees", &self.fees),
+            html_table("Performance", &self.performance),
+            format!("<h2>Equity Curve</h2>\n{}", equity_curve_svg(equity_curve)),
+        ]
+        .concat();
+
+        html_document(title, &body)
     }
 }
 
@@ -63,6 +157,14 @@ impl TableBuilder for TradingSummary {
             titles.push(title.clone())
         }
 
+        for title in &self.fees.titles() {
+            titles.push(title.clone())
+        }
+
+        for title in &self.performance.titles() {
+            titles.push(title.clone())
+        }
+
         Row::new(titles)
     }
 
@@ -81,6 +183,14 @@ impl TableBuilder for TradingSummary {
             cells.push(cell.clone())
         }
 
+        for cell in &self.fees.row() {
+            cells.push(cell.clone())
+        }
+
+        for cell in &self.performance.row() {
+            cells.push(cell.clone())
+        }
+
         Row::new(cells)
     }
 }
@@ -132,3 +242,93 @@ pub fn calculate_trading_duration(start_time: &DateTime<Utc>, position: &Positio
         Some(exit_balance) => exit_balance.time.signed_duration_since(*start_time),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{portfolio::Balance, test_util::position};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_trading_summary_export() {
+        let mut summary = TradingSummary::init(Config {
+            starting_equity: dec!(1000),
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        });
+
+        let mut exited = position();
+        exited.realised_profit_loss = dec!(50);
+        exited.meta.exit_balance = Some(Balance::new(Utc::now(), dec!(1050), dec!(1050)));
+        let positions = vec![exited];
+        summary.generate_summary(&positions);
+
+        let json = serde_json::from_str::<serde_json::Value>(&summary.to_json().unwrap()).unwrap();
+        assert!(json["pnl_returns"].is_object());
+        assert!(json["performance"].is_object());
+
+        let csv = summary.to_csv();
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[0].split(',').count(),
+            summary.titles().len(),
+            "CSV header column count"
+        );
+        assert_eq!(lines[0].split(',').count(), lines[1].split(',').count());
+        assert!(lines[0].starts_with("Trades,Wins,Losses"));
+
+        let html = summary.to_html(
+            "Backtest <RSI>",
+            &EquityCurve::generate(dec!(1000), &positions),
+        );
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>Backtest &lt;RSI&gt;</title>"));
+        assert_eq!(html.matches("<table>").count(), 5);
+        assert!(html.contains("<polyline"));
+
+        let empty = summary.to_html("Empty", &EquityCurve::new(dec!(1000)));
+        assert!(empty.contains("No exited positions"));
+    }
+
+    #[test]
+    fn test_trading_summary_so_far() {
+        let start = Utc::now();
+        let mut summary = TradingSummary::init(Config {
+            starting_equity: dec!(1000),
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        });
+
+        let mut exited = position();
+        exited.meta.enter_time = start;
+        exited.meta.update_time = start + Duration::days(1);
+        exited.realised_profit_loss = dec!(-100);
+        exited.meta.exit_balance = Some(Balance::new(
+            start + Duration::days(1),
+            dec!(900),
+            dec!(900),
+        ));
+        summary.update(&exited);
+
+        let so_far = summary.summary_so_far(start + Duration::days(4));
+
+        // Trades per day & CAGR are calculated over the whole elapsed interval
+        assert_eq!(so_far.pnl_returns.duration, Duration::days(4));
+        assert_eq!(so_far.pnl_returns.trades_per_day, 0.25);
+        assert!(so_far.performance.cagr > summary.performance.cagr);
+        assert_eq!(
+            so_far.tear_sheet.sharpe_ratio.trades_per_day,
+            so_far.pnl_returns.trades_per_day
+        );
+
+        // Ongoing underwater period is extended up to now
+        assert_eq!(
+            so_far.performance.time_under_water.max_duration,
+            Duration::days(3)
+        );
+
+        // Summary itself is unchanged, so it continues to be updated incrementally
+        assert_eq!(summary.pnl_returns.duration, Duration::days(1));
+    }
+}
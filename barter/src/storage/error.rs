@@ -0,0 +1,21 @@
+use barter_execution::error::ExecutionError;
+use thiserror::Error;
+
+/// All errors generated in the barter::storage module.
+#[derive(Error, Debug)]
+pub enum StorageError {
+    #[error("SQL executor failed to execute statement: {0}")]
+    Executor(String),
+
+    #[error("sqlx: {0}")]
+    Sqlx(#[from] ::sqlx::Error),
+
+    #[error("SQL executor does not support queries")]
+    UnsupportedQuery,
+
+    #[error("failed to decode {0} from SqlRow")]
+    Decode(String),
+
+    #[error("failed to fetch exchange trade history: {0}")]
+    Exchange(#[from] ExecutionError),
+}
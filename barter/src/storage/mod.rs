@@ -0,0 +1,768 @@
+use crate::{
+    event::{Event, MessageTransmitter},
+    execution::FillEvent,
+    portfolio::{
+        position::{Position, PositionExit, PositionUpdate},
+        Balance, OrderEvent,
+    },
+    storage::error::StorageError,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, SecondsFormat, Utc};
+use rust_decimal::Decimal;
+use std::{collections::HashMap, str::FromStr};
+use tokio::sync::mpsc;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// Barter storage module specific errors.
+pub mod error;
+
+/// Reconciliation of persisted fills against the engine & the exchange trade history, producing
+/// an exportable discrepancy report.
+pub mod reconcile;
+
+/// sqlx backed SQLite & Postgres [`SqlExecutor`]s.
+pub mod sqlx;
+
+/// SQL dialect of the database an [`SqlStore`] persists to.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum SqlDialect {
+    Sqlite,
+    Postgres,
+}
+
+impl SqlDialect {
+    /// Bind parameter placeholder of the provided 1-based parameter index.
+    fn placeholder(&self, index: usize) -> String {
+        match self {
+            SqlDialect::Sqlite => "?".to_string(),
+            SqlDialect::Postgres => format!("${index}"),
+        }
+    }
+
+    fn id_column(&self) -> &'static str {
+        match self {
+            SqlDialect::Sqlite => "id INTEGER PRIMARY KEY AUTOINCREMENT",
+            SqlDialect::Postgres => "id BIGSERIAL PRIMARY KEY",
+        }
+    }
+
+    fn decimal_type(&self) -> &'static str {
+        match self {
+            SqlDialect::Sqlite => "TEXT",
+            SqlDialect::Postgres => "NUMERIC",
+        }
+    }
+
+    fn timestamp_type(&self) -> &'static str {
+        match self {
+            SqlDialect::Sqlite => "TEXT",
+            SqlDialect::Postgres => "TIMESTAMPTZ",
+        }
+    }
+}
+
+/// Bind parameter of an [`SqlStatement`], mapped to the native type of the database driver by
+/// the [`SqlExecutor`].
+#[derive(Clone, PartialEq, Debug)]
+pub enum SqlValue {
+    Null,
+    Integer(i64),
+    Real(f64),
+    Text(String),
+    /// Bound as `NUMERIC` in Postgres, and as exact decimal text in SQLite.
+    Decimal(Decimal),
+    /// Bound as `TIMESTAMPTZ` in Postgres, and as [`sqlite_timestamp`] text in SQLite so that
+    /// timestamps order correctly.
+    Timestamp(DateTime<Utc>),
+}
+
+impl SqlValue {
+    /// Decode a [`Decimal`] from a `NUMERIC` (Postgres) or decimal text (SQLite) column.
+    pub fn as_decimal(&self) -> Option<Decimal> {
+        match self {
+            SqlValue::Decimal(value) => Some(*value),
+            SqlValue::Text(value) => Decimal::from_str(value).ok(),
+            _ => None,
+        }
+    }
+
+    /// Decode a timestamp from a `TIMESTAMPTZ` (Postgres) or RFC 3339 text (SQLite) column.
+    pub fn as_timestamp(&self) -> Option<DateTime<Utc>> {
+        match self {
+            SqlValue::Timestamp(value) => Some(*value),
+            SqlValue::Text(value) => DateTime::parse_from_rfc3339(value)
+                .ok()
+                .map(|time| time.with_timezone(&Utc)),
+            _ => None,
+        }
+    }
+
+    /// Decode a text column.
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            SqlValue::Text(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<Option<DateTime<Utc>>> for SqlValue {
+    fn from(value: Option<DateTime<Utc>>) -> Self {
+        value.map_or(SqlValue::Null, SqlValue::Timestamp)
+    }
+}
+
+/// Fixed precision RFC 3339 text representation of a timestamp, used to bind
+/// [`SqlValue::Timestamp`]s in SQLite so they order lexicographically.
+pub fn sqlite_timestamp(time: DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
+/// Row returned by [`SqlExecutor::query`], keyed by column name.
+pub type SqlRow = HashMap<String, SqlValue>;
+
+/// Parameterised SQL statement, executed by an [`SqlExecutor`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct SqlStatement {
+    pub sql: String,
+    pub params: Vec<SqlValue>,
+}
+
+impl SqlStatement {
+    fn new(sql: impl Into<String>, params: Vec<SqlValue>) -> Self {
+        Self {
+            sql: sql.into(),
+            params,
+        }
+    }
+}
+
+/// Executes [`SqlStatement`]s against a SQLite or Postgres database.
+///
+/// Driver agnostic: binds each [`SqlValue`] to the native driver type. Implemented by the sqlx [`SqliteExecutor`](sqlx::SqliteExecutor) & [`PostgresExecutor`](sqlx::PostgresExecutor)
+/// executors, or by the application using it's database driver of choice.
+#[async_trait]
+pub trait SqlExecutor {
+    /// Execute a single [`SqlStatement`], returning the number of rows affected.
+    async fn execute(&mut self, statement: &SqlStatement) -> Result<u64, StorageError>;
+
+    /// Execute every [`SqlStatement`] in order. Implementations should override this to execute
+    /// the statements in a single transaction.
+    async fn execute_batch(&mut self, statements: &[SqlStatement]) -> Result<(), StorageError> {
+        for statement in statements {
+            self.execute(statement).await?;
+        }
+        Ok(())
+    }
+
+    /// Execute a query [`SqlStatement`] (eg/ [`SqlStore::fills_between`]), returning every
+    /// resulting [`SqlRow`].
+    ///
+    /// Defaults to failing with [`StorageError::UnsupportedQuery`], since persisting Events only
+    /// requires [`Self::execute`].
+    async fn query(&mut self, _statement: &SqlStatement) -> Result<Vec<SqlRow>, StorageError> {
+        Err(StorageError::UnsupportedQuery)
+    }
+}
+
+/// Generates the schema, persistence & query [`SqlStatement`]s of the fills, orders, positions
+/// & balance snapshots of an [`Engine`](crate::engine::Engine).
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct SqlStore {
+    engine_id: Uuid,
+    dialect: SqlDialect,
+}
+
+impl SqlStore {
+    /// Construct a new [`SqlStore`] for the provided engine_id & [`SqlDialect`].
+    pub fn new(engine_id: Uuid, dialect: SqlDialect) -> Self {
+        Self { engine_id, dialect }
+    }
+
+    /// Idempotent `CREATE TABLE` & `CREATE INDEX` statements of the [`SqlStore`] schema.
+    pub fn schema(&self) -> Vec<SqlStatement> {
+        let id = self.dialect.id_column();
+        let decimal = self.dialect.decimal_type();
+        let timestamp = self.dialect.timestamp_type();
+
+        [
+            format!(
+                "CREATE TABLE IF NOT EXISTS fills ({id}, engine_id TEXT NOT NULL, \
+                time {timestamp} NOT NULL, exchange TEXT NOT NULL, instrument TEXT NOT NULL, \
+                decision TEXT NOT NULL, quantity {decimal} NOT NULL, \
+                fill_value_gross {decimal} NOT NULL, fees_exchange {decimal} NOT NULL, \
+                fees_slippage {decimal} NOT NULL, fees_network {decimal} NOT NULL)"
+            ),
+            format!(
+                "CREATE TABLE IF NOT EXISTS orders ({id}, engine_id TEXT NOT NULL, \
+                time {timestamp} NOT NULL, exchange TEXT NOT NULL, instrument TEXT NOT NULL, \
+                decision TEXT NOT NULL, quantity {decimal} NOT NULL, order_type TEXT NOT NULL, \
+                market_close {decimal} NOT NULL)"
+            ),
+            format!(
+                "CREATE TABLE IF NOT EXISTS positions (position_id TEXT PRIMARY KEY, \
+                engine_id TEXT NOT NULL, exchange TEXT NOT NULL, instrument TEXT NOT NULL, \
+                side TEXT NOT NULL, quantity {decimal} NOT NULL, \
+                enter_time {timestamp} NOT NULL, update_time {timestamp} NOT NULL, \
+                exit_time {timestamp}, enter_fees_total {decimal} NOT NULL, \
+                enter_avg_price_gross {decimal} NOT NULL, enter_value_gross {decimal} NOT NULL, \
+                exit_fees_total {decimal} NOT NULL, exit_avg_price_gross {decimal} NOT NULL, \
+                exit_value_gross {decimal} NOT NULL, current_symbol_price {decimal} NOT NULL, \
+                current_value_gross {decimal} NOT NULL, \
+                unrealised_profit_loss {decimal} NOT NULL, \
+                realised_profit_loss {decimal} NOT NULL)"
+            ),
+            format!(
+                "CREATE TABLE IF NOT EXISTS balances ({id}, engine_id TEXT NOT NULL, \
+                time {timestamp} NOT NULL, total {decimal} NOT NULL, \
+                available {decimal} NOT NULL)"
+            ),
+            "CREATE INDEX IF NOT EXISTS fills_engine_time ON fills (engine_id, time)".to_string(),
+            "CREATE INDEX IF NOT EXISTS orders_engine_time ON orders (engine_id, time)".to_string(),
+            "CREATE INDEX IF NOT EXISTS positions_engine ON positions (engine_id)".to_string(),
+            "CREATE INDEX IF NOT EXISTS balances_engine_time ON balances (engine_id, time)"
+                .to_string(),
+        ]
+        .into_iter()
+        .map(|sql| SqlStatement::new(sql, vec![]))
+        .collect()
+    }
+
+    /// [`SqlStatement`]s that persist the provided [`Event`], if it is a fill, order, position or
+    /// balance [`Event`].
+    pub fn statements(&self, event: &Event) -> Vec<SqlStatement> {
+        match event {
+            Event::Fill(fill) => vec![self.insert_fill(fill)],
+            Event::OrderNew(order) => vec![self.insert_order(order)],
+            Event::PositionNew(position) => vec![self.upsert_position(position)],
+            Event::PositionUpdate(update) => vec![self.update_position(update)],
+            Event::PositionExit(exit) => vec![self.exit_position(exit)],
+            Event::Balance(balance) => vec![self.insert_balance(balance)],
+            _ => vec![],
+        }
+    }
+
+    /// Every fill with a time in the range `[from, to)`, oldest first.
+    pub fn fills_between(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> SqlStatement {
+        self.select_between("fills", from, to)
+    }
+
+    /// Every order with a time in the range `[from, to)`, oldest first.
+    pub fn orders_between(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> SqlStatement {
+        self.select_between("orders", from, to)
+    }
+
+    /// Every balance snapshot with a time in the range `[from, to)`, oldest first.
+    pub fn balances_between(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> SqlStatement {
+        self.select_between("balances", from, to)
+    }
+
+    /// Most recent balance snapshot.
+    pub fn latest_balance(&self) -> SqlStatement {
+        SqlStatement::new(
+            format!(
+                "SELECT * FROM balances WHERE engine_id = {} ORDER BY time DESC, id DESC LIMIT 1",
+                self.dialect.placeholder(1)
+            ),
+            vec![self.engine_id()],
+        )
+    }
+
+    /// Every open position.
+    pub fn open_positions(&self) -> SqlStatement {
+        SqlStatement::new(
+            format!(
+                "SELECT * FROM positions WHERE engine_id = {} AND exit_time IS NULL \
+                ORDER BY enter_time",
+                self.dialect.placeholder(1)
+            ),
+            vec![self.engine_id()],
+        )
+    }
+
+    /// Every exited position, in the order they were exited.
+    pub fn exited_positions(&self) -> SqlStatement {
+        SqlStatement::new(
+            format!(
+                "SELECT * FROM positions WHERE engine_id = {} AND exit_time IS NOT NULL \
+                ORDER BY exit_time",
+                self.dialect.placeholder(1)
+            ),
+            vec![self.engine_id()],
+        )
+    }
+
+    fn engine_id(&self) -> SqlValue {
+        SqlValue::Text(self.engine_id.to_string())
+    }
+
+    fn select_between(&self, table: &str, from: DateTime<Utc>, to: DateTime<Utc>) -> SqlStatement {
+        SqlStatement::new(
+            format!(
+                "SELECT * FROM {table} WHERE engine_id = {} AND time >= {} AND time < {} \
+                ORDER BY time, id",
+                self.dialect.placeholder(1),
+                self.dialect.placeholder(2),
+                self.dialect.placeholder(3),
+            ),
+            vec![
+                self.engine_id(),
+                SqlValue::Timestamp(from),
+                SqlValue::Timestamp(to),
+            ],
+        )
+    }
+
+    fn insert(&self, table: &str, columns: &[&str], params: Vec<SqlValue>) -> SqlStatement {
+        debug_assert_eq!(columns.len(), params.len());
+        SqlStatement::new(
+            format!(
+                "INSERT INTO {table} ({}) VALUES ({})",
+                columns.join(", "),
+                self.placeholders(1, columns.len())
+            ),
+            params,
+        )
+    }
+
+    fn update(
+        &self,
+        table: &str,
+        columns: &[&str],
+        key: &str,
+        mut params: Vec<SqlValue>,
+        key_value: SqlValue,
+    ) -> SqlStatement {
+        debug_assert_eq!(columns.len(), params.len());
+        let assignments = columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| format!("{column} = {}", self.dialect.placeholder(index + 1)))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        params.push(key_value);
+        SqlStatement::new(
+            format!(
+                "UPDATE {table} SET {assignments} WHERE {key} = {}",
+                self.dialect.placeholder(params.len())
+            ),
+            params,
+        )
+    }
+
+    fn placeholders(&self, first: usize, count: usize) -> String {
+        (first..first + count)
+            .map(|index| self.dialect.placeholder(index))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn insert_fill(&self, fill: &FillEvent) -> SqlStatement {
+        self.insert(
+            "fills",
+            &[
+                "engine_id",
+                "time",
+                "exchange",
+                "instrument",
+                "decision",
+                "quantity",
+                "fill_value_gross",
+                "fees_exchange",
+                "fees_slippage",
+                "fees_network",
+            ],
+            vec![
+                self.engine_id(),
+                SqlValue::Timestamp(fill.time),
+                SqlValue::Text(fill.exchange.to_string()),
+                SqlValue::Text(fill.instrument.to_string()),
+                SqlValue::Text(format!("{:?}", fill.decision)),
+                SqlValue::Decimal(fill.quantity),
+                SqlValue::Decimal(fill.fill_value_gross),
+                SqlValue::Decimal(fill.fees.exchange),
+                SqlValue::Decimal(fill.fees.slippage),
+                SqlValue::Decimal(fill.fees.network),
+            ],
+        )
+    }
+
+    fn insert_order(&self, order: &OrderEvent) -> SqlStatement {
+        self.insert(
+            "orders",
+            &[
+                "engine_id",
+                "time",
+                "exchange",
+                "instrument",
+                "decision",
+                "quantity",
+                "order_type",
+                "market_close",
+            ],
+            vec![
+                self.engine_id(),
+                SqlValue::Timestamp(order.time),
+                SqlValue::Text(order.exchange.to_string()),
+                SqlValue::Text(order.instrument.to_string()),
+                SqlValue::Text(format!("{:?}", order.decision)),
+                SqlValue::Decimal(order.quantity),
+                SqlValue::Text(format!("{:?}", order.order_type)),
+                SqlValue::Decimal(order.market_meta.close),
+            ],
+        )
+    }
+
+    fn upsert_position(&self, position: &Position) -> SqlStatement {
+        const COLUMNS: [&str; 19] = [
+            "position_id",
+            "engine_id",
+            "exchange",
+            "instrument",
+            "side",
+            "quantity",
+            "enter_time",
+            "update_time",
+            "exit_time",
+            "enter_fees_total",
+            "enter_avg_price_gross",
+            "enter_value_gross",
+            "exit_fees_total",
+            "exit_avg_price_gross",
+            "exit_value_gross",
+            "current_symbol_price",
+            "current_value_gross",
+            "unrealised_profit_loss",
+            "realised_profit_loss",
+        ];
+
+        let exit_time = position
+            .meta
+            .exit_balance
+            .as_ref()
+            .map(|balance| balance.time);
+
+        let mut statement = self.insert(
+            "positions",
+            &COLUMNS,
+            vec![
+                SqlValue::Text(position.position_id.to_string()),
+                self.engine_id(),
+                SqlValue::Text(position.exchange.to_string()),
+                SqlValue::Text(position.instrument.to_string()),
+                SqlValue::Text(position.side.to_string()),
+                SqlValue::Decimal(position.quantity),
+                SqlValue::Timestamp(position.meta.enter_time),
+                SqlValue::Timestamp(position.meta.update_time),
+                SqlValue::from(exit_time),
+                SqlValue::Decimal(position.enter_fees_total),
+                SqlValue::Decimal(position.enter_avg_price_gross),
+                SqlValue::Decimal(position.enter_value_gross),
+                SqlValue::Decimal(position.exit_fees_total),
+                SqlValue::Decimal(position.exit_avg_price_gross),
+                SqlValue::Decimal(position.exit_value_gross),
+                SqlValue::Decimal(position.current_symbol_price),
+                SqlValue::Decimal(position.current_value_gross),
+                SqlValue::Decimal(position.unrealised_profit_loss),
+                SqlValue::Decimal(position.realised_profit_loss),
+            ],
+        );
+
+        // Supported by both SQLite (3.24+) & Postgres (9.5+)
+        let assignments = COLUMNS[1..]
+            .iter()
+            .map(|column| format!("{column} = excluded.{column}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        statement.sql = format!(
+            "{} ON CONFLICT (position_id) DO UPDATE SET {assignments}",
+            statement.sql
+        );
+        statement
+    }
+
+    fn update_position(&self, update: &PositionUpdate) -> SqlStatement {
+        self.update(
+            "positions",
+            &[
+                "update_time",
+                "current_symbol_price",
+                "current_value_gross",
+                "unrealised_profit_loss",
+            ],
+            "position_id",
+            vec![
+                SqlValue::Timestamp(update.update_time),
+                SqlValue::Decimal(update.current_symbol_price),
+                SqlValue::Decimal(update.current_value_gross),
+                SqlValue::Decimal(update.unrealised_profit_loss),
+            ],
+            SqlValue::Text(update.position_id.to_string()),
+        )
+    }
+
+    fn exit_position(&self, exit: &PositionExit) -> SqlStatement {
+        self.update(
+            "positions",
+            &[
+                "update_time",
+                "exit_time",
+                "exit_fees_total",
+                "exit_avg_price_gross",
+                "exit_value_gross",
+                "unrealised_profit_loss",
+                "realised_profit_loss",
+            ],
+            "position_id",
+            vec![
+                SqlValue::Timestamp(exit.exit_time),
+                SqlValue::Timestamp(exit.exit_time),
+                SqlValue::Decimal(exit.exit_fees_total),
+                SqlValue::Decimal(exit.exit_avg_price_gross),
+                SqlValue::Decimal(exit.exit_value_gross),
+                SqlValue::Decimal(Decimal::ZERO),
+                SqlValue::Decimal(exit.realised_profit_loss),
+            ],
+            SqlValue::Text(exit.position_id.to_string()),
+        )
+    }
+
+    fn insert_balance(&self, balance: &Balance) -> SqlStatement {
+        self.insert(
+            "balances",
+            &["engine_id", "time", "total", "available"],
+            vec![
+                self.engine_id(),
+                SqlValue::Timestamp(balance.time),
+                SqlValue::Decimal(balance.total),
+                SqlValue::Decimal(balance.available),
+            ],
+        )
+    }
+}
+
+/// [`Event`] transmitter that converts every fill, order, position & balance [`Event`] into
+/// [`SqlStatement`]s, sending them to the associated [`SqlWriter`] to be persisted.
+#[derive(Clone, Debug)]
+pub struct SqlEventTx {
+    store: SqlStore,
+    statements_tx: mpsc::UnboundedSender<Vec<SqlStatement>>,
+}
+
+impl MessageTransmitter<Event> for SqlEventTx {
+    fn send(&mut self, message: Event) {
+        let statements = self.store.statements(&message);
+        if statements.is_empty() {
+            return;
+        }
+
+        if self.statements_tx.send(statements).is_err() {
+            warn!(
+                why = "SqlWriter dropped",
+                "cannot persist Event to SqlStore"
+            );
+        }
+    }
+
+    fn send_many(&mut self, messages: Vec<Event>) {
+        let statements = messages
+            .iter()
+            .flat_map(|message| self.store.statements(message))
+            .collect::<Vec<_>>();
+        if statements.is_empty() {
+            return;
+        }
+
+        if self.statements_tx.send(statements).is_err() {
+            warn!(
+                why = "SqlWriter dropped",
+                "cannot persist Events to SqlStore"
+            );
+        }
+    }
+}
+
+/// Persists the [`SqlStatement`]s sent by the associated [`SqlEventTx`] using an
+/// [`SqlExecutor`], off the [`Trader`](crate::engine::trader::Trader) event loop.
+#[derive(Debug)]
+pub struct SqlWriter<Executor> {
+    store: SqlStore,
+    executor: Executor,
+    statements_rx: mpsc::UnboundedReceiver<Vec<SqlStatement>>,
+}
+
+impl<Executor> SqlWriter<Executor>
+where
+    Executor: SqlExecutor + Send,
+{
+    /// Construct a new [`SqlWriter`] using the provided [`SqlStore`] & [`SqlExecutor`],
+    /// returning the [`SqlEventTx`] that feeds it.
+    pub fn new(store: SqlStore, executor: Executor) -> (Self, SqlEventTx) {
+        let (statements_tx, statements_rx) = mpsc::unbounded_channel();
+
+        (
+            Self {
+                store,
+                executor,
+                statements_rx,
+            },
+            SqlEventTx {
+                store,
+                statements_tx,
+            },
+        )
+    }
+
+    /// Run the [`SqlWriter`], creating the [`SqlStore::schema`] if it does not exist and then
+    /// persisting every batch of [`SqlStatement`]s until every [`SqlEventTx`] is dropped.
+    ///
+    /// A failed batch is logged and skipped, so the trade history is never blocked by a single
+    /// bad write. Returns the number of failed batches.
+    pub async fn run(mut self) -> Result<usize, StorageError> {
+        self.executor.execute_batch(&self.store.schema()).await?;
+        info!(engine_id = %self.store.engine_id, "SqlWriter persisting Events");
+
+        let mut failed = 0;
+        while let Some(statements) = self.statements_rx.recv().await {
+            if let Err(error) = self.executor.execute_batch(&statements).await {
+                warn!(%error, ?statements, "SqlWriter failed to persist statements");
+                failed += 1;
+            }
+        }
+
+        Ok(failed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{fill_event, market_event_trade, position};
+    use barter_integration::Side;
+    use parking_lot::Mutex;
+    use rust_decimal_macros::dec;
+    use std::sync::Arc;
+
+    #[derive(Clone, Default)]
+    struct MockExecutor {
+        executed: Arc<Mutex<Vec<SqlStatement>>>,
+    }
+
+    #[async_trait]
+    impl SqlExecutor for MockExecutor {
+        async fn execute(&mut self, statement: &SqlStatement) -> Result<u64, StorageError> {
+            if statement.sql.starts_with("UPDATE") {
+                return Err(StorageError::Executor("mock failure".to_string()));
+            }
+            self.executed.lock().push(statement.clone());
+            Ok(1)
+        }
+    }
+
+    #[test]
+    fn test_sql_store_statements() {
+        struct TestCase {
+            dialect: SqlDialect,
+            input: Event,
+            expected_sql: Vec<&'static str>,
+        }
+
+        let engine_id = Uuid::new_v4();
+        let update = PositionUpdate::from(&mut position());
+
+        let tests = vec![
+            TestCase {
+                // TC0: SQLite fill insert
+                dialect: SqlDialect::Sqlite,
+                input: Event::Fill(fill_event()),
+                expected_sql: vec![
+                    "INSERT INTO fills (engine_id, time, exchange, instrument, decision, \
+                    quantity, fill_value_gross, fees_exchange, fees_slippage, fees_network) \
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                ],
+            },
+            TestCase {
+                // TC1: Postgres position update keyed by position_id
+                dialect: SqlDialect::Postgres,
+                input: Event::PositionUpdate(update),
+                expected_sql: vec![
+                    "UPDATE positions SET update_time = $1, current_symbol_price = $2, \
+                    current_value_gross = $3, unrealised_profit_loss = $4 \
+                    WHERE position_id = $5",
+                ],
+            },
+            TestCase {
+                // TC2: Events that are not persisted
+                dialect: SqlDialect::Sqlite,
+                input: Event::OrderUpdate,
+                expected_sql: vec![],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = SqlStore::new(engine_id, test.dialect).statements(&test.input);
+            let actual_sql = actual
+                .iter()
+                .map(|statement| statement.sql.as_str())
+                .collect::<Vec<_>>();
+            assert_eq!(actual_sql, test.expected_sql, "TC{} failed", index);
+
+            for statement in actual {
+                assert_eq!(
+                    statement.params.len(),
+                    statement.sql.matches(['?', '$']).count(),
+                    "TC{} failed",
+                    index
+                );
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sql_writer_run() {
+        let store = SqlStore::new(Uuid::new_v4(), SqlDialect::Sqlite);
+        let executor = MockExecutor::default();
+        let (writer, mut event_tx) = SqlWriter::new(store, executor.clone());
+
+        let mut position = position();
+        let update = PositionUpdate::from(&mut position);
+        let balance = Balance::new(position.meta.update_time, dec!(1012.5), dec!(1012.5));
+
+        event_tx.send_many(vec![
+            Event::PositionNew(Box::new(position)),
+            Event::Market(market_event_trade(Side::Buy)),
+            Event::Fill(fill_event()),
+        ]);
+        event_tx.send(Event::PositionUpdate(update));
+        event_tx.send(Event::Balance(balance));
+        drop(event_tx);
+
+        // PositionUpdate batch fails to execute, but does not block subsequent batches
+        assert_eq!(writer.run().await.unwrap(), 1);
+
+        let executed = executor.executed.lock();
+        let persisted = executed
+            .iter()
+            .skip(store.schema().len())
+            .map(|statement| statement.sql.split(" (").next().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            persisted,
+            vec![
+                "INSERT INTO positions",
+                "INSERT INTO fills",
+                "INSERT INTO balances"
+            ]
+        );
+        assert_eq!(
+            executed.last().unwrap().params[2..],
+            [
+                SqlValue::Decimal(dec!(1012.5)),
+                SqlValue::Decimal(dec!(1012.5))
+            ]
+        );
+    }
+}
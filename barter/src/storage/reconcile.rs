@@ -0,0 +1,580 @@
+use crate::{
+    execution::FillEvent,
+    storage::{error::StorageError, SqlExecutor, SqlRow, SqlStore},
+    strategy::Decision,
+};
+use barter_execution::{model::trade::TradeRecord, ExecutionClient};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::Side;
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use rust_decimal::{prelude::FromPrimitive, Decimal};
+use serde::{Deserialize, Serialize};
+use smol_str::{SmolStr, ToSmolStr};
+use std::fmt::Write;
+
+/// Fill recorded by the engine or persisted in the [`SqlStore`], normalised for comparison with
+/// an exchange [`TradeRecord`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct FillRecord {
+    pub time: DateTime<Utc>,
+    pub exchange: SmolStr,
+    pub instrument: SmolStr,
+    pub side: Side,
+    /// Absolute quantity filled.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub quantity: Decimal,
+    /// Average fill price, excluding fees.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub price: Decimal,
+    /// Total fees incurred, denominated in the quote symbol.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub fees: Decimal,
+}
+
+impl FillRecord {
+    fn side(decision: Decision) -> Side {
+        match decision {
+            Decision::Long | Decision::CloseShort => Side::Buy,
+            Decision::Short | Decision::CloseLong => Side::Sell,
+        }
+    }
+
+    fn price(fill_value_gross: Decimal, quantity: Decimal) -> Decimal {
+        fill_value_gross
+            .checked_div(quantity)
+            .unwrap_or(Decimal::ZERO)
+    }
+}
+
+impl From<&FillEvent> for FillRecord {
+    fn from(fill: &FillEvent) -> Self {
+        let quantity = fill.quantity.abs();
+        Self {
+            time: fill.time,
+            exchange: fill.exchange.to_smolstr(),
+            instrument: fill.instrument.to_smolstr(),
+            side: Self::side(fill.decision),
+            quantity,
+            price: Self::price(fill.fill_value_gross, quantity),
+            fees: fill.fees.calculate_total_fees(),
+        }
+    }
+}
+
+impl TryFrom<&SqlRow> for FillRecord {
+    type Error = StorageError;
+
+    fn try_from(row: &SqlRow) -> Result<Self, Self::Error> {
+        let text = |column: &str| {
+            row.get(column)
+                .and_then(|value| value.as_text())
+                .ok_or_else(|| StorageError::Decode(format!("fills.{column}")))
+        };
+        let decimal = |column: &str| {
+            row.get(column)
+                .and_then(|value| value.as_decimal())
+                .ok_or_else(|| StorageError::Decode(format!("fills.{column}")))
+        };
+
+        let decision = match text("decision")? {
+            "Long" => Decision::Long,
+            "CloseLong" => Decision::CloseLong,
+            "Short" => Decision::Short,
+            "CloseShort" => Decision::CloseShort,
+            _ => return Err(StorageError::Decode("fills.decision".to_string())),
+        };
+        let quantity = decimal("quantity")?.abs();
+
+        Ok(Self {
+            time: row
+                .get("time")
+                .and_then(|value| value.as_timestamp())
+                .ok_or_else(|| StorageError::Decode("fills.time".to_string()))?,
+            exchange: text("exchange")?.to_smolstr(),
+            instrument: text("instrument")?.to_smolstr(),
+            side: Self::side(decision),
+            quantity,
+            price: Self::price(decimal("fill_value_gross")?, quantity),
+            fees: decimal("fees_exchange")? + decimal("fees_slippage")? + decimal("fees_network")?,
+        })
+    }
+}
+
+/// Persisted [`FillRecord`] matched to an exchange [`TradeRecord`] with different fees.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct FeeMismatch {
+    pub fill: FillRecord,
+    pub trade: TradeRecord,
+    /// Exchange [`TradeRecord`] fees, denominated in the quote symbol.
+    #[serde(with = "rust_decimal::serde::str")]
+    pub exchange_fees: Decimal,
+}
+
+/// Tolerances used by the [`TradeReconciler`] to match fills & trades.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ReconcileConfig {
+    /// Maximum difference between the time of a fill & it's exchange trade.
+    pub time_tolerance: Duration,
+    /// Maximum difference between the quantity of a fill & it's exchange trade.
+    pub quantity_tolerance: Decimal,
+    /// Maximum difference between the quote denominated fees of a fill & it's exchange trade.
+    pub fee_tolerance: Decimal,
+}
+
+impl Default for ReconcileConfig {
+    fn default() -> Self {
+        Self {
+            time_tolerance: Duration::seconds(1),
+            quantity_tolerance: Decimal::new(1, 8),
+            fee_tolerance: Decimal::new(1, 8),
+        }
+    }
+}
+
+/// Discrepancies between the engine, database & exchange trades of an exchange over a time range.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ReconciliationReport {
+    pub exchange: ExchangeId,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    /// Number of persisted fills matched to an exchange trade.
+    pub matched: usize,
+    /// Fills recorded by the engine that were never persisted.
+    pub missing_from_database: Vec<FillRecord>,
+    /// Persisted fills absent from the exchange trade history.
+    pub missing_from_exchange: Vec<FillRecord>,
+    /// Exchange trades that were never persisted (eg/ fills missed during a disconnect, or
+    /// manual trades).
+    pub unrecorded_trades: Vec<TradeRecord>,
+    /// Persisted fills matched to an exchange trade with different fees.
+    pub fee_mismatches: Vec<FeeMismatch>,
+}
+
+impl ReconciliationReport {
+    /// Determine if the engine, database & exchange trades fully agree.
+    pub fn is_reconciled(&self) -> bool {
+        self.missing_from_database.is_empty()
+            && self.missing_from_exchange.is_empty()
+            && self.unrecorded_trades.is_empty()
+            && self.fee_mismatches.is_empty()
+    }
+
+    /// Export every discrepancy as CSV, one row per discrepancy.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(
+            "discrepancy,time,instrument,side,quantity,price,fees,exchange_fees,trade_id\n",
+        );
+
+        let mut write_fill = |kind: &str, fill: &FillRecord, exchange_fees: &str, id: &str| {
+            let _ = writeln!(
+                csv,
+                "{kind},{},{},{},{},{},{},{exchange_fees},{id}",
+                fill.time.to_rfc3339_opts(SecondsFormat::Micros, true),
+                csv_field(&fill.instrument),
+                fill.side,
+                fill.quantity,
+                fill.price,
+                fill.fees,
+            );
+        };
+        for fill in &self.missing_from_database {
+            write_fill("missing_from_database", fill, "", "");
+        }
+        for fill in &self.missing_from_exchange {
+            write_fill("missing_from_exchange", fill, "", "");
+        }
+        for mismatch in &self.fee_mismatches {
+            write_fill(
+                "fee_mismatch",
+                &mismatch.fill,
+                &mismatch.exchange_fees.to_string(),
+                &csv_field(&mismatch.trade.trade.id.0),
+            );
+        }
+
+        for record in &self.unrecorded_trades {
+            let _ = writeln!(
+                csv,
+                "unrecorded_trade,{},{},{},{},{},,{},{}",
+                record.time.to_rfc3339_opts(SecondsFormat::Micros, true),
+                csv_field(&record.trade.instrument.to_string()),
+                record.trade.side,
+                record.trade.quantity,
+                record.trade.price,
+                exchange_fees(record),
+                csv_field(&record.trade.id.0),
+            );
+        }
+
+        csv
+    }
+}
+
+/// Quote a CSV field if it contains a delimiter, quote or newline.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// [`TradeRecord`] fees denominated in the quote symbol. Fees charged in the base symbol are
+/// converted using the trade price.
+fn exchange_fees(record: &TradeRecord) -> Decimal {
+    let trade = &record.trade;
+    let fees = Decimal::from_f64(trade.fees.fees).unwrap_or_default();
+    if trade.fees.symbol == trade.instrument.base {
+        fees * Decimal::from_f64(trade.price).unwrap_or_default()
+    } else {
+        fees
+    }
+}
+
+/// Reporting job that cross-checks persisted fills against the fills recorded by the engine & the
+/// exchange trade history over a time range, producing a [`ReconciliationReport`] for accounting
+/// & tax reporting.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct TradeReconciler {
+    pub config: ReconcileConfig,
+}
+
+impl TradeReconciler {
+    /// Construct a new [`TradeReconciler`] using the provided [`ReconcileConfig`].
+    pub fn new(config: ReconcileConfig) -> Self {
+        Self { config }
+    }
+
+    /// Fetch the fills persisted in the [`SqlStore`] & the exchange trade history in the range
+    /// `[from, to)`, and [`reconcile`](Self::reconcile) them. Optionally also cross-checks the
+    /// fills recorded by the engine (eg/ replayed from an
+    /// [`EventJournal`](crate::event::journal::EventJournal)).
+    pub async fn run<Executor, Client>(
+        &self,
+        store: &SqlStore,
+        executor: &mut Executor,
+        client: &Client,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        engine: Option<&[FillRecord]>,
+    ) -> Result<ReconciliationReport, StorageError>
+    where
+        Executor: SqlExecutor + Send,
+        Client: ExecutionClient + Sync,
+    {
+        let database = executor
+            .query(&store.fills_between(from, to))
+            .await?
+            .iter()
+            .map(FillRecord::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let trades = client.fetch_trades(from, to).await?;
+
+        Ok(self.reconcile(Client::CLIENT, from, to, engine, &database, &trades))
+    }
+
+    /// Reconcile the [`FillRecord`]s of the provided exchange against each other & the exchange
+    /// [`TradeRecord`]s. Fills of other exchanges are ignored.
+    ///
+    /// Fills & trades are matched on instrument, side, quantity & time (within the
+    /// [`ReconcileConfig`] tolerances), oldest first.
+    pub fn reconcile(
+        &self,
+        exchange: ExchangeId,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        engine: Option<&[FillRecord]>,
+        database: &[FillRecord],
+        trades: &[TradeRecord],
+    ) -> ReconciliationReport {
+        let exchange_name = exchange.to_smolstr();
+        let of_exchange = |fills: &[FillRecord]| {
+            fills
+                .iter()
+                .filter(|fill| fill.exchange == exchange_name)
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+        let database = of_exchange(database);
+
+        // Engine vs database
+        let missing_from_database = engine
+            .map(|engine| {
+                let mut unmatched = database.iter().collect::<Vec<_>>();
+                of_exchange(engine)
+                    .into_iter()
+                    .filter(|fill| {
+                        self.take_match(&mut unmatched, |persisted| {
+                            self.is_match(
+                                persisted,
+                                &fill.instrument,
+                                fill.side,
+                                fill.quantity,
+                                fill.time,
+                            )
+                        })
+                        .is_none()
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // Database vs exchange
+        let mut unmatched = database.iter().collect::<Vec<_>>();
+        let mut matched = 0;
+        let mut fee_mismatches = vec![];
+        let mut unrecorded_trades = vec![];
+
+        for record in trades {
+            let instrument = record.trade.instrument.to_smolstr();
+            let quantity = Decimal::from_f64(record.trade.quantity).unwrap_or_default();
+
+            match self.take_match(&mut unmatched, |fill| {
+                self.is_match(fill, &instrument, record.trade.side, quantity, record.time)
+            }) {
+                Some(fill) => {
+                    matched += 1;
+                    let exchange_fees = exchange_fees(record);
+                    if (fill.fees - exchange_fees).abs() > self.config.fee_tolerance {
+                        fee_mismatches.push(FeeMismatch {
+                            fill: fill.clone(),
+                            trade: record.clone(),
+                            exchange_fees,
+                        });
+                    }
+                }
+                None => unrecorded_trades.push(record.clone()),
+            }
+        }
+
+        ReconciliationReport {
+            exchange,
+            from,
+            to,
+            matched,
+            missing_from_database,
+            missing_from_exchange: unmatched.into_iter().cloned().collect(),
+            unrecorded_trades,
+            fee_mismatches,
+        }
+    }
+
+    fn is_match(
+        &self,
+        fill: &FillRecord,
+        instrument: &str,
+        side: Side,
+        quantity: Decimal,
+        time: DateTime<Utc>,
+    ) -> bool {
+        fill.instrument == instrument
+            && fill.side == side
+            && (fill.quantity - quantity).abs() <= self.config.quantity_tolerance
+            && (fill.time - time).abs() <= self.config.time_tolerance
+    }
+
+    /// Remove & return the oldest unmatched [`FillRecord`] satisfying the predicate.
+    fn take_match<'a, F>(
+        &self,
+        unmatched: &mut Vec<&'a FillRecord>,
+        predicate: F,
+    ) -> Option<&'a FillRecord>
+    where
+        F: Fn(&FillRecord) -> bool,
+    {
+        unmatched
+            .iter()
+            .enumerate()
+            .filter(|(_, fill)| predicate(fill))
+            .min_by_key(|(_, fill)| fill.time)
+            .map(|(index, _)| index)
+            .map(|index| unmatched.remove(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{storage::SqlValue, test_util::fill_event};
+    use barter_execution::model::trade::{SymbolFees, Trade};
+    use rust_decimal_macros::dec;
+
+    fn trade_record(fill: &FillRecord, id: &str, fees: SymbolFees) -> TradeRecord {
+        TradeRecord {
+            time: fill.time,
+            trade: Trade {
+                id: id.into(),
+                order_id: id.into(),
+                instrument: fill_event().instrument,
+                side: fill.side,
+                price: 100.0,
+                quantity: 1.0,
+                fees,
+            },
+        }
+    }
+
+    #[test]
+    fn test_fill_record_from_sql_row() {
+        let mut fill = fill_event();
+        fill.fees.exchange = dec!(0.1);
+        let expected = FillRecord::from(&fill);
+
+        let row = SqlRow::from([
+            (
+                "time".to_string(),
+                SqlValue::Text(crate::storage::sqlite_timestamp(fill.time)),
+            ),
+            (
+                "exchange".to_string(),
+                SqlValue::Text(fill.exchange.to_string()),
+            ),
+            (
+                "instrument".to_string(),
+                SqlValue::Text(fill.instrument.to_string()),
+            ),
+            ("decision".to_string(), SqlValue::Text("Long".to_string())),
+            ("quantity".to_string(), SqlValue::Text("1".to_string())),
+            ("fill_value_gross".to_string(), SqlValue::Decimal(dec!(100))),
+            (
+                "fees_exchange".to_string(),
+                SqlValue::Text("0.1".to_string()),
+            ),
+            ("fees_slippage".to_string(), SqlValue::Decimal(dec!(0))),
+            ("fees_network".to_string(), SqlValue::Decimal(dec!(0))),
+        ]);
+
+        let actual = FillRecord::try_from(&row).unwrap();
+        assert_eq!(actual.side, Side::Buy);
+        assert_eq!(actual.price, dec!(100));
+        assert_eq!(actual.fees, dec!(0.1));
+        assert_eq!(
+            actual.time.timestamp_micros(),
+            expected.time.timestamp_micros()
+        );
+    }
+
+    #[test]
+    fn test_trade_reconciler_reconcile() {
+        struct TestCase {
+            engine: Option<Vec<FillRecord>>,
+            database: Vec<FillRecord>,
+            trades: Vec<TradeRecord>,
+            expected_matched: usize,
+            expected_missing_from_database: usize,
+            expected_missing_from_exchange: usize,
+            expected_unrecorded_trades: usize,
+            expected_fee_mismatches: usize,
+        }
+
+        let mut fill = FillRecord::from(&fill_event());
+        fill.fees = dec!(0.1);
+        let mut later = fill.clone();
+        later.time = fill.time + Duration::minutes(5);
+
+        let usdt_fees = SymbolFees::new("usdt", 0.1);
+
+        let tests = vec![
+            TestCase {
+                // TC0: engine, database & exchange fully agree
+                engine: Some(vec![fill.clone()]),
+                database: vec![fill.clone()],
+                trades: vec![trade_record(&fill, "0", usdt_fees.clone())],
+                expected_matched: 1,
+                expected_missing_from_database: 0,
+                expected_missing_from_exchange: 0,
+                expected_unrecorded_trades: 0,
+                expected_fee_mismatches: 0,
+            },
+            TestCase {
+                // TC1: engine fill never persisted, so exchange trade is missing from database
+                engine: Some(vec![fill.clone()]),
+                database: vec![],
+                trades: vec![trade_record(&fill, "0", usdt_fees.clone())],
+                expected_matched: 0,
+                expected_missing_from_database: 1,
+                expected_missing_from_exchange: 0,
+                expected_unrecorded_trades: 1,
+                expected_fee_mismatches: 0,
+            },
+            TestCase {
+                // TC2: persisted fill outside the time tolerance of the exchange trade
+                engine: None,
+                database: vec![later.clone()],
+                trades: vec![trade_record(&fill, "0", usdt_fees.clone())],
+                expected_matched: 0,
+                expected_missing_from_database: 0,
+                expected_missing_from_exchange: 1,
+                expected_unrecorded_trades: 1,
+                expected_fee_mismatches: 0,
+            },
+            TestCase {
+                // TC3: exchange fees charged in the base symbol converted using the trade price
+                engine: None,
+                database: vec![fill.clone(), later.clone()],
+                trades: vec![
+                    trade_record(&fill, "0", SymbolFees::new("eth", 0.001)),
+                    trade_record(&later, "1", SymbolFees::new("eth", 0.002)),
+                ],
+                expected_matched: 2,
+                expected_missing_from_database: 0,
+                expected_missing_from_exchange: 0,
+                expected_unrecorded_trades: 0,
+                expected_fee_mismatches: 1,
+            },
+            TestCase {
+                // TC4: fills of other exchanges are ignored
+                engine: None,
+                database: vec![FillRecord {
+                    exchange: ExchangeId::Kraken.to_smolstr(),
+                    ..fill.clone()
+                }],
+                trades: vec![],
+                expected_matched: 0,
+                expected_missing_from_database: 0,
+                expected_missing_from_exchange: 0,
+                expected_unrecorded_trades: 0,
+                expected_fee_mismatches: 0,
+            },
+        ];
+
+        let reconciler = TradeReconciler::default();
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = reconciler.reconcile(
+                ExchangeId::BinanceSpot,
+                fill.time,
+                later.time,
+                test.engine.as_deref(),
+                &test.database,
+                &test.trades,
+            );
+            assert_eq!(actual.matched, test.expected_matched, "TC{} failed", index);
+            assert_eq!(
+                actual.missing_from_database.len(),
+                test.expected_missing_from_database,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                actual.missing_from_exchange.len(),
+                test.expected_missing_from_exchange,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                actual.unrecorded_trades.len(),
+                test.expected_unrecorded_trades,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                actual.fee_mismatches.len(),
+                test.expected_fee_mismatches,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
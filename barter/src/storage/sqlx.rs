@@ -0,0 +1,373 @@
+use crate::storage::{
+    error::StorageError, sqlite_timestamp, SqlExecutor, SqlRow, SqlStatement, SqlValue,
+};
+use ::sqlx::{
+    postgres::{PgArguments, PgPool, PgRow},
+    query::Query,
+    sqlite::{SqliteArguments, SqlitePool, SqliteRow},
+    Column, Postgres, Row, Sqlite, TypeInfo, ValueRef,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+/// [`SqlExecutor`] persisting to a SQLite database via a sqlx [`SqlitePool`].
+///
+/// Use with an [`SqlStore`](super::SqlStore) of [`SqlDialect::Sqlite`](super::SqlDialect).
+#[derive(Clone, Debug)]
+pub struct SqliteExecutor {
+    pool: SqlitePool,
+}
+
+impl SqliteExecutor {
+    /// Construct a new [`SqliteExecutor`] using the provided [`SqlitePool`].
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Connect a new [`SqliteExecutor`] to the provided url (eg/ "sqlite://trades.db?mode=rwc").
+    pub async fn connect(url: &str) -> Result<Self, StorageError> {
+        Ok(Self::new(SqlitePool::connect(url).await?))
+    }
+
+    /// [`SqlitePool`] used by the [`SqliteExecutor`].
+    pub fn pool(&self) -> &SqlitePool {
+        &self.pool
+    }
+
+    fn bind(statement: &SqlStatement) -> Query<'_, Sqlite, SqliteArguments<'_>> {
+        statement
+            .params
+            .iter()
+            .fold(::sqlx::query(&statement.sql), |query, param| match param {
+                SqlValue::Null => query.bind(None::<String>),
+                SqlValue::Integer(value) => query.bind(*value),
+                SqlValue::Real(value) => query.bind(*value),
+                SqlValue::Text(value) => query.bind(value.clone()),
+                SqlValue::Decimal(value) => query.bind(value.to_string()),
+                SqlValue::Timestamp(value) => query.bind(sqlite_timestamp(*value)),
+            })
+    }
+
+    fn decode(row: &SqliteRow) -> Result<SqlRow, StorageError> {
+        row.columns()
+            .iter()
+            .map(|column| {
+                let index = column.ordinal();
+                let raw = row.try_get_raw(index)?;
+                let value = if raw.is_null() {
+                    SqlValue::Null
+                } else {
+                    match raw.type_info().name() {
+                        "INTEGER" => SqlValue::Integer(row.try_get(index)?),
+                        "REAL" => SqlValue::Real(row.try_get(index)?),
+                        "TEXT" => SqlValue::Text(row.try_get(index)?),
+                        other => return Err(StorageError::Decode(format!("SQLite {other}"))),
+                    }
+                };
+                Ok((column.name().to_owned(), value))
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl SqlExecutor for SqliteExecutor {
+    async fn execute(&mut self, statement: &SqlStatement) -> Result<u64, StorageError> {
+        let result = Self::bind(statement).execute(&self.pool).await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn execute_batch(&mut self, statements: &[SqlStatement]) -> Result<(), StorageError> {
+        let mut transaction = self.pool.begin().await?;
+        for statement in statements {
+            Self::bind(statement).execute(&mut *transaction).await?;
+        }
+        transaction.commit().await.map_err(StorageError::from)
+    }
+
+    async fn query(&mut self, statement: &SqlStatement) -> Result<Vec<SqlRow>, StorageError> {
+        Self::bind(statement)
+            .fetch_all(&self.pool)
+            .await?
+            .iter()
+            .map(Self::decode)
+            .collect()
+    }
+}
+
+/// [`SqlExecutor`] persisting to a Postgres database via a sqlx [`PgPool`].
+///
+/// Use with an [`SqlStore`](super::SqlStore) of [`SqlDialect::Postgres`](super::SqlDialect).
+#[derive(Clone, Debug)]
+pub struct PostgresExecutor {
+    pool: PgPool,
+}
+
+impl PostgresExecutor {
+    /// Construct a new [`PostgresExecutor`] using the provided [`PgPool`].
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Connect a new [`PostgresExecutor`] to the provided url (eg/ "postgres://localhost/barter").
+    pub async fn connect(url: &str) -> Result<Self, StorageError> {
+        Ok(Self::new(PgPool::connect(url).await?))
+    }
+
+    /// [`PgPool`] used by the [`PostgresExecutor`].
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+
+    fn bind(statement: &SqlStatement) -> Query<'_, Postgres, PgArguments> {
+        statement
+            .params
+            .iter()
+            .fold(::sqlx::query(&statement.sql), |query, param| match param {
+                // Postgres requires a typed NULL, and the only nullable columns of the SqlStore
+                // schema are timestamps
+                SqlValue::Null => query.bind(None::<DateTime<Utc>>),
+                SqlValue::Integer(value) => query.bind(*value),
+                SqlValue::Real(value) => query.bind(*value),
+                SqlValue::Text(value) => query.bind(value.clone()),
+                SqlValue::Decimal(value) => query.bind(*value),
+                SqlValue::Timestamp(value) => query.bind(*value),
+            })
+    }
+
+    fn decode(row: &PgRow) -> Result<SqlRow, StorageError> {
+        row.columns()
+            .iter()
+            .map(|column| {
+                let index = column.ordinal();
+                if row.try_get_raw(index)?.is_null() {
+                    return Ok((column.name().to_owned(), SqlValue::Null));
+                }
+
+                let value = match column.type_info().name() {
+                    "INT8" => SqlValue::Integer(row.try_get(index)?),
+                    "FLOAT8" => SqlValue::Real(row.try_get(index)?),
+                    "TEXT" | "VARCHAR" => SqlValue::Text(row.try_get(index)?),
+                    "NUMERIC" => SqlValue::Decimal(row.try_get::<Decimal, _>(index)?),
+                    "TIMESTAMPTZ" => SqlValue::Timestamp(row.try_get(index)?),
+                    other => return Err(StorageError::Decode(format!("Postgres {other}"))),
+                };
+                Ok((column.name().to_owned(), value))
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl SqlExecutor for PostgresExecutor {
+    async fn execute(&mut self, statement: &SqlStatement) -> Result<u64, StorageError> {
+        let result = Self::bind(statement).execute(&self.pool).await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn execute_batch(&mut self, statements: &[SqlStatement]) -> Result<(), StorageError> {
+        let mut transaction = self.pool.begin().await?;
+        for statement in statements {
+            Self::bind(statement).execute(&mut *transaction).await?;
+        }
+        transaction.commit().await.map_err(StorageError::from)
+    }
+
+    async fn query(&mut self, statement: &SqlStatement) -> Result<Vec<SqlRow>, StorageError> {
+        Self::bind(statement)
+            .fetch_all(&self.pool)
+            .await?
+            .iter()
+            .map(Self::decode)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::Event,
+        portfolio::{
+            position::{PositionExit, PositionUpdate},
+            Balance,
+        },
+        storage::{SqlDialect, SqlStore},
+        test_util::position,
+    };
+    use ::sqlx::sqlite::SqlitePoolOptions;
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+    use uuid::Uuid;
+
+    async fn sqlite_in_memory(store: &SqlStore) -> SqliteExecutor {
+        // Each connection to "sqlite::memory:" opens a distinct database, so limit the pool to one
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+
+        let mut executor = SqliteExecutor::new(pool);
+        executor.execute_batch(&store.schema()).await.unwrap();
+        executor
+    }
+
+    async fn persist(executor: &mut SqliteExecutor, store: &SqlStore, event: Event) {
+        executor
+            .execute_batch(&store.statements(&event))
+            .await
+            .unwrap();
+    }
+
+    fn decimal(row: &SqlRow, column: &str) -> Decimal {
+        row[column].as_decimal().unwrap()
+    }
+
+    fn timestamp(row: &SqlRow, column: &str) -> DateTime<Utc> {
+        row[column].as_timestamp().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_executor_position_round_trip() {
+        let store = SqlStore::new(Uuid::new_v4(), SqlDialect::Sqlite);
+        let mut executor = sqlite_in_memory(&store).await;
+
+        let enter_time = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let mut position = position();
+        position.meta.enter_time = enter_time;
+        position.meta.update_time = enter_time;
+
+        // Save
+        persist(
+            &mut executor,
+            &store,
+            Event::PositionNew(Box::new(position.clone())),
+        )
+        .await;
+
+        // Load
+        let open = executor.query(&store.open_positions()).await.unwrap();
+        assert_eq!(open.len(), 1);
+        assert_eq!(
+            open[0]["position_id"].as_text(),
+            Some(position.position_id.as_str())
+        );
+        assert_eq!(decimal(&open[0], "quantity"), Decimal::ONE);
+        assert_eq!(decimal(&open[0], "enter_value_gross"), dec!(100));
+        assert_eq!(timestamp(&open[0], "enter_time"), enter_time);
+        assert_eq!(open[0]["exit_time"], SqlValue::Null);
+
+        // Update
+        position.meta.update_time = enter_time + chrono::Duration::minutes(1);
+        position.current_symbol_price = dec!(110.5);
+        position.current_value_gross = dec!(110.5);
+        position.unrealised_profit_loss = dec!(10.5);
+        persist(
+            &mut executor,
+            &store,
+            Event::PositionUpdate(PositionUpdate::from(&mut position)),
+        )
+        .await;
+
+        let open = executor.query(&store.open_positions()).await.unwrap();
+        assert_eq!(open.len(), 1);
+        assert_eq!(decimal(&open[0], "current_symbol_price"), dec!(110.5));
+        assert_eq!(decimal(&open[0], "unrealised_profit_loss"), dec!(10.5));
+        assert_eq!(
+            timestamp(&open[0], "update_time"),
+            position.meta.update_time
+        );
+
+        // Remove
+        let exit_time = enter_time + chrono::Duration::minutes(2);
+        position.meta.update_time = exit_time;
+        position.meta.exit_balance = Some(Balance::new(exit_time, dec!(1012.5), dec!(1012.5)));
+        position.exit_avg_price_gross = dec!(112.5);
+        position.exit_value_gross = dec!(112.5);
+        position.realised_profit_loss = dec!(12.5);
+        persist(
+            &mut executor,
+            &store,
+            Event::PositionExit(PositionExit::try_from(&mut position).unwrap()),
+        )
+        .await;
+
+        assert!(executor
+            .query(&store.open_positions())
+            .await
+            .unwrap()
+            .is_empty());
+
+        let exited = executor.query(&store.exited_positions()).await.unwrap();
+        assert_eq!(exited.len(), 1);
+        assert_eq!(timestamp(&exited[0], "exit_time"), exit_time);
+        assert_eq!(decimal(&exited[0], "exit_avg_price_gross"), dec!(112.5));
+        assert_eq!(decimal(&exited[0], "unrealised_profit_loss"), Decimal::ZERO);
+        assert_eq!(decimal(&exited[0], "realised_profit_loss"), dec!(12.5));
+
+        // Positions of other engines are not loaded
+        let other = SqlStore::new(Uuid::new_v4(), SqlDialect::Sqlite);
+        assert!(executor
+            .query(&other.exited_positions())
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_executor_balance_summary_round_trip() {
+        let store = SqlStore::new(Uuid::new_v4(), SqlDialect::Sqlite);
+        let mut executor = sqlite_in_memory(&store).await;
+
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let later = start + chrono::Duration::hours(1);
+
+        // No balance snapshot has been saved yet
+        assert!(executor
+            .query(&store.latest_balance())
+            .await
+            .unwrap()
+            .is_empty());
+
+        // Save (out of time order)
+        persist(
+            &mut executor,
+            &store,
+            Event::Balance(Balance::new(later, dec!(1012.5), dec!(912.5))),
+        )
+        .await;
+        persist(
+            &mut executor,
+            &store,
+            Event::Balance(Balance::new(start, dec!(1000), dec!(1000))),
+        )
+        .await;
+
+        // Load the latest summary
+        let latest = executor.query(&store.latest_balance()).await.unwrap();
+        assert_eq!(latest.len(), 1);
+        assert_eq!(timestamp(&latest[0], "time"), later);
+        assert_eq!(decimal(&latest[0], "total"), dec!(1012.5));
+        assert_eq!(decimal(&latest[0], "available"), dec!(912.5));
+
+        // Load the summary history, oldest first, excluding the end of the range
+        let history = executor
+            .query(&store.balances_between(start, later + chrono::Duration::seconds(1)))
+            .await
+            .unwrap();
+        let totals = history
+            .iter()
+            .map(|row| decimal(row, "total"))
+            .collect::<Vec<_>>();
+        assert_eq!(totals, vec![dec!(1000), dec!(1012.5)]);
+
+        let history = executor
+            .query(&store.balances_between(start, later))
+            .await
+            .unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(timestamp(&history[0], "time"), start);
+    }
+}
@@ -0,0 +1,14 @@
+use thiserror::Error;
+
+/// All errors generated in the barter::strategy module.
+#[derive(Error, Debug)]
+pub enum StrategyError {
+    #[error("Strategy does not support runtime reconfiguration")]
+    NotReconfigurable,
+
+    #[error("Failed to deserialise Strategy config: {0}")]
+    DeserialiseConfig(#[from] serde_json::Error),
+
+    #[error("Invalid Strategy config: {0}")]
+    InvalidConfig(String),
+}
@@ -1,11 +1,16 @@
-use super::{Decision, Signal, SignalGenerator, SignalStrength};
+use super::{
+    error::StrategyError, update_config, Decision, ReconfigurableStrategy, Signal, SignalGenerator,
+    SignalStrength,
+};
 use crate::data::MarketMeta;
 use barter_data::event::{DataKind, MarketEvent};
 use barter_instrument::instrument::Instrument;
 use chrono::Utc;
+use rust_decimal::{prelude::FromPrimitive, Decimal};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use ta::{indicators::RelativeStrengthIndex, Next};
+use tracing::warn;
 
 /// Configuration for constructing a [`RSIStrategy`] via the new() constructor method.
 #[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
@@ -16,6 +21,7 @@ pub struct Config {
 #[derive(Clone, Debug)]
 /// Example RSI based strategy that implements [`SignalGenerator`].
 pub struct RSIStrategy {
+    config: Config,
     rsi: RelativeStrengthIndex,
 }
 
@@ -27,6 +33,12 @@ impl SignalGenerator for RSIStrategy {
             _ => return None,
         };
 
+        // Ignore candles without a finite close, so they do not corrupt the RSI
+        let Some(close) = Decimal::from_f64(candle_close) else {
+            warn!(instrument = %market.instrument, candle_close, "ignoring Candle with a non-finite close");
+            return None;
+        };
+
         // Calculate the next RSI value using the new MarketEvent Candle data
         let rsi = self.rsi.next(candle_close);
 
@@ -43,12 +55,32 @@ impl SignalGenerator for RSIStrategy {
             exchange: market.exchange,
             instrument: market.instrument.clone(),
             market_meta: MarketMeta {
-                close: candle_close,
+                close,
                 time: market.time_exchange,
             },
             signals,
         })
     }
+
+    fn update_config(&mut self, config: serde_json::Value) -> Result<(), StrategyError> {
+        update_config(self, config)
+    }
+}
+
+impl ReconfigurableStrategy for RSIStrategy {
+    type Config = Config;
+
+    /// Changing the `rsi_period` resets the RSI indicator, which must warm up again.
+    fn reconfigure(&mut self, config: Self::Config) -> Result<(), StrategyError> {
+        if config == self.config {
+            return Ok(());
+        }
+
+        self.rsi = RelativeStrengthIndex::new(config.rsi_period)
+            .map_err(|error| StrategyError::InvalidConfig(error.to_string()))?;
+        self.config = config;
+        Ok(())
+    }
 }
 
 impl RSIStrategy {
@@ -57,7 +89,10 @@ impl RSIStrategy {
         let rsi_indicator = RelativeStrengthIndex::new(config.rsi_period)
             .expect("Failed to construct RSI indicator");
 
-        Self { rsi: rsi_indicator }
+        Self {
+            config,
+            rsi: rsi_indicator,
+        }
     }
 
     /// Given the latest RSI value for a symbol, generates a map containing the [`SignalStrength`] for
@@ -0,0 +1,200 @@
+use super::{Indicator, Window};
+
+/// Simple moving average of the last `period` values.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Sma {
+    window: Window,
+    sum: f64,
+}
+
+impl Sma {
+    /// Construct a new [`Sma`] over the provided period.
+    pub fn new(period: usize) -> Self {
+        Self {
+            window: Window::new(period),
+            sum: 0.0,
+        }
+    }
+}
+
+impl Indicator<f64, f64> for Sma {
+    fn update(&mut self, input: f64) -> Option<f64> {
+        self.sum += input - self.window.push(input).unwrap_or_default();
+        self.window
+            .is_full()
+            .then(|| self.sum / self.window.capacity as f64)
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.sum = 0.0;
+    }
+}
+
+/// Exponential moving average with smoothing factor `2 / (period + 1)`, seeded with the
+/// [`Sma`] of the first `period` values.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Ema {
+    period: usize,
+    alpha: f64,
+    count: usize,
+    value: f64,
+}
+
+impl Ema {
+    /// Construct a new [`Ema`] over the provided period.
+    pub fn new(period: usize) -> Self {
+        let period = period.max(1);
+        Self {
+            period,
+            alpha: 2.0 / (period as f64 + 1.0),
+            count: 0,
+            value: 0.0,
+        }
+    }
+
+    /// Construct a new [`Ema`] with Wilder's smoothing factor `1 / period`, as used by the
+    /// [`Rsi`](super::oscillator::Rsi) & [`Atr`](super::volatility::Atr).
+    pub fn wilder(period: usize) -> Self {
+        let period = period.max(1);
+        Self {
+            alpha: 1.0 / period as f64,
+            ..Self::new(period)
+        }
+    }
+
+    /// Latest value, if warmed up.
+    pub fn value(&self) -> Option<f64> {
+        (self.count >= self.period).then_some(self.value)
+    }
+}
+
+impl Indicator<f64, f64> for Ema {
+    fn update(&mut self, input: f64) -> Option<f64> {
+        self.count = self.count.saturating_add(1);
+        if self.count <= self.period {
+            // Seed with the running mean of the first period values
+            self.value += (input - self.value) / self.count as f64;
+        } else {
+            self.value += self.alpha * (input - self.value);
+        }
+        self.value()
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.value = 0.0;
+    }
+}
+
+/// Linearly weighted moving average of the last `period` values, where the most recent value has
+/// weight `period` and the oldest weight 1.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Wma {
+    window: Window,
+    /// Sum of the window values.
+    sum: f64,
+    /// Weighted sum of the window values.
+    numerator: f64,
+}
+
+impl Wma {
+    /// Construct a new [`Wma`] over the provided period.
+    pub fn new(period: usize) -> Self {
+        Self {
+            window: Window::new(period),
+            sum: 0.0,
+            numerator: 0.0,
+        }
+    }
+}
+
+impl Indicator<f64, f64> for Wma {
+    fn update(&mut self, input: f64) -> Option<f64> {
+        let period = self.window.capacity as f64;
+        match self.window.push(input) {
+            Some(evicted) => {
+                self.numerator += period * input - self.sum;
+                self.sum += input - evicted;
+            }
+            None => {
+                self.numerator += self.window.values.len() as f64 * input;
+                self.sum += input;
+            }
+        }
+
+        self.window
+            .is_full()
+            .then(|| self.numerator / (period * (period + 1.0) / 2.0))
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.sum = 0.0;
+        self.numerator = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::indicator::assert_approx;
+
+    #[test]
+    fn test_moving_averages() {
+        struct TestCase {
+            input: f64,
+            expected_sma: Option<f64>,
+            expected_ema: Option<f64>,
+            expected_wma: Option<f64>,
+        }
+
+        let mut sma = Sma::new(3);
+        let mut ema = Ema::new(3);
+        let mut wma = Wma::new(3);
+
+        let tests = vec![
+            TestCase {
+                // TC0: warming up
+                input: 1.0,
+                expected_sma: None,
+                expected_ema: None,
+                expected_wma: None,
+            },
+            TestCase {
+                // TC1: warming up
+                input: 2.0,
+                expected_sma: None,
+                expected_ema: None,
+                expected_wma: None,
+            },
+            TestCase {
+                // TC2: warmed up, Ema seeded with Sma
+                input: 3.0,
+                expected_sma: Some(2.0),
+                expected_ema: Some(2.0),
+                expected_wma: Some((1.0 + 4.0 + 9.0) / 6.0),
+            },
+            TestCase {
+                // TC3: oldest value evicted
+                input: 4.0,
+                expected_sma: Some(3.0),
+                expected_ema: Some(3.0),
+                expected_wma: Some((2.0 + 6.0 + 12.0) / 6.0),
+            },
+            TestCase {
+                // TC4: oldest value evicted
+                input: 10.0,
+                expected_sma: Some(17.0 / 3.0),
+                expected_ema: Some(6.5),
+                expected_wma: Some((3.0 + 8.0 + 30.0) / 6.0),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_approx(sma.update(test.input), test.expected_sma, index);
+            assert_approx(ema.update(test.input), test.expected_ema, index);
+            assert_approx(wma.update(test.input), test.expected_wma, index);
+        }
+    }
+}
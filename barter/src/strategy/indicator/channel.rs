@@ -0,0 +1,99 @@
+use super::{volatility::BandsOutput, Bar, Extremum, Indicator};
+
+/// Donchian channel: the highest high & lowest low of the last `period` [`Bar`]s, and their
+/// midpoint.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Donchian {
+    highest: Extremum,
+    lowest: Extremum,
+}
+
+impl Donchian {
+    /// Construct a new [`Donchian`] channel over the provided period (eg/ 20).
+    pub fn new(period: usize) -> Self {
+        Self {
+            highest: Extremum::max(period),
+            lowest: Extremum::min(period),
+        }
+    }
+}
+
+impl Indicator<Bar, BandsOutput> for Donchian {
+    fn update(&mut self, input: Bar) -> Option<BandsOutput> {
+        let upper = self.highest.push(input.high);
+        let lower = self.lowest.push(input.low);
+        let (upper, lower) = (upper?, lower?);
+
+        Some(BandsOutput {
+            upper,
+            middle: (upper + lower) / 2.0,
+            lower,
+        })
+    }
+
+    fn reset(&mut self) {
+        self.highest.clear();
+        self.lowest.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_donchian() {
+        struct TestCase {
+            input: (f64, f64),
+            expected: Option<(f64, f64)>,
+        }
+
+        let mut donchian = Donchian::new(3);
+
+        let tests = vec![
+            TestCase {
+                // TC0: warming up
+                input: (10.0, 8.0),
+                expected: None,
+            },
+            TestCase {
+                // TC1: warming up
+                input: (12.0, 9.0),
+                expected: None,
+            },
+            TestCase {
+                // TC2: warmed up
+                input: (11.0, 7.0),
+                expected: Some((12.0, 7.0)),
+            },
+            TestCase {
+                // TC3: first bar evicted
+                input: (9.0, 8.5),
+                expected: Some((12.0, 7.0)),
+            },
+            TestCase {
+                // TC4: highest high evicted
+                input: (9.5, 8.0),
+                expected: Some((11.0, 7.0)),
+            },
+            TestCase {
+                // TC5: lowest low evicted
+                input: (9.0, 8.2),
+                expected: Some((9.5, 8.0)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (high, low) = test.input;
+            let actual = donchian
+                .update(Bar {
+                    high,
+                    low,
+                    close: low,
+                    volume: 1.0,
+                })
+                .map(|bands| (bands.upper, bands.lower));
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
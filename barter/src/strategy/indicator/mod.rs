@@ -0,0 +1,177 @@
+use barter_data::subscription::candle::Candle;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Moving averages: [`Sma`](average::Sma), [`Ema`](average::Ema) & [`Wma`](average::Wma).
+pub mod average;
+
+/// Price channels: [`Donchian`](channel::Donchian).
+pub mod channel;
+
+/// Momentum oscillators: [`Macd`](oscillator::Macd), [`Rsi`](oscillator::Rsi) &
+/// [`Stochastic`](oscillator::Stochastic).
+pub mod oscillator;
+
+/// Volatility indicators: [`BollingerBands`](volatility::BollingerBands) &
+/// [`Atr`](volatility::Atr).
+pub mod volatility;
+
+/// Volume indicators: [`Vwap`](volume::Vwap) & [`Obv`](volume::Obv).
+pub mod volume;
+
+/// Incremental technical indicator updated with one `Input` at a time.
+///
+/// Updates are O(1) and allocation free (any buffers are allocated on construction), so
+/// indicators can be updated with every `MarketEvent` of the event-driven engine.
+pub trait Indicator<Input, Output> {
+    /// Update the indicator with the next `Input`, returning the latest `Output` once enough
+    /// inputs have been observed to warm up.
+    fn update(&mut self, input: Input) -> Option<Output>;
+
+    /// Reset the indicator to it's newly constructed state.
+    fn reset(&mut self);
+}
+
+/// High, low, close & volume of a period, used as the input of range & volume based indicators.
+#[derive(Copy, Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct Bar {
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+impl Bar {
+    /// Typical price of the [`Bar`], `(high + low + close) / 3`.
+    pub fn typical_price(&self) -> f64 {
+        (self.high + self.low + self.close) / 3.0
+    }
+}
+
+impl From<&Candle> for Bar {
+    fn from(candle: &Candle) -> Self {
+        Self {
+            high: candle.high,
+            low: candle.low,
+            close: candle.close,
+            volume: candle.volume,
+        }
+    }
+}
+
+/// Fixed capacity window of the most recent values, allocated on construction.
+#[derive(Clone, PartialEq, Debug)]
+struct Window {
+    capacity: usize,
+    values: VecDeque<f64>,
+}
+
+impl Window {
+    fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            values: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Push the value into the [`Window`], returning the evicted value if the window was full.
+    fn push(&mut self, value: f64) -> Option<f64> {
+        let evicted = if self.is_full() {
+            self.values.pop_front()
+        } else {
+            None
+        };
+        self.values.push_back(value);
+        evicted
+    }
+
+    fn is_full(&self) -> bool {
+        self.values.len() == self.capacity
+    }
+
+    fn clear(&mut self) {
+        self.values.clear();
+    }
+}
+
+/// Sliding window maximum (or minimum) with amortised O(1) updates, using a monotonic deque
+/// allocated on construction.
+#[derive(Clone, PartialEq, Debug)]
+struct Extremum {
+    period: usize,
+    maximum: bool,
+    count: usize,
+    candidates: VecDeque<(usize, f64)>,
+}
+
+impl Extremum {
+    fn max(period: usize) -> Self {
+        Self::new(period, true)
+    }
+
+    fn min(period: usize) -> Self {
+        Self::new(period, false)
+    }
+
+    fn new(period: usize, maximum: bool) -> Self {
+        let period = period.max(1);
+        Self {
+            period,
+            maximum,
+            count: 0,
+            candidates: VecDeque::with_capacity(period),
+        }
+    }
+
+    /// Push the value, returning the extremum of the window once it is full.
+    fn push(&mut self, value: f64) -> Option<f64> {
+        let dominates = |candidate: f64| {
+            if self.maximum {
+                candidate <= value
+            } else {
+                candidate >= value
+            }
+        };
+        while self
+            .candidates
+            .back()
+            .is_some_and(|(_, candidate)| dominates(*candidate))
+        {
+            self.candidates.pop_back();
+        }
+        self.candidates.push_back((self.count, value));
+
+        while self
+            .candidates
+            .front()
+            .is_some_and(|(index, _)| index + self.period <= self.count)
+        {
+            self.candidates.pop_front();
+        }
+
+        self.count += 1;
+        (self.count >= self.period)
+            .then(|| self.candidates.front().map(|(_, extremum)| *extremum))
+            .flatten()
+    }
+
+    fn clear(&mut self) {
+        self.count = 0;
+        self.candidates.clear();
+    }
+}
+
+#[cfg(test)]
+fn assert_approx(actual: Option<f64>, expected: Option<f64>, index: usize) {
+    match (actual, expected) {
+        (Some(actual), Some(expected)) => assert!(
+            (actual - expected).abs() < 1e-6,
+            "TC{index} failed: actual {actual} != expected {expected}"
+        ),
+        (None, None) => {}
+        (actual, expected) => {
+            panic!("TC{index} failed: actual {actual:?} != expected {expected:?}")
+        }
+    }
+}
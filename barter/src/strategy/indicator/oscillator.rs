@@ -0,0 +1,227 @@
+use super::{
+    average::{Ema, Sma},
+    Bar, Extremum, Indicator,
+};
+use serde::{Deserialize, Serialize};
+
+/// [`Macd`] line, signal line & histogram.
+#[derive(Copy, Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct MacdOutput {
+    /// Fast [`Ema`] minus slow [`Ema`].
+    pub macd: f64,
+    /// [`Ema`] of the MACD line.
+    pub signal: f64,
+    /// MACD line minus signal line.
+    pub histogram: f64,
+}
+
+/// Moving average convergence divergence (eg/ 12, 26, 9).
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Macd {
+    fast: Ema,
+    slow: Ema,
+    signal: Ema,
+}
+
+impl Macd {
+    /// Construct a new [`Macd`] using the provided fast, slow & signal periods.
+    pub fn new(fast: usize, slow: usize, signal: usize) -> Self {
+        Self {
+            fast: Ema::new(fast),
+            slow: Ema::new(slow),
+            signal: Ema::new(signal),
+        }
+    }
+}
+
+impl Indicator<f64, MacdOutput> for Macd {
+    fn update(&mut self, input: f64) -> Option<MacdOutput> {
+        let fast = self.fast.update(input);
+        let slow = self.slow.update(input);
+        let macd = fast? - slow?;
+        let signal = self.signal.update(macd)?;
+        Some(MacdOutput {
+            macd,
+            signal,
+            histogram: macd - signal,
+        })
+    }
+
+    fn reset(&mut self) {
+        self.fast.reset();
+        self.slow.reset();
+        self.signal.reset();
+    }
+}
+
+/// Relative strength index in the range [0, 100], using Wilder's smoothing of the average gain
+/// & loss.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Rsi {
+    gain: Ema,
+    loss: Ema,
+    previous: Option<f64>,
+}
+
+impl Rsi {
+    /// Construct a new [`Rsi`] over the provided period (eg/ 14).
+    pub fn new(period: usize) -> Self {
+        Self {
+            gain: Ema::wilder(period),
+            loss: Ema::wilder(period),
+            previous: None,
+        }
+    }
+}
+
+impl Indicator<f64, f64> for Rsi {
+    fn update(&mut self, input: f64) -> Option<f64> {
+        let change = input - self.previous.replace(input)?;
+        let gain = self.gain.update(change.max(0.0));
+        let loss = self.loss.update((-change).max(0.0));
+
+        match (gain?, loss?) {
+            (gain, loss) if gain + loss == 0.0 => Some(50.0),
+            (gain, loss) => Some(100.0 * gain / (gain + loss)),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.gain.reset();
+        self.loss.reset();
+        self.previous = None;
+    }
+}
+
+/// [`Stochastic`] %K & %D lines, in the range [0, 100].
+#[derive(Copy, Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct StochasticOutput {
+    /// Position of the close within the high-low range of the last `k_period` [`Bar`]s.
+    pub k: f64,
+    /// [`Sma`] of %K over the last `d_period` [`Bar`]s.
+    pub d: f64,
+}
+
+/// Stochastic oscillator (eg/ 14, 3).
+#[derive(Clone, PartialEq, Debug)]
+pub struct Stochastic {
+    highest: Extremum,
+    lowest: Extremum,
+    d: Sma,
+}
+
+impl Stochastic {
+    /// Construct a new [`Stochastic`] using the provided %K & %D periods.
+    pub fn new(k_period: usize, d_period: usize) -> Self {
+        Self {
+            highest: Extremum::max(k_period),
+            lowest: Extremum::min(k_period),
+            d: Sma::new(d_period),
+        }
+    }
+}
+
+impl Indicator<Bar, StochasticOutput> for Stochastic {
+    fn update(&mut self, input: Bar) -> Option<StochasticOutput> {
+        let highest = self.highest.push(input.high);
+        let lowest = self.lowest.push(input.low);
+        let (highest, lowest) = (highest?, lowest?);
+
+        let range = highest - lowest;
+        let k = if range == 0.0 {
+            50.0
+        } else {
+            100.0 * (input.close - lowest) / range
+        };
+
+        Some(StochasticOutput {
+            k,
+            d: self.d.update(k)?,
+        })
+    }
+
+    fn reset(&mut self) {
+        self.highest.clear();
+        self.lowest.clear();
+        self.d.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::indicator::assert_approx;
+
+    #[test]
+    fn test_oscillators() {
+        struct TestCase {
+            input: Bar,
+            expected_macd: Option<f64>,
+            expected_rsi: Option<f64>,
+            expected_stochastic: Option<(f64, f64)>,
+        }
+
+        let bar = |high, low, close| Bar {
+            high,
+            low,
+            close,
+            volume: 1.0,
+        };
+
+        let mut macd = Macd::new(1, 2, 1);
+        let mut rsi = Rsi::new(2);
+        let mut stochastic = Stochastic::new(2, 2);
+
+        let tests = vec![
+            TestCase {
+                // TC0: warming up
+                input: bar(11.0, 9.0, 10.0),
+                expected_macd: None,
+                expected_rsi: None,
+                expected_stochastic: None,
+            },
+            TestCase {
+                // TC1: Macd fast Ema 12, slow Ema 11, Stochastic %K warmed up
+                input: bar(13.0, 11.0, 12.0),
+                expected_macd: Some(1.0),
+                expected_rsi: None,
+                expected_stochastic: None,
+            },
+            TestCase {
+                // TC2: Rsi seeded with mean gain 1.0 & loss 0.5
+                input: bar(12.0, 10.0, 11.0),
+                expected_macd: Some(11.0 - 11.0),
+                expected_rsi: Some(100.0 * 1.0 / (1.0 + 0.5)),
+                expected_stochastic: Some((100.0 / 3.0, (75.0 + 100.0 / 3.0) / 2.0)),
+            },
+            TestCase {
+                // TC3: Wilder smoothing
+                input: bar(15.0, 11.0, 14.0),
+                expected_macd: Some(14.0 - 13.0),
+                expected_rsi: Some(100.0 * 2.0 / (2.0 + 0.25)),
+                expected_stochastic: Some((80.0, (100.0 / 3.0 + 80.0) / 2.0)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_approx(
+                macd.update(test.input.close).map(|output| output.macd),
+                test.expected_macd,
+                index,
+            );
+            assert_approx(rsi.update(test.input.close), test.expected_rsi, index);
+
+            let actual = stochastic.update(test.input);
+            assert_approx(
+                actual.map(|output| output.k),
+                test.expected_stochastic.map(|(k, _)| k),
+                index,
+            );
+            assert_approx(
+                actual.map(|output| output.d),
+                test.expected_stochastic.map(|(_, d)| d),
+                index,
+            );
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use super::{average::Ema, Bar, Indicator, Window};
+use serde::{Deserialize, Serialize};
+
+/// [`BollingerBands`] upper, middle & lower bands.
+#[derive(Copy, Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct BandsOutput {
+    pub upper: f64,
+    pub middle: f64,
+    pub lower: f64,
+}
+
+/// Bollinger Bands (eg/ 20, 2.0): the [`Sma`](super::average::Sma) of the last `period` values,
+/// plus & minus a multiple of their population standard deviation.
+#[derive(Clone, PartialEq, Debug)]
+pub struct BollingerBands {
+    multiplier: f64,
+    window: Window,
+    sum: f64,
+    sum_squares: f64,
+}
+
+impl BollingerBands {
+    /// Construct a new [`BollingerBands`] using the provided period & standard deviation
+    /// multiplier.
+    pub fn new(period: usize, multiplier: f64) -> Self {
+        Self {
+            multiplier,
+            window: Window::new(period),
+            sum: 0.0,
+            sum_squares: 0.0,
+        }
+    }
+}
+
+impl Indicator<f64, BandsOutput> for BollingerBands {
+    fn update(&mut self, input: f64) -> Option<BandsOutput> {
+        let evicted = self.window.push(input).unwrap_or_default();
+        self.sum += input - evicted;
+        self.sum_squares += input.powi(2) - evicted.powi(2);
+
+        if !self.window.is_full() {
+            return None;
+        }
+
+        let period = self.window.capacity as f64;
+        let middle = self.sum / period;
+        let variance = (self.sum_squares / period - middle.powi(2)).max(0.0);
+        let width = self.multiplier * variance.sqrt();
+
+        Some(BandsOutput {
+            upper: middle + width,
+            middle,
+            lower: middle - width,
+        })
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.sum = 0.0;
+        self.sum_squares = 0.0;
+    }
+}
+
+/// Average true range, using Wilder's smoothing of the true range.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Atr {
+    average: Ema,
+    previous_close: Option<f64>,
+}
+
+impl Atr {
+    /// Construct a new [`Atr`] over the provided period (eg/ 14).
+    pub fn new(period: usize) -> Self {
+        Self {
+            average: Ema::wilder(period),
+            previous_close: None,
+        }
+    }
+}
+
+impl Indicator<Bar, f64> for Atr {
+    fn update(&mut self, input: Bar) -> Option<f64> {
+        let range = input.high - input.low;
+        let true_range = match self.previous_close.replace(input.close) {
+            Some(close) => range
+                .max((input.high - close).abs())
+                .max((input.low - close).abs()),
+            None => range,
+        };
+
+        self.average.update(true_range)
+    }
+
+    fn reset(&mut self) {
+        self.average.reset();
+        self.previous_close = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::indicator::assert_approx;
+
+    #[test]
+    fn test_volatility_indicators() {
+        struct TestCase {
+            input: Bar,
+            expected_bands: Option<(f64, f64, f64)>,
+            expected_atr: Option<f64>,
+        }
+
+        let bar = |high, low, close| Bar {
+            high,
+            low,
+            close,
+            volume: 1.0,
+        };
+
+        let mut bands = BollingerBands::new(2, 2.0);
+        let mut atr = Atr::new(2);
+
+        let tests = vec![
+            TestCase {
+                // TC0: warming up
+                input: bar(11.0, 9.0, 10.0),
+                expected_bands: None,
+                expected_atr: None,
+            },
+            TestCase {
+                // TC1: std dev 1.0, true range includes gap from previous close
+                input: bar(14.0, 11.0, 12.0),
+                expected_bands: Some((13.0, 11.0, 9.0)),
+                expected_atr: Some((2.0 + 4.0) / 2.0),
+            },
+            TestCase {
+                // TC2: no deviation, Wilder smoothing
+                input: bar(13.0, 11.0, 12.0),
+                expected_bands: Some((12.0, 12.0, 12.0)),
+                expected_atr: Some(3.0 + (2.0 - 3.0) / 2.0),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = bands.update(test.input.close);
+            assert_approx(
+                actual.map(|bands| bands.upper),
+                test.expected_bands.map(|b| b.0),
+                index,
+            );
+            assert_approx(
+                actual.map(|bands| bands.middle),
+                test.expected_bands.map(|b| b.1),
+                index,
+            );
+            assert_approx(
+                actual.map(|bands| bands.lower),
+                test.expected_bands.map(|b| b.2),
+                index,
+            );
+            assert_approx(atr.update(test.input), test.expected_atr, index);
+        }
+    }
+}
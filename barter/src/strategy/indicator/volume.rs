@@ -0,0 +1,117 @@
+use super::{Bar, Indicator};
+use std::cmp::Ordering;
+
+/// Volume weighted average price of every [`Bar`] since construction (or the last reset, eg/ at
+/// the start of each session), weighting the typical price by volume.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Vwap {
+    volume: f64,
+    notional: f64,
+}
+
+impl Vwap {
+    /// Construct a new [`Vwap`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Indicator<Bar, f64> for Vwap {
+    fn update(&mut self, input: Bar) -> Option<f64> {
+        self.volume += input.volume;
+        self.notional += input.typical_price() * input.volume;
+        (self.volume > 0.0).then(|| self.notional / self.volume)
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// On-balance volume: cumulative volume, added on up closes and subtracted on down closes.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Obv {
+    value: f64,
+    previous_close: Option<f64>,
+}
+
+impl Obv {
+    /// Construct a new [`Obv`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Indicator<Bar, f64> for Obv {
+    fn update(&mut self, input: Bar) -> Option<f64> {
+        if let Some(previous) = self.previous_close.replace(input.close) {
+            match input.close.partial_cmp(&previous) {
+                Some(Ordering::Greater) => self.value += input.volume,
+                Some(Ordering::Less) => self.value -= input.volume,
+                _ => {}
+            }
+        }
+        Some(self.value)
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::indicator::assert_approx;
+
+    #[test]
+    fn test_volume_indicators() {
+        struct TestCase {
+            input: Bar,
+            expected_vwap: Option<f64>,
+            expected_obv: Option<f64>,
+        }
+
+        let bar = |close, volume| Bar {
+            high: close,
+            low: close,
+            close,
+            volume,
+        };
+
+        let mut vwap = Vwap::new();
+        let mut obv = Obv::new();
+
+        let tests = vec![
+            TestCase {
+                // TC0: no volume
+                input: bar(10.0, 0.0),
+                expected_vwap: None,
+                expected_obv: Some(0.0),
+            },
+            TestCase {
+                // TC1: up close
+                input: bar(12.0, 2.0),
+                expected_vwap: Some(12.0),
+                expected_obv: Some(2.0),
+            },
+            TestCase {
+                // TC2: down close
+                input: bar(6.0, 1.0),
+                expected_vwap: Some((24.0 + 6.0) / 3.0),
+                expected_obv: Some(1.0),
+            },
+            TestCase {
+                // TC3: unchanged close
+                input: bar(6.0, 3.0),
+                expected_vwap: Some((24.0 + 6.0 + 18.0) / 6.0),
+                expected_obv: Some(1.0),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_approx(vwap.update(test.input), test.expected_vwap, index);
+            assert_approx(obv.update(test.input), test.expected_obv, index);
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use super::signal;
+use crate::strategy::{
+    error::StrategyError,
+    indicator::{channel::Donchian, volatility::BandsOutput, Bar, Indicator},
+    update_config, Decision, ReconfigurableStrategy, Signal, SignalGenerator,
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_instrument::instrument::Instrument;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for constructing a [`Breakout`] via the new() constructor method.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Config {
+    /// Number of previous candles the breakout channel is computed over (eg/ 20).
+    pub period: usize,
+}
+
+/// Momentum breakout strategy that goes long when a candle closes above the highest high of the
+/// previous `period` candles, and short when it closes below their lowest low.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Breakout {
+    config: Config,
+    channel: Donchian,
+    /// Channel of the previous `period` candles, excluding the latest.
+    previous: Option<BandsOutput>,
+}
+
+impl SignalGenerator for Breakout {
+    fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+        let DataKind::Candle(candle) = &market.kind else {
+            return None;
+        };
+
+        let previous =
+            std::mem::replace(&mut self.previous, self.channel.update(Bar::from(candle)))?;
+
+        let decisions = if candle.close > previous.upper {
+            [Decision::Long, Decision::CloseShort]
+        } else if candle.close < previous.lower {
+            [Decision::Short, Decision::CloseLong]
+        } else {
+            return None;
+        };
+
+        signal(market, candle.close, &decisions)
+    }
+
+    fn update_config(&mut self, config: serde_json::Value) -> Result<(), StrategyError> {
+        update_config(self, config)
+    }
+}
+
+impl ReconfigurableStrategy for Breakout {
+    type Config = Config;
+
+    /// Changing the `period` resets the breakout channel, which must warm up again.
+    fn reconfigure(&mut self, config: Self::Config) -> Result<(), StrategyError> {
+        if config.period == 0 {
+            return Err(StrategyError::InvalidConfig(
+                "period must be greater than zero".to_owned(),
+            ));
+        }
+
+        if config != self.config {
+            *self = Self::new(config);
+        }
+        Ok(())
+    }
+}
+
+impl Breakout {
+    /// Constructs a new [`Breakout`] component using the provided configuration struct.
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            channel: Donchian::new(config.period),
+            previous: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use barter_data::subscription::candle::Candle;
+    use barter_integration::Side;
+    use std::collections::BTreeSet;
+
+    fn candle(high: f64, low: f64, close: f64) -> MarketEvent<Instrument, DataKind> {
+        let mut market = market_event_trade(Side::Buy);
+        market.kind = DataKind::Candle(Candle {
+            close_time: market.time_exchange,
+            open: close,
+            high,
+            low,
+            close,
+            volume: 1.0,
+            trade_count: 1,
+        });
+        market
+    }
+
+    #[test]
+    fn test_breakout() {
+        struct TestCase {
+            input: MarketEvent<Instrument, DataKind>,
+            expected: Option<BTreeSet<Decision>>,
+        }
+
+        let mut strategy = Breakout::new(Config { period: 2 });
+
+        let tests = vec![
+            TestCase {
+                // TC0: warming up
+                input: candle(11.0, 9.0, 10.0),
+                expected: None,
+            },
+            TestCase {
+                // TC1: warming up
+                input: candle(12.0, 10.0, 11.0),
+                expected: None,
+            },
+            TestCase {
+                // TC2: close within previous channel [9, 12]
+                input: candle(12.0, 10.0, 11.5),
+                expected: None,
+            },
+            TestCase {
+                // TC3: close above previous channel [10, 12]
+                input: candle(13.0, 11.0, 12.5),
+                expected: Some(BTreeSet::from([Decision::Long, Decision::CloseShort])),
+            },
+            TestCase {
+                // TC4: close below previous channel [10, 13]
+                input: candle(11.0, 9.0, 9.5),
+                expected: Some(BTreeSet::from([Decision::Short, Decision::CloseLong])),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = strategy
+                .generate_signal(&test.input)
+                .map(|signal| signal.signals.into_keys().collect::<BTreeSet<_>>());
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use super::signal;
+use crate::{
+    data::features::market_price,
+    strategy::{
+        error::StrategyError, update_config, Decision, ReconfigurableStrategy, Signal,
+        SignalGenerator,
+    },
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_instrument::{exchange::ExchangeId, instrument::Instrument};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Arc};
+
+/// Latest funding rate of each exchange perpetual [`Instrument`], per funding interval (eg/
+/// 0.0001 for 0.01%). Positive rates are paid by longs to shorts.
+pub type FundingRates = HashMap<(ExchangeId, Instrument), f64>;
+
+/// Configuration for constructing a [`FundingCarry`] via the new() constructor method.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Config {
+    /// Absolute funding rate at or above which a position is entered to receive funding.
+    pub entry_rate: f64,
+    /// Absolute funding rate at or below which any position is closed.
+    pub exit_rate: f64,
+}
+
+/// Side of the carry position targeted for an [`Instrument`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum Carry {
+    Long,
+    Short,
+    Flat,
+}
+
+/// Funding rate carry strategy that shorts perpetuals paying a high positive funding rate, and
+/// goes long those paying a high negative funding rate, closing the position once the funding
+/// rate reverts.
+///
+/// Funding rates are read from the shared [`FundingRates`], which must be kept up to date by the
+/// caller (eg/ polled from the exchange REST API), and a [`Signal`] is only generated when the
+/// targeted carry position changes.
+#[derive(Clone, Debug)]
+pub struct FundingCarry {
+    config: Config,
+    funding: Arc<RwLock<FundingRates>>,
+    carry: HashMap<(ExchangeId, Instrument), Carry>,
+}
+
+impl SignalGenerator for FundingCarry {
+    fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+        let price = market_price(&market.kind)?;
+        let key = (market.exchange, market.instrument.clone());
+        let rate = *self.funding.read().get(&key)?;
+
+        let previous = self.carry.get(&key).copied().unwrap_or(Carry::Flat);
+        let carry = if rate >= self.config.entry_rate {
+            Carry::Short
+        } else if rate <= -self.config.entry_rate {
+            Carry::Long
+        } else if rate.abs() <= self.config.exit_rate {
+            Carry::Flat
+        } else {
+            previous
+        };
+
+        if carry == previous {
+            return None;
+        }
+        self.carry.insert(key, carry);
+
+        let decisions = match carry {
+            Carry::Long => [Decision::Long, Decision::CloseShort],
+            Carry::Short => [Decision::Short, Decision::CloseLong],
+            Carry::Flat => [Decision::CloseLong, Decision::CloseShort],
+        };
+
+        signal(market, price, &decisions)
+    }
+
+    fn update_config(&mut self, config: serde_json::Value) -> Result<(), StrategyError> {
+        update_config(self, config)
+    }
+}
+
+impl ReconfigurableStrategy for FundingCarry {
+    type Config = Config;
+
+    /// Existing carry positions are kept until the funding rate crosses the new thresholds.
+    fn reconfigure(&mut self, config: Self::Config) -> Result<(), StrategyError> {
+        if config.exit_rate > config.entry_rate {
+            return Err(StrategyError::InvalidConfig(format!(
+                "exit_rate must not exceed entry_rate: {config:?}"
+            )));
+        }
+
+        self.config = config;
+        Ok(())
+    }
+}
+
+impl FundingCarry {
+    /// Constructs a new [`FundingCarry`] component using the provided configuration struct &
+    /// shared [`FundingRates`].
+    pub fn new(config: Config, funding: Arc<RwLock<FundingRates>>) -> Self {
+        Self {
+            config,
+            funding,
+            carry: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use barter_integration::Side;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_funding_carry() {
+        struct TestCase {
+            rate: Option<f64>,
+            expected: Option<BTreeSet<Decision>>,
+        }
+
+        let market = market_event_trade(Side::Buy);
+        let key = (market.exchange, market.instrument.clone());
+        let funding = Arc::new(RwLock::new(FundingRates::new()));
+        let mut strategy = FundingCarry::new(
+            Config {
+                entry_rate: 0.001,
+                exit_rate: 0.0002,
+            },
+            Arc::clone(&funding),
+        );
+
+        let tests = vec![
+            TestCase {
+                // TC0: no funding rate
+                rate: None,
+                expected: None,
+            },
+            TestCase {
+                // TC1: funding rate below entry
+                rate: Some(0.0005),
+                expected: None,
+            },
+            TestCase {
+                // TC2: high positive funding rate, so short to receive funding
+                rate: Some(0.0015),
+                expected: Some(BTreeSet::from([Decision::Short, Decision::CloseLong])),
+            },
+            TestCase {
+                // TC3: funding rate between exit & entry, so hold
+                rate: Some(0.0005),
+                expected: None,
+            },
+            TestCase {
+                // TC4: high negative funding rate, so long to receive funding
+                rate: Some(-0.002),
+                expected: Some(BTreeSet::from([Decision::Long, Decision::CloseShort])),
+            },
+            TestCase {
+                // TC5: funding rate reverted
+                rate: Some(0.0001),
+                expected: Some(BTreeSet::from([Decision::CloseLong, Decision::CloseShort])),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            if let Some(rate) = test.rate {
+                funding.write().insert(key.clone(), rate);
+            }
+
+            let actual = strategy
+                .generate_signal(&market)
+                .map(|signal| signal.signals.into_keys().collect::<BTreeSet<_>>());
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
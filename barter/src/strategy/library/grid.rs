@@ -0,0 +1,185 @@
+use super::signal;
+use crate::{
+    data::features::market_price,
+    strategy::{
+        error::StrategyError, update_config, Decision, ReconfigurableStrategy, Signal,
+        SignalGenerator,
+    },
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_instrument::instrument::Instrument;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for constructing a [`GridTrader`] via the new() constructor method.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Config {
+    /// Lowest price of the grid.
+    pub lower: f64,
+    /// Highest price of the grid.
+    pub upper: f64,
+    /// Number of equally spaced grid cells between the lower & upper prices.
+    pub levels: usize,
+}
+
+/// Grid trader that buys each time the price crosses down through a grid level, and sells each
+/// time it crosses back up. Any position is closed if the price leaves the grid.
+///
+/// Prices are sampled from trades, candle closes & best bid-ask mid prices.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct GridTrader {
+    config: Config,
+    /// Grid cell of the previous price, or `None` if it was outside the grid.
+    cell: Option<usize>,
+}
+
+impl SignalGenerator for GridTrader {
+    fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+        let price = market_price(&market.kind)?;
+
+        let decision = match (self.cell, self.cell(price)) {
+            (Some(_), None) => Decision::CloseLong,
+            (Some(previous), Some(current)) if current < previous => Decision::Long,
+            (Some(previous), Some(current)) if current > previous => Decision::CloseLong,
+            (_, current) => {
+                self.cell = current;
+                return None;
+            }
+        };
+        self.cell = self.cell(price);
+
+        signal(market, price, &[decision])
+    }
+
+    fn update_config(&mut self, config: serde_json::Value) -> Result<(), StrategyError> {
+        update_config(self, config)
+    }
+}
+
+impl ReconfigurableStrategy for GridTrader {
+    type Config = Config;
+
+    /// The grid cell of the next price is used as the new reference, so no [`Signal`] is
+    /// generated for the grid moving underneath the price.
+    fn reconfigure(&mut self, config: Self::Config) -> Result<(), StrategyError> {
+        if config.lower >= config.upper || config.levels == 0 {
+            return Err(StrategyError::InvalidConfig(format!(
+                "grid must have lower < upper & at least one level: {config:?}"
+            )));
+        }
+
+        *self = Self::new(config);
+        Ok(())
+    }
+}
+
+impl GridTrader {
+    /// Constructs a new [`GridTrader`] component using the provided configuration struct.
+    pub fn new(config: Config) -> Self {
+        Self {
+            config: Config {
+                levels: config.levels.max(1),
+                ..config
+            },
+            cell: None,
+        }
+    }
+
+    /// Price of each grid level, from the lower to the upper price inclusive.
+    pub fn levels(&self) -> impl Iterator<Item = f64> + '_ {
+        let spacing = self.spacing();
+        (0..=self.config.levels).map(move |level| self.config.lower + level as f64 * spacing)
+    }
+
+    fn spacing(&self) -> f64 {
+        (self.config.upper - self.config.lower) / self.config.levels as f64
+    }
+
+    /// Grid cell containing the price, or `None` if it is outside the grid.
+    fn cell(&self, price: f64) -> Option<usize> {
+        if !(self.config.lower..=self.config.upper).contains(&price) {
+            return None;
+        }
+
+        let cell = ((price - self.config.lower) / self.spacing()).floor() as usize;
+        Some(cell.min(self.config.levels - 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use barter_data::subscription::trade::PublicTrade;
+    use barter_integration::Side;
+
+    fn trade(price: f64) -> MarketEvent<Instrument, DataKind> {
+        let mut market = market_event_trade(Side::Buy);
+        market.kind = DataKind::Trade(PublicTrade {
+            id: "id".to_string(),
+            price,
+            amount: 1.0,
+            side: Side::Buy,
+            aggregate_count: None,
+        });
+        market
+    }
+
+    #[test]
+    fn test_grid_trader() {
+        struct TestCase {
+            input: f64,
+            expected: Option<Decision>,
+        }
+
+        // Grid levels 90, 95, 100, 105, 110
+        let mut strategy = GridTrader::new(Config {
+            lower: 90.0,
+            upper: 110.0,
+            levels: 4,
+        });
+        assert_eq!(
+            strategy.levels().collect::<Vec<_>>(),
+            vec![90.0, 95.0, 100.0, 105.0, 110.0]
+        );
+
+        let tests = vec![
+            TestCase {
+                // TC0: first price in the grid
+                input: 101.0,
+                expected: None,
+            },
+            TestCase {
+                // TC1: moves within the same cell
+                input: 104.0,
+                expected: None,
+            },
+            TestCase {
+                // TC2: crosses down through 100
+                input: 99.0,
+                expected: Some(Decision::Long),
+            },
+            TestCase {
+                // TC3: crosses up through 100
+                input: 100.5,
+                expected: Some(Decision::CloseLong),
+            },
+            TestCase {
+                // TC4: leaves the grid
+                input: 111.0,
+                expected: Some(Decision::CloseLong),
+            },
+            TestCase {
+                // TC5: re-enters the grid
+                input: 109.0,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = strategy
+                .generate_signal(&trade(test.input))
+                .map(|signal| *signal.signals.keys().next().unwrap());
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
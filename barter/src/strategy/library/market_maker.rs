@@ -0,0 +1,232 @@
+use super::signal;
+use crate::strategy::{
+    error::StrategyError, update_config, Decision, ReconfigurableStrategy, Signal, SignalGenerator,
+};
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    subscription::book::OrderBookEvent,
+};
+use barter_instrument::instrument::Instrument;
+use barter_integration::Side;
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for constructing a [`MarketMaker`] via the new() constructor method.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Config {
+    /// Distance of each quote from the reservation price, in basis points of the mid price.
+    pub half_spread_bps: f64,
+    /// Shift of the reservation price per unit of inventory, in basis points of the mid price.
+    pub skew_bps_per_unit: f64,
+    /// Maximum absolute units of inventory, beyond which the [`MarketMaker`] stops quoting the
+    /// side that would increase it.
+    pub max_inventory: i64,
+}
+
+/// Market maker quoting a bid & ask around a reservation price, which is the mid price skewed
+/// against the current inventory to encourage fills that flatten it.
+///
+/// The mid price is tracked from [`OrderBookL1`](barter_data::subscription::book::OrderBookL1)s
+/// & order book snapshots. A quote is assumed filled when a public trade crosses it, at which
+/// point a [`Signal`] is generated at the quote price and the inventory updated by one unit.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct MarketMaker {
+    config: Config,
+    mid: Option<f64>,
+    inventory: i64,
+}
+
+impl SignalGenerator for MarketMaker {
+    fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+        let trade = match &market.kind {
+            DataKind::Trade(trade) => trade,
+            DataKind::OrderBookL1(book) => {
+                self.mid = book.mid_price().to_f64();
+                return None;
+            }
+            DataKind::OrderBook(OrderBookEvent::Snapshot(book)) => {
+                self.mid = book.mid_price().and_then(|mid| mid.to_f64());
+                return None;
+            }
+            _ => return None,
+        };
+
+        let (bid, ask) = self.quotes()?;
+        let inventory = self.inventory;
+
+        let (close, decision) = match trade.side {
+            Side::Sell if trade.price <= bid && inventory < self.config.max_inventory => {
+                self.inventory += 1;
+                let decision = if inventory < 0 {
+                    Decision::CloseShort
+                } else {
+                    Decision::Long
+                };
+                (bid, decision)
+            }
+            Side::Buy if trade.price >= ask && inventory > -self.config.max_inventory => {
+                self.inventory -= 1;
+                let decision = if inventory > 0 {
+                    Decision::CloseLong
+                } else {
+                    Decision::Short
+                };
+                (ask, decision)
+            }
+            _ => return None,
+        };
+
+        signal(market, close, &[decision])
+    }
+
+    fn update_config(&mut self, config: serde_json::Value) -> Result<(), StrategyError> {
+        update_config(self, config)
+    }
+}
+
+impl ReconfigurableStrategy for MarketMaker {
+    type Config = Config;
+
+    /// The mid price & inventory are kept, so the new quotes apply from the next trade.
+    fn reconfigure(&mut self, config: Self::Config) -> Result<(), StrategyError> {
+        if config.half_spread_bps < 0.0 || config.max_inventory < 0 {
+            return Err(StrategyError::InvalidConfig(format!(
+                "half_spread_bps & max_inventory must be non-negative: {config:?}"
+            )));
+        }
+
+        self.config = config;
+        Ok(())
+    }
+}
+
+impl MarketMaker {
+    /// Constructs a new flat [`MarketMaker`] component using the provided configuration struct.
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            mid: None,
+            inventory: 0,
+        }
+    }
+
+    /// Current inventory, in units of assumed quote fills (positive if long).
+    pub fn inventory(&self) -> i64 {
+        self.inventory
+    }
+
+    /// Current bid & ask quotes, once a mid price has been received.
+    pub fn quotes(&self) -> Option<(f64, f64)> {
+        let mid = self.mid?;
+        let reservation =
+            mid * (1.0 - self.config.skew_bps_per_unit * self.inventory as f64 / 10_000.0);
+        let half_spread = mid * self.config.half_spread_bps / 10_000.0;
+        Some((reservation - half_spread, reservation + half_spread))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_event_trade;
+    use barter_data::{
+        books::Level,
+        subscription::{book::OrderBookL1, trade::PublicTrade},
+    };
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    fn trade(side: Side, price: f64) -> MarketEvent<Instrument, DataKind> {
+        let mut market = market_event_trade(side);
+        market.kind = DataKind::Trade(PublicTrade {
+            id: "id".to_string(),
+            price,
+            amount: 1.0,
+            side,
+            aggregate_count: None,
+        });
+        market
+    }
+
+    #[test]
+    fn test_market_maker() {
+        struct TestCase {
+            input: MarketEvent<Instrument, DataKind>,
+            expected: Option<(Decimal, Decision)>,
+            expected_inventory: i64,
+        }
+
+        let mut book = market_event_trade(Side::Buy);
+        book.kind = DataKind::OrderBookL1(OrderBookL1 {
+            last_update_time: book.time_exchange,
+            best_bid: Level::new(dec!(99), dec!(1)),
+            best_ask: Level::new(dec!(101), dec!(1)),
+        });
+
+        // Quotes 99 / 101 when flat, shifted by 1.0 per unit of inventory
+        let mut strategy = MarketMaker::new(Config {
+            half_spread_bps: 100.0,
+            skew_bps_per_unit: 100.0,
+            max_inventory: 1,
+        });
+
+        let tests = vec![
+            TestCase {
+                // TC0: no mid price to quote around
+                input: trade(Side::Sell, 90.0),
+                expected: None,
+                expected_inventory: 0,
+            },
+            TestCase {
+                // TC1: mid price received
+                input: book,
+                expected: None,
+                expected_inventory: 0,
+            },
+            TestCase {
+                // TC2: sell trade does not cross the bid
+                input: trade(Side::Sell, 99.5),
+                expected: None,
+                expected_inventory: 0,
+            },
+            TestCase {
+                // TC3: sell trade crosses the bid
+                input: trade(Side::Sell, 99.0),
+                expected: Some((dec!(99), Decision::Long)),
+                expected_inventory: 1,
+            },
+            TestCase {
+                // TC4: max inventory reached, so the bid is not quoted
+                input: trade(Side::Sell, 90.0),
+                expected: None,
+                expected_inventory: 1,
+            },
+            TestCase {
+                // TC5: buy trade crosses the skewed ask
+                input: trade(Side::Buy, 100.0),
+                expected: Some((dec!(100), Decision::CloseLong)),
+                expected_inventory: 0,
+            },
+            TestCase {
+                // TC6: buy trade crosses the ask when flat
+                input: trade(Side::Buy, 101.0),
+                expected: Some((dec!(101), Decision::Short)),
+                expected_inventory: -1,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = strategy.generate_signal(&test.input).map(|signal| {
+                let decision = *signal.signals.keys().next().unwrap();
+                (signal.market_meta.close, decision)
+            });
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+            assert_eq!(
+                strategy.inventory(),
+                test.expected_inventory,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
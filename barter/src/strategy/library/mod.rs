@@ -0,0 +1,52 @@
+use super::{Decision, Signal, SignalStrength};
+use crate::data::MarketMeta;
+use barter_data::event::{DataKind, MarketEvent};
+use barter_instrument::instrument::Instrument;
+use chrono::Utc;
+use rust_decimal::{prelude::FromPrimitive, Decimal};
+use tracing::warn;
+
+/// Market maker quoting around the mid price, skewing it's quotes against it's inventory.
+pub mod market_maker;
+
+/// Momentum breakout of the Donchian channel of the previous candles.
+pub mod breakout;
+
+/// Grid trader buying each grid level crossed downwards & selling each level crossed upwards.
+pub mod grid;
+
+/// Funding rate carry, positioned to receive the funding payments of perpetual instruments.
+pub mod carry;
+
+/// Construct a [`Signal`] for the [`MarketEvent`] instrument, advising each of the provided
+/// [`Decision`]s with full strength.
+///
+/// Returns `None` if the close is not a finite price.
+fn signal(
+    market: &MarketEvent<Instrument, DataKind>,
+    close: f64,
+    decisions: &[Decision],
+) -> Option<Signal> {
+    let Some(close) = Decimal::from_f64(close) else {
+        warn!(
+            instrument = %market.instrument,
+            close,
+            "refusing to generate Signal with a non-finite close"
+        );
+        return None;
+    };
+
+    Some(Signal {
+        time: Utc::now(),
+        exchange: market.exchange,
+        instrument: market.instrument.clone(),
+        signals: decisions
+            .iter()
+            .map(|decision| (*decision, SignalStrength(1.0)))
+            .collect(),
+        market_meta: MarketMeta {
+            close,
+            time: market.time_exchange,
+        },
+    })
+}
@@ -1,22 +1,80 @@
-use crate::data::MarketMeta;
-use barter_data::event::{DataKind, MarketEvent};
+use crate::{data::MarketMeta, engine::schedule::ScheduledEvent, strategy::error::StrategyError};
+use barter_data::event::{CustomData, DataKind, MarketEvent};
 use barter_instrument::{exchange::ExchangeId, instrument::Instrument, market::Market};
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use schemars::JsonSchema;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Barter strategy module specific errors.
+pub mod error;
+
 /// Barter example RSI strategy [`SignalGenerator`] implementation.
 pub mod example;
 
+/// Incremental technical [`Indicator`](indicator::Indicator)s (eg/ EMA, MACD, RSI, ATR) with O(1)
+/// updates.
+pub mod indicator;
+
+/// Library of production-shaped [`SignalGenerator`] strategy templates (market maker, breakout,
+/// grid & funding carry), each configurable via a serde config.
+pub mod library;
+
 /// May generate an advisory [`Signal`] as a result of analysing an input [`MarketEvent`].
 pub trait SignalGenerator {
     /// Optionally return a [`Signal`] given input [`MarketEvent`].
     fn generate_signal(&mut self, market: &MarketEvent<Instrument, DataKind>) -> Option<Signal>;
+
+    /// Optionally return a [`Signal`] given a due [`ScheduledEvent`] with
+    /// [`ScheduledAction::Strategy`](crate::engine::schedule::ScheduledAction), allowing the
+    /// strategy to act on time (eg/ rebalance every 5 minutes) rather than market data.
+    fn on_schedule(&mut self, _scheduled: &ScheduledEvent) -> Option<Signal> {
+        None
+    }
+
+    /// Optionally return a [`Signal`] given a downstream defined custom event (eg/ news, model
+    /// signals) received via a [`Command::Custom`](crate::engine::Command). Strategies dispatch
+    /// on the [`CustomKind`](barter_data::event::CustomKind) using [`CustomData::decode`].
+    fn on_custom(&mut self, _custom: &CustomData) -> Option<Signal> {
+        None
+    }
+
+    /// Update the strategy configuration at runtime from a serialised config, without dropping
+    /// any other strategy state. Actioned when the [`Trader`](crate::engine::trader::Trader)
+    /// receives a [`Command::UpdateStrategyConfig`](crate::engine::Command).
+    ///
+    /// Strategies that implement [`ReconfigurableStrategy`] should override this with
+    /// [`update_config`], all others reject every update.
+    fn update_config(&mut self, _config: serde_json::Value) -> Result<(), StrategyError> {
+        Err(StrategyError::NotReconfigurable)
+    }
+}
+
+/// Strategy whose parameters (eg/ spreads, sizes, thresholds) can be updated at runtime without
+/// restarting the [`Engine`](crate::engine::Engine).
+pub trait ReconfigurableStrategy {
+    type Config: DeserializeOwned;
+
+    /// Validate & apply the new [`Self::Config`], keeping any state that is still valid (eg/
+    /// warmed up indicators, inventory).
+    fn reconfigure(&mut self, config: Self::Config) -> Result<(), StrategyError>;
+}
+
+/// Deserialise the serialised config into the [`ReconfigurableStrategy::Config`] and use it to
+/// reconfigure the strategy.
+pub fn update_config<Strategy>(
+    strategy: &mut Strategy,
+    config: serde_json::Value,
+) -> Result<(), StrategyError>
+where
+    Strategy: ReconfigurableStrategy,
+{
+    strategy.reconfigure(serde_json::from_value(config)?)
 }
 
 /// Advisory [`Signal`] for a [`Market`] detailing the [`SignalStrength`] associated with each
 /// possible [`Decision`]. Interpreted by an [`OrderGenerator`](crate::portfolio::OrderGenerator).
-#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize, JsonSchema)]
 pub struct Signal {
     pub time: DateTime<Utc>,
     pub exchange: ExchangeId,
@@ -27,7 +85,9 @@ pub struct Signal {
 }
 
 /// Describes the type of advisory signal the strategy is endorsing.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize, JsonSchema,
+)]
 pub enum Decision {
     Long,
     CloseLong,
@@ -35,6 +95,7 @@ pub enum Decision {
     CloseShort,
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for Decision {
     fn default() -> Self {
         Self::Long
@@ -64,12 +125,12 @@ impl Decision {
 }
 
 /// Strength of an advisory [`Signal`] decision produced by [`SignalGenerator`] strategy.
-#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize, JsonSchema)]
 pub struct SignalStrength(pub f64);
 
 /// Force exit Signal produced after an [`Engine`](crate::engine::Engine) receives a
 /// [`Command::ExitPosition`](crate::engine::Command) from an external source.
-#[derive(Clone, Eq, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[derive(Clone, Eq, PartialEq, PartialOrd, Debug, Deserialize, Serialize, JsonSchema)]
 pub struct SignalForceExit {
     pub time: DateTime<Utc>,
     pub exchange: ExchangeId,
@@ -107,51 +168,89 @@ impl SignalForceExit {
 mod tests {
     use super::*;
 
+    #[test]
+    fn should_update_strategy_config_if_reconfigurable() {
+        use crate::strategy::{
+            example::{Config, RSIStrategy},
+            library::breakout::{Breakout, Config as BreakoutConfig},
+        };
+
+        struct NotReconfigurable;
+        impl SignalGenerator for NotReconfigurable {
+            fn generate_signal(&mut self, _: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+                None
+            }
+        }
+
+        let mut rsi = RSIStrategy::new(Config { rsi_period: 14 });
+        assert!(rsi
+            .update_config(serde_json::json!({ "rsi_period": 7 }))
+            .is_ok());
+        assert!(matches!(
+            rsi.update_config(serde_json::json!({ "rsi_period": 0 })),
+            Err(StrategyError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            rsi.update_config(serde_json::json!({ "period": 7 })),
+            Err(StrategyError::DeserialiseConfig(_))
+        ));
+
+        let mut breakout = Breakout::new(BreakoutConfig { period: 20 });
+        assert!(breakout
+            .update_config(serde_json::json!({ "period": 10 }))
+            .is_ok());
+
+        assert!(matches!(
+            NotReconfigurable.update_config(serde_json::json!({})),
+            Err(StrategyError::NotReconfigurable)
+        ));
+    }
+
     #[test]
     fn should_return_decision_is_long() {
         let decision = Decision::Long;
-        assert_eq!(decision.is_long(), true)
+        assert!(decision.is_long())
     }
 
     #[test]
     fn should_return_decision_is_not_long() {
         let decision = Decision::Short;
-        assert_eq!(decision.is_long(), false)
+        assert!(!decision.is_long())
     }
 
     #[test]
     fn should_return_decision_is_short() {
         let decision = Decision::Short;
-        assert_eq!(decision.is_short(), true)
+        assert!(decision.is_short())
     }
 
     #[test]
     fn should_return_decision_is_not_short() {
         let decision = Decision::Long;
-        assert_eq!(decision.is_short(), false)
+        assert!(!decision.is_short())
     }
 
     #[test]
     fn should_return_decision_is_entry() {
         let decision = Decision::Long;
-        assert_eq!(decision.is_entry(), true)
+        assert!(decision.is_entry())
     }
 
     #[test]
     fn should_return_decision_is_not_entry() {
         let decision = Decision::CloseLong;
-        assert_eq!(decision.is_entry(), false)
+        assert!(!decision.is_entry())
     }
 
     #[test]
     fn should_return_decision_is_exit() {
         let decision = Decision::CloseShort;
-        assert_eq!(decision.is_exit(), true)
+        assert!(decision.is_exit())
     }
 
     #[test]
     fn should_return_decision_is_not_exit() {
         let decision = Decision::Long;
-        assert_eq!(decision.is_exit(), false)
+        assert!(!decision.is_exit())
     }
 }
@@ -0,0 +1,255 @@
+use crate::{
+    data::MarketGenerator,
+    engine::{trader::Trader, Command, Engine},
+    event::EventTx,
+    execution::ExecutionClient,
+    portfolio::{
+        allocator::DefaultAllocator,
+        portfolio::MetaPortfolio,
+        repository::in_memory::InMemoryRepository,
+        risk::{DefaultRisk, LiquidationRisk, LiquidationRiskConfig},
+    },
+    statistic::summary::{trading::TradingSummary, Initialiser},
+    strategy::SignalGenerator,
+    system::{
+        config::{ExchangeConfig, InstrumentConfig, SystemConfig},
+        error::SystemError,
+    },
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_instrument::{instrument::Instrument, market::Market};
+use parking_lot::Mutex;
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+/// Capacity of the [`Command`] channels of the [`Engine`] & each [`Trader`].
+const COMMAND_CAPACITY: usize = 20;
+
+/// Portfolio materialised by a [`SystemBuilder`].
+pub type SystemPortfolio = MetaPortfolio<
+    InMemoryRepository<TradingSummary>,
+    DefaultAllocator,
+    LiquidationRisk<DefaultRisk>,
+    TradingSummary,
+>;
+
+/// Trading system materialised from a [`SystemConfig`] by a [`SystemBuilder`].
+#[derive(Debug)]
+pub struct System<Data, Strategy, Execution>
+where
+    Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send + 'static,
+    Strategy: SignalGenerator + Send,
+    Execution: ExecutionClient + Send,
+{
+    /// [`Engine`] trading every configured instrument, ready to be run.
+    pub engine: Engine<EventTx, TradingSummary, SystemPortfolio, Data, Strategy, Execution>,
+    /// Transmitter for sending [`Command`]s to the [`Engine`].
+    pub command_tx: mpsc::Sender<Command>,
+    /// Shared-access to the global Portfolio of the [`Engine`].
+    pub portfolio: Arc<Mutex<SystemPortfolio>>,
+}
+
+/// Materialises the [`System`] described by a validated [`SystemConfig`], replacing the
+/// bespoke wiring of the Portfolio, [`Trader`]s & [`Engine`] for each deployment.
+///
+/// The market data feed, Strategy & execution client of each [`Trader`] are constructed by the
+/// closures provided to [`SystemBuilder::build`], since they depend on the deployment (eg/ live
+/// vs historical market data).
+#[derive(Debug)]
+pub struct SystemBuilder {
+    config: SystemConfig,
+    engine_id: Uuid,
+}
+
+impl SystemBuilder {
+    /// Construct a new [`SystemBuilder`], validating the provided [`SystemConfig`].
+    pub fn new(config: SystemConfig) -> Result<Self, SystemError> {
+        config.validate()?;
+        Ok(Self {
+            config,
+            engine_id: Uuid::new_v4(),
+        })
+    }
+
+    /// Use the provided [`Engine`] identifier rather than a random one (eg/ so an
+    /// [`EngineCheckpoint`](crate::engine::checkpoint::EngineCheckpoint) can be restored).
+    pub fn engine_id(self, engine_id: Uuid) -> Self {
+        Self { engine_id, ..self }
+    }
+
+    /// Build the [`System`], constructing the components of each configured instrument's
+    /// [`Trader`] with the provided closures:
+    /// - `data`: market data feed of the [`Market`].
+    /// - `strategy`: Strategy of the [`Market`] from it's merged
+    ///   [`strategy_params`](SystemConfig::strategy_params).
+    /// - `execution`: execution client of the [`Market`], using the [`ExchangeConfig`]
+    ///   credentials, [`Environment`](barter_integration::environment::Environment) &
+    ///   [`ExecutionMode`](crate::execution::mode::ExecutionMode).
+    ///
+    /// The market streams constructed by `data` should connect using the
+    /// [`SystemConfig::connectors`], which carry each exchange's environment & proxy.
+    pub fn build<Data, Strategy, Execution, FnData, FnStrategy, FnExecution>(
+        self,
+        event_tx: EventTx,
+        mut data: FnData,
+        mut strategy: FnStrategy,
+        mut execution: FnExecution,
+    ) -> Result<System<Data, Strategy, Execution>, SystemError>
+    where
+        Data: MarketGenerator<MarketEvent<Instrument, DataKind>> + Send + 'static,
+        Strategy: SignalGenerator + Send + 'static,
+        Execution: ExecutionClient + Send + 'static,
+        FnData: FnMut(&Market, &InstrumentConfig) -> Result<Data, SystemError>,
+        FnStrategy: FnMut(&Market, serde_json::Value) -> Result<Strategy, SystemError>,
+        FnExecution: FnMut(&Market, &ExchangeConfig) -> Result<Execution, SystemError>,
+    {
+        let Self { config, engine_id } = self;
+
+        let markets = config
+            .markets()
+            .map(|(market, _)| market)
+            .collect::<Vec<_>>();
+
+        let portfolio = Arc::new(Mutex::new(
+            MetaPortfolio::builder()
+                .engine_id(engine_id)
+                .markets(markets)
+                .starting_cash(config.portfolio.starting_cash)
+                .repository(InMemoryRepository::new())
+                .allocation_manager(DefaultAllocator {
+                    default_order_value: config.portfolio.default_order_value,
+                })
+                .risk_manager(LiquidationRisk {
+                    config: config
+                        .risk
+                        .liquidation
+                        .clone()
+                        .unwrap_or_else(LiquidationRiskConfig::disabled),
+                    risk: DefaultRisk {},
+                })
+                .statistic_config(config.portfolio.statistic)
+                .build_and_init()?,
+        ));
+
+        let mut traders = Vec::new();
+        let mut trader_command_txs = HashMap::new();
+        for exchange in &config.exchanges {
+            for instrument in &exchange.instruments {
+                let market = Market::new(exchange.exchange, instrument.instrument.clone());
+                let (trader_command_tx, trader_command_rx) = mpsc::channel(COMMAND_CAPACITY);
+
+                traders.push(
+                    Trader::builder()
+                        .engine_id(engine_id)
+                        .market(market.clone())
+                        .command_rx(trader_command_rx)
+                        .event_tx(event_tx.clone())
+                        .portfolio(Arc::clone(&portfolio))
+                        .data(data(&market, instrument)?)
+                        .strategy(strategy(&market, config.strategy_params(instrument))?)
+                        .execution(execution(&market, exchange)?)
+                        .build()?,
+                );
+                trader_command_txs.insert(market, trader_command_tx);
+            }
+        }
+
+        let (command_tx, command_rx) = mpsc::channel(COMMAND_CAPACITY);
+        let engine = Engine::builder()
+            .engine_id(engine_id)
+            .command_rx(command_rx)
+            .portfolio(Arc::clone(&portfolio))
+            .traders(traders)
+            .trader_command_txs(trader_command_txs)
+            .statistics_summary(TradingSummary::init(config.portfolio.statistic))
+            .topology(config.topology)
+            .build()?;
+
+        Ok(System {
+            engine,
+            command_tx,
+            portfolio,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        data::historical,
+        execution::simulated::{Config as ExecutionConfig, SimulatedExecution},
+        strategy::example::{Config as StrategyConfig, RSIStrategy},
+        test_util::market_event_trade,
+    };
+    use barter_integration::Side;
+
+    #[tokio::test]
+    async fn test_system_builder_build() {
+        let config = SystemConfig::from_json(
+            r#"{
+                "exchanges": [
+                    {
+                        "exchange": "binance_spot",
+                        "instruments": [
+                            {
+                                "base": "btc",
+                                "quote": "usdt",
+                                "instrument_kind": "spot",
+                                "subscriptions": ["PublicTrades"]
+                            },
+                            {
+                                "base": "eth",
+                                "quote": "usdt",
+                                "instrument_kind": "spot",
+                                "subscriptions": ["PublicTrades"],
+                                "strategy": {"rsi_period": 7}
+                            }
+                        ]
+                    }
+                ],
+                "portfolio": {
+                    "starting_cash": 10000.0,
+                    "default_order_value": 100.0,
+                    "statistic": {
+                        "starting_equity": 10000.0,
+                        "trading_days_per_year": 365,
+                        "risk_free_return": 0.0
+                    }
+                },
+                "strategy": {"rsi_period": 14}
+            }"#,
+        )
+        .unwrap();
+
+        let (event_tx, _event_rx) = mpsc::unbounded_channel();
+        let mut rsi_periods = vec![];
+
+        let system = SystemBuilder::new(config)
+            .unwrap()
+            .build(
+                EventTx::new(event_tx),
+                |_, _| {
+                    Ok(historical::MarketFeed::new(
+                        vec![market_event_trade(Side::Buy)].into_iter(),
+                    ))
+                },
+                |_, params| {
+                    let config = serde_json::from_value::<StrategyConfig>(params)?;
+                    rsi_periods.push(config.rsi_period);
+                    Ok(RSIStrategy::new(config))
+                },
+                |_, _| Ok(SimulatedExecution::new(ExecutionConfig::default())),
+            )
+            .unwrap();
+
+        assert_eq!(rsi_periods, vec![14, 7]);
+        assert!(system.command_tx.capacity() > 0);
+
+        // Engine stops once every Trader has consumed it's historical MarketFeed
+        tokio::time::timeout(std::time::Duration::from_secs(1), system.engine.run())
+            .await
+            .expect("System Engine failed to stop after MarketFeeds finished");
+    }
+}
@@ -0,0 +1,611 @@
+use crate::{
+    execution::mode::{ExecutionMode, ExecutionModeConfig},
+    portfolio::risk::LiquidationRiskConfig,
+    statistic::summary::trading::Config as StatisticConfig,
+    system::{error::SystemError, topology::ThreadTopology},
+};
+use barter_data::{
+    exchange::config::ConnectorConfig,
+    subscription::{params::SubscriptionParams, SubKind, Subscription},
+};
+use barter_instrument::{exchange::ExchangeId, instrument::Instrument, market::Market};
+use barter_integration::{environment::Environment, protocol::proxy::ProxyConfig};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    path::Path,
+};
+
+/// Configuration of a full trading system, describing the exchanges, instruments & market data
+/// subscriptions to trade, the execution credentials, risk limits & strategy parameters.
+///
+/// Every type is (de)serialisable with serde, so a [`SystemConfig`] can be deserialised from any
+/// serde format. [`SystemConfig::load`] natively supports JSON, TOML & YAML files.
+///
+/// Use a [`SystemBuilder`](super::builder::SystemBuilder) to materialise the
+/// [`System`](super::builder::System) described by a [`SystemConfig`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct SystemConfig {
+    /// Exchanges to trade, along with their instruments & market data subscriptions.
+    pub exchanges: Vec<ExchangeConfig>,
+    /// Global Portfolio configuration.
+    pub portfolio: PortfolioConfig,
+    /// Risk limits applied to every `OrderEvent` generated by the Portfolio.
+    #[serde(default)]
+    pub risk: RiskConfig,
+    /// Strategy parameters used for every instrument, unless overridden by the
+    /// [`InstrumentConfig`].
+    #[serde(default)]
+    pub strategy: serde_json::Value,
+    /// [`ThreadTopology`] of the [`Engine`](crate::engine::Engine) & it's
+    /// [`Trader`](crate::engine::trader::Trader)s.
+    #[serde(default)]
+    pub topology: ThreadTopology,
+}
+
+/// Configuration of an exchange traded by a [`SystemConfig`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ExchangeConfig {
+    pub exchange: ExchangeId,
+    /// [`Environment`] of the exchange market data & execution APIs, defaulting to
+    /// [`Environment::Mainnet`].
+    #[serde(default)]
+    pub environment: Environment,
+    /// Optional [`ProxyConfig`] that the exchange market data connections are routed through.
+    #[serde(default)]
+    pub proxy: Option<ProxyConfig>,
+    /// [`ExecutionMode`] of the exchange, defaulting to dry-run (ie/ paper trading).
+    #[serde(default)]
+    pub execution: ExecutionMode,
+    /// API credentials of the exchange account, required for [`ExecutionMode::Live`].
+    #[serde(default)]
+    pub credentials: Option<Credentials>,
+    /// Instruments to trade on the exchange (1-to-1 relationship with a
+    /// [`Trader`](crate::engine::trader::Trader)).
+    pub instruments: Vec<InstrumentConfig>,
+}
+
+/// Configuration of an instrument traded by a [`SystemConfig`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct InstrumentConfig {
+    #[serde(flatten)]
+    pub instrument: Instrument,
+    /// Market data subscriptions of the instrument.
+    pub subscriptions: Vec<SubKind>,
+    /// [`SubscriptionParams`] of each [`SubKind`] (eg/ aggregated `PublicTrades`), with any
+    /// [`SubKind`] not present using the exchange defaults.
+    #[serde(default)]
+    pub subscription_params: BTreeMap<SubKind, SubscriptionParams>,
+    /// Instrument specific strategy parameters, overriding the same top level
+    /// [`SystemConfig::strategy`] parameters.
+    #[serde(default)]
+    pub strategy: Option<serde_json::Value>,
+}
+
+/// Exchange API credentials.
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct Credentials {
+    pub api_key: Secret,
+    pub api_secret: Secret,
+}
+
+/// Secret configuration value, provided inline or via an environment variable so it never has to
+/// be committed alongside the rest of the [`SystemConfig`].
+///
+/// The [`Debug`] representation never includes the secret value.
+#[derive(Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Secret {
+    Value(String),
+    Env(String),
+}
+
+impl Secret {
+    /// Resolve the secret value, reading it from the environment if required.
+    pub fn resolve(&self) -> Result<String, SystemError> {
+        match self {
+            Self::Value(value) => Ok(value.clone()),
+            Self::Env(var) => std::env::var(var).map_err(|_| {
+                SystemError::InvalidConfig(format!("environment variable {var} is not set"))
+            }),
+        }
+    }
+}
+
+impl std::fmt::Debug for Secret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Value(_) => f.write_str("Value(<redacted>)"),
+            Self::Env(var) => f.debug_tuple("Env").field(var).finish(),
+        }
+    }
+}
+
+/// Global Portfolio configuration of a [`SystemConfig`].
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct PortfolioConfig {
+    pub starting_cash: Decimal,
+    /// Quote value of a full strength entry `OrderEvent`, see
+    /// [`DefaultAllocator`](crate::portfolio::allocator::DefaultAllocator).
+    pub default_order_value: Decimal,
+    pub statistic: StatisticConfig,
+}
+
+/// Risk limits of a [`SystemConfig`].
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct RiskConfig {
+    /// Optional [`LiquidationRisk`](crate::portfolio::risk::LiquidationRisk) check of derivative
+    /// entry `OrderEvent`s.
+    #[serde(default)]
+    pub liquidation: Option<LiquidationRiskConfig>,
+}
+
+impl SystemConfig {
+    /// Load & [`validate`](Self::validate) a [`SystemConfig`] from a JSON, TOML or YAML file,
+    /// with the format determined by the file extension (json, toml, yaml or yml).
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, SystemError> {
+        let path = path.as_ref();
+        let parse = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Self::from_json,
+            Some("toml") => Self::from_toml,
+            Some("yaml" | "yml") => Self::from_yaml,
+            extension => {
+                return Err(SystemError::UnsupportedConfigFormat(
+                    extension.unwrap_or_default().to_owned(),
+                ))
+            }
+        };
+
+        parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Deserialise & [`validate`](Self::validate) a [`SystemConfig`] from a JSON string.
+    pub fn from_json(input: &str) -> Result<Self, SystemError> {
+        serde_json::from_str::<Self>(input)?.validated()
+    }
+
+    /// Deserialise & [`validate`](Self::validate) a [`SystemConfig`] from a TOML string.
+    pub fn from_toml(input: &str) -> Result<Self, SystemError> {
+        toml::from_str::<Self>(input)?.validated()
+    }
+
+    /// Deserialise & [`validate`](Self::validate) a [`SystemConfig`] from a YAML string.
+    ///
+    /// Enum variants containing data are written as YAML tags, eg/ a [`Secret`] is written as
+    /// `api_key: !env BINANCE_API_KEY`.
+    pub fn from_yaml(input: &str) -> Result<Self, SystemError> {
+        serde_yaml::from_str::<Self>(input)?.validated()
+    }
+
+    fn validated(self) -> Result<Self, SystemError> {
+        self.validate()?;
+        Ok(self)
+    }
+
+    /// Validate the [`SystemConfig`] describes a tradable system.
+    pub fn validate(&self) -> Result<(), SystemError> {
+        let invalid = |reason: String| Err(SystemError::InvalidConfig(reason));
+
+        if self.portfolio.starting_cash <= Decimal::ZERO {
+            return invalid("portfolio starting_cash must be positive".to_owned());
+        }
+        if self.portfolio.default_order_value <= Decimal::ZERO {
+            return invalid("portfolio default_order_value must be positive".to_owned());
+        }
+
+        let mut markets = HashSet::new();
+        for exchange in &self.exchanges {
+            if exchange.execution == ExecutionMode::Live && exchange.credentials.is_none() {
+                return invalid(format!(
+                    "{} has a live ExecutionMode but no credentials",
+                    exchange.exchange
+                ));
+            }
+
+            for instrument in &exchange.instruments {
+                let market: Market = Market::new(exchange.exchange, instrument.instrument.clone());
+                if instrument.subscriptions.is_empty() {
+                    return invalid(format!("{market:?} has no market data subscriptions"));
+                }
+                if !markets.insert(market.clone()) {
+                    return invalid(format!("{market:?} is configured more than once"));
+                }
+            }
+        }
+
+        if markets.is_empty() {
+            return invalid("no instruments are configured".to_owned());
+        }
+
+        Ok(())
+    }
+
+    /// Return an [`Iterator`] over every configured [`Market`] & it's [`InstrumentConfig`].
+    pub fn markets(&self) -> impl Iterator<Item = (Market, &InstrumentConfig)> {
+        self.exchanges.iter().flat_map(|exchange| {
+            exchange.instruments.iter().map(|instrument| {
+                (
+                    Market::new(exchange.exchange, instrument.instrument.clone()),
+                    instrument,
+                )
+            })
+        })
+    }
+
+    /// Every configured market data [`Subscription`], eg/ to initialise barter-data
+    /// `DynamicStreams`.
+    pub fn subscriptions(&self) -> Vec<Subscription<ExchangeId, Instrument, SubKind>> {
+        self.exchanges
+            .iter()
+            .flat_map(|exchange| {
+                exchange.instruments.iter().flat_map(|instrument| {
+                    instrument.subscriptions.iter().map(|kind| {
+                        Subscription::new(exchange.exchange, instrument.instrument.clone(), *kind)
+                            .with_params(
+                                instrument
+                                    .subscription_params
+                                    .get(kind)
+                                    .copied()
+                                    .unwrap_or_default(),
+                            )
+                    })
+                })
+            })
+            .collect()
+    }
+
+    /// [`ConnectorConfig`] of every configured exchange's market streams, eg/ to initialise
+    /// barter-data `DynamicStreams` alongside the [`SystemConfig::subscriptions`].
+    pub fn connectors(&self) -> HashMap<ExchangeId, ConnectorConfig> {
+        self.exchanges
+            .iter()
+            .map(|exchange| {
+                (
+                    exchange.exchange,
+                    ConnectorConfig {
+                        environment: exchange.environment,
+                        proxy: exchange.proxy.clone(),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// [`ExecutionModeConfig`] of every configured exchange, eg/ to construct a
+    /// [`RoutedExecution`](crate::execution::mode::RoutedExecution).
+    pub fn execution_modes(&self) -> ExecutionModeConfig {
+        ExecutionModeConfig {
+            default: ExecutionMode::default(),
+            exchanges: self
+                .exchanges
+                .iter()
+                .map(|exchange| (exchange.exchange, exchange.execution))
+                .collect(),
+        }
+    }
+
+    /// Strategy parameters of the provided [`InstrumentConfig`], with any instrument specific
+    /// parameters overriding the top level [`SystemConfig::strategy`] parameters.
+    pub fn strategy_params(&self, instrument: &InstrumentConfig) -> serde_json::Value {
+        match (&self.strategy, &instrument.strategy) {
+            (serde_json::Value::Object(global), Some(serde_json::Value::Object(specific))) => {
+                let mut params = global.clone();
+                params.extend(specific.clone());
+                serde_json::Value::Object(params)
+            }
+            (_, Some(specific)) => specific.clone(),
+            (global, None) => global.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_instrument::instrument::kind::InstrumentKind;
+
+    fn config() -> &'static str {
+        r#"{
+            "exchanges": [
+                {
+                    "exchange": "binance_spot",
+                    "environment": "testnet",
+                    "execution": "Live",
+                    "credentials": {
+                        "api_key": {"env": "BINANCE_API_KEY"},
+                        "api_secret": {"value": "secret"}
+                    },
+                    "instruments": [
+                        {
+                            "base": "btc",
+                            "quote": "usdt",
+                            "instrument_kind": "spot",
+                            "subscriptions": ["PublicTrades", "OrderBooksL1"],
+                            "subscription_params": {"PublicTrades": {"aggregated_trades": true}},
+                            "strategy": {"rsi_period": 7}
+                        }
+                    ]
+                },
+                {
+                    "exchange": "okx",
+                    "proxy": {"url": "socks5h://127.0.0.1:1080"},
+                    "instruments": [
+                        {
+                            "base": "eth",
+                            "quote": "usdt",
+                            "instrument_kind": "spot",
+                            "subscriptions": ["PublicTrades"]
+                        }
+                    ]
+                }
+            ],
+            "portfolio": {
+                "starting_cash": 10000.0,
+                "default_order_value": 100.0,
+                "statistic": {
+                    "starting_equity": 10000.0,
+                    "trading_days_per_year": 365,
+                    "risk_free_return": 0.0
+                }
+            },
+            "strategy": {"rsi_period": 14, "oversold": 30}
+        }"#
+    }
+
+    fn config_toml() -> &'static str {
+        r#"
+            strategy = { rsi_period = 14, oversold = 30 }
+
+            [[exchanges]]
+            exchange = "binance_spot"
+            environment = "testnet"
+            execution = "Live"
+            credentials = { api_key = { env = "BINANCE_API_KEY" }, api_secret = { value = "secret" } }
+
+            [[exchanges.instruments]]
+            base = "btc"
+            quote = "usdt"
+            instrument_kind = "spot"
+            subscriptions = ["PublicTrades", "OrderBooksL1"]
+            subscription_params = { PublicTrades = { aggregated_trades = true } }
+            strategy = { rsi_period = 7 }
+
+            [[exchanges]]
+            exchange = "okx"
+            proxy = { url = "socks5h://127.0.0.1:1080" }
+
+            [[exchanges.instruments]]
+            base = "eth"
+            quote = "usdt"
+            instrument_kind = "spot"
+            subscriptions = ["PublicTrades"]
+
+            [portfolio]
+            starting_cash = 10000.0
+            default_order_value = 100.0
+            statistic = { starting_equity = 10000.0, trading_days_per_year = 365, risk_free_return = 0.0 }
+        "#
+    }
+
+    fn config_yaml() -> &'static str {
+        r#"
+            exchanges:
+              - exchange: binance_spot
+                environment: testnet
+                execution: Live
+                credentials:
+                  api_key: !env BINANCE_API_KEY
+                  api_secret: !value secret
+                instruments:
+                  - base: btc
+                    quote: usdt
+                    instrument_kind: spot
+                    subscriptions: [PublicTrades, OrderBooksL1]
+                    subscription_params: { PublicTrades: { aggregated_trades: true } }
+                    strategy: { rsi_period: 7 }
+              - exchange: okx
+                proxy: { url: "socks5h://127.0.0.1:1080" }
+                instruments:
+                  - base: eth
+                    quote: usdt
+                    instrument_kind: spot
+                    subscriptions: [PublicTrades]
+            portfolio:
+              starting_cash: 10000.0
+              default_order_value: 100.0
+              statistic:
+                starting_equity: 10000.0
+                trading_days_per_year: 365
+                risk_free_return: 0.0
+            strategy: { rsi_period: 14, oversold: 30 }
+        "#
+    }
+
+    #[test]
+    fn test_system_config_load() {
+        struct TestCase {
+            extension: &'static str,
+            input: &'static str,
+            expected_ok: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: JSON
+                extension: "json",
+                input: config(),
+                expected_ok: true,
+            },
+            TestCase {
+                // TC1: TOML
+                extension: "toml",
+                input: config_toml(),
+                expected_ok: true,
+            },
+            TestCase {
+                // TC2: YAML
+                extension: "yaml",
+                input: config_yaml(),
+                expected_ok: true,
+            },
+            TestCase {
+                // TC3: YAML with short extension
+                extension: "yml",
+                input: config_yaml(),
+                expected_ok: true,
+            },
+            TestCase {
+                // TC4: format not matching the extension
+                extension: "toml",
+                input: config(),
+                expected_ok: false,
+            },
+            TestCase {
+                // TC5: unsupported extension
+                extension: "ini",
+                input: config(),
+                expected_ok: false,
+            },
+        ];
+
+        let expected = SystemConfig::from_json(config()).unwrap();
+        let directory =
+            std::env::temp_dir().join(format!("barter_config_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&directory).unwrap();
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let path = directory.join(format!("config_{index}.{}", test.extension));
+            std::fs::write(&path, test.input).unwrap();
+
+            match SystemConfig::load(&path) {
+                Ok(actual) => {
+                    assert!(test.expected_ok, "TC{index} failed");
+                    assert_eq!(actual, expected, "TC{index} failed");
+                }
+                Err(_) => assert!(!test.expected_ok, "TC{index} failed"),
+            }
+        }
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_system_config_from_json() {
+        let config = SystemConfig::from_json(config()).unwrap();
+
+        assert_eq!(
+            config.subscriptions(),
+            vec![
+                Subscription::new(
+                    ExchangeId::BinanceSpot,
+                    Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                    SubKind::PublicTrades,
+                )
+                .with_params(SubscriptionParams::aggregated_trades()),
+                Subscription::new(
+                    ExchangeId::BinanceSpot,
+                    Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                    SubKind::OrderBooksL1,
+                ),
+                Subscription::new(
+                    ExchangeId::Okx,
+                    Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
+                    SubKind::PublicTrades,
+                ),
+            ]
+        );
+
+        assert_eq!(config.exchanges[0].environment, Environment::Testnet);
+        assert_eq!(config.exchanges[1].environment, Environment::Mainnet);
+        assert_eq!(
+            config.connectors(),
+            HashMap::from([
+                (
+                    ExchangeId::BinanceSpot,
+                    ConnectorConfig::new(Environment::Testnet)
+                ),
+                (
+                    ExchangeId::Okx,
+                    ConnectorConfig::new(Environment::Mainnet)
+                        .with_proxy(ProxyConfig::new("socks5h://127.0.0.1:1080").unwrap())
+                ),
+            ])
+        );
+
+        assert_eq!(
+            config.execution_modes().mode(ExchangeId::BinanceSpot),
+            ExecutionMode::Live
+        );
+        assert_eq!(
+            config.execution_modes().mode(ExchangeId::Okx),
+            ExecutionMode::DryRun
+        );
+
+        let params = config
+            .markets()
+            .map(|(_, instrument)| config.strategy_params(instrument))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            params,
+            vec![
+                serde_json::json!({"rsi_period": 7, "oversold": 30}),
+                serde_json::json!({"rsi_period": 14, "oversold": 30}),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_system_config_validate() {
+        struct TestCase {
+            modify: fn(&mut SystemConfig),
+            expected_valid: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: valid config
+                modify: |_| {},
+                expected_valid: true,
+            },
+            TestCase {
+                // TC1: live execution without credentials
+                modify: |config| config.exchanges[0].credentials = None,
+                expected_valid: false,
+            },
+            TestCase {
+                // TC2: instrument without subscriptions
+                modify: |config| config.exchanges[1].instruments[0].subscriptions.clear(),
+                expected_valid: false,
+            },
+            TestCase {
+                // TC3: duplicate instrument
+                modify: |config| {
+                    let duplicate = config.exchanges[1].instruments[0].clone();
+                    config.exchanges[1].instruments.push(duplicate);
+                },
+                expected_valid: false,
+            },
+            TestCase {
+                // TC4: non-positive starting cash
+                modify: |config| config.portfolio.starting_cash = Decimal::ZERO,
+                expected_valid: false,
+            },
+            TestCase {
+                // TC5: no instruments
+                modify: |config| config.exchanges.clear(),
+                expected_valid: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut config = serde_json::from_str::<SystemConfig>(config()).unwrap();
+            (test.modify)(&mut config);
+            assert_eq!(
+                config.validate().is_ok(),
+                test.expected_valid,
+                "TC{index} failed"
+            );
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{
+    engine::error::EngineError, portfolio::error::PortfolioError, system::topology::CoreId,
+};
+use thiserror::Error;
+
+/// All errors generated in the barter::system module.
+#[derive(Error, Debug)]
+pub enum SystemError {
+    #[error("Failed to pin thread to {core}: {error}")]
+    Affinity { core: CoreId, error: std::io::Error },
+
+    #[error("Thread core affinity is not supported on this platform")]
+    AffinityUnsupported,
+
+    #[error("Failed to build tokio runtime: {0}")]
+    Runtime(std::io::Error),
+
+    #[error("Invalid SystemConfig: {0}")]
+    InvalidConfig(String),
+
+    #[error("Unsupported SystemConfig file format: {0:?}")]
+    UnsupportedConfigFormat(String),
+
+    #[error("Failed to read SystemConfig: {0}")]
+    ConfigIo(#[from] std::io::Error),
+
+    #[error("Failed to (de)serialise SystemConfig: {0}")]
+    ConfigSerde(#[from] serde_json::Error),
+
+    #[error("Failed to deserialise TOML SystemConfig: {0}")]
+    ConfigToml(#[from] toml::de::Error),
+
+    #[error("Failed to deserialise YAML SystemConfig: {0}")]
+    ConfigYaml(#[from] serde_yaml::Error),
+
+    #[error("Failed to build Portfolio: {0}")]
+    Portfolio(#[from] PortfolioError),
+
+    #[error("Failed to build Engine: {0}")]
+    Engine(#[from] EngineError),
+}
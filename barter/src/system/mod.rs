@@ -0,0 +1,24 @@
+/// Barter system module specific errors.
+pub mod error;
+
+/// Typed [`SystemConfig`](config::SystemConfig) describing the exchanges, instruments,
+/// subscriptions, execution credentials, risk limits & strategy parameters of a trading system.
+pub mod config;
+
+/// [`SystemBuilder`](builder::SystemBuilder) that materialises the trading system described by a
+/// [`SystemConfig`](config::SystemConfig).
+pub mod builder;
+
+/// Thread topology of a trading system, pinning the market data consumer, [`Engine`] loop &
+/// execution threads to specific cores (core affinity).
+///
+/// [`Engine`]: crate::engine::Engine
+pub mod topology;
+
+/// Lightweight [`Supervisor`](supervisor::Supervisor) of the market data tasks, execution managers
+/// & [`Engine`] loop of a trading system, restarting each according to it's
+/// [`RestartPolicy`](supervisor::RestartPolicy) & emitting
+/// [`HealthEvent`](supervisor::HealthEvent)s.
+///
+/// [`Engine`]: crate::engine::Engine
+pub mod supervisor;
@@ -0,0 +1,468 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
+use std::{any::Any, fmt::Display, future::Future, time::Duration};
+use tokio::{sync::mpsc, task::JoinHandle};
+use tracing::{error, info, warn};
+
+/// Category of a [`Component`] supervised by a [`Supervisor`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentKind {
+    /// Market data task (eg/ a barter-data `Streams` consumer of an exchange).
+    MarketData,
+    /// Execution manager (eg/ an exchange order & account stream task).
+    Execution,
+    /// [`Engine`](crate::engine::Engine) loop.
+    Engine,
+    /// Any other downstream defined task.
+    Other,
+}
+
+/// Uniquely named task of a trading system supervised by a [`Supervisor`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct Component {
+    pub kind: ComponentKind,
+    /// Unique name of the [`Component`] (eg/ "binance_spot").
+    pub name: SmolStr,
+}
+
+impl Component {
+    /// Construct a new [`Component`].
+    pub fn new<S>(kind: ComponentKind, name: S) -> Self
+    where
+        S: Into<SmolStr>,
+    {
+        Self {
+            kind,
+            name: name.into(),
+        }
+    }
+}
+
+/// Determines if a [`Supervisor`] restarts a [`Component`] once it has stopped.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RestartPolicy {
+    /// Restart the [`Component`] however it stopped.
+    Always,
+    /// Restart the [`Component`] only if it failed or panicked.
+    #[default]
+    OnFailure,
+    /// Never restart the [`Component`].
+    Never,
+}
+
+impl RestartPolicy {
+    /// Determines if a [`Component`] that stopped with the provided [`ComponentExit`] should be
+    /// restarted.
+    pub fn should_restart(&self, exit: &ComponentExit) -> bool {
+        match self {
+            Self::Always => true,
+            Self::OnFailure => !matches!(exit, ComponentExit::Completed),
+            Self::Never => false,
+        }
+    }
+}
+
+/// [`RestartPolicy`] & exponential backoff of a supervised [`Component`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RestartConfig {
+    pub policy: RestartPolicy,
+    /// Delay before the first restart, doubled for every subsequent restart.
+    pub initial_backoff: Duration,
+    /// Upper bound of the delay between restarts.
+    pub max_backoff: Duration,
+    /// Maximum number of consecutive restarts before the [`Supervisor`] gives up on the
+    /// [`Component`], or unlimited if `None`.
+    pub max_restarts: Option<u32>,
+    /// Run duration after which the [`Component`] is considered stable, resetting it's restart
+    /// count (& so it's backoff) once it stops. Never reset if `None`.
+    pub stable_period: Option<Duration>,
+}
+
+impl Default for RestartConfig {
+    fn default() -> Self {
+        Self {
+            policy: RestartPolicy::default(),
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(30),
+            max_restarts: None,
+            stable_period: Some(Duration::from_secs(60)),
+        }
+    }
+}
+
+impl RestartConfig {
+    /// Delay before restarting a [`Component`] that has already been restarted the provided
+    /// number of times.
+    pub fn backoff(&self, restarts: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2_u32.saturating_pow(restarts))
+            .min(self.max_backoff)
+    }
+}
+
+/// How a single run of a supervised [`Component`] stopped.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentExit {
+    Completed,
+    Failed(String),
+    Panicked(String),
+}
+
+/// Status of a supervised [`Component`], sent in a [`HealthEvent`] whenever it changes.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentStatus {
+    /// [`Component`] is running, having been restarted the provided number of times.
+    Running { restarts: u32 },
+    /// [`Component`] has stopped. Unless followed by [`ComponentStatus::Restarting`], it will not
+    /// be restarted.
+    Stopped(ComponentExit),
+    /// [`Component`] will be restarted after the provided delay.
+    Restarting { restarts: u32, delay: Duration },
+    /// [`Component`] reached it's `max_restarts`, and will not be restarted.
+    GaveUp { restarts: u32 },
+}
+
+/// Change in the [`ComponentStatus`] of a [`Component`] supervised by a [`Supervisor`].
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct HealthEvent {
+    pub time: DateTime<Utc>,
+    pub component: Component,
+    pub status: ComponentStatus,
+}
+
+/// Lightweight supervisor of the tasks of a trading system (eg/ market data tasks, execution
+/// managers & the [`Engine`](crate::engine::Engine) loop).
+///
+/// Each supervised [`Component`] is run as it's own tokio task, so a panic is contained & observed
+/// rather than silently degrading the system. Once it stops, it is restarted according to it's
+/// [`RestartConfig`], and every change in it's [`ComponentStatus`] is sent as a [`HealthEvent`]
+/// to the optional health transmitter.
+#[derive(Debug, Default)]
+pub struct Supervisor {
+    health_tx: Option<mpsc::UnboundedSender<HealthEvent>>,
+    components: Vec<(Component, JoinHandle<()>)>,
+}
+
+impl Supervisor {
+    /// Construct a new [`Supervisor`] without any supervised [`Component`]s.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send a [`HealthEvent`] for every change in the [`ComponentStatus`] of a supervised
+    /// [`Component`] to the provided health transmitter.
+    pub fn with_health_tx(self, health_tx: mpsc::UnboundedSender<HealthEvent>) -> Self {
+        Self {
+            health_tx: Some(health_tx),
+            ..self
+        }
+    }
+
+    /// Supervise the [`Component`] run by the provided closure, which is called again to
+    /// construct a fresh run every time the [`Component`] is restarted.
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn supervise<FnRun, Fut, E>(
+        &mut self,
+        component: Component,
+        config: RestartConfig,
+        run: FnRun,
+    ) where
+        FnRun: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+        E: Display + Send + 'static,
+    {
+        let handle = tokio::spawn(supervise(
+            component.clone(),
+            config,
+            run,
+            self.health_tx.clone(),
+        ));
+        self.components.push((component, handle));
+    }
+
+    /// Supervised [`Component`]s, in the order they were supervised.
+    pub fn components(&self) -> impl Iterator<Item = &Component> {
+        self.components.iter().map(|(component, _)| component)
+    }
+
+    /// Wait until every supervised [`Component`] has stopped & will not be restarted.
+    pub async fn join(self) {
+        for (component, handle) in self.components {
+            if let Err(error) = handle.await {
+                error!(?component, %error, "Supervisor task of Component failed");
+            }
+        }
+    }
+}
+
+/// Run the [`Component`] until it stops & it's [`RestartConfig`] determines it should not be
+/// restarted.
+async fn supervise<FnRun, Fut, E>(
+    component: Component,
+    config: RestartConfig,
+    mut run: FnRun,
+    health_tx: Option<mpsc::UnboundedSender<HealthEvent>>,
+) where
+    FnRun: FnMut() -> Fut + Send + 'static,
+    Fut: Future<Output = Result<(), E>> + Send + 'static,
+    E: Display + Send + 'static,
+{
+    let send_health = |status: ComponentStatus| {
+        if let Some(health_tx) = &health_tx {
+            let _ = health_tx.send(HealthEvent {
+                time: Utc::now(),
+                component: component.clone(),
+                status,
+            });
+        }
+    };
+
+    let mut restarts = 0;
+    loop {
+        info!(?component, restarts, "Supervisor running Component");
+        send_health(ComponentStatus::Running { restarts });
+
+        let started = tokio::time::Instant::now();
+        let exit = match tokio::spawn(run()).await {
+            Ok(Ok(())) => ComponentExit::Completed,
+            Ok(Err(error)) => ComponentExit::Failed(error.to_string()),
+            Err(error) if error.is_panic() => {
+                ComponentExit::Panicked(panic_message(error.into_panic()))
+            }
+            Err(error) => ComponentExit::Failed(error.to_string()),
+        };
+
+        match &exit {
+            ComponentExit::Completed => info!(?component, "Component completed"),
+            ComponentExit::Failed(reason) => warn!(?component, %reason, "Component failed"),
+            ComponentExit::Panicked(reason) => error!(?component, %reason, "Component panicked"),
+        }
+
+        let restart = config.policy.should_restart(&exit);
+        send_health(ComponentStatus::Stopped(exit));
+        if !restart {
+            return;
+        }
+
+        // Component ran long enough to be considered recovered, so restart with a fresh backoff
+        if config
+            .stable_period
+            .is_some_and(|stable_period| started.elapsed() >= stable_period)
+        {
+            restarts = 0;
+        }
+
+        if config.max_restarts.is_some_and(|max| restarts >= max) {
+            error!(
+                ?component,
+                restarts, "Supervisor giving up on Component after reaching max_restarts"
+            );
+            send_health(ComponentStatus::GaveUp { restarts });
+            return;
+        }
+
+        let delay = config.backoff(restarts);
+        restarts += 1;
+        send_health(ComponentStatus::Restarting { restarts, delay });
+        tokio::time::sleep(delay).await;
+    }
+}
+
+/// Extract the message of a panic payload.
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&'static str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic payload".to_owned(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    };
+
+    #[test]
+    fn test_restart_config_should_restart_with_backoff() {
+        struct TestCase {
+            policy: RestartPolicy,
+            exit: ComponentExit,
+            expected: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Always restarts a Completed Component
+                policy: RestartPolicy::Always,
+                exit: ComponentExit::Completed,
+                expected: true,
+            },
+            TestCase {
+                // TC1: OnFailure does not restart a Completed Component
+                policy: RestartPolicy::OnFailure,
+                exit: ComponentExit::Completed,
+                expected: false,
+            },
+            TestCase {
+                // TC2: OnFailure restarts a Panicked Component
+                policy: RestartPolicy::OnFailure,
+                exit: ComponentExit::Panicked("boom".to_owned()),
+                expected: true,
+            },
+            TestCase {
+                // TC3: Never does not restart a Failed Component
+                policy: RestartPolicy::Never,
+                exit: ComponentExit::Failed("disconnected".to_owned()),
+                expected: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.policy.should_restart(&test.exit);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+
+        let config = RestartConfig {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(500),
+            ..RestartConfig::default()
+        };
+        let backoffs = (0..5).map(|restarts| config.backoff(restarts));
+        assert!(backoffs.eq([100, 200, 400, 500, 500].map(Duration::from_millis)));
+    }
+
+    #[tokio::test]
+    async fn test_supervisor_restarts_failed_and_panicked_component() {
+        let (health_tx, mut health_rx) = mpsc::unbounded_channel();
+        let mut supervisor = Supervisor::new().with_health_tx(health_tx);
+
+        let component = Component::new(ComponentKind::MarketData, "binance_spot");
+        let config = RestartConfig {
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(1),
+            ..RestartConfig::default()
+        };
+
+        // Component fails, then panics, then completes
+        let runs = Arc::new(AtomicU32::new(0));
+        supervisor.supervise(component.clone(), config, move || {
+            let run = runs.fetch_add(1, Ordering::SeqCst);
+            async move {
+                match run {
+                    0 => Err("disconnected"),
+                    1 => panic!("boom"),
+                    _ => Ok(()),
+                }
+            }
+        });
+        supervisor.join().await;
+
+        let delay = Duration::from_millis(1);
+        let expected = vec![
+            ComponentStatus::Running { restarts: 0 },
+            ComponentStatus::Stopped(ComponentExit::Failed("disconnected".to_owned())),
+            ComponentStatus::Restarting { restarts: 1, delay },
+            ComponentStatus::Running { restarts: 1 },
+            ComponentStatus::Stopped(ComponentExit::Panicked("boom".to_owned())),
+            ComponentStatus::Restarting { restarts: 2, delay },
+            ComponentStatus::Running { restarts: 2 },
+            ComponentStatus::Stopped(ComponentExit::Completed),
+        ];
+
+        let mut actual = Vec::new();
+        while let Ok(event) = health_rx.try_recv() {
+            assert_eq!(event.component, component);
+            actual.push(event.status);
+        }
+        assert_eq!(actual, expected);
+    }
+
+    #[tokio::test]
+    async fn test_supervisor_gives_up_after_max_restarts() {
+        let (health_tx, mut health_rx) = mpsc::unbounded_channel();
+        let mut supervisor = Supervisor::new().with_health_tx(health_tx);
+
+        let config = RestartConfig {
+            policy: RestartPolicy::Always,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(1),
+            max_restarts: Some(1),
+            stable_period: None,
+        };
+        supervisor.supervise(
+            Component::new(ComponentKind::Execution, "binance_spot"),
+            config,
+            || async { Ok::<(), std::convert::Infallible>(()) },
+        );
+        supervisor.join().await;
+
+        let mut last = None;
+        while let Ok(event) = health_rx.try_recv() {
+            last = Some(event.status);
+        }
+        assert_eq!(last, Some(ComponentStatus::GaveUp { restarts: 1 }));
+    }
+
+    #[tokio::test]
+    async fn test_supervisor_resets_restarts_after_stable_period() {
+        let (health_tx, mut health_rx) = mpsc::unbounded_channel();
+        let mut supervisor = Supervisor::new().with_health_tx(health_tx);
+
+        let component = Component::new(ComponentKind::MarketData, "binance_spot");
+        let config = RestartConfig {
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(10),
+            stable_period: Some(Duration::from_millis(20)),
+            ..RestartConfig::default()
+        };
+
+        // Component fails immediately, then fails after a stable run, then completes
+        let runs = Arc::new(AtomicU32::new(0));
+        supervisor.supervise(component.clone(), config, move || {
+            let run = runs.fetch_add(1, Ordering::SeqCst);
+            async move {
+                match run {
+                    0 => Err("disconnected"),
+                    1 => {
+                        tokio::time::sleep(Duration::from_millis(50)).await;
+                        Err("disconnected")
+                    }
+                    _ => Ok(()),
+                }
+            }
+        });
+        supervisor.join().await;
+
+        // Restart after the stable run uses the initial backoff rather than doubling it
+        let failed = || ComponentStatus::Stopped(ComponentExit::Failed("disconnected".to_owned()));
+        let delay = Duration::from_millis(1);
+        let expected = vec![
+            ComponentStatus::Running { restarts: 0 },
+            failed(),
+            ComponentStatus::Restarting { restarts: 1, delay },
+            ComponentStatus::Running { restarts: 1 },
+            failed(),
+            ComponentStatus::Restarting { restarts: 1, delay },
+            ComponentStatus::Running { restarts: 1 },
+            ComponentStatus::Stopped(ComponentExit::Completed),
+        ];
+
+        let mut actual = Vec::new();
+        while let Ok(event) = health_rx.try_recv() {
+            actual.push(event.status);
+        }
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,219 @@
+use crate::system::error::SystemError;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+/// Identifier of a logical CPU core (eg/ `0` for `cpu0` in `/proc/cpuinfo`).
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+#[serde(transparent)]
+pub struct CoreId(pub usize);
+
+impl std::fmt::Display for CoreId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "core {}", self.0)
+    }
+}
+
+/// Determines how the [`Engine`](crate::engine::Engine) command loop is run.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EngineRuntime {
+    /// Run the [`Engine`](crate::engine::Engine) loop as a task on the caller's tokio runtime.
+    #[default]
+    Task,
+    /// Run the [`Engine`](crate::engine::Engine) loop on a dedicated thread driving it's own
+    /// single threaded tokio runtime, so it never shares a worker with other tasks.
+    DedicatedThread,
+}
+
+/// Thread topology & core affinity of a trading system, for latency sensitive deployments.
+///
+/// Every core is optional, with unpinned threads left to the OS scheduler. Cores should be
+/// isolated from the OS scheduler (eg/ `isolcpus`) for pinning to be most effective.
+///
+/// | Component | Threads |
+/// |---|---|
+/// | `traders` | [`Trader`](crate::engine::trader::Trader) threads, each consuming it's market data feed & generating orders |
+/// | `engine` | [`EngineRuntime::DedicatedThread`] [`Engine`](crate::engine::Engine) loop |
+/// | `market_data` | [`market_data_runtime`](Self::market_data_runtime) worker (eg/ running barter-data `Streams`) |
+/// | `execution` | [`execution_runtime`](Self::execution_runtime) worker (eg/ running an execution client) |
+#[derive(Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ThreadTopology {
+    /// How the [`Engine`](crate::engine::Engine) command loop is run.
+    pub engine_runtime: EngineRuntime,
+    /// Core the [`EngineRuntime::DedicatedThread`] is pinned to. Ignored for
+    /// [`EngineRuntime::Task`].
+    pub engine: Option<CoreId>,
+    /// Cores the [`Trader`](crate::engine::trader::Trader) threads are pinned to, assigned
+    /// round-robin in [`Trader`](crate::engine::trader::Trader) order.
+    pub traders: Vec<CoreId>,
+    /// Core the market data runtime worker is pinned to.
+    pub market_data: Option<CoreId>,
+    /// Core the execution runtime worker is pinned to.
+    pub execution: Option<CoreId>,
+}
+
+impl ThreadTopology {
+    /// Core the [`Trader`](crate::engine::trader::Trader) thread with the provided index is
+    /// pinned to, if any.
+    pub fn trader_core(&self, index: usize) -> Option<CoreId> {
+        (!self.traders.is_empty()).then(|| self.traders[index % self.traders.len()])
+    }
+
+    /// Build a tokio runtime for consuming market data (eg/ initialising barter-data `Streams`
+    /// within it), with a single worker pinned to the `market_data` core.
+    ///
+    /// If no core is configured, a default multi-threaded runtime is built.
+    pub fn market_data_runtime(&self) -> Result<tokio::runtime::Runtime, SystemError> {
+        pinned_runtime("barter-market-data", self.market_data)
+    }
+
+    /// Build a tokio runtime for running execution tasks, with a single worker pinned to the
+    /// `execution` core.
+    ///
+    /// If no core is configured, a default multi-threaded runtime is built.
+    pub fn execution_runtime(&self) -> Result<tokio::runtime::Runtime, SystemError> {
+        pinned_runtime("barter-execution", self.execution)
+    }
+}
+
+fn pinned_runtime(
+    name: &'static str,
+    core: Option<CoreId>,
+) -> Result<tokio::runtime::Runtime, SystemError> {
+    let mut builder = tokio::runtime::Builder::new_multi_thread();
+    builder.thread_name(name).enable_all();
+
+    if let Some(core) = core {
+        builder
+            .worker_threads(1)
+            .on_thread_start(move || pin_current_thread_or_warn(Some(core), name));
+    }
+
+    builder.build().map_err(SystemError::Runtime)
+}
+
+/// Pin the current thread to the provided [`CoreId`].
+#[cfg(target_os = "linux")]
+pub fn pin_current_thread(core: CoreId) -> Result<(), SystemError> {
+    if core.0 >= libc::CPU_SETSIZE as usize {
+        return Err(SystemError::Affinity {
+            core,
+            error: std::io::Error::from(std::io::ErrorKind::InvalidInput),
+        });
+    }
+
+    // SAFETY: cpu_set_t is a plain bitmask for which all zeroes is a valid empty set, the core
+    // is within CPU_SETSIZE, and sched_setaffinity only reads size_of::<cpu_set_t>() bytes.
+    let result = unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        libc::CPU_SET(core.0, &mut set);
+        libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &set)
+    };
+
+    match result {
+        0 => Ok(()),
+        _ => Err(SystemError::Affinity {
+            core,
+            error: std::io::Error::last_os_error(),
+        }),
+    }
+}
+
+/// Pin the current thread to the provided [`CoreId`].
+#[cfg(not(target_os = "linux"))]
+pub fn pin_current_thread(_: CoreId) -> Result<(), SystemError> {
+    Err(SystemError::AffinityUnsupported)
+}
+
+/// Pin the current thread to the provided [`CoreId`] (if any), logging rather than failing if it
+/// cannot be pinned.
+pub(crate) fn pin_current_thread_or_warn(core: Option<CoreId>, thread: &str) {
+    let Some(core) = core else {
+        return;
+    };
+
+    match pin_current_thread(core) {
+        Ok(()) => debug!(%core, thread, "pinned thread to core"),
+        Err(error) => warn!(%error, thread, "failed to pin thread to core, continuing unpinned"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thread_topology_trader_core() {
+        struct TestCase {
+            traders: Vec<CoreId>,
+            index: usize,
+            expected: Option<CoreId>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: no trader cores configured
+                traders: vec![],
+                index: 0,
+                expected: None,
+            },
+            TestCase {
+                // TC1: index within configured cores
+                traders: vec![CoreId(2), CoreId(3)],
+                index: 1,
+                expected: Some(CoreId(3)),
+            },
+            TestCase {
+                // TC2: index beyond configured cores is assigned round-robin
+                traders: vec![CoreId(2), CoreId(3)],
+                index: 4,
+                expected: Some(CoreId(2)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let topology = ThreadTopology {
+                traders: test.traders,
+                ..ThreadTopology::default()
+            };
+            assert_eq!(
+                topology.trader_core(test.index),
+                test.expected,
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_de_thread_topology() {
+        let input = r#"{"engine_runtime": "dedicated_thread", "engine": 1, "traders": [2, 3]}"#;
+
+        assert_eq!(
+            serde_json::from_str::<ThreadTopology>(input).unwrap(),
+            ThreadTopology {
+                engine_runtime: EngineRuntime::DedicatedThread,
+                engine: Some(CoreId(1)),
+                traders: vec![CoreId(2), CoreId(3)],
+                market_data: None,
+                execution: None,
+            }
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_pin_current_thread() {
+        std::thread::spawn(|| {
+            assert!(pin_current_thread(CoreId(0)).is_ok());
+            assert!(matches!(
+                pin_current_thread(CoreId(usize::MAX)),
+                Err(SystemError::Affinity { .. })
+            ));
+        })
+        .join()
+        .unwrap();
+    }
+}
@@ -1,27 +1,58 @@
 use barter::{
-    data::historical,
-    engine::{trader::Trader, Engine},
-    event::EventTx,
+    data::{
+        cache::{MarketEventCache, MarketEventCacheConfig},
+        historical, Feed, MarketEventObserver, MarketGenerator, MarketMeta,
+    },
+    engine::{
+        checkpoint::{CheckpointStore, Checkpointer, FileCheckpointStore},
+        error::EngineError,
+        priority::{priority_channel, PriorityCommand},
+        shutdown::ShutdownPolicy,
+        trader::Trader,
+        Command, Engine,
+    },
+    event::{Event, EventTx},
     execution::{
+        error::ExecutionError,
+        lifecycle::OrderStatus,
         simulated::{Config as ExecutionConfig, SimulatedExecution},
-        Fees,
+        ExecutionClient, Fees, FillEvent,
     },
     portfolio::{
         allocator::DefaultAllocator, portfolio::MetaPortfolio,
-        repository::in_memory::InMemoryRepository, risk::DefaultRisk,
+        repository::in_memory::InMemoryRepository, risk::DefaultRisk, OrderEvent,
     },
     statistic::summary::{
         trading::{Config as StatisticConfig, TradingSummary},
         Initialiser,
     },
-    strategy::example::{Config as StrategyConfig, RSIStrategy},
-    test_util::market_event_trade,
+    strategy::{
+        example::{Config as StrategyConfig, RSIStrategy},
+        library::breakout::{Breakout, Config as BreakoutConfig},
+        Decision, Signal, SignalGenerator, SignalStrength,
+    },
+    test_util::{market_event_candle, market_event_trade},
+};
+use barter_data::{
+    event::{CustomData, CustomKind, DataKind, MarketEvent},
+    subscription::SubKind,
+};
+use barter_instrument::{
+    exchange::ExchangeId,
+    instrument::{kind::InstrumentKind, Instrument},
+    market::Market,
 };
-use barter_instrument::{exchange::ExchangeId, instrument::kind::InstrumentKind, market::Market};
 use barter_integration::Side;
-use parking_lot::Mutex;
-use std::{collections::HashMap, sync::Arc, time::Duration};
-use tokio::sync::mpsc;
+use chrono::Utc;
+use parking_lot::{Mutex, RwLock};
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+    time::Duration,
+};
+use tokio::sync::{mpsc, oneshot};
 use uuid::Uuid;
 
 #[tokio::test]
@@ -47,14 +78,14 @@ async fn engine_with_historic_data_stops_after_candles_finished() {
         MetaPortfolio::builder()
             .engine_id(engine_id)
             .markets(vec![market.clone()])
-            .starting_cash(10_000.0)
+            .starting_cash(dec!(10_000))
             .repository(InMemoryRepository::new())
             .allocation_manager(DefaultAllocator {
-                default_order_value: 100.0,
+                default_order_value: dec!(100),
             })
             .risk_manager(DefaultRisk {})
             .statistic_config(StatisticConfig {
-                starting_equity: 10_000.0,
+                starting_equity: dec!(10_000),
                 trading_days_per_year: 365,
                 risk_free_return: 0.0,
             })
@@ -81,9 +112,9 @@ async fn engine_with_historic_data_stops_after_candles_finished() {
             .strategy(RSIStrategy::new(StrategyConfig { rsi_period: 14 }))
             .execution(SimulatedExecution::new(ExecutionConfig {
                 simulated_fees_pct: Fees {
-                    exchange: 0.1,
-                    slippage: 0.05,
-                    network: 0.0,
+                    exchange: dec!(0.1),
+                    slippage: dec!(0.05),
+                    network: dec!(0),
                 },
             }))
             .build()
@@ -101,7 +132,7 @@ async fn engine_with_historic_data_stops_after_candles_finished() {
         .traders(traders)
         .trader_command_txs(trader_command_txs)
         .statistics_summary(TradingSummary::init(StatisticConfig {
-            starting_equity: 1000.0,
+            starting_equity: dec!(1000),
             trading_days_per_year: 365,
             risk_free_return: 0.0,
         }))
@@ -120,3 +151,949 @@ async fn engine_with_historic_data_stops_after_candles_finished() {
         "failed because Engine's command_rx.await is blocking the Engine from stopping"
     )
 }
+
+#[tokio::test]
+async fn engine_with_breakout_strategy_enters_position_on_breakout_candle() {
+    let (_command_tx, command_rx) = mpsc::channel(20);
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let event_tx = EventTx::new(event_tx);
+    let engine_id = Uuid::new_v4();
+
+    let market = Market::new(
+        ExchangeId::BinanceSpot,
+        ("btc", "usdt", InstrumentKind::Spot),
+    );
+
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![market.clone()])
+            .starting_cash(dec!(10_000))
+            .repository(InMemoryRepository::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: dec!(100),
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: dec!(10_000),
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    // Two candles within a channel, followed by a candle closing above it
+    let candles = [
+        (1000.0, 990.0, 995.0),
+        (1005.0, 995.0, 1000.0),
+        (1020.0, 1000.0, 1015.0),
+    ]
+    .into_iter()
+    .map(|(high, low, close)| {
+        let mut market = market_event_candle();
+        if let DataKind::Candle(candle) = &mut market.kind {
+            candle.high = high;
+            candle.low = low;
+            candle.close = close;
+        }
+        market
+    })
+    .collect::<Vec<_>>();
+
+    let (trader_command_tx, trader_command_rx) = mpsc::channel(10);
+    let trader = Trader::builder()
+        .engine_id(engine_id)
+        .market(market.clone())
+        .command_rx(trader_command_rx)
+        .event_tx(event_tx)
+        .portfolio(Arc::clone(&portfolio))
+        .data(historical::MarketFeed::new(candles.into_iter()))
+        .strategy(Breakout::new(BreakoutConfig { period: 2 }))
+        .execution(SimulatedExecution::new(ExecutionConfig {
+            simulated_fees_pct: Fees {
+                exchange: dec!(0.1),
+                slippage: dec!(0.05),
+                network: dec!(0),
+            },
+        }))
+        .build()
+        .expect("failed to build trader");
+
+    let engine = Engine::builder()
+        .engine_id(engine_id)
+        .command_rx(command_rx)
+        .portfolio(portfolio)
+        .traders(vec![trader])
+        .trader_command_txs(HashMap::from_iter([(market, trader_command_tx)]))
+        .statistics_summary(TradingSummary::init(StatisticConfig {
+            starting_equity: dec!(10_000),
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        }))
+        .build()
+        .expect("failed to build engine");
+
+    tokio::time::timeout(Duration::from_secs(1), engine.run())
+        .await
+        .expect("Engine failed to stop after candles finished");
+
+    let mut position_opened = false;
+    while let Ok(event) = event_rx.try_recv() {
+        position_opened |= matches!(event, Event::PositionNew(_));
+    }
+    assert!(position_opened, "breakout candle failed to open a Position");
+}
+
+#[tokio::test]
+async fn engine_routes_custom_event_to_strategy() {
+    #[derive(Serialize, Deserialize)]
+    struct News {
+        sentiment: f64,
+    }
+
+    impl CustomKind for News {
+        const NAME: &'static str = "news";
+    }
+
+    // Strategy that only trades on positive News
+    struct NewsStrategy {
+        market: Market,
+    }
+
+    impl SignalGenerator for NewsStrategy {
+        fn generate_signal(&mut self, _: &MarketEvent<Instrument, DataKind>) -> Option<Signal> {
+            None
+        }
+
+        fn on_custom(&mut self, custom: &CustomData) -> Option<Signal> {
+            let news = custom.decode::<News>()?.ok()?;
+            (news.sentiment > 0.0).then(|| Signal {
+                time: Utc::now(),
+                exchange: self.market.exchange,
+                instrument: self.market.instrument.clone(),
+                signals: HashMap::from([(Decision::Long, SignalStrength(news.sentiment))]),
+                market_meta: MarketMeta::default(),
+            })
+        }
+    }
+
+    let (_command_tx, command_rx) = mpsc::channel(20);
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let event_tx = EventTx::new(event_tx);
+    let engine_id = Uuid::new_v4();
+
+    let market = Market::new(
+        ExchangeId::BinanceSpot,
+        ("btc", "usdt", InstrumentKind::Spot),
+    );
+
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![market.clone()])
+            .starting_cash(dec!(10_000))
+            .repository(InMemoryRepository::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: dec!(100),
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: dec!(10_000),
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    let (trader_command_tx, trader_command_rx) = mpsc::channel(10);
+    let custom_tx = trader_command_tx.clone();
+    let trader = Trader::builder()
+        .engine_id(engine_id)
+        .market(market.clone())
+        .command_rx(trader_command_rx)
+        .event_tx(event_tx)
+        .portfolio(Arc::clone(&portfolio))
+        .data(historical::MarketFeed::new(
+            [market_event_trade(Side::Buy)].into_iter(),
+        ))
+        .strategy(NewsStrategy {
+            market: market.clone(),
+        })
+        .execution(SimulatedExecution::new(ExecutionConfig {
+            simulated_fees_pct: Fees {
+                exchange: dec!(0.1),
+                slippage: dec!(0.05),
+                network: dec!(0),
+            },
+        }))
+        .build()
+        .expect("failed to build trader");
+
+    let engine = Engine::builder()
+        .engine_id(engine_id)
+        .command_rx(command_rx)
+        .portfolio(portfolio)
+        .traders(vec![trader])
+        .trader_command_txs(HashMap::from_iter([(market, trader_command_tx)]))
+        .statistics_summary(TradingSummary::init(StatisticConfig {
+            starting_equity: dec!(10_000),
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        }))
+        .build()
+        .expect("failed to build engine");
+
+    // Custom events are queued before the Trader's first MarketEvent
+    for sentiment in [-0.5, 0.8] {
+        custom_tx
+            .send(Command::Custom {
+                market: None,
+                event: CustomData::new(&News { sentiment }).unwrap(),
+            })
+            .await
+            .unwrap();
+    }
+
+    tokio::time::timeout(Duration::from_secs(1), engine.run())
+        .await
+        .expect("Engine failed to stop after MarketEvents finished");
+
+    let mut custom_events = 0;
+    let mut signals = Vec::new();
+    while let Ok(event) = event_rx.try_recv() {
+        match event {
+            Event::Custom(custom) => {
+                assert!(custom.is::<News>());
+                custom_events += 1;
+            }
+            Event::Signal(signal) => signals.push(signal),
+            _ => {}
+        }
+    }
+
+    assert_eq!(custom_events, 2);
+    assert_eq!(signals.len(), 1);
+    assert_eq!(
+        signals[0].signals.get(&Decision::Long),
+        Some(&SignalStrength(0.8))
+    );
+}
+
+#[tokio::test]
+async fn trader_handles_order_submit_errors_by_class() {
+    // ExecutionClient that fails to submit an OrderEvent with each queued error, before
+    // delegating to a SimulatedExecution
+    struct FailingExecution {
+        errors: VecDeque<ExecutionError>,
+        simulated: SimulatedExecution,
+    }
+
+    impl ExecutionClient for FailingExecution {
+        fn generate_fill(&self, order: &OrderEvent) -> Result<FillEvent, ExecutionError> {
+            self.simulated.generate_fill(order)
+        }
+
+        fn submit_order(
+            &mut self,
+            order: &OrderEvent,
+        ) -> Result<Option<FillEvent>, ExecutionError> {
+            match self.errors.pop_front() {
+                Some(error) => Err(error),
+                None => self.simulated.submit_order(order),
+            }
+        }
+    }
+
+    // Run a Breakout Trader that enters a Position on the third of five candles
+    async fn run(errors: Vec<barter_execution::error::ExecutionError>) -> Vec<Event> {
+        let (_command_tx, command_rx) = mpsc::channel(20);
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+        let engine_id = Uuid::new_v4();
+        let market = Market::new(
+            ExchangeId::BinanceSpot,
+            ("btc", "usdt", InstrumentKind::Spot),
+        );
+
+        let portfolio = Arc::new(Mutex::new(
+            MetaPortfolio::builder()
+                .engine_id(engine_id)
+                .markets(vec![market.clone()])
+                .starting_cash(dec!(10_000))
+                .repository(InMemoryRepository::new())
+                .allocation_manager(DefaultAllocator {
+                    default_order_value: dec!(100),
+                })
+                .risk_manager(DefaultRisk {})
+                .statistic_config(StatisticConfig {
+                    starting_equity: dec!(10_000),
+                    trading_days_per_year: 365,
+                    risk_free_return: 0.0,
+                })
+                .build_and_init()
+                .expect("failed to build & initialise MetaPortfolio"),
+        ));
+
+        let candles = [
+            (1000.0, 990.0, 995.0),
+            (1005.0, 995.0, 1000.0),
+            (1020.0, 1000.0, 1015.0),
+            (1025.0, 1010.0, 1020.0),
+            (1030.0, 1015.0, 1025.0),
+        ]
+        .into_iter()
+        .map(|(high, low, close)| {
+            let mut market = market_event_candle();
+            if let DataKind::Candle(candle) = &mut market.kind {
+                candle.high = high;
+                candle.low = low;
+                candle.close = close;
+            }
+            market
+        })
+        .collect::<Vec<_>>();
+
+        let (trader_command_tx, trader_command_rx) = mpsc::channel(10);
+        let trader = Trader::builder()
+            .engine_id(engine_id)
+            .market(market.clone())
+            .command_rx(trader_command_rx)
+            .event_tx(EventTx::new(event_tx))
+            .portfolio(Arc::clone(&portfolio))
+            .data(historical::MarketFeed::new(candles.into_iter()))
+            .strategy(Breakout::new(BreakoutConfig { period: 2 }))
+            .execution(FailingExecution {
+                errors: errors.into_iter().map(ExecutionError::from).collect(),
+                simulated: SimulatedExecution::new(ExecutionConfig {
+                    simulated_fees_pct: Fees {
+                        exchange: dec!(0.1),
+                        slippage: dec!(0.05),
+                        network: dec!(0),
+                    },
+                }),
+            })
+            .build()
+            .expect("failed to build trader");
+
+        let engine = Engine::builder()
+            .engine_id(engine_id)
+            .command_rx(command_rx)
+            .portfolio(portfolio)
+            .traders(vec![trader])
+            .trader_command_txs(HashMap::from_iter([(market, trader_command_tx)]))
+            .statistics_summary(TradingSummary::init(StatisticConfig {
+                starting_equity: dec!(10_000),
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            }))
+            .build()
+            .expect("failed to build engine");
+
+        tokio::time::timeout(Duration::from_secs(1), engine.run())
+            .await
+            .expect("Engine failed to stop");
+
+        let mut events = Vec::new();
+        while let Ok(event) = event_rx.try_recv() {
+            events.push(event);
+        }
+        events
+    }
+
+    fn count(events: &[Event], predicate: impl Fn(&Event) -> bool) -> usize {
+        events.iter().filter(|event| predicate(event)).count()
+    }
+
+    let is_market = |event: &Event| matches!(event, Event::Market(_));
+    let is_rejected = |event: &Event| matches!(event, Event::OrderLifecycle(order) if order.status == Some(OrderStatus::Rejected));
+    let is_position_new = |event: &Event| matches!(event, Event::PositionNew(_));
+
+    // Retryable error: OrderEvent is resubmitted with the next MarketEvent & enters a Position
+    let events = run(vec![barter_execution::error::ExecutionError::Connectivity(
+        "connection reset".to_string(),
+    )])
+    .await;
+    assert_eq!(count(&events, is_market), 5);
+    assert_eq!(count(&events, is_rejected), 1);
+    assert_eq!(count(&events, is_position_new), 1);
+
+    // Rejected error: OrderEvent is abandoned, but the Trader keeps trading
+    let events = run(vec![
+        barter_execution::error::ExecutionError::InvalidOrderParams("bad qty".to_string()),
+    ])
+    .await;
+    assert_eq!(count(&events, is_market), 5);
+    assert_eq!(count(&events, is_rejected), 1);
+    assert_eq!(count(&events, is_position_new), 0);
+
+    // Unrecoverable error: Trader stops before consuming the remaining MarketEvents
+    let events = run(vec![
+        barter_execution::error::ExecutionError::Authentication("invalid api key".to_string()),
+    ])
+    .await;
+    assert_eq!(count(&events, is_market), 3);
+    assert_eq!(count(&events, is_rejected), 1);
+    assert_eq!(count(&events, is_position_new), 0);
+}
+
+#[test]
+fn trader_ignores_signals_while_trading_disabled() {
+    // Run a Trader over breakout candles after the provided PriorityCommands, returning whether
+    // the breakout candle opened a Position
+    fn run(commands: Vec<PriorityCommand>) -> bool {
+        let (_trader_command_tx, trader_command_rx) = mpsc::channel(10);
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+        let (priority_tx, priority_rx) = priority_channel(10);
+        let engine_id = Uuid::new_v4();
+
+        let market = Market::new(
+            ExchangeId::BinanceSpot,
+            ("btc", "usdt", InstrumentKind::Spot),
+        );
+
+        let portfolio = Arc::new(Mutex::new(
+            MetaPortfolio::builder()
+                .engine_id(engine_id)
+                .markets(vec![market.clone()])
+                .starting_cash(dec!(10_000))
+                .repository(InMemoryRepository::<TradingSummary>::new())
+                .allocation_manager(DefaultAllocator {
+                    default_order_value: dec!(100),
+                })
+                .risk_manager(DefaultRisk {})
+                .statistic_config(StatisticConfig {
+                    starting_equity: dec!(10_000),
+                    trading_days_per_year: 365,
+                    risk_free_return: 0.0,
+                })
+                .build_and_init()
+                .expect("failed to build & initialise MetaPortfolio"),
+        ));
+
+        // Two candles within a channel, followed by a candle closing above it
+        let candles = [
+            (1000.0, 990.0, 995.0),
+            (1005.0, 995.0, 1000.0),
+            (1020.0, 1000.0, 1015.0),
+        ]
+        .into_iter()
+        .map(|(high, low, close)| {
+            let mut market = market_event_candle();
+            if let DataKind::Candle(candle) = &mut market.kind {
+                candle.high = high;
+                candle.low = low;
+                candle.close = close;
+            }
+            market
+        })
+        .collect::<Vec<_>>();
+
+        let trader: Trader<_, TradingSummary, _, _, _, _> = Trader::builder()
+            .engine_id(engine_id)
+            .market(market)
+            .command_rx(trader_command_rx)
+            .priority_rx(priority_rx)
+            .event_tx(EventTx::new(event_tx))
+            .portfolio(portfolio)
+            .data(historical::MarketFeed::new(candles))
+            .strategy(Breakout::new(BreakoutConfig { period: 2 }))
+            .execution(SimulatedExecution::new(ExecutionConfig {
+                simulated_fees_pct: Fees {
+                    exchange: dec!(0.1),
+                    slippage: dec!(0.05),
+                    network: dec!(0),
+                },
+            }))
+            .build()
+            .expect("failed to build trader");
+
+        commands.into_iter().for_each(|command| {
+            priority_tx.send(command);
+        });
+        trader.run();
+
+        let mut position_opened = false;
+        while let Ok(event) = event_rx.try_recv() {
+            position_opened |= matches!(event, Event::PositionNew(_));
+        }
+        position_opened
+    }
+
+    // Trading disabled: breakout Signal is ignored
+    assert!(!run(vec![PriorityCommand::DisableTrading(
+        "risk limit".to_string()
+    )]));
+
+    // Trading re-enabled: breakout Signal opens a Position
+    assert!(run(vec![
+        PriorityCommand::DisableTrading("risk limit".to_string()),
+        PriorityCommand::EnableTrading,
+    ]));
+}
+
+#[test]
+fn trader_skips_position_update_from_non_finite_market_price() {
+    let (_trader_command_tx, trader_command_rx) = mpsc::channel(10);
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let engine_id = Uuid::new_v4();
+
+    let market = Market::new(
+        ExchangeId::BinanceSpot,
+        ("btc", "usdt", InstrumentKind::Spot),
+    );
+
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![market.clone()])
+            .starting_cash(dec!(10_000))
+            .repository(InMemoryRepository::<TradingSummary>::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: dec!(100),
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: dec!(10_000),
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    // Breakout candle opens a Position, which is then updated by a NaN priced candle & a
+    // valid candle
+    let candles = [
+        (1000.0, 990.0, 995.0),
+        (1005.0, 995.0, 1000.0),
+        (1020.0, 1000.0, 1015.0),
+        (1020.0, 1000.0, f64::NAN),
+        (1020.0, 1000.0, 1010.0),
+    ]
+    .into_iter()
+    .map(|(high, low, close)| {
+        let mut market = market_event_candle();
+        if let DataKind::Candle(candle) = &mut market.kind {
+            candle.high = high;
+            candle.low = low;
+            candle.close = close;
+        }
+        market
+    })
+    .collect::<Vec<_>>();
+
+    let trader: Trader<_, TradingSummary, _, _, _, _> = Trader::builder()
+        .engine_id(engine_id)
+        .market(market)
+        .command_rx(trader_command_rx)
+        .event_tx(EventTx::new(event_tx))
+        .portfolio(portfolio)
+        .data(historical::MarketFeed::new(candles))
+        .strategy(Breakout::new(BreakoutConfig { period: 2 }))
+        .execution(SimulatedExecution::new(ExecutionConfig {
+            simulated_fees_pct: Fees::default(),
+        }))
+        .build()
+        .expect("failed to build trader");
+
+    // NaN priced candle is skipped rather than stopping the Trader
+    trader.run();
+
+    let (mut positions_new, mut position_updates) = (0, 0);
+    while let Ok(event) = event_rx.try_recv() {
+        match event {
+            Event::PositionNew(_) => positions_new += 1,
+            Event::PositionUpdate(_) => position_updates += 1,
+            _ => {}
+        }
+    }
+    assert_eq!(positions_new, 1);
+    assert_eq!(position_updates, 1);
+}
+
+#[test]
+fn trader_updates_market_event_observers() {
+    // Counts every MarketEvent & FillEvent observed by the Trader
+    #[derive(Debug, Default)]
+    struct Counter {
+        markets: usize,
+        fills: usize,
+    }
+
+    impl MarketEventObserver for Counter {
+        fn on_market(&mut self, _: &MarketEvent<Instrument, DataKind>) -> Option<Event> {
+            self.markets += 1;
+            None
+        }
+
+        fn on_fill(&mut self, _: &FillEvent) {
+            self.fills += 1;
+        }
+    }
+
+    let (_trader_command_tx, trader_command_rx) = mpsc::channel(10);
+    let (event_tx, _event_rx) = mpsc::unbounded_channel();
+    let engine_id = Uuid::new_v4();
+
+    let market = Market::new(
+        ExchangeId::BinanceSpot,
+        ("btc", "usdt", InstrumentKind::Spot),
+    );
+
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![market.clone()])
+            .starting_cash(dec!(10_000))
+            .repository(InMemoryRepository::<TradingSummary>::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: dec!(100),
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: dec!(10_000),
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    // Two candles within a channel, followed by a candle closing above it
+    let candles = [
+        (1000.0, 990.0, 995.0),
+        (1005.0, 995.0, 1000.0),
+        (1020.0, 1000.0, 1015.0),
+    ]
+    .into_iter()
+    .map(|(high, low, close)| {
+        let mut market = market_event_candle();
+        if let DataKind::Candle(candle) = &mut market.kind {
+            candle.high = high;
+            candle.low = low;
+            candle.close = close;
+        }
+        market
+    })
+    .collect::<Vec<_>>();
+
+    let counter = Arc::new(Mutex::new(Counter::default()));
+    let cache = Arc::new(RwLock::new(MarketEventCache::new(MarketEventCacheConfig {
+        capacity: 2,
+        kinds: None,
+    })));
+
+    let trader: Trader<_, TradingSummary, _, _, _, _> = Trader::builder()
+        .engine_id(engine_id)
+        .market(market)
+        .command_rx(trader_command_rx)
+        .event_tx(EventTx::new(event_tx))
+        .observer(Arc::clone(&counter))
+        .observer(Arc::clone(&cache))
+        .portfolio(portfolio)
+        .data(historical::MarketFeed::new(candles.clone()))
+        .strategy(Breakout::new(BreakoutConfig { period: 2 }))
+        .execution(SimulatedExecution::new(ExecutionConfig {
+            simulated_fees_pct: Fees {
+                exchange: dec!(0.1),
+                slippage: dec!(0.05),
+                network: dec!(0),
+            },
+        }))
+        .build()
+        .expect("failed to build trader");
+
+    trader.run();
+
+    // Breakout candle opens a Position, generating a single FillEvent
+    let counter = counter.lock();
+    assert_eq!(counter.markets, 3);
+    assert_eq!(counter.fills, 1);
+
+    let cache = cache.read();
+    let (exchange, instrument) = (candles[2].exchange, &candles[2].instrument);
+    assert_eq!(cache.len(exchange, instrument, SubKind::Candles), 2);
+    assert_eq!(
+        cache.get(exchange, instrument, SubKind::Candles, 0),
+        Some(&candles[2])
+    );
+}
+
+#[tokio::test]
+async fn engine_snapshot_times_out_on_stalled_trader() {
+    let (command_tx, command_rx) = mpsc::channel(20);
+    let engine = stalled_engine(Uuid::new_v4(), command_rx, None);
+
+    let commands = async {
+        // Let the Trader start waiting on the StalledFeed for it's first MarketEvent
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        // Snapshot fails once the stalled Trader does not respond within the timeout
+        let (state_tx, state_rx) = oneshot::channel();
+        command_tx
+            .send(Command::RequestSnapshot(state_tx))
+            .await
+            .unwrap();
+        let state = tokio::time::timeout(Duration::from_millis(200), state_rx)
+            .await
+            .expect("Engine blocked waiting for stalled Trader")
+            .unwrap();
+        assert!(matches!(state, Err(EngineError::TraderTimeout { .. })));
+
+        // Command::FetchOrdersPending is forwarded to the stalled Trader, and fails the same way
+        let (orders_tx, orders_rx) = oneshot::channel();
+        command_tx
+            .send(Command::FetchOrdersPending(orders_tx))
+            .await
+            .unwrap();
+        let orders = tokio::time::timeout(Duration::from_millis(200), orders_rx)
+            .await
+            .expect("Engine blocked waiting for stalled Trader")
+            .unwrap();
+        assert!(matches!(orders, Err(EngineError::TraderTimeout { .. })));
+
+        // Command loop is not blocked, so the Engine still actions Command::Terminate
+        command_tx
+            .send(Command::Terminate("test".to_string()))
+            .await
+            .unwrap();
+    };
+
+    tokio::time::timeout(Duration::from_millis(400), async {
+        tokio::join!(engine.run(), commands)
+    })
+    .await
+    .expect("Engine failed to stop");
+}
+
+#[tokio::test]
+async fn engine_skips_checkpoint_on_stalled_trader() {
+    let directory = std::env::temp_dir().join(format!("barter_checkpoint_{}", Uuid::new_v4()));
+    let store = FileCheckpointStore::new(&directory);
+
+    let engine_id = Uuid::new_v4();
+    let (command_tx, command_rx) = mpsc::channel(20);
+    let engine = stalled_engine(
+        engine_id,
+        command_rx,
+        Some(Checkpointer::new(store.clone(), Duration::from_millis(10))),
+    );
+
+    let commands = async {
+        // Checkpoint interval ticks several times while the Trader is stalled
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        // Command loop is not blocked, so the Engine still actions Command::Terminate
+        command_tx
+            .send(Command::Terminate("test".to_string()))
+            .await
+            .unwrap();
+    };
+
+    tokio::time::timeout(Duration::from_millis(400), async {
+        tokio::join!(engine.run(), commands)
+    })
+    .await
+    .expect("Engine failed to stop");
+
+    // Every checkpoint was skipped rather than saved without the Trader's in-flight OrderEvents
+    let mut store = store;
+    assert_eq!(store.load(engine_id).unwrap(), None);
+    let _ = std::fs::remove_dir_all(directory);
+}
+
+#[cfg(feature = "sql")]
+#[tokio::test]
+async fn sql_writer_persists_events_to_sqlite() {
+    use barter::{
+        event::MessageTransmitter,
+        portfolio::{
+            position::{PositionExit, PositionUpdate},
+            Balance,
+        },
+        storage::{
+            reconcile::FillRecord, sqlx::SqliteExecutor, SqlDialect, SqlExecutor, SqlStore,
+            SqlValue, SqlWriter,
+        },
+        test_util::{fill_event, position},
+    };
+    use chrono::TimeZone;
+
+    let path = std::env::temp_dir().join(format!("barter_sql_{}.db", Uuid::new_v4()));
+    let mut executor = SqliteExecutor::connect(&format!("sqlite://{}?mode=rwc", path.display()))
+        .await
+        .unwrap();
+    let store = SqlStore::new(Uuid::new_v4(), SqlDialect::Sqlite);
+
+    let time = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let mut position = position();
+    let update = PositionUpdate::from(&mut position);
+    let fill = FillEvent {
+        time,
+        ..fill_event()
+    };
+    let balance = Balance::new(time, dec!(1012.5), dec!(912.5));
+
+    // Persist the entry
+    let (writer, mut event_tx) = SqlWriter::new(store, executor.clone());
+    event_tx.send_many(vec![
+        Event::PositionNew(Box::new(position.clone())),
+        Event::Fill(fill.clone()),
+        Event::Balance(balance),
+    ]);
+    event_tx.send(Event::PositionUpdate(update));
+    drop(event_tx);
+    assert_eq!(writer.run().await.unwrap(), 0);
+
+    let open = executor.query(&store.open_positions()).await.unwrap();
+    assert_eq!(open.len(), 1);
+    assert_eq!(
+        open[0]["position_id"].as_text(),
+        Some(position.position_id.as_str())
+    );
+    assert_eq!(open[0]["quantity"].as_decimal(), Some(dec!(1)));
+    assert_eq!(open[0]["exit_time"], SqlValue::Null);
+
+    let fills = executor
+        .query(&store.fills_between(time, time + chrono::Duration::seconds(1)))
+        .await
+        .unwrap()
+        .iter()
+        .map(FillRecord::try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(fills, vec![FillRecord::from(&fill)]);
+
+    let latest = executor.query(&store.latest_balance()).await.unwrap();
+    assert_eq!(latest[0]["id"], SqlValue::Integer(1));
+    assert_eq!(latest[0]["total"].as_decimal(), Some(dec!(1012.5)));
+    assert_eq!(latest[0]["time"].as_timestamp(), Some(time));
+
+    // Persist the exit with a new SqlWriter, re-running the idempotent schema
+    let exit_time = time + chrono::Duration::minutes(1);
+    let (writer, mut event_tx) = SqlWriter::new(store, executor.clone());
+    event_tx.send(Event::PositionExit(PositionExit {
+        position_id: position.position_id.clone(),
+        exit_time,
+        exit_balance: Balance::new(exit_time, dec!(1021.5), dec!(1021.5)),
+        exit_fees: Fees::default(),
+        exit_fees_total: dec!(0.5),
+        exit_avg_price_gross: dec!(110),
+        exit_value_gross: dec!(110),
+        realised_profit_loss: dec!(9.5),
+    }));
+    drop(event_tx);
+    assert_eq!(writer.run().await.unwrap(), 0);
+
+    assert!(executor
+        .query(&store.open_positions())
+        .await
+        .unwrap()
+        .is_empty());
+    let exited = executor.query(&store.exited_positions()).await.unwrap();
+    assert_eq!(exited[0]["exit_time"].as_timestamp(), Some(exit_time));
+    assert_eq!(
+        exited[0]["realised_profit_loss"].as_decimal(),
+        Some(dec!(9.5))
+    );
+
+    std::fs::remove_file(path).unwrap();
+}
+
+// MarketGenerator that stalls the Trader waiting for it's next MarketEvent
+struct StalledFeed;
+
+impl MarketGenerator<MarketEvent<Instrument, DataKind>> for StalledFeed {
+    fn next(&mut self) -> Feed<MarketEvent<Instrument, DataKind>> {
+        std::thread::sleep(Duration::from_millis(500));
+        Feed::Finished
+    }
+}
+
+type StalledEngine = Engine<
+    EventTx,
+    TradingSummary,
+    MetaPortfolio<
+        InMemoryRepository<TradingSummary>,
+        DefaultAllocator,
+        DefaultRisk,
+        TradingSummary,
+    >,
+    StalledFeed,
+    Breakout,
+    SimulatedExecution,
+>;
+
+/// Engine with a single Trader stalled on a [`StalledFeed`] & a 10ms trader_response_timeout.
+fn stalled_engine(
+    engine_id: Uuid,
+    command_rx: mpsc::Receiver<Command>,
+    checkpoint: Option<Checkpointer>,
+) -> StalledEngine {
+    let (event_tx, _event_rx) = mpsc::unbounded_channel();
+
+    let market = Market::new(
+        ExchangeId::BinanceSpot,
+        ("btc", "usdt", InstrumentKind::Spot),
+    );
+
+    let portfolio = Arc::new(Mutex::new(
+        MetaPortfolio::builder()
+            .engine_id(engine_id)
+            .markets(vec![market.clone()])
+            .starting_cash(dec!(10_000))
+            .repository(InMemoryRepository::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: dec!(100),
+            })
+            .risk_manager(DefaultRisk {})
+            .statistic_config(StatisticConfig {
+                starting_equity: dec!(10_000),
+                trading_days_per_year: 365,
+                risk_free_return: 0.0,
+            })
+            .build_and_init()
+            .expect("failed to build & initialise MetaPortfolio"),
+    ));
+
+    let (trader_command_tx, trader_command_rx) = mpsc::channel(10);
+    let trader = Trader::builder()
+        .engine_id(engine_id)
+        .market(market.clone())
+        .command_rx(trader_command_rx)
+        .event_tx(EventTx::new(event_tx))
+        .portfolio(Arc::clone(&portfolio))
+        .data(StalledFeed)
+        .strategy(Breakout::new(BreakoutConfig { period: 2 }))
+        .execution(SimulatedExecution::new(ExecutionConfig {
+            simulated_fees_pct: Fees {
+                exchange: dec!(0.1),
+                slippage: dec!(0.05),
+                network: dec!(0),
+            },
+        }))
+        .build()
+        .expect("failed to build trader");
+
+    let engine = Engine::builder()
+        .engine_id(engine_id)
+        .command_rx(command_rx)
+        .portfolio(portfolio)
+        .traders(vec![trader])
+        .trader_command_txs(HashMap::from_iter([(market, trader_command_tx)]))
+        .statistics_summary(TradingSummary::init(StatisticConfig {
+            starting_equity: dec!(10_000),
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        }))
+        .shutdown_policy(ShutdownPolicy::stop_only(Duration::from_millis(10)))
+        .trader_response_timeout(Duration::from_millis(10));
+
+    match checkpoint {
+        Some(checkpointer) => engine.checkpoint(checkpointer),
+        None => engine,
+    }
+    .build()
+    .expect("failed to build engine")
+}